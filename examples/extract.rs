@@ -7,7 +7,6 @@ use std::path;
 use std::io::BufWriter;
 use std::fs::File;
 use pdf_extract::*;
-use lopdf::*;
 use simple_logger::SimpleLogger;
 
 fn main() {
@@ -27,7 +26,7 @@ fn main() {
     let mut doc = Document::load(path).unwrap();
 
 
-    print_metadata(&doc);
+    println!("{:?}", info::DocumentInfo::from(&doc));
 
     let mut output: Box<dyn OutputDev> = match output_kind.as_ref() {
         "txt" => Box::new(PlainTextOutput::new(&mut output_file as &mut dyn std::io::Write)),
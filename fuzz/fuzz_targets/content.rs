@@ -0,0 +1,48 @@
+// Content streams run on a fixed page
+//
+// Few mutations of a whole file still parse, so this target puts the input
+// in the content stream of a page whose resources hold a font, a form, a
+// colour space and a graphics state, and reaches the operators directly.
+// Run with `cargo +nightly fuzz run content` from the repository root.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pdf_extract::{dictionary, output_doc, Document, Object, PlainTextOutput, SVGOutput, Stream};
+
+fn page_doc(content: &[u8]) -> Document {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font = doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica" });
+    let form = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), 10.into(), 10.into()],
+    }, b"0 0 m 10 10 l S".to_vec()));
+    let content = doc.add_object(Stream::new(dictionary! {}, content.to_vec()));
+    let page = doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content,
+        "Resources" => dictionary! {
+            "Font" => dictionary! { "F1" => font },
+            "XObject" => dictionary! { "X1" => form },
+            "ColorSpace" => dictionary! { "CS1" => vec![Object::Name(b"CalGray".to_vec()), dictionary! { "WhitePoint" => vec![1.into(), 1.into(), 1.into()] }.into()] },
+            "ExtGState" => dictionary! { "GS1" => dictionary! { "LW" => 2, "CA" => 0.5 } },
+        },
+    });
+    doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page)],
+        "Count" => 1,
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }));
+    let catalog = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog);
+    doc
+}
+
+fuzz_target!(|data: &[u8]| {
+    let doc = page_doc(data);
+    let _ = output_doc(&doc, &mut PlainTextOutput::new(std::io::sink()));
+    let _ = output_doc(&doc, &mut SVGOutput::new(std::io::sink()));
+});
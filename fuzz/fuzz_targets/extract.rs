@@ -0,0 +1,19 @@
+// Extraction from arbitrary files
+//
+// Loads the input as a whole document and extracts it as text and as SVG,
+// so the parser, the font and colour space loading and the interpreter all
+// see hostile data. Errors are fine; a panic, hang or runaway allocation is
+// a bug. Run with `cargo +nightly fuzz run extract` from the repository root.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pdf_extract::{output_doc_with_options, Document, ExtractionOptions, PlainTextOutput, SVGOutput};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(doc) = Document::load_mem(data) else {
+        return;
+    };
+    let options = ExtractionOptions::default();
+    let _ = output_doc_with_options(&doc, &mut PlainTextOutput::new(std::io::sink()), &options);
+    let _ = output_doc_with_options(&doc, &mut SVGOutput::new(std::io::sink()), &options);
+});
@@ -0,0 +1,302 @@
+// Adobe Font Metrics (AFM) parsing and kerning for non-embedded core fonts
+//
+// The generated `core_fonts` tables carry the font-wide metrics, advance
+// widths, glyph bounding boxes and `KPX` pairs of Adobe's Core14 AFM files,
+// available through `standard`. Callers needing different metrics register
+// AFM files on `ExtractionOptions::core_font_metrics`, which take precedence.
+// Kerning is only used for standard 14 fonts that are not embedded, where the
+// AFM data is the authoritative description of the font. Glyphs are still
+// placed by the font's widths as the spec requires; the kerning only tells
+// devices how close the glyphs of a pair sit in the font's design, see
+// `OutputDev::kerning`.
+use std::collections::HashMap;
+use std::slice::Iter;
+use std::sync::{Arc, OnceLock};
+
+use crate::fonts::FontStyle;
+use crate::{core_fonts, glyphnames, zapfglyphnames, CharCode, GlyphProcedure, Path, PdfError, PdfFont, PdfResult};
+
+/// Metrics of a single glyph from a `C ... ;` line
+#[derive(Debug, Clone, PartialEq)]
+pub struct AfmCharMetrics {
+    /// Code in the font's built-in encoding, -1 if unencoded
+    pub code: i64,
+    pub width: f64,
+    pub name: String,
+    pub bbox: Option<[f64; 4]>,
+}
+
+/// Contents of an AFM file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AfmMetrics {
+    pub font_name: String,
+    pub family_name: Option<String>,
+    pub weight: Option<String>,
+    pub encoding_scheme: Option<String>,
+    pub italic_angle: f64,
+    pub is_fixed_pitch: bool,
+    pub font_bbox: Option<[f64; 4]>,
+    pub cap_height: Option<f64>,
+    pub x_height: Option<f64>,
+    pub ascender: Option<f64>,
+    pub descender: Option<f64>,
+    pub underline_position: Option<f64>,
+    pub underline_thickness: Option<f64>,
+    pub char_metrics: Vec<AfmCharMetrics>,
+    /// `KPX` pairs by glyph name, in 1/1000 text space units
+    pub kerning: HashMap<(String, String), f64>,
+}
+
+impl AfmMetrics {
+    /// Parse the text of an AFM file
+    pub fn parse(text: &str) -> PdfResult<AfmMetrics> {
+        let mut metrics = AfmMetrics::default();
+        let mut lines = text.lines();
+        match lines.next().map(str::trim_start) {
+            Some(first) if first.starts_with("StartFontMetrics") => {}
+            _ => return Err(PdfError::FontError("AFM data must start with StartFontMetrics".to_string())),
+        }
+        for line in lines {
+            let line = line.trim();
+            let (key, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let value = value.trim();
+            let number = || value.parse::<f64>().ok();
+            match key {
+                "FontName" => metrics.font_name = value.to_string(),
+                "FamilyName" => metrics.family_name = Some(value.to_string()),
+                "Weight" => metrics.weight = Some(value.to_string()),
+                "EncodingScheme" => metrics.encoding_scheme = Some(value.to_string()),
+                "ItalicAngle" => metrics.italic_angle = number().unwrap_or(0.),
+                "IsFixedPitch" => metrics.is_fixed_pitch = value == "true",
+                "FontBBox" => metrics.font_bbox = parse_bbox(value),
+                "CapHeight" => metrics.cap_height = number(),
+                "XHeight" => metrics.x_height = number(),
+                "Ascender" => metrics.ascender = number(),
+                "Descender" => metrics.descender = number(),
+                "UnderlinePosition" => metrics.underline_position = number(),
+                "UnderlineThickness" => metrics.underline_thickness = number(),
+                "C" | "CH" => {
+                    if let Some(c) = parse_char_metrics(line) {
+                        metrics.char_metrics.push(c);
+                    }
+                }
+                "KPX" => {
+                    let mut parts = value.split_whitespace();
+                    if let (Some(left), Some(right), Some(Ok(kern))) =
+                        (parts.next(), parts.next(), parts.next().map(str::parse::<f64>))
+                    {
+                        metrics.kerning.insert((left.to_string(), right.to_string()), kern);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if metrics.font_name.is_empty() {
+            return Err(PdfError::FontError("AFM data has no FontName".to_string()));
+        }
+        Ok(metrics)
+    }
+
+    pub fn width(&self, glyph_name: &str) -> Option<f64> {
+        self.char_metrics.iter().find(|c| c.name == glyph_name).map(|c| c.width)
+    }
+
+    /// Kerning adjustment between two glyphs, 0 if the pair is not listed
+    pub fn kern(&self, left: &str, right: &str) -> f64 {
+        self.kerning.get(&(left.to_string(), right.to_string())).copied().unwrap_or(0.)
+    }
+}
+
+// `C 65 ; WX 667 ; N A ; B 14 0 654 718 ;`
+fn parse_char_metrics(line: &str) -> Option<AfmCharMetrics> {
+    let mut metrics = AfmCharMetrics { code: -1, width: 0., name: String::new(), bbox: None };
+    for field in line.split(';') {
+        let field = field.trim();
+        let (key, value) = field.split_once(char::is_whitespace).unwrap_or((field, ""));
+        let value = value.trim();
+        match key {
+            "C" => metrics.code = value.parse().ok()?,
+            "CH" => metrics.code = i64::from_str_radix(value.trim_matches(['<', '>']), 16).ok()?,
+            "WX" | "W0X" => metrics.width = value.parse().ok()?,
+            "N" => metrics.name = value.to_string(),
+            "B" => metrics.bbox = parse_bbox(value),
+            _ => {}
+        }
+    }
+    (!metrics.name.is_empty()).then_some(metrics)
+}
+
+fn parse_bbox(value: &str) -> Option<[f64; 4]> {
+    let v: Vec<f64> = value.split_whitespace().filter_map(|n| n.parse().ok()).collect();
+    (v.len() == 4).then(|| [v[0], v[1], v[2], v[3]])
+}
+
+/// AFM metrics registered for core fonts, looked up by `FontName`
+#[derive(Debug, Clone, Default)]
+pub struct CoreFontMetrics {
+    fonts: HashMap<String, Arc<AfmMetrics>>,
+}
+
+impl CoreFontMetrics {
+    pub fn new() -> CoreFontMetrics {
+        CoreFontMetrics::default()
+    }
+
+    pub fn add(&mut self, metrics: AfmMetrics) -> &mut Self {
+        self.fonts.insert(metrics.font_name.clone(), Arc::new(metrics));
+        self
+    }
+
+    /// Register every `*.afm` file in `dir`, e.g. Adobe's Core14 AFM distribution
+    pub fn load_dir<P: AsRef<std::path::Path>>(&mut self, dir: P) -> PdfResult<&mut Self> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("afm")) {
+                let text = String::from_utf8_lossy(&std::fs::read(&path)?).into_owned();
+                self.add(AfmMetrics::parse(&text)?);
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn get(&self, font_name: &str) -> Option<&Arc<AfmMetrics>> {
+        self.fonts.get(font_name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+}
+
+/// Font wrapper adding kerning between consecutive codes
+#[derive(Debug)]
+struct KernedFont {
+    inner: Arc<dyn PdfFont>,
+    pairs: HashMap<(CharCode, CharCode), f64>,
+}
+
+impl PdfFont for KernedFont {
+    fn base_name(&self) -> Option<&str> {
+        self.inner.base_name()
+    }
+
+    fn get_width(&self, id: CharCode) -> f64 {
+        self.inner.get_width(id)
+    }
+
+    fn next_char(&self, iter: &mut Iter<u8>) -> Option<(CharCode, u8)> {
+        self.inner.next_char(iter)
+    }
+
+    fn decode_char(&self, char: CharCode) -> String {
+        self.inner.decode_char(char)
+    }
+
+    fn kerning(&self, left: CharCode, right: CharCode) -> f64 {
+        self.pairs.get(&(left, right)).copied().unwrap_or(0.)
+    }
+
+    fn glyph_procedure(&self, char: CharCode) -> Option<GlyphProcedure<'_>> {
+        self.inner.glyph_procedure(char)
+    }
+
+    fn glyph_outline(&self, char: CharCode) -> Option<Path> {
+        self.inner.glyph_outline(char)
+    }
+
+    fn glyph_id(&self, char: CharCode) -> Option<u16> {
+        self.inner.glyph_id(char)
+    }
+
+    fn space_width(&self) -> Option<f64> {
+        self.inner.space_width()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.inner.style()
+    }
+}
+
+/// Metrics of the standard 14 font `font_name` from Adobe's Core14 AFM files
+pub fn standard(font_name: &str) -> Option<&'static Arc<AfmMetrics>> {
+    static STANDARD: OnceLock<HashMap<&'static str, Arc<AfmMetrics>>> = OnceLock::new();
+    STANDARD.get_or_init(|| {
+        let mut kerning: HashMap<_, _> = core_fonts::kerning().into_vec().into_iter().collect();
+        let mut info: HashMap<_, _> = core_fonts::info().into_vec().into_iter().collect();
+        let mut bboxes: HashMap<_, _> = core_fonts::bboxes().into_vec().into_iter().collect();
+        core_fonts::metrics().iter().map(|&(name, encoding, chars)| {
+            let bboxes = bboxes.remove(name).unwrap_or_default();
+            let mut metrics = AfmMetrics {
+                font_name: name.to_string(),
+                encoding_scheme: Some(encoding.to_string()),
+                char_metrics: chars.iter().enumerate()
+                    .map(|(i, &(code, width, glyph))| AfmCharMetrics { code, width, name: glyph.to_string(), bbox: bboxes.get(i).copied() })
+                    .collect(),
+                kerning: kerning.remove(name).unwrap_or_default().iter()
+                    .map(|&(left, right, kern)| ((left.to_string(), right.to_string()), kern))
+                    .collect(),
+                ..AfmMetrics::default()
+            };
+            if let Some(info) = info.remove(name) {
+                metrics.family_name = Some(info.family_name.to_string());
+                metrics.weight = Some(info.weight.to_string());
+                metrics.italic_angle = info.italic_angle;
+                metrics.is_fixed_pitch = info.is_fixed_pitch;
+                metrics.font_bbox = Some(info.font_bbox);
+                metrics.cap_height = info.cap_height;
+                metrics.x_height = info.x_height;
+                metrics.ascender = info.ascender;
+                metrics.descender = info.descender;
+                metrics.underline_position = Some(info.underline_position);
+                metrics.underline_thickness = Some(info.underline_thickness);
+            }
+            (name, Arc::new(metrics))
+        }).collect()
+    }).get(font_name)
+}
+
+// Wrap a simple core font with the kerning pairs of its registered AFM metrics, or of the
+// Core14 AFM files if none are registered for it. Codes are matched to AFM glyph names
+// through the Unicode they decode to.
+pub(crate) fn apply(registry: &CoreFontMetrics, font: Arc<dyn PdfFont>, applies: bool) -> Arc<dyn PdfFont> {
+    if !applies {
+        return font;
+    }
+    let Some(metrics) = font.base_name().and_then(|name| registry.get(name).or_else(|| standard(name))) else {
+        return font;
+    };
+    if metrics.kerning.is_empty() {
+        return font;
+    }
+
+    let mut codes_by_name: HashMap<&str, Vec<CharCode>> = HashMap::new();
+    let mut by_unicode: HashMap<String, &str> = HashMap::new();
+    for c in &metrics.char_metrics {
+        let unicode = glyphnames::name_to_unicode(&c.name)
+            .or_else(|| zapfglyphnames::zapfdigbats_names_to_unicode(&c.name))
+            .and_then(|u| String::from_utf16(&[u]).ok());
+        if let Some(unicode) = unicode {
+            by_unicode.entry(unicode).or_insert(&c.name);
+        }
+    }
+    for code in 0..256 {
+        if let Some(name) = by_unicode.get(&font.decode_char(code)) {
+            codes_by_name.entry(name).or_default().push(code);
+        }
+    }
+
+    let mut pairs = HashMap::new();
+    for ((left, right), kern) in &metrics.kerning {
+        if let (Some(lefts), Some(rights)) = (codes_by_name.get(left.as_str()), codes_by_name.get(right.as_str())) {
+            for &l in lefts {
+                for &r in rights {
+                    pairs.insert((l, r), *kern);
+                }
+            }
+        }
+    }
+    if pairs.is_empty() {
+        return font;
+    }
+    Arc::new(KernedFont { inner: font, pairs })
+}
@@ -0,0 +1,303 @@
+// Page annotations and review comments
+//
+// Annotations live beside the content stream in each page's `/Annots`, so
+// they never reach an `OutputDev`. Sticky notes (`/Text`) and free-standing
+// pop-ups carry the comments of review workflows; their text, author and
+// date are collected here, as a list or appended to the page text. Text
+// markup annotations only mark regions of the page, so the highlighted
+// text is read from the glyphs under their `/QuadPoints`. FreeText
+// annotations are drawn into the page text when
+// `ExtractionOptions::include_free_text` is set.
+use euclid::point2;
+
+use crate::layout::{collect_text, join_lines, BBox, PageText, TextGlyph};
+use crate::{dictionary, object_utils, string_utils, Dictionary, Document, Extractor, Object, PdfResult, PdfTransform, Stream};
+
+// Subtypes of text markup annotations
+const TEXT_MARKUP: &[&str] = &["Highlight", "Underline", "StrikeOut", "Squiggly"];
+
+/// An annotation of a page
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub page_num: u32,
+    /// `/Subtype`, e.g. `Text`, `Highlight` or `Link`
+    pub subtype: String,
+    /// `/Rect` in user space
+    pub rect: BBox,
+    /// `/Contents`
+    pub contents: Option<String>,
+    /// `/T`, the author for markup annotations
+    pub author: Option<String>,
+    /// `/Subj`
+    pub subject: Option<String>,
+    /// `/M` as written, usually a PDF date such as `D:20240131120000Z`
+    pub modified: Option<String>,
+}
+
+/// Every annotation of `doc`, in page order
+pub fn extract_annotations(doc: &Document) -> PdfResult<Vec<Annotation>> {
+    let mut annotations = Vec::new();
+    for (page_num, page_id) in doc.get_pages() {
+        let annots = page_annotations(doc, doc.get_dictionary(page_id)?);
+        annotations.extend(annots.iter().map(|annot| annotation(doc, page_num, annot)));
+    }
+    Ok(annotations)
+}
+
+/// Comments of `doc`: sticky notes and pop-ups not attached to another annotation, with content
+pub fn extract_comments(doc: &Document) -> PdfResult<Vec<Annotation>> {
+    let mut comments = Vec::new();
+    for (page_num, page_id) in doc.get_pages() {
+        for annot in page_annotations(doc, doc.get_dictionary(page_id)?) {
+            let annotation = annotation(doc, page_num, annot);
+            let is_comment = match annotation.subtype.as_str() {
+                "Text" => true,
+                // A pop-up with a parent shows the parent's contents
+                "Popup" => !annot.has(b"Parent"),
+                _ => false,
+            };
+            if is_comment && annotation.contents.as_deref().is_some_and(|c| !c.trim().is_empty()) {
+                comments.push(annotation);
+            }
+        }
+    }
+    Ok(comments)
+}
+
+/// A highlight, underline, strikeout or squiggly annotation and the text it marks
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMarkup {
+    pub annotation: Annotation,
+    /// Bounding box of each quadrilateral in `/QuadPoints`, or the `/Rect` without them
+    pub quads: Vec<BBox>,
+    /// Text of the glyphs whose centre lies in a quad, quads joined by spaces
+    pub text: String,
+}
+
+/// Text markup annotations of `doc`, with the text under each
+pub fn extract_text_markup(doc: &Document) -> PdfResult<Vec<TextMarkup>> {
+    let pages = collect_text(doc)?;
+    let mut markups = Vec::new();
+    for (page_num, page_id) in doc.get_pages() {
+        let Some(page) = pages.iter().find(|p| p.page_num == page_num) else { continue };
+        for annot in page_annotations(doc, doc.get_dictionary(page_id)?) {
+            let annotation = annotation(doc, page_num, annot);
+            if !TEXT_MARKUP.contains(&annotation.subtype.as_str()) {
+                continue;
+            }
+            let quads = quad_points(doc, annot).unwrap_or_else(|| vec![annotation.rect]);
+            let text = quads.iter()
+                .map(|quad| text_in(page, quad))
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            markups.push(TextMarkup { annotation, quads, text });
+        }
+    }
+    Ok(markups)
+}
+
+// Lines of the glyphs of `page` centred inside `quad`
+fn text_in(page: &PageText, quad: &BBox) -> String {
+    let centred = |g: &TextGlyph| {
+        let (x, y) = (g.x + g.width / 2., g.y + g.font_size * 0.3);
+        (quad.llx..=quad.urx).contains(&x) && (quad.lly..=quad.ury).contains(&y)
+    };
+    let glyphs = page.glyphs.iter().filter(|g| centred(g)).cloned().collect();
+    join_lines(&PageText { page_num: page.page_num, media_box: page.media_box, glyphs }.lines())
+}
+
+// Bounding boxes of the quadrilaterals, eight coordinates each
+fn quad_points(doc: &Document, annot: &Dictionary) -> Option<Vec<BBox>> {
+    let values: Vec<f64> = object_utils::maybe_get_obj(doc, annot, b"QuadPoints")?
+        .as_array().ok()?
+        .iter()
+        .filter_map(|o| object_utils::as_num(o).ok())
+        .collect();
+    let quads: Vec<BBox> = values.chunks_exact(8)
+        .map(|q| {
+            let (xs, ys) = ([q[0], q[2], q[4], q[6]], [q[1], q[3], q[5], q[7]]);
+            BBox {
+                llx: xs.iter().copied().fold(f64::INFINITY, f64::min),
+                lly: ys.iter().copied().fold(f64::INFINITY, f64::min),
+                urx: xs.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                ury: ys.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            }
+        })
+        .collect();
+    (!quads.is_empty()).then_some(quads)
+}
+
+// A FreeText annotation as the interpreter draws it
+pub(crate) struct FreeTextAppearance<'a> {
+    /// The normal appearance stream and the matrix mapping its form space onto the `/Rect`
+    pub(crate) appearance: Option<(&'a Stream, PdfTransform)>,
+    rect: BBox,
+    contents: String,
+    font_size: f64,
+}
+
+impl FreeTextAppearance<'_> {
+    // Content stream setting `/Contents` line by line from the top left of the rect, in the
+    // font from `fallback_resources`
+    pub(crate) fn fallback_content(&self) -> Vec<u8> {
+        let size = self.font_size;
+        let mut content = format!("BT /F1 {} Tf {} TL {} {} Td", size, size * 1.2, self.rect.llx + 2., self.rect.ury - 2. - size);
+        for (i, line) in self.contents.lines().enumerate() {
+            // WinAnsi agrees with Latin-1 from U+00A0 up
+            let hex: String = line.chars()
+                .map(|c| if (' '..='~').contains(&c) || ('\u{a0}'..='\u{ff}').contains(&c) { c as u32 } else { b'?' as u32 })
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            content += &format!("{} <{}> Tj", if i > 0 { " T*" } else { "" }, hex);
+        }
+        content += " ET";
+        content.into_bytes()
+    }
+}
+
+pub(crate) fn fallback_resources() -> Dictionary {
+    dictionary! {
+        "Font" => dictionary! {
+            "F1" => dictionary! {
+                "Type" => "Font",
+                "Subtype" => "Type1",
+                "BaseFont" => "Helvetica",
+                "Encoding" => "WinAnsiEncoding",
+            },
+        },
+    }
+}
+
+// Visible FreeText annotations of a page
+pub(crate) fn free_text_appearances<'a>(doc: &'a Document, page: &'a Dictionary) -> Vec<FreeTextAppearance<'a>> {
+    const HIDDEN: i64 = 2;
+    page_annotations(doc, page)
+        .into_iter()
+        .filter(|annot| {
+            annot.get(b"Subtype").and_then(|o| o.as_name()).ok() == Some(b"FreeText")
+                && object_utils::maybe_get_obj(doc, annot, b"F").and_then(|o| o.as_i64().ok()).unwrap_or(0) & HIDDEN == 0
+        })
+        .filter_map(|annot| {
+            let rect = rect(doc, annot, b"Rect")?;
+            // The font size of the default appearance, e.g. `/Helv 10 Tf 0 g`
+            let da = text(doc, annot, b"DA").unwrap_or_default();
+            let words: Vec<&str> = da.split_whitespace().collect();
+            let font_size = words.windows(2)
+                .find(|w| w[1] == "Tf")
+                .and_then(|w| w[0].parse::<f64>().ok())
+                .filter(|size| *size > 0.)
+                .unwrap_or(12.);
+            Some(FreeTextAppearance {
+                appearance: normal_appearance(doc, annot).map(|stream| (stream, appearance_matrix(doc, stream, &rect))),
+                rect,
+                contents: text(doc, annot, b"Contents").unwrap_or_default(),
+                font_size,
+            })
+        })
+        .collect()
+}
+
+// `/AP /N`, picking the `/AS` state when there are several
+fn normal_appearance<'a>(doc: &'a Document, annot: &'a Dictionary) -> Option<&'a Stream> {
+    let ap = object_utils::maybe_get_obj(doc, annot, b"AP")?.as_dict().ok()?;
+    match object_utils::maybe_get_obj(doc, ap, b"N")? {
+        Object::Stream(stream) => Some(stream),
+        Object::Dictionary(states) => {
+            let state = annot.get(b"AS").and_then(|o| o.as_name()).ok()?;
+            object_utils::maybe_get_obj(doc, states, state)?.as_stream().ok()
+        }
+        _ => None,
+    }
+}
+
+// Form matrix followed by the mapping of the transformed `/BBox` onto `rect`
+fn appearance_matrix(doc: &Document, stream: &Stream, rect: &BBox) -> PdfTransform {
+    let m: Vec<f64> = object_utils::maybe_get_obj(doc, &stream.dict, b"Matrix")
+        .and_then(|o| o.as_array().ok())
+        .map(|values| values.iter().filter_map(|o| object_utils::as_num(o).ok()).collect())
+        .filter(|m: &Vec<f64>| m.len() == 6)
+        .unwrap_or_else(|| vec![1., 0., 0., 1., 0., 0.]);
+    let matrix = PdfTransform::new(m[0], m[1], m[2], m[3], m[4], m[5]);
+    let Some(bbox) = self::rect(doc, &stream.dict, b"BBox") else {
+        return matrix.then(&PdfTransform::translation(rect.llx, rect.lly));
+    };
+    let corners = [(bbox.llx, bbox.lly), (bbox.urx, bbox.lly), (bbox.llx, bbox.ury), (bbox.urx, bbox.ury)]
+        .map(|(x, y)| matrix.transform_point(point2(x, y)));
+    let (llx, lly) = corners.iter().fold((f64::INFINITY, f64::INFINITY), |(x, y), p| (x.min(p.x), y.min(p.y)));
+    let (urx, ury) = corners.iter().fold((f64::NEG_INFINITY, f64::NEG_INFINITY), |(x, y), p| (x.max(p.x), y.max(p.y)));
+    let scale = |target: f64, source: f64| if source > 0. { target / source } else { 1. };
+    matrix
+        .then(&PdfTransform::translation(-llx, -lly))
+        .then(&PdfTransform::scale(scale(rect.width(), urx - llx), scale(rect.height(), ury - lly)))
+        .then(&PdfTransform::translation(rect.llx, rect.lly))
+}
+
+/// Text of each page of `doc` followed by its comments, one `[Comment by <author>, <date>]` line each
+pub fn extract_text_with_comments(doc: &Document) -> PdfResult<String> {
+    let comments = extract_comments(doc)?;
+    let mut text = String::new();
+    let pages = doc.get_pages().into_keys();
+    for (page_num, page_text) in pages.zip(Extractor::new().build().text_by_pages(doc)?) {
+        text += &page_text;
+        for comment in comments.iter().filter(|c| c.page_num == page_num) {
+            text += &format!("\n{}\n", format_comment(comment));
+        }
+    }
+    Ok(text)
+}
+
+/// `[Comment by <author>, <date>] <contents>`, leaving out what the comment lacks
+pub fn format_comment(comment: &Annotation) -> String {
+    let mut header = "Comment".to_string();
+    if let Some(author) = &comment.author {
+        header += &format!(" by {}", author);
+    }
+    if let Some(modified) = &comment.modified {
+        header += &format!(", {}", modified);
+    }
+    format!("[{}] {}", header, comment.contents.as_deref().unwrap_or("").trim())
+}
+
+fn annotation(doc: &Document, page_num: u32, annot: &Dictionary) -> Annotation {
+    Annotation {
+        page_num,
+        subtype: object_utils::maybe_get_obj(doc, annot, b"Subtype")
+            .and_then(|o| o.as_name().ok())
+            .map(|n| String::from_utf8_lossy(n).into_owned())
+            .unwrap_or_default(),
+        rect: rect(doc, annot, b"Rect").unwrap_or(BBox { llx: 0., lly: 0., urx: 0., ury: 0. }),
+        contents: text(doc, annot, b"Contents"),
+        author: text(doc, annot, b"T"),
+        subject: text(doc, annot, b"Subj"),
+        modified: text(doc, annot, b"M"),
+    }
+}
+
+// Annotation dictionaries of a page, skipping entries that aren't dictionaries
+fn page_annotations<'a>(doc: &'a Document, page: &'a Dictionary) -> Vec<&'a Dictionary> {
+    let Some(Object::Array(annots)) = object_utils::maybe_get_obj(doc, page, b"Annots") else {
+        return Vec::new();
+    };
+    annots.iter()
+        .filter_map(|o| object_utils::maybe_deref(doc, o).ok()?.as_dict().ok())
+        .collect()
+}
+
+// A text string entry decoded from PDFDocEncoding or UTF-16
+fn text(doc: &Document, dict: &Dictionary, key: &[u8]) -> Option<String> {
+    object_utils::maybe_get_obj(doc, dict, key)
+        .and_then(|o| o.as_str().ok())
+        .and_then(|s| string_utils::pdf_to_utf8(s).ok())
+}
+
+// A rectangle array, normalised so that ll is below and left of ur
+fn rect(doc: &Document, dict: &Dictionary, key: &[u8]) -> Option<BBox> {
+    let values: Vec<f64> = object_utils::maybe_get_obj(doc, dict, key)?
+        .as_array().ok()?
+        .iter()
+        .filter_map(|o| object_utils::as_num(object_utils::maybe_deref(doc, o).ok()?).ok())
+        .collect();
+    let [x0, y0, x1, y1] = values[..] else { return None };
+    Some(BBox { llx: x0.min(x1), lly: y0.min(y1), urx: x0.max(x1), ury: y0.max(y1) })
+}
@@ -0,0 +1,80 @@
+// Embedded files
+//
+// Files are attached to a document through the `EmbeddedFiles` name tree
+// or to a spot on a page through file attachment annotations. Both point
+// to a file specification whose `/EF` holds the embedded file stream.
+use log::warn;
+
+use crate::{document_utils, object_utils, string_utils, Dictionary, Document, Object, PdfResult};
+
+/// A file embedded in the document
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attachment {
+    /// `/UF` or `/F` of the file specification, falling back to the name tree key
+    pub filename: String,
+    /// `/Desc` of the file specification
+    pub description: Option<String>,
+    /// `/Subtype` of the embedded file stream, e.g. `application/pdf`
+    pub mime_type: Option<String>,
+    /// Page of the file attachment annotation, `None` for document attachments
+    pub page_num: Option<u32>,
+    /// Contents of the file, filters removed
+    pub data: Vec<u8>,
+}
+
+/// Document attachments, then those of file attachment annotations in page order; files whose
+/// stream filters can't be decoded are skipped
+pub fn extract_attachments(doc: &Document) -> PdfResult<Vec<Attachment>> {
+    let mut attachments = Vec::new();
+    for (key, spec) in document_utils::name_tree(doc, b"EmbeddedFiles")? {
+        let Some(spec) = object_utils::maybe_deref(doc, spec).ok().and_then(|o| o.as_dict().ok()) else { continue };
+        let key = string_utils::pdf_to_utf8(key).unwrap_or_default();
+        attachments.extend(attachment(doc, spec, &key, None));
+    }
+    for (page_num, page_id) in doc.get_pages() {
+        let Some(Object::Array(annots)) = object_utils::maybe_get_obj(doc, doc.get_dictionary(page_id)?, b"Annots") else {
+            continue;
+        };
+        for annot in annots.iter().filter_map(|o| object_utils::maybe_deref(doc, o).ok()?.as_dict().ok()) {
+            if annot.get(b"Subtype").and_then(|o| o.as_name()).ok() != Some(b"FileAttachment") {
+                continue;
+            }
+            if let Some(spec) = object_utils::maybe_get_obj(doc, annot, b"FS").and_then(|o| o.as_dict().ok()) {
+                attachments.extend(attachment(doc, spec, "", Some(page_num)));
+            }
+        }
+    }
+    Ok(attachments)
+}
+
+fn attachment(doc: &Document, spec: &Dictionary, key: &str, page_num: Option<u32>) -> Option<Attachment> {
+    let text = |dict: &Dictionary, key: &[u8]| {
+        object_utils::maybe_get_obj(doc, dict, key)
+            .and_then(|o| o.as_str().ok())
+            .and_then(|s| string_utils::pdf_to_utf8(s).ok())
+    };
+    let filename = text(spec, b"UF").or_else(|| text(spec, b"F")).unwrap_or_else(|| key.to_string());
+    let ef = object_utils::maybe_get_obj(doc, spec, b"EF")?.as_dict().ok()?;
+    let stream = object_utils::maybe_get_obj(doc, ef, b"UF")
+        .or_else(|| object_utils::maybe_get_obj(doc, ef, b"F"))?
+        .as_stream().ok()?;
+    let data = if stream.filters().map_or(true, |f| f.is_empty()) {
+        stream.content.clone()
+    } else {
+        match stream.decompressed_content() {
+            Ok(data) => data,
+            Err(e) => {
+                warn!("Skipping attachment {}, {:?}", filename, e);
+                return None;
+            }
+        }
+    };
+    Some(Attachment {
+        description: text(spec, b"Desc"),
+        mime_type: stream.dict.get(b"Subtype").and_then(|o| o.as_name()).ok()
+            .map(|n| String::from_utf8_lossy(n).into_owned()),
+        filename,
+        page_num,
+        data,
+    })
+}
@@ -0,0 +1,146 @@
+// Bates stamp detection for e-discovery productions
+//
+// A Bates number is a prefix plus a zero padded counter ("ABC0001234",
+// "SMITH-000017") stamped at the same spot in the margin of every page, with
+// the counter increasing page by page.
+use std::collections::HashMap;
+
+use crate::layout::{collect_text, BBox, PageText};
+use crate::{Document, PdfResult};
+
+// Fraction of the page height/width treated as margin
+const VERTICAL_MARGIN: f64 = 0.15;
+const HORIZONTAL_MARGIN: f64 = 0.12;
+const MIN_DIGITS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatesNumber {
+    pub page_num: u32,
+    /// The stamp as printed, e.g. `ABC-000123`
+    pub label: String,
+    /// Prefix with separators trimmed, e.g. `ABC`
+    pub prefix: String,
+    pub number: u64,
+    /// Number of digits in the counter, including zero padding
+    pub digits: usize,
+    /// Bounding box of the margin line that carries the stamp
+    pub bbox: BBox,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatesRange {
+    pub first: BatesNumber,
+    pub last: BatesNumber,
+    /// Stamps in page order; pages without a detected stamp are absent
+    pub pages: Vec<BatesNumber>,
+}
+
+impl BatesRange {
+    /// Stamp detected on page `page_num`, if any
+    pub fn for_page(&self, page_num: u32) -> Option<&BatesNumber> {
+        self.pages.iter().find(|b| b.page_num == page_num)
+    }
+
+    /// Pages whose counter does not follow its predecessor by exactly one
+    pub fn gaps(&self) -> Vec<u32> {
+        self.pages.windows(2)
+            .filter(|w| w[1].number != w[0].number + 1)
+            .map(|w| w[1].page_num)
+            .collect()
+    }
+}
+
+/// Per-page Bates numbers of `doc`, using the stamp series that is most consistent across pages
+pub fn bates_numbers(doc: &Document) -> PdfResult<Vec<BatesNumber>> {
+    Ok(detect(&collect_text(doc)?))
+}
+
+/// First and last Bates number of `doc` plus the per-page stamps, if a series was found
+pub fn bates_range(doc: &Document) -> PdfResult<Option<BatesRange>> {
+    let pages = bates_numbers(doc)?;
+    Ok(match (pages.first(), pages.last()) {
+        (Some(first), Some(last)) => Some(BatesRange { first: first.clone(), last: last.clone(), pages: pages.clone() }),
+        _ => None,
+    })
+}
+
+/// Pick the stamp series that appears on the most pages with strictly increasing counters; a
+/// counter repeated on a later page is a footer or reference number, not a Bates series
+pub fn detect(pages: &[PageText]) -> Vec<BatesNumber> {
+    let mut series: HashMap<(String, usize), Vec<BatesNumber>> = HashMap::new();
+    for page in pages {
+        for candidate in page_candidates(page) {
+            let stamps = series.entry((candidate.prefix.clone(), candidate.digits)).or_default();
+            // Keep one stamp per page per series
+            if stamps.last().is_none_or(|s| s.page_num != candidate.page_num) {
+                stamps.push(candidate);
+            }
+        }
+    }
+
+    let min_pages = if pages.len() > 1 { 2 } else { 1 };
+    series.into_values()
+        .filter(|stamps| stamps.len() >= min_pages)
+        .filter(|stamps| stamps.windows(2).all(|w| w[1].number > w[0].number))
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b[0].label.cmp(&a[0].label)))
+        .unwrap_or_default()
+}
+
+fn in_margin(page: &PageText, bbox: &BBox) -> bool {
+    let mb = &page.media_box;
+    let (width, height) = (mb.urx - mb.llx, mb.ury - mb.lly);
+    bbox.ury <= mb.lly + height * VERTICAL_MARGIN
+        || bbox.lly >= mb.ury - height * VERTICAL_MARGIN
+        || bbox.urx <= mb.llx + width * HORIZONTAL_MARGIN
+        || bbox.llx >= mb.urx - width * HORIZONTAL_MARGIN
+}
+
+fn page_candidates(page: &PageText) -> Vec<BatesNumber> {
+    page.lines().into_iter()
+        .filter(|line| in_margin(page, &line.bbox))
+        .filter_map(|line| {
+            parse_stamp(&line.text).map(|(label, prefix, number, digits)| BatesNumber {
+                page_num: page.page_num,
+                label,
+                prefix,
+                number,
+                digits,
+                bbox: line.bbox,
+            })
+        })
+        .collect()
+}
+
+// Find the last `PREFIX[-_ ]000123` token in `text`: (label, prefix, counter, digits)
+fn parse_stamp(text: &str) -> Option<(String, String, u64, usize)> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    for (i, token) in tokens.iter().enumerate().rev() {
+        let token = token.trim_end_matches([',', ';', ')', ']']);
+        let digits = token.bytes().rev().take_while(u8::is_ascii_digit).count();
+        if digits < MIN_DIGITS {
+            continue;
+        }
+        let (head, counter) = token.split_at(token.len() - digits);
+        let Ok(number) = counter.parse() else {
+            continue;
+        };
+        let head = head.trim_end_matches(['-', '_', '.']);
+        let is_prefix = |s: &str| {
+            s.len() <= 12
+                && s.starts_with(|c: char| c.is_ascii_uppercase())
+                && s.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        };
+        if !head.is_empty() {
+            if is_prefix(head) {
+                return Some((token.to_string(), head.to_string(), number, digits));
+            }
+        } else if i > 0 && is_prefix(tokens[i - 1]) && tokens[i - 1].chars().all(|c| c.is_ascii_uppercase()) {
+            let label = format!("{} {}", tokens[i - 1], token);
+            return Some((label, tokens[i - 1].to_string(), number, digits));
+        } else if counter.starts_with('0') {
+            // A bare counter needs zero padding to be told apart from page numbers and years
+            return Some((token.to_string(), String::new(), number, digits));
+        }
+    }
+    None
+}
@@ -0,0 +1,145 @@
+// Command line front end
+//
+// `pdf-extract [options] <file.pdf | ->` extracts a document, or a selection
+// of its pages, as text, HTML, SVG, JSON lines or Markdown. Output goes to
+// standard output unless `--output` names a file, and `-` reads the document
+// from standard input, so the tool works in shell pipelines.
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use pdf_extract::layout::{write_json_lines, write_markdown, TextCollector};
+use pdf_extract::page_ranges::PageRanges;
+use pdf_extract::{Extractor, HTMLOutput, PdfError, PdfResult, PlainTextOutput, SVGOutput};
+
+const USAGE: &str = "\
+Usage: pdf-extract [options] <file.pdf | ->
+
+Options:
+  -f, --format <txt|html|svg|json|md>  output format (default: txt)
+  -p, --pages <pages>                  pages to extract, e.g. 1-3,7,12- or last
+      --password <password>            password of an encrypted document
+  -o, --output <file>                  write to a file instead of standard output
+  -h, --help                           print this help";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Html,
+    Svg,
+    Json,
+    Markdown,
+}
+
+struct Args {
+    input: String,
+    format: Format,
+    pages: Option<PageRanges>,
+    password: Option<String>,
+    output: Option<String>,
+}
+
+// `None` when help was asked for
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut pages = None;
+    let mut password = None;
+    let mut output = None;
+    while let Some(arg) = args.next() {
+        // Both `--pages 1-3` and `--pages=1-3`
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg.clone(), None),
+        };
+        let mut value = || inline.clone().or_else(|| args.next()).ok_or_else(|| format!("{} needs a value", flag));
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--format" => {
+                format = match value()?.as_str() {
+                    "txt" | "text" => Format::Text,
+                    "html" => Format::Html,
+                    "svg" => Format::Svg,
+                    "json" => Format::Json,
+                    "md" | "markdown" => Format::Markdown,
+                    other => return Err(format!("Unknown format {:?}", other)),
+                }
+            }
+            "-p" | "--pages" => pages = Some(value()?.parse::<PageRanges>().map_err(|e| e.to_string())?),
+            "--password" => password = Some(value()?),
+            "-o" | "--output" => output = Some(value()?),
+            _ if arg.starts_with('-') && arg != "-" => return Err(format!("Unknown option {}", arg)),
+            _ if input.is_some() => return Err(format!("Unexpected argument {}", arg)),
+            _ => input = Some(arg),
+        }
+    }
+    let input = input.ok_or("No input file given")?;
+    Ok(Some(Args { input, format, pages, password, output }))
+}
+
+fn run(args: Args) -> PdfResult<()> {
+    let mut builder = Extractor::new();
+    if let Some(password) = &args.password {
+        builder = builder.password(password);
+    }
+    if let Some(pages) = args.pages {
+        builder = builder.page_ranges(pages);
+    }
+    let extractor = builder.build();
+    let doc = if args.input == "-" {
+        let mut buffer = Vec::new();
+        io::stdin().lock().read_to_end(&mut buffer)?;
+        extractor.load_document_from_mem(&buffer)?
+    } else {
+        extractor.load_document(&args.input)?
+    };
+
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    match args.format {
+        Format::Text => {
+            extractor.output(&doc, &mut PlainTextOutput::new(&mut writer))?;
+        }
+        Format::Html => {
+            extractor.output(&doc, &mut HTMLOutput::new(&mut writer))?;
+        }
+        Format::Svg => {
+            extractor.output(&doc, &mut SVGOutput::new(&mut writer))?;
+        }
+        Format::Json | Format::Markdown => {
+            let mut collector = TextCollector::new();
+            extractor.output(&doc, &mut collector)?;
+            match args.format {
+                Format::Json => write_json_lines(collector.pages(), &mut writer)?,
+                _ => write_markdown(collector.pages(), &mut writer)?,
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("pdf-extract: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        // A reader that went away, as with `| head`, isn't a failure
+        Err(PdfError::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("pdf-extract: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
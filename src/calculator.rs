@@ -0,0 +1,414 @@
+// PostScript calculator programs of Type 4 functions
+//
+// A Type 4 function is a brace-delimited program in a small subset of
+// PostScript: numbers, booleans, the arithmetic, relational, boolean and
+// stack operators, and `if`/`ifelse` on nested procedures. There are no
+// loops, so a program runs in one pass over its operators. Programs are
+// parsed once when the function is loaded; running one on the inputs leaves
+// the outputs on the stack. Integers and reals are kept apart as PostScript
+// does, since `idiv`, `mod` and `bitshift` take only integers. Errors such as
+// stack underflow or a type mismatch make the evaluation fail; the stack is
+// capped at the 100 entries PostScript allows calculator functions. Parsing
+// and running recurse into nested procedures, so their nesting is capped too.
+use crate::{PdfError, PdfResult};
+
+const MAX_STACK: usize = 100;
+
+// Procedures a procedure may be nested in
+const MAX_NESTING: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Value {
+    Int(i64),
+    Real(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn num(self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(i as f64),
+            Value::Real(r) => Some(r),
+            Value::Bool(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Op {
+    Push(Value),
+    Operator(Operator),
+    If(Vec<Op>),
+    IfElse(Vec<Op>, Vec<Op>),
+}
+
+macro_rules! operators {
+    ($($name:literal => $variant:ident,)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        enum Operator {
+            $($variant,)*
+        }
+
+        impl Operator {
+            fn from_name(name: &str) -> Option<Operator> {
+                match name {
+                    $($name => Some(Operator::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+operators! {
+    "abs" => Abs, "add" => Add, "atan" => Atan, "ceiling" => Ceiling, "cos" => Cos, "cvi" => Cvi,
+    "cvr" => Cvr, "div" => Div, "exp" => Exp, "floor" => Floor, "idiv" => Idiv, "ln" => Ln,
+    "log" => Log, "mod" => Mod, "mul" => Mul, "neg" => Neg, "round" => Round, "sin" => Sin,
+    "sqrt" => Sqrt, "sub" => Sub, "truncate" => Truncate, "and" => And, "bitshift" => Bitshift,
+    "eq" => Eq, "ge" => Ge, "gt" => Gt, "le" => Le, "lt" => Lt, "ne" => Ne, "not" => Not,
+    "or" => Or, "xor" => Xor, "copy" => Copy, "dup" => Dup, "exch" => Exch, "index" => Index,
+    "pop" => Pop, "roll" => Roll,
+}
+
+/// A parsed calculator program
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Program {
+    ops: Vec<Op>,
+}
+
+impl Program {
+    pub(crate) fn parse(source: &[u8]) -> PdfResult<Program> {
+        let text = String::from_utf8_lossy(source);
+        let mut tokens = tokenize(&text).into_iter();
+        if tokens.next().as_deref() != Some("{") {
+            return Err(invalid("the program must start with {"));
+        }
+        let ops = parse_procedure(&mut tokens, 0)?;
+        Ok(Program { ops })
+    }
+
+    /// The stack left by running the program on `inputs`, bottom first
+    pub(crate) fn run(&self, inputs: &[f64]) -> Option<Vec<f64>> {
+        let mut stack: Vec<Value> = inputs.iter().map(|&x| Value::Real(x)).collect();
+        execute(&self.ops, &mut stack, 0)?;
+        stack.into_iter().map(Value::num).collect()
+    }
+}
+
+fn invalid(reason: &str) -> PdfError {
+    PdfError::InvalidStructure(format!("Invalid type 4 function: {}", reason))
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut comment = false;
+    for c in text.chars() {
+        if comment {
+            comment = !matches!(c, '\n' | '\r');
+            continue;
+        }
+        if c.is_whitespace() || matches!(c, '{' | '}' | '%') {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            match c {
+                '{' | '}' => tokens.push(c.to_string()),
+                '%' => comment = true,
+                _ => {}
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+// Operators up to the `}` closing the procedure, `depth` procedures deep
+fn parse_procedure(tokens: &mut impl Iterator<Item = String>, depth: usize) -> PdfResult<Vec<Op>> {
+    if depth > MAX_NESTING {
+        return Err(invalid("procedures are nested too deep"));
+    }
+    let mut ops = Vec::new();
+    // Procedures waiting for the `if` or `ifelse` that takes them
+    let mut procedures: Vec<Vec<Op>> = Vec::new();
+    while let Some(token) = tokens.next() {
+        let op = match token.as_str() {
+            "}" => {
+                if !procedures.is_empty() {
+                    return Err(invalid("a procedure isn't followed by if or ifelse"));
+                }
+                return Ok(ops);
+            }
+            "{" => {
+                procedures.push(parse_procedure(tokens, depth + 1)?);
+                continue;
+            }
+            "if" => match (procedures.pop(), procedures.is_empty()) {
+                (Some(then), true) => Op::If(then),
+                _ => return Err(invalid("if needs one procedure")),
+            },
+            "ifelse" => match (procedures.pop(), procedures.pop(), procedures.is_empty()) {
+                (Some(otherwise), Some(then), true) => Op::IfElse(then, otherwise),
+                _ => return Err(invalid("ifelse needs two procedures")),
+            },
+            "true" => Op::Push(Value::Bool(true)),
+            "false" => Op::Push(Value::Bool(false)),
+            token => match (token.parse::<i64>(), token.parse::<f64>(), Operator::from_name(token)) {
+                (Ok(i), _, _) => Op::Push(Value::Int(i)),
+                (_, Ok(r), _) => Op::Push(Value::Real(r)),
+                (_, _, Some(operator)) => Op::Operator(operator),
+                _ => return Err(invalid(&format!("unknown operator {}", token))),
+            },
+        };
+        if !procedures.is_empty() {
+            return Err(invalid("a procedure isn't followed by if or ifelse"));
+        }
+        ops.push(op);
+    }
+    Err(invalid("missing }"))
+}
+
+fn execute(ops: &[Op], stack: &mut Vec<Value>, depth: usize) -> Option<()> {
+    if depth > MAX_NESTING {
+        return None;
+    }
+    for op in ops {
+        match op {
+            Op::Push(value) => stack.push(*value),
+            Op::Operator(operator) => apply(*operator, stack)?,
+            Op::If(then) => {
+                if pop_bool(stack)? {
+                    execute(then, stack, depth + 1)?;
+                }
+            }
+            Op::IfElse(then, otherwise) => {
+                let procedure = if pop_bool(stack)? { then } else { otherwise };
+                execute(procedure, stack, depth + 1)?;
+            }
+        }
+        if stack.len() > MAX_STACK {
+            return None;
+        }
+    }
+    Some(())
+}
+
+fn pop_bool(stack: &mut Vec<Value>) -> Option<bool> {
+    match stack.pop()? {
+        Value::Bool(b) => Some(b),
+        _ => None,
+    }
+}
+
+fn pop_int(stack: &mut Vec<Value>) -> Option<i64> {
+    match stack.pop()? {
+        Value::Int(i) => Some(i),
+        _ => None,
+    }
+}
+
+fn pop_num(stack: &mut Vec<Value>) -> Option<f64> {
+    stack.pop()?.num()
+}
+
+// A real result, which must be a number
+fn real(r: f64) -> Option<Value> {
+    r.is_finite().then_some(Value::Real(r))
+}
+
+// Integer operations stay integers unless they overflow
+fn arithmetic(stack: &mut Vec<Value>, int: fn(i64, i64) -> Option<i64>, float: fn(f64, f64) -> f64) -> Option<()> {
+    let (b, a) = (stack.pop()?, stack.pop()?);
+    let result = match (a, b) {
+        (Value::Int(a), Value::Int(b)) => int(a, b).map(Value::Int).or_else(|| real(float(a as f64, b as f64)))?,
+        _ => real(float(a.num()?, b.num()?))?,
+    };
+    stack.push(result);
+    Some(())
+}
+
+fn compare(stack: &mut Vec<Value>, test: fn(f64, f64) -> bool) -> Option<()> {
+    let (b, a) = (pop_num(stack)?, pop_num(stack)?);
+    stack.push(Value::Bool(test(a, b)));
+    Some(())
+}
+
+// Boolean or bitwise operation, depending on the operands
+fn logical(stack: &mut Vec<Value>, bool_op: fn(bool, bool) -> bool, int_op: fn(i64, i64) -> i64) -> Option<()> {
+    let result = match (stack.pop()?, stack.pop()?) {
+        (Value::Bool(b), Value::Bool(a)) => Value::Bool(bool_op(a, b)),
+        (Value::Int(b), Value::Int(a)) => Value::Int(int_op(a, b)),
+        _ => return None,
+    };
+    stack.push(result);
+    Some(())
+}
+
+// Rounding that keeps integers as they are
+fn rounding(stack: &mut Vec<Value>, round: fn(f64) -> f64) -> Option<()> {
+    let value = match stack.pop()? {
+        Value::Int(i) => Value::Int(i),
+        Value::Real(r) => Value::Real(round(r)),
+        Value::Bool(_) => return None,
+    };
+    stack.push(value);
+    Some(())
+}
+
+fn apply(operator: Operator, stack: &mut Vec<Value>) -> Option<()> {
+    use Operator::*;
+
+    match operator {
+        Add => arithmetic(stack, i64::checked_add, |a, b| a + b)?,
+        Sub => arithmetic(stack, i64::checked_sub, |a, b| a - b)?,
+        Mul => arithmetic(stack, i64::checked_mul, |a, b| a * b)?,
+        Div => {
+            let (b, a) = (pop_num(stack)?, pop_num(stack)?);
+            if b == 0. {
+                return None;
+            }
+            stack.push(real(a / b)?);
+        }
+        Idiv => {
+            let (b, a) = (pop_int(stack)?, pop_int(stack)?);
+            stack.push(Value::Int(a.checked_div(b)?));
+        }
+        Mod => {
+            let (b, a) = (pop_int(stack)?, pop_int(stack)?);
+            stack.push(Value::Int(a.checked_rem(b)?));
+        }
+        Abs | Neg => {
+            let value = match stack.pop()? {
+                Value::Int(i) if operator == Abs => i.checked_abs().map_or(Value::Real((i as f64).abs()), Value::Int),
+                Value::Int(i) => i.checked_neg().map_or(Value::Real(-(i as f64)), Value::Int),
+                Value::Real(r) if operator == Abs => Value::Real(r.abs()),
+                Value::Real(r) => Value::Real(-r),
+                Value::Bool(_) => return None,
+            };
+            stack.push(value);
+        }
+        Ceiling => rounding(stack, f64::ceil)?,
+        Floor => rounding(stack, f64::floor)?,
+        // PostScript rounds halves up
+        Round => rounding(stack, |r| (r + 0.5).floor())?,
+        Truncate => rounding(stack, f64::trunc)?,
+        Cvi => {
+            let r = pop_num(stack)?.trunc();
+            if r.abs() >= i64::MAX as f64 {
+                return None;
+            }
+            stack.push(Value::Int(r as i64));
+        }
+        Cvr => {
+            let r = pop_num(stack)?;
+            stack.push(Value::Real(r));
+        }
+        Sqrt => {
+            let r = pop_num(stack)?;
+            if r < 0. {
+                return None;
+            }
+            stack.push(Value::Real(r.sqrt()));
+        }
+        // Angles are in degrees
+        Sin => {
+            let r = pop_num(stack)?;
+            stack.push(Value::Real(r.to_radians().sin()));
+        }
+        Cos => {
+            let r = pop_num(stack)?;
+            stack.push(Value::Real(r.to_radians().cos()));
+        }
+        Atan => {
+            let (den, num) = (pop_num(stack)?, pop_num(stack)?);
+            if num == 0. && den == 0. {
+                return None;
+            }
+            stack.push(Value::Real(num.atan2(den).to_degrees().rem_euclid(360.)));
+        }
+        Exp => {
+            let (exponent, base) = (pop_num(stack)?, pop_num(stack)?);
+            stack.push(real(base.powf(exponent))?);
+        }
+        Ln | Log => {
+            let r = pop_num(stack)?;
+            if r <= 0. {
+                return None;
+            }
+            stack.push(Value::Real(if operator == Ln { r.ln() } else { r.log10() }));
+        }
+        Eq | Ne => {
+            let (b, a) = (stack.pop()?, stack.pop()?);
+            let equal = match (a, b) {
+                (Value::Bool(a), Value::Bool(b)) => a == b,
+                (Value::Bool(_), _) | (_, Value::Bool(_)) => false,
+                (a, b) => a.num() == b.num(),
+            };
+            stack.push(Value::Bool(equal == (operator == Eq)));
+        }
+        Ge => compare(stack, |a, b| a >= b)?,
+        Gt => compare(stack, |a, b| a > b)?,
+        Le => compare(stack, |a, b| a <= b)?,
+        Lt => compare(stack, |a, b| a < b)?,
+        And => logical(stack, |a, b| a && b, |a, b| a & b)?,
+        Or => logical(stack, |a, b| a || b, |a, b| a | b)?,
+        Xor => logical(stack, |a, b| a ^ b, |a, b| a ^ b)?,
+        Not => {
+            let value = match stack.pop()? {
+                Value::Bool(b) => Value::Bool(!b),
+                Value::Int(i) => Value::Int(!i),
+                Value::Real(_) => return None,
+            };
+            stack.push(value);
+        }
+        Bitshift => {
+            let (shift, value) = (pop_int(stack)?, pop_int(stack)?);
+            let shifted = match shift {
+                0.. => value.checked_shl(u32::try_from(shift).ok()?).unwrap_or(0),
+                _ => value.checked_shr(u32::try_from(-shift).ok()?).unwrap_or(0),
+            };
+            stack.push(Value::Int(shifted));
+        }
+        Pop => {
+            stack.pop()?;
+        }
+        Dup => stack.push(*stack.last()?),
+        Exch => {
+            let len = stack.len();
+            if len < 2 {
+                return None;
+            }
+            stack.swap(len - 1, len - 2);
+        }
+        Copy => {
+            let n = usize::try_from(pop_int(stack)?).ok()?;
+            let start = stack.len().checked_sub(n)?;
+            if stack.len() + n > MAX_STACK {
+                return None;
+            }
+            stack.extend_from_within(start..);
+        }
+        Index => {
+            let n = usize::try_from(pop_int(stack)?).ok()?;
+            let value = *stack.iter().rev().nth(n)?;
+            stack.push(value);
+        }
+        Roll => {
+            let (j, n) = (pop_int(stack)?, usize::try_from(pop_int(stack)?).ok()?);
+            let start = stack.len().checked_sub(n)?;
+            if n > 0 {
+                let shift = j.rem_euclid(n as i64) as usize;
+                stack[start..].rotate_right(shift);
+            }
+        }
+    }
+    Some(())
+}
@@ -0,0 +1,237 @@
+// Embedded CMap parsing with `usecmap` chaining
+use std::collections::HashMap;
+
+use adobe_cmap_parser::{ByteMapping, CIDRange, CodeRange, Value};
+use lopdf::{Document, Object, Stream};
+use log::warn;
+
+use crate::report::{self, WarningKind};
+use crate::{get_contents, object_utils, string_utils, PdfError, PdfResult};
+
+// Guards against CMaps that (directly or indirectly) use themselves; the chain is cut there
+const MAX_USECMAP_DEPTH: usize = 8;
+
+// Codes a single `bfrange` entry may map, so a range over the whole code space can't exhaust memory
+const MAX_BFRANGE_CODES: u32 = 0x10000;
+
+/// Load an embedded CMap stream, merging in any CMap it references
+/// through a `/UseCMap` entry or a `usecmap` operator.
+pub(crate) fn load_embedded_cmap(doc: &Document, stream: &Stream) -> PdfResult<ByteMapping> {
+    load_cmap_stream(doc, stream, 0)
+}
+
+/// Mapping for the predefined `Identity-H` / `Identity-V` CMaps
+pub(crate) fn identity_mapping() -> ByteMapping {
+    ByteMapping {
+        codespace: vec![CodeRange { width: 2, start: 0, end: 0xffff }],
+        cid: vec![CIDRange {
+            src_code_lo: 0,
+            src_code_hi: 0xffff,
+            dst_CID_lo: 0,
+        }],
+    }
+}
+
+fn predefined_cmap(name: &[u8]) -> PdfResult<Option<ByteMapping>> {
+    let name = string_utils::pdf_to_utf8(name)?;
+    match name.as_str() {
+        "Identity-H" | "Identity-V" => Ok(Some(identity_mapping())),
+        _ => {
+            warn!("Unsupported predefined CMap in usecmap: {}", name);
+            Ok(None)
+        }
+    }
+}
+
+fn load_cmap_stream(doc: &Document, stream: &Stream, depth: usize) -> PdfResult<ByteMapping> {
+    let contents = get_contents(stream)?;
+    let (mut mapping, used_name) = parse_byte_mapping(&contents)?;
+
+    // The stream dictionary entry takes precedence over the operator in the body
+    let parent = match stream.dict.get(b"UseCMap").ok().map(|o| object_utils::maybe_deref(doc, o)) {
+        Some(Ok(Object::Stream(parent))) if depth < MAX_USECMAP_DEPTH => Some(load_cmap_stream(doc, parent, depth + 1)?),
+        Some(Ok(Object::Stream(_))) => {
+            report::warn(WarningKind::Recovered, format!("usecmap chain longer than {} CMaps, ignoring the rest", MAX_USECMAP_DEPTH + 1));
+            None
+        }
+        Some(Ok(Object::Name(name))) => predefined_cmap(name)?,
+        Some(Ok(_)) => {
+            return Err(PdfError::InvalidStructure("UseCMap must be a name or stream".to_string()));
+        }
+        Some(Err(e)) => return Err(e),
+        None => match used_name {
+            Some(name) => predefined_cmap(&name)?,
+            None => None,
+        },
+    };
+
+    if let Some(parent) = parent {
+        merge_parent(&mut mapping, parent);
+    }
+    Ok(mapping)
+}
+
+// Ranges defined by the referencing CMap win over the ones it inherits;
+// lookups scan ranges in order, so the parent's are appended after ours.
+fn merge_parent(mapping: &mut ByteMapping, parent: ByteMapping) {
+    for range in parent.codespace {
+        let duplicate = mapping.codespace.iter()
+            .any(|r| r.width == range.width && r.start == range.start && r.end == range.end);
+        if !duplicate {
+            mapping.codespace.push(range);
+        }
+    }
+    mapping.cid.extend(parent.cid);
+}
+
+fn as_code(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0, |code, &b| (code << 8) | b as u32)
+}
+
+fn entry_count(lexed: &[Value], i: usize, op: &str) -> PdfResult<usize> {
+    match i.checked_sub(1).and_then(|j| lexed.get(j)) {
+        Some(Value::Integer(c)) if *c >= 0 => Ok(*c as usize),
+        _ => Err(PdfError::InvalidStructure(format!("{} expects a count", op))),
+    }
+}
+
+fn code_operand<'v>(lexed: &'v [Value], i: usize, op: &str) -> PdfResult<&'v [u8]> {
+    match lexed.get(i) {
+        Some(Value::LiteralString(s)) => Ok(s),
+        _ => Err(PdfError::InvalidStructure(format!("{} expects a hex string", op))),
+    }
+}
+
+fn cid_operand(lexed: &[Value], i: usize, op: &str) -> PdfResult<u32> {
+    match lexed.get(i) {
+        Some(Value::Integer(cid)) if *cid >= 0 => Ok(*cid as u32),
+        _ => Err(PdfError::InvalidStructure(format!("{} expects a CID", op))),
+    }
+}
+
+/// Parse the code space and CID mappings of a CMap body, returning the
+/// name given to a `usecmap` operator if there is one.
+fn parse_byte_mapping(contents: &[u8]) -> PdfResult<(ByteMapping, Option<Vec<u8>>)> {
+    let lexed = adobe_cmap_parser::parse(contents)
+        .map_err(|_| PdfError::InvalidStructure("Invalid CMap".to_string()))?;
+
+    let mut mapping = ByteMapping { codespace: Vec::new(), cid: Vec::new() };
+    let mut used_name = None;
+    let mut i = 0;
+    while i < lexed.len() {
+        let Value::Operator(op) = &lexed[i] else {
+            i += 1;
+            continue;
+        };
+        match op.as_str() {
+            "usecmap" => {
+                if let Some(Value::Name(name)) = i.checked_sub(1).and_then(|j| lexed.get(j)) {
+                    used_name = Some(name.clone());
+                }
+                i += 1;
+            }
+            "begincodespacerange" => {
+                let count = entry_count(&lexed, i, op)?;
+                i += 1;
+                for _ in 0..count {
+                    let start = code_operand(&lexed, i, op)?;
+                    let end = code_operand(&lexed, i + 1, op)?;
+                    if start.len() != end.len() {
+                        return Err(PdfError::InvalidStructure("Mismatched codespace range".to_string()));
+                    }
+                    mapping.codespace.push(CodeRange {
+                        width: start.len() as u32,
+                        start: as_code(start),
+                        end: as_code(end),
+                    });
+                    i += 2;
+                }
+            }
+            "begincidrange" => {
+                let count = entry_count(&lexed, i, op)?;
+                i += 1;
+                for _ in 0..count {
+                    let lo = as_code(code_operand(&lexed, i, op)?);
+                    let hi = as_code(code_operand(&lexed, i + 1, op)?);
+                    let cid = cid_operand(&lexed, i + 2, op)?;
+                    mapping.cid.push(CIDRange { src_code_lo: lo, src_code_hi: hi, dst_CID_lo: cid });
+                    i += 3;
+                }
+            }
+            "begincidchar" => {
+                let count = entry_count(&lexed, i, op)?;
+                i += 1;
+                for _ in 0..count {
+                    let code = as_code(code_operand(&lexed, i, op)?);
+                    let cid = cid_operand(&lexed, i + 1, op)?;
+                    mapping.cid.push(CIDRange { src_code_lo: code, src_code_hi: code, dst_CID_lo: cid });
+                    i += 2;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    Ok((mapping, used_name))
+}
+
+/// Parse the `bfchar` and `bfrange` mappings of a ToUnicode CMap into UTF-16BE strings by
+/// character code.
+pub(crate) fn parse_unicode_map(contents: &[u8]) -> PdfResult<HashMap<u32, Vec<u8>>> {
+    let lexed = adobe_cmap_parser::parse(contents)
+        .map_err(|_| PdfError::InvalidStructure("Invalid ToUnicode CMap".to_string()))?;
+
+    let mut map = HashMap::new();
+    let mut i = 0;
+    while i < lexed.len() {
+        let Value::Operator(op) = &lexed[i] else {
+            i += 1;
+            continue;
+        };
+        match op.as_str() {
+            "beginbfchar" => {
+                let count = entry_count(&lexed, i, op)?;
+                i += 1;
+                for _ in 0..count {
+                    let code = as_code(code_operand(&lexed, i, op)?);
+                    map.insert(code, code_operand(&lexed, i + 1, op)?.to_vec());
+                    i += 2;
+                }
+            }
+            "beginbfrange" => {
+                let count = entry_count(&lexed, i, op)?;
+                i += 1;
+                for _ in 0..count {
+                    let lo = as_code(code_operand(&lexed, i, op)?);
+                    let hi = as_code(code_operand(&lexed, i + 1, op)?);
+                    if hi.saturating_sub(lo) >= MAX_BFRANGE_CODES {
+                        return Err(PdfError::InvalidStructure("bfrange maps too many codes".to_string()));
+                    }
+                    match lexed.get(i + 2) {
+                        // Successive codes increment the last UTF-16 code unit of the start
+                        Some(Value::LiteralString(start)) if start.len() >= 2 && start.len() % 2 == 0 => {
+                            let (prefix, last) = start.split_at(start.len() - 2);
+                            let last = u16::from_be_bytes([last[0], last[1]]);
+                            for code in lo..=hi {
+                                let mut unicode = prefix.to_vec();
+                                unicode.extend(last.wrapping_add((code - lo) as u16).to_be_bytes());
+                                map.insert(code, unicode);
+                            }
+                        }
+                        Some(Value::Array(strings)) if strings.len() as u64 == u64::from(hi.saturating_sub(lo)) + 1 && lo <= hi => {
+                            for (code, unicode) in (lo..=hi).zip(strings) {
+                                match unicode {
+                                    Value::LiteralString(unicode) => map.insert(code, unicode.clone()),
+                                    _ => return Err(PdfError::InvalidStructure(format!("{} expects hex strings", op))),
+                                };
+                            }
+                        }
+                        _ => return Err(PdfError::InvalidStructure(format!("{} expects a hex string or array of them", op))),
+                    }
+                    i += 3;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    Ok(map)
+}
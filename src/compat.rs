@@ -0,0 +1,179 @@
+// Function signatures and error type of upstream pdf-extract
+//
+// Projects migrating from jrmuizel/pdf-extract can replace `pdf_extract::`
+// with `pdf_extract::compat::` and keep compiling. The functions are the
+// upstream names, argument types and `Result<_, OutputError>` returns on top
+// of this crate's implementation; output devices are this crate's
+// `OutputDev` implementations.
+use std::fmt;
+
+use log::debug;
+
+use crate::info::DocumentInfo;
+use crate::{Document, PdfError};
+
+pub use crate::{ColorSpace, HTMLOutput, MediaBox, OutputDev, Path, PlainTextOutput, SVGOutput};
+
+pub type Transform = crate::PdfTransform;
+
+/// Upstream's error type; the structural errors it would have panicked on are reported as
+/// `PdfError(lopdf::Error::Syntax(..))`, missing keys as `PdfError(lopdf::Error::DictKey(..))`
+#[derive(Debug)]
+pub enum OutputError {
+    FormatError(fmt::Error),
+    IoError(std::io::Error),
+    PdfError(lopdf::Error),
+}
+
+impl fmt::Display for OutputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutputError::FormatError(e) => write!(f, "Formating error: {}", e),
+            OutputError::IoError(e) => write!(f, "IO error: {}", e),
+            OutputError::PdfError(e) => write!(f, "PDF error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for OutputError {}
+
+impl From<fmt::Error> for OutputError {
+    fn from(e: fmt::Error) -> Self {
+        OutputError::FormatError(e)
+    }
+}
+
+impl From<std::io::Error> for OutputError {
+    fn from(e: std::io::Error) -> Self {
+        OutputError::IoError(e)
+    }
+}
+
+impl From<lopdf::Error> for OutputError {
+    fn from(e: lopdf::Error) -> Self {
+        OutputError::PdfError(e)
+    }
+}
+
+impl From<PdfError> for OutputError {
+    fn from(e: PdfError) -> Self {
+        match e {
+            PdfError::Format(e) => OutputError::FormatError(e),
+            PdfError::Io(e) => OutputError::IoError(e),
+            PdfError::Parse(e) => OutputError::PdfError(e),
+            PdfError::MissingField(key) => OutputError::PdfError(lopdf::Error::DictKey(key)),
+            PdfError::InvalidStructure(msg) | PdfError::FontError(msg) | PdfError::EncodingError(msg) => {
+                OutputError::PdfError(lopdf::Error::Syntax(msg))
+            }
+            PdfError::Cancelled => OutputError::PdfError(lopdf::Error::Syntax("Extraction cancelled".to_string())),
+            PdfError::ResourceLimit(msg) => OutputError::PdfError(lopdf::Error::Syntax(msg)),
+        }
+    }
+}
+
+pub fn extract_text<P: AsRef<std::path::Path>>(path: P) -> Result<String, OutputError> {
+    Ok(crate::extract_text(path)?)
+}
+
+pub fn extract_text_encrypted<P: AsRef<std::path::Path>, PW: AsRef<[u8]>>(
+    path: P,
+    password: PW,
+) -> Result<String, OutputError> {
+    let mut doc = Document::load(path)?;
+    doc.decrypt_raw(password)?;
+    text(&doc)
+}
+
+pub fn extract_text_from_mem(buffer: &[u8]) -> Result<String, OutputError> {
+    Ok(crate::extract_text_from_mem(buffer)?)
+}
+
+pub fn extract_text_from_mem_encrypted<PW: AsRef<[u8]>>(buffer: &[u8], password: PW) -> Result<String, OutputError> {
+    let mut doc = Document::load_mem(buffer)?;
+    doc.decrypt_raw(password)?;
+    text(&doc)
+}
+
+pub fn extract_text_by_pages<P: AsRef<std::path::Path>>(path: P) -> Result<Vec<String>, OutputError> {
+    Ok(crate::extract_text_by_pages(path)?)
+}
+
+pub fn extract_text_by_pages_encrypted<P: AsRef<std::path::Path>, PW: AsRef<[u8]>>(
+    path: P,
+    password: PW,
+) -> Result<Vec<String>, OutputError> {
+    let mut doc = Document::load(path)?;
+    doc.decrypt_raw(password)?;
+    text_by_pages(&doc)
+}
+
+pub fn extract_text_from_mem_by_pages(buffer: &[u8]) -> Result<Vec<String>, OutputError> {
+    Ok(crate::extract_text_from_mem_by_pages(buffer)?)
+}
+
+pub fn extract_text_from_mem_by_pages_encrypted<PW: AsRef<[u8]>>(
+    buffer: &[u8],
+    password: PW,
+) -> Result<Vec<String>, OutputError> {
+    let mut doc = Document::load_mem(buffer)?;
+    doc.decrypt_raw(password)?;
+    text_by_pages(&doc)
+}
+
+pub fn output_doc(doc: &Document, output: &mut dyn OutputDev) -> Result<(), OutputError> {
+    Ok(crate::output_doc(doc, output)?)
+}
+
+pub fn output_doc_encrypted<PW: AsRef<[u8]>>(
+    doc: &mut Document,
+    output: &mut dyn OutputDev,
+    password: PW,
+) -> Result<(), OutputError> {
+    doc.decrypt_raw(password)?;
+    output_doc(doc, output)
+}
+
+pub fn output_doc_page(doc: &Document, output: &mut dyn OutputDev, page_num: u32) -> Result<(), OutputError> {
+    Ok(crate::output_doc_page(doc, output, page_num)?)
+}
+
+/// Logs the version, `/Info` entries and page count at debug level; `DocumentInfo` has them typed
+pub fn print_metadata(doc: &Document) {
+    let info = DocumentInfo::from(doc);
+    debug!("Version: {}", info.version);
+    let fields = [
+        ("Title", &info.title),
+        ("Author", &info.author),
+        ("Subject", &info.subject),
+        ("Keywords", &info.keywords),
+        ("Creator", &info.creator),
+        ("Producer", &info.producer),
+        ("Trapped", &info.trapped),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            debug!("{}: {}", key, value);
+        }
+    }
+    for (key, value) in &info.custom {
+        debug!("{}: {}", key, value);
+    }
+    debug!("Page count: {}", info.page_count);
+}
+
+fn text(doc: &Document) -> Result<String, OutputError> {
+    let mut s = Vec::new();
+    output_doc(doc, &mut PlainTextOutput::new(&mut s))?;
+    String::from_utf8(s).map_err(|_| lopdf::Error::Syntax("Invalid UTF-8".to_string()).into())
+}
+
+// One string per page of the page tree, failing on the first page that fails to extract
+fn text_by_pages(doc: &Document) -> Result<Vec<String>, OutputError> {
+    let mut pages = Vec::new();
+    for &page_num in doc.get_pages().keys() {
+        let mut s = Vec::new();
+        output_doc_page(doc, &mut PlainTextOutput::new(&mut s), page_num)?;
+        pages.push(String::from_utf8(s).map_err(|_| lopdf::Error::Syntax("Invalid UTF-8".to_string()))?);
+    }
+    Ok(pages)
+}
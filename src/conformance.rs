@@ -0,0 +1,92 @@
+// PDF/A and PDF/UA conformance claims
+//
+// A document claims conformance in its XMP metadata: the `pdfaid` schema
+// names the PDF/A part and level, `pdfuaid` the PDF/UA part. PDF/A also
+// requires an output intent describing the colour characteristics the
+// document was prepared for. The claims are read as written; nothing here
+// validates the document against them.
+use crate::{document_utils, object_utils, string_utils, Document, Object};
+
+/// A PDF/A claim, e.g. part 2 level `B` for PDF/A-2b
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdfA {
+    pub part: u32,
+    /// `A`, `B` or `U`; PDF/A-4 has none or `E` and `F`
+    pub conformance: Option<String>,
+}
+
+/// An entry of the catalog's `/OutputIntents`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputIntent {
+    /// `/S`, e.g. `GTS_PDFA1` or `GTS_PDFX`
+    pub subtype: String,
+    /// `/OutputConditionIdentifier`, e.g. `sRGB IEC61966-2.1`
+    pub condition_identifier: Option<String>,
+    /// `/Info`
+    pub info: Option<String>,
+    /// Whether `/DestOutputProfile` embeds an ICC profile
+    pub has_profile: bool,
+}
+
+/// Conformance claims of a document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentProfile {
+    pub pdfa: Option<PdfA>,
+    /// PDF/UA part
+    pub pdfua: Option<u32>,
+    pub output_intents: Vec<OutputIntent>,
+}
+
+impl From<&Document> for DocumentProfile {
+    fn from(doc: &Document) -> DocumentProfile {
+        let Ok(catalog) = document_utils::get_catalog(doc) else { return DocumentProfile::default() };
+        let xmp = object_utils::maybe_get_obj(doc, catalog, b"Metadata")
+            .and_then(|o| o.as_stream().ok())
+            .and_then(|s| if s.filters().map_or(true, |f| f.is_empty()) { Some(s.content.clone()) } else { s.decompressed_content().ok() })
+            .map(|xmp| String::from_utf8_lossy(&xmp).into_owned())
+            .unwrap_or_default();
+        let pdfa = xmp_property(&xmp, "pdfaid:part").and_then(|part| part.parse().ok()).map(|part| PdfA {
+            part,
+            conformance: xmp_property(&xmp, "pdfaid:conformance"),
+        });
+        let pdfua = xmp_property(&xmp, "pdfuaid:part").and_then(|part| part.parse().ok());
+
+        let text = |dict: &lopdf::Dictionary, key: &[u8]| {
+            object_utils::maybe_get_obj(doc, dict, key)
+                .and_then(|o| o.as_str().ok())
+                .and_then(|s| string_utils::pdf_to_utf8(s).ok())
+        };
+        let output_intents = match object_utils::maybe_get_obj(doc, catalog, b"OutputIntents") {
+            Some(Object::Array(intents)) => intents.iter()
+                .filter_map(|o| object_utils::maybe_deref(doc, o).ok()?.as_dict().ok())
+                .map(|intent| OutputIntent {
+                    subtype: intent.get(b"S").and_then(|o| o.as_name()).map(|n| String::from_utf8_lossy(n).into_owned()).unwrap_or_default(),
+                    condition_identifier: text(intent, b"OutputConditionIdentifier"),
+                    info: text(intent, b"Info"),
+                    has_profile: object_utils::maybe_get_obj(doc, intent, b"DestOutputProfile").is_some_and(|o| o.as_stream().is_ok()),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        DocumentProfile { pdfa, pdfua, output_intents }
+    }
+}
+
+// Value of an XMP simple property, written as an attribute `name="value"` of an
+// `rdf:Description` or as an element `<name>value</name>`
+fn xmp_property(xmp: &str, name: &str) -> Option<String> {
+    let attribute = format!("{}=", name);
+    if let Some(start) = xmp.find(&attribute) {
+        let rest = &xmp[start + attribute.len()..];
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &rest[1..];
+        return Some(value[..value.find(quote)?].trim().to_string());
+    }
+    let open = format!("<{}>", name);
+    let start = xmp.find(&open)? + open.len();
+    let end = xmp[start..].find("</")? + start;
+    Some(xmp[start..end].trim().to_string())
+}
@@ -1,4 +1,5 @@
 // Generated by afm/example/metrics.rs
+#[allow(clippy::type_complexity)]
 pub fn metrics() -> Box<[(&'static str, &'static str, &'static [(i64, f64, &'static str)])]> {
 Box::new([
 ("Courier-Bold", "AdobeStandardEncoding", &[(32, 600.0, "space"), (33, 600.0, "exclam"), (34, 600.0, "quotedbl"), (35, 600.0, "numbersign"), (36, 600.0, "dollar"), (37, 600.0, "percent"), (38, 600.0, "ampersand"), (39, 600.0, "quoteright"), (40, 600.0, "parenleft"), (41, 600.0, "parenright"), (42, 600.0, "asterisk"), (43, 600.0, "plus"), (44, 600.0, "comma"), (45, 600.0, "hyphen"), (46, 600.0, "period"), (47, 600.0, "slash"), (48, 600.0, "zero"), (49, 600.0, "one"), (50, 600.0, "two"), (51, 600.0, "three"), (52, 600.0, "four"), (53, 600.0, "five"), (54, 600.0, "six"), (55, 600.0, "seven"), (56, 600.0, "eight"), (57, 600.0, "nine"), (58, 600.0, "colon"), (59, 600.0, "semicolon"), (60, 600.0, "less"), (61, 600.0, "equal"), (62, 600.0, "greater"), (63, 600.0, "question"), (64, 600.0, "at"), (65, 600.0, "A"), (66, 600.0, "B"), (67, 600.0, "C"), (68, 600.0, "D"), (69, 600.0, "E"), (70, 600.0, "F"), (71, 600.0, "G"), (72, 600.0, "H"), (73, 600.0, "I"), (74, 600.0, "J"), (75, 600.0, "K"), (76, 600.0, "L"), (77, 600.0, "M"), (78, 600.0, "N"), (79, 600.0, "O"), (80, 600.0, "P"), (81, 600.0, "Q"), (82, 600.0, "R"), (83, 600.0, "S"), (84, 600.0, "T"), (85, 600.0, "U"), (86, 600.0, "V"), (87, 600.0, "W"), (88, 600.0, "X"), (89, 600.0, "Y"), (90, 600.0, "Z"), (91, 600.0, "bracketleft"), (92, 600.0, "backslash"), (93, 600.0, "bracketright"), (94, 600.0, "asciicircum"), (95, 600.0, "underscore"), (96, 600.0, "quoteleft"), (97, 600.0, "a"), (98, 600.0, "b"), (99, 600.0, "c"), (100, 600.0, "d"), (101, 600.0, "e"), (102, 600.0, "f"), (103, 600.0, "g"), (104, 600.0, "h"), (105, 600.0, "i"), (106, 600.0, "j"), (107, 600.0, "k"), (108, 600.0, "l"), (109, 600.0, "m"), (110, 600.0, "n"), (111, 600.0, "o"), (112, 600.0, "p"), (113, 600.0, "q"), (114, 600.0, "r"), (115, 600.0, "s"), (116, 600.0, "t"), (117, 600.0, "u"), (118, 600.0, "v"), (119, 600.0, "w"), (120, 600.0, "x"), (121, 600.0, "y"), (122, 600.0, "z"), (123, 600.0, "braceleft"), (124, 600.0, "bar"), (125, 600.0, "braceright"), (126, 600.0, "asciitilde"), (161, 600.0, "exclamdown"), (162, 600.0, "cent"), (163, 600.0, "sterling"), (164, 600.0, "fraction"), (165, 600.0, "yen"), (166, 600.0, "florin"), (167, 600.0, "section"), (168, 600.0, "currency"), (169, 600.0, "quotesingle"), (170, 600.0, "quotedblleft"), (171, 600.0, "guillemotleft"), (172, 600.0, "guilsinglleft"), (173, 600.0, "guilsinglright"), (174, 600.0, "fi"), (175, 600.0, "fl"), (177, 600.0, "endash"), (178, 600.0, "dagger"), (179, 600.0, "daggerdbl"), (180, 600.0, "periodcentered"), (182, 600.0, "paragraph"), (183, 600.0, "bullet"), (184, 600.0, "quotesinglbase"), (185, 600.0, "quotedblbase"), (186, 600.0, "quotedblright"), (187, 600.0, "guillemotright"), (188, 600.0, "ellipsis"), (189, 600.0, "perthousand"), (191, 600.0, "questiondown"), (193, 600.0, "grave"), (194, 600.0, "acute"), (195, 600.0, "circumflex"), (196, 600.0, "tilde"), (197, 600.0, "macron"), (198, 600.0, "breve"), (199, 600.0, "dotaccent"), (200, 600.0, "dieresis"), (202, 600.0, "ring"), (203, 600.0, "cedilla"), (205, 600.0, "hungarumlaut"), (206, 600.0, "ogonek"), (207, 600.0, "caron"), (208, 600.0, "emdash"), (225, 600.0, "AE"), (227, 600.0, "ordfeminine"), (232, 600.0, "Lslash"), (233, 600.0, "Oslash"), (234, 600.0, "OE"), (235, 600.0, "ordmasculine"), (241, 600.0, "ae"), (245, 600.0, "dotlessi"), (248, 600.0, "lslash"), (249, 600.0, "oslash"), (250, 600.0, "oe"), (251, 600.0, "germandbls"), (-1, 600.0, "Idieresis"), (-1, 600.0, "eacute"), (-1, 600.0, "abreve"), (-1, 600.0, "uhungarumlaut"), (-1, 600.0, "ecaron"), (-1, 600.0, "Ydieresis"), (-1, 600.0, "divide"), (-1, 600.0, "Yacute"), (-1, 600.0, "Acircumflex"), (-1, 600.0, "aacute"), (-1, 600.0, "Ucircumflex"), (-1, 600.0, "yacute"), (-1, 600.0, "scommaaccent"), (-1, 600.0, "ecircumflex"), (-1, 600.0, "Uring"), (-1, 600.0, "Udieresis"), (-1, 600.0, "aogonek"), (-1, 600.0, "Uacute"), (-1, 600.0, "uogonek"), (-1, 600.0, "Edieresis"), (-1, 600.0, "Dcroat"), (-1, 600.0, "commaaccent"), (-1, 600.0, "copyright"), (-1, 600.0, "Emacron"), (-1, 600.0, "ccaron"), (-1, 600.0, "aring"), (-1, 600.0, "Ncommaaccent"), (-1, 600.0, "lacute"), (-1, 600.0, "agrave"), (-1, 600.0, "Tcommaaccent"), (-1, 600.0, "Cacute"), (-1, 600.0, "atilde"), (-1, 600.0, "Edotaccent"), (-1, 600.0, "scaron"), (-1, 600.0, "scedilla"), (-1, 600.0, "iacute"), (-1, 600.0, "lozenge"), (-1, 600.0, "Rcaron"), (-1, 600.0, "Gcommaaccent"), (-1, 600.0, "ucircumflex"), (-1, 600.0, "acircumflex"), (-1, 600.0, "Amacron"), (-1, 600.0, "rcaron"), (-1, 600.0, "ccedilla"), (-1, 600.0, "Zdotaccent"), (-1, 600.0, "Thorn"), (-1, 600.0, "Omacron"), (-1, 600.0, "Racute"), (-1, 600.0, "Sacute"), (-1, 600.0, "dcaron"), (-1, 600.0, "Umacron"), (-1, 600.0, "uring"), (-1, 600.0, "threesuperior"), (-1, 600.0, "Ograve"), (-1, 600.0, "Agrave"), (-1, 600.0, "Abreve"), (-1, 600.0, "multiply"), (-1, 600.0, "uacute"), (-1, 600.0, "Tcaron"), (-1, 600.0, "partialdiff"), (-1, 600.0, "ydieresis"), (-1, 600.0, "Nacute"), (-1, 600.0, "icircumflex"), (-1, 600.0, "Ecircumflex"), (-1, 600.0, "adieresis"), (-1, 600.0, "edieresis"), (-1, 600.0, "cacute"), (-1, 600.0, "nacute"), (-1, 600.0, "umacron"), (-1, 600.0, "Ncaron"), (-1, 600.0, "Iacute"), (-1, 600.0, "plusminus"), (-1, 600.0, "brokenbar"), (-1, 600.0, "registered"), (-1, 600.0, "Gbreve"), (-1, 600.0, "Idotaccent"), (-1, 600.0, "summation"), (-1, 600.0, "Egrave"), (-1, 600.0, "racute"), (-1, 600.0, "omacron"), (-1, 600.0, "Zacute"), (-1, 600.0, "Zcaron"), (-1, 600.0, "greaterequal"), (-1, 600.0, "Eth"), (-1, 600.0, "Ccedilla"), (-1, 600.0, "lcommaaccent"), (-1, 600.0, "tcaron"), (-1, 600.0, "eogonek"), (-1, 600.0, "Uogonek"), (-1, 600.0, "Aacute"), (-1, 600.0, "Adieresis"), (-1, 600.0, "egrave"), (-1, 600.0, "zacute"), (-1, 600.0, "iogonek"), (-1, 600.0, "Oacute"), (-1, 600.0, "oacute"), (-1, 600.0, "amacron"), (-1, 600.0, "sacute"), (-1, 600.0, "idieresis"), (-1, 600.0, "Ocircumflex"), (-1, 600.0, "Ugrave"), (-1, 600.0, "Delta"), (-1, 600.0, "thorn"), (-1, 600.0, "twosuperior"), (-1, 600.0, "Odieresis"), (-1, 600.0, "mu"), (-1, 600.0, "igrave"), (-1, 600.0, "ohungarumlaut"), (-1, 600.0, "Eogonek"), (-1, 600.0, "dcroat"), (-1, 600.0, "threequarters"), (-1, 600.0, "Scedilla"), (-1, 600.0, "lcaron"), (-1, 600.0, "Kcommaaccent"), (-1, 600.0, "Lacute"), (-1, 600.0, "trademark"), (-1, 600.0, "edotaccent"), (-1, 600.0, "Igrave"), (-1, 600.0, "Imacron"), (-1, 600.0, "Lcaron"), (-1, 600.0, "onehalf"), (-1, 600.0, "lessequal"), (-1, 600.0, "ocircumflex"), (-1, 600.0, "ntilde"), (-1, 600.0, "Uhungarumlaut"), (-1, 600.0, "Eacute"), (-1, 600.0, "emacron"), (-1, 600.0, "gbreve"), (-1, 600.0, "onequarter"), (-1, 600.0, "Scaron"), (-1, 600.0, "Scommaaccent"), (-1, 600.0, "Ohungarumlaut"), (-1, 600.0, "degree"), (-1, 600.0, "ograve"), (-1, 600.0, "Ccaron"), (-1, 600.0, "ugrave"), (-1, 600.0, "radical"), (-1, 600.0, "Dcaron"), (-1, 600.0, "rcommaaccent"), (-1, 600.0, "Ntilde"), (-1, 600.0, "otilde"), (-1, 600.0, "Rcommaaccent"), (-1, 600.0, "Lcommaaccent"), (-1, 600.0, "Atilde"), (-1, 600.0, "Aogonek"), (-1, 600.0, "Aring"), (-1, 600.0, "Otilde"), (-1, 600.0, "zdotaccent"), (-1, 600.0, "Ecaron"), (-1, 600.0, "Iogonek"), (-1, 600.0, "kcommaaccent"), (-1, 600.0, "minus"), (-1, 600.0, "Icircumflex"), (-1, 600.0, "ncaron"), (-1, 600.0, "tcommaaccent"), (-1, 600.0, "logicalnot"), (-1, 600.0, "odieresis"), (-1, 600.0, "udieresis"), (-1, 600.0, "notequal"), (-1, 600.0, "gcommaaccent"), (-1, 600.0, "eth"), (-1, 600.0, "zcaron"), (-1, 600.0, "ncommaaccent"), (-1, 600.0, "onesuperior"), (-1, 600.0, "imacron"), (-1, 600.0, "Euro")][..]),
@@ -16,3 +17,75 @@ Box::new([
 ("Times-Roman", "AdobeStandardEncoding", &[(32, 250.0, "space"), (33, 333.0, "exclam"), (34, 408.0, "quotedbl"), (35, 500.0, "numbersign"), (36, 500.0, "dollar"), (37, 833.0, "percent"), (38, 778.0, "ampersand"), (39, 333.0, "quoteright"), (40, 333.0, "parenleft"), (41, 333.0, "parenright"), (42, 500.0, "asterisk"), (43, 564.0, "plus"), (44, 250.0, "comma"), (45, 333.0, "hyphen"), (46, 250.0, "period"), (47, 278.0, "slash"), (48, 500.0, "zero"), (49, 500.0, "one"), (50, 500.0, "two"), (51, 500.0, "three"), (52, 500.0, "four"), (53, 500.0, "five"), (54, 500.0, "six"), (55, 500.0, "seven"), (56, 500.0, "eight"), (57, 500.0, "nine"), (58, 278.0, "colon"), (59, 278.0, "semicolon"), (60, 564.0, "less"), (61, 564.0, "equal"), (62, 564.0, "greater"), (63, 444.0, "question"), (64, 921.0, "at"), (65, 722.0, "A"), (66, 667.0, "B"), (67, 667.0, "C"), (68, 722.0, "D"), (69, 611.0, "E"), (70, 556.0, "F"), (71, 722.0, "G"), (72, 722.0, "H"), (73, 333.0, "I"), (74, 389.0, "J"), (75, 722.0, "K"), (76, 611.0, "L"), (77, 889.0, "M"), (78, 722.0, "N"), (79, 722.0, "O"), (80, 556.0, "P"), (81, 722.0, "Q"), (82, 667.0, "R"), (83, 556.0, "S"), (84, 611.0, "T"), (85, 722.0, "U"), (86, 722.0, "V"), (87, 944.0, "W"), (88, 722.0, "X"), (89, 722.0, "Y"), (90, 611.0, "Z"), (91, 333.0, "bracketleft"), (92, 278.0, "backslash"), (93, 333.0, "bracketright"), (94, 469.0, "asciicircum"), (95, 500.0, "underscore"), (96, 333.0, "quoteleft"), (97, 444.0, "a"), (98, 500.0, "b"), (99, 444.0, "c"), (100, 500.0, "d"), (101, 444.0, "e"), (102, 333.0, "f"), (103, 500.0, "g"), (104, 500.0, "h"), (105, 278.0, "i"), (106, 278.0, "j"), (107, 500.0, "k"), (108, 278.0, "l"), (109, 778.0, "m"), (110, 500.0, "n"), (111, 500.0, "o"), (112, 500.0, "p"), (113, 500.0, "q"), (114, 333.0, "r"), (115, 389.0, "s"), (116, 278.0, "t"), (117, 500.0, "u"), (118, 500.0, "v"), (119, 722.0, "w"), (120, 500.0, "x"), (121, 500.0, "y"), (122, 444.0, "z"), (123, 480.0, "braceleft"), (124, 200.0, "bar"), (125, 480.0, "braceright"), (126, 541.0, "asciitilde"), (161, 333.0, "exclamdown"), (162, 500.0, "cent"), (163, 500.0, "sterling"), (164, 167.0, "fraction"), (165, 500.0, "yen"), (166, 500.0, "florin"), (167, 500.0, "section"), (168, 500.0, "currency"), (169, 180.0, "quotesingle"), (170, 444.0, "quotedblleft"), (171, 500.0, "guillemotleft"), (172, 333.0, "guilsinglleft"), (173, 333.0, "guilsinglright"), (174, 556.0, "fi"), (175, 556.0, "fl"), (177, 500.0, "endash"), (178, 500.0, "dagger"), (179, 500.0, "daggerdbl"), (180, 250.0, "periodcentered"), (182, 453.0, "paragraph"), (183, 350.0, "bullet"), (184, 333.0, "quotesinglbase"), (185, 444.0, "quotedblbase"), (186, 444.0, "quotedblright"), (187, 500.0, "guillemotright"), (188, 1000.0, "ellipsis"), (189, 1000.0, "perthousand"), (191, 444.0, "questiondown"), (193, 333.0, "grave"), (194, 333.0, "acute"), (195, 333.0, "circumflex"), (196, 333.0, "tilde"), (197, 333.0, "macron"), (198, 333.0, "breve"), (199, 333.0, "dotaccent"), (200, 333.0, "dieresis"), (202, 333.0, "ring"), (203, 333.0, "cedilla"), (205, 333.0, "hungarumlaut"), (206, 333.0, "ogonek"), (207, 333.0, "caron"), (208, 1000.0, "emdash"), (225, 889.0, "AE"), (227, 276.0, "ordfeminine"), (232, 611.0, "Lslash"), (233, 722.0, "Oslash"), (234, 889.0, "OE"), (235, 310.0, "ordmasculine"), (241, 667.0, "ae"), (245, 278.0, "dotlessi"), (248, 278.0, "lslash"), (249, 500.0, "oslash"), (250, 722.0, "oe"), (251, 500.0, "germandbls"), (-1, 333.0, "Idieresis"), (-1, 444.0, "eacute"), (-1, 444.0, "abreve"), (-1, 500.0, "uhungarumlaut"), (-1, 444.0, "ecaron"), (-1, 722.0, "Ydieresis"), (-1, 564.0, "divide"), (-1, 722.0, "Yacute"), (-1, 722.0, "Acircumflex"), (-1, 444.0, "aacute"), (-1, 722.0, "Ucircumflex"), (-1, 500.0, "yacute"), (-1, 389.0, "scommaaccent"), (-1, 444.0, "ecircumflex"), (-1, 722.0, "Uring"), (-1, 722.0, "Udieresis"), (-1, 444.0, "aogonek"), (-1, 722.0, "Uacute"), (-1, 500.0, "uogonek"), (-1, 611.0, "Edieresis"), (-1, 722.0, "Dcroat"), (-1, 250.0, "commaaccent"), (-1, 760.0, "copyright"), (-1, 611.0, "Emacron"), (-1, 444.0, "ccaron"), (-1, 444.0, "aring"), (-1, 722.0, "Ncommaaccent"), (-1, 278.0, "lacute"), (-1, 444.0, "agrave"), (-1, 611.0, "Tcommaaccent"), (-1, 667.0, "Cacute"), (-1, 444.0, "atilde"), (-1, 611.0, "Edotaccent"), (-1, 389.0, "scaron"), (-1, 389.0, "scedilla"), (-1, 278.0, "iacute"), (-1, 471.0, "lozenge"), (-1, 667.0, "Rcaron"), (-1, 722.0, "Gcommaaccent"), (-1, 500.0, "ucircumflex"), (-1, 444.0, "acircumflex"), (-1, 722.0, "Amacron"), (-1, 333.0, "rcaron"), (-1, 444.0, "ccedilla"), (-1, 611.0, "Zdotaccent"), (-1, 556.0, "Thorn"), (-1, 722.0, "Omacron"), (-1, 667.0, "Racute"), (-1, 556.0, "Sacute"), (-1, 588.0, "dcaron"), (-1, 722.0, "Umacron"), (-1, 500.0, "uring"), (-1, 300.0, "threesuperior"), (-1, 722.0, "Ograve"), (-1, 722.0, "Agrave"), (-1, 722.0, "Abreve"), (-1, 564.0, "multiply"), (-1, 500.0, "uacute"), (-1, 611.0, "Tcaron"), (-1, 476.0, "partialdiff"), (-1, 500.0, "ydieresis"), (-1, 722.0, "Nacute"), (-1, 278.0, "icircumflex"), (-1, 611.0, "Ecircumflex"), (-1, 444.0, "adieresis"), (-1, 444.0, "edieresis"), (-1, 444.0, "cacute"), (-1, 500.0, "nacute"), (-1, 500.0, "umacron"), (-1, 722.0, "Ncaron"), (-1, 333.0, "Iacute"), (-1, 564.0, "plusminus"), (-1, 200.0, "brokenbar"), (-1, 760.0, "registered"), (-1, 722.0, "Gbreve"), (-1, 333.0, "Idotaccent"), (-1, 600.0, "summation"), (-1, 611.0, "Egrave"), (-1, 333.0, "racute"), (-1, 500.0, "omacron"), (-1, 611.0, "Zacute"), (-1, 611.0, "Zcaron"), (-1, 549.0, "greaterequal"), (-1, 722.0, "Eth"), (-1, 667.0, "Ccedilla"), (-1, 278.0, "lcommaaccent"), (-1, 326.0, "tcaron"), (-1, 444.0, "eogonek"), (-1, 722.0, "Uogonek"), (-1, 722.0, "Aacute"), (-1, 722.0, "Adieresis"), (-1, 444.0, "egrave"), (-1, 444.0, "zacute"), (-1, 278.0, "iogonek"), (-1, 722.0, "Oacute"), (-1, 500.0, "oacute"), (-1, 444.0, "amacron"), (-1, 389.0, "sacute"), (-1, 278.0, "idieresis"), (-1, 722.0, "Ocircumflex"), (-1, 722.0, "Ugrave"), (-1, 612.0, "Delta"), (-1, 500.0, "thorn"), (-1, 300.0, "twosuperior"), (-1, 722.0, "Odieresis"), (-1, 500.0, "mu"), (-1, 278.0, "igrave"), (-1, 500.0, "ohungarumlaut"), (-1, 611.0, "Eogonek"), (-1, 500.0, "dcroat"), (-1, 750.0, "threequarters"), (-1, 556.0, "Scedilla"), (-1, 344.0, "lcaron"), (-1, 722.0, "Kcommaaccent"), (-1, 611.0, "Lacute"), (-1, 980.0, "trademark"), (-1, 444.0, "edotaccent"), (-1, 333.0, "Igrave"), (-1, 333.0, "Imacron"), (-1, 611.0, "Lcaron"), (-1, 750.0, "onehalf"), (-1, 549.0, "lessequal"), (-1, 500.0, "ocircumflex"), (-1, 500.0, "ntilde"), (-1, 722.0, "Uhungarumlaut"), (-1, 611.0, "Eacute"), (-1, 444.0, "emacron"), (-1, 500.0, "gbreve"), (-1, 750.0, "onequarter"), (-1, 556.0, "Scaron"), (-1, 556.0, "Scommaaccent"), (-1, 722.0, "Ohungarumlaut"), (-1, 400.0, "degree"), (-1, 500.0, "ograve"), (-1, 667.0, "Ccaron"), (-1, 500.0, "ugrave"), (-1, 453.0, "radical"), (-1, 722.0, "Dcaron"), (-1, 333.0, "rcommaaccent"), (-1, 722.0, "Ntilde"), (-1, 500.0, "otilde"), (-1, 667.0, "Rcommaaccent"), (-1, 611.0, "Lcommaaccent"), (-1, 722.0, "Atilde"), (-1, 722.0, "Aogonek"), (-1, 722.0, "Aring"), (-1, 722.0, "Otilde"), (-1, 444.0, "zdotaccent"), (-1, 611.0, "Ecaron"), (-1, 333.0, "Iogonek"), (-1, 500.0, "kcommaaccent"), (-1, 564.0, "minus"), (-1, 333.0, "Icircumflex"), (-1, 500.0, "ncaron"), (-1, 278.0, "tcommaaccent"), (-1, 564.0, "logicalnot"), (-1, 500.0, "odieresis"), (-1, 500.0, "udieresis"), (-1, 549.0, "notequal"), (-1, 500.0, "gcommaaccent"), (-1, 500.0, "eth"), (-1, 444.0, "zcaron"), (-1, 500.0, "ncommaaccent"), (-1, 300.0, "onesuperior"), (-1, 278.0, "imacron"), (-1, 500.0, "Euro")][..]),
 ("ZapfDingbats", "FontSpecific", &[(32, 278.0, "space"), (33, 974.0, "a1"), (34, 961.0, "a2"), (35, 974.0, "a202"), (36, 980.0, "a3"), (37, 719.0, "a4"), (38, 789.0, "a5"), (39, 790.0, "a119"), (40, 791.0, "a118"), (41, 690.0, "a117"), (42, 960.0, "a11"), (43, 939.0, "a12"), (44, 549.0, "a13"), (45, 855.0, "a14"), (46, 911.0, "a15"), (47, 933.0, "a16"), (48, 911.0, "a105"), (49, 945.0, "a17"), (50, 974.0, "a18"), (51, 755.0, "a19"), (52, 846.0, "a20"), (53, 762.0, "a21"), (54, 761.0, "a22"), (55, 571.0, "a23"), (56, 677.0, "a24"), (57, 763.0, "a25"), (58, 760.0, "a26"), (59, 759.0, "a27"), (60, 754.0, "a28"), (61, 494.0, "a6"), (62, 552.0, "a7"), (63, 537.0, "a8"), (64, 577.0, "a9"), (65, 692.0, "a10"), (66, 786.0, "a29"), (67, 788.0, "a30"), (68, 788.0, "a31"), (69, 790.0, "a32"), (70, 793.0, "a33"), (71, 794.0, "a34"), (72, 816.0, "a35"), (73, 823.0, "a36"), (74, 789.0, "a37"), (75, 841.0, "a38"), (76, 823.0, "a39"), (77, 833.0, "a40"), (78, 816.0, "a41"), (79, 831.0, "a42"), (80, 923.0, "a43"), (81, 744.0, "a44"), (82, 723.0, "a45"), (83, 749.0, "a46"), (84, 790.0, "a47"), (85, 792.0, "a48"), (86, 695.0, "a49"), (87, 776.0, "a50"), (88, 768.0, "a51"), (89, 792.0, "a52"), (90, 759.0, "a53"), (91, 707.0, "a54"), (92, 708.0, "a55"), (93, 682.0, "a56"), (94, 701.0, "a57"), (95, 826.0, "a58"), (96, 815.0, "a59"), (97, 789.0, "a60"), (98, 789.0, "a61"), (99, 707.0, "a62"), (100, 687.0, "a63"), (101, 696.0, "a64"), (102, 689.0, "a65"), (103, 786.0, "a66"), (104, 787.0, "a67"), (105, 713.0, "a68"), (106, 791.0, "a69"), (107, 785.0, "a70"), (108, 791.0, "a71"), (109, 873.0, "a72"), (110, 761.0, "a73"), (111, 762.0, "a74"), (112, 762.0, "a203"), (113, 759.0, "a75"), (114, 759.0, "a204"), (115, 892.0, "a76"), (116, 892.0, "a77"), (117, 788.0, "a78"), (118, 784.0, "a79"), (119, 438.0, "a81"), (120, 138.0, "a82"), (121, 277.0, "a83"), (122, 415.0, "a84"), (123, 392.0, "a97"), (124, 392.0, "a98"), (125, 668.0, "a99"), (126, 668.0, "a100"), (128, 390.0, "a89"), (129, 390.0, "a90"), (130, 317.0, "a93"), (131, 317.0, "a94"), (132, 276.0, "a91"), (133, 276.0, "a92"), (134, 509.0, "a205"), (135, 509.0, "a85"), (136, 410.0, "a206"), (137, 410.0, "a86"), (138, 234.0, "a87"), (139, 234.0, "a88"), (140, 334.0, "a95"), (141, 334.0, "a96"), (161, 732.0, "a101"), (162, 544.0, "a102"), (163, 544.0, "a103"), (164, 910.0, "a104"), (165, 667.0, "a106"), (166, 760.0, "a107"), (167, 760.0, "a108"), (168, 776.0, "a112"), (169, 595.0, "a111"), (170, 694.0, "a110"), (171, 626.0, "a109"), (172, 788.0, "a120"), (173, 788.0, "a121"), (174, 788.0, "a122"), (175, 788.0, "a123"), (176, 788.0, "a124"), (177, 788.0, "a125"), (178, 788.0, "a126"), (179, 788.0, "a127"), (180, 788.0, "a128"), (181, 788.0, "a129"), (182, 788.0, "a130"), (183, 788.0, "a131"), (184, 788.0, "a132"), (185, 788.0, "a133"), (186, 788.0, "a134"), (187, 788.0, "a135"), (188, 788.0, "a136"), (189, 788.0, "a137"), (190, 788.0, "a138"), (191, 788.0, "a139"), (192, 788.0, "a140"), (193, 788.0, "a141"), (194, 788.0, "a142"), (195, 788.0, "a143"), (196, 788.0, "a144"), (197, 788.0, "a145"), (198, 788.0, "a146"), (199, 788.0, "a147"), (200, 788.0, "a148"), (201, 788.0, "a149"), (202, 788.0, "a150"), (203, 788.0, "a151"), (204, 788.0, "a152"), (205, 788.0, "a153"), (206, 788.0, "a154"), (207, 788.0, "a155"), (208, 788.0, "a156"), (209, 788.0, "a157"), (210, 788.0, "a158"), (211, 788.0, "a159"), (212, 894.0, "a160"), (213, 838.0, "a161"), (214, 1016.0, "a163"), (215, 458.0, "a164"), (216, 748.0, "a196"), (217, 924.0, "a165"), (218, 748.0, "a192"), (219, 918.0, "a166"), (220, 927.0, "a167"), (221, 928.0, "a168"), (222, 928.0, "a169"), (223, 834.0, "a170"), (224, 873.0, "a171"), (225, 828.0, "a172"), (226, 924.0, "a173"), (227, 924.0, "a162"), (228, 917.0, "a174"), (229, 930.0, "a175"), (230, 931.0, "a176"), (231, 463.0, "a177"), (232, 883.0, "a178"), (233, 836.0, "a179"), (234, 836.0, "a193"), (235, 867.0, "a180"), (236, 867.0, "a199"), (237, 696.0, "a181"), (238, 696.0, "a200"), (239, 874.0, "a182"), (241, 874.0, "a201"), (242, 760.0, "a183"), (243, 946.0, "a184"), (244, 771.0, "a197"), (245, 865.0, "a185"), (246, 771.0, "a194"), (247, 888.0, "a198"), (248, 967.0, "a186"), (249, 888.0, "a195"), (250, 831.0, "a187"), (251, 873.0, "a188"), (252, 927.0, "a189"), (253, 970.0, "a190"), (254, 918.0, "a191")][..]),
 ]) }
+
+#[allow(clippy::type_complexity)]
+pub fn kerning() -> Box<[(&'static str, &'static [(&'static str, &'static str, f64)])]> {
+Box::new([
+("Courier-Bold", &[]),
+("Courier-BoldOblique", &[]),
+("Courier-Oblique", &[]),
+("Courier", &[]),
+("Helvetica-Bold", &[("A", "C", -40.0), ("A", "Cacute", -40.0), ("A", "Ccaron", -40.0), ("A", "Ccedilla", -40.0), ("A", "G", -50.0), ("A", "Gbreve", -50.0), ("A", "Gcommaaccent", -50.0), ("A", "O", -40.0), ("A", "Oacute", -40.0), ("A", "Ocircumflex", -40.0), ("A", "Odieresis", -40.0), ("A", "Ograve", -40.0), ("A", "Ohungarumlaut", -40.0), ("A", "Omacron", -40.0), ("A", "Oslash", -40.0), ("A", "Otilde", -40.0), ("A", "Q", -40.0), ("A", "T", -90.0), ("A", "Tcaron", -90.0), ("A", "Tcommaaccent", -90.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -80.0), ("A", "W", -60.0), ("A", "Y", -110.0), ("A", "Yacute", -110.0), ("A", "Ydieresis", -110.0), ("A", "u", -30.0), ("A", "uacute", -30.0), ("A", "ucircumflex", -30.0), ("A", "udieresis", -30.0), ("A", "ugrave", -30.0), ("A", "uhungarumlaut", -30.0), ("A", "umacron", -30.0), ("A", "uogonek", -30.0), ("A", "uring", -30.0), ("A", "v", -40.0), ("A", "w", -30.0), ("A", "y", -30.0), ("A", "yacute", -30.0), ("A", "ydieresis", -30.0), ("Aacute", "C", -40.0), ("Aacute", "Cacute", -40.0), ("Aacute", "Ccaron", -40.0), ("Aacute", "Ccedilla", -40.0), ("Aacute", "G", -50.0), ("Aacute", "Gbreve", -50.0), ("Aacute", "Gcommaaccent", -50.0), ("Aacute", "O", -40.0), ("Aacute", "Oacute", -40.0), ("Aacute", "Ocircumflex", -40.0), ("Aacute", "Odieresis", -40.0), ("Aacute", "Ograve", -40.0), ("Aacute", "Ohungarumlaut", -40.0), ("Aacute", "Omacron", -40.0), ("Aacute", "Oslash", -40.0), ("Aacute", "Otilde", -40.0), ("Aacute", "Q", -40.0), ("Aacute", "T", -90.0), ("Aacute", "Tcaron", -90.0), ("Aacute", "Tcommaaccent", -90.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -80.0), ("Aacute", "W", -60.0), ("Aacute", "Y", -110.0), ("Aacute", "Yacute", -110.0), ("Aacute", "Ydieresis", -110.0), ("Aacute", "u", -30.0), ("Aacute", "uacute", -30.0), ("Aacute", "ucircumflex", -30.0), ("Aacute", "udieresis", -30.0), ("Aacute", "ugrave", -30.0), ("Aacute", "uhungarumlaut", -30.0), ("Aacute", "umacron", -30.0), ("Aacute", "uogonek", -30.0), ("Aacute", "uring", -30.0), ("Aacute", "v", -40.0), ("Aacute", "w", -30.0), ("Aacute", "y", -30.0), ("Aacute", "yacute", -30.0), ("Aacute", "ydieresis", -30.0), ("Abreve", "C", -40.0), ("Abreve", "Cacute", -40.0), ("Abreve", "Ccaron", -40.0), ("Abreve", "Ccedilla", -40.0), ("Abreve", "G", -50.0), ("Abreve", "Gbreve", -50.0), ("Abreve", "Gcommaaccent", -50.0), ("Abreve", "O", -40.0), ("Abreve", "Oacute", -40.0), ("Abreve", "Ocircumflex", -40.0), ("Abreve", "Odieresis", -40.0), ("Abreve", "Ograve", -40.0), ("Abreve", "Ohungarumlaut", -40.0), ("Abreve", "Omacron", -40.0), ("Abreve", "Oslash", -40.0), ("Abreve", "Otilde", -40.0), ("Abreve", "Q", -40.0), ("Abreve", "T", -90.0), ("Abreve", "Tcaron", -90.0), ("Abreve", "Tcommaaccent", -90.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -80.0), ("Abreve", "W", -60.0), ("Abreve", "Y", -110.0), ("Abreve", "Yacute", -110.0), ("Abreve", "Ydieresis", -110.0), ("Abreve", "u", -30.0), ("Abreve", "uacute", -30.0), ("Abreve", "ucircumflex", -30.0), ("Abreve", "udieresis", -30.0), ("Abreve", "ugrave", -30.0), ("Abreve", "uhungarumlaut", -30.0), ("Abreve", "umacron", -30.0), ("Abreve", "uogonek", -30.0), ("Abreve", "uring", -30.0), ("Abreve", "v", -40.0), ("Abreve", "w", -30.0), ("Abreve", "y", -30.0), ("Abreve", "yacute", -30.0), ("Abreve", "ydieresis", -30.0), ("Acircumflex", "C", -40.0), ("Acircumflex", "Cacute", -40.0), ("Acircumflex", "Ccaron", -40.0), ("Acircumflex", "Ccedilla", -40.0), ("Acircumflex", "G", -50.0), ("Acircumflex", "Gbreve", -50.0), ("Acircumflex", "Gcommaaccent", -50.0), ("Acircumflex", "O", -40.0), ("Acircumflex", "Oacute", -40.0), ("Acircumflex", "Ocircumflex", -40.0), ("Acircumflex", "Odieresis", -40.0), ("Acircumflex", "Ograve", -40.0), ("Acircumflex", "Ohungarumlaut", -40.0), ("Acircumflex", "Omacron", -40.0), ("Acircumflex", "Oslash", -40.0), ("Acircumflex", "Otilde", -40.0), ("Acircumflex", "Q", -40.0), ("Acircumflex", "T", -90.0), ("Acircumflex", "Tcaron", -90.0), ("Acircumflex", "Tcommaaccent", -90.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -80.0), ("Acircumflex", "W", -60.0), ("Acircumflex", "Y", -110.0), ("Acircumflex", "Yacute", -110.0), ("Acircumflex", "Ydieresis", -110.0), ("Acircumflex", "u", -30.0), ("Acircumflex", "uacute", -30.0), ("Acircumflex", "ucircumflex", -30.0), ("Acircumflex", "udieresis", -30.0), ("Acircumflex", "ugrave", -30.0), ("Acircumflex", "uhungarumlaut", -30.0), ("Acircumflex", "umacron", -30.0), ("Acircumflex", "uogonek", -30.0), ("Acircumflex", "uring", -30.0), ("Acircumflex", "v", -40.0), ("Acircumflex", "w", -30.0), ("Acircumflex", "y", -30.0), ("Acircumflex", "yacute", -30.0), ("Acircumflex", "ydieresis", -30.0), ("Adieresis", "C", -40.0), ("Adieresis", "Cacute", -40.0), ("Adieresis", "Ccaron", -40.0), ("Adieresis", "Ccedilla", -40.0), ("Adieresis", "G", -50.0), ("Adieresis", "Gbreve", -50.0), ("Adieresis", "Gcommaaccent", -50.0), ("Adieresis", "O", -40.0), ("Adieresis", "Oacute", -40.0), ("Adieresis", "Ocircumflex", -40.0), ("Adieresis", "Odieresis", -40.0), ("Adieresis", "Ograve", -40.0), ("Adieresis", "Ohungarumlaut", -40.0), ("Adieresis", "Omacron", -40.0), ("Adieresis", "Oslash", -40.0), ("Adieresis", "Otilde", -40.0), ("Adieresis", "Q", -40.0), ("Adieresis", "T", -90.0), ("Adieresis", "Tcaron", -90.0), ("Adieresis", "Tcommaaccent", -90.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -80.0), ("Adieresis", "W", -60.0), ("Adieresis", "Y", -110.0), ("Adieresis", "Yacute", -110.0), ("Adieresis", "Ydieresis", -110.0), ("Adieresis", "u", -30.0), ("Adieresis", "uacute", -30.0), ("Adieresis", "ucircumflex", -30.0), ("Adieresis", "udieresis", -30.0), ("Adieresis", "ugrave", -30.0), ("Adieresis", "uhungarumlaut", -30.0), ("Adieresis", "umacron", -30.0), ("Adieresis", "uogonek", -30.0), ("Adieresis", "uring", -30.0), ("Adieresis", "v", -40.0), ("Adieresis", "w", -30.0), ("Adieresis", "y", -30.0), ("Adieresis", "yacute", -30.0), ("Adieresis", "ydieresis", -30.0), ("Agrave", "C", -40.0), ("Agrave", "Cacute", -40.0), ("Agrave", "Ccaron", -40.0), ("Agrave", "Ccedilla", -40.0), ("Agrave", "G", -50.0), ("Agrave", "Gbreve", -50.0), ("Agrave", "Gcommaaccent", -50.0), ("Agrave", "O", -40.0), ("Agrave", "Oacute", -40.0), ("Agrave", "Ocircumflex", -40.0), ("Agrave", "Odieresis", -40.0), ("Agrave", "Ograve", -40.0), ("Agrave", "Ohungarumlaut", -40.0), ("Agrave", "Omacron", -40.0), ("Agrave", "Oslash", -40.0), ("Agrave", "Otilde", -40.0), ("Agrave", "Q", -40.0), ("Agrave", "T", -90.0), ("Agrave", "Tcaron", -90.0), ("Agrave", "Tcommaaccent", -90.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -80.0), ("Agrave", "W", -60.0), ("Agrave", "Y", -110.0), ("Agrave", "Yacute", -110.0), ("Agrave", "Ydieresis", -110.0), ("Agrave", "u", -30.0), ("Agrave", "uacute", -30.0), ("Agrave", "ucircumflex", -30.0), ("Agrave", "udieresis", -30.0), ("Agrave", "ugrave", -30.0), ("Agrave", "uhungarumlaut", -30.0), ("Agrave", "umacron", -30.0), ("Agrave", "uogonek", -30.0), ("Agrave", "uring", -30.0), ("Agrave", "v", -40.0), ("Agrave", "w", -30.0), ("Agrave", "y", -30.0), ("Agrave", "yacute", -30.0), ("Agrave", "ydieresis", -30.0), ("Amacron", "C", -40.0), ("Amacron", "Cacute", -40.0), ("Amacron", "Ccaron", -40.0), ("Amacron", "Ccedilla", -40.0), ("Amacron", "G", -50.0), ("Amacron", "Gbreve", -50.0), ("Amacron", "Gcommaaccent", -50.0), ("Amacron", "O", -40.0), ("Amacron", "Oacute", -40.0), ("Amacron", "Ocircumflex", -40.0), ("Amacron", "Odieresis", -40.0), ("Amacron", "Ograve", -40.0), ("Amacron", "Ohungarumlaut", -40.0), ("Amacron", "Omacron", -40.0), ("Amacron", "Oslash", -40.0), ("Amacron", "Otilde", -40.0), ("Amacron", "Q", -40.0), ("Amacron", "T", -90.0), ("Amacron", "Tcaron", -90.0), ("Amacron", "Tcommaaccent", -90.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -80.0), ("Amacron", "W", -60.0), ("Amacron", "Y", -110.0), ("Amacron", "Yacute", -110.0), ("Amacron", "Ydieresis", -110.0), ("Amacron", "u", -30.0), ("Amacron", "uacute", -30.0), ("Amacron", "ucircumflex", -30.0), ("Amacron", "udieresis", -30.0), ("Amacron", "ugrave", -30.0), ("Amacron", "uhungarumlaut", -30.0), ("Amacron", "umacron", -30.0), ("Amacron", "uogonek", -30.0), ("Amacron", "uring", -30.0), ("Amacron", "v", -40.0), ("Amacron", "w", -30.0), ("Amacron", "y", -30.0), ("Amacron", "yacute", -30.0), ("Amacron", "ydieresis", -30.0), ("Aogonek", "C", -40.0), ("Aogonek", "Cacute", -40.0), ("Aogonek", "Ccaron", -40.0), ("Aogonek", "Ccedilla", -40.0), ("Aogonek", "G", -50.0), ("Aogonek", "Gbreve", -50.0), ("Aogonek", "Gcommaaccent", -50.0), ("Aogonek", "O", -40.0), ("Aogonek", "Oacute", -40.0), ("Aogonek", "Ocircumflex", -40.0), ("Aogonek", "Odieresis", -40.0), ("Aogonek", "Ograve", -40.0), ("Aogonek", "Ohungarumlaut", -40.0), ("Aogonek", "Omacron", -40.0), ("Aogonek", "Oslash", -40.0), ("Aogonek", "Otilde", -40.0), ("Aogonek", "Q", -40.0), ("Aogonek", "T", -90.0), ("Aogonek", "Tcaron", -90.0), ("Aogonek", "Tcommaaccent", -90.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -80.0), ("Aogonek", "W", -60.0), ("Aogonek", "Y", -110.0), ("Aogonek", "Yacute", -110.0), ("Aogonek", "Ydieresis", -110.0), ("Aogonek", "u", -30.0), ("Aogonek", "uacute", -30.0), ("Aogonek", "ucircumflex", -30.0), ("Aogonek", "udieresis", -30.0), ("Aogonek", "ugrave", -30.0), ("Aogonek", "uhungarumlaut", -30.0), ("Aogonek", "umacron", -30.0), ("Aogonek", "uogonek", -30.0), ("Aogonek", "uring", -30.0), ("Aogonek", "v", -40.0), ("Aogonek", "w", -30.0), ("Aogonek", "y", -30.0), ("Aogonek", "yacute", -30.0), ("Aogonek", "ydieresis", -30.0), ("Aring", "C", -40.0), ("Aring", "Cacute", -40.0), ("Aring", "Ccaron", -40.0), ("Aring", "Ccedilla", -40.0), ("Aring", "G", -50.0), ("Aring", "Gbreve", -50.0), ("Aring", "Gcommaaccent", -50.0), ("Aring", "O", -40.0), ("Aring", "Oacute", -40.0), ("Aring", "Ocircumflex", -40.0), ("Aring", "Odieresis", -40.0), ("Aring", "Ograve", -40.0), ("Aring", "Ohungarumlaut", -40.0), ("Aring", "Omacron", -40.0), ("Aring", "Oslash", -40.0), ("Aring", "Otilde", -40.0), ("Aring", "Q", -40.0), ("Aring", "T", -90.0), ("Aring", "Tcaron", -90.0), ("Aring", "Tcommaaccent", -90.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -80.0), ("Aring", "W", -60.0), ("Aring", "Y", -110.0), ("Aring", "Yacute", -110.0), ("Aring", "Ydieresis", -110.0), ("Aring", "u", -30.0), ("Aring", "uacute", -30.0), ("Aring", "ucircumflex", -30.0), ("Aring", "udieresis", -30.0), ("Aring", "ugrave", -30.0), ("Aring", "uhungarumlaut", -30.0), ("Aring", "umacron", -30.0), ("Aring", "uogonek", -30.0), ("Aring", "uring", -30.0), ("Aring", "v", -40.0), ("Aring", "w", -30.0), ("Aring", "y", -30.0), ("Aring", "yacute", -30.0), ("Aring", "ydieresis", -30.0), ("Atilde", "C", -40.0), ("Atilde", "Cacute", -40.0), ("Atilde", "Ccaron", -40.0), ("Atilde", "Ccedilla", -40.0), ("Atilde", "G", -50.0), ("Atilde", "Gbreve", -50.0), ("Atilde", "Gcommaaccent", -50.0), ("Atilde", "O", -40.0), ("Atilde", "Oacute", -40.0), ("Atilde", "Ocircumflex", -40.0), ("Atilde", "Odieresis", -40.0), ("Atilde", "Ograve", -40.0), ("Atilde", "Ohungarumlaut", -40.0), ("Atilde", "Omacron", -40.0), ("Atilde", "Oslash", -40.0), ("Atilde", "Otilde", -40.0), ("Atilde", "Q", -40.0), ("Atilde", "T", -90.0), ("Atilde", "Tcaron", -90.0), ("Atilde", "Tcommaaccent", -90.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -80.0), ("Atilde", "W", -60.0), ("Atilde", "Y", -110.0), ("Atilde", "Yacute", -110.0), ("Atilde", "Ydieresis", -110.0), ("Atilde", "u", -30.0), ("Atilde", "uacute", -30.0), ("Atilde", "ucircumflex", -30.0), ("Atilde", "udieresis", -30.0), ("Atilde", "ugrave", -30.0), ("Atilde", "uhungarumlaut", -30.0), ("Atilde", "umacron", -30.0), ("Atilde", "uogonek", -30.0), ("Atilde", "uring", -30.0), ("Atilde", "v", -40.0), ("Atilde", "w", -30.0), ("Atilde", "y", -30.0), ("Atilde", "yacute", -30.0), ("Atilde", "ydieresis", -30.0), ("B", "A", -30.0), ("B", "Aacute", -30.0), ("B", "Abreve", -30.0), ("B", "Acircumflex", -30.0), ("B", "Adieresis", -30.0), ("B", "Agrave", -30.0), ("B", "Amacron", -30.0), ("B", "Aogonek", -30.0), ("B", "Aring", -30.0), ("B", "Atilde", -30.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -40.0), ("D", "Aacute", -40.0), ("D", "Abreve", -40.0), ("D", "Acircumflex", -40.0), ("D", "Adieresis", -40.0), ("D", "Agrave", -40.0), ("D", "Amacron", -40.0), ("D", "Aogonek", -40.0), ("D", "Aring", -40.0), ("D", "Atilde", -40.0), ("D", "V", -40.0), ("D", "W", -40.0), ("D", "Y", -70.0), ("D", "Yacute", -70.0), ("D", "Ydieresis", -70.0), ("D", "comma", -30.0), ("D", "period", -30.0), ("Dcaron", "A", -40.0), ("Dcaron", "Aacute", -40.0), ("Dcaron", "Abreve", -40.0), ("Dcaron", "Acircumflex", -40.0), ("Dcaron", "Adieresis", -40.0), ("Dcaron", "Agrave", -40.0), ("Dcaron", "Amacron", -40.0), ("Dcaron", "Aogonek", -40.0), ("Dcaron", "Aring", -40.0), ("Dcaron", "Atilde", -40.0), ("Dcaron", "V", -40.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -70.0), ("Dcaron", "Yacute", -70.0), ("Dcaron", "Ydieresis", -70.0), ("Dcaron", "comma", -30.0), ("Dcaron", "period", -30.0), ("Dcroat", "A", -40.0), ("Dcroat", "Aacute", -40.0), ("Dcroat", "Abreve", -40.0), ("Dcroat", "Acircumflex", -40.0), ("Dcroat", "Adieresis", -40.0), ("Dcroat", "Agrave", -40.0), ("Dcroat", "Amacron", -40.0), ("Dcroat", "Aogonek", -40.0), ("Dcroat", "Aring", -40.0), ("Dcroat", "Atilde", -40.0), ("Dcroat", "V", -40.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -70.0), ("Dcroat", "Yacute", -70.0), ("Dcroat", "Ydieresis", -70.0), ("Dcroat", "comma", -30.0), ("Dcroat", "period", -30.0), ("F", "A", -80.0), ("F", "Aacute", -80.0), ("F", "Abreve", -80.0), ("F", "Acircumflex", -80.0), ("F", "Adieresis", -80.0), ("F", "Agrave", -80.0), ("F", "Amacron", -80.0), ("F", "Aogonek", -80.0), ("F", "Aring", -80.0), ("F", "Atilde", -80.0), ("F", "a", -20.0), ("F", "aacute", -20.0), ("F", "abreve", -20.0), ("F", "acircumflex", -20.0), ("F", "adieresis", -20.0), ("F", "agrave", -20.0), ("F", "amacron", -20.0), ("F", "aogonek", -20.0), ("F", "aring", -20.0), ("F", "atilde", -20.0), ("F", "comma", -100.0), ("F", "period", -100.0), ("J", "A", -20.0), ("J", "Aacute", -20.0), ("J", "Abreve", -20.0), ("J", "Acircumflex", -20.0), ("J", "Adieresis", -20.0), ("J", "Agrave", -20.0), ("J", "Amacron", -20.0), ("J", "Aogonek", -20.0), ("J", "Aring", -20.0), ("J", "Atilde", -20.0), ("J", "comma", -20.0), ("J", "period", -20.0), ("J", "u", -20.0), ("J", "uacute", -20.0), ("J", "ucircumflex", -20.0), ("J", "udieresis", -20.0), ("J", "ugrave", -20.0), ("J", "uhungarumlaut", -20.0), ("J", "umacron", -20.0), ("J", "uogonek", -20.0), ("J", "uring", -20.0), ("K", "O", -30.0), ("K", "Oacute", -30.0), ("K", "Ocircumflex", -30.0), ("K", "Odieresis", -30.0), ("K", "Ograve", -30.0), ("K", "Ohungarumlaut", -30.0), ("K", "Omacron", -30.0), ("K", "Oslash", -30.0), ("K", "Otilde", -30.0), ("K", "e", -15.0), ("K", "eacute", -15.0), ("K", "ecaron", -15.0), ("K", "ecircumflex", -15.0), ("K", "edieresis", -15.0), ("K", "edotaccent", -15.0), ("K", "egrave", -15.0), ("K", "emacron", -15.0), ("K", "eogonek", -15.0), ("K", "o", -35.0), ("K", "oacute", -35.0), ("K", "ocircumflex", -35.0), ("K", "odieresis", -35.0), ("K", "ograve", -35.0), ("K", "ohungarumlaut", -35.0), ("K", "omacron", -35.0), ("K", "oslash", -35.0), ("K", "otilde", -35.0), ("K", "u", -30.0), ("K", "uacute", -30.0), ("K", "ucircumflex", -30.0), ("K", "udieresis", -30.0), ("K", "ugrave", -30.0), ("K", "uhungarumlaut", -30.0), ("K", "umacron", -30.0), ("K", "uogonek", -30.0), ("K", "uring", -30.0), ("K", "y", -40.0), ("K", "yacute", -40.0), ("K", "ydieresis", -40.0), ("Kcommaaccent", "O", -30.0), ("Kcommaaccent", "Oacute", -30.0), ("Kcommaaccent", "Ocircumflex", -30.0), ("Kcommaaccent", "Odieresis", -30.0), ("Kcommaaccent", "Ograve", -30.0), ("Kcommaaccent", "Ohungarumlaut", -30.0), ("Kcommaaccent", "Omacron", -30.0), ("Kcommaaccent", "Oslash", -30.0), ("Kcommaaccent", "Otilde", -30.0), ("Kcommaaccent", "e", -15.0), ("Kcommaaccent", "eacute", -15.0), ("Kcommaaccent", "ecaron", -15.0), ("Kcommaaccent", "ecircumflex", -15.0), ("Kcommaaccent", "edieresis", -15.0), ("Kcommaaccent", "edotaccent", -15.0), ("Kcommaaccent", "egrave", -15.0), ("Kcommaaccent", "emacron", -15.0), ("Kcommaaccent", "eogonek", -15.0), ("Kcommaaccent", "o", -35.0), ("Kcommaaccent", "oacute", -35.0), ("Kcommaaccent", "ocircumflex", -35.0), ("Kcommaaccent", "odieresis", -35.0), ("Kcommaaccent", "ograve", -35.0), ("Kcommaaccent", "ohungarumlaut", -35.0), ("Kcommaaccent", "omacron", -35.0), ("Kcommaaccent", "oslash", -35.0), ("Kcommaaccent", "otilde", -35.0), ("Kcommaaccent", "u", -30.0), ("Kcommaaccent", "uacute", -30.0), ("Kcommaaccent", "ucircumflex", -30.0), ("Kcommaaccent", "udieresis", -30.0), ("Kcommaaccent", "ugrave", -30.0), ("Kcommaaccent", "uhungarumlaut", -30.0), ("Kcommaaccent", "umacron", -30.0), ("Kcommaaccent", "uogonek", -30.0), ("Kcommaaccent", "uring", -30.0), ("Kcommaaccent", "y", -40.0), ("Kcommaaccent", "yacute", -40.0), ("Kcommaaccent", "ydieresis", -40.0), ("L", "T", -90.0), ("L", "Tcaron", -90.0), ("L", "Tcommaaccent", -90.0), ("L", "V", -110.0), ("L", "W", -80.0), ("L", "Y", -120.0), ("L", "Yacute", -120.0), ("L", "Ydieresis", -120.0), ("L", "quotedblright", -140.0), ("L", "quoteright", -140.0), ("L", "y", -30.0), ("L", "yacute", -30.0), ("L", "ydieresis", -30.0), ("Lacute", "T", -90.0), ("Lacute", "Tcaron", -90.0), ("Lacute", "Tcommaaccent", -90.0), ("Lacute", "V", -110.0), ("Lacute", "W", -80.0), ("Lacute", "Y", -120.0), ("Lacute", "Yacute", -120.0), ("Lacute", "Ydieresis", -120.0), ("Lacute", "quotedblright", -140.0), ("Lacute", "quoteright", -140.0), ("Lacute", "y", -30.0), ("Lacute", "yacute", -30.0), ("Lacute", "ydieresis", -30.0), ("Lcommaaccent", "T", -90.0), ("Lcommaaccent", "Tcaron", -90.0), ("Lcommaaccent", "Tcommaaccent", -90.0), ("Lcommaaccent", "V", -110.0), ("Lcommaaccent", "W", -80.0), ("Lcommaaccent", "Y", -120.0), ("Lcommaaccent", "Yacute", -120.0), ("Lcommaaccent", "Ydieresis", -120.0), ("Lcommaaccent", "quotedblright", -140.0), ("Lcommaaccent", "quoteright", -140.0), ("Lcommaaccent", "y", -30.0), ("Lcommaaccent", "yacute", -30.0), ("Lcommaaccent", "ydieresis", -30.0), ("Lslash", "T", -90.0), ("Lslash", "Tcaron", -90.0), ("Lslash", "Tcommaaccent", -90.0), ("Lslash", "V", -110.0), ("Lslash", "W", -80.0), ("Lslash", "Y", -120.0), ("Lslash", "Yacute", -120.0), ("Lslash", "Ydieresis", -120.0), ("Lslash", "quotedblright", -140.0), ("Lslash", "quoteright", -140.0), ("Lslash", "y", -30.0), ("Lslash", "yacute", -30.0), ("Lslash", "ydieresis", -30.0), ("O", "A", -50.0), ("O", "Aacute", -50.0), ("O", "Abreve", -50.0), ("O", "Acircumflex", -50.0), ("O", "Adieresis", -50.0), ("O", "Agrave", -50.0), ("O", "Amacron", -50.0), ("O", "Aogonek", -50.0), ("O", "Aring", -50.0), ("O", "Atilde", -50.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -50.0), ("O", "X", -50.0), ("O", "Y", -70.0), ("O", "Yacute", -70.0), ("O", "Ydieresis", -70.0), ("O", "comma", -40.0), ("O", "period", -40.0), ("Oacute", "A", -50.0), ("Oacute", "Aacute", -50.0), ("Oacute", "Abreve", -50.0), ("Oacute", "Acircumflex", -50.0), ("Oacute", "Adieresis", -50.0), ("Oacute", "Agrave", -50.0), ("Oacute", "Amacron", -50.0), ("Oacute", "Aogonek", -50.0), ("Oacute", "Aring", -50.0), ("Oacute", "Atilde", -50.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -50.0), ("Oacute", "X", -50.0), ("Oacute", "Y", -70.0), ("Oacute", "Yacute", -70.0), ("Oacute", "Ydieresis", -70.0), ("Oacute", "comma", -40.0), ("Oacute", "period", -40.0), ("Ocircumflex", "A", -50.0), ("Ocircumflex", "Aacute", -50.0), ("Ocircumflex", "Abreve", -50.0), ("Ocircumflex", "Acircumflex", -50.0), ("Ocircumflex", "Adieresis", -50.0), ("Ocircumflex", "Agrave", -50.0), ("Ocircumflex", "Amacron", -50.0), ("Ocircumflex", "Aogonek", -50.0), ("Ocircumflex", "Aring", -50.0), ("Ocircumflex", "Atilde", -50.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -50.0), ("Ocircumflex", "X", -50.0), ("Ocircumflex", "Y", -70.0), ("Ocircumflex", "Yacute", -70.0), ("Ocircumflex", "Ydieresis", -70.0), ("Ocircumflex", "comma", -40.0), ("Ocircumflex", "period", -40.0), ("Odieresis", "A", -50.0), ("Odieresis", "Aacute", -50.0), ("Odieresis", "Abreve", -50.0), ("Odieresis", "Acircumflex", -50.0), ("Odieresis", "Adieresis", -50.0), ("Odieresis", "Agrave", -50.0), ("Odieresis", "Amacron", -50.0), ("Odieresis", "Aogonek", -50.0), ("Odieresis", "Aring", -50.0), ("Odieresis", "Atilde", -50.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -50.0), ("Odieresis", "X", -50.0), ("Odieresis", "Y", -70.0), ("Odieresis", "Yacute", -70.0), ("Odieresis", "Ydieresis", -70.0), ("Odieresis", "comma", -40.0), ("Odieresis", "period", -40.0), ("Ograve", "A", -50.0), ("Ograve", "Aacute", -50.0), ("Ograve", "Abreve", -50.0), ("Ograve", "Acircumflex", -50.0), ("Ograve", "Adieresis", -50.0), ("Ograve", "Agrave", -50.0), ("Ograve", "Amacron", -50.0), ("Ograve", "Aogonek", -50.0), ("Ograve", "Aring", -50.0), ("Ograve", "Atilde", -50.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -50.0), ("Ograve", "X", -50.0), ("Ograve", "Y", -70.0), ("Ograve", "Yacute", -70.0), ("Ograve", "Ydieresis", -70.0), ("Ograve", "comma", -40.0), ("Ograve", "period", -40.0), ("Ohungarumlaut", "A", -50.0), ("Ohungarumlaut", "Aacute", -50.0), ("Ohungarumlaut", "Abreve", -50.0), ("Ohungarumlaut", "Acircumflex", -50.0), ("Ohungarumlaut", "Adieresis", -50.0), ("Ohungarumlaut", "Agrave", -50.0), ("Ohungarumlaut", "Amacron", -50.0), ("Ohungarumlaut", "Aogonek", -50.0), ("Ohungarumlaut", "Aring", -50.0), ("Ohungarumlaut", "Atilde", -50.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -50.0), ("Ohungarumlaut", "X", -50.0), ("Ohungarumlaut", "Y", -70.0), ("Ohungarumlaut", "Yacute", -70.0), ("Ohungarumlaut", "Ydieresis", -70.0), ("Ohungarumlaut", "comma", -40.0), ("Ohungarumlaut", "period", -40.0), ("Omacron", "A", -50.0), ("Omacron", "Aacute", -50.0), ("Omacron", "Abreve", -50.0), ("Omacron", "Acircumflex", -50.0), ("Omacron", "Adieresis", -50.0), ("Omacron", "Agrave", -50.0), ("Omacron", "Amacron", -50.0), ("Omacron", "Aogonek", -50.0), ("Omacron", "Aring", -50.0), ("Omacron", "Atilde", -50.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -50.0), ("Omacron", "X", -50.0), ("Omacron", "Y", -70.0), ("Omacron", "Yacute", -70.0), ("Omacron", "Ydieresis", -70.0), ("Omacron", "comma", -40.0), ("Omacron", "period", -40.0), ("Oslash", "A", -50.0), ("Oslash", "Aacute", -50.0), ("Oslash", "Abreve", -50.0), ("Oslash", "Acircumflex", -50.0), ("Oslash", "Adieresis", -50.0), ("Oslash", "Agrave", -50.0), ("Oslash", "Amacron", -50.0), ("Oslash", "Aogonek", -50.0), ("Oslash", "Aring", -50.0), ("Oslash", "Atilde", -50.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -50.0), ("Oslash", "X", -50.0), ("Oslash", "Y", -70.0), ("Oslash", "Yacute", -70.0), ("Oslash", "Ydieresis", -70.0), ("Oslash", "comma", -40.0), ("Oslash", "period", -40.0), ("Otilde", "A", -50.0), ("Otilde", "Aacute", -50.0), ("Otilde", "Abreve", -50.0), ("Otilde", "Acircumflex", -50.0), ("Otilde", "Adieresis", -50.0), ("Otilde", "Agrave", -50.0), ("Otilde", "Amacron", -50.0), ("Otilde", "Aogonek", -50.0), ("Otilde", "Aring", -50.0), ("Otilde", "Atilde", -50.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -50.0), ("Otilde", "X", -50.0), ("Otilde", "Y", -70.0), ("Otilde", "Yacute", -70.0), ("Otilde", "Ydieresis", -70.0), ("Otilde", "comma", -40.0), ("Otilde", "period", -40.0), ("P", "A", -100.0), ("P", "Aacute", -100.0), ("P", "Abreve", -100.0), ("P", "Acircumflex", -100.0), ("P", "Adieresis", -100.0), ("P", "Agrave", -100.0), ("P", "Amacron", -100.0), ("P", "Aogonek", -100.0), ("P", "Aring", -100.0), ("P", "Atilde", -100.0), ("P", "a", -30.0), ("P", "aacute", -30.0), ("P", "abreve", -30.0), ("P", "acircumflex", -30.0), ("P", "adieresis", -30.0), ("P", "agrave", -30.0), ("P", "amacron", -30.0), ("P", "aogonek", -30.0), ("P", "aring", -30.0), ("P", "atilde", -30.0), ("P", "comma", -120.0), ("P", "e", -30.0), ("P", "eacute", -30.0), ("P", "ecaron", -30.0), ("P", "ecircumflex", -30.0), ("P", "edieresis", -30.0), ("P", "edotaccent", -30.0), ("P", "egrave", -30.0), ("P", "emacron", -30.0), ("P", "eogonek", -30.0), ("P", "o", -40.0), ("P", "oacute", -40.0), ("P", "ocircumflex", -40.0), ("P", "odieresis", -40.0), ("P", "ograve", -40.0), ("P", "ohungarumlaut", -40.0), ("P", "omacron", -40.0), ("P", "oslash", -40.0), ("P", "otilde", -40.0), ("P", "period", -120.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("Q", "comma", 20.0), ("Q", "period", 20.0), ("R", "O", -20.0), ("R", "Oacute", -20.0), ("R", "Ocircumflex", -20.0), ("R", "Odieresis", -20.0), ("R", "Ograve", -20.0), ("R", "Ohungarumlaut", -20.0), ("R", "Omacron", -20.0), ("R", "Oslash", -20.0), ("R", "Otilde", -20.0), ("R", "T", -20.0), ("R", "Tcaron", -20.0), ("R", "Tcommaaccent", -20.0), ("R", "U", -20.0), ("R", "Uacute", -20.0), ("R", "Ucircumflex", -20.0), ("R", "Udieresis", -20.0), ("R", "Ugrave", -20.0), ("R", "Uhungarumlaut", -20.0), ("R", "Umacron", -20.0), ("R", "Uogonek", -20.0), ("R", "Uring", -20.0), ("R", "V", -50.0), ("R", "W", -40.0), ("R", "Y", -50.0), ("R", "Yacute", -50.0), ("R", "Ydieresis", -50.0), ("Racute", "O", -20.0), ("Racute", "Oacute", -20.0), ("Racute", "Ocircumflex", -20.0), ("Racute", "Odieresis", -20.0), ("Racute", "Ograve", -20.0), ("Racute", "Ohungarumlaut", -20.0), ("Racute", "Omacron", -20.0), ("Racute", "Oslash", -20.0), ("Racute", "Otilde", -20.0), ("Racute", "T", -20.0), ("Racute", "Tcaron", -20.0), ("Racute", "Tcommaaccent", -20.0), ("Racute", "U", -20.0), ("Racute", "Uacute", -20.0), ("Racute", "Ucircumflex", -20.0), ("Racute", "Udieresis", -20.0), ("Racute", "Ugrave", -20.0), ("Racute", "Uhungarumlaut", -20.0), ("Racute", "Umacron", -20.0), ("Racute", "Uogonek", -20.0), ("Racute", "Uring", -20.0), ("Racute", "V", -50.0), ("Racute", "W", -40.0), ("Racute", "Y", -50.0), ("Racute", "Yacute", -50.0), ("Racute", "Ydieresis", -50.0), ("Rcaron", "O", -20.0), ("Rcaron", "Oacute", -20.0), ("Rcaron", "Ocircumflex", -20.0), ("Rcaron", "Odieresis", -20.0), ("Rcaron", "Ograve", -20.0), ("Rcaron", "Ohungarumlaut", -20.0), ("Rcaron", "Omacron", -20.0), ("Rcaron", "Oslash", -20.0), ("Rcaron", "Otilde", -20.0), ("Rcaron", "T", -20.0), ("Rcaron", "Tcaron", -20.0), ("Rcaron", "Tcommaaccent", -20.0), ("Rcaron", "U", -20.0), ("Rcaron", "Uacute", -20.0), ("Rcaron", "Ucircumflex", -20.0), ("Rcaron", "Udieresis", -20.0), ("Rcaron", "Ugrave", -20.0), ("Rcaron", "Uhungarumlaut", -20.0), ("Rcaron", "Umacron", -20.0), ("Rcaron", "Uogonek", -20.0), ("Rcaron", "Uring", -20.0), ("Rcaron", "V", -50.0), ("Rcaron", "W", -40.0), ("Rcaron", "Y", -50.0), ("Rcaron", "Yacute", -50.0), ("Rcaron", "Ydieresis", -50.0), ("Rcommaaccent", "O", -20.0), ("Rcommaaccent", "Oacute", -20.0), ("Rcommaaccent", "Ocircumflex", -20.0), ("Rcommaaccent", "Odieresis", -20.0), ("Rcommaaccent", "Ograve", -20.0), ("Rcommaaccent", "Ohungarumlaut", -20.0), ("Rcommaaccent", "Omacron", -20.0), ("Rcommaaccent", "Oslash", -20.0), ("Rcommaaccent", "Otilde", -20.0), ("Rcommaaccent", "T", -20.0), ("Rcommaaccent", "Tcaron", -20.0), ("Rcommaaccent", "Tcommaaccent", -20.0), ("Rcommaaccent", "U", -20.0), ("Rcommaaccent", "Uacute", -20.0), ("Rcommaaccent", "Ucircumflex", -20.0), ("Rcommaaccent", "Udieresis", -20.0), ("Rcommaaccent", "Ugrave", -20.0), ("Rcommaaccent", "Uhungarumlaut", -20.0), ("Rcommaaccent", "Umacron", -20.0), ("Rcommaaccent", "Uogonek", -20.0), ("Rcommaaccent", "Uring", -20.0), ("Rcommaaccent", "V", -50.0), ("Rcommaaccent", "W", -40.0), ("Rcommaaccent", "Y", -50.0), ("Rcommaaccent", "Yacute", -50.0), ("Rcommaaccent", "Ydieresis", -50.0), ("T", "A", -90.0), ("T", "Aacute", -90.0), ("T", "Abreve", -90.0), ("T", "Acircumflex", -90.0), ("T", "Adieresis", -90.0), ("T", "Agrave", -90.0), ("T", "Amacron", -90.0), ("T", "Aogonek", -90.0), ("T", "Aring", -90.0), ("T", "Atilde", -90.0), ("T", "O", -40.0), ("T", "Oacute", -40.0), ("T", "Ocircumflex", -40.0), ("T", "Odieresis", -40.0), ("T", "Ograve", -40.0), ("T", "Ohungarumlaut", -40.0), ("T", "Omacron", -40.0), ("T", "Oslash", -40.0), ("T", "Otilde", -40.0), ("T", "a", -80.0), ("T", "aacute", -80.0), ("T", "abreve", -80.0), ("T", "acircumflex", -80.0), ("T", "adieresis", -80.0), ("T", "agrave", -80.0), ("T", "amacron", -80.0), ("T", "aogonek", -80.0), ("T", "aring", -80.0), ("T", "atilde", -80.0), ("T", "colon", -40.0), ("T", "comma", -80.0), ("T", "e", -60.0), ("T", "eacute", -60.0), ("T", "ecaron", -60.0), ("T", "ecircumflex", -60.0), ("T", "edieresis", -60.0), ("T", "edotaccent", -60.0), ("T", "egrave", -60.0), ("T", "emacron", -60.0), ("T", "eogonek", -60.0), ("T", "hyphen", -120.0), ("T", "o", -80.0), ("T", "oacute", -80.0), ("T", "ocircumflex", -80.0), ("T", "odieresis", -80.0), ("T", "ograve", -80.0), ("T", "ohungarumlaut", -80.0), ("T", "omacron", -80.0), ("T", "oslash", -80.0), ("T", "otilde", -80.0), ("T", "period", -80.0), ("T", "r", -80.0), ("T", "racute", -80.0), ("T", "rcommaaccent", -80.0), ("T", "semicolon", -40.0), ("T", "u", -90.0), ("T", "uacute", -90.0), ("T", "ucircumflex", -90.0), ("T", "udieresis", -90.0), ("T", "ugrave", -90.0), ("T", "uhungarumlaut", -90.0), ("T", "umacron", -90.0), ("T", "uogonek", -90.0), ("T", "uring", -90.0), ("T", "w", -60.0), ("T", "y", -60.0), ("T", "yacute", -60.0), ("T", "ydieresis", -60.0), ("Tcaron", "A", -90.0), ("Tcaron", "Aacute", -90.0), ("Tcaron", "Abreve", -90.0), ("Tcaron", "Acircumflex", -90.0), ("Tcaron", "Adieresis", -90.0), ("Tcaron", "Agrave", -90.0), ("Tcaron", "Amacron", -90.0), ("Tcaron", "Aogonek", -90.0), ("Tcaron", "Aring", -90.0), ("Tcaron", "Atilde", -90.0), ("Tcaron", "O", -40.0), ("Tcaron", "Oacute", -40.0), ("Tcaron", "Ocircumflex", -40.0), ("Tcaron", "Odieresis", -40.0), ("Tcaron", "Ograve", -40.0), ("Tcaron", "Ohungarumlaut", -40.0), ("Tcaron", "Omacron", -40.0), ("Tcaron", "Oslash", -40.0), ("Tcaron", "Otilde", -40.0), ("Tcaron", "a", -80.0), ("Tcaron", "aacute", -80.0), ("Tcaron", "abreve", -80.0), ("Tcaron", "acircumflex", -80.0), ("Tcaron", "adieresis", -80.0), ("Tcaron", "agrave", -80.0), ("Tcaron", "amacron", -80.0), ("Tcaron", "aogonek", -80.0), ("Tcaron", "aring", -80.0), ("Tcaron", "atilde", -80.0), ("Tcaron", "colon", -40.0), ("Tcaron", "comma", -80.0), ("Tcaron", "e", -60.0), ("Tcaron", "eacute", -60.0), ("Tcaron", "ecaron", -60.0), ("Tcaron", "ecircumflex", -60.0), ("Tcaron", "edieresis", -60.0), ("Tcaron", "edotaccent", -60.0), ("Tcaron", "egrave", -60.0), ("Tcaron", "emacron", -60.0), ("Tcaron", "eogonek", -60.0), ("Tcaron", "hyphen", -120.0), ("Tcaron", "o", -80.0), ("Tcaron", "oacute", -80.0), ("Tcaron", "ocircumflex", -80.0), ("Tcaron", "odieresis", -80.0), ("Tcaron", "ograve", -80.0), ("Tcaron", "ohungarumlaut", -80.0), ("Tcaron", "omacron", -80.0), ("Tcaron", "oslash", -80.0), ("Tcaron", "otilde", -80.0), ("Tcaron", "period", -80.0), ("Tcaron", "r", -80.0), ("Tcaron", "racute", -80.0), ("Tcaron", "rcommaaccent", -80.0), ("Tcaron", "semicolon", -40.0), ("Tcaron", "u", -90.0), ("Tcaron", "uacute", -90.0), ("Tcaron", "ucircumflex", -90.0), ("Tcaron", "udieresis", -90.0), ("Tcaron", "ugrave", -90.0), ("Tcaron", "uhungarumlaut", -90.0), ("Tcaron", "umacron", -90.0), ("Tcaron", "uogonek", -90.0), ("Tcaron", "uring", -90.0), ("Tcaron", "w", -60.0), ("Tcaron", "y", -60.0), ("Tcaron", "yacute", -60.0), ("Tcaron", "ydieresis", -60.0), ("Tcommaaccent", "A", -90.0), ("Tcommaaccent", "Aacute", -90.0), ("Tcommaaccent", "Abreve", -90.0), ("Tcommaaccent", "Acircumflex", -90.0), ("Tcommaaccent", "Adieresis", -90.0), ("Tcommaaccent", "Agrave", -90.0), ("Tcommaaccent", "Amacron", -90.0), ("Tcommaaccent", "Aogonek", -90.0), ("Tcommaaccent", "Aring", -90.0), ("Tcommaaccent", "Atilde", -90.0), ("Tcommaaccent", "O", -40.0), ("Tcommaaccent", "Oacute", -40.0), ("Tcommaaccent", "Ocircumflex", -40.0), ("Tcommaaccent", "Odieresis", -40.0), ("Tcommaaccent", "Ograve", -40.0), ("Tcommaaccent", "Ohungarumlaut", -40.0), ("Tcommaaccent", "Omacron", -40.0), ("Tcommaaccent", "Oslash", -40.0), ("Tcommaaccent", "Otilde", -40.0), ("Tcommaaccent", "a", -80.0), ("Tcommaaccent", "aacute", -80.0), ("Tcommaaccent", "abreve", -80.0), ("Tcommaaccent", "acircumflex", -80.0), ("Tcommaaccent", "adieresis", -80.0), ("Tcommaaccent", "agrave", -80.0), ("Tcommaaccent", "amacron", -80.0), ("Tcommaaccent", "aogonek", -80.0), ("Tcommaaccent", "aring", -80.0), ("Tcommaaccent", "atilde", -80.0), ("Tcommaaccent", "colon", -40.0), ("Tcommaaccent", "comma", -80.0), ("Tcommaaccent", "e", -60.0), ("Tcommaaccent", "eacute", -60.0), ("Tcommaaccent", "ecaron", -60.0), ("Tcommaaccent", "ecircumflex", -60.0), ("Tcommaaccent", "edieresis", -60.0), ("Tcommaaccent", "edotaccent", -60.0), ("Tcommaaccent", "egrave", -60.0), ("Tcommaaccent", "emacron", -60.0), ("Tcommaaccent", "eogonek", -60.0), ("Tcommaaccent", "hyphen", -120.0), ("Tcommaaccent", "o", -80.0), ("Tcommaaccent", "oacute", -80.0), ("Tcommaaccent", "ocircumflex", -80.0), ("Tcommaaccent", "odieresis", -80.0), ("Tcommaaccent", "ograve", -80.0), ("Tcommaaccent", "ohungarumlaut", -80.0), ("Tcommaaccent", "omacron", -80.0), ("Tcommaaccent", "oslash", -80.0), ("Tcommaaccent", "otilde", -80.0), ("Tcommaaccent", "period", -80.0), ("Tcommaaccent", "r", -80.0), ("Tcommaaccent", "racute", -80.0), ("Tcommaaccent", "rcommaaccent", -80.0), ("Tcommaaccent", "semicolon", -40.0), ("Tcommaaccent", "u", -90.0), ("Tcommaaccent", "uacute", -90.0), ("Tcommaaccent", "ucircumflex", -90.0), ("Tcommaaccent", "udieresis", -90.0), ("Tcommaaccent", "ugrave", -90.0), ("Tcommaaccent", "uhungarumlaut", -90.0), ("Tcommaaccent", "umacron", -90.0), ("Tcommaaccent", "uogonek", -90.0), ("Tcommaaccent", "uring", -90.0), ("Tcommaaccent", "w", -60.0), ("Tcommaaccent", "y", -60.0), ("Tcommaaccent", "yacute", -60.0), ("Tcommaaccent", "ydieresis", -60.0), ("U", "A", -50.0), ("U", "Aacute", -50.0), ("U", "Abreve", -50.0), ("U", "Acircumflex", -50.0), ("U", "Adieresis", -50.0), ("U", "Agrave", -50.0), ("U", "Amacron", -50.0), ("U", "Aogonek", -50.0), ("U", "Aring", -50.0), ("U", "Atilde", -50.0), ("U", "comma", -30.0), ("U", "period", -30.0), ("Uacute", "A", -50.0), ("Uacute", "Aacute", -50.0), ("Uacute", "Abreve", -50.0), ("Uacute", "Acircumflex", -50.0), ("Uacute", "Adieresis", -50.0), ("Uacute", "Agrave", -50.0), ("Uacute", "Amacron", -50.0), ("Uacute", "Aogonek", -50.0), ("Uacute", "Aring", -50.0), ("Uacute", "Atilde", -50.0), ("Uacute", "comma", -30.0), ("Uacute", "period", -30.0), ("Ucircumflex", "A", -50.0), ("Ucircumflex", "Aacute", -50.0), ("Ucircumflex", "Abreve", -50.0), ("Ucircumflex", "Acircumflex", -50.0), ("Ucircumflex", "Adieresis", -50.0), ("Ucircumflex", "Agrave", -50.0), ("Ucircumflex", "Amacron", -50.0), ("Ucircumflex", "Aogonek", -50.0), ("Ucircumflex", "Aring", -50.0), ("Ucircumflex", "Atilde", -50.0), ("Ucircumflex", "comma", -30.0), ("Ucircumflex", "period", -30.0), ("Udieresis", "A", -50.0), ("Udieresis", "Aacute", -50.0), ("Udieresis", "Abreve", -50.0), ("Udieresis", "Acircumflex", -50.0), ("Udieresis", "Adieresis", -50.0), ("Udieresis", "Agrave", -50.0), ("Udieresis", "Amacron", -50.0), ("Udieresis", "Aogonek", -50.0), ("Udieresis", "Aring", -50.0), ("Udieresis", "Atilde", -50.0), ("Udieresis", "comma", -30.0), ("Udieresis", "period", -30.0), ("Ugrave", "A", -50.0), ("Ugrave", "Aacute", -50.0), ("Ugrave", "Abreve", -50.0), ("Ugrave", "Acircumflex", -50.0), ("Ugrave", "Adieresis", -50.0), ("Ugrave", "Agrave", -50.0), ("Ugrave", "Amacron", -50.0), ("Ugrave", "Aogonek", -50.0), ("Ugrave", "Aring", -50.0), ("Ugrave", "Atilde", -50.0), ("Ugrave", "comma", -30.0), ("Ugrave", "period", -30.0), ("Uhungarumlaut", "A", -50.0), ("Uhungarumlaut", "Aacute", -50.0), ("Uhungarumlaut", "Abreve", -50.0), ("Uhungarumlaut", "Acircumflex", -50.0), ("Uhungarumlaut", "Adieresis", -50.0), ("Uhungarumlaut", "Agrave", -50.0), ("Uhungarumlaut", "Amacron", -50.0), ("Uhungarumlaut", "Aogonek", -50.0), ("Uhungarumlaut", "Aring", -50.0), ("Uhungarumlaut", "Atilde", -50.0), ("Uhungarumlaut", "comma", -30.0), ("Uhungarumlaut", "period", -30.0), ("Umacron", "A", -50.0), ("Umacron", "Aacute", -50.0), ("Umacron", "Abreve", -50.0), ("Umacron", "Acircumflex", -50.0), ("Umacron", "Adieresis", -50.0), ("Umacron", "Agrave", -50.0), ("Umacron", "Amacron", -50.0), ("Umacron", "Aogonek", -50.0), ("Umacron", "Aring", -50.0), ("Umacron", "Atilde", -50.0), ("Umacron", "comma", -30.0), ("Umacron", "period", -30.0), ("Uogonek", "A", -50.0), ("Uogonek", "Aacute", -50.0), ("Uogonek", "Abreve", -50.0), ("Uogonek", "Acircumflex", -50.0), ("Uogonek", "Adieresis", -50.0), ("Uogonek", "Agrave", -50.0), ("Uogonek", "Amacron", -50.0), ("Uogonek", "Aogonek", -50.0), ("Uogonek", "Aring", -50.0), ("Uogonek", "Atilde", -50.0), ("Uogonek", "comma", -30.0), ("Uogonek", "period", -30.0), ("Uring", "A", -50.0), ("Uring", "Aacute", -50.0), ("Uring", "Abreve", -50.0), ("Uring", "Acircumflex", -50.0), ("Uring", "Adieresis", -50.0), ("Uring", "Agrave", -50.0), ("Uring", "Amacron", -50.0), ("Uring", "Aogonek", -50.0), ("Uring", "Aring", -50.0), ("Uring", "Atilde", -50.0), ("Uring", "comma", -30.0), ("Uring", "period", -30.0), ("V", "A", -80.0), ("V", "Aacute", -80.0), ("V", "Abreve", -80.0), ("V", "Acircumflex", -80.0), ("V", "Adieresis", -80.0), ("V", "Agrave", -80.0), ("V", "Amacron", -80.0), ("V", "Aogonek", -80.0), ("V", "Aring", -80.0), ("V", "Atilde", -80.0), ("V", "G", -50.0), ("V", "Gbreve", -50.0), ("V", "Gcommaaccent", -50.0), ("V", "O", -50.0), ("V", "Oacute", -50.0), ("V", "Ocircumflex", -50.0), ("V", "Odieresis", -50.0), ("V", "Ograve", -50.0), ("V", "Ohungarumlaut", -50.0), ("V", "Omacron", -50.0), ("V", "Oslash", -50.0), ("V", "Otilde", -50.0), ("V", "a", -60.0), ("V", "aacute", -60.0), ("V", "abreve", -60.0), ("V", "acircumflex", -60.0), ("V", "adieresis", -60.0), ("V", "agrave", -60.0), ("V", "amacron", -60.0), ("V", "aogonek", -60.0), ("V", "aring", -60.0), ("V", "atilde", -60.0), ("V", "colon", -40.0), ("V", "comma", -120.0), ("V", "e", -50.0), ("V", "eacute", -50.0), ("V", "ecaron", -50.0), ("V", "ecircumflex", -50.0), ("V", "edieresis", -50.0), ("V", "edotaccent", -50.0), ("V", "egrave", -50.0), ("V", "emacron", -50.0), ("V", "eogonek", -50.0), ("V", "hyphen", -80.0), ("V", "o", -90.0), ("V", "oacute", -90.0), ("V", "ocircumflex", -90.0), ("V", "odieresis", -90.0), ("V", "ograve", -90.0), ("V", "ohungarumlaut", -90.0), ("V", "omacron", -90.0), ("V", "oslash", -90.0), ("V", "otilde", -90.0), ("V", "period", -120.0), ("V", "semicolon", -40.0), ("V", "u", -60.0), ("V", "uacute", -60.0), ("V", "ucircumflex", -60.0), ("V", "udieresis", -60.0), ("V", "ugrave", -60.0), ("V", "uhungarumlaut", -60.0), ("V", "umacron", -60.0), ("V", "uogonek", -60.0), ("V", "uring", -60.0), ("W", "A", -60.0), ("W", "Aacute", -60.0), ("W", "Abreve", -60.0), ("W", "Acircumflex", -60.0), ("W", "Adieresis", -60.0), ("W", "Agrave", -60.0), ("W", "Amacron", -60.0), ("W", "Aogonek", -60.0), ("W", "Aring", -60.0), ("W", "Atilde", -60.0), ("W", "O", -20.0), ("W", "Oacute", -20.0), ("W", "Ocircumflex", -20.0), ("W", "Odieresis", -20.0), ("W", "Ograve", -20.0), ("W", "Ohungarumlaut", -20.0), ("W", "Omacron", -20.0), ("W", "Oslash", -20.0), ("W", "Otilde", -20.0), ("W", "a", -40.0), ("W", "aacute", -40.0), ("W", "abreve", -40.0), ("W", "acircumflex", -40.0), ("W", "adieresis", -40.0), ("W", "agrave", -40.0), ("W", "amacron", -40.0), ("W", "aogonek", -40.0), ("W", "aring", -40.0), ("W", "atilde", -40.0), ("W", "colon", -10.0), ("W", "comma", -80.0), ("W", "e", -35.0), ("W", "eacute", -35.0), ("W", "ecaron", -35.0), ("W", "ecircumflex", -35.0), ("W", "edieresis", -35.0), ("W", "edotaccent", -35.0), ("W", "egrave", -35.0), ("W", "emacron", -35.0), ("W", "eogonek", -35.0), ("W", "hyphen", -40.0), ("W", "o", -60.0), ("W", "oacute", -60.0), ("W", "ocircumflex", -60.0), ("W", "odieresis", -60.0), ("W", "ograve", -60.0), ("W", "ohungarumlaut", -60.0), ("W", "omacron", -60.0), ("W", "oslash", -60.0), ("W", "otilde", -60.0), ("W", "period", -80.0), ("W", "semicolon", -10.0), ("W", "u", -45.0), ("W", "uacute", -45.0), ("W", "ucircumflex", -45.0), ("W", "udieresis", -45.0), ("W", "ugrave", -45.0), ("W", "uhungarumlaut", -45.0), ("W", "umacron", -45.0), ("W", "uogonek", -45.0), ("W", "uring", -45.0), ("W", "y", -20.0), ("W", "yacute", -20.0), ("W", "ydieresis", -20.0), ("Y", "A", -110.0), ("Y", "Aacute", -110.0), ("Y", "Abreve", -110.0), ("Y", "Acircumflex", -110.0), ("Y", "Adieresis", -110.0), ("Y", "Agrave", -110.0), ("Y", "Amacron", -110.0), ("Y", "Aogonek", -110.0), ("Y", "Aring", -110.0), ("Y", "Atilde", -110.0), ("Y", "O", -70.0), ("Y", "Oacute", -70.0), ("Y", "Ocircumflex", -70.0), ("Y", "Odieresis", -70.0), ("Y", "Ograve", -70.0), ("Y", "Ohungarumlaut", -70.0), ("Y", "Omacron", -70.0), ("Y", "Oslash", -70.0), ("Y", "Otilde", -70.0), ("Y", "a", -90.0), ("Y", "aacute", -90.0), ("Y", "abreve", -90.0), ("Y", "acircumflex", -90.0), ("Y", "adieresis", -90.0), ("Y", "agrave", -90.0), ("Y", "amacron", -90.0), ("Y", "aogonek", -90.0), ("Y", "aring", -90.0), ("Y", "atilde", -90.0), ("Y", "colon", -50.0), ("Y", "comma", -100.0), ("Y", "e", -80.0), ("Y", "eacute", -80.0), ("Y", "ecaron", -80.0), ("Y", "ecircumflex", -80.0), ("Y", "edieresis", -80.0), ("Y", "edotaccent", -80.0), ("Y", "egrave", -80.0), ("Y", "emacron", -80.0), ("Y", "eogonek", -80.0), ("Y", "o", -100.0), ("Y", "oacute", -100.0), ("Y", "ocircumflex", -100.0), ("Y", "odieresis", -100.0), ("Y", "ograve", -100.0), ("Y", "ohungarumlaut", -100.0), ("Y", "omacron", -100.0), ("Y", "oslash", -100.0), ("Y", "otilde", -100.0), ("Y", "period", -100.0), ("Y", "semicolon", -50.0), ("Y", "u", -100.0), ("Y", "uacute", -100.0), ("Y", "ucircumflex", -100.0), ("Y", "udieresis", -100.0), ("Y", "ugrave", -100.0), ("Y", "uhungarumlaut", -100.0), ("Y", "umacron", -100.0), ("Y", "uogonek", -100.0), ("Y", "uring", -100.0), ("Yacute", "A", -110.0), ("Yacute", "Aacute", -110.0), ("Yacute", "Abreve", -110.0), ("Yacute", "Acircumflex", -110.0), ("Yacute", "Adieresis", -110.0), ("Yacute", "Agrave", -110.0), ("Yacute", "Amacron", -110.0), ("Yacute", "Aogonek", -110.0), ("Yacute", "Aring", -110.0), ("Yacute", "Atilde", -110.0), ("Yacute", "O", -70.0), ("Yacute", "Oacute", -70.0), ("Yacute", "Ocircumflex", -70.0), ("Yacute", "Odieresis", -70.0), ("Yacute", "Ograve", -70.0), ("Yacute", "Ohungarumlaut", -70.0), ("Yacute", "Omacron", -70.0), ("Yacute", "Oslash", -70.0), ("Yacute", "Otilde", -70.0), ("Yacute", "a", -90.0), ("Yacute", "aacute", -90.0), ("Yacute", "abreve", -90.0), ("Yacute", "acircumflex", -90.0), ("Yacute", "adieresis", -90.0), ("Yacute", "agrave", -90.0), ("Yacute", "amacron", -90.0), ("Yacute", "aogonek", -90.0), ("Yacute", "aring", -90.0), ("Yacute", "atilde", -90.0), ("Yacute", "colon", -50.0), ("Yacute", "comma", -100.0), ("Yacute", "e", -80.0), ("Yacute", "eacute", -80.0), ("Yacute", "ecaron", -80.0), ("Yacute", "ecircumflex", -80.0), ("Yacute", "edieresis", -80.0), ("Yacute", "edotaccent", -80.0), ("Yacute", "egrave", -80.0), ("Yacute", "emacron", -80.0), ("Yacute", "eogonek", -80.0), ("Yacute", "o", -100.0), ("Yacute", "oacute", -100.0), ("Yacute", "ocircumflex", -100.0), ("Yacute", "odieresis", -100.0), ("Yacute", "ograve", -100.0), ("Yacute", "ohungarumlaut", -100.0), ("Yacute", "omacron", -100.0), ("Yacute", "oslash", -100.0), ("Yacute", "otilde", -100.0), ("Yacute", "period", -100.0), ("Yacute", "semicolon", -50.0), ("Yacute", "u", -100.0), ("Yacute", "uacute", -100.0), ("Yacute", "ucircumflex", -100.0), ("Yacute", "udieresis", -100.0), ("Yacute", "ugrave", -100.0), ("Yacute", "uhungarumlaut", -100.0), ("Yacute", "umacron", -100.0), ("Yacute", "uogonek", -100.0), ("Yacute", "uring", -100.0), ("Ydieresis", "A", -110.0), ("Ydieresis", "Aacute", -110.0), ("Ydieresis", "Abreve", -110.0), ("Ydieresis", "Acircumflex", -110.0), ("Ydieresis", "Adieresis", -110.0), ("Ydieresis", "Agrave", -110.0), ("Ydieresis", "Amacron", -110.0), ("Ydieresis", "Aogonek", -110.0), ("Ydieresis", "Aring", -110.0), ("Ydieresis", "Atilde", -110.0), ("Ydieresis", "O", -70.0), ("Ydieresis", "Oacute", -70.0), ("Ydieresis", "Ocircumflex", -70.0), ("Ydieresis", "Odieresis", -70.0), ("Ydieresis", "Ograve", -70.0), ("Ydieresis", "Ohungarumlaut", -70.0), ("Ydieresis", "Omacron", -70.0), ("Ydieresis", "Oslash", -70.0), ("Ydieresis", "Otilde", -70.0), ("Ydieresis", "a", -90.0), ("Ydieresis", "aacute", -90.0), ("Ydieresis", "abreve", -90.0), ("Ydieresis", "acircumflex", -90.0), ("Ydieresis", "adieresis", -90.0), ("Ydieresis", "agrave", -90.0), ("Ydieresis", "amacron", -90.0), ("Ydieresis", "aogonek", -90.0), ("Ydieresis", "aring", -90.0), ("Ydieresis", "atilde", -90.0), ("Ydieresis", "colon", -50.0), ("Ydieresis", "comma", -100.0), ("Ydieresis", "e", -80.0), ("Ydieresis", "eacute", -80.0), ("Ydieresis", "ecaron", -80.0), ("Ydieresis", "ecircumflex", -80.0), ("Ydieresis", "edieresis", -80.0), ("Ydieresis", "edotaccent", -80.0), ("Ydieresis", "egrave", -80.0), ("Ydieresis", "emacron", -80.0), ("Ydieresis", "eogonek", -80.0), ("Ydieresis", "o", -100.0), ("Ydieresis", "oacute", -100.0), ("Ydieresis", "ocircumflex", -100.0), ("Ydieresis", "odieresis", -100.0), ("Ydieresis", "ograve", -100.0), ("Ydieresis", "ohungarumlaut", -100.0), ("Ydieresis", "omacron", -100.0), ("Ydieresis", "oslash", -100.0), ("Ydieresis", "otilde", -100.0), ("Ydieresis", "period", -100.0), ("Ydieresis", "semicolon", -50.0), ("Ydieresis", "u", -100.0), ("Ydieresis", "uacute", -100.0), ("Ydieresis", "ucircumflex", -100.0), ("Ydieresis", "udieresis", -100.0), ("Ydieresis", "ugrave", -100.0), ("Ydieresis", "uhungarumlaut", -100.0), ("Ydieresis", "umacron", -100.0), ("Ydieresis", "uogonek", -100.0), ("Ydieresis", "uring", -100.0), ("a", "g", -10.0), ("a", "gbreve", -10.0), ("a", "gcommaaccent", -10.0), ("a", "v", -15.0), ("a", "w", -15.0), ("a", "y", -20.0), ("a", "yacute", -20.0), ("a", "ydieresis", -20.0), ("aacute", "g", -10.0), ("aacute", "gbreve", -10.0), ("aacute", "gcommaaccent", -10.0), ("aacute", "v", -15.0), ("aacute", "w", -15.0), ("aacute", "y", -20.0), ("aacute", "yacute", -20.0), ("aacute", "ydieresis", -20.0), ("abreve", "g", -10.0), ("abreve", "gbreve", -10.0), ("abreve", "gcommaaccent", -10.0), ("abreve", "v", -15.0), ("abreve", "w", -15.0), ("abreve", "y", -20.0), ("abreve", "yacute", -20.0), ("abreve", "ydieresis", -20.0), ("acircumflex", "g", -10.0), ("acircumflex", "gbreve", -10.0), ("acircumflex", "gcommaaccent", -10.0), ("acircumflex", "v", -15.0), ("acircumflex", "w", -15.0), ("acircumflex", "y", -20.0), ("acircumflex", "yacute", -20.0), ("acircumflex", "ydieresis", -20.0), ("adieresis", "g", -10.0), ("adieresis", "gbreve", -10.0), ("adieresis", "gcommaaccent", -10.0), ("adieresis", "v", -15.0), ("adieresis", "w", -15.0), ("adieresis", "y", -20.0), ("adieresis", "yacute", -20.0), ("adieresis", "ydieresis", -20.0), ("agrave", "g", -10.0), ("agrave", "gbreve", -10.0), ("agrave", "gcommaaccent", -10.0), ("agrave", "v", -15.0), ("agrave", "w", -15.0), ("agrave", "y", -20.0), ("agrave", "yacute", -20.0), ("agrave", "ydieresis", -20.0), ("amacron", "g", -10.0), ("amacron", "gbreve", -10.0), ("amacron", "gcommaaccent", -10.0), ("amacron", "v", -15.0), ("amacron", "w", -15.0), ("amacron", "y", -20.0), ("amacron", "yacute", -20.0), ("amacron", "ydieresis", -20.0), ("aogonek", "g", -10.0), ("aogonek", "gbreve", -10.0), ("aogonek", "gcommaaccent", -10.0), ("aogonek", "v", -15.0), ("aogonek", "w", -15.0), ("aogonek", "y", -20.0), ("aogonek", "yacute", -20.0), ("aogonek", "ydieresis", -20.0), ("aring", "g", -10.0), ("aring", "gbreve", -10.0), ("aring", "gcommaaccent", -10.0), ("aring", "v", -15.0), ("aring", "w", -15.0), ("aring", "y", -20.0), ("aring", "yacute", -20.0), ("aring", "ydieresis", -20.0), ("atilde", "g", -10.0), ("atilde", "gbreve", -10.0), ("atilde", "gcommaaccent", -10.0), ("atilde", "v", -15.0), ("atilde", "w", -15.0), ("atilde", "y", -20.0), ("atilde", "yacute", -20.0), ("atilde", "ydieresis", -20.0), ("b", "l", -10.0), ("b", "lacute", -10.0), ("b", "lcommaaccent", -10.0), ("b", "lslash", -10.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -20.0), ("b", "y", -20.0), ("b", "yacute", -20.0), ("b", "ydieresis", -20.0), ("c", "h", -10.0), ("c", "k", -20.0), ("c", "kcommaaccent", -20.0), ("c", "l", -20.0), ("c", "lacute", -20.0), ("c", "lcommaaccent", -20.0), ("c", "lslash", -20.0), ("c", "y", -10.0), ("c", "yacute", -10.0), ("c", "ydieresis", -10.0), ("cacute", "h", -10.0), ("cacute", "k", -20.0), ("cacute", "kcommaaccent", -20.0), ("cacute", "l", -20.0), ("cacute", "lacute", -20.0), ("cacute", "lcommaaccent", -20.0), ("cacute", "lslash", -20.0), ("cacute", "y", -10.0), ("cacute", "yacute", -10.0), ("cacute", "ydieresis", -10.0), ("ccaron", "h", -10.0), ("ccaron", "k", -20.0), ("ccaron", "kcommaaccent", -20.0), ("ccaron", "l", -20.0), ("ccaron", "lacute", -20.0), ("ccaron", "lcommaaccent", -20.0), ("ccaron", "lslash", -20.0), ("ccaron", "y", -10.0), ("ccaron", "yacute", -10.0), ("ccaron", "ydieresis", -10.0), ("ccedilla", "h", -10.0), ("ccedilla", "k", -20.0), ("ccedilla", "kcommaaccent", -20.0), ("ccedilla", "l", -20.0), ("ccedilla", "lacute", -20.0), ("ccedilla", "lcommaaccent", -20.0), ("ccedilla", "lslash", -20.0), ("ccedilla", "y", -10.0), ("ccedilla", "yacute", -10.0), ("ccedilla", "ydieresis", -10.0), ("colon", "space", -40.0), ("comma", "quotedblright", -120.0), ("comma", "quoteright", -120.0), ("comma", "space", -40.0), ("d", "d", -10.0), ("d", "dcroat", -10.0), ("d", "v", -15.0), ("d", "w", -15.0), ("d", "y", -15.0), ("d", "yacute", -15.0), ("d", "ydieresis", -15.0), ("dcroat", "d", -10.0), ("dcroat", "dcroat", -10.0), ("dcroat", "v", -15.0), ("dcroat", "w", -15.0), ("dcroat", "y", -15.0), ("dcroat", "yacute", -15.0), ("dcroat", "ydieresis", -15.0), ("e", "comma", 10.0), ("e", "period", 20.0), ("e", "v", -15.0), ("e", "w", -15.0), ("e", "x", -15.0), ("e", "y", -15.0), ("e", "yacute", -15.0), ("e", "ydieresis", -15.0), ("eacute", "comma", 10.0), ("eacute", "period", 20.0), ("eacute", "v", -15.0), ("eacute", "w", -15.0), ("eacute", "x", -15.0), ("eacute", "y", -15.0), ("eacute", "yacute", -15.0), ("eacute", "ydieresis", -15.0), ("ecaron", "comma", 10.0), ("ecaron", "period", 20.0), ("ecaron", "v", -15.0), ("ecaron", "w", -15.0), ("ecaron", "x", -15.0), ("ecaron", "y", -15.0), ("ecaron", "yacute", -15.0), ("ecaron", "ydieresis", -15.0), ("ecircumflex", "comma", 10.0), ("ecircumflex", "period", 20.0), ("ecircumflex", "v", -15.0), ("ecircumflex", "w", -15.0), ("ecircumflex", "x", -15.0), ("ecircumflex", "y", -15.0), ("ecircumflex", "yacute", -15.0), ("ecircumflex", "ydieresis", -15.0), ("edieresis", "comma", 10.0), ("edieresis", "period", 20.0), ("edieresis", "v", -15.0), ("edieresis", "w", -15.0), ("edieresis", "x", -15.0), ("edieresis", "y", -15.0), ("edieresis", "yacute", -15.0), ("edieresis", "ydieresis", -15.0), ("edotaccent", "comma", 10.0), ("edotaccent", "period", 20.0), ("edotaccent", "v", -15.0), ("edotaccent", "w", -15.0), ("edotaccent", "x", -15.0), ("edotaccent", "y", -15.0), ("edotaccent", "yacute", -15.0), ("edotaccent", "ydieresis", -15.0), ("egrave", "comma", 10.0), ("egrave", "period", 20.0), ("egrave", "v", -15.0), ("egrave", "w", -15.0), ("egrave", "x", -15.0), ("egrave", "y", -15.0), ("egrave", "yacute", -15.0), ("egrave", "ydieresis", -15.0), ("emacron", "comma", 10.0), ("emacron", "period", 20.0), ("emacron", "v", -15.0), ("emacron", "w", -15.0), ("emacron", "x", -15.0), ("emacron", "y", -15.0), ("emacron", "yacute", -15.0), ("emacron", "ydieresis", -15.0), ("eogonek", "comma", 10.0), ("eogonek", "period", 20.0), ("eogonek", "v", -15.0), ("eogonek", "w", -15.0), ("eogonek", "x", -15.0), ("eogonek", "y", -15.0), ("eogonek", "yacute", -15.0), ("eogonek", "ydieresis", -15.0), ("f", "comma", -10.0), ("f", "e", -10.0), ("f", "eacute", -10.0), ("f", "ecaron", -10.0), ("f", "ecircumflex", -10.0), ("f", "edieresis", -10.0), ("f", "edotaccent", -10.0), ("f", "egrave", -10.0), ("f", "emacron", -10.0), ("f", "eogonek", -10.0), ("f", "o", -20.0), ("f", "oacute", -20.0), ("f", "ocircumflex", -20.0), ("f", "odieresis", -20.0), ("f", "ograve", -20.0), ("f", "ohungarumlaut", -20.0), ("f", "omacron", -20.0), ("f", "oslash", -20.0), ("f", "otilde", -20.0), ("f", "period", -10.0), ("f", "quotedblright", 30.0), ("f", "quoteright", 30.0), ("g", "e", 10.0), ("g", "eacute", 10.0), ("g", "ecaron", 10.0), ("g", "ecircumflex", 10.0), ("g", "edieresis", 10.0), ("g", "edotaccent", 10.0), ("g", "egrave", 10.0), ("g", "emacron", 10.0), ("g", "eogonek", 10.0), ("g", "g", -10.0), ("g", "gbreve", -10.0), ("g", "gcommaaccent", -10.0), ("gbreve", "e", 10.0), ("gbreve", "eacute", 10.0), ("gbreve", "ecaron", 10.0), ("gbreve", "ecircumflex", 10.0), ("gbreve", "edieresis", 10.0), ("gbreve", "edotaccent", 10.0), ("gbreve", "egrave", 10.0), ("gbreve", "emacron", 10.0), ("gbreve", "eogonek", 10.0), ("gbreve", "g", -10.0), ("gbreve", "gbreve", -10.0), ("gbreve", "gcommaaccent", -10.0), ("gcommaaccent", "e", 10.0), ("gcommaaccent", "eacute", 10.0), ("gcommaaccent", "ecaron", 10.0), ("gcommaaccent", "ecircumflex", 10.0), ("gcommaaccent", "edieresis", 10.0), ("gcommaaccent", "edotaccent", 10.0), ("gcommaaccent", "egrave", 10.0), ("gcommaaccent", "emacron", 10.0), ("gcommaaccent", "eogonek", 10.0), ("gcommaaccent", "g", -10.0), ("gcommaaccent", "gbreve", -10.0), ("gcommaaccent", "gcommaaccent", -10.0), ("h", "y", -20.0), ("h", "yacute", -20.0), ("h", "ydieresis", -20.0), ("k", "o", -15.0), ("k", "oacute", -15.0), ("k", "ocircumflex", -15.0), ("k", "odieresis", -15.0), ("k", "ograve", -15.0), ("k", "ohungarumlaut", -15.0), ("k", "omacron", -15.0), ("k", "oslash", -15.0), ("k", "otilde", -15.0), ("kcommaaccent", "o", -15.0), ("kcommaaccent", "oacute", -15.0), ("kcommaaccent", "ocircumflex", -15.0), ("kcommaaccent", "odieresis", -15.0), ("kcommaaccent", "ograve", -15.0), ("kcommaaccent", "ohungarumlaut", -15.0), ("kcommaaccent", "omacron", -15.0), ("kcommaaccent", "oslash", -15.0), ("kcommaaccent", "otilde", -15.0), ("l", "w", -15.0), ("l", "y", -15.0), ("l", "yacute", -15.0), ("l", "ydieresis", -15.0), ("lacute", "w", -15.0), ("lacute", "y", -15.0), ("lacute", "yacute", -15.0), ("lacute", "ydieresis", -15.0), ("lcommaaccent", "w", -15.0), ("lcommaaccent", "y", -15.0), ("lcommaaccent", "yacute", -15.0), ("lcommaaccent", "ydieresis", -15.0), ("lslash", "w", -15.0), ("lslash", "y", -15.0), ("lslash", "yacute", -15.0), ("lslash", "ydieresis", -15.0), ("m", "u", -20.0), ("m", "uacute", -20.0), ("m", "ucircumflex", -20.0), ("m", "udieresis", -20.0), ("m", "ugrave", -20.0), ("m", "uhungarumlaut", -20.0), ("m", "umacron", -20.0), ("m", "uogonek", -20.0), ("m", "uring", -20.0), ("m", "y", -30.0), ("m", "yacute", -30.0), ("m", "ydieresis", -30.0), ("n", "u", -10.0), ("n", "uacute", -10.0), ("n", "ucircumflex", -10.0), ("n", "udieresis", -10.0), ("n", "ugrave", -10.0), ("n", "uhungarumlaut", -10.0), ("n", "umacron", -10.0), ("n", "uogonek", -10.0), ("n", "uring", -10.0), ("n", "v", -40.0), ("n", "y", -20.0), ("n", "yacute", -20.0), ("n", "ydieresis", -20.0), ("nacute", "u", -10.0), ("nacute", "uacute", -10.0), ("nacute", "ucircumflex", -10.0), ("nacute", "udieresis", -10.0), ("nacute", "ugrave", -10.0), ("nacute", "uhungarumlaut", -10.0), ("nacute", "umacron", -10.0), ("nacute", "uogonek", -10.0), ("nacute", "uring", -10.0), ("nacute", "v", -40.0), ("nacute", "y", -20.0), ("nacute", "yacute", -20.0), ("nacute", "ydieresis", -20.0), ("ncaron", "u", -10.0), ("ncaron", "uacute", -10.0), ("ncaron", "ucircumflex", -10.0), ("ncaron", "udieresis", -10.0), ("ncaron", "ugrave", -10.0), ("ncaron", "uhungarumlaut", -10.0), ("ncaron", "umacron", -10.0), ("ncaron", "uogonek", -10.0), ("ncaron", "uring", -10.0), ("ncaron", "v", -40.0), ("ncaron", "y", -20.0), ("ncaron", "yacute", -20.0), ("ncaron", "ydieresis", -20.0), ("ncommaaccent", "u", -10.0), ("ncommaaccent", "uacute", -10.0), ("ncommaaccent", "ucircumflex", -10.0), ("ncommaaccent", "udieresis", -10.0), ("ncommaaccent", "ugrave", -10.0), ("ncommaaccent", "uhungarumlaut", -10.0), ("ncommaaccent", "umacron", -10.0), ("ncommaaccent", "uogonek", -10.0), ("ncommaaccent", "uring", -10.0), ("ncommaaccent", "v", -40.0), ("ncommaaccent", "y", -20.0), ("ncommaaccent", "yacute", -20.0), ("ncommaaccent", "ydieresis", -20.0), ("ntilde", "u", -10.0), ("ntilde", "uacute", -10.0), ("ntilde", "ucircumflex", -10.0), ("ntilde", "udieresis", -10.0), ("ntilde", "ugrave", -10.0), ("ntilde", "uhungarumlaut", -10.0), ("ntilde", "umacron", -10.0), ("ntilde", "uogonek", -10.0), ("ntilde", "uring", -10.0), ("ntilde", "v", -40.0), ("ntilde", "y", -20.0), ("ntilde", "yacute", -20.0), ("ntilde", "ydieresis", -20.0), ("o", "v", -20.0), ("o", "w", -15.0), ("o", "x", -30.0), ("o", "y", -20.0), ("o", "yacute", -20.0), ("o", "ydieresis", -20.0), ("oacute", "v", -20.0), ("oacute", "w", -15.0), ("oacute", "x", -30.0), ("oacute", "y", -20.0), ("oacute", "yacute", -20.0), ("oacute", "ydieresis", -20.0), ("ocircumflex", "v", -20.0), ("ocircumflex", "w", -15.0), ("ocircumflex", "x", -30.0), ("ocircumflex", "y", -20.0), ("ocircumflex", "yacute", -20.0), ("ocircumflex", "ydieresis", -20.0), ("odieresis", "v", -20.0), ("odieresis", "w", -15.0), ("odieresis", "x", -30.0), ("odieresis", "y", -20.0), ("odieresis", "yacute", -20.0), ("odieresis", "ydieresis", -20.0), ("ograve", "v", -20.0), ("ograve", "w", -15.0), ("ograve", "x", -30.0), ("ograve", "y", -20.0), ("ograve", "yacute", -20.0), ("ograve", "ydieresis", -20.0), ("ohungarumlaut", "v", -20.0), ("ohungarumlaut", "w", -15.0), ("ohungarumlaut", "x", -30.0), ("ohungarumlaut", "y", -20.0), ("ohungarumlaut", "yacute", -20.0), ("ohungarumlaut", "ydieresis", -20.0), ("omacron", "v", -20.0), ("omacron", "w", -15.0), ("omacron", "x", -30.0), ("omacron", "y", -20.0), ("omacron", "yacute", -20.0), ("omacron", "ydieresis", -20.0), ("oslash", "v", -20.0), ("oslash", "w", -15.0), ("oslash", "x", -30.0), ("oslash", "y", -20.0), ("oslash", "yacute", -20.0), ("oslash", "ydieresis", -20.0), ("otilde", "v", -20.0), ("otilde", "w", -15.0), ("otilde", "x", -30.0), ("otilde", "y", -20.0), ("otilde", "yacute", -20.0), ("otilde", "ydieresis", -20.0), ("p", "y", -15.0), ("p", "yacute", -15.0), ("p", "ydieresis", -15.0), ("period", "quotedblright", -120.0), ("period", "quoteright", -120.0), ("period", "space", -40.0), ("quotedblright", "space", -80.0), ("quoteleft", "quoteleft", -46.0), ("quoteright", "d", -80.0), ("quoteright", "dcroat", -80.0), ("quoteright", "l", -20.0), ("quoteright", "lacute", -20.0), ("quoteright", "lcommaaccent", -20.0), ("quoteright", "lslash", -20.0), ("quoteright", "quoteright", -46.0), ("quoteright", "r", -40.0), ("quoteright", "racute", -40.0), ("quoteright", "rcaron", -40.0), ("quoteright", "rcommaaccent", -40.0), ("quoteright", "s", -60.0), ("quoteright", "sacute", -60.0), ("quoteright", "scaron", -60.0), ("quoteright", "scedilla", -60.0), ("quoteright", "scommaaccent", -60.0), ("quoteright", "space", -80.0), ("quoteright", "v", -20.0), ("r", "c", -20.0), ("r", "cacute", -20.0), ("r", "ccaron", -20.0), ("r", "ccedilla", -20.0), ("r", "comma", -60.0), ("r", "d", -20.0), ("r", "dcroat", -20.0), ("r", "g", -15.0), ("r", "gbreve", -15.0), ("r", "gcommaaccent", -15.0), ("r", "hyphen", -20.0), ("r", "o", -20.0), ("r", "oacute", -20.0), ("r", "ocircumflex", -20.0), ("r", "odieresis", -20.0), ("r", "ograve", -20.0), ("r", "ohungarumlaut", -20.0), ("r", "omacron", -20.0), ("r", "oslash", -20.0), ("r", "otilde", -20.0), ("r", "period", -60.0), ("r", "q", -20.0), ("r", "s", -15.0), ("r", "sacute", -15.0), ("r", "scaron", -15.0), ("r", "scedilla", -15.0), ("r", "scommaaccent", -15.0), ("r", "t", 20.0), ("r", "tcommaaccent", 20.0), ("r", "v", 10.0), ("r", "y", 10.0), ("r", "yacute", 10.0), ("r", "ydieresis", 10.0), ("racute", "c", -20.0), ("racute", "cacute", -20.0), ("racute", "ccaron", -20.0), ("racute", "ccedilla", -20.0), ("racute", "comma", -60.0), ("racute", "d", -20.0), ("racute", "dcroat", -20.0), ("racute", "g", -15.0), ("racute", "gbreve", -15.0), ("racute", "gcommaaccent", -15.0), ("racute", "hyphen", -20.0), ("racute", "o", -20.0), ("racute", "oacute", -20.0), ("racute", "ocircumflex", -20.0), ("racute", "odieresis", -20.0), ("racute", "ograve", -20.0), ("racute", "ohungarumlaut", -20.0), ("racute", "omacron", -20.0), ("racute", "oslash", -20.0), ("racute", "otilde", -20.0), ("racute", "period", -60.0), ("racute", "q", -20.0), ("racute", "s", -15.0), ("racute", "sacute", -15.0), ("racute", "scaron", -15.0), ("racute", "scedilla", -15.0), ("racute", "scommaaccent", -15.0), ("racute", "t", 20.0), ("racute", "tcommaaccent", 20.0), ("racute", "v", 10.0), ("racute", "y", 10.0), ("racute", "yacute", 10.0), ("racute", "ydieresis", 10.0), ("rcaron", "c", -20.0), ("rcaron", "cacute", -20.0), ("rcaron", "ccaron", -20.0), ("rcaron", "ccedilla", -20.0), ("rcaron", "comma", -60.0), ("rcaron", "d", -20.0), ("rcaron", "dcroat", -20.0), ("rcaron", "g", -15.0), ("rcaron", "gbreve", -15.0), ("rcaron", "gcommaaccent", -15.0), ("rcaron", "hyphen", -20.0), ("rcaron", "o", -20.0), ("rcaron", "oacute", -20.0), ("rcaron", "ocircumflex", -20.0), ("rcaron", "odieresis", -20.0), ("rcaron", "ograve", -20.0), ("rcaron", "ohungarumlaut", -20.0), ("rcaron", "omacron", -20.0), ("rcaron", "oslash", -20.0), ("rcaron", "otilde", -20.0), ("rcaron", "period", -60.0), ("rcaron", "q", -20.0), ("rcaron", "s", -15.0), ("rcaron", "sacute", -15.0), ("rcaron", "scaron", -15.0), ("rcaron", "scedilla", -15.0), ("rcaron", "scommaaccent", -15.0), ("rcaron", "t", 20.0), ("rcaron", "tcommaaccent", 20.0), ("rcaron", "v", 10.0), ("rcaron", "y", 10.0), ("rcaron", "yacute", 10.0), ("rcaron", "ydieresis", 10.0), ("rcommaaccent", "c", -20.0), ("rcommaaccent", "cacute", -20.0), ("rcommaaccent", "ccaron", -20.0), ("rcommaaccent", "ccedilla", -20.0), ("rcommaaccent", "comma", -60.0), ("rcommaaccent", "d", -20.0), ("rcommaaccent", "dcroat", -20.0), ("rcommaaccent", "g", -15.0), ("rcommaaccent", "gbreve", -15.0), ("rcommaaccent", "gcommaaccent", -15.0), ("rcommaaccent", "hyphen", -20.0), ("rcommaaccent", "o", -20.0), ("rcommaaccent", "oacute", -20.0), ("rcommaaccent", "ocircumflex", -20.0), ("rcommaaccent", "odieresis", -20.0), ("rcommaaccent", "ograve", -20.0), ("rcommaaccent", "ohungarumlaut", -20.0), ("rcommaaccent", "omacron", -20.0), ("rcommaaccent", "oslash", -20.0), ("rcommaaccent", "otilde", -20.0), ("rcommaaccent", "period", -60.0), ("rcommaaccent", "q", -20.0), ("rcommaaccent", "s", -15.0), ("rcommaaccent", "sacute", -15.0), ("rcommaaccent", "scaron", -15.0), ("rcommaaccent", "scedilla", -15.0), ("rcommaaccent", "scommaaccent", -15.0), ("rcommaaccent", "t", 20.0), ("rcommaaccent", "tcommaaccent", 20.0), ("rcommaaccent", "v", 10.0), ("rcommaaccent", "y", 10.0), ("rcommaaccent", "yacute", 10.0), ("rcommaaccent", "ydieresis", 10.0), ("s", "w", -15.0), ("sacute", "w", -15.0), ("scaron", "w", -15.0), ("scedilla", "w", -15.0), ("scommaaccent", "w", -15.0), ("semicolon", "space", -40.0), ("space", "T", -100.0), ("space", "Tcaron", -100.0), ("space", "Tcommaaccent", -100.0), ("space", "V", -80.0), ("space", "W", -80.0), ("space", "Y", -120.0), ("space", "Yacute", -120.0), ("space", "Ydieresis", -120.0), ("space", "quotedblleft", -80.0), ("space", "quoteleft", -60.0), ("v", "a", -20.0), ("v", "aacute", -20.0), ("v", "abreve", -20.0), ("v", "acircumflex", -20.0), ("v", "adieresis", -20.0), ("v", "agrave", -20.0), ("v", "amacron", -20.0), ("v", "aogonek", -20.0), ("v", "aring", -20.0), ("v", "atilde", -20.0), ("v", "comma", -80.0), ("v", "o", -30.0), ("v", "oacute", -30.0), ("v", "ocircumflex", -30.0), ("v", "odieresis", -30.0), ("v", "ograve", -30.0), ("v", "ohungarumlaut", -30.0), ("v", "omacron", -30.0), ("v", "oslash", -30.0), ("v", "otilde", -30.0), ("v", "period", -80.0), ("w", "comma", -40.0), ("w", "o", -20.0), ("w", "oacute", -20.0), ("w", "ocircumflex", -20.0), ("w", "odieresis", -20.0), ("w", "ograve", -20.0), ("w", "ohungarumlaut", -20.0), ("w", "omacron", -20.0), ("w", "oslash", -20.0), ("w", "otilde", -20.0), ("w", "period", -40.0), ("x", "e", -10.0), ("x", "eacute", -10.0), ("x", "ecaron", -10.0), ("x", "ecircumflex", -10.0), ("x", "edieresis", -10.0), ("x", "edotaccent", -10.0), ("x", "egrave", -10.0), ("x", "emacron", -10.0), ("x", "eogonek", -10.0), ("y", "a", -30.0), ("y", "aacute", -30.0), ("y", "abreve", -30.0), ("y", "acircumflex", -30.0), ("y", "adieresis", -30.0), ("y", "agrave", -30.0), ("y", "amacron", -30.0), ("y", "aogonek", -30.0), ("y", "aring", -30.0), ("y", "atilde", -30.0), ("y", "comma", -80.0), ("y", "e", -10.0), ("y", "eacute", -10.0), ("y", "ecaron", -10.0), ("y", "ecircumflex", -10.0), ("y", "edieresis", -10.0), ("y", "edotaccent", -10.0), ("y", "egrave", -10.0), ("y", "emacron", -10.0), ("y", "eogonek", -10.0), ("y", "o", -25.0), ("y", "oacute", -25.0), ("y", "ocircumflex", -25.0), ("y", "odieresis", -25.0), ("y", "ograve", -25.0), ("y", "ohungarumlaut", -25.0), ("y", "omacron", -25.0), ("y", "oslash", -25.0), ("y", "otilde", -25.0), ("y", "period", -80.0), ("yacute", "a", -30.0), ("yacute", "aacute", -30.0), ("yacute", "abreve", -30.0), ("yacute", "acircumflex", -30.0), ("yacute", "adieresis", -30.0), ("yacute", "agrave", -30.0), ("yacute", "amacron", -30.0), ("yacute", "aogonek", -30.0), ("yacute", "aring", -30.0), ("yacute", "atilde", -30.0), ("yacute", "comma", -80.0), ("yacute", "e", -10.0), ("yacute", "eacute", -10.0), ("yacute", "ecaron", -10.0), ("yacute", "ecircumflex", -10.0), ("yacute", "edieresis", -10.0), ("yacute", "edotaccent", -10.0), ("yacute", "egrave", -10.0), ("yacute", "emacron", -10.0), ("yacute", "eogonek", -10.0), ("yacute", "o", -25.0), ("yacute", "oacute", -25.0), ("yacute", "ocircumflex", -25.0), ("yacute", "odieresis", -25.0), ("yacute", "ograve", -25.0), ("yacute", "ohungarumlaut", -25.0), ("yacute", "omacron", -25.0), ("yacute", "oslash", -25.0), ("yacute", "otilde", -25.0), ("yacute", "period", -80.0), ("ydieresis", "a", -30.0), ("ydieresis", "aacute", -30.0), ("ydieresis", "abreve", -30.0), ("ydieresis", "acircumflex", -30.0), ("ydieresis", "adieresis", -30.0), ("ydieresis", "agrave", -30.0), ("ydieresis", "amacron", -30.0), ("ydieresis", "aogonek", -30.0), ("ydieresis", "aring", -30.0), ("ydieresis", "atilde", -30.0), ("ydieresis", "comma", -80.0), ("ydieresis", "e", -10.0), ("ydieresis", "eacute", -10.0), ("ydieresis", "ecaron", -10.0), ("ydieresis", "ecircumflex", -10.0), ("ydieresis", "edieresis", -10.0), ("ydieresis", "edotaccent", -10.0), ("ydieresis", "egrave", -10.0), ("ydieresis", "emacron", -10.0), ("ydieresis", "eogonek", -10.0), ("ydieresis", "o", -25.0), ("ydieresis", "oacute", -25.0), ("ydieresis", "ocircumflex", -25.0), ("ydieresis", "odieresis", -25.0), ("ydieresis", "ograve", -25.0), ("ydieresis", "ohungarumlaut", -25.0), ("ydieresis", "omacron", -25.0), ("ydieresis", "oslash", -25.0), ("ydieresis", "otilde", -25.0), ("ydieresis", "period", -80.0), ("z", "e", 10.0), ("z", "eacute", 10.0), ("z", "ecaron", 10.0), ("z", "ecircumflex", 10.0), ("z", "edieresis", 10.0), ("z", "edotaccent", 10.0), ("z", "egrave", 10.0), ("z", "emacron", 10.0), ("z", "eogonek", 10.0), ("zacute", "e", 10.0), ("zacute", "eacute", 10.0), ("zacute", "ecaron", 10.0), ("zacute", "ecircumflex", 10.0), ("zacute", "edieresis", 10.0), ("zacute", "edotaccent", 10.0), ("zacute", "egrave", 10.0), ("zacute", "emacron", 10.0), ("zacute", "eogonek", 10.0), ("zcaron", "e", 10.0), ("zcaron", "eacute", 10.0), ("zcaron", "ecaron", 10.0), ("zcaron", "ecircumflex", 10.0), ("zcaron", "edieresis", 10.0), ("zcaron", "edotaccent", 10.0), ("zcaron", "egrave", 10.0), ("zcaron", "emacron", 10.0), ("zcaron", "eogonek", 10.0), ("zdotaccent", "e", 10.0), ("zdotaccent", "eacute", 10.0), ("zdotaccent", "ecaron", 10.0), ("zdotaccent", "ecircumflex", 10.0), ("zdotaccent", "edieresis", 10.0), ("zdotaccent", "edotaccent", 10.0), ("zdotaccent", "egrave", 10.0), ("zdotaccent", "emacron", 10.0), ("zdotaccent", "eogonek", 10.0)]),
+("Helvetica-BoldOblique", &[("A", "C", -40.0), ("A", "Cacute", -40.0), ("A", "Ccaron", -40.0), ("A", "Ccedilla", -40.0), ("A", "G", -50.0), ("A", "Gbreve", -50.0), ("A", "Gcommaaccent", -50.0), ("A", "O", -40.0), ("A", "Oacute", -40.0), ("A", "Ocircumflex", -40.0), ("A", "Odieresis", -40.0), ("A", "Ograve", -40.0), ("A", "Ohungarumlaut", -40.0), ("A", "Omacron", -40.0), ("A", "Oslash", -40.0), ("A", "Otilde", -40.0), ("A", "Q", -40.0), ("A", "T", -90.0), ("A", "Tcaron", -90.0), ("A", "Tcommaaccent", -90.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -80.0), ("A", "W", -60.0), ("A", "Y", -110.0), ("A", "Yacute", -110.0), ("A", "Ydieresis", -110.0), ("A", "u", -30.0), ("A", "uacute", -30.0), ("A", "ucircumflex", -30.0), ("A", "udieresis", -30.0), ("A", "ugrave", -30.0), ("A", "uhungarumlaut", -30.0), ("A", "umacron", -30.0), ("A", "uogonek", -30.0), ("A", "uring", -30.0), ("A", "v", -40.0), ("A", "w", -30.0), ("A", "y", -30.0), ("A", "yacute", -30.0), ("A", "ydieresis", -30.0), ("Aacute", "C", -40.0), ("Aacute", "Cacute", -40.0), ("Aacute", "Ccaron", -40.0), ("Aacute", "Ccedilla", -40.0), ("Aacute", "G", -50.0), ("Aacute", "Gbreve", -50.0), ("Aacute", "Gcommaaccent", -50.0), ("Aacute", "O", -40.0), ("Aacute", "Oacute", -40.0), ("Aacute", "Ocircumflex", -40.0), ("Aacute", "Odieresis", -40.0), ("Aacute", "Ograve", -40.0), ("Aacute", "Ohungarumlaut", -40.0), ("Aacute", "Omacron", -40.0), ("Aacute", "Oslash", -40.0), ("Aacute", "Otilde", -40.0), ("Aacute", "Q", -40.0), ("Aacute", "T", -90.0), ("Aacute", "Tcaron", -90.0), ("Aacute", "Tcommaaccent", -90.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -80.0), ("Aacute", "W", -60.0), ("Aacute", "Y", -110.0), ("Aacute", "Yacute", -110.0), ("Aacute", "Ydieresis", -110.0), ("Aacute", "u", -30.0), ("Aacute", "uacute", -30.0), ("Aacute", "ucircumflex", -30.0), ("Aacute", "udieresis", -30.0), ("Aacute", "ugrave", -30.0), ("Aacute", "uhungarumlaut", -30.0), ("Aacute", "umacron", -30.0), ("Aacute", "uogonek", -30.0), ("Aacute", "uring", -30.0), ("Aacute", "v", -40.0), ("Aacute", "w", -30.0), ("Aacute", "y", -30.0), ("Aacute", "yacute", -30.0), ("Aacute", "ydieresis", -30.0), ("Abreve", "C", -40.0), ("Abreve", "Cacute", -40.0), ("Abreve", "Ccaron", -40.0), ("Abreve", "Ccedilla", -40.0), ("Abreve", "G", -50.0), ("Abreve", "Gbreve", -50.0), ("Abreve", "Gcommaaccent", -50.0), ("Abreve", "O", -40.0), ("Abreve", "Oacute", -40.0), ("Abreve", "Ocircumflex", -40.0), ("Abreve", "Odieresis", -40.0), ("Abreve", "Ograve", -40.0), ("Abreve", "Ohungarumlaut", -40.0), ("Abreve", "Omacron", -40.0), ("Abreve", "Oslash", -40.0), ("Abreve", "Otilde", -40.0), ("Abreve", "Q", -40.0), ("Abreve", "T", -90.0), ("Abreve", "Tcaron", -90.0), ("Abreve", "Tcommaaccent", -90.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -80.0), ("Abreve", "W", -60.0), ("Abreve", "Y", -110.0), ("Abreve", "Yacute", -110.0), ("Abreve", "Ydieresis", -110.0), ("Abreve", "u", -30.0), ("Abreve", "uacute", -30.0), ("Abreve", "ucircumflex", -30.0), ("Abreve", "udieresis", -30.0), ("Abreve", "ugrave", -30.0), ("Abreve", "uhungarumlaut", -30.0), ("Abreve", "umacron", -30.0), ("Abreve", "uogonek", -30.0), ("Abreve", "uring", -30.0), ("Abreve", "v", -40.0), ("Abreve", "w", -30.0), ("Abreve", "y", -30.0), ("Abreve", "yacute", -30.0), ("Abreve", "ydieresis", -30.0), ("Acircumflex", "C", -40.0), ("Acircumflex", "Cacute", -40.0), ("Acircumflex", "Ccaron", -40.0), ("Acircumflex", "Ccedilla", -40.0), ("Acircumflex", "G", -50.0), ("Acircumflex", "Gbreve", -50.0), ("Acircumflex", "Gcommaaccent", -50.0), ("Acircumflex", "O", -40.0), ("Acircumflex", "Oacute", -40.0), ("Acircumflex", "Ocircumflex", -40.0), ("Acircumflex", "Odieresis", -40.0), ("Acircumflex", "Ograve", -40.0), ("Acircumflex", "Ohungarumlaut", -40.0), ("Acircumflex", "Omacron", -40.0), ("Acircumflex", "Oslash", -40.0), ("Acircumflex", "Otilde", -40.0), ("Acircumflex", "Q", -40.0), ("Acircumflex", "T", -90.0), ("Acircumflex", "Tcaron", -90.0), ("Acircumflex", "Tcommaaccent", -90.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -80.0), ("Acircumflex", "W", -60.0), ("Acircumflex", "Y", -110.0), ("Acircumflex", "Yacute", -110.0), ("Acircumflex", "Ydieresis", -110.0), ("Acircumflex", "u", -30.0), ("Acircumflex", "uacute", -30.0), ("Acircumflex", "ucircumflex", -30.0), ("Acircumflex", "udieresis", -30.0), ("Acircumflex", "ugrave", -30.0), ("Acircumflex", "uhungarumlaut", -30.0), ("Acircumflex", "umacron", -30.0), ("Acircumflex", "uogonek", -30.0), ("Acircumflex", "uring", -30.0), ("Acircumflex", "v", -40.0), ("Acircumflex", "w", -30.0), ("Acircumflex", "y", -30.0), ("Acircumflex", "yacute", -30.0), ("Acircumflex", "ydieresis", -30.0), ("Adieresis", "C", -40.0), ("Adieresis", "Cacute", -40.0), ("Adieresis", "Ccaron", -40.0), ("Adieresis", "Ccedilla", -40.0), ("Adieresis", "G", -50.0), ("Adieresis", "Gbreve", -50.0), ("Adieresis", "Gcommaaccent", -50.0), ("Adieresis", "O", -40.0), ("Adieresis", "Oacute", -40.0), ("Adieresis", "Ocircumflex", -40.0), ("Adieresis", "Odieresis", -40.0), ("Adieresis", "Ograve", -40.0), ("Adieresis", "Ohungarumlaut", -40.0), ("Adieresis", "Omacron", -40.0), ("Adieresis", "Oslash", -40.0), ("Adieresis", "Otilde", -40.0), ("Adieresis", "Q", -40.0), ("Adieresis", "T", -90.0), ("Adieresis", "Tcaron", -90.0), ("Adieresis", "Tcommaaccent", -90.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -80.0), ("Adieresis", "W", -60.0), ("Adieresis", "Y", -110.0), ("Adieresis", "Yacute", -110.0), ("Adieresis", "Ydieresis", -110.0), ("Adieresis", "u", -30.0), ("Adieresis", "uacute", -30.0), ("Adieresis", "ucircumflex", -30.0), ("Adieresis", "udieresis", -30.0), ("Adieresis", "ugrave", -30.0), ("Adieresis", "uhungarumlaut", -30.0), ("Adieresis", "umacron", -30.0), ("Adieresis", "uogonek", -30.0), ("Adieresis", "uring", -30.0), ("Adieresis", "v", -40.0), ("Adieresis", "w", -30.0), ("Adieresis", "y", -30.0), ("Adieresis", "yacute", -30.0), ("Adieresis", "ydieresis", -30.0), ("Agrave", "C", -40.0), ("Agrave", "Cacute", -40.0), ("Agrave", "Ccaron", -40.0), ("Agrave", "Ccedilla", -40.0), ("Agrave", "G", -50.0), ("Agrave", "Gbreve", -50.0), ("Agrave", "Gcommaaccent", -50.0), ("Agrave", "O", -40.0), ("Agrave", "Oacute", -40.0), ("Agrave", "Ocircumflex", -40.0), ("Agrave", "Odieresis", -40.0), ("Agrave", "Ograve", -40.0), ("Agrave", "Ohungarumlaut", -40.0), ("Agrave", "Omacron", -40.0), ("Agrave", "Oslash", -40.0), ("Agrave", "Otilde", -40.0), ("Agrave", "Q", -40.0), ("Agrave", "T", -90.0), ("Agrave", "Tcaron", -90.0), ("Agrave", "Tcommaaccent", -90.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -80.0), ("Agrave", "W", -60.0), ("Agrave", "Y", -110.0), ("Agrave", "Yacute", -110.0), ("Agrave", "Ydieresis", -110.0), ("Agrave", "u", -30.0), ("Agrave", "uacute", -30.0), ("Agrave", "ucircumflex", -30.0), ("Agrave", "udieresis", -30.0), ("Agrave", "ugrave", -30.0), ("Agrave", "uhungarumlaut", -30.0), ("Agrave", "umacron", -30.0), ("Agrave", "uogonek", -30.0), ("Agrave", "uring", -30.0), ("Agrave", "v", -40.0), ("Agrave", "w", -30.0), ("Agrave", "y", -30.0), ("Agrave", "yacute", -30.0), ("Agrave", "ydieresis", -30.0), ("Amacron", "C", -40.0), ("Amacron", "Cacute", -40.0), ("Amacron", "Ccaron", -40.0), ("Amacron", "Ccedilla", -40.0), ("Amacron", "G", -50.0), ("Amacron", "Gbreve", -50.0), ("Amacron", "Gcommaaccent", -50.0), ("Amacron", "O", -40.0), ("Amacron", "Oacute", -40.0), ("Amacron", "Ocircumflex", -40.0), ("Amacron", "Odieresis", -40.0), ("Amacron", "Ograve", -40.0), ("Amacron", "Ohungarumlaut", -40.0), ("Amacron", "Omacron", -40.0), ("Amacron", "Oslash", -40.0), ("Amacron", "Otilde", -40.0), ("Amacron", "Q", -40.0), ("Amacron", "T", -90.0), ("Amacron", "Tcaron", -90.0), ("Amacron", "Tcommaaccent", -90.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -80.0), ("Amacron", "W", -60.0), ("Amacron", "Y", -110.0), ("Amacron", "Yacute", -110.0), ("Amacron", "Ydieresis", -110.0), ("Amacron", "u", -30.0), ("Amacron", "uacute", -30.0), ("Amacron", "ucircumflex", -30.0), ("Amacron", "udieresis", -30.0), ("Amacron", "ugrave", -30.0), ("Amacron", "uhungarumlaut", -30.0), ("Amacron", "umacron", -30.0), ("Amacron", "uogonek", -30.0), ("Amacron", "uring", -30.0), ("Amacron", "v", -40.0), ("Amacron", "w", -30.0), ("Amacron", "y", -30.0), ("Amacron", "yacute", -30.0), ("Amacron", "ydieresis", -30.0), ("Aogonek", "C", -40.0), ("Aogonek", "Cacute", -40.0), ("Aogonek", "Ccaron", -40.0), ("Aogonek", "Ccedilla", -40.0), ("Aogonek", "G", -50.0), ("Aogonek", "Gbreve", -50.0), ("Aogonek", "Gcommaaccent", -50.0), ("Aogonek", "O", -40.0), ("Aogonek", "Oacute", -40.0), ("Aogonek", "Ocircumflex", -40.0), ("Aogonek", "Odieresis", -40.0), ("Aogonek", "Ograve", -40.0), ("Aogonek", "Ohungarumlaut", -40.0), ("Aogonek", "Omacron", -40.0), ("Aogonek", "Oslash", -40.0), ("Aogonek", "Otilde", -40.0), ("Aogonek", "Q", -40.0), ("Aogonek", "T", -90.0), ("Aogonek", "Tcaron", -90.0), ("Aogonek", "Tcommaaccent", -90.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -80.0), ("Aogonek", "W", -60.0), ("Aogonek", "Y", -110.0), ("Aogonek", "Yacute", -110.0), ("Aogonek", "Ydieresis", -110.0), ("Aogonek", "u", -30.0), ("Aogonek", "uacute", -30.0), ("Aogonek", "ucircumflex", -30.0), ("Aogonek", "udieresis", -30.0), ("Aogonek", "ugrave", -30.0), ("Aogonek", "uhungarumlaut", -30.0), ("Aogonek", "umacron", -30.0), ("Aogonek", "uogonek", -30.0), ("Aogonek", "uring", -30.0), ("Aogonek", "v", -40.0), ("Aogonek", "w", -30.0), ("Aogonek", "y", -30.0), ("Aogonek", "yacute", -30.0), ("Aogonek", "ydieresis", -30.0), ("Aring", "C", -40.0), ("Aring", "Cacute", -40.0), ("Aring", "Ccaron", -40.0), ("Aring", "Ccedilla", -40.0), ("Aring", "G", -50.0), ("Aring", "Gbreve", -50.0), ("Aring", "Gcommaaccent", -50.0), ("Aring", "O", -40.0), ("Aring", "Oacute", -40.0), ("Aring", "Ocircumflex", -40.0), ("Aring", "Odieresis", -40.0), ("Aring", "Ograve", -40.0), ("Aring", "Ohungarumlaut", -40.0), ("Aring", "Omacron", -40.0), ("Aring", "Oslash", -40.0), ("Aring", "Otilde", -40.0), ("Aring", "Q", -40.0), ("Aring", "T", -90.0), ("Aring", "Tcaron", -90.0), ("Aring", "Tcommaaccent", -90.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -80.0), ("Aring", "W", -60.0), ("Aring", "Y", -110.0), ("Aring", "Yacute", -110.0), ("Aring", "Ydieresis", -110.0), ("Aring", "u", -30.0), ("Aring", "uacute", -30.0), ("Aring", "ucircumflex", -30.0), ("Aring", "udieresis", -30.0), ("Aring", "ugrave", -30.0), ("Aring", "uhungarumlaut", -30.0), ("Aring", "umacron", -30.0), ("Aring", "uogonek", -30.0), ("Aring", "uring", -30.0), ("Aring", "v", -40.0), ("Aring", "w", -30.0), ("Aring", "y", -30.0), ("Aring", "yacute", -30.0), ("Aring", "ydieresis", -30.0), ("Atilde", "C", -40.0), ("Atilde", "Cacute", -40.0), ("Atilde", "Ccaron", -40.0), ("Atilde", "Ccedilla", -40.0), ("Atilde", "G", -50.0), ("Atilde", "Gbreve", -50.0), ("Atilde", "Gcommaaccent", -50.0), ("Atilde", "O", -40.0), ("Atilde", "Oacute", -40.0), ("Atilde", "Ocircumflex", -40.0), ("Atilde", "Odieresis", -40.0), ("Atilde", "Ograve", -40.0), ("Atilde", "Ohungarumlaut", -40.0), ("Atilde", "Omacron", -40.0), ("Atilde", "Oslash", -40.0), ("Atilde", "Otilde", -40.0), ("Atilde", "Q", -40.0), ("Atilde", "T", -90.0), ("Atilde", "Tcaron", -90.0), ("Atilde", "Tcommaaccent", -90.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -80.0), ("Atilde", "W", -60.0), ("Atilde", "Y", -110.0), ("Atilde", "Yacute", -110.0), ("Atilde", "Ydieresis", -110.0), ("Atilde", "u", -30.0), ("Atilde", "uacute", -30.0), ("Atilde", "ucircumflex", -30.0), ("Atilde", "udieresis", -30.0), ("Atilde", "ugrave", -30.0), ("Atilde", "uhungarumlaut", -30.0), ("Atilde", "umacron", -30.0), ("Atilde", "uogonek", -30.0), ("Atilde", "uring", -30.0), ("Atilde", "v", -40.0), ("Atilde", "w", -30.0), ("Atilde", "y", -30.0), ("Atilde", "yacute", -30.0), ("Atilde", "ydieresis", -30.0), ("B", "A", -30.0), ("B", "Aacute", -30.0), ("B", "Abreve", -30.0), ("B", "Acircumflex", -30.0), ("B", "Adieresis", -30.0), ("B", "Agrave", -30.0), ("B", "Amacron", -30.0), ("B", "Aogonek", -30.0), ("B", "Aring", -30.0), ("B", "Atilde", -30.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -40.0), ("D", "Aacute", -40.0), ("D", "Abreve", -40.0), ("D", "Acircumflex", -40.0), ("D", "Adieresis", -40.0), ("D", "Agrave", -40.0), ("D", "Amacron", -40.0), ("D", "Aogonek", -40.0), ("D", "Aring", -40.0), ("D", "Atilde", -40.0), ("D", "V", -40.0), ("D", "W", -40.0), ("D", "Y", -70.0), ("D", "Yacute", -70.0), ("D", "Ydieresis", -70.0), ("D", "comma", -30.0), ("D", "period", -30.0), ("Dcaron", "A", -40.0), ("Dcaron", "Aacute", -40.0), ("Dcaron", "Abreve", -40.0), ("Dcaron", "Acircumflex", -40.0), ("Dcaron", "Adieresis", -40.0), ("Dcaron", "Agrave", -40.0), ("Dcaron", "Amacron", -40.0), ("Dcaron", "Aogonek", -40.0), ("Dcaron", "Aring", -40.0), ("Dcaron", "Atilde", -40.0), ("Dcaron", "V", -40.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -70.0), ("Dcaron", "Yacute", -70.0), ("Dcaron", "Ydieresis", -70.0), ("Dcaron", "comma", -30.0), ("Dcaron", "period", -30.0), ("Dcroat", "A", -40.0), ("Dcroat", "Aacute", -40.0), ("Dcroat", "Abreve", -40.0), ("Dcroat", "Acircumflex", -40.0), ("Dcroat", "Adieresis", -40.0), ("Dcroat", "Agrave", -40.0), ("Dcroat", "Amacron", -40.0), ("Dcroat", "Aogonek", -40.0), ("Dcroat", "Aring", -40.0), ("Dcroat", "Atilde", -40.0), ("Dcroat", "V", -40.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -70.0), ("Dcroat", "Yacute", -70.0), ("Dcroat", "Ydieresis", -70.0), ("Dcroat", "comma", -30.0), ("Dcroat", "period", -30.0), ("F", "A", -80.0), ("F", "Aacute", -80.0), ("F", "Abreve", -80.0), ("F", "Acircumflex", -80.0), ("F", "Adieresis", -80.0), ("F", "Agrave", -80.0), ("F", "Amacron", -80.0), ("F", "Aogonek", -80.0), ("F", "Aring", -80.0), ("F", "Atilde", -80.0), ("F", "a", -20.0), ("F", "aacute", -20.0), ("F", "abreve", -20.0), ("F", "acircumflex", -20.0), ("F", "adieresis", -20.0), ("F", "agrave", -20.0), ("F", "amacron", -20.0), ("F", "aogonek", -20.0), ("F", "aring", -20.0), ("F", "atilde", -20.0), ("F", "comma", -100.0), ("F", "period", -100.0), ("J", "A", -20.0), ("J", "Aacute", -20.0), ("J", "Abreve", -20.0), ("J", "Acircumflex", -20.0), ("J", "Adieresis", -20.0), ("J", "Agrave", -20.0), ("J", "Amacron", -20.0), ("J", "Aogonek", -20.0), ("J", "Aring", -20.0), ("J", "Atilde", -20.0), ("J", "comma", -20.0), ("J", "period", -20.0), ("J", "u", -20.0), ("J", "uacute", -20.0), ("J", "ucircumflex", -20.0), ("J", "udieresis", -20.0), ("J", "ugrave", -20.0), ("J", "uhungarumlaut", -20.0), ("J", "umacron", -20.0), ("J", "uogonek", -20.0), ("J", "uring", -20.0), ("K", "O", -30.0), ("K", "Oacute", -30.0), ("K", "Ocircumflex", -30.0), ("K", "Odieresis", -30.0), ("K", "Ograve", -30.0), ("K", "Ohungarumlaut", -30.0), ("K", "Omacron", -30.0), ("K", "Oslash", -30.0), ("K", "Otilde", -30.0), ("K", "e", -15.0), ("K", "eacute", -15.0), ("K", "ecaron", -15.0), ("K", "ecircumflex", -15.0), ("K", "edieresis", -15.0), ("K", "edotaccent", -15.0), ("K", "egrave", -15.0), ("K", "emacron", -15.0), ("K", "eogonek", -15.0), ("K", "o", -35.0), ("K", "oacute", -35.0), ("K", "ocircumflex", -35.0), ("K", "odieresis", -35.0), ("K", "ograve", -35.0), ("K", "ohungarumlaut", -35.0), ("K", "omacron", -35.0), ("K", "oslash", -35.0), ("K", "otilde", -35.0), ("K", "u", -30.0), ("K", "uacute", -30.0), ("K", "ucircumflex", -30.0), ("K", "udieresis", -30.0), ("K", "ugrave", -30.0), ("K", "uhungarumlaut", -30.0), ("K", "umacron", -30.0), ("K", "uogonek", -30.0), ("K", "uring", -30.0), ("K", "y", -40.0), ("K", "yacute", -40.0), ("K", "ydieresis", -40.0), ("Kcommaaccent", "O", -30.0), ("Kcommaaccent", "Oacute", -30.0), ("Kcommaaccent", "Ocircumflex", -30.0), ("Kcommaaccent", "Odieresis", -30.0), ("Kcommaaccent", "Ograve", -30.0), ("Kcommaaccent", "Ohungarumlaut", -30.0), ("Kcommaaccent", "Omacron", -30.0), ("Kcommaaccent", "Oslash", -30.0), ("Kcommaaccent", "Otilde", -30.0), ("Kcommaaccent", "e", -15.0), ("Kcommaaccent", "eacute", -15.0), ("Kcommaaccent", "ecaron", -15.0), ("Kcommaaccent", "ecircumflex", -15.0), ("Kcommaaccent", "edieresis", -15.0), ("Kcommaaccent", "edotaccent", -15.0), ("Kcommaaccent", "egrave", -15.0), ("Kcommaaccent", "emacron", -15.0), ("Kcommaaccent", "eogonek", -15.0), ("Kcommaaccent", "o", -35.0), ("Kcommaaccent", "oacute", -35.0), ("Kcommaaccent", "ocircumflex", -35.0), ("Kcommaaccent", "odieresis", -35.0), ("Kcommaaccent", "ograve", -35.0), ("Kcommaaccent", "ohungarumlaut", -35.0), ("Kcommaaccent", "omacron", -35.0), ("Kcommaaccent", "oslash", -35.0), ("Kcommaaccent", "otilde", -35.0), ("Kcommaaccent", "u", -30.0), ("Kcommaaccent", "uacute", -30.0), ("Kcommaaccent", "ucircumflex", -30.0), ("Kcommaaccent", "udieresis", -30.0), ("Kcommaaccent", "ugrave", -30.0), ("Kcommaaccent", "uhungarumlaut", -30.0), ("Kcommaaccent", "umacron", -30.0), ("Kcommaaccent", "uogonek", -30.0), ("Kcommaaccent", "uring", -30.0), ("Kcommaaccent", "y", -40.0), ("Kcommaaccent", "yacute", -40.0), ("Kcommaaccent", "ydieresis", -40.0), ("L", "T", -90.0), ("L", "Tcaron", -90.0), ("L", "Tcommaaccent", -90.0), ("L", "V", -110.0), ("L", "W", -80.0), ("L", "Y", -120.0), ("L", "Yacute", -120.0), ("L", "Ydieresis", -120.0), ("L", "quotedblright", -140.0), ("L", "quoteright", -140.0), ("L", "y", -30.0), ("L", "yacute", -30.0), ("L", "ydieresis", -30.0), ("Lacute", "T", -90.0), ("Lacute", "Tcaron", -90.0), ("Lacute", "Tcommaaccent", -90.0), ("Lacute", "V", -110.0), ("Lacute", "W", -80.0), ("Lacute", "Y", -120.0), ("Lacute", "Yacute", -120.0), ("Lacute", "Ydieresis", -120.0), ("Lacute", "quotedblright", -140.0), ("Lacute", "quoteright", -140.0), ("Lacute", "y", -30.0), ("Lacute", "yacute", -30.0), ("Lacute", "ydieresis", -30.0), ("Lcommaaccent", "T", -90.0), ("Lcommaaccent", "Tcaron", -90.0), ("Lcommaaccent", "Tcommaaccent", -90.0), ("Lcommaaccent", "V", -110.0), ("Lcommaaccent", "W", -80.0), ("Lcommaaccent", "Y", -120.0), ("Lcommaaccent", "Yacute", -120.0), ("Lcommaaccent", "Ydieresis", -120.0), ("Lcommaaccent", "quotedblright", -140.0), ("Lcommaaccent", "quoteright", -140.0), ("Lcommaaccent", "y", -30.0), ("Lcommaaccent", "yacute", -30.0), ("Lcommaaccent", "ydieresis", -30.0), ("Lslash", "T", -90.0), ("Lslash", "Tcaron", -90.0), ("Lslash", "Tcommaaccent", -90.0), ("Lslash", "V", -110.0), ("Lslash", "W", -80.0), ("Lslash", "Y", -120.0), ("Lslash", "Yacute", -120.0), ("Lslash", "Ydieresis", -120.0), ("Lslash", "quotedblright", -140.0), ("Lslash", "quoteright", -140.0), ("Lslash", "y", -30.0), ("Lslash", "yacute", -30.0), ("Lslash", "ydieresis", -30.0), ("O", "A", -50.0), ("O", "Aacute", -50.0), ("O", "Abreve", -50.0), ("O", "Acircumflex", -50.0), ("O", "Adieresis", -50.0), ("O", "Agrave", -50.0), ("O", "Amacron", -50.0), ("O", "Aogonek", -50.0), ("O", "Aring", -50.0), ("O", "Atilde", -50.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -50.0), ("O", "X", -50.0), ("O", "Y", -70.0), ("O", "Yacute", -70.0), ("O", "Ydieresis", -70.0), ("O", "comma", -40.0), ("O", "period", -40.0), ("Oacute", "A", -50.0), ("Oacute", "Aacute", -50.0), ("Oacute", "Abreve", -50.0), ("Oacute", "Acircumflex", -50.0), ("Oacute", "Adieresis", -50.0), ("Oacute", "Agrave", -50.0), ("Oacute", "Amacron", -50.0), ("Oacute", "Aogonek", -50.0), ("Oacute", "Aring", -50.0), ("Oacute", "Atilde", -50.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -50.0), ("Oacute", "X", -50.0), ("Oacute", "Y", -70.0), ("Oacute", "Yacute", -70.0), ("Oacute", "Ydieresis", -70.0), ("Oacute", "comma", -40.0), ("Oacute", "period", -40.0), ("Ocircumflex", "A", -50.0), ("Ocircumflex", "Aacute", -50.0), ("Ocircumflex", "Abreve", -50.0), ("Ocircumflex", "Acircumflex", -50.0), ("Ocircumflex", "Adieresis", -50.0), ("Ocircumflex", "Agrave", -50.0), ("Ocircumflex", "Amacron", -50.0), ("Ocircumflex", "Aogonek", -50.0), ("Ocircumflex", "Aring", -50.0), ("Ocircumflex", "Atilde", -50.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -50.0), ("Ocircumflex", "X", -50.0), ("Ocircumflex", "Y", -70.0), ("Ocircumflex", "Yacute", -70.0), ("Ocircumflex", "Ydieresis", -70.0), ("Ocircumflex", "comma", -40.0), ("Ocircumflex", "period", -40.0), ("Odieresis", "A", -50.0), ("Odieresis", "Aacute", -50.0), ("Odieresis", "Abreve", -50.0), ("Odieresis", "Acircumflex", -50.0), ("Odieresis", "Adieresis", -50.0), ("Odieresis", "Agrave", -50.0), ("Odieresis", "Amacron", -50.0), ("Odieresis", "Aogonek", -50.0), ("Odieresis", "Aring", -50.0), ("Odieresis", "Atilde", -50.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -50.0), ("Odieresis", "X", -50.0), ("Odieresis", "Y", -70.0), ("Odieresis", "Yacute", -70.0), ("Odieresis", "Ydieresis", -70.0), ("Odieresis", "comma", -40.0), ("Odieresis", "period", -40.0), ("Ograve", "A", -50.0), ("Ograve", "Aacute", -50.0), ("Ograve", "Abreve", -50.0), ("Ograve", "Acircumflex", -50.0), ("Ograve", "Adieresis", -50.0), ("Ograve", "Agrave", -50.0), ("Ograve", "Amacron", -50.0), ("Ograve", "Aogonek", -50.0), ("Ograve", "Aring", -50.0), ("Ograve", "Atilde", -50.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -50.0), ("Ograve", "X", -50.0), ("Ograve", "Y", -70.0), ("Ograve", "Yacute", -70.0), ("Ograve", "Ydieresis", -70.0), ("Ograve", "comma", -40.0), ("Ograve", "period", -40.0), ("Ohungarumlaut", "A", -50.0), ("Ohungarumlaut", "Aacute", -50.0), ("Ohungarumlaut", "Abreve", -50.0), ("Ohungarumlaut", "Acircumflex", -50.0), ("Ohungarumlaut", "Adieresis", -50.0), ("Ohungarumlaut", "Agrave", -50.0), ("Ohungarumlaut", "Amacron", -50.0), ("Ohungarumlaut", "Aogonek", -50.0), ("Ohungarumlaut", "Aring", -50.0), ("Ohungarumlaut", "Atilde", -50.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -50.0), ("Ohungarumlaut", "X", -50.0), ("Ohungarumlaut", "Y", -70.0), ("Ohungarumlaut", "Yacute", -70.0), ("Ohungarumlaut", "Ydieresis", -70.0), ("Ohungarumlaut", "comma", -40.0), ("Ohungarumlaut", "period", -40.0), ("Omacron", "A", -50.0), ("Omacron", "Aacute", -50.0), ("Omacron", "Abreve", -50.0), ("Omacron", "Acircumflex", -50.0), ("Omacron", "Adieresis", -50.0), ("Omacron", "Agrave", -50.0), ("Omacron", "Amacron", -50.0), ("Omacron", "Aogonek", -50.0), ("Omacron", "Aring", -50.0), ("Omacron", "Atilde", -50.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -50.0), ("Omacron", "X", -50.0), ("Omacron", "Y", -70.0), ("Omacron", "Yacute", -70.0), ("Omacron", "Ydieresis", -70.0), ("Omacron", "comma", -40.0), ("Omacron", "period", -40.0), ("Oslash", "A", -50.0), ("Oslash", "Aacute", -50.0), ("Oslash", "Abreve", -50.0), ("Oslash", "Acircumflex", -50.0), ("Oslash", "Adieresis", -50.0), ("Oslash", "Agrave", -50.0), ("Oslash", "Amacron", -50.0), ("Oslash", "Aogonek", -50.0), ("Oslash", "Aring", -50.0), ("Oslash", "Atilde", -50.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -50.0), ("Oslash", "X", -50.0), ("Oslash", "Y", -70.0), ("Oslash", "Yacute", -70.0), ("Oslash", "Ydieresis", -70.0), ("Oslash", "comma", -40.0), ("Oslash", "period", -40.0), ("Otilde", "A", -50.0), ("Otilde", "Aacute", -50.0), ("Otilde", "Abreve", -50.0), ("Otilde", "Acircumflex", -50.0), ("Otilde", "Adieresis", -50.0), ("Otilde", "Agrave", -50.0), ("Otilde", "Amacron", -50.0), ("Otilde", "Aogonek", -50.0), ("Otilde", "Aring", -50.0), ("Otilde", "Atilde", -50.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -50.0), ("Otilde", "X", -50.0), ("Otilde", "Y", -70.0), ("Otilde", "Yacute", -70.0), ("Otilde", "Ydieresis", -70.0), ("Otilde", "comma", -40.0), ("Otilde", "period", -40.0), ("P", "A", -100.0), ("P", "Aacute", -100.0), ("P", "Abreve", -100.0), ("P", "Acircumflex", -100.0), ("P", "Adieresis", -100.0), ("P", "Agrave", -100.0), ("P", "Amacron", -100.0), ("P", "Aogonek", -100.0), ("P", "Aring", -100.0), ("P", "Atilde", -100.0), ("P", "a", -30.0), ("P", "aacute", -30.0), ("P", "abreve", -30.0), ("P", "acircumflex", -30.0), ("P", "adieresis", -30.0), ("P", "agrave", -30.0), ("P", "amacron", -30.0), ("P", "aogonek", -30.0), ("P", "aring", -30.0), ("P", "atilde", -30.0), ("P", "comma", -120.0), ("P", "e", -30.0), ("P", "eacute", -30.0), ("P", "ecaron", -30.0), ("P", "ecircumflex", -30.0), ("P", "edieresis", -30.0), ("P", "edotaccent", -30.0), ("P", "egrave", -30.0), ("P", "emacron", -30.0), ("P", "eogonek", -30.0), ("P", "o", -40.0), ("P", "oacute", -40.0), ("P", "ocircumflex", -40.0), ("P", "odieresis", -40.0), ("P", "ograve", -40.0), ("P", "ohungarumlaut", -40.0), ("P", "omacron", -40.0), ("P", "oslash", -40.0), ("P", "otilde", -40.0), ("P", "period", -120.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("Q", "comma", 20.0), ("Q", "period", 20.0), ("R", "O", -20.0), ("R", "Oacute", -20.0), ("R", "Ocircumflex", -20.0), ("R", "Odieresis", -20.0), ("R", "Ograve", -20.0), ("R", "Ohungarumlaut", -20.0), ("R", "Omacron", -20.0), ("R", "Oslash", -20.0), ("R", "Otilde", -20.0), ("R", "T", -20.0), ("R", "Tcaron", -20.0), ("R", "Tcommaaccent", -20.0), ("R", "U", -20.0), ("R", "Uacute", -20.0), ("R", "Ucircumflex", -20.0), ("R", "Udieresis", -20.0), ("R", "Ugrave", -20.0), ("R", "Uhungarumlaut", -20.0), ("R", "Umacron", -20.0), ("R", "Uogonek", -20.0), ("R", "Uring", -20.0), ("R", "V", -50.0), ("R", "W", -40.0), ("R", "Y", -50.0), ("R", "Yacute", -50.0), ("R", "Ydieresis", -50.0), ("Racute", "O", -20.0), ("Racute", "Oacute", -20.0), ("Racute", "Ocircumflex", -20.0), ("Racute", "Odieresis", -20.0), ("Racute", "Ograve", -20.0), ("Racute", "Ohungarumlaut", -20.0), ("Racute", "Omacron", -20.0), ("Racute", "Oslash", -20.0), ("Racute", "Otilde", -20.0), ("Racute", "T", -20.0), ("Racute", "Tcaron", -20.0), ("Racute", "Tcommaaccent", -20.0), ("Racute", "U", -20.0), ("Racute", "Uacute", -20.0), ("Racute", "Ucircumflex", -20.0), ("Racute", "Udieresis", -20.0), ("Racute", "Ugrave", -20.0), ("Racute", "Uhungarumlaut", -20.0), ("Racute", "Umacron", -20.0), ("Racute", "Uogonek", -20.0), ("Racute", "Uring", -20.0), ("Racute", "V", -50.0), ("Racute", "W", -40.0), ("Racute", "Y", -50.0), ("Racute", "Yacute", -50.0), ("Racute", "Ydieresis", -50.0), ("Rcaron", "O", -20.0), ("Rcaron", "Oacute", -20.0), ("Rcaron", "Ocircumflex", -20.0), ("Rcaron", "Odieresis", -20.0), ("Rcaron", "Ograve", -20.0), ("Rcaron", "Ohungarumlaut", -20.0), ("Rcaron", "Omacron", -20.0), ("Rcaron", "Oslash", -20.0), ("Rcaron", "Otilde", -20.0), ("Rcaron", "T", -20.0), ("Rcaron", "Tcaron", -20.0), ("Rcaron", "Tcommaaccent", -20.0), ("Rcaron", "U", -20.0), ("Rcaron", "Uacute", -20.0), ("Rcaron", "Ucircumflex", -20.0), ("Rcaron", "Udieresis", -20.0), ("Rcaron", "Ugrave", -20.0), ("Rcaron", "Uhungarumlaut", -20.0), ("Rcaron", "Umacron", -20.0), ("Rcaron", "Uogonek", -20.0), ("Rcaron", "Uring", -20.0), ("Rcaron", "V", -50.0), ("Rcaron", "W", -40.0), ("Rcaron", "Y", -50.0), ("Rcaron", "Yacute", -50.0), ("Rcaron", "Ydieresis", -50.0), ("Rcommaaccent", "O", -20.0), ("Rcommaaccent", "Oacute", -20.0), ("Rcommaaccent", "Ocircumflex", -20.0), ("Rcommaaccent", "Odieresis", -20.0), ("Rcommaaccent", "Ograve", -20.0), ("Rcommaaccent", "Ohungarumlaut", -20.0), ("Rcommaaccent", "Omacron", -20.0), ("Rcommaaccent", "Oslash", -20.0), ("Rcommaaccent", "Otilde", -20.0), ("Rcommaaccent", "T", -20.0), ("Rcommaaccent", "Tcaron", -20.0), ("Rcommaaccent", "Tcommaaccent", -20.0), ("Rcommaaccent", "U", -20.0), ("Rcommaaccent", "Uacute", -20.0), ("Rcommaaccent", "Ucircumflex", -20.0), ("Rcommaaccent", "Udieresis", -20.0), ("Rcommaaccent", "Ugrave", -20.0), ("Rcommaaccent", "Uhungarumlaut", -20.0), ("Rcommaaccent", "Umacron", -20.0), ("Rcommaaccent", "Uogonek", -20.0), ("Rcommaaccent", "Uring", -20.0), ("Rcommaaccent", "V", -50.0), ("Rcommaaccent", "W", -40.0), ("Rcommaaccent", "Y", -50.0), ("Rcommaaccent", "Yacute", -50.0), ("Rcommaaccent", "Ydieresis", -50.0), ("T", "A", -90.0), ("T", "Aacute", -90.0), ("T", "Abreve", -90.0), ("T", "Acircumflex", -90.0), ("T", "Adieresis", -90.0), ("T", "Agrave", -90.0), ("T", "Amacron", -90.0), ("T", "Aogonek", -90.0), ("T", "Aring", -90.0), ("T", "Atilde", -90.0), ("T", "O", -40.0), ("T", "Oacute", -40.0), ("T", "Ocircumflex", -40.0), ("T", "Odieresis", -40.0), ("T", "Ograve", -40.0), ("T", "Ohungarumlaut", -40.0), ("T", "Omacron", -40.0), ("T", "Oslash", -40.0), ("T", "Otilde", -40.0), ("T", "a", -80.0), ("T", "aacute", -80.0), ("T", "abreve", -80.0), ("T", "acircumflex", -80.0), ("T", "adieresis", -80.0), ("T", "agrave", -80.0), ("T", "amacron", -80.0), ("T", "aogonek", -80.0), ("T", "aring", -80.0), ("T", "atilde", -80.0), ("T", "colon", -40.0), ("T", "comma", -80.0), ("T", "e", -60.0), ("T", "eacute", -60.0), ("T", "ecaron", -60.0), ("T", "ecircumflex", -60.0), ("T", "edieresis", -60.0), ("T", "edotaccent", -60.0), ("T", "egrave", -60.0), ("T", "emacron", -60.0), ("T", "eogonek", -60.0), ("T", "hyphen", -120.0), ("T", "o", -80.0), ("T", "oacute", -80.0), ("T", "ocircumflex", -80.0), ("T", "odieresis", -80.0), ("T", "ograve", -80.0), ("T", "ohungarumlaut", -80.0), ("T", "omacron", -80.0), ("T", "oslash", -80.0), ("T", "otilde", -80.0), ("T", "period", -80.0), ("T", "r", -80.0), ("T", "racute", -80.0), ("T", "rcommaaccent", -80.0), ("T", "semicolon", -40.0), ("T", "u", -90.0), ("T", "uacute", -90.0), ("T", "ucircumflex", -90.0), ("T", "udieresis", -90.0), ("T", "ugrave", -90.0), ("T", "uhungarumlaut", -90.0), ("T", "umacron", -90.0), ("T", "uogonek", -90.0), ("T", "uring", -90.0), ("T", "w", -60.0), ("T", "y", -60.0), ("T", "yacute", -60.0), ("T", "ydieresis", -60.0), ("Tcaron", "A", -90.0), ("Tcaron", "Aacute", -90.0), ("Tcaron", "Abreve", -90.0), ("Tcaron", "Acircumflex", -90.0), ("Tcaron", "Adieresis", -90.0), ("Tcaron", "Agrave", -90.0), ("Tcaron", "Amacron", -90.0), ("Tcaron", "Aogonek", -90.0), ("Tcaron", "Aring", -90.0), ("Tcaron", "Atilde", -90.0), ("Tcaron", "O", -40.0), ("Tcaron", "Oacute", -40.0), ("Tcaron", "Ocircumflex", -40.0), ("Tcaron", "Odieresis", -40.0), ("Tcaron", "Ograve", -40.0), ("Tcaron", "Ohungarumlaut", -40.0), ("Tcaron", "Omacron", -40.0), ("Tcaron", "Oslash", -40.0), ("Tcaron", "Otilde", -40.0), ("Tcaron", "a", -80.0), ("Tcaron", "aacute", -80.0), ("Tcaron", "abreve", -80.0), ("Tcaron", "acircumflex", -80.0), ("Tcaron", "adieresis", -80.0), ("Tcaron", "agrave", -80.0), ("Tcaron", "amacron", -80.0), ("Tcaron", "aogonek", -80.0), ("Tcaron", "aring", -80.0), ("Tcaron", "atilde", -80.0), ("Tcaron", "colon", -40.0), ("Tcaron", "comma", -80.0), ("Tcaron", "e", -60.0), ("Tcaron", "eacute", -60.0), ("Tcaron", "ecaron", -60.0), ("Tcaron", "ecircumflex", -60.0), ("Tcaron", "edieresis", -60.0), ("Tcaron", "edotaccent", -60.0), ("Tcaron", "egrave", -60.0), ("Tcaron", "emacron", -60.0), ("Tcaron", "eogonek", -60.0), ("Tcaron", "hyphen", -120.0), ("Tcaron", "o", -80.0), ("Tcaron", "oacute", -80.0), ("Tcaron", "ocircumflex", -80.0), ("Tcaron", "odieresis", -80.0), ("Tcaron", "ograve", -80.0), ("Tcaron", "ohungarumlaut", -80.0), ("Tcaron", "omacron", -80.0), ("Tcaron", "oslash", -80.0), ("Tcaron", "otilde", -80.0), ("Tcaron", "period", -80.0), ("Tcaron", "r", -80.0), ("Tcaron", "racute", -80.0), ("Tcaron", "rcommaaccent", -80.0), ("Tcaron", "semicolon", -40.0), ("Tcaron", "u", -90.0), ("Tcaron", "uacute", -90.0), ("Tcaron", "ucircumflex", -90.0), ("Tcaron", "udieresis", -90.0), ("Tcaron", "ugrave", -90.0), ("Tcaron", "uhungarumlaut", -90.0), ("Tcaron", "umacron", -90.0), ("Tcaron", "uogonek", -90.0), ("Tcaron", "uring", -90.0), ("Tcaron", "w", -60.0), ("Tcaron", "y", -60.0), ("Tcaron", "yacute", -60.0), ("Tcaron", "ydieresis", -60.0), ("Tcommaaccent", "A", -90.0), ("Tcommaaccent", "Aacute", -90.0), ("Tcommaaccent", "Abreve", -90.0), ("Tcommaaccent", "Acircumflex", -90.0), ("Tcommaaccent", "Adieresis", -90.0), ("Tcommaaccent", "Agrave", -90.0), ("Tcommaaccent", "Amacron", -90.0), ("Tcommaaccent", "Aogonek", -90.0), ("Tcommaaccent", "Aring", -90.0), ("Tcommaaccent", "Atilde", -90.0), ("Tcommaaccent", "O", -40.0), ("Tcommaaccent", "Oacute", -40.0), ("Tcommaaccent", "Ocircumflex", -40.0), ("Tcommaaccent", "Odieresis", -40.0), ("Tcommaaccent", "Ograve", -40.0), ("Tcommaaccent", "Ohungarumlaut", -40.0), ("Tcommaaccent", "Omacron", -40.0), ("Tcommaaccent", "Oslash", -40.0), ("Tcommaaccent", "Otilde", -40.0), ("Tcommaaccent", "a", -80.0), ("Tcommaaccent", "aacute", -80.0), ("Tcommaaccent", "abreve", -80.0), ("Tcommaaccent", "acircumflex", -80.0), ("Tcommaaccent", "adieresis", -80.0), ("Tcommaaccent", "agrave", -80.0), ("Tcommaaccent", "amacron", -80.0), ("Tcommaaccent", "aogonek", -80.0), ("Tcommaaccent", "aring", -80.0), ("Tcommaaccent", "atilde", -80.0), ("Tcommaaccent", "colon", -40.0), ("Tcommaaccent", "comma", -80.0), ("Tcommaaccent", "e", -60.0), ("Tcommaaccent", "eacute", -60.0), ("Tcommaaccent", "ecaron", -60.0), ("Tcommaaccent", "ecircumflex", -60.0), ("Tcommaaccent", "edieresis", -60.0), ("Tcommaaccent", "edotaccent", -60.0), ("Tcommaaccent", "egrave", -60.0), ("Tcommaaccent", "emacron", -60.0), ("Tcommaaccent", "eogonek", -60.0), ("Tcommaaccent", "hyphen", -120.0), ("Tcommaaccent", "o", -80.0), ("Tcommaaccent", "oacute", -80.0), ("Tcommaaccent", "ocircumflex", -80.0), ("Tcommaaccent", "odieresis", -80.0), ("Tcommaaccent", "ograve", -80.0), ("Tcommaaccent", "ohungarumlaut", -80.0), ("Tcommaaccent", "omacron", -80.0), ("Tcommaaccent", "oslash", -80.0), ("Tcommaaccent", "otilde", -80.0), ("Tcommaaccent", "period", -80.0), ("Tcommaaccent", "r", -80.0), ("Tcommaaccent", "racute", -80.0), ("Tcommaaccent", "rcommaaccent", -80.0), ("Tcommaaccent", "semicolon", -40.0), ("Tcommaaccent", "u", -90.0), ("Tcommaaccent", "uacute", -90.0), ("Tcommaaccent", "ucircumflex", -90.0), ("Tcommaaccent", "udieresis", -90.0), ("Tcommaaccent", "ugrave", -90.0), ("Tcommaaccent", "uhungarumlaut", -90.0), ("Tcommaaccent", "umacron", -90.0), ("Tcommaaccent", "uogonek", -90.0), ("Tcommaaccent", "uring", -90.0), ("Tcommaaccent", "w", -60.0), ("Tcommaaccent", "y", -60.0), ("Tcommaaccent", "yacute", -60.0), ("Tcommaaccent", "ydieresis", -60.0), ("U", "A", -50.0), ("U", "Aacute", -50.0), ("U", "Abreve", -50.0), ("U", "Acircumflex", -50.0), ("U", "Adieresis", -50.0), ("U", "Agrave", -50.0), ("U", "Amacron", -50.0), ("U", "Aogonek", -50.0), ("U", "Aring", -50.0), ("U", "Atilde", -50.0), ("U", "comma", -30.0), ("U", "period", -30.0), ("Uacute", "A", -50.0), ("Uacute", "Aacute", -50.0), ("Uacute", "Abreve", -50.0), ("Uacute", "Acircumflex", -50.0), ("Uacute", "Adieresis", -50.0), ("Uacute", "Agrave", -50.0), ("Uacute", "Amacron", -50.0), ("Uacute", "Aogonek", -50.0), ("Uacute", "Aring", -50.0), ("Uacute", "Atilde", -50.0), ("Uacute", "comma", -30.0), ("Uacute", "period", -30.0), ("Ucircumflex", "A", -50.0), ("Ucircumflex", "Aacute", -50.0), ("Ucircumflex", "Abreve", -50.0), ("Ucircumflex", "Acircumflex", -50.0), ("Ucircumflex", "Adieresis", -50.0), ("Ucircumflex", "Agrave", -50.0), ("Ucircumflex", "Amacron", -50.0), ("Ucircumflex", "Aogonek", -50.0), ("Ucircumflex", "Aring", -50.0), ("Ucircumflex", "Atilde", -50.0), ("Ucircumflex", "comma", -30.0), ("Ucircumflex", "period", -30.0), ("Udieresis", "A", -50.0), ("Udieresis", "Aacute", -50.0), ("Udieresis", "Abreve", -50.0), ("Udieresis", "Acircumflex", -50.0), ("Udieresis", "Adieresis", -50.0), ("Udieresis", "Agrave", -50.0), ("Udieresis", "Amacron", -50.0), ("Udieresis", "Aogonek", -50.0), ("Udieresis", "Aring", -50.0), ("Udieresis", "Atilde", -50.0), ("Udieresis", "comma", -30.0), ("Udieresis", "period", -30.0), ("Ugrave", "A", -50.0), ("Ugrave", "Aacute", -50.0), ("Ugrave", "Abreve", -50.0), ("Ugrave", "Acircumflex", -50.0), ("Ugrave", "Adieresis", -50.0), ("Ugrave", "Agrave", -50.0), ("Ugrave", "Amacron", -50.0), ("Ugrave", "Aogonek", -50.0), ("Ugrave", "Aring", -50.0), ("Ugrave", "Atilde", -50.0), ("Ugrave", "comma", -30.0), ("Ugrave", "period", -30.0), ("Uhungarumlaut", "A", -50.0), ("Uhungarumlaut", "Aacute", -50.0), ("Uhungarumlaut", "Abreve", -50.0), ("Uhungarumlaut", "Acircumflex", -50.0), ("Uhungarumlaut", "Adieresis", -50.0), ("Uhungarumlaut", "Agrave", -50.0), ("Uhungarumlaut", "Amacron", -50.0), ("Uhungarumlaut", "Aogonek", -50.0), ("Uhungarumlaut", "Aring", -50.0), ("Uhungarumlaut", "Atilde", -50.0), ("Uhungarumlaut", "comma", -30.0), ("Uhungarumlaut", "period", -30.0), ("Umacron", "A", -50.0), ("Umacron", "Aacute", -50.0), ("Umacron", "Abreve", -50.0), ("Umacron", "Acircumflex", -50.0), ("Umacron", "Adieresis", -50.0), ("Umacron", "Agrave", -50.0), ("Umacron", "Amacron", -50.0), ("Umacron", "Aogonek", -50.0), ("Umacron", "Aring", -50.0), ("Umacron", "Atilde", -50.0), ("Umacron", "comma", -30.0), ("Umacron", "period", -30.0), ("Uogonek", "A", -50.0), ("Uogonek", "Aacute", -50.0), ("Uogonek", "Abreve", -50.0), ("Uogonek", "Acircumflex", -50.0), ("Uogonek", "Adieresis", -50.0), ("Uogonek", "Agrave", -50.0), ("Uogonek", "Amacron", -50.0), ("Uogonek", "Aogonek", -50.0), ("Uogonek", "Aring", -50.0), ("Uogonek", "Atilde", -50.0), ("Uogonek", "comma", -30.0), ("Uogonek", "period", -30.0), ("Uring", "A", -50.0), ("Uring", "Aacute", -50.0), ("Uring", "Abreve", -50.0), ("Uring", "Acircumflex", -50.0), ("Uring", "Adieresis", -50.0), ("Uring", "Agrave", -50.0), ("Uring", "Amacron", -50.0), ("Uring", "Aogonek", -50.0), ("Uring", "Aring", -50.0), ("Uring", "Atilde", -50.0), ("Uring", "comma", -30.0), ("Uring", "period", -30.0), ("V", "A", -80.0), ("V", "Aacute", -80.0), ("V", "Abreve", -80.0), ("V", "Acircumflex", -80.0), ("V", "Adieresis", -80.0), ("V", "Agrave", -80.0), ("V", "Amacron", -80.0), ("V", "Aogonek", -80.0), ("V", "Aring", -80.0), ("V", "Atilde", -80.0), ("V", "G", -50.0), ("V", "Gbreve", -50.0), ("V", "Gcommaaccent", -50.0), ("V", "O", -50.0), ("V", "Oacute", -50.0), ("V", "Ocircumflex", -50.0), ("V", "Odieresis", -50.0), ("V", "Ograve", -50.0), ("V", "Ohungarumlaut", -50.0), ("V", "Omacron", -50.0), ("V", "Oslash", -50.0), ("V", "Otilde", -50.0), ("V", "a", -60.0), ("V", "aacute", -60.0), ("V", "abreve", -60.0), ("V", "acircumflex", -60.0), ("V", "adieresis", -60.0), ("V", "agrave", -60.0), ("V", "amacron", -60.0), ("V", "aogonek", -60.0), ("V", "aring", -60.0), ("V", "atilde", -60.0), ("V", "colon", -40.0), ("V", "comma", -120.0), ("V", "e", -50.0), ("V", "eacute", -50.0), ("V", "ecaron", -50.0), ("V", "ecircumflex", -50.0), ("V", "edieresis", -50.0), ("V", "edotaccent", -50.0), ("V", "egrave", -50.0), ("V", "emacron", -50.0), ("V", "eogonek", -50.0), ("V", "hyphen", -80.0), ("V", "o", -90.0), ("V", "oacute", -90.0), ("V", "ocircumflex", -90.0), ("V", "odieresis", -90.0), ("V", "ograve", -90.0), ("V", "ohungarumlaut", -90.0), ("V", "omacron", -90.0), ("V", "oslash", -90.0), ("V", "otilde", -90.0), ("V", "period", -120.0), ("V", "semicolon", -40.0), ("V", "u", -60.0), ("V", "uacute", -60.0), ("V", "ucircumflex", -60.0), ("V", "udieresis", -60.0), ("V", "ugrave", -60.0), ("V", "uhungarumlaut", -60.0), ("V", "umacron", -60.0), ("V", "uogonek", -60.0), ("V", "uring", -60.0), ("W", "A", -60.0), ("W", "Aacute", -60.0), ("W", "Abreve", -60.0), ("W", "Acircumflex", -60.0), ("W", "Adieresis", -60.0), ("W", "Agrave", -60.0), ("W", "Amacron", -60.0), ("W", "Aogonek", -60.0), ("W", "Aring", -60.0), ("W", "Atilde", -60.0), ("W", "O", -20.0), ("W", "Oacute", -20.0), ("W", "Ocircumflex", -20.0), ("W", "Odieresis", -20.0), ("W", "Ograve", -20.0), ("W", "Ohungarumlaut", -20.0), ("W", "Omacron", -20.0), ("W", "Oslash", -20.0), ("W", "Otilde", -20.0), ("W", "a", -40.0), ("W", "aacute", -40.0), ("W", "abreve", -40.0), ("W", "acircumflex", -40.0), ("W", "adieresis", -40.0), ("W", "agrave", -40.0), ("W", "amacron", -40.0), ("W", "aogonek", -40.0), ("W", "aring", -40.0), ("W", "atilde", -40.0), ("W", "colon", -10.0), ("W", "comma", -80.0), ("W", "e", -35.0), ("W", "eacute", -35.0), ("W", "ecaron", -35.0), ("W", "ecircumflex", -35.0), ("W", "edieresis", -35.0), ("W", "edotaccent", -35.0), ("W", "egrave", -35.0), ("W", "emacron", -35.0), ("W", "eogonek", -35.0), ("W", "hyphen", -40.0), ("W", "o", -60.0), ("W", "oacute", -60.0), ("W", "ocircumflex", -60.0), ("W", "odieresis", -60.0), ("W", "ograve", -60.0), ("W", "ohungarumlaut", -60.0), ("W", "omacron", -60.0), ("W", "oslash", -60.0), ("W", "otilde", -60.0), ("W", "period", -80.0), ("W", "semicolon", -10.0), ("W", "u", -45.0), ("W", "uacute", -45.0), ("W", "ucircumflex", -45.0), ("W", "udieresis", -45.0), ("W", "ugrave", -45.0), ("W", "uhungarumlaut", -45.0), ("W", "umacron", -45.0), ("W", "uogonek", -45.0), ("W", "uring", -45.0), ("W", "y", -20.0), ("W", "yacute", -20.0), ("W", "ydieresis", -20.0), ("Y", "A", -110.0), ("Y", "Aacute", -110.0), ("Y", "Abreve", -110.0), ("Y", "Acircumflex", -110.0), ("Y", "Adieresis", -110.0), ("Y", "Agrave", -110.0), ("Y", "Amacron", -110.0), ("Y", "Aogonek", -110.0), ("Y", "Aring", -110.0), ("Y", "Atilde", -110.0), ("Y", "O", -70.0), ("Y", "Oacute", -70.0), ("Y", "Ocircumflex", -70.0), ("Y", "Odieresis", -70.0), ("Y", "Ograve", -70.0), ("Y", "Ohungarumlaut", -70.0), ("Y", "Omacron", -70.0), ("Y", "Oslash", -70.0), ("Y", "Otilde", -70.0), ("Y", "a", -90.0), ("Y", "aacute", -90.0), ("Y", "abreve", -90.0), ("Y", "acircumflex", -90.0), ("Y", "adieresis", -90.0), ("Y", "agrave", -90.0), ("Y", "amacron", -90.0), ("Y", "aogonek", -90.0), ("Y", "aring", -90.0), ("Y", "atilde", -90.0), ("Y", "colon", -50.0), ("Y", "comma", -100.0), ("Y", "e", -80.0), ("Y", "eacute", -80.0), ("Y", "ecaron", -80.0), ("Y", "ecircumflex", -80.0), ("Y", "edieresis", -80.0), ("Y", "edotaccent", -80.0), ("Y", "egrave", -80.0), ("Y", "emacron", -80.0), ("Y", "eogonek", -80.0), ("Y", "o", -100.0), ("Y", "oacute", -100.0), ("Y", "ocircumflex", -100.0), ("Y", "odieresis", -100.0), ("Y", "ograve", -100.0), ("Y", "ohungarumlaut", -100.0), ("Y", "omacron", -100.0), ("Y", "oslash", -100.0), ("Y", "otilde", -100.0), ("Y", "period", -100.0), ("Y", "semicolon", -50.0), ("Y", "u", -100.0), ("Y", "uacute", -100.0), ("Y", "ucircumflex", -100.0), ("Y", "udieresis", -100.0), ("Y", "ugrave", -100.0), ("Y", "uhungarumlaut", -100.0), ("Y", "umacron", -100.0), ("Y", "uogonek", -100.0), ("Y", "uring", -100.0), ("Yacute", "A", -110.0), ("Yacute", "Aacute", -110.0), ("Yacute", "Abreve", -110.0), ("Yacute", "Acircumflex", -110.0), ("Yacute", "Adieresis", -110.0), ("Yacute", "Agrave", -110.0), ("Yacute", "Amacron", -110.0), ("Yacute", "Aogonek", -110.0), ("Yacute", "Aring", -110.0), ("Yacute", "Atilde", -110.0), ("Yacute", "O", -70.0), ("Yacute", "Oacute", -70.0), ("Yacute", "Ocircumflex", -70.0), ("Yacute", "Odieresis", -70.0), ("Yacute", "Ograve", -70.0), ("Yacute", "Ohungarumlaut", -70.0), ("Yacute", "Omacron", -70.0), ("Yacute", "Oslash", -70.0), ("Yacute", "Otilde", -70.0), ("Yacute", "a", -90.0), ("Yacute", "aacute", -90.0), ("Yacute", "abreve", -90.0), ("Yacute", "acircumflex", -90.0), ("Yacute", "adieresis", -90.0), ("Yacute", "agrave", -90.0), ("Yacute", "amacron", -90.0), ("Yacute", "aogonek", -90.0), ("Yacute", "aring", -90.0), ("Yacute", "atilde", -90.0), ("Yacute", "colon", -50.0), ("Yacute", "comma", -100.0), ("Yacute", "e", -80.0), ("Yacute", "eacute", -80.0), ("Yacute", "ecaron", -80.0), ("Yacute", "ecircumflex", -80.0), ("Yacute", "edieresis", -80.0), ("Yacute", "edotaccent", -80.0), ("Yacute", "egrave", -80.0), ("Yacute", "emacron", -80.0), ("Yacute", "eogonek", -80.0), ("Yacute", "o", -100.0), ("Yacute", "oacute", -100.0), ("Yacute", "ocircumflex", -100.0), ("Yacute", "odieresis", -100.0), ("Yacute", "ograve", -100.0), ("Yacute", "ohungarumlaut", -100.0), ("Yacute", "omacron", -100.0), ("Yacute", "oslash", -100.0), ("Yacute", "otilde", -100.0), ("Yacute", "period", -100.0), ("Yacute", "semicolon", -50.0), ("Yacute", "u", -100.0), ("Yacute", "uacute", -100.0), ("Yacute", "ucircumflex", -100.0), ("Yacute", "udieresis", -100.0), ("Yacute", "ugrave", -100.0), ("Yacute", "uhungarumlaut", -100.0), ("Yacute", "umacron", -100.0), ("Yacute", "uogonek", -100.0), ("Yacute", "uring", -100.0), ("Ydieresis", "A", -110.0), ("Ydieresis", "Aacute", -110.0), ("Ydieresis", "Abreve", -110.0), ("Ydieresis", "Acircumflex", -110.0), ("Ydieresis", "Adieresis", -110.0), ("Ydieresis", "Agrave", -110.0), ("Ydieresis", "Amacron", -110.0), ("Ydieresis", "Aogonek", -110.0), ("Ydieresis", "Aring", -110.0), ("Ydieresis", "Atilde", -110.0), ("Ydieresis", "O", -70.0), ("Ydieresis", "Oacute", -70.0), ("Ydieresis", "Ocircumflex", -70.0), ("Ydieresis", "Odieresis", -70.0), ("Ydieresis", "Ograve", -70.0), ("Ydieresis", "Ohungarumlaut", -70.0), ("Ydieresis", "Omacron", -70.0), ("Ydieresis", "Oslash", -70.0), ("Ydieresis", "Otilde", -70.0), ("Ydieresis", "a", -90.0), ("Ydieresis", "aacute", -90.0), ("Ydieresis", "abreve", -90.0), ("Ydieresis", "acircumflex", -90.0), ("Ydieresis", "adieresis", -90.0), ("Ydieresis", "agrave", -90.0), ("Ydieresis", "amacron", -90.0), ("Ydieresis", "aogonek", -90.0), ("Ydieresis", "aring", -90.0), ("Ydieresis", "atilde", -90.0), ("Ydieresis", "colon", -50.0), ("Ydieresis", "comma", -100.0), ("Ydieresis", "e", -80.0), ("Ydieresis", "eacute", -80.0), ("Ydieresis", "ecaron", -80.0), ("Ydieresis", "ecircumflex", -80.0), ("Ydieresis", "edieresis", -80.0), ("Ydieresis", "edotaccent", -80.0), ("Ydieresis", "egrave", -80.0), ("Ydieresis", "emacron", -80.0), ("Ydieresis", "eogonek", -80.0), ("Ydieresis", "o", -100.0), ("Ydieresis", "oacute", -100.0), ("Ydieresis", "ocircumflex", -100.0), ("Ydieresis", "odieresis", -100.0), ("Ydieresis", "ograve", -100.0), ("Ydieresis", "ohungarumlaut", -100.0), ("Ydieresis", "omacron", -100.0), ("Ydieresis", "oslash", -100.0), ("Ydieresis", "otilde", -100.0), ("Ydieresis", "period", -100.0), ("Ydieresis", "semicolon", -50.0), ("Ydieresis", "u", -100.0), ("Ydieresis", "uacute", -100.0), ("Ydieresis", "ucircumflex", -100.0), ("Ydieresis", "udieresis", -100.0), ("Ydieresis", "ugrave", -100.0), ("Ydieresis", "uhungarumlaut", -100.0), ("Ydieresis", "umacron", -100.0), ("Ydieresis", "uogonek", -100.0), ("Ydieresis", "uring", -100.0), ("a", "g", -10.0), ("a", "gbreve", -10.0), ("a", "gcommaaccent", -10.0), ("a", "v", -15.0), ("a", "w", -15.0), ("a", "y", -20.0), ("a", "yacute", -20.0), ("a", "ydieresis", -20.0), ("aacute", "g", -10.0), ("aacute", "gbreve", -10.0), ("aacute", "gcommaaccent", -10.0), ("aacute", "v", -15.0), ("aacute", "w", -15.0), ("aacute", "y", -20.0), ("aacute", "yacute", -20.0), ("aacute", "ydieresis", -20.0), ("abreve", "g", -10.0), ("abreve", "gbreve", -10.0), ("abreve", "gcommaaccent", -10.0), ("abreve", "v", -15.0), ("abreve", "w", -15.0), ("abreve", "y", -20.0), ("abreve", "yacute", -20.0), ("abreve", "ydieresis", -20.0), ("acircumflex", "g", -10.0), ("acircumflex", "gbreve", -10.0), ("acircumflex", "gcommaaccent", -10.0), ("acircumflex", "v", -15.0), ("acircumflex", "w", -15.0), ("acircumflex", "y", -20.0), ("acircumflex", "yacute", -20.0), ("acircumflex", "ydieresis", -20.0), ("adieresis", "g", -10.0), ("adieresis", "gbreve", -10.0), ("adieresis", "gcommaaccent", -10.0), ("adieresis", "v", -15.0), ("adieresis", "w", -15.0), ("adieresis", "y", -20.0), ("adieresis", "yacute", -20.0), ("adieresis", "ydieresis", -20.0), ("agrave", "g", -10.0), ("agrave", "gbreve", -10.0), ("agrave", "gcommaaccent", -10.0), ("agrave", "v", -15.0), ("agrave", "w", -15.0), ("agrave", "y", -20.0), ("agrave", "yacute", -20.0), ("agrave", "ydieresis", -20.0), ("amacron", "g", -10.0), ("amacron", "gbreve", -10.0), ("amacron", "gcommaaccent", -10.0), ("amacron", "v", -15.0), ("amacron", "w", -15.0), ("amacron", "y", -20.0), ("amacron", "yacute", -20.0), ("amacron", "ydieresis", -20.0), ("aogonek", "g", -10.0), ("aogonek", "gbreve", -10.0), ("aogonek", "gcommaaccent", -10.0), ("aogonek", "v", -15.0), ("aogonek", "w", -15.0), ("aogonek", "y", -20.0), ("aogonek", "yacute", -20.0), ("aogonek", "ydieresis", -20.0), ("aring", "g", -10.0), ("aring", "gbreve", -10.0), ("aring", "gcommaaccent", -10.0), ("aring", "v", -15.0), ("aring", "w", -15.0), ("aring", "y", -20.0), ("aring", "yacute", -20.0), ("aring", "ydieresis", -20.0), ("atilde", "g", -10.0), ("atilde", "gbreve", -10.0), ("atilde", "gcommaaccent", -10.0), ("atilde", "v", -15.0), ("atilde", "w", -15.0), ("atilde", "y", -20.0), ("atilde", "yacute", -20.0), ("atilde", "ydieresis", -20.0), ("b", "l", -10.0), ("b", "lacute", -10.0), ("b", "lcommaaccent", -10.0), ("b", "lslash", -10.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -20.0), ("b", "y", -20.0), ("b", "yacute", -20.0), ("b", "ydieresis", -20.0), ("c", "h", -10.0), ("c", "k", -20.0), ("c", "kcommaaccent", -20.0), ("c", "l", -20.0), ("c", "lacute", -20.0), ("c", "lcommaaccent", -20.0), ("c", "lslash", -20.0), ("c", "y", -10.0), ("c", "yacute", -10.0), ("c", "ydieresis", -10.0), ("cacute", "h", -10.0), ("cacute", "k", -20.0), ("cacute", "kcommaaccent", -20.0), ("cacute", "l", -20.0), ("cacute", "lacute", -20.0), ("cacute", "lcommaaccent", -20.0), ("cacute", "lslash", -20.0), ("cacute", "y", -10.0), ("cacute", "yacute", -10.0), ("cacute", "ydieresis", -10.0), ("ccaron", "h", -10.0), ("ccaron", "k", -20.0), ("ccaron", "kcommaaccent", -20.0), ("ccaron", "l", -20.0), ("ccaron", "lacute", -20.0), ("ccaron", "lcommaaccent", -20.0), ("ccaron", "lslash", -20.0), ("ccaron", "y", -10.0), ("ccaron", "yacute", -10.0), ("ccaron", "ydieresis", -10.0), ("ccedilla", "h", -10.0), ("ccedilla", "k", -20.0), ("ccedilla", "kcommaaccent", -20.0), ("ccedilla", "l", -20.0), ("ccedilla", "lacute", -20.0), ("ccedilla", "lcommaaccent", -20.0), ("ccedilla", "lslash", -20.0), ("ccedilla", "y", -10.0), ("ccedilla", "yacute", -10.0), ("ccedilla", "ydieresis", -10.0), ("colon", "space", -40.0), ("comma", "quotedblright", -120.0), ("comma", "quoteright", -120.0), ("comma", "space", -40.0), ("d", "d", -10.0), ("d", "dcroat", -10.0), ("d", "v", -15.0), ("d", "w", -15.0), ("d", "y", -15.0), ("d", "yacute", -15.0), ("d", "ydieresis", -15.0), ("dcroat", "d", -10.0), ("dcroat", "dcroat", -10.0), ("dcroat", "v", -15.0), ("dcroat", "w", -15.0), ("dcroat", "y", -15.0), ("dcroat", "yacute", -15.0), ("dcroat", "ydieresis", -15.0), ("e", "comma", 10.0), ("e", "period", 20.0), ("e", "v", -15.0), ("e", "w", -15.0), ("e", "x", -15.0), ("e", "y", -15.0), ("e", "yacute", -15.0), ("e", "ydieresis", -15.0), ("eacute", "comma", 10.0), ("eacute", "period", 20.0), ("eacute", "v", -15.0), ("eacute", "w", -15.0), ("eacute", "x", -15.0), ("eacute", "y", -15.0), ("eacute", "yacute", -15.0), ("eacute", "ydieresis", -15.0), ("ecaron", "comma", 10.0), ("ecaron", "period", 20.0), ("ecaron", "v", -15.0), ("ecaron", "w", -15.0), ("ecaron", "x", -15.0), ("ecaron", "y", -15.0), ("ecaron", "yacute", -15.0), ("ecaron", "ydieresis", -15.0), ("ecircumflex", "comma", 10.0), ("ecircumflex", "period", 20.0), ("ecircumflex", "v", -15.0), ("ecircumflex", "w", -15.0), ("ecircumflex", "x", -15.0), ("ecircumflex", "y", -15.0), ("ecircumflex", "yacute", -15.0), ("ecircumflex", "ydieresis", -15.0), ("edieresis", "comma", 10.0), ("edieresis", "period", 20.0), ("edieresis", "v", -15.0), ("edieresis", "w", -15.0), ("edieresis", "x", -15.0), ("edieresis", "y", -15.0), ("edieresis", "yacute", -15.0), ("edieresis", "ydieresis", -15.0), ("edotaccent", "comma", 10.0), ("edotaccent", "period", 20.0), ("edotaccent", "v", -15.0), ("edotaccent", "w", -15.0), ("edotaccent", "x", -15.0), ("edotaccent", "y", -15.0), ("edotaccent", "yacute", -15.0), ("edotaccent", "ydieresis", -15.0), ("egrave", "comma", 10.0), ("egrave", "period", 20.0), ("egrave", "v", -15.0), ("egrave", "w", -15.0), ("egrave", "x", -15.0), ("egrave", "y", -15.0), ("egrave", "yacute", -15.0), ("egrave", "ydieresis", -15.0), ("emacron", "comma", 10.0), ("emacron", "period", 20.0), ("emacron", "v", -15.0), ("emacron", "w", -15.0), ("emacron", "x", -15.0), ("emacron", "y", -15.0), ("emacron", "yacute", -15.0), ("emacron", "ydieresis", -15.0), ("eogonek", "comma", 10.0), ("eogonek", "period", 20.0), ("eogonek", "v", -15.0), ("eogonek", "w", -15.0), ("eogonek", "x", -15.0), ("eogonek", "y", -15.0), ("eogonek", "yacute", -15.0), ("eogonek", "ydieresis", -15.0), ("f", "comma", -10.0), ("f", "e", -10.0), ("f", "eacute", -10.0), ("f", "ecaron", -10.0), ("f", "ecircumflex", -10.0), ("f", "edieresis", -10.0), ("f", "edotaccent", -10.0), ("f", "egrave", -10.0), ("f", "emacron", -10.0), ("f", "eogonek", -10.0), ("f", "o", -20.0), ("f", "oacute", -20.0), ("f", "ocircumflex", -20.0), ("f", "odieresis", -20.0), ("f", "ograve", -20.0), ("f", "ohungarumlaut", -20.0), ("f", "omacron", -20.0), ("f", "oslash", -20.0), ("f", "otilde", -20.0), ("f", "period", -10.0), ("f", "quotedblright", 30.0), ("f", "quoteright", 30.0), ("g", "e", 10.0), ("g", "eacute", 10.0), ("g", "ecaron", 10.0), ("g", "ecircumflex", 10.0), ("g", "edieresis", 10.0), ("g", "edotaccent", 10.0), ("g", "egrave", 10.0), ("g", "emacron", 10.0), ("g", "eogonek", 10.0), ("g", "g", -10.0), ("g", "gbreve", -10.0), ("g", "gcommaaccent", -10.0), ("gbreve", "e", 10.0), ("gbreve", "eacute", 10.0), ("gbreve", "ecaron", 10.0), ("gbreve", "ecircumflex", 10.0), ("gbreve", "edieresis", 10.0), ("gbreve", "edotaccent", 10.0), ("gbreve", "egrave", 10.0), ("gbreve", "emacron", 10.0), ("gbreve", "eogonek", 10.0), ("gbreve", "g", -10.0), ("gbreve", "gbreve", -10.0), ("gbreve", "gcommaaccent", -10.0), ("gcommaaccent", "e", 10.0), ("gcommaaccent", "eacute", 10.0), ("gcommaaccent", "ecaron", 10.0), ("gcommaaccent", "ecircumflex", 10.0), ("gcommaaccent", "edieresis", 10.0), ("gcommaaccent", "edotaccent", 10.0), ("gcommaaccent", "egrave", 10.0), ("gcommaaccent", "emacron", 10.0), ("gcommaaccent", "eogonek", 10.0), ("gcommaaccent", "g", -10.0), ("gcommaaccent", "gbreve", -10.0), ("gcommaaccent", "gcommaaccent", -10.0), ("h", "y", -20.0), ("h", "yacute", -20.0), ("h", "ydieresis", -20.0), ("k", "o", -15.0), ("k", "oacute", -15.0), ("k", "ocircumflex", -15.0), ("k", "odieresis", -15.0), ("k", "ograve", -15.0), ("k", "ohungarumlaut", -15.0), ("k", "omacron", -15.0), ("k", "oslash", -15.0), ("k", "otilde", -15.0), ("kcommaaccent", "o", -15.0), ("kcommaaccent", "oacute", -15.0), ("kcommaaccent", "ocircumflex", -15.0), ("kcommaaccent", "odieresis", -15.0), ("kcommaaccent", "ograve", -15.0), ("kcommaaccent", "ohungarumlaut", -15.0), ("kcommaaccent", "omacron", -15.0), ("kcommaaccent", "oslash", -15.0), ("kcommaaccent", "otilde", -15.0), ("l", "w", -15.0), ("l", "y", -15.0), ("l", "yacute", -15.0), ("l", "ydieresis", -15.0), ("lacute", "w", -15.0), ("lacute", "y", -15.0), ("lacute", "yacute", -15.0), ("lacute", "ydieresis", -15.0), ("lcommaaccent", "w", -15.0), ("lcommaaccent", "y", -15.0), ("lcommaaccent", "yacute", -15.0), ("lcommaaccent", "ydieresis", -15.0), ("lslash", "w", -15.0), ("lslash", "y", -15.0), ("lslash", "yacute", -15.0), ("lslash", "ydieresis", -15.0), ("m", "u", -20.0), ("m", "uacute", -20.0), ("m", "ucircumflex", -20.0), ("m", "udieresis", -20.0), ("m", "ugrave", -20.0), ("m", "uhungarumlaut", -20.0), ("m", "umacron", -20.0), ("m", "uogonek", -20.0), ("m", "uring", -20.0), ("m", "y", -30.0), ("m", "yacute", -30.0), ("m", "ydieresis", -30.0), ("n", "u", -10.0), ("n", "uacute", -10.0), ("n", "ucircumflex", -10.0), ("n", "udieresis", -10.0), ("n", "ugrave", -10.0), ("n", "uhungarumlaut", -10.0), ("n", "umacron", -10.0), ("n", "uogonek", -10.0), ("n", "uring", -10.0), ("n", "v", -40.0), ("n", "y", -20.0), ("n", "yacute", -20.0), ("n", "ydieresis", -20.0), ("nacute", "u", -10.0), ("nacute", "uacute", -10.0), ("nacute", "ucircumflex", -10.0), ("nacute", "udieresis", -10.0), ("nacute", "ugrave", -10.0), ("nacute", "uhungarumlaut", -10.0), ("nacute", "umacron", -10.0), ("nacute", "uogonek", -10.0), ("nacute", "uring", -10.0), ("nacute", "v", -40.0), ("nacute", "y", -20.0), ("nacute", "yacute", -20.0), ("nacute", "ydieresis", -20.0), ("ncaron", "u", -10.0), ("ncaron", "uacute", -10.0), ("ncaron", "ucircumflex", -10.0), ("ncaron", "udieresis", -10.0), ("ncaron", "ugrave", -10.0), ("ncaron", "uhungarumlaut", -10.0), ("ncaron", "umacron", -10.0), ("ncaron", "uogonek", -10.0), ("ncaron", "uring", -10.0), ("ncaron", "v", -40.0), ("ncaron", "y", -20.0), ("ncaron", "yacute", -20.0), ("ncaron", "ydieresis", -20.0), ("ncommaaccent", "u", -10.0), ("ncommaaccent", "uacute", -10.0), ("ncommaaccent", "ucircumflex", -10.0), ("ncommaaccent", "udieresis", -10.0), ("ncommaaccent", "ugrave", -10.0), ("ncommaaccent", "uhungarumlaut", -10.0), ("ncommaaccent", "umacron", -10.0), ("ncommaaccent", "uogonek", -10.0), ("ncommaaccent", "uring", -10.0), ("ncommaaccent", "v", -40.0), ("ncommaaccent", "y", -20.0), ("ncommaaccent", "yacute", -20.0), ("ncommaaccent", "ydieresis", -20.0), ("ntilde", "u", -10.0), ("ntilde", "uacute", -10.0), ("ntilde", "ucircumflex", -10.0), ("ntilde", "udieresis", -10.0), ("ntilde", "ugrave", -10.0), ("ntilde", "uhungarumlaut", -10.0), ("ntilde", "umacron", -10.0), ("ntilde", "uogonek", -10.0), ("ntilde", "uring", -10.0), ("ntilde", "v", -40.0), ("ntilde", "y", -20.0), ("ntilde", "yacute", -20.0), ("ntilde", "ydieresis", -20.0), ("o", "v", -20.0), ("o", "w", -15.0), ("o", "x", -30.0), ("o", "y", -20.0), ("o", "yacute", -20.0), ("o", "ydieresis", -20.0), ("oacute", "v", -20.0), ("oacute", "w", -15.0), ("oacute", "x", -30.0), ("oacute", "y", -20.0), ("oacute", "yacute", -20.0), ("oacute", "ydieresis", -20.0), ("ocircumflex", "v", -20.0), ("ocircumflex", "w", -15.0), ("ocircumflex", "x", -30.0), ("ocircumflex", "y", -20.0), ("ocircumflex", "yacute", -20.0), ("ocircumflex", "ydieresis", -20.0), ("odieresis", "v", -20.0), ("odieresis", "w", -15.0), ("odieresis", "x", -30.0), ("odieresis", "y", -20.0), ("odieresis", "yacute", -20.0), ("odieresis", "ydieresis", -20.0), ("ograve", "v", -20.0), ("ograve", "w", -15.0), ("ograve", "x", -30.0), ("ograve", "y", -20.0), ("ograve", "yacute", -20.0), ("ograve", "ydieresis", -20.0), ("ohungarumlaut", "v", -20.0), ("ohungarumlaut", "w", -15.0), ("ohungarumlaut", "x", -30.0), ("ohungarumlaut", "y", -20.0), ("ohungarumlaut", "yacute", -20.0), ("ohungarumlaut", "ydieresis", -20.0), ("omacron", "v", -20.0), ("omacron", "w", -15.0), ("omacron", "x", -30.0), ("omacron", "y", -20.0), ("omacron", "yacute", -20.0), ("omacron", "ydieresis", -20.0), ("oslash", "v", -20.0), ("oslash", "w", -15.0), ("oslash", "x", -30.0), ("oslash", "y", -20.0), ("oslash", "yacute", -20.0), ("oslash", "ydieresis", -20.0), ("otilde", "v", -20.0), ("otilde", "w", -15.0), ("otilde", "x", -30.0), ("otilde", "y", -20.0), ("otilde", "yacute", -20.0), ("otilde", "ydieresis", -20.0), ("p", "y", -15.0), ("p", "yacute", -15.0), ("p", "ydieresis", -15.0), ("period", "quotedblright", -120.0), ("period", "quoteright", -120.0), ("period", "space", -40.0), ("quotedblright", "space", -80.0), ("quoteleft", "quoteleft", -46.0), ("quoteright", "d", -80.0), ("quoteright", "dcroat", -80.0), ("quoteright", "l", -20.0), ("quoteright", "lacute", -20.0), ("quoteright", "lcommaaccent", -20.0), ("quoteright", "lslash", -20.0), ("quoteright", "quoteright", -46.0), ("quoteright", "r", -40.0), ("quoteright", "racute", -40.0), ("quoteright", "rcaron", -40.0), ("quoteright", "rcommaaccent", -40.0), ("quoteright", "s", -60.0), ("quoteright", "sacute", -60.0), ("quoteright", "scaron", -60.0), ("quoteright", "scedilla", -60.0), ("quoteright", "scommaaccent", -60.0), ("quoteright", "space", -80.0), ("quoteright", "v", -20.0), ("r", "c", -20.0), ("r", "cacute", -20.0), ("r", "ccaron", -20.0), ("r", "ccedilla", -20.0), ("r", "comma", -60.0), ("r", "d", -20.0), ("r", "dcroat", -20.0), ("r", "g", -15.0), ("r", "gbreve", -15.0), ("r", "gcommaaccent", -15.0), ("r", "hyphen", -20.0), ("r", "o", -20.0), ("r", "oacute", -20.0), ("r", "ocircumflex", -20.0), ("r", "odieresis", -20.0), ("r", "ograve", -20.0), ("r", "ohungarumlaut", -20.0), ("r", "omacron", -20.0), ("r", "oslash", -20.0), ("r", "otilde", -20.0), ("r", "period", -60.0), ("r", "q", -20.0), ("r", "s", -15.0), ("r", "sacute", -15.0), ("r", "scaron", -15.0), ("r", "scedilla", -15.0), ("r", "scommaaccent", -15.0), ("r", "t", 20.0), ("r", "tcommaaccent", 20.0), ("r", "v", 10.0), ("r", "y", 10.0), ("r", "yacute", 10.0), ("r", "ydieresis", 10.0), ("racute", "c", -20.0), ("racute", "cacute", -20.0), ("racute", "ccaron", -20.0), ("racute", "ccedilla", -20.0), ("racute", "comma", -60.0), ("racute", "d", -20.0), ("racute", "dcroat", -20.0), ("racute", "g", -15.0), ("racute", "gbreve", -15.0), ("racute", "gcommaaccent", -15.0), ("racute", "hyphen", -20.0), ("racute", "o", -20.0), ("racute", "oacute", -20.0), ("racute", "ocircumflex", -20.0), ("racute", "odieresis", -20.0), ("racute", "ograve", -20.0), ("racute", "ohungarumlaut", -20.0), ("racute", "omacron", -20.0), ("racute", "oslash", -20.0), ("racute", "otilde", -20.0), ("racute", "period", -60.0), ("racute", "q", -20.0), ("racute", "s", -15.0), ("racute", "sacute", -15.0), ("racute", "scaron", -15.0), ("racute", "scedilla", -15.0), ("racute", "scommaaccent", -15.0), ("racute", "t", 20.0), ("racute", "tcommaaccent", 20.0), ("racute", "v", 10.0), ("racute", "y", 10.0), ("racute", "yacute", 10.0), ("racute", "ydieresis", 10.0), ("rcaron", "c", -20.0), ("rcaron", "cacute", -20.0), ("rcaron", "ccaron", -20.0), ("rcaron", "ccedilla", -20.0), ("rcaron", "comma", -60.0), ("rcaron", "d", -20.0), ("rcaron", "dcroat", -20.0), ("rcaron", "g", -15.0), ("rcaron", "gbreve", -15.0), ("rcaron", "gcommaaccent", -15.0), ("rcaron", "hyphen", -20.0), ("rcaron", "o", -20.0), ("rcaron", "oacute", -20.0), ("rcaron", "ocircumflex", -20.0), ("rcaron", "odieresis", -20.0), ("rcaron", "ograve", -20.0), ("rcaron", "ohungarumlaut", -20.0), ("rcaron", "omacron", -20.0), ("rcaron", "oslash", -20.0), ("rcaron", "otilde", -20.0), ("rcaron", "period", -60.0), ("rcaron", "q", -20.0), ("rcaron", "s", -15.0), ("rcaron", "sacute", -15.0), ("rcaron", "scaron", -15.0), ("rcaron", "scedilla", -15.0), ("rcaron", "scommaaccent", -15.0), ("rcaron", "t", 20.0), ("rcaron", "tcommaaccent", 20.0), ("rcaron", "v", 10.0), ("rcaron", "y", 10.0), ("rcaron", "yacute", 10.0), ("rcaron", "ydieresis", 10.0), ("rcommaaccent", "c", -20.0), ("rcommaaccent", "cacute", -20.0), ("rcommaaccent", "ccaron", -20.0), ("rcommaaccent", "ccedilla", -20.0), ("rcommaaccent", "comma", -60.0), ("rcommaaccent", "d", -20.0), ("rcommaaccent", "dcroat", -20.0), ("rcommaaccent", "g", -15.0), ("rcommaaccent", "gbreve", -15.0), ("rcommaaccent", "gcommaaccent", -15.0), ("rcommaaccent", "hyphen", -20.0), ("rcommaaccent", "o", -20.0), ("rcommaaccent", "oacute", -20.0), ("rcommaaccent", "ocircumflex", -20.0), ("rcommaaccent", "odieresis", -20.0), ("rcommaaccent", "ograve", -20.0), ("rcommaaccent", "ohungarumlaut", -20.0), ("rcommaaccent", "omacron", -20.0), ("rcommaaccent", "oslash", -20.0), ("rcommaaccent", "otilde", -20.0), ("rcommaaccent", "period", -60.0), ("rcommaaccent", "q", -20.0), ("rcommaaccent", "s", -15.0), ("rcommaaccent", "sacute", -15.0), ("rcommaaccent", "scaron", -15.0), ("rcommaaccent", "scedilla", -15.0), ("rcommaaccent", "scommaaccent", -15.0), ("rcommaaccent", "t", 20.0), ("rcommaaccent", "tcommaaccent", 20.0), ("rcommaaccent", "v", 10.0), ("rcommaaccent", "y", 10.0), ("rcommaaccent", "yacute", 10.0), ("rcommaaccent", "ydieresis", 10.0), ("s", "w", -15.0), ("sacute", "w", -15.0), ("scaron", "w", -15.0), ("scedilla", "w", -15.0), ("scommaaccent", "w", -15.0), ("semicolon", "space", -40.0), ("space", "T", -100.0), ("space", "Tcaron", -100.0), ("space", "Tcommaaccent", -100.0), ("space", "V", -80.0), ("space", "W", -80.0), ("space", "Y", -120.0), ("space", "Yacute", -120.0), ("space", "Ydieresis", -120.0), ("space", "quotedblleft", -80.0), ("space", "quoteleft", -60.0), ("v", "a", -20.0), ("v", "aacute", -20.0), ("v", "abreve", -20.0), ("v", "acircumflex", -20.0), ("v", "adieresis", -20.0), ("v", "agrave", -20.0), ("v", "amacron", -20.0), ("v", "aogonek", -20.0), ("v", "aring", -20.0), ("v", "atilde", -20.0), ("v", "comma", -80.0), ("v", "o", -30.0), ("v", "oacute", -30.0), ("v", "ocircumflex", -30.0), ("v", "odieresis", -30.0), ("v", "ograve", -30.0), ("v", "ohungarumlaut", -30.0), ("v", "omacron", -30.0), ("v", "oslash", -30.0), ("v", "otilde", -30.0), ("v", "period", -80.0), ("w", "comma", -40.0), ("w", "o", -20.0), ("w", "oacute", -20.0), ("w", "ocircumflex", -20.0), ("w", "odieresis", -20.0), ("w", "ograve", -20.0), ("w", "ohungarumlaut", -20.0), ("w", "omacron", -20.0), ("w", "oslash", -20.0), ("w", "otilde", -20.0), ("w", "period", -40.0), ("x", "e", -10.0), ("x", "eacute", -10.0), ("x", "ecaron", -10.0), ("x", "ecircumflex", -10.0), ("x", "edieresis", -10.0), ("x", "edotaccent", -10.0), ("x", "egrave", -10.0), ("x", "emacron", -10.0), ("x", "eogonek", -10.0), ("y", "a", -30.0), ("y", "aacute", -30.0), ("y", "abreve", -30.0), ("y", "acircumflex", -30.0), ("y", "adieresis", -30.0), ("y", "agrave", -30.0), ("y", "amacron", -30.0), ("y", "aogonek", -30.0), ("y", "aring", -30.0), ("y", "atilde", -30.0), ("y", "comma", -80.0), ("y", "e", -10.0), ("y", "eacute", -10.0), ("y", "ecaron", -10.0), ("y", "ecircumflex", -10.0), ("y", "edieresis", -10.0), ("y", "edotaccent", -10.0), ("y", "egrave", -10.0), ("y", "emacron", -10.0), ("y", "eogonek", -10.0), ("y", "o", -25.0), ("y", "oacute", -25.0), ("y", "ocircumflex", -25.0), ("y", "odieresis", -25.0), ("y", "ograve", -25.0), ("y", "ohungarumlaut", -25.0), ("y", "omacron", -25.0), ("y", "oslash", -25.0), ("y", "otilde", -25.0), ("y", "period", -80.0), ("yacute", "a", -30.0), ("yacute", "aacute", -30.0), ("yacute", "abreve", -30.0), ("yacute", "acircumflex", -30.0), ("yacute", "adieresis", -30.0), ("yacute", "agrave", -30.0), ("yacute", "amacron", -30.0), ("yacute", "aogonek", -30.0), ("yacute", "aring", -30.0), ("yacute", "atilde", -30.0), ("yacute", "comma", -80.0), ("yacute", "e", -10.0), ("yacute", "eacute", -10.0), ("yacute", "ecaron", -10.0), ("yacute", "ecircumflex", -10.0), ("yacute", "edieresis", -10.0), ("yacute", "edotaccent", -10.0), ("yacute", "egrave", -10.0), ("yacute", "emacron", -10.0), ("yacute", "eogonek", -10.0), ("yacute", "o", -25.0), ("yacute", "oacute", -25.0), ("yacute", "ocircumflex", -25.0), ("yacute", "odieresis", -25.0), ("yacute", "ograve", -25.0), ("yacute", "ohungarumlaut", -25.0), ("yacute", "omacron", -25.0), ("yacute", "oslash", -25.0), ("yacute", "otilde", -25.0), ("yacute", "period", -80.0), ("ydieresis", "a", -30.0), ("ydieresis", "aacute", -30.0), ("ydieresis", "abreve", -30.0), ("ydieresis", "acircumflex", -30.0), ("ydieresis", "adieresis", -30.0), ("ydieresis", "agrave", -30.0), ("ydieresis", "amacron", -30.0), ("ydieresis", "aogonek", -30.0), ("ydieresis", "aring", -30.0), ("ydieresis", "atilde", -30.0), ("ydieresis", "comma", -80.0), ("ydieresis", "e", -10.0), ("ydieresis", "eacute", -10.0), ("ydieresis", "ecaron", -10.0), ("ydieresis", "ecircumflex", -10.0), ("ydieresis", "edieresis", -10.0), ("ydieresis", "edotaccent", -10.0), ("ydieresis", "egrave", -10.0), ("ydieresis", "emacron", -10.0), ("ydieresis", "eogonek", -10.0), ("ydieresis", "o", -25.0), ("ydieresis", "oacute", -25.0), ("ydieresis", "ocircumflex", -25.0), ("ydieresis", "odieresis", -25.0), ("ydieresis", "ograve", -25.0), ("ydieresis", "ohungarumlaut", -25.0), ("ydieresis", "omacron", -25.0), ("ydieresis", "oslash", -25.0), ("ydieresis", "otilde", -25.0), ("ydieresis", "period", -80.0), ("z", "e", 10.0), ("z", "eacute", 10.0), ("z", "ecaron", 10.0), ("z", "ecircumflex", 10.0), ("z", "edieresis", 10.0), ("z", "edotaccent", 10.0), ("z", "egrave", 10.0), ("z", "emacron", 10.0), ("z", "eogonek", 10.0), ("zacute", "e", 10.0), ("zacute", "eacute", 10.0), ("zacute", "ecaron", 10.0), ("zacute", "ecircumflex", 10.0), ("zacute", "edieresis", 10.0), ("zacute", "edotaccent", 10.0), ("zacute", "egrave", 10.0), ("zacute", "emacron", 10.0), ("zacute", "eogonek", 10.0), ("zcaron", "e", 10.0), ("zcaron", "eacute", 10.0), ("zcaron", "ecaron", 10.0), ("zcaron", "ecircumflex", 10.0), ("zcaron", "edieresis", 10.0), ("zcaron", "edotaccent", 10.0), ("zcaron", "egrave", 10.0), ("zcaron", "emacron", 10.0), ("zcaron", "eogonek", 10.0), ("zdotaccent", "e", 10.0), ("zdotaccent", "eacute", 10.0), ("zdotaccent", "ecaron", 10.0), ("zdotaccent", "ecircumflex", 10.0), ("zdotaccent", "edieresis", 10.0), ("zdotaccent", "edotaccent", 10.0), ("zdotaccent", "egrave", 10.0), ("zdotaccent", "emacron", 10.0), ("zdotaccent", "eogonek", 10.0)]),
+("Helvetica-Oblique", &[("A", "C", -30.0), ("A", "Cacute", -30.0), ("A", "Ccaron", -30.0), ("A", "Ccedilla", -30.0), ("A", "G", -30.0), ("A", "Gbreve", -30.0), ("A", "Gcommaaccent", -30.0), ("A", "O", -30.0), ("A", "Oacute", -30.0), ("A", "Ocircumflex", -30.0), ("A", "Odieresis", -30.0), ("A", "Ograve", -30.0), ("A", "Ohungarumlaut", -30.0), ("A", "Omacron", -30.0), ("A", "Oslash", -30.0), ("A", "Otilde", -30.0), ("A", "Q", -30.0), ("A", "T", -120.0), ("A", "Tcaron", -120.0), ("A", "Tcommaaccent", -120.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -70.0), ("A", "W", -50.0), ("A", "Y", -100.0), ("A", "Yacute", -100.0), ("A", "Ydieresis", -100.0), ("A", "u", -30.0), ("A", "uacute", -30.0), ("A", "ucircumflex", -30.0), ("A", "udieresis", -30.0), ("A", "ugrave", -30.0), ("A", "uhungarumlaut", -30.0), ("A", "umacron", -30.0), ("A", "uogonek", -30.0), ("A", "uring", -30.0), ("A", "v", -40.0), ("A", "w", -40.0), ("A", "y", -40.0), ("A", "yacute", -40.0), ("A", "ydieresis", -40.0), ("Aacute", "C", -30.0), ("Aacute", "Cacute", -30.0), ("Aacute", "Ccaron", -30.0), ("Aacute", "Ccedilla", -30.0), ("Aacute", "G", -30.0), ("Aacute", "Gbreve", -30.0), ("Aacute", "Gcommaaccent", -30.0), ("Aacute", "O", -30.0), ("Aacute", "Oacute", -30.0), ("Aacute", "Ocircumflex", -30.0), ("Aacute", "Odieresis", -30.0), ("Aacute", "Ograve", -30.0), ("Aacute", "Ohungarumlaut", -30.0), ("Aacute", "Omacron", -30.0), ("Aacute", "Oslash", -30.0), ("Aacute", "Otilde", -30.0), ("Aacute", "Q", -30.0), ("Aacute", "T", -120.0), ("Aacute", "Tcaron", -120.0), ("Aacute", "Tcommaaccent", -120.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -70.0), ("Aacute", "W", -50.0), ("Aacute", "Y", -100.0), ("Aacute", "Yacute", -100.0), ("Aacute", "Ydieresis", -100.0), ("Aacute", "u", -30.0), ("Aacute", "uacute", -30.0), ("Aacute", "ucircumflex", -30.0), ("Aacute", "udieresis", -30.0), ("Aacute", "ugrave", -30.0), ("Aacute", "uhungarumlaut", -30.0), ("Aacute", "umacron", -30.0), ("Aacute", "uogonek", -30.0), ("Aacute", "uring", -30.0), ("Aacute", "v", -40.0), ("Aacute", "w", -40.0), ("Aacute", "y", -40.0), ("Aacute", "yacute", -40.0), ("Aacute", "ydieresis", -40.0), ("Abreve", "C", -30.0), ("Abreve", "Cacute", -30.0), ("Abreve", "Ccaron", -30.0), ("Abreve", "Ccedilla", -30.0), ("Abreve", "G", -30.0), ("Abreve", "Gbreve", -30.0), ("Abreve", "Gcommaaccent", -30.0), ("Abreve", "O", -30.0), ("Abreve", "Oacute", -30.0), ("Abreve", "Ocircumflex", -30.0), ("Abreve", "Odieresis", -30.0), ("Abreve", "Ograve", -30.0), ("Abreve", "Ohungarumlaut", -30.0), ("Abreve", "Omacron", -30.0), ("Abreve", "Oslash", -30.0), ("Abreve", "Otilde", -30.0), ("Abreve", "Q", -30.0), ("Abreve", "T", -120.0), ("Abreve", "Tcaron", -120.0), ("Abreve", "Tcommaaccent", -120.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -70.0), ("Abreve", "W", -50.0), ("Abreve", "Y", -100.0), ("Abreve", "Yacute", -100.0), ("Abreve", "Ydieresis", -100.0), ("Abreve", "u", -30.0), ("Abreve", "uacute", -30.0), ("Abreve", "ucircumflex", -30.0), ("Abreve", "udieresis", -30.0), ("Abreve", "ugrave", -30.0), ("Abreve", "uhungarumlaut", -30.0), ("Abreve", "umacron", -30.0), ("Abreve", "uogonek", -30.0), ("Abreve", "uring", -30.0), ("Abreve", "v", -40.0), ("Abreve", "w", -40.0), ("Abreve", "y", -40.0), ("Abreve", "yacute", -40.0), ("Abreve", "ydieresis", -40.0), ("Acircumflex", "C", -30.0), ("Acircumflex", "Cacute", -30.0), ("Acircumflex", "Ccaron", -30.0), ("Acircumflex", "Ccedilla", -30.0), ("Acircumflex", "G", -30.0), ("Acircumflex", "Gbreve", -30.0), ("Acircumflex", "Gcommaaccent", -30.0), ("Acircumflex", "O", -30.0), ("Acircumflex", "Oacute", -30.0), ("Acircumflex", "Ocircumflex", -30.0), ("Acircumflex", "Odieresis", -30.0), ("Acircumflex", "Ograve", -30.0), ("Acircumflex", "Ohungarumlaut", -30.0), ("Acircumflex", "Omacron", -30.0), ("Acircumflex", "Oslash", -30.0), ("Acircumflex", "Otilde", -30.0), ("Acircumflex", "Q", -30.0), ("Acircumflex", "T", -120.0), ("Acircumflex", "Tcaron", -120.0), ("Acircumflex", "Tcommaaccent", -120.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -70.0), ("Acircumflex", "W", -50.0), ("Acircumflex", "Y", -100.0), ("Acircumflex", "Yacute", -100.0), ("Acircumflex", "Ydieresis", -100.0), ("Acircumflex", "u", -30.0), ("Acircumflex", "uacute", -30.0), ("Acircumflex", "ucircumflex", -30.0), ("Acircumflex", "udieresis", -30.0), ("Acircumflex", "ugrave", -30.0), ("Acircumflex", "uhungarumlaut", -30.0), ("Acircumflex", "umacron", -30.0), ("Acircumflex", "uogonek", -30.0), ("Acircumflex", "uring", -30.0), ("Acircumflex", "v", -40.0), ("Acircumflex", "w", -40.0), ("Acircumflex", "y", -40.0), ("Acircumflex", "yacute", -40.0), ("Acircumflex", "ydieresis", -40.0), ("Adieresis", "C", -30.0), ("Adieresis", "Cacute", -30.0), ("Adieresis", "Ccaron", -30.0), ("Adieresis", "Ccedilla", -30.0), ("Adieresis", "G", -30.0), ("Adieresis", "Gbreve", -30.0), ("Adieresis", "Gcommaaccent", -30.0), ("Adieresis", "O", -30.0), ("Adieresis", "Oacute", -30.0), ("Adieresis", "Ocircumflex", -30.0), ("Adieresis", "Odieresis", -30.0), ("Adieresis", "Ograve", -30.0), ("Adieresis", "Ohungarumlaut", -30.0), ("Adieresis", "Omacron", -30.0), ("Adieresis", "Oslash", -30.0), ("Adieresis", "Otilde", -30.0), ("Adieresis", "Q", -30.0), ("Adieresis", "T", -120.0), ("Adieresis", "Tcaron", -120.0), ("Adieresis", "Tcommaaccent", -120.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -70.0), ("Adieresis", "W", -50.0), ("Adieresis", "Y", -100.0), ("Adieresis", "Yacute", -100.0), ("Adieresis", "Ydieresis", -100.0), ("Adieresis", "u", -30.0), ("Adieresis", "uacute", -30.0), ("Adieresis", "ucircumflex", -30.0), ("Adieresis", "udieresis", -30.0), ("Adieresis", "ugrave", -30.0), ("Adieresis", "uhungarumlaut", -30.0), ("Adieresis", "umacron", -30.0), ("Adieresis", "uogonek", -30.0), ("Adieresis", "uring", -30.0), ("Adieresis", "v", -40.0), ("Adieresis", "w", -40.0), ("Adieresis", "y", -40.0), ("Adieresis", "yacute", -40.0), ("Adieresis", "ydieresis", -40.0), ("Agrave", "C", -30.0), ("Agrave", "Cacute", -30.0), ("Agrave", "Ccaron", -30.0), ("Agrave", "Ccedilla", -30.0), ("Agrave", "G", -30.0), ("Agrave", "Gbreve", -30.0), ("Agrave", "Gcommaaccent", -30.0), ("Agrave", "O", -30.0), ("Agrave", "Oacute", -30.0), ("Agrave", "Ocircumflex", -30.0), ("Agrave", "Odieresis", -30.0), ("Agrave", "Ograve", -30.0), ("Agrave", "Ohungarumlaut", -30.0), ("Agrave", "Omacron", -30.0), ("Agrave", "Oslash", -30.0), ("Agrave", "Otilde", -30.0), ("Agrave", "Q", -30.0), ("Agrave", "T", -120.0), ("Agrave", "Tcaron", -120.0), ("Agrave", "Tcommaaccent", -120.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -70.0), ("Agrave", "W", -50.0), ("Agrave", "Y", -100.0), ("Agrave", "Yacute", -100.0), ("Agrave", "Ydieresis", -100.0), ("Agrave", "u", -30.0), ("Agrave", "uacute", -30.0), ("Agrave", "ucircumflex", -30.0), ("Agrave", "udieresis", -30.0), ("Agrave", "ugrave", -30.0), ("Agrave", "uhungarumlaut", -30.0), ("Agrave", "umacron", -30.0), ("Agrave", "uogonek", -30.0), ("Agrave", "uring", -30.0), ("Agrave", "v", -40.0), ("Agrave", "w", -40.0), ("Agrave", "y", -40.0), ("Agrave", "yacute", -40.0), ("Agrave", "ydieresis", -40.0), ("Amacron", "C", -30.0), ("Amacron", "Cacute", -30.0), ("Amacron", "Ccaron", -30.0), ("Amacron", "Ccedilla", -30.0), ("Amacron", "G", -30.0), ("Amacron", "Gbreve", -30.0), ("Amacron", "Gcommaaccent", -30.0), ("Amacron", "O", -30.0), ("Amacron", "Oacute", -30.0), ("Amacron", "Ocircumflex", -30.0), ("Amacron", "Odieresis", -30.0), ("Amacron", "Ograve", -30.0), ("Amacron", "Ohungarumlaut", -30.0), ("Amacron", "Omacron", -30.0), ("Amacron", "Oslash", -30.0), ("Amacron", "Otilde", -30.0), ("Amacron", "Q", -30.0), ("Amacron", "T", -120.0), ("Amacron", "Tcaron", -120.0), ("Amacron", "Tcommaaccent", -120.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -70.0), ("Amacron", "W", -50.0), ("Amacron", "Y", -100.0), ("Amacron", "Yacute", -100.0), ("Amacron", "Ydieresis", -100.0), ("Amacron", "u", -30.0), ("Amacron", "uacute", -30.0), ("Amacron", "ucircumflex", -30.0), ("Amacron", "udieresis", -30.0), ("Amacron", "ugrave", -30.0), ("Amacron", "uhungarumlaut", -30.0), ("Amacron", "umacron", -30.0), ("Amacron", "uogonek", -30.0), ("Amacron", "uring", -30.0), ("Amacron", "v", -40.0), ("Amacron", "w", -40.0), ("Amacron", "y", -40.0), ("Amacron", "yacute", -40.0), ("Amacron", "ydieresis", -40.0), ("Aogonek", "C", -30.0), ("Aogonek", "Cacute", -30.0), ("Aogonek", "Ccaron", -30.0), ("Aogonek", "Ccedilla", -30.0), ("Aogonek", "G", -30.0), ("Aogonek", "Gbreve", -30.0), ("Aogonek", "Gcommaaccent", -30.0), ("Aogonek", "O", -30.0), ("Aogonek", "Oacute", -30.0), ("Aogonek", "Ocircumflex", -30.0), ("Aogonek", "Odieresis", -30.0), ("Aogonek", "Ograve", -30.0), ("Aogonek", "Ohungarumlaut", -30.0), ("Aogonek", "Omacron", -30.0), ("Aogonek", "Oslash", -30.0), ("Aogonek", "Otilde", -30.0), ("Aogonek", "Q", -30.0), ("Aogonek", "T", -120.0), ("Aogonek", "Tcaron", -120.0), ("Aogonek", "Tcommaaccent", -120.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -70.0), ("Aogonek", "W", -50.0), ("Aogonek", "Y", -100.0), ("Aogonek", "Yacute", -100.0), ("Aogonek", "Ydieresis", -100.0), ("Aogonek", "u", -30.0), ("Aogonek", "uacute", -30.0), ("Aogonek", "ucircumflex", -30.0), ("Aogonek", "udieresis", -30.0), ("Aogonek", "ugrave", -30.0), ("Aogonek", "uhungarumlaut", -30.0), ("Aogonek", "umacron", -30.0), ("Aogonek", "uogonek", -30.0), ("Aogonek", "uring", -30.0), ("Aogonek", "v", -40.0), ("Aogonek", "w", -40.0), ("Aogonek", "y", -40.0), ("Aogonek", "yacute", -40.0), ("Aogonek", "ydieresis", -40.0), ("Aring", "C", -30.0), ("Aring", "Cacute", -30.0), ("Aring", "Ccaron", -30.0), ("Aring", "Ccedilla", -30.0), ("Aring", "G", -30.0), ("Aring", "Gbreve", -30.0), ("Aring", "Gcommaaccent", -30.0), ("Aring", "O", -30.0), ("Aring", "Oacute", -30.0), ("Aring", "Ocircumflex", -30.0), ("Aring", "Odieresis", -30.0), ("Aring", "Ograve", -30.0), ("Aring", "Ohungarumlaut", -30.0), ("Aring", "Omacron", -30.0), ("Aring", "Oslash", -30.0), ("Aring", "Otilde", -30.0), ("Aring", "Q", -30.0), ("Aring", "T", -120.0), ("Aring", "Tcaron", -120.0), ("Aring", "Tcommaaccent", -120.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -70.0), ("Aring", "W", -50.0), ("Aring", "Y", -100.0), ("Aring", "Yacute", -100.0), ("Aring", "Ydieresis", -100.0), ("Aring", "u", -30.0), ("Aring", "uacute", -30.0), ("Aring", "ucircumflex", -30.0), ("Aring", "udieresis", -30.0), ("Aring", "ugrave", -30.0), ("Aring", "uhungarumlaut", -30.0), ("Aring", "umacron", -30.0), ("Aring", "uogonek", -30.0), ("Aring", "uring", -30.0), ("Aring", "v", -40.0), ("Aring", "w", -40.0), ("Aring", "y", -40.0), ("Aring", "yacute", -40.0), ("Aring", "ydieresis", -40.0), ("Atilde", "C", -30.0), ("Atilde", "Cacute", -30.0), ("Atilde", "Ccaron", -30.0), ("Atilde", "Ccedilla", -30.0), ("Atilde", "G", -30.0), ("Atilde", "Gbreve", -30.0), ("Atilde", "Gcommaaccent", -30.0), ("Atilde", "O", -30.0), ("Atilde", "Oacute", -30.0), ("Atilde", "Ocircumflex", -30.0), ("Atilde", "Odieresis", -30.0), ("Atilde", "Ograve", -30.0), ("Atilde", "Ohungarumlaut", -30.0), ("Atilde", "Omacron", -30.0), ("Atilde", "Oslash", -30.0), ("Atilde", "Otilde", -30.0), ("Atilde", "Q", -30.0), ("Atilde", "T", -120.0), ("Atilde", "Tcaron", -120.0), ("Atilde", "Tcommaaccent", -120.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -70.0), ("Atilde", "W", -50.0), ("Atilde", "Y", -100.0), ("Atilde", "Yacute", -100.0), ("Atilde", "Ydieresis", -100.0), ("Atilde", "u", -30.0), ("Atilde", "uacute", -30.0), ("Atilde", "ucircumflex", -30.0), ("Atilde", "udieresis", -30.0), ("Atilde", "ugrave", -30.0), ("Atilde", "uhungarumlaut", -30.0), ("Atilde", "umacron", -30.0), ("Atilde", "uogonek", -30.0), ("Atilde", "uring", -30.0), ("Atilde", "v", -40.0), ("Atilde", "w", -40.0), ("Atilde", "y", -40.0), ("Atilde", "yacute", -40.0), ("Atilde", "ydieresis", -40.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("B", "comma", -20.0), ("B", "period", -20.0), ("C", "comma", -30.0), ("C", "period", -30.0), ("Cacute", "comma", -30.0), ("Cacute", "period", -30.0), ("Ccaron", "comma", -30.0), ("Ccaron", "period", -30.0), ("Ccedilla", "comma", -30.0), ("Ccedilla", "period", -30.0), ("D", "A", -40.0), ("D", "Aacute", -40.0), ("D", "Abreve", -40.0), ("D", "Acircumflex", -40.0), ("D", "Adieresis", -40.0), ("D", "Agrave", -40.0), ("D", "Amacron", -40.0), ("D", "Aogonek", -40.0), ("D", "Aring", -40.0), ("D", "Atilde", -40.0), ("D", "V", -70.0), ("D", "W", -40.0), ("D", "Y", -90.0), ("D", "Yacute", -90.0), ("D", "Ydieresis", -90.0), ("D", "comma", -70.0), ("D", "period", -70.0), ("Dcaron", "A", -40.0), ("Dcaron", "Aacute", -40.0), ("Dcaron", "Abreve", -40.0), ("Dcaron", "Acircumflex", -40.0), ("Dcaron", "Adieresis", -40.0), ("Dcaron", "Agrave", -40.0), ("Dcaron", "Amacron", -40.0), ("Dcaron", "Aogonek", -40.0), ("Dcaron", "Aring", -40.0), ("Dcaron", "Atilde", -40.0), ("Dcaron", "V", -70.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -90.0), ("Dcaron", "Yacute", -90.0), ("Dcaron", "Ydieresis", -90.0), ("Dcaron", "comma", -70.0), ("Dcaron", "period", -70.0), ("Dcroat", "A", -40.0), ("Dcroat", "Aacute", -40.0), ("Dcroat", "Abreve", -40.0), ("Dcroat", "Acircumflex", -40.0), ("Dcroat", "Adieresis", -40.0), ("Dcroat", "Agrave", -40.0), ("Dcroat", "Amacron", -40.0), ("Dcroat", "Aogonek", -40.0), ("Dcroat", "Aring", -40.0), ("Dcroat", "Atilde", -40.0), ("Dcroat", "V", -70.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -90.0), ("Dcroat", "Yacute", -90.0), ("Dcroat", "Ydieresis", -90.0), ("Dcroat", "comma", -70.0), ("Dcroat", "period", -70.0), ("F", "A", -80.0), ("F", "Aacute", -80.0), ("F", "Abreve", -80.0), ("F", "Acircumflex", -80.0), ("F", "Adieresis", -80.0), ("F", "Agrave", -80.0), ("F", "Amacron", -80.0), ("F", "Aogonek", -80.0), ("F", "Aring", -80.0), ("F", "Atilde", -80.0), ("F", "a", -50.0), ("F", "aacute", -50.0), ("F", "abreve", -50.0), ("F", "acircumflex", -50.0), ("F", "adieresis", -50.0), ("F", "agrave", -50.0), ("F", "amacron", -50.0), ("F", "aogonek", -50.0), ("F", "aring", -50.0), ("F", "atilde", -50.0), ("F", "comma", -150.0), ("F", "e", -30.0), ("F", "eacute", -30.0), ("F", "ecaron", -30.0), ("F", "ecircumflex", -30.0), ("F", "edieresis", -30.0), ("F", "edotaccent", -30.0), ("F", "egrave", -30.0), ("F", "emacron", -30.0), ("F", "eogonek", -30.0), ("F", "o", -30.0), ("F", "oacute", -30.0), ("F", "ocircumflex", -30.0), ("F", "odieresis", -30.0), ("F", "ograve", -30.0), ("F", "ohungarumlaut", -30.0), ("F", "omacron", -30.0), ("F", "oslash", -30.0), ("F", "otilde", -30.0), ("F", "period", -150.0), ("F", "r", -45.0), ("F", "racute", -45.0), ("F", "rcaron", -45.0), ("F", "rcommaaccent", -45.0), ("J", "A", -20.0), ("J", "Aacute", -20.0), ("J", "Abreve", -20.0), ("J", "Acircumflex", -20.0), ("J", "Adieresis", -20.0), ("J", "Agrave", -20.0), ("J", "Amacron", -20.0), ("J", "Aogonek", -20.0), ("J", "Aring", -20.0), ("J", "Atilde", -20.0), ("J", "a", -20.0), ("J", "aacute", -20.0), ("J", "abreve", -20.0), ("J", "acircumflex", -20.0), ("J", "adieresis", -20.0), ("J", "agrave", -20.0), ("J", "amacron", -20.0), ("J", "aogonek", -20.0), ("J", "aring", -20.0), ("J", "atilde", -20.0), ("J", "comma", -30.0), ("J", "period", -30.0), ("J", "u", -20.0), ("J", "uacute", -20.0), ("J", "ucircumflex", -20.0), ("J", "udieresis", -20.0), ("J", "ugrave", -20.0), ("J", "uhungarumlaut", -20.0), ("J", "umacron", -20.0), ("J", "uogonek", -20.0), ("J", "uring", -20.0), ("K", "O", -50.0), ("K", "Oacute", -50.0), ("K", "Ocircumflex", -50.0), ("K", "Odieresis", -50.0), ("K", "Ograve", -50.0), ("K", "Ohungarumlaut", -50.0), ("K", "Omacron", -50.0), ("K", "Oslash", -50.0), ("K", "Otilde", -50.0), ("K", "e", -40.0), ("K", "eacute", -40.0), ("K", "ecaron", -40.0), ("K", "ecircumflex", -40.0), ("K", "edieresis", -40.0), ("K", "edotaccent", -40.0), ("K", "egrave", -40.0), ("K", "emacron", -40.0), ("K", "eogonek", -40.0), ("K", "o", -40.0), ("K", "oacute", -40.0), ("K", "ocircumflex", -40.0), ("K", "odieresis", -40.0), ("K", "ograve", -40.0), ("K", "ohungarumlaut", -40.0), ("K", "omacron", -40.0), ("K", "oslash", -40.0), ("K", "otilde", -40.0), ("K", "u", -30.0), ("K", "uacute", -30.0), ("K", "ucircumflex", -30.0), ("K", "udieresis", -30.0), ("K", "ugrave", -30.0), ("K", "uhungarumlaut", -30.0), ("K", "umacron", -30.0), ("K", "uogonek", -30.0), ("K", "uring", -30.0), ("K", "y", -50.0), ("K", "yacute", -50.0), ("K", "ydieresis", -50.0), ("Kcommaaccent", "O", -50.0), ("Kcommaaccent", "Oacute", -50.0), ("Kcommaaccent", "Ocircumflex", -50.0), ("Kcommaaccent", "Odieresis", -50.0), ("Kcommaaccent", "Ograve", -50.0), ("Kcommaaccent", "Ohungarumlaut", -50.0), ("Kcommaaccent", "Omacron", -50.0), ("Kcommaaccent", "Oslash", -50.0), ("Kcommaaccent", "Otilde", -50.0), ("Kcommaaccent", "e", -40.0), ("Kcommaaccent", "eacute", -40.0), ("Kcommaaccent", "ecaron", -40.0), ("Kcommaaccent", "ecircumflex", -40.0), ("Kcommaaccent", "edieresis", -40.0), ("Kcommaaccent", "edotaccent", -40.0), ("Kcommaaccent", "egrave", -40.0), ("Kcommaaccent", "emacron", -40.0), ("Kcommaaccent", "eogonek", -40.0), ("Kcommaaccent", "o", -40.0), ("Kcommaaccent", "oacute", -40.0), ("Kcommaaccent", "ocircumflex", -40.0), ("Kcommaaccent", "odieresis", -40.0), ("Kcommaaccent", "ograve", -40.0), ("Kcommaaccent", "ohungarumlaut", -40.0), ("Kcommaaccent", "omacron", -40.0), ("Kcommaaccent", "oslash", -40.0), ("Kcommaaccent", "otilde", -40.0), ("Kcommaaccent", "u", -30.0), ("Kcommaaccent", "uacute", -30.0), ("Kcommaaccent", "ucircumflex", -30.0), ("Kcommaaccent", "udieresis", -30.0), ("Kcommaaccent", "ugrave", -30.0), ("Kcommaaccent", "uhungarumlaut", -30.0), ("Kcommaaccent", "umacron", -30.0), ("Kcommaaccent", "uogonek", -30.0), ("Kcommaaccent", "uring", -30.0), ("Kcommaaccent", "y", -50.0), ("Kcommaaccent", "yacute", -50.0), ("Kcommaaccent", "ydieresis", -50.0), ("L", "T", -110.0), ("L", "Tcaron", -110.0), ("L", "Tcommaaccent", -110.0), ("L", "V", -110.0), ("L", "W", -70.0), ("L", "Y", -140.0), ("L", "Yacute", -140.0), ("L", "Ydieresis", -140.0), ("L", "quotedblright", -140.0), ("L", "quoteright", -160.0), ("L", "y", -30.0), ("L", "yacute", -30.0), ("L", "ydieresis", -30.0), ("Lacute", "T", -110.0), ("Lacute", "Tcaron", -110.0), ("Lacute", "Tcommaaccent", -110.0), ("Lacute", "V", -110.0), ("Lacute", "W", -70.0), ("Lacute", "Y", -140.0), ("Lacute", "Yacute", -140.0), ("Lacute", "Ydieresis", -140.0), ("Lacute", "quotedblright", -140.0), ("Lacute", "quoteright", -160.0), ("Lacute", "y", -30.0), ("Lacute", "yacute", -30.0), ("Lacute", "ydieresis", -30.0), ("Lcaron", "T", -110.0), ("Lcaron", "Tcaron", -110.0), ("Lcaron", "Tcommaaccent", -110.0), ("Lcaron", "V", -110.0), ("Lcaron", "W", -70.0), ("Lcaron", "Y", -140.0), ("Lcaron", "Yacute", -140.0), ("Lcaron", "Ydieresis", -140.0), ("Lcaron", "quotedblright", -140.0), ("Lcaron", "quoteright", -160.0), ("Lcaron", "y", -30.0), ("Lcaron", "yacute", -30.0), ("Lcaron", "ydieresis", -30.0), ("Lcommaaccent", "T", -110.0), ("Lcommaaccent", "Tcaron", -110.0), ("Lcommaaccent", "Tcommaaccent", -110.0), ("Lcommaaccent", "V", -110.0), ("Lcommaaccent", "W", -70.0), ("Lcommaaccent", "Y", -140.0), ("Lcommaaccent", "Yacute", -140.0), ("Lcommaaccent", "Ydieresis", -140.0), ("Lcommaaccent", "quotedblright", -140.0), ("Lcommaaccent", "quoteright", -160.0), ("Lcommaaccent", "y", -30.0), ("Lcommaaccent", "yacute", -30.0), ("Lcommaaccent", "ydieresis", -30.0), ("Lslash", "T", -110.0), ("Lslash", "Tcaron", -110.0), ("Lslash", "Tcommaaccent", -110.0), ("Lslash", "V", -110.0), ("Lslash", "W", -70.0), ("Lslash", "Y", -140.0), ("Lslash", "Yacute", -140.0), ("Lslash", "Ydieresis", -140.0), ("Lslash", "quotedblright", -140.0), ("Lslash", "quoteright", -160.0), ("Lslash", "y", -30.0), ("Lslash", "yacute", -30.0), ("Lslash", "ydieresis", -30.0), ("O", "A", -20.0), ("O", "Aacute", -20.0), ("O", "Abreve", -20.0), ("O", "Acircumflex", -20.0), ("O", "Adieresis", -20.0), ("O", "Agrave", -20.0), ("O", "Amacron", -20.0), ("O", "Aogonek", -20.0), ("O", "Aring", -20.0), ("O", "Atilde", -20.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -30.0), ("O", "X", -60.0), ("O", "Y", -70.0), ("O", "Yacute", -70.0), ("O", "Ydieresis", -70.0), ("O", "comma", -40.0), ("O", "period", -40.0), ("Oacute", "A", -20.0), ("Oacute", "Aacute", -20.0), ("Oacute", "Abreve", -20.0), ("Oacute", "Acircumflex", -20.0), ("Oacute", "Adieresis", -20.0), ("Oacute", "Agrave", -20.0), ("Oacute", "Amacron", -20.0), ("Oacute", "Aogonek", -20.0), ("Oacute", "Aring", -20.0), ("Oacute", "Atilde", -20.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -30.0), ("Oacute", "X", -60.0), ("Oacute", "Y", -70.0), ("Oacute", "Yacute", -70.0), ("Oacute", "Ydieresis", -70.0), ("Oacute", "comma", -40.0), ("Oacute", "period", -40.0), ("Ocircumflex", "A", -20.0), ("Ocircumflex", "Aacute", -20.0), ("Ocircumflex", "Abreve", -20.0), ("Ocircumflex", "Acircumflex", -20.0), ("Ocircumflex", "Adieresis", -20.0), ("Ocircumflex", "Agrave", -20.0), ("Ocircumflex", "Amacron", -20.0), ("Ocircumflex", "Aogonek", -20.0), ("Ocircumflex", "Aring", -20.0), ("Ocircumflex", "Atilde", -20.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -30.0), ("Ocircumflex", "X", -60.0), ("Ocircumflex", "Y", -70.0), ("Ocircumflex", "Yacute", -70.0), ("Ocircumflex", "Ydieresis", -70.0), ("Ocircumflex", "comma", -40.0), ("Ocircumflex", "period", -40.0), ("Odieresis", "A", -20.0), ("Odieresis", "Aacute", -20.0), ("Odieresis", "Abreve", -20.0), ("Odieresis", "Acircumflex", -20.0), ("Odieresis", "Adieresis", -20.0), ("Odieresis", "Agrave", -20.0), ("Odieresis", "Amacron", -20.0), ("Odieresis", "Aogonek", -20.0), ("Odieresis", "Aring", -20.0), ("Odieresis", "Atilde", -20.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -30.0), ("Odieresis", "X", -60.0), ("Odieresis", "Y", -70.0), ("Odieresis", "Yacute", -70.0), ("Odieresis", "Ydieresis", -70.0), ("Odieresis", "comma", -40.0), ("Odieresis", "period", -40.0), ("Ograve", "A", -20.0), ("Ograve", "Aacute", -20.0), ("Ograve", "Abreve", -20.0), ("Ograve", "Acircumflex", -20.0), ("Ograve", "Adieresis", -20.0), ("Ograve", "Agrave", -20.0), ("Ograve", "Amacron", -20.0), ("Ograve", "Aogonek", -20.0), ("Ograve", "Aring", -20.0), ("Ograve", "Atilde", -20.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -30.0), ("Ograve", "X", -60.0), ("Ograve", "Y", -70.0), ("Ograve", "Yacute", -70.0), ("Ograve", "Ydieresis", -70.0), ("Ograve", "comma", -40.0), ("Ograve", "period", -40.0), ("Ohungarumlaut", "A", -20.0), ("Ohungarumlaut", "Aacute", -20.0), ("Ohungarumlaut", "Abreve", -20.0), ("Ohungarumlaut", "Acircumflex", -20.0), ("Ohungarumlaut", "Adieresis", -20.0), ("Ohungarumlaut", "Agrave", -20.0), ("Ohungarumlaut", "Amacron", -20.0), ("Ohungarumlaut", "Aogonek", -20.0), ("Ohungarumlaut", "Aring", -20.0), ("Ohungarumlaut", "Atilde", -20.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -30.0), ("Ohungarumlaut", "X", -60.0), ("Ohungarumlaut", "Y", -70.0), ("Ohungarumlaut", "Yacute", -70.0), ("Ohungarumlaut", "Ydieresis", -70.0), ("Ohungarumlaut", "comma", -40.0), ("Ohungarumlaut", "period", -40.0), ("Omacron", "A", -20.0), ("Omacron", "Aacute", -20.0), ("Omacron", "Abreve", -20.0), ("Omacron", "Acircumflex", -20.0), ("Omacron", "Adieresis", -20.0), ("Omacron", "Agrave", -20.0), ("Omacron", "Amacron", -20.0), ("Omacron", "Aogonek", -20.0), ("Omacron", "Aring", -20.0), ("Omacron", "Atilde", -20.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -30.0), ("Omacron", "X", -60.0), ("Omacron", "Y", -70.0), ("Omacron", "Yacute", -70.0), ("Omacron", "Ydieresis", -70.0), ("Omacron", "comma", -40.0), ("Omacron", "period", -40.0), ("Oslash", "A", -20.0), ("Oslash", "Aacute", -20.0), ("Oslash", "Abreve", -20.0), ("Oslash", "Acircumflex", -20.0), ("Oslash", "Adieresis", -20.0), ("Oslash", "Agrave", -20.0), ("Oslash", "Amacron", -20.0), ("Oslash", "Aogonek", -20.0), ("Oslash", "Aring", -20.0), ("Oslash", "Atilde", -20.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -30.0), ("Oslash", "X", -60.0), ("Oslash", "Y", -70.0), ("Oslash", "Yacute", -70.0), ("Oslash", "Ydieresis", -70.0), ("Oslash", "comma", -40.0), ("Oslash", "period", -40.0), ("Otilde", "A", -20.0), ("Otilde", "Aacute", -20.0), ("Otilde", "Abreve", -20.0), ("Otilde", "Acircumflex", -20.0), ("Otilde", "Adieresis", -20.0), ("Otilde", "Agrave", -20.0), ("Otilde", "Amacron", -20.0), ("Otilde", "Aogonek", -20.0), ("Otilde", "Aring", -20.0), ("Otilde", "Atilde", -20.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -30.0), ("Otilde", "X", -60.0), ("Otilde", "Y", -70.0), ("Otilde", "Yacute", -70.0), ("Otilde", "Ydieresis", -70.0), ("Otilde", "comma", -40.0), ("Otilde", "period", -40.0), ("P", "A", -120.0), ("P", "Aacute", -120.0), ("P", "Abreve", -120.0), ("P", "Acircumflex", -120.0), ("P", "Adieresis", -120.0), ("P", "Agrave", -120.0), ("P", "Amacron", -120.0), ("P", "Aogonek", -120.0), ("P", "Aring", -120.0), ("P", "Atilde", -120.0), ("P", "a", -40.0), ("P", "aacute", -40.0), ("P", "abreve", -40.0), ("P", "acircumflex", -40.0), ("P", "adieresis", -40.0), ("P", "agrave", -40.0), ("P", "amacron", -40.0), ("P", "aogonek", -40.0), ("P", "aring", -40.0), ("P", "atilde", -40.0), ("P", "comma", -180.0), ("P", "e", -50.0), ("P", "eacute", -50.0), ("P", "ecaron", -50.0), ("P", "ecircumflex", -50.0), ("P", "edieresis", -50.0), ("P", "edotaccent", -50.0), ("P", "egrave", -50.0), ("P", "emacron", -50.0), ("P", "eogonek", -50.0), ("P", "o", -50.0), ("P", "oacute", -50.0), ("P", "ocircumflex", -50.0), ("P", "odieresis", -50.0), ("P", "ograve", -50.0), ("P", "ohungarumlaut", -50.0), ("P", "omacron", -50.0), ("P", "oslash", -50.0), ("P", "otilde", -50.0), ("P", "period", -180.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("R", "O", -20.0), ("R", "Oacute", -20.0), ("R", "Ocircumflex", -20.0), ("R", "Odieresis", -20.0), ("R", "Ograve", -20.0), ("R", "Ohungarumlaut", -20.0), ("R", "Omacron", -20.0), ("R", "Oslash", -20.0), ("R", "Otilde", -20.0), ("R", "T", -30.0), ("R", "Tcaron", -30.0), ("R", "Tcommaaccent", -30.0), ("R", "U", -40.0), ("R", "Uacute", -40.0), ("R", "Ucircumflex", -40.0), ("R", "Udieresis", -40.0), ("R", "Ugrave", -40.0), ("R", "Uhungarumlaut", -40.0), ("R", "Umacron", -40.0), ("R", "Uogonek", -40.0), ("R", "Uring", -40.0), ("R", "V", -50.0), ("R", "W", -30.0), ("R", "Y", -50.0), ("R", "Yacute", -50.0), ("R", "Ydieresis", -50.0), ("Racute", "O", -20.0), ("Racute", "Oacute", -20.0), ("Racute", "Ocircumflex", -20.0), ("Racute", "Odieresis", -20.0), ("Racute", "Ograve", -20.0), ("Racute", "Ohungarumlaut", -20.0), ("Racute", "Omacron", -20.0), ("Racute", "Oslash", -20.0), ("Racute", "Otilde", -20.0), ("Racute", "T", -30.0), ("Racute", "Tcaron", -30.0), ("Racute", "Tcommaaccent", -30.0), ("Racute", "U", -40.0), ("Racute", "Uacute", -40.0), ("Racute", "Ucircumflex", -40.0), ("Racute", "Udieresis", -40.0), ("Racute", "Ugrave", -40.0), ("Racute", "Uhungarumlaut", -40.0), ("Racute", "Umacron", -40.0), ("Racute", "Uogonek", -40.0), ("Racute", "Uring", -40.0), ("Racute", "V", -50.0), ("Racute", "W", -30.0), ("Racute", "Y", -50.0), ("Racute", "Yacute", -50.0), ("Racute", "Ydieresis", -50.0), ("Rcaron", "O", -20.0), ("Rcaron", "Oacute", -20.0), ("Rcaron", "Ocircumflex", -20.0), ("Rcaron", "Odieresis", -20.0), ("Rcaron", "Ograve", -20.0), ("Rcaron", "Ohungarumlaut", -20.0), ("Rcaron", "Omacron", -20.0), ("Rcaron", "Oslash", -20.0), ("Rcaron", "Otilde", -20.0), ("Rcaron", "T", -30.0), ("Rcaron", "Tcaron", -30.0), ("Rcaron", "Tcommaaccent", -30.0), ("Rcaron", "U", -40.0), ("Rcaron", "Uacute", -40.0), ("Rcaron", "Ucircumflex", -40.0), ("Rcaron", "Udieresis", -40.0), ("Rcaron", "Ugrave", -40.0), ("Rcaron", "Uhungarumlaut", -40.0), ("Rcaron", "Umacron", -40.0), ("Rcaron", "Uogonek", -40.0), ("Rcaron", "Uring", -40.0), ("Rcaron", "V", -50.0), ("Rcaron", "W", -30.0), ("Rcaron", "Y", -50.0), ("Rcaron", "Yacute", -50.0), ("Rcaron", "Ydieresis", -50.0), ("Rcommaaccent", "O", -20.0), ("Rcommaaccent", "Oacute", -20.0), ("Rcommaaccent", "Ocircumflex", -20.0), ("Rcommaaccent", "Odieresis", -20.0), ("Rcommaaccent", "Ograve", -20.0), ("Rcommaaccent", "Ohungarumlaut", -20.0), ("Rcommaaccent", "Omacron", -20.0), ("Rcommaaccent", "Oslash", -20.0), ("Rcommaaccent", "Otilde", -20.0), ("Rcommaaccent", "T", -30.0), ("Rcommaaccent", "Tcaron", -30.0), ("Rcommaaccent", "Tcommaaccent", -30.0), ("Rcommaaccent", "U", -40.0), ("Rcommaaccent", "Uacute", -40.0), ("Rcommaaccent", "Ucircumflex", -40.0), ("Rcommaaccent", "Udieresis", -40.0), ("Rcommaaccent", "Ugrave", -40.0), ("Rcommaaccent", "Uhungarumlaut", -40.0), ("Rcommaaccent", "Umacron", -40.0), ("Rcommaaccent", "Uogonek", -40.0), ("Rcommaaccent", "Uring", -40.0), ("Rcommaaccent", "V", -50.0), ("Rcommaaccent", "W", -30.0), ("Rcommaaccent", "Y", -50.0), ("Rcommaaccent", "Yacute", -50.0), ("Rcommaaccent", "Ydieresis", -50.0), ("S", "comma", -20.0), ("S", "period", -20.0), ("Sacute", "comma", -20.0), ("Sacute", "period", -20.0), ("Scaron", "comma", -20.0), ("Scaron", "period", -20.0), ("Scedilla", "comma", -20.0), ("Scedilla", "period", -20.0), ("Scommaaccent", "comma", -20.0), ("Scommaaccent", "period", -20.0), ("T", "A", -120.0), ("T", "Aacute", -120.0), ("T", "Abreve", -120.0), ("T", "Acircumflex", -120.0), ("T", "Adieresis", -120.0), ("T", "Agrave", -120.0), ("T", "Amacron", -120.0), ("T", "Aogonek", -120.0), ("T", "Aring", -120.0), ("T", "Atilde", -120.0), ("T", "O", -40.0), ("T", "Oacute", -40.0), ("T", "Ocircumflex", -40.0), ("T", "Odieresis", -40.0), ("T", "Ograve", -40.0), ("T", "Ohungarumlaut", -40.0), ("T", "Omacron", -40.0), ("T", "Oslash", -40.0), ("T", "Otilde", -40.0), ("T", "a", -120.0), ("T", "aacute", -120.0), ("T", "abreve", -60.0), ("T", "acircumflex", -120.0), ("T", "adieresis", -120.0), ("T", "agrave", -120.0), ("T", "amacron", -60.0), ("T", "aogonek", -120.0), ("T", "aring", -120.0), ("T", "atilde", -60.0), ("T", "colon", -20.0), ("T", "comma", -120.0), ("T", "e", -120.0), ("T", "eacute", -120.0), ("T", "ecaron", -120.0), ("T", "ecircumflex", -120.0), ("T", "edieresis", -120.0), ("T", "edotaccent", -120.0), ("T", "egrave", -60.0), ("T", "emacron", -60.0), ("T", "eogonek", -120.0), ("T", "hyphen", -140.0), ("T", "o", -120.0), ("T", "oacute", -120.0), ("T", "ocircumflex", -120.0), ("T", "odieresis", -120.0), ("T", "ograve", -120.0), ("T", "ohungarumlaut", -120.0), ("T", "omacron", -60.0), ("T", "oslash", -120.0), ("T", "otilde", -60.0), ("T", "period", -120.0), ("T", "r", -120.0), ("T", "racute", -120.0), ("T", "rcaron", -120.0), ("T", "rcommaaccent", -120.0), ("T", "semicolon", -20.0), ("T", "u", -120.0), ("T", "uacute", -120.0), ("T", "ucircumflex", -120.0), ("T", "udieresis", -120.0), ("T", "ugrave", -120.0), ("T", "uhungarumlaut", -120.0), ("T", "umacron", -60.0), ("T", "uogonek", -120.0), ("T", "uring", -120.0), ("T", "w", -120.0), ("T", "y", -120.0), ("T", "yacute", -120.0), ("T", "ydieresis", -60.0), ("Tcaron", "A", -120.0), ("Tcaron", "Aacute", -120.0), ("Tcaron", "Abreve", -120.0), ("Tcaron", "Acircumflex", -120.0), ("Tcaron", "Adieresis", -120.0), ("Tcaron", "Agrave", -120.0), ("Tcaron", "Amacron", -120.0), ("Tcaron", "Aogonek", -120.0), ("Tcaron", "Aring", -120.0), ("Tcaron", "Atilde", -120.0), ("Tcaron", "O", -40.0), ("Tcaron", "Oacute", -40.0), ("Tcaron", "Ocircumflex", -40.0), ("Tcaron", "Odieresis", -40.0), ("Tcaron", "Ograve", -40.0), ("Tcaron", "Ohungarumlaut", -40.0), ("Tcaron", "Omacron", -40.0), ("Tcaron", "Oslash", -40.0), ("Tcaron", "Otilde", -40.0), ("Tcaron", "a", -120.0), ("Tcaron", "aacute", -120.0), ("Tcaron", "abreve", -60.0), ("Tcaron", "acircumflex", -120.0), ("Tcaron", "adieresis", -120.0), ("Tcaron", "agrave", -120.0), ("Tcaron", "amacron", -60.0), ("Tcaron", "aogonek", -120.0), ("Tcaron", "aring", -120.0), ("Tcaron", "atilde", -60.0), ("Tcaron", "colon", -20.0), ("Tcaron", "comma", -120.0), ("Tcaron", "e", -120.0), ("Tcaron", "eacute", -120.0), ("Tcaron", "ecaron", -120.0), ("Tcaron", "ecircumflex", -120.0), ("Tcaron", "edieresis", -120.0), ("Tcaron", "edotaccent", -120.0), ("Tcaron", "egrave", -60.0), ("Tcaron", "emacron", -60.0), ("Tcaron", "eogonek", -120.0), ("Tcaron", "hyphen", -140.0), ("Tcaron", "o", -120.0), ("Tcaron", "oacute", -120.0), ("Tcaron", "ocircumflex", -120.0), ("Tcaron", "odieresis", -120.0), ("Tcaron", "ograve", -120.0), ("Tcaron", "ohungarumlaut", -120.0), ("Tcaron", "omacron", -60.0), ("Tcaron", "oslash", -120.0), ("Tcaron", "otilde", -60.0), ("Tcaron", "period", -120.0), ("Tcaron", "r", -120.0), ("Tcaron", "racute", -120.0), ("Tcaron", "rcaron", -120.0), ("Tcaron", "rcommaaccent", -120.0), ("Tcaron", "semicolon", -20.0), ("Tcaron", "u", -120.0), ("Tcaron", "uacute", -120.0), ("Tcaron", "ucircumflex", -120.0), ("Tcaron", "udieresis", -120.0), ("Tcaron", "ugrave", -120.0), ("Tcaron", "uhungarumlaut", -120.0), ("Tcaron", "umacron", -60.0), ("Tcaron", "uogonek", -120.0), ("Tcaron", "uring", -120.0), ("Tcaron", "w", -120.0), ("Tcaron", "y", -120.0), ("Tcaron", "yacute", -120.0), ("Tcaron", "ydieresis", -60.0), ("Tcommaaccent", "A", -120.0), ("Tcommaaccent", "Aacute", -120.0), ("Tcommaaccent", "Abreve", -120.0), ("Tcommaaccent", "Acircumflex", -120.0), ("Tcommaaccent", "Adieresis", -120.0), ("Tcommaaccent", "Agrave", -120.0), ("Tcommaaccent", "Amacron", -120.0), ("Tcommaaccent", "Aogonek", -120.0), ("Tcommaaccent", "Aring", -120.0), ("Tcommaaccent", "Atilde", -120.0), ("Tcommaaccent", "O", -40.0), ("Tcommaaccent", "Oacute", -40.0), ("Tcommaaccent", "Ocircumflex", -40.0), ("Tcommaaccent", "Odieresis", -40.0), ("Tcommaaccent", "Ograve", -40.0), ("Tcommaaccent", "Ohungarumlaut", -40.0), ("Tcommaaccent", "Omacron", -40.0), ("Tcommaaccent", "Oslash", -40.0), ("Tcommaaccent", "Otilde", -40.0), ("Tcommaaccent", "a", -120.0), ("Tcommaaccent", "aacute", -120.0), ("Tcommaaccent", "abreve", -60.0), ("Tcommaaccent", "acircumflex", -120.0), ("Tcommaaccent", "adieresis", -120.0), ("Tcommaaccent", "agrave", -120.0), ("Tcommaaccent", "amacron", -60.0), ("Tcommaaccent", "aogonek", -120.0), ("Tcommaaccent", "aring", -120.0), ("Tcommaaccent", "atilde", -60.0), ("Tcommaaccent", "colon", -20.0), ("Tcommaaccent", "comma", -120.0), ("Tcommaaccent", "e", -120.0), ("Tcommaaccent", "eacute", -120.0), ("Tcommaaccent", "ecaron", -120.0), ("Tcommaaccent", "ecircumflex", -120.0), ("Tcommaaccent", "edieresis", -120.0), ("Tcommaaccent", "edotaccent", -120.0), ("Tcommaaccent", "egrave", -60.0), ("Tcommaaccent", "emacron", -60.0), ("Tcommaaccent", "eogonek", -120.0), ("Tcommaaccent", "hyphen", -140.0), ("Tcommaaccent", "o", -120.0), ("Tcommaaccent", "oacute", -120.0), ("Tcommaaccent", "ocircumflex", -120.0), ("Tcommaaccent", "odieresis", -120.0), ("Tcommaaccent", "ograve", -120.0), ("Tcommaaccent", "ohungarumlaut", -120.0), ("Tcommaaccent", "omacron", -60.0), ("Tcommaaccent", "oslash", -120.0), ("Tcommaaccent", "otilde", -60.0), ("Tcommaaccent", "period", -120.0), ("Tcommaaccent", "r", -120.0), ("Tcommaaccent", "racute", -120.0), ("Tcommaaccent", "rcaron", -120.0), ("Tcommaaccent", "rcommaaccent", -120.0), ("Tcommaaccent", "semicolon", -20.0), ("Tcommaaccent", "u", -120.0), ("Tcommaaccent", "uacute", -120.0), ("Tcommaaccent", "ucircumflex", -120.0), ("Tcommaaccent", "udieresis", -120.0), ("Tcommaaccent", "ugrave", -120.0), ("Tcommaaccent", "uhungarumlaut", -120.0), ("Tcommaaccent", "umacron", -60.0), ("Tcommaaccent", "uogonek", -120.0), ("Tcommaaccent", "uring", -120.0), ("Tcommaaccent", "w", -120.0), ("Tcommaaccent", "y", -120.0), ("Tcommaaccent", "yacute", -120.0), ("Tcommaaccent", "ydieresis", -60.0), ("U", "A", -40.0), ("U", "Aacute", -40.0), ("U", "Abreve", -40.0), ("U", "Acircumflex", -40.0), ("U", "Adieresis", -40.0), ("U", "Agrave", -40.0), ("U", "Amacron", -40.0), ("U", "Aogonek", -40.0), ("U", "Aring", -40.0), ("U", "Atilde", -40.0), ("U", "comma", -40.0), ("U", "period", -40.0), ("Uacute", "A", -40.0), ("Uacute", "Aacute", -40.0), ("Uacute", "Abreve", -40.0), ("Uacute", "Acircumflex", -40.0), ("Uacute", "Adieresis", -40.0), ("Uacute", "Agrave", -40.0), ("Uacute", "Amacron", -40.0), ("Uacute", "Aogonek", -40.0), ("Uacute", "Aring", -40.0), ("Uacute", "Atilde", -40.0), ("Uacute", "comma", -40.0), ("Uacute", "period", -40.0), ("Ucircumflex", "A", -40.0), ("Ucircumflex", "Aacute", -40.0), ("Ucircumflex", "Abreve", -40.0), ("Ucircumflex", "Acircumflex", -40.0), ("Ucircumflex", "Adieresis", -40.0), ("Ucircumflex", "Agrave", -40.0), ("Ucircumflex", "Amacron", -40.0), ("Ucircumflex", "Aogonek", -40.0), ("Ucircumflex", "Aring", -40.0), ("Ucircumflex", "Atilde", -40.0), ("Ucircumflex", "comma", -40.0), ("Ucircumflex", "period", -40.0), ("Udieresis", "A", -40.0), ("Udieresis", "Aacute", -40.0), ("Udieresis", "Abreve", -40.0), ("Udieresis", "Acircumflex", -40.0), ("Udieresis", "Adieresis", -40.0), ("Udieresis", "Agrave", -40.0), ("Udieresis", "Amacron", -40.0), ("Udieresis", "Aogonek", -40.0), ("Udieresis", "Aring", -40.0), ("Udieresis", "Atilde", -40.0), ("Udieresis", "comma", -40.0), ("Udieresis", "period", -40.0), ("Ugrave", "A", -40.0), ("Ugrave", "Aacute", -40.0), ("Ugrave", "Abreve", -40.0), ("Ugrave", "Acircumflex", -40.0), ("Ugrave", "Adieresis", -40.0), ("Ugrave", "Agrave", -40.0), ("Ugrave", "Amacron", -40.0), ("Ugrave", "Aogonek", -40.0), ("Ugrave", "Aring", -40.0), ("Ugrave", "Atilde", -40.0), ("Ugrave", "comma", -40.0), ("Ugrave", "period", -40.0), ("Uhungarumlaut", "A", -40.0), ("Uhungarumlaut", "Aacute", -40.0), ("Uhungarumlaut", "Abreve", -40.0), ("Uhungarumlaut", "Acircumflex", -40.0), ("Uhungarumlaut", "Adieresis", -40.0), ("Uhungarumlaut", "Agrave", -40.0), ("Uhungarumlaut", "Amacron", -40.0), ("Uhungarumlaut", "Aogonek", -40.0), ("Uhungarumlaut", "Aring", -40.0), ("Uhungarumlaut", "Atilde", -40.0), ("Uhungarumlaut", "comma", -40.0), ("Uhungarumlaut", "period", -40.0), ("Umacron", "A", -40.0), ("Umacron", "Aacute", -40.0), ("Umacron", "Abreve", -40.0), ("Umacron", "Acircumflex", -40.0), ("Umacron", "Adieresis", -40.0), ("Umacron", "Agrave", -40.0), ("Umacron", "Amacron", -40.0), ("Umacron", "Aogonek", -40.0), ("Umacron", "Aring", -40.0), ("Umacron", "Atilde", -40.0), ("Umacron", "comma", -40.0), ("Umacron", "period", -40.0), ("Uogonek", "A", -40.0), ("Uogonek", "Aacute", -40.0), ("Uogonek", "Abreve", -40.0), ("Uogonek", "Acircumflex", -40.0), ("Uogonek", "Adieresis", -40.0), ("Uogonek", "Agrave", -40.0), ("Uogonek", "Amacron", -40.0), ("Uogonek", "Aogonek", -40.0), ("Uogonek", "Aring", -40.0), ("Uogonek", "Atilde", -40.0), ("Uogonek", "comma", -40.0), ("Uogonek", "period", -40.0), ("Uring", "A", -40.0), ("Uring", "Aacute", -40.0), ("Uring", "Abreve", -40.0), ("Uring", "Acircumflex", -40.0), ("Uring", "Adieresis", -40.0), ("Uring", "Agrave", -40.0), ("Uring", "Amacron", -40.0), ("Uring", "Aogonek", -40.0), ("Uring", "Aring", -40.0), ("Uring", "Atilde", -40.0), ("Uring", "comma", -40.0), ("Uring", "period", -40.0), ("V", "A", -80.0), ("V", "Aacute", -80.0), ("V", "Abreve", -80.0), ("V", "Acircumflex", -80.0), ("V", "Adieresis", -80.0), ("V", "Agrave", -80.0), ("V", "Amacron", -80.0), ("V", "Aogonek", -80.0), ("V", "Aring", -80.0), ("V", "Atilde", -80.0), ("V", "G", -40.0), ("V", "Gbreve", -40.0), ("V", "Gcommaaccent", -40.0), ("V", "O", -40.0), ("V", "Oacute", -40.0), ("V", "Ocircumflex", -40.0), ("V", "Odieresis", -40.0), ("V", "Ograve", -40.0), ("V", "Ohungarumlaut", -40.0), ("V", "Omacron", -40.0), ("V", "Oslash", -40.0), ("V", "Otilde", -40.0), ("V", "a", -70.0), ("V", "aacute", -70.0), ("V", "abreve", -70.0), ("V", "acircumflex", -70.0), ("V", "adieresis", -70.0), ("V", "agrave", -70.0), ("V", "amacron", -70.0), ("V", "aogonek", -70.0), ("V", "aring", -70.0), ("V", "atilde", -70.0), ("V", "colon", -40.0), ("V", "comma", -125.0), ("V", "e", -80.0), ("V", "eacute", -80.0), ("V", "ecaron", -80.0), ("V", "ecircumflex", -80.0), ("V", "edieresis", -80.0), ("V", "edotaccent", -80.0), ("V", "egrave", -80.0), ("V", "emacron", -80.0), ("V", "eogonek", -80.0), ("V", "hyphen", -80.0), ("V", "o", -80.0), ("V", "oacute", -80.0), ("V", "ocircumflex", -80.0), ("V", "odieresis", -80.0), ("V", "ograve", -80.0), ("V", "ohungarumlaut", -80.0), ("V", "omacron", -80.0), ("V", "oslash", -80.0), ("V", "otilde", -80.0), ("V", "period", -125.0), ("V", "semicolon", -40.0), ("V", "u", -70.0), ("V", "uacute", -70.0), ("V", "ucircumflex", -70.0), ("V", "udieresis", -70.0), ("V", "ugrave", -70.0), ("V", "uhungarumlaut", -70.0), ("V", "umacron", -70.0), ("V", "uogonek", -70.0), ("V", "uring", -70.0), ("W", "A", -50.0), ("W", "Aacute", -50.0), ("W", "Abreve", -50.0), ("W", "Acircumflex", -50.0), ("W", "Adieresis", -50.0), ("W", "Agrave", -50.0), ("W", "Amacron", -50.0), ("W", "Aogonek", -50.0), ("W", "Aring", -50.0), ("W", "Atilde", -50.0), ("W", "O", -20.0), ("W", "Oacute", -20.0), ("W", "Ocircumflex", -20.0), ("W", "Odieresis", -20.0), ("W", "Ograve", -20.0), ("W", "Ohungarumlaut", -20.0), ("W", "Omacron", -20.0), ("W", "Oslash", -20.0), ("W", "Otilde", -20.0), ("W", "a", -40.0), ("W", "aacute", -40.0), ("W", "abreve", -40.0), ("W", "acircumflex", -40.0), ("W", "adieresis", -40.0), ("W", "agrave", -40.0), ("W", "amacron", -40.0), ("W", "aogonek", -40.0), ("W", "aring", -40.0), ("W", "atilde", -40.0), ("W", "comma", -80.0), ("W", "e", -30.0), ("W", "eacute", -30.0), ("W", "ecaron", -30.0), ("W", "ecircumflex", -30.0), ("W", "edieresis", -30.0), ("W", "edotaccent", -30.0), ("W", "egrave", -30.0), ("W", "emacron", -30.0), ("W", "eogonek", -30.0), ("W", "hyphen", -40.0), ("W", "o", -30.0), ("W", "oacute", -30.0), ("W", "ocircumflex", -30.0), ("W", "odieresis", -30.0), ("W", "ograve", -30.0), ("W", "ohungarumlaut", -30.0), ("W", "omacron", -30.0), ("W", "oslash", -30.0), ("W", "otilde", -30.0), ("W", "period", -80.0), ("W", "u", -30.0), ("W", "uacute", -30.0), ("W", "ucircumflex", -30.0), ("W", "udieresis", -30.0), ("W", "ugrave", -30.0), ("W", "uhungarumlaut", -30.0), ("W", "umacron", -30.0), ("W", "uogonek", -30.0), ("W", "uring", -30.0), ("W", "y", -20.0), ("W", "yacute", -20.0), ("W", "ydieresis", -20.0), ("Y", "A", -110.0), ("Y", "Aacute", -110.0), ("Y", "Abreve", -110.0), ("Y", "Acircumflex", -110.0), ("Y", "Adieresis", -110.0), ("Y", "Agrave", -110.0), ("Y", "Amacron", -110.0), ("Y", "Aogonek", -110.0), ("Y", "Aring", -110.0), ("Y", "Atilde", -110.0), ("Y", "O", -85.0), ("Y", "Oacute", -85.0), ("Y", "Ocircumflex", -85.0), ("Y", "Odieresis", -85.0), ("Y", "Ograve", -85.0), ("Y", "Ohungarumlaut", -85.0), ("Y", "Omacron", -85.0), ("Y", "Oslash", -85.0), ("Y", "Otilde", -85.0), ("Y", "a", -140.0), ("Y", "aacute", -140.0), ("Y", "abreve", -70.0), ("Y", "acircumflex", -140.0), ("Y", "adieresis", -140.0), ("Y", "agrave", -140.0), ("Y", "amacron", -70.0), ("Y", "aogonek", -140.0), ("Y", "aring", -140.0), ("Y", "atilde", -140.0), ("Y", "colon", -60.0), ("Y", "comma", -140.0), ("Y", "e", -140.0), ("Y", "eacute", -140.0), ("Y", "ecaron", -140.0), ("Y", "ecircumflex", -140.0), ("Y", "edieresis", -140.0), ("Y", "edotaccent", -140.0), ("Y", "egrave", -140.0), ("Y", "emacron", -70.0), ("Y", "eogonek", -140.0), ("Y", "hyphen", -140.0), ("Y", "i", -20.0), ("Y", "iacute", -20.0), ("Y", "iogonek", -20.0), ("Y", "o", -140.0), ("Y", "oacute", -140.0), ("Y", "ocircumflex", -140.0), ("Y", "odieresis", -140.0), ("Y", "ograve", -140.0), ("Y", "ohungarumlaut", -140.0), ("Y", "omacron", -140.0), ("Y", "oslash", -140.0), ("Y", "otilde", -140.0), ("Y", "period", -140.0), ("Y", "semicolon", -60.0), ("Y", "u", -110.0), ("Y", "uacute", -110.0), ("Y", "ucircumflex", -110.0), ("Y", "udieresis", -110.0), ("Y", "ugrave", -110.0), ("Y", "uhungarumlaut", -110.0), ("Y", "umacron", -110.0), ("Y", "uogonek", -110.0), ("Y", "uring", -110.0), ("Yacute", "A", -110.0), ("Yacute", "Aacute", -110.0), ("Yacute", "Abreve", -110.0), ("Yacute", "Acircumflex", -110.0), ("Yacute", "Adieresis", -110.0), ("Yacute", "Agrave", -110.0), ("Yacute", "Amacron", -110.0), ("Yacute", "Aogonek", -110.0), ("Yacute", "Aring", -110.0), ("Yacute", "Atilde", -110.0), ("Yacute", "O", -85.0), ("Yacute", "Oacute", -85.0), ("Yacute", "Ocircumflex", -85.0), ("Yacute", "Odieresis", -85.0), ("Yacute", "Ograve", -85.0), ("Yacute", "Ohungarumlaut", -85.0), ("Yacute", "Omacron", -85.0), ("Yacute", "Oslash", -85.0), ("Yacute", "Otilde", -85.0), ("Yacute", "a", -140.0), ("Yacute", "aacute", -140.0), ("Yacute", "abreve", -70.0), ("Yacute", "acircumflex", -140.0), ("Yacute", "adieresis", -140.0), ("Yacute", "agrave", -140.0), ("Yacute", "amacron", -70.0), ("Yacute", "aogonek", -140.0), ("Yacute", "aring", -140.0), ("Yacute", "atilde", -70.0), ("Yacute", "colon", -60.0), ("Yacute", "comma", -140.0), ("Yacute", "e", -140.0), ("Yacute", "eacute", -140.0), ("Yacute", "ecaron", -140.0), ("Yacute", "ecircumflex", -140.0), ("Yacute", "edieresis", -140.0), ("Yacute", "edotaccent", -140.0), ("Yacute", "egrave", -140.0), ("Yacute", "emacron", -70.0), ("Yacute", "eogonek", -140.0), ("Yacute", "hyphen", -140.0), ("Yacute", "i", -20.0), ("Yacute", "iacute", -20.0), ("Yacute", "iogonek", -20.0), ("Yacute", "o", -140.0), ("Yacute", "oacute", -140.0), ("Yacute", "ocircumflex", -140.0), ("Yacute", "odieresis", -140.0), ("Yacute", "ograve", -140.0), ("Yacute", "ohungarumlaut", -140.0), ("Yacute", "omacron", -70.0), ("Yacute", "oslash", -140.0), ("Yacute", "otilde", -140.0), ("Yacute", "period", -140.0), ("Yacute", "semicolon", -60.0), ("Yacute", "u", -110.0), ("Yacute", "uacute", -110.0), ("Yacute", "ucircumflex", -110.0), ("Yacute", "udieresis", -110.0), ("Yacute", "ugrave", -110.0), ("Yacute", "uhungarumlaut", -110.0), ("Yacute", "umacron", -110.0), ("Yacute", "uogonek", -110.0), ("Yacute", "uring", -110.0), ("Ydieresis", "A", -110.0), ("Ydieresis", "Aacute", -110.0), ("Ydieresis", "Abreve", -110.0), ("Ydieresis", "Acircumflex", -110.0), ("Ydieresis", "Adieresis", -110.0), ("Ydieresis", "Agrave", -110.0), ("Ydieresis", "Amacron", -110.0), ("Ydieresis", "Aogonek", -110.0), ("Ydieresis", "Aring", -110.0), ("Ydieresis", "Atilde", -110.0), ("Ydieresis", "O", -85.0), ("Ydieresis", "Oacute", -85.0), ("Ydieresis", "Ocircumflex", -85.0), ("Ydieresis", "Odieresis", -85.0), ("Ydieresis", "Ograve", -85.0), ("Ydieresis", "Ohungarumlaut", -85.0), ("Ydieresis", "Omacron", -85.0), ("Ydieresis", "Oslash", -85.0), ("Ydieresis", "Otilde", -85.0), ("Ydieresis", "a", -140.0), ("Ydieresis", "aacute", -140.0), ("Ydieresis", "abreve", -70.0), ("Ydieresis", "acircumflex", -140.0), ("Ydieresis", "adieresis", -140.0), ("Ydieresis", "agrave", -140.0), ("Ydieresis", "amacron", -70.0), ("Ydieresis", "aogonek", -140.0), ("Ydieresis", "aring", -140.0), ("Ydieresis", "atilde", -70.0), ("Ydieresis", "colon", -60.0), ("Ydieresis", "comma", -140.0), ("Ydieresis", "e", -140.0), ("Ydieresis", "eacute", -140.0), ("Ydieresis", "ecaron", -140.0), ("Ydieresis", "ecircumflex", -140.0), ("Ydieresis", "edieresis", -140.0), ("Ydieresis", "edotaccent", -140.0), ("Ydieresis", "egrave", -140.0), ("Ydieresis", "emacron", -70.0), ("Ydieresis", "eogonek", -140.0), ("Ydieresis", "hyphen", -140.0), ("Ydieresis", "i", -20.0), ("Ydieresis", "iacute", -20.0), ("Ydieresis", "iogonek", -20.0), ("Ydieresis", "o", -140.0), ("Ydieresis", "oacute", -140.0), ("Ydieresis", "ocircumflex", -140.0), ("Ydieresis", "odieresis", -140.0), ("Ydieresis", "ograve", -140.0), ("Ydieresis", "ohungarumlaut", -140.0), ("Ydieresis", "omacron", -140.0), ("Ydieresis", "oslash", -140.0), ("Ydieresis", "otilde", -140.0), ("Ydieresis", "period", -140.0), ("Ydieresis", "semicolon", -60.0), ("Ydieresis", "u", -110.0), ("Ydieresis", "uacute", -110.0), ("Ydieresis", "ucircumflex", -110.0), ("Ydieresis", "udieresis", -110.0), ("Ydieresis", "ugrave", -110.0), ("Ydieresis", "uhungarumlaut", -110.0), ("Ydieresis", "umacron", -110.0), ("Ydieresis", "uogonek", -110.0), ("Ydieresis", "uring", -110.0), ("a", "v", -20.0), ("a", "w", -20.0), ("a", "y", -30.0), ("a", "yacute", -30.0), ("a", "ydieresis", -30.0), ("aacute", "v", -20.0), ("aacute", "w", -20.0), ("aacute", "y", -30.0), ("aacute", "yacute", -30.0), ("aacute", "ydieresis", -30.0), ("abreve", "v", -20.0), ("abreve", "w", -20.0), ("abreve", "y", -30.0), ("abreve", "yacute", -30.0), ("abreve", "ydieresis", -30.0), ("acircumflex", "v", -20.0), ("acircumflex", "w", -20.0), ("acircumflex", "y", -30.0), ("acircumflex", "yacute", -30.0), ("acircumflex", "ydieresis", -30.0), ("adieresis", "v", -20.0), ("adieresis", "w", -20.0), ("adieresis", "y", -30.0), ("adieresis", "yacute", -30.0), ("adieresis", "ydieresis", -30.0), ("agrave", "v", -20.0), ("agrave", "w", -20.0), ("agrave", "y", -30.0), ("agrave", "yacute", -30.0), ("agrave", "ydieresis", -30.0), ("amacron", "v", -20.0), ("amacron", "w", -20.0), ("amacron", "y", -30.0), ("amacron", "yacute", -30.0), ("amacron", "ydieresis", -30.0), ("aogonek", "v", -20.0), ("aogonek", "w", -20.0), ("aogonek", "y", -30.0), ("aogonek", "yacute", -30.0), ("aogonek", "ydieresis", -30.0), ("aring", "v", -20.0), ("aring", "w", -20.0), ("aring", "y", -30.0), ("aring", "yacute", -30.0), ("aring", "ydieresis", -30.0), ("atilde", "v", -20.0), ("atilde", "w", -20.0), ("atilde", "y", -30.0), ("atilde", "yacute", -30.0), ("atilde", "ydieresis", -30.0), ("b", "b", -10.0), ("b", "comma", -40.0), ("b", "l", -20.0), ("b", "lacute", -20.0), ("b", "lcommaaccent", -20.0), ("b", "lslash", -20.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -20.0), ("b", "y", -20.0), ("b", "yacute", -20.0), ("b", "ydieresis", -20.0), ("c", "comma", -15.0), ("c", "k", -20.0), ("c", "kcommaaccent", -20.0), ("cacute", "comma", -15.0), ("cacute", "k", -20.0), ("cacute", "kcommaaccent", -20.0), ("ccaron", "comma", -15.0), ("ccaron", "k", -20.0), ("ccaron", "kcommaaccent", -20.0), ("ccedilla", "comma", -15.0), ("ccedilla", "k", -20.0), ("ccedilla", "kcommaaccent", -20.0), ("colon", "space", -50.0), ("comma", "quotedblright", -100.0), ("comma", "quoteright", -100.0), ("e", "comma", -15.0), ("e", "period", -15.0), ("e", "v", -30.0), ("e", "w", -20.0), ("e", "x", -30.0), ("e", "y", -20.0), ("e", "yacute", -20.0), ("e", "ydieresis", -20.0), ("eacute", "comma", -15.0), ("eacute", "period", -15.0), ("eacute", "v", -30.0), ("eacute", "w", -20.0), ("eacute", "x", -30.0), ("eacute", "y", -20.0), ("eacute", "yacute", -20.0), ("eacute", "ydieresis", -20.0), ("ecaron", "comma", -15.0), ("ecaron", "period", -15.0), ("ecaron", "v", -30.0), ("ecaron", "w", -20.0), ("ecaron", "x", -30.0), ("ecaron", "y", -20.0), ("ecaron", "yacute", -20.0), ("ecaron", "ydieresis", -20.0), ("ecircumflex", "comma", -15.0), ("ecircumflex", "period", -15.0), ("ecircumflex", "v", -30.0), ("ecircumflex", "w", -20.0), ("ecircumflex", "x", -30.0), ("ecircumflex", "y", -20.0), ("ecircumflex", "yacute", -20.0), ("ecircumflex", "ydieresis", -20.0), ("edieresis", "comma", -15.0), ("edieresis", "period", -15.0), ("edieresis", "v", -30.0), ("edieresis", "w", -20.0), ("edieresis", "x", -30.0), ("edieresis", "y", -20.0), ("edieresis", "yacute", -20.0), ("edieresis", "ydieresis", -20.0), ("edotaccent", "comma", -15.0), ("edotaccent", "period", -15.0), ("edotaccent", "v", -30.0), ("edotaccent", "w", -20.0), ("edotaccent", "x", -30.0), ("edotaccent", "y", -20.0), ("edotaccent", "yacute", -20.0), ("edotaccent", "ydieresis", -20.0), ("egrave", "comma", -15.0), ("egrave", "period", -15.0), ("egrave", "v", -30.0), ("egrave", "w", -20.0), ("egrave", "x", -30.0), ("egrave", "y", -20.0), ("egrave", "yacute", -20.0), ("egrave", "ydieresis", -20.0), ("emacron", "comma", -15.0), ("emacron", "period", -15.0), ("emacron", "v", -30.0), ("emacron", "w", -20.0), ("emacron", "x", -30.0), ("emacron", "y", -20.0), ("emacron", "yacute", -20.0), ("emacron", "ydieresis", -20.0), ("eogonek", "comma", -15.0), ("eogonek", "period", -15.0), ("eogonek", "v", -30.0), ("eogonek", "w", -20.0), ("eogonek", "x", -30.0), ("eogonek", "y", -20.0), ("eogonek", "yacute", -20.0), ("eogonek", "ydieresis", -20.0), ("f", "a", -30.0), ("f", "aacute", -30.0), ("f", "abreve", -30.0), ("f", "acircumflex", -30.0), ("f", "adieresis", -30.0), ("f", "agrave", -30.0), ("f", "amacron", -30.0), ("f", "aogonek", -30.0), ("f", "aring", -30.0), ("f", "atilde", -30.0), ("f", "comma", -30.0), ("f", "dotlessi", -28.0), ("f", "e", -30.0), ("f", "eacute", -30.0), ("f", "ecaron", -30.0), ("f", "ecircumflex", -30.0), ("f", "edieresis", -30.0), ("f", "edotaccent", -30.0), ("f", "egrave", -30.0), ("f", "emacron", -30.0), ("f", "eogonek", -30.0), ("f", "o", -30.0), ("f", "oacute", -30.0), ("f", "ocircumflex", -30.0), ("f", "odieresis", -30.0), ("f", "ograve", -30.0), ("f", "ohungarumlaut", -30.0), ("f", "omacron", -30.0), ("f", "oslash", -30.0), ("f", "otilde", -30.0), ("f", "period", -30.0), ("f", "quotedblright", 60.0), ("f", "quoteright", 50.0), ("g", "r", -10.0), ("g", "racute", -10.0), ("g", "rcaron", -10.0), ("g", "rcommaaccent", -10.0), ("gbreve", "r", -10.0), ("gbreve", "racute", -10.0), ("gbreve", "rcaron", -10.0), ("gbreve", "rcommaaccent", -10.0), ("gcommaaccent", "r", -10.0), ("gcommaaccent", "racute", -10.0), ("gcommaaccent", "rcaron", -10.0), ("gcommaaccent", "rcommaaccent", -10.0), ("h", "y", -30.0), ("h", "yacute", -30.0), ("h", "ydieresis", -30.0), ("k", "e", -20.0), ("k", "eacute", -20.0), ("k", "ecaron", -20.0), ("k", "ecircumflex", -20.0), ("k", "edieresis", -20.0), ("k", "edotaccent", -20.0), ("k", "egrave", -20.0), ("k", "emacron", -20.0), ("k", "eogonek", -20.0), ("k", "o", -20.0), ("k", "oacute", -20.0), ("k", "ocircumflex", -20.0), ("k", "odieresis", -20.0), ("k", "ograve", -20.0), ("k", "ohungarumlaut", -20.0), ("k", "omacron", -20.0), ("k", "oslash", -20.0), ("k", "otilde", -20.0), ("kcommaaccent", "e", -20.0), ("kcommaaccent", "eacute", -20.0), ("kcommaaccent", "ecaron", -20.0), ("kcommaaccent", "ecircumflex", -20.0), ("kcommaaccent", "edieresis", -20.0), ("kcommaaccent", "edotaccent", -20.0), ("kcommaaccent", "egrave", -20.0), ("kcommaaccent", "emacron", -20.0), ("kcommaaccent", "eogonek", -20.0), ("kcommaaccent", "o", -20.0), ("kcommaaccent", "oacute", -20.0), ("kcommaaccent", "ocircumflex", -20.0), ("kcommaaccent", "odieresis", -20.0), ("kcommaaccent", "ograve", -20.0), ("kcommaaccent", "ohungarumlaut", -20.0), ("kcommaaccent", "omacron", -20.0), ("kcommaaccent", "oslash", -20.0), ("kcommaaccent", "otilde", -20.0), ("m", "u", -10.0), ("m", "uacute", -10.0), ("m", "ucircumflex", -10.0), ("m", "udieresis", -10.0), ("m", "ugrave", -10.0), ("m", "uhungarumlaut", -10.0), ("m", "umacron", -10.0), ("m", "uogonek", -10.0), ("m", "uring", -10.0), ("m", "y", -15.0), ("m", "yacute", -15.0), ("m", "ydieresis", -15.0), ("n", "u", -10.0), ("n", "uacute", -10.0), ("n", "ucircumflex", -10.0), ("n", "udieresis", -10.0), ("n", "ugrave", -10.0), ("n", "uhungarumlaut", -10.0), ("n", "umacron", -10.0), ("n", "uogonek", -10.0), ("n", "uring", -10.0), ("n", "v", -20.0), ("n", "y", -15.0), ("n", "yacute", -15.0), ("n", "ydieresis", -15.0), ("nacute", "u", -10.0), ("nacute", "uacute", -10.0), ("nacute", "ucircumflex", -10.0), ("nacute", "udieresis", -10.0), ("nacute", "ugrave", -10.0), ("nacute", "uhungarumlaut", -10.0), ("nacute", "umacron", -10.0), ("nacute", "uogonek", -10.0), ("nacute", "uring", -10.0), ("nacute", "v", -20.0), ("nacute", "y", -15.0), ("nacute", "yacute", -15.0), ("nacute", "ydieresis", -15.0), ("ncaron", "u", -10.0), ("ncaron", "uacute", -10.0), ("ncaron", "ucircumflex", -10.0), ("ncaron", "udieresis", -10.0), ("ncaron", "ugrave", -10.0), ("ncaron", "uhungarumlaut", -10.0), ("ncaron", "umacron", -10.0), ("ncaron", "uogonek", -10.0), ("ncaron", "uring", -10.0), ("ncaron", "v", -20.0), ("ncaron", "y", -15.0), ("ncaron", "yacute", -15.0), ("ncaron", "ydieresis", -15.0), ("ncommaaccent", "u", -10.0), ("ncommaaccent", "uacute", -10.0), ("ncommaaccent", "ucircumflex", -10.0), ("ncommaaccent", "udieresis", -10.0), ("ncommaaccent", "ugrave", -10.0), ("ncommaaccent", "uhungarumlaut", -10.0), ("ncommaaccent", "umacron", -10.0), ("ncommaaccent", "uogonek", -10.0), ("ncommaaccent", "uring", -10.0), ("ncommaaccent", "v", -20.0), ("ncommaaccent", "y", -15.0), ("ncommaaccent", "yacute", -15.0), ("ncommaaccent", "ydieresis", -15.0), ("ntilde", "u", -10.0), ("ntilde", "uacute", -10.0), ("ntilde", "ucircumflex", -10.0), ("ntilde", "udieresis", -10.0), ("ntilde", "ugrave", -10.0), ("ntilde", "uhungarumlaut", -10.0), ("ntilde", "umacron", -10.0), ("ntilde", "uogonek", -10.0), ("ntilde", "uring", -10.0), ("ntilde", "v", -20.0), ("ntilde", "y", -15.0), ("ntilde", "yacute", -15.0), ("ntilde", "ydieresis", -15.0), ("o", "comma", -40.0), ("o", "period", -40.0), ("o", "v", -15.0), ("o", "w", -15.0), ("o", "x", -30.0), ("o", "y", -30.0), ("o", "yacute", -30.0), ("o", "ydieresis", -30.0), ("oacute", "comma", -40.0), ("oacute", "period", -40.0), ("oacute", "v", -15.0), ("oacute", "w", -15.0), ("oacute", "x", -30.0), ("oacute", "y", -30.0), ("oacute", "yacute", -30.0), ("oacute", "ydieresis", -30.0), ("ocircumflex", "comma", -40.0), ("ocircumflex", "period", -40.0), ("ocircumflex", "v", -15.0), ("ocircumflex", "w", -15.0), ("ocircumflex", "x", -30.0), ("ocircumflex", "y", -30.0), ("ocircumflex", "yacute", -30.0), ("ocircumflex", "ydieresis", -30.0), ("odieresis", "comma", -40.0), ("odieresis", "period", -40.0), ("odieresis", "v", -15.0), ("odieresis", "w", -15.0), ("odieresis", "x", -30.0), ("odieresis", "y", -30.0), ("odieresis", "yacute", -30.0), ("odieresis", "ydieresis", -30.0), ("ograve", "comma", -40.0), ("ograve", "period", -40.0), ("ograve", "v", -15.0), ("ograve", "w", -15.0), ("ograve", "x", -30.0), ("ograve", "y", -30.0), ("ograve", "yacute", -30.0), ("ograve", "ydieresis", -30.0), ("ohungarumlaut", "comma", -40.0), ("ohungarumlaut", "period", -40.0), ("ohungarumlaut", "v", -15.0), ("ohungarumlaut", "w", -15.0), ("ohungarumlaut", "x", -30.0), ("ohungarumlaut", "y", -30.0), ("ohungarumlaut", "yacute", -30.0), ("ohungarumlaut", "ydieresis", -30.0), ("omacron", "comma", -40.0), ("omacron", "period", -40.0), ("omacron", "v", -15.0), ("omacron", "w", -15.0), ("omacron", "x", -30.0), ("omacron", "y", -30.0), ("omacron", "yacute", -30.0), ("omacron", "ydieresis", -30.0), ("oslash", "a", -55.0), ("oslash", "aacute", -55.0), ("oslash", "abreve", -55.0), ("oslash", "acircumflex", -55.0), ("oslash", "adieresis", -55.0), ("oslash", "agrave", -55.0), ("oslash", "amacron", -55.0), ("oslash", "aogonek", -55.0), ("oslash", "aring", -55.0), ("oslash", "atilde", -55.0), ("oslash", "b", -55.0), ("oslash", "c", -55.0), ("oslash", "cacute", -55.0), ("oslash", "ccaron", -55.0), ("oslash", "ccedilla", -55.0), ("oslash", "comma", -95.0), ("oslash", "d", -55.0), ("oslash", "dcroat", -55.0), ("oslash", "e", -55.0), ("oslash", "eacute", -55.0), ("oslash", "ecaron", -55.0), ("oslash", "ecircumflex", -55.0), ("oslash", "edieresis", -55.0), ("oslash", "edotaccent", -55.0), ("oslash", "egrave", -55.0), ("oslash", "emacron", -55.0), ("oslash", "eogonek", -55.0), ("oslash", "f", -55.0), ("oslash", "g", -55.0), ("oslash", "gbreve", -55.0), ("oslash", "gcommaaccent", -55.0), ("oslash", "h", -55.0), ("oslash", "i", -55.0), ("oslash", "iacute", -55.0), ("oslash", "icircumflex", -55.0), ("oslash", "idieresis", -55.0), ("oslash", "igrave", -55.0), ("oslash", "imacron", -55.0), ("oslash", "iogonek", -55.0), ("oslash", "j", -55.0), ("oslash", "k", -55.0), ("oslash", "kcommaaccent", -55.0), ("oslash", "l", -55.0), ("oslash", "lacute", -55.0), ("oslash", "lcommaaccent", -55.0), ("oslash", "lslash", -55.0), ("oslash", "m", -55.0), ("oslash", "n", -55.0), ("oslash", "nacute", -55.0), ("oslash", "ncaron", -55.0), ("oslash", "ncommaaccent", -55.0), ("oslash", "ntilde", -55.0), ("oslash", "o", -55.0), ("oslash", "oacute", -55.0), ("oslash", "ocircumflex", -55.0), ("oslash", "odieresis", -55.0), ("oslash", "ograve", -55.0), ("oslash", "ohungarumlaut", -55.0), ("oslash", "omacron", -55.0), ("oslash", "oslash", -55.0), ("oslash", "otilde", -55.0), ("oslash", "p", -55.0), ("oslash", "period", -95.0), ("oslash", "q", -55.0), ("oslash", "r", -55.0), ("oslash", "racute", -55.0), ("oslash", "rcaron", -55.0), ("oslash", "rcommaaccent", -55.0), ("oslash", "s", -55.0), ("oslash", "sacute", -55.0), ("oslash", "scaron", -55.0), ("oslash", "scedilla", -55.0), ("oslash", "scommaaccent", -55.0), ("oslash", "t", -55.0), ("oslash", "tcommaaccent", -55.0), ("oslash", "u", -55.0), ("oslash", "uacute", -55.0), ("oslash", "ucircumflex", -55.0), ("oslash", "udieresis", -55.0), ("oslash", "ugrave", -55.0), ("oslash", "uhungarumlaut", -55.0), ("oslash", "umacron", -55.0), ("oslash", "uogonek", -55.0), ("oslash", "uring", -55.0), ("oslash", "v", -70.0), ("oslash", "w", -70.0), ("oslash", "x", -85.0), ("oslash", "y", -70.0), ("oslash", "yacute", -70.0), ("oslash", "ydieresis", -70.0), ("oslash", "z", -55.0), ("oslash", "zacute", -55.0), ("oslash", "zcaron", -55.0), ("oslash", "zdotaccent", -55.0), ("otilde", "comma", -40.0), ("otilde", "period", -40.0), ("otilde", "v", -15.0), ("otilde", "w", -15.0), ("otilde", "x", -30.0), ("otilde", "y", -30.0), ("otilde", "yacute", -30.0), ("otilde", "ydieresis", -30.0), ("p", "comma", -35.0), ("p", "period", -35.0), ("p", "y", -30.0), ("p", "yacute", -30.0), ("p", "ydieresis", -30.0), ("period", "quotedblright", -100.0), ("period", "quoteright", -100.0), ("period", "space", -60.0), ("quotedblright", "space", -40.0), ("quoteleft", "quoteleft", -57.0), ("quoteright", "d", -50.0), ("quoteright", "dcroat", -50.0), ("quoteright", "quoteright", -57.0), ("quoteright", "r", -50.0), ("quoteright", "racute", -50.0), ("quoteright", "rcaron", -50.0), ("quoteright", "rcommaaccent", -50.0), ("quoteright", "s", -50.0), ("quoteright", "sacute", -50.0), ("quoteright", "scaron", -50.0), ("quoteright", "scedilla", -50.0), ("quoteright", "scommaaccent", -50.0), ("quoteright", "space", -70.0), ("r", "a", -10.0), ("r", "aacute", -10.0), ("r", "abreve", -10.0), ("r", "acircumflex", -10.0), ("r", "adieresis", -10.0), ("r", "agrave", -10.0), ("r", "amacron", -10.0), ("r", "aogonek", -10.0), ("r", "aring", -10.0), ("r", "atilde", -10.0), ("r", "colon", 30.0), ("r", "comma", -50.0), ("r", "i", 15.0), ("r", "iacute", 15.0), ("r", "icircumflex", 15.0), ("r", "idieresis", 15.0), ("r", "igrave", 15.0), ("r", "imacron", 15.0), ("r", "iogonek", 15.0), ("r", "k", 15.0), ("r", "kcommaaccent", 15.0), ("r", "l", 15.0), ("r", "lacute", 15.0), ("r", "lcommaaccent", 15.0), ("r", "lslash", 15.0), ("r", "m", 25.0), ("r", "n", 25.0), ("r", "nacute", 25.0), ("r", "ncaron", 25.0), ("r", "ncommaaccent", 25.0), ("r", "ntilde", 25.0), ("r", "p", 30.0), ("r", "period", -50.0), ("r", "semicolon", 30.0), ("r", "t", 40.0), ("r", "tcommaaccent", 40.0), ("r", "u", 15.0), ("r", "uacute", 15.0), ("r", "ucircumflex", 15.0), ("r", "udieresis", 15.0), ("r", "ugrave", 15.0), ("r", "uhungarumlaut", 15.0), ("r", "umacron", 15.0), ("r", "uogonek", 15.0), ("r", "uring", 15.0), ("r", "v", 30.0), ("r", "y", 30.0), ("r", "yacute", 30.0), ("r", "ydieresis", 30.0), ("racute", "a", -10.0), ("racute", "aacute", -10.0), ("racute", "abreve", -10.0), ("racute", "acircumflex", -10.0), ("racute", "adieresis", -10.0), ("racute", "agrave", -10.0), ("racute", "amacron", -10.0), ("racute", "aogonek", -10.0), ("racute", "aring", -10.0), ("racute", "atilde", -10.0), ("racute", "colon", 30.0), ("racute", "comma", -50.0), ("racute", "i", 15.0), ("racute", "iacute", 15.0), ("racute", "icircumflex", 15.0), ("racute", "idieresis", 15.0), ("racute", "igrave", 15.0), ("racute", "imacron", 15.0), ("racute", "iogonek", 15.0), ("racute", "k", 15.0), ("racute", "kcommaaccent", 15.0), ("racute", "l", 15.0), ("racute", "lacute", 15.0), ("racute", "lcommaaccent", 15.0), ("racute", "lslash", 15.0), ("racute", "m", 25.0), ("racute", "n", 25.0), ("racute", "nacute", 25.0), ("racute", "ncaron", 25.0), ("racute", "ncommaaccent", 25.0), ("racute", "ntilde", 25.0), ("racute", "p", 30.0), ("racute", "period", -50.0), ("racute", "semicolon", 30.0), ("racute", "t", 40.0), ("racute", "tcommaaccent", 40.0), ("racute", "u", 15.0), ("racute", "uacute", 15.0), ("racute", "ucircumflex", 15.0), ("racute", "udieresis", 15.0), ("racute", "ugrave", 15.0), ("racute", "uhungarumlaut", 15.0), ("racute", "umacron", 15.0), ("racute", "uogonek", 15.0), ("racute", "uring", 15.0), ("racute", "v", 30.0), ("racute", "y", 30.0), ("racute", "yacute", 30.0), ("racute", "ydieresis", 30.0), ("rcaron", "a", -10.0), ("rcaron", "aacute", -10.0), ("rcaron", "abreve", -10.0), ("rcaron", "acircumflex", -10.0), ("rcaron", "adieresis", -10.0), ("rcaron", "agrave", -10.0), ("rcaron", "amacron", -10.0), ("rcaron", "aogonek", -10.0), ("rcaron", "aring", -10.0), ("rcaron", "atilde", -10.0), ("rcaron", "colon", 30.0), ("rcaron", "comma", -50.0), ("rcaron", "i", 15.0), ("rcaron", "iacute", 15.0), ("rcaron", "icircumflex", 15.0), ("rcaron", "idieresis", 15.0), ("rcaron", "igrave", 15.0), ("rcaron", "imacron", 15.0), ("rcaron", "iogonek", 15.0), ("rcaron", "k", 15.0), ("rcaron", "kcommaaccent", 15.0), ("rcaron", "l", 15.0), ("rcaron", "lacute", 15.0), ("rcaron", "lcommaaccent", 15.0), ("rcaron", "lslash", 15.0), ("rcaron", "m", 25.0), ("rcaron", "n", 25.0), ("rcaron", "nacute", 25.0), ("rcaron", "ncaron", 25.0), ("rcaron", "ncommaaccent", 25.0), ("rcaron", "ntilde", 25.0), ("rcaron", "p", 30.0), ("rcaron", "period", -50.0), ("rcaron", "semicolon", 30.0), ("rcaron", "t", 40.0), ("rcaron", "tcommaaccent", 40.0), ("rcaron", "u", 15.0), ("rcaron", "uacute", 15.0), ("rcaron", "ucircumflex", 15.0), ("rcaron", "udieresis", 15.0), ("rcaron", "ugrave", 15.0), ("rcaron", "uhungarumlaut", 15.0), ("rcaron", "umacron", 15.0), ("rcaron", "uogonek", 15.0), ("rcaron", "uring", 15.0), ("rcaron", "v", 30.0), ("rcaron", "y", 30.0), ("rcaron", "yacute", 30.0), ("rcaron", "ydieresis", 30.0), ("rcommaaccent", "a", -10.0), ("rcommaaccent", "aacute", -10.0), ("rcommaaccent", "abreve", -10.0), ("rcommaaccent", "acircumflex", -10.0), ("rcommaaccent", "adieresis", -10.0), ("rcommaaccent", "agrave", -10.0), ("rcommaaccent", "amacron", -10.0), ("rcommaaccent", "aogonek", -10.0), ("rcommaaccent", "aring", -10.0), ("rcommaaccent", "atilde", -10.0), ("rcommaaccent", "colon", 30.0), ("rcommaaccent", "comma", -50.0), ("rcommaaccent", "i", 15.0), ("rcommaaccent", "iacute", 15.0), ("rcommaaccent", "icircumflex", 15.0), ("rcommaaccent", "idieresis", 15.0), ("rcommaaccent", "igrave", 15.0), ("rcommaaccent", "imacron", 15.0), ("rcommaaccent", "iogonek", 15.0), ("rcommaaccent", "k", 15.0), ("rcommaaccent", "kcommaaccent", 15.0), ("rcommaaccent", "l", 15.0), ("rcommaaccent", "lacute", 15.0), ("rcommaaccent", "lcommaaccent", 15.0), ("rcommaaccent", "lslash", 15.0), ("rcommaaccent", "m", 25.0), ("rcommaaccent", "n", 25.0), ("rcommaaccent", "nacute", 25.0), ("rcommaaccent", "ncaron", 25.0), ("rcommaaccent", "ncommaaccent", 25.0), ("rcommaaccent", "ntilde", 25.0), ("rcommaaccent", "p", 30.0), ("rcommaaccent", "period", -50.0), ("rcommaaccent", "semicolon", 30.0), ("rcommaaccent", "t", 40.0), ("rcommaaccent", "tcommaaccent", 40.0), ("rcommaaccent", "u", 15.0), ("rcommaaccent", "uacute", 15.0), ("rcommaaccent", "ucircumflex", 15.0), ("rcommaaccent", "udieresis", 15.0), ("rcommaaccent", "ugrave", 15.0), ("rcommaaccent", "uhungarumlaut", 15.0), ("rcommaaccent", "umacron", 15.0), ("rcommaaccent", "uogonek", 15.0), ("rcommaaccent", "uring", 15.0), ("rcommaaccent", "v", 30.0), ("rcommaaccent", "y", 30.0), ("rcommaaccent", "yacute", 30.0), ("rcommaaccent", "ydieresis", 30.0), ("s", "comma", -15.0), ("s", "period", -15.0), ("s", "w", -30.0), ("sacute", "comma", -15.0), ("sacute", "period", -15.0), ("sacute", "w", -30.0), ("scaron", "comma", -15.0), ("scaron", "period", -15.0), ("scaron", "w", -30.0), ("scedilla", "comma", -15.0), ("scedilla", "period", -15.0), ("scedilla", "w", -30.0), ("scommaaccent", "comma", -15.0), ("scommaaccent", "period", -15.0), ("scommaaccent", "w", -30.0), ("semicolon", "space", -50.0), ("space", "T", -50.0), ("space", "Tcaron", -50.0), ("space", "Tcommaaccent", -50.0), ("space", "V", -50.0), ("space", "W", -40.0), ("space", "Y", -90.0), ("space", "Yacute", -90.0), ("space", "Ydieresis", -90.0), ("space", "quotedblleft", -30.0), ("space", "quoteleft", -60.0), ("v", "a", -25.0), ("v", "aacute", -25.0), ("v", "abreve", -25.0), ("v", "acircumflex", -25.0), ("v", "adieresis", -25.0), ("v", "agrave", -25.0), ("v", "amacron", -25.0), ("v", "aogonek", -25.0), ("v", "aring", -25.0), ("v", "atilde", -25.0), ("v", "comma", -80.0), ("v", "e", -25.0), ("v", "eacute", -25.0), ("v", "ecaron", -25.0), ("v", "ecircumflex", -25.0), ("v", "edieresis", -25.0), ("v", "edotaccent", -25.0), ("v", "egrave", -25.0), ("v", "emacron", -25.0), ("v", "eogonek", -25.0), ("v", "o", -25.0), ("v", "oacute", -25.0), ("v", "ocircumflex", -25.0), ("v", "odieresis", -25.0), ("v", "ograve", -25.0), ("v", "ohungarumlaut", -25.0), ("v", "omacron", -25.0), ("v", "oslash", -25.0), ("v", "otilde", -25.0), ("v", "period", -80.0), ("w", "a", -15.0), ("w", "aacute", -15.0), ("w", "abreve", -15.0), ("w", "acircumflex", -15.0), ("w", "adieresis", -15.0), ("w", "agrave", -15.0), ("w", "amacron", -15.0), ("w", "aogonek", -15.0), ("w", "aring", -15.0), ("w", "atilde", -15.0), ("w", "comma", -60.0), ("w", "e", -10.0), ("w", "eacute", -10.0), ("w", "ecaron", -10.0), ("w", "ecircumflex", -10.0), ("w", "edieresis", -10.0), ("w", "edotaccent", -10.0), ("w", "egrave", -10.0), ("w", "emacron", -10.0), ("w", "eogonek", -10.0), ("w", "o", -10.0), ("w", "oacute", -10.0), ("w", "ocircumflex", -10.0), ("w", "odieresis", -10.0), ("w", "ograve", -10.0), ("w", "ohungarumlaut", -10.0), ("w", "omacron", -10.0), ("w", "oslash", -10.0), ("w", "otilde", -10.0), ("w", "period", -60.0), ("x", "e", -30.0), ("x", "eacute", -30.0), ("x", "ecaron", -30.0), ("x", "ecircumflex", -30.0), ("x", "edieresis", -30.0), ("x", "edotaccent", -30.0), ("x", "egrave", -30.0), ("x", "emacron", -30.0), ("x", "eogonek", -30.0), ("y", "a", -20.0), ("y", "aacute", -20.0), ("y", "abreve", -20.0), ("y", "acircumflex", -20.0), ("y", "adieresis", -20.0), ("y", "agrave", -20.0), ("y", "amacron", -20.0), ("y", "aogonek", -20.0), ("y", "aring", -20.0), ("y", "atilde", -20.0), ("y", "comma", -100.0), ("y", "e", -20.0), ("y", "eacute", -20.0), ("y", "ecaron", -20.0), ("y", "ecircumflex", -20.0), ("y", "edieresis", -20.0), ("y", "edotaccent", -20.0), ("y", "egrave", -20.0), ("y", "emacron", -20.0), ("y", "eogonek", -20.0), ("y", "o", -20.0), ("y", "oacute", -20.0), ("y", "ocircumflex", -20.0), ("y", "odieresis", -20.0), ("y", "ograve", -20.0), ("y", "ohungarumlaut", -20.0), ("y", "omacron", -20.0), ("y", "oslash", -20.0), ("y", "otilde", -20.0), ("y", "period", -100.0), ("yacute", "a", -20.0), ("yacute", "aacute", -20.0), ("yacute", "abreve", -20.0), ("yacute", "acircumflex", -20.0), ("yacute", "adieresis", -20.0), ("yacute", "agrave", -20.0), ("yacute", "amacron", -20.0), ("yacute", "aogonek", -20.0), ("yacute", "aring", -20.0), ("yacute", "atilde", -20.0), ("yacute", "comma", -100.0), ("yacute", "e", -20.0), ("yacute", "eacute", -20.0), ("yacute", "ecaron", -20.0), ("yacute", "ecircumflex", -20.0), ("yacute", "edieresis", -20.0), ("yacute", "edotaccent", -20.0), ("yacute", "egrave", -20.0), ("yacute", "emacron", -20.0), ("yacute", "eogonek", -20.0), ("yacute", "o", -20.0), ("yacute", "oacute", -20.0), ("yacute", "ocircumflex", -20.0), ("yacute", "odieresis", -20.0), ("yacute", "ograve", -20.0), ("yacute", "ohungarumlaut", -20.0), ("yacute", "omacron", -20.0), ("yacute", "oslash", -20.0), ("yacute", "otilde", -20.0), ("yacute", "period", -100.0), ("ydieresis", "a", -20.0), ("ydieresis", "aacute", -20.0), ("ydieresis", "abreve", -20.0), ("ydieresis", "acircumflex", -20.0), ("ydieresis", "adieresis", -20.0), ("ydieresis", "agrave", -20.0), ("ydieresis", "amacron", -20.0), ("ydieresis", "aogonek", -20.0), ("ydieresis", "aring", -20.0), ("ydieresis", "atilde", -20.0), ("ydieresis", "comma", -100.0), ("ydieresis", "e", -20.0), ("ydieresis", "eacute", -20.0), ("ydieresis", "ecaron", -20.0), ("ydieresis", "ecircumflex", -20.0), ("ydieresis", "edieresis", -20.0), ("ydieresis", "edotaccent", -20.0), ("ydieresis", "egrave", -20.0), ("ydieresis", "emacron", -20.0), ("ydieresis", "eogonek", -20.0), ("ydieresis", "o", -20.0), ("ydieresis", "oacute", -20.0), ("ydieresis", "ocircumflex", -20.0), ("ydieresis", "odieresis", -20.0), ("ydieresis", "ograve", -20.0), ("ydieresis", "ohungarumlaut", -20.0), ("ydieresis", "omacron", -20.0), ("ydieresis", "oslash", -20.0), ("ydieresis", "otilde", -20.0), ("ydieresis", "period", -100.0), ("z", "e", -15.0), ("z", "eacute", -15.0), ("z", "ecaron", -15.0), ("z", "ecircumflex", -15.0), ("z", "edieresis", -15.0), ("z", "edotaccent", -15.0), ("z", "egrave", -15.0), ("z", "emacron", -15.0), ("z", "eogonek", -15.0), ("z", "o", -15.0), ("z", "oacute", -15.0), ("z", "ocircumflex", -15.0), ("z", "odieresis", -15.0), ("z", "ograve", -15.0), ("z", "ohungarumlaut", -15.0), ("z", "omacron", -15.0), ("z", "oslash", -15.0), ("z", "otilde", -15.0), ("zacute", "e", -15.0), ("zacute", "eacute", -15.0), ("zacute", "ecaron", -15.0), ("zacute", "ecircumflex", -15.0), ("zacute", "edieresis", -15.0), ("zacute", "edotaccent", -15.0), ("zacute", "egrave", -15.0), ("zacute", "emacron", -15.0), ("zacute", "eogonek", -15.0), ("zacute", "o", -15.0), ("zacute", "oacute", -15.0), ("zacute", "ocircumflex", -15.0), ("zacute", "odieresis", -15.0), ("zacute", "ograve", -15.0), ("zacute", "ohungarumlaut", -15.0), ("zacute", "omacron", -15.0), ("zacute", "oslash", -15.0), ("zacute", "otilde", -15.0), ("zcaron", "e", -15.0), ("zcaron", "eacute", -15.0), ("zcaron", "ecaron", -15.0), ("zcaron", "ecircumflex", -15.0), ("zcaron", "edieresis", -15.0), ("zcaron", "edotaccent", -15.0), ("zcaron", "egrave", -15.0), ("zcaron", "emacron", -15.0), ("zcaron", "eogonek", -15.0), ("zcaron", "o", -15.0), ("zcaron", "oacute", -15.0), ("zcaron", "ocircumflex", -15.0), ("zcaron", "odieresis", -15.0), ("zcaron", "ograve", -15.0), ("zcaron", "ohungarumlaut", -15.0), ("zcaron", "omacron", -15.0), ("zcaron", "oslash", -15.0), ("zcaron", "otilde", -15.0), ("zdotaccent", "e", -15.0), ("zdotaccent", "eacute", -15.0), ("zdotaccent", "ecaron", -15.0), ("zdotaccent", "ecircumflex", -15.0), ("zdotaccent", "edieresis", -15.0), ("zdotaccent", "edotaccent", -15.0), ("zdotaccent", "egrave", -15.0), ("zdotaccent", "emacron", -15.0), ("zdotaccent", "eogonek", -15.0), ("zdotaccent", "o", -15.0), ("zdotaccent", "oacute", -15.0), ("zdotaccent", "ocircumflex", -15.0), ("zdotaccent", "odieresis", -15.0), ("zdotaccent", "ograve", -15.0), ("zdotaccent", "ohungarumlaut", -15.0), ("zdotaccent", "omacron", -15.0), ("zdotaccent", "oslash", -15.0), ("zdotaccent", "otilde", -15.0)]),
+("Helvetica", &[("A", "C", -30.0), ("A", "Cacute", -30.0), ("A", "Ccaron", -30.0), ("A", "Ccedilla", -30.0), ("A", "G", -30.0), ("A", "Gbreve", -30.0), ("A", "Gcommaaccent", -30.0), ("A", "O", -30.0), ("A", "Oacute", -30.0), ("A", "Ocircumflex", -30.0), ("A", "Odieresis", -30.0), ("A", "Ograve", -30.0), ("A", "Ohungarumlaut", -30.0), ("A", "Omacron", -30.0), ("A", "Oslash", -30.0), ("A", "Otilde", -30.0), ("A", "Q", -30.0), ("A", "T", -120.0), ("A", "Tcaron", -120.0), ("A", "Tcommaaccent", -120.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -70.0), ("A", "W", -50.0), ("A", "Y", -100.0), ("A", "Yacute", -100.0), ("A", "Ydieresis", -100.0), ("A", "u", -30.0), ("A", "uacute", -30.0), ("A", "ucircumflex", -30.0), ("A", "udieresis", -30.0), ("A", "ugrave", -30.0), ("A", "uhungarumlaut", -30.0), ("A", "umacron", -30.0), ("A", "uogonek", -30.0), ("A", "uring", -30.0), ("A", "v", -40.0), ("A", "w", -40.0), ("A", "y", -40.0), ("A", "yacute", -40.0), ("A", "ydieresis", -40.0), ("Aacute", "C", -30.0), ("Aacute", "Cacute", -30.0), ("Aacute", "Ccaron", -30.0), ("Aacute", "Ccedilla", -30.0), ("Aacute", "G", -30.0), ("Aacute", "Gbreve", -30.0), ("Aacute", "Gcommaaccent", -30.0), ("Aacute", "O", -30.0), ("Aacute", "Oacute", -30.0), ("Aacute", "Ocircumflex", -30.0), ("Aacute", "Odieresis", -30.0), ("Aacute", "Ograve", -30.0), ("Aacute", "Ohungarumlaut", -30.0), ("Aacute", "Omacron", -30.0), ("Aacute", "Oslash", -30.0), ("Aacute", "Otilde", -30.0), ("Aacute", "Q", -30.0), ("Aacute", "T", -120.0), ("Aacute", "Tcaron", -120.0), ("Aacute", "Tcommaaccent", -120.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -70.0), ("Aacute", "W", -50.0), ("Aacute", "Y", -100.0), ("Aacute", "Yacute", -100.0), ("Aacute", "Ydieresis", -100.0), ("Aacute", "u", -30.0), ("Aacute", "uacute", -30.0), ("Aacute", "ucircumflex", -30.0), ("Aacute", "udieresis", -30.0), ("Aacute", "ugrave", -30.0), ("Aacute", "uhungarumlaut", -30.0), ("Aacute", "umacron", -30.0), ("Aacute", "uogonek", -30.0), ("Aacute", "uring", -30.0), ("Aacute", "v", -40.0), ("Aacute", "w", -40.0), ("Aacute", "y", -40.0), ("Aacute", "yacute", -40.0), ("Aacute", "ydieresis", -40.0), ("Abreve", "C", -30.0), ("Abreve", "Cacute", -30.0), ("Abreve", "Ccaron", -30.0), ("Abreve", "Ccedilla", -30.0), ("Abreve", "G", -30.0), ("Abreve", "Gbreve", -30.0), ("Abreve", "Gcommaaccent", -30.0), ("Abreve", "O", -30.0), ("Abreve", "Oacute", -30.0), ("Abreve", "Ocircumflex", -30.0), ("Abreve", "Odieresis", -30.0), ("Abreve", "Ograve", -30.0), ("Abreve", "Ohungarumlaut", -30.0), ("Abreve", "Omacron", -30.0), ("Abreve", "Oslash", -30.0), ("Abreve", "Otilde", -30.0), ("Abreve", "Q", -30.0), ("Abreve", "T", -120.0), ("Abreve", "Tcaron", -120.0), ("Abreve", "Tcommaaccent", -120.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -70.0), ("Abreve", "W", -50.0), ("Abreve", "Y", -100.0), ("Abreve", "Yacute", -100.0), ("Abreve", "Ydieresis", -100.0), ("Abreve", "u", -30.0), ("Abreve", "uacute", -30.0), ("Abreve", "ucircumflex", -30.0), ("Abreve", "udieresis", -30.0), ("Abreve", "ugrave", -30.0), ("Abreve", "uhungarumlaut", -30.0), ("Abreve", "umacron", -30.0), ("Abreve", "uogonek", -30.0), ("Abreve", "uring", -30.0), ("Abreve", "v", -40.0), ("Abreve", "w", -40.0), ("Abreve", "y", -40.0), ("Abreve", "yacute", -40.0), ("Abreve", "ydieresis", -40.0), ("Acircumflex", "C", -30.0), ("Acircumflex", "Cacute", -30.0), ("Acircumflex", "Ccaron", -30.0), ("Acircumflex", "Ccedilla", -30.0), ("Acircumflex", "G", -30.0), ("Acircumflex", "Gbreve", -30.0), ("Acircumflex", "Gcommaaccent", -30.0), ("Acircumflex", "O", -30.0), ("Acircumflex", "Oacute", -30.0), ("Acircumflex", "Ocircumflex", -30.0), ("Acircumflex", "Odieresis", -30.0), ("Acircumflex", "Ograve", -30.0), ("Acircumflex", "Ohungarumlaut", -30.0), ("Acircumflex", "Omacron", -30.0), ("Acircumflex", "Oslash", -30.0), ("Acircumflex", "Otilde", -30.0), ("Acircumflex", "Q", -30.0), ("Acircumflex", "T", -120.0), ("Acircumflex", "Tcaron", -120.0), ("Acircumflex", "Tcommaaccent", -120.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -70.0), ("Acircumflex", "W", -50.0), ("Acircumflex", "Y", -100.0), ("Acircumflex", "Yacute", -100.0), ("Acircumflex", "Ydieresis", -100.0), ("Acircumflex", "u", -30.0), ("Acircumflex", "uacute", -30.0), ("Acircumflex", "ucircumflex", -30.0), ("Acircumflex", "udieresis", -30.0), ("Acircumflex", "ugrave", -30.0), ("Acircumflex", "uhungarumlaut", -30.0), ("Acircumflex", "umacron", -30.0), ("Acircumflex", "uogonek", -30.0), ("Acircumflex", "uring", -30.0), ("Acircumflex", "v", -40.0), ("Acircumflex", "w", -40.0), ("Acircumflex", "y", -40.0), ("Acircumflex", "yacute", -40.0), ("Acircumflex", "ydieresis", -40.0), ("Adieresis", "C", -30.0), ("Adieresis", "Cacute", -30.0), ("Adieresis", "Ccaron", -30.0), ("Adieresis", "Ccedilla", -30.0), ("Adieresis", "G", -30.0), ("Adieresis", "Gbreve", -30.0), ("Adieresis", "Gcommaaccent", -30.0), ("Adieresis", "O", -30.0), ("Adieresis", "Oacute", -30.0), ("Adieresis", "Ocircumflex", -30.0), ("Adieresis", "Odieresis", -30.0), ("Adieresis", "Ograve", -30.0), ("Adieresis", "Ohungarumlaut", -30.0), ("Adieresis", "Omacron", -30.0), ("Adieresis", "Oslash", -30.0), ("Adieresis", "Otilde", -30.0), ("Adieresis", "Q", -30.0), ("Adieresis", "T", -120.0), ("Adieresis", "Tcaron", -120.0), ("Adieresis", "Tcommaaccent", -120.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -70.0), ("Adieresis", "W", -50.0), ("Adieresis", "Y", -100.0), ("Adieresis", "Yacute", -100.0), ("Adieresis", "Ydieresis", -100.0), ("Adieresis", "u", -30.0), ("Adieresis", "uacute", -30.0), ("Adieresis", "ucircumflex", -30.0), ("Adieresis", "udieresis", -30.0), ("Adieresis", "ugrave", -30.0), ("Adieresis", "uhungarumlaut", -30.0), ("Adieresis", "umacron", -30.0), ("Adieresis", "uogonek", -30.0), ("Adieresis", "uring", -30.0), ("Adieresis", "v", -40.0), ("Adieresis", "w", -40.0), ("Adieresis", "y", -40.0), ("Adieresis", "yacute", -40.0), ("Adieresis", "ydieresis", -40.0), ("Agrave", "C", -30.0), ("Agrave", "Cacute", -30.0), ("Agrave", "Ccaron", -30.0), ("Agrave", "Ccedilla", -30.0), ("Agrave", "G", -30.0), ("Agrave", "Gbreve", -30.0), ("Agrave", "Gcommaaccent", -30.0), ("Agrave", "O", -30.0), ("Agrave", "Oacute", -30.0), ("Agrave", "Ocircumflex", -30.0), ("Agrave", "Odieresis", -30.0), ("Agrave", "Ograve", -30.0), ("Agrave", "Ohungarumlaut", -30.0), ("Agrave", "Omacron", -30.0), ("Agrave", "Oslash", -30.0), ("Agrave", "Otilde", -30.0), ("Agrave", "Q", -30.0), ("Agrave", "T", -120.0), ("Agrave", "Tcaron", -120.0), ("Agrave", "Tcommaaccent", -120.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -70.0), ("Agrave", "W", -50.0), ("Agrave", "Y", -100.0), ("Agrave", "Yacute", -100.0), ("Agrave", "Ydieresis", -100.0), ("Agrave", "u", -30.0), ("Agrave", "uacute", -30.0), ("Agrave", "ucircumflex", -30.0), ("Agrave", "udieresis", -30.0), ("Agrave", "ugrave", -30.0), ("Agrave", "uhungarumlaut", -30.0), ("Agrave", "umacron", -30.0), ("Agrave", "uogonek", -30.0), ("Agrave", "uring", -30.0), ("Agrave", "v", -40.0), ("Agrave", "w", -40.0), ("Agrave", "y", -40.0), ("Agrave", "yacute", -40.0), ("Agrave", "ydieresis", -40.0), ("Amacron", "C", -30.0), ("Amacron", "Cacute", -30.0), ("Amacron", "Ccaron", -30.0), ("Amacron", "Ccedilla", -30.0), ("Amacron", "G", -30.0), ("Amacron", "Gbreve", -30.0), ("Amacron", "Gcommaaccent", -30.0), ("Amacron", "O", -30.0), ("Amacron", "Oacute", -30.0), ("Amacron", "Ocircumflex", -30.0), ("Amacron", "Odieresis", -30.0), ("Amacron", "Ograve", -30.0), ("Amacron", "Ohungarumlaut", -30.0), ("Amacron", "Omacron", -30.0), ("Amacron", "Oslash", -30.0), ("Amacron", "Otilde", -30.0), ("Amacron", "Q", -30.0), ("Amacron", "T", -120.0), ("Amacron", "Tcaron", -120.0), ("Amacron", "Tcommaaccent", -120.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -70.0), ("Amacron", "W", -50.0), ("Amacron", "Y", -100.0), ("Amacron", "Yacute", -100.0), ("Amacron", "Ydieresis", -100.0), ("Amacron", "u", -30.0), ("Amacron", "uacute", -30.0), ("Amacron", "ucircumflex", -30.0), ("Amacron", "udieresis", -30.0), ("Amacron", "ugrave", -30.0), ("Amacron", "uhungarumlaut", -30.0), ("Amacron", "umacron", -30.0), ("Amacron", "uogonek", -30.0), ("Amacron", "uring", -30.0), ("Amacron", "v", -40.0), ("Amacron", "w", -40.0), ("Amacron", "y", -40.0), ("Amacron", "yacute", -40.0), ("Amacron", "ydieresis", -40.0), ("Aogonek", "C", -30.0), ("Aogonek", "Cacute", -30.0), ("Aogonek", "Ccaron", -30.0), ("Aogonek", "Ccedilla", -30.0), ("Aogonek", "G", -30.0), ("Aogonek", "Gbreve", -30.0), ("Aogonek", "Gcommaaccent", -30.0), ("Aogonek", "O", -30.0), ("Aogonek", "Oacute", -30.0), ("Aogonek", "Ocircumflex", -30.0), ("Aogonek", "Odieresis", -30.0), ("Aogonek", "Ograve", -30.0), ("Aogonek", "Ohungarumlaut", -30.0), ("Aogonek", "Omacron", -30.0), ("Aogonek", "Oslash", -30.0), ("Aogonek", "Otilde", -30.0), ("Aogonek", "Q", -30.0), ("Aogonek", "T", -120.0), ("Aogonek", "Tcaron", -120.0), ("Aogonek", "Tcommaaccent", -120.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -70.0), ("Aogonek", "W", -50.0), ("Aogonek", "Y", -100.0), ("Aogonek", "Yacute", -100.0), ("Aogonek", "Ydieresis", -100.0), ("Aogonek", "u", -30.0), ("Aogonek", "uacute", -30.0), ("Aogonek", "ucircumflex", -30.0), ("Aogonek", "udieresis", -30.0), ("Aogonek", "ugrave", -30.0), ("Aogonek", "uhungarumlaut", -30.0), ("Aogonek", "umacron", -30.0), ("Aogonek", "uogonek", -30.0), ("Aogonek", "uring", -30.0), ("Aogonek", "v", -40.0), ("Aogonek", "w", -40.0), ("Aogonek", "y", -40.0), ("Aogonek", "yacute", -40.0), ("Aogonek", "ydieresis", -40.0), ("Aring", "C", -30.0), ("Aring", "Cacute", -30.0), ("Aring", "Ccaron", -30.0), ("Aring", "Ccedilla", -30.0), ("Aring", "G", -30.0), ("Aring", "Gbreve", -30.0), ("Aring", "Gcommaaccent", -30.0), ("Aring", "O", -30.0), ("Aring", "Oacute", -30.0), ("Aring", "Ocircumflex", -30.0), ("Aring", "Odieresis", -30.0), ("Aring", "Ograve", -30.0), ("Aring", "Ohungarumlaut", -30.0), ("Aring", "Omacron", -30.0), ("Aring", "Oslash", -30.0), ("Aring", "Otilde", -30.0), ("Aring", "Q", -30.0), ("Aring", "T", -120.0), ("Aring", "Tcaron", -120.0), ("Aring", "Tcommaaccent", -120.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -70.0), ("Aring", "W", -50.0), ("Aring", "Y", -100.0), ("Aring", "Yacute", -100.0), ("Aring", "Ydieresis", -100.0), ("Aring", "u", -30.0), ("Aring", "uacute", -30.0), ("Aring", "ucircumflex", -30.0), ("Aring", "udieresis", -30.0), ("Aring", "ugrave", -30.0), ("Aring", "uhungarumlaut", -30.0), ("Aring", "umacron", -30.0), ("Aring", "uogonek", -30.0), ("Aring", "uring", -30.0), ("Aring", "v", -40.0), ("Aring", "w", -40.0), ("Aring", "y", -40.0), ("Aring", "yacute", -40.0), ("Aring", "ydieresis", -40.0), ("Atilde", "C", -30.0), ("Atilde", "Cacute", -30.0), ("Atilde", "Ccaron", -30.0), ("Atilde", "Ccedilla", -30.0), ("Atilde", "G", -30.0), ("Atilde", "Gbreve", -30.0), ("Atilde", "Gcommaaccent", -30.0), ("Atilde", "O", -30.0), ("Atilde", "Oacute", -30.0), ("Atilde", "Ocircumflex", -30.0), ("Atilde", "Odieresis", -30.0), ("Atilde", "Ograve", -30.0), ("Atilde", "Ohungarumlaut", -30.0), ("Atilde", "Omacron", -30.0), ("Atilde", "Oslash", -30.0), ("Atilde", "Otilde", -30.0), ("Atilde", "Q", -30.0), ("Atilde", "T", -120.0), ("Atilde", "Tcaron", -120.0), ("Atilde", "Tcommaaccent", -120.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -70.0), ("Atilde", "W", -50.0), ("Atilde", "Y", -100.0), ("Atilde", "Yacute", -100.0), ("Atilde", "Ydieresis", -100.0), ("Atilde", "u", -30.0), ("Atilde", "uacute", -30.0), ("Atilde", "ucircumflex", -30.0), ("Atilde", "udieresis", -30.0), ("Atilde", "ugrave", -30.0), ("Atilde", "uhungarumlaut", -30.0), ("Atilde", "umacron", -30.0), ("Atilde", "uogonek", -30.0), ("Atilde", "uring", -30.0), ("Atilde", "v", -40.0), ("Atilde", "w", -40.0), ("Atilde", "y", -40.0), ("Atilde", "yacute", -40.0), ("Atilde", "ydieresis", -40.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("B", "comma", -20.0), ("B", "period", -20.0), ("C", "comma", -30.0), ("C", "period", -30.0), ("Cacute", "comma", -30.0), ("Cacute", "period", -30.0), ("Ccaron", "comma", -30.0), ("Ccaron", "period", -30.0), ("Ccedilla", "comma", -30.0), ("Ccedilla", "period", -30.0), ("D", "A", -40.0), ("D", "Aacute", -40.0), ("D", "Abreve", -40.0), ("D", "Acircumflex", -40.0), ("D", "Adieresis", -40.0), ("D", "Agrave", -40.0), ("D", "Amacron", -40.0), ("D", "Aogonek", -40.0), ("D", "Aring", -40.0), ("D", "Atilde", -40.0), ("D", "V", -70.0), ("D", "W", -40.0), ("D", "Y", -90.0), ("D", "Yacute", -90.0), ("D", "Ydieresis", -90.0), ("D", "comma", -70.0), ("D", "period", -70.0), ("Dcaron", "A", -40.0), ("Dcaron", "Aacute", -40.0), ("Dcaron", "Abreve", -40.0), ("Dcaron", "Acircumflex", -40.0), ("Dcaron", "Adieresis", -40.0), ("Dcaron", "Agrave", -40.0), ("Dcaron", "Amacron", -40.0), ("Dcaron", "Aogonek", -40.0), ("Dcaron", "Aring", -40.0), ("Dcaron", "Atilde", -40.0), ("Dcaron", "V", -70.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -90.0), ("Dcaron", "Yacute", -90.0), ("Dcaron", "Ydieresis", -90.0), ("Dcaron", "comma", -70.0), ("Dcaron", "period", -70.0), ("Dcroat", "A", -40.0), ("Dcroat", "Aacute", -40.0), ("Dcroat", "Abreve", -40.0), ("Dcroat", "Acircumflex", -40.0), ("Dcroat", "Adieresis", -40.0), ("Dcroat", "Agrave", -40.0), ("Dcroat", "Amacron", -40.0), ("Dcroat", "Aogonek", -40.0), ("Dcroat", "Aring", -40.0), ("Dcroat", "Atilde", -40.0), ("Dcroat", "V", -70.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -90.0), ("Dcroat", "Yacute", -90.0), ("Dcroat", "Ydieresis", -90.0), ("Dcroat", "comma", -70.0), ("Dcroat", "period", -70.0), ("F", "A", -80.0), ("F", "Aacute", -80.0), ("F", "Abreve", -80.0), ("F", "Acircumflex", -80.0), ("F", "Adieresis", -80.0), ("F", "Agrave", -80.0), ("F", "Amacron", -80.0), ("F", "Aogonek", -80.0), ("F", "Aring", -80.0), ("F", "Atilde", -80.0), ("F", "a", -50.0), ("F", "aacute", -50.0), ("F", "abreve", -50.0), ("F", "acircumflex", -50.0), ("F", "adieresis", -50.0), ("F", "agrave", -50.0), ("F", "amacron", -50.0), ("F", "aogonek", -50.0), ("F", "aring", -50.0), ("F", "atilde", -50.0), ("F", "comma", -150.0), ("F", "e", -30.0), ("F", "eacute", -30.0), ("F", "ecaron", -30.0), ("F", "ecircumflex", -30.0), ("F", "edieresis", -30.0), ("F", "edotaccent", -30.0), ("F", "egrave", -30.0), ("F", "emacron", -30.0), ("F", "eogonek", -30.0), ("F", "o", -30.0), ("F", "oacute", -30.0), ("F", "ocircumflex", -30.0), ("F", "odieresis", -30.0), ("F", "ograve", -30.0), ("F", "ohungarumlaut", -30.0), ("F", "omacron", -30.0), ("F", "oslash", -30.0), ("F", "otilde", -30.0), ("F", "period", -150.0), ("F", "r", -45.0), ("F", "racute", -45.0), ("F", "rcaron", -45.0), ("F", "rcommaaccent", -45.0), ("J", "A", -20.0), ("J", "Aacute", -20.0), ("J", "Abreve", -20.0), ("J", "Acircumflex", -20.0), ("J", "Adieresis", -20.0), ("J", "Agrave", -20.0), ("J", "Amacron", -20.0), ("J", "Aogonek", -20.0), ("J", "Aring", -20.0), ("J", "Atilde", -20.0), ("J", "a", -20.0), ("J", "aacute", -20.0), ("J", "abreve", -20.0), ("J", "acircumflex", -20.0), ("J", "adieresis", -20.0), ("J", "agrave", -20.0), ("J", "amacron", -20.0), ("J", "aogonek", -20.0), ("J", "aring", -20.0), ("J", "atilde", -20.0), ("J", "comma", -30.0), ("J", "period", -30.0), ("J", "u", -20.0), ("J", "uacute", -20.0), ("J", "ucircumflex", -20.0), ("J", "udieresis", -20.0), ("J", "ugrave", -20.0), ("J", "uhungarumlaut", -20.0), ("J", "umacron", -20.0), ("J", "uogonek", -20.0), ("J", "uring", -20.0), ("K", "O", -50.0), ("K", "Oacute", -50.0), ("K", "Ocircumflex", -50.0), ("K", "Odieresis", -50.0), ("K", "Ograve", -50.0), ("K", "Ohungarumlaut", -50.0), ("K", "Omacron", -50.0), ("K", "Oslash", -50.0), ("K", "Otilde", -50.0), ("K", "e", -40.0), ("K", "eacute", -40.0), ("K", "ecaron", -40.0), ("K", "ecircumflex", -40.0), ("K", "edieresis", -40.0), ("K", "edotaccent", -40.0), ("K", "egrave", -40.0), ("K", "emacron", -40.0), ("K", "eogonek", -40.0), ("K", "o", -40.0), ("K", "oacute", -40.0), ("K", "ocircumflex", -40.0), ("K", "odieresis", -40.0), ("K", "ograve", -40.0), ("K", "ohungarumlaut", -40.0), ("K", "omacron", -40.0), ("K", "oslash", -40.0), ("K", "otilde", -40.0), ("K", "u", -30.0), ("K", "uacute", -30.0), ("K", "ucircumflex", -30.0), ("K", "udieresis", -30.0), ("K", "ugrave", -30.0), ("K", "uhungarumlaut", -30.0), ("K", "umacron", -30.0), ("K", "uogonek", -30.0), ("K", "uring", -30.0), ("K", "y", -50.0), ("K", "yacute", -50.0), ("K", "ydieresis", -50.0), ("Kcommaaccent", "O", -50.0), ("Kcommaaccent", "Oacute", -50.0), ("Kcommaaccent", "Ocircumflex", -50.0), ("Kcommaaccent", "Odieresis", -50.0), ("Kcommaaccent", "Ograve", -50.0), ("Kcommaaccent", "Ohungarumlaut", -50.0), ("Kcommaaccent", "Omacron", -50.0), ("Kcommaaccent", "Oslash", -50.0), ("Kcommaaccent", "Otilde", -50.0), ("Kcommaaccent", "e", -40.0), ("Kcommaaccent", "eacute", -40.0), ("Kcommaaccent", "ecaron", -40.0), ("Kcommaaccent", "ecircumflex", -40.0), ("Kcommaaccent", "edieresis", -40.0), ("Kcommaaccent", "edotaccent", -40.0), ("Kcommaaccent", "egrave", -40.0), ("Kcommaaccent", "emacron", -40.0), ("Kcommaaccent", "eogonek", -40.0), ("Kcommaaccent", "o", -40.0), ("Kcommaaccent", "oacute", -40.0), ("Kcommaaccent", "ocircumflex", -40.0), ("Kcommaaccent", "odieresis", -40.0), ("Kcommaaccent", "ograve", -40.0), ("Kcommaaccent", "ohungarumlaut", -40.0), ("Kcommaaccent", "omacron", -40.0), ("Kcommaaccent", "oslash", -40.0), ("Kcommaaccent", "otilde", -40.0), ("Kcommaaccent", "u", -30.0), ("Kcommaaccent", "uacute", -30.0), ("Kcommaaccent", "ucircumflex", -30.0), ("Kcommaaccent", "udieresis", -30.0), ("Kcommaaccent", "ugrave", -30.0), ("Kcommaaccent", "uhungarumlaut", -30.0), ("Kcommaaccent", "umacron", -30.0), ("Kcommaaccent", "uogonek", -30.0), ("Kcommaaccent", "uring", -30.0), ("Kcommaaccent", "y", -50.0), ("Kcommaaccent", "yacute", -50.0), ("Kcommaaccent", "ydieresis", -50.0), ("L", "T", -110.0), ("L", "Tcaron", -110.0), ("L", "Tcommaaccent", -110.0), ("L", "V", -110.0), ("L", "W", -70.0), ("L", "Y", -140.0), ("L", "Yacute", -140.0), ("L", "Ydieresis", -140.0), ("L", "quotedblright", -140.0), ("L", "quoteright", -160.0), ("L", "y", -30.0), ("L", "yacute", -30.0), ("L", "ydieresis", -30.0), ("Lacute", "T", -110.0), ("Lacute", "Tcaron", -110.0), ("Lacute", "Tcommaaccent", -110.0), ("Lacute", "V", -110.0), ("Lacute", "W", -70.0), ("Lacute", "Y", -140.0), ("Lacute", "Yacute", -140.0), ("Lacute", "Ydieresis", -140.0), ("Lacute", "quotedblright", -140.0), ("Lacute", "quoteright", -160.0), ("Lacute", "y", -30.0), ("Lacute", "yacute", -30.0), ("Lacute", "ydieresis", -30.0), ("Lcaron", "T", -110.0), ("Lcaron", "Tcaron", -110.0), ("Lcaron", "Tcommaaccent", -110.0), ("Lcaron", "V", -110.0), ("Lcaron", "W", -70.0), ("Lcaron", "Y", -140.0), ("Lcaron", "Yacute", -140.0), ("Lcaron", "Ydieresis", -140.0), ("Lcaron", "quotedblright", -140.0), ("Lcaron", "quoteright", -160.0), ("Lcaron", "y", -30.0), ("Lcaron", "yacute", -30.0), ("Lcaron", "ydieresis", -30.0), ("Lcommaaccent", "T", -110.0), ("Lcommaaccent", "Tcaron", -110.0), ("Lcommaaccent", "Tcommaaccent", -110.0), ("Lcommaaccent", "V", -110.0), ("Lcommaaccent", "W", -70.0), ("Lcommaaccent", "Y", -140.0), ("Lcommaaccent", "Yacute", -140.0), ("Lcommaaccent", "Ydieresis", -140.0), ("Lcommaaccent", "quotedblright", -140.0), ("Lcommaaccent", "quoteright", -160.0), ("Lcommaaccent", "y", -30.0), ("Lcommaaccent", "yacute", -30.0), ("Lcommaaccent", "ydieresis", -30.0), ("Lslash", "T", -110.0), ("Lslash", "Tcaron", -110.0), ("Lslash", "Tcommaaccent", -110.0), ("Lslash", "V", -110.0), ("Lslash", "W", -70.0), ("Lslash", "Y", -140.0), ("Lslash", "Yacute", -140.0), ("Lslash", "Ydieresis", -140.0), ("Lslash", "quotedblright", -140.0), ("Lslash", "quoteright", -160.0), ("Lslash", "y", -30.0), ("Lslash", "yacute", -30.0), ("Lslash", "ydieresis", -30.0), ("O", "A", -20.0), ("O", "Aacute", -20.0), ("O", "Abreve", -20.0), ("O", "Acircumflex", -20.0), ("O", "Adieresis", -20.0), ("O", "Agrave", -20.0), ("O", "Amacron", -20.0), ("O", "Aogonek", -20.0), ("O", "Aring", -20.0), ("O", "Atilde", -20.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -30.0), ("O", "X", -60.0), ("O", "Y", -70.0), ("O", "Yacute", -70.0), ("O", "Ydieresis", -70.0), ("O", "comma", -40.0), ("O", "period", -40.0), ("Oacute", "A", -20.0), ("Oacute", "Aacute", -20.0), ("Oacute", "Abreve", -20.0), ("Oacute", "Acircumflex", -20.0), ("Oacute", "Adieresis", -20.0), ("Oacute", "Agrave", -20.0), ("Oacute", "Amacron", -20.0), ("Oacute", "Aogonek", -20.0), ("Oacute", "Aring", -20.0), ("Oacute", "Atilde", -20.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -30.0), ("Oacute", "X", -60.0), ("Oacute", "Y", -70.0), ("Oacute", "Yacute", -70.0), ("Oacute", "Ydieresis", -70.0), ("Oacute", "comma", -40.0), ("Oacute", "period", -40.0), ("Ocircumflex", "A", -20.0), ("Ocircumflex", "Aacute", -20.0), ("Ocircumflex", "Abreve", -20.0), ("Ocircumflex", "Acircumflex", -20.0), ("Ocircumflex", "Adieresis", -20.0), ("Ocircumflex", "Agrave", -20.0), ("Ocircumflex", "Amacron", -20.0), ("Ocircumflex", "Aogonek", -20.0), ("Ocircumflex", "Aring", -20.0), ("Ocircumflex", "Atilde", -20.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -30.0), ("Ocircumflex", "X", -60.0), ("Ocircumflex", "Y", -70.0), ("Ocircumflex", "Yacute", -70.0), ("Ocircumflex", "Ydieresis", -70.0), ("Ocircumflex", "comma", -40.0), ("Ocircumflex", "period", -40.0), ("Odieresis", "A", -20.0), ("Odieresis", "Aacute", -20.0), ("Odieresis", "Abreve", -20.0), ("Odieresis", "Acircumflex", -20.0), ("Odieresis", "Adieresis", -20.0), ("Odieresis", "Agrave", -20.0), ("Odieresis", "Amacron", -20.0), ("Odieresis", "Aogonek", -20.0), ("Odieresis", "Aring", -20.0), ("Odieresis", "Atilde", -20.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -30.0), ("Odieresis", "X", -60.0), ("Odieresis", "Y", -70.0), ("Odieresis", "Yacute", -70.0), ("Odieresis", "Ydieresis", -70.0), ("Odieresis", "comma", -40.0), ("Odieresis", "period", -40.0), ("Ograve", "A", -20.0), ("Ograve", "Aacute", -20.0), ("Ograve", "Abreve", -20.0), ("Ograve", "Acircumflex", -20.0), ("Ograve", "Adieresis", -20.0), ("Ograve", "Agrave", -20.0), ("Ograve", "Amacron", -20.0), ("Ograve", "Aogonek", -20.0), ("Ograve", "Aring", -20.0), ("Ograve", "Atilde", -20.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -30.0), ("Ograve", "X", -60.0), ("Ograve", "Y", -70.0), ("Ograve", "Yacute", -70.0), ("Ograve", "Ydieresis", -70.0), ("Ograve", "comma", -40.0), ("Ograve", "period", -40.0), ("Ohungarumlaut", "A", -20.0), ("Ohungarumlaut", "Aacute", -20.0), ("Ohungarumlaut", "Abreve", -20.0), ("Ohungarumlaut", "Acircumflex", -20.0), ("Ohungarumlaut", "Adieresis", -20.0), ("Ohungarumlaut", "Agrave", -20.0), ("Ohungarumlaut", "Amacron", -20.0), ("Ohungarumlaut", "Aogonek", -20.0), ("Ohungarumlaut", "Aring", -20.0), ("Ohungarumlaut", "Atilde", -20.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -30.0), ("Ohungarumlaut", "X", -60.0), ("Ohungarumlaut", "Y", -70.0), ("Ohungarumlaut", "Yacute", -70.0), ("Ohungarumlaut", "Ydieresis", -70.0), ("Ohungarumlaut", "comma", -40.0), ("Ohungarumlaut", "period", -40.0), ("Omacron", "A", -20.0), ("Omacron", "Aacute", -20.0), ("Omacron", "Abreve", -20.0), ("Omacron", "Acircumflex", -20.0), ("Omacron", "Adieresis", -20.0), ("Omacron", "Agrave", -20.0), ("Omacron", "Amacron", -20.0), ("Omacron", "Aogonek", -20.0), ("Omacron", "Aring", -20.0), ("Omacron", "Atilde", -20.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -30.0), ("Omacron", "X", -60.0), ("Omacron", "Y", -70.0), ("Omacron", "Yacute", -70.0), ("Omacron", "Ydieresis", -70.0), ("Omacron", "comma", -40.0), ("Omacron", "period", -40.0), ("Oslash", "A", -20.0), ("Oslash", "Aacute", -20.0), ("Oslash", "Abreve", -20.0), ("Oslash", "Acircumflex", -20.0), ("Oslash", "Adieresis", -20.0), ("Oslash", "Agrave", -20.0), ("Oslash", "Amacron", -20.0), ("Oslash", "Aogonek", -20.0), ("Oslash", "Aring", -20.0), ("Oslash", "Atilde", -20.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -30.0), ("Oslash", "X", -60.0), ("Oslash", "Y", -70.0), ("Oslash", "Yacute", -70.0), ("Oslash", "Ydieresis", -70.0), ("Oslash", "comma", -40.0), ("Oslash", "period", -40.0), ("Otilde", "A", -20.0), ("Otilde", "Aacute", -20.0), ("Otilde", "Abreve", -20.0), ("Otilde", "Acircumflex", -20.0), ("Otilde", "Adieresis", -20.0), ("Otilde", "Agrave", -20.0), ("Otilde", "Amacron", -20.0), ("Otilde", "Aogonek", -20.0), ("Otilde", "Aring", -20.0), ("Otilde", "Atilde", -20.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -30.0), ("Otilde", "X", -60.0), ("Otilde", "Y", -70.0), ("Otilde", "Yacute", -70.0), ("Otilde", "Ydieresis", -70.0), ("Otilde", "comma", -40.0), ("Otilde", "period", -40.0), ("P", "A", -120.0), ("P", "Aacute", -120.0), ("P", "Abreve", -120.0), ("P", "Acircumflex", -120.0), ("P", "Adieresis", -120.0), ("P", "Agrave", -120.0), ("P", "Amacron", -120.0), ("P", "Aogonek", -120.0), ("P", "Aring", -120.0), ("P", "Atilde", -120.0), ("P", "a", -40.0), ("P", "aacute", -40.0), ("P", "abreve", -40.0), ("P", "acircumflex", -40.0), ("P", "adieresis", -40.0), ("P", "agrave", -40.0), ("P", "amacron", -40.0), ("P", "aogonek", -40.0), ("P", "aring", -40.0), ("P", "atilde", -40.0), ("P", "comma", -180.0), ("P", "e", -50.0), ("P", "eacute", -50.0), ("P", "ecaron", -50.0), ("P", "ecircumflex", -50.0), ("P", "edieresis", -50.0), ("P", "edotaccent", -50.0), ("P", "egrave", -50.0), ("P", "emacron", -50.0), ("P", "eogonek", -50.0), ("P", "o", -50.0), ("P", "oacute", -50.0), ("P", "ocircumflex", -50.0), ("P", "odieresis", -50.0), ("P", "ograve", -50.0), ("P", "ohungarumlaut", -50.0), ("P", "omacron", -50.0), ("P", "oslash", -50.0), ("P", "otilde", -50.0), ("P", "period", -180.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("R", "O", -20.0), ("R", "Oacute", -20.0), ("R", "Ocircumflex", -20.0), ("R", "Odieresis", -20.0), ("R", "Ograve", -20.0), ("R", "Ohungarumlaut", -20.0), ("R", "Omacron", -20.0), ("R", "Oslash", -20.0), ("R", "Otilde", -20.0), ("R", "T", -30.0), ("R", "Tcaron", -30.0), ("R", "Tcommaaccent", -30.0), ("R", "U", -40.0), ("R", "Uacute", -40.0), ("R", "Ucircumflex", -40.0), ("R", "Udieresis", -40.0), ("R", "Ugrave", -40.0), ("R", "Uhungarumlaut", -40.0), ("R", "Umacron", -40.0), ("R", "Uogonek", -40.0), ("R", "Uring", -40.0), ("R", "V", -50.0), ("R", "W", -30.0), ("R", "Y", -50.0), ("R", "Yacute", -50.0), ("R", "Ydieresis", -50.0), ("Racute", "O", -20.0), ("Racute", "Oacute", -20.0), ("Racute", "Ocircumflex", -20.0), ("Racute", "Odieresis", -20.0), ("Racute", "Ograve", -20.0), ("Racute", "Ohungarumlaut", -20.0), ("Racute", "Omacron", -20.0), ("Racute", "Oslash", -20.0), ("Racute", "Otilde", -20.0), ("Racute", "T", -30.0), ("Racute", "Tcaron", -30.0), ("Racute", "Tcommaaccent", -30.0), ("Racute", "U", -40.0), ("Racute", "Uacute", -40.0), ("Racute", "Ucircumflex", -40.0), ("Racute", "Udieresis", -40.0), ("Racute", "Ugrave", -40.0), ("Racute", "Uhungarumlaut", -40.0), ("Racute", "Umacron", -40.0), ("Racute", "Uogonek", -40.0), ("Racute", "Uring", -40.0), ("Racute", "V", -50.0), ("Racute", "W", -30.0), ("Racute", "Y", -50.0), ("Racute", "Yacute", -50.0), ("Racute", "Ydieresis", -50.0), ("Rcaron", "O", -20.0), ("Rcaron", "Oacute", -20.0), ("Rcaron", "Ocircumflex", -20.0), ("Rcaron", "Odieresis", -20.0), ("Rcaron", "Ograve", -20.0), ("Rcaron", "Ohungarumlaut", -20.0), ("Rcaron", "Omacron", -20.0), ("Rcaron", "Oslash", -20.0), ("Rcaron", "Otilde", -20.0), ("Rcaron", "T", -30.0), ("Rcaron", "Tcaron", -30.0), ("Rcaron", "Tcommaaccent", -30.0), ("Rcaron", "U", -40.0), ("Rcaron", "Uacute", -40.0), ("Rcaron", "Ucircumflex", -40.0), ("Rcaron", "Udieresis", -40.0), ("Rcaron", "Ugrave", -40.0), ("Rcaron", "Uhungarumlaut", -40.0), ("Rcaron", "Umacron", -40.0), ("Rcaron", "Uogonek", -40.0), ("Rcaron", "Uring", -40.0), ("Rcaron", "V", -50.0), ("Rcaron", "W", -30.0), ("Rcaron", "Y", -50.0), ("Rcaron", "Yacute", -50.0), ("Rcaron", "Ydieresis", -50.0), ("Rcommaaccent", "O", -20.0), ("Rcommaaccent", "Oacute", -20.0), ("Rcommaaccent", "Ocircumflex", -20.0), ("Rcommaaccent", "Odieresis", -20.0), ("Rcommaaccent", "Ograve", -20.0), ("Rcommaaccent", "Ohungarumlaut", -20.0), ("Rcommaaccent", "Omacron", -20.0), ("Rcommaaccent", "Oslash", -20.0), ("Rcommaaccent", "Otilde", -20.0), ("Rcommaaccent", "T", -30.0), ("Rcommaaccent", "Tcaron", -30.0), ("Rcommaaccent", "Tcommaaccent", -30.0), ("Rcommaaccent", "U", -40.0), ("Rcommaaccent", "Uacute", -40.0), ("Rcommaaccent", "Ucircumflex", -40.0), ("Rcommaaccent", "Udieresis", -40.0), ("Rcommaaccent", "Ugrave", -40.0), ("Rcommaaccent", "Uhungarumlaut", -40.0), ("Rcommaaccent", "Umacron", -40.0), ("Rcommaaccent", "Uogonek", -40.0), ("Rcommaaccent", "Uring", -40.0), ("Rcommaaccent", "V", -50.0), ("Rcommaaccent", "W", -30.0), ("Rcommaaccent", "Y", -50.0), ("Rcommaaccent", "Yacute", -50.0), ("Rcommaaccent", "Ydieresis", -50.0), ("S", "comma", -20.0), ("S", "period", -20.0), ("Sacute", "comma", -20.0), ("Sacute", "period", -20.0), ("Scaron", "comma", -20.0), ("Scaron", "period", -20.0), ("Scedilla", "comma", -20.0), ("Scedilla", "period", -20.0), ("Scommaaccent", "comma", -20.0), ("Scommaaccent", "period", -20.0), ("T", "A", -120.0), ("T", "Aacute", -120.0), ("T", "Abreve", -120.0), ("T", "Acircumflex", -120.0), ("T", "Adieresis", -120.0), ("T", "Agrave", -120.0), ("T", "Amacron", -120.0), ("T", "Aogonek", -120.0), ("T", "Aring", -120.0), ("T", "Atilde", -120.0), ("T", "O", -40.0), ("T", "Oacute", -40.0), ("T", "Ocircumflex", -40.0), ("T", "Odieresis", -40.0), ("T", "Ograve", -40.0), ("T", "Ohungarumlaut", -40.0), ("T", "Omacron", -40.0), ("T", "Oslash", -40.0), ("T", "Otilde", -40.0), ("T", "a", -120.0), ("T", "aacute", -120.0), ("T", "abreve", -60.0), ("T", "acircumflex", -120.0), ("T", "adieresis", -120.0), ("T", "agrave", -120.0), ("T", "amacron", -60.0), ("T", "aogonek", -120.0), ("T", "aring", -120.0), ("T", "atilde", -60.0), ("T", "colon", -20.0), ("T", "comma", -120.0), ("T", "e", -120.0), ("T", "eacute", -120.0), ("T", "ecaron", -120.0), ("T", "ecircumflex", -120.0), ("T", "edieresis", -120.0), ("T", "edotaccent", -120.0), ("T", "egrave", -60.0), ("T", "emacron", -60.0), ("T", "eogonek", -120.0), ("T", "hyphen", -140.0), ("T", "o", -120.0), ("T", "oacute", -120.0), ("T", "ocircumflex", -120.0), ("T", "odieresis", -120.0), ("T", "ograve", -120.0), ("T", "ohungarumlaut", -120.0), ("T", "omacron", -60.0), ("T", "oslash", -120.0), ("T", "otilde", -60.0), ("T", "period", -120.0), ("T", "r", -120.0), ("T", "racute", -120.0), ("T", "rcaron", -120.0), ("T", "rcommaaccent", -120.0), ("T", "semicolon", -20.0), ("T", "u", -120.0), ("T", "uacute", -120.0), ("T", "ucircumflex", -120.0), ("T", "udieresis", -120.0), ("T", "ugrave", -120.0), ("T", "uhungarumlaut", -120.0), ("T", "umacron", -60.0), ("T", "uogonek", -120.0), ("T", "uring", -120.0), ("T", "w", -120.0), ("T", "y", -120.0), ("T", "yacute", -120.0), ("T", "ydieresis", -60.0), ("Tcaron", "A", -120.0), ("Tcaron", "Aacute", -120.0), ("Tcaron", "Abreve", -120.0), ("Tcaron", "Acircumflex", -120.0), ("Tcaron", "Adieresis", -120.0), ("Tcaron", "Agrave", -120.0), ("Tcaron", "Amacron", -120.0), ("Tcaron", "Aogonek", -120.0), ("Tcaron", "Aring", -120.0), ("Tcaron", "Atilde", -120.0), ("Tcaron", "O", -40.0), ("Tcaron", "Oacute", -40.0), ("Tcaron", "Ocircumflex", -40.0), ("Tcaron", "Odieresis", -40.0), ("Tcaron", "Ograve", -40.0), ("Tcaron", "Ohungarumlaut", -40.0), ("Tcaron", "Omacron", -40.0), ("Tcaron", "Oslash", -40.0), ("Tcaron", "Otilde", -40.0), ("Tcaron", "a", -120.0), ("Tcaron", "aacute", -120.0), ("Tcaron", "abreve", -60.0), ("Tcaron", "acircumflex", -120.0), ("Tcaron", "adieresis", -120.0), ("Tcaron", "agrave", -120.0), ("Tcaron", "amacron", -60.0), ("Tcaron", "aogonek", -120.0), ("Tcaron", "aring", -120.0), ("Tcaron", "atilde", -60.0), ("Tcaron", "colon", -20.0), ("Tcaron", "comma", -120.0), ("Tcaron", "e", -120.0), ("Tcaron", "eacute", -120.0), ("Tcaron", "ecaron", -120.0), ("Tcaron", "ecircumflex", -120.0), ("Tcaron", "edieresis", -120.0), ("Tcaron", "edotaccent", -120.0), ("Tcaron", "egrave", -60.0), ("Tcaron", "emacron", -60.0), ("Tcaron", "eogonek", -120.0), ("Tcaron", "hyphen", -140.0), ("Tcaron", "o", -120.0), ("Tcaron", "oacute", -120.0), ("Tcaron", "ocircumflex", -120.0), ("Tcaron", "odieresis", -120.0), ("Tcaron", "ograve", -120.0), ("Tcaron", "ohungarumlaut", -120.0), ("Tcaron", "omacron", -60.0), ("Tcaron", "oslash", -120.0), ("Tcaron", "otilde", -60.0), ("Tcaron", "period", -120.0), ("Tcaron", "r", -120.0), ("Tcaron", "racute", -120.0), ("Tcaron", "rcaron", -120.0), ("Tcaron", "rcommaaccent", -120.0), ("Tcaron", "semicolon", -20.0), ("Tcaron", "u", -120.0), ("Tcaron", "uacute", -120.0), ("Tcaron", "ucircumflex", -120.0), ("Tcaron", "udieresis", -120.0), ("Tcaron", "ugrave", -120.0), ("Tcaron", "uhungarumlaut", -120.0), ("Tcaron", "umacron", -60.0), ("Tcaron", "uogonek", -120.0), ("Tcaron", "uring", -120.0), ("Tcaron", "w", -120.0), ("Tcaron", "y", -120.0), ("Tcaron", "yacute", -120.0), ("Tcaron", "ydieresis", -60.0), ("Tcommaaccent", "A", -120.0), ("Tcommaaccent", "Aacute", -120.0), ("Tcommaaccent", "Abreve", -120.0), ("Tcommaaccent", "Acircumflex", -120.0), ("Tcommaaccent", "Adieresis", -120.0), ("Tcommaaccent", "Agrave", -120.0), ("Tcommaaccent", "Amacron", -120.0), ("Tcommaaccent", "Aogonek", -120.0), ("Tcommaaccent", "Aring", -120.0), ("Tcommaaccent", "Atilde", -120.0), ("Tcommaaccent", "O", -40.0), ("Tcommaaccent", "Oacute", -40.0), ("Tcommaaccent", "Ocircumflex", -40.0), ("Tcommaaccent", "Odieresis", -40.0), ("Tcommaaccent", "Ograve", -40.0), ("Tcommaaccent", "Ohungarumlaut", -40.0), ("Tcommaaccent", "Omacron", -40.0), ("Tcommaaccent", "Oslash", -40.0), ("Tcommaaccent", "Otilde", -40.0), ("Tcommaaccent", "a", -120.0), ("Tcommaaccent", "aacute", -120.0), ("Tcommaaccent", "abreve", -60.0), ("Tcommaaccent", "acircumflex", -120.0), ("Tcommaaccent", "adieresis", -120.0), ("Tcommaaccent", "agrave", -120.0), ("Tcommaaccent", "amacron", -60.0), ("Tcommaaccent", "aogonek", -120.0), ("Tcommaaccent", "aring", -120.0), ("Tcommaaccent", "atilde", -60.0), ("Tcommaaccent", "colon", -20.0), ("Tcommaaccent", "comma", -120.0), ("Tcommaaccent", "e", -120.0), ("Tcommaaccent", "eacute", -120.0), ("Tcommaaccent", "ecaron", -120.0), ("Tcommaaccent", "ecircumflex", -120.0), ("Tcommaaccent", "edieresis", -120.0), ("Tcommaaccent", "edotaccent", -120.0), ("Tcommaaccent", "egrave", -60.0), ("Tcommaaccent", "emacron", -60.0), ("Tcommaaccent", "eogonek", -120.0), ("Tcommaaccent", "hyphen", -140.0), ("Tcommaaccent", "o", -120.0), ("Tcommaaccent", "oacute", -120.0), ("Tcommaaccent", "ocircumflex", -120.0), ("Tcommaaccent", "odieresis", -120.0), ("Tcommaaccent", "ograve", -120.0), ("Tcommaaccent", "ohungarumlaut", -120.0), ("Tcommaaccent", "omacron", -60.0), ("Tcommaaccent", "oslash", -120.0), ("Tcommaaccent", "otilde", -60.0), ("Tcommaaccent", "period", -120.0), ("Tcommaaccent", "r", -120.0), ("Tcommaaccent", "racute", -120.0), ("Tcommaaccent", "rcaron", -120.0), ("Tcommaaccent", "rcommaaccent", -120.0), ("Tcommaaccent", "semicolon", -20.0), ("Tcommaaccent", "u", -120.0), ("Tcommaaccent", "uacute", -120.0), ("Tcommaaccent", "ucircumflex", -120.0), ("Tcommaaccent", "udieresis", -120.0), ("Tcommaaccent", "ugrave", -120.0), ("Tcommaaccent", "uhungarumlaut", -120.0), ("Tcommaaccent", "umacron", -60.0), ("Tcommaaccent", "uogonek", -120.0), ("Tcommaaccent", "uring", -120.0), ("Tcommaaccent", "w", -120.0), ("Tcommaaccent", "y", -120.0), ("Tcommaaccent", "yacute", -120.0), ("Tcommaaccent", "ydieresis", -60.0), ("U", "A", -40.0), ("U", "Aacute", -40.0), ("U", "Abreve", -40.0), ("U", "Acircumflex", -40.0), ("U", "Adieresis", -40.0), ("U", "Agrave", -40.0), ("U", "Amacron", -40.0), ("U", "Aogonek", -40.0), ("U", "Aring", -40.0), ("U", "Atilde", -40.0), ("U", "comma", -40.0), ("U", "period", -40.0), ("Uacute", "A", -40.0), ("Uacute", "Aacute", -40.0), ("Uacute", "Abreve", -40.0), ("Uacute", "Acircumflex", -40.0), ("Uacute", "Adieresis", -40.0), ("Uacute", "Agrave", -40.0), ("Uacute", "Amacron", -40.0), ("Uacute", "Aogonek", -40.0), ("Uacute", "Aring", -40.0), ("Uacute", "Atilde", -40.0), ("Uacute", "comma", -40.0), ("Uacute", "period", -40.0), ("Ucircumflex", "A", -40.0), ("Ucircumflex", "Aacute", -40.0), ("Ucircumflex", "Abreve", -40.0), ("Ucircumflex", "Acircumflex", -40.0), ("Ucircumflex", "Adieresis", -40.0), ("Ucircumflex", "Agrave", -40.0), ("Ucircumflex", "Amacron", -40.0), ("Ucircumflex", "Aogonek", -40.0), ("Ucircumflex", "Aring", -40.0), ("Ucircumflex", "Atilde", -40.0), ("Ucircumflex", "comma", -40.0), ("Ucircumflex", "period", -40.0), ("Udieresis", "A", -40.0), ("Udieresis", "Aacute", -40.0), ("Udieresis", "Abreve", -40.0), ("Udieresis", "Acircumflex", -40.0), ("Udieresis", "Adieresis", -40.0), ("Udieresis", "Agrave", -40.0), ("Udieresis", "Amacron", -40.0), ("Udieresis", "Aogonek", -40.0), ("Udieresis", "Aring", -40.0), ("Udieresis", "Atilde", -40.0), ("Udieresis", "comma", -40.0), ("Udieresis", "period", -40.0), ("Ugrave", "A", -40.0), ("Ugrave", "Aacute", -40.0), ("Ugrave", "Abreve", -40.0), ("Ugrave", "Acircumflex", -40.0), ("Ugrave", "Adieresis", -40.0), ("Ugrave", "Agrave", -40.0), ("Ugrave", "Amacron", -40.0), ("Ugrave", "Aogonek", -40.0), ("Ugrave", "Aring", -40.0), ("Ugrave", "Atilde", -40.0), ("Ugrave", "comma", -40.0), ("Ugrave", "period", -40.0), ("Uhungarumlaut", "A", -40.0), ("Uhungarumlaut", "Aacute", -40.0), ("Uhungarumlaut", "Abreve", -40.0), ("Uhungarumlaut", "Acircumflex", -40.0), ("Uhungarumlaut", "Adieresis", -40.0), ("Uhungarumlaut", "Agrave", -40.0), ("Uhungarumlaut", "Amacron", -40.0), ("Uhungarumlaut", "Aogonek", -40.0), ("Uhungarumlaut", "Aring", -40.0), ("Uhungarumlaut", "Atilde", -40.0), ("Uhungarumlaut", "comma", -40.0), ("Uhungarumlaut", "period", -40.0), ("Umacron", "A", -40.0), ("Umacron", "Aacute", -40.0), ("Umacron", "Abreve", -40.0), ("Umacron", "Acircumflex", -40.0), ("Umacron", "Adieresis", -40.0), ("Umacron", "Agrave", -40.0), ("Umacron", "Amacron", -40.0), ("Umacron", "Aogonek", -40.0), ("Umacron", "Aring", -40.0), ("Umacron", "Atilde", -40.0), ("Umacron", "comma", -40.0), ("Umacron", "period", -40.0), ("Uogonek", "A", -40.0), ("Uogonek", "Aacute", -40.0), ("Uogonek", "Abreve", -40.0), ("Uogonek", "Acircumflex", -40.0), ("Uogonek", "Adieresis", -40.0), ("Uogonek", "Agrave", -40.0), ("Uogonek", "Amacron", -40.0), ("Uogonek", "Aogonek", -40.0), ("Uogonek", "Aring", -40.0), ("Uogonek", "Atilde", -40.0), ("Uogonek", "comma", -40.0), ("Uogonek", "period", -40.0), ("Uring", "A", -40.0), ("Uring", "Aacute", -40.0), ("Uring", "Abreve", -40.0), ("Uring", "Acircumflex", -40.0), ("Uring", "Adieresis", -40.0), ("Uring", "Agrave", -40.0), ("Uring", "Amacron", -40.0), ("Uring", "Aogonek", -40.0), ("Uring", "Aring", -40.0), ("Uring", "Atilde", -40.0), ("Uring", "comma", -40.0), ("Uring", "period", -40.0), ("V", "A", -80.0), ("V", "Aacute", -80.0), ("V", "Abreve", -80.0), ("V", "Acircumflex", -80.0), ("V", "Adieresis", -80.0), ("V", "Agrave", -80.0), ("V", "Amacron", -80.0), ("V", "Aogonek", -80.0), ("V", "Aring", -80.0), ("V", "Atilde", -80.0), ("V", "G", -40.0), ("V", "Gbreve", -40.0), ("V", "Gcommaaccent", -40.0), ("V", "O", -40.0), ("V", "Oacute", -40.0), ("V", "Ocircumflex", -40.0), ("V", "Odieresis", -40.0), ("V", "Ograve", -40.0), ("V", "Ohungarumlaut", -40.0), ("V", "Omacron", -40.0), ("V", "Oslash", -40.0), ("V", "Otilde", -40.0), ("V", "a", -70.0), ("V", "aacute", -70.0), ("V", "abreve", -70.0), ("V", "acircumflex", -70.0), ("V", "adieresis", -70.0), ("V", "agrave", -70.0), ("V", "amacron", -70.0), ("V", "aogonek", -70.0), ("V", "aring", -70.0), ("V", "atilde", -70.0), ("V", "colon", -40.0), ("V", "comma", -125.0), ("V", "e", -80.0), ("V", "eacute", -80.0), ("V", "ecaron", -80.0), ("V", "ecircumflex", -80.0), ("V", "edieresis", -80.0), ("V", "edotaccent", -80.0), ("V", "egrave", -80.0), ("V", "emacron", -80.0), ("V", "eogonek", -80.0), ("V", "hyphen", -80.0), ("V", "o", -80.0), ("V", "oacute", -80.0), ("V", "ocircumflex", -80.0), ("V", "odieresis", -80.0), ("V", "ograve", -80.0), ("V", "ohungarumlaut", -80.0), ("V", "omacron", -80.0), ("V", "oslash", -80.0), ("V", "otilde", -80.0), ("V", "period", -125.0), ("V", "semicolon", -40.0), ("V", "u", -70.0), ("V", "uacute", -70.0), ("V", "ucircumflex", -70.0), ("V", "udieresis", -70.0), ("V", "ugrave", -70.0), ("V", "uhungarumlaut", -70.0), ("V", "umacron", -70.0), ("V", "uogonek", -70.0), ("V", "uring", -70.0), ("W", "A", -50.0), ("W", "Aacute", -50.0), ("W", "Abreve", -50.0), ("W", "Acircumflex", -50.0), ("W", "Adieresis", -50.0), ("W", "Agrave", -50.0), ("W", "Amacron", -50.0), ("W", "Aogonek", -50.0), ("W", "Aring", -50.0), ("W", "Atilde", -50.0), ("W", "O", -20.0), ("W", "Oacute", -20.0), ("W", "Ocircumflex", -20.0), ("W", "Odieresis", -20.0), ("W", "Ograve", -20.0), ("W", "Ohungarumlaut", -20.0), ("W", "Omacron", -20.0), ("W", "Oslash", -20.0), ("W", "Otilde", -20.0), ("W", "a", -40.0), ("W", "aacute", -40.0), ("W", "abreve", -40.0), ("W", "acircumflex", -40.0), ("W", "adieresis", -40.0), ("W", "agrave", -40.0), ("W", "amacron", -40.0), ("W", "aogonek", -40.0), ("W", "aring", -40.0), ("W", "atilde", -40.0), ("W", "comma", -80.0), ("W", "e", -30.0), ("W", "eacute", -30.0), ("W", "ecaron", -30.0), ("W", "ecircumflex", -30.0), ("W", "edieresis", -30.0), ("W", "edotaccent", -30.0), ("W", "egrave", -30.0), ("W", "emacron", -30.0), ("W", "eogonek", -30.0), ("W", "hyphen", -40.0), ("W", "o", -30.0), ("W", "oacute", -30.0), ("W", "ocircumflex", -30.0), ("W", "odieresis", -30.0), ("W", "ograve", -30.0), ("W", "ohungarumlaut", -30.0), ("W", "omacron", -30.0), ("W", "oslash", -30.0), ("W", "otilde", -30.0), ("W", "period", -80.0), ("W", "u", -30.0), ("W", "uacute", -30.0), ("W", "ucircumflex", -30.0), ("W", "udieresis", -30.0), ("W", "ugrave", -30.0), ("W", "uhungarumlaut", -30.0), ("W", "umacron", -30.0), ("W", "uogonek", -30.0), ("W", "uring", -30.0), ("W", "y", -20.0), ("W", "yacute", -20.0), ("W", "ydieresis", -20.0), ("Y", "A", -110.0), ("Y", "Aacute", -110.0), ("Y", "Abreve", -110.0), ("Y", "Acircumflex", -110.0), ("Y", "Adieresis", -110.0), ("Y", "Agrave", -110.0), ("Y", "Amacron", -110.0), ("Y", "Aogonek", -110.0), ("Y", "Aring", -110.0), ("Y", "Atilde", -110.0), ("Y", "O", -85.0), ("Y", "Oacute", -85.0), ("Y", "Ocircumflex", -85.0), ("Y", "Odieresis", -85.0), ("Y", "Ograve", -85.0), ("Y", "Ohungarumlaut", -85.0), ("Y", "Omacron", -85.0), ("Y", "Oslash", -85.0), ("Y", "Otilde", -85.0), ("Y", "a", -140.0), ("Y", "aacute", -140.0), ("Y", "abreve", -70.0), ("Y", "acircumflex", -140.0), ("Y", "adieresis", -140.0), ("Y", "agrave", -140.0), ("Y", "amacron", -70.0), ("Y", "aogonek", -140.0), ("Y", "aring", -140.0), ("Y", "atilde", -140.0), ("Y", "colon", -60.0), ("Y", "comma", -140.0), ("Y", "e", -140.0), ("Y", "eacute", -140.0), ("Y", "ecaron", -140.0), ("Y", "ecircumflex", -140.0), ("Y", "edieresis", -140.0), ("Y", "edotaccent", -140.0), ("Y", "egrave", -140.0), ("Y", "emacron", -70.0), ("Y", "eogonek", -140.0), ("Y", "hyphen", -140.0), ("Y", "i", -20.0), ("Y", "iacute", -20.0), ("Y", "iogonek", -20.0), ("Y", "o", -140.0), ("Y", "oacute", -140.0), ("Y", "ocircumflex", -140.0), ("Y", "odieresis", -140.0), ("Y", "ograve", -140.0), ("Y", "ohungarumlaut", -140.0), ("Y", "omacron", -140.0), ("Y", "oslash", -140.0), ("Y", "otilde", -140.0), ("Y", "period", -140.0), ("Y", "semicolon", -60.0), ("Y", "u", -110.0), ("Y", "uacute", -110.0), ("Y", "ucircumflex", -110.0), ("Y", "udieresis", -110.0), ("Y", "ugrave", -110.0), ("Y", "uhungarumlaut", -110.0), ("Y", "umacron", -110.0), ("Y", "uogonek", -110.0), ("Y", "uring", -110.0), ("Yacute", "A", -110.0), ("Yacute", "Aacute", -110.0), ("Yacute", "Abreve", -110.0), ("Yacute", "Acircumflex", -110.0), ("Yacute", "Adieresis", -110.0), ("Yacute", "Agrave", -110.0), ("Yacute", "Amacron", -110.0), ("Yacute", "Aogonek", -110.0), ("Yacute", "Aring", -110.0), ("Yacute", "Atilde", -110.0), ("Yacute", "O", -85.0), ("Yacute", "Oacute", -85.0), ("Yacute", "Ocircumflex", -85.0), ("Yacute", "Odieresis", -85.0), ("Yacute", "Ograve", -85.0), ("Yacute", "Ohungarumlaut", -85.0), ("Yacute", "Omacron", -85.0), ("Yacute", "Oslash", -85.0), ("Yacute", "Otilde", -85.0), ("Yacute", "a", -140.0), ("Yacute", "aacute", -140.0), ("Yacute", "abreve", -70.0), ("Yacute", "acircumflex", -140.0), ("Yacute", "adieresis", -140.0), ("Yacute", "agrave", -140.0), ("Yacute", "amacron", -70.0), ("Yacute", "aogonek", -140.0), ("Yacute", "aring", -140.0), ("Yacute", "atilde", -70.0), ("Yacute", "colon", -60.0), ("Yacute", "comma", -140.0), ("Yacute", "e", -140.0), ("Yacute", "eacute", -140.0), ("Yacute", "ecaron", -140.0), ("Yacute", "ecircumflex", -140.0), ("Yacute", "edieresis", -140.0), ("Yacute", "edotaccent", -140.0), ("Yacute", "egrave", -140.0), ("Yacute", "emacron", -70.0), ("Yacute", "eogonek", -140.0), ("Yacute", "hyphen", -140.0), ("Yacute", "i", -20.0), ("Yacute", "iacute", -20.0), ("Yacute", "iogonek", -20.0), ("Yacute", "o", -140.0), ("Yacute", "oacute", -140.0), ("Yacute", "ocircumflex", -140.0), ("Yacute", "odieresis", -140.0), ("Yacute", "ograve", -140.0), ("Yacute", "ohungarumlaut", -140.0), ("Yacute", "omacron", -70.0), ("Yacute", "oslash", -140.0), ("Yacute", "otilde", -140.0), ("Yacute", "period", -140.0), ("Yacute", "semicolon", -60.0), ("Yacute", "u", -110.0), ("Yacute", "uacute", -110.0), ("Yacute", "ucircumflex", -110.0), ("Yacute", "udieresis", -110.0), ("Yacute", "ugrave", -110.0), ("Yacute", "uhungarumlaut", -110.0), ("Yacute", "umacron", -110.0), ("Yacute", "uogonek", -110.0), ("Yacute", "uring", -110.0), ("Ydieresis", "A", -110.0), ("Ydieresis", "Aacute", -110.0), ("Ydieresis", "Abreve", -110.0), ("Ydieresis", "Acircumflex", -110.0), ("Ydieresis", "Adieresis", -110.0), ("Ydieresis", "Agrave", -110.0), ("Ydieresis", "Amacron", -110.0), ("Ydieresis", "Aogonek", -110.0), ("Ydieresis", "Aring", -110.0), ("Ydieresis", "Atilde", -110.0), ("Ydieresis", "O", -85.0), ("Ydieresis", "Oacute", -85.0), ("Ydieresis", "Ocircumflex", -85.0), ("Ydieresis", "Odieresis", -85.0), ("Ydieresis", "Ograve", -85.0), ("Ydieresis", "Ohungarumlaut", -85.0), ("Ydieresis", "Omacron", -85.0), ("Ydieresis", "Oslash", -85.0), ("Ydieresis", "Otilde", -85.0), ("Ydieresis", "a", -140.0), ("Ydieresis", "aacute", -140.0), ("Ydieresis", "abreve", -70.0), ("Ydieresis", "acircumflex", -140.0), ("Ydieresis", "adieresis", -140.0), ("Ydieresis", "agrave", -140.0), ("Ydieresis", "amacron", -70.0), ("Ydieresis", "aogonek", -140.0), ("Ydieresis", "aring", -140.0), ("Ydieresis", "atilde", -70.0), ("Ydieresis", "colon", -60.0), ("Ydieresis", "comma", -140.0), ("Ydieresis", "e", -140.0), ("Ydieresis", "eacute", -140.0), ("Ydieresis", "ecaron", -140.0), ("Ydieresis", "ecircumflex", -140.0), ("Ydieresis", "edieresis", -140.0), ("Ydieresis", "edotaccent", -140.0), ("Ydieresis", "egrave", -140.0), ("Ydieresis", "emacron", -70.0), ("Ydieresis", "eogonek", -140.0), ("Ydieresis", "hyphen", -140.0), ("Ydieresis", "i", -20.0), ("Ydieresis", "iacute", -20.0), ("Ydieresis", "iogonek", -20.0), ("Ydieresis", "o", -140.0), ("Ydieresis", "oacute", -140.0), ("Ydieresis", "ocircumflex", -140.0), ("Ydieresis", "odieresis", -140.0), ("Ydieresis", "ograve", -140.0), ("Ydieresis", "ohungarumlaut", -140.0), ("Ydieresis", "omacron", -140.0), ("Ydieresis", "oslash", -140.0), ("Ydieresis", "otilde", -140.0), ("Ydieresis", "period", -140.0), ("Ydieresis", "semicolon", -60.0), ("Ydieresis", "u", -110.0), ("Ydieresis", "uacute", -110.0), ("Ydieresis", "ucircumflex", -110.0), ("Ydieresis", "udieresis", -110.0), ("Ydieresis", "ugrave", -110.0), ("Ydieresis", "uhungarumlaut", -110.0), ("Ydieresis", "umacron", -110.0), ("Ydieresis", "uogonek", -110.0), ("Ydieresis", "uring", -110.0), ("a", "v", -20.0), ("a", "w", -20.0), ("a", "y", -30.0), ("a", "yacute", -30.0), ("a", "ydieresis", -30.0), ("aacute", "v", -20.0), ("aacute", "w", -20.0), ("aacute", "y", -30.0), ("aacute", "yacute", -30.0), ("aacute", "ydieresis", -30.0), ("abreve", "v", -20.0), ("abreve", "w", -20.0), ("abreve", "y", -30.0), ("abreve", "yacute", -30.0), ("abreve", "ydieresis", -30.0), ("acircumflex", "v", -20.0), ("acircumflex", "w", -20.0), ("acircumflex", "y", -30.0), ("acircumflex", "yacute", -30.0), ("acircumflex", "ydieresis", -30.0), ("adieresis", "v", -20.0), ("adieresis", "w", -20.0), ("adieresis", "y", -30.0), ("adieresis", "yacute", -30.0), ("adieresis", "ydieresis", -30.0), ("agrave", "v", -20.0), ("agrave", "w", -20.0), ("agrave", "y", -30.0), ("agrave", "yacute", -30.0), ("agrave", "ydieresis", -30.0), ("amacron", "v", -20.0), ("amacron", "w", -20.0), ("amacron", "y", -30.0), ("amacron", "yacute", -30.0), ("amacron", "ydieresis", -30.0), ("aogonek", "v", -20.0), ("aogonek", "w", -20.0), ("aogonek", "y", -30.0), ("aogonek", "yacute", -30.0), ("aogonek", "ydieresis", -30.0), ("aring", "v", -20.0), ("aring", "w", -20.0), ("aring", "y", -30.0), ("aring", "yacute", -30.0), ("aring", "ydieresis", -30.0), ("atilde", "v", -20.0), ("atilde", "w", -20.0), ("atilde", "y", -30.0), ("atilde", "yacute", -30.0), ("atilde", "ydieresis", -30.0), ("b", "b", -10.0), ("b", "comma", -40.0), ("b", "l", -20.0), ("b", "lacute", -20.0), ("b", "lcommaaccent", -20.0), ("b", "lslash", -20.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -20.0), ("b", "y", -20.0), ("b", "yacute", -20.0), ("b", "ydieresis", -20.0), ("c", "comma", -15.0), ("c", "k", -20.0), ("c", "kcommaaccent", -20.0), ("cacute", "comma", -15.0), ("cacute", "k", -20.0), ("cacute", "kcommaaccent", -20.0), ("ccaron", "comma", -15.0), ("ccaron", "k", -20.0), ("ccaron", "kcommaaccent", -20.0), ("ccedilla", "comma", -15.0), ("ccedilla", "k", -20.0), ("ccedilla", "kcommaaccent", -20.0), ("colon", "space", -50.0), ("comma", "quotedblright", -100.0), ("comma", "quoteright", -100.0), ("e", "comma", -15.0), ("e", "period", -15.0), ("e", "v", -30.0), ("e", "w", -20.0), ("e", "x", -30.0), ("e", "y", -20.0), ("e", "yacute", -20.0), ("e", "ydieresis", -20.0), ("eacute", "comma", -15.0), ("eacute", "period", -15.0), ("eacute", "v", -30.0), ("eacute", "w", -20.0), ("eacute", "x", -30.0), ("eacute", "y", -20.0), ("eacute", "yacute", -20.0), ("eacute", "ydieresis", -20.0), ("ecaron", "comma", -15.0), ("ecaron", "period", -15.0), ("ecaron", "v", -30.0), ("ecaron", "w", -20.0), ("ecaron", "x", -30.0), ("ecaron", "y", -20.0), ("ecaron", "yacute", -20.0), ("ecaron", "ydieresis", -20.0), ("ecircumflex", "comma", -15.0), ("ecircumflex", "period", -15.0), ("ecircumflex", "v", -30.0), ("ecircumflex", "w", -20.0), ("ecircumflex", "x", -30.0), ("ecircumflex", "y", -20.0), ("ecircumflex", "yacute", -20.0), ("ecircumflex", "ydieresis", -20.0), ("edieresis", "comma", -15.0), ("edieresis", "period", -15.0), ("edieresis", "v", -30.0), ("edieresis", "w", -20.0), ("edieresis", "x", -30.0), ("edieresis", "y", -20.0), ("edieresis", "yacute", -20.0), ("edieresis", "ydieresis", -20.0), ("edotaccent", "comma", -15.0), ("edotaccent", "period", -15.0), ("edotaccent", "v", -30.0), ("edotaccent", "w", -20.0), ("edotaccent", "x", -30.0), ("edotaccent", "y", -20.0), ("edotaccent", "yacute", -20.0), ("edotaccent", "ydieresis", -20.0), ("egrave", "comma", -15.0), ("egrave", "period", -15.0), ("egrave", "v", -30.0), ("egrave", "w", -20.0), ("egrave", "x", -30.0), ("egrave", "y", -20.0), ("egrave", "yacute", -20.0), ("egrave", "ydieresis", -20.0), ("emacron", "comma", -15.0), ("emacron", "period", -15.0), ("emacron", "v", -30.0), ("emacron", "w", -20.0), ("emacron", "x", -30.0), ("emacron", "y", -20.0), ("emacron", "yacute", -20.0), ("emacron", "ydieresis", -20.0), ("eogonek", "comma", -15.0), ("eogonek", "period", -15.0), ("eogonek", "v", -30.0), ("eogonek", "w", -20.0), ("eogonek", "x", -30.0), ("eogonek", "y", -20.0), ("eogonek", "yacute", -20.0), ("eogonek", "ydieresis", -20.0), ("f", "a", -30.0), ("f", "aacute", -30.0), ("f", "abreve", -30.0), ("f", "acircumflex", -30.0), ("f", "adieresis", -30.0), ("f", "agrave", -30.0), ("f", "amacron", -30.0), ("f", "aogonek", -30.0), ("f", "aring", -30.0), ("f", "atilde", -30.0), ("f", "comma", -30.0), ("f", "dotlessi", -28.0), ("f", "e", -30.0), ("f", "eacute", -30.0), ("f", "ecaron", -30.0), ("f", "ecircumflex", -30.0), ("f", "edieresis", -30.0), ("f", "edotaccent", -30.0), ("f", "egrave", -30.0), ("f", "emacron", -30.0), ("f", "eogonek", -30.0), ("f", "o", -30.0), ("f", "oacute", -30.0), ("f", "ocircumflex", -30.0), ("f", "odieresis", -30.0), ("f", "ograve", -30.0), ("f", "ohungarumlaut", -30.0), ("f", "omacron", -30.0), ("f", "oslash", -30.0), ("f", "otilde", -30.0), ("f", "period", -30.0), ("f", "quotedblright", 60.0), ("f", "quoteright", 50.0), ("g", "r", -10.0), ("g", "racute", -10.0), ("g", "rcaron", -10.0), ("g", "rcommaaccent", -10.0), ("gbreve", "r", -10.0), ("gbreve", "racute", -10.0), ("gbreve", "rcaron", -10.0), ("gbreve", "rcommaaccent", -10.0), ("gcommaaccent", "r", -10.0), ("gcommaaccent", "racute", -10.0), ("gcommaaccent", "rcaron", -10.0), ("gcommaaccent", "rcommaaccent", -10.0), ("h", "y", -30.0), ("h", "yacute", -30.0), ("h", "ydieresis", -30.0), ("k", "e", -20.0), ("k", "eacute", -20.0), ("k", "ecaron", -20.0), ("k", "ecircumflex", -20.0), ("k", "edieresis", -20.0), ("k", "edotaccent", -20.0), ("k", "egrave", -20.0), ("k", "emacron", -20.0), ("k", "eogonek", -20.0), ("k", "o", -20.0), ("k", "oacute", -20.0), ("k", "ocircumflex", -20.0), ("k", "odieresis", -20.0), ("k", "ograve", -20.0), ("k", "ohungarumlaut", -20.0), ("k", "omacron", -20.0), ("k", "oslash", -20.0), ("k", "otilde", -20.0), ("kcommaaccent", "e", -20.0), ("kcommaaccent", "eacute", -20.0), ("kcommaaccent", "ecaron", -20.0), ("kcommaaccent", "ecircumflex", -20.0), ("kcommaaccent", "edieresis", -20.0), ("kcommaaccent", "edotaccent", -20.0), ("kcommaaccent", "egrave", -20.0), ("kcommaaccent", "emacron", -20.0), ("kcommaaccent", "eogonek", -20.0), ("kcommaaccent", "o", -20.0), ("kcommaaccent", "oacute", -20.0), ("kcommaaccent", "ocircumflex", -20.0), ("kcommaaccent", "odieresis", -20.0), ("kcommaaccent", "ograve", -20.0), ("kcommaaccent", "ohungarumlaut", -20.0), ("kcommaaccent", "omacron", -20.0), ("kcommaaccent", "oslash", -20.0), ("kcommaaccent", "otilde", -20.0), ("m", "u", -10.0), ("m", "uacute", -10.0), ("m", "ucircumflex", -10.0), ("m", "udieresis", -10.0), ("m", "ugrave", -10.0), ("m", "uhungarumlaut", -10.0), ("m", "umacron", -10.0), ("m", "uogonek", -10.0), ("m", "uring", -10.0), ("m", "y", -15.0), ("m", "yacute", -15.0), ("m", "ydieresis", -15.0), ("n", "u", -10.0), ("n", "uacute", -10.0), ("n", "ucircumflex", -10.0), ("n", "udieresis", -10.0), ("n", "ugrave", -10.0), ("n", "uhungarumlaut", -10.0), ("n", "umacron", -10.0), ("n", "uogonek", -10.0), ("n", "uring", -10.0), ("n", "v", -20.0), ("n", "y", -15.0), ("n", "yacute", -15.0), ("n", "ydieresis", -15.0), ("nacute", "u", -10.0), ("nacute", "uacute", -10.0), ("nacute", "ucircumflex", -10.0), ("nacute", "udieresis", -10.0), ("nacute", "ugrave", -10.0), ("nacute", "uhungarumlaut", -10.0), ("nacute", "umacron", -10.0), ("nacute", "uogonek", -10.0), ("nacute", "uring", -10.0), ("nacute", "v", -20.0), ("nacute", "y", -15.0), ("nacute", "yacute", -15.0), ("nacute", "ydieresis", -15.0), ("ncaron", "u", -10.0), ("ncaron", "uacute", -10.0), ("ncaron", "ucircumflex", -10.0), ("ncaron", "udieresis", -10.0), ("ncaron", "ugrave", -10.0), ("ncaron", "uhungarumlaut", -10.0), ("ncaron", "umacron", -10.0), ("ncaron", "uogonek", -10.0), ("ncaron", "uring", -10.0), ("ncaron", "v", -20.0), ("ncaron", "y", -15.0), ("ncaron", "yacute", -15.0), ("ncaron", "ydieresis", -15.0), ("ncommaaccent", "u", -10.0), ("ncommaaccent", "uacute", -10.0), ("ncommaaccent", "ucircumflex", -10.0), ("ncommaaccent", "udieresis", -10.0), ("ncommaaccent", "ugrave", -10.0), ("ncommaaccent", "uhungarumlaut", -10.0), ("ncommaaccent", "umacron", -10.0), ("ncommaaccent", "uogonek", -10.0), ("ncommaaccent", "uring", -10.0), ("ncommaaccent", "v", -20.0), ("ncommaaccent", "y", -15.0), ("ncommaaccent", "yacute", -15.0), ("ncommaaccent", "ydieresis", -15.0), ("ntilde", "u", -10.0), ("ntilde", "uacute", -10.0), ("ntilde", "ucircumflex", -10.0), ("ntilde", "udieresis", -10.0), ("ntilde", "ugrave", -10.0), ("ntilde", "uhungarumlaut", -10.0), ("ntilde", "umacron", -10.0), ("ntilde", "uogonek", -10.0), ("ntilde", "uring", -10.0), ("ntilde", "v", -20.0), ("ntilde", "y", -15.0), ("ntilde", "yacute", -15.0), ("ntilde", "ydieresis", -15.0), ("o", "comma", -40.0), ("o", "period", -40.0), ("o", "v", -15.0), ("o", "w", -15.0), ("o", "x", -30.0), ("o", "y", -30.0), ("o", "yacute", -30.0), ("o", "ydieresis", -30.0), ("oacute", "comma", -40.0), ("oacute", "period", -40.0), ("oacute", "v", -15.0), ("oacute", "w", -15.0), ("oacute", "x", -30.0), ("oacute", "y", -30.0), ("oacute", "yacute", -30.0), ("oacute", "ydieresis", -30.0), ("ocircumflex", "comma", -40.0), ("ocircumflex", "period", -40.0), ("ocircumflex", "v", -15.0), ("ocircumflex", "w", -15.0), ("ocircumflex", "x", -30.0), ("ocircumflex", "y", -30.0), ("ocircumflex", "yacute", -30.0), ("ocircumflex", "ydieresis", -30.0), ("odieresis", "comma", -40.0), ("odieresis", "period", -40.0), ("odieresis", "v", -15.0), ("odieresis", "w", -15.0), ("odieresis", "x", -30.0), ("odieresis", "y", -30.0), ("odieresis", "yacute", -30.0), ("odieresis", "ydieresis", -30.0), ("ograve", "comma", -40.0), ("ograve", "period", -40.0), ("ograve", "v", -15.0), ("ograve", "w", -15.0), ("ograve", "x", -30.0), ("ograve", "y", -30.0), ("ograve", "yacute", -30.0), ("ograve", "ydieresis", -30.0), ("ohungarumlaut", "comma", -40.0), ("ohungarumlaut", "period", -40.0), ("ohungarumlaut", "v", -15.0), ("ohungarumlaut", "w", -15.0), ("ohungarumlaut", "x", -30.0), ("ohungarumlaut", "y", -30.0), ("ohungarumlaut", "yacute", -30.0), ("ohungarumlaut", "ydieresis", -30.0), ("omacron", "comma", -40.0), ("omacron", "period", -40.0), ("omacron", "v", -15.0), ("omacron", "w", -15.0), ("omacron", "x", -30.0), ("omacron", "y", -30.0), ("omacron", "yacute", -30.0), ("omacron", "ydieresis", -30.0), ("oslash", "a", -55.0), ("oslash", "aacute", -55.0), ("oslash", "abreve", -55.0), ("oslash", "acircumflex", -55.0), ("oslash", "adieresis", -55.0), ("oslash", "agrave", -55.0), ("oslash", "amacron", -55.0), ("oslash", "aogonek", -55.0), ("oslash", "aring", -55.0), ("oslash", "atilde", -55.0), ("oslash", "b", -55.0), ("oslash", "c", -55.0), ("oslash", "cacute", -55.0), ("oslash", "ccaron", -55.0), ("oslash", "ccedilla", -55.0), ("oslash", "comma", -95.0), ("oslash", "d", -55.0), ("oslash", "dcroat", -55.0), ("oslash", "e", -55.0), ("oslash", "eacute", -55.0), ("oslash", "ecaron", -55.0), ("oslash", "ecircumflex", -55.0), ("oslash", "edieresis", -55.0), ("oslash", "edotaccent", -55.0), ("oslash", "egrave", -55.0), ("oslash", "emacron", -55.0), ("oslash", "eogonek", -55.0), ("oslash", "f", -55.0), ("oslash", "g", -55.0), ("oslash", "gbreve", -55.0), ("oslash", "gcommaaccent", -55.0), ("oslash", "h", -55.0), ("oslash", "i", -55.0), ("oslash", "iacute", -55.0), ("oslash", "icircumflex", -55.0), ("oslash", "idieresis", -55.0), ("oslash", "igrave", -55.0), ("oslash", "imacron", -55.0), ("oslash", "iogonek", -55.0), ("oslash", "j", -55.0), ("oslash", "k", -55.0), ("oslash", "kcommaaccent", -55.0), ("oslash", "l", -55.0), ("oslash", "lacute", -55.0), ("oslash", "lcommaaccent", -55.0), ("oslash", "lslash", -55.0), ("oslash", "m", -55.0), ("oslash", "n", -55.0), ("oslash", "nacute", -55.0), ("oslash", "ncaron", -55.0), ("oslash", "ncommaaccent", -55.0), ("oslash", "ntilde", -55.0), ("oslash", "o", -55.0), ("oslash", "oacute", -55.0), ("oslash", "ocircumflex", -55.0), ("oslash", "odieresis", -55.0), ("oslash", "ograve", -55.0), ("oslash", "ohungarumlaut", -55.0), ("oslash", "omacron", -55.0), ("oslash", "oslash", -55.0), ("oslash", "otilde", -55.0), ("oslash", "p", -55.0), ("oslash", "period", -95.0), ("oslash", "q", -55.0), ("oslash", "r", -55.0), ("oslash", "racute", -55.0), ("oslash", "rcaron", -55.0), ("oslash", "rcommaaccent", -55.0), ("oslash", "s", -55.0), ("oslash", "sacute", -55.0), ("oslash", "scaron", -55.0), ("oslash", "scedilla", -55.0), ("oslash", "scommaaccent", -55.0), ("oslash", "t", -55.0), ("oslash", "tcommaaccent", -55.0), ("oslash", "u", -55.0), ("oslash", "uacute", -55.0), ("oslash", "ucircumflex", -55.0), ("oslash", "udieresis", -55.0), ("oslash", "ugrave", -55.0), ("oslash", "uhungarumlaut", -55.0), ("oslash", "umacron", -55.0), ("oslash", "uogonek", -55.0), ("oslash", "uring", -55.0), ("oslash", "v", -70.0), ("oslash", "w", -70.0), ("oslash", "x", -85.0), ("oslash", "y", -70.0), ("oslash", "yacute", -70.0), ("oslash", "ydieresis", -70.0), ("oslash", "z", -55.0), ("oslash", "zacute", -55.0), ("oslash", "zcaron", -55.0), ("oslash", "zdotaccent", -55.0), ("otilde", "comma", -40.0), ("otilde", "period", -40.0), ("otilde", "v", -15.0), ("otilde", "w", -15.0), ("otilde", "x", -30.0), ("otilde", "y", -30.0), ("otilde", "yacute", -30.0), ("otilde", "ydieresis", -30.0), ("p", "comma", -35.0), ("p", "period", -35.0), ("p", "y", -30.0), ("p", "yacute", -30.0), ("p", "ydieresis", -30.0), ("period", "quotedblright", -100.0), ("period", "quoteright", -100.0), ("period", "space", -60.0), ("quotedblright", "space", -40.0), ("quoteleft", "quoteleft", -57.0), ("quoteright", "d", -50.0), ("quoteright", "dcroat", -50.0), ("quoteright", "quoteright", -57.0), ("quoteright", "r", -50.0), ("quoteright", "racute", -50.0), ("quoteright", "rcaron", -50.0), ("quoteright", "rcommaaccent", -50.0), ("quoteright", "s", -50.0), ("quoteright", "sacute", -50.0), ("quoteright", "scaron", -50.0), ("quoteright", "scedilla", -50.0), ("quoteright", "scommaaccent", -50.0), ("quoteright", "space", -70.0), ("r", "a", -10.0), ("r", "aacute", -10.0), ("r", "abreve", -10.0), ("r", "acircumflex", -10.0), ("r", "adieresis", -10.0), ("r", "agrave", -10.0), ("r", "amacron", -10.0), ("r", "aogonek", -10.0), ("r", "aring", -10.0), ("r", "atilde", -10.0), ("r", "colon", 30.0), ("r", "comma", -50.0), ("r", "i", 15.0), ("r", "iacute", 15.0), ("r", "icircumflex", 15.0), ("r", "idieresis", 15.0), ("r", "igrave", 15.0), ("r", "imacron", 15.0), ("r", "iogonek", 15.0), ("r", "k", 15.0), ("r", "kcommaaccent", 15.0), ("r", "l", 15.0), ("r", "lacute", 15.0), ("r", "lcommaaccent", 15.0), ("r", "lslash", 15.0), ("r", "m", 25.0), ("r", "n", 25.0), ("r", "nacute", 25.0), ("r", "ncaron", 25.0), ("r", "ncommaaccent", 25.0), ("r", "ntilde", 25.0), ("r", "p", 30.0), ("r", "period", -50.0), ("r", "semicolon", 30.0), ("r", "t", 40.0), ("r", "tcommaaccent", 40.0), ("r", "u", 15.0), ("r", "uacute", 15.0), ("r", "ucircumflex", 15.0), ("r", "udieresis", 15.0), ("r", "ugrave", 15.0), ("r", "uhungarumlaut", 15.0), ("r", "umacron", 15.0), ("r", "uogonek", 15.0), ("r", "uring", 15.0), ("r", "v", 30.0), ("r", "y", 30.0), ("r", "yacute", 30.0), ("r", "ydieresis", 30.0), ("racute", "a", -10.0), ("racute", "aacute", -10.0), ("racute", "abreve", -10.0), ("racute", "acircumflex", -10.0), ("racute", "adieresis", -10.0), ("racute", "agrave", -10.0), ("racute", "amacron", -10.0), ("racute", "aogonek", -10.0), ("racute", "aring", -10.0), ("racute", "atilde", -10.0), ("racute", "colon", 30.0), ("racute", "comma", -50.0), ("racute", "i", 15.0), ("racute", "iacute", 15.0), ("racute", "icircumflex", 15.0), ("racute", "idieresis", 15.0), ("racute", "igrave", 15.0), ("racute", "imacron", 15.0), ("racute", "iogonek", 15.0), ("racute", "k", 15.0), ("racute", "kcommaaccent", 15.0), ("racute", "l", 15.0), ("racute", "lacute", 15.0), ("racute", "lcommaaccent", 15.0), ("racute", "lslash", 15.0), ("racute", "m", 25.0), ("racute", "n", 25.0), ("racute", "nacute", 25.0), ("racute", "ncaron", 25.0), ("racute", "ncommaaccent", 25.0), ("racute", "ntilde", 25.0), ("racute", "p", 30.0), ("racute", "period", -50.0), ("racute", "semicolon", 30.0), ("racute", "t", 40.0), ("racute", "tcommaaccent", 40.0), ("racute", "u", 15.0), ("racute", "uacute", 15.0), ("racute", "ucircumflex", 15.0), ("racute", "udieresis", 15.0), ("racute", "ugrave", 15.0), ("racute", "uhungarumlaut", 15.0), ("racute", "umacron", 15.0), ("racute", "uogonek", 15.0), ("racute", "uring", 15.0), ("racute", "v", 30.0), ("racute", "y", 30.0), ("racute", "yacute", 30.0), ("racute", "ydieresis", 30.0), ("rcaron", "a", -10.0), ("rcaron", "aacute", -10.0), ("rcaron", "abreve", -10.0), ("rcaron", "acircumflex", -10.0), ("rcaron", "adieresis", -10.0), ("rcaron", "agrave", -10.0), ("rcaron", "amacron", -10.0), ("rcaron", "aogonek", -10.0), ("rcaron", "aring", -10.0), ("rcaron", "atilde", -10.0), ("rcaron", "colon", 30.0), ("rcaron", "comma", -50.0), ("rcaron", "i", 15.0), ("rcaron", "iacute", 15.0), ("rcaron", "icircumflex", 15.0), ("rcaron", "idieresis", 15.0), ("rcaron", "igrave", 15.0), ("rcaron", "imacron", 15.0), ("rcaron", "iogonek", 15.0), ("rcaron", "k", 15.0), ("rcaron", "kcommaaccent", 15.0), ("rcaron", "l", 15.0), ("rcaron", "lacute", 15.0), ("rcaron", "lcommaaccent", 15.0), ("rcaron", "lslash", 15.0), ("rcaron", "m", 25.0), ("rcaron", "n", 25.0), ("rcaron", "nacute", 25.0), ("rcaron", "ncaron", 25.0), ("rcaron", "ncommaaccent", 25.0), ("rcaron", "ntilde", 25.0), ("rcaron", "p", 30.0), ("rcaron", "period", -50.0), ("rcaron", "semicolon", 30.0), ("rcaron", "t", 40.0), ("rcaron", "tcommaaccent", 40.0), ("rcaron", "u", 15.0), ("rcaron", "uacute", 15.0), ("rcaron", "ucircumflex", 15.0), ("rcaron", "udieresis", 15.0), ("rcaron", "ugrave", 15.0), ("rcaron", "uhungarumlaut", 15.0), ("rcaron", "umacron", 15.0), ("rcaron", "uogonek", 15.0), ("rcaron", "uring", 15.0), ("rcaron", "v", 30.0), ("rcaron", "y", 30.0), ("rcaron", "yacute", 30.0), ("rcaron", "ydieresis", 30.0), ("rcommaaccent", "a", -10.0), ("rcommaaccent", "aacute", -10.0), ("rcommaaccent", "abreve", -10.0), ("rcommaaccent", "acircumflex", -10.0), ("rcommaaccent", "adieresis", -10.0), ("rcommaaccent", "agrave", -10.0), ("rcommaaccent", "amacron", -10.0), ("rcommaaccent", "aogonek", -10.0), ("rcommaaccent", "aring", -10.0), ("rcommaaccent", "atilde", -10.0), ("rcommaaccent", "colon", 30.0), ("rcommaaccent", "comma", -50.0), ("rcommaaccent", "i", 15.0), ("rcommaaccent", "iacute", 15.0), ("rcommaaccent", "icircumflex", 15.0), ("rcommaaccent", "idieresis", 15.0), ("rcommaaccent", "igrave", 15.0), ("rcommaaccent", "imacron", 15.0), ("rcommaaccent", "iogonek", 15.0), ("rcommaaccent", "k", 15.0), ("rcommaaccent", "kcommaaccent", 15.0), ("rcommaaccent", "l", 15.0), ("rcommaaccent", "lacute", 15.0), ("rcommaaccent", "lcommaaccent", 15.0), ("rcommaaccent", "lslash", 15.0), ("rcommaaccent", "m", 25.0), ("rcommaaccent", "n", 25.0), ("rcommaaccent", "nacute", 25.0), ("rcommaaccent", "ncaron", 25.0), ("rcommaaccent", "ncommaaccent", 25.0), ("rcommaaccent", "ntilde", 25.0), ("rcommaaccent", "p", 30.0), ("rcommaaccent", "period", -50.0), ("rcommaaccent", "semicolon", 30.0), ("rcommaaccent", "t", 40.0), ("rcommaaccent", "tcommaaccent", 40.0), ("rcommaaccent", "u", 15.0), ("rcommaaccent", "uacute", 15.0), ("rcommaaccent", "ucircumflex", 15.0), ("rcommaaccent", "udieresis", 15.0), ("rcommaaccent", "ugrave", 15.0), ("rcommaaccent", "uhungarumlaut", 15.0), ("rcommaaccent", "umacron", 15.0), ("rcommaaccent", "uogonek", 15.0), ("rcommaaccent", "uring", 15.0), ("rcommaaccent", "v", 30.0), ("rcommaaccent", "y", 30.0), ("rcommaaccent", "yacute", 30.0), ("rcommaaccent", "ydieresis", 30.0), ("s", "comma", -15.0), ("s", "period", -15.0), ("s", "w", -30.0), ("sacute", "comma", -15.0), ("sacute", "period", -15.0), ("sacute", "w", -30.0), ("scaron", "comma", -15.0), ("scaron", "period", -15.0), ("scaron", "w", -30.0), ("scedilla", "comma", -15.0), ("scedilla", "period", -15.0), ("scedilla", "w", -30.0), ("scommaaccent", "comma", -15.0), ("scommaaccent", "period", -15.0), ("scommaaccent", "w", -30.0), ("semicolon", "space", -50.0), ("space", "T", -50.0), ("space", "Tcaron", -50.0), ("space", "Tcommaaccent", -50.0), ("space", "V", -50.0), ("space", "W", -40.0), ("space", "Y", -90.0), ("space", "Yacute", -90.0), ("space", "Ydieresis", -90.0), ("space", "quotedblleft", -30.0), ("space", "quoteleft", -60.0), ("v", "a", -25.0), ("v", "aacute", -25.0), ("v", "abreve", -25.0), ("v", "acircumflex", -25.0), ("v", "adieresis", -25.0), ("v", "agrave", -25.0), ("v", "amacron", -25.0), ("v", "aogonek", -25.0), ("v", "aring", -25.0), ("v", "atilde", -25.0), ("v", "comma", -80.0), ("v", "e", -25.0), ("v", "eacute", -25.0), ("v", "ecaron", -25.0), ("v", "ecircumflex", -25.0), ("v", "edieresis", -25.0), ("v", "edotaccent", -25.0), ("v", "egrave", -25.0), ("v", "emacron", -25.0), ("v", "eogonek", -25.0), ("v", "o", -25.0), ("v", "oacute", -25.0), ("v", "ocircumflex", -25.0), ("v", "odieresis", -25.0), ("v", "ograve", -25.0), ("v", "ohungarumlaut", -25.0), ("v", "omacron", -25.0), ("v", "oslash", -25.0), ("v", "otilde", -25.0), ("v", "period", -80.0), ("w", "a", -15.0), ("w", "aacute", -15.0), ("w", "abreve", -15.0), ("w", "acircumflex", -15.0), ("w", "adieresis", -15.0), ("w", "agrave", -15.0), ("w", "amacron", -15.0), ("w", "aogonek", -15.0), ("w", "aring", -15.0), ("w", "atilde", -15.0), ("w", "comma", -60.0), ("w", "e", -10.0), ("w", "eacute", -10.0), ("w", "ecaron", -10.0), ("w", "ecircumflex", -10.0), ("w", "edieresis", -10.0), ("w", "edotaccent", -10.0), ("w", "egrave", -10.0), ("w", "emacron", -10.0), ("w", "eogonek", -10.0), ("w", "o", -10.0), ("w", "oacute", -10.0), ("w", "ocircumflex", -10.0), ("w", "odieresis", -10.0), ("w", "ograve", -10.0), ("w", "ohungarumlaut", -10.0), ("w", "omacron", -10.0), ("w", "oslash", -10.0), ("w", "otilde", -10.0), ("w", "period", -60.0), ("x", "e", -30.0), ("x", "eacute", -30.0), ("x", "ecaron", -30.0), ("x", "ecircumflex", -30.0), ("x", "edieresis", -30.0), ("x", "edotaccent", -30.0), ("x", "egrave", -30.0), ("x", "emacron", -30.0), ("x", "eogonek", -30.0), ("y", "a", -20.0), ("y", "aacute", -20.0), ("y", "abreve", -20.0), ("y", "acircumflex", -20.0), ("y", "adieresis", -20.0), ("y", "agrave", -20.0), ("y", "amacron", -20.0), ("y", "aogonek", -20.0), ("y", "aring", -20.0), ("y", "atilde", -20.0), ("y", "comma", -100.0), ("y", "e", -20.0), ("y", "eacute", -20.0), ("y", "ecaron", -20.0), ("y", "ecircumflex", -20.0), ("y", "edieresis", -20.0), ("y", "edotaccent", -20.0), ("y", "egrave", -20.0), ("y", "emacron", -20.0), ("y", "eogonek", -20.0), ("y", "o", -20.0), ("y", "oacute", -20.0), ("y", "ocircumflex", -20.0), ("y", "odieresis", -20.0), ("y", "ograve", -20.0), ("y", "ohungarumlaut", -20.0), ("y", "omacron", -20.0), ("y", "oslash", -20.0), ("y", "otilde", -20.0), ("y", "period", -100.0), ("yacute", "a", -20.0), ("yacute", "aacute", -20.0), ("yacute", "abreve", -20.0), ("yacute", "acircumflex", -20.0), ("yacute", "adieresis", -20.0), ("yacute", "agrave", -20.0), ("yacute", "amacron", -20.0), ("yacute", "aogonek", -20.0), ("yacute", "aring", -20.0), ("yacute", "atilde", -20.0), ("yacute", "comma", -100.0), ("yacute", "e", -20.0), ("yacute", "eacute", -20.0), ("yacute", "ecaron", -20.0), ("yacute", "ecircumflex", -20.0), ("yacute", "edieresis", -20.0), ("yacute", "edotaccent", -20.0), ("yacute", "egrave", -20.0), ("yacute", "emacron", -20.0), ("yacute", "eogonek", -20.0), ("yacute", "o", -20.0), ("yacute", "oacute", -20.0), ("yacute", "ocircumflex", -20.0), ("yacute", "odieresis", -20.0), ("yacute", "ograve", -20.0), ("yacute", "ohungarumlaut", -20.0), ("yacute", "omacron", -20.0), ("yacute", "oslash", -20.0), ("yacute", "otilde", -20.0), ("yacute", "period", -100.0), ("ydieresis", "a", -20.0), ("ydieresis", "aacute", -20.0), ("ydieresis", "abreve", -20.0), ("ydieresis", "acircumflex", -20.0), ("ydieresis", "adieresis", -20.0), ("ydieresis", "agrave", -20.0), ("ydieresis", "amacron", -20.0), ("ydieresis", "aogonek", -20.0), ("ydieresis", "aring", -20.0), ("ydieresis", "atilde", -20.0), ("ydieresis", "comma", -100.0), ("ydieresis", "e", -20.0), ("ydieresis", "eacute", -20.0), ("ydieresis", "ecaron", -20.0), ("ydieresis", "ecircumflex", -20.0), ("ydieresis", "edieresis", -20.0), ("ydieresis", "edotaccent", -20.0), ("ydieresis", "egrave", -20.0), ("ydieresis", "emacron", -20.0), ("ydieresis", "eogonek", -20.0), ("ydieresis", "o", -20.0), ("ydieresis", "oacute", -20.0), ("ydieresis", "ocircumflex", -20.0), ("ydieresis", "odieresis", -20.0), ("ydieresis", "ograve", -20.0), ("ydieresis", "ohungarumlaut", -20.0), ("ydieresis", "omacron", -20.0), ("ydieresis", "oslash", -20.0), ("ydieresis", "otilde", -20.0), ("ydieresis", "period", -100.0), ("z", "e", -15.0), ("z", "eacute", -15.0), ("z", "ecaron", -15.0), ("z", "ecircumflex", -15.0), ("z", "edieresis", -15.0), ("z", "edotaccent", -15.0), ("z", "egrave", -15.0), ("z", "emacron", -15.0), ("z", "eogonek", -15.0), ("z", "o", -15.0), ("z", "oacute", -15.0), ("z", "ocircumflex", -15.0), ("z", "odieresis", -15.0), ("z", "ograve", -15.0), ("z", "ohungarumlaut", -15.0), ("z", "omacron", -15.0), ("z", "oslash", -15.0), ("z", "otilde", -15.0), ("zacute", "e", -15.0), ("zacute", "eacute", -15.0), ("zacute", "ecaron", -15.0), ("zacute", "ecircumflex", -15.0), ("zacute", "edieresis", -15.0), ("zacute", "edotaccent", -15.0), ("zacute", "egrave", -15.0), ("zacute", "emacron", -15.0), ("zacute", "eogonek", -15.0), ("zacute", "o", -15.0), ("zacute", "oacute", -15.0), ("zacute", "ocircumflex", -15.0), ("zacute", "odieresis", -15.0), ("zacute", "ograve", -15.0), ("zacute", "ohungarumlaut", -15.0), ("zacute", "omacron", -15.0), ("zacute", "oslash", -15.0), ("zacute", "otilde", -15.0), ("zcaron", "e", -15.0), ("zcaron", "eacute", -15.0), ("zcaron", "ecaron", -15.0), ("zcaron", "ecircumflex", -15.0), ("zcaron", "edieresis", -15.0), ("zcaron", "edotaccent", -15.0), ("zcaron", "egrave", -15.0), ("zcaron", "emacron", -15.0), ("zcaron", "eogonek", -15.0), ("zcaron", "o", -15.0), ("zcaron", "oacute", -15.0), ("zcaron", "ocircumflex", -15.0), ("zcaron", "odieresis", -15.0), ("zcaron", "ograve", -15.0), ("zcaron", "ohungarumlaut", -15.0), ("zcaron", "omacron", -15.0), ("zcaron", "oslash", -15.0), ("zcaron", "otilde", -15.0), ("zdotaccent", "e", -15.0), ("zdotaccent", "eacute", -15.0), ("zdotaccent", "ecaron", -15.0), ("zdotaccent", "ecircumflex", -15.0), ("zdotaccent", "edieresis", -15.0), ("zdotaccent", "edotaccent", -15.0), ("zdotaccent", "egrave", -15.0), ("zdotaccent", "emacron", -15.0), ("zdotaccent", "eogonek", -15.0), ("zdotaccent", "o", -15.0), ("zdotaccent", "oacute", -15.0), ("zdotaccent", "ocircumflex", -15.0), ("zdotaccent", "odieresis", -15.0), ("zdotaccent", "ograve", -15.0), ("zdotaccent", "ohungarumlaut", -15.0), ("zdotaccent", "omacron", -15.0), ("zdotaccent", "oslash", -15.0), ("zdotaccent", "otilde", -15.0)]),
+("Symbol", &[]),
+("Times-Bold", &[("A", "C", -55.0), ("A", "Cacute", -55.0), ("A", "Ccaron", -55.0), ("A", "Ccedilla", -55.0), ("A", "G", -55.0), ("A", "Gbreve", -55.0), ("A", "Gcommaaccent", -55.0), ("A", "O", -45.0), ("A", "Oacute", -45.0), ("A", "Ocircumflex", -45.0), ("A", "Odieresis", -45.0), ("A", "Ograve", -45.0), ("A", "Ohungarumlaut", -45.0), ("A", "Omacron", -45.0), ("A", "Oslash", -45.0), ("A", "Otilde", -45.0), ("A", "Q", -45.0), ("A", "T", -95.0), ("A", "Tcaron", -95.0), ("A", "Tcommaaccent", -95.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -145.0), ("A", "W", -130.0), ("A", "Y", -100.0), ("A", "Yacute", -100.0), ("A", "Ydieresis", -100.0), ("A", "p", -25.0), ("A", "quoteright", -74.0), ("A", "u", -50.0), ("A", "uacute", -50.0), ("A", "ucircumflex", -50.0), ("A", "udieresis", -50.0), ("A", "ugrave", -50.0), ("A", "uhungarumlaut", -50.0), ("A", "umacron", -50.0), ("A", "uogonek", -50.0), ("A", "uring", -50.0), ("A", "v", -100.0), ("A", "w", -90.0), ("A", "y", -74.0), ("A", "yacute", -74.0), ("A", "ydieresis", -74.0), ("Aacute", "C", -55.0), ("Aacute", "Cacute", -55.0), ("Aacute", "Ccaron", -55.0), ("Aacute", "Ccedilla", -55.0), ("Aacute", "G", -55.0), ("Aacute", "Gbreve", -55.0), ("Aacute", "Gcommaaccent", -55.0), ("Aacute", "O", -45.0), ("Aacute", "Oacute", -45.0), ("Aacute", "Ocircumflex", -45.0), ("Aacute", "Odieresis", -45.0), ("Aacute", "Ograve", -45.0), ("Aacute", "Ohungarumlaut", -45.0), ("Aacute", "Omacron", -45.0), ("Aacute", "Oslash", -45.0), ("Aacute", "Otilde", -45.0), ("Aacute", "Q", -45.0), ("Aacute", "T", -95.0), ("Aacute", "Tcaron", -95.0), ("Aacute", "Tcommaaccent", -95.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -145.0), ("Aacute", "W", -130.0), ("Aacute", "Y", -100.0), ("Aacute", "Yacute", -100.0), ("Aacute", "Ydieresis", -100.0), ("Aacute", "p", -25.0), ("Aacute", "quoteright", -74.0), ("Aacute", "u", -50.0), ("Aacute", "uacute", -50.0), ("Aacute", "ucircumflex", -50.0), ("Aacute", "udieresis", -50.0), ("Aacute", "ugrave", -50.0), ("Aacute", "uhungarumlaut", -50.0), ("Aacute", "umacron", -50.0), ("Aacute", "uogonek", -50.0), ("Aacute", "uring", -50.0), ("Aacute", "v", -100.0), ("Aacute", "w", -90.0), ("Aacute", "y", -74.0), ("Aacute", "yacute", -74.0), ("Aacute", "ydieresis", -74.0), ("Abreve", "C", -55.0), ("Abreve", "Cacute", -55.0), ("Abreve", "Ccaron", -55.0), ("Abreve", "Ccedilla", -55.0), ("Abreve", "G", -55.0), ("Abreve", "Gbreve", -55.0), ("Abreve", "Gcommaaccent", -55.0), ("Abreve", "O", -45.0), ("Abreve", "Oacute", -45.0), ("Abreve", "Ocircumflex", -45.0), ("Abreve", "Odieresis", -45.0), ("Abreve", "Ograve", -45.0), ("Abreve", "Ohungarumlaut", -45.0), ("Abreve", "Omacron", -45.0), ("Abreve", "Oslash", -45.0), ("Abreve", "Otilde", -45.0), ("Abreve", "Q", -45.0), ("Abreve", "T", -95.0), ("Abreve", "Tcaron", -95.0), ("Abreve", "Tcommaaccent", -95.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -145.0), ("Abreve", "W", -130.0), ("Abreve", "Y", -100.0), ("Abreve", "Yacute", -100.0), ("Abreve", "Ydieresis", -100.0), ("Abreve", "p", -25.0), ("Abreve", "quoteright", -74.0), ("Abreve", "u", -50.0), ("Abreve", "uacute", -50.0), ("Abreve", "ucircumflex", -50.0), ("Abreve", "udieresis", -50.0), ("Abreve", "ugrave", -50.0), ("Abreve", "uhungarumlaut", -50.0), ("Abreve", "umacron", -50.0), ("Abreve", "uogonek", -50.0), ("Abreve", "uring", -50.0), ("Abreve", "v", -100.0), ("Abreve", "w", -90.0), ("Abreve", "y", -74.0), ("Abreve", "yacute", -74.0), ("Abreve", "ydieresis", -74.0), ("Acircumflex", "C", -55.0), ("Acircumflex", "Cacute", -55.0), ("Acircumflex", "Ccaron", -55.0), ("Acircumflex", "Ccedilla", -55.0), ("Acircumflex", "G", -55.0), ("Acircumflex", "Gbreve", -55.0), ("Acircumflex", "Gcommaaccent", -55.0), ("Acircumflex", "O", -45.0), ("Acircumflex", "Oacute", -45.0), ("Acircumflex", "Ocircumflex", -45.0), ("Acircumflex", "Odieresis", -45.0), ("Acircumflex", "Ograve", -45.0), ("Acircumflex", "Ohungarumlaut", -45.0), ("Acircumflex", "Omacron", -45.0), ("Acircumflex", "Oslash", -45.0), ("Acircumflex", "Otilde", -45.0), ("Acircumflex", "Q", -45.0), ("Acircumflex", "T", -95.0), ("Acircumflex", "Tcaron", -95.0), ("Acircumflex", "Tcommaaccent", -95.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -145.0), ("Acircumflex", "W", -130.0), ("Acircumflex", "Y", -100.0), ("Acircumflex", "Yacute", -100.0), ("Acircumflex", "Ydieresis", -100.0), ("Acircumflex", "p", -25.0), ("Acircumflex", "quoteright", -74.0), ("Acircumflex", "u", -50.0), ("Acircumflex", "uacute", -50.0), ("Acircumflex", "ucircumflex", -50.0), ("Acircumflex", "udieresis", -50.0), ("Acircumflex", "ugrave", -50.0), ("Acircumflex", "uhungarumlaut", -50.0), ("Acircumflex", "umacron", -50.0), ("Acircumflex", "uogonek", -50.0), ("Acircumflex", "uring", -50.0), ("Acircumflex", "v", -100.0), ("Acircumflex", "w", -90.0), ("Acircumflex", "y", -74.0), ("Acircumflex", "yacute", -74.0), ("Acircumflex", "ydieresis", -74.0), ("Adieresis", "C", -55.0), ("Adieresis", "Cacute", -55.0), ("Adieresis", "Ccaron", -55.0), ("Adieresis", "Ccedilla", -55.0), ("Adieresis", "G", -55.0), ("Adieresis", "Gbreve", -55.0), ("Adieresis", "Gcommaaccent", -55.0), ("Adieresis", "O", -45.0), ("Adieresis", "Oacute", -45.0), ("Adieresis", "Ocircumflex", -45.0), ("Adieresis", "Odieresis", -45.0), ("Adieresis", "Ograve", -45.0), ("Adieresis", "Ohungarumlaut", -45.0), ("Adieresis", "Omacron", -45.0), ("Adieresis", "Oslash", -45.0), ("Adieresis", "Otilde", -45.0), ("Adieresis", "Q", -45.0), ("Adieresis", "T", -95.0), ("Adieresis", "Tcaron", -95.0), ("Adieresis", "Tcommaaccent", -95.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -145.0), ("Adieresis", "W", -130.0), ("Adieresis", "Y", -100.0), ("Adieresis", "Yacute", -100.0), ("Adieresis", "Ydieresis", -100.0), ("Adieresis", "p", -25.0), ("Adieresis", "quoteright", -74.0), ("Adieresis", "u", -50.0), ("Adieresis", "uacute", -50.0), ("Adieresis", "ucircumflex", -50.0), ("Adieresis", "udieresis", -50.0), ("Adieresis", "ugrave", -50.0), ("Adieresis", "uhungarumlaut", -50.0), ("Adieresis", "umacron", -50.0), ("Adieresis", "uogonek", -50.0), ("Adieresis", "uring", -50.0), ("Adieresis", "v", -100.0), ("Adieresis", "w", -90.0), ("Adieresis", "y", -74.0), ("Adieresis", "yacute", -74.0), ("Adieresis", "ydieresis", -74.0), ("Agrave", "C", -55.0), ("Agrave", "Cacute", -55.0), ("Agrave", "Ccaron", -55.0), ("Agrave", "Ccedilla", -55.0), ("Agrave", "G", -55.0), ("Agrave", "Gbreve", -55.0), ("Agrave", "Gcommaaccent", -55.0), ("Agrave", "O", -45.0), ("Agrave", "Oacute", -45.0), ("Agrave", "Ocircumflex", -45.0), ("Agrave", "Odieresis", -45.0), ("Agrave", "Ograve", -45.0), ("Agrave", "Ohungarumlaut", -45.0), ("Agrave", "Omacron", -45.0), ("Agrave", "Oslash", -45.0), ("Agrave", "Otilde", -45.0), ("Agrave", "Q", -45.0), ("Agrave", "T", -95.0), ("Agrave", "Tcaron", -95.0), ("Agrave", "Tcommaaccent", -95.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -145.0), ("Agrave", "W", -130.0), ("Agrave", "Y", -100.0), ("Agrave", "Yacute", -100.0), ("Agrave", "Ydieresis", -100.0), ("Agrave", "p", -25.0), ("Agrave", "quoteright", -74.0), ("Agrave", "u", -50.0), ("Agrave", "uacute", -50.0), ("Agrave", "ucircumflex", -50.0), ("Agrave", "udieresis", -50.0), ("Agrave", "ugrave", -50.0), ("Agrave", "uhungarumlaut", -50.0), ("Agrave", "umacron", -50.0), ("Agrave", "uogonek", -50.0), ("Agrave", "uring", -50.0), ("Agrave", "v", -100.0), ("Agrave", "w", -90.0), ("Agrave", "y", -74.0), ("Agrave", "yacute", -74.0), ("Agrave", "ydieresis", -74.0), ("Amacron", "C", -55.0), ("Amacron", "Cacute", -55.0), ("Amacron", "Ccaron", -55.0), ("Amacron", "Ccedilla", -55.0), ("Amacron", "G", -55.0), ("Amacron", "Gbreve", -55.0), ("Amacron", "Gcommaaccent", -55.0), ("Amacron", "O", -45.0), ("Amacron", "Oacute", -45.0), ("Amacron", "Ocircumflex", -45.0), ("Amacron", "Odieresis", -45.0), ("Amacron", "Ograve", -45.0), ("Amacron", "Ohungarumlaut", -45.0), ("Amacron", "Omacron", -45.0), ("Amacron", "Oslash", -45.0), ("Amacron", "Otilde", -45.0), ("Amacron", "Q", -45.0), ("Amacron", "T", -95.0), ("Amacron", "Tcaron", -95.0), ("Amacron", "Tcommaaccent", -95.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -145.0), ("Amacron", "W", -130.0), ("Amacron", "Y", -100.0), ("Amacron", "Yacute", -100.0), ("Amacron", "Ydieresis", -100.0), ("Amacron", "p", -25.0), ("Amacron", "quoteright", -74.0), ("Amacron", "u", -50.0), ("Amacron", "uacute", -50.0), ("Amacron", "ucircumflex", -50.0), ("Amacron", "udieresis", -50.0), ("Amacron", "ugrave", -50.0), ("Amacron", "uhungarumlaut", -50.0), ("Amacron", "umacron", -50.0), ("Amacron", "uogonek", -50.0), ("Amacron", "uring", -50.0), ("Amacron", "v", -100.0), ("Amacron", "w", -90.0), ("Amacron", "y", -74.0), ("Amacron", "yacute", -74.0), ("Amacron", "ydieresis", -74.0), ("Aogonek", "C", -55.0), ("Aogonek", "Cacute", -55.0), ("Aogonek", "Ccaron", -55.0), ("Aogonek", "Ccedilla", -55.0), ("Aogonek", "G", -55.0), ("Aogonek", "Gbreve", -55.0), ("Aogonek", "Gcommaaccent", -55.0), ("Aogonek", "O", -45.0), ("Aogonek", "Oacute", -45.0), ("Aogonek", "Ocircumflex", -45.0), ("Aogonek", "Odieresis", -45.0), ("Aogonek", "Ograve", -45.0), ("Aogonek", "Ohungarumlaut", -45.0), ("Aogonek", "Omacron", -45.0), ("Aogonek", "Oslash", -45.0), ("Aogonek", "Otilde", -45.0), ("Aogonek", "Q", -45.0), ("Aogonek", "T", -95.0), ("Aogonek", "Tcaron", -95.0), ("Aogonek", "Tcommaaccent", -95.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -145.0), ("Aogonek", "W", -130.0), ("Aogonek", "Y", -100.0), ("Aogonek", "Yacute", -100.0), ("Aogonek", "Ydieresis", -100.0), ("Aogonek", "p", -25.0), ("Aogonek", "quoteright", -74.0), ("Aogonek", "u", -50.0), ("Aogonek", "uacute", -50.0), ("Aogonek", "ucircumflex", -50.0), ("Aogonek", "udieresis", -50.0), ("Aogonek", "ugrave", -50.0), ("Aogonek", "uhungarumlaut", -50.0), ("Aogonek", "umacron", -50.0), ("Aogonek", "uogonek", -50.0), ("Aogonek", "uring", -50.0), ("Aogonek", "v", -100.0), ("Aogonek", "w", -90.0), ("Aogonek", "y", -34.0), ("Aogonek", "yacute", -34.0), ("Aogonek", "ydieresis", -34.0), ("Aring", "C", -55.0), ("Aring", "Cacute", -55.0), ("Aring", "Ccaron", -55.0), ("Aring", "Ccedilla", -55.0), ("Aring", "G", -55.0), ("Aring", "Gbreve", -55.0), ("Aring", "Gcommaaccent", -55.0), ("Aring", "O", -45.0), ("Aring", "Oacute", -45.0), ("Aring", "Ocircumflex", -45.0), ("Aring", "Odieresis", -45.0), ("Aring", "Ograve", -45.0), ("Aring", "Ohungarumlaut", -45.0), ("Aring", "Omacron", -45.0), ("Aring", "Oslash", -45.0), ("Aring", "Otilde", -45.0), ("Aring", "Q", -45.0), ("Aring", "T", -95.0), ("Aring", "Tcaron", -95.0), ("Aring", "Tcommaaccent", -95.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -145.0), ("Aring", "W", -130.0), ("Aring", "Y", -100.0), ("Aring", "Yacute", -100.0), ("Aring", "Ydieresis", -100.0), ("Aring", "p", -25.0), ("Aring", "quoteright", -74.0), ("Aring", "u", -50.0), ("Aring", "uacute", -50.0), ("Aring", "ucircumflex", -50.0), ("Aring", "udieresis", -50.0), ("Aring", "ugrave", -50.0), ("Aring", "uhungarumlaut", -50.0), ("Aring", "umacron", -50.0), ("Aring", "uogonek", -50.0), ("Aring", "uring", -50.0), ("Aring", "v", -100.0), ("Aring", "w", -90.0), ("Aring", "y", -74.0), ("Aring", "yacute", -74.0), ("Aring", "ydieresis", -74.0), ("Atilde", "C", -55.0), ("Atilde", "Cacute", -55.0), ("Atilde", "Ccaron", -55.0), ("Atilde", "Ccedilla", -55.0), ("Atilde", "G", -55.0), ("Atilde", "Gbreve", -55.0), ("Atilde", "Gcommaaccent", -55.0), ("Atilde", "O", -45.0), ("Atilde", "Oacute", -45.0), ("Atilde", "Ocircumflex", -45.0), ("Atilde", "Odieresis", -45.0), ("Atilde", "Ograve", -45.0), ("Atilde", "Ohungarumlaut", -45.0), ("Atilde", "Omacron", -45.0), ("Atilde", "Oslash", -45.0), ("Atilde", "Otilde", -45.0), ("Atilde", "Q", -45.0), ("Atilde", "T", -95.0), ("Atilde", "Tcaron", -95.0), ("Atilde", "Tcommaaccent", -95.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -145.0), ("Atilde", "W", -130.0), ("Atilde", "Y", -100.0), ("Atilde", "Yacute", -100.0), ("Atilde", "Ydieresis", -100.0), ("Atilde", "p", -25.0), ("Atilde", "quoteright", -74.0), ("Atilde", "u", -50.0), ("Atilde", "uacute", -50.0), ("Atilde", "ucircumflex", -50.0), ("Atilde", "udieresis", -50.0), ("Atilde", "ugrave", -50.0), ("Atilde", "uhungarumlaut", -50.0), ("Atilde", "umacron", -50.0), ("Atilde", "uogonek", -50.0), ("Atilde", "uring", -50.0), ("Atilde", "v", -100.0), ("Atilde", "w", -90.0), ("Atilde", "y", -74.0), ("Atilde", "yacute", -74.0), ("Atilde", "ydieresis", -74.0), ("B", "A", -30.0), ("B", "Aacute", -30.0), ("B", "Abreve", -30.0), ("B", "Acircumflex", -30.0), ("B", "Adieresis", -30.0), ("B", "Agrave", -30.0), ("B", "Amacron", -30.0), ("B", "Aogonek", -30.0), ("B", "Aring", -30.0), ("B", "Atilde", -30.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -35.0), ("D", "Aacute", -35.0), ("D", "Abreve", -35.0), ("D", "Acircumflex", -35.0), ("D", "Adieresis", -35.0), ("D", "Agrave", -35.0), ("D", "Amacron", -35.0), ("D", "Aogonek", -35.0), ("D", "Aring", -35.0), ("D", "Atilde", -35.0), ("D", "V", -40.0), ("D", "W", -40.0), ("D", "Y", -40.0), ("D", "Yacute", -40.0), ("D", "Ydieresis", -40.0), ("D", "period", -20.0), ("Dcaron", "A", -35.0), ("Dcaron", "Aacute", -35.0), ("Dcaron", "Abreve", -35.0), ("Dcaron", "Acircumflex", -35.0), ("Dcaron", "Adieresis", -35.0), ("Dcaron", "Agrave", -35.0), ("Dcaron", "Amacron", -35.0), ("Dcaron", "Aogonek", -35.0), ("Dcaron", "Aring", -35.0), ("Dcaron", "Atilde", -35.0), ("Dcaron", "V", -40.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -40.0), ("Dcaron", "Yacute", -40.0), ("Dcaron", "Ydieresis", -40.0), ("Dcaron", "period", -20.0), ("Dcroat", "A", -35.0), ("Dcroat", "Aacute", -35.0), ("Dcroat", "Abreve", -35.0), ("Dcroat", "Acircumflex", -35.0), ("Dcroat", "Adieresis", -35.0), ("Dcroat", "Agrave", -35.0), ("Dcroat", "Amacron", -35.0), ("Dcroat", "Aogonek", -35.0), ("Dcroat", "Aring", -35.0), ("Dcroat", "Atilde", -35.0), ("Dcroat", "V", -40.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -40.0), ("Dcroat", "Yacute", -40.0), ("Dcroat", "Ydieresis", -40.0), ("Dcroat", "period", -20.0), ("F", "A", -90.0), ("F", "Aacute", -90.0), ("F", "Abreve", -90.0), ("F", "Acircumflex", -90.0), ("F", "Adieresis", -90.0), ("F", "Agrave", -90.0), ("F", "Amacron", -90.0), ("F", "Aogonek", -90.0), ("F", "Aring", -90.0), ("F", "Atilde", -90.0), ("F", "a", -25.0), ("F", "aacute", -25.0), ("F", "abreve", -25.0), ("F", "acircumflex", -25.0), ("F", "adieresis", -25.0), ("F", "agrave", -25.0), ("F", "amacron", -25.0), ("F", "aogonek", -25.0), ("F", "aring", -25.0), ("F", "atilde", -25.0), ("F", "comma", -92.0), ("F", "e", -25.0), ("F", "eacute", -25.0), ("F", "ecaron", -25.0), ("F", "ecircumflex", -25.0), ("F", "edieresis", -25.0), ("F", "edotaccent", -25.0), ("F", "egrave", -25.0), ("F", "emacron", -25.0), ("F", "eogonek", -25.0), ("F", "o", -25.0), ("F", "oacute", -25.0), ("F", "ocircumflex", -25.0), ("F", "odieresis", -25.0), ("F", "ograve", -25.0), ("F", "ohungarumlaut", -25.0), ("F", "omacron", -25.0), ("F", "oslash", -25.0), ("F", "otilde", -25.0), ("F", "period", -110.0), ("J", "A", -30.0), ("J", "Aacute", -30.0), ("J", "Abreve", -30.0), ("J", "Acircumflex", -30.0), ("J", "Adieresis", -30.0), ("J", "Agrave", -30.0), ("J", "Amacron", -30.0), ("J", "Aogonek", -30.0), ("J", "Aring", -30.0), ("J", "Atilde", -30.0), ("J", "a", -15.0), ("J", "aacute", -15.0), ("J", "abreve", -15.0), ("J", "acircumflex", -15.0), ("J", "adieresis", -15.0), ("J", "agrave", -15.0), ("J", "amacron", -15.0), ("J", "aogonek", -15.0), ("J", "aring", -15.0), ("J", "atilde", -15.0), ("J", "e", -15.0), ("J", "eacute", -15.0), ("J", "ecaron", -15.0), ("J", "ecircumflex", -15.0), ("J", "edieresis", -15.0), ("J", "edotaccent", -15.0), ("J", "egrave", -15.0), ("J", "emacron", -15.0), ("J", "eogonek", -15.0), ("J", "o", -15.0), ("J", "oacute", -15.0), ("J", "ocircumflex", -15.0), ("J", "odieresis", -15.0), ("J", "ograve", -15.0), ("J", "ohungarumlaut", -15.0), ("J", "omacron", -15.0), ("J", "oslash", -15.0), ("J", "otilde", -15.0), ("J", "period", -20.0), ("J", "u", -15.0), ("J", "uacute", -15.0), ("J", "ucircumflex", -15.0), ("J", "udieresis", -15.0), ("J", "ugrave", -15.0), ("J", "uhungarumlaut", -15.0), ("J", "umacron", -15.0), ("J", "uogonek", -15.0), ("J", "uring", -15.0), ("K", "O", -30.0), ("K", "Oacute", -30.0), ("K", "Ocircumflex", -30.0), ("K", "Odieresis", -30.0), ("K", "Ograve", -30.0), ("K", "Ohungarumlaut", -30.0), ("K", "Omacron", -30.0), ("K", "Oslash", -30.0), ("K", "Otilde", -30.0), ("K", "e", -25.0), ("K", "eacute", -25.0), ("K", "ecaron", -25.0), ("K", "ecircumflex", -25.0), ("K", "edieresis", -25.0), ("K", "edotaccent", -25.0), ("K", "egrave", -25.0), ("K", "emacron", -25.0), ("K", "eogonek", -25.0), ("K", "o", -25.0), ("K", "oacute", -25.0), ("K", "ocircumflex", -25.0), ("K", "odieresis", -25.0), ("K", "ograve", -25.0), ("K", "ohungarumlaut", -25.0), ("K", "omacron", -25.0), ("K", "oslash", -25.0), ("K", "otilde", -25.0), ("K", "u", -15.0), ("K", "uacute", -15.0), ("K", "ucircumflex", -15.0), ("K", "udieresis", -15.0), ("K", "ugrave", -15.0), ("K", "uhungarumlaut", -15.0), ("K", "umacron", -15.0), ("K", "uogonek", -15.0), ("K", "uring", -15.0), ("K", "y", -45.0), ("K", "yacute", -45.0), ("K", "ydieresis", -45.0), ("Kcommaaccent", "O", -30.0), ("Kcommaaccent", "Oacute", -30.0), ("Kcommaaccent", "Ocircumflex", -30.0), ("Kcommaaccent", "Odieresis", -30.0), ("Kcommaaccent", "Ograve", -30.0), ("Kcommaaccent", "Ohungarumlaut", -30.0), ("Kcommaaccent", "Omacron", -30.0), ("Kcommaaccent", "Oslash", -30.0), ("Kcommaaccent", "Otilde", -30.0), ("Kcommaaccent", "e", -25.0), ("Kcommaaccent", "eacute", -25.0), ("Kcommaaccent", "ecaron", -25.0), ("Kcommaaccent", "ecircumflex", -25.0), ("Kcommaaccent", "edieresis", -25.0), ("Kcommaaccent", "edotaccent", -25.0), ("Kcommaaccent", "egrave", -25.0), ("Kcommaaccent", "emacron", -25.0), ("Kcommaaccent", "eogonek", -25.0), ("Kcommaaccent", "o", -25.0), ("Kcommaaccent", "oacute", -25.0), ("Kcommaaccent", "ocircumflex", -25.0), ("Kcommaaccent", "odieresis", -25.0), ("Kcommaaccent", "ograve", -25.0), ("Kcommaaccent", "ohungarumlaut", -25.0), ("Kcommaaccent", "omacron", -25.0), ("Kcommaaccent", "oslash", -25.0), ("Kcommaaccent", "otilde", -25.0), ("Kcommaaccent", "u", -15.0), ("Kcommaaccent", "uacute", -15.0), ("Kcommaaccent", "ucircumflex", -15.0), ("Kcommaaccent", "udieresis", -15.0), ("Kcommaaccent", "ugrave", -15.0), ("Kcommaaccent", "uhungarumlaut", -15.0), ("Kcommaaccent", "umacron", -15.0), ("Kcommaaccent", "uogonek", -15.0), ("Kcommaaccent", "uring", -15.0), ("Kcommaaccent", "y", -45.0), ("Kcommaaccent", "yacute", -45.0), ("Kcommaaccent", "ydieresis", -45.0), ("L", "T", -92.0), ("L", "Tcaron", -92.0), ("L", "Tcommaaccent", -92.0), ("L", "V", -92.0), ("L", "W", -92.0), ("L", "Y", -92.0), ("L", "Yacute", -92.0), ("L", "Ydieresis", -92.0), ("L", "quotedblright", -20.0), ("L", "quoteright", -110.0), ("L", "y", -55.0), ("L", "yacute", -55.0), ("L", "ydieresis", -55.0), ("Lacute", "T", -92.0), ("Lacute", "Tcaron", -92.0), ("Lacute", "Tcommaaccent", -92.0), ("Lacute", "V", -92.0), ("Lacute", "W", -92.0), ("Lacute", "Y", -92.0), ("Lacute", "Yacute", -92.0), ("Lacute", "Ydieresis", -92.0), ("Lacute", "quotedblright", -20.0), ("Lacute", "quoteright", -110.0), ("Lacute", "y", -55.0), ("Lacute", "yacute", -55.0), ("Lacute", "ydieresis", -55.0), ("Lcommaaccent", "T", -92.0), ("Lcommaaccent", "Tcaron", -92.0), ("Lcommaaccent", "Tcommaaccent", -92.0), ("Lcommaaccent", "V", -92.0), ("Lcommaaccent", "W", -92.0), ("Lcommaaccent", "Y", -92.0), ("Lcommaaccent", "Yacute", -92.0), ("Lcommaaccent", "Ydieresis", -92.0), ("Lcommaaccent", "quotedblright", -20.0), ("Lcommaaccent", "quoteright", -110.0), ("Lcommaaccent", "y", -55.0), ("Lcommaaccent", "yacute", -55.0), ("Lcommaaccent", "ydieresis", -55.0), ("Lslash", "T", -92.0), ("Lslash", "Tcaron", -92.0), ("Lslash", "Tcommaaccent", -92.0), ("Lslash", "V", -92.0), ("Lslash", "W", -92.0), ("Lslash", "Y", -92.0), ("Lslash", "Yacute", -92.0), ("Lslash", "Ydieresis", -92.0), ("Lslash", "quotedblright", -20.0), ("Lslash", "quoteright", -110.0), ("Lslash", "y", -55.0), ("Lslash", "yacute", -55.0), ("Lslash", "ydieresis", -55.0), ("N", "A", -20.0), ("N", "Aacute", -20.0), ("N", "Abreve", -20.0), ("N", "Acircumflex", -20.0), ("N", "Adieresis", -20.0), ("N", "Agrave", -20.0), ("N", "Amacron", -20.0), ("N", "Aogonek", -20.0), ("N", "Aring", -20.0), ("N", "Atilde", -20.0), ("Nacute", "A", -20.0), ("Nacute", "Aacute", -20.0), ("Nacute", "Abreve", -20.0), ("Nacute", "Acircumflex", -20.0), ("Nacute", "Adieresis", -20.0), ("Nacute", "Agrave", -20.0), ("Nacute", "Amacron", -20.0), ("Nacute", "Aogonek", -20.0), ("Nacute", "Aring", -20.0), ("Nacute", "Atilde", -20.0), ("Ncaron", "A", -20.0), ("Ncaron", "Aacute", -20.0), ("Ncaron", "Abreve", -20.0), ("Ncaron", "Acircumflex", -20.0), ("Ncaron", "Adieresis", -20.0), ("Ncaron", "Agrave", -20.0), ("Ncaron", "Amacron", -20.0), ("Ncaron", "Aogonek", -20.0), ("Ncaron", "Aring", -20.0), ("Ncaron", "Atilde", -20.0), ("Ncommaaccent", "A", -20.0), ("Ncommaaccent", "Aacute", -20.0), ("Ncommaaccent", "Abreve", -20.0), ("Ncommaaccent", "Acircumflex", -20.0), ("Ncommaaccent", "Adieresis", -20.0), ("Ncommaaccent", "Agrave", -20.0), ("Ncommaaccent", "Amacron", -20.0), ("Ncommaaccent", "Aogonek", -20.0), ("Ncommaaccent", "Aring", -20.0), ("Ncommaaccent", "Atilde", -20.0), ("Ntilde", "A", -20.0), ("Ntilde", "Aacute", -20.0), ("Ntilde", "Abreve", -20.0), ("Ntilde", "Acircumflex", -20.0), ("Ntilde", "Adieresis", -20.0), ("Ntilde", "Agrave", -20.0), ("Ntilde", "Amacron", -20.0), ("Ntilde", "Aogonek", -20.0), ("Ntilde", "Aring", -20.0), ("Ntilde", "Atilde", -20.0), ("O", "A", -40.0), ("O", "Aacute", -40.0), ("O", "Abreve", -40.0), ("O", "Acircumflex", -40.0), ("O", "Adieresis", -40.0), ("O", "Agrave", -40.0), ("O", "Amacron", -40.0), ("O", "Aogonek", -40.0), ("O", "Aring", -40.0), ("O", "Atilde", -40.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -50.0), ("O", "X", -40.0), ("O", "Y", -50.0), ("O", "Yacute", -50.0), ("O", "Ydieresis", -50.0), ("Oacute", "A", -40.0), ("Oacute", "Aacute", -40.0), ("Oacute", "Abreve", -40.0), ("Oacute", "Acircumflex", -40.0), ("Oacute", "Adieresis", -40.0), ("Oacute", "Agrave", -40.0), ("Oacute", "Amacron", -40.0), ("Oacute", "Aogonek", -40.0), ("Oacute", "Aring", -40.0), ("Oacute", "Atilde", -40.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -50.0), ("Oacute", "X", -40.0), ("Oacute", "Y", -50.0), ("Oacute", "Yacute", -50.0), ("Oacute", "Ydieresis", -50.0), ("Ocircumflex", "A", -40.0), ("Ocircumflex", "Aacute", -40.0), ("Ocircumflex", "Abreve", -40.0), ("Ocircumflex", "Acircumflex", -40.0), ("Ocircumflex", "Adieresis", -40.0), ("Ocircumflex", "Agrave", -40.0), ("Ocircumflex", "Amacron", -40.0), ("Ocircumflex", "Aogonek", -40.0), ("Ocircumflex", "Aring", -40.0), ("Ocircumflex", "Atilde", -40.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -50.0), ("Ocircumflex", "X", -40.0), ("Ocircumflex", "Y", -50.0), ("Ocircumflex", "Yacute", -50.0), ("Ocircumflex", "Ydieresis", -50.0), ("Odieresis", "A", -40.0), ("Odieresis", "Aacute", -40.0), ("Odieresis", "Abreve", -40.0), ("Odieresis", "Acircumflex", -40.0), ("Odieresis", "Adieresis", -40.0), ("Odieresis", "Agrave", -40.0), ("Odieresis", "Amacron", -40.0), ("Odieresis", "Aogonek", -40.0), ("Odieresis", "Aring", -40.0), ("Odieresis", "Atilde", -40.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -50.0), ("Odieresis", "X", -40.0), ("Odieresis", "Y", -50.0), ("Odieresis", "Yacute", -50.0), ("Odieresis", "Ydieresis", -50.0), ("Ograve", "A", -40.0), ("Ograve", "Aacute", -40.0), ("Ograve", "Abreve", -40.0), ("Ograve", "Acircumflex", -40.0), ("Ograve", "Adieresis", -40.0), ("Ograve", "Agrave", -40.0), ("Ograve", "Amacron", -40.0), ("Ograve", "Aogonek", -40.0), ("Ograve", "Aring", -40.0), ("Ograve", "Atilde", -40.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -50.0), ("Ograve", "X", -40.0), ("Ograve", "Y", -50.0), ("Ograve", "Yacute", -50.0), ("Ograve", "Ydieresis", -50.0), ("Ohungarumlaut", "A", -40.0), ("Ohungarumlaut", "Aacute", -40.0), ("Ohungarumlaut", "Abreve", -40.0), ("Ohungarumlaut", "Acircumflex", -40.0), ("Ohungarumlaut", "Adieresis", -40.0), ("Ohungarumlaut", "Agrave", -40.0), ("Ohungarumlaut", "Amacron", -40.0), ("Ohungarumlaut", "Aogonek", -40.0), ("Ohungarumlaut", "Aring", -40.0), ("Ohungarumlaut", "Atilde", -40.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -50.0), ("Ohungarumlaut", "X", -40.0), ("Ohungarumlaut", "Y", -50.0), ("Ohungarumlaut", "Yacute", -50.0), ("Ohungarumlaut", "Ydieresis", -50.0), ("Omacron", "A", -40.0), ("Omacron", "Aacute", -40.0), ("Omacron", "Abreve", -40.0), ("Omacron", "Acircumflex", -40.0), ("Omacron", "Adieresis", -40.0), ("Omacron", "Agrave", -40.0), ("Omacron", "Amacron", -40.0), ("Omacron", "Aogonek", -40.0), ("Omacron", "Aring", -40.0), ("Omacron", "Atilde", -40.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -50.0), ("Omacron", "X", -40.0), ("Omacron", "Y", -50.0), ("Omacron", "Yacute", -50.0), ("Omacron", "Ydieresis", -50.0), ("Oslash", "A", -40.0), ("Oslash", "Aacute", -40.0), ("Oslash", "Abreve", -40.0), ("Oslash", "Acircumflex", -40.0), ("Oslash", "Adieresis", -40.0), ("Oslash", "Agrave", -40.0), ("Oslash", "Amacron", -40.0), ("Oslash", "Aogonek", -40.0), ("Oslash", "Aring", -40.0), ("Oslash", "Atilde", -40.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -50.0), ("Oslash", "X", -40.0), ("Oslash", "Y", -50.0), ("Oslash", "Yacute", -50.0), ("Oslash", "Ydieresis", -50.0), ("Otilde", "A", -40.0), ("Otilde", "Aacute", -40.0), ("Otilde", "Abreve", -40.0), ("Otilde", "Acircumflex", -40.0), ("Otilde", "Adieresis", -40.0), ("Otilde", "Agrave", -40.0), ("Otilde", "Amacron", -40.0), ("Otilde", "Aogonek", -40.0), ("Otilde", "Aring", -40.0), ("Otilde", "Atilde", -40.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -50.0), ("Otilde", "X", -40.0), ("Otilde", "Y", -50.0), ("Otilde", "Yacute", -50.0), ("Otilde", "Ydieresis", -50.0), ("P", "A", -74.0), ("P", "Aacute", -74.0), ("P", "Abreve", -74.0), ("P", "Acircumflex", -74.0), ("P", "Adieresis", -74.0), ("P", "Agrave", -74.0), ("P", "Amacron", -74.0), ("P", "Aogonek", -74.0), ("P", "Aring", -74.0), ("P", "Atilde", -74.0), ("P", "a", -10.0), ("P", "aacute", -10.0), ("P", "abreve", -10.0), ("P", "acircumflex", -10.0), ("P", "adieresis", -10.0), ("P", "agrave", -10.0), ("P", "amacron", -10.0), ("P", "aogonek", -10.0), ("P", "aring", -10.0), ("P", "atilde", -10.0), ("P", "comma", -92.0), ("P", "e", -20.0), ("P", "eacute", -20.0), ("P", "ecaron", -20.0), ("P", "ecircumflex", -20.0), ("P", "edieresis", -20.0), ("P", "edotaccent", -20.0), ("P", "egrave", -20.0), ("P", "emacron", -20.0), ("P", "eogonek", -20.0), ("P", "o", -20.0), ("P", "oacute", -20.0), ("P", "ocircumflex", -20.0), ("P", "odieresis", -20.0), ("P", "ograve", -20.0), ("P", "ohungarumlaut", -20.0), ("P", "omacron", -20.0), ("P", "oslash", -20.0), ("P", "otilde", -20.0), ("P", "period", -110.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("Q", "period", -20.0), ("R", "O", -30.0), ("R", "Oacute", -30.0), ("R", "Ocircumflex", -30.0), ("R", "Odieresis", -30.0), ("R", "Ograve", -30.0), ("R", "Ohungarumlaut", -30.0), ("R", "Omacron", -30.0), ("R", "Oslash", -30.0), ("R", "Otilde", -30.0), ("R", "T", -40.0), ("R", "Tcaron", -40.0), ("R", "Tcommaaccent", -40.0), ("R", "U", -30.0), ("R", "Uacute", -30.0), ("R", "Ucircumflex", -30.0), ("R", "Udieresis", -30.0), ("R", "Ugrave", -30.0), ("R", "Uhungarumlaut", -30.0), ("R", "Umacron", -30.0), ("R", "Uogonek", -30.0), ("R", "Uring", -30.0), ("R", "V", -55.0), ("R", "W", -35.0), ("R", "Y", -35.0), ("R", "Yacute", -35.0), ("R", "Ydieresis", -35.0), ("Racute", "O", -30.0), ("Racute", "Oacute", -30.0), ("Racute", "Ocircumflex", -30.0), ("Racute", "Odieresis", -30.0), ("Racute", "Ograve", -30.0), ("Racute", "Ohungarumlaut", -30.0), ("Racute", "Omacron", -30.0), ("Racute", "Oslash", -30.0), ("Racute", "Otilde", -30.0), ("Racute", "T", -40.0), ("Racute", "Tcaron", -40.0), ("Racute", "Tcommaaccent", -40.0), ("Racute", "U", -30.0), ("Racute", "Uacute", -30.0), ("Racute", "Ucircumflex", -30.0), ("Racute", "Udieresis", -30.0), ("Racute", "Ugrave", -30.0), ("Racute", "Uhungarumlaut", -30.0), ("Racute", "Umacron", -30.0), ("Racute", "Uogonek", -30.0), ("Racute", "Uring", -30.0), ("Racute", "V", -55.0), ("Racute", "W", -35.0), ("Racute", "Y", -35.0), ("Racute", "Yacute", -35.0), ("Racute", "Ydieresis", -35.0), ("Rcaron", "O", -30.0), ("Rcaron", "Oacute", -30.0), ("Rcaron", "Ocircumflex", -30.0), ("Rcaron", "Odieresis", -30.0), ("Rcaron", "Ograve", -30.0), ("Rcaron", "Ohungarumlaut", -30.0), ("Rcaron", "Omacron", -30.0), ("Rcaron", "Oslash", -30.0), ("Rcaron", "Otilde", -30.0), ("Rcaron", "T", -40.0), ("Rcaron", "Tcaron", -40.0), ("Rcaron", "Tcommaaccent", -40.0), ("Rcaron", "U", -30.0), ("Rcaron", "Uacute", -30.0), ("Rcaron", "Ucircumflex", -30.0), ("Rcaron", "Udieresis", -30.0), ("Rcaron", "Ugrave", -30.0), ("Rcaron", "Uhungarumlaut", -30.0), ("Rcaron", "Umacron", -30.0), ("Rcaron", "Uogonek", -30.0), ("Rcaron", "Uring", -30.0), ("Rcaron", "V", -55.0), ("Rcaron", "W", -35.0), ("Rcaron", "Y", -35.0), ("Rcaron", "Yacute", -35.0), ("Rcaron", "Ydieresis", -35.0), ("Rcommaaccent", "O", -30.0), ("Rcommaaccent", "Oacute", -30.0), ("Rcommaaccent", "Ocircumflex", -30.0), ("Rcommaaccent", "Odieresis", -30.0), ("Rcommaaccent", "Ograve", -30.0), ("Rcommaaccent", "Ohungarumlaut", -30.0), ("Rcommaaccent", "Omacron", -30.0), ("Rcommaaccent", "Oslash", -30.0), ("Rcommaaccent", "Otilde", -30.0), ("Rcommaaccent", "T", -40.0), ("Rcommaaccent", "Tcaron", -40.0), ("Rcommaaccent", "Tcommaaccent", -40.0), ("Rcommaaccent", "U", -30.0), ("Rcommaaccent", "Uacute", -30.0), ("Rcommaaccent", "Ucircumflex", -30.0), ("Rcommaaccent", "Udieresis", -30.0), ("Rcommaaccent", "Ugrave", -30.0), ("Rcommaaccent", "Uhungarumlaut", -30.0), ("Rcommaaccent", "Umacron", -30.0), ("Rcommaaccent", "Uogonek", -30.0), ("Rcommaaccent", "Uring", -30.0), ("Rcommaaccent", "V", -55.0), ("Rcommaaccent", "W", -35.0), ("Rcommaaccent", "Y", -35.0), ("Rcommaaccent", "Yacute", -35.0), ("Rcommaaccent", "Ydieresis", -35.0), ("T", "A", -90.0), ("T", "Aacute", -90.0), ("T", "Abreve", -90.0), ("T", "Acircumflex", -90.0), ("T", "Adieresis", -90.0), ("T", "Agrave", -90.0), ("T", "Amacron", -90.0), ("T", "Aogonek", -90.0), ("T", "Aring", -90.0), ("T", "Atilde", -90.0), ("T", "O", -18.0), ("T", "Oacute", -18.0), ("T", "Ocircumflex", -18.0), ("T", "Odieresis", -18.0), ("T", "Ograve", -18.0), ("T", "Ohungarumlaut", -18.0), ("T", "Omacron", -18.0), ("T", "Oslash", -18.0), ("T", "Otilde", -18.0), ("T", "a", -92.0), ("T", "aacute", -92.0), ("T", "abreve", -52.0), ("T", "acircumflex", -52.0), ("T", "adieresis", -52.0), ("T", "agrave", -52.0), ("T", "amacron", -52.0), ("T", "aogonek", -92.0), ("T", "aring", -92.0), ("T", "atilde", -52.0), ("T", "colon", -74.0), ("T", "comma", -74.0), ("T", "e", -92.0), ("T", "eacute", -92.0), ("T", "ecaron", -92.0), ("T", "ecircumflex", -92.0), ("T", "edieresis", -52.0), ("T", "edotaccent", -92.0), ("T", "egrave", -52.0), ("T", "emacron", -52.0), ("T", "eogonek", -92.0), ("T", "hyphen", -92.0), ("T", "i", -18.0), ("T", "iacute", -18.0), ("T", "iogonek", -18.0), ("T", "o", -92.0), ("T", "oacute", -92.0), ("T", "ocircumflex", -92.0), ("T", "odieresis", -92.0), ("T", "ograve", -92.0), ("T", "ohungarumlaut", -92.0), ("T", "omacron", -92.0), ("T", "oslash", -92.0), ("T", "otilde", -92.0), ("T", "period", -90.0), ("T", "r", -74.0), ("T", "racute", -74.0), ("T", "rcaron", -74.0), ("T", "rcommaaccent", -74.0), ("T", "semicolon", -74.0), ("T", "u", -92.0), ("T", "uacute", -92.0), ("T", "ucircumflex", -92.0), ("T", "udieresis", -92.0), ("T", "ugrave", -92.0), ("T", "uhungarumlaut", -92.0), ("T", "umacron", -92.0), ("T", "uogonek", -92.0), ("T", "uring", -92.0), ("T", "w", -74.0), ("T", "y", -34.0), ("T", "yacute", -34.0), ("T", "ydieresis", -34.0), ("Tcaron", "A", -90.0), ("Tcaron", "Aacute", -90.0), ("Tcaron", "Abreve", -90.0), ("Tcaron", "Acircumflex", -90.0), ("Tcaron", "Adieresis", -90.0), ("Tcaron", "Agrave", -90.0), ("Tcaron", "Amacron", -90.0), ("Tcaron", "Aogonek", -90.0), ("Tcaron", "Aring", -90.0), ("Tcaron", "Atilde", -90.0), ("Tcaron", "O", -18.0), ("Tcaron", "Oacute", -18.0), ("Tcaron", "Ocircumflex", -18.0), ("Tcaron", "Odieresis", -18.0), ("Tcaron", "Ograve", -18.0), ("Tcaron", "Ohungarumlaut", -18.0), ("Tcaron", "Omacron", -18.0), ("Tcaron", "Oslash", -18.0), ("Tcaron", "Otilde", -18.0), ("Tcaron", "a", -92.0), ("Tcaron", "aacute", -92.0), ("Tcaron", "abreve", -52.0), ("Tcaron", "acircumflex", -52.0), ("Tcaron", "adieresis", -52.0), ("Tcaron", "agrave", -52.0), ("Tcaron", "amacron", -52.0), ("Tcaron", "aogonek", -92.0), ("Tcaron", "aring", -92.0), ("Tcaron", "atilde", -52.0), ("Tcaron", "colon", -74.0), ("Tcaron", "comma", -74.0), ("Tcaron", "e", -92.0), ("Tcaron", "eacute", -92.0), ("Tcaron", "ecaron", -92.0), ("Tcaron", "ecircumflex", -92.0), ("Tcaron", "edieresis", -52.0), ("Tcaron", "edotaccent", -92.0), ("Tcaron", "egrave", -52.0), ("Tcaron", "emacron", -52.0), ("Tcaron", "eogonek", -92.0), ("Tcaron", "hyphen", -92.0), ("Tcaron", "i", -18.0), ("Tcaron", "iacute", -18.0), ("Tcaron", "iogonek", -18.0), ("Tcaron", "o", -92.0), ("Tcaron", "oacute", -92.0), ("Tcaron", "ocircumflex", -92.0), ("Tcaron", "odieresis", -92.0), ("Tcaron", "ograve", -92.0), ("Tcaron", "ohungarumlaut", -92.0), ("Tcaron", "omacron", -92.0), ("Tcaron", "oslash", -92.0), ("Tcaron", "otilde", -92.0), ("Tcaron", "period", -90.0), ("Tcaron", "r", -74.0), ("Tcaron", "racute", -74.0), ("Tcaron", "rcaron", -74.0), ("Tcaron", "rcommaaccent", -74.0), ("Tcaron", "semicolon", -74.0), ("Tcaron", "u", -92.0), ("Tcaron", "uacute", -92.0), ("Tcaron", "ucircumflex", -92.0), ("Tcaron", "udieresis", -92.0), ("Tcaron", "ugrave", -92.0), ("Tcaron", "uhungarumlaut", -92.0), ("Tcaron", "umacron", -92.0), ("Tcaron", "uogonek", -92.0), ("Tcaron", "uring", -92.0), ("Tcaron", "w", -74.0), ("Tcaron", "y", -34.0), ("Tcaron", "yacute", -34.0), ("Tcaron", "ydieresis", -34.0), ("Tcommaaccent", "A", -90.0), ("Tcommaaccent", "Aacute", -90.0), ("Tcommaaccent", "Abreve", -90.0), ("Tcommaaccent", "Acircumflex", -90.0), ("Tcommaaccent", "Adieresis", -90.0), ("Tcommaaccent", "Agrave", -90.0), ("Tcommaaccent", "Amacron", -90.0), ("Tcommaaccent", "Aogonek", -90.0), ("Tcommaaccent", "Aring", -90.0), ("Tcommaaccent", "Atilde", -90.0), ("Tcommaaccent", "O", -18.0), ("Tcommaaccent", "Oacute", -18.0), ("Tcommaaccent", "Ocircumflex", -18.0), ("Tcommaaccent", "Odieresis", -18.0), ("Tcommaaccent", "Ograve", -18.0), ("Tcommaaccent", "Ohungarumlaut", -18.0), ("Tcommaaccent", "Omacron", -18.0), ("Tcommaaccent", "Oslash", -18.0), ("Tcommaaccent", "Otilde", -18.0), ("Tcommaaccent", "a", -92.0), ("Tcommaaccent", "aacute", -92.0), ("Tcommaaccent", "abreve", -52.0), ("Tcommaaccent", "acircumflex", -52.0), ("Tcommaaccent", "adieresis", -52.0), ("Tcommaaccent", "agrave", -52.0), ("Tcommaaccent", "amacron", -52.0), ("Tcommaaccent", "aogonek", -92.0), ("Tcommaaccent", "aring", -92.0), ("Tcommaaccent", "atilde", -52.0), ("Tcommaaccent", "colon", -74.0), ("Tcommaaccent", "comma", -74.0), ("Tcommaaccent", "e", -92.0), ("Tcommaaccent", "eacute", -92.0), ("Tcommaaccent", "ecaron", -92.0), ("Tcommaaccent", "ecircumflex", -92.0), ("Tcommaaccent", "edieresis", -52.0), ("Tcommaaccent", "edotaccent", -92.0), ("Tcommaaccent", "egrave", -52.0), ("Tcommaaccent", "emacron", -52.0), ("Tcommaaccent", "eogonek", -92.0), ("Tcommaaccent", "hyphen", -92.0), ("Tcommaaccent", "i", -18.0), ("Tcommaaccent", "iacute", -18.0), ("Tcommaaccent", "iogonek", -18.0), ("Tcommaaccent", "o", -92.0), ("Tcommaaccent", "oacute", -92.0), ("Tcommaaccent", "ocircumflex", -92.0), ("Tcommaaccent", "odieresis", -92.0), ("Tcommaaccent", "ograve", -92.0), ("Tcommaaccent", "ohungarumlaut", -92.0), ("Tcommaaccent", "omacron", -92.0), ("Tcommaaccent", "oslash", -92.0), ("Tcommaaccent", "otilde", -92.0), ("Tcommaaccent", "period", -90.0), ("Tcommaaccent", "r", -74.0), ("Tcommaaccent", "racute", -74.0), ("Tcommaaccent", "rcaron", -74.0), ("Tcommaaccent", "rcommaaccent", -74.0), ("Tcommaaccent", "semicolon", -74.0), ("Tcommaaccent", "u", -92.0), ("Tcommaaccent", "uacute", -92.0), ("Tcommaaccent", "ucircumflex", -92.0), ("Tcommaaccent", "udieresis", -92.0), ("Tcommaaccent", "ugrave", -92.0), ("Tcommaaccent", "uhungarumlaut", -92.0), ("Tcommaaccent", "umacron", -92.0), ("Tcommaaccent", "uogonek", -92.0), ("Tcommaaccent", "uring", -92.0), ("Tcommaaccent", "w", -74.0), ("Tcommaaccent", "y", -34.0), ("Tcommaaccent", "yacute", -34.0), ("Tcommaaccent", "ydieresis", -34.0), ("U", "A", -60.0), ("U", "Aacute", -60.0), ("U", "Abreve", -60.0), ("U", "Acircumflex", -60.0), ("U", "Adieresis", -60.0), ("U", "Agrave", -60.0), ("U", "Amacron", -60.0), ("U", "Aogonek", -60.0), ("U", "Aring", -60.0), ("U", "Atilde", -60.0), ("U", "comma", -50.0), ("U", "period", -50.0), ("Uacute", "A", -60.0), ("Uacute", "Aacute", -60.0), ("Uacute", "Abreve", -60.0), ("Uacute", "Acircumflex", -60.0), ("Uacute", "Adieresis", -60.0), ("Uacute", "Agrave", -60.0), ("Uacute", "Amacron", -60.0), ("Uacute", "Aogonek", -60.0), ("Uacute", "Aring", -60.0), ("Uacute", "Atilde", -60.0), ("Uacute", "comma", -50.0), ("Uacute", "period", -50.0), ("Ucircumflex", "A", -60.0), ("Ucircumflex", "Aacute", -60.0), ("Ucircumflex", "Abreve", -60.0), ("Ucircumflex", "Acircumflex", -60.0), ("Ucircumflex", "Adieresis", -60.0), ("Ucircumflex", "Agrave", -60.0), ("Ucircumflex", "Amacron", -60.0), ("Ucircumflex", "Aogonek", -60.0), ("Ucircumflex", "Aring", -60.0), ("Ucircumflex", "Atilde", -60.0), ("Ucircumflex", "comma", -50.0), ("Ucircumflex", "period", -50.0), ("Udieresis", "A", -60.0), ("Udieresis", "Aacute", -60.0), ("Udieresis", "Abreve", -60.0), ("Udieresis", "Acircumflex", -60.0), ("Udieresis", "Adieresis", -60.0), ("Udieresis", "Agrave", -60.0), ("Udieresis", "Amacron", -60.0), ("Udieresis", "Aogonek", -60.0), ("Udieresis", "Aring", -60.0), ("Udieresis", "Atilde", -60.0), ("Udieresis", "comma", -50.0), ("Udieresis", "period", -50.0), ("Ugrave", "A", -60.0), ("Ugrave", "Aacute", -60.0), ("Ugrave", "Abreve", -60.0), ("Ugrave", "Acircumflex", -60.0), ("Ugrave", "Adieresis", -60.0), ("Ugrave", "Agrave", -60.0), ("Ugrave", "Amacron", -60.0), ("Ugrave", "Aogonek", -60.0), ("Ugrave", "Aring", -60.0), ("Ugrave", "Atilde", -60.0), ("Ugrave", "comma", -50.0), ("Ugrave", "period", -50.0), ("Uhungarumlaut", "A", -60.0), ("Uhungarumlaut", "Aacute", -60.0), ("Uhungarumlaut", "Abreve", -60.0), ("Uhungarumlaut", "Acircumflex", -60.0), ("Uhungarumlaut", "Adieresis", -60.0), ("Uhungarumlaut", "Agrave", -60.0), ("Uhungarumlaut", "Amacron", -60.0), ("Uhungarumlaut", "Aogonek", -60.0), ("Uhungarumlaut", "Aring", -60.0), ("Uhungarumlaut", "Atilde", -60.0), ("Uhungarumlaut", "comma", -50.0), ("Uhungarumlaut", "period", -50.0), ("Umacron", "A", -60.0), ("Umacron", "Aacute", -60.0), ("Umacron", "Abreve", -60.0), ("Umacron", "Acircumflex", -60.0), ("Umacron", "Adieresis", -60.0), ("Umacron", "Agrave", -60.0), ("Umacron", "Amacron", -60.0), ("Umacron", "Aogonek", -60.0), ("Umacron", "Aring", -60.0), ("Umacron", "Atilde", -60.0), ("Umacron", "comma", -50.0), ("Umacron", "period", -50.0), ("Uogonek", "A", -60.0), ("Uogonek", "Aacute", -60.0), ("Uogonek", "Abreve", -60.0), ("Uogonek", "Acircumflex", -60.0), ("Uogonek", "Adieresis", -60.0), ("Uogonek", "Agrave", -60.0), ("Uogonek", "Amacron", -60.0), ("Uogonek", "Aogonek", -60.0), ("Uogonek", "Aring", -60.0), ("Uogonek", "Atilde", -60.0), ("Uogonek", "comma", -50.0), ("Uogonek", "period", -50.0), ("Uring", "A", -60.0), ("Uring", "Aacute", -60.0), ("Uring", "Abreve", -60.0), ("Uring", "Acircumflex", -60.0), ("Uring", "Adieresis", -60.0), ("Uring", "Agrave", -60.0), ("Uring", "Amacron", -60.0), ("Uring", "Aogonek", -60.0), ("Uring", "Aring", -60.0), ("Uring", "Atilde", -60.0), ("Uring", "comma", -50.0), ("Uring", "period", -50.0), ("V", "A", -135.0), ("V", "Aacute", -135.0), ("V", "Abreve", -135.0), ("V", "Acircumflex", -135.0), ("V", "Adieresis", -135.0), ("V", "Agrave", -135.0), ("V", "Amacron", -135.0), ("V", "Aogonek", -135.0), ("V", "Aring", -135.0), ("V", "Atilde", -135.0), ("V", "G", -30.0), ("V", "Gbreve", -30.0), ("V", "Gcommaaccent", -30.0), ("V", "O", -45.0), ("V", "Oacute", -45.0), ("V", "Ocircumflex", -45.0), ("V", "Odieresis", -45.0), ("V", "Ograve", -45.0), ("V", "Ohungarumlaut", -45.0), ("V", "Omacron", -45.0), ("V", "Oslash", -45.0), ("V", "Otilde", -45.0), ("V", "a", -92.0), ("V", "aacute", -92.0), ("V", "abreve", -92.0), ("V", "acircumflex", -92.0), ("V", "adieresis", -92.0), ("V", "agrave", -92.0), ("V", "amacron", -92.0), ("V", "aogonek", -92.0), ("V", "aring", -92.0), ("V", "atilde", -92.0), ("V", "colon", -92.0), ("V", "comma", -129.0), ("V", "e", -100.0), ("V", "eacute", -100.0), ("V", "ecaron", -100.0), ("V", "ecircumflex", -100.0), ("V", "edieresis", -100.0), ("V", "edotaccent", -100.0), ("V", "egrave", -100.0), ("V", "emacron", -100.0), ("V", "eogonek", -100.0), ("V", "hyphen", -74.0), ("V", "i", -37.0), ("V", "iacute", -37.0), ("V", "icircumflex", -37.0), ("V", "idieresis", -37.0), ("V", "igrave", -37.0), ("V", "imacron", -37.0), ("V", "iogonek", -37.0), ("V", "o", -100.0), ("V", "oacute", -100.0), ("V", "ocircumflex", -100.0), ("V", "odieresis", -100.0), ("V", "ograve", -100.0), ("V", "ohungarumlaut", -100.0), ("V", "omacron", -100.0), ("V", "oslash", -100.0), ("V", "otilde", -100.0), ("V", "period", -145.0), ("V", "semicolon", -92.0), ("V", "u", -92.0), ("V", "uacute", -92.0), ("V", "ucircumflex", -92.0), ("V", "udieresis", -92.0), ("V", "ugrave", -92.0), ("V", "uhungarumlaut", -92.0), ("V", "umacron", -92.0), ("V", "uogonek", -92.0), ("V", "uring", -92.0), ("W", "A", -120.0), ("W", "Aacute", -120.0), ("W", "Abreve", -120.0), ("W", "Acircumflex", -120.0), ("W", "Adieresis", -120.0), ("W", "Agrave", -120.0), ("W", "Amacron", -120.0), ("W", "Aogonek", -120.0), ("W", "Aring", -120.0), ("W", "Atilde", -120.0), ("W", "O", -10.0), ("W", "Oacute", -10.0), ("W", "Ocircumflex", -10.0), ("W", "Odieresis", -10.0), ("W", "Ograve", -10.0), ("W", "Ohungarumlaut", -10.0), ("W", "Omacron", -10.0), ("W", "Oslash", -10.0), ("W", "Otilde", -10.0), ("W", "a", -65.0), ("W", "aacute", -65.0), ("W", "abreve", -65.0), ("W", "acircumflex", -65.0), ("W", "adieresis", -65.0), ("W", "agrave", -65.0), ("W", "amacron", -65.0), ("W", "aogonek", -65.0), ("W", "aring", -65.0), ("W", "atilde", -65.0), ("W", "colon", -55.0), ("W", "comma", -92.0), ("W", "e", -65.0), ("W", "eacute", -65.0), ("W", "ecaron", -65.0), ("W", "ecircumflex", -65.0), ("W", "edieresis", -65.0), ("W", "edotaccent", -65.0), ("W", "egrave", -65.0), ("W", "emacron", -65.0), ("W", "eogonek", -65.0), ("W", "hyphen", -37.0), ("W", "i", -18.0), ("W", "iacute", -18.0), ("W", "iogonek", -18.0), ("W", "o", -75.0), ("W", "oacute", -75.0), ("W", "ocircumflex", -75.0), ("W", "odieresis", -75.0), ("W", "ograve", -75.0), ("W", "ohungarumlaut", -75.0), ("W", "omacron", -75.0), ("W", "oslash", -75.0), ("W", "otilde", -75.0), ("W", "period", -92.0), ("W", "semicolon", -55.0), ("W", "u", -50.0), ("W", "uacute", -50.0), ("W", "ucircumflex", -50.0), ("W", "udieresis", -50.0), ("W", "ugrave", -50.0), ("W", "uhungarumlaut", -50.0), ("W", "umacron", -50.0), ("W", "uogonek", -50.0), ("W", "uring", -50.0), ("W", "y", -60.0), ("W", "yacute", -60.0), ("W", "ydieresis", -60.0), ("Y", "A", -110.0), ("Y", "Aacute", -110.0), ("Y", "Abreve", -110.0), ("Y", "Acircumflex", -110.0), ("Y", "Adieresis", -110.0), ("Y", "Agrave", -110.0), ("Y", "Amacron", -110.0), ("Y", "Aogonek", -110.0), ("Y", "Aring", -110.0), ("Y", "Atilde", -110.0), ("Y", "O", -35.0), ("Y", "Oacute", -35.0), ("Y", "Ocircumflex", -35.0), ("Y", "Odieresis", -35.0), ("Y", "Ograve", -35.0), ("Y", "Ohungarumlaut", -35.0), ("Y", "Omacron", -35.0), ("Y", "Oslash", -35.0), ("Y", "Otilde", -35.0), ("Y", "a", -85.0), ("Y", "aacute", -85.0), ("Y", "abreve", -85.0), ("Y", "acircumflex", -85.0), ("Y", "adieresis", -85.0), ("Y", "agrave", -85.0), ("Y", "amacron", -85.0), ("Y", "aogonek", -85.0), ("Y", "aring", -85.0), ("Y", "atilde", -85.0), ("Y", "colon", -92.0), ("Y", "comma", -92.0), ("Y", "e", -111.0), ("Y", "eacute", -111.0), ("Y", "ecaron", -111.0), ("Y", "ecircumflex", -111.0), ("Y", "edieresis", -71.0), ("Y", "edotaccent", -111.0), ("Y", "egrave", -71.0), ("Y", "emacron", -71.0), ("Y", "eogonek", -111.0), ("Y", "hyphen", -92.0), ("Y", "i", -37.0), ("Y", "iacute", -37.0), ("Y", "iogonek", -37.0), ("Y", "o", -111.0), ("Y", "oacute", -111.0), ("Y", "ocircumflex", -111.0), ("Y", "odieresis", -111.0), ("Y", "ograve", -111.0), ("Y", "ohungarumlaut", -111.0), ("Y", "omacron", -111.0), ("Y", "oslash", -111.0), ("Y", "otilde", -111.0), ("Y", "period", -92.0), ("Y", "semicolon", -92.0), ("Y", "u", -92.0), ("Y", "uacute", -92.0), ("Y", "ucircumflex", -92.0), ("Y", "udieresis", -92.0), ("Y", "ugrave", -92.0), ("Y", "uhungarumlaut", -92.0), ("Y", "umacron", -92.0), ("Y", "uogonek", -92.0), ("Y", "uring", -92.0), ("Yacute", "A", -110.0), ("Yacute", "Aacute", -110.0), ("Yacute", "Abreve", -110.0), ("Yacute", "Acircumflex", -110.0), ("Yacute", "Adieresis", -110.0), ("Yacute", "Agrave", -110.0), ("Yacute", "Amacron", -110.0), ("Yacute", "Aogonek", -110.0), ("Yacute", "Aring", -110.0), ("Yacute", "Atilde", -110.0), ("Yacute", "O", -35.0), ("Yacute", "Oacute", -35.0), ("Yacute", "Ocircumflex", -35.0), ("Yacute", "Odieresis", -35.0), ("Yacute", "Ograve", -35.0), ("Yacute", "Ohungarumlaut", -35.0), ("Yacute", "Omacron", -35.0), ("Yacute", "Oslash", -35.0), ("Yacute", "Otilde", -35.0), ("Yacute", "a", -85.0), ("Yacute", "aacute", -85.0), ("Yacute", "abreve", -85.0), ("Yacute", "acircumflex", -85.0), ("Yacute", "adieresis", -85.0), ("Yacute", "agrave", -85.0), ("Yacute", "amacron", -85.0), ("Yacute", "aogonek", -85.0), ("Yacute", "aring", -85.0), ("Yacute", "atilde", -85.0), ("Yacute", "colon", -92.0), ("Yacute", "comma", -92.0), ("Yacute", "e", -111.0), ("Yacute", "eacute", -111.0), ("Yacute", "ecaron", -111.0), ("Yacute", "ecircumflex", -111.0), ("Yacute", "edieresis", -71.0), ("Yacute", "edotaccent", -111.0), ("Yacute", "egrave", -71.0), ("Yacute", "emacron", -71.0), ("Yacute", "eogonek", -111.0), ("Yacute", "hyphen", -92.0), ("Yacute", "i", -37.0), ("Yacute", "iacute", -37.0), ("Yacute", "iogonek", -37.0), ("Yacute", "o", -111.0), ("Yacute", "oacute", -111.0), ("Yacute", "ocircumflex", -111.0), ("Yacute", "odieresis", -111.0), ("Yacute", "ograve", -111.0), ("Yacute", "ohungarumlaut", -111.0), ("Yacute", "omacron", -111.0), ("Yacute", "oslash", -111.0), ("Yacute", "otilde", -111.0), ("Yacute", "period", -92.0), ("Yacute", "semicolon", -92.0), ("Yacute", "u", -92.0), ("Yacute", "uacute", -92.0), ("Yacute", "ucircumflex", -92.0), ("Yacute", "udieresis", -92.0), ("Yacute", "ugrave", -92.0), ("Yacute", "uhungarumlaut", -92.0), ("Yacute", "umacron", -92.0), ("Yacute", "uogonek", -92.0), ("Yacute", "uring", -92.0), ("Ydieresis", "A", -110.0), ("Ydieresis", "Aacute", -110.0), ("Ydieresis", "Abreve", -110.0), ("Ydieresis", "Acircumflex", -110.0), ("Ydieresis", "Adieresis", -110.0), ("Ydieresis", "Agrave", -110.0), ("Ydieresis", "Amacron", -110.0), ("Ydieresis", "Aogonek", -110.0), ("Ydieresis", "Aring", -110.0), ("Ydieresis", "Atilde", -110.0), ("Ydieresis", "O", -35.0), ("Ydieresis", "Oacute", -35.0), ("Ydieresis", "Ocircumflex", -35.0), ("Ydieresis", "Odieresis", -35.0), ("Ydieresis", "Ograve", -35.0), ("Ydieresis", "Ohungarumlaut", -35.0), ("Ydieresis", "Omacron", -35.0), ("Ydieresis", "Oslash", -35.0), ("Ydieresis", "Otilde", -35.0), ("Ydieresis", "a", -85.0), ("Ydieresis", "aacute", -85.0), ("Ydieresis", "abreve", -85.0), ("Ydieresis", "acircumflex", -85.0), ("Ydieresis", "adieresis", -85.0), ("Ydieresis", "agrave", -85.0), ("Ydieresis", "amacron", -85.0), ("Ydieresis", "aogonek", -85.0), ("Ydieresis", "aring", -85.0), ("Ydieresis", "atilde", -85.0), ("Ydieresis", "colon", -92.0), ("Ydieresis", "comma", -92.0), ("Ydieresis", "e", -111.0), ("Ydieresis", "eacute", -111.0), ("Ydieresis", "ecaron", -111.0), ("Ydieresis", "ecircumflex", -111.0), ("Ydieresis", "edieresis", -71.0), ("Ydieresis", "edotaccent", -111.0), ("Ydieresis", "egrave", -71.0), ("Ydieresis", "emacron", -71.0), ("Ydieresis", "eogonek", -111.0), ("Ydieresis", "hyphen", -92.0), ("Ydieresis", "i", -37.0), ("Ydieresis", "iacute", -37.0), ("Ydieresis", "iogonek", -37.0), ("Ydieresis", "o", -111.0), ("Ydieresis", "oacute", -111.0), ("Ydieresis", "ocircumflex", -111.0), ("Ydieresis", "odieresis", -111.0), ("Ydieresis", "ograve", -111.0), ("Ydieresis", "ohungarumlaut", -111.0), ("Ydieresis", "omacron", -111.0), ("Ydieresis", "oslash", -111.0), ("Ydieresis", "otilde", -111.0), ("Ydieresis", "period", -92.0), ("Ydieresis", "semicolon", -92.0), ("Ydieresis", "u", -92.0), ("Ydieresis", "uacute", -92.0), ("Ydieresis", "ucircumflex", -92.0), ("Ydieresis", "udieresis", -92.0), ("Ydieresis", "ugrave", -92.0), ("Ydieresis", "uhungarumlaut", -92.0), ("Ydieresis", "umacron", -92.0), ("Ydieresis", "uogonek", -92.0), ("Ydieresis", "uring", -92.0), ("a", "v", -25.0), ("aacute", "v", -25.0), ("abreve", "v", -25.0), ("acircumflex", "v", -25.0), ("adieresis", "v", -25.0), ("agrave", "v", -25.0), ("amacron", "v", -25.0), ("aogonek", "v", -25.0), ("aring", "v", -25.0), ("atilde", "v", -25.0), ("b", "b", -10.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -15.0), ("comma", "quotedblright", -45.0), ("comma", "quoteright", -55.0), ("d", "w", -15.0), ("dcroat", "w", -15.0), ("e", "v", -15.0), ("eacute", "v", -15.0), ("ecaron", "v", -15.0), ("ecircumflex", "v", -15.0), ("edieresis", "v", -15.0), ("edotaccent", "v", -15.0), ("egrave", "v", -15.0), ("emacron", "v", -15.0), ("eogonek", "v", -15.0), ("f", "comma", -15.0), ("f", "dotlessi", -35.0), ("f", "i", -25.0), ("f", "o", -25.0), ("f", "oacute", -25.0), ("f", "ocircumflex", -25.0), ("f", "odieresis", -25.0), ("f", "ograve", -25.0), ("f", "ohungarumlaut", -25.0), ("f", "omacron", -25.0), ("f", "oslash", -25.0), ("f", "otilde", -25.0), ("f", "period", -15.0), ("f", "quotedblright", 50.0), ("f", "quoteright", 55.0), ("g", "period", -15.0), ("gbreve", "period", -15.0), ("gcommaaccent", "period", -15.0), ("h", "y", -15.0), ("h", "yacute", -15.0), ("h", "ydieresis", -15.0), ("i", "v", -10.0), ("iacute", "v", -10.0), ("icircumflex", "v", -10.0), ("idieresis", "v", -10.0), ("igrave", "v", -10.0), ("imacron", "v", -10.0), ("iogonek", "v", -10.0), ("k", "e", -10.0), ("k", "eacute", -10.0), ("k", "ecaron", -10.0), ("k", "ecircumflex", -10.0), ("k", "edieresis", -10.0), ("k", "edotaccent", -10.0), ("k", "egrave", -10.0), ("k", "emacron", -10.0), ("k", "eogonek", -10.0), ("k", "o", -15.0), ("k", "oacute", -15.0), ("k", "ocircumflex", -15.0), ("k", "odieresis", -15.0), ("k", "ograve", -15.0), ("k", "ohungarumlaut", -15.0), ("k", "omacron", -15.0), ("k", "oslash", -15.0), ("k", "otilde", -15.0), ("k", "y", -15.0), ("k", "yacute", -15.0), ("k", "ydieresis", -15.0), ("kcommaaccent", "e", -10.0), ("kcommaaccent", "eacute", -10.0), ("kcommaaccent", "ecaron", -10.0), ("kcommaaccent", "ecircumflex", -10.0), ("kcommaaccent", "edieresis", -10.0), ("kcommaaccent", "edotaccent", -10.0), ("kcommaaccent", "egrave", -10.0), ("kcommaaccent", "emacron", -10.0), ("kcommaaccent", "eogonek", -10.0), ("kcommaaccent", "o", -15.0), ("kcommaaccent", "oacute", -15.0), ("kcommaaccent", "ocircumflex", -15.0), ("kcommaaccent", "odieresis", -15.0), ("kcommaaccent", "ograve", -15.0), ("kcommaaccent", "ohungarumlaut", -15.0), ("kcommaaccent", "omacron", -15.0), ("kcommaaccent", "oslash", -15.0), ("kcommaaccent", "otilde", -15.0), ("kcommaaccent", "y", -15.0), ("kcommaaccent", "yacute", -15.0), ("kcommaaccent", "ydieresis", -15.0), ("n", "v", -40.0), ("nacute", "v", -40.0), ("ncaron", "v", -40.0), ("ncommaaccent", "v", -40.0), ("ntilde", "v", -40.0), ("o", "v", -10.0), ("o", "w", -10.0), ("oacute", "v", -10.0), ("oacute", "w", -10.0), ("ocircumflex", "v", -10.0), ("ocircumflex", "w", -10.0), ("odieresis", "v", -10.0), ("odieresis", "w", -10.0), ("ograve", "v", -10.0), ("ograve", "w", -10.0), ("ohungarumlaut", "v", -10.0), ("ohungarumlaut", "w", -10.0), ("omacron", "v", -10.0), ("omacron", "w", -10.0), ("oslash", "v", -10.0), ("oslash", "w", -10.0), ("otilde", "v", -10.0), ("otilde", "w", -10.0), ("period", "quotedblright", -55.0), ("period", "quoteright", -55.0), ("quotedblleft", "A", -10.0), ("quotedblleft", "Aacute", -10.0), ("quotedblleft", "Abreve", -10.0), ("quotedblleft", "Acircumflex", -10.0), ("quotedblleft", "Adieresis", -10.0), ("quotedblleft", "Agrave", -10.0), ("quotedblleft", "Amacron", -10.0), ("quotedblleft", "Aogonek", -10.0), ("quotedblleft", "Aring", -10.0), ("quotedblleft", "Atilde", -10.0), ("quoteleft", "A", -10.0), ("quoteleft", "Aacute", -10.0), ("quoteleft", "Abreve", -10.0), ("quoteleft", "Acircumflex", -10.0), ("quoteleft", "Adieresis", -10.0), ("quoteleft", "Agrave", -10.0), ("quoteleft", "Amacron", -10.0), ("quoteleft", "Aogonek", -10.0), ("quoteleft", "Aring", -10.0), ("quoteleft", "Atilde", -10.0), ("quoteleft", "quoteleft", -63.0), ("quoteright", "d", -20.0), ("quoteright", "dcroat", -20.0), ("quoteright", "quoteright", -63.0), ("quoteright", "r", -20.0), ("quoteright", "racute", -20.0), ("quoteright", "rcaron", -20.0), ("quoteright", "rcommaaccent", -20.0), ("quoteright", "s", -37.0), ("quoteright", "sacute", -37.0), ("quoteright", "scaron", -37.0), ("quoteright", "scedilla", -37.0), ("quoteright", "scommaaccent", -37.0), ("quoteright", "space", -74.0), ("quoteright", "v", -20.0), ("r", "c", -18.0), ("r", "cacute", -18.0), ("r", "ccaron", -18.0), ("r", "ccedilla", -18.0), ("r", "comma", -92.0), ("r", "e", -18.0), ("r", "eacute", -18.0), ("r", "ecaron", -18.0), ("r", "ecircumflex", -18.0), ("r", "edieresis", -18.0), ("r", "edotaccent", -18.0), ("r", "egrave", -18.0), ("r", "emacron", -18.0), ("r", "eogonek", -18.0), ("r", "g", -10.0), ("r", "gbreve", -10.0), ("r", "gcommaaccent", -10.0), ("r", "hyphen", -37.0), ("r", "n", -15.0), ("r", "nacute", -15.0), ("r", "ncaron", -15.0), ("r", "ncommaaccent", -15.0), ("r", "ntilde", -15.0), ("r", "o", -18.0), ("r", "oacute", -18.0), ("r", "ocircumflex", -18.0), ("r", "odieresis", -18.0), ("r", "ograve", -18.0), ("r", "ohungarumlaut", -18.0), ("r", "omacron", -18.0), ("r", "oslash", -18.0), ("r", "otilde", -18.0), ("r", "p", -10.0), ("r", "period", -100.0), ("r", "q", -18.0), ("r", "v", -10.0), ("racute", "c", -18.0), ("racute", "cacute", -18.0), ("racute", "ccaron", -18.0), ("racute", "ccedilla", -18.0), ("racute", "comma", -92.0), ("racute", "e", -18.0), ("racute", "eacute", -18.0), ("racute", "ecaron", -18.0), ("racute", "ecircumflex", -18.0), ("racute", "edieresis", -18.0), ("racute", "edotaccent", -18.0), ("racute", "egrave", -18.0), ("racute", "emacron", -18.0), ("racute", "eogonek", -18.0), ("racute", "g", -10.0), ("racute", "gbreve", -10.0), ("racute", "gcommaaccent", -10.0), ("racute", "hyphen", -37.0), ("racute", "n", -15.0), ("racute", "nacute", -15.0), ("racute", "ncaron", -15.0), ("racute", "ncommaaccent", -15.0), ("racute", "ntilde", -15.0), ("racute", "o", -18.0), ("racute", "oacute", -18.0), ("racute", "ocircumflex", -18.0), ("racute", "odieresis", -18.0), ("racute", "ograve", -18.0), ("racute", "ohungarumlaut", -18.0), ("racute", "omacron", -18.0), ("racute", "oslash", -18.0), ("racute", "otilde", -18.0), ("racute", "p", -10.0), ("racute", "period", -100.0), ("racute", "q", -18.0), ("racute", "v", -10.0), ("rcaron", "c", -18.0), ("rcaron", "cacute", -18.0), ("rcaron", "ccaron", -18.0), ("rcaron", "ccedilla", -18.0), ("rcaron", "comma", -92.0), ("rcaron", "e", -18.0), ("rcaron", "eacute", -18.0), ("rcaron", "ecaron", -18.0), ("rcaron", "ecircumflex", -18.0), ("rcaron", "edieresis", -18.0), ("rcaron", "edotaccent", -18.0), ("rcaron", "egrave", -18.0), ("rcaron", "emacron", -18.0), ("rcaron", "eogonek", -18.0), ("rcaron", "g", -10.0), ("rcaron", "gbreve", -10.0), ("rcaron", "gcommaaccent", -10.0), ("rcaron", "hyphen", -37.0), ("rcaron", "n", -15.0), ("rcaron", "nacute", -15.0), ("rcaron", "ncaron", -15.0), ("rcaron", "ncommaaccent", -15.0), ("rcaron", "ntilde", -15.0), ("rcaron", "o", -18.0), ("rcaron", "oacute", -18.0), ("rcaron", "ocircumflex", -18.0), ("rcaron", "odieresis", -18.0), ("rcaron", "ograve", -18.0), ("rcaron", "ohungarumlaut", -18.0), ("rcaron", "omacron", -18.0), ("rcaron", "oslash", -18.0), ("rcaron", "otilde", -18.0), ("rcaron", "p", -10.0), ("rcaron", "period", -100.0), ("rcaron", "q", -18.0), ("rcaron", "v", -10.0), ("rcommaaccent", "c", -18.0), ("rcommaaccent", "cacute", -18.0), ("rcommaaccent", "ccaron", -18.0), ("rcommaaccent", "ccedilla", -18.0), ("rcommaaccent", "comma", -92.0), ("rcommaaccent", "e", -18.0), ("rcommaaccent", "eacute", -18.0), ("rcommaaccent", "ecaron", -18.0), ("rcommaaccent", "ecircumflex", -18.0), ("rcommaaccent", "edieresis", -18.0), ("rcommaaccent", "edotaccent", -18.0), ("rcommaaccent", "egrave", -18.0), ("rcommaaccent", "emacron", -18.0), ("rcommaaccent", "eogonek", -18.0), ("rcommaaccent", "g", -10.0), ("rcommaaccent", "gbreve", -10.0), ("rcommaaccent", "gcommaaccent", -10.0), ("rcommaaccent", "hyphen", -37.0), ("rcommaaccent", "n", -15.0), ("rcommaaccent", "nacute", -15.0), ("rcommaaccent", "ncaron", -15.0), ("rcommaaccent", "ncommaaccent", -15.0), ("rcommaaccent", "ntilde", -15.0), ("rcommaaccent", "o", -18.0), ("rcommaaccent", "oacute", -18.0), ("rcommaaccent", "ocircumflex", -18.0), ("rcommaaccent", "odieresis", -18.0), ("rcommaaccent", "ograve", -18.0), ("rcommaaccent", "ohungarumlaut", -18.0), ("rcommaaccent", "omacron", -18.0), ("rcommaaccent", "oslash", -18.0), ("rcommaaccent", "otilde", -18.0), ("rcommaaccent", "p", -10.0), ("rcommaaccent", "period", -100.0), ("rcommaaccent", "q", -18.0), ("rcommaaccent", "v", -10.0), ("space", "A", -55.0), ("space", "Aacute", -55.0), ("space", "Abreve", -55.0), ("space", "Acircumflex", -55.0), ("space", "Adieresis", -55.0), ("space", "Agrave", -55.0), ("space", "Amacron", -55.0), ("space", "Aogonek", -55.0), ("space", "Aring", -55.0), ("space", "Atilde", -55.0), ("space", "T", -30.0), ("space", "Tcaron", -30.0), ("space", "Tcommaaccent", -30.0), ("space", "V", -45.0), ("space", "W", -30.0), ("space", "Y", -55.0), ("space", "Yacute", -55.0), ("space", "Ydieresis", -55.0), ("v", "a", -10.0), ("v", "aacute", -10.0), ("v", "abreve", -10.0), ("v", "acircumflex", -10.0), ("v", "adieresis", -10.0), ("v", "agrave", -10.0), ("v", "amacron", -10.0), ("v", "aogonek", -10.0), ("v", "aring", -10.0), ("v", "atilde", -10.0), ("v", "comma", -55.0), ("v", "e", -10.0), ("v", "eacute", -10.0), ("v", "ecaron", -10.0), ("v", "ecircumflex", -10.0), ("v", "edieresis", -10.0), ("v", "edotaccent", -10.0), ("v", "egrave", -10.0), ("v", "emacron", -10.0), ("v", "eogonek", -10.0), ("v", "o", -10.0), ("v", "oacute", -10.0), ("v", "ocircumflex", -10.0), ("v", "odieresis", -10.0), ("v", "ograve", -10.0), ("v", "ohungarumlaut", -10.0), ("v", "omacron", -10.0), ("v", "oslash", -10.0), ("v", "otilde", -10.0), ("v", "period", -70.0), ("w", "comma", -55.0), ("w", "o", -10.0), ("w", "oacute", -10.0), ("w", "ocircumflex", -10.0), ("w", "odieresis", -10.0), ("w", "ograve", -10.0), ("w", "ohungarumlaut", -10.0), ("w", "omacron", -10.0), ("w", "oslash", -10.0), ("w", "otilde", -10.0), ("w", "period", -70.0), ("y", "comma", -55.0), ("y", "e", -10.0), ("y", "eacute", -10.0), ("y", "ecaron", -10.0), ("y", "ecircumflex", -10.0), ("y", "edieresis", -10.0), ("y", "edotaccent", -10.0), ("y", "egrave", -10.0), ("y", "emacron", -10.0), ("y", "eogonek", -10.0), ("y", "o", -25.0), ("y", "oacute", -25.0), ("y", "ocircumflex", -25.0), ("y", "odieresis", -25.0), ("y", "ograve", -25.0), ("y", "ohungarumlaut", -25.0), ("y", "omacron", -25.0), ("y", "oslash", -25.0), ("y", "otilde", -25.0), ("y", "period", -70.0), ("yacute", "comma", -55.0), ("yacute", "e", -10.0), ("yacute", "eacute", -10.0), ("yacute", "ecaron", -10.0), ("yacute", "ecircumflex", -10.0), ("yacute", "edieresis", -10.0), ("yacute", "edotaccent", -10.0), ("yacute", "egrave", -10.0), ("yacute", "emacron", -10.0), ("yacute", "eogonek", -10.0), ("yacute", "o", -25.0), ("yacute", "oacute", -25.0), ("yacute", "ocircumflex", -25.0), ("yacute", "odieresis", -25.0), ("yacute", "ograve", -25.0), ("yacute", "ohungarumlaut", -25.0), ("yacute", "omacron", -25.0), ("yacute", "oslash", -25.0), ("yacute", "otilde", -25.0), ("yacute", "period", -70.0), ("ydieresis", "comma", -55.0), ("ydieresis", "e", -10.0), ("ydieresis", "eacute", -10.0), ("ydieresis", "ecaron", -10.0), ("ydieresis", "ecircumflex", -10.0), ("ydieresis", "edieresis", -10.0), ("ydieresis", "edotaccent", -10.0), ("ydieresis", "egrave", -10.0), ("ydieresis", "emacron", -10.0), ("ydieresis", "eogonek", -10.0), ("ydieresis", "o", -25.0), ("ydieresis", "oacute", -25.0), ("ydieresis", "ocircumflex", -25.0), ("ydieresis", "odieresis", -25.0), ("ydieresis", "ograve", -25.0), ("ydieresis", "ohungarumlaut", -25.0), ("ydieresis", "omacron", -25.0), ("ydieresis", "oslash", -25.0), ("ydieresis", "otilde", -25.0), ("ydieresis", "period", -70.0)]),
+("Times-BoldItalic", &[("A", "C", -65.0), ("A", "Cacute", -65.0), ("A", "Ccaron", -65.0), ("A", "Ccedilla", -65.0), ("A", "G", -60.0), ("A", "Gbreve", -60.0), ("A", "Gcommaaccent", -60.0), ("A", "O", -50.0), ("A", "Oacute", -50.0), ("A", "Ocircumflex", -50.0), ("A", "Odieresis", -50.0), ("A", "Ograve", -50.0), ("A", "Ohungarumlaut", -50.0), ("A", "Omacron", -50.0), ("A", "Oslash", -50.0), ("A", "Otilde", -50.0), ("A", "Q", -55.0), ("A", "T", -55.0), ("A", "Tcaron", -55.0), ("A", "Tcommaaccent", -55.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -95.0), ("A", "W", -100.0), ("A", "Y", -70.0), ("A", "Yacute", -70.0), ("A", "Ydieresis", -70.0), ("A", "quoteright", -74.0), ("A", "u", -30.0), ("A", "uacute", -30.0), ("A", "ucircumflex", -30.0), ("A", "udieresis", -30.0), ("A", "ugrave", -30.0), ("A", "uhungarumlaut", -30.0), ("A", "umacron", -30.0), ("A", "uogonek", -30.0), ("A", "uring", -30.0), ("A", "v", -74.0), ("A", "w", -74.0), ("A", "y", -74.0), ("A", "yacute", -74.0), ("A", "ydieresis", -74.0), ("Aacute", "C", -65.0), ("Aacute", "Cacute", -65.0), ("Aacute", "Ccaron", -65.0), ("Aacute", "Ccedilla", -65.0), ("Aacute", "G", -60.0), ("Aacute", "Gbreve", -60.0), ("Aacute", "Gcommaaccent", -60.0), ("Aacute", "O", -50.0), ("Aacute", "Oacute", -50.0), ("Aacute", "Ocircumflex", -50.0), ("Aacute", "Odieresis", -50.0), ("Aacute", "Ograve", -50.0), ("Aacute", "Ohungarumlaut", -50.0), ("Aacute", "Omacron", -50.0), ("Aacute", "Oslash", -50.0), ("Aacute", "Otilde", -50.0), ("Aacute", "Q", -55.0), ("Aacute", "T", -55.0), ("Aacute", "Tcaron", -55.0), ("Aacute", "Tcommaaccent", -55.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -95.0), ("Aacute", "W", -100.0), ("Aacute", "Y", -70.0), ("Aacute", "Yacute", -70.0), ("Aacute", "Ydieresis", -70.0), ("Aacute", "quoteright", -74.0), ("Aacute", "u", -30.0), ("Aacute", "uacute", -30.0), ("Aacute", "ucircumflex", -30.0), ("Aacute", "udieresis", -30.0), ("Aacute", "ugrave", -30.0), ("Aacute", "uhungarumlaut", -30.0), ("Aacute", "umacron", -30.0), ("Aacute", "uogonek", -30.0), ("Aacute", "uring", -30.0), ("Aacute", "v", -74.0), ("Aacute", "w", -74.0), ("Aacute", "y", -74.0), ("Aacute", "yacute", -74.0), ("Aacute", "ydieresis", -74.0), ("Abreve", "C", -65.0), ("Abreve", "Cacute", -65.0), ("Abreve", "Ccaron", -65.0), ("Abreve", "Ccedilla", -65.0), ("Abreve", "G", -60.0), ("Abreve", "Gbreve", -60.0), ("Abreve", "Gcommaaccent", -60.0), ("Abreve", "O", -50.0), ("Abreve", "Oacute", -50.0), ("Abreve", "Ocircumflex", -50.0), ("Abreve", "Odieresis", -50.0), ("Abreve", "Ograve", -50.0), ("Abreve", "Ohungarumlaut", -50.0), ("Abreve", "Omacron", -50.0), ("Abreve", "Oslash", -50.0), ("Abreve", "Otilde", -50.0), ("Abreve", "Q", -55.0), ("Abreve", "T", -55.0), ("Abreve", "Tcaron", -55.0), ("Abreve", "Tcommaaccent", -55.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -95.0), ("Abreve", "W", -100.0), ("Abreve", "Y", -70.0), ("Abreve", "Yacute", -70.0), ("Abreve", "Ydieresis", -70.0), ("Abreve", "quoteright", -74.0), ("Abreve", "u", -30.0), ("Abreve", "uacute", -30.0), ("Abreve", "ucircumflex", -30.0), ("Abreve", "udieresis", -30.0), ("Abreve", "ugrave", -30.0), ("Abreve", "uhungarumlaut", -30.0), ("Abreve", "umacron", -30.0), ("Abreve", "uogonek", -30.0), ("Abreve", "uring", -30.0), ("Abreve", "v", -74.0), ("Abreve", "w", -74.0), ("Abreve", "y", -74.0), ("Abreve", "yacute", -74.0), ("Abreve", "ydieresis", -74.0), ("Acircumflex", "C", -65.0), ("Acircumflex", "Cacute", -65.0), ("Acircumflex", "Ccaron", -65.0), ("Acircumflex", "Ccedilla", -65.0), ("Acircumflex", "G", -60.0), ("Acircumflex", "Gbreve", -60.0), ("Acircumflex", "Gcommaaccent", -60.0), ("Acircumflex", "O", -50.0), ("Acircumflex", "Oacute", -50.0), ("Acircumflex", "Ocircumflex", -50.0), ("Acircumflex", "Odieresis", -50.0), ("Acircumflex", "Ograve", -50.0), ("Acircumflex", "Ohungarumlaut", -50.0), ("Acircumflex", "Omacron", -50.0), ("Acircumflex", "Oslash", -50.0), ("Acircumflex", "Otilde", -50.0), ("Acircumflex", "Q", -55.0), ("Acircumflex", "T", -55.0), ("Acircumflex", "Tcaron", -55.0), ("Acircumflex", "Tcommaaccent", -55.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -95.0), ("Acircumflex", "W", -100.0), ("Acircumflex", "Y", -70.0), ("Acircumflex", "Yacute", -70.0), ("Acircumflex", "Ydieresis", -70.0), ("Acircumflex", "quoteright", -74.0), ("Acircumflex", "u", -30.0), ("Acircumflex", "uacute", -30.0), ("Acircumflex", "ucircumflex", -30.0), ("Acircumflex", "udieresis", -30.0), ("Acircumflex", "ugrave", -30.0), ("Acircumflex", "uhungarumlaut", -30.0), ("Acircumflex", "umacron", -30.0), ("Acircumflex", "uogonek", -30.0), ("Acircumflex", "uring", -30.0), ("Acircumflex", "v", -74.0), ("Acircumflex", "w", -74.0), ("Acircumflex", "y", -74.0), ("Acircumflex", "yacute", -74.0), ("Acircumflex", "ydieresis", -74.0), ("Adieresis", "C", -65.0), ("Adieresis", "Cacute", -65.0), ("Adieresis", "Ccaron", -65.0), ("Adieresis", "Ccedilla", -65.0), ("Adieresis", "G", -60.0), ("Adieresis", "Gbreve", -60.0), ("Adieresis", "Gcommaaccent", -60.0), ("Adieresis", "O", -50.0), ("Adieresis", "Oacute", -50.0), ("Adieresis", "Ocircumflex", -50.0), ("Adieresis", "Odieresis", -50.0), ("Adieresis", "Ograve", -50.0), ("Adieresis", "Ohungarumlaut", -50.0), ("Adieresis", "Omacron", -50.0), ("Adieresis", "Oslash", -50.0), ("Adieresis", "Otilde", -50.0), ("Adieresis", "Q", -55.0), ("Adieresis", "T", -55.0), ("Adieresis", "Tcaron", -55.0), ("Adieresis", "Tcommaaccent", -55.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -95.0), ("Adieresis", "W", -100.0), ("Adieresis", "Y", -70.0), ("Adieresis", "Yacute", -70.0), ("Adieresis", "Ydieresis", -70.0), ("Adieresis", "quoteright", -74.0), ("Adieresis", "u", -30.0), ("Adieresis", "uacute", -30.0), ("Adieresis", "ucircumflex", -30.0), ("Adieresis", "udieresis", -30.0), ("Adieresis", "ugrave", -30.0), ("Adieresis", "uhungarumlaut", -30.0), ("Adieresis", "umacron", -30.0), ("Adieresis", "uogonek", -30.0), ("Adieresis", "uring", -30.0), ("Adieresis", "v", -74.0), ("Adieresis", "w", -74.0), ("Adieresis", "y", -74.0), ("Adieresis", "yacute", -74.0), ("Adieresis", "ydieresis", -74.0), ("Agrave", "C", -65.0), ("Agrave", "Cacute", -65.0), ("Agrave", "Ccaron", -65.0), ("Agrave", "Ccedilla", -65.0), ("Agrave", "G", -60.0), ("Agrave", "Gbreve", -60.0), ("Agrave", "Gcommaaccent", -60.0), ("Agrave", "O", -50.0), ("Agrave", "Oacute", -50.0), ("Agrave", "Ocircumflex", -50.0), ("Agrave", "Odieresis", -50.0), ("Agrave", "Ograve", -50.0), ("Agrave", "Ohungarumlaut", -50.0), ("Agrave", "Omacron", -50.0), ("Agrave", "Oslash", -50.0), ("Agrave", "Otilde", -50.0), ("Agrave", "Q", -55.0), ("Agrave", "T", -55.0), ("Agrave", "Tcaron", -55.0), ("Agrave", "Tcommaaccent", -55.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -95.0), ("Agrave", "W", -100.0), ("Agrave", "Y", -70.0), ("Agrave", "Yacute", -70.0), ("Agrave", "Ydieresis", -70.0), ("Agrave", "quoteright", -74.0), ("Agrave", "u", -30.0), ("Agrave", "uacute", -30.0), ("Agrave", "ucircumflex", -30.0), ("Agrave", "udieresis", -30.0), ("Agrave", "ugrave", -30.0), ("Agrave", "uhungarumlaut", -30.0), ("Agrave", "umacron", -30.0), ("Agrave", "uogonek", -30.0), ("Agrave", "uring", -30.0), ("Agrave", "v", -74.0), ("Agrave", "w", -74.0), ("Agrave", "y", -74.0), ("Agrave", "yacute", -74.0), ("Agrave", "ydieresis", -74.0), ("Amacron", "C", -65.0), ("Amacron", "Cacute", -65.0), ("Amacron", "Ccaron", -65.0), ("Amacron", "Ccedilla", -65.0), ("Amacron", "G", -60.0), ("Amacron", "Gbreve", -60.0), ("Amacron", "Gcommaaccent", -60.0), ("Amacron", "O", -50.0), ("Amacron", "Oacute", -50.0), ("Amacron", "Ocircumflex", -50.0), ("Amacron", "Odieresis", -50.0), ("Amacron", "Ograve", -50.0), ("Amacron", "Ohungarumlaut", -50.0), ("Amacron", "Omacron", -50.0), ("Amacron", "Oslash", -50.0), ("Amacron", "Otilde", -50.0), ("Amacron", "Q", -55.0), ("Amacron", "T", -55.0), ("Amacron", "Tcaron", -55.0), ("Amacron", "Tcommaaccent", -55.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -95.0), ("Amacron", "W", -100.0), ("Amacron", "Y", -70.0), ("Amacron", "Yacute", -70.0), ("Amacron", "Ydieresis", -70.0), ("Amacron", "quoteright", -74.0), ("Amacron", "u", -30.0), ("Amacron", "uacute", -30.0), ("Amacron", "ucircumflex", -30.0), ("Amacron", "udieresis", -30.0), ("Amacron", "ugrave", -30.0), ("Amacron", "uhungarumlaut", -30.0), ("Amacron", "umacron", -30.0), ("Amacron", "uogonek", -30.0), ("Amacron", "uring", -30.0), ("Amacron", "v", -74.0), ("Amacron", "w", -74.0), ("Amacron", "y", -74.0), ("Amacron", "yacute", -74.0), ("Amacron", "ydieresis", -74.0), ("Aogonek", "C", -65.0), ("Aogonek", "Cacute", -65.0), ("Aogonek", "Ccaron", -65.0), ("Aogonek", "Ccedilla", -65.0), ("Aogonek", "G", -60.0), ("Aogonek", "Gbreve", -60.0), ("Aogonek", "Gcommaaccent", -60.0), ("Aogonek", "O", -50.0), ("Aogonek", "Oacute", -50.0), ("Aogonek", "Ocircumflex", -50.0), ("Aogonek", "Odieresis", -50.0), ("Aogonek", "Ograve", -50.0), ("Aogonek", "Ohungarumlaut", -50.0), ("Aogonek", "Omacron", -50.0), ("Aogonek", "Oslash", -50.0), ("Aogonek", "Otilde", -50.0), ("Aogonek", "Q", -55.0), ("Aogonek", "T", -55.0), ("Aogonek", "Tcaron", -55.0), ("Aogonek", "Tcommaaccent", -55.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -95.0), ("Aogonek", "W", -100.0), ("Aogonek", "Y", -70.0), ("Aogonek", "Yacute", -70.0), ("Aogonek", "Ydieresis", -70.0), ("Aogonek", "quoteright", -74.0), ("Aogonek", "u", -30.0), ("Aogonek", "uacute", -30.0), ("Aogonek", "ucircumflex", -30.0), ("Aogonek", "udieresis", -30.0), ("Aogonek", "ugrave", -30.0), ("Aogonek", "uhungarumlaut", -30.0), ("Aogonek", "umacron", -30.0), ("Aogonek", "uogonek", -30.0), ("Aogonek", "uring", -30.0), ("Aogonek", "v", -74.0), ("Aogonek", "w", -74.0), ("Aogonek", "y", -34.0), ("Aogonek", "yacute", -34.0), ("Aogonek", "ydieresis", -34.0), ("Aring", "C", -65.0), ("Aring", "Cacute", -65.0), ("Aring", "Ccaron", -65.0), ("Aring", "Ccedilla", -65.0), ("Aring", "G", -60.0), ("Aring", "Gbreve", -60.0), ("Aring", "Gcommaaccent", -60.0), ("Aring", "O", -50.0), ("Aring", "Oacute", -50.0), ("Aring", "Ocircumflex", -50.0), ("Aring", "Odieresis", -50.0), ("Aring", "Ograve", -50.0), ("Aring", "Ohungarumlaut", -50.0), ("Aring", "Omacron", -50.0), ("Aring", "Oslash", -50.0), ("Aring", "Otilde", -50.0), ("Aring", "Q", -55.0), ("Aring", "T", -55.0), ("Aring", "Tcaron", -55.0), ("Aring", "Tcommaaccent", -55.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -95.0), ("Aring", "W", -100.0), ("Aring", "Y", -70.0), ("Aring", "Yacute", -70.0), ("Aring", "Ydieresis", -70.0), ("Aring", "quoteright", -74.0), ("Aring", "u", -30.0), ("Aring", "uacute", -30.0), ("Aring", "ucircumflex", -30.0), ("Aring", "udieresis", -30.0), ("Aring", "ugrave", -30.0), ("Aring", "uhungarumlaut", -30.0), ("Aring", "umacron", -30.0), ("Aring", "uogonek", -30.0), ("Aring", "uring", -30.0), ("Aring", "v", -74.0), ("Aring", "w", -74.0), ("Aring", "y", -74.0), ("Aring", "yacute", -74.0), ("Aring", "ydieresis", -74.0), ("Atilde", "C", -65.0), ("Atilde", "Cacute", -65.0), ("Atilde", "Ccaron", -65.0), ("Atilde", "Ccedilla", -65.0), ("Atilde", "G", -60.0), ("Atilde", "Gbreve", -60.0), ("Atilde", "Gcommaaccent", -60.0), ("Atilde", "O", -50.0), ("Atilde", "Oacute", -50.0), ("Atilde", "Ocircumflex", -50.0), ("Atilde", "Odieresis", -50.0), ("Atilde", "Ograve", -50.0), ("Atilde", "Ohungarumlaut", -50.0), ("Atilde", "Omacron", -50.0), ("Atilde", "Oslash", -50.0), ("Atilde", "Otilde", -50.0), ("Atilde", "Q", -55.0), ("Atilde", "T", -55.0), ("Atilde", "Tcaron", -55.0), ("Atilde", "Tcommaaccent", -55.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -95.0), ("Atilde", "W", -100.0), ("Atilde", "Y", -70.0), ("Atilde", "Yacute", -70.0), ("Atilde", "Ydieresis", -70.0), ("Atilde", "quoteright", -74.0), ("Atilde", "u", -30.0), ("Atilde", "uacute", -30.0), ("Atilde", "ucircumflex", -30.0), ("Atilde", "udieresis", -30.0), ("Atilde", "ugrave", -30.0), ("Atilde", "uhungarumlaut", -30.0), ("Atilde", "umacron", -30.0), ("Atilde", "uogonek", -30.0), ("Atilde", "uring", -30.0), ("Atilde", "v", -74.0), ("Atilde", "w", -74.0), ("Atilde", "y", -74.0), ("Atilde", "yacute", -74.0), ("Atilde", "ydieresis", -74.0), ("B", "A", -25.0), ("B", "Aacute", -25.0), ("B", "Abreve", -25.0), ("B", "Acircumflex", -25.0), ("B", "Adieresis", -25.0), ("B", "Agrave", -25.0), ("B", "Amacron", -25.0), ("B", "Aogonek", -25.0), ("B", "Aring", -25.0), ("B", "Atilde", -25.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -25.0), ("D", "Aacute", -25.0), ("D", "Abreve", -25.0), ("D", "Acircumflex", -25.0), ("D", "Adieresis", -25.0), ("D", "Agrave", -25.0), ("D", "Amacron", -25.0), ("D", "Aogonek", -25.0), ("D", "Aring", -25.0), ("D", "Atilde", -25.0), ("D", "V", -50.0), ("D", "W", -40.0), ("D", "Y", -50.0), ("D", "Yacute", -50.0), ("D", "Ydieresis", -50.0), ("Dcaron", "A", -25.0), ("Dcaron", "Aacute", -25.0), ("Dcaron", "Abreve", -25.0), ("Dcaron", "Acircumflex", -25.0), ("Dcaron", "Adieresis", -25.0), ("Dcaron", "Agrave", -25.0), ("Dcaron", "Amacron", -25.0), ("Dcaron", "Aogonek", -25.0), ("Dcaron", "Aring", -25.0), ("Dcaron", "Atilde", -25.0), ("Dcaron", "V", -50.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -50.0), ("Dcaron", "Yacute", -50.0), ("Dcaron", "Ydieresis", -50.0), ("Dcroat", "A", -25.0), ("Dcroat", "Aacute", -25.0), ("Dcroat", "Abreve", -25.0), ("Dcroat", "Acircumflex", -25.0), ("Dcroat", "Adieresis", -25.0), ("Dcroat", "Agrave", -25.0), ("Dcroat", "Amacron", -25.0), ("Dcroat", "Aogonek", -25.0), ("Dcroat", "Aring", -25.0), ("Dcroat", "Atilde", -25.0), ("Dcroat", "V", -50.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -50.0), ("Dcroat", "Yacute", -50.0), ("Dcroat", "Ydieresis", -50.0), ("F", "A", -100.0), ("F", "Aacute", -100.0), ("F", "Abreve", -100.0), ("F", "Acircumflex", -100.0), ("F", "Adieresis", -100.0), ("F", "Agrave", -100.0), ("F", "Amacron", -100.0), ("F", "Aogonek", -100.0), ("F", "Aring", -100.0), ("F", "Atilde", -100.0), ("F", "a", -95.0), ("F", "aacute", -95.0), ("F", "abreve", -95.0), ("F", "acircumflex", -95.0), ("F", "adieresis", -95.0), ("F", "agrave", -95.0), ("F", "amacron", -95.0), ("F", "aogonek", -95.0), ("F", "aring", -95.0), ("F", "atilde", -95.0), ("F", "comma", -129.0), ("F", "e", -100.0), ("F", "eacute", -100.0), ("F", "ecaron", -100.0), ("F", "ecircumflex", -100.0), ("F", "edieresis", -100.0), ("F", "edotaccent", -100.0), ("F", "egrave", -100.0), ("F", "emacron", -100.0), ("F", "eogonek", -100.0), ("F", "i", -40.0), ("F", "iacute", -40.0), ("F", "icircumflex", -40.0), ("F", "idieresis", -40.0), ("F", "igrave", -40.0), ("F", "imacron", -40.0), ("F", "iogonek", -40.0), ("F", "o", -70.0), ("F", "oacute", -70.0), ("F", "ocircumflex", -70.0), ("F", "odieresis", -70.0), ("F", "ograve", -70.0), ("F", "ohungarumlaut", -70.0), ("F", "omacron", -70.0), ("F", "oslash", -70.0), ("F", "otilde", -70.0), ("F", "period", -129.0), ("F", "r", -50.0), ("F", "racute", -50.0), ("F", "rcaron", -50.0), ("F", "rcommaaccent", -50.0), ("J", "A", -25.0), ("J", "Aacute", -25.0), ("J", "Abreve", -25.0), ("J", "Acircumflex", -25.0), ("J", "Adieresis", -25.0), ("J", "Agrave", -25.0), ("J", "Amacron", -25.0), ("J", "Aogonek", -25.0), ("J", "Aring", -25.0), ("J", "Atilde", -25.0), ("J", "a", -40.0), ("J", "aacute", -40.0), ("J", "abreve", -40.0), ("J", "acircumflex", -40.0), ("J", "adieresis", -40.0), ("J", "agrave", -40.0), ("J", "amacron", -40.0), ("J", "aogonek", -40.0), ("J", "aring", -40.0), ("J", "atilde", -40.0), ("J", "comma", -10.0), ("J", "e", -40.0), ("J", "eacute", -40.0), ("J", "ecaron", -40.0), ("J", "ecircumflex", -40.0), ("J", "edieresis", -40.0), ("J", "edotaccent", -40.0), ("J", "egrave", -40.0), ("J", "emacron", -40.0), ("J", "eogonek", -40.0), ("J", "o", -40.0), ("J", "oacute", -40.0), ("J", "ocircumflex", -40.0), ("J", "odieresis", -40.0), ("J", "ograve", -40.0), ("J", "ohungarumlaut", -40.0), ("J", "omacron", -40.0), ("J", "oslash", -40.0), ("J", "otilde", -40.0), ("J", "period", -10.0), ("J", "u", -40.0), ("J", "uacute", -40.0), ("J", "ucircumflex", -40.0), ("J", "udieresis", -40.0), ("J", "ugrave", -40.0), ("J", "uhungarumlaut", -40.0), ("J", "umacron", -40.0), ("J", "uogonek", -40.0), ("J", "uring", -40.0), ("K", "O", -30.0), ("K", "Oacute", -30.0), ("K", "Ocircumflex", -30.0), ("K", "Odieresis", -30.0), ("K", "Ograve", -30.0), ("K", "Ohungarumlaut", -30.0), ("K", "Omacron", -30.0), ("K", "Oslash", -30.0), ("K", "Otilde", -30.0), ("K", "e", -25.0), ("K", "eacute", -25.0), ("K", "ecaron", -25.0), ("K", "ecircumflex", -25.0), ("K", "edieresis", -25.0), ("K", "edotaccent", -25.0), ("K", "egrave", -25.0), ("K", "emacron", -25.0), ("K", "eogonek", -25.0), ("K", "o", -25.0), ("K", "oacute", -25.0), ("K", "ocircumflex", -25.0), ("K", "odieresis", -25.0), ("K", "ograve", -25.0), ("K", "ohungarumlaut", -25.0), ("K", "omacron", -25.0), ("K", "oslash", -25.0), ("K", "otilde", -25.0), ("K", "u", -20.0), ("K", "uacute", -20.0), ("K", "ucircumflex", -20.0), ("K", "udieresis", -20.0), ("K", "ugrave", -20.0), ("K", "uhungarumlaut", -20.0), ("K", "umacron", -20.0), ("K", "uogonek", -20.0), ("K", "uring", -20.0), ("K", "y", -20.0), ("K", "yacute", -20.0), ("K", "ydieresis", -20.0), ("Kcommaaccent", "O", -30.0), ("Kcommaaccent", "Oacute", -30.0), ("Kcommaaccent", "Ocircumflex", -30.0), ("Kcommaaccent", "Odieresis", -30.0), ("Kcommaaccent", "Ograve", -30.0), ("Kcommaaccent", "Ohungarumlaut", -30.0), ("Kcommaaccent", "Omacron", -30.0), ("Kcommaaccent", "Oslash", -30.0), ("Kcommaaccent", "Otilde", -30.0), ("Kcommaaccent", "e", -25.0), ("Kcommaaccent", "eacute", -25.0), ("Kcommaaccent", "ecaron", -25.0), ("Kcommaaccent", "ecircumflex", -25.0), ("Kcommaaccent", "edieresis", -25.0), ("Kcommaaccent", "edotaccent", -25.0), ("Kcommaaccent", "egrave", -25.0), ("Kcommaaccent", "emacron", -25.0), ("Kcommaaccent", "eogonek", -25.0), ("Kcommaaccent", "o", -25.0), ("Kcommaaccent", "oacute", -25.0), ("Kcommaaccent", "ocircumflex", -25.0), ("Kcommaaccent", "odieresis", -25.0), ("Kcommaaccent", "ograve", -25.0), ("Kcommaaccent", "ohungarumlaut", -25.0), ("Kcommaaccent", "omacron", -25.0), ("Kcommaaccent", "oslash", -25.0), ("Kcommaaccent", "otilde", -25.0), ("Kcommaaccent", "u", -20.0), ("Kcommaaccent", "uacute", -20.0), ("Kcommaaccent", "ucircumflex", -20.0), ("Kcommaaccent", "udieresis", -20.0), ("Kcommaaccent", "ugrave", -20.0), ("Kcommaaccent", "uhungarumlaut", -20.0), ("Kcommaaccent", "umacron", -20.0), ("Kcommaaccent", "uogonek", -20.0), ("Kcommaaccent", "uring", -20.0), ("Kcommaaccent", "y", -20.0), ("Kcommaaccent", "yacute", -20.0), ("Kcommaaccent", "ydieresis", -20.0), ("L", "T", -18.0), ("L", "Tcaron", -18.0), ("L", "Tcommaaccent", -18.0), ("L", "V", -37.0), ("L", "W", -37.0), ("L", "Y", -37.0), ("L", "Yacute", -37.0), ("L", "Ydieresis", -37.0), ("L", "quoteright", -55.0), ("L", "y", -37.0), ("L", "yacute", -37.0), ("L", "ydieresis", -37.0), ("Lacute", "T", -18.0), ("Lacute", "Tcaron", -18.0), ("Lacute", "Tcommaaccent", -18.0), ("Lacute", "V", -37.0), ("Lacute", "W", -37.0), ("Lacute", "Y", -37.0), ("Lacute", "Yacute", -37.0), ("Lacute", "Ydieresis", -37.0), ("Lacute", "quoteright", -55.0), ("Lacute", "y", -37.0), ("Lacute", "yacute", -37.0), ("Lacute", "ydieresis", -37.0), ("Lcommaaccent", "T", -18.0), ("Lcommaaccent", "Tcaron", -18.0), ("Lcommaaccent", "Tcommaaccent", -18.0), ("Lcommaaccent", "V", -37.0), ("Lcommaaccent", "W", -37.0), ("Lcommaaccent", "Y", -37.0), ("Lcommaaccent", "Yacute", -37.0), ("Lcommaaccent", "Ydieresis", -37.0), ("Lcommaaccent", "quoteright", -55.0), ("Lcommaaccent", "y", -37.0), ("Lcommaaccent", "yacute", -37.0), ("Lcommaaccent", "ydieresis", -37.0), ("Lslash", "T", -18.0), ("Lslash", "Tcaron", -18.0), ("Lslash", "Tcommaaccent", -18.0), ("Lslash", "V", -37.0), ("Lslash", "W", -37.0), ("Lslash", "Y", -37.0), ("Lslash", "Yacute", -37.0), ("Lslash", "Ydieresis", -37.0), ("Lslash", "quoteright", -55.0), ("Lslash", "y", -37.0), ("Lslash", "yacute", -37.0), ("Lslash", "ydieresis", -37.0), ("N", "A", -30.0), ("N", "Aacute", -30.0), ("N", "Abreve", -30.0), ("N", "Acircumflex", -30.0), ("N", "Adieresis", -30.0), ("N", "Agrave", -30.0), ("N", "Amacron", -30.0), ("N", "Aogonek", -30.0), ("N", "Aring", -30.0), ("N", "Atilde", -30.0), ("Nacute", "A", -30.0), ("Nacute", "Aacute", -30.0), ("Nacute", "Abreve", -30.0), ("Nacute", "Acircumflex", -30.0), ("Nacute", "Adieresis", -30.0), ("Nacute", "Agrave", -30.0), ("Nacute", "Amacron", -30.0), ("Nacute", "Aogonek", -30.0), ("Nacute", "Aring", -30.0), ("Nacute", "Atilde", -30.0), ("Ncaron", "A", -30.0), ("Ncaron", "Aacute", -30.0), ("Ncaron", "Abreve", -30.0), ("Ncaron", "Acircumflex", -30.0), ("Ncaron", "Adieresis", -30.0), ("Ncaron", "Agrave", -30.0), ("Ncaron", "Amacron", -30.0), ("Ncaron", "Aogonek", -30.0), ("Ncaron", "Aring", -30.0), ("Ncaron", "Atilde", -30.0), ("Ncommaaccent", "A", -30.0), ("Ncommaaccent", "Aacute", -30.0), ("Ncommaaccent", "Abreve", -30.0), ("Ncommaaccent", "Acircumflex", -30.0), ("Ncommaaccent", "Adieresis", -30.0), ("Ncommaaccent", "Agrave", -30.0), ("Ncommaaccent", "Amacron", -30.0), ("Ncommaaccent", "Aogonek", -30.0), ("Ncommaaccent", "Aring", -30.0), ("Ncommaaccent", "Atilde", -30.0), ("Ntilde", "A", -30.0), ("Ntilde", "Aacute", -30.0), ("Ntilde", "Abreve", -30.0), ("Ntilde", "Acircumflex", -30.0), ("Ntilde", "Adieresis", -30.0), ("Ntilde", "Agrave", -30.0), ("Ntilde", "Amacron", -30.0), ("Ntilde", "Aogonek", -30.0), ("Ntilde", "Aring", -30.0), ("Ntilde", "Atilde", -30.0), ("O", "A", -40.0), ("O", "Aacute", -40.0), ("O", "Abreve", -40.0), ("O", "Acircumflex", -40.0), ("O", "Adieresis", -40.0), ("O", "Agrave", -40.0), ("O", "Amacron", -40.0), ("O", "Aogonek", -40.0), ("O", "Aring", -40.0), ("O", "Atilde", -40.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -50.0), ("O", "X", -40.0), ("O", "Y", -50.0), ("O", "Yacute", -50.0), ("O", "Ydieresis", -50.0), ("Oacute", "A", -40.0), ("Oacute", "Aacute", -40.0), ("Oacute", "Abreve", -40.0), ("Oacute", "Acircumflex", -40.0), ("Oacute", "Adieresis", -40.0), ("Oacute", "Agrave", -40.0), ("Oacute", "Amacron", -40.0), ("Oacute", "Aogonek", -40.0), ("Oacute", "Aring", -40.0), ("Oacute", "Atilde", -40.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -50.0), ("Oacute", "X", -40.0), ("Oacute", "Y", -50.0), ("Oacute", "Yacute", -50.0), ("Oacute", "Ydieresis", -50.0), ("Ocircumflex", "A", -40.0), ("Ocircumflex", "Aacute", -40.0), ("Ocircumflex", "Abreve", -40.0), ("Ocircumflex", "Acircumflex", -40.0), ("Ocircumflex", "Adieresis", -40.0), ("Ocircumflex", "Agrave", -40.0), ("Ocircumflex", "Amacron", -40.0), ("Ocircumflex", "Aogonek", -40.0), ("Ocircumflex", "Aring", -40.0), ("Ocircumflex", "Atilde", -40.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -50.0), ("Ocircumflex", "X", -40.0), ("Ocircumflex", "Y", -50.0), ("Ocircumflex", "Yacute", -50.0), ("Ocircumflex", "Ydieresis", -50.0), ("Odieresis", "A", -40.0), ("Odieresis", "Aacute", -40.0), ("Odieresis", "Abreve", -40.0), ("Odieresis", "Acircumflex", -40.0), ("Odieresis", "Adieresis", -40.0), ("Odieresis", "Agrave", -40.0), ("Odieresis", "Amacron", -40.0), ("Odieresis", "Aogonek", -40.0), ("Odieresis", "Aring", -40.0), ("Odieresis", "Atilde", -40.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -50.0), ("Odieresis", "X", -40.0), ("Odieresis", "Y", -50.0), ("Odieresis", "Yacute", -50.0), ("Odieresis", "Ydieresis", -50.0), ("Ograve", "A", -40.0), ("Ograve", "Aacute", -40.0), ("Ograve", "Abreve", -40.0), ("Ograve", "Acircumflex", -40.0), ("Ograve", "Adieresis", -40.0), ("Ograve", "Agrave", -40.0), ("Ograve", "Amacron", -40.0), ("Ograve", "Aogonek", -40.0), ("Ograve", "Aring", -40.0), ("Ograve", "Atilde", -40.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -50.0), ("Ograve", "X", -40.0), ("Ograve", "Y", -50.0), ("Ograve", "Yacute", -50.0), ("Ograve", "Ydieresis", -50.0), ("Ohungarumlaut", "A", -40.0), ("Ohungarumlaut", "Aacute", -40.0), ("Ohungarumlaut", "Abreve", -40.0), ("Ohungarumlaut", "Acircumflex", -40.0), ("Ohungarumlaut", "Adieresis", -40.0), ("Ohungarumlaut", "Agrave", -40.0), ("Ohungarumlaut", "Amacron", -40.0), ("Ohungarumlaut", "Aogonek", -40.0), ("Ohungarumlaut", "Aring", -40.0), ("Ohungarumlaut", "Atilde", -40.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -50.0), ("Ohungarumlaut", "X", -40.0), ("Ohungarumlaut", "Y", -50.0), ("Ohungarumlaut", "Yacute", -50.0), ("Ohungarumlaut", "Ydieresis", -50.0), ("Omacron", "A", -40.0), ("Omacron", "Aacute", -40.0), ("Omacron", "Abreve", -40.0), ("Omacron", "Acircumflex", -40.0), ("Omacron", "Adieresis", -40.0), ("Omacron", "Agrave", -40.0), ("Omacron", "Amacron", -40.0), ("Omacron", "Aogonek", -40.0), ("Omacron", "Aring", -40.0), ("Omacron", "Atilde", -40.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -50.0), ("Omacron", "X", -40.0), ("Omacron", "Y", -50.0), ("Omacron", "Yacute", -50.0), ("Omacron", "Ydieresis", -50.0), ("Oslash", "A", -40.0), ("Oslash", "Aacute", -40.0), ("Oslash", "Abreve", -40.0), ("Oslash", "Acircumflex", -40.0), ("Oslash", "Adieresis", -40.0), ("Oslash", "Agrave", -40.0), ("Oslash", "Amacron", -40.0), ("Oslash", "Aogonek", -40.0), ("Oslash", "Aring", -40.0), ("Oslash", "Atilde", -40.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -50.0), ("Oslash", "X", -40.0), ("Oslash", "Y", -50.0), ("Oslash", "Yacute", -50.0), ("Oslash", "Ydieresis", -50.0), ("Otilde", "A", -40.0), ("Otilde", "Aacute", -40.0), ("Otilde", "Abreve", -40.0), ("Otilde", "Acircumflex", -40.0), ("Otilde", "Adieresis", -40.0), ("Otilde", "Agrave", -40.0), ("Otilde", "Amacron", -40.0), ("Otilde", "Aogonek", -40.0), ("Otilde", "Aring", -40.0), ("Otilde", "Atilde", -40.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -50.0), ("Otilde", "X", -40.0), ("Otilde", "Y", -50.0), ("Otilde", "Yacute", -50.0), ("Otilde", "Ydieresis", -50.0), ("P", "A", -85.0), ("P", "Aacute", -85.0), ("P", "Abreve", -85.0), ("P", "Acircumflex", -85.0), ("P", "Adieresis", -85.0), ("P", "Agrave", -85.0), ("P", "Amacron", -85.0), ("P", "Aogonek", -85.0), ("P", "Aring", -85.0), ("P", "Atilde", -85.0), ("P", "a", -40.0), ("P", "aacute", -40.0), ("P", "abreve", -40.0), ("P", "acircumflex", -40.0), ("P", "adieresis", -40.0), ("P", "agrave", -40.0), ("P", "amacron", -40.0), ("P", "aogonek", -40.0), ("P", "aring", -40.0), ("P", "atilde", -40.0), ("P", "comma", -129.0), ("P", "e", -50.0), ("P", "eacute", -50.0), ("P", "ecaron", -50.0), ("P", "ecircumflex", -50.0), ("P", "edieresis", -50.0), ("P", "edotaccent", -50.0), ("P", "egrave", -50.0), ("P", "emacron", -50.0), ("P", "eogonek", -50.0), ("P", "o", -55.0), ("P", "oacute", -55.0), ("P", "ocircumflex", -55.0), ("P", "odieresis", -55.0), ("P", "ograve", -55.0), ("P", "ohungarumlaut", -55.0), ("P", "omacron", -55.0), ("P", "oslash", -55.0), ("P", "otilde", -55.0), ("P", "period", -129.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("R", "O", -40.0), ("R", "Oacute", -40.0), ("R", "Ocircumflex", -40.0), ("R", "Odieresis", -40.0), ("R", "Ograve", -40.0), ("R", "Ohungarumlaut", -40.0), ("R", "Omacron", -40.0), ("R", "Oslash", -40.0), ("R", "Otilde", -40.0), ("R", "T", -30.0), ("R", "Tcaron", -30.0), ("R", "Tcommaaccent", -30.0), ("R", "U", -40.0), ("R", "Uacute", -40.0), ("R", "Ucircumflex", -40.0), ("R", "Udieresis", -40.0), ("R", "Ugrave", -40.0), ("R", "Uhungarumlaut", -40.0), ("R", "Umacron", -40.0), ("R", "Uogonek", -40.0), ("R", "Uring", -40.0), ("R", "V", -18.0), ("R", "W", -18.0), ("R", "Y", -18.0), ("R", "Yacute", -18.0), ("R", "Ydieresis", -18.0), ("Racute", "O", -40.0), ("Racute", "Oacute", -40.0), ("Racute", "Ocircumflex", -40.0), ("Racute", "Odieresis", -40.0), ("Racute", "Ograve", -40.0), ("Racute", "Ohungarumlaut", -40.0), ("Racute", "Omacron", -40.0), ("Racute", "Oslash", -40.0), ("Racute", "Otilde", -40.0), ("Racute", "T", -30.0), ("Racute", "Tcaron", -30.0), ("Racute", "Tcommaaccent", -30.0), ("Racute", "U", -40.0), ("Racute", "Uacute", -40.0), ("Racute", "Ucircumflex", -40.0), ("Racute", "Udieresis", -40.0), ("Racute", "Ugrave", -40.0), ("Racute", "Uhungarumlaut", -40.0), ("Racute", "Umacron", -40.0), ("Racute", "Uogonek", -40.0), ("Racute", "Uring", -40.0), ("Racute", "V", -18.0), ("Racute", "W", -18.0), ("Racute", "Y", -18.0), ("Racute", "Yacute", -18.0), ("Racute", "Ydieresis", -18.0), ("Rcaron", "O", -40.0), ("Rcaron", "Oacute", -40.0), ("Rcaron", "Ocircumflex", -40.0), ("Rcaron", "Odieresis", -40.0), ("Rcaron", "Ograve", -40.0), ("Rcaron", "Ohungarumlaut", -40.0), ("Rcaron", "Omacron", -40.0), ("Rcaron", "Oslash", -40.0), ("Rcaron", "Otilde", -40.0), ("Rcaron", "T", -30.0), ("Rcaron", "Tcaron", -30.0), ("Rcaron", "Tcommaaccent", -30.0), ("Rcaron", "U", -40.0), ("Rcaron", "Uacute", -40.0), ("Rcaron", "Ucircumflex", -40.0), ("Rcaron", "Udieresis", -40.0), ("Rcaron", "Ugrave", -40.0), ("Rcaron", "Uhungarumlaut", -40.0), ("Rcaron", "Umacron", -40.0), ("Rcaron", "Uogonek", -40.0), ("Rcaron", "Uring", -40.0), ("Rcaron", "V", -18.0), ("Rcaron", "W", -18.0), ("Rcaron", "Y", -18.0), ("Rcaron", "Yacute", -18.0), ("Rcaron", "Ydieresis", -18.0), ("Rcommaaccent", "O", -40.0), ("Rcommaaccent", "Oacute", -40.0), ("Rcommaaccent", "Ocircumflex", -40.0), ("Rcommaaccent", "Odieresis", -40.0), ("Rcommaaccent", "Ograve", -40.0), ("Rcommaaccent", "Ohungarumlaut", -40.0), ("Rcommaaccent", "Omacron", -40.0), ("Rcommaaccent", "Oslash", -40.0), ("Rcommaaccent", "Otilde", -40.0), ("Rcommaaccent", "T", -30.0), ("Rcommaaccent", "Tcaron", -30.0), ("Rcommaaccent", "Tcommaaccent", -30.0), ("Rcommaaccent", "U", -40.0), ("Rcommaaccent", "Uacute", -40.0), ("Rcommaaccent", "Ucircumflex", -40.0), ("Rcommaaccent", "Udieresis", -40.0), ("Rcommaaccent", "Ugrave", -40.0), ("Rcommaaccent", "Uhungarumlaut", -40.0), ("Rcommaaccent", "Umacron", -40.0), ("Rcommaaccent", "Uogonek", -40.0), ("Rcommaaccent", "Uring", -40.0), ("Rcommaaccent", "V", -18.0), ("Rcommaaccent", "W", -18.0), ("Rcommaaccent", "Y", -18.0), ("Rcommaaccent", "Yacute", -18.0), ("Rcommaaccent", "Ydieresis", -18.0), ("T", "A", -55.0), ("T", "Aacute", -55.0), ("T", "Abreve", -55.0), ("T", "Acircumflex", -55.0), ("T", "Adieresis", -55.0), ("T", "Agrave", -55.0), ("T", "Amacron", -55.0), ("T", "Aogonek", -55.0), ("T", "Aring", -55.0), ("T", "Atilde", -55.0), ("T", "O", -18.0), ("T", "Oacute", -18.0), ("T", "Ocircumflex", -18.0), ("T", "Odieresis", -18.0), ("T", "Ograve", -18.0), ("T", "Ohungarumlaut", -18.0), ("T", "Omacron", -18.0), ("T", "Oslash", -18.0), ("T", "Otilde", -18.0), ("T", "a", -92.0), ("T", "aacute", -92.0), ("T", "abreve", -92.0), ("T", "acircumflex", -92.0), ("T", "adieresis", -92.0), ("T", "agrave", -92.0), ("T", "amacron", -92.0), ("T", "aogonek", -92.0), ("T", "aring", -92.0), ("T", "atilde", -92.0), ("T", "colon", -74.0), ("T", "comma", -92.0), ("T", "e", -92.0), ("T", "eacute", -92.0), ("T", "ecaron", -92.0), ("T", "ecircumflex", -92.0), ("T", "edieresis", -52.0), ("T", "edotaccent", -92.0), ("T", "egrave", -52.0), ("T", "emacron", -52.0), ("T", "eogonek", -92.0), ("T", "hyphen", -92.0), ("T", "i", -37.0), ("T", "iacute", -37.0), ("T", "iogonek", -37.0), ("T", "o", -95.0), ("T", "oacute", -95.0), ("T", "ocircumflex", -95.0), ("T", "odieresis", -95.0), ("T", "ograve", -95.0), ("T", "ohungarumlaut", -95.0), ("T", "omacron", -95.0), ("T", "oslash", -95.0), ("T", "otilde", -95.0), ("T", "period", -92.0), ("T", "r", -37.0), ("T", "racute", -37.0), ("T", "rcaron", -37.0), ("T", "rcommaaccent", -37.0), ("T", "semicolon", -74.0), ("T", "u", -37.0), ("T", "uacute", -37.0), ("T", "ucircumflex", -37.0), ("T", "udieresis", -37.0), ("T", "ugrave", -37.0), ("T", "uhungarumlaut", -37.0), ("T", "umacron", -37.0), ("T", "uogonek", -37.0), ("T", "uring", -37.0), ("T", "w", -37.0), ("T", "y", -37.0), ("T", "yacute", -37.0), ("T", "ydieresis", -37.0), ("Tcaron", "A", -55.0), ("Tcaron", "Aacute", -55.0), ("Tcaron", "Abreve", -55.0), ("Tcaron", "Acircumflex", -55.0), ("Tcaron", "Adieresis", -55.0), ("Tcaron", "Agrave", -55.0), ("Tcaron", "Amacron", -55.0), ("Tcaron", "Aogonek", -55.0), ("Tcaron", "Aring", -55.0), ("Tcaron", "Atilde", -55.0), ("Tcaron", "O", -18.0), ("Tcaron", "Oacute", -18.0), ("Tcaron", "Ocircumflex", -18.0), ("Tcaron", "Odieresis", -18.0), ("Tcaron", "Ograve", -18.0), ("Tcaron", "Ohungarumlaut", -18.0), ("Tcaron", "Omacron", -18.0), ("Tcaron", "Oslash", -18.0), ("Tcaron", "Otilde", -18.0), ("Tcaron", "a", -92.0), ("Tcaron", "aacute", -92.0), ("Tcaron", "abreve", -92.0), ("Tcaron", "acircumflex", -92.0), ("Tcaron", "adieresis", -92.0), ("Tcaron", "agrave", -92.0), ("Tcaron", "amacron", -92.0), ("Tcaron", "aogonek", -92.0), ("Tcaron", "aring", -92.0), ("Tcaron", "atilde", -92.0), ("Tcaron", "colon", -74.0), ("Tcaron", "comma", -92.0), ("Tcaron", "e", -92.0), ("Tcaron", "eacute", -92.0), ("Tcaron", "ecaron", -92.0), ("Tcaron", "ecircumflex", -92.0), ("Tcaron", "edieresis", -52.0), ("Tcaron", "edotaccent", -92.0), ("Tcaron", "egrave", -52.0), ("Tcaron", "emacron", -52.0), ("Tcaron", "eogonek", -92.0), ("Tcaron", "hyphen", -92.0), ("Tcaron", "i", -37.0), ("Tcaron", "iacute", -37.0), ("Tcaron", "iogonek", -37.0), ("Tcaron", "o", -95.0), ("Tcaron", "oacute", -95.0), ("Tcaron", "ocircumflex", -95.0), ("Tcaron", "odieresis", -95.0), ("Tcaron", "ograve", -95.0), ("Tcaron", "ohungarumlaut", -95.0), ("Tcaron", "omacron", -95.0), ("Tcaron", "oslash", -95.0), ("Tcaron", "otilde", -95.0), ("Tcaron", "period", -92.0), ("Tcaron", "r", -37.0), ("Tcaron", "racute", -37.0), ("Tcaron", "rcaron", -37.0), ("Tcaron", "rcommaaccent", -37.0), ("Tcaron", "semicolon", -74.0), ("Tcaron", "u", -37.0), ("Tcaron", "uacute", -37.0), ("Tcaron", "ucircumflex", -37.0), ("Tcaron", "udieresis", -37.0), ("Tcaron", "ugrave", -37.0), ("Tcaron", "uhungarumlaut", -37.0), ("Tcaron", "umacron", -37.0), ("Tcaron", "uogonek", -37.0), ("Tcaron", "uring", -37.0), ("Tcaron", "w", -37.0), ("Tcaron", "y", -37.0), ("Tcaron", "yacute", -37.0), ("Tcaron", "ydieresis", -37.0), ("Tcommaaccent", "A", -55.0), ("Tcommaaccent", "Aacute", -55.0), ("Tcommaaccent", "Abreve", -55.0), ("Tcommaaccent", "Acircumflex", -55.0), ("Tcommaaccent", "Adieresis", -55.0), ("Tcommaaccent", "Agrave", -55.0), ("Tcommaaccent", "Amacron", -55.0), ("Tcommaaccent", "Aogonek", -55.0), ("Tcommaaccent", "Aring", -55.0), ("Tcommaaccent", "Atilde", -55.0), ("Tcommaaccent", "O", -18.0), ("Tcommaaccent", "Oacute", -18.0), ("Tcommaaccent", "Ocircumflex", -18.0), ("Tcommaaccent", "Odieresis", -18.0), ("Tcommaaccent", "Ograve", -18.0), ("Tcommaaccent", "Ohungarumlaut", -18.0), ("Tcommaaccent", "Omacron", -18.0), ("Tcommaaccent", "Oslash", -18.0), ("Tcommaaccent", "Otilde", -18.0), ("Tcommaaccent", "a", -92.0), ("Tcommaaccent", "aacute", -92.0), ("Tcommaaccent", "abreve", -92.0), ("Tcommaaccent", "acircumflex", -92.0), ("Tcommaaccent", "adieresis", -92.0), ("Tcommaaccent", "agrave", -92.0), ("Tcommaaccent", "amacron", -92.0), ("Tcommaaccent", "aogonek", -92.0), ("Tcommaaccent", "aring", -92.0), ("Tcommaaccent", "atilde", -92.0), ("Tcommaaccent", "colon", -74.0), ("Tcommaaccent", "comma", -92.0), ("Tcommaaccent", "e", -92.0), ("Tcommaaccent", "eacute", -92.0), ("Tcommaaccent", "ecaron", -92.0), ("Tcommaaccent", "ecircumflex", -92.0), ("Tcommaaccent", "edieresis", -52.0), ("Tcommaaccent", "edotaccent", -92.0), ("Tcommaaccent", "egrave", -52.0), ("Tcommaaccent", "emacron", -52.0), ("Tcommaaccent", "eogonek", -92.0), ("Tcommaaccent", "hyphen", -92.0), ("Tcommaaccent", "i", -37.0), ("Tcommaaccent", "iacute", -37.0), ("Tcommaaccent", "iogonek", -37.0), ("Tcommaaccent", "o", -95.0), ("Tcommaaccent", "oacute", -95.0), ("Tcommaaccent", "ocircumflex", -95.0), ("Tcommaaccent", "odieresis", -95.0), ("Tcommaaccent", "ograve", -95.0), ("Tcommaaccent", "ohungarumlaut", -95.0), ("Tcommaaccent", "omacron", -95.0), ("Tcommaaccent", "oslash", -95.0), ("Tcommaaccent", "otilde", -95.0), ("Tcommaaccent", "period", -92.0), ("Tcommaaccent", "r", -37.0), ("Tcommaaccent", "racute", -37.0), ("Tcommaaccent", "rcaron", -37.0), ("Tcommaaccent", "rcommaaccent", -37.0), ("Tcommaaccent", "semicolon", -74.0), ("Tcommaaccent", "u", -37.0), ("Tcommaaccent", "uacute", -37.0), ("Tcommaaccent", "ucircumflex", -37.0), ("Tcommaaccent", "udieresis", -37.0), ("Tcommaaccent", "ugrave", -37.0), ("Tcommaaccent", "uhungarumlaut", -37.0), ("Tcommaaccent", "umacron", -37.0), ("Tcommaaccent", "uogonek", -37.0), ("Tcommaaccent", "uring", -37.0), ("Tcommaaccent", "w", -37.0), ("Tcommaaccent", "y", -37.0), ("Tcommaaccent", "yacute", -37.0), ("Tcommaaccent", "ydieresis", -37.0), ("U", "A", -45.0), ("U", "Aacute", -45.0), ("U", "Abreve", -45.0), ("U", "Acircumflex", -45.0), ("U", "Adieresis", -45.0), ("U", "Agrave", -45.0), ("U", "Amacron", -45.0), ("U", "Aogonek", -45.0), ("U", "Aring", -45.0), ("U", "Atilde", -45.0), ("Uacute", "A", -45.0), ("Uacute", "Aacute", -45.0), ("Uacute", "Abreve", -45.0), ("Uacute", "Acircumflex", -45.0), ("Uacute", "Adieresis", -45.0), ("Uacute", "Agrave", -45.0), ("Uacute", "Amacron", -45.0), ("Uacute", "Aogonek", -45.0), ("Uacute", "Aring", -45.0), ("Uacute", "Atilde", -45.0), ("Ucircumflex", "A", -45.0), ("Ucircumflex", "Aacute", -45.0), ("Ucircumflex", "Abreve", -45.0), ("Ucircumflex", "Acircumflex", -45.0), ("Ucircumflex", "Adieresis", -45.0), ("Ucircumflex", "Agrave", -45.0), ("Ucircumflex", "Amacron", -45.0), ("Ucircumflex", "Aogonek", -45.0), ("Ucircumflex", "Aring", -45.0), ("Ucircumflex", "Atilde", -45.0), ("Udieresis", "A", -45.0), ("Udieresis", "Aacute", -45.0), ("Udieresis", "Abreve", -45.0), ("Udieresis", "Acircumflex", -45.0), ("Udieresis", "Adieresis", -45.0), ("Udieresis", "Agrave", -45.0), ("Udieresis", "Amacron", -45.0), ("Udieresis", "Aogonek", -45.0), ("Udieresis", "Aring", -45.0), ("Udieresis", "Atilde", -45.0), ("Ugrave", "A", -45.0), ("Ugrave", "Aacute", -45.0), ("Ugrave", "Abreve", -45.0), ("Ugrave", "Acircumflex", -45.0), ("Ugrave", "Adieresis", -45.0), ("Ugrave", "Agrave", -45.0), ("Ugrave", "Amacron", -45.0), ("Ugrave", "Aogonek", -45.0), ("Ugrave", "Aring", -45.0), ("Ugrave", "Atilde", -45.0), ("Uhungarumlaut", "A", -45.0), ("Uhungarumlaut", "Aacute", -45.0), ("Uhungarumlaut", "Abreve", -45.0), ("Uhungarumlaut", "Acircumflex", -45.0), ("Uhungarumlaut", "Adieresis", -45.0), ("Uhungarumlaut", "Agrave", -45.0), ("Uhungarumlaut", "Amacron", -45.0), ("Uhungarumlaut", "Aogonek", -45.0), ("Uhungarumlaut", "Aring", -45.0), ("Uhungarumlaut", "Atilde", -45.0), ("Umacron", "A", -45.0), ("Umacron", "Aacute", -45.0), ("Umacron", "Abreve", -45.0), ("Umacron", "Acircumflex", -45.0), ("Umacron", "Adieresis", -45.0), ("Umacron", "Agrave", -45.0), ("Umacron", "Amacron", -45.0), ("Umacron", "Aogonek", -45.0), ("Umacron", "Aring", -45.0), ("Umacron", "Atilde", -45.0), ("Uogonek", "A", -45.0), ("Uogonek", "Aacute", -45.0), ("Uogonek", "Abreve", -45.0), ("Uogonek", "Acircumflex", -45.0), ("Uogonek", "Adieresis", -45.0), ("Uogonek", "Agrave", -45.0), ("Uogonek", "Amacron", -45.0), ("Uogonek", "Aogonek", -45.0), ("Uogonek", "Aring", -45.0), ("Uogonek", "Atilde", -45.0), ("Uring", "A", -45.0), ("Uring", "Aacute", -45.0), ("Uring", "Abreve", -45.0), ("Uring", "Acircumflex", -45.0), ("Uring", "Adieresis", -45.0), ("Uring", "Agrave", -45.0), ("Uring", "Amacron", -45.0), ("Uring", "Aogonek", -45.0), ("Uring", "Aring", -45.0), ("Uring", "Atilde", -45.0), ("V", "A", -85.0), ("V", "Aacute", -85.0), ("V", "Abreve", -85.0), ("V", "Acircumflex", -85.0), ("V", "Adieresis", -85.0), ("V", "Agrave", -85.0), ("V", "Amacron", -85.0), ("V", "Aogonek", -85.0), ("V", "Aring", -85.0), ("V", "Atilde", -85.0), ("V", "G", -10.0), ("V", "Gbreve", -10.0), ("V", "Gcommaaccent", -10.0), ("V", "O", -30.0), ("V", "Oacute", -30.0), ("V", "Ocircumflex", -30.0), ("V", "Odieresis", -30.0), ("V", "Ograve", -30.0), ("V", "Ohungarumlaut", -30.0), ("V", "Omacron", -30.0), ("V", "Oslash", -30.0), ("V", "Otilde", -30.0), ("V", "a", -111.0), ("V", "aacute", -111.0), ("V", "abreve", -111.0), ("V", "acircumflex", -111.0), ("V", "adieresis", -111.0), ("V", "agrave", -111.0), ("V", "amacron", -111.0), ("V", "aogonek", -111.0), ("V", "aring", -111.0), ("V", "atilde", -111.0), ("V", "colon", -74.0), ("V", "comma", -129.0), ("V", "e", -111.0), ("V", "eacute", -111.0), ("V", "ecaron", -111.0), ("V", "ecircumflex", -111.0), ("V", "edieresis", -71.0), ("V", "edotaccent", -111.0), ("V", "egrave", -71.0), ("V", "emacron", -71.0), ("V", "eogonek", -111.0), ("V", "hyphen", -70.0), ("V", "i", -55.0), ("V", "iacute", -55.0), ("V", "iogonek", -55.0), ("V", "o", -111.0), ("V", "oacute", -111.0), ("V", "ocircumflex", -111.0), ("V", "odieresis", -111.0), ("V", "ograve", -111.0), ("V", "ohungarumlaut", -111.0), ("V", "omacron", -111.0), ("V", "oslash", -111.0), ("V", "otilde", -111.0), ("V", "period", -129.0), ("V", "semicolon", -74.0), ("V", "u", -55.0), ("V", "uacute", -55.0), ("V", "ucircumflex", -55.0), ("V", "udieresis", -55.0), ("V", "ugrave", -55.0), ("V", "uhungarumlaut", -55.0), ("V", "umacron", -55.0), ("V", "uogonek", -55.0), ("V", "uring", -55.0), ("W", "A", -74.0), ("W", "Aacute", -74.0), ("W", "Abreve", -74.0), ("W", "Acircumflex", -74.0), ("W", "Adieresis", -74.0), ("W", "Agrave", -74.0), ("W", "Amacron", -74.0), ("W", "Aogonek", -74.0), ("W", "Aring", -74.0), ("W", "Atilde", -74.0), ("W", "O", -15.0), ("W", "Oacute", -15.0), ("W", "Ocircumflex", -15.0), ("W", "Odieresis", -15.0), ("W", "Ograve", -15.0), ("W", "Ohungarumlaut", -15.0), ("W", "Omacron", -15.0), ("W", "Oslash", -15.0), ("W", "Otilde", -15.0), ("W", "a", -85.0), ("W", "aacute", -85.0), ("W", "abreve", -85.0), ("W", "acircumflex", -85.0), ("W", "adieresis", -85.0), ("W", "agrave", -85.0), ("W", "amacron", -85.0), ("W", "aogonek", -85.0), ("W", "aring", -85.0), ("W", "atilde", -85.0), ("W", "colon", -55.0), ("W", "comma", -74.0), ("W", "e", -90.0), ("W", "eacute", -90.0), ("W", "ecaron", -90.0), ("W", "ecircumflex", -90.0), ("W", "edieresis", -50.0), ("W", "edotaccent", -90.0), ("W", "egrave", -50.0), ("W", "emacron", -50.0), ("W", "eogonek", -90.0), ("W", "hyphen", -50.0), ("W", "i", -37.0), ("W", "iacute", -37.0), ("W", "iogonek", -37.0), ("W", "o", -80.0), ("W", "oacute", -80.0), ("W", "ocircumflex", -80.0), ("W", "odieresis", -80.0), ("W", "ograve", -80.0), ("W", "ohungarumlaut", -80.0), ("W", "omacron", -80.0), ("W", "oslash", -80.0), ("W", "otilde", -80.0), ("W", "period", -74.0), ("W", "semicolon", -55.0), ("W", "u", -55.0), ("W", "uacute", -55.0), ("W", "ucircumflex", -55.0), ("W", "udieresis", -55.0), ("W", "ugrave", -55.0), ("W", "uhungarumlaut", -55.0), ("W", "umacron", -55.0), ("W", "uogonek", -55.0), ("W", "uring", -55.0), ("W", "y", -55.0), ("W", "yacute", -55.0), ("W", "ydieresis", -55.0), ("Y", "A", -74.0), ("Y", "Aacute", -74.0), ("Y", "Abreve", -74.0), ("Y", "Acircumflex", -74.0), ("Y", "Adieresis", -74.0), ("Y", "Agrave", -74.0), ("Y", "Amacron", -74.0), ("Y", "Aogonek", -74.0), ("Y", "Aring", -74.0), ("Y", "Atilde", -74.0), ("Y", "O", -25.0), ("Y", "Oacute", -25.0), ("Y", "Ocircumflex", -25.0), ("Y", "Odieresis", -25.0), ("Y", "Ograve", -25.0), ("Y", "Ohungarumlaut", -25.0), ("Y", "Omacron", -25.0), ("Y", "Oslash", -25.0), ("Y", "Otilde", -25.0), ("Y", "a", -92.0), ("Y", "aacute", -92.0), ("Y", "abreve", -92.0), ("Y", "acircumflex", -92.0), ("Y", "adieresis", -92.0), ("Y", "agrave", -92.0), ("Y", "amacron", -92.0), ("Y", "aogonek", -92.0), ("Y", "aring", -92.0), ("Y", "atilde", -92.0), ("Y", "colon", -92.0), ("Y", "comma", -92.0), ("Y", "e", -111.0), ("Y", "eacute", -111.0), ("Y", "ecaron", -111.0), ("Y", "ecircumflex", -71.0), ("Y", "edieresis", -71.0), ("Y", "edotaccent", -111.0), ("Y", "egrave", -71.0), ("Y", "emacron", -71.0), ("Y", "eogonek", -111.0), ("Y", "hyphen", -92.0), ("Y", "i", -55.0), ("Y", "iacute", -55.0), ("Y", "iogonek", -55.0), ("Y", "o", -111.0), ("Y", "oacute", -111.0), ("Y", "ocircumflex", -111.0), ("Y", "odieresis", -111.0), ("Y", "ograve", -111.0), ("Y", "ohungarumlaut", -111.0), ("Y", "omacron", -111.0), ("Y", "oslash", -111.0), ("Y", "otilde", -111.0), ("Y", "period", -74.0), ("Y", "semicolon", -92.0), ("Y", "u", -92.0), ("Y", "uacute", -92.0), ("Y", "ucircumflex", -92.0), ("Y", "udieresis", -92.0), ("Y", "ugrave", -92.0), ("Y", "uhungarumlaut", -92.0), ("Y", "umacron", -92.0), ("Y", "uogonek", -92.0), ("Y", "uring", -92.0), ("Yacute", "A", -74.0), ("Yacute", "Aacute", -74.0), ("Yacute", "Abreve", -74.0), ("Yacute", "Acircumflex", -74.0), ("Yacute", "Adieresis", -74.0), ("Yacute", "Agrave", -74.0), ("Yacute", "Amacron", -74.0), ("Yacute", "Aogonek", -74.0), ("Yacute", "Aring", -74.0), ("Yacute", "Atilde", -74.0), ("Yacute", "O", -25.0), ("Yacute", "Oacute", -25.0), ("Yacute", "Ocircumflex", -25.0), ("Yacute", "Odieresis", -25.0), ("Yacute", "Ograve", -25.0), ("Yacute", "Ohungarumlaut", -25.0), ("Yacute", "Omacron", -25.0), ("Yacute", "Oslash", -25.0), ("Yacute", "Otilde", -25.0), ("Yacute", "a", -92.0), ("Yacute", "aacute", -92.0), ("Yacute", "abreve", -92.0), ("Yacute", "acircumflex", -92.0), ("Yacute", "adieresis", -92.0), ("Yacute", "agrave", -92.0), ("Yacute", "amacron", -92.0), ("Yacute", "aogonek", -92.0), ("Yacute", "aring", -92.0), ("Yacute", "atilde", -92.0), ("Yacute", "colon", -92.0), ("Yacute", "comma", -92.0), ("Yacute", "e", -111.0), ("Yacute", "eacute", -111.0), ("Yacute", "ecaron", -111.0), ("Yacute", "ecircumflex", -71.0), ("Yacute", "edieresis", -71.0), ("Yacute", "edotaccent", -111.0), ("Yacute", "egrave", -71.0), ("Yacute", "emacron", -71.0), ("Yacute", "eogonek", -111.0), ("Yacute", "hyphen", -92.0), ("Yacute", "i", -55.0), ("Yacute", "iacute", -55.0), ("Yacute", "iogonek", -55.0), ("Yacute", "o", -111.0), ("Yacute", "oacute", -111.0), ("Yacute", "ocircumflex", -111.0), ("Yacute", "odieresis", -111.0), ("Yacute", "ograve", -111.0), ("Yacute", "ohungarumlaut", -111.0), ("Yacute", "omacron", -111.0), ("Yacute", "oslash", -111.0), ("Yacute", "otilde", -111.0), ("Yacute", "period", -74.0), ("Yacute", "semicolon", -92.0), ("Yacute", "u", -92.0), ("Yacute", "uacute", -92.0), ("Yacute", "ucircumflex", -92.0), ("Yacute", "udieresis", -92.0), ("Yacute", "ugrave", -92.0), ("Yacute", "uhungarumlaut", -92.0), ("Yacute", "umacron", -92.0), ("Yacute", "uogonek", -92.0), ("Yacute", "uring", -92.0), ("Ydieresis", "A", -74.0), ("Ydieresis", "Aacute", -74.0), ("Ydieresis", "Abreve", -74.0), ("Ydieresis", "Acircumflex", -74.0), ("Ydieresis", "Adieresis", -74.0), ("Ydieresis", "Agrave", -74.0), ("Ydieresis", "Amacron", -74.0), ("Ydieresis", "Aogonek", -74.0), ("Ydieresis", "Aring", -74.0), ("Ydieresis", "Atilde", -74.0), ("Ydieresis", "O", -25.0), ("Ydieresis", "Oacute", -25.0), ("Ydieresis", "Ocircumflex", -25.0), ("Ydieresis", "Odieresis", -25.0), ("Ydieresis", "Ograve", -25.0), ("Ydieresis", "Ohungarumlaut", -25.0), ("Ydieresis", "Omacron", -25.0), ("Ydieresis", "Oslash", -25.0), ("Ydieresis", "Otilde", -25.0), ("Ydieresis", "a", -92.0), ("Ydieresis", "aacute", -92.0), ("Ydieresis", "abreve", -92.0), ("Ydieresis", "acircumflex", -92.0), ("Ydieresis", "adieresis", -92.0), ("Ydieresis", "agrave", -92.0), ("Ydieresis", "amacron", -92.0), ("Ydieresis", "aogonek", -92.0), ("Ydieresis", "aring", -92.0), ("Ydieresis", "atilde", -92.0), ("Ydieresis", "colon", -92.0), ("Ydieresis", "comma", -92.0), ("Ydieresis", "e", -111.0), ("Ydieresis", "eacute", -111.0), ("Ydieresis", "ecaron", -111.0), ("Ydieresis", "ecircumflex", -71.0), ("Ydieresis", "edieresis", -71.0), ("Ydieresis", "edotaccent", -111.0), ("Ydieresis", "egrave", -71.0), ("Ydieresis", "emacron", -71.0), ("Ydieresis", "eogonek", -111.0), ("Ydieresis", "hyphen", -92.0), ("Ydieresis", "i", -55.0), ("Ydieresis", "iacute", -55.0), ("Ydieresis", "iogonek", -55.0), ("Ydieresis", "o", -111.0), ("Ydieresis", "oacute", -111.0), ("Ydieresis", "ocircumflex", -111.0), ("Ydieresis", "odieresis", -111.0), ("Ydieresis", "ograve", -111.0), ("Ydieresis", "ohungarumlaut", -111.0), ("Ydieresis", "omacron", -111.0), ("Ydieresis", "oslash", -111.0), ("Ydieresis", "otilde", -111.0), ("Ydieresis", "period", -74.0), ("Ydieresis", "semicolon", -92.0), ("Ydieresis", "u", -92.0), ("Ydieresis", "uacute", -92.0), ("Ydieresis", "ucircumflex", -92.0), ("Ydieresis", "udieresis", -92.0), ("Ydieresis", "ugrave", -92.0), ("Ydieresis", "uhungarumlaut", -92.0), ("Ydieresis", "umacron", -92.0), ("Ydieresis", "uogonek", -92.0), ("Ydieresis", "uring", -92.0), ("b", "b", -10.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("c", "h", -10.0), ("c", "k", -10.0), ("c", "kcommaaccent", -10.0), ("cacute", "h", -10.0), ("cacute", "k", -10.0), ("cacute", "kcommaaccent", -10.0), ("ccaron", "h", -10.0), ("ccaron", "k", -10.0), ("ccaron", "kcommaaccent", -10.0), ("ccedilla", "h", -10.0), ("ccedilla", "k", -10.0), ("ccedilla", "kcommaaccent", -10.0), ("comma", "quotedblright", -95.0), ("comma", "quoteright", -95.0), ("e", "b", -10.0), ("eacute", "b", -10.0), ("ecaron", "b", -10.0), ("ecircumflex", "b", -10.0), ("edieresis", "b", -10.0), ("edotaccent", "b", -10.0), ("egrave", "b", -10.0), ("emacron", "b", -10.0), ("eogonek", "b", -10.0), ("f", "comma", -10.0), ("f", "dotlessi", -30.0), ("f", "e", -10.0), ("f", "eacute", -10.0), ("f", "edotaccent", -10.0), ("f", "eogonek", -10.0), ("f", "f", -18.0), ("f", "o", -10.0), ("f", "oacute", -10.0), ("f", "ocircumflex", -10.0), ("f", "ograve", -10.0), ("f", "ohungarumlaut", -10.0), ("f", "oslash", -10.0), ("f", "otilde", -10.0), ("f", "period", -10.0), ("f", "quoteright", 55.0), ("k", "e", -30.0), ("k", "eacute", -30.0), ("k", "ecaron", -30.0), ("k", "ecircumflex", -30.0), ("k", "edieresis", -30.0), ("k", "edotaccent", -30.0), ("k", "egrave", -30.0), ("k", "emacron", -30.0), ("k", "eogonek", -30.0), ("k", "o", -10.0), ("k", "oacute", -10.0), ("k", "ocircumflex", -10.0), ("k", "odieresis", -10.0), ("k", "ograve", -10.0), ("k", "ohungarumlaut", -10.0), ("k", "omacron", -10.0), ("k", "oslash", -10.0), ("k", "otilde", -10.0), ("kcommaaccent", "e", -30.0), ("kcommaaccent", "eacute", -30.0), ("kcommaaccent", "ecaron", -30.0), ("kcommaaccent", "ecircumflex", -30.0), ("kcommaaccent", "edieresis", -30.0), ("kcommaaccent", "edotaccent", -30.0), ("kcommaaccent", "egrave", -30.0), ("kcommaaccent", "emacron", -30.0), ("kcommaaccent", "eogonek", -30.0), ("kcommaaccent", "o", -10.0), ("kcommaaccent", "oacute", -10.0), ("kcommaaccent", "ocircumflex", -10.0), ("kcommaaccent", "odieresis", -10.0), ("kcommaaccent", "ograve", -10.0), ("kcommaaccent", "ohungarumlaut", -10.0), ("kcommaaccent", "omacron", -10.0), ("kcommaaccent", "oslash", -10.0), ("kcommaaccent", "otilde", -10.0), ("n", "v", -40.0), ("nacute", "v", -40.0), ("ncaron", "v", -40.0), ("ncommaaccent", "v", -40.0), ("ntilde", "v", -40.0), ("o", "v", -15.0), ("o", "w", -25.0), ("o", "x", -10.0), ("o", "y", -10.0), ("o", "yacute", -10.0), ("o", "ydieresis", -10.0), ("oacute", "v", -15.0), ("oacute", "w", -25.0), ("oacute", "x", -10.0), ("oacute", "y", -10.0), ("oacute", "yacute", -10.0), ("oacute", "ydieresis", -10.0), ("ocircumflex", "v", -15.0), ("ocircumflex", "w", -25.0), ("ocircumflex", "x", -10.0), ("ocircumflex", "y", -10.0), ("ocircumflex", "yacute", -10.0), ("ocircumflex", "ydieresis", -10.0), ("odieresis", "v", -15.0), ("odieresis", "w", -25.0), ("odieresis", "x", -10.0), ("odieresis", "y", -10.0), ("odieresis", "yacute", -10.0), ("odieresis", "ydieresis", -10.0), ("ograve", "v", -15.0), ("ograve", "w", -25.0), ("ograve", "x", -10.0), ("ograve", "y", -10.0), ("ograve", "yacute", -10.0), ("ograve", "ydieresis", -10.0), ("ohungarumlaut", "v", -15.0), ("ohungarumlaut", "w", -25.0), ("ohungarumlaut", "x", -10.0), ("ohungarumlaut", "y", -10.0), ("ohungarumlaut", "yacute", -10.0), ("ohungarumlaut", "ydieresis", -10.0), ("omacron", "v", -15.0), ("omacron", "w", -25.0), ("omacron", "x", -10.0), ("omacron", "y", -10.0), ("omacron", "yacute", -10.0), ("omacron", "ydieresis", -10.0), ("oslash", "v", -15.0), ("oslash", "w", -25.0), ("oslash", "x", -10.0), ("oslash", "y", -10.0), ("oslash", "yacute", -10.0), ("oslash", "ydieresis", -10.0), ("otilde", "v", -15.0), ("otilde", "w", -25.0), ("otilde", "x", -10.0), ("otilde", "y", -10.0), ("otilde", "yacute", -10.0), ("otilde", "ydieresis", -10.0), ("period", "quotedblright", -95.0), ("period", "quoteright", -95.0), ("quoteleft", "quoteleft", -74.0), ("quoteright", "d", -15.0), ("quoteright", "dcroat", -15.0), ("quoteright", "quoteright", -74.0), ("quoteright", "r", -15.0), ("quoteright", "racute", -15.0), ("quoteright", "rcaron", -15.0), ("quoteright", "rcommaaccent", -15.0), ("quoteright", "s", -74.0), ("quoteright", "sacute", -74.0), ("quoteright", "scaron", -74.0), ("quoteright", "scedilla", -74.0), ("quoteright", "scommaaccent", -74.0), ("quoteright", "space", -74.0), ("quoteright", "t", -37.0), ("quoteright", "tcommaaccent", -37.0), ("quoteright", "v", -15.0), ("r", "comma", -65.0), ("r", "period", -65.0), ("racute", "comma", -65.0), ("racute", "period", -65.0), ("rcaron", "comma", -65.0), ("rcaron", "period", -65.0), ("rcommaaccent", "comma", -65.0), ("rcommaaccent", "period", -65.0), ("space", "A", -37.0), ("space", "Aacute", -37.0), ("space", "Abreve", -37.0), ("space", "Acircumflex", -37.0), ("space", "Adieresis", -37.0), ("space", "Agrave", -37.0), ("space", "Amacron", -37.0), ("space", "Aogonek", -37.0), ("space", "Aring", -37.0), ("space", "Atilde", -37.0), ("space", "V", -70.0), ("space", "W", -70.0), ("space", "Y", -70.0), ("space", "Yacute", -70.0), ("space", "Ydieresis", -70.0), ("v", "comma", -37.0), ("v", "e", -15.0), ("v", "eacute", -15.0), ("v", "ecaron", -15.0), ("v", "ecircumflex", -15.0), ("v", "edieresis", -15.0), ("v", "edotaccent", -15.0), ("v", "egrave", -15.0), ("v", "emacron", -15.0), ("v", "eogonek", -15.0), ("v", "o", -15.0), ("v", "oacute", -15.0), ("v", "ocircumflex", -15.0), ("v", "odieresis", -15.0), ("v", "ograve", -15.0), ("v", "ohungarumlaut", -15.0), ("v", "omacron", -15.0), ("v", "oslash", -15.0), ("v", "otilde", -15.0), ("v", "period", -37.0), ("w", "a", -10.0), ("w", "aacute", -10.0), ("w", "abreve", -10.0), ("w", "acircumflex", -10.0), ("w", "adieresis", -10.0), ("w", "agrave", -10.0), ("w", "amacron", -10.0), ("w", "aogonek", -10.0), ("w", "aring", -10.0), ("w", "atilde", -10.0), ("w", "comma", -37.0), ("w", "e", -10.0), ("w", "eacute", -10.0), ("w", "ecaron", -10.0), ("w", "ecircumflex", -10.0), ("w", "edieresis", -10.0), ("w", "edotaccent", -10.0), ("w", "egrave", -10.0), ("w", "emacron", -10.0), ("w", "eogonek", -10.0), ("w", "o", -15.0), ("w", "oacute", -15.0), ("w", "ocircumflex", -15.0), ("w", "odieresis", -15.0), ("w", "ograve", -15.0), ("w", "ohungarumlaut", -15.0), ("w", "omacron", -15.0), ("w", "oslash", -15.0), ("w", "otilde", -15.0), ("w", "period", -37.0), ("x", "e", -10.0), ("x", "eacute", -10.0), ("x", "ecaron", -10.0), ("x", "ecircumflex", -10.0), ("x", "edieresis", -10.0), ("x", "edotaccent", -10.0), ("x", "egrave", -10.0), ("x", "emacron", -10.0), ("x", "eogonek", -10.0), ("y", "comma", -37.0), ("y", "period", -37.0), ("yacute", "comma", -37.0), ("yacute", "period", -37.0), ("ydieresis", "comma", -37.0), ("ydieresis", "period", -37.0)]),
+("Times-Italic", &[("A", "C", -30.0), ("A", "Cacute", -30.0), ("A", "Ccaron", -30.0), ("A", "Ccedilla", -30.0), ("A", "G", -35.0), ("A", "Gbreve", -35.0), ("A", "Gcommaaccent", -35.0), ("A", "O", -40.0), ("A", "Oacute", -40.0), ("A", "Ocircumflex", -40.0), ("A", "Odieresis", -40.0), ("A", "Ograve", -40.0), ("A", "Ohungarumlaut", -40.0), ("A", "Omacron", -40.0), ("A", "Oslash", -40.0), ("A", "Otilde", -40.0), ("A", "Q", -40.0), ("A", "T", -37.0), ("A", "Tcaron", -37.0), ("A", "Tcommaaccent", -37.0), ("A", "U", -50.0), ("A", "Uacute", -50.0), ("A", "Ucircumflex", -50.0), ("A", "Udieresis", -50.0), ("A", "Ugrave", -50.0), ("A", "Uhungarumlaut", -50.0), ("A", "Umacron", -50.0), ("A", "Uogonek", -50.0), ("A", "Uring", -50.0), ("A", "V", -105.0), ("A", "W", -95.0), ("A", "Y", -55.0), ("A", "Yacute", -55.0), ("A", "Ydieresis", -55.0), ("A", "quoteright", -37.0), ("A", "u", -20.0), ("A", "uacute", -20.0), ("A", "ucircumflex", -20.0), ("A", "udieresis", -20.0), ("A", "ugrave", -20.0), ("A", "uhungarumlaut", -20.0), ("A", "umacron", -20.0), ("A", "uogonek", -20.0), ("A", "uring", -20.0), ("A", "v", -55.0), ("A", "w", -55.0), ("A", "y", -55.0), ("A", "yacute", -55.0), ("A", "ydieresis", -55.0), ("Aacute", "C", -30.0), ("Aacute", "Cacute", -30.0), ("Aacute", "Ccaron", -30.0), ("Aacute", "Ccedilla", -30.0), ("Aacute", "G", -35.0), ("Aacute", "Gbreve", -35.0), ("Aacute", "Gcommaaccent", -35.0), ("Aacute", "O", -40.0), ("Aacute", "Oacute", -40.0), ("Aacute", "Ocircumflex", -40.0), ("Aacute", "Odieresis", -40.0), ("Aacute", "Ograve", -40.0), ("Aacute", "Ohungarumlaut", -40.0), ("Aacute", "Omacron", -40.0), ("Aacute", "Oslash", -40.0), ("Aacute", "Otilde", -40.0), ("Aacute", "Q", -40.0), ("Aacute", "T", -37.0), ("Aacute", "Tcaron", -37.0), ("Aacute", "Tcommaaccent", -37.0), ("Aacute", "U", -50.0), ("Aacute", "Uacute", -50.0), ("Aacute", "Ucircumflex", -50.0), ("Aacute", "Udieresis", -50.0), ("Aacute", "Ugrave", -50.0), ("Aacute", "Uhungarumlaut", -50.0), ("Aacute", "Umacron", -50.0), ("Aacute", "Uogonek", -50.0), ("Aacute", "Uring", -50.0), ("Aacute", "V", -105.0), ("Aacute", "W", -95.0), ("Aacute", "Y", -55.0), ("Aacute", "Yacute", -55.0), ("Aacute", "Ydieresis", -55.0), ("Aacute", "quoteright", -37.0), ("Aacute", "u", -20.0), ("Aacute", "uacute", -20.0), ("Aacute", "ucircumflex", -20.0), ("Aacute", "udieresis", -20.0), ("Aacute", "ugrave", -20.0), ("Aacute", "uhungarumlaut", -20.0), ("Aacute", "umacron", -20.0), ("Aacute", "uogonek", -20.0), ("Aacute", "uring", -20.0), ("Aacute", "v", -55.0), ("Aacute", "w", -55.0), ("Aacute", "y", -55.0), ("Aacute", "yacute", -55.0), ("Aacute", "ydieresis", -55.0), ("Abreve", "C", -30.0), ("Abreve", "Cacute", -30.0), ("Abreve", "Ccaron", -30.0), ("Abreve", "Ccedilla", -30.0), ("Abreve", "G", -35.0), ("Abreve", "Gbreve", -35.0), ("Abreve", "Gcommaaccent", -35.0), ("Abreve", "O", -40.0), ("Abreve", "Oacute", -40.0), ("Abreve", "Ocircumflex", -40.0), ("Abreve", "Odieresis", -40.0), ("Abreve", "Ograve", -40.0), ("Abreve", "Ohungarumlaut", -40.0), ("Abreve", "Omacron", -40.0), ("Abreve", "Oslash", -40.0), ("Abreve", "Otilde", -40.0), ("Abreve", "Q", -40.0), ("Abreve", "T", -37.0), ("Abreve", "Tcaron", -37.0), ("Abreve", "Tcommaaccent", -37.0), ("Abreve", "U", -50.0), ("Abreve", "Uacute", -50.0), ("Abreve", "Ucircumflex", -50.0), ("Abreve", "Udieresis", -50.0), ("Abreve", "Ugrave", -50.0), ("Abreve", "Uhungarumlaut", -50.0), ("Abreve", "Umacron", -50.0), ("Abreve", "Uogonek", -50.0), ("Abreve", "Uring", -50.0), ("Abreve", "V", -105.0), ("Abreve", "W", -95.0), ("Abreve", "Y", -55.0), ("Abreve", "Yacute", -55.0), ("Abreve", "Ydieresis", -55.0), ("Abreve", "quoteright", -37.0), ("Abreve", "u", -20.0), ("Abreve", "uacute", -20.0), ("Abreve", "ucircumflex", -20.0), ("Abreve", "udieresis", -20.0), ("Abreve", "ugrave", -20.0), ("Abreve", "uhungarumlaut", -20.0), ("Abreve", "umacron", -20.0), ("Abreve", "uogonek", -20.0), ("Abreve", "uring", -20.0), ("Abreve", "v", -55.0), ("Abreve", "w", -55.0), ("Abreve", "y", -55.0), ("Abreve", "yacute", -55.0), ("Abreve", "ydieresis", -55.0), ("Acircumflex", "C", -30.0), ("Acircumflex", "Cacute", -30.0), ("Acircumflex", "Ccaron", -30.0), ("Acircumflex", "Ccedilla", -30.0), ("Acircumflex", "G", -35.0), ("Acircumflex", "Gbreve", -35.0), ("Acircumflex", "Gcommaaccent", -35.0), ("Acircumflex", "O", -40.0), ("Acircumflex", "Oacute", -40.0), ("Acircumflex", "Ocircumflex", -40.0), ("Acircumflex", "Odieresis", -40.0), ("Acircumflex", "Ograve", -40.0), ("Acircumflex", "Ohungarumlaut", -40.0), ("Acircumflex", "Omacron", -40.0), ("Acircumflex", "Oslash", -40.0), ("Acircumflex", "Otilde", -40.0), ("Acircumflex", "Q", -40.0), ("Acircumflex", "T", -37.0), ("Acircumflex", "Tcaron", -37.0), ("Acircumflex", "Tcommaaccent", -37.0), ("Acircumflex", "U", -50.0), ("Acircumflex", "Uacute", -50.0), ("Acircumflex", "Ucircumflex", -50.0), ("Acircumflex", "Udieresis", -50.0), ("Acircumflex", "Ugrave", -50.0), ("Acircumflex", "Uhungarumlaut", -50.0), ("Acircumflex", "Umacron", -50.0), ("Acircumflex", "Uogonek", -50.0), ("Acircumflex", "Uring", -50.0), ("Acircumflex", "V", -105.0), ("Acircumflex", "W", -95.0), ("Acircumflex", "Y", -55.0), ("Acircumflex", "Yacute", -55.0), ("Acircumflex", "Ydieresis", -55.0), ("Acircumflex", "quoteright", -37.0), ("Acircumflex", "u", -20.0), ("Acircumflex", "uacute", -20.0), ("Acircumflex", "ucircumflex", -20.0), ("Acircumflex", "udieresis", -20.0), ("Acircumflex", "ugrave", -20.0), ("Acircumflex", "uhungarumlaut", -20.0), ("Acircumflex", "umacron", -20.0), ("Acircumflex", "uogonek", -20.0), ("Acircumflex", "uring", -20.0), ("Acircumflex", "v", -55.0), ("Acircumflex", "w", -55.0), ("Acircumflex", "y", -55.0), ("Acircumflex", "yacute", -55.0), ("Acircumflex", "ydieresis", -55.0), ("Adieresis", "C", -30.0), ("Adieresis", "Cacute", -30.0), ("Adieresis", "Ccaron", -30.0), ("Adieresis", "Ccedilla", -30.0), ("Adieresis", "G", -35.0), ("Adieresis", "Gbreve", -35.0), ("Adieresis", "Gcommaaccent", -35.0), ("Adieresis", "O", -40.0), ("Adieresis", "Oacute", -40.0), ("Adieresis", "Ocircumflex", -40.0), ("Adieresis", "Odieresis", -40.0), ("Adieresis", "Ograve", -40.0), ("Adieresis", "Ohungarumlaut", -40.0), ("Adieresis", "Omacron", -40.0), ("Adieresis", "Oslash", -40.0), ("Adieresis", "Otilde", -40.0), ("Adieresis", "Q", -40.0), ("Adieresis", "T", -37.0), ("Adieresis", "Tcaron", -37.0), ("Adieresis", "Tcommaaccent", -37.0), ("Adieresis", "U", -50.0), ("Adieresis", "Uacute", -50.0), ("Adieresis", "Ucircumflex", -50.0), ("Adieresis", "Udieresis", -50.0), ("Adieresis", "Ugrave", -50.0), ("Adieresis", "Uhungarumlaut", -50.0), ("Adieresis", "Umacron", -50.0), ("Adieresis", "Uogonek", -50.0), ("Adieresis", "Uring", -50.0), ("Adieresis", "V", -105.0), ("Adieresis", "W", -95.0), ("Adieresis", "Y", -55.0), ("Adieresis", "Yacute", -55.0), ("Adieresis", "Ydieresis", -55.0), ("Adieresis", "quoteright", -37.0), ("Adieresis", "u", -20.0), ("Adieresis", "uacute", -20.0), ("Adieresis", "ucircumflex", -20.0), ("Adieresis", "udieresis", -20.0), ("Adieresis", "ugrave", -20.0), ("Adieresis", "uhungarumlaut", -20.0), ("Adieresis", "umacron", -20.0), ("Adieresis", "uogonek", -20.0), ("Adieresis", "uring", -20.0), ("Adieresis", "v", -55.0), ("Adieresis", "w", -55.0), ("Adieresis", "y", -55.0), ("Adieresis", "yacute", -55.0), ("Adieresis", "ydieresis", -55.0), ("Agrave", "C", -30.0), ("Agrave", "Cacute", -30.0), ("Agrave", "Ccaron", -30.0), ("Agrave", "Ccedilla", -30.0), ("Agrave", "G", -35.0), ("Agrave", "Gbreve", -35.0), ("Agrave", "Gcommaaccent", -35.0), ("Agrave", "O", -40.0), ("Agrave", "Oacute", -40.0), ("Agrave", "Ocircumflex", -40.0), ("Agrave", "Odieresis", -40.0), ("Agrave", "Ograve", -40.0), ("Agrave", "Ohungarumlaut", -40.0), ("Agrave", "Omacron", -40.0), ("Agrave", "Oslash", -40.0), ("Agrave", "Otilde", -40.0), ("Agrave", "Q", -40.0), ("Agrave", "T", -37.0), ("Agrave", "Tcaron", -37.0), ("Agrave", "Tcommaaccent", -37.0), ("Agrave", "U", -50.0), ("Agrave", "Uacute", -50.0), ("Agrave", "Ucircumflex", -50.0), ("Agrave", "Udieresis", -50.0), ("Agrave", "Ugrave", -50.0), ("Agrave", "Uhungarumlaut", -50.0), ("Agrave", "Umacron", -50.0), ("Agrave", "Uogonek", -50.0), ("Agrave", "Uring", -50.0), ("Agrave", "V", -105.0), ("Agrave", "W", -95.0), ("Agrave", "Y", -55.0), ("Agrave", "Yacute", -55.0), ("Agrave", "Ydieresis", -55.0), ("Agrave", "quoteright", -37.0), ("Agrave", "u", -20.0), ("Agrave", "uacute", -20.0), ("Agrave", "ucircumflex", -20.0), ("Agrave", "udieresis", -20.0), ("Agrave", "ugrave", -20.0), ("Agrave", "uhungarumlaut", -20.0), ("Agrave", "umacron", -20.0), ("Agrave", "uogonek", -20.0), ("Agrave", "uring", -20.0), ("Agrave", "v", -55.0), ("Agrave", "w", -55.0), ("Agrave", "y", -55.0), ("Agrave", "yacute", -55.0), ("Agrave", "ydieresis", -55.0), ("Amacron", "C", -30.0), ("Amacron", "Cacute", -30.0), ("Amacron", "Ccaron", -30.0), ("Amacron", "Ccedilla", -30.0), ("Amacron", "G", -35.0), ("Amacron", "Gbreve", -35.0), ("Amacron", "Gcommaaccent", -35.0), ("Amacron", "O", -40.0), ("Amacron", "Oacute", -40.0), ("Amacron", "Ocircumflex", -40.0), ("Amacron", "Odieresis", -40.0), ("Amacron", "Ograve", -40.0), ("Amacron", "Ohungarumlaut", -40.0), ("Amacron", "Omacron", -40.0), ("Amacron", "Oslash", -40.0), ("Amacron", "Otilde", -40.0), ("Amacron", "Q", -40.0), ("Amacron", "T", -37.0), ("Amacron", "Tcaron", -37.0), ("Amacron", "Tcommaaccent", -37.0), ("Amacron", "U", -50.0), ("Amacron", "Uacute", -50.0), ("Amacron", "Ucircumflex", -50.0), ("Amacron", "Udieresis", -50.0), ("Amacron", "Ugrave", -50.0), ("Amacron", "Uhungarumlaut", -50.0), ("Amacron", "Umacron", -50.0), ("Amacron", "Uogonek", -50.0), ("Amacron", "Uring", -50.0), ("Amacron", "V", -105.0), ("Amacron", "W", -95.0), ("Amacron", "Y", -55.0), ("Amacron", "Yacute", -55.0), ("Amacron", "Ydieresis", -55.0), ("Amacron", "quoteright", -37.0), ("Amacron", "u", -20.0), ("Amacron", "uacute", -20.0), ("Amacron", "ucircumflex", -20.0), ("Amacron", "udieresis", -20.0), ("Amacron", "ugrave", -20.0), ("Amacron", "uhungarumlaut", -20.0), ("Amacron", "umacron", -20.0), ("Amacron", "uogonek", -20.0), ("Amacron", "uring", -20.0), ("Amacron", "v", -55.0), ("Amacron", "w", -55.0), ("Amacron", "y", -55.0), ("Amacron", "yacute", -55.0), ("Amacron", "ydieresis", -55.0), ("Aogonek", "C", -30.0), ("Aogonek", "Cacute", -30.0), ("Aogonek", "Ccaron", -30.0), ("Aogonek", "Ccedilla", -30.0), ("Aogonek", "G", -35.0), ("Aogonek", "Gbreve", -35.0), ("Aogonek", "Gcommaaccent", -35.0), ("Aogonek", "O", -40.0), ("Aogonek", "Oacute", -40.0), ("Aogonek", "Ocircumflex", -40.0), ("Aogonek", "Odieresis", -40.0), ("Aogonek", "Ograve", -40.0), ("Aogonek", "Ohungarumlaut", -40.0), ("Aogonek", "Omacron", -40.0), ("Aogonek", "Oslash", -40.0), ("Aogonek", "Otilde", -40.0), ("Aogonek", "Q", -40.0), ("Aogonek", "T", -37.0), ("Aogonek", "Tcaron", -37.0), ("Aogonek", "Tcommaaccent", -37.0), ("Aogonek", "U", -50.0), ("Aogonek", "Uacute", -50.0), ("Aogonek", "Ucircumflex", -50.0), ("Aogonek", "Udieresis", -50.0), ("Aogonek", "Ugrave", -50.0), ("Aogonek", "Uhungarumlaut", -50.0), ("Aogonek", "Umacron", -50.0), ("Aogonek", "Uogonek", -50.0), ("Aogonek", "Uring", -50.0), ("Aogonek", "V", -105.0), ("Aogonek", "W", -95.0), ("Aogonek", "Y", -55.0), ("Aogonek", "Yacute", -55.0), ("Aogonek", "Ydieresis", -55.0), ("Aogonek", "quoteright", -37.0), ("Aogonek", "u", -20.0), ("Aogonek", "uacute", -20.0), ("Aogonek", "ucircumflex", -20.0), ("Aogonek", "udieresis", -20.0), ("Aogonek", "ugrave", -20.0), ("Aogonek", "uhungarumlaut", -20.0), ("Aogonek", "umacron", -20.0), ("Aogonek", "uogonek", -20.0), ("Aogonek", "uring", -20.0), ("Aogonek", "v", -55.0), ("Aogonek", "w", -55.0), ("Aogonek", "y", -55.0), ("Aogonek", "yacute", -55.0), ("Aogonek", "ydieresis", -55.0), ("Aring", "C", -30.0), ("Aring", "Cacute", -30.0), ("Aring", "Ccaron", -30.0), ("Aring", "Ccedilla", -30.0), ("Aring", "G", -35.0), ("Aring", "Gbreve", -35.0), ("Aring", "Gcommaaccent", -35.0), ("Aring", "O", -40.0), ("Aring", "Oacute", -40.0), ("Aring", "Ocircumflex", -40.0), ("Aring", "Odieresis", -40.0), ("Aring", "Ograve", -40.0), ("Aring", "Ohungarumlaut", -40.0), ("Aring", "Omacron", -40.0), ("Aring", "Oslash", -40.0), ("Aring", "Otilde", -40.0), ("Aring", "Q", -40.0), ("Aring", "T", -37.0), ("Aring", "Tcaron", -37.0), ("Aring", "Tcommaaccent", -37.0), ("Aring", "U", -50.0), ("Aring", "Uacute", -50.0), ("Aring", "Ucircumflex", -50.0), ("Aring", "Udieresis", -50.0), ("Aring", "Ugrave", -50.0), ("Aring", "Uhungarumlaut", -50.0), ("Aring", "Umacron", -50.0), ("Aring", "Uogonek", -50.0), ("Aring", "Uring", -50.0), ("Aring", "V", -105.0), ("Aring", "W", -95.0), ("Aring", "Y", -55.0), ("Aring", "Yacute", -55.0), ("Aring", "Ydieresis", -55.0), ("Aring", "quoteright", -37.0), ("Aring", "u", -20.0), ("Aring", "uacute", -20.0), ("Aring", "ucircumflex", -20.0), ("Aring", "udieresis", -20.0), ("Aring", "ugrave", -20.0), ("Aring", "uhungarumlaut", -20.0), ("Aring", "umacron", -20.0), ("Aring", "uogonek", -20.0), ("Aring", "uring", -20.0), ("Aring", "v", -55.0), ("Aring", "w", -55.0), ("Aring", "y", -55.0), ("Aring", "yacute", -55.0), ("Aring", "ydieresis", -55.0), ("Atilde", "C", -30.0), ("Atilde", "Cacute", -30.0), ("Atilde", "Ccaron", -30.0), ("Atilde", "Ccedilla", -30.0), ("Atilde", "G", -35.0), ("Atilde", "Gbreve", -35.0), ("Atilde", "Gcommaaccent", -35.0), ("Atilde", "O", -40.0), ("Atilde", "Oacute", -40.0), ("Atilde", "Ocircumflex", -40.0), ("Atilde", "Odieresis", -40.0), ("Atilde", "Ograve", -40.0), ("Atilde", "Ohungarumlaut", -40.0), ("Atilde", "Omacron", -40.0), ("Atilde", "Oslash", -40.0), ("Atilde", "Otilde", -40.0), ("Atilde", "Q", -40.0), ("Atilde", "T", -37.0), ("Atilde", "Tcaron", -37.0), ("Atilde", "Tcommaaccent", -37.0), ("Atilde", "U", -50.0), ("Atilde", "Uacute", -50.0), ("Atilde", "Ucircumflex", -50.0), ("Atilde", "Udieresis", -50.0), ("Atilde", "Ugrave", -50.0), ("Atilde", "Uhungarumlaut", -50.0), ("Atilde", "Umacron", -50.0), ("Atilde", "Uogonek", -50.0), ("Atilde", "Uring", -50.0), ("Atilde", "V", -105.0), ("Atilde", "W", -95.0), ("Atilde", "Y", -55.0), ("Atilde", "Yacute", -55.0), ("Atilde", "Ydieresis", -55.0), ("Atilde", "quoteright", -37.0), ("Atilde", "u", -20.0), ("Atilde", "uacute", -20.0), ("Atilde", "ucircumflex", -20.0), ("Atilde", "udieresis", -20.0), ("Atilde", "ugrave", -20.0), ("Atilde", "uhungarumlaut", -20.0), ("Atilde", "umacron", -20.0), ("Atilde", "uogonek", -20.0), ("Atilde", "uring", -20.0), ("Atilde", "v", -55.0), ("Atilde", "w", -55.0), ("Atilde", "y", -55.0), ("Atilde", "yacute", -55.0), ("Atilde", "ydieresis", -55.0), ("B", "A", -25.0), ("B", "Aacute", -25.0), ("B", "Abreve", -25.0), ("B", "Acircumflex", -25.0), ("B", "Adieresis", -25.0), ("B", "Agrave", -25.0), ("B", "Amacron", -25.0), ("B", "Aogonek", -25.0), ("B", "Aring", -25.0), ("B", "Atilde", -25.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -35.0), ("D", "Aacute", -35.0), ("D", "Abreve", -35.0), ("D", "Acircumflex", -35.0), ("D", "Adieresis", -35.0), ("D", "Agrave", -35.0), ("D", "Amacron", -35.0), ("D", "Aogonek", -35.0), ("D", "Aring", -35.0), ("D", "Atilde", -35.0), ("D", "V", -40.0), ("D", "W", -40.0), ("D", "Y", -40.0), ("D", "Yacute", -40.0), ("D", "Ydieresis", -40.0), ("Dcaron", "A", -35.0), ("Dcaron", "Aacute", -35.0), ("Dcaron", "Abreve", -35.0), ("Dcaron", "Acircumflex", -35.0), ("Dcaron", "Adieresis", -35.0), ("Dcaron", "Agrave", -35.0), ("Dcaron", "Amacron", -35.0), ("Dcaron", "Aogonek", -35.0), ("Dcaron", "Aring", -35.0), ("Dcaron", "Atilde", -35.0), ("Dcaron", "V", -40.0), ("Dcaron", "W", -40.0), ("Dcaron", "Y", -40.0), ("Dcaron", "Yacute", -40.0), ("Dcaron", "Ydieresis", -40.0), ("Dcroat", "A", -35.0), ("Dcroat", "Aacute", -35.0), ("Dcroat", "Abreve", -35.0), ("Dcroat", "Acircumflex", -35.0), ("Dcroat", "Adieresis", -35.0), ("Dcroat", "Agrave", -35.0), ("Dcroat", "Amacron", -35.0), ("Dcroat", "Aogonek", -35.0), ("Dcroat", "Aring", -35.0), ("Dcroat", "Atilde", -35.0), ("Dcroat", "V", -40.0), ("Dcroat", "W", -40.0), ("Dcroat", "Y", -40.0), ("Dcroat", "Yacute", -40.0), ("Dcroat", "Ydieresis", -40.0), ("F", "A", -115.0), ("F", "Aacute", -115.0), ("F", "Abreve", -115.0), ("F", "Acircumflex", -115.0), ("F", "Adieresis", -115.0), ("F", "Agrave", -115.0), ("F", "Amacron", -115.0), ("F", "Aogonek", -115.0), ("F", "Aring", -115.0), ("F", "Atilde", -115.0), ("F", "a", -75.0), ("F", "aacute", -75.0), ("F", "abreve", -75.0), ("F", "acircumflex", -75.0), ("F", "adieresis", -75.0), ("F", "agrave", -75.0), ("F", "amacron", -75.0), ("F", "aogonek", -75.0), ("F", "aring", -75.0), ("F", "atilde", -75.0), ("F", "comma", -135.0), ("F", "e", -75.0), ("F", "eacute", -75.0), ("F", "ecaron", -75.0), ("F", "ecircumflex", -75.0), ("F", "edieresis", -75.0), ("F", "edotaccent", -75.0), ("F", "egrave", -75.0), ("F", "emacron", -75.0), ("F", "eogonek", -75.0), ("F", "i", -45.0), ("F", "iacute", -45.0), ("F", "icircumflex", -45.0), ("F", "idieresis", -45.0), ("F", "igrave", -45.0), ("F", "imacron", -45.0), ("F", "iogonek", -45.0), ("F", "o", -105.0), ("F", "oacute", -105.0), ("F", "ocircumflex", -105.0), ("F", "odieresis", -105.0), ("F", "ograve", -105.0), ("F", "ohungarumlaut", -105.0), ("F", "omacron", -105.0), ("F", "oslash", -105.0), ("F", "otilde", -105.0), ("F", "period", -135.0), ("F", "r", -55.0), ("F", "racute", -55.0), ("F", "rcaron", -55.0), ("F", "rcommaaccent", -55.0), ("J", "A", -40.0), ("J", "Aacute", -40.0), ("J", "Abreve", -40.0), ("J", "Acircumflex", -40.0), ("J", "Adieresis", -40.0), ("J", "Agrave", -40.0), ("J", "Amacron", -40.0), ("J", "Aogonek", -40.0), ("J", "Aring", -40.0), ("J", "Atilde", -40.0), ("J", "a", -35.0), ("J", "aacute", -35.0), ("J", "abreve", -35.0), ("J", "acircumflex", -35.0), ("J", "adieresis", -35.0), ("J", "agrave", -35.0), ("J", "amacron", -35.0), ("J", "aogonek", -35.0), ("J", "aring", -35.0), ("J", "atilde", -35.0), ("J", "comma", -25.0), ("J", "e", -25.0), ("J", "eacute", -25.0), ("J", "ecaron", -25.0), ("J", "ecircumflex", -25.0), ("J", "edieresis", -25.0), ("J", "edotaccent", -25.0), ("J", "egrave", -25.0), ("J", "emacron", -25.0), ("J", "eogonek", -25.0), ("J", "o", -25.0), ("J", "oacute", -25.0), ("J", "ocircumflex", -25.0), ("J", "odieresis", -25.0), ("J", "ograve", -25.0), ("J", "ohungarumlaut", -25.0), ("J", "omacron", -25.0), ("J", "oslash", -25.0), ("J", "otilde", -25.0), ("J", "period", -25.0), ("J", "u", -35.0), ("J", "uacute", -35.0), ("J", "ucircumflex", -35.0), ("J", "udieresis", -35.0), ("J", "ugrave", -35.0), ("J", "uhungarumlaut", -35.0), ("J", "umacron", -35.0), ("J", "uogonek", -35.0), ("J", "uring", -35.0), ("K", "O", -50.0), ("K", "Oacute", -50.0), ("K", "Ocircumflex", -50.0), ("K", "Odieresis", -50.0), ("K", "Ograve", -50.0), ("K", "Ohungarumlaut", -50.0), ("K", "Omacron", -50.0), ("K", "Oslash", -50.0), ("K", "Otilde", -50.0), ("K", "e", -35.0), ("K", "eacute", -35.0), ("K", "ecaron", -35.0), ("K", "ecircumflex", -35.0), ("K", "edieresis", -35.0), ("K", "edotaccent", -35.0), ("K", "egrave", -35.0), ("K", "emacron", -35.0), ("K", "eogonek", -35.0), ("K", "o", -40.0), ("K", "oacute", -40.0), ("K", "ocircumflex", -40.0), ("K", "odieresis", -40.0), ("K", "ograve", -40.0), ("K", "ohungarumlaut", -40.0), ("K", "omacron", -40.0), ("K", "oslash", -40.0), ("K", "otilde", -40.0), ("K", "u", -40.0), ("K", "uacute", -40.0), ("K", "ucircumflex", -40.0), ("K", "udieresis", -40.0), ("K", "ugrave", -40.0), ("K", "uhungarumlaut", -40.0), ("K", "umacron", -40.0), ("K", "uogonek", -40.0), ("K", "uring", -40.0), ("K", "y", -40.0), ("K", "yacute", -40.0), ("K", "ydieresis", -40.0), ("Kcommaaccent", "O", -50.0), ("Kcommaaccent", "Oacute", -50.0), ("Kcommaaccent", "Ocircumflex", -50.0), ("Kcommaaccent", "Odieresis", -50.0), ("Kcommaaccent", "Ograve", -50.0), ("Kcommaaccent", "Ohungarumlaut", -50.0), ("Kcommaaccent", "Omacron", -50.0), ("Kcommaaccent", "Oslash", -50.0), ("Kcommaaccent", "Otilde", -50.0), ("Kcommaaccent", "e", -35.0), ("Kcommaaccent", "eacute", -35.0), ("Kcommaaccent", "ecaron", -35.0), ("Kcommaaccent", "ecircumflex", -35.0), ("Kcommaaccent", "edieresis", -35.0), ("Kcommaaccent", "edotaccent", -35.0), ("Kcommaaccent", "egrave", -35.0), ("Kcommaaccent", "emacron", -35.0), ("Kcommaaccent", "eogonek", -35.0), ("Kcommaaccent", "o", -40.0), ("Kcommaaccent", "oacute", -40.0), ("Kcommaaccent", "ocircumflex", -40.0), ("Kcommaaccent", "odieresis", -40.0), ("Kcommaaccent", "ograve", -40.0), ("Kcommaaccent", "ohungarumlaut", -40.0), ("Kcommaaccent", "omacron", -40.0), ("Kcommaaccent", "oslash", -40.0), ("Kcommaaccent", "otilde", -40.0), ("Kcommaaccent", "u", -40.0), ("Kcommaaccent", "uacute", -40.0), ("Kcommaaccent", "ucircumflex", -40.0), ("Kcommaaccent", "udieresis", -40.0), ("Kcommaaccent", "ugrave", -40.0), ("Kcommaaccent", "uhungarumlaut", -40.0), ("Kcommaaccent", "umacron", -40.0), ("Kcommaaccent", "uogonek", -40.0), ("Kcommaaccent", "uring", -40.0), ("Kcommaaccent", "y", -40.0), ("Kcommaaccent", "yacute", -40.0), ("Kcommaaccent", "ydieresis", -40.0), ("L", "T", -20.0), ("L", "Tcaron", -20.0), ("L", "Tcommaaccent", -20.0), ("L", "V", -55.0), ("L", "W", -55.0), ("L", "Y", -20.0), ("L", "Yacute", -20.0), ("L", "Ydieresis", -20.0), ("L", "quoteright", -37.0), ("L", "y", -30.0), ("L", "yacute", -30.0), ("L", "ydieresis", -30.0), ("Lacute", "T", -20.0), ("Lacute", "Tcaron", -20.0), ("Lacute", "Tcommaaccent", -20.0), ("Lacute", "V", -55.0), ("Lacute", "W", -55.0), ("Lacute", "Y", -20.0), ("Lacute", "Yacute", -20.0), ("Lacute", "Ydieresis", -20.0), ("Lacute", "quoteright", -37.0), ("Lacute", "y", -30.0), ("Lacute", "yacute", -30.0), ("Lacute", "ydieresis", -30.0), ("Lcommaaccent", "T", -20.0), ("Lcommaaccent", "Tcaron", -20.0), ("Lcommaaccent", "Tcommaaccent", -20.0), ("Lcommaaccent", "V", -55.0), ("Lcommaaccent", "W", -55.0), ("Lcommaaccent", "Y", -20.0), ("Lcommaaccent", "Yacute", -20.0), ("Lcommaaccent", "Ydieresis", -20.0), ("Lcommaaccent", "quoteright", -37.0), ("Lcommaaccent", "y", -30.0), ("Lcommaaccent", "yacute", -30.0), ("Lcommaaccent", "ydieresis", -30.0), ("Lslash", "T", -20.0), ("Lslash", "Tcaron", -20.0), ("Lslash", "Tcommaaccent", -20.0), ("Lslash", "V", -55.0), ("Lslash", "W", -55.0), ("Lslash", "Y", -20.0), ("Lslash", "Yacute", -20.0), ("Lslash", "Ydieresis", -20.0), ("Lslash", "quoteright", -37.0), ("Lslash", "y", -30.0), ("Lslash", "yacute", -30.0), ("Lslash", "ydieresis", -30.0), ("N", "A", -27.0), ("N", "Aacute", -27.0), ("N", "Abreve", -27.0), ("N", "Acircumflex", -27.0), ("N", "Adieresis", -27.0), ("N", "Agrave", -27.0), ("N", "Amacron", -27.0), ("N", "Aogonek", -27.0), ("N", "Aring", -27.0), ("N", "Atilde", -27.0), ("Nacute", "A", -27.0), ("Nacute", "Aacute", -27.0), ("Nacute", "Abreve", -27.0), ("Nacute", "Acircumflex", -27.0), ("Nacute", "Adieresis", -27.0), ("Nacute", "Agrave", -27.0), ("Nacute", "Amacron", -27.0), ("Nacute", "Aogonek", -27.0), ("Nacute", "Aring", -27.0), ("Nacute", "Atilde", -27.0), ("Ncaron", "A", -27.0), ("Ncaron", "Aacute", -27.0), ("Ncaron", "Abreve", -27.0), ("Ncaron", "Acircumflex", -27.0), ("Ncaron", "Adieresis", -27.0), ("Ncaron", "Agrave", -27.0), ("Ncaron", "Amacron", -27.0), ("Ncaron", "Aogonek", -27.0), ("Ncaron", "Aring", -27.0), ("Ncaron", "Atilde", -27.0), ("Ncommaaccent", "A", -27.0), ("Ncommaaccent", "Aacute", -27.0), ("Ncommaaccent", "Abreve", -27.0), ("Ncommaaccent", "Acircumflex", -27.0), ("Ncommaaccent", "Adieresis", -27.0), ("Ncommaaccent", "Agrave", -27.0), ("Ncommaaccent", "Amacron", -27.0), ("Ncommaaccent", "Aogonek", -27.0), ("Ncommaaccent", "Aring", -27.0), ("Ncommaaccent", "Atilde", -27.0), ("Ntilde", "A", -27.0), ("Ntilde", "Aacute", -27.0), ("Ntilde", "Abreve", -27.0), ("Ntilde", "Acircumflex", -27.0), ("Ntilde", "Adieresis", -27.0), ("Ntilde", "Agrave", -27.0), ("Ntilde", "Amacron", -27.0), ("Ntilde", "Aogonek", -27.0), ("Ntilde", "Aring", -27.0), ("Ntilde", "Atilde", -27.0), ("O", "A", -55.0), ("O", "Aacute", -55.0), ("O", "Abreve", -55.0), ("O", "Acircumflex", -55.0), ("O", "Adieresis", -55.0), ("O", "Agrave", -55.0), ("O", "Amacron", -55.0), ("O", "Aogonek", -55.0), ("O", "Aring", -55.0), ("O", "Atilde", -55.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -50.0), ("O", "X", -40.0), ("O", "Y", -50.0), ("O", "Yacute", -50.0), ("O", "Ydieresis", -50.0), ("Oacute", "A", -55.0), ("Oacute", "Aacute", -55.0), ("Oacute", "Abreve", -55.0), ("Oacute", "Acircumflex", -55.0), ("Oacute", "Adieresis", -55.0), ("Oacute", "Agrave", -55.0), ("Oacute", "Amacron", -55.0), ("Oacute", "Aogonek", -55.0), ("Oacute", "Aring", -55.0), ("Oacute", "Atilde", -55.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -50.0), ("Oacute", "X", -40.0), ("Oacute", "Y", -50.0), ("Oacute", "Yacute", -50.0), ("Oacute", "Ydieresis", -50.0), ("Ocircumflex", "A", -55.0), ("Ocircumflex", "Aacute", -55.0), ("Ocircumflex", "Abreve", -55.0), ("Ocircumflex", "Acircumflex", -55.0), ("Ocircumflex", "Adieresis", -55.0), ("Ocircumflex", "Agrave", -55.0), ("Ocircumflex", "Amacron", -55.0), ("Ocircumflex", "Aogonek", -55.0), ("Ocircumflex", "Aring", -55.0), ("Ocircumflex", "Atilde", -55.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -50.0), ("Ocircumflex", "X", -40.0), ("Ocircumflex", "Y", -50.0), ("Ocircumflex", "Yacute", -50.0), ("Ocircumflex", "Ydieresis", -50.0), ("Odieresis", "A", -55.0), ("Odieresis", "Aacute", -55.0), ("Odieresis", "Abreve", -55.0), ("Odieresis", "Acircumflex", -55.0), ("Odieresis", "Adieresis", -55.0), ("Odieresis", "Agrave", -55.0), ("Odieresis", "Amacron", -55.0), ("Odieresis", "Aogonek", -55.0), ("Odieresis", "Aring", -55.0), ("Odieresis", "Atilde", -55.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -50.0), ("Odieresis", "X", -40.0), ("Odieresis", "Y", -50.0), ("Odieresis", "Yacute", -50.0), ("Odieresis", "Ydieresis", -50.0), ("Ograve", "A", -55.0), ("Ograve", "Aacute", -55.0), ("Ograve", "Abreve", -55.0), ("Ograve", "Acircumflex", -55.0), ("Ograve", "Adieresis", -55.0), ("Ograve", "Agrave", -55.0), ("Ograve", "Amacron", -55.0), ("Ograve", "Aogonek", -55.0), ("Ograve", "Aring", -55.0), ("Ograve", "Atilde", -55.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -50.0), ("Ograve", "X", -40.0), ("Ograve", "Y", -50.0), ("Ograve", "Yacute", -50.0), ("Ograve", "Ydieresis", -50.0), ("Ohungarumlaut", "A", -55.0), ("Ohungarumlaut", "Aacute", -55.0), ("Ohungarumlaut", "Abreve", -55.0), ("Ohungarumlaut", "Acircumflex", -55.0), ("Ohungarumlaut", "Adieresis", -55.0), ("Ohungarumlaut", "Agrave", -55.0), ("Ohungarumlaut", "Amacron", -55.0), ("Ohungarumlaut", "Aogonek", -55.0), ("Ohungarumlaut", "Aring", -55.0), ("Ohungarumlaut", "Atilde", -55.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -50.0), ("Ohungarumlaut", "X", -40.0), ("Ohungarumlaut", "Y", -50.0), ("Ohungarumlaut", "Yacute", -50.0), ("Ohungarumlaut", "Ydieresis", -50.0), ("Omacron", "A", -55.0), ("Omacron", "Aacute", -55.0), ("Omacron", "Abreve", -55.0), ("Omacron", "Acircumflex", -55.0), ("Omacron", "Adieresis", -55.0), ("Omacron", "Agrave", -55.0), ("Omacron", "Amacron", -55.0), ("Omacron", "Aogonek", -55.0), ("Omacron", "Aring", -55.0), ("Omacron", "Atilde", -55.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -50.0), ("Omacron", "X", -40.0), ("Omacron", "Y", -50.0), ("Omacron", "Yacute", -50.0), ("Omacron", "Ydieresis", -50.0), ("Oslash", "A", -55.0), ("Oslash", "Aacute", -55.0), ("Oslash", "Abreve", -55.0), ("Oslash", "Acircumflex", -55.0), ("Oslash", "Adieresis", -55.0), ("Oslash", "Agrave", -55.0), ("Oslash", "Amacron", -55.0), ("Oslash", "Aogonek", -55.0), ("Oslash", "Aring", -55.0), ("Oslash", "Atilde", -55.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -50.0), ("Oslash", "X", -40.0), ("Oslash", "Y", -50.0), ("Oslash", "Yacute", -50.0), ("Oslash", "Ydieresis", -50.0), ("Otilde", "A", -55.0), ("Otilde", "Aacute", -55.0), ("Otilde", "Abreve", -55.0), ("Otilde", "Acircumflex", -55.0), ("Otilde", "Adieresis", -55.0), ("Otilde", "Agrave", -55.0), ("Otilde", "Amacron", -55.0), ("Otilde", "Aogonek", -55.0), ("Otilde", "Aring", -55.0), ("Otilde", "Atilde", -55.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -50.0), ("Otilde", "X", -40.0), ("Otilde", "Y", -50.0), ("Otilde", "Yacute", -50.0), ("Otilde", "Ydieresis", -50.0), ("P", "A", -90.0), ("P", "Aacute", -90.0), ("P", "Abreve", -90.0), ("P", "Acircumflex", -90.0), ("P", "Adieresis", -90.0), ("P", "Agrave", -90.0), ("P", "Amacron", -90.0), ("P", "Aogonek", -90.0), ("P", "Aring", -90.0), ("P", "Atilde", -90.0), ("P", "a", -80.0), ("P", "aacute", -80.0), ("P", "abreve", -80.0), ("P", "acircumflex", -80.0), ("P", "adieresis", -80.0), ("P", "agrave", -80.0), ("P", "amacron", -80.0), ("P", "aogonek", -80.0), ("P", "aring", -80.0), ("P", "atilde", -80.0), ("P", "comma", -135.0), ("P", "e", -80.0), ("P", "eacute", -80.0), ("P", "ecaron", -80.0), ("P", "ecircumflex", -80.0), ("P", "edieresis", -80.0), ("P", "edotaccent", -80.0), ("P", "egrave", -80.0), ("P", "emacron", -80.0), ("P", "eogonek", -80.0), ("P", "o", -80.0), ("P", "oacute", -80.0), ("P", "ocircumflex", -80.0), ("P", "odieresis", -80.0), ("P", "ograve", -80.0), ("P", "ohungarumlaut", -80.0), ("P", "omacron", -80.0), ("P", "oslash", -80.0), ("P", "otilde", -80.0), ("P", "period", -135.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("R", "O", -40.0), ("R", "Oacute", -40.0), ("R", "Ocircumflex", -40.0), ("R", "Odieresis", -40.0), ("R", "Ograve", -40.0), ("R", "Ohungarumlaut", -40.0), ("R", "Omacron", -40.0), ("R", "Oslash", -40.0), ("R", "Otilde", -40.0), ("R", "U", -40.0), ("R", "Uacute", -40.0), ("R", "Ucircumflex", -40.0), ("R", "Udieresis", -40.0), ("R", "Ugrave", -40.0), ("R", "Uhungarumlaut", -40.0), ("R", "Umacron", -40.0), ("R", "Uogonek", -40.0), ("R", "Uring", -40.0), ("R", "V", -18.0), ("R", "W", -18.0), ("R", "Y", -18.0), ("R", "Yacute", -18.0), ("R", "Ydieresis", -18.0), ("Racute", "O", -40.0), ("Racute", "Oacute", -40.0), ("Racute", "Ocircumflex", -40.0), ("Racute", "Odieresis", -40.0), ("Racute", "Ograve", -40.0), ("Racute", "Ohungarumlaut", -40.0), ("Racute", "Omacron", -40.0), ("Racute", "Oslash", -40.0), ("Racute", "Otilde", -40.0), ("Racute", "U", -40.0), ("Racute", "Uacute", -40.0), ("Racute", "Ucircumflex", -40.0), ("Racute", "Udieresis", -40.0), ("Racute", "Ugrave", -40.0), ("Racute", "Uhungarumlaut", -40.0), ("Racute", "Umacron", -40.0), ("Racute", "Uogonek", -40.0), ("Racute", "Uring", -40.0), ("Racute", "V", -18.0), ("Racute", "W", -18.0), ("Racute", "Y", -18.0), ("Racute", "Yacute", -18.0), ("Racute", "Ydieresis", -18.0), ("Rcaron", "O", -40.0), ("Rcaron", "Oacute", -40.0), ("Rcaron", "Ocircumflex", -40.0), ("Rcaron", "Odieresis", -40.0), ("Rcaron", "Ograve", -40.0), ("Rcaron", "Ohungarumlaut", -40.0), ("Rcaron", "Omacron", -40.0), ("Rcaron", "Oslash", -40.0), ("Rcaron", "Otilde", -40.0), ("Rcaron", "U", -40.0), ("Rcaron", "Uacute", -40.0), ("Rcaron", "Ucircumflex", -40.0), ("Rcaron", "Udieresis", -40.0), ("Rcaron", "Ugrave", -40.0), ("Rcaron", "Uhungarumlaut", -40.0), ("Rcaron", "Umacron", -40.0), ("Rcaron", "Uogonek", -40.0), ("Rcaron", "Uring", -40.0), ("Rcaron", "V", -18.0), ("Rcaron", "W", -18.0), ("Rcaron", "Y", -18.0), ("Rcaron", "Yacute", -18.0), ("Rcaron", "Ydieresis", -18.0), ("Rcommaaccent", "O", -40.0), ("Rcommaaccent", "Oacute", -40.0), ("Rcommaaccent", "Ocircumflex", -40.0), ("Rcommaaccent", "Odieresis", -40.0), ("Rcommaaccent", "Ograve", -40.0), ("Rcommaaccent", "Ohungarumlaut", -40.0), ("Rcommaaccent", "Omacron", -40.0), ("Rcommaaccent", "Oslash", -40.0), ("Rcommaaccent", "Otilde", -40.0), ("Rcommaaccent", "U", -40.0), ("Rcommaaccent", "Uacute", -40.0), ("Rcommaaccent", "Ucircumflex", -40.0), ("Rcommaaccent", "Udieresis", -40.0), ("Rcommaaccent", "Ugrave", -40.0), ("Rcommaaccent", "Uhungarumlaut", -40.0), ("Rcommaaccent", "Umacron", -40.0), ("Rcommaaccent", "Uogonek", -40.0), ("Rcommaaccent", "Uring", -40.0), ("Rcommaaccent", "V", -18.0), ("Rcommaaccent", "W", -18.0), ("Rcommaaccent", "Y", -18.0), ("Rcommaaccent", "Yacute", -18.0), ("Rcommaaccent", "Ydieresis", -18.0), ("T", "A", -50.0), ("T", "Aacute", -50.0), ("T", "Abreve", -50.0), ("T", "Acircumflex", -50.0), ("T", "Adieresis", -50.0), ("T", "Agrave", -50.0), ("T", "Amacron", -50.0), ("T", "Aogonek", -50.0), ("T", "Aring", -50.0), ("T", "Atilde", -50.0), ("T", "O", -18.0), ("T", "Oacute", -18.0), ("T", "Ocircumflex", -18.0), ("T", "Odieresis", -18.0), ("T", "Ograve", -18.0), ("T", "Ohungarumlaut", -18.0), ("T", "Omacron", -18.0), ("T", "Oslash", -18.0), ("T", "Otilde", -18.0), ("T", "a", -92.0), ("T", "aacute", -92.0), ("T", "abreve", -92.0), ("T", "acircumflex", -92.0), ("T", "adieresis", -92.0), ("T", "agrave", -92.0), ("T", "amacron", -92.0), ("T", "aogonek", -92.0), ("T", "aring", -92.0), ("T", "atilde", -92.0), ("T", "colon", -55.0), ("T", "comma", -74.0), ("T", "e", -92.0), ("T", "eacute", -92.0), ("T", "ecaron", -92.0), ("T", "ecircumflex", -52.0), ("T", "edieresis", -52.0), ("T", "edotaccent", -92.0), ("T", "egrave", -52.0), ("T", "emacron", -52.0), ("T", "eogonek", -92.0), ("T", "hyphen", -74.0), ("T", "i", -55.0), ("T", "iacute", -55.0), ("T", "iogonek", -55.0), ("T", "o", -92.0), ("T", "oacute", -92.0), ("T", "ocircumflex", -92.0), ("T", "odieresis", -92.0), ("T", "ograve", -92.0), ("T", "ohungarumlaut", -92.0), ("T", "omacron", -92.0), ("T", "oslash", -92.0), ("T", "otilde", -92.0), ("T", "period", -74.0), ("T", "r", -55.0), ("T", "racute", -55.0), ("T", "rcaron", -55.0), ("T", "rcommaaccent", -55.0), ("T", "semicolon", -65.0), ("T", "u", -55.0), ("T", "uacute", -55.0), ("T", "ucircumflex", -55.0), ("T", "udieresis", -55.0), ("T", "ugrave", -55.0), ("T", "uhungarumlaut", -55.0), ("T", "umacron", -55.0), ("T", "uogonek", -55.0), ("T", "uring", -55.0), ("T", "w", -74.0), ("T", "y", -74.0), ("T", "yacute", -74.0), ("T", "ydieresis", -34.0), ("Tcaron", "A", -50.0), ("Tcaron", "Aacute", -50.0), ("Tcaron", "Abreve", -50.0), ("Tcaron", "Acircumflex", -50.0), ("Tcaron", "Adieresis", -50.0), ("Tcaron", "Agrave", -50.0), ("Tcaron", "Amacron", -50.0), ("Tcaron", "Aogonek", -50.0), ("Tcaron", "Aring", -50.0), ("Tcaron", "Atilde", -50.0), ("Tcaron", "O", -18.0), ("Tcaron", "Oacute", -18.0), ("Tcaron", "Ocircumflex", -18.0), ("Tcaron", "Odieresis", -18.0), ("Tcaron", "Ograve", -18.0), ("Tcaron", "Ohungarumlaut", -18.0), ("Tcaron", "Omacron", -18.0), ("Tcaron", "Oslash", -18.0), ("Tcaron", "Otilde", -18.0), ("Tcaron", "a", -92.0), ("Tcaron", "aacute", -92.0), ("Tcaron", "abreve", -92.0), ("Tcaron", "acircumflex", -92.0), ("Tcaron", "adieresis", -92.0), ("Tcaron", "agrave", -92.0), ("Tcaron", "amacron", -92.0), ("Tcaron", "aogonek", -92.0), ("Tcaron", "aring", -92.0), ("Tcaron", "atilde", -92.0), ("Tcaron", "colon", -55.0), ("Tcaron", "comma", -74.0), ("Tcaron", "e", -92.0), ("Tcaron", "eacute", -92.0), ("Tcaron", "ecaron", -92.0), ("Tcaron", "ecircumflex", -52.0), ("Tcaron", "edieresis", -52.0), ("Tcaron", "edotaccent", -92.0), ("Tcaron", "egrave", -52.0), ("Tcaron", "emacron", -52.0), ("Tcaron", "eogonek", -92.0), ("Tcaron", "hyphen", -74.0), ("Tcaron", "i", -55.0), ("Tcaron", "iacute", -55.0), ("Tcaron", "iogonek", -55.0), ("Tcaron", "o", -92.0), ("Tcaron", "oacute", -92.0), ("Tcaron", "ocircumflex", -92.0), ("Tcaron", "odieresis", -92.0), ("Tcaron", "ograve", -92.0), ("Tcaron", "ohungarumlaut", -92.0), ("Tcaron", "omacron", -92.0), ("Tcaron", "oslash", -92.0), ("Tcaron", "otilde", -92.0), ("Tcaron", "period", -74.0), ("Tcaron", "r", -55.0), ("Tcaron", "racute", -55.0), ("Tcaron", "rcaron", -55.0), ("Tcaron", "rcommaaccent", -55.0), ("Tcaron", "semicolon", -65.0), ("Tcaron", "u", -55.0), ("Tcaron", "uacute", -55.0), ("Tcaron", "ucircumflex", -55.0), ("Tcaron", "udieresis", -55.0), ("Tcaron", "ugrave", -55.0), ("Tcaron", "uhungarumlaut", -55.0), ("Tcaron", "umacron", -55.0), ("Tcaron", "uogonek", -55.0), ("Tcaron", "uring", -55.0), ("Tcaron", "w", -74.0), ("Tcaron", "y", -74.0), ("Tcaron", "yacute", -74.0), ("Tcaron", "ydieresis", -34.0), ("Tcommaaccent", "A", -50.0), ("Tcommaaccent", "Aacute", -50.0), ("Tcommaaccent", "Abreve", -50.0), ("Tcommaaccent", "Acircumflex", -50.0), ("Tcommaaccent", "Adieresis", -50.0), ("Tcommaaccent", "Agrave", -50.0), ("Tcommaaccent", "Amacron", -50.0), ("Tcommaaccent", "Aogonek", -50.0), ("Tcommaaccent", "Aring", -50.0), ("Tcommaaccent", "Atilde", -50.0), ("Tcommaaccent", "O", -18.0), ("Tcommaaccent", "Oacute", -18.0), ("Tcommaaccent", "Ocircumflex", -18.0), ("Tcommaaccent", "Odieresis", -18.0), ("Tcommaaccent", "Ograve", -18.0), ("Tcommaaccent", "Ohungarumlaut", -18.0), ("Tcommaaccent", "Omacron", -18.0), ("Tcommaaccent", "Oslash", -18.0), ("Tcommaaccent", "Otilde", -18.0), ("Tcommaaccent", "a", -92.0), ("Tcommaaccent", "aacute", -92.0), ("Tcommaaccent", "abreve", -92.0), ("Tcommaaccent", "acircumflex", -92.0), ("Tcommaaccent", "adieresis", -92.0), ("Tcommaaccent", "agrave", -92.0), ("Tcommaaccent", "amacron", -92.0), ("Tcommaaccent", "aogonek", -92.0), ("Tcommaaccent", "aring", -92.0), ("Tcommaaccent", "atilde", -92.0), ("Tcommaaccent", "colon", -55.0), ("Tcommaaccent", "comma", -74.0), ("Tcommaaccent", "e", -92.0), ("Tcommaaccent", "eacute", -92.0), ("Tcommaaccent", "ecaron", -92.0), ("Tcommaaccent", "ecircumflex", -52.0), ("Tcommaaccent", "edieresis", -52.0), ("Tcommaaccent", "edotaccent", -92.0), ("Tcommaaccent", "egrave", -52.0), ("Tcommaaccent", "emacron", -52.0), ("Tcommaaccent", "eogonek", -92.0), ("Tcommaaccent", "hyphen", -74.0), ("Tcommaaccent", "i", -55.0), ("Tcommaaccent", "iacute", -55.0), ("Tcommaaccent", "iogonek", -55.0), ("Tcommaaccent", "o", -92.0), ("Tcommaaccent", "oacute", -92.0), ("Tcommaaccent", "ocircumflex", -92.0), ("Tcommaaccent", "odieresis", -92.0), ("Tcommaaccent", "ograve", -92.0), ("Tcommaaccent", "ohungarumlaut", -92.0), ("Tcommaaccent", "omacron", -92.0), ("Tcommaaccent", "oslash", -92.0), ("Tcommaaccent", "otilde", -92.0), ("Tcommaaccent", "period", -74.0), ("Tcommaaccent", "r", -55.0), ("Tcommaaccent", "racute", -55.0), ("Tcommaaccent", "rcaron", -55.0), ("Tcommaaccent", "rcommaaccent", -55.0), ("Tcommaaccent", "semicolon", -65.0), ("Tcommaaccent", "u", -55.0), ("Tcommaaccent", "uacute", -55.0), ("Tcommaaccent", "ucircumflex", -55.0), ("Tcommaaccent", "udieresis", -55.0), ("Tcommaaccent", "ugrave", -55.0), ("Tcommaaccent", "uhungarumlaut", -55.0), ("Tcommaaccent", "umacron", -55.0), ("Tcommaaccent", "uogonek", -55.0), ("Tcommaaccent", "uring", -55.0), ("Tcommaaccent", "w", -74.0), ("Tcommaaccent", "y", -74.0), ("Tcommaaccent", "yacute", -74.0), ("Tcommaaccent", "ydieresis", -34.0), ("U", "A", -40.0), ("U", "Aacute", -40.0), ("U", "Abreve", -40.0), ("U", "Acircumflex", -40.0), ("U", "Adieresis", -40.0), ("U", "Agrave", -40.0), ("U", "Amacron", -40.0), ("U", "Aogonek", -40.0), ("U", "Aring", -40.0), ("U", "Atilde", -40.0), ("U", "comma", -25.0), ("U", "period", -25.0), ("Uacute", "A", -40.0), ("Uacute", "Aacute", -40.0), ("Uacute", "Abreve", -40.0), ("Uacute", "Acircumflex", -40.0), ("Uacute", "Adieresis", -40.0), ("Uacute", "Agrave", -40.0), ("Uacute", "Amacron", -40.0), ("Uacute", "Aogonek", -40.0), ("Uacute", "Aring", -40.0), ("Uacute", "Atilde", -40.0), ("Uacute", "comma", -25.0), ("Uacute", "period", -25.0), ("Ucircumflex", "A", -40.0), ("Ucircumflex", "Aacute", -40.0), ("Ucircumflex", "Abreve", -40.0), ("Ucircumflex", "Acircumflex", -40.0), ("Ucircumflex", "Adieresis", -40.0), ("Ucircumflex", "Agrave", -40.0), ("Ucircumflex", "Amacron", -40.0), ("Ucircumflex", "Aogonek", -40.0), ("Ucircumflex", "Aring", -40.0), ("Ucircumflex", "Atilde", -40.0), ("Ucircumflex", "comma", -25.0), ("Ucircumflex", "period", -25.0), ("Udieresis", "A", -40.0), ("Udieresis", "Aacute", -40.0), ("Udieresis", "Abreve", -40.0), ("Udieresis", "Acircumflex", -40.0), ("Udieresis", "Adieresis", -40.0), ("Udieresis", "Agrave", -40.0), ("Udieresis", "Amacron", -40.0), ("Udieresis", "Aogonek", -40.0), ("Udieresis", "Aring", -40.0), ("Udieresis", "Atilde", -40.0), ("Udieresis", "comma", -25.0), ("Udieresis", "period", -25.0), ("Ugrave", "A", -40.0), ("Ugrave", "Aacute", -40.0), ("Ugrave", "Abreve", -40.0), ("Ugrave", "Acircumflex", -40.0), ("Ugrave", "Adieresis", -40.0), ("Ugrave", "Agrave", -40.0), ("Ugrave", "Amacron", -40.0), ("Ugrave", "Aogonek", -40.0), ("Ugrave", "Aring", -40.0), ("Ugrave", "Atilde", -40.0), ("Ugrave", "comma", -25.0), ("Ugrave", "period", -25.0), ("Uhungarumlaut", "A", -40.0), ("Uhungarumlaut", "Aacute", -40.0), ("Uhungarumlaut", "Abreve", -40.0), ("Uhungarumlaut", "Acircumflex", -40.0), ("Uhungarumlaut", "Adieresis", -40.0), ("Uhungarumlaut", "Agrave", -40.0), ("Uhungarumlaut", "Amacron", -40.0), ("Uhungarumlaut", "Aogonek", -40.0), ("Uhungarumlaut", "Aring", -40.0), ("Uhungarumlaut", "Atilde", -40.0), ("Uhungarumlaut", "comma", -25.0), ("Uhungarumlaut", "period", -25.0), ("Umacron", "A", -40.0), ("Umacron", "Aacute", -40.0), ("Umacron", "Abreve", -40.0), ("Umacron", "Acircumflex", -40.0), ("Umacron", "Adieresis", -40.0), ("Umacron", "Agrave", -40.0), ("Umacron", "Amacron", -40.0), ("Umacron", "Aogonek", -40.0), ("Umacron", "Aring", -40.0), ("Umacron", "Atilde", -40.0), ("Umacron", "comma", -25.0), ("Umacron", "period", -25.0), ("Uogonek", "A", -40.0), ("Uogonek", "Aacute", -40.0), ("Uogonek", "Abreve", -40.0), ("Uogonek", "Acircumflex", -40.0), ("Uogonek", "Adieresis", -40.0), ("Uogonek", "Agrave", -40.0), ("Uogonek", "Amacron", -40.0), ("Uogonek", "Aogonek", -40.0), ("Uogonek", "Aring", -40.0), ("Uogonek", "Atilde", -40.0), ("Uogonek", "comma", -25.0), ("Uogonek", "period", -25.0), ("Uring", "A", -40.0), ("Uring", "Aacute", -40.0), ("Uring", "Abreve", -40.0), ("Uring", "Acircumflex", -40.0), ("Uring", "Adieresis", -40.0), ("Uring", "Agrave", -40.0), ("Uring", "Amacron", -40.0), ("Uring", "Aogonek", -40.0), ("Uring", "Aring", -40.0), ("Uring", "Atilde", -40.0), ("Uring", "comma", -25.0), ("Uring", "period", -25.0), ("V", "A", -60.0), ("V", "Aacute", -60.0), ("V", "Abreve", -60.0), ("V", "Acircumflex", -60.0), ("V", "Adieresis", -60.0), ("V", "Agrave", -60.0), ("V", "Amacron", -60.0), ("V", "Aogonek", -60.0), ("V", "Aring", -60.0), ("V", "Atilde", -60.0), ("V", "O", -30.0), ("V", "Oacute", -30.0), ("V", "Ocircumflex", -30.0), ("V", "Odieresis", -30.0), ("V", "Ograve", -30.0), ("V", "Ohungarumlaut", -30.0), ("V", "Omacron", -30.0), ("V", "Oslash", -30.0), ("V", "Otilde", -30.0), ("V", "a", -111.0), ("V", "aacute", -111.0), ("V", "abreve", -111.0), ("V", "acircumflex", -111.0), ("V", "adieresis", -111.0), ("V", "agrave", -111.0), ("V", "amacron", -111.0), ("V", "aogonek", -111.0), ("V", "aring", -111.0), ("V", "atilde", -111.0), ("V", "colon", -65.0), ("V", "comma", -129.0), ("V", "e", -111.0), ("V", "eacute", -111.0), ("V", "ecaron", -111.0), ("V", "ecircumflex", -111.0), ("V", "edieresis", -71.0), ("V", "edotaccent", -111.0), ("V", "egrave", -71.0), ("V", "emacron", -71.0), ("V", "eogonek", -111.0), ("V", "hyphen", -55.0), ("V", "i", -74.0), ("V", "iacute", -74.0), ("V", "icircumflex", -34.0), ("V", "idieresis", -34.0), ("V", "igrave", -34.0), ("V", "imacron", -34.0), ("V", "iogonek", -74.0), ("V", "o", -111.0), ("V", "oacute", -111.0), ("V", "ocircumflex", -111.0), ("V", "odieresis", -111.0), ("V", "ograve", -111.0), ("V", "ohungarumlaut", -111.0), ("V", "omacron", -111.0), ("V", "oslash", -111.0), ("V", "otilde", -111.0), ("V", "period", -129.0), ("V", "semicolon", -74.0), ("V", "u", -74.0), ("V", "uacute", -74.0), ("V", "ucircumflex", -74.0), ("V", "udieresis", -74.0), ("V", "ugrave", -74.0), ("V", "uhungarumlaut", -74.0), ("V", "umacron", -74.0), ("V", "uogonek", -74.0), ("V", "uring", -74.0), ("W", "A", -60.0), ("W", "Aacute", -60.0), ("W", "Abreve", -60.0), ("W", "Acircumflex", -60.0), ("W", "Adieresis", -60.0), ("W", "Agrave", -60.0), ("W", "Amacron", -60.0), ("W", "Aogonek", -60.0), ("W", "Aring", -60.0), ("W", "Atilde", -60.0), ("W", "O", -25.0), ("W", "Oacute", -25.0), ("W", "Ocircumflex", -25.0), ("W", "Odieresis", -25.0), ("W", "Ograve", -25.0), ("W", "Ohungarumlaut", -25.0), ("W", "Omacron", -25.0), ("W", "Oslash", -25.0), ("W", "Otilde", -25.0), ("W", "a", -92.0), ("W", "aacute", -92.0), ("W", "abreve", -92.0), ("W", "acircumflex", -92.0), ("W", "adieresis", -92.0), ("W", "agrave", -92.0), ("W", "amacron", -92.0), ("W", "aogonek", -92.0), ("W", "aring", -92.0), ("W", "atilde", -92.0), ("W", "colon", -65.0), ("W", "comma", -92.0), ("W", "e", -92.0), ("W", "eacute", -92.0), ("W", "ecaron", -92.0), ("W", "ecircumflex", -92.0), ("W", "edieresis", -52.0), ("W", "edotaccent", -92.0), ("W", "egrave", -52.0), ("W", "emacron", -52.0), ("W", "eogonek", -92.0), ("W", "hyphen", -37.0), ("W", "i", -55.0), ("W", "iacute", -55.0), ("W", "iogonek", -55.0), ("W", "o", -92.0), ("W", "oacute", -92.0), ("W", "ocircumflex", -92.0), ("W", "odieresis", -92.0), ("W", "ograve", -92.0), ("W", "ohungarumlaut", -92.0), ("W", "omacron", -92.0), ("W", "oslash", -92.0), ("W", "otilde", -92.0), ("W", "period", -92.0), ("W", "semicolon", -65.0), ("W", "u", -55.0), ("W", "uacute", -55.0), ("W", "ucircumflex", -55.0), ("W", "udieresis", -55.0), ("W", "ugrave", -55.0), ("W", "uhungarumlaut", -55.0), ("W", "umacron", -55.0), ("W", "uogonek", -55.0), ("W", "uring", -55.0), ("W", "y", -70.0), ("W", "yacute", -70.0), ("W", "ydieresis", -70.0), ("Y", "A", -50.0), ("Y", "Aacute", -50.0), ("Y", "Abreve", -50.0), ("Y", "Acircumflex", -50.0), ("Y", "Adieresis", -50.0), ("Y", "Agrave", -50.0), ("Y", "Amacron", -50.0), ("Y", "Aogonek", -50.0), ("Y", "Aring", -50.0), ("Y", "Atilde", -50.0), ("Y", "O", -15.0), ("Y", "Oacute", -15.0), ("Y", "Ocircumflex", -15.0), ("Y", "Odieresis", -15.0), ("Y", "Ograve", -15.0), ("Y", "Ohungarumlaut", -15.0), ("Y", "Omacron", -15.0), ("Y", "Oslash", -15.0), ("Y", "Otilde", -15.0), ("Y", "a", -92.0), ("Y", "aacute", -92.0), ("Y", "abreve", -92.0), ("Y", "acircumflex", -92.0), ("Y", "adieresis", -92.0), ("Y", "agrave", -92.0), ("Y", "amacron", -92.0), ("Y", "aogonek", -92.0), ("Y", "aring", -92.0), ("Y", "atilde", -92.0), ("Y", "colon", -65.0), ("Y", "comma", -92.0), ("Y", "e", -92.0), ("Y", "eacute", -92.0), ("Y", "ecaron", -92.0), ("Y", "ecircumflex", -92.0), ("Y", "edieresis", -52.0), ("Y", "edotaccent", -92.0), ("Y", "egrave", -52.0), ("Y", "emacron", -52.0), ("Y", "eogonek", -92.0), ("Y", "hyphen", -74.0), ("Y", "i", -74.0), ("Y", "iacute", -74.0), ("Y", "icircumflex", -34.0), ("Y", "idieresis", -34.0), ("Y", "igrave", -34.0), ("Y", "imacron", -34.0), ("Y", "iogonek", -74.0), ("Y", "o", -92.0), ("Y", "oacute", -92.0), ("Y", "ocircumflex", -92.0), ("Y", "odieresis", -92.0), ("Y", "ograve", -92.0), ("Y", "ohungarumlaut", -92.0), ("Y", "omacron", -92.0), ("Y", "oslash", -92.0), ("Y", "otilde", -92.0), ("Y", "period", -92.0), ("Y", "semicolon", -65.0), ("Y", "u", -92.0), ("Y", "uacute", -92.0), ("Y", "ucircumflex", -92.0), ("Y", "udieresis", -92.0), ("Y", "ugrave", -92.0), ("Y", "uhungarumlaut", -92.0), ("Y", "umacron", -92.0), ("Y", "uogonek", -92.0), ("Y", "uring", -92.0), ("Yacute", "A", -50.0), ("Yacute", "Aacute", -50.0), ("Yacute", "Abreve", -50.0), ("Yacute", "Acircumflex", -50.0), ("Yacute", "Adieresis", -50.0), ("Yacute", "Agrave", -50.0), ("Yacute", "Amacron", -50.0), ("Yacute", "Aogonek", -50.0), ("Yacute", "Aring", -50.0), ("Yacute", "Atilde", -50.0), ("Yacute", "O", -15.0), ("Yacute", "Oacute", -15.0), ("Yacute", "Ocircumflex", -15.0), ("Yacute", "Odieresis", -15.0), ("Yacute", "Ograve", -15.0), ("Yacute", "Ohungarumlaut", -15.0), ("Yacute", "Omacron", -15.0), ("Yacute", "Oslash", -15.0), ("Yacute", "Otilde", -15.0), ("Yacute", "a", -92.0), ("Yacute", "aacute", -92.0), ("Yacute", "abreve", -92.0), ("Yacute", "acircumflex", -92.0), ("Yacute", "adieresis", -92.0), ("Yacute", "agrave", -92.0), ("Yacute", "amacron", -92.0), ("Yacute", "aogonek", -92.0), ("Yacute", "aring", -92.0), ("Yacute", "atilde", -92.0), ("Yacute", "colon", -65.0), ("Yacute", "comma", -92.0), ("Yacute", "e", -92.0), ("Yacute", "eacute", -92.0), ("Yacute", "ecaron", -92.0), ("Yacute", "ecircumflex", -92.0), ("Yacute", "edieresis", -52.0), ("Yacute", "edotaccent", -92.0), ("Yacute", "egrave", -52.0), ("Yacute", "emacron", -52.0), ("Yacute", "eogonek", -92.0), ("Yacute", "hyphen", -74.0), ("Yacute", "i", -74.0), ("Yacute", "iacute", -74.0), ("Yacute", "icircumflex", -34.0), ("Yacute", "idieresis", -34.0), ("Yacute", "igrave", -34.0), ("Yacute", "imacron", -34.0), ("Yacute", "iogonek", -74.0), ("Yacute", "o", -92.0), ("Yacute", "oacute", -92.0), ("Yacute", "ocircumflex", -92.0), ("Yacute", "odieresis", -92.0), ("Yacute", "ograve", -92.0), ("Yacute", "ohungarumlaut", -92.0), ("Yacute", "omacron", -92.0), ("Yacute", "oslash", -92.0), ("Yacute", "otilde", -92.0), ("Yacute", "period", -92.0), ("Yacute", "semicolon", -65.0), ("Yacute", "u", -92.0), ("Yacute", "uacute", -92.0), ("Yacute", "ucircumflex", -92.0), ("Yacute", "udieresis", -92.0), ("Yacute", "ugrave", -92.0), ("Yacute", "uhungarumlaut", -92.0), ("Yacute", "umacron", -92.0), ("Yacute", "uogonek", -92.0), ("Yacute", "uring", -92.0), ("Ydieresis", "A", -50.0), ("Ydieresis", "Aacute", -50.0), ("Ydieresis", "Abreve", -50.0), ("Ydieresis", "Acircumflex", -50.0), ("Ydieresis", "Adieresis", -50.0), ("Ydieresis", "Agrave", -50.0), ("Ydieresis", "Amacron", -50.0), ("Ydieresis", "Aogonek", -50.0), ("Ydieresis", "Aring", -50.0), ("Ydieresis", "Atilde", -50.0), ("Ydieresis", "O", -15.0), ("Ydieresis", "Oacute", -15.0), ("Ydieresis", "Ocircumflex", -15.0), ("Ydieresis", "Odieresis", -15.0), ("Ydieresis", "Ograve", -15.0), ("Ydieresis", "Ohungarumlaut", -15.0), ("Ydieresis", "Omacron", -15.0), ("Ydieresis", "Oslash", -15.0), ("Ydieresis", "Otilde", -15.0), ("Ydieresis", "a", -92.0), ("Ydieresis", "aacute", -92.0), ("Ydieresis", "abreve", -92.0), ("Ydieresis", "acircumflex", -92.0), ("Ydieresis", "adieresis", -92.0), ("Ydieresis", "agrave", -92.0), ("Ydieresis", "amacron", -92.0), ("Ydieresis", "aogonek", -92.0), ("Ydieresis", "aring", -92.0), ("Ydieresis", "atilde", -92.0), ("Ydieresis", "colon", -65.0), ("Ydieresis", "comma", -92.0), ("Ydieresis", "e", -92.0), ("Ydieresis", "eacute", -92.0), ("Ydieresis", "ecaron", -92.0), ("Ydieresis", "ecircumflex", -92.0), ("Ydieresis", "edieresis", -52.0), ("Ydieresis", "edotaccent", -92.0), ("Ydieresis", "egrave", -52.0), ("Ydieresis", "emacron", -52.0), ("Ydieresis", "eogonek", -92.0), ("Ydieresis", "hyphen", -74.0), ("Ydieresis", "i", -74.0), ("Ydieresis", "iacute", -74.0), ("Ydieresis", "icircumflex", -34.0), ("Ydieresis", "idieresis", -34.0), ("Ydieresis", "igrave", -34.0), ("Ydieresis", "imacron", -34.0), ("Ydieresis", "iogonek", -74.0), ("Ydieresis", "o", -92.0), ("Ydieresis", "oacute", -92.0), ("Ydieresis", "ocircumflex", -92.0), ("Ydieresis", "odieresis", -92.0), ("Ydieresis", "ograve", -92.0), ("Ydieresis", "ohungarumlaut", -92.0), ("Ydieresis", "omacron", -92.0), ("Ydieresis", "oslash", -92.0), ("Ydieresis", "otilde", -92.0), ("Ydieresis", "period", -92.0), ("Ydieresis", "semicolon", -65.0), ("Ydieresis", "u", -92.0), ("Ydieresis", "uacute", -92.0), ("Ydieresis", "ucircumflex", -92.0), ("Ydieresis", "udieresis", -92.0), ("Ydieresis", "ugrave", -92.0), ("Ydieresis", "uhungarumlaut", -92.0), ("Ydieresis", "umacron", -92.0), ("Ydieresis", "uogonek", -92.0), ("Ydieresis", "uring", -92.0), ("a", "g", -10.0), ("a", "gbreve", -10.0), ("a", "gcommaaccent", -10.0), ("aacute", "g", -10.0), ("aacute", "gbreve", -10.0), ("aacute", "gcommaaccent", -10.0), ("abreve", "g", -10.0), ("abreve", "gbreve", -10.0), ("abreve", "gcommaaccent", -10.0), ("acircumflex", "g", -10.0), ("acircumflex", "gbreve", -10.0), ("acircumflex", "gcommaaccent", -10.0), ("adieresis", "g", -10.0), ("adieresis", "gbreve", -10.0), ("adieresis", "gcommaaccent", -10.0), ("agrave", "g", -10.0), ("agrave", "gbreve", -10.0), ("agrave", "gcommaaccent", -10.0), ("amacron", "g", -10.0), ("amacron", "gbreve", -10.0), ("amacron", "gcommaaccent", -10.0), ("aogonek", "g", -10.0), ("aogonek", "gbreve", -10.0), ("aogonek", "gcommaaccent", -10.0), ("aring", "g", -10.0), ("aring", "gbreve", -10.0), ("aring", "gcommaaccent", -10.0), ("atilde", "g", -10.0), ("atilde", "gbreve", -10.0), ("atilde", "gcommaaccent", -10.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("c", "h", -15.0), ("c", "k", -20.0), ("c", "kcommaaccent", -20.0), ("cacute", "h", -15.0), ("cacute", "k", -20.0), ("cacute", "kcommaaccent", -20.0), ("ccaron", "h", -15.0), ("ccaron", "k", -20.0), ("ccaron", "kcommaaccent", -20.0), ("ccedilla", "h", -15.0), ("ccedilla", "k", -20.0), ("ccedilla", "kcommaaccent", -20.0), ("comma", "quotedblright", -140.0), ("comma", "quoteright", -140.0), ("e", "comma", -10.0), ("e", "g", -40.0), ("e", "gbreve", -40.0), ("e", "gcommaaccent", -40.0), ("e", "period", -15.0), ("e", "v", -15.0), ("e", "w", -15.0), ("e", "x", -20.0), ("e", "y", -30.0), ("e", "yacute", -30.0), ("e", "ydieresis", -30.0), ("eacute", "comma", -10.0), ("eacute", "g", -40.0), ("eacute", "gbreve", -40.0), ("eacute", "gcommaaccent", -40.0), ("eacute", "period", -15.0), ("eacute", "v", -15.0), ("eacute", "w", -15.0), ("eacute", "x", -20.0), ("eacute", "y", -30.0), ("eacute", "yacute", -30.0), ("eacute", "ydieresis", -30.0), ("ecaron", "comma", -10.0), ("ecaron", "g", -40.0), ("ecaron", "gbreve", -40.0), ("ecaron", "gcommaaccent", -40.0), ("ecaron", "period", -15.0), ("ecaron", "v", -15.0), ("ecaron", "w", -15.0), ("ecaron", "x", -20.0), ("ecaron", "y", -30.0), ("ecaron", "yacute", -30.0), ("ecaron", "ydieresis", -30.0), ("ecircumflex", "comma", -10.0), ("ecircumflex", "g", -40.0), ("ecircumflex", "gbreve", -40.0), ("ecircumflex", "gcommaaccent", -40.0), ("ecircumflex", "period", -15.0), ("ecircumflex", "v", -15.0), ("ecircumflex", "w", -15.0), ("ecircumflex", "x", -20.0), ("ecircumflex", "y", -30.0), ("ecircumflex", "yacute", -30.0), ("ecircumflex", "ydieresis", -30.0), ("edieresis", "comma", -10.0), ("edieresis", "g", -40.0), ("edieresis", "gbreve", -40.0), ("edieresis", "gcommaaccent", -40.0), ("edieresis", "period", -15.0), ("edieresis", "v", -15.0), ("edieresis", "w", -15.0), ("edieresis", "x", -20.0), ("edieresis", "y", -30.0), ("edieresis", "yacute", -30.0), ("edieresis", "ydieresis", -30.0), ("edotaccent", "comma", -10.0), ("edotaccent", "g", -40.0), ("edotaccent", "gbreve", -40.0), ("edotaccent", "gcommaaccent", -40.0), ("edotaccent", "period", -15.0), ("edotaccent", "v", -15.0), ("edotaccent", "w", -15.0), ("edotaccent", "x", -20.0), ("edotaccent", "y", -30.0), ("edotaccent", "yacute", -30.0), ("edotaccent", "ydieresis", -30.0), ("egrave", "comma", -10.0), ("egrave", "g", -40.0), ("egrave", "gbreve", -40.0), ("egrave", "gcommaaccent", -40.0), ("egrave", "period", -15.0), ("egrave", "v", -15.0), ("egrave", "w", -15.0), ("egrave", "x", -20.0), ("egrave", "y", -30.0), ("egrave", "yacute", -30.0), ("egrave", "ydieresis", -30.0), ("emacron", "comma", -10.0), ("emacron", "g", -40.0), ("emacron", "gbreve", -40.0), ("emacron", "gcommaaccent", -40.0), ("emacron", "period", -15.0), ("emacron", "v", -15.0), ("emacron", "w", -15.0), ("emacron", "x", -20.0), ("emacron", "y", -30.0), ("emacron", "yacute", -30.0), ("emacron", "ydieresis", -30.0), ("eogonek", "comma", -10.0), ("eogonek", "g", -40.0), ("eogonek", "gbreve", -40.0), ("eogonek", "gcommaaccent", -40.0), ("eogonek", "period", -15.0), ("eogonek", "v", -15.0), ("eogonek", "w", -15.0), ("eogonek", "x", -20.0), ("eogonek", "y", -30.0), ("eogonek", "yacute", -30.0), ("eogonek", "ydieresis", -30.0), ("f", "comma", -10.0), ("f", "dotlessi", -60.0), ("f", "f", -18.0), ("f", "i", -20.0), ("f", "iogonek", -20.0), ("f", "period", -15.0), ("f", "quoteright", 92.0), ("g", "comma", -10.0), ("g", "e", -10.0), ("g", "eacute", -10.0), ("g", "ecaron", -10.0), ("g", "ecircumflex", -10.0), ("g", "edieresis", -10.0), ("g", "edotaccent", -10.0), ("g", "egrave", -10.0), ("g", "emacron", -10.0), ("g", "eogonek", -10.0), ("g", "g", -10.0), ("g", "gbreve", -10.0), ("g", "gcommaaccent", -10.0), ("g", "period", -15.0), ("gbreve", "comma", -10.0), ("gbreve", "e", -10.0), ("gbreve", "eacute", -10.0), ("gbreve", "ecaron", -10.0), ("gbreve", "ecircumflex", -10.0), ("gbreve", "edieresis", -10.0), ("gbreve", "edotaccent", -10.0), ("gbreve", "egrave", -10.0), ("gbreve", "emacron", -10.0), ("gbreve", "eogonek", -10.0), ("gbreve", "g", -10.0), ("gbreve", "gbreve", -10.0), ("gbreve", "gcommaaccent", -10.0), ("gbreve", "period", -15.0), ("gcommaaccent", "comma", -10.0), ("gcommaaccent", "e", -10.0), ("gcommaaccent", "eacute", -10.0), ("gcommaaccent", "ecaron", -10.0), ("gcommaaccent", "ecircumflex", -10.0), ("gcommaaccent", "edieresis", -10.0), ("gcommaaccent", "edotaccent", -10.0), ("gcommaaccent", "egrave", -10.0), ("gcommaaccent", "emacron", -10.0), ("gcommaaccent", "eogonek", -10.0), ("gcommaaccent", "g", -10.0), ("gcommaaccent", "gbreve", -10.0), ("gcommaaccent", "gcommaaccent", -10.0), ("gcommaaccent", "period", -15.0), ("k", "e", -10.0), ("k", "eacute", -10.0), ("k", "ecaron", -10.0), ("k", "ecircumflex", -10.0), ("k", "edieresis", -10.0), ("k", "edotaccent", -10.0), ("k", "egrave", -10.0), ("k", "emacron", -10.0), ("k", "eogonek", -10.0), ("k", "o", -10.0), ("k", "oacute", -10.0), ("k", "ocircumflex", -10.0), ("k", "odieresis", -10.0), ("k", "ograve", -10.0), ("k", "ohungarumlaut", -10.0), ("k", "omacron", -10.0), ("k", "oslash", -10.0), ("k", "otilde", -10.0), ("k", "y", -10.0), ("k", "yacute", -10.0), ("k", "ydieresis", -10.0), ("kcommaaccent", "e", -10.0), ("kcommaaccent", "eacute", -10.0), ("kcommaaccent", "ecaron", -10.0), ("kcommaaccent", "ecircumflex", -10.0), ("kcommaaccent", "edieresis", -10.0), ("kcommaaccent", "edotaccent", -10.0), ("kcommaaccent", "egrave", -10.0), ("kcommaaccent", "emacron", -10.0), ("kcommaaccent", "eogonek", -10.0), ("kcommaaccent", "o", -10.0), ("kcommaaccent", "oacute", -10.0), ("kcommaaccent", "ocircumflex", -10.0), ("kcommaaccent", "odieresis", -10.0), ("kcommaaccent", "ograve", -10.0), ("kcommaaccent", "ohungarumlaut", -10.0), ("kcommaaccent", "omacron", -10.0), ("kcommaaccent", "oslash", -10.0), ("kcommaaccent", "otilde", -10.0), ("kcommaaccent", "y", -10.0), ("kcommaaccent", "yacute", -10.0), ("kcommaaccent", "ydieresis", -10.0), ("n", "v", -40.0), ("nacute", "v", -40.0), ("ncaron", "v", -40.0), ("ncommaaccent", "v", -40.0), ("ntilde", "v", -40.0), ("o", "g", -10.0), ("o", "gbreve", -10.0), ("o", "gcommaaccent", -10.0), ("o", "v", -10.0), ("oacute", "g", -10.0), ("oacute", "gbreve", -10.0), ("oacute", "gcommaaccent", -10.0), ("oacute", "v", -10.0), ("ocircumflex", "g", -10.0), ("ocircumflex", "gbreve", -10.0), ("ocircumflex", "gcommaaccent", -10.0), ("ocircumflex", "v", -10.0), ("odieresis", "g", -10.0), ("odieresis", "gbreve", -10.0), ("odieresis", "gcommaaccent", -10.0), ("odieresis", "v", -10.0), ("ograve", "g", -10.0), ("ograve", "gbreve", -10.0), ("ograve", "gcommaaccent", -10.0), ("ograve", "v", -10.0), ("ohungarumlaut", "g", -10.0), ("ohungarumlaut", "gbreve", -10.0), ("ohungarumlaut", "gcommaaccent", -10.0), ("ohungarumlaut", "v", -10.0), ("omacron", "g", -10.0), ("omacron", "gbreve", -10.0), ("omacron", "gcommaaccent", -10.0), ("omacron", "v", -10.0), ("oslash", "g", -10.0), ("oslash", "gbreve", -10.0), ("oslash", "gcommaaccent", -10.0), ("oslash", "v", -10.0), ("otilde", "g", -10.0), ("otilde", "gbreve", -10.0), ("otilde", "gcommaaccent", -10.0), ("otilde", "v", -10.0), ("period", "quotedblright", -140.0), ("period", "quoteright", -140.0), ("quoteleft", "quoteleft", -111.0), ("quoteright", "d", -25.0), ("quoteright", "dcroat", -25.0), ("quoteright", "quoteright", -111.0), ("quoteright", "r", -25.0), ("quoteright", "racute", -25.0), ("quoteright", "rcaron", -25.0), ("quoteright", "rcommaaccent", -25.0), ("quoteright", "s", -40.0), ("quoteright", "sacute", -40.0), ("quoteright", "scaron", -40.0), ("quoteright", "scedilla", -40.0), ("quoteright", "scommaaccent", -40.0), ("quoteright", "space", -111.0), ("quoteright", "t", -30.0), ("quoteright", "tcommaaccent", -30.0), ("quoteright", "v", -10.0), ("r", "a", -15.0), ("r", "aacute", -15.0), ("r", "abreve", -15.0), ("r", "acircumflex", -15.0), ("r", "adieresis", -15.0), ("r", "agrave", -15.0), ("r", "amacron", -15.0), ("r", "aogonek", -15.0), ("r", "aring", -15.0), ("r", "atilde", -15.0), ("r", "c", -37.0), ("r", "cacute", -37.0), ("r", "ccaron", -37.0), ("r", "ccedilla", -37.0), ("r", "comma", -111.0), ("r", "d", -37.0), ("r", "dcroat", -37.0), ("r", "e", -37.0), ("r", "eacute", -37.0), ("r", "ecaron", -37.0), ("r", "ecircumflex", -37.0), ("r", "edieresis", -37.0), ("r", "edotaccent", -37.0), ("r", "egrave", -37.0), ("r", "emacron", -37.0), ("r", "eogonek", -37.0), ("r", "g", -37.0), ("r", "gbreve", -37.0), ("r", "gcommaaccent", -37.0), ("r", "hyphen", -20.0), ("r", "o", -45.0), ("r", "oacute", -45.0), ("r", "ocircumflex", -45.0), ("r", "odieresis", -45.0), ("r", "ograve", -45.0), ("r", "ohungarumlaut", -45.0), ("r", "omacron", -45.0), ("r", "oslash", -45.0), ("r", "otilde", -45.0), ("r", "period", -111.0), ("r", "q", -37.0), ("r", "s", -10.0), ("r", "sacute", -10.0), ("r", "scaron", -10.0), ("r", "scedilla", -10.0), ("r", "scommaaccent", -10.0), ("racute", "a", -15.0), ("racute", "aacute", -15.0), ("racute", "abreve", -15.0), ("racute", "acircumflex", -15.0), ("racute", "adieresis", -15.0), ("racute", "agrave", -15.0), ("racute", "amacron", -15.0), ("racute", "aogonek", -15.0), ("racute", "aring", -15.0), ("racute", "atilde", -15.0), ("racute", "c", -37.0), ("racute", "cacute", -37.0), ("racute", "ccaron", -37.0), ("racute", "ccedilla", -37.0), ("racute", "comma", -111.0), ("racute", "d", -37.0), ("racute", "dcroat", -37.0), ("racute", "e", -37.0), ("racute", "eacute", -37.0), ("racute", "ecaron", -37.0), ("racute", "ecircumflex", -37.0), ("racute", "edieresis", -37.0), ("racute", "edotaccent", -37.0), ("racute", "egrave", -37.0), ("racute", "emacron", -37.0), ("racute", "eogonek", -37.0), ("racute", "g", -37.0), ("racute", "gbreve", -37.0), ("racute", "gcommaaccent", -37.0), ("racute", "hyphen", -20.0), ("racute", "o", -45.0), ("racute", "oacute", -45.0), ("racute", "ocircumflex", -45.0), ("racute", "odieresis", -45.0), ("racute", "ograve", -45.0), ("racute", "ohungarumlaut", -45.0), ("racute", "omacron", -45.0), ("racute", "oslash", -45.0), ("racute", "otilde", -45.0), ("racute", "period", -111.0), ("racute", "q", -37.0), ("racute", "s", -10.0), ("racute", "sacute", -10.0), ("racute", "scaron", -10.0), ("racute", "scedilla", -10.0), ("racute", "scommaaccent", -10.0), ("rcaron", "a", -15.0), ("rcaron", "aacute", -15.0), ("rcaron", "abreve", -15.0), ("rcaron", "acircumflex", -15.0), ("rcaron", "adieresis", -15.0), ("rcaron", "agrave", -15.0), ("rcaron", "amacron", -15.0), ("rcaron", "aogonek", -15.0), ("rcaron", "aring", -15.0), ("rcaron", "atilde", -15.0), ("rcaron", "c", -37.0), ("rcaron", "cacute", -37.0), ("rcaron", "ccaron", -37.0), ("rcaron", "ccedilla", -37.0), ("rcaron", "comma", -111.0), ("rcaron", "d", -37.0), ("rcaron", "dcroat", -37.0), ("rcaron", "e", -37.0), ("rcaron", "eacute", -37.0), ("rcaron", "ecaron", -37.0), ("rcaron", "ecircumflex", -37.0), ("rcaron", "edieresis", -37.0), ("rcaron", "edotaccent", -37.0), ("rcaron", "egrave", -37.0), ("rcaron", "emacron", -37.0), ("rcaron", "eogonek", -37.0), ("rcaron", "g", -37.0), ("rcaron", "gbreve", -37.0), ("rcaron", "gcommaaccent", -37.0), ("rcaron", "hyphen", -20.0), ("rcaron", "o", -45.0), ("rcaron", "oacute", -45.0), ("rcaron", "ocircumflex", -45.0), ("rcaron", "odieresis", -45.0), ("rcaron", "ograve", -45.0), ("rcaron", "ohungarumlaut", -45.0), ("rcaron", "omacron", -45.0), ("rcaron", "oslash", -45.0), ("rcaron", "otilde", -45.0), ("rcaron", "period", -111.0), ("rcaron", "q", -37.0), ("rcaron", "s", -10.0), ("rcaron", "sacute", -10.0), ("rcaron", "scaron", -10.0), ("rcaron", "scedilla", -10.0), ("rcaron", "scommaaccent", -10.0), ("rcommaaccent", "a", -15.0), ("rcommaaccent", "aacute", -15.0), ("rcommaaccent", "abreve", -15.0), ("rcommaaccent", "acircumflex", -15.0), ("rcommaaccent", "adieresis", -15.0), ("rcommaaccent", "agrave", -15.0), ("rcommaaccent", "amacron", -15.0), ("rcommaaccent", "aogonek", -15.0), ("rcommaaccent", "aring", -15.0), ("rcommaaccent", "atilde", -15.0), ("rcommaaccent", "c", -37.0), ("rcommaaccent", "cacute", -37.0), ("rcommaaccent", "ccaron", -37.0), ("rcommaaccent", "ccedilla", -37.0), ("rcommaaccent", "comma", -111.0), ("rcommaaccent", "d", -37.0), ("rcommaaccent", "dcroat", -37.0), ("rcommaaccent", "e", -37.0), ("rcommaaccent", "eacute", -37.0), ("rcommaaccent", "ecaron", -37.0), ("rcommaaccent", "ecircumflex", -37.0), ("rcommaaccent", "edieresis", -37.0), ("rcommaaccent", "edotaccent", -37.0), ("rcommaaccent", "egrave", -37.0), ("rcommaaccent", "emacron", -37.0), ("rcommaaccent", "eogonek", -37.0), ("rcommaaccent", "g", -37.0), ("rcommaaccent", "gbreve", -37.0), ("rcommaaccent", "gcommaaccent", -37.0), ("rcommaaccent", "hyphen", -20.0), ("rcommaaccent", "o", -45.0), ("rcommaaccent", "oacute", -45.0), ("rcommaaccent", "ocircumflex", -45.0), ("rcommaaccent", "odieresis", -45.0), ("rcommaaccent", "ograve", -45.0), ("rcommaaccent", "ohungarumlaut", -45.0), ("rcommaaccent", "omacron", -45.0), ("rcommaaccent", "oslash", -45.0), ("rcommaaccent", "otilde", -45.0), ("rcommaaccent", "period", -111.0), ("rcommaaccent", "q", -37.0), ("rcommaaccent", "s", -10.0), ("rcommaaccent", "sacute", -10.0), ("rcommaaccent", "scaron", -10.0), ("rcommaaccent", "scedilla", -10.0), ("rcommaaccent", "scommaaccent", -10.0), ("space", "A", -18.0), ("space", "Aacute", -18.0), ("space", "Abreve", -18.0), ("space", "Acircumflex", -18.0), ("space", "Adieresis", -18.0), ("space", "Agrave", -18.0), ("space", "Amacron", -18.0), ("space", "Aogonek", -18.0), ("space", "Aring", -18.0), ("space", "Atilde", -18.0), ("space", "T", -18.0), ("space", "Tcaron", -18.0), ("space", "Tcommaaccent", -18.0), ("space", "V", -35.0), ("space", "W", -40.0), ("space", "Y", -75.0), ("space", "Yacute", -75.0), ("space", "Ydieresis", -75.0), ("v", "comma", -74.0), ("v", "period", -74.0), ("w", "comma", -74.0), ("w", "period", -74.0), ("y", "comma", -55.0), ("y", "period", -55.0), ("yacute", "comma", -55.0), ("yacute", "period", -55.0), ("ydieresis", "comma", -55.0), ("ydieresis", "period", -55.0)]),
+("Times-Roman", &[("A", "C", -40.0), ("A", "Cacute", -40.0), ("A", "Ccaron", -40.0), ("A", "Ccedilla", -40.0), ("A", "G", -40.0), ("A", "Gbreve", -40.0), ("A", "Gcommaaccent", -40.0), ("A", "O", -55.0), ("A", "Oacute", -55.0), ("A", "Ocircumflex", -55.0), ("A", "Odieresis", -55.0), ("A", "Ograve", -55.0), ("A", "Ohungarumlaut", -55.0), ("A", "Omacron", -55.0), ("A", "Oslash", -55.0), ("A", "Otilde", -55.0), ("A", "Q", -55.0), ("A", "T", -111.0), ("A", "Tcaron", -111.0), ("A", "Tcommaaccent", -111.0), ("A", "U", -55.0), ("A", "Uacute", -55.0), ("A", "Ucircumflex", -55.0), ("A", "Udieresis", -55.0), ("A", "Ugrave", -55.0), ("A", "Uhungarumlaut", -55.0), ("A", "Umacron", -55.0), ("A", "Uogonek", -55.0), ("A", "Uring", -55.0), ("A", "V", -135.0), ("A", "W", -90.0), ("A", "Y", -105.0), ("A", "Yacute", -105.0), ("A", "Ydieresis", -105.0), ("A", "quoteright", -111.0), ("A", "v", -74.0), ("A", "w", -92.0), ("A", "y", -92.0), ("A", "yacute", -92.0), ("A", "ydieresis", -92.0), ("Aacute", "C", -40.0), ("Aacute", "Cacute", -40.0), ("Aacute", "Ccaron", -40.0), ("Aacute", "Ccedilla", -40.0), ("Aacute", "G", -40.0), ("Aacute", "Gbreve", -40.0), ("Aacute", "Gcommaaccent", -40.0), ("Aacute", "O", -55.0), ("Aacute", "Oacute", -55.0), ("Aacute", "Ocircumflex", -55.0), ("Aacute", "Odieresis", -55.0), ("Aacute", "Ograve", -55.0), ("Aacute", "Ohungarumlaut", -55.0), ("Aacute", "Omacron", -55.0), ("Aacute", "Oslash", -55.0), ("Aacute", "Otilde", -55.0), ("Aacute", "Q", -55.0), ("Aacute", "T", -111.0), ("Aacute", "Tcaron", -111.0), ("Aacute", "Tcommaaccent", -111.0), ("Aacute", "U", -55.0), ("Aacute", "Uacute", -55.0), ("Aacute", "Ucircumflex", -55.0), ("Aacute", "Udieresis", -55.0), ("Aacute", "Ugrave", -55.0), ("Aacute", "Uhungarumlaut", -55.0), ("Aacute", "Umacron", -55.0), ("Aacute", "Uogonek", -55.0), ("Aacute", "Uring", -55.0), ("Aacute", "V", -135.0), ("Aacute", "W", -90.0), ("Aacute", "Y", -105.0), ("Aacute", "Yacute", -105.0), ("Aacute", "Ydieresis", -105.0), ("Aacute", "quoteright", -111.0), ("Aacute", "v", -74.0), ("Aacute", "w", -92.0), ("Aacute", "y", -92.0), ("Aacute", "yacute", -92.0), ("Aacute", "ydieresis", -92.0), ("Abreve", "C", -40.0), ("Abreve", "Cacute", -40.0), ("Abreve", "Ccaron", -40.0), ("Abreve", "Ccedilla", -40.0), ("Abreve", "G", -40.0), ("Abreve", "Gbreve", -40.0), ("Abreve", "Gcommaaccent", -40.0), ("Abreve", "O", -55.0), ("Abreve", "Oacute", -55.0), ("Abreve", "Ocircumflex", -55.0), ("Abreve", "Odieresis", -55.0), ("Abreve", "Ograve", -55.0), ("Abreve", "Ohungarumlaut", -55.0), ("Abreve", "Omacron", -55.0), ("Abreve", "Oslash", -55.0), ("Abreve", "Otilde", -55.0), ("Abreve", "Q", -55.0), ("Abreve", "T", -111.0), ("Abreve", "Tcaron", -111.0), ("Abreve", "Tcommaaccent", -111.0), ("Abreve", "U", -55.0), ("Abreve", "Uacute", -55.0), ("Abreve", "Ucircumflex", -55.0), ("Abreve", "Udieresis", -55.0), ("Abreve", "Ugrave", -55.0), ("Abreve", "Uhungarumlaut", -55.0), ("Abreve", "Umacron", -55.0), ("Abreve", "Uogonek", -55.0), ("Abreve", "Uring", -55.0), ("Abreve", "V", -135.0), ("Abreve", "W", -90.0), ("Abreve", "Y", -105.0), ("Abreve", "Yacute", -105.0), ("Abreve", "Ydieresis", -105.0), ("Abreve", "quoteright", -111.0), ("Abreve", "v", -74.0), ("Abreve", "w", -92.0), ("Abreve", "y", -92.0), ("Abreve", "yacute", -92.0), ("Abreve", "ydieresis", -92.0), ("Acircumflex", "C", -40.0), ("Acircumflex", "Cacute", -40.0), ("Acircumflex", "Ccaron", -40.0), ("Acircumflex", "Ccedilla", -40.0), ("Acircumflex", "G", -40.0), ("Acircumflex", "Gbreve", -40.0), ("Acircumflex", "Gcommaaccent", -40.0), ("Acircumflex", "O", -55.0), ("Acircumflex", "Oacute", -55.0), ("Acircumflex", "Ocircumflex", -55.0), ("Acircumflex", "Odieresis", -55.0), ("Acircumflex", "Ograve", -55.0), ("Acircumflex", "Ohungarumlaut", -55.0), ("Acircumflex", "Omacron", -55.0), ("Acircumflex", "Oslash", -55.0), ("Acircumflex", "Otilde", -55.0), ("Acircumflex", "Q", -55.0), ("Acircumflex", "T", -111.0), ("Acircumflex", "Tcaron", -111.0), ("Acircumflex", "Tcommaaccent", -111.0), ("Acircumflex", "U", -55.0), ("Acircumflex", "Uacute", -55.0), ("Acircumflex", "Ucircumflex", -55.0), ("Acircumflex", "Udieresis", -55.0), ("Acircumflex", "Ugrave", -55.0), ("Acircumflex", "Uhungarumlaut", -55.0), ("Acircumflex", "Umacron", -55.0), ("Acircumflex", "Uogonek", -55.0), ("Acircumflex", "Uring", -55.0), ("Acircumflex", "V", -135.0), ("Acircumflex", "W", -90.0), ("Acircumflex", "Y", -105.0), ("Acircumflex", "Yacute", -105.0), ("Acircumflex", "Ydieresis", -105.0), ("Acircumflex", "quoteright", -111.0), ("Acircumflex", "v", -74.0), ("Acircumflex", "w", -92.0), ("Acircumflex", "y", -92.0), ("Acircumflex", "yacute", -92.0), ("Acircumflex", "ydieresis", -92.0), ("Adieresis", "C", -40.0), ("Adieresis", "Cacute", -40.0), ("Adieresis", "Ccaron", -40.0), ("Adieresis", "Ccedilla", -40.0), ("Adieresis", "G", -40.0), ("Adieresis", "Gbreve", -40.0), ("Adieresis", "Gcommaaccent", -40.0), ("Adieresis", "O", -55.0), ("Adieresis", "Oacute", -55.0), ("Adieresis", "Ocircumflex", -55.0), ("Adieresis", "Odieresis", -55.0), ("Adieresis", "Ograve", -55.0), ("Adieresis", "Ohungarumlaut", -55.0), ("Adieresis", "Omacron", -55.0), ("Adieresis", "Oslash", -55.0), ("Adieresis", "Otilde", -55.0), ("Adieresis", "Q", -55.0), ("Adieresis", "T", -111.0), ("Adieresis", "Tcaron", -111.0), ("Adieresis", "Tcommaaccent", -111.0), ("Adieresis", "U", -55.0), ("Adieresis", "Uacute", -55.0), ("Adieresis", "Ucircumflex", -55.0), ("Adieresis", "Udieresis", -55.0), ("Adieresis", "Ugrave", -55.0), ("Adieresis", "Uhungarumlaut", -55.0), ("Adieresis", "Umacron", -55.0), ("Adieresis", "Uogonek", -55.0), ("Adieresis", "Uring", -55.0), ("Adieresis", "V", -135.0), ("Adieresis", "W", -90.0), ("Adieresis", "Y", -105.0), ("Adieresis", "Yacute", -105.0), ("Adieresis", "Ydieresis", -105.0), ("Adieresis", "quoteright", -111.0), ("Adieresis", "v", -74.0), ("Adieresis", "w", -92.0), ("Adieresis", "y", -92.0), ("Adieresis", "yacute", -92.0), ("Adieresis", "ydieresis", -92.0), ("Agrave", "C", -40.0), ("Agrave", "Cacute", -40.0), ("Agrave", "Ccaron", -40.0), ("Agrave", "Ccedilla", -40.0), ("Agrave", "G", -40.0), ("Agrave", "Gbreve", -40.0), ("Agrave", "Gcommaaccent", -40.0), ("Agrave", "O", -55.0), ("Agrave", "Oacute", -55.0), ("Agrave", "Ocircumflex", -55.0), ("Agrave", "Odieresis", -55.0), ("Agrave", "Ograve", -55.0), ("Agrave", "Ohungarumlaut", -55.0), ("Agrave", "Omacron", -55.0), ("Agrave", "Oslash", -55.0), ("Agrave", "Otilde", -55.0), ("Agrave", "Q", -55.0), ("Agrave", "T", -111.0), ("Agrave", "Tcaron", -111.0), ("Agrave", "Tcommaaccent", -111.0), ("Agrave", "U", -55.0), ("Agrave", "Uacute", -55.0), ("Agrave", "Ucircumflex", -55.0), ("Agrave", "Udieresis", -55.0), ("Agrave", "Ugrave", -55.0), ("Agrave", "Uhungarumlaut", -55.0), ("Agrave", "Umacron", -55.0), ("Agrave", "Uogonek", -55.0), ("Agrave", "Uring", -55.0), ("Agrave", "V", -135.0), ("Agrave", "W", -90.0), ("Agrave", "Y", -105.0), ("Agrave", "Yacute", -105.0), ("Agrave", "Ydieresis", -105.0), ("Agrave", "quoteright", -111.0), ("Agrave", "v", -74.0), ("Agrave", "w", -92.0), ("Agrave", "y", -92.0), ("Agrave", "yacute", -92.0), ("Agrave", "ydieresis", -92.0), ("Amacron", "C", -40.0), ("Amacron", "Cacute", -40.0), ("Amacron", "Ccaron", -40.0), ("Amacron", "Ccedilla", -40.0), ("Amacron", "G", -40.0), ("Amacron", "Gbreve", -40.0), ("Amacron", "Gcommaaccent", -40.0), ("Amacron", "O", -55.0), ("Amacron", "Oacute", -55.0), ("Amacron", "Ocircumflex", -55.0), ("Amacron", "Odieresis", -55.0), ("Amacron", "Ograve", -55.0), ("Amacron", "Ohungarumlaut", -55.0), ("Amacron", "Omacron", -55.0), ("Amacron", "Oslash", -55.0), ("Amacron", "Otilde", -55.0), ("Amacron", "Q", -55.0), ("Amacron", "T", -111.0), ("Amacron", "Tcaron", -111.0), ("Amacron", "Tcommaaccent", -111.0), ("Amacron", "U", -55.0), ("Amacron", "Uacute", -55.0), ("Amacron", "Ucircumflex", -55.0), ("Amacron", "Udieresis", -55.0), ("Amacron", "Ugrave", -55.0), ("Amacron", "Uhungarumlaut", -55.0), ("Amacron", "Umacron", -55.0), ("Amacron", "Uogonek", -55.0), ("Amacron", "Uring", -55.0), ("Amacron", "V", -135.0), ("Amacron", "W", -90.0), ("Amacron", "Y", -105.0), ("Amacron", "Yacute", -105.0), ("Amacron", "Ydieresis", -105.0), ("Amacron", "quoteright", -111.0), ("Amacron", "v", -74.0), ("Amacron", "w", -92.0), ("Amacron", "y", -92.0), ("Amacron", "yacute", -92.0), ("Amacron", "ydieresis", -92.0), ("Aogonek", "C", -40.0), ("Aogonek", "Cacute", -40.0), ("Aogonek", "Ccaron", -40.0), ("Aogonek", "Ccedilla", -40.0), ("Aogonek", "G", -40.0), ("Aogonek", "Gbreve", -40.0), ("Aogonek", "Gcommaaccent", -40.0), ("Aogonek", "O", -55.0), ("Aogonek", "Oacute", -55.0), ("Aogonek", "Ocircumflex", -55.0), ("Aogonek", "Odieresis", -55.0), ("Aogonek", "Ograve", -55.0), ("Aogonek", "Ohungarumlaut", -55.0), ("Aogonek", "Omacron", -55.0), ("Aogonek", "Oslash", -55.0), ("Aogonek", "Otilde", -55.0), ("Aogonek", "Q", -55.0), ("Aogonek", "T", -111.0), ("Aogonek", "Tcaron", -111.0), ("Aogonek", "Tcommaaccent", -111.0), ("Aogonek", "U", -55.0), ("Aogonek", "Uacute", -55.0), ("Aogonek", "Ucircumflex", -55.0), ("Aogonek", "Udieresis", -55.0), ("Aogonek", "Ugrave", -55.0), ("Aogonek", "Uhungarumlaut", -55.0), ("Aogonek", "Umacron", -55.0), ("Aogonek", "Uogonek", -55.0), ("Aogonek", "Uring", -55.0), ("Aogonek", "V", -135.0), ("Aogonek", "W", -90.0), ("Aogonek", "Y", -105.0), ("Aogonek", "Yacute", -105.0), ("Aogonek", "Ydieresis", -105.0), ("Aogonek", "quoteright", -111.0), ("Aogonek", "v", -74.0), ("Aogonek", "w", -52.0), ("Aogonek", "y", -52.0), ("Aogonek", "yacute", -52.0), ("Aogonek", "ydieresis", -52.0), ("Aring", "C", -40.0), ("Aring", "Cacute", -40.0), ("Aring", "Ccaron", -40.0), ("Aring", "Ccedilla", -40.0), ("Aring", "G", -40.0), ("Aring", "Gbreve", -40.0), ("Aring", "Gcommaaccent", -40.0), ("Aring", "O", -55.0), ("Aring", "Oacute", -55.0), ("Aring", "Ocircumflex", -55.0), ("Aring", "Odieresis", -55.0), ("Aring", "Ograve", -55.0), ("Aring", "Ohungarumlaut", -55.0), ("Aring", "Omacron", -55.0), ("Aring", "Oslash", -55.0), ("Aring", "Otilde", -55.0), ("Aring", "Q", -55.0), ("Aring", "T", -111.0), ("Aring", "Tcaron", -111.0), ("Aring", "Tcommaaccent", -111.0), ("Aring", "U", -55.0), ("Aring", "Uacute", -55.0), ("Aring", "Ucircumflex", -55.0), ("Aring", "Udieresis", -55.0), ("Aring", "Ugrave", -55.0), ("Aring", "Uhungarumlaut", -55.0), ("Aring", "Umacron", -55.0), ("Aring", "Uogonek", -55.0), ("Aring", "Uring", -55.0), ("Aring", "V", -135.0), ("Aring", "W", -90.0), ("Aring", "Y", -105.0), ("Aring", "Yacute", -105.0), ("Aring", "Ydieresis", -105.0), ("Aring", "quoteright", -111.0), ("Aring", "v", -74.0), ("Aring", "w", -92.0), ("Aring", "y", -92.0), ("Aring", "yacute", -92.0), ("Aring", "ydieresis", -92.0), ("Atilde", "C", -40.0), ("Atilde", "Cacute", -40.0), ("Atilde", "Ccaron", -40.0), ("Atilde", "Ccedilla", -40.0), ("Atilde", "G", -40.0), ("Atilde", "Gbreve", -40.0), ("Atilde", "Gcommaaccent", -40.0), ("Atilde", "O", -55.0), ("Atilde", "Oacute", -55.0), ("Atilde", "Ocircumflex", -55.0), ("Atilde", "Odieresis", -55.0), ("Atilde", "Ograve", -55.0), ("Atilde", "Ohungarumlaut", -55.0), ("Atilde", "Omacron", -55.0), ("Atilde", "Oslash", -55.0), ("Atilde", "Otilde", -55.0), ("Atilde", "Q", -55.0), ("Atilde", "T", -111.0), ("Atilde", "Tcaron", -111.0), ("Atilde", "Tcommaaccent", -111.0), ("Atilde", "U", -55.0), ("Atilde", "Uacute", -55.0), ("Atilde", "Ucircumflex", -55.0), ("Atilde", "Udieresis", -55.0), ("Atilde", "Ugrave", -55.0), ("Atilde", "Uhungarumlaut", -55.0), ("Atilde", "Umacron", -55.0), ("Atilde", "Uogonek", -55.0), ("Atilde", "Uring", -55.0), ("Atilde", "V", -135.0), ("Atilde", "W", -90.0), ("Atilde", "Y", -105.0), ("Atilde", "Yacute", -105.0), ("Atilde", "Ydieresis", -105.0), ("Atilde", "quoteright", -111.0), ("Atilde", "v", -74.0), ("Atilde", "w", -92.0), ("Atilde", "y", -92.0), ("Atilde", "yacute", -92.0), ("Atilde", "ydieresis", -92.0), ("B", "A", -35.0), ("B", "Aacute", -35.0), ("B", "Abreve", -35.0), ("B", "Acircumflex", -35.0), ("B", "Adieresis", -35.0), ("B", "Agrave", -35.0), ("B", "Amacron", -35.0), ("B", "Aogonek", -35.0), ("B", "Aring", -35.0), ("B", "Atilde", -35.0), ("B", "U", -10.0), ("B", "Uacute", -10.0), ("B", "Ucircumflex", -10.0), ("B", "Udieresis", -10.0), ("B", "Ugrave", -10.0), ("B", "Uhungarumlaut", -10.0), ("B", "Umacron", -10.0), ("B", "Uogonek", -10.0), ("B", "Uring", -10.0), ("D", "A", -40.0), ("D", "Aacute", -40.0), ("D", "Abreve", -40.0), ("D", "Acircumflex", -40.0), ("D", "Adieresis", -40.0), ("D", "Agrave", -40.0), ("D", "Amacron", -40.0), ("D", "Aogonek", -40.0), ("D", "Aring", -40.0), ("D", "Atilde", -40.0), ("D", "V", -40.0), ("D", "W", -30.0), ("D", "Y", -55.0), ("D", "Yacute", -55.0), ("D", "Ydieresis", -55.0), ("Dcaron", "A", -40.0), ("Dcaron", "Aacute", -40.0), ("Dcaron", "Abreve", -40.0), ("Dcaron", "Acircumflex", -40.0), ("Dcaron", "Adieresis", -40.0), ("Dcaron", "Agrave", -40.0), ("Dcaron", "Amacron", -40.0), ("Dcaron", "Aogonek", -40.0), ("Dcaron", "Aring", -40.0), ("Dcaron", "Atilde", -40.0), ("Dcaron", "V", -40.0), ("Dcaron", "W", -30.0), ("Dcaron", "Y", -55.0), ("Dcaron", "Yacute", -55.0), ("Dcaron", "Ydieresis", -55.0), ("Dcroat", "A", -40.0), ("Dcroat", "Aacute", -40.0), ("Dcroat", "Abreve", -40.0), ("Dcroat", "Acircumflex", -40.0), ("Dcroat", "Adieresis", -40.0), ("Dcroat", "Agrave", -40.0), ("Dcroat", "Amacron", -40.0), ("Dcroat", "Aogonek", -40.0), ("Dcroat", "Aring", -40.0), ("Dcroat", "Atilde", -40.0), ("Dcroat", "V", -40.0), ("Dcroat", "W", -30.0), ("Dcroat", "Y", -55.0), ("Dcroat", "Yacute", -55.0), ("Dcroat", "Ydieresis", -55.0), ("F", "A", -74.0), ("F", "Aacute", -74.0), ("F", "Abreve", -74.0), ("F", "Acircumflex", -74.0), ("F", "Adieresis", -74.0), ("F", "Agrave", -74.0), ("F", "Amacron", -74.0), ("F", "Aogonek", -74.0), ("F", "Aring", -74.0), ("F", "Atilde", -74.0), ("F", "a", -15.0), ("F", "aacute", -15.0), ("F", "abreve", -15.0), ("F", "acircumflex", -15.0), ("F", "adieresis", -15.0), ("F", "agrave", -15.0), ("F", "amacron", -15.0), ("F", "aogonek", -15.0), ("F", "aring", -15.0), ("F", "atilde", -15.0), ("F", "comma", -80.0), ("F", "o", -15.0), ("F", "oacute", -15.0), ("F", "ocircumflex", -15.0), ("F", "odieresis", -15.0), ("F", "ograve", -15.0), ("F", "ohungarumlaut", -15.0), ("F", "omacron", -15.0), ("F", "oslash", -15.0), ("F", "otilde", -15.0), ("F", "period", -80.0), ("J", "A", -60.0), ("J", "Aacute", -60.0), ("J", "Abreve", -60.0), ("J", "Acircumflex", -60.0), ("J", "Adieresis", -60.0), ("J", "Agrave", -60.0), ("J", "Amacron", -60.0), ("J", "Aogonek", -60.0), ("J", "Aring", -60.0), ("J", "Atilde", -60.0), ("K", "O", -30.0), ("K", "Oacute", -30.0), ("K", "Ocircumflex", -30.0), ("K", "Odieresis", -30.0), ("K", "Ograve", -30.0), ("K", "Ohungarumlaut", -30.0), ("K", "Omacron", -30.0), ("K", "Oslash", -30.0), ("K", "Otilde", -30.0), ("K", "e", -25.0), ("K", "eacute", -25.0), ("K", "ecaron", -25.0), ("K", "ecircumflex", -25.0), ("K", "edieresis", -25.0), ("K", "edotaccent", -25.0), ("K", "egrave", -25.0), ("K", "emacron", -25.0), ("K", "eogonek", -25.0), ("K", "o", -35.0), ("K", "oacute", -35.0), ("K", "ocircumflex", -35.0), ("K", "odieresis", -35.0), ("K", "ograve", -35.0), ("K", "ohungarumlaut", -35.0), ("K", "omacron", -35.0), ("K", "oslash", -35.0), ("K", "otilde", -35.0), ("K", "u", -15.0), ("K", "uacute", -15.0), ("K", "ucircumflex", -15.0), ("K", "udieresis", -15.0), ("K", "ugrave", -15.0), ("K", "uhungarumlaut", -15.0), ("K", "umacron", -15.0), ("K", "uogonek", -15.0), ("K", "uring", -15.0), ("K", "y", -25.0), ("K", "yacute", -25.0), ("K", "ydieresis", -25.0), ("Kcommaaccent", "O", -30.0), ("Kcommaaccent", "Oacute", -30.0), ("Kcommaaccent", "Ocircumflex", -30.0), ("Kcommaaccent", "Odieresis", -30.0), ("Kcommaaccent", "Ograve", -30.0), ("Kcommaaccent", "Ohungarumlaut", -30.0), ("Kcommaaccent", "Omacron", -30.0), ("Kcommaaccent", "Oslash", -30.0), ("Kcommaaccent", "Otilde", -30.0), ("Kcommaaccent", "e", -25.0), ("Kcommaaccent", "eacute", -25.0), ("Kcommaaccent", "ecaron", -25.0), ("Kcommaaccent", "ecircumflex", -25.0), ("Kcommaaccent", "edieresis", -25.0), ("Kcommaaccent", "edotaccent", -25.0), ("Kcommaaccent", "egrave", -25.0), ("Kcommaaccent", "emacron", -25.0), ("Kcommaaccent", "eogonek", -25.0), ("Kcommaaccent", "o", -35.0), ("Kcommaaccent", "oacute", -35.0), ("Kcommaaccent", "ocircumflex", -35.0), ("Kcommaaccent", "odieresis", -35.0), ("Kcommaaccent", "ograve", -35.0), ("Kcommaaccent", "ohungarumlaut", -35.0), ("Kcommaaccent", "omacron", -35.0), ("Kcommaaccent", "oslash", -35.0), ("Kcommaaccent", "otilde", -35.0), ("Kcommaaccent", "u", -15.0), ("Kcommaaccent", "uacute", -15.0), ("Kcommaaccent", "ucircumflex", -15.0), ("Kcommaaccent", "udieresis", -15.0), ("Kcommaaccent", "ugrave", -15.0), ("Kcommaaccent", "uhungarumlaut", -15.0), ("Kcommaaccent", "umacron", -15.0), ("Kcommaaccent", "uogonek", -15.0), ("Kcommaaccent", "uring", -15.0), ("Kcommaaccent", "y", -25.0), ("Kcommaaccent", "yacute", -25.0), ("Kcommaaccent", "ydieresis", -25.0), ("L", "T", -92.0), ("L", "Tcaron", -92.0), ("L", "Tcommaaccent", -92.0), ("L", "V", -100.0), ("L", "W", -74.0), ("L", "Y", -100.0), ("L", "Yacute", -100.0), ("L", "Ydieresis", -100.0), ("L", "quoteright", -92.0), ("L", "y", -55.0), ("L", "yacute", -55.0), ("L", "ydieresis", -55.0), ("Lacute", "T", -92.0), ("Lacute", "Tcaron", -92.0), ("Lacute", "Tcommaaccent", -92.0), ("Lacute", "V", -100.0), ("Lacute", "W", -74.0), ("Lacute", "Y", -100.0), ("Lacute", "Yacute", -100.0), ("Lacute", "Ydieresis", -100.0), ("Lacute", "quoteright", -92.0), ("Lacute", "y", -55.0), ("Lacute", "yacute", -55.0), ("Lacute", "ydieresis", -55.0), ("Lcaron", "quoteright", -92.0), ("Lcaron", "y", -55.0), ("Lcaron", "yacute", -55.0), ("Lcaron", "ydieresis", -55.0), ("Lcommaaccent", "T", -92.0), ("Lcommaaccent", "Tcaron", -92.0), ("Lcommaaccent", "Tcommaaccent", -92.0), ("Lcommaaccent", "V", -100.0), ("Lcommaaccent", "W", -74.0), ("Lcommaaccent", "Y", -100.0), ("Lcommaaccent", "Yacute", -100.0), ("Lcommaaccent", "Ydieresis", -100.0), ("Lcommaaccent", "quoteright", -92.0), ("Lcommaaccent", "y", -55.0), ("Lcommaaccent", "yacute", -55.0), ("Lcommaaccent", "ydieresis", -55.0), ("Lslash", "T", -92.0), ("Lslash", "Tcaron", -92.0), ("Lslash", "Tcommaaccent", -92.0), ("Lslash", "V", -100.0), ("Lslash", "W", -74.0), ("Lslash", "Y", -100.0), ("Lslash", "Yacute", -100.0), ("Lslash", "Ydieresis", -100.0), ("Lslash", "quoteright", -92.0), ("Lslash", "y", -55.0), ("Lslash", "yacute", -55.0), ("Lslash", "ydieresis", -55.0), ("N", "A", -35.0), ("N", "Aacute", -35.0), ("N", "Abreve", -35.0), ("N", "Acircumflex", -35.0), ("N", "Adieresis", -35.0), ("N", "Agrave", -35.0), ("N", "Amacron", -35.0), ("N", "Aogonek", -35.0), ("N", "Aring", -35.0), ("N", "Atilde", -35.0), ("Nacute", "A", -35.0), ("Nacute", "Aacute", -35.0), ("Nacute", "Abreve", -35.0), ("Nacute", "Acircumflex", -35.0), ("Nacute", "Adieresis", -35.0), ("Nacute", "Agrave", -35.0), ("Nacute", "Amacron", -35.0), ("Nacute", "Aogonek", -35.0), ("Nacute", "Aring", -35.0), ("Nacute", "Atilde", -35.0), ("Ncaron", "A", -35.0), ("Ncaron", "Aacute", -35.0), ("Ncaron", "Abreve", -35.0), ("Ncaron", "Acircumflex", -35.0), ("Ncaron", "Adieresis", -35.0), ("Ncaron", "Agrave", -35.0), ("Ncaron", "Amacron", -35.0), ("Ncaron", "Aogonek", -35.0), ("Ncaron", "Aring", -35.0), ("Ncaron", "Atilde", -35.0), ("Ncommaaccent", "A", -35.0), ("Ncommaaccent", "Aacute", -35.0), ("Ncommaaccent", "Abreve", -35.0), ("Ncommaaccent", "Acircumflex", -35.0), ("Ncommaaccent", "Adieresis", -35.0), ("Ncommaaccent", "Agrave", -35.0), ("Ncommaaccent", "Amacron", -35.0), ("Ncommaaccent", "Aogonek", -35.0), ("Ncommaaccent", "Aring", -35.0), ("Ncommaaccent", "Atilde", -35.0), ("Ntilde", "A", -35.0), ("Ntilde", "Aacute", -35.0), ("Ntilde", "Abreve", -35.0), ("Ntilde", "Acircumflex", -35.0), ("Ntilde", "Adieresis", -35.0), ("Ntilde", "Agrave", -35.0), ("Ntilde", "Amacron", -35.0), ("Ntilde", "Aogonek", -35.0), ("Ntilde", "Aring", -35.0), ("Ntilde", "Atilde", -35.0), ("O", "A", -35.0), ("O", "Aacute", -35.0), ("O", "Abreve", -35.0), ("O", "Acircumflex", -35.0), ("O", "Adieresis", -35.0), ("O", "Agrave", -35.0), ("O", "Amacron", -35.0), ("O", "Aogonek", -35.0), ("O", "Aring", -35.0), ("O", "Atilde", -35.0), ("O", "T", -40.0), ("O", "Tcaron", -40.0), ("O", "Tcommaaccent", -40.0), ("O", "V", -50.0), ("O", "W", -35.0), ("O", "X", -40.0), ("O", "Y", -50.0), ("O", "Yacute", -50.0), ("O", "Ydieresis", -50.0), ("Oacute", "A", -35.0), ("Oacute", "Aacute", -35.0), ("Oacute", "Abreve", -35.0), ("Oacute", "Acircumflex", -35.0), ("Oacute", "Adieresis", -35.0), ("Oacute", "Agrave", -35.0), ("Oacute", "Amacron", -35.0), ("Oacute", "Aogonek", -35.0), ("Oacute", "Aring", -35.0), ("Oacute", "Atilde", -35.0), ("Oacute", "T", -40.0), ("Oacute", "Tcaron", -40.0), ("Oacute", "Tcommaaccent", -40.0), ("Oacute", "V", -50.0), ("Oacute", "W", -35.0), ("Oacute", "X", -40.0), ("Oacute", "Y", -50.0), ("Oacute", "Yacute", -50.0), ("Oacute", "Ydieresis", -50.0), ("Ocircumflex", "A", -35.0), ("Ocircumflex", "Aacute", -35.0), ("Ocircumflex", "Abreve", -35.0), ("Ocircumflex", "Acircumflex", -35.0), ("Ocircumflex", "Adieresis", -35.0), ("Ocircumflex", "Agrave", -35.0), ("Ocircumflex", "Amacron", -35.0), ("Ocircumflex", "Aogonek", -35.0), ("Ocircumflex", "Aring", -35.0), ("Ocircumflex", "Atilde", -35.0), ("Ocircumflex", "T", -40.0), ("Ocircumflex", "Tcaron", -40.0), ("Ocircumflex", "Tcommaaccent", -40.0), ("Ocircumflex", "V", -50.0), ("Ocircumflex", "W", -35.0), ("Ocircumflex", "X", -40.0), ("Ocircumflex", "Y", -50.0), ("Ocircumflex", "Yacute", -50.0), ("Ocircumflex", "Ydieresis", -50.0), ("Odieresis", "A", -35.0), ("Odieresis", "Aacute", -35.0), ("Odieresis", "Abreve", -35.0), ("Odieresis", "Acircumflex", -35.0), ("Odieresis", "Adieresis", -35.0), ("Odieresis", "Agrave", -35.0), ("Odieresis", "Amacron", -35.0), ("Odieresis", "Aogonek", -35.0), ("Odieresis", "Aring", -35.0), ("Odieresis", "Atilde", -35.0), ("Odieresis", "T", -40.0), ("Odieresis", "Tcaron", -40.0), ("Odieresis", "Tcommaaccent", -40.0), ("Odieresis", "V", -50.0), ("Odieresis", "W", -35.0), ("Odieresis", "X", -40.0), ("Odieresis", "Y", -50.0), ("Odieresis", "Yacute", -50.0), ("Odieresis", "Ydieresis", -50.0), ("Ograve", "A", -35.0), ("Ograve", "Aacute", -35.0), ("Ograve", "Abreve", -35.0), ("Ograve", "Acircumflex", -35.0), ("Ograve", "Adieresis", -35.0), ("Ograve", "Agrave", -35.0), ("Ograve", "Amacron", -35.0), ("Ograve", "Aogonek", -35.0), ("Ograve", "Aring", -35.0), ("Ograve", "Atilde", -35.0), ("Ograve", "T", -40.0), ("Ograve", "Tcaron", -40.0), ("Ograve", "Tcommaaccent", -40.0), ("Ograve", "V", -50.0), ("Ograve", "W", -35.0), ("Ograve", "X", -40.0), ("Ograve", "Y", -50.0), ("Ograve", "Yacute", -50.0), ("Ograve", "Ydieresis", -50.0), ("Ohungarumlaut", "A", -35.0), ("Ohungarumlaut", "Aacute", -35.0), ("Ohungarumlaut", "Abreve", -35.0), ("Ohungarumlaut", "Acircumflex", -35.0), ("Ohungarumlaut", "Adieresis", -35.0), ("Ohungarumlaut", "Agrave", -35.0), ("Ohungarumlaut", "Amacron", -35.0), ("Ohungarumlaut", "Aogonek", -35.0), ("Ohungarumlaut", "Aring", -35.0), ("Ohungarumlaut", "Atilde", -35.0), ("Ohungarumlaut", "T", -40.0), ("Ohungarumlaut", "Tcaron", -40.0), ("Ohungarumlaut", "Tcommaaccent", -40.0), ("Ohungarumlaut", "V", -50.0), ("Ohungarumlaut", "W", -35.0), ("Ohungarumlaut", "X", -40.0), ("Ohungarumlaut", "Y", -50.0), ("Ohungarumlaut", "Yacute", -50.0), ("Ohungarumlaut", "Ydieresis", -50.0), ("Omacron", "A", -35.0), ("Omacron", "Aacute", -35.0), ("Omacron", "Abreve", -35.0), ("Omacron", "Acircumflex", -35.0), ("Omacron", "Adieresis", -35.0), ("Omacron", "Agrave", -35.0), ("Omacron", "Amacron", -35.0), ("Omacron", "Aogonek", -35.0), ("Omacron", "Aring", -35.0), ("Omacron", "Atilde", -35.0), ("Omacron", "T", -40.0), ("Omacron", "Tcaron", -40.0), ("Omacron", "Tcommaaccent", -40.0), ("Omacron", "V", -50.0), ("Omacron", "W", -35.0), ("Omacron", "X", -40.0), ("Omacron", "Y", -50.0), ("Omacron", "Yacute", -50.0), ("Omacron", "Ydieresis", -50.0), ("Oslash", "A", -35.0), ("Oslash", "Aacute", -35.0), ("Oslash", "Abreve", -35.0), ("Oslash", "Acircumflex", -35.0), ("Oslash", "Adieresis", -35.0), ("Oslash", "Agrave", -35.0), ("Oslash", "Amacron", -35.0), ("Oslash", "Aogonek", -35.0), ("Oslash", "Aring", -35.0), ("Oslash", "Atilde", -35.0), ("Oslash", "T", -40.0), ("Oslash", "Tcaron", -40.0), ("Oslash", "Tcommaaccent", -40.0), ("Oslash", "V", -50.0), ("Oslash", "W", -35.0), ("Oslash", "X", -40.0), ("Oslash", "Y", -50.0), ("Oslash", "Yacute", -50.0), ("Oslash", "Ydieresis", -50.0), ("Otilde", "A", -35.0), ("Otilde", "Aacute", -35.0), ("Otilde", "Abreve", -35.0), ("Otilde", "Acircumflex", -35.0), ("Otilde", "Adieresis", -35.0), ("Otilde", "Agrave", -35.0), ("Otilde", "Amacron", -35.0), ("Otilde", "Aogonek", -35.0), ("Otilde", "Aring", -35.0), ("Otilde", "Atilde", -35.0), ("Otilde", "T", -40.0), ("Otilde", "Tcaron", -40.0), ("Otilde", "Tcommaaccent", -40.0), ("Otilde", "V", -50.0), ("Otilde", "W", -35.0), ("Otilde", "X", -40.0), ("Otilde", "Y", -50.0), ("Otilde", "Yacute", -50.0), ("Otilde", "Ydieresis", -50.0), ("P", "A", -92.0), ("P", "Aacute", -92.0), ("P", "Abreve", -92.0), ("P", "Acircumflex", -92.0), ("P", "Adieresis", -92.0), ("P", "Agrave", -92.0), ("P", "Amacron", -92.0), ("P", "Aogonek", -92.0), ("P", "Aring", -92.0), ("P", "Atilde", -92.0), ("P", "a", -15.0), ("P", "aacute", -15.0), ("P", "abreve", -15.0), ("P", "acircumflex", -15.0), ("P", "adieresis", -15.0), ("P", "agrave", -15.0), ("P", "amacron", -15.0), ("P", "aogonek", -15.0), ("P", "aring", -15.0), ("P", "atilde", -15.0), ("P", "comma", -111.0), ("P", "period", -111.0), ("Q", "U", -10.0), ("Q", "Uacute", -10.0), ("Q", "Ucircumflex", -10.0), ("Q", "Udieresis", -10.0), ("Q", "Ugrave", -10.0), ("Q", "Uhungarumlaut", -10.0), ("Q", "Umacron", -10.0), ("Q", "Uogonek", -10.0), ("Q", "Uring", -10.0), ("R", "O", -40.0), ("R", "Oacute", -40.0), ("R", "Ocircumflex", -40.0), ("R", "Odieresis", -40.0), ("R", "Ograve", -40.0), ("R", "Ohungarumlaut", -40.0), ("R", "Omacron", -40.0), ("R", "Oslash", -40.0), ("R", "Otilde", -40.0), ("R", "T", -60.0), ("R", "Tcaron", -60.0), ("R", "Tcommaaccent", -60.0), ("R", "U", -40.0), ("R", "Uacute", -40.0), ("R", "Ucircumflex", -40.0), ("R", "Udieresis", -40.0), ("R", "Ugrave", -40.0), ("R", "Uhungarumlaut", -40.0), ("R", "Umacron", -40.0), ("R", "Uogonek", -40.0), ("R", "Uring", -40.0), ("R", "V", -80.0), ("R", "W", -55.0), ("R", "Y", -65.0), ("R", "Yacute", -65.0), ("R", "Ydieresis", -65.0), ("Racute", "O", -40.0), ("Racute", "Oacute", -40.0), ("Racute", "Ocircumflex", -40.0), ("Racute", "Odieresis", -40.0), ("Racute", "Ograve", -40.0), ("Racute", "Ohungarumlaut", -40.0), ("Racute", "Omacron", -40.0), ("Racute", "Oslash", -40.0), ("Racute", "Otilde", -40.0), ("Racute", "T", -60.0), ("Racute", "Tcaron", -60.0), ("Racute", "Tcommaaccent", -60.0), ("Racute", "U", -40.0), ("Racute", "Uacute", -40.0), ("Racute", "Ucircumflex", -40.0), ("Racute", "Udieresis", -40.0), ("Racute", "Ugrave", -40.0), ("Racute", "Uhungarumlaut", -40.0), ("Racute", "Umacron", -40.0), ("Racute", "Uogonek", -40.0), ("Racute", "Uring", -40.0), ("Racute", "V", -80.0), ("Racute", "W", -55.0), ("Racute", "Y", -65.0), ("Racute", "Yacute", -65.0), ("Racute", "Ydieresis", -65.0), ("Rcaron", "O", -40.0), ("Rcaron", "Oacute", -40.0), ("Rcaron", "Ocircumflex", -40.0), ("Rcaron", "Odieresis", -40.0), ("Rcaron", "Ograve", -40.0), ("Rcaron", "Ohungarumlaut", -40.0), ("Rcaron", "Omacron", -40.0), ("Rcaron", "Oslash", -40.0), ("Rcaron", "Otilde", -40.0), ("Rcaron", "T", -60.0), ("Rcaron", "Tcaron", -60.0), ("Rcaron", "Tcommaaccent", -60.0), ("Rcaron", "U", -40.0), ("Rcaron", "Uacute", -40.0), ("Rcaron", "Ucircumflex", -40.0), ("Rcaron", "Udieresis", -40.0), ("Rcaron", "Ugrave", -40.0), ("Rcaron", "Uhungarumlaut", -40.0), ("Rcaron", "Umacron", -40.0), ("Rcaron", "Uogonek", -40.0), ("Rcaron", "Uring", -40.0), ("Rcaron", "V", -80.0), ("Rcaron", "W", -55.0), ("Rcaron", "Y", -65.0), ("Rcaron", "Yacute", -65.0), ("Rcaron", "Ydieresis", -65.0), ("Rcommaaccent", "O", -40.0), ("Rcommaaccent", "Oacute", -40.0), ("Rcommaaccent", "Ocircumflex", -40.0), ("Rcommaaccent", "Odieresis", -40.0), ("Rcommaaccent", "Ograve", -40.0), ("Rcommaaccent", "Ohungarumlaut", -40.0), ("Rcommaaccent", "Omacron", -40.0), ("Rcommaaccent", "Oslash", -40.0), ("Rcommaaccent", "Otilde", -40.0), ("Rcommaaccent", "T", -60.0), ("Rcommaaccent", "Tcaron", -60.0), ("Rcommaaccent", "Tcommaaccent", -60.0), ("Rcommaaccent", "U", -40.0), ("Rcommaaccent", "Uacute", -40.0), ("Rcommaaccent", "Ucircumflex", -40.0), ("Rcommaaccent", "Udieresis", -40.0), ("Rcommaaccent", "Ugrave", -40.0), ("Rcommaaccent", "Uhungarumlaut", -40.0), ("Rcommaaccent", "Umacron", -40.0), ("Rcommaaccent", "Uogonek", -40.0), ("Rcommaaccent", "Uring", -40.0), ("Rcommaaccent", "V", -80.0), ("Rcommaaccent", "W", -55.0), ("Rcommaaccent", "Y", -65.0), ("Rcommaaccent", "Yacute", -65.0), ("Rcommaaccent", "Ydieresis", -65.0), ("T", "A", -93.0), ("T", "Aacute", -93.0), ("T", "Abreve", -93.0), ("T", "Acircumflex", -93.0), ("T", "Adieresis", -93.0), ("T", "Agrave", -93.0), ("T", "Amacron", -93.0), ("T", "Aogonek", -93.0), ("T", "Aring", -93.0), ("T", "Atilde", -93.0), ("T", "O", -18.0), ("T", "Oacute", -18.0), ("T", "Ocircumflex", -18.0), ("T", "Odieresis", -18.0), ("T", "Ograve", -18.0), ("T", "Ohungarumlaut", -18.0), ("T", "Omacron", -18.0), ("T", "Oslash", -18.0), ("T", "Otilde", -18.0), ("T", "a", -80.0), ("T", "aacute", -80.0), ("T", "abreve", -80.0), ("T", "acircumflex", -80.0), ("T", "adieresis", -40.0), ("T", "agrave", -40.0), ("T", "amacron", -40.0), ("T", "aogonek", -80.0), ("T", "aring", -80.0), ("T", "atilde", -40.0), ("T", "colon", -50.0), ("T", "comma", -74.0), ("T", "e", -70.0), ("T", "eacute", -70.0), ("T", "ecaron", -70.0), ("T", "ecircumflex", -70.0), ("T", "edieresis", -30.0), ("T", "edotaccent", -70.0), ("T", "egrave", -70.0), ("T", "emacron", -30.0), ("T", "eogonek", -70.0), ("T", "hyphen", -92.0), ("T", "i", -35.0), ("T", "iacute", -35.0), ("T", "iogonek", -35.0), ("T", "o", -80.0), ("T", "oacute", -80.0), ("T", "ocircumflex", -80.0), ("T", "odieresis", -80.0), ("T", "ograve", -80.0), ("T", "ohungarumlaut", -80.0), ("T", "omacron", -80.0), ("T", "oslash", -80.0), ("T", "otilde", -80.0), ("T", "period", -74.0), ("T", "r", -35.0), ("T", "racute", -35.0), ("T", "rcaron", -35.0), ("T", "rcommaaccent", -35.0), ("T", "semicolon", -55.0), ("T", "u", -45.0), ("T", "uacute", -45.0), ("T", "ucircumflex", -45.0), ("T", "udieresis", -45.0), ("T", "ugrave", -45.0), ("T", "uhungarumlaut", -45.0), ("T", "umacron", -45.0), ("T", "uogonek", -45.0), ("T", "uring", -45.0), ("T", "w", -80.0), ("T", "y", -80.0), ("T", "yacute", -80.0), ("T", "ydieresis", -80.0), ("Tcaron", "A", -93.0), ("Tcaron", "Aacute", -93.0), ("Tcaron", "Abreve", -93.0), ("Tcaron", "Acircumflex", -93.0), ("Tcaron", "Adieresis", -93.0), ("Tcaron", "Agrave", -93.0), ("Tcaron", "Amacron", -93.0), ("Tcaron", "Aogonek", -93.0), ("Tcaron", "Aring", -93.0), ("Tcaron", "Atilde", -93.0), ("Tcaron", "O", -18.0), ("Tcaron", "Oacute", -18.0), ("Tcaron", "Ocircumflex", -18.0), ("Tcaron", "Odieresis", -18.0), ("Tcaron", "Ograve", -18.0), ("Tcaron", "Ohungarumlaut", -18.0), ("Tcaron", "Omacron", -18.0), ("Tcaron", "Oslash", -18.0), ("Tcaron", "Otilde", -18.0), ("Tcaron", "a", -80.0), ("Tcaron", "aacute", -80.0), ("Tcaron", "abreve", -80.0), ("Tcaron", "acircumflex", -80.0), ("Tcaron", "adieresis", -40.0), ("Tcaron", "agrave", -40.0), ("Tcaron", "amacron", -40.0), ("Tcaron", "aogonek", -80.0), ("Tcaron", "aring", -80.0), ("Tcaron", "atilde", -40.0), ("Tcaron", "colon", -50.0), ("Tcaron", "comma", -74.0), ("Tcaron", "e", -70.0), ("Tcaron", "eacute", -70.0), ("Tcaron", "ecaron", -70.0), ("Tcaron", "ecircumflex", -30.0), ("Tcaron", "edieresis", -30.0), ("Tcaron", "edotaccent", -70.0), ("Tcaron", "egrave", -70.0), ("Tcaron", "emacron", -30.0), ("Tcaron", "eogonek", -70.0), ("Tcaron", "hyphen", -92.0), ("Tcaron", "i", -35.0), ("Tcaron", "iacute", -35.0), ("Tcaron", "iogonek", -35.0), ("Tcaron", "o", -80.0), ("Tcaron", "oacute", -80.0), ("Tcaron", "ocircumflex", -80.0), ("Tcaron", "odieresis", -80.0), ("Tcaron", "ograve", -80.0), ("Tcaron", "ohungarumlaut", -80.0), ("Tcaron", "omacron", -80.0), ("Tcaron", "oslash", -80.0), ("Tcaron", "otilde", -80.0), ("Tcaron", "period", -74.0), ("Tcaron", "r", -35.0), ("Tcaron", "racute", -35.0), ("Tcaron", "rcaron", -35.0), ("Tcaron", "rcommaaccent", -35.0), ("Tcaron", "semicolon", -55.0), ("Tcaron", "u", -45.0), ("Tcaron", "uacute", -45.0), ("Tcaron", "ucircumflex", -45.0), ("Tcaron", "udieresis", -45.0), ("Tcaron", "ugrave", -45.0), ("Tcaron", "uhungarumlaut", -45.0), ("Tcaron", "umacron", -45.0), ("Tcaron", "uogonek", -45.0), ("Tcaron", "uring", -45.0), ("Tcaron", "w", -80.0), ("Tcaron", "y", -80.0), ("Tcaron", "yacute", -80.0), ("Tcaron", "ydieresis", -80.0), ("Tcommaaccent", "A", -93.0), ("Tcommaaccent", "Aacute", -93.0), ("Tcommaaccent", "Abreve", -93.0), ("Tcommaaccent", "Acircumflex", -93.0), ("Tcommaaccent", "Adieresis", -93.0), ("Tcommaaccent", "Agrave", -93.0), ("Tcommaaccent", "Amacron", -93.0), ("Tcommaaccent", "Aogonek", -93.0), ("Tcommaaccent", "Aring", -93.0), ("Tcommaaccent", "Atilde", -93.0), ("Tcommaaccent", "O", -18.0), ("Tcommaaccent", "Oacute", -18.0), ("Tcommaaccent", "Ocircumflex", -18.0), ("Tcommaaccent", "Odieresis", -18.0), ("Tcommaaccent", "Ograve", -18.0), ("Tcommaaccent", "Ohungarumlaut", -18.0), ("Tcommaaccent", "Omacron", -18.0), ("Tcommaaccent", "Oslash", -18.0), ("Tcommaaccent", "Otilde", -18.0), ("Tcommaaccent", "a", -80.0), ("Tcommaaccent", "aacute", -80.0), ("Tcommaaccent", "abreve", -80.0), ("Tcommaaccent", "acircumflex", -80.0), ("Tcommaaccent", "adieresis", -40.0), ("Tcommaaccent", "agrave", -40.0), ("Tcommaaccent", "amacron", -40.0), ("Tcommaaccent", "aogonek", -80.0), ("Tcommaaccent", "aring", -80.0), ("Tcommaaccent", "atilde", -40.0), ("Tcommaaccent", "colon", -50.0), ("Tcommaaccent", "comma", -74.0), ("Tcommaaccent", "e", -70.0), ("Tcommaaccent", "eacute", -70.0), ("Tcommaaccent", "ecaron", -70.0), ("Tcommaaccent", "ecircumflex", -30.0), ("Tcommaaccent", "edieresis", -30.0), ("Tcommaaccent", "edotaccent", -70.0), ("Tcommaaccent", "egrave", -30.0), ("Tcommaaccent", "emacron", -70.0), ("Tcommaaccent", "eogonek", -70.0), ("Tcommaaccent", "hyphen", -92.0), ("Tcommaaccent", "i", -35.0), ("Tcommaaccent", "iacute", -35.0), ("Tcommaaccent", "iogonek", -35.0), ("Tcommaaccent", "o", -80.0), ("Tcommaaccent", "oacute", -80.0), ("Tcommaaccent", "ocircumflex", -80.0), ("Tcommaaccent", "odieresis", -80.0), ("Tcommaaccent", "ograve", -80.0), ("Tcommaaccent", "ohungarumlaut", -80.0), ("Tcommaaccent", "omacron", -80.0), ("Tcommaaccent", "oslash", -80.0), ("Tcommaaccent", "otilde", -80.0), ("Tcommaaccent", "period", -74.0), ("Tcommaaccent", "r", -35.0), ("Tcommaaccent", "racute", -35.0), ("Tcommaaccent", "rcaron", -35.0), ("Tcommaaccent", "rcommaaccent", -35.0), ("Tcommaaccent", "semicolon", -55.0), ("Tcommaaccent", "u", -45.0), ("Tcommaaccent", "uacute", -45.0), ("Tcommaaccent", "ucircumflex", -45.0), ("Tcommaaccent", "udieresis", -45.0), ("Tcommaaccent", "ugrave", -45.0), ("Tcommaaccent", "uhungarumlaut", -45.0), ("Tcommaaccent", "umacron", -45.0), ("Tcommaaccent", "uogonek", -45.0), ("Tcommaaccent", "uring", -45.0), ("Tcommaaccent", "w", -80.0), ("Tcommaaccent", "y", -80.0), ("Tcommaaccent", "yacute", -80.0), ("Tcommaaccent", "ydieresis", -80.0), ("U", "A", -40.0), ("U", "Aacute", -40.0), ("U", "Abreve", -40.0), ("U", "Acircumflex", -40.0), ("U", "Adieresis", -40.0), ("U", "Agrave", -40.0), ("U", "Amacron", -40.0), ("U", "Aogonek", -40.0), ("U", "Aring", -40.0), ("U", "Atilde", -40.0), ("Uacute", "A", -40.0), ("Uacute", "Aacute", -40.0), ("Uacute", "Abreve", -40.0), ("Uacute", "Acircumflex", -40.0), ("Uacute", "Adieresis", -40.0), ("Uacute", "Agrave", -40.0), ("Uacute", "Amacron", -40.0), ("Uacute", "Aogonek", -40.0), ("Uacute", "Aring", -40.0), ("Uacute", "Atilde", -40.0), ("Ucircumflex", "A", -40.0), ("Ucircumflex", "Aacute", -40.0), ("Ucircumflex", "Abreve", -40.0), ("Ucircumflex", "Acircumflex", -40.0), ("Ucircumflex", "Adieresis", -40.0), ("Ucircumflex", "Agrave", -40.0), ("Ucircumflex", "Amacron", -40.0), ("Ucircumflex", "Aogonek", -40.0), ("Ucircumflex", "Aring", -40.0), ("Ucircumflex", "Atilde", -40.0), ("Udieresis", "A", -40.0), ("Udieresis", "Aacute", -40.0), ("Udieresis", "Abreve", -40.0), ("Udieresis", "Acircumflex", -40.0), ("Udieresis", "Adieresis", -40.0), ("Udieresis", "Agrave", -40.0), ("Udieresis", "Amacron", -40.0), ("Udieresis", "Aogonek", -40.0), ("Udieresis", "Aring", -40.0), ("Udieresis", "Atilde", -40.0), ("Ugrave", "A", -40.0), ("Ugrave", "Aacute", -40.0), ("Ugrave", "Abreve", -40.0), ("Ugrave", "Acircumflex", -40.0), ("Ugrave", "Adieresis", -40.0), ("Ugrave", "Agrave", -40.0), ("Ugrave", "Amacron", -40.0), ("Ugrave", "Aogonek", -40.0), ("Ugrave", "Aring", -40.0), ("Ugrave", "Atilde", -40.0), ("Uhungarumlaut", "A", -40.0), ("Uhungarumlaut", "Aacute", -40.0), ("Uhungarumlaut", "Abreve", -40.0), ("Uhungarumlaut", "Acircumflex", -40.0), ("Uhungarumlaut", "Adieresis", -40.0), ("Uhungarumlaut", "Agrave", -40.0), ("Uhungarumlaut", "Amacron", -40.0), ("Uhungarumlaut", "Aogonek", -40.0), ("Uhungarumlaut", "Aring", -40.0), ("Uhungarumlaut", "Atilde", -40.0), ("Umacron", "A", -40.0), ("Umacron", "Aacute", -40.0), ("Umacron", "Abreve", -40.0), ("Umacron", "Acircumflex", -40.0), ("Umacron", "Adieresis", -40.0), ("Umacron", "Agrave", -40.0), ("Umacron", "Amacron", -40.0), ("Umacron", "Aogonek", -40.0), ("Umacron", "Aring", -40.0), ("Umacron", "Atilde", -40.0), ("Uogonek", "A", -40.0), ("Uogonek", "Aacute", -40.0), ("Uogonek", "Abreve", -40.0), ("Uogonek", "Acircumflex", -40.0), ("Uogonek", "Adieresis", -40.0), ("Uogonek", "Agrave", -40.0), ("Uogonek", "Amacron", -40.0), ("Uogonek", "Aogonek", -40.0), ("Uogonek", "Aring", -40.0), ("Uogonek", "Atilde", -40.0), ("Uring", "A", -40.0), ("Uring", "Aacute", -40.0), ("Uring", "Abreve", -40.0), ("Uring", "Acircumflex", -40.0), ("Uring", "Adieresis", -40.0), ("Uring", "Agrave", -40.0), ("Uring", "Amacron", -40.0), ("Uring", "Aogonek", -40.0), ("Uring", "Aring", -40.0), ("Uring", "Atilde", -40.0), ("V", "A", -135.0), ("V", "Aacute", -135.0), ("V", "Abreve", -135.0), ("V", "Acircumflex", -135.0), ("V", "Adieresis", -135.0), ("V", "Agrave", -135.0), ("V", "Amacron", -135.0), ("V", "Aogonek", -135.0), ("V", "Aring", -135.0), ("V", "Atilde", -135.0), ("V", "G", -15.0), ("V", "Gbreve", -15.0), ("V", "Gcommaaccent", -15.0), ("V", "O", -40.0), ("V", "Oacute", -40.0), ("V", "Ocircumflex", -40.0), ("V", "Odieresis", -40.0), ("V", "Ograve", -40.0), ("V", "Ohungarumlaut", -40.0), ("V", "Omacron", -40.0), ("V", "Oslash", -40.0), ("V", "Otilde", -40.0), ("V", "a", -111.0), ("V", "aacute", -111.0), ("V", "abreve", -111.0), ("V", "acircumflex", -71.0), ("V", "adieresis", -71.0), ("V", "agrave", -71.0), ("V", "amacron", -71.0), ("V", "aogonek", -111.0), ("V", "aring", -111.0), ("V", "atilde", -71.0), ("V", "colon", -74.0), ("V", "comma", -129.0), ("V", "e", -111.0), ("V", "eacute", -111.0), ("V", "ecaron", -71.0), ("V", "ecircumflex", -71.0), ("V", "edieresis", -71.0), ("V", "edotaccent", -111.0), ("V", "egrave", -71.0), ("V", "emacron", -71.0), ("V", "eogonek", -111.0), ("V", "hyphen", -100.0), ("V", "i", -60.0), ("V", "iacute", -60.0), ("V", "icircumflex", -20.0), ("V", "idieresis", -20.0), ("V", "igrave", -20.0), ("V", "imacron", -20.0), ("V", "iogonek", -60.0), ("V", "o", -129.0), ("V", "oacute", -129.0), ("V", "ocircumflex", -129.0), ("V", "odieresis", -89.0), ("V", "ograve", -89.0), ("V", "ohungarumlaut", -129.0), ("V", "omacron", -89.0), ("V", "oslash", -129.0), ("V", "otilde", -89.0), ("V", "period", -129.0), ("V", "semicolon", -74.0), ("V", "u", -75.0), ("V", "uacute", -75.0), ("V", "ucircumflex", -75.0), ("V", "udieresis", -75.0), ("V", "ugrave", -75.0), ("V", "uhungarumlaut", -75.0), ("V", "umacron", -75.0), ("V", "uogonek", -75.0), ("V", "uring", -75.0), ("W", "A", -120.0), ("W", "Aacute", -120.0), ("W", "Abreve", -120.0), ("W", "Acircumflex", -120.0), ("W", "Adieresis", -120.0), ("W", "Agrave", -120.0), ("W", "Amacron", -120.0), ("W", "Aogonek", -120.0), ("W", "Aring", -120.0), ("W", "Atilde", -120.0), ("W", "O", -10.0), ("W", "Oacute", -10.0), ("W", "Ocircumflex", -10.0), ("W", "Odieresis", -10.0), ("W", "Ograve", -10.0), ("W", "Ohungarumlaut", -10.0), ("W", "Omacron", -10.0), ("W", "Oslash", -10.0), ("W", "Otilde", -10.0), ("W", "a", -80.0), ("W", "aacute", -80.0), ("W", "abreve", -80.0), ("W", "acircumflex", -80.0), ("W", "adieresis", -80.0), ("W", "agrave", -80.0), ("W", "amacron", -80.0), ("W", "aogonek", -80.0), ("W", "aring", -80.0), ("W", "atilde", -80.0), ("W", "colon", -37.0), ("W", "comma", -92.0), ("W", "e", -80.0), ("W", "eacute", -80.0), ("W", "ecaron", -80.0), ("W", "ecircumflex", -80.0), ("W", "edieresis", -40.0), ("W", "edotaccent", -80.0), ("W", "egrave", -40.0), ("W", "emacron", -40.0), ("W", "eogonek", -80.0), ("W", "hyphen", -65.0), ("W", "i", -40.0), ("W", "iacute", -40.0), ("W", "iogonek", -40.0), ("W", "o", -80.0), ("W", "oacute", -80.0), ("W", "ocircumflex", -80.0), ("W", "odieresis", -80.0), ("W", "ograve", -80.0), ("W", "ohungarumlaut", -80.0), ("W", "omacron", -80.0), ("W", "oslash", -80.0), ("W", "otilde", -80.0), ("W", "period", -92.0), ("W", "semicolon", -37.0), ("W", "u", -50.0), ("W", "uacute", -50.0), ("W", "ucircumflex", -50.0), ("W", "udieresis", -50.0), ("W", "ugrave", -50.0), ("W", "uhungarumlaut", -50.0), ("W", "umacron", -50.0), ("W", "uogonek", -50.0), ("W", "uring", -50.0), ("W", "y", -73.0), ("W", "yacute", -73.0), ("W", "ydieresis", -73.0), ("Y", "A", -120.0), ("Y", "Aacute", -120.0), ("Y", "Abreve", -120.0), ("Y", "Acircumflex", -120.0), ("Y", "Adieresis", -120.0), ("Y", "Agrave", -120.0), ("Y", "Amacron", -120.0), ("Y", "Aogonek", -120.0), ("Y", "Aring", -120.0), ("Y", "Atilde", -120.0), ("Y", "O", -30.0), ("Y", "Oacute", -30.0), ("Y", "Ocircumflex", -30.0), ("Y", "Odieresis", -30.0), ("Y", "Ograve", -30.0), ("Y", "Ohungarumlaut", -30.0), ("Y", "Omacron", -30.0), ("Y", "Oslash", -30.0), ("Y", "Otilde", -30.0), ("Y", "a", -100.0), ("Y", "aacute", -100.0), ("Y", "abreve", -100.0), ("Y", "acircumflex", -100.0), ("Y", "adieresis", -60.0), ("Y", "agrave", -60.0), ("Y", "amacron", -60.0), ("Y", "aogonek", -100.0), ("Y", "aring", -100.0), ("Y", "atilde", -60.0), ("Y", "colon", -92.0), ("Y", "comma", -129.0), ("Y", "e", -100.0), ("Y", "eacute", -100.0), ("Y", "ecaron", -100.0), ("Y", "ecircumflex", -100.0), ("Y", "edieresis", -60.0), ("Y", "edotaccent", -100.0), ("Y", "egrave", -60.0), ("Y", "emacron", -60.0), ("Y", "eogonek", -100.0), ("Y", "hyphen", -111.0), ("Y", "i", -55.0), ("Y", "iacute", -55.0), ("Y", "iogonek", -55.0), ("Y", "o", -110.0), ("Y", "oacute", -110.0), ("Y", "ocircumflex", -110.0), ("Y", "odieresis", -70.0), ("Y", "ograve", -70.0), ("Y", "ohungarumlaut", -110.0), ("Y", "omacron", -70.0), ("Y", "oslash", -110.0), ("Y", "otilde", -70.0), ("Y", "period", -129.0), ("Y", "semicolon", -92.0), ("Y", "u", -111.0), ("Y", "uacute", -111.0), ("Y", "ucircumflex", -111.0), ("Y", "udieresis", -71.0), ("Y", "ugrave", -71.0), ("Y", "uhungarumlaut", -111.0), ("Y", "umacron", -71.0), ("Y", "uogonek", -111.0), ("Y", "uring", -111.0), ("Yacute", "A", -120.0), ("Yacute", "Aacute", -120.0), ("Yacute", "Abreve", -120.0), ("Yacute", "Acircumflex", -120.0), ("Yacute", "Adieresis", -120.0), ("Yacute", "Agrave", -120.0), ("Yacute", "Amacron", -120.0), ("Yacute", "Aogonek", -120.0), ("Yacute", "Aring", -120.0), ("Yacute", "Atilde", -120.0), ("Yacute", "O", -30.0), ("Yacute", "Oacute", -30.0), ("Yacute", "Ocircumflex", -30.0), ("Yacute", "Odieresis", -30.0), ("Yacute", "Ograve", -30.0), ("Yacute", "Ohungarumlaut", -30.0), ("Yacute", "Omacron", -30.0), ("Yacute", "Oslash", -30.0), ("Yacute", "Otilde", -30.0), ("Yacute", "a", -100.0), ("Yacute", "aacute", -100.0), ("Yacute", "abreve", -100.0), ("Yacute", "acircumflex", -100.0), ("Yacute", "adieresis", -60.0), ("Yacute", "agrave", -60.0), ("Yacute", "amacron", -60.0), ("Yacute", "aogonek", -100.0), ("Yacute", "aring", -100.0), ("Yacute", "atilde", -60.0), ("Yacute", "colon", -92.0), ("Yacute", "comma", -129.0), ("Yacute", "e", -100.0), ("Yacute", "eacute", -100.0), ("Yacute", "ecaron", -100.0), ("Yacute", "ecircumflex", -100.0), ("Yacute", "edieresis", -60.0), ("Yacute", "edotaccent", -100.0), ("Yacute", "egrave", -60.0), ("Yacute", "emacron", -60.0), ("Yacute", "eogonek", -100.0), ("Yacute", "hyphen", -111.0), ("Yacute", "i", -55.0), ("Yacute", "iacute", -55.0), ("Yacute", "iogonek", -55.0), ("Yacute", "o", -110.0), ("Yacute", "oacute", -110.0), ("Yacute", "ocircumflex", -110.0), ("Yacute", "odieresis", -70.0), ("Yacute", "ograve", -70.0), ("Yacute", "ohungarumlaut", -110.0), ("Yacute", "omacron", -70.0), ("Yacute", "oslash", -110.0), ("Yacute", "otilde", -70.0), ("Yacute", "period", -129.0), ("Yacute", "semicolon", -92.0), ("Yacute", "u", -111.0), ("Yacute", "uacute", -111.0), ("Yacute", "ucircumflex", -111.0), ("Yacute", "udieresis", -71.0), ("Yacute", "ugrave", -71.0), ("Yacute", "uhungarumlaut", -111.0), ("Yacute", "umacron", -71.0), ("Yacute", "uogonek", -111.0), ("Yacute", "uring", -111.0), ("Ydieresis", "A", -120.0), ("Ydieresis", "Aacute", -120.0), ("Ydieresis", "Abreve", -120.0), ("Ydieresis", "Acircumflex", -120.0), ("Ydieresis", "Adieresis", -120.0), ("Ydieresis", "Agrave", -120.0), ("Ydieresis", "Amacron", -120.0), ("Ydieresis", "Aogonek", -120.0), ("Ydieresis", "Aring", -120.0), ("Ydieresis", "Atilde", -120.0), ("Ydieresis", "O", -30.0), ("Ydieresis", "Oacute", -30.0), ("Ydieresis", "Ocircumflex", -30.0), ("Ydieresis", "Odieresis", -30.0), ("Ydieresis", "Ograve", -30.0), ("Ydieresis", "Ohungarumlaut", -30.0), ("Ydieresis", "Omacron", -30.0), ("Ydieresis", "Oslash", -30.0), ("Ydieresis", "Otilde", -30.0), ("Ydieresis", "a", -100.0), ("Ydieresis", "aacute", -100.0), ("Ydieresis", "abreve", -100.0), ("Ydieresis", "acircumflex", -100.0), ("Ydieresis", "adieresis", -60.0), ("Ydieresis", "agrave", -60.0), ("Ydieresis", "amacron", -60.0), ("Ydieresis", "aogonek", -100.0), ("Ydieresis", "aring", -100.0), ("Ydieresis", "atilde", -100.0), ("Ydieresis", "colon", -92.0), ("Ydieresis", "comma", -129.0), ("Ydieresis", "e", -100.0), ("Ydieresis", "eacute", -100.0), ("Ydieresis", "ecaron", -100.0), ("Ydieresis", "ecircumflex", -100.0), ("Ydieresis", "edieresis", -60.0), ("Ydieresis", "edotaccent", -100.0), ("Ydieresis", "egrave", -60.0), ("Ydieresis", "emacron", -60.0), ("Ydieresis", "eogonek", -100.0), ("Ydieresis", "hyphen", -111.0), ("Ydieresis", "i", -55.0), ("Ydieresis", "iacute", -55.0), ("Ydieresis", "iogonek", -55.0), ("Ydieresis", "o", -110.0), ("Ydieresis", "oacute", -110.0), ("Ydieresis", "ocircumflex", -110.0), ("Ydieresis", "odieresis", -70.0), ("Ydieresis", "ograve", -70.0), ("Ydieresis", "ohungarumlaut", -110.0), ("Ydieresis", "omacron", -70.0), ("Ydieresis", "oslash", -110.0), ("Ydieresis", "otilde", -70.0), ("Ydieresis", "period", -129.0), ("Ydieresis", "semicolon", -92.0), ("Ydieresis", "u", -111.0), ("Ydieresis", "uacute", -111.0), ("Ydieresis", "ucircumflex", -111.0), ("Ydieresis", "udieresis", -71.0), ("Ydieresis", "ugrave", -71.0), ("Ydieresis", "uhungarumlaut", -111.0), ("Ydieresis", "umacron", -71.0), ("Ydieresis", "uogonek", -111.0), ("Ydieresis", "uring", -111.0), ("a", "v", -20.0), ("a", "w", -15.0), ("aacute", "v", -20.0), ("aacute", "w", -15.0), ("abreve", "v", -20.0), ("abreve", "w", -15.0), ("acircumflex", "v", -20.0), ("acircumflex", "w", -15.0), ("adieresis", "v", -20.0), ("adieresis", "w", -15.0), ("agrave", "v", -20.0), ("agrave", "w", -15.0), ("amacron", "v", -20.0), ("amacron", "w", -15.0), ("aogonek", "v", -20.0), ("aogonek", "w", -15.0), ("aring", "v", -20.0), ("aring", "w", -15.0), ("atilde", "v", -20.0), ("atilde", "w", -15.0), ("b", "period", -40.0), ("b", "u", -20.0), ("b", "uacute", -20.0), ("b", "ucircumflex", -20.0), ("b", "udieresis", -20.0), ("b", "ugrave", -20.0), ("b", "uhungarumlaut", -20.0), ("b", "umacron", -20.0), ("b", "uogonek", -20.0), ("b", "uring", -20.0), ("b", "v", -15.0), ("c", "y", -15.0), ("c", "yacute", -15.0), ("c", "ydieresis", -15.0), ("cacute", "y", -15.0), ("cacute", "yacute", -15.0), ("cacute", "ydieresis", -15.0), ("ccaron", "y", -15.0), ("ccaron", "yacute", -15.0), ("ccaron", "ydieresis", -15.0), ("ccedilla", "y", -15.0), ("ccedilla", "yacute", -15.0), ("ccedilla", "ydieresis", -15.0), ("comma", "quotedblright", -70.0), ("comma", "quoteright", -70.0), ("e", "g", -15.0), ("e", "gbreve", -15.0), ("e", "gcommaaccent", -15.0), ("e", "v", -25.0), ("e", "w", -25.0), ("e", "x", -15.0), ("e", "y", -15.0), ("e", "yacute", -15.0), ("e", "ydieresis", -15.0), ("eacute", "g", -15.0), ("eacute", "gbreve", -15.0), ("eacute", "gcommaaccent", -15.0), ("eacute", "v", -25.0), ("eacute", "w", -25.0), ("eacute", "x", -15.0), ("eacute", "y", -15.0), ("eacute", "yacute", -15.0), ("eacute", "ydieresis", -15.0), ("ecaron", "g", -15.0), ("ecaron", "gbreve", -15.0), ("ecaron", "gcommaaccent", -15.0), ("ecaron", "v", -25.0), ("ecaron", "w", -25.0), ("ecaron", "x", -15.0), ("ecaron", "y", -15.0), ("ecaron", "yacute", -15.0), ("ecaron", "ydieresis", -15.0), ("ecircumflex", "g", -15.0), ("ecircumflex", "gbreve", -15.0), ("ecircumflex", "gcommaaccent", -15.0), ("ecircumflex", "v", -25.0), ("ecircumflex", "w", -25.0), ("ecircumflex", "x", -15.0), ("ecircumflex", "y", -15.0), ("ecircumflex", "yacute", -15.0), ("ecircumflex", "ydieresis", -15.0), ("edieresis", "g", -15.0), ("edieresis", "gbreve", -15.0), ("edieresis", "gcommaaccent", -15.0), ("edieresis", "v", -25.0), ("edieresis", "w", -25.0), ("edieresis", "x", -15.0), ("edieresis", "y", -15.0), ("edieresis", "yacute", -15.0), ("edieresis", "ydieresis", -15.0), ("edotaccent", "g", -15.0), ("edotaccent", "gbreve", -15.0), ("edotaccent", "gcommaaccent", -15.0), ("edotaccent", "v", -25.0), ("edotaccent", "w", -25.0), ("edotaccent", "x", -15.0), ("edotaccent", "y", -15.0), ("edotaccent", "yacute", -15.0), ("edotaccent", "ydieresis", -15.0), ("egrave", "g", -15.0), ("egrave", "gbreve", -15.0), ("egrave", "gcommaaccent", -15.0), ("egrave", "v", -25.0), ("egrave", "w", -25.0), ("egrave", "x", -15.0), ("egrave", "y", -15.0), ("egrave", "yacute", -15.0), ("egrave", "ydieresis", -15.0), ("emacron", "g", -15.0), ("emacron", "gbreve", -15.0), ("emacron", "gcommaaccent", -15.0), ("emacron", "v", -25.0), ("emacron", "w", -25.0), ("emacron", "x", -15.0), ("emacron", "y", -15.0), ("emacron", "yacute", -15.0), ("emacron", "ydieresis", -15.0), ("eogonek", "g", -15.0), ("eogonek", "gbreve", -15.0), ("eogonek", "gcommaaccent", -15.0), ("eogonek", "v", -25.0), ("eogonek", "w", -25.0), ("eogonek", "x", -15.0), ("eogonek", "y", -15.0), ("eogonek", "yacute", -15.0), ("eogonek", "ydieresis", -15.0), ("f", "a", -10.0), ("f", "aacute", -10.0), ("f", "abreve", -10.0), ("f", "acircumflex", -10.0), ("f", "adieresis", -10.0), ("f", "agrave", -10.0), ("f", "amacron", -10.0), ("f", "aogonek", -10.0), ("f", "aring", -10.0), ("f", "atilde", -10.0), ("f", "dotlessi", -50.0), ("f", "f", -25.0), ("f", "i", -20.0), ("f", "iacute", -20.0), ("f", "quoteright", 55.0), ("g", "a", -5.0), ("g", "aacute", -5.0), ("g", "abreve", -5.0), ("g", "acircumflex", -5.0), ("g", "adieresis", -5.0), ("g", "agrave", -5.0), ("g", "amacron", -5.0), ("g", "aogonek", -5.0), ("g", "aring", -5.0), ("g", "atilde", -5.0), ("gbreve", "a", -5.0), ("gbreve", "aacute", -5.0), ("gbreve", "abreve", -5.0), ("gbreve", "acircumflex", -5.0), ("gbreve", "adieresis", -5.0), ("gbreve", "agrave", -5.0), ("gbreve", "amacron", -5.0), ("gbreve", "aogonek", -5.0), ("gbreve", "aring", -5.0), ("gbreve", "atilde", -5.0), ("gcommaaccent", "a", -5.0), ("gcommaaccent", "aacute", -5.0), ("gcommaaccent", "abreve", -5.0), ("gcommaaccent", "acircumflex", -5.0), ("gcommaaccent", "adieresis", -5.0), ("gcommaaccent", "agrave", -5.0), ("gcommaaccent", "amacron", -5.0), ("gcommaaccent", "aogonek", -5.0), ("gcommaaccent", "aring", -5.0), ("gcommaaccent", "atilde", -5.0), ("h", "y", -5.0), ("h", "yacute", -5.0), ("h", "ydieresis", -5.0), ("i", "v", -25.0), ("iacute", "v", -25.0), ("icircumflex", "v", -25.0), ("idieresis", "v", -25.0), ("igrave", "v", -25.0), ("imacron", "v", -25.0), ("iogonek", "v", -25.0), ("k", "e", -10.0), ("k", "eacute", -10.0), ("k", "ecaron", -10.0), ("k", "ecircumflex", -10.0), ("k", "edieresis", -10.0), ("k", "edotaccent", -10.0), ("k", "egrave", -10.0), ("k", "emacron", -10.0), ("k", "eogonek", -10.0), ("k", "o", -10.0), ("k", "oacute", -10.0), ("k", "ocircumflex", -10.0), ("k", "odieresis", -10.0), ("k", "ograve", -10.0), ("k", "ohungarumlaut", -10.0), ("k", "omacron", -10.0), ("k", "oslash", -10.0), ("k", "otilde", -10.0), ("k", "y", -15.0), ("k", "yacute", -15.0), ("k", "ydieresis", -15.0), ("kcommaaccent", "e", -10.0), ("kcommaaccent", "eacute", -10.0), ("kcommaaccent", "ecaron", -10.0), ("kcommaaccent", "ecircumflex", -10.0), ("kcommaaccent", "edieresis", -10.0), ("kcommaaccent", "edotaccent", -10.0), ("kcommaaccent", "egrave", -10.0), ("kcommaaccent", "emacron", -10.0), ("kcommaaccent", "eogonek", -10.0), ("kcommaaccent", "o", -10.0), ("kcommaaccent", "oacute", -10.0), ("kcommaaccent", "ocircumflex", -10.0), ("kcommaaccent", "odieresis", -10.0), ("kcommaaccent", "ograve", -10.0), ("kcommaaccent", "ohungarumlaut", -10.0), ("kcommaaccent", "omacron", -10.0), ("kcommaaccent", "oslash", -10.0), ("kcommaaccent", "otilde", -10.0), ("kcommaaccent", "y", -15.0), ("kcommaaccent", "yacute", -15.0), ("kcommaaccent", "ydieresis", -15.0), ("l", "w", -10.0), ("lacute", "w", -10.0), ("lcommaaccent", "w", -10.0), ("lslash", "w", -10.0), ("n", "v", -40.0), ("n", "y", -15.0), ("n", "yacute", -15.0), ("n", "ydieresis", -15.0), ("nacute", "v", -40.0), ("nacute", "y", -15.0), ("nacute", "yacute", -15.0), ("nacute", "ydieresis", -15.0), ("ncaron", "v", -40.0), ("ncaron", "y", -15.0), ("ncaron", "yacute", -15.0), ("ncaron", "ydieresis", -15.0), ("ncommaaccent", "v", -40.0), ("ncommaaccent", "y", -15.0), ("ncommaaccent", "yacute", -15.0), ("ncommaaccent", "ydieresis", -15.0), ("ntilde", "v", -40.0), ("ntilde", "y", -15.0), ("ntilde", "yacute", -15.0), ("ntilde", "ydieresis", -15.0), ("o", "v", -15.0), ("o", "w", -25.0), ("o", "y", -10.0), ("o", "yacute", -10.0), ("o", "ydieresis", -10.0), ("oacute", "v", -15.0), ("oacute", "w", -25.0), ("oacute", "y", -10.0), ("oacute", "yacute", -10.0), ("oacute", "ydieresis", -10.0), ("ocircumflex", "v", -15.0), ("ocircumflex", "w", -25.0), ("ocircumflex", "y", -10.0), ("ocircumflex", "yacute", -10.0), ("ocircumflex", "ydieresis", -10.0), ("odieresis", "v", -15.0), ("odieresis", "w", -25.0), ("odieresis", "y", -10.0), ("odieresis", "yacute", -10.0), ("odieresis", "ydieresis", -10.0), ("ograve", "v", -15.0), ("ograve", "w", -25.0), ("ograve", "y", -10.0), ("ograve", "yacute", -10.0), ("ograve", "ydieresis", -10.0), ("ohungarumlaut", "v", -15.0), ("ohungarumlaut", "w", -25.0), ("ohungarumlaut", "y", -10.0), ("ohungarumlaut", "yacute", -10.0), ("ohungarumlaut", "ydieresis", -10.0), ("omacron", "v", -15.0), ("omacron", "w", -25.0), ("omacron", "y", -10.0), ("omacron", "yacute", -10.0), ("omacron", "ydieresis", -10.0), ("oslash", "v", -15.0), ("oslash", "w", -25.0), ("oslash", "y", -10.0), ("oslash", "yacute", -10.0), ("oslash", "ydieresis", -10.0), ("otilde", "v", -15.0), ("otilde", "w", -25.0), ("otilde", "y", -10.0), ("otilde", "yacute", -10.0), ("otilde", "ydieresis", -10.0), ("p", "y", -10.0), ("p", "yacute", -10.0), ("p", "ydieresis", -10.0), ("period", "quotedblright", -70.0), ("period", "quoteright", -70.0), ("quotedblleft", "A", -80.0), ("quotedblleft", "Aacute", -80.0), ("quotedblleft", "Abreve", -80.0), ("quotedblleft", "Acircumflex", -80.0), ("quotedblleft", "Adieresis", -80.0), ("quotedblleft", "Agrave", -80.0), ("quotedblleft", "Amacron", -80.0), ("quotedblleft", "Aogonek", -80.0), ("quotedblleft", "Aring", -80.0), ("quotedblleft", "Atilde", -80.0), ("quoteleft", "A", -80.0), ("quoteleft", "Aacute", -80.0), ("quoteleft", "Abreve", -80.0), ("quoteleft", "Acircumflex", -80.0), ("quoteleft", "Adieresis", -80.0), ("quoteleft", "Agrave", -80.0), ("quoteleft", "Amacron", -80.0), ("quoteleft", "Aogonek", -80.0), ("quoteleft", "Aring", -80.0), ("quoteleft", "Atilde", -80.0), ("quoteleft", "quoteleft", -74.0), ("quoteright", "d", -50.0), ("quoteright", "dcroat", -50.0), ("quoteright", "l", -10.0), ("quoteright", "lacute", -10.0), ("quoteright", "lcommaaccent", -10.0), ("quoteright", "lslash", -10.0), ("quoteright", "quoteright", -74.0), ("quoteright", "r", -50.0), ("quoteright", "racute", -50.0), ("quoteright", "rcaron", -50.0), ("quoteright", "rcommaaccent", -50.0), ("quoteright", "s", -55.0), ("quoteright", "sacute", -55.0), ("quoteright", "scaron", -55.0), ("quoteright", "scedilla", -55.0), ("quoteright", "scommaaccent", -55.0), ("quoteright", "space", -74.0), ("quoteright", "t", -18.0), ("quoteright", "tcommaaccent", -18.0), ("quoteright", "v", -50.0), ("r", "comma", -40.0), ("r", "g", -18.0), ("r", "gbreve", -18.0), ("r", "gcommaaccent", -18.0), ("r", "hyphen", -20.0), ("r", "period", -55.0), ("racute", "comma", -40.0), ("racute", "g", -18.0), ("racute", "gbreve", -18.0), ("racute", "gcommaaccent", -18.0), ("racute", "hyphen", -20.0), ("racute", "period", -55.0), ("rcaron", "comma", -40.0), ("rcaron", "g", -18.0), ("rcaron", "gbreve", -18.0), ("rcaron", "gcommaaccent", -18.0), ("rcaron", "hyphen", -20.0), ("rcaron", "period", -55.0), ("rcommaaccent", "comma", -40.0), ("rcommaaccent", "g", -18.0), ("rcommaaccent", "gbreve", -18.0), ("rcommaaccent", "gcommaaccent", -18.0), ("rcommaaccent", "hyphen", -20.0), ("rcommaaccent", "period", -55.0), ("space", "A", -55.0), ("space", "Aacute", -55.0), ("space", "Abreve", -55.0), ("space", "Acircumflex", -55.0), ("space", "Adieresis", -55.0), ("space", "Agrave", -55.0), ("space", "Amacron", -55.0), ("space", "Aogonek", -55.0), ("space", "Aring", -55.0), ("space", "Atilde", -55.0), ("space", "T", -18.0), ("space", "Tcaron", -18.0), ("space", "Tcommaaccent", -18.0), ("space", "V", -50.0), ("space", "W", -30.0), ("space", "Y", -90.0), ("space", "Yacute", -90.0), ("space", "Ydieresis", -90.0), ("v", "a", -25.0), ("v", "aacute", -25.0), ("v", "abreve", -25.0), ("v", "acircumflex", -25.0), ("v", "adieresis", -25.0), ("v", "agrave", -25.0), ("v", "amacron", -25.0), ("v", "aogonek", -25.0), ("v", "aring", -25.0), ("v", "atilde", -25.0), ("v", "comma", -65.0), ("v", "e", -15.0), ("v", "eacute", -15.0), ("v", "ecaron", -15.0), ("v", "ecircumflex", -15.0), ("v", "edieresis", -15.0), ("v", "edotaccent", -15.0), ("v", "egrave", -15.0), ("v", "emacron", -15.0), ("v", "eogonek", -15.0), ("v", "o", -20.0), ("v", "oacute", -20.0), ("v", "ocircumflex", -20.0), ("v", "odieresis", -20.0), ("v", "ograve", -20.0), ("v", "ohungarumlaut", -20.0), ("v", "omacron", -20.0), ("v", "oslash", -20.0), ("v", "otilde", -20.0), ("v", "period", -65.0), ("w", "a", -10.0), ("w", "aacute", -10.0), ("w", "abreve", -10.0), ("w", "acircumflex", -10.0), ("w", "adieresis", -10.0), ("w", "agrave", -10.0), ("w", "amacron", -10.0), ("w", "aogonek", -10.0), ("w", "aring", -10.0), ("w", "atilde", -10.0), ("w", "comma", -65.0), ("w", "o", -10.0), ("w", "oacute", -10.0), ("w", "ocircumflex", -10.0), ("w", "odieresis", -10.0), ("w", "ograve", -10.0), ("w", "ohungarumlaut", -10.0), ("w", "omacron", -10.0), ("w", "oslash", -10.0), ("w", "otilde", -10.0), ("w", "period", -65.0), ("x", "e", -15.0), ("x", "eacute", -15.0), ("x", "ecaron", -15.0), ("x", "ecircumflex", -15.0), ("x", "edieresis", -15.0), ("x", "edotaccent", -15.0), ("x", "egrave", -15.0), ("x", "emacron", -15.0), ("x", "eogonek", -15.0), ("y", "comma", -65.0), ("y", "period", -65.0), ("yacute", "comma", -65.0), ("yacute", "period", -65.0), ("ydieresis", "comma", -65.0), ("ydieresis", "period", -65.0)]),
+("ZapfDingbats", &[]),
+]) }
+
+/// Font-wide values of an AFM file's header
+pub struct FontInfo {
+    pub family_name: &'static str,
+    pub weight: &'static str,
+    pub italic_angle: f64,
+    pub is_fixed_pitch: bool,
+    pub font_bbox: [f64; 4],
+    pub cap_height: Option<f64>,
+    pub x_height: Option<f64>,
+    pub ascender: Option<f64>,
+    pub descender: Option<f64>,
+    pub underline_position: f64,
+    pub underline_thickness: f64,
+}
+
+pub fn info() -> Box<[(&'static str, FontInfo)]> {
+Box::new([
+("Courier-Bold", FontInfo { family_name: "Courier", weight: "Bold", italic_angle: 0.0, is_fixed_pitch: true, font_bbox: [-113.0, -250.0, 749.0, 801.0], cap_height: Some(562.0), x_height: Some(439.0), ascender: Some(629.0), descender: Some(-157.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Courier-BoldOblique", FontInfo { family_name: "Courier", weight: "Bold", italic_angle: -12.0, is_fixed_pitch: true, font_bbox: [-57.0, -250.0, 869.0, 801.0], cap_height: Some(562.0), x_height: Some(439.0), ascender: Some(629.0), descender: Some(-157.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Courier-Oblique", FontInfo { family_name: "Courier", weight: "Medium", italic_angle: -12.0, is_fixed_pitch: true, font_bbox: [-27.0, -250.0, 849.0, 805.0], cap_height: Some(562.0), x_height: Some(426.0), ascender: Some(629.0), descender: Some(-157.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Courier", FontInfo { family_name: "Courier", weight: "Medium", italic_angle: 0.0, is_fixed_pitch: true, font_bbox: [-23.0, -250.0, 715.0, 805.0], cap_height: Some(562.0), x_height: Some(426.0), ascender: Some(629.0), descender: Some(-157.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Helvetica-Bold", FontInfo { family_name: "Helvetica", weight: "Bold", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-170.0, -228.0, 1003.0, 962.0], cap_height: Some(718.0), x_height: Some(532.0), ascender: Some(718.0), descender: Some(-207.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Helvetica-BoldOblique", FontInfo { family_name: "Helvetica", weight: "Bold", italic_angle: -12.0, is_fixed_pitch: false, font_bbox: [-174.0, -228.0, 1114.0, 962.0], cap_height: Some(718.0), x_height: Some(532.0), ascender: Some(718.0), descender: Some(-207.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Helvetica-Oblique", FontInfo { family_name: "Helvetica", weight: "Medium", italic_angle: -12.0, is_fixed_pitch: false, font_bbox: [-170.0, -225.0, 1116.0, 931.0], cap_height: Some(718.0), x_height: Some(523.0), ascender: Some(718.0), descender: Some(-207.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Helvetica", FontInfo { family_name: "Helvetica", weight: "Medium", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-166.0, -225.0, 1000.0, 931.0], cap_height: Some(718.0), x_height: Some(523.0), ascender: Some(718.0), descender: Some(-207.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Symbol", FontInfo { family_name: "Symbol", weight: "Medium", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-180.0, -293.0, 1090.0, 1010.0], cap_height: None, x_height: None, ascender: None, descender: None, underline_position: -100.0, underline_thickness: 50.0 }),
+("Times-Bold", FontInfo { family_name: "Times", weight: "Bold", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-168.0, -218.0, 1000.0, 935.0], cap_height: Some(676.0), x_height: Some(461.0), ascender: Some(683.0), descender: Some(-217.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Times-BoldItalic", FontInfo { family_name: "Times", weight: "Bold", italic_angle: -15.0, is_fixed_pitch: false, font_bbox: [-200.0, -218.0, 996.0, 921.0], cap_height: Some(669.0), x_height: Some(462.0), ascender: Some(683.0), descender: Some(-217.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Times-Italic", FontInfo { family_name: "Times", weight: "Medium", italic_angle: -15.5, is_fixed_pitch: false, font_bbox: [-169.0, -217.0, 1010.0, 883.0], cap_height: Some(653.0), x_height: Some(441.0), ascender: Some(683.0), descender: Some(-217.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("Times-Roman", FontInfo { family_name: "Times", weight: "Roman", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-168.0, -218.0, 1000.0, 898.0], cap_height: Some(662.0), x_height: Some(450.0), ascender: Some(683.0), descender: Some(-217.0), underline_position: -100.0, underline_thickness: 50.0 }),
+("ZapfDingbats", FontInfo { family_name: "ZapfDingbats", weight: "Medium", italic_angle: 0.0, is_fixed_pitch: false, font_bbox: [-1.0, -143.0, 981.0, 820.0], cap_height: None, x_height: None, ascender: None, descender: None, underline_position: -100.0, underline_thickness: 50.0 }),
+]) }
+
+// Glyph bounding boxes in the order of the glyphs of `metrics()`
+#[allow(clippy::type_complexity)]
+pub fn bboxes() -> Box<[(&'static str, &'static [[f64; 4]])]> {
+Box::new([
+("Courier-Bold", &[[0.0, 0.0, 0.0, 0.0], [202.0, -15.0, 398.0, 572.0], [135.0, 277.0, 465.0, 562.0], [56.0, -45.0, 544.0, 651.0], [82.0, -126.0, 519.0, 666.0], [5.0, -15.0, 595.0, 616.0], [36.0, -15.0, 546.0, 543.0], [171.0, 277.0, 423.0, 562.0], [219.0, -102.0, 461.0, 616.0], [139.0, -102.0, 381.0, 616.0], [91.0, 219.0, 509.0, 601.0], [71.0, 39.0, 529.0, 478.0], [123.0, -111.0, 393.0, 174.0], [100.0, 203.0, 500.0, 313.0], [192.0, -15.0, 408.0, 171.0], [98.0, -77.0, 502.0, 626.0], [87.0, -15.0, 513.0, 616.0], [81.0, 0.0, 539.0, 616.0], [61.0, 0.0, 499.0, 616.0], [63.0, -15.0, 501.0, 616.0], [53.0, 0.0, 507.0, 616.0], [70.0, -15.0, 521.0, 601.0], [90.0, -15.0, 521.0, 616.0], [55.0, 0.0, 494.0, 601.0], [83.0, -15.0, 517.0, 616.0], [79.0, -15.0, 510.0, 616.0], [191.0, -15.0, 407.0, 425.0], [123.0, -111.0, 408.0, 425.0], [66.0, 15.0, 523.0, 501.0], [71.0, 118.0, 529.0, 398.0], [77.0, 15.0, 534.0, 501.0], [98.0, -14.0, 501.0, 580.0], [16.0, -15.0, 584.0, 616.0], [-9.0, 0.0, 609.0, 562.0], [30.0, 0.0, 573.0, 562.0], [22.0, -18.0, 560.0, 580.0], [30.0, 0.0, 594.0, 562.0], [25.0, 0.0, 560.0, 562.0], [39.0, 0.0, 570.0, 562.0], [22.0, -18.0, 594.0, 580.0], [20.0, 0.0, 580.0, 562.0], [77.0, 0.0, 523.0, 562.0], [37.0, -18.0, 601.0, 562.0], [21.0, 0.0, 599.0, 562.0], [39.0, 0.0, 578.0, 562.0], [-2.0, 0.0, 602.0, 562.0], [8.0, -12.0, 610.0, 562.0], [22.0, -18.0, 578.0, 580.0], [48.0, 0.0, 559.0, 562.0], [32.0, -138.0, 578.0, 580.0], [24.0, 0.0, 599.0, 562.0], [47.0, -22.0, 553.0, 582.0], [21.0, 0.0, 579.0, 562.0], [4.0, -18.0, 596.0, 562.0], [-13.0, 0.0, 613.0, 562.0], [-18.0, 0.0, 618.0, 562.0], [12.0, 0.0, 588.0, 562.0], [12.0, 0.0, 589.0, 562.0], [62.0, 0.0, 539.0, 562.0], [245.0, -102.0, 475.0, 616.0], [99.0, -77.0, 503.0, 626.0], [125.0, -102.0, 355.0, 616.0], [108.0, 250.0, 492.0, 616.0], [0.0, -125.0, 600.0, -75.0], [178.0, 277.0, 428.0, 562.0], [35.0, -15.0, 570.0, 454.0], [0.0, -15.0, 584.0, 626.0], [40.0, -15.0, 545.0, 459.0], [20.0, -15.0, 591.0, 626.0], [40.0, -15.0, 563.0, 454.0], [83.0, 0.0, 547.0, 626.0], [30.0, -146.0, 580.0, 454.0], [5.0, 0.0, 592.0, 626.0], [77.0, 0.0, 523.0, 658.0], [63.0, -146.0, 440.0, 658.0], [20.0, 0.0, 585.0, 626.0], [77.0, 0.0, 523.0, 626.0], [-22.0, 0.0, 626.0, 454.0], [18.0, 0.0, 592.0, 454.0], [30.0, -15.0, 570.0, 454.0], [-1.0, -142.0, 570.0, 454.0], [20.0, -142.0, 591.0, 454.0], [47.0, 0.0, 580.0, 454.0], [68.0, -17.0, 535.0, 459.0], [47.0, -15.0, 532.0, 562.0], [-1.0, -15.0, 569.0, 439.0], [-1.0, 0.0, 601.0, 439.0], [-18.0, 0.0, 618.0, 439.0], [6.0, 0.0, 594.0, 439.0], [-4.0, -142.0, 601.0, 439.0], [81.0, 0.0, 520.0, 439.0], [160.0, -102.0, 464.0, 616.0], [255.0, -250.0, 345.0, 750.0], [136.0, -102.0, 440.0, 616.0], [71.0, 153.0, 530.0, 356.0], [202.0, -146.0, 398.0, 449.0], [66.0, -49.0, 518.0, 614.0], [72.0, -28.0, 558.0, 611.0], [25.0, -60.0, 576.0, 661.0], [10.0, 0.0, 590.0, 562.0], [-30.0, -131.0, 572.0, 616.0], [83.0, -70.0, 517.0, 580.0], [54.0, 49.0, 546.0, 517.0], [227.0, 277.0, 373.0, 562.0], [71.0, 277.0, 535.0, 562.0], [8.0, 70.0, 553.0, 446.0], [141.0, 70.0, 459.0, 446.0], [141.0, 70.0, 459.0, 446.0], [12.0, 0.0, 593.0, 626.0], [12.0, 0.0, 593.0, 626.0], [65.0, 203.0, 535.0, 313.0], [106.0, -70.0, 494.0, 580.0], [106.0, -70.0, 494.0, 580.0], [196.0, 165.0, 404.0, 351.0], [6.0, -70.0, 576.0, 580.0], [140.0, 132.0, 460.0, 430.0], [175.0, -142.0, 427.0, 143.0], [65.0, -142.0, 529.0, 143.0], [61.0, 277.0, 525.0, 562.0], [47.0, 70.0, 592.0, 446.0], [26.0, -15.0, 574.0, 116.0], [-113.0, -15.0, 713.0, 616.0], [99.0, -146.0, 502.0, 449.0], [132.0, 508.0, 395.0, 661.0], [205.0, 508.0, 468.0, 661.0], [103.0, 483.0, 497.0, 657.0], [89.0, 493.0, 512.0, 636.0], [88.0, 505.0, 512.0, 585.0], [83.0, 468.0, 517.0, 631.0], [230.0, 498.0, 370.0, 638.0], [128.0, 498.0, 472.0, 638.0], [198.0, 481.0, 402.0, 678.0], [205.0, -206.0, 387.0, 0.0], [68.0, 488.0, 588.0, 661.0], [169.0, -199.0, 400.0, 0.0], [103.0, 493.0, 497.0, 667.0], [-10.0, 203.0, 610.0, 313.0], [-29.0, 0.0, 602.0, 562.0], [147.0, 196.0, 453.0, 580.0], [39.0, 0.0, 578.0, 562.0], [22.0, -22.0, 578.0, 584.0], [-25.0, 0.0, 595.0, 562.0], [147.0, 196.0, 453.0, 580.0], [-4.0, -15.0, 601.0, 454.0], [77.0, 0.0, 523.0, 439.0], [77.0, 0.0, 523.0, 626.0], [30.0, -24.0, 570.0, 463.0], [-18.0, -15.0, 611.0, 454.0], [22.0, -15.0, 596.0, 626.0], [77.0, 0.0, 523.0, 761.0], [40.0, -15.0, 563.0, 661.0], [35.0, -15.0, 570.0, 661.0], [-1.0, -15.0, 628.0, 661.0], [40.0, -15.0, 563.0, 667.0], [12.0, 0.0, 589.0, 761.0], [71.0, 16.0, 529.0, 500.0], [12.0, 0.0, 589.0, 784.0], [-9.0, 0.0, 609.0, 780.0], [35.0, -15.0, 570.0, 661.0], [4.0, -18.0, 596.0, 780.0], [-4.0, -142.0, 601.0, 661.0], [68.0, -250.0, 535.0, 459.0], [40.0, -15.0, 563.0, 657.0], [4.0, -18.0, 596.0, 801.0], [4.0, -18.0, 596.0, 761.0], [35.0, -199.0, 586.0, 454.0], [4.0, -18.0, 596.0, 784.0], [-1.0, -199.0, 585.0, 439.0], [25.0, 0.0, 560.0, 761.0], [30.0, 0.0, 594.0, 562.0], [205.0, -250.0, 397.0, -57.0], [0.0, -18.0, 600.0, 580.0], [25.0, 0.0, 560.0, 708.0], [40.0, -15.0, 545.0, 667.0], [35.0, -15.0, 570.0, 678.0], [8.0, -250.0, 610.0, 562.0], [77.0, 0.0, 523.0, 801.0], [35.0, -15.0, 570.0, 661.0], [21.0, -250.0, 579.0, 562.0], [22.0, -18.0, 560.0, 784.0], [35.0, -15.0, 570.0, 636.0], [25.0, 0.0, 560.0, 761.0], [68.0, -17.0, 535.0, 667.0], [68.0, -206.0, 535.0, 459.0], [77.0, 0.0, 523.0, 661.0], [66.0, 0.0, 534.0, 740.0], [24.0, 0.0, 599.0, 790.0], [22.0, -250.0, 594.0, 580.0], [-1.0, -15.0, 569.0, 657.0], [35.0, -15.0, 570.0, 657.0], [-9.0, 0.0, 609.0, 708.0], [47.0, 0.0, 580.0, 667.0], [40.0, -206.0, 545.0, 459.0], [62.0, 0.0, 539.0, 761.0], [48.0, 0.0, 557.0, 562.0], [22.0, -18.0, 578.0, 708.0], [24.0, 0.0, 599.0, 784.0], [47.0, -22.0, 553.0, 784.0], [20.0, -15.0, 727.0, 626.0], [4.0, -18.0, 596.0, 708.0], [-1.0, -15.0, 569.0, 678.0], [138.0, 222.0, 433.0, 616.0], [22.0, -18.0, 578.0, 784.0], [-9.0, 0.0, 609.0, 784.0], [-9.0, 0.0, 609.0, 784.0], [81.0, 39.0, 520.0, 478.0], [-1.0, -15.0, 569.0, 661.0], [21.0, 0.0, 579.0, 790.0], [63.0, -38.0, 537.0, 728.0], [-4.0, -142.0, 601.0, 638.0], [8.0, -12.0, 610.0, 784.0], [73.0, 0.0, 523.0, 657.0], [25.0, 0.0, 560.0, 780.0], [35.0, -15.0, 570.0, 638.0], [40.0, -15.0, 563.0, 638.0], [40.0, -15.0, 545.0, 661.0], [18.0, 0.0, 592.0, 661.0], [-1.0, -15.0, 569.0, 585.0], [8.0, -12.0, 610.0, 790.0], [77.0, 0.0, 523.0, 784.0], [71.0, 24.0, 529.0, 515.0], [255.0, -175.0, 345.0, 675.0], [0.0, -18.0, 600.0, 580.0], [22.0, -18.0, 594.0, 784.0], [77.0, 0.0, 523.0, 761.0], [15.0, -10.0, 586.0, 706.0], [25.0, 0.0, 560.0, 784.0], [47.0, 0.0, 580.0, 661.0], [30.0, -15.0, 570.0, 585.0], [62.0, 0.0, 539.0, 784.0], [62.0, 0.0, 539.0, 790.0], [26.0, 0.0, 523.0, 696.0], [30.0, 0.0, 594.0, 562.0], [22.0, -206.0, 560.0, 580.0], [77.0, -250.0, 523.0, 626.0], [47.0, -15.0, 532.0, 703.0], [40.0, -199.0, 563.0, 454.0], [4.0, -199.0, 596.0, 562.0], [-9.0, 0.0, 609.0, 784.0], [-9.0, 0.0, 609.0, 761.0], [40.0, -15.0, 563.0, 661.0], [81.0, 0.0, 520.0, 661.0], [77.0, -199.0, 523.0, 658.0], [22.0, -18.0, 578.0, 784.0], [30.0, -15.0, 570.0, 661.0], [35.0, -15.0, 570.0, 585.0], [68.0, -17.0, 535.0, 661.0], [77.0, 0.0, 523.0, 618.0], [22.0, -18.0, 578.0, 780.0], [4.0, -18.0, 596.0, 784.0], [6.0, 0.0, 594.0, 688.0], [-14.0, -142.0, 570.0, 626.0], [143.0, 230.0, 436.0, 616.0], [22.0, -18.0, 578.0, 761.0], [-1.0, -142.0, 569.0, 439.0], [77.0, 0.0, 523.0, 661.0], [30.0, -15.0, 668.0, 661.0], [25.0, -199.0, 576.0, 562.0], [20.0, -15.0, 591.0, 626.0], [-47.0, -60.0, 648.0, 661.0], [47.0, -206.0, 553.0, 582.0], [77.0, 0.0, 597.0, 626.0], [21.0, -250.0, 599.0, 562.0], [39.0, 0.0, 578.0, 784.0], [-9.0, 230.0, 749.0, 562.0], [40.0, -15.0, 563.0, 638.0], [77.0, 0.0, 523.0, 784.0], [77.0, 0.0, 523.0, 708.0], [39.0, 0.0, 637.0, 562.0], [-47.0, -60.0, 648.0, 661.0], [26.0, 0.0, 523.0, 696.0], [30.0, -15.0, 570.0, 657.0], [18.0, 0.0, 592.0, 636.0], [4.0, -18.0, 638.0, 784.0], [25.0, 0.0, 560.0, 784.0], [40.0, -15.0, 563.0, 585.0], [30.0, -146.0, 580.0, 661.0], [-56.0, -60.0, 656.0, 661.0], [47.0, -22.0, 553.0, 790.0], [47.0, -250.0, 553.0, 582.0], [22.0, -18.0, 628.0, 784.0], [86.0, 243.0, 474.0, 616.0], [30.0, -15.0, 570.0, 661.0], [22.0, -18.0, 560.0, 790.0], [-1.0, -15.0, 569.0, 661.0], [-19.0, -104.0, 473.0, 778.0], [30.0, 0.0, 594.0, 790.0], [47.0, -250.0, 580.0, 454.0], [8.0, -12.0, 610.0, 759.0], [30.0, -15.0, 570.0, 636.0], [24.0, -250.0, 599.0, 562.0], [39.0, -250.0, 578.0, 562.0], [-9.0, 0.0, 609.0, 759.0], [-9.0, -199.0, 625.0, 562.0], [-9.0, 0.0, 609.0, 801.0], [22.0, -18.0, 578.0, 759.0], [81.0, 0.0, 520.0, 638.0], [25.0, 0.0, 560.0, 790.0], [77.0, -199.0, 523.0, 562.0], [20.0, -250.0, 585.0, 626.0], [71.0, 203.0, 529.0, 313.0], [77.0, 0.0, 523.0, 780.0], [18.0, 0.0, 592.0, 667.0], [47.0, -250.0, 532.0, 562.0], [71.0, 103.0, 529.0, 413.0], [30.0, -15.0, 570.0, 638.0], [-1.0, -15.0, 569.0, 638.0], [12.0, -47.0, 537.0, 563.0], [30.0, -146.0, 580.0, 714.0], [58.0, -27.0, 543.0, 626.0], [81.0, 0.0, 520.0, 667.0], [18.0, -250.0, 592.0, 454.0], [153.0, 230.0, 447.0, 616.0], [77.0, 0.0, 523.0, 585.0], [0.0, 0.0, 0.0, 0.0]]),
+("Courier-BoldOblique", &[[0.0, 0.0, 0.0, 0.0], [215.0, -15.0, 495.0, 572.0], [211.0, 277.0, 585.0, 562.0], [88.0, -45.0, 641.0, 651.0], [87.0, -126.0, 630.0, 666.0], [101.0, -15.0, 625.0, 616.0], [61.0, -15.0, 595.0, 543.0], [229.0, 277.0, 543.0, 562.0], [265.0, -102.0, 592.0, 616.0], [117.0, -102.0, 444.0, 616.0], [179.0, 219.0, 598.0, 601.0], [114.0, 39.0, 596.0, 478.0], [99.0, -111.0, 430.0, 174.0], [143.0, 203.0, 567.0, 313.0], [206.0, -15.0, 427.0, 171.0], [90.0, -77.0, 626.0, 626.0], [135.0, -15.0, 593.0, 616.0], [93.0, 0.0, 562.0, 616.0], [61.0, 0.0, 594.0, 616.0], [71.0, -15.0, 571.0, 616.0], [81.0, 0.0, 559.0, 616.0], [77.0, -15.0, 621.0, 601.0], [135.0, -15.0, 652.0, 616.0], [147.0, 0.0, 622.0, 601.0], [115.0, -15.0, 604.0, 616.0], [75.0, -15.0, 592.0, 616.0], [205.0, -15.0, 480.0, 425.0], [99.0, -111.0, 481.0, 425.0], [120.0, 15.0, 613.0, 501.0], [96.0, 118.0, 614.0, 398.0], [97.0, 15.0, 589.0, 501.0], [183.0, -14.0, 592.0, 580.0], [65.0, -15.0, 642.0, 616.0], [-9.0, 0.0, 632.0, 562.0], [30.0, 0.0, 630.0, 562.0], [74.0, -18.0, 675.0, 580.0], [30.0, 0.0, 664.0, 562.0], [25.0, 0.0, 670.0, 562.0], [39.0, 0.0, 684.0, 562.0], [74.0, -18.0, 675.0, 580.0], [20.0, 0.0, 700.0, 562.0], [77.0, 0.0, 643.0, 562.0], [58.0, -18.0, 721.0, 562.0], [21.0, 0.0, 692.0, 562.0], [39.0, 0.0, 636.0, 562.0], [-2.0, 0.0, 722.0, 562.0], [8.0, -12.0, 730.0, 562.0], [74.0, -18.0, 645.0, 580.0], [48.0, 0.0, 643.0, 562.0], [83.0, -138.0, 636.0, 580.0], [24.0, 0.0, 617.0, 562.0], [54.0, -22.0, 673.0, 582.0], [86.0, 0.0, 679.0, 562.0], [101.0, -18.0, 716.0, 562.0], [84.0, 0.0, 733.0, 562.0], [79.0, 0.0, 738.0, 562.0], [12.0, 0.0, 690.0, 562.0], [109.0, 0.0, 709.0, 562.0], [62.0, 0.0, 637.0, 562.0], [223.0, -102.0, 606.0, 616.0], [222.0, -77.0, 496.0, 626.0], [103.0, -102.0, 486.0, 616.0], [171.0, 250.0, 556.0, 616.0], [-27.0, -125.0, 585.0, -75.0], [297.0, 277.0, 487.0, 562.0], [61.0, -15.0, 593.0, 454.0], [13.0, -15.0, 636.0, 626.0], [81.0, -15.0, 631.0, 459.0], [60.0, -15.0, 645.0, 626.0], [81.0, -15.0, 605.0, 454.0], [83.0, 0.0, 677.0, 626.0], [40.0, -146.0, 674.0, 454.0], [18.0, 0.0, 615.0, 626.0], [77.0, 0.0, 546.0, 658.0], [36.0, -146.0, 580.0, 658.0], [33.0, 0.0, 643.0, 626.0], [77.0, 0.0, 546.0, 626.0], [-22.0, 0.0, 649.0, 454.0], [18.0, 0.0, 615.0, 454.0], [71.0, -15.0, 622.0, 454.0], [-32.0, -142.0, 622.0, 454.0], [60.0, -142.0, 685.0, 454.0], [47.0, 0.0, 655.0, 454.0], [66.0, -17.0, 608.0, 459.0], [118.0, -15.0, 567.0, 562.0], [70.0, -15.0, 592.0, 439.0], [70.0, 0.0, 695.0, 439.0], [53.0, 0.0, 712.0, 439.0], [6.0, 0.0, 671.0, 439.0], [-21.0, -142.0, 695.0, 439.0], [81.0, 0.0, 614.0, 439.0], [203.0, -102.0, 595.0, 616.0], [201.0, -250.0, 505.0, 750.0], [114.0, -102.0, 506.0, 616.0], [120.0, 153.0, 590.0, 356.0], [196.0, -146.0, 477.0, 449.0], [121.0, -49.0, 605.0, 614.0], [106.0, -28.0, 650.0, 611.0], [22.0, -60.0, 708.0, 661.0], [98.0, 0.0, 710.0, 562.0], [-57.0, -131.0, 702.0, 616.0], [74.0, -70.0, 620.0, 580.0], [77.0, 49.0, 644.0, 517.0], [303.0, 277.0, 493.0, 562.0], [190.0, 277.0, 594.0, 562.0], [62.0, 70.0, 639.0, 446.0], [195.0, 70.0, 545.0, 446.0], [165.0, 70.0, 514.0, 446.0], [12.0, 0.0, 644.0, 626.0], [12.0, 0.0, 644.0, 626.0], [108.0, 203.0, 602.0, 313.0], [175.0, -70.0, 586.0, 580.0], [121.0, -70.0, 587.0, 580.0], [248.0, 165.0, 461.0, 351.0], [61.0, -70.0, 700.0, 580.0], [196.0, 132.0, 523.0, 430.0], [144.0, -142.0, 458.0, 143.0], [34.0, -142.0, 560.0, 143.0], [119.0, 277.0, 645.0, 562.0], [71.0, 70.0, 647.0, 446.0], [35.0, -15.0, 587.0, 116.0], [-45.0, -15.0, 743.0, 616.0], [100.0, -146.0, 509.0, 449.0], [272.0, 508.0, 503.0, 661.0], [312.0, 508.0, 609.0, 661.0], [212.0, 483.0, 607.0, 657.0], [199.0, 493.0, 643.0, 636.0], [195.0, 505.0, 637.0, 585.0], [217.0, 468.0, 652.0, 631.0], [348.0, 498.0, 493.0, 638.0], [246.0, 498.0, 595.0, 638.0], [319.0, 481.0, 528.0, 678.0], [168.0, -206.0, 368.0, 0.0], [171.0, 488.0, 729.0, 661.0], [143.0, -199.0, 367.0, 0.0], [238.0, 493.0, 633.0, 667.0], [33.0, 203.0, 677.0, 313.0], [-29.0, 0.0, 708.0, 562.0], [188.0, 196.0, 526.0, 580.0], [39.0, 0.0, 636.0, 562.0], [48.0, -22.0, 673.0, 584.0], [26.0, 0.0, 701.0, 562.0], [188.0, 196.0, 543.0, 580.0], [21.0, -15.0, 652.0, 454.0], [77.0, 0.0, 546.0, 439.0], [77.0, 0.0, 587.0, 626.0], [54.0, -24.0, 638.0, 463.0], [18.0, -15.0, 662.0, 454.0], [22.0, -15.0, 629.0, 626.0], [77.0, 0.0, 643.0, 761.0], [81.0, -15.0, 609.0, 661.0], [61.0, -15.0, 658.0, 661.0], [70.0, -15.0, 769.0, 661.0], [81.0, -15.0, 633.0, 667.0], [109.0, 0.0, 709.0, 761.0], [114.0, 16.0, 596.0, 500.0], [109.0, 0.0, 709.0, 784.0], [-9.0, 0.0, 632.0, 780.0], [61.0, -15.0, 609.0, 661.0], [101.0, -18.0, 716.0, 780.0], [-21.0, -142.0, 695.0, 661.0], [66.0, -250.0, 608.0, 459.0], [81.0, -15.0, 607.0, 657.0], [101.0, -18.0, 716.0, 801.0], [101.0, -18.0, 716.0, 761.0], [61.0, -199.0, 593.0, 454.0], [101.0, -18.0, 716.0, 784.0], [70.0, -199.0, 592.0, 439.0], [25.0, 0.0, 670.0, 761.0], [30.0, 0.0, 664.0, 562.0], [151.0, -250.0, 385.0, -57.0], [53.0, -18.0, 667.0, 580.0], [25.0, 0.0, 670.0, 708.0], [81.0, -15.0, 633.0, 667.0], [61.0, -15.0, 593.0, 678.0], [8.0, -250.0, 730.0, 562.0], [77.0, 0.0, 639.0, 801.0], [61.0, -15.0, 593.0, 661.0], [86.0, -250.0, 679.0, 562.0], [74.0, -18.0, 675.0, 784.0], [61.0, -15.0, 643.0, 636.0], [25.0, 0.0, 670.0, 761.0], [66.0, -17.0, 633.0, 667.0], [66.0, -206.0, 608.0, 459.0], [77.0, 0.0, 609.0, 661.0], [145.0, 0.0, 614.0, 740.0], [24.0, 0.0, 659.0, 790.0], [74.0, -250.0, 675.0, 580.0], [70.0, -15.0, 597.0, 657.0], [61.0, -15.0, 607.0, 657.0], [-9.0, 0.0, 633.0, 708.0], [47.0, 0.0, 655.0, 667.0], [81.0, -206.0, 631.0, 459.0], [62.0, 0.0, 637.0, 761.0], [48.0, 0.0, 620.0, 562.0], [74.0, -18.0, 663.0, 708.0], [24.0, 0.0, 665.0, 784.0], [54.0, -22.0, 673.0, 784.0], [60.0, -15.0, 861.0, 626.0], [101.0, -18.0, 716.0, 708.0], [70.0, -15.0, 592.0, 678.0], [193.0, 222.0, 526.0, 616.0], [74.0, -18.0, 645.0, 784.0], [-9.0, 0.0, 632.0, 784.0], [-9.0, 0.0, 684.0, 784.0], [104.0, 39.0, 606.0, 478.0], [70.0, -15.0, 599.0, 661.0], [86.0, 0.0, 679.0, 790.0], [91.0, -38.0, 627.0, 728.0], [-21.0, -142.0, 695.0, 638.0], [8.0, -12.0, 730.0, 784.0], [77.0, 0.0, 577.0, 657.0], [25.0, 0.0, 670.0, 780.0], [61.0, -15.0, 595.0, 638.0], [81.0, -15.0, 605.0, 638.0], [81.0, -15.0, 649.0, 661.0], [18.0, 0.0, 639.0, 661.0], [70.0, -15.0, 637.0, 585.0], [8.0, -12.0, 730.0, 790.0], [77.0, 0.0, 643.0, 784.0], [76.0, 24.0, 614.0, 515.0], [217.0, -175.0, 489.0, 675.0], [53.0, -18.0, 667.0, 580.0], [74.0, -18.0, 684.0, 784.0], [77.0, 0.0, 643.0, 761.0], [15.0, -10.0, 672.0, 706.0], [25.0, 0.0, 670.0, 784.0], [47.0, 0.0, 655.0, 661.0], [71.0, -15.0, 637.0, 585.0], [62.0, 0.0, 665.0, 784.0], [62.0, 0.0, 659.0, 790.0], [26.0, 0.0, 627.0, 696.0], [30.0, 0.0, 664.0, 562.0], [74.0, -206.0, 675.0, 580.0], [77.0, -250.0, 546.0, 626.0], [118.0, -15.0, 627.0, 703.0], [81.0, -199.0, 605.0, 454.0], [101.0, -199.0, 716.0, 562.0], [-9.0, 0.0, 655.0, 784.0], [-9.0, 0.0, 632.0, 761.0], [81.0, -15.0, 605.0, 661.0], [81.0, 0.0, 614.0, 661.0], [77.0, -199.0, 546.0, 658.0], [74.0, -18.0, 645.0, 784.0], [71.0, -15.0, 649.0, 661.0], [61.0, -15.0, 637.0, 585.0], [66.0, -17.0, 609.0, 661.0], [77.0, 0.0, 561.0, 618.0], [74.0, -18.0, 645.0, 780.0], [101.0, -18.0, 716.0, 784.0], [6.0, 0.0, 594.0, 688.0], [-32.0, -142.0, 622.0, 626.0], [191.0, 230.0, 542.0, 616.0], [74.0, -18.0, 645.0, 761.0], [49.0, -142.0, 592.0, 439.0], [77.0, 0.0, 546.0, 661.0], [71.0, -15.0, 809.0, 661.0], [25.0, -199.0, 670.0, 562.0], [60.0, -15.0, 712.0, 626.0], [8.0, -60.0, 699.0, 661.0], [54.0, -206.0, 673.0, 582.0], [77.0, 0.0, 731.0, 626.0], [21.0, -250.0, 692.0, 562.0], [39.0, 0.0, 636.0, 784.0], [86.0, 230.0, 869.0, 562.0], [81.0, -15.0, 605.0, 638.0], [77.0, 0.0, 643.0, 784.0], [77.0, 0.0, 663.0, 708.0], [39.0, 0.0, 757.0, 562.0], [22.0, -60.0, 716.0, 661.0], [26.0, 0.0, 671.0, 696.0], [71.0, -15.0, 622.0, 657.0], [18.0, 0.0, 643.0, 636.0], [101.0, -18.0, 805.0, 784.0], [25.0, 0.0, 670.0, 784.0], [81.0, -15.0, 637.0, 585.0], [40.0, -146.0, 674.0, 661.0], [13.0, -60.0, 707.0, 661.0], [54.0, -22.0, 689.0, 790.0], [54.0, -250.0, 673.0, 582.0], [74.0, -18.0, 795.0, 784.0], [173.0, 243.0, 570.0, 616.0], [71.0, -15.0, 622.0, 661.0], [74.0, -18.0, 689.0, 790.0], [70.0, -15.0, 592.0, 661.0], [67.0, -104.0, 635.0, 778.0], [30.0, 0.0, 664.0, 790.0], [47.0, -250.0, 655.0, 454.0], [8.0, -12.0, 730.0, 759.0], [71.0, -15.0, 643.0, 636.0], [24.0, -250.0, 617.0, 562.0], [39.0, -250.0, 636.0, 562.0], [-9.0, 0.0, 669.0, 759.0], [-9.0, -199.0, 632.0, 562.0], [-9.0, 0.0, 632.0, 801.0], [74.0, -18.0, 669.0, 759.0], [81.0, 0.0, 614.0, 638.0], [25.0, 0.0, 670.0, 790.0], [77.0, -199.0, 643.0, 562.0], [33.0, -250.0, 643.0, 626.0], [114.0, 203.0, 596.0, 313.0], [77.0, 0.0, 643.0, 780.0], [18.0, 0.0, 633.0, 667.0], [118.0, -250.0, 567.0, 562.0], [135.0, 103.0, 617.0, 413.0], [71.0, -15.0, 622.0, 638.0], [70.0, -15.0, 595.0, 638.0], [30.0, -47.0, 626.0, 563.0], [40.0, -146.0, 674.0, 714.0], [93.0, -27.0, 661.0, 626.0], [81.0, 0.0, 643.0, 667.0], [18.0, -250.0, 615.0, 454.0], [212.0, 230.0, 514.0, 616.0], [77.0, 0.0, 575.0, 585.0], [0.0, 0.0, 0.0, 0.0]]),
+("Courier-Oblique", &[[0.0, 0.0, 0.0, 0.0], [243.0, -15.0, 464.0, 572.0], [273.0, 328.0, 532.0, 562.0], [133.0, -32.0, 596.0, 639.0], [108.0, -126.0, 596.0, 662.0], [134.0, -15.0, 599.0, 622.0], [87.0, -15.0, 580.0, 543.0], [283.0, 328.0, 495.0, 562.0], [313.0, -108.0, 572.0, 622.0], [137.0, -108.0, 396.0, 622.0], [212.0, 257.0, 580.0, 607.0], [129.0, 44.0, 580.0, 470.0], [157.0, -112.0, 370.0, 122.0], [152.0, 231.0, 558.0, 285.0], [238.0, -15.0, 382.0, 109.0], [112.0, -80.0, 604.0, 629.0], [154.0, -15.0, 575.0, 622.0], [98.0, 0.0, 515.0, 622.0], [70.0, 0.0, 568.0, 622.0], [82.0, -15.0, 538.0, 622.0], [108.0, 0.0, 541.0, 622.0], [99.0, -15.0, 589.0, 607.0], [155.0, -15.0, 629.0, 622.0], [182.0, 0.0, 612.0, 607.0], [132.0, -15.0, 588.0, 622.0], [93.0, -15.0, 574.0, 622.0], [238.0, -15.0, 441.0, 385.0], [157.0, -112.0, 441.0, 385.0], [96.0, 42.0, 610.0, 472.0], [109.0, 138.0, 600.0, 376.0], [85.0, 42.0, 599.0, 472.0], [222.0, -15.0, 583.0, 572.0], [127.0, -15.0, 582.0, 622.0], [3.0, 0.0, 607.0, 562.0], [43.0, 0.0, 616.0, 562.0], [93.0, -18.0, 655.0, 580.0], [43.0, 0.0, 645.0, 562.0], [53.0, 0.0, 660.0, 562.0], [53.0, 0.0, 660.0, 562.0], [83.0, -18.0, 645.0, 580.0], [32.0, 0.0, 687.0, 562.0], [96.0, 0.0, 623.0, 562.0], [52.0, -18.0, 685.0, 562.0], [38.0, 0.0, 671.0, 562.0], [47.0, 0.0, 607.0, 562.0], [4.0, 0.0, 715.0, 562.0], [7.0, -13.0, 712.0, 562.0], [94.0, -18.0, 625.0, 580.0], [79.0, 0.0, 644.0, 562.0], [95.0, -138.0, 625.0, 580.0], [38.0, 0.0, 598.0, 562.0], [76.0, -20.0, 650.0, 580.0], [108.0, 0.0, 665.0, 562.0], [125.0, -18.0, 702.0, 562.0], [105.0, -13.0, 723.0, 562.0], [106.0, -13.0, 722.0, 562.0], [23.0, 0.0, 675.0, 562.0], [133.0, 0.0, 695.0, 562.0], [86.0, 0.0, 610.0, 562.0], [246.0, -108.0, 574.0, 622.0], [249.0, -80.0, 468.0, 629.0], [135.0, -108.0, 463.0, 622.0], [175.0, 354.0, 587.0, 622.0], [-27.0, -125.0, 584.0, -75.0], [343.0, 328.0, 457.0, 562.0], [76.0, -15.0, 569.0, 441.0], [29.0, -15.0, 625.0, 629.0], [106.0, -15.0, 608.0, 441.0], [85.0, -15.0, 640.0, 629.0], [106.0, -15.0, 598.0, 441.0], [114.0, 0.0, 662.0, 629.0], [61.0, -157.0, 657.0, 441.0], [33.0, 0.0, 592.0, 629.0], [95.0, 0.0, 515.0, 657.0], [52.0, -157.0, 550.0, 657.0], [58.0, 0.0, 633.0, 629.0], [95.0, 0.0, 515.0, 629.0], [-5.0, 0.0, 615.0, 441.0], [26.0, 0.0, 585.0, 441.0], [102.0, -15.0, 588.0, 441.0], [-24.0, -157.0, 605.0, 441.0], [85.0, -157.0, 682.0, 441.0], [60.0, 0.0, 636.0, 441.0], [78.0, -15.0, 584.0, 441.0], [167.0, -15.0, 561.0, 561.0], [101.0, -15.0, 572.0, 426.0], [90.0, -10.0, 681.0, 426.0], [76.0, -10.0, 695.0, 426.0], [20.0, 0.0, 655.0, 426.0], [-4.0, -157.0, 683.0, 426.0], [99.0, 0.0, 593.0, 426.0], [233.0, -108.0, 569.0, 622.0], [222.0, -250.0, 485.0, 750.0], [140.0, -108.0, 477.0, 622.0], [116.0, 197.0, 600.0, 320.0], [225.0, -157.0, 445.0, 430.0], [151.0, -49.0, 588.0, 614.0], [124.0, -21.0, 621.0, 611.0], [84.0, -57.0, 646.0, 665.0], [120.0, 0.0, 693.0, 562.0], [-26.0, -143.0, 671.0, 622.0], [104.0, -78.0, 590.0, 580.0], [94.0, 58.0, 628.0, 506.0], [345.0, 328.0, 460.0, 562.0], [262.0, 328.0, 541.0, 562.0], [92.0, 70.0, 652.0, 446.0], [204.0, 70.0, 540.0, 446.0], [170.0, 70.0, 506.0, 446.0], [3.0, 0.0, 619.0, 629.0], [3.0, 0.0, 619.0, 629.0], [124.0, 231.0, 586.0, 285.0], [217.0, -78.0, 546.0, 580.0], [163.0, -78.0, 546.0, 580.0], [275.0, 189.0, 434.0, 327.0], [100.0, -78.0, 630.0, 562.0], [224.0, 130.0, 485.0, 383.0], [185.0, -134.0, 397.0, 100.0], [115.0, -134.0, 478.0, 100.0], [213.0, 328.0, 576.0, 562.0], [58.0, 70.0, 618.0, 446.0], [46.0, -15.0, 575.0, 111.0], [59.0, -15.0, 627.0, 622.0], [105.0, -157.0, 466.0, 430.0], [294.0, 497.0, 484.0, 672.0], [348.0, 497.0, 612.0, 672.0], [229.0, 477.0, 581.0, 654.0], [212.0, 489.0, 629.0, 606.0], [232.0, 525.0, 600.0, 565.0], [279.0, 501.0, 576.0, 609.0], [373.0, 537.0, 478.0, 640.0], [272.0, 537.0, 579.0, 640.0], [332.0, 463.0, 500.0, 627.0], [197.0, -151.0, 344.0, 10.0], [239.0, 497.0, 683.0, 672.0], [189.0, -172.0, 377.0, 4.0], [262.0, 492.0, 614.0, 669.0], [49.0, 231.0, 661.0, 285.0], [3.0, 0.0, 655.0, 562.0], [209.0, 249.0, 512.0, 580.0], [47.0, 0.0, 607.0, 562.0], [94.0, -80.0, 625.0, 629.0], [59.0, 0.0, 672.0, 562.0], [210.0, 249.0, 535.0, 580.0], [41.0, -15.0, 626.0, 441.0], [95.0, 0.0, 515.0, 426.0], [95.0, 0.0, 587.0, 629.0], [102.0, -80.0, 588.0, 506.0], [54.0, -15.0, 615.0, 441.0], [48.0, -15.0, 617.0, 629.0], [96.0, 0.0, 623.0, 753.0], [106.0, -15.0, 612.0, 672.0], [76.0, -15.0, 576.0, 609.0], [101.0, -15.0, 723.0, 672.0], [106.0, -15.0, 614.0, 669.0], [133.0, 0.0, 695.0, 753.0], [136.0, 48.0, 573.0, 467.0], [133.0, 0.0, 695.0, 805.0], [3.0, 0.0, 607.0, 787.0], [76.0, -15.0, 612.0, 672.0], [125.0, -18.0, 702.0, 787.0], [-4.0, -157.0, 683.0, 672.0], [78.0, -250.0, 584.0, 441.0], [106.0, -15.0, 598.0, 654.0], [125.0, -18.0, 702.0, 760.0], [125.0, -18.0, 702.0, 753.0], [76.0, -172.0, 569.0, 441.0], [125.0, -18.0, 702.0, 805.0], [101.0, -172.0, 572.0, 426.0], [53.0, 0.0, 660.0, 753.0], [43.0, 0.0, 645.0, 562.0], [145.0, -250.0, 323.0, -58.0], [53.0, -18.0, 667.0, 580.0], [53.0, 0.0, 660.0, 698.0], [106.0, -15.0, 614.0, 669.0], [76.0, -15.0, 569.0, 627.0], [7.0, -250.0, 712.0, 562.0], [95.0, 0.0, 640.0, 805.0], [76.0, -15.0, 569.0, 672.0], [108.0, -250.0, 665.0, 562.0], [93.0, -18.0, 655.0, 805.0], [76.0, -15.0, 629.0, 606.0], [53.0, 0.0, 660.0, 753.0], [78.0, -15.0, 614.0, 669.0], [78.0, -151.0, 584.0, 441.0], [95.0, 0.0, 612.0, 672.0], [94.0, 0.0, 519.0, 706.0], [38.0, 0.0, 642.0, 802.0], [83.0, -250.0, 645.0, 580.0], [101.0, -15.0, 572.0, 654.0], [76.0, -15.0, 581.0, 654.0], [3.0, 0.0, 607.0, 698.0], [60.0, 0.0, 636.0, 669.0], [106.0, -151.0, 614.0, 441.0], [86.0, 0.0, 610.0, 753.0], [79.0, 0.0, 606.0, 562.0], [94.0, -18.0, 628.0, 698.0], [38.0, 0.0, 670.0, 805.0], [76.0, -20.0, 650.0, 805.0], [85.0, -15.0, 849.0, 629.0], [125.0, -18.0, 702.0, 698.0], [101.0, -15.0, 572.0, 627.0], [213.0, 240.0, 501.0, 622.0], [94.0, -18.0, 625.0, 805.0], [3.0, 0.0, 607.0, 805.0], [3.0, 0.0, 607.0, 732.0], [103.0, 43.0, 607.0, 470.0], [101.0, -15.0, 602.0, 672.0], [108.0, 0.0, 665.0, 802.0], [45.0, -38.0, 546.0, 710.0], [-4.0, -157.0, 683.0, 620.0], [7.0, -13.0, 712.0, 805.0], [95.0, 0.0, 551.0, 654.0], [53.0, 0.0, 660.0, 787.0], [76.0, -15.0, 575.0, 620.0], [106.0, -15.0, 598.0, 620.0], [106.0, -15.0, 612.0, 672.0], [26.0, 0.0, 602.0, 672.0], [101.0, -15.0, 600.0, 565.0], [7.0, -13.0, 712.0, 802.0], [96.0, 0.0, 640.0, 805.0], [96.0, 44.0, 594.0, 558.0], [238.0, -175.0, 469.0, 675.0], [53.0, -18.0, 667.0, 580.0], [83.0, -18.0, 645.0, 732.0], [96.0, 0.0, 623.0, 753.0], [15.0, -10.0, 670.0, 706.0], [53.0, 0.0, 660.0, 805.0], [60.0, 0.0, 636.0, 672.0], [102.0, -15.0, 600.0, 565.0], [86.0, 0.0, 670.0, 805.0], [86.0, 0.0, 642.0, 802.0], [98.0, 0.0, 594.0, 710.0], [43.0, 0.0, 645.0, 562.0], [93.0, -151.0, 658.0, 580.0], [95.0, -250.0, 515.0, 629.0], [167.0, -15.0, 587.0, 717.0], [106.0, -172.0, 598.0, 441.0], [124.0, -172.0, 702.0, 562.0], [3.0, 0.0, 660.0, 805.0], [3.0, 0.0, 607.0, 753.0], [106.0, -15.0, 598.0, 672.0], [99.0, 0.0, 612.0, 672.0], [95.0, -172.0, 515.0, 657.0], [94.0, -18.0, 640.0, 805.0], [102.0, -15.0, 612.0, 672.0], [76.0, -15.0, 600.0, 565.0], [78.0, -15.0, 612.0, 672.0], [95.0, 0.0, 545.0, 620.0], [94.0, -18.0, 625.0, 787.0], [125.0, -18.0, 702.0, 805.0], [6.0, 0.0, 598.0, 688.0], [-24.0, -157.0, 605.0, 629.0], [230.0, 249.0, 535.0, 622.0], [94.0, -18.0, 625.0, 753.0], [72.0, -157.0, 572.0, 426.0], [95.0, 0.0, 515.0, 672.0], [102.0, -15.0, 723.0, 672.0], [53.0, -172.0, 660.0, 562.0], [85.0, -15.0, 704.0, 629.0], [73.0, -56.0, 659.0, 666.0], [76.0, -151.0, 650.0, 580.0], [95.0, 0.0, 667.0, 629.0], [38.0, -250.0, 671.0, 562.0], [47.0, 0.0, 607.0, 805.0], [75.0, 263.0, 742.0, 562.0], [106.0, -15.0, 598.0, 620.0], [96.0, 0.0, 623.0, 805.0], [96.0, 0.0, 628.0, 698.0], [47.0, 0.0, 632.0, 562.0], [65.0, -57.0, 669.0, 665.0], [98.0, 0.0, 645.0, 710.0], [102.0, -15.0, 588.0, 654.0], [26.0, 0.0, 629.0, 606.0], [125.0, -18.0, 761.0, 805.0], [53.0, 0.0, 670.0, 805.0], [106.0, -15.0, 600.0, 565.0], [61.0, -157.0, 657.0, 609.0], [65.0, -57.0, 674.0, 665.0], [76.0, -20.0, 672.0, 802.0], [76.0, -250.0, 650.0, 580.0], [94.0, -18.0, 751.0, 805.0], [214.0, 269.0, 576.0, 622.0], [102.0, -15.0, 588.0, 672.0], [93.0, -18.0, 672.0, 802.0], [101.0, -15.0, 572.0, 672.0], [85.0, -15.0, 765.0, 792.0], [43.0, 0.0, 645.0, 802.0], [60.0, -250.0, 636.0, 441.0], [7.0, -13.0, 712.0, 729.0], [102.0, -15.0, 629.0, 606.0], [38.0, -250.0, 598.0, 562.0], [47.0, -250.0, 607.0, 562.0], [3.0, 0.0, 655.0, 729.0], [3.0, -172.0, 607.0, 562.0], [3.0, 0.0, 607.0, 750.0], [94.0, -18.0, 655.0, 729.0], [99.0, 0.0, 593.0, 620.0], [53.0, 0.0, 660.0, 802.0], [96.0, -172.0, 623.0, 562.0], [58.0, -250.0, 633.0, 629.0], [129.0, 232.0, 580.0, 283.0], [96.0, 0.0, 623.0, 787.0], [26.0, 0.0, 614.0, 669.0], [165.0, -250.0, 561.0, 561.0], [155.0, 108.0, 591.0, 369.0], [102.0, -15.0, 588.0, 620.0], [101.0, -15.0, 575.0, 620.0], [43.0, -16.0, 621.0, 529.0], [61.0, -157.0, 657.0, 708.0], [102.0, -15.0, 639.0, 629.0], [99.0, 0.0, 624.0, 669.0], [26.0, -250.0, 585.0, 441.0], [231.0, 249.0, 491.0, 622.0], [95.0, 0.0, 543.0, 565.0], [0.0, 0.0, 0.0, 0.0]]),
+("Courier", &[[0.0, 0.0, 0.0, 0.0], [236.0, -15.0, 364.0, 572.0], [187.0, 328.0, 413.0, 562.0], [93.0, -32.0, 507.0, 639.0], [105.0, -126.0, 496.0, 662.0], [81.0, -15.0, 518.0, 622.0], [63.0, -15.0, 538.0, 543.0], [213.0, 328.0, 376.0, 562.0], [269.0, -108.0, 440.0, 622.0], [160.0, -108.0, 331.0, 622.0], [116.0, 257.0, 484.0, 607.0], [80.0, 44.0, 520.0, 470.0], [181.0, -112.0, 344.0, 122.0], [103.0, 231.0, 497.0, 285.0], [229.0, -15.0, 371.0, 109.0], [125.0, -80.0, 475.0, 629.0], [106.0, -15.0, 494.0, 622.0], [96.0, 0.0, 505.0, 622.0], [70.0, 0.0, 471.0, 622.0], [75.0, -15.0, 466.0, 622.0], [78.0, 0.0, 500.0, 622.0], [92.0, -15.0, 497.0, 607.0], [111.0, -15.0, 497.0, 622.0], [82.0, 0.0, 483.0, 607.0], [102.0, -15.0, 498.0, 622.0], [96.0, -15.0, 489.0, 622.0], [229.0, -15.0, 371.0, 385.0], [181.0, -112.0, 371.0, 385.0], [41.0, 42.0, 519.0, 472.0], [80.0, 138.0, 520.0, 376.0], [66.0, 42.0, 544.0, 472.0], [129.0, -15.0, 492.0, 572.0], [77.0, -15.0, 533.0, 622.0], [3.0, 0.0, 597.0, 562.0], [43.0, 0.0, 559.0, 562.0], [41.0, -18.0, 540.0, 580.0], [43.0, 0.0, 574.0, 562.0], [53.0, 0.0, 550.0, 562.0], [53.0, 0.0, 545.0, 562.0], [31.0, -18.0, 575.0, 580.0], [32.0, 0.0, 568.0, 562.0], [96.0, 0.0, 504.0, 562.0], [34.0, -18.0, 566.0, 562.0], [38.0, 0.0, 582.0, 562.0], [47.0, 0.0, 554.0, 562.0], [4.0, 0.0, 596.0, 562.0], [7.0, -13.0, 593.0, 562.0], [43.0, -18.0, 557.0, 580.0], [79.0, 0.0, 558.0, 562.0], [43.0, -138.0, 557.0, 580.0], [38.0, 0.0, 588.0, 562.0], [72.0, -20.0, 529.0, 580.0], [38.0, 0.0, 563.0, 562.0], [17.0, -18.0, 583.0, 562.0], [-4.0, -13.0, 604.0, 562.0], [-3.0, -13.0, 603.0, 562.0], [23.0, 0.0, 577.0, 562.0], [24.0, 0.0, 576.0, 562.0], [86.0, 0.0, 514.0, 562.0], [269.0, -108.0, 442.0, 622.0], [118.0, -80.0, 482.0, 629.0], [158.0, -108.0, 331.0, 622.0], [94.0, 354.0, 506.0, 622.0], [0.0, -125.0, 600.0, -75.0], [224.0, 328.0, 387.0, 562.0], [53.0, -15.0, 559.0, 441.0], [14.0, -15.0, 575.0, 629.0], [66.0, -15.0, 529.0, 441.0], [45.0, -15.0, 591.0, 629.0], [66.0, -15.0, 548.0, 441.0], [114.0, 0.0, 531.0, 629.0], [45.0, -157.0, 566.0, 441.0], [18.0, 0.0, 582.0, 629.0], [95.0, 0.0, 505.0, 657.0], [82.0, -157.0, 410.0, 657.0], [43.0, 0.0, 580.0, 629.0], [95.0, 0.0, 505.0, 629.0], [-5.0, 0.0, 605.0, 441.0], [26.0, 0.0, 575.0, 441.0], [62.0, -15.0, 538.0, 441.0], [9.0, -157.0, 555.0, 441.0], [45.0, -157.0, 591.0, 441.0], [60.0, 0.0, 559.0, 441.0], [80.0, -15.0, 513.0, 441.0], [87.0, -15.0, 530.0, 561.0], [21.0, -15.0, 562.0, 426.0], [10.0, -10.0, 590.0, 426.0], [-4.0, -10.0, 604.0, 426.0], [20.0, 0.0, 580.0, 426.0], [7.0, -157.0, 592.0, 426.0], [99.0, 0.0, 502.0, 426.0], [182.0, -108.0, 437.0, 622.0], [275.0, -250.0, 326.0, 750.0], [163.0, -108.0, 418.0, 622.0], [63.0, 197.0, 540.0, 320.0], [236.0, -157.0, 364.0, 430.0], [96.0, -49.0, 500.0, 614.0], [84.0, -21.0, 521.0, 611.0], [92.0, -57.0, 509.0, 665.0], [26.0, 0.0, 574.0, 562.0], [4.0, -143.0, 539.0, 622.0], [113.0, -78.0, 488.0, 580.0], [73.0, 58.0, 527.0, 506.0], [259.0, 328.0, 341.0, 562.0], [143.0, 328.0, 471.0, 562.0], [37.0, 70.0, 563.0, 446.0], [149.0, 70.0, 451.0, 446.0], [149.0, 70.0, 451.0, 446.0], [3.0, 0.0, 597.0, 629.0], [3.0, 0.0, 597.0, 629.0], [75.0, 231.0, 525.0, 285.0], [141.0, -78.0, 459.0, 580.0], [141.0, -78.0, 459.0, 580.0], [222.0, 189.0, 378.0, 327.0], [50.0, -78.0, 511.0, 562.0], [172.0, 130.0, 428.0, 383.0], [213.0, -134.0, 376.0, 100.0], [143.0, -134.0, 457.0, 100.0], [143.0, 328.0, 457.0, 562.0], [37.0, 70.0, 563.0, 446.0], [37.0, -15.0, 563.0, 111.0], [3.0, -15.0, 600.0, 622.0], [108.0, -157.0, 471.0, 430.0], [151.0, 497.0, 378.0, 672.0], [242.0, 497.0, 469.0, 672.0], [124.0, 477.0, 476.0, 654.0], [105.0, 489.0, 503.0, 606.0], [120.0, 525.0, 480.0, 565.0], [153.0, 501.0, 447.0, 609.0], [249.0, 537.0, 352.0, 640.0], [148.0, 537.0, 453.0, 640.0], [218.0, 463.0, 382.0, 627.0], [224.0, -151.0, 362.0, 10.0], [133.0, 497.0, 540.0, 672.0], [211.0, -172.0, 407.0, 4.0], [124.0, 492.0, 476.0, 669.0], [0.0, 231.0, 600.0, 285.0], [3.0, 0.0, 550.0, 562.0], [156.0, 249.0, 442.0, 580.0], [47.0, 0.0, 554.0, 562.0], [43.0, -80.0, 557.0, 629.0], [7.0, 0.0, 567.0, 562.0], [157.0, 249.0, 443.0, 580.0], [19.0, -15.0, 570.0, 441.0], [95.0, 0.0, 505.0, 426.0], [95.0, 0.0, 505.0, 629.0], [62.0, -80.0, 538.0, 506.0], [19.0, -15.0, 559.0, 441.0], [48.0, -15.0, 588.0, 629.0], [96.0, 0.0, 504.0, 753.0], [66.0, -15.0, 548.0, 672.0], [53.0, -15.0, 559.0, 609.0], [21.0, -15.0, 580.0, 672.0], [66.0, -15.0, 548.0, 669.0], [24.0, 0.0, 576.0, 753.0], [87.0, 48.0, 513.0, 467.0], [24.0, 0.0, 576.0, 805.0], [3.0, 0.0, 597.0, 787.0], [53.0, -15.0, 559.0, 672.0], [17.0, -18.0, 583.0, 787.0], [7.0, -157.0, 592.0, 672.0], [80.0, -250.0, 513.0, 441.0], [66.0, -15.0, 548.0, 654.0], [17.0, -18.0, 583.0, 760.0], [17.0, -18.0, 583.0, 753.0], [53.0, -172.0, 587.0, 441.0], [17.0, -18.0, 583.0, 805.0], [21.0, -172.0, 590.0, 426.0], [53.0, 0.0, 550.0, 753.0], [30.0, 0.0, 574.0, 562.0], [198.0, -250.0, 335.0, -58.0], [0.0, -18.0, 600.0, 580.0], [53.0, 0.0, 550.0, 698.0], [66.0, -15.0, 529.0, 669.0], [53.0, -15.0, 559.0, 627.0], [7.0, -250.0, 593.0, 562.0], [95.0, 0.0, 505.0, 805.0], [53.0, -15.0, 559.0, 672.0], [38.0, -250.0, 563.0, 562.0], [41.0, -18.0, 540.0, 805.0], [53.0, -15.0, 559.0, 606.0], [53.0, 0.0, 550.0, 753.0], [80.0, -15.0, 513.0, 669.0], [80.0, -151.0, 513.0, 441.0], [95.0, 0.0, 505.0, 672.0], [18.0, 0.0, 443.0, 706.0], [38.0, 0.0, 588.0, 802.0], [31.0, -250.0, 575.0, 580.0], [21.0, -15.0, 562.0, 654.0], [53.0, -15.0, 559.0, 654.0], [3.0, 0.0, 597.0, 698.0], [60.0, 0.0, 559.0, 669.0], [66.0, -151.0, 529.0, 441.0], [86.0, 0.0, 514.0, 753.0], [79.0, 0.0, 538.0, 562.0], [43.0, -18.0, 557.0, 698.0], [38.0, 0.0, 588.0, 805.0], [72.0, -20.0, 529.0, 805.0], [45.0, -15.0, 715.0, 629.0], [17.0, -18.0, 583.0, 698.0], [21.0, -15.0, 562.0, 627.0], [155.0, 240.0, 406.0, 622.0], [43.0, -18.0, 557.0, 805.0], [3.0, 0.0, 597.0, 805.0], [3.0, 0.0, 597.0, 732.0], [87.0, 43.0, 515.0, 470.0], [21.0, -15.0, 562.0, 672.0], [38.0, 0.0, 563.0, 802.0], [17.0, -38.0, 459.0, 710.0], [7.0, -157.0, 592.0, 620.0], [7.0, -13.0, 593.0, 805.0], [94.0, 0.0, 505.0, 654.0], [53.0, 0.0, 550.0, 787.0], [53.0, -15.0, 559.0, 620.0], [66.0, -15.0, 548.0, 620.0], [66.0, -15.0, 529.0, 672.0], [26.0, 0.0, 575.0, 672.0], [21.0, -15.0, 562.0, 565.0], [7.0, -13.0, 593.0, 802.0], [96.0, 0.0, 504.0, 805.0], [87.0, 44.0, 513.0, 558.0], [275.0, -175.0, 326.0, 675.0], [0.0, -18.0, 600.0, 580.0], [31.0, -18.0, 575.0, 732.0], [96.0, 0.0, 504.0, 753.0], [15.0, -10.0, 585.0, 706.0], [53.0, 0.0, 550.0, 805.0], [60.0, 0.0, 559.0, 672.0], [62.0, -15.0, 538.0, 565.0], [86.0, 0.0, 514.0, 805.0], [86.0, 0.0, 514.0, 802.0], [98.0, 0.0, 502.0, 710.0], [30.0, 0.0, 574.0, 562.0], [41.0, -151.0, 540.0, 580.0], [95.0, -250.0, 505.0, 629.0], [87.0, -15.0, 530.0, 717.0], [66.0, -172.0, 548.0, 441.0], [17.0, -172.0, 583.0, 562.0], [3.0, 0.0, 597.0, 805.0], [3.0, 0.0, 597.0, 753.0], [66.0, -15.0, 548.0, 672.0], [99.0, 0.0, 502.0, 672.0], [95.0, -172.0, 505.0, 657.0], [43.0, -18.0, 557.0, 805.0], [62.0, -15.0, 538.0, 672.0], [53.0, -15.0, 559.0, 565.0], [80.0, -15.0, 513.0, 672.0], [95.0, 0.0, 505.0, 620.0], [43.0, -18.0, 557.0, 787.0], [17.0, -18.0, 583.0, 805.0], [6.0, 0.0, 598.0, 688.0], [-6.0, -157.0, 555.0, 629.0], [177.0, 249.0, 424.0, 622.0], [43.0, -18.0, 557.0, 753.0], [21.0, -157.0, 562.0, 426.0], [95.0, 0.0, 505.0, 672.0], [62.0, -15.0, 580.0, 672.0], [53.0, -172.0, 561.0, 562.0], [45.0, -15.0, 591.0, 629.0], [8.0, -56.0, 593.0, 666.0], [72.0, -151.0, 529.0, 580.0], [95.0, 0.0, 533.0, 629.0], [38.0, -250.0, 582.0, 562.0], [47.0, 0.0, 554.0, 805.0], [-23.0, 263.0, 623.0, 562.0], [66.0, -15.0, 548.0, 620.0], [96.0, 0.0, 504.0, 805.0], [96.0, 0.0, 504.0, 698.0], [47.0, 0.0, 554.0, 562.0], [0.0, -57.0, 611.0, 665.0], [98.0, 0.0, 502.0, 710.0], [62.0, -15.0, 538.0, 654.0], [26.0, 0.0, 575.0, 606.0], [17.0, -18.0, 590.0, 805.0], [53.0, 0.0, 550.0, 805.0], [66.0, -15.0, 548.0, 565.0], [45.0, -157.0, 566.0, 609.0], [0.0, -57.0, 600.0, 665.0], [72.0, -20.0, 529.0, 802.0], [72.0, -250.0, 529.0, 580.0], [43.0, -18.0, 580.0, 805.0], [123.0, 269.0, 477.0, 622.0], [62.0, -15.0, 538.0, 672.0], [41.0, -18.0, 540.0, 802.0], [21.0, -15.0, 562.0, 672.0], [3.0, -15.0, 597.0, 792.0], [43.0, 0.0, 574.0, 802.0], [60.0, -250.0, 559.0, 441.0], [7.0, -13.0, 593.0, 729.0], [62.0, -15.0, 538.0, 606.0], [38.0, -250.0, 588.0, 562.0], [47.0, -250.0, 554.0, 562.0], [3.0, 0.0, 597.0, 729.0], [3.0, -172.0, 608.0, 562.0], [3.0, 0.0, 597.0, 750.0], [43.0, -18.0, 557.0, 729.0], [99.0, 0.0, 502.0, 620.0], [53.0, 0.0, 550.0, 802.0], [96.0, -172.0, 504.0, 562.0], [43.0, -250.0, 580.0, 629.0], [80.0, 232.0, 520.0, 283.0], [96.0, 0.0, 504.0, 787.0], [26.0, 0.0, 575.0, 669.0], [87.0, -250.0, 530.0, 561.0], [87.0, 108.0, 513.0, 369.0], [62.0, -15.0, 538.0, 620.0], [21.0, -15.0, 562.0, 620.0], [15.0, -16.0, 540.0, 529.0], [45.0, -157.0, 566.0, 708.0], [62.0, -15.0, 538.0, 629.0], [99.0, 0.0, 502.0, 669.0], [26.0, -250.0, 575.0, 441.0], [172.0, 249.0, 428.0, 622.0], [95.0, 0.0, 505.0, 565.0], [0.0, 0.0, 0.0, 0.0]]),
+("Helvetica-Bold", &[[0.0, 0.0, 0.0, 0.0], [90.0, 0.0, 244.0, 718.0], [98.0, 447.0, 376.0, 718.0], [18.0, 0.0, 538.0, 698.0], [30.0, -115.0, 523.0, 775.0], [28.0, -19.0, 861.0, 710.0], [54.0, -19.0, 701.0, 718.0], [69.0, 445.0, 209.0, 718.0], [35.0, -208.0, 314.0, 734.0], [19.0, -208.0, 298.0, 734.0], [27.0, 387.0, 362.0, 718.0], [40.0, 0.0, 544.0, 506.0], [64.0, -168.0, 214.0, 146.0], [27.0, 215.0, 306.0, 345.0], [64.0, 0.0, 214.0, 146.0], [-33.0, -19.0, 311.0, 737.0], [32.0, -19.0, 524.0, 710.0], [69.0, 0.0, 378.0, 710.0], [26.0, 0.0, 511.0, 710.0], [27.0, -19.0, 516.0, 710.0], [27.0, 0.0, 526.0, 710.0], [27.0, -19.0, 516.0, 698.0], [31.0, -19.0, 520.0, 710.0], [25.0, 0.0, 528.0, 698.0], [32.0, -19.0, 524.0, 710.0], [30.0, -19.0, 522.0, 710.0], [92.0, 0.0, 242.0, 512.0], [92.0, -168.0, 242.0, 512.0], [38.0, -8.0, 546.0, 514.0], [40.0, 87.0, 544.0, 419.0], [38.0, -8.0, 546.0, 514.0], [60.0, 0.0, 556.0, 727.0], [118.0, -19.0, 856.0, 737.0], [20.0, 0.0, 702.0, 718.0], [76.0, 0.0, 669.0, 718.0], [44.0, -19.0, 684.0, 737.0], [76.0, 0.0, 685.0, 718.0], [76.0, 0.0, 621.0, 718.0], [76.0, 0.0, 587.0, 718.0], [44.0, -19.0, 713.0, 737.0], [71.0, 0.0, 651.0, 718.0], [64.0, 0.0, 214.0, 718.0], [22.0, -18.0, 484.0, 718.0], [87.0, 0.0, 722.0, 718.0], [76.0, 0.0, 583.0, 718.0], [69.0, 0.0, 765.0, 718.0], [69.0, 0.0, 654.0, 718.0], [44.0, -19.0, 734.0, 737.0], [76.0, 0.0, 627.0, 718.0], [44.0, -52.0, 737.0, 737.0], [76.0, 0.0, 677.0, 718.0], [39.0, -19.0, 629.0, 737.0], [14.0, 0.0, 598.0, 718.0], [72.0, -19.0, 651.0, 718.0], [19.0, 0.0, 648.0, 718.0], [16.0, 0.0, 929.0, 718.0], [14.0, 0.0, 653.0, 718.0], [15.0, 0.0, 653.0, 718.0], [25.0, 0.0, 586.0, 718.0], [63.0, -196.0, 309.0, 722.0], [-33.0, -19.0, 311.0, 737.0], [24.0, -196.0, 270.0, 722.0], [62.0, 323.0, 522.0, 698.0], [0.0, -125.0, 556.0, -75.0], [69.0, 454.0, 209.0, 727.0], [29.0, -14.0, 527.0, 546.0], [61.0, -14.0, 578.0, 718.0], [34.0, -14.0, 524.0, 546.0], [34.0, -14.0, 551.0, 718.0], [23.0, -14.0, 528.0, 546.0], [10.0, 0.0, 318.0, 727.0], [40.0, -217.0, 553.0, 546.0], [65.0, 0.0, 546.0, 718.0], [69.0, 0.0, 209.0, 725.0], [3.0, -214.0, 209.0, 725.0], [69.0, 0.0, 562.0, 718.0], [69.0, 0.0, 209.0, 718.0], [64.0, 0.0, 826.0, 546.0], [65.0, 0.0, 546.0, 546.0], [34.0, -14.0, 578.0, 546.0], [62.0, -207.0, 578.0, 546.0], [34.0, -207.0, 552.0, 546.0], [64.0, 0.0, 373.0, 546.0], [30.0, -14.0, 519.0, 546.0], [10.0, -6.0, 309.0, 676.0], [66.0, -14.0, 545.0, 532.0], [13.0, 0.0, 543.0, 532.0], [10.0, 0.0, 769.0, 532.0], [15.0, 0.0, 541.0, 532.0], [10.0, -214.0, 539.0, 532.0], [20.0, 0.0, 480.0, 532.0], [48.0, -196.0, 365.0, 722.0], [84.0, -225.0, 196.0, 775.0], [24.0, -196.0, 341.0, 722.0], [61.0, 163.0, 523.0, 343.0], [90.0, -186.0, 244.0, 532.0], [34.0, -118.0, 524.0, 628.0], [28.0, -16.0, 541.0, 718.0], [-170.0, -19.0, 336.0, 710.0], [-9.0, 0.0, 565.0, 698.0], [-10.0, -210.0, 516.0, 737.0], [34.0, -184.0, 522.0, 727.0], [-3.0, 76.0, 559.0, 636.0], [70.0, 447.0, 168.0, 718.0], [64.0, 454.0, 436.0, 727.0], [88.0, 76.0, 468.0, 484.0], [83.0, 76.0, 250.0, 484.0], [83.0, 76.0, 250.0, 484.0], [10.0, 0.0, 542.0, 727.0], [10.0, 0.0, 542.0, 727.0], [0.0, 227.0, 556.0, 333.0], [36.0, -171.0, 520.0, 718.0], [36.0, -171.0, 520.0, 718.0], [58.0, 172.0, 220.0, 334.0], [-8.0, -191.0, 539.0, 700.0], [10.0, 194.0, 340.0, 524.0], [69.0, -146.0, 209.0, 127.0], [64.0, -146.0, 436.0, 127.0], [64.0, 445.0, 436.0, 718.0], [88.0, 76.0, 468.0, 484.0], [92.0, 0.0, 908.0, 146.0], [-3.0, -19.0, 1003.0, 710.0], [55.0, -195.0, 551.0, 532.0], [-23.0, 604.0, 225.0, 750.0], [108.0, 604.0, 356.0, 750.0], [-10.0, 604.0, 343.0, 750.0], [-17.0, 610.0, 350.0, 737.0], [-6.0, 604.0, 339.0, 678.0], [-2.0, 604.0, 335.0, 750.0], [104.0, 614.0, 230.0, 729.0], [6.0, 614.0, 327.0, 729.0], [59.0, 568.0, 275.0, 776.0], [6.0, -228.0, 245.0, 0.0], [9.0, 604.0, 486.0, 750.0], [71.0, -228.0, 304.0, 0.0], [-10.0, 604.0, 343.0, 750.0], [0.0, 227.0, 1000.0, 333.0], [5.0, 0.0, 954.0, 718.0], [22.0, 401.0, 347.0, 737.0], [-20.0, 0.0, 583.0, 718.0], [33.0, -27.0, 744.0, 745.0], [37.0, -19.0, 961.0, 737.0], [6.0, 401.0, 360.0, 737.0], [29.0, -14.0, 858.0, 546.0], [69.0, 0.0, 209.0, 532.0], [-18.0, 0.0, 296.0, 718.0], [22.0, -29.0, 589.0, 560.0], [34.0, -14.0, 912.0, 546.0], [69.0, -14.0, 579.0, 731.0], [-21.0, 0.0, 300.0, 915.0], [23.0, -14.0, 528.0, 750.0], [29.0, -14.0, 527.0, 750.0], [66.0, -14.0, 625.0, 750.0], [23.0, -14.0, 528.0, 750.0], [15.0, 0.0, 653.0, 915.0], [40.0, -42.0, 544.0, 548.0], [15.0, 0.0, 653.0, 936.0], [20.0, 0.0, 702.0, 936.0], [29.0, -14.0, 527.0, 750.0], [72.0, -19.0, 651.0, 936.0], [10.0, -214.0, 539.0, 750.0], [30.0, -228.0, 519.0, 546.0], [23.0, -14.0, 528.0, 750.0], [72.0, -19.0, 651.0, 962.0], [72.0, -19.0, 651.0, 915.0], [29.0, -224.0, 545.0, 546.0], [72.0, -19.0, 651.0, 936.0], [66.0, -228.0, 545.0, 532.0], [76.0, 0.0, 621.0, 915.0], [-5.0, 0.0, 685.0, 718.0], [64.0, -228.0, 199.0, -50.0], [-11.0, -19.0, 749.0, 737.0], [76.0, 0.0, 621.0, 864.0], [34.0, -14.0, 524.0, 750.0], [29.0, -14.0, 527.0, 776.0], [69.0, -228.0, 654.0, 718.0], [69.0, 0.0, 329.0, 936.0], [29.0, -14.0, 527.0, 750.0], [14.0, -228.0, 598.0, 718.0], [44.0, -19.0, 684.0, 936.0], [29.0, -14.0, 527.0, 737.0], [76.0, 0.0, 621.0, 915.0], [30.0, -14.0, 519.0, 750.0], [30.0, -228.0, 519.0, 546.0], [69.0, 0.0, 329.0, 750.0], [10.0, 0.0, 484.0, 745.0], [76.0, 0.0, 677.0, 936.0], [44.0, -228.0, 713.0, 737.0], [66.0, -14.0, 545.0, 750.0], [29.0, -14.0, 527.0, 750.0], [20.0, 0.0, 702.0, 864.0], [18.0, 0.0, 373.0, 750.0], [34.0, -228.0, 524.0, 546.0], [25.0, 0.0, 586.0, 915.0], [76.0, 0.0, 627.0, 718.0], [44.0, -19.0, 734.0, 864.0], [76.0, 0.0, 677.0, 936.0], [39.0, -19.0, 629.0, 936.0], [34.0, -14.0, 750.0, 718.0], [72.0, -19.0, 651.0, 864.0], [66.0, -14.0, 545.0, 776.0], [8.0, 271.0, 326.0, 710.0], [44.0, -19.0, 734.0, 936.0], [20.0, 0.0, 702.0, 936.0], [20.0, 0.0, 702.0, 936.0], [40.0, 1.0, 545.0, 505.0], [66.0, -14.0, 545.0, 750.0], [14.0, 0.0, 598.0, 936.0], [11.0, -21.0, 494.0, 750.0], [10.0, -214.0, 539.0, 729.0], [69.0, 0.0, 654.0, 936.0], [-37.0, 0.0, 316.0, 750.0], [76.0, 0.0, 621.0, 936.0], [29.0, -14.0, 527.0, 729.0], [23.0, -14.0, 528.0, 729.0], [34.0, -14.0, 524.0, 750.0], [65.0, 0.0, 546.0, 750.0], [66.0, -14.0, 545.0, 678.0], [69.0, 0.0, 654.0, 936.0], [64.0, 0.0, 329.0, 936.0], [40.0, 0.0, 544.0, 506.0], [84.0, -150.0, 196.0, 700.0], [-11.0, -19.0, 748.0, 737.0], [44.0, -19.0, 713.0, 936.0], [64.0, 0.0, 214.0, 915.0], [14.0, -10.0, 585.0, 706.0], [76.0, 0.0, 621.0, 936.0], [64.0, 0.0, 384.0, 750.0], [34.0, -14.0, 578.0, 678.0], [25.0, 0.0, 586.0, 936.0], [25.0, 0.0, 586.0, 936.0], [26.0, 0.0, 523.0, 704.0], [-5.0, 0.0, 685.0, 718.0], [44.0, -228.0, 684.0, 737.0], [69.0, -228.0, 213.0, 718.0], [10.0, -6.0, 421.0, 878.0], [23.0, -228.0, 528.0, 546.0], [72.0, -228.0, 651.0, 718.0], [20.0, 0.0, 702.0, 936.0], [20.0, 0.0, 702.0, 915.0], [23.0, -14.0, 528.0, 750.0], [20.0, 0.0, 480.0, 750.0], [16.0, -224.0, 249.0, 725.0], [44.0, -19.0, 734.0, 936.0], [34.0, -14.0, 578.0, 750.0], [29.0, -14.0, 527.0, 678.0], [30.0, -14.0, 519.0, 750.0], [-21.0, 0.0, 300.0, 729.0], [44.0, -19.0, 734.0, 936.0], [72.0, -19.0, 651.0, 936.0], [6.0, 0.0, 608.0, 688.0], [62.0, -208.0, 578.0, 718.0], [9.0, 283.0, 324.0, 710.0], [44.0, -19.0, 734.0, 915.0], [66.0, -207.0, 545.0, 532.0], [-50.0, 0.0, 209.0, 750.0], [34.0, -14.0, 625.0, 750.0], [76.0, -224.0, 639.0, 718.0], [34.0, -14.0, 650.0, 718.0], [16.0, -19.0, 799.0, 710.0], [39.0, -228.0, 629.0, 737.0], [69.0, 0.0, 408.0, 718.0], [87.0, -228.0, 722.0, 718.0], [76.0, 0.0, 583.0, 936.0], [44.0, 306.0, 956.0, 718.0], [23.0, -14.0, 528.0, 729.0], [-50.0, 0.0, 214.0, 936.0], [-33.0, 0.0, 312.0, 864.0], [76.0, 0.0, 583.0, 718.0], [26.0, -19.0, 794.0, 710.0], [29.0, 0.0, 526.0, 704.0], [34.0, -14.0, 578.0, 750.0], [65.0, 0.0, 546.0, 737.0], [72.0, -19.0, 681.0, 936.0], [76.0, 0.0, 621.0, 936.0], [23.0, -14.0, 528.0, 678.0], [40.0, -217.0, 553.0, 750.0], [26.0, -19.0, 766.0, 710.0], [39.0, -19.0, 629.0, 936.0], [39.0, -228.0, 629.0, 737.0], [44.0, -19.0, 734.0, 936.0], [57.0, 426.0, 343.0, 712.0], [34.0, -14.0, 578.0, 750.0], [44.0, -19.0, 684.0, 936.0], [66.0, -14.0, 545.0, 750.0], [10.0, -46.0, 512.0, 850.0], [76.0, 0.0, 685.0, 936.0], [64.0, -228.0, 373.0, 546.0], [69.0, 0.0, 654.0, 923.0], [34.0, -14.0, 578.0, 737.0], [76.0, -228.0, 677.0, 718.0], [76.0, -228.0, 583.0, 718.0], [20.0, 0.0, 702.0, 923.0], [20.0, -224.0, 742.0, 718.0], [20.0, 0.0, 702.0, 962.0], [44.0, -19.0, 734.0, 923.0], [20.0, 0.0, 480.0, 729.0], [76.0, 0.0, 621.0, 936.0], [-11.0, -228.0, 222.0, 718.0], [69.0, -228.0, 562.0, 718.0], [40.0, 197.0, 544.0, 309.0], [-37.0, 0.0, 316.0, 936.0], [65.0, 0.0, 546.0, 750.0], [10.0, -228.0, 309.0, 676.0], [40.0, 108.0, 544.0, 419.0], [34.0, -14.0, 578.0, 729.0], [66.0, -14.0, 545.0, 729.0], [15.0, -49.0, 540.0, 570.0], [40.0, -217.0, 553.0, 850.0], [34.0, -14.0, 578.0, 737.0], [20.0, 0.0, 480.0, 750.0], [65.0, -228.0, 546.0, 546.0], [26.0, 283.0, 237.0, 710.0], [-8.0, 0.0, 285.0, 678.0], [0.0, 0.0, 0.0, 0.0]]),
+("Helvetica-BoldOblique", &[[0.0, 0.0, 0.0, 0.0], [94.0, 0.0, 397.0, 718.0], [193.0, 447.0, 529.0, 718.0], [60.0, 0.0, 644.0, 698.0], [67.0, -115.0, 622.0, 775.0], [136.0, -19.0, 901.0, 710.0], [89.0, -19.0, 732.0, 718.0], [167.0, 445.0, 362.0, 718.0], [76.0, -208.0, 470.0, 734.0], [-25.0, -208.0, 369.0, 734.0], [146.0, 387.0, 481.0, 718.0], [82.0, 0.0, 610.0, 506.0], [28.0, -168.0, 245.0, 146.0], [73.0, 215.0, 379.0, 345.0], [64.0, 0.0, 245.0, 146.0], [-37.0, -19.0, 468.0, 737.0], [86.0, -19.0, 617.0, 710.0], [173.0, 0.0, 529.0, 710.0], [26.0, 0.0, 619.0, 710.0], [65.0, -19.0, 608.0, 710.0], [60.0, 0.0, 598.0, 710.0], [64.0, -19.0, 636.0, 698.0], [85.0, -19.0, 619.0, 710.0], [125.0, 0.0, 676.0, 698.0], [69.0, -19.0, 616.0, 710.0], [78.0, -19.0, 615.0, 710.0], [92.0, 0.0, 351.0, 512.0], [56.0, -168.0, 351.0, 512.0], [82.0, -8.0, 655.0, 514.0], [58.0, 87.0, 633.0, 419.0], [36.0, -8.0, 609.0, 514.0], [165.0, 0.0, 671.0, 727.0], [186.0, -19.0, 954.0, 737.0], [20.0, 0.0, 702.0, 718.0], [76.0, 0.0, 764.0, 718.0], [107.0, -19.0, 789.0, 737.0], [76.0, 0.0, 777.0, 718.0], [76.0, 0.0, 757.0, 718.0], [76.0, 0.0, 740.0, 718.0], [108.0, -19.0, 817.0, 737.0], [71.0, 0.0, 804.0, 718.0], [64.0, 0.0, 367.0, 718.0], [60.0, -18.0, 637.0, 718.0], [87.0, 0.0, 858.0, 718.0], [76.0, 0.0, 611.0, 718.0], [69.0, 0.0, 918.0, 718.0], [69.0, 0.0, 807.0, 718.0], [107.0, -19.0, 823.0, 737.0], [76.0, 0.0, 738.0, 718.0], [107.0, -52.0, 823.0, 737.0], [76.0, 0.0, 778.0, 718.0], [81.0, -19.0, 718.0, 737.0], [140.0, 0.0, 751.0, 718.0], [116.0, -19.0, 804.0, 718.0], [172.0, 0.0, 801.0, 718.0], [169.0, 0.0, 1082.0, 718.0], [14.0, 0.0, 791.0, 718.0], [168.0, 0.0, 806.0, 718.0], [25.0, 0.0, 737.0, 718.0], [21.0, -196.0, 462.0, 722.0], [124.0, -19.0, 307.0, 737.0], [-18.0, -196.0, 423.0, 722.0], [131.0, 323.0, 591.0, 698.0], [-27.0, -125.0, 540.0, -75.0], [165.0, 454.0, 361.0, 727.0], [55.0, -14.0, 583.0, 546.0], [61.0, -14.0, 645.0, 718.0], [79.0, -14.0, 599.0, 546.0], [82.0, -14.0, 704.0, 718.0], [70.0, -14.0, 593.0, 546.0], [87.0, 0.0, 469.0, 727.0], [38.0, -217.0, 666.0, 546.0], [65.0, 0.0, 629.0, 718.0], [69.0, 0.0, 363.0, 725.0], [-42.0, -214.0, 363.0, 725.0], [69.0, 0.0, 670.0, 718.0], [69.0, 0.0, 362.0, 718.0], [64.0, 0.0, 909.0, 546.0], [65.0, 0.0, 629.0, 546.0], [82.0, -14.0, 643.0, 546.0], [18.0, -207.0, 645.0, 546.0], [80.0, -207.0, 665.0, 546.0], [64.0, 0.0, 489.0, 546.0], [63.0, -14.0, 584.0, 546.0], [100.0, -6.0, 422.0, 676.0], [98.0, -14.0, 658.0, 532.0], [126.0, 0.0, 656.0, 532.0], [123.0, 0.0, 882.0, 532.0], [15.0, 0.0, 648.0, 532.0], [42.0, -214.0, 652.0, 532.0], [20.0, 0.0, 583.0, 532.0], [94.0, -196.0, 518.0, 722.0], [36.0, -225.0, 361.0, 775.0], [-18.0, -196.0, 407.0, 722.0], [115.0, 163.0, 577.0, 343.0], [50.0, -186.0, 353.0, 532.0], [79.0, -118.0, 599.0, 628.0], [50.0, -16.0, 635.0, 718.0], [-174.0, -19.0, 487.0, 710.0], [60.0, 0.0, 713.0, 698.0], [-50.0, -210.0, 669.0, 737.0], [61.0, -184.0, 598.0, 727.0], [27.0, 76.0, 680.0, 636.0], [165.0, 447.0, 321.0, 718.0], [160.0, 454.0, 588.0, 727.0], [135.0, 76.0, 571.0, 484.0], [130.0, 76.0, 353.0, 484.0], [99.0, 76.0, 322.0, 484.0], [87.0, 0.0, 696.0, 727.0], [87.0, 0.0, 695.0, 727.0], [48.0, 227.0, 627.0, 333.0], [118.0, -171.0, 626.0, 718.0], [46.0, -171.0, 628.0, 718.0], [110.0, 172.0, 276.0, 334.0], [98.0, -191.0, 688.0, 700.0], [83.0, 194.0, 420.0, 524.0], [41.0, -146.0, 236.0, 127.0], [36.0, -146.0, 463.0, 127.0], [162.0, 445.0, 589.0, 718.0], [104.0, 76.0, 540.0, 484.0], [92.0, 0.0, 939.0, 146.0], [76.0, -19.0, 1038.0, 710.0], [53.0, -195.0, 559.0, 532.0], [136.0, 604.0, 353.0, 750.0], [236.0, 604.0, 515.0, 750.0], [118.0, 604.0, 471.0, 750.0], [113.0, 610.0, 507.0, 737.0], [122.0, 604.0, 483.0, 678.0], [156.0, 604.0, 494.0, 750.0], [235.0, 614.0, 385.0, 729.0], [137.0, 614.0, 482.0, 729.0], [200.0, 568.0, 420.0, 776.0], [-37.0, -228.0, 220.0, 0.0], [137.0, 604.0, 645.0, 750.0], [41.0, -228.0, 264.0, 0.0], [149.0, 604.0, 502.0, 750.0], [48.0, 227.0, 1071.0, 333.0], [5.0, 0.0, 1100.0, 718.0], [125.0, 401.0, 465.0, 737.0], [34.0, 0.0, 611.0, 718.0], [35.0, -27.0, 894.0, 745.0], [99.0, -19.0, 1114.0, 737.0], [123.0, 401.0, 485.0, 737.0], [56.0, -14.0, 923.0, 546.0], [69.0, 0.0, 322.0, 532.0], [40.0, 0.0, 407.0, 718.0], [22.0, -29.0, 701.0, 560.0], [82.0, -14.0, 977.0, 546.0], [69.0, -14.0, 657.0, 731.0], [64.0, 0.0, 494.0, 915.0], [70.0, -14.0, 627.0, 750.0], [55.0, -14.0, 606.0, 750.0], [98.0, -14.0, 784.0, 750.0], [70.0, -14.0, 614.0, 750.0], [168.0, 0.0, 806.0, 915.0], [82.0, -42.0, 610.0, 548.0], [168.0, 0.0, 806.0, 936.0], [20.0, 0.0, 706.0, 936.0], [55.0, -14.0, 627.0, 750.0], [116.0, -19.0, 804.0, 936.0], [42.0, -214.0, 652.0, 750.0], [63.0, -228.0, 584.0, 546.0], [70.0, -14.0, 593.0, 750.0], [116.0, -19.0, 804.0, 962.0], [116.0, -19.0, 804.0, 915.0], [55.0, -224.0, 583.0, 546.0], [116.0, -19.0, 804.0, 936.0], [98.0, -228.0, 658.0, 532.0], [76.0, 0.0, 757.0, 915.0], [62.0, 0.0, 777.0, 718.0], [16.0, -228.0, 188.0, -50.0], [56.0, -19.0, 835.0, 737.0], [76.0, 0.0, 757.0, 864.0], [79.0, -14.0, 614.0, 750.0], [55.0, -14.0, 583.0, 776.0], [69.0, -228.0, 807.0, 718.0], [69.0, 0.0, 528.0, 936.0], [55.0, -14.0, 583.0, 750.0], [140.0, -228.0, 751.0, 718.0], [107.0, -19.0, 789.0, 936.0], [55.0, -14.0, 619.0, 737.0], [76.0, 0.0, 757.0, 915.0], [63.0, -14.0, 614.0, 750.0], [63.0, -228.0, 584.0, 546.0], [69.0, 0.0, 488.0, 750.0], [90.0, 0.0, 564.0, 745.0], [76.0, 0.0, 778.0, 936.0], [108.0, -228.0, 817.0, 737.0], [98.0, -14.0, 658.0, 750.0], [55.0, -14.0, 583.0, 750.0], [20.0, 0.0, 718.0, 864.0], [64.0, 0.0, 530.0, 750.0], [79.0, -228.0, 599.0, 546.0], [25.0, 0.0, 737.0, 915.0], [76.0, 0.0, 716.0, 718.0], [107.0, -19.0, 823.0, 864.0], [76.0, 0.0, 778.0, 936.0], [81.0, -19.0, 722.0, 936.0], [82.0, -14.0, 903.0, 718.0], [116.0, -19.0, 804.0, 864.0], [98.0, -14.0, 658.0, 776.0], [91.0, 271.0, 441.0, 710.0], [107.0, -19.0, 823.0, 936.0], [20.0, 0.0, 702.0, 936.0], [20.0, 0.0, 729.0, 936.0], [57.0, 1.0, 635.0, 505.0], [98.0, -14.0, 658.0, 750.0], [140.0, 0.0, 751.0, 936.0], [43.0, -21.0, 585.0, 750.0], [42.0, -214.0, 652.0, 729.0], [69.0, 0.0, 807.0, 936.0], [69.0, 0.0, 444.0, 750.0], [76.0, 0.0, 757.0, 936.0], [55.0, -14.0, 594.0, 729.0], [70.0, -14.0, 594.0, 729.0], [79.0, -14.0, 627.0, 750.0], [65.0, 0.0, 654.0, 750.0], [98.0, -14.0, 658.0, 678.0], [69.0, 0.0, 807.0, 936.0], [64.0, 0.0, 528.0, 936.0], [40.0, 0.0, 625.0, 506.0], [52.0, -150.0, 345.0, 700.0], [55.0, -19.0, 834.0, 737.0], [108.0, -19.0, 817.0, 936.0], [64.0, 0.0, 397.0, 915.0], [14.0, -10.0, 670.0, 706.0], [76.0, 0.0, 757.0, 936.0], [64.0, 0.0, 543.0, 750.0], [82.0, -14.0, 643.0, 678.0], [25.0, 0.0, 737.0, 936.0], [25.0, 0.0, 737.0, 936.0], [26.0, 0.0, 629.0, 704.0], [62.0, 0.0, 777.0, 718.0], [107.0, -228.0, 789.0, 737.0], [30.0, -228.0, 362.0, 718.0], [100.0, -6.0, 608.0, 878.0], [70.0, -228.0, 593.0, 546.0], [116.0, -228.0, 804.0, 718.0], [20.0, 0.0, 750.0, 936.0], [20.0, 0.0, 716.0, 915.0], [70.0, -14.0, 593.0, 750.0], [20.0, 0.0, 599.0, 750.0], [-14.0, -224.0, 363.0, 725.0], [107.0, -19.0, 823.0, 936.0], [82.0, -14.0, 654.0, 750.0], [55.0, -14.0, 595.0, 678.0], [63.0, -14.0, 627.0, 750.0], [69.0, 0.0, 455.0, 729.0], [107.0, -19.0, 823.0, 936.0], [116.0, -19.0, 804.0, 936.0], [6.0, 0.0, 608.0, 688.0], [18.0, -208.0, 645.0, 718.0], [69.0, 283.0, 449.0, 710.0], [107.0, -19.0, 823.0, 915.0], [22.0, -207.0, 658.0, 532.0], [69.0, 0.0, 326.0, 750.0], [82.0, -14.0, 784.0, 750.0], [76.0, -224.0, 757.0, 718.0], [82.0, -14.0, 789.0, 718.0], [99.0, -19.0, 839.0, 710.0], [81.0, -228.0, 718.0, 737.0], [69.0, 0.0, 561.0, 718.0], [87.0, -228.0, 858.0, 718.0], [76.0, 0.0, 611.0, 936.0], [179.0, 306.0, 1109.0, 718.0], [70.0, -14.0, 593.0, 729.0], [64.0, 0.0, 367.0, 936.0], [64.0, 0.0, 496.0, 864.0], [76.0, 0.0, 643.0, 718.0], [132.0, -19.0, 858.0, 710.0], [29.0, 0.0, 676.0, 704.0], [82.0, -14.0, 643.0, 750.0], [65.0, 0.0, 646.0, 737.0], [116.0, -19.0, 880.0, 936.0], [76.0, 0.0, 757.0, 936.0], [70.0, -14.0, 595.0, 678.0], [38.0, -217.0, 666.0, 750.0], [132.0, -19.0, 806.0, 710.0], [81.0, -19.0, 718.0, 936.0], [81.0, -228.0, 718.0, 737.0], [107.0, -19.0, 908.0, 936.0], [175.0, 426.0, 467.0, 712.0], [82.0, -14.0, 643.0, 750.0], [107.0, -19.0, 789.0, 936.0], [98.0, -14.0, 658.0, 750.0], [112.0, -46.0, 689.0, 850.0], [76.0, 0.0, 777.0, 936.0], [26.0, -228.0, 489.0, 546.0], [69.0, 0.0, 807.0, 923.0], [82.0, -14.0, 646.0, 737.0], [76.0, -228.0, 778.0, 718.0], [76.0, -228.0, 611.0, 718.0], [20.0, 0.0, 741.0, 923.0], [20.0, -224.0, 702.0, 718.0], [20.0, 0.0, 702.0, 962.0], [107.0, -19.0, 823.0, 923.0], [20.0, 0.0, 583.0, 729.0], [76.0, 0.0, 757.0, 936.0], [-41.0, -228.0, 367.0, 718.0], [69.0, -228.0, 670.0, 718.0], [82.0, 197.0, 610.0, 309.0], [64.0, 0.0, 484.0, 936.0], [65.0, 0.0, 641.0, 750.0], [58.0, -228.0, 422.0, 676.0], [105.0, 108.0, 633.0, 419.0], [82.0, -14.0, 643.0, 729.0], [98.0, -14.0, 658.0, 729.0], [32.0, -49.0, 630.0, 570.0], [38.0, -217.0, 666.0, 850.0], [82.0, -14.0, 670.0, 737.0], [20.0, 0.0, 586.0, 750.0], [65.0, -228.0, 629.0, 546.0], [148.0, 283.0, 388.0, 710.0], [69.0, 0.0, 429.0, 678.0], [0.0, 0.0, 0.0, 0.0]]),
+("Helvetica-Oblique", &[[0.0, 0.0, 0.0, 0.0], [90.0, 0.0, 340.0, 718.0], [168.0, 463.0, 438.0, 718.0], [73.0, 0.0, 631.0, 688.0], [69.0, -115.0, 617.0, 775.0], [147.0, -19.0, 889.0, 703.0], [77.0, -15.0, 647.0, 718.0], [151.0, 463.0, 310.0, 718.0], [108.0, -207.0, 454.0, 733.0], [-9.0, -207.0, 337.0, 733.0], [165.0, 431.0, 475.0, 718.0], [85.0, 0.0, 606.0, 505.0], [56.0, -147.0, 214.0, 106.0], [93.0, 232.0, 357.0, 322.0], [87.0, 0.0, 214.0, 106.0], [-21.0, -19.0, 452.0, 737.0], [93.0, -19.0, 608.0, 703.0], [207.0, 0.0, 508.0, 703.0], [26.0, 0.0, 617.0, 703.0], [75.0, -19.0, 610.0, 703.0], [61.0, 0.0, 576.0, 703.0], [68.0, -19.0, 621.0, 688.0], [91.0, -19.0, 615.0, 703.0], [137.0, 0.0, 669.0, 688.0], [74.0, -19.0, 607.0, 703.0], [82.0, -19.0, 609.0, 703.0], [87.0, 0.0, 301.0, 516.0], [56.0, -147.0, 301.0, 516.0], [94.0, 11.0, 641.0, 495.0], [63.0, 115.0, 628.0, 390.0], [50.0, 11.0, 597.0, 495.0], [161.0, 0.0, 610.0, 727.0], [215.0, -19.0, 965.0, 737.0], [14.0, 0.0, 654.0, 718.0], [74.0, 0.0, 712.0, 718.0], [108.0, -19.0, 782.0, 737.0], [81.0, 0.0, 764.0, 718.0], [86.0, 0.0, 762.0, 718.0], [86.0, 0.0, 736.0, 718.0], [111.0, -19.0, 799.0, 737.0], [77.0, 0.0, 799.0, 718.0], [91.0, 0.0, 341.0, 718.0], [47.0, -19.0, 581.0, 718.0], [76.0, 0.0, 808.0, 718.0], [76.0, 0.0, 555.0, 718.0], [73.0, 0.0, 914.0, 718.0], [76.0, 0.0, 799.0, 718.0], [105.0, -19.0, 826.0, 737.0], [86.0, 0.0, 737.0, 718.0], [105.0, -56.0, 826.0, 737.0], [88.0, 0.0, 773.0, 718.0], [90.0, -19.0, 713.0, 737.0], [148.0, 0.0, 750.0, 718.0], [123.0, -19.0, 797.0, 718.0], [173.0, 0.0, 800.0, 718.0], [169.0, 0.0, 1081.0, 718.0], [19.0, 0.0, 790.0, 718.0], [167.0, 0.0, 806.0, 718.0], [23.0, 0.0, 741.0, 718.0], [21.0, -196.0, 403.0, 722.0], [140.0, -19.0, 291.0, 737.0], [-14.0, -196.0, 368.0, 722.0], [42.0, 264.0, 539.0, 688.0], [-27.0, -125.0, 540.0, -75.0], [165.0, 470.0, 323.0, 725.0], [61.0, -15.0, 559.0, 538.0], [58.0, -15.0, 584.0, 718.0], [74.0, -15.0, 553.0, 538.0], [84.0, -15.0, 652.0, 718.0], [84.0, -15.0, 578.0, 538.0], [86.0, 0.0, 416.0, 728.0], [42.0, -220.0, 610.0, 538.0], [65.0, 0.0, 573.0, 718.0], [67.0, 0.0, 308.0, 718.0], [-60.0, -210.0, 308.0, 718.0], [67.0, 0.0, 600.0, 718.0], [67.0, 0.0, 308.0, 718.0], [65.0, 0.0, 852.0, 538.0], [65.0, 0.0, 573.0, 538.0], [83.0, -14.0, 585.0, 538.0], [14.0, -207.0, 584.0, 538.0], [84.0, -207.0, 605.0, 538.0], [77.0, 0.0, 446.0, 538.0], [63.0, -15.0, 529.0, 538.0], [102.0, -7.0, 368.0, 669.0], [94.0, -15.0, 600.0, 523.0], [119.0, 0.0, 603.0, 523.0], [125.0, 0.0, 820.0, 523.0], [11.0, 0.0, 594.0, 523.0], [15.0, -214.0, 600.0, 523.0], [31.0, 0.0, 571.0, 523.0], [92.0, -196.0, 445.0, 722.0], [46.0, -225.0, 332.0, 775.0], [0.0, -196.0, 354.0, 722.0], [111.0, 180.0, 580.0, 326.0], [77.0, -195.0, 326.0, 523.0], [95.0, -115.0, 584.0, 623.0], [49.0, -16.0, 634.0, 718.0], [-170.0, -19.0, 482.0, 703.0], [81.0, 0.0, 699.0, 688.0], [-52.0, -207.0, 654.0, 737.0], [76.0, -191.0, 584.0, 737.0], [60.0, 99.0, 646.0, 603.0], [157.0, 463.0, 285.0, 718.0], [138.0, 470.0, 461.0, 725.0], [146.0, 108.0, 554.0, 446.0], [137.0, 108.0, 340.0, 446.0], [111.0, 108.0, 314.0, 446.0], [86.0, 0.0, 587.0, 728.0], [86.0, 0.0, 585.0, 728.0], [51.0, 240.0, 623.0, 313.0], [135.0, -159.0, 622.0, 718.0], [52.0, -159.0, 623.0, 718.0], [129.0, 190.0, 257.0, 315.0], [126.0, -173.0, 650.0, 718.0], [91.0, 202.0, 413.0, 517.0], [21.0, -149.0, 180.0, 106.0], [-6.0, -149.0, 318.0, 106.0], [124.0, 463.0, 448.0, 718.0], [120.0, 108.0, 528.0, 446.0], [115.0, 0.0, 908.0, 106.0], [88.0, -19.0, 1029.0, 703.0], [85.0, -201.0, 534.0, 525.0], [170.0, 593.0, 337.0, 734.0], [248.0, 593.0, 475.0, 734.0], [147.0, 593.0, 438.0, 734.0], [125.0, 606.0, 490.0, 722.0], [143.0, 627.0, 468.0, 684.0], [167.0, 595.0, 476.0, 731.0], [249.0, 604.0, 362.0, 706.0], [168.0, 604.0, 443.0, 706.0], [214.0, 572.0, 402.0, 756.0], [2.0, -225.0, 232.0, 0.0], [157.0, 593.0, 565.0, 734.0], [43.0, -225.0, 249.0, 0.0], [177.0, 593.0, 468.0, 734.0], [51.0, 240.0, 1067.0, 313.0], [8.0, 0.0, 1097.0, 718.0], [127.0, 405.0, 449.0, 737.0], [41.0, 0.0, 555.0, 718.0], [43.0, -19.0, 890.0, 737.0], [98.0, -19.0, 1116.0, 737.0], [141.0, 405.0, 468.0, 737.0], [61.0, -15.0, 909.0, 538.0], [95.0, 0.0, 294.0, 523.0], [41.0, 0.0, 347.0, 718.0], [29.0, -22.0, 647.0, 545.0], [83.0, -15.0, 964.0, 538.0], [67.0, -15.0, 658.0, 728.0], [91.0, 0.0, 458.0, 901.0], [84.0, -15.0, 587.0, 734.0], [61.0, -15.0, 578.0, 731.0], [94.0, -15.0, 677.0, 734.0], [84.0, -15.0, 580.0, 734.0], [167.0, 0.0, 806.0, 901.0], [85.0, -19.0, 606.0, 524.0], [167.0, 0.0, 806.0, 929.0], [14.0, 0.0, 654.0, 929.0], [61.0, -15.0, 587.0, 734.0], [123.0, -19.0, 797.0, 929.0], [15.0, -214.0, 600.0, 734.0], [63.0, -225.0, 529.0, 538.0], [84.0, -15.0, 578.0, 734.0], [123.0, -19.0, 797.0, 931.0], [123.0, -19.0, 797.0, 901.0], [61.0, -220.0, 559.0, 538.0], [123.0, -19.0, 797.0, 929.0], [94.0, -225.0, 600.0, 523.0], [86.0, 0.0, 762.0, 901.0], [69.0, 0.0, 764.0, 718.0], [39.0, -225.0, 172.0, -40.0], [54.0, -19.0, 837.0, 737.0], [86.0, 0.0, 762.0, 879.0], [74.0, -15.0, 553.0, 734.0], [61.0, -15.0, 559.0, 756.0], [76.0, -225.0, 799.0, 718.0], [67.0, 0.0, 461.0, 929.0], [61.0, -15.0, 559.0, 734.0], [148.0, -225.0, 750.0, 718.0], [108.0, -19.0, 782.0, 929.0], [61.0, -15.0, 592.0, 722.0], [86.0, 0.0, 762.0, 901.0], [63.0, -15.0, 552.0, 734.0], [63.0, -225.0, 529.0, 538.0], [95.0, 0.0, 448.0, 734.0], [88.0, 0.0, 540.0, 728.0], [88.0, 0.0, 773.0, 929.0], [111.0, -225.0, 799.0, 737.0], [94.0, -15.0, 600.0, 734.0], [61.0, -15.0, 559.0, 734.0], [14.0, 0.0, 677.0, 879.0], [77.0, 0.0, 508.0, 734.0], [74.0, -225.0, 553.0, 538.0], [23.0, 0.0, 741.0, 901.0], [86.0, 0.0, 712.0, 718.0], [105.0, -19.0, 826.0, 879.0], [88.0, 0.0, 773.0, 929.0], [90.0, -19.0, 713.0, 929.0], [84.0, -15.0, 808.0, 718.0], [123.0, -19.0, 797.0, 879.0], [94.0, -15.0, 600.0, 756.0], [90.0, 270.0, 436.0, 703.0], [105.0, -19.0, 826.0, 929.0], [14.0, 0.0, 654.0, 929.0], [14.0, 0.0, 685.0, 926.0], [50.0, 0.0, 642.0, 506.0], [94.0, -15.0, 600.0, 734.0], [148.0, 0.0, 750.0, 929.0], [41.0, -38.0, 550.0, 714.0], [15.0, -214.0, 600.0, 706.0], [76.0, 0.0, 799.0, 929.0], [95.0, 0.0, 411.0, 734.0], [86.0, 0.0, 762.0, 929.0], [61.0, -15.0, 559.0, 706.0], [84.0, -15.0, 578.0, 706.0], [74.0, -15.0, 559.0, 734.0], [65.0, 0.0, 587.0, 734.0], [94.0, -15.0, 600.0, 684.0], [76.0, 0.0, 799.0, 929.0], [91.0, 0.0, 489.0, 929.0], [39.0, 0.0, 618.0, 506.0], [62.0, -150.0, 316.0, 700.0], [54.0, -19.0, 837.0, 737.0], [111.0, -19.0, 799.0, 926.0], [91.0, 0.0, 377.0, 901.0], [15.0, -10.0, 671.0, 706.0], [86.0, 0.0, 762.0, 929.0], [77.0, 0.0, 475.0, 734.0], [83.0, -14.0, 585.0, 684.0], [23.0, 0.0, 741.0, 929.0], [23.0, 0.0, 741.0, 929.0], [26.0, 0.0, 620.0, 674.0], [69.0, 0.0, 764.0, 718.0], [108.0, -225.0, 782.0, 737.0], [25.0, -225.0, 308.0, 718.0], [102.0, -7.0, 501.0, 808.0], [84.0, -225.0, 578.0, 538.0], [123.0, -225.0, 797.0, 718.0], [14.0, 0.0, 683.0, 929.0], [14.0, 0.0, 654.0, 901.0], [84.0, -15.0, 578.0, 734.0], [31.0, 0.0, 571.0, 734.0], [-61.0, -225.0, 308.0, 718.0], [105.0, -19.0, 826.0, 929.0], [83.0, -14.0, 587.0, 734.0], [61.0, -15.0, 580.0, 684.0], [63.0, -15.0, 559.0, 734.0], [95.0, 0.0, 416.0, 706.0], [105.0, -19.0, 826.0, 929.0], [123.0, -19.0, 797.0, 929.0], [6.0, 0.0, 608.0, 688.0], [14.0, -207.0, 584.0, 718.0], [64.0, 281.0, 449.0, 703.0], [105.0, -19.0, 826.0, 901.0], [24.0, -207.0, 600.0, 523.0], [95.0, 0.0, 310.0, 734.0], [83.0, -14.0, 677.0, 734.0], [86.0, -220.0, 762.0, 718.0], [84.0, -15.0, 689.0, 718.0], [130.0, -19.0, 861.0, 703.0], [90.0, -225.0, 713.0, 737.0], [67.0, 0.0, 464.0, 718.0], [76.0, -225.0, 808.0, 718.0], [76.0, 0.0, 555.0, 929.0], [186.0, 306.0, 1056.0, 718.0], [84.0, -15.0, 578.0, 706.0], [91.0, 0.0, 351.0, 929.0], [91.0, 0.0, 483.0, 879.0], [76.0, 0.0, 570.0, 718.0], [114.0, -19.0, 839.0, 703.0], [26.0, 0.0, 666.0, 674.0], [83.0, -14.0, 585.0, 734.0], [65.0, 0.0, 592.0, 722.0], [123.0, -19.0, 801.0, 929.0], [86.0, 0.0, 762.0, 929.0], [84.0, -15.0, 580.0, 684.0], [42.0, -220.0, 610.0, 731.0], [150.0, -19.0, 802.0, 703.0], [90.0, -19.0, 713.0, 929.0], [90.0, -225.0, 713.0, 737.0], [105.0, -19.0, 829.0, 929.0], [169.0, 411.0, 468.0, 703.0], [83.0, -14.0, 585.0, 734.0], [108.0, -19.0, 782.0, 929.0], [94.0, -15.0, 600.0, 734.0], [79.0, -80.0, 617.0, 762.0], [81.0, 0.0, 764.0, 929.0], [30.0, -225.0, 446.0, 538.0], [76.0, 0.0, 799.0, 917.0], [83.0, -14.0, 602.0, 722.0], [88.0, -225.0, 773.0, 718.0], [76.0, -225.0, 555.0, 718.0], [14.0, 0.0, 699.0, 917.0], [14.0, -225.0, 654.0, 718.0], [14.0, 0.0, 654.0, 931.0], [105.0, -19.0, 826.0, 917.0], [31.0, 0.0, 571.0, 706.0], [86.0, 0.0, 762.0, 929.0], [-33.0, -225.0, 341.0, 718.0], [67.0, -225.0, 600.0, 718.0], [85.0, 216.0, 606.0, 289.0], [91.0, 0.0, 452.0, 929.0], [65.0, 0.0, 580.0, 734.0], [63.0, -225.0, 368.0, 669.0], [106.0, 108.0, 628.0, 390.0], [83.0, -14.0, 585.0, 706.0], [94.0, -15.0, 600.0, 706.0], [34.0, -35.0, 623.0, 551.0], [42.0, -220.0, 610.0, 822.0], [81.0, -15.0, 617.0, 737.0], [31.0, 0.0, 571.0, 734.0], [65.0, -225.0, 573.0, 538.0], [166.0, 281.0, 371.0, 703.0], [95.0, 0.0, 417.0, 684.0], [0.0, 0.0, 0.0, 0.0]]),
+("Helvetica", &[[0.0, 0.0, 0.0, 0.0], [90.0, 0.0, 187.0, 718.0], [70.0, 463.0, 285.0, 718.0], [28.0, 0.0, 529.0, 688.0], [32.0, -115.0, 520.0, 775.0], [39.0, -19.0, 850.0, 703.0], [44.0, -15.0, 645.0, 718.0], [53.0, 463.0, 157.0, 718.0], [68.0, -207.0, 299.0, 733.0], [34.0, -207.0, 265.0, 733.0], [39.0, 431.0, 349.0, 718.0], [39.0, 0.0, 545.0, 505.0], [87.0, -147.0, 191.0, 106.0], [44.0, 232.0, 289.0, 322.0], [87.0, 0.0, 191.0, 106.0], [-17.0, -19.0, 295.0, 737.0], [37.0, -19.0, 519.0, 703.0], [101.0, 0.0, 359.0, 703.0], [26.0, 0.0, 507.0, 703.0], [34.0, -19.0, 522.0, 703.0], [25.0, 0.0, 523.0, 703.0], [32.0, -19.0, 514.0, 688.0], [38.0, -19.0, 518.0, 703.0], [37.0, 0.0, 523.0, 688.0], [38.0, -19.0, 517.0, 703.0], [42.0, -19.0, 514.0, 703.0], [87.0, 0.0, 191.0, 516.0], [87.0, -147.0, 191.0, 516.0], [48.0, 11.0, 536.0, 495.0], [39.0, 115.0, 545.0, 390.0], [48.0, 11.0, 536.0, 495.0], [56.0, 0.0, 492.0, 727.0], [147.0, -19.0, 868.0, 737.0], [14.0, 0.0, 654.0, 718.0], [74.0, 0.0, 627.0, 718.0], [44.0, -19.0, 681.0, 737.0], [81.0, 0.0, 674.0, 718.0], [86.0, 0.0, 616.0, 718.0], [86.0, 0.0, 583.0, 718.0], [48.0, -19.0, 704.0, 737.0], [77.0, 0.0, 646.0, 718.0], [91.0, 0.0, 188.0, 718.0], [17.0, -19.0, 428.0, 718.0], [76.0, 0.0, 663.0, 718.0], [76.0, 0.0, 537.0, 718.0], [73.0, 0.0, 761.0, 718.0], [76.0, 0.0, 646.0, 718.0], [39.0, -19.0, 739.0, 737.0], [86.0, 0.0, 622.0, 718.0], [39.0, -56.0, 739.0, 737.0], [88.0, 0.0, 684.0, 718.0], [49.0, -19.0, 620.0, 737.0], [14.0, 0.0, 597.0, 718.0], [79.0, -19.0, 644.0, 718.0], [20.0, 0.0, 647.0, 718.0], [16.0, 0.0, 928.0, 718.0], [19.0, 0.0, 648.0, 718.0], [14.0, 0.0, 653.0, 718.0], [23.0, 0.0, 588.0, 718.0], [63.0, -196.0, 250.0, 722.0], [-17.0, -19.0, 295.0, 737.0], [28.0, -196.0, 215.0, 722.0], [-14.0, 264.0, 483.0, 688.0], [0.0, -125.0, 556.0, -75.0], [65.0, 470.0, 169.0, 725.0], [36.0, -15.0, 530.0, 538.0], [58.0, -15.0, 517.0, 718.0], [30.0, -15.0, 477.0, 538.0], [35.0, -15.0, 499.0, 718.0], [40.0, -15.0, 516.0, 538.0], [14.0, 0.0, 262.0, 728.0], [40.0, -220.0, 499.0, 538.0], [65.0, 0.0, 491.0, 718.0], [67.0, 0.0, 155.0, 718.0], [-16.0, -210.0, 155.0, 718.0], [67.0, 0.0, 501.0, 718.0], [67.0, 0.0, 155.0, 718.0], [65.0, 0.0, 769.0, 538.0], [65.0, 0.0, 491.0, 538.0], [35.0, -14.0, 521.0, 538.0], [58.0, -207.0, 517.0, 538.0], [35.0, -207.0, 494.0, 538.0], [77.0, 0.0, 332.0, 538.0], [32.0, -15.0, 464.0, 538.0], [14.0, -7.0, 257.0, 669.0], [68.0, -15.0, 489.0, 523.0], [8.0, 0.0, 492.0, 523.0], [14.0, 0.0, 709.0, 523.0], [11.0, 0.0, 490.0, 523.0], [11.0, -214.0, 489.0, 523.0], [31.0, 0.0, 469.0, 523.0], [42.0, -196.0, 292.0, 722.0], [94.0, -225.0, 167.0, 775.0], [42.0, -196.0, 292.0, 722.0], [61.0, 180.0, 523.0, 326.0], [118.0, -195.0, 215.0, 523.0], [51.0, -115.0, 513.0, 623.0], [33.0, -16.0, 539.0, 718.0], [-166.0, -19.0, 333.0, 703.0], [3.0, 0.0, 553.0, 688.0], [-11.0, -207.0, 501.0, 737.0], [43.0, -191.0, 512.0, 737.0], [28.0, 99.0, 528.0, 603.0], [59.0, 463.0, 132.0, 718.0], [38.0, 470.0, 307.0, 725.0], [97.0, 108.0, 459.0, 446.0], [88.0, 108.0, 245.0, 446.0], [88.0, 108.0, 245.0, 446.0], [14.0, 0.0, 434.0, 728.0], [14.0, 0.0, 432.0, 728.0], [0.0, 240.0, 556.0, 313.0], [43.0, -159.0, 514.0, 718.0], [43.0, -159.0, 514.0, 718.0], [77.0, 190.0, 202.0, 315.0], [18.0, -173.0, 497.0, 718.0], [18.0, 202.0, 333.0, 517.0], [53.0, -149.0, 157.0, 106.0], [26.0, -149.0, 295.0, 106.0], [26.0, 463.0, 295.0, 718.0], [97.0, 108.0, 459.0, 446.0], [115.0, 0.0, 885.0, 106.0], [7.0, -19.0, 994.0, 703.0], [91.0, -201.0, 527.0, 525.0], [14.0, 593.0, 211.0, 734.0], [122.0, 593.0, 319.0, 734.0], [21.0, 593.0, 312.0, 734.0], [-4.0, 606.0, 337.0, 722.0], [10.0, 627.0, 323.0, 684.0], [13.0, 595.0, 321.0, 731.0], [121.0, 604.0, 212.0, 706.0], [40.0, 604.0, 293.0, 706.0], [75.0, 572.0, 259.0, 756.0], [45.0, -225.0, 259.0, 0.0], [31.0, 593.0, 409.0, 734.0], [73.0, -225.0, 287.0, 0.0], [21.0, 593.0, 312.0, 734.0], [0.0, 240.0, 1000.0, 313.0], [8.0, 0.0, 951.0, 718.0], [24.0, 405.0, 346.0, 737.0], [-20.0, 0.0, 537.0, 718.0], [39.0, -19.0, 740.0, 737.0], [36.0, -19.0, 965.0, 737.0], [25.0, 405.0, 341.0, 737.0], [36.0, -15.0, 847.0, 538.0], [95.0, 0.0, 183.0, 523.0], [-20.0, 0.0, 242.0, 718.0], [28.0, -22.0, 537.0, 545.0], [35.0, -15.0, 902.0, 538.0], [67.0, -15.0, 571.0, 728.0], [13.0, 0.0, 266.0, 901.0], [40.0, -15.0, 516.0, 734.0], [36.0, -15.0, 530.0, 731.0], [68.0, -15.0, 521.0, 734.0], [40.0, -15.0, 516.0, 734.0], [14.0, 0.0, 653.0, 901.0], [39.0, -19.0, 545.0, 524.0], [14.0, 0.0, 653.0, 929.0], [14.0, 0.0, 654.0, 929.0], [36.0, -15.0, 530.0, 734.0], [79.0, -19.0, 644.0, 929.0], [11.0, -214.0, 489.0, 734.0], [32.0, -225.0, 464.0, 538.0], [40.0, -15.0, 516.0, 734.0], [79.0, -19.0, 644.0, 931.0], [79.0, -19.0, 644.0, 901.0], [36.0, -220.0, 547.0, 538.0], [79.0, -19.0, 644.0, 929.0], [68.0, -225.0, 519.0, 523.0], [86.0, 0.0, 616.0, 901.0], [0.0, 0.0, 674.0, 718.0], [87.0, -225.0, 181.0, -40.0], [-14.0, -19.0, 752.0, 737.0], [86.0, 0.0, 616.0, 879.0], [30.0, -15.0, 477.0, 734.0], [36.0, -15.0, 530.0, 756.0], [76.0, -225.0, 646.0, 718.0], [67.0, 0.0, 264.0, 929.0], [36.0, -15.0, 530.0, 734.0], [14.0, -225.0, 597.0, 718.0], [44.0, -19.0, 681.0, 929.0], [36.0, -15.0, 530.0, 722.0], [86.0, 0.0, 616.0, 901.0], [32.0, -15.0, 464.0, 734.0], [32.0, -225.0, 464.0, 538.0], [95.0, 0.0, 292.0, 734.0], [10.0, 0.0, 462.0, 728.0], [88.0, 0.0, 684.0, 929.0], [48.0, -225.0, 704.0, 737.0], [68.0, -15.0, 489.0, 734.0], [36.0, -15.0, 530.0, 734.0], [14.0, 0.0, 654.0, 879.0], [61.0, 0.0, 352.0, 734.0], [30.0, -225.0, 477.0, 538.0], [23.0, 0.0, 588.0, 901.0], [86.0, 0.0, 622.0, 718.0], [39.0, -19.0, 739.0, 879.0], [88.0, 0.0, 684.0, 929.0], [49.0, -19.0, 620.0, 929.0], [35.0, -15.0, 655.0, 718.0], [79.0, -19.0, 644.0, 879.0], [68.0, -15.0, 489.0, 756.0], [5.0, 270.0, 325.0, 703.0], [39.0, -19.0, 739.0, 929.0], [14.0, 0.0, 654.0, 929.0], [14.0, 0.0, 654.0, 926.0], [39.0, 0.0, 545.0, 506.0], [68.0, -15.0, 489.0, 734.0], [14.0, 0.0, 597.0, 929.0], [13.0, -38.0, 463.0, 714.0], [11.0, -214.0, 489.0, 706.0], [76.0, 0.0, 646.0, 929.0], [-6.0, 0.0, 285.0, 734.0], [86.0, 0.0, 616.0, 929.0], [36.0, -15.0, 530.0, 706.0], [40.0, -15.0, 516.0, 706.0], [30.0, -15.0, 477.0, 734.0], [65.0, 0.0, 491.0, 734.0], [68.0, -15.0, 489.0, 684.0], [76.0, 0.0, 646.0, 929.0], [91.0, 0.0, 292.0, 929.0], [39.0, 0.0, 545.0, 506.0], [94.0, -150.0, 167.0, 700.0], [-14.0, -19.0, 752.0, 737.0], [48.0, -19.0, 704.0, 926.0], [91.0, 0.0, 188.0, 901.0], [15.0, -10.0, 586.0, 706.0], [86.0, 0.0, 616.0, 929.0], [77.0, 0.0, 332.0, 734.0], [35.0, -14.0, 521.0, 684.0], [23.0, 0.0, 588.0, 929.0], [23.0, 0.0, 588.0, 929.0], [26.0, 0.0, 523.0, 674.0], [0.0, 0.0, 674.0, 718.0], [44.0, -225.0, 681.0, 737.0], [67.0, -225.0, 167.0, 718.0], [14.0, -7.0, 329.0, 808.0], [40.0, -225.0, 516.0, 538.0], [79.0, -225.0, 644.0, 718.0], [14.0, 0.0, 654.0, 929.0], [14.0, 0.0, 654.0, 901.0], [40.0, -15.0, 516.0, 734.0], [31.0, 0.0, 469.0, 734.0], [-31.0, -225.0, 183.0, 718.0], [39.0, -19.0, 739.0, 929.0], [35.0, -14.0, 521.0, 734.0], [36.0, -15.0, 530.0, 684.0], [32.0, -15.0, 464.0, 734.0], [13.0, 0.0, 266.0, 706.0], [39.0, -19.0, 739.0, 929.0], [79.0, -19.0, 644.0, 929.0], [6.0, 0.0, 608.0, 688.0], [58.0, -207.0, 517.0, 718.0], [4.0, 281.0, 323.0, 703.0], [39.0, -19.0, 739.0, 901.0], [68.0, -207.0, 489.0, 523.0], [-13.0, 0.0, 184.0, 734.0], [35.0, -14.0, 521.0, 734.0], [86.0, -220.0, 633.0, 718.0], [35.0, -15.0, 550.0, 718.0], [45.0, -19.0, 810.0, 703.0], [49.0, -225.0, 620.0, 737.0], [67.0, 0.0, 311.0, 718.0], [76.0, -225.0, 663.0, 718.0], [76.0, 0.0, 537.0, 929.0], [46.0, 306.0, 903.0, 718.0], [40.0, -15.0, 516.0, 706.0], [-13.0, 0.0, 188.0, 929.0], [-17.0, 0.0, 296.0, 879.0], [76.0, 0.0, 537.0, 718.0], [43.0, -19.0, 773.0, 703.0], [26.0, 0.0, 523.0, 674.0], [35.0, -14.0, 521.0, 734.0], [65.0, 0.0, 491.0, 722.0], [79.0, -19.0, 644.0, 929.0], [86.0, 0.0, 616.0, 929.0], [40.0, -15.0, 516.0, 684.0], [40.0, -220.0, 499.0, 731.0], [73.0, -19.0, 756.0, 703.0], [49.0, -19.0, 620.0, 929.0], [49.0, -225.0, 620.0, 737.0], [39.0, -19.0, 739.0, 929.0], [54.0, 411.0, 346.0, 703.0], [35.0, -14.0, 521.0, 734.0], [44.0, -19.0, 681.0, 929.0], [68.0, -15.0, 489.0, 734.0], [-4.0, -80.0, 458.0, 762.0], [81.0, 0.0, 674.0, 929.0], [77.0, -225.0, 332.0, 538.0], [76.0, 0.0, 646.0, 917.0], [35.0, -14.0, 521.0, 722.0], [88.0, -225.0, 684.0, 718.0], [76.0, -225.0, 537.0, 718.0], [14.0, 0.0, 654.0, 917.0], [14.0, -225.0, 654.0, 718.0], [14.0, 0.0, 654.0, 931.0], [39.0, -19.0, 739.0, 917.0], [31.0, 0.0, 469.0, 706.0], [86.0, 0.0, 616.0, 929.0], [-3.0, -225.0, 211.0, 718.0], [67.0, -225.0, 501.0, 718.0], [39.0, 216.0, 545.0, 289.0], [-6.0, 0.0, 285.0, 929.0], [65.0, 0.0, 491.0, 734.0], [14.0, -225.0, 257.0, 669.0], [39.0, 108.0, 545.0, 390.0], [35.0, -14.0, 521.0, 706.0], [68.0, -15.0, 489.0, 706.0], [12.0, -35.0, 537.0, 551.0], [40.0, -220.0, 499.0, 822.0], [35.0, -15.0, 522.0, 737.0], [31.0, 0.0, 469.0, 734.0], [65.0, -225.0, 491.0, 538.0], [43.0, 281.0, 222.0, 703.0], [5.0, 0.0, 272.0, 684.0], [0.0, 0.0, 0.0, 0.0]]),
+("Symbol", &[[0.0, 0.0, 0.0, 0.0], [128.0, -17.0, 240.0, 672.0], [31.0, 0.0, 681.0, 705.0], [20.0, -16.0, 481.0, 673.0], [25.0, 0.0, 478.0, 707.0], [63.0, -36.0, 771.0, 655.0], [41.0, -18.0, 750.0, 661.0], [48.0, -17.0, 414.0, 500.0], [53.0, -191.0, 300.0, 673.0], [30.0, -191.0, 277.0, 673.0], [65.0, 134.0, 427.0, 551.0], [10.0, 0.0, 539.0, 533.0], [56.0, -152.0, 194.0, 104.0], [11.0, 233.0, 535.0, 288.0], [69.0, -17.0, 181.0, 95.0], [0.0, -18.0, 254.0, 646.0], [24.0, -14.0, 476.0, 685.0], [117.0, 0.0, 390.0, 673.0], [25.0, 0.0, 475.0, 685.0], [43.0, -14.0, 435.0, 685.0], [15.0, 0.0, 469.0, 685.0], [32.0, -14.0, 445.0, 690.0], [34.0, -14.0, 468.0, 685.0], [24.0, -16.0, 448.0, 673.0], [56.0, -14.0, 445.0, 685.0], [30.0, -18.0, 459.0, 685.0], [81.0, -17.0, 193.0, 460.0], [83.0, -152.0, 221.0, 460.0], [26.0, 0.0, 523.0, 522.0], [11.0, 141.0, 537.0, 390.0], [26.0, 0.0, 523.0, 522.0], [70.0, -17.0, 412.0, 686.0], [11.0, 0.0, 537.0, 475.0], [4.0, 0.0, 684.0, 673.0], [29.0, 0.0, 592.0, 673.0], [-9.0, 0.0, 704.0, 673.0], [6.0, 0.0, 608.0, 688.0], [32.0, 0.0, 617.0, 673.0], [26.0, 0.0, 741.0, 673.0], [24.0, 0.0, 609.0, 673.0], [39.0, 0.0, 729.0, 673.0], [32.0, 0.0, 316.0, 673.0], [18.0, -18.0, 623.0, 689.0], [35.0, 0.0, 722.0, 673.0], [6.0, 0.0, 680.0, 688.0], [28.0, 0.0, 887.0, 673.0], [29.0, -8.0, 720.0, 673.0], [41.0, -17.0, 715.0, 685.0], [25.0, 0.0, 745.0, 673.0], [41.0, -17.0, 715.0, 685.0], [28.0, 0.0, 563.0, 673.0], [5.0, 0.0, 589.0, 673.0], [33.0, 0.0, 607.0, 673.0], [-8.0, 0.0, 694.0, 673.0], [40.0, -233.0, 436.0, 500.0], [34.0, 0.0, 736.0, 688.0], [40.0, 0.0, 599.0, 673.0], [15.0, 0.0, 781.0, 684.0], [44.0, 0.0, 636.0, 673.0], [86.0, -155.0, 299.0, 674.0], [163.0, 0.0, 701.0, 487.0], [33.0, -155.0, 246.0, 674.0], [15.0, 0.0, 652.0, 674.0], [-2.0, -125.0, 502.0, -75.0], [480.0, 881.0, 1090.0, 917.0], [41.0, -18.0, 622.0, 500.0], [61.0, -223.0, 515.0, 741.0], [12.0, -231.0, 522.0, 499.0], [40.0, -19.0, 481.0, 740.0], [22.0, -19.0, 427.0, 502.0], [28.0, -224.0, 492.0, 673.0], [5.0, -225.0, 484.0, 499.0], [0.0, -202.0, 527.0, 514.0], [0.0, -17.0, 301.0, 503.0], [36.0, -224.0, 587.0, 499.0], [33.0, 0.0, 558.0, 501.0], [24.0, -17.0, 548.0, 739.0], [33.0, -223.0, 567.0, 500.0], [-9.0, -16.0, 475.0, 507.0], [35.0, -19.0, 501.0, 499.0], [10.0, -19.0, 530.0, 487.0], [43.0, -17.0, 485.0, 690.0], [50.0, -230.0, 490.0, 499.0], [30.0, -21.0, 588.0, 500.0], [10.0, -19.0, 418.0, 500.0], [7.0, -18.0, 535.0, 507.0], [12.0, -18.0, 671.0, 583.0], [42.0, -17.0, 684.0, 500.0], [27.0, -224.0, 469.0, 766.0], [12.0, -228.0, 701.0, 500.0], [60.0, -225.0, 467.0, 756.0], [58.0, -183.0, 397.0, 673.0], [65.0, -293.0, 135.0, 707.0], [79.0, -183.0, 418.0, 673.0], [17.0, 203.0, 529.0, 307.0], [20.0, -12.0, 714.0, 685.0], [-2.0, 0.0, 610.0, 685.0], [27.0, 459.0, 228.0, 735.0], [29.0, 0.0, 526.0, 639.0], [-180.0, -12.0, 340.0, 677.0], [26.0, 124.0, 688.0, 404.0], [2.0, -193.0, 494.0, 686.0], [86.0, -26.0, 660.0, 533.0], [142.0, -36.0, 600.0, 550.0], [117.0, -33.0, 631.0, 532.0], [113.0, -36.0, 629.0, 548.0], [24.0, -15.0, 1024.0, 511.0], [32.0, -15.0, 942.0, 511.0], [45.0, 0.0, 571.0, 910.0], [49.0, -15.0, 959.0, 511.0], [45.0, -22.0, 571.0, 888.0], [50.0, 385.0, 350.0, 685.0], [10.0, 0.0, 539.0, 645.0], [20.0, 459.0, 413.0, 737.0], [29.0, 0.0, 526.0, 639.0], [17.0, 8.0, 533.0, 524.0], [27.0, 123.0, 639.0, 404.0], [26.0, -20.0, 462.0, 746.0], [50.0, 113.0, 410.0, 473.0], [10.0, 71.0, 536.0, 456.0], [15.0, -25.0, 540.0, 549.0], [14.0, 82.0, 538.0, 443.0], [14.0, 135.0, 527.0, 394.0], [111.0, -17.0, 889.0, 95.0], [280.0, -120.0, 336.0, 1010.0], [-60.0, 220.0, 1050.0, 276.0], [15.0, -16.0, 602.0, 629.0], [175.0, -18.0, 661.0, 658.0], [10.0, -53.0, 578.0, 740.0], [26.0, -15.0, 759.0, 734.0], [159.0, -211.0, 870.0, 573.0], [43.0, -17.0, 733.0, 673.0], [43.0, -15.0, 733.0, 675.0], [39.0, -24.0, 781.0, 719.0], [40.0, 0.0, 732.0, 509.0], [40.0, -17.0, 732.0, 492.0], [20.0, 0.0, 673.0, 470.0], [20.0, -125.0, 673.0, 470.0], [36.0, -70.0, 690.0, 540.0], [37.0, 0.0, 690.0, 470.0], [37.0, -125.0, 690.0, 470.0], [45.0, 0.0, 505.0, 468.0], [45.0, -58.0, 505.0, 555.0], [26.0, 0.0, 738.0, 673.0], [36.0, -19.0, 681.0, 718.0], [50.0, -17.0, 740.0, 673.0], [51.0, -15.0, 741.0, 675.0], [18.0, 293.0, 855.0, 673.0], [25.0, -101.0, 803.0, 751.0], [10.0, -38.0, 515.0, 917.0], [69.0, 210.0, 169.0, 310.0], [15.0, 0.0, 680.0, 288.0], [23.0, 0.0, 583.0, 454.0], [30.0, 0.0, 578.0, 477.0], [27.0, -20.0, 1023.0, 510.0], [30.0, -15.0, 939.0, 513.0], [39.0, 2.0, 567.0, 911.0], [45.0, -20.0, 954.0, 508.0], [44.0, -19.0, 572.0, 890.0], [18.0, 0.0, 466.0, 745.0], [25.0, -198.0, 306.0, 746.0], [50.0, -20.0, 740.0, 670.0], [49.0, -15.0, 739.0, 675.0], [5.0, 293.0, 725.0, 673.0], [14.0, -108.0, 695.0, 752.0], [24.0, -293.0, 436.0, 926.0], [24.0, -85.0, 108.0, 925.0], [24.0, -293.0, 436.0, 926.0], [0.0, -80.0, 349.0, 926.0], [0.0, -79.0, 77.0, 925.0], [0.0, -80.0, 349.0, 926.0], [209.0, -85.0, 445.0, 925.0], [20.0, -85.0, 284.0, 935.0], [209.0, -75.0, 445.0, 935.0], [209.0, -85.0, 284.0, 935.0], [21.0, -198.0, 302.0, 746.0], [2.0, -107.0, 291.0, 916.0], [308.0, -88.0, 675.0, 920.0], [308.0, -88.0, 378.0, 975.0], [11.0, -87.0, 378.0, 921.0], [54.0, -293.0, 466.0, 926.0], [382.0, -85.0, 466.0, 925.0], [54.0, -293.0, 466.0, 926.0], [22.0, -80.0, 371.0, 926.0], [294.0, -79.0, 371.0, 925.0], [22.0, -80.0, 371.0, 926.0], [48.0, -85.0, 284.0, 925.0], [209.0, -85.0, 473.0, 935.0], [48.0, -75.0, 284.0, 935.0], [56.0, -3.0, 733.0, 808.0]]),
+("Times-Bold", &[[0.0, 0.0, 0.0, 0.0], [81.0, -13.0, 251.0, 691.0], [83.0, 404.0, 472.0, 691.0], [4.0, 0.0, 496.0, 700.0], [29.0, -99.0, 472.0, 750.0], [124.0, -14.0, 877.0, 692.0], [62.0, -16.0, 787.0, 691.0], [79.0, 356.0, 263.0, 691.0], [46.0, -168.0, 306.0, 694.0], [27.0, -168.0, 287.0, 694.0], [56.0, 255.0, 447.0, 691.0], [33.0, 0.0, 537.0, 506.0], [39.0, -180.0, 223.0, 155.0], [44.0, 171.0, 287.0, 287.0], [41.0, -13.0, 210.0, 156.0], [-24.0, -19.0, 302.0, 691.0], [24.0, -13.0, 476.0, 688.0], [65.0, 0.0, 442.0, 688.0], [17.0, 0.0, 478.0, 688.0], [16.0, -14.0, 468.0, 688.0], [19.0, 0.0, 475.0, 688.0], [22.0, -8.0, 470.0, 676.0], [28.0, -13.0, 475.0, 688.0], [17.0, 0.0, 477.0, 676.0], [28.0, -13.0, 472.0, 688.0], [26.0, -13.0, 473.0, 688.0], [82.0, -13.0, 251.0, 472.0], [82.0, -180.0, 266.0, 472.0], [31.0, -8.0, 539.0, 514.0], [33.0, 107.0, 537.0, 399.0], [31.0, -8.0, 539.0, 514.0], [57.0, -13.0, 445.0, 689.0], [108.0, -19.0, 822.0, 691.0], [9.0, 0.0, 689.0, 690.0], [16.0, 0.0, 619.0, 676.0], [49.0, -19.0, 687.0, 691.0], [14.0, 0.0, 690.0, 676.0], [16.0, 0.0, 641.0, 676.0], [16.0, 0.0, 583.0, 676.0], [37.0, -19.0, 755.0, 691.0], [21.0, 0.0, 759.0, 676.0], [20.0, 0.0, 370.0, 676.0], [3.0, -96.0, 479.0, 676.0], [30.0, 0.0, 769.0, 676.0], [19.0, 0.0, 638.0, 676.0], [14.0, 0.0, 921.0, 676.0], [16.0, -18.0, 701.0, 676.0], [35.0, -19.0, 743.0, 691.0], [16.0, 0.0, 600.0, 676.0], [35.0, -176.0, 743.0, 691.0], [26.0, 0.0, 715.0, 676.0], [35.0, -19.0, 513.0, 692.0], [31.0, 0.0, 636.0, 676.0], [16.0, -19.0, 701.0, 676.0], [16.0, -18.0, 701.0, 676.0], [19.0, -15.0, 981.0, 676.0], [16.0, 0.0, 699.0, 676.0], [15.0, 0.0, 699.0, 676.0], [28.0, 0.0, 634.0, 676.0], [67.0, -149.0, 301.0, 678.0], [-25.0, -19.0, 303.0, 691.0], [32.0, -149.0, 266.0, 678.0], [73.0, 311.0, 509.0, 676.0], [0.0, -125.0, 500.0, -75.0], [70.0, 356.0, 254.0, 691.0], [25.0, -14.0, 488.0, 473.0], [17.0, -14.0, 521.0, 676.0], [25.0, -14.0, 430.0, 473.0], [25.0, -14.0, 534.0, 676.0], [25.0, -14.0, 426.0, 473.0], [14.0, 0.0, 389.0, 691.0], [28.0, -206.0, 483.0, 473.0], [16.0, 0.0, 534.0, 676.0], [16.0, 0.0, 255.0, 691.0], [-57.0, -203.0, 263.0, 691.0], [22.0, 0.0, 543.0, 676.0], [16.0, 0.0, 255.0, 676.0], [16.0, 0.0, 814.0, 473.0], [21.0, 0.0, 539.0, 473.0], [25.0, -14.0, 476.0, 473.0], [19.0, -205.0, 524.0, 473.0], [34.0, -205.0, 536.0, 473.0], [29.0, 0.0, 434.0, 473.0], [25.0, -14.0, 361.0, 473.0], [20.0, -12.0, 332.0, 630.0], [16.0, -14.0, 537.0, 461.0], [21.0, -14.0, 485.0, 461.0], [23.0, -14.0, 707.0, 461.0], [12.0, 0.0, 484.0, 461.0], [16.0, -205.0, 480.0, 461.0], [21.0, 0.0, 420.0, 461.0], [22.0, -175.0, 340.0, 698.0], [66.0, -218.0, 154.0, 782.0], [54.0, -175.0, 372.0, 698.0], [29.0, 173.0, 491.0, 333.0], [82.0, -203.0, 252.0, 501.0], [53.0, -140.0, 458.0, 588.0], [21.0, -14.0, 477.0, 684.0], [-168.0, -12.0, 329.0, 688.0], [-64.0, 0.0, 547.0, 676.0], [0.0, -155.0, 498.0, 706.0], [57.0, -132.0, 443.0, 691.0], [-26.0, 61.0, 526.0, 613.0], [75.0, 404.0, 204.0, 691.0], [32.0, 356.0, 486.0, 691.0], [23.0, 36.0, 473.0, 415.0], [51.0, 36.0, 305.0, 415.0], [28.0, 36.0, 282.0, 415.0], [14.0, 0.0, 536.0, 691.0], [14.0, 0.0, 536.0, 691.0], [0.0, 181.0, 500.0, 271.0], [47.0, -134.0, 453.0, 691.0], [45.0, -132.0, 456.0, 691.0], [41.0, 248.0, 210.0, 417.0], [0.0, -186.0, 519.0, 676.0], [35.0, 198.0, 315.0, 478.0], [79.0, -180.0, 263.0, 155.0], [14.0, -180.0, 468.0, 155.0], [14.0, 356.0, 468.0, 691.0], [27.0, 36.0, 477.0, 415.0], [82.0, -13.0, 917.0, 156.0], [7.0, -29.0, 995.0, 706.0], [55.0, -201.0, 443.0, 501.0], [8.0, 528.0, 246.0, 713.0], [86.0, 528.0, 324.0, 713.0], [-2.0, 528.0, 335.0, 704.0], [-16.0, 547.0, 349.0, 674.0], [1.0, 565.0, 331.0, 637.0], [15.0, 528.0, 318.0, 691.0], [103.0, 536.0, 258.0, 691.0], [-2.0, 537.0, 335.0, 667.0], [60.0, 527.0, 273.0, 740.0], [68.0, -218.0, 294.0, 0.0], [-13.0, 528.0, 425.0, 713.0], [90.0, -193.0, 319.0, 24.0], [-2.0, 528.0, 335.0, 704.0], [0.0, 181.0, 1000.0, 271.0], [4.0, 0.0, 951.0, 676.0], [-1.0, 397.0, 301.0, 688.0], [19.0, 0.0, 638.0, 676.0], [35.0, -74.0, 743.0, 737.0], [22.0, -5.0, 981.0, 684.0], [18.0, 397.0, 312.0, 688.0], [33.0, -14.0, 693.0, 473.0], [16.0, 0.0, 255.0, 461.0], [-22.0, 0.0, 303.0, 676.0], [25.0, -92.0, 476.0, 549.0], [22.0, -14.0, 696.0, 473.0], [19.0, -12.0, 517.0, 691.0], [20.0, 0.0, 370.0, 877.0], [25.0, -14.0, 426.0, 713.0], [25.0, -14.0, 488.0, 691.0], [16.0, -14.0, 557.0, 713.0], [25.0, -14.0, 426.0, 704.0], [15.0, 0.0, 699.0, 877.0], [33.0, -31.0, 537.0, 537.0], [15.0, 0.0, 699.0, 923.0], [9.0, 0.0, 689.0, 914.0], [25.0, -14.0, 488.0, 713.0], [16.0, -19.0, 701.0, 914.0], [16.0, -205.0, 480.0, 713.0], [25.0, -218.0, 361.0, 473.0], [25.0, -14.0, 426.0, 704.0], [16.0, -19.0, 701.0, 935.0], [16.0, -19.0, 701.0, 877.0], [25.0, -193.0, 504.0, 473.0], [16.0, -19.0, 701.0, 923.0], [16.0, -193.0, 539.0, 461.0], [16.0, 0.0, 641.0, 877.0], [6.0, 0.0, 690.0, 676.0], [47.0, -218.0, 203.0, -50.0], [26.0, -19.0, 721.0, 691.0], [16.0, 0.0, 641.0, 847.0], [25.0, -14.0, 430.0, 704.0], [25.0, -14.0, 488.0, 740.0], [16.0, -188.0, 701.0, 676.0], [16.0, 0.0, 297.0, 923.0], [25.0, -14.0, 488.0, 713.0], [31.0, -218.0, 636.0, 676.0], [49.0, -19.0, 687.0, 923.0], [25.0, -14.0, 488.0, 674.0], [16.0, 0.0, 641.0, 901.0], [25.0, -14.0, 363.0, 704.0], [25.0, -218.0, 361.0, 473.0], [16.0, 0.0, 289.0, 713.0], [10.0, 0.0, 484.0, 745.0], [26.0, 0.0, 715.0, 914.0], [37.0, -218.0, 755.0, 691.0], [16.0, -14.0, 537.0, 704.0], [25.0, -14.0, 488.0, 704.0], [9.0, 0.0, 689.0, 847.0], [29.0, 0.0, 434.0, 704.0], [25.0, -218.0, 430.0, 473.0], [28.0, 0.0, 634.0, 901.0], [16.0, 0.0, 600.0, 676.0], [35.0, -19.0, 743.0, 847.0], [26.0, 0.0, 715.0, 923.0], [35.0, -19.0, 513.0, 923.0], [25.0, -14.0, 681.0, 682.0], [16.0, -19.0, 701.0, 847.0], [16.0, -14.0, 537.0, 740.0], [3.0, 268.0, 297.0, 688.0], [35.0, -19.0, 743.0, 923.0], [9.0, 0.0, 689.0, 923.0], [9.0, 0.0, 689.0, 901.0], [48.0, 16.0, 522.0, 490.0], [16.0, -14.0, 537.0, 713.0], [31.0, 0.0, 636.0, 914.0], [11.0, -21.0, 494.0, 750.0], [16.0, -205.0, 480.0, 667.0], [16.0, -18.0, 701.0, 923.0], [-37.0, 0.0, 300.0, 704.0], [16.0, 0.0, 641.0, 914.0], [25.0, -14.0, 488.0, 667.0], [25.0, -14.0, 426.0, 667.0], [25.0, -14.0, 430.0, 713.0], [21.0, 0.0, 539.0, 713.0], [16.0, -14.0, 537.0, 637.0], [16.0, -18.0, 701.0, 914.0], [20.0, 0.0, 370.0, 923.0], [33.0, 0.0, 537.0, 506.0], [66.0, -143.0, 154.0, 707.0], [26.0, -19.0, 721.0, 691.0], [37.0, -19.0, 755.0, 901.0], [20.0, 0.0, 370.0, 901.0], [14.0, -10.0, 585.0, 706.0], [16.0, 0.0, 641.0, 923.0], [29.0, 0.0, 434.0, 713.0], [25.0, -14.0, 476.0, 637.0], [28.0, 0.0, 634.0, 923.0], [28.0, 0.0, 634.0, 914.0], [26.0, 0.0, 523.0, 704.0], [6.0, 0.0, 690.0, 676.0], [49.0, -218.0, 687.0, 691.0], [16.0, -218.0, 255.0, 676.0], [20.0, -12.0, 425.0, 815.0], [25.0, -193.0, 426.0, 473.0], [16.0, -193.0, 701.0, 676.0], [9.0, 0.0, 689.0, 923.0], [9.0, 0.0, 689.0, 877.0], [25.0, -14.0, 426.0, 713.0], [21.0, 0.0, 420.0, 713.0], [16.0, -193.0, 274.0, 691.0], [35.0, -19.0, 743.0, 923.0], [25.0, -14.0, 476.0, 713.0], [25.0, -14.0, 488.0, 637.0], [25.0, -14.0, 361.0, 713.0], [-37.0, 0.0, 300.0, 667.0], [35.0, -19.0, 743.0, 914.0], [16.0, -19.0, 701.0, 923.0], [6.0, 0.0, 608.0, 688.0], [19.0, -205.0, 524.0, 676.0], [0.0, 275.0, 300.0, 688.0], [35.0, -19.0, 743.0, 877.0], [33.0, -206.0, 536.0, 461.0], [-27.0, 0.0, 255.0, 713.0], [25.0, -14.0, 529.0, 713.0], [16.0, -193.0, 644.0, 676.0], [25.0, -14.0, 534.0, 676.0], [23.0, -12.0, 733.0, 688.0], [35.0, -218.0, 513.0, 692.0], [16.0, 0.0, 412.0, 682.0], [30.0, -218.0, 769.0, 676.0], [19.0, 0.0, 638.0, 923.0], [24.0, 271.0, 977.0, 676.0], [25.0, -14.0, 426.0, 691.0], [20.0, 0.0, 370.0, 923.0], [20.0, 0.0, 370.0, 847.0], [19.0, 0.0, 652.0, 682.0], [-7.0, -12.0, 775.0, 688.0], [29.0, 0.0, 526.0, 704.0], [25.0, -14.0, 476.0, 704.0], [21.0, 0.0, 539.0, 674.0], [16.0, -19.0, 701.0, 923.0], [16.0, 0.0, 641.0, 923.0], [25.0, -14.0, 426.0, 637.0], [28.0, -206.0, 483.0, 691.0], [28.0, -12.0, 743.0, 688.0], [35.0, -19.0, 513.0, 914.0], [35.0, -218.0, 513.0, 692.0], [35.0, -19.0, 743.0, 923.0], [57.0, 402.0, 343.0, 688.0], [25.0, -14.0, 476.0, 713.0], [49.0, -19.0, 687.0, 914.0], [16.0, -14.0, 537.0, 713.0], [10.0, -46.0, 512.0, 850.0], [14.0, 0.0, 690.0, 914.0], [29.0, -218.0, 434.0, 473.0], [16.0, -18.0, 701.0, 884.0], [25.0, -14.0, 476.0, 674.0], [26.0, -218.0, 715.0, 676.0], [19.0, -218.0, 638.0, 676.0], [9.0, 0.0, 689.0, 884.0], [9.0, -193.0, 699.0, 690.0], [9.0, 0.0, 689.0, 935.0], [35.0, -19.0, 743.0, 884.0], [21.0, 0.0, 420.0, 691.0], [16.0, 0.0, 641.0, 914.0], [20.0, -193.0, 370.0, 676.0], [22.0, -218.0, 543.0, 676.0], [33.0, 209.0, 537.0, 297.0], [20.0, 0.0, 370.0, 914.0], [21.0, 0.0, 539.0, 704.0], [20.0, -218.0, 332.0, 630.0], [33.0, 108.0, 537.0, 399.0], [25.0, -14.0, 476.0, 667.0], [16.0, -14.0, 537.0, 667.0], [15.0, -49.0, 540.0, 570.0], [28.0, -206.0, 483.0, 829.0], [25.0, -14.0, 476.0, 691.0], [21.0, 0.0, 420.0, 704.0], [21.0, -218.0, 539.0, 473.0], [28.0, 275.0, 273.0, 688.0], [-8.0, 0.0, 272.0, 637.0], [0.0, 0.0, 0.0, 0.0]]),
+("Times-BoldItalic", &[[0.0, 0.0, 0.0, 0.0], [67.0, -13.0, 370.0, 684.0], [136.0, 398.0, 536.0, 685.0], [-33.0, 0.0, 533.0, 700.0], [-20.0, -100.0, 497.0, 733.0], [39.0, -10.0, 793.0, 692.0], [5.0, -19.0, 699.0, 682.0], [98.0, 369.0, 302.0, 685.0], [28.0, -179.0, 344.0, 685.0], [-44.0, -179.0, 271.0, 685.0], [65.0, 249.0, 456.0, 685.0], [33.0, 0.0, 537.0, 506.0], [-60.0, -182.0, 144.0, 134.0], [2.0, 166.0, 271.0, 282.0], [-9.0, -13.0, 139.0, 135.0], [-64.0, -18.0, 342.0, 685.0], [17.0, -14.0, 477.0, 683.0], [5.0, 0.0, 419.0, 683.0], [-27.0, 0.0, 446.0, 683.0], [-15.0, -13.0, 450.0, 683.0], [-15.0, 0.0, 503.0, 683.0], [-11.0, -13.0, 487.0, 669.0], [23.0, -15.0, 509.0, 679.0], [52.0, 0.0, 525.0, 669.0], [3.0, -13.0, 476.0, 683.0], [-12.0, -10.0, 475.0, 683.0], [23.0, -13.0, 264.0, 459.0], [-25.0, -183.0, 264.0, 459.0], [31.0, -8.0, 539.0, 514.0], [33.0, 107.0, 537.0, 399.0], [31.0, -8.0, 539.0, 514.0], [79.0, -13.0, 470.0, 684.0], [63.0, -18.0, 770.0, 685.0], [-67.0, 0.0, 593.0, 683.0], [-24.0, 0.0, 624.0, 669.0], [32.0, -18.0, 677.0, 685.0], [-46.0, 0.0, 685.0, 669.0], [-27.0, 0.0, 653.0, 669.0], [-13.0, 0.0, 660.0, 669.0], [21.0, -18.0, 706.0, 685.0], [-24.0, 0.0, 799.0, 669.0], [-32.0, 0.0, 406.0, 669.0], [-46.0, -99.0, 524.0, 669.0], [-21.0, 0.0, 702.0, 669.0], [-22.0, 0.0, 590.0, 669.0], [-29.0, -12.0, 917.0, 669.0], [-27.0, -15.0, 748.0, 669.0], [27.0, -18.0, 691.0, 685.0], [-27.0, 0.0, 613.0, 669.0], [27.0, -208.0, 691.0, 685.0], [-29.0, 0.0, 623.0, 669.0], [2.0, -18.0, 526.0, 685.0], [50.0, 0.0, 650.0, 669.0], [67.0, -18.0, 744.0, 669.0], [65.0, -18.0, 715.0, 669.0], [65.0, -18.0, 940.0, 669.0], [-24.0, 0.0, 694.0, 669.0], [73.0, 0.0, 659.0, 669.0], [-11.0, 0.0, 590.0, 669.0], [-37.0, -159.0, 362.0, 674.0], [-1.0, -18.0, 279.0, 685.0], [-56.0, -157.0, 343.0, 674.0], [67.0, 304.0, 503.0, 669.0], [0.0, -125.0, 500.0, -75.0], [128.0, 369.0, 332.0, 685.0], [-21.0, -14.0, 455.0, 462.0], [-14.0, -13.0, 444.0, 699.0], [-5.0, -13.0, 392.0, 462.0], [-21.0, -13.0, 517.0, 699.0], [5.0, -13.0, 398.0, 462.0], [-169.0, -205.0, 446.0, 698.0], [-52.0, -203.0, 478.0, 462.0], [-13.0, -9.0, 498.0, 699.0], [2.0, -9.0, 263.0, 684.0], [-189.0, -207.0, 279.0, 684.0], [-23.0, -8.0, 483.0, 699.0], [2.0, -9.0, 290.0, 699.0], [-14.0, -9.0, 722.0, 462.0], [-6.0, -9.0, 493.0, 462.0], [-3.0, -13.0, 441.0, 462.0], [-120.0, -205.0, 446.0, 462.0], [1.0, -205.0, 471.0, 462.0], [-21.0, 0.0, 389.0, 462.0], [-19.0, -13.0, 333.0, 462.0], [-11.0, -9.0, 281.0, 594.0], [15.0, -9.0, 492.0, 462.0], [16.0, -13.0, 401.0, 462.0], [16.0, -13.0, 614.0, 462.0], [-46.0, -13.0, 469.0, 462.0], [-94.0, -205.0, 392.0, 462.0], [-43.0, -78.0, 368.0, 449.0], [5.0, -187.0, 436.0, 686.0], [66.0, -218.0, 154.0, 782.0], [-129.0, -187.0, 302.0, 686.0], [54.0, 173.0, 516.0, 333.0], [19.0, -205.0, 322.0, 492.0], [42.0, -143.0, 439.0, 576.0], [-32.0, -12.0, 510.0, 683.0], [-169.0, -14.0, 324.0, 683.0], [33.0, 0.0, 628.0, 669.0], [-87.0, -156.0, 537.0, 707.0], [36.0, -143.0, 459.0, 685.0], [-26.0, 34.0, 526.0, 586.0], [128.0, 398.0, 268.0, 685.0], [53.0, 369.0, 513.0, 685.0], [12.0, 32.0, 468.0, 415.0], [32.0, 32.0, 303.0, 415.0], [10.0, 32.0, 281.0, 415.0], [-188.0, -205.0, 514.0, 703.0], [-186.0, -205.0, 553.0, 704.0], [-40.0, 178.0, 477.0, 269.0], [91.0, -145.0, 494.0, 685.0], [10.0, -139.0, 493.0, 685.0], [51.0, 257.0, 199.0, 405.0], [-57.0, -193.0, 562.0, 669.0], [0.0, 175.0, 350.0, 525.0], [-5.0, -182.0, 199.0, 134.0], [-57.0, -182.0, 403.0, 134.0], [53.0, 369.0, 513.0, 685.0], [12.0, 32.0, 468.0, 415.0], [40.0, -13.0, 852.0, 135.0], [7.0, -29.0, 996.0, 706.0], [30.0, -205.0, 421.0, 492.0], [85.0, 516.0, 297.0, 697.0], [139.0, 516.0, 379.0, 697.0], [40.0, 516.0, 367.0, 690.0], [48.0, 536.0, 407.0, 655.0], [51.0, 553.0, 393.0, 623.0], [71.0, 516.0, 387.0, 678.0], [163.0, 550.0, 298.0, 684.0], [55.0, 550.0, 402.0, 684.0], [127.0, 516.0, 340.0, 729.0], [-80.0, -218.0, 156.0, 5.0], [69.0, 516.0, 498.0, 697.0], [15.0, -183.0, 244.0, 34.0], [79.0, 516.0, 411.0, 690.0], [-40.0, 178.0, 977.0, 269.0], [-64.0, 0.0, 918.0, 669.0], [16.0, 399.0, 330.0, 685.0], [-22.0, 0.0, 590.0, 669.0], [27.0, -125.0, 691.0, 764.0], [23.0, -8.0, 946.0, 677.0], [56.0, 400.0, 347.0, 685.0], [-5.0, -13.0, 673.0, 462.0], [2.0, -9.0, 238.0, 462.0], [-7.0, -9.0, 307.0, 699.0], [-3.0, -119.0, 441.0, 560.0], [6.0, -13.0, 674.0, 462.0], [-200.0, -200.0, 473.0, 705.0], [-32.0, 0.0, 450.0, 862.0], [5.0, -13.0, 435.0, 697.0], [-21.0, -14.0, 471.0, 678.0], [15.0, -9.0, 610.0, 697.0], [5.0, -13.0, 467.0, 690.0], [73.0, 0.0, 659.0, 862.0], [33.0, -29.0, 537.0, 535.0], [73.0, 0.0, 659.0, 904.0], [-67.0, 0.0, 593.0, 897.0], [-21.0, -14.0, 463.0, 697.0], [67.0, -18.0, 744.0, 897.0], [-94.0, -205.0, 435.0, 697.0], [-19.0, -218.0, 333.0, 462.0], [5.0, -13.0, 423.0, 690.0], [67.0, -18.0, 744.0, 921.0], [67.0, -18.0, 744.0, 862.0], [-21.0, -183.0, 455.0, 462.0], [67.0, -18.0, 744.0, 904.0], [15.0, -183.0, 492.0, 462.0], [-27.0, 0.0, 653.0, 862.0], [-31.0, 0.0, 700.0, 669.0], [-36.0, -218.0, 131.0, -50.0], [30.0, -18.0, 718.0, 685.0], [-27.0, 0.0, 653.0, 830.0], [-5.0, -13.0, 467.0, 690.0], [-21.0, -14.0, 455.0, 729.0], [-27.0, -218.0, 748.0, 669.0], [2.0, -9.0, 392.0, 904.0], [-21.0, -14.0, 455.0, 697.0], [50.0, -218.0, 650.0, 669.0], [32.0, -18.0, 677.0, 904.0], [-21.0, -14.0, 491.0, 655.0], [-27.0, 0.0, 653.0, 862.0], [-19.0, -13.0, 424.0, 690.0], [-19.0, -218.0, 333.0, 462.0], [2.0, -9.0, 352.0, 697.0], [10.0, 0.0, 484.0, 745.0], [-29.0, 0.0, 623.0, 897.0], [21.0, -218.0, 706.0, 685.0], [15.0, -9.0, 492.0, 690.0], [-21.0, -14.0, 455.0, 690.0], [-67.0, 0.0, 593.0, 830.0], [-21.0, 0.0, 424.0, 690.0], [-5.0, -218.0, 392.0, 462.0], [-11.0, 0.0, 590.0, 862.0], [-27.0, 0.0, 573.0, 669.0], [27.0, -18.0, 691.0, 830.0], [-29.0, 0.0, 623.0, 904.0], [2.0, -18.0, 531.0, 904.0], [-21.0, -13.0, 675.0, 708.0], [67.0, -18.0, 744.0, 830.0], [15.0, -9.0, 492.0, 729.0], [17.0, 265.0, 321.0, 683.0], [27.0, -18.0, 691.0, 904.0], [-67.0, 0.0, 593.0, 904.0], [-67.0, 0.0, 593.0, 885.0], [48.0, 16.0, 522.0, 490.0], [15.0, -9.0, 492.0, 697.0], [50.0, 0.0, 650.0, 897.0], [11.0, -21.0, 494.0, 750.0], [-94.0, -205.0, 443.0, 655.0], [-27.0, -15.0, 748.0, 904.0], [-3.0, -9.0, 324.0, 690.0], [-27.0, 0.0, 653.0, 897.0], [-21.0, -14.0, 476.0, 655.0], [5.0, -13.0, 448.0, 655.0], [-5.0, -13.0, 435.0, 697.0], [-6.0, -9.0, 493.0, 697.0], [15.0, -9.0, 492.0, 623.0], [-27.0, -15.0, 748.0, 897.0], [-32.0, 0.0, 432.0, 904.0], [33.0, 0.0, 537.0, 506.0], [66.0, -143.0, 154.0, 707.0], [30.0, -18.0, 718.0, 685.0], [21.0, -18.0, 706.0, 885.0], [-32.0, 0.0, 406.0, 862.0], [14.0, -10.0, 585.0, 706.0], [-27.0, 0.0, 653.0, 904.0], [-21.0, 0.0, 407.0, 697.0], [-3.0, -13.0, 462.0, 623.0], [-11.0, 0.0, 590.0, 904.0], [-11.0, 0.0, 590.0, 897.0], [26.0, 0.0, 523.0, 704.0], [-31.0, 0.0, 700.0, 669.0], [32.0, -218.0, 677.0, 685.0], [-42.0, -218.0, 290.0, 699.0], [-11.0, -9.0, 434.0, 754.0], [5.0, -183.0, 398.0, 462.0], [67.0, -183.0, 744.0, 669.0], [-67.0, 0.0, 593.0, 904.0], [-67.0, 0.0, 593.0, 862.0], [5.0, -13.0, 398.0, 697.0], [-43.0, -78.0, 407.0, 697.0], [-20.0, -183.0, 263.0, 684.0], [27.0, -18.0, 691.0, 904.0], [-3.0, -13.0, 463.0, 697.0], [-21.0, -14.0, 467.0, 623.0], [-19.0, -13.0, 407.0, 697.0], [2.0, -9.0, 364.0, 655.0], [27.0, -18.0, 691.0, 897.0], [67.0, -18.0, 744.0, 904.0], [6.0, 0.0, 608.0, 688.0], [-120.0, -205.0, 446.0, 699.0], [2.0, 274.0, 313.0, 683.0], [27.0, -18.0, 691.0, 862.0], [-60.0, -207.0, 516.0, 449.0], [2.0, -9.0, 259.0, 697.0], [-3.0, -13.0, 582.0, 697.0], [-27.0, -183.0, 653.0, 669.0], [-21.0, -13.0, 552.0, 699.0], [7.0, -14.0, 726.0, 683.0], [2.0, -218.0, 526.0, 685.0], [2.0, -9.0, 448.0, 708.0], [-21.0, -218.0, 702.0, 669.0], [-22.0, 0.0, 590.0, 904.0], [32.0, 263.0, 968.0, 669.0], [5.0, -13.0, 398.0, 655.0], [-32.0, 0.0, 406.0, 904.0], [-32.0, 0.0, 461.0, 830.0], [-22.0, 0.0, 671.0, 718.0], [-9.0, -14.0, 723.0, 683.0], [29.0, 0.0, 526.0, 704.0], [-3.0, -13.0, 451.0, 690.0], [-6.0, -9.0, 504.0, 655.0], [67.0, -18.0, 744.0, 904.0], [-27.0, 0.0, 653.0, 904.0], [5.0, -13.0, 439.0, 623.0], [-52.0, -203.0, 478.0, 678.0], [7.0, -14.0, 721.0, 683.0], [2.0, -18.0, 553.0, 897.0], [2.0, -218.0, 526.0, 685.0], [27.0, -18.0, 723.0, 904.0], [83.0, 397.0, 369.0, 683.0], [-3.0, -13.0, 441.0, 697.0], [32.0, -18.0, 677.0, 897.0], [15.0, -9.0, 492.0, 697.0], [10.0, -46.0, 512.0, 850.0], [-46.0, 0.0, 685.0, 897.0], [-67.0, -218.0, 389.0, 462.0], [-27.0, -15.0, 748.0, 862.0], [-3.0, -13.0, 491.0, 655.0], [-29.0, -218.0, 623.0, 669.0], [-22.0, -218.0, 590.0, 669.0], [-67.0, 0.0, 593.0, 862.0], [-67.0, -183.0, 604.0, 683.0], [-67.0, 0.0, 593.0, 921.0], [27.0, -18.0, 691.0, 862.0], [-43.0, -78.0, 368.0, 655.0], [-27.0, 0.0, 653.0, 897.0], [-32.0, -183.0, 406.0, 669.0], [-23.0, -218.0, 483.0, 699.0], [51.0, 209.0, 555.0, 297.0], [-32.0, 0.0, 450.0, 897.0], [-6.0, -9.0, 523.0, 690.0], [-62.0, -218.0, 281.0, 594.0], [51.0, 108.0, 555.0, 399.0], [-3.0, -13.0, 471.0, 655.0], [15.0, -9.0, 499.0, 655.0], [15.0, -49.0, 540.0, 570.0], [-52.0, -203.0, 478.0, 767.0], [-3.0, -13.0, 454.0, 699.0], [-43.0, -78.0, 424.0, 690.0], [-6.0, -218.0, 493.0, 462.0], [30.0, 274.0, 301.0, 683.0], [2.0, -9.0, 294.0, 623.0], [0.0, 0.0, 0.0, 0.0]]),
+("Times-Italic", &[[0.0, 0.0, 0.0, 0.0], [39.0, -11.0, 302.0, 667.0], [144.0, 421.0, 432.0, 666.0], [2.0, 0.0, 540.0, 676.0], [31.0, -89.0, 497.0, 731.0], [79.0, -13.0, 790.0, 676.0], [76.0, -18.0, 723.0, 666.0], [151.0, 436.0, 290.0, 666.0], [42.0, -181.0, 315.0, 669.0], [16.0, -180.0, 289.0, 669.0], [128.0, 255.0, 492.0, 666.0], [86.0, 0.0, 590.0, 506.0], [-4.0, -129.0, 135.0, 101.0], [49.0, 192.0, 282.0, 255.0], [27.0, -11.0, 138.0, 100.0], [-65.0, -18.0, 386.0, 666.0], [32.0, -7.0, 497.0, 676.0], [49.0, 0.0, 409.0, 676.0], [12.0, 0.0, 452.0, 676.0], [15.0, -7.0, 465.0, 676.0], [1.0, 0.0, 479.0, 676.0], [15.0, -7.0, 491.0, 666.0], [30.0, -7.0, 521.0, 686.0], [75.0, -8.0, 537.0, 666.0], [30.0, -7.0, 493.0, 676.0], [23.0, -17.0, 492.0, 676.0], [50.0, -11.0, 261.0, 441.0], [27.0, -129.0, 261.0, 441.0], [84.0, -8.0, 592.0, 514.0], [86.0, 120.0, 590.0, 386.0], [84.0, -8.0, 592.0, 514.0], [132.0, -12.0, 472.0, 664.0], [118.0, -18.0, 806.0, 666.0], [-51.0, 0.0, 564.0, 668.0], [-8.0, 0.0, 588.0, 653.0], [66.0, -18.0, 689.0, 666.0], [-8.0, 0.0, 700.0, 653.0], [-1.0, 0.0, 634.0, 653.0], [8.0, 0.0, 645.0, 653.0], [52.0, -18.0, 722.0, 666.0], [-8.0, 0.0, 767.0, 653.0], [-8.0, 0.0, 384.0, 653.0], [-6.0, -18.0, 491.0, 653.0], [7.0, 0.0, 722.0, 653.0], [-8.0, 0.0, 559.0, 653.0], [-18.0, 0.0, 873.0, 653.0], [-20.0, -15.0, 727.0, 653.0], [60.0, -18.0, 699.0, 666.0], [0.0, 0.0, 605.0, 653.0], [59.0, -182.0, 699.0, 666.0], [-13.0, 0.0, 588.0, 653.0], [17.0, -18.0, 508.0, 667.0], [59.0, 0.0, 633.0, 653.0], [102.0, -18.0, 765.0, 653.0], [76.0, -18.0, 688.0, 653.0], [71.0, -18.0, 906.0, 653.0], [-29.0, 0.0, 655.0, 653.0], [78.0, 0.0, 633.0, 653.0], [-6.0, 0.0, 606.0, 653.0], [21.0, -153.0, 391.0, 663.0], [-41.0, -18.0, 319.0, 666.0], [12.0, -153.0, 382.0, 663.0], [0.0, 301.0, 422.0, 666.0], [0.0, -125.0, 500.0, -75.0], [171.0, 436.0, 310.0, 666.0], [17.0, -11.0, 476.0, 441.0], [23.0, -11.0, 473.0, 683.0], [30.0, -11.0, 425.0, 441.0], [15.0, -13.0, 527.0, 683.0], [31.0, -11.0, 412.0, 441.0], [-147.0, -207.0, 424.0, 678.0], [8.0, -206.0, 472.0, 441.0], [19.0, -9.0, 478.0, 683.0], [49.0, -11.0, 264.0, 654.0], [-124.0, -207.0, 276.0, 654.0], [14.0, -11.0, 461.0, 683.0], [41.0, -11.0, 279.0, 683.0], [12.0, -9.0, 704.0, 441.0], [14.0, -9.0, 474.0, 441.0], [27.0, -11.0, 468.0, 441.0], [-75.0, -205.0, 469.0, 441.0], [25.0, -209.0, 483.0, 441.0], [45.0, 0.0, 412.0, 441.0], [16.0, -13.0, 366.0, 442.0], [37.0, -11.0, 296.0, 546.0], [42.0, -11.0, 475.0, 441.0], [21.0, -18.0, 426.0, 441.0], [16.0, -18.0, 648.0, 441.0], [-27.0, -11.0, 447.0, 441.0], [-24.0, -206.0, 426.0, 441.0], [-2.0, -81.0, 380.0, 428.0], [51.0, -177.0, 407.0, 687.0], [105.0, -217.0, 171.0, 783.0], [-7.0, -177.0, 349.0, 687.0], [40.0, 183.0, 502.0, 323.0], [59.0, -205.0, 322.0, 473.0], [77.0, -143.0, 472.0, 560.0], [10.0, -6.0, 517.0, 670.0], [-169.0, -10.0, 337.0, 676.0], [27.0, 0.0, 603.0, 653.0], [25.0, -182.0, 507.0, 682.0], [53.0, -162.0, 461.0, 666.0], [-22.0, 53.0, 522.0, 597.0], [132.0, 421.0, 241.0, 666.0], [166.0, 436.0, 514.0, 666.0], [53.0, 37.0, 445.0, 403.0], [51.0, 37.0, 281.0, 403.0], [52.0, 37.0, 282.0, 403.0], [-141.0, -207.0, 481.0, 681.0], [-141.0, -204.0, 518.0, 682.0], [-6.0, 197.0, 505.0, 243.0], [101.0, -159.0, 488.0, 666.0], [22.0, -143.0, 491.0, 666.0], [70.0, 199.0, 181.0, 310.0], [55.0, -123.0, 616.0, 653.0], [40.0, 191.0, 310.0, 461.0], [44.0, -129.0, 183.0, 101.0], [57.0, -129.0, 405.0, 101.0], [151.0, 436.0, 499.0, 666.0], [55.0, 37.0, 447.0, 403.0], [57.0, -11.0, 762.0, 100.0], [25.0, -19.0, 1010.0, 706.0], [28.0, -205.0, 368.0, 471.0], [121.0, 492.0, 311.0, 664.0], [180.0, 494.0, 403.0, 664.0], [91.0, 492.0, 385.0, 661.0], [100.0, 517.0, 427.0, 624.0], [99.0, 532.0, 411.0, 583.0], [117.0, 492.0, 418.0, 650.0], [207.0, 548.0, 305.0, 646.0], [107.0, 548.0, 405.0, 646.0], [155.0, 492.0, 355.0, 691.0], [-30.0, -217.0, 182.0, 0.0], [93.0, 494.0, 486.0, 664.0], [20.0, -169.0, 203.0, 40.0], [121.0, 492.0, 426.0, 661.0], [-6.0, 197.0, 894.0, 243.0], [-27.0, 0.0, 911.0, 653.0], [42.0, 406.0, 352.0, 676.0], [-8.0, 0.0, 559.0, 653.0], [60.0, -105.0, 699.0, 722.0], [49.0, -8.0, 964.0, 666.0], [67.0, 406.0, 362.0, 676.0], [23.0, -11.0, 640.0, 441.0], [49.0, -11.0, 235.0, 441.0], [41.0, -11.0, 312.0, 683.0], [28.0, -135.0, 469.0, 554.0], [20.0, -12.0, 646.0, 441.0], [-168.0, -207.0, 493.0, 679.0], [-8.0, 0.0, 435.0, 818.0], [31.0, -11.0, 459.0, 664.0], [17.0, -11.0, 502.0, 650.0], [42.0, -11.0, 580.0, 664.0], [31.0, -11.0, 482.0, 661.0], [78.0, 0.0, 633.0, 818.0], [86.0, -11.0, 590.0, 517.0], [78.0, 0.0, 633.0, 876.0], [-51.0, 0.0, 564.0, 873.0], [17.0, -11.0, 487.0, 664.0], [102.0, -18.0, 765.0, 873.0], [-24.0, -206.0, 459.0, 664.0], [16.0, -217.0, 366.0, 442.0], [31.0, -11.0, 441.0, 661.0], [102.0, -18.0, 765.0, 883.0], [102.0, -18.0, 765.0, 818.0], [17.0, -169.0, 476.0, 441.0], [102.0, -18.0, 765.0, 876.0], [42.0, -169.0, 477.0, 441.0], [-1.0, 0.0, 634.0, 818.0], [-8.0, 0.0, 700.0, 653.0], [8.0, -217.0, 133.0, -50.0], [41.0, -18.0, 719.0, 666.0], [-1.0, 0.0, 634.0, 795.0], [30.0, -11.0, 482.0, 661.0], [17.0, -11.0, 476.0, 691.0], [-20.0, -187.0, 727.0, 653.0], [41.0, -11.0, 395.0, 876.0], [17.0, -11.0, 476.0, 664.0], [59.0, -217.0, 633.0, 653.0], [66.0, -18.0, 690.0, 876.0], [17.0, -11.0, 511.0, 624.0], [-1.0, 0.0, 634.0, 818.0], [16.0, -13.0, 454.0, 661.0], [16.0, -217.0, 366.0, 442.0], [49.0, -11.0, 355.0, 664.0], [13.0, 0.0, 459.0, 724.0], [-13.0, 0.0, 588.0, 873.0], [52.0, -217.0, 722.0, 666.0], [42.0, -11.0, 475.0, 661.0], [17.0, -11.0, 476.0, 661.0], [-51.0, 0.0, 564.0, 795.0], [45.0, 0.0, 434.0, 661.0], [30.0, -217.0, 425.0, 441.0], [-6.0, 0.0, 606.0, 818.0], [0.0, 0.0, 569.0, 653.0], [60.0, -18.0, 699.0, 795.0], [-13.0, 0.0, 588.0, 876.0], [17.0, -18.0, 508.0, 876.0], [15.0, -13.0, 658.0, 683.0], [102.0, -18.0, 765.0, 795.0], [42.0, -11.0, 475.0, 691.0], [43.0, 268.0, 339.0, 676.0], [60.0, -18.0, 699.0, 876.0], [-51.0, 0.0, 564.0, 876.0], [-51.0, 0.0, 564.0, 862.0], [93.0, 8.0, 582.0, 497.0], [42.0, -11.0, 477.0, 664.0], [59.0, 0.0, 633.0, 873.0], [17.0, -38.0, 459.0, 710.0], [-24.0, -206.0, 441.0, 606.0], [-20.0, -15.0, 727.0, 876.0], [33.0, -11.0, 327.0, 661.0], [-1.0, 0.0, 634.0, 873.0], [17.0, -11.0, 489.0, 606.0], [31.0, -11.0, 451.0, 606.0], [30.0, -11.0, 459.0, 664.0], [14.0, -9.0, 477.0, 664.0], [42.0, -11.0, 485.0, 583.0], [-20.0, -15.0, 727.0, 873.0], [-8.0, 0.0, 433.0, 876.0], [86.0, 0.0, 590.0, 506.0], [105.0, -142.0, 171.0, 708.0], [41.0, -18.0, 719.0, 666.0], [52.0, -18.0, 722.0, 862.0], [-8.0, 0.0, 384.0, 818.0], [15.0, -10.0, 585.0, 706.0], [-1.0, 0.0, 634.0, 876.0], [45.0, 0.0, 431.0, 664.0], [27.0, -11.0, 495.0, 583.0], [-6.0, 0.0, 606.0, 876.0], [-6.0, 0.0, 606.0, 873.0], [26.0, 0.0, 523.0, 658.0], [-8.0, 0.0, 700.0, 653.0], [66.0, -217.0, 689.0, 666.0], [22.0, -217.0, 279.0, 683.0], [37.0, -11.0, 407.0, 681.0], [31.0, -169.0, 412.0, 441.0], [102.0, -184.0, 765.0, 653.0], [-51.0, 0.0, 564.0, 876.0], [-51.0, 0.0, 564.0, 818.0], [31.0, -11.0, 412.0, 664.0], [-2.0, -81.0, 431.0, 664.0], [49.0, -169.0, 264.0, 654.0], [60.0, -18.0, 699.0, 876.0], [27.0, -11.0, 487.0, 664.0], [17.0, -11.0, 495.0, 583.0], [16.0, -13.0, 431.0, 664.0], [49.0, -11.0, 352.0, 606.0], [60.0, -18.0, 699.0, 873.0], [102.0, -18.0, 765.0, 876.0], [6.0, 0.0, 608.0, 688.0], [-75.0, -205.0, 469.0, 683.0], [33.0, 271.0, 324.0, 676.0], [60.0, -18.0, 699.0, 818.0], [-30.0, -209.0, 497.0, 428.0], [49.0, -11.0, 284.0, 664.0], [27.0, -11.0, 590.0, 664.0], [-1.0, -169.0, 634.0, 653.0], [15.0, -13.0, 572.0, 683.0], [23.0, -10.0, 736.0, 676.0], [17.0, -217.0, 508.0, 667.0], [41.0, -11.0, 407.0, 683.0], [7.0, -217.0, 722.0, 653.0], [-8.0, 0.0, 559.0, 876.0], [30.0, 247.0, 957.0, 653.0], [31.0, -11.0, 412.0, 606.0], [-8.0, 0.0, 384.0, 876.0], [-8.0, 0.0, 441.0, 795.0], [-8.0, 0.0, 586.0, 653.0], [34.0, -10.0, 749.0, 676.0], [26.0, 0.0, 523.0, 658.0], [27.0, -11.0, 468.0, 661.0], [14.0, -9.0, 476.0, 624.0], [102.0, -18.0, 765.0, 876.0], [-1.0, 0.0, 634.0, 876.0], [31.0, -11.0, 457.0, 583.0], [8.0, -206.0, 487.0, 650.0], [33.0, -10.0, 736.0, 676.0], [17.0, -18.0, 520.0, 873.0], [17.0, -217.0, 508.0, 667.0], [60.0, -18.0, 699.0, 876.0], [101.0, 390.0, 387.0, 676.0], [27.0, -11.0, 468.0, 664.0], [66.0, -18.0, 689.0, 873.0], [42.0, -11.0, 475.0, 664.0], [2.0, -60.0, 452.0, 768.0], [-8.0, 0.0, 700.0, 873.0], [-3.0, -217.0, 412.0, 441.0], [-20.0, -15.0, 727.0, 836.0], [27.0, -11.0, 496.0, 624.0], [-13.0, -187.0, 588.0, 653.0], [-8.0, -217.0, 559.0, 653.0], [-51.0, 0.0, 566.0, 836.0], [-51.0, -169.0, 566.0, 668.0], [-51.0, 0.0, 564.0, 883.0], [60.0, -18.0, 699.0, 836.0], [-2.0, -81.0, 380.0, 606.0], [-1.0, 0.0, 634.0, 873.0], [-8.0, -169.0, 384.0, 653.0], [14.0, -187.0, 461.0, 683.0], [86.0, 220.0, 590.0, 286.0], [-8.0, 0.0, 425.0, 873.0], [14.0, -9.0, 510.0, 661.0], [2.0, -217.0, 296.0, 546.0], [86.0, 108.0, 590.0, 386.0], [27.0, -11.0, 489.0, 606.0], [42.0, -11.0, 479.0, 606.0], [12.0, -29.0, 537.0, 541.0], [8.0, -206.0, 472.0, 706.0], [27.0, -11.0, 482.0, 683.0], [-2.0, -81.0, 434.0, 661.0], [14.0, -187.0, 474.0, 441.0], [43.0, 271.0, 284.0, 676.0], [46.0, -11.0, 311.0, 583.0], [0.0, 0.0, 0.0, 0.0]]),
+("Times-Roman", &[[0.0, 0.0, 0.0, 0.0], [130.0, -9.0, 238.0, 676.0], [77.0, 431.0, 331.0, 676.0], [5.0, 0.0, 496.0, 662.0], [44.0, -87.0, 457.0, 727.0], [61.0, -13.0, 772.0, 676.0], [42.0, -13.0, 750.0, 676.0], [79.0, 433.0, 218.0, 676.0], [48.0, -177.0, 304.0, 676.0], [29.0, -177.0, 285.0, 676.0], [69.0, 265.0, 432.0, 676.0], [30.0, 0.0, 534.0, 506.0], [56.0, -141.0, 195.0, 102.0], [39.0, 194.0, 285.0, 257.0], [70.0, -11.0, 181.0, 100.0], [-9.0, -14.0, 287.0, 676.0], [24.0, -14.0, 476.0, 676.0], [111.0, 0.0, 394.0, 676.0], [30.0, 0.0, 475.0, 676.0], [43.0, -14.0, 431.0, 676.0], [12.0, 0.0, 472.0, 676.0], [32.0, -14.0, 438.0, 688.0], [34.0, -14.0, 468.0, 684.0], [20.0, -8.0, 449.0, 662.0], [56.0, -14.0, 445.0, 676.0], [30.0, -22.0, 459.0, 676.0], [81.0, -11.0, 192.0, 459.0], [80.0, -141.0, 219.0, 459.0], [28.0, -8.0, 536.0, 514.0], [30.0, 120.0, 534.0, 386.0], [28.0, -8.0, 536.0, 514.0], [68.0, -8.0, 414.0, 676.0], [116.0, -14.0, 809.0, 676.0], [15.0, 0.0, 706.0, 674.0], [17.0, 0.0, 593.0, 662.0], [28.0, -14.0, 633.0, 676.0], [16.0, 0.0, 685.0, 662.0], [12.0, 0.0, 597.0, 662.0], [12.0, 0.0, 546.0, 662.0], [32.0, -14.0, 709.0, 676.0], [19.0, 0.0, 702.0, 662.0], [18.0, 0.0, 315.0, 662.0], [10.0, -14.0, 370.0, 662.0], [34.0, 0.0, 723.0, 662.0], [12.0, 0.0, 598.0, 662.0], [12.0, 0.0, 863.0, 662.0], [12.0, -11.0, 707.0, 662.0], [34.0, -14.0, 688.0, 676.0], [16.0, 0.0, 542.0, 662.0], [34.0, -178.0, 701.0, 676.0], [17.0, 0.0, 659.0, 662.0], [42.0, -14.0, 491.0, 676.0], [17.0, 0.0, 593.0, 662.0], [14.0, -14.0, 705.0, 662.0], [16.0, -11.0, 697.0, 662.0], [5.0, -11.0, 932.0, 662.0], [10.0, 0.0, 704.0, 662.0], [22.0, 0.0, 703.0, 662.0], [9.0, 0.0, 597.0, 662.0], [88.0, -156.0, 299.0, 662.0], [-9.0, -14.0, 287.0, 676.0], [34.0, -156.0, 245.0, 662.0], [24.0, 297.0, 446.0, 662.0], [0.0, -125.0, 500.0, -75.0], [115.0, 433.0, 254.0, 676.0], [37.0, -10.0, 442.0, 460.0], [3.0, -10.0, 468.0, 683.0], [25.0, -10.0, 412.0, 460.0], [27.0, -10.0, 491.0, 683.0], [25.0, -10.0, 424.0, 460.0], [20.0, 0.0, 383.0, 683.0], [28.0, -218.0, 470.0, 460.0], [9.0, 0.0, 487.0, 683.0], [16.0, 0.0, 253.0, 683.0], [-70.0, -218.0, 194.0, 683.0], [7.0, 0.0, 505.0, 683.0], [19.0, 0.0, 257.0, 683.0], [16.0, 0.0, 775.0, 460.0], [16.0, 0.0, 485.0, 460.0], [29.0, -10.0, 470.0, 460.0], [5.0, -217.0, 470.0, 460.0], [24.0, -217.0, 488.0, 460.0], [5.0, 0.0, 335.0, 460.0], [51.0, -10.0, 348.0, 460.0], [13.0, -10.0, 279.0, 579.0], [9.0, -10.0, 479.0, 450.0], [19.0, -14.0, 477.0, 450.0], [21.0, -14.0, 694.0, 450.0], [17.0, 0.0, 479.0, 450.0], [14.0, -218.0, 475.0, 450.0], [27.0, 0.0, 418.0, 450.0], [100.0, -181.0, 350.0, 680.0], [67.0, -218.0, 133.0, 782.0], [130.0, -181.0, 380.0, 680.0], [40.0, 183.0, 502.0, 323.0], [97.0, -218.0, 205.0, 467.0], [53.0, -138.0, 448.0, 579.0], [12.0, -8.0, 490.0, 676.0], [-168.0, -14.0, 331.0, 676.0], [-53.0, 0.0, 512.0, 662.0], [7.0, -189.0, 490.0, 676.0], [70.0, -148.0, 426.0, 676.0], [-22.0, 58.0, 522.0, 602.0], [48.0, 431.0, 133.0, 676.0], [43.0, 433.0, 414.0, 676.0], [42.0, 33.0, 456.0, 416.0], [63.0, 33.0, 285.0, 416.0], [48.0, 33.0, 270.0, 416.0], [31.0, 0.0, 521.0, 683.0], [32.0, 0.0, 521.0, 683.0], [0.0, 201.0, 500.0, 250.0], [59.0, -149.0, 442.0, 676.0], [58.0, -153.0, 442.0, 676.0], [70.0, 199.0, 181.0, 310.0], [-22.0, -154.0, 450.0, 662.0], [40.0, 196.0, 310.0, 466.0], [79.0, -141.0, 218.0, 102.0], [45.0, -141.0, 416.0, 102.0], [30.0, 433.0, 401.0, 676.0], [44.0, 33.0, 458.0, 416.0], [111.0, -11.0, 888.0, 100.0], [7.0, -19.0, 994.0, 706.0], [30.0, -218.0, 376.0, 466.0], [19.0, 507.0, 242.0, 678.0], [93.0, 507.0, 317.0, 678.0], [11.0, 507.0, 322.0, 674.0], [1.0, 532.0, 331.0, 638.0], [11.0, 547.0, 322.0, 601.0], [26.0, 507.0, 307.0, 664.0], [118.0, 581.0, 216.0, 681.0], [18.0, 581.0, 315.0, 681.0], [67.0, 512.0, 266.0, 711.0], [52.0, -215.0, 261.0, 0.0], [-3.0, 507.0, 377.0, 678.0], [62.0, -165.0, 243.0, 0.0], [11.0, 507.0, 322.0, 674.0], [0.0, 201.0, 1000.0, 250.0], [0.0, 0.0, 863.0, 662.0], [4.0, 394.0, 270.0, 676.0], [12.0, 0.0, 598.0, 662.0], [34.0, -80.0, 688.0, 734.0], [30.0, -6.0, 885.0, 668.0], [6.0, 394.0, 304.0, 676.0], [38.0, -10.0, 632.0, 460.0], [16.0, 0.0, 253.0, 460.0], [19.0, 0.0, 259.0, 683.0], [29.0, -112.0, 470.0, 551.0], [30.0, -10.0, 690.0, 460.0], [12.0, -9.0, 468.0, 683.0], [18.0, 0.0, 315.0, 835.0], [25.0, -10.0, 424.0, 678.0], [37.0, -10.0, 442.0, 664.0], [9.0, -10.0, 501.0, 678.0], [25.0, -10.0, 424.0, 674.0], [22.0, 0.0, 703.0, 835.0], [30.0, -10.0, 534.0, 516.0], [22.0, 0.0, 703.0, 890.0], [15.0, 0.0, 706.0, 886.0], [37.0, -10.0, 442.0, 678.0], [14.0, -14.0, 705.0, 886.0], [14.0, -218.0, 475.0, 678.0], [51.0, -218.0, 348.0, 460.0], [25.0, -10.0, 424.0, 674.0], [14.0, -14.0, 705.0, 898.0], [14.0, -14.0, 705.0, 835.0], [37.0, -165.0, 469.0, 460.0], [14.0, -14.0, 705.0, 890.0], [9.0, -155.0, 487.0, 450.0], [12.0, 0.0, 597.0, 835.0], [16.0, 0.0, 685.0, 662.0], [59.0, -218.0, 184.0, -50.0], [38.0, -14.0, 722.0, 676.0], [12.0, 0.0, 597.0, 813.0], [25.0, -10.0, 412.0, 674.0], [37.0, -10.0, 442.0, 711.0], [12.0, -198.0, 707.0, 662.0], [19.0, 0.0, 290.0, 890.0], [37.0, -10.0, 442.0, 678.0], [17.0, -218.0, 593.0, 662.0], [28.0, -14.0, 633.0, 890.0], [37.0, -10.0, 442.0, 638.0], [12.0, 0.0, 597.0, 835.0], [39.0, -10.0, 350.0, 674.0], [51.0, -215.0, 348.0, 460.0], [16.0, 0.0, 290.0, 678.0], [13.0, 0.0, 459.0, 724.0], [17.0, 0.0, 659.0, 886.0], [32.0, -218.0, 709.0, 676.0], [9.0, -10.0, 479.0, 674.0], [37.0, -10.0, 442.0, 674.0], [15.0, 0.0, 706.0, 813.0], [5.0, 0.0, 335.0, 674.0], [25.0, -215.0, 412.0, 460.0], [9.0, 0.0, 597.0, 835.0], [16.0, 0.0, 542.0, 662.0], [34.0, -14.0, 688.0, 813.0], [17.0, 0.0, 659.0, 890.0], [42.0, -14.0, 491.0, 890.0], [27.0, -10.0, 589.0, 695.0], [14.0, -14.0, 705.0, 813.0], [9.0, -10.0, 479.0, 711.0], [15.0, 262.0, 291.0, 676.0], [34.0, -14.0, 688.0, 890.0], [15.0, 0.0, 706.0, 890.0], [15.0, 0.0, 706.0, 876.0], [38.0, 8.0, 527.0, 497.0], [9.0, -10.0, 479.0, 678.0], [17.0, 0.0, 593.0, 886.0], [17.0, -38.0, 459.0, 710.0], [14.0, -218.0, 475.0, 623.0], [12.0, -11.0, 707.0, 890.0], [-16.0, 0.0, 295.0, 674.0], [12.0, 0.0, 597.0, 886.0], [37.0, -10.0, 442.0, 623.0], [25.0, -10.0, 424.0, 623.0], [25.0, -10.0, 413.0, 678.0], [16.0, 0.0, 485.0, 678.0], [9.0, -10.0, 479.0, 601.0], [12.0, -11.0, 707.0, 886.0], [18.0, 0.0, 317.0, 890.0], [30.0, 0.0, 534.0, 506.0], [67.0, -143.0, 133.0, 707.0], [38.0, -14.0, 722.0, 676.0], [32.0, -14.0, 709.0, 876.0], [18.0, 0.0, 315.0, 835.0], [15.0, -10.0, 585.0, 706.0], [12.0, 0.0, 597.0, 890.0], [5.0, 0.0, 335.0, 678.0], [29.0, -10.0, 470.0, 601.0], [9.0, 0.0, 597.0, 890.0], [9.0, 0.0, 597.0, 886.0], [26.0, 0.0, 523.0, 666.0], [16.0, 0.0, 685.0, 662.0], [28.0, -215.0, 633.0, 676.0], [19.0, -218.0, 257.0, 683.0], [13.0, -10.0, 318.0, 722.0], [25.0, -165.0, 424.0, 460.0], [14.0, -165.0, 705.0, 662.0], [15.0, 0.0, 706.0, 890.0], [15.0, 0.0, 706.0, 835.0], [25.0, -10.0, 424.0, 678.0], [27.0, 0.0, 418.0, 678.0], [16.0, -165.0, 265.0, 683.0], [34.0, -14.0, 688.0, 890.0], [29.0, -10.0, 470.0, 678.0], [37.0, -10.0, 442.0, 601.0], [51.0, -10.0, 348.0, 678.0], [-9.0, 0.0, 288.0, 623.0], [34.0, -14.0, 688.0, 886.0], [14.0, -14.0, 705.0, 890.0], [6.0, 0.0, 608.0, 688.0], [5.0, -217.0, 470.0, 683.0], [1.0, 270.0, 296.0, 676.0], [34.0, -14.0, 688.0, 835.0], [36.0, -218.0, 512.0, 450.0], [-8.0, 0.0, 253.0, 678.0], [29.0, -10.0, 491.0, 678.0], [12.0, -165.0, 597.0, 662.0], [27.0, -10.0, 500.0, 683.0], [15.0, -14.0, 718.0, 676.0], [42.0, -215.0, 491.0, 676.0], [19.0, 0.0, 347.0, 695.0], [34.0, -198.0, 723.0, 662.0], [12.0, 0.0, 598.0, 890.0], [30.0, 256.0, 957.0, 662.0], [25.0, -10.0, 424.0, 623.0], [18.0, 0.0, 315.0, 890.0], [11.0, 0.0, 322.0, 813.0], [12.0, 0.0, 598.0, 676.0], [31.0, -14.0, 746.0, 676.0], [26.0, 0.0, 523.0, 666.0], [29.0, -10.0, 470.0, 674.0], [16.0, 0.0, 485.0, 638.0], [14.0, -14.0, 705.0, 890.0], [12.0, 0.0, 597.0, 890.0], [25.0, -10.0, 424.0, 601.0], [28.0, -218.0, 470.0, 664.0], [37.0, -14.0, 718.0, 676.0], [42.0, -14.0, 491.0, 886.0], [42.0, -218.0, 491.0, 676.0], [34.0, -14.0, 688.0, 890.0], [57.0, 390.0, 343.0, 676.0], [29.0, -10.0, 470.0, 678.0], [28.0, -14.0, 633.0, 886.0], [9.0, -10.0, 479.0, 678.0], [2.0, -60.0, 452.0, 768.0], [16.0, 0.0, 685.0, 886.0], [5.0, -218.0, 335.0, 460.0], [12.0, -11.0, 707.0, 850.0], [29.0, -10.0, 470.0, 638.0], [17.0, -198.0, 659.0, 662.0], [12.0, -218.0, 598.0, 662.0], [15.0, 0.0, 706.0, 850.0], [15.0, -165.0, 738.0, 674.0], [15.0, 0.0, 706.0, 898.0], [34.0, -14.0, 688.0, 850.0], [27.0, 0.0, 418.0, 623.0], [12.0, 0.0, 597.0, 886.0], [18.0, -165.0, 315.0, 662.0], [7.0, -218.0, 505.0, 683.0], [30.0, 220.0, 534.0, 286.0], [11.0, 0.0, 322.0, 886.0], [16.0, 0.0, 485.0, 674.0], [13.0, -218.0, 279.0, 579.0], [30.0, 108.0, 534.0, 386.0], [29.0, -10.0, 470.0, 623.0], [9.0, -10.0, 479.0, 623.0], [12.0, -31.0, 537.0, 547.0], [28.0, -218.0, 470.0, 749.0], [29.0, -10.0, 471.0, 686.0], [27.0, 0.0, 418.0, 674.0], [16.0, -218.0, 485.0, 460.0], [57.0, 270.0, 248.0, 676.0], [6.0, 0.0, 271.0, 601.0], [0.0, 0.0, 0.0, 0.0]]),
+("ZapfDingbats", &[[0.0, 0.0, 0.0, 0.0], [35.0, 72.0, 939.0, 621.0], [35.0, 81.0, 927.0, 611.0], [35.0, 72.0, 939.0, 621.0], [35.0, 0.0, 945.0, 692.0], [34.0, 139.0, 685.0, 566.0], [35.0, -14.0, 755.0, 705.0], [35.0, -14.0, 755.0, 705.0], [35.0, -13.0, 761.0, 705.0], [34.0, 138.0, 655.0, 553.0], [35.0, 123.0, 925.0, 568.0], [35.0, 134.0, 904.0, 559.0], [29.0, -11.0, 516.0, 705.0], [34.0, 59.0, 820.0, 632.0], [35.0, 50.0, 876.0, 642.0], [35.0, 139.0, 899.0, 550.0], [35.0, 50.0, 876.0, 642.0], [35.0, 139.0, 909.0, 553.0], [35.0, 104.0, 938.0, 587.0], [34.0, -13.0, 721.0, 705.0], [36.0, -14.0, 811.0, 705.0], [35.0, 0.0, 727.0, 692.0], [35.0, 0.0, 727.0, 692.0], [-1.0, -68.0, 571.0, 661.0], [36.0, -13.0, 642.0, 705.0], [35.0, 0.0, 728.0, 692.0], [35.0, 0.0, 726.0, 692.0], [35.0, 0.0, 725.0, 692.0], [35.0, 0.0, 720.0, 692.0], [35.0, 0.0, 460.0, 692.0], [35.0, 0.0, 517.0, 692.0], [35.0, 0.0, 503.0, 692.0], [35.0, 96.0, 542.0, 596.0], [35.0, -14.0, 657.0, 705.0], [35.0, -14.0, 751.0, 705.0], [35.0, -14.0, 752.0, 705.0], [35.0, -14.0, 753.0, 705.0], [35.0, -14.0, 756.0, 705.0], [35.0, -13.0, 759.0, 705.0], [35.0, -13.0, 759.0, 705.0], [35.0, -14.0, 782.0, 705.0], [35.0, -14.0, 787.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 807.0, 705.0], [35.0, -14.0, 789.0, 705.0], [35.0, -14.0, 798.0, 705.0], [35.0, -13.0, 782.0, 705.0], [35.0, -14.0, 796.0, 705.0], [35.0, -14.0, 888.0, 705.0], [35.0, 0.0, 710.0, 692.0], [35.0, 0.0, 688.0, 692.0], [35.0, 0.0, 714.0, 692.0], [34.0, -14.0, 756.0, 705.0], [35.0, -14.0, 758.0, 705.0], [35.0, -14.0, 661.0, 706.0], [35.0, -6.0, 741.0, 699.0], [35.0, -7.0, 734.0, 699.0], [35.0, -14.0, 757.0, 705.0], [35.0, 0.0, 725.0, 692.0], [35.0, -13.0, 672.0, 704.0], [35.0, -14.0, 672.0, 705.0], [35.0, -14.0, 647.0, 705.0], [35.0, -14.0, 666.0, 705.0], [35.0, -14.0, 791.0, 705.0], [35.0, -14.0, 780.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [34.0, -14.0, 673.0, 705.0], [36.0, 0.0, 651.0, 692.0], [35.0, 0.0, 661.0, 691.0], [35.0, 0.0, 655.0, 692.0], [34.0, -14.0, 751.0, 705.0], [35.0, -14.0, 752.0, 705.0], [35.0, -14.0, 678.0, 705.0], [35.0, -14.0, 756.0, 705.0], [36.0, -14.0, 751.0, 705.0], [35.0, -14.0, 757.0, 705.0], [35.0, -14.0, 838.0, 705.0], [35.0, 0.0, 726.0, 692.0], [35.0, 0.0, 727.0, 692.0], [35.0, 0.0, 727.0, 692.0], [35.0, 0.0, 725.0, 692.0], [35.0, 0.0, 725.0, 692.0], [35.0, 0.0, 858.0, 705.0], [35.0, -14.0, 858.0, 692.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 749.0, 705.0], [35.0, -14.0, 403.0, 705.0], [35.0, 0.0, 104.0, 692.0], [35.0, 0.0, 242.0, 692.0], [35.0, 0.0, 380.0, 692.0], [35.0, 263.0, 357.0, 705.0], [34.0, 263.0, 357.0, 705.0], [35.0, 263.0, 633.0, 705.0], [36.0, 263.0, 634.0, 705.0], [35.0, -14.0, 356.0, 705.0], [35.0, -14.0, 355.0, 705.0], [35.0, 0.0, 283.0, 692.0], [35.0, 0.0, 283.0, 692.0], [35.0, 0.0, 242.0, 692.0], [35.0, 0.0, 242.0, 692.0], [35.0, 0.0, 475.0, 692.0], [35.0, 0.0, 475.0, 692.0], [35.0, 0.0, 375.0, 692.0], [35.0, 0.0, 375.0, 692.0], [35.0, -14.0, 199.0, 705.0], [35.0, -14.0, 199.0, 705.0], [35.0, 0.0, 299.0, 692.0], [35.0, 0.0, 299.0, 692.0], [35.0, -143.0, 697.0, 806.0], [56.0, -14.0, 488.0, 706.0], [34.0, -14.0, 508.0, 705.0], [35.0, 40.0, 875.0, 651.0], [35.0, -14.0, 633.0, 705.0], [35.0, -14.0, 726.0, 705.0], [0.0, 121.0, 758.0, 569.0], [35.0, 0.0, 741.0, 705.0], [34.0, -14.0, 560.0, 705.0], [35.0, -14.0, 659.0, 705.0], [34.0, 0.0, 591.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, -14.0, 754.0, 705.0], [35.0, 58.0, 860.0, 634.0], [35.0, 152.0, 803.0, 540.0], [34.0, 152.0, 981.0, 540.0], [35.0, -127.0, 422.0, 820.0], [35.0, 94.0, 698.0, 597.0], [35.0, 140.0, 890.0, 552.0], [35.0, 94.0, 698.0, 597.0], [35.0, 166.0, 884.0, 526.0], [35.0, 32.0, 892.0, 660.0], [35.0, 129.0, 891.0, 562.0], [35.0, 128.0, 893.0, 563.0], [35.0, 155.0, 799.0, 537.0], [35.0, 93.0, 838.0, 599.0], [35.0, 104.0, 791.0, 588.0], [35.0, 98.0, 889.0, 594.0], [35.0, 98.0, 889.0, 594.0], [35.0, 0.0, 882.0, 692.0], [35.0, 84.0, 896.0, 608.0], [35.0, 84.0, 896.0, 608.0], [35.0, -99.0, 429.0, 791.0], [35.0, 71.0, 848.0, 623.0], [35.0, 44.0, 802.0, 648.0], [35.0, 44.0, 802.0, 648.0], [35.0, 101.0, 832.0, 591.0], [35.0, 101.0, 832.0, 591.0], [35.0, 44.0, 661.0, 648.0], [35.0, 44.0, 661.0, 648.0], [35.0, 77.0, 840.0, 619.0], [35.0, 73.0, 840.0, 615.0], [35.0, 0.0, 725.0, 692.0], [35.0, 160.0, 911.0, 533.0], [34.0, 37.0, 736.0, 655.0], [35.0, 207.0, 830.0, 481.0], [34.0, 37.0, 736.0, 655.0], [34.0, -19.0, 853.0, 712.0], [35.0, 124.0, 932.0, 568.0], [34.0, -19.0, 853.0, 712.0], [35.0, 113.0, 796.0, 579.0], [36.0, 118.0, 838.0, 578.0], [35.0, 150.0, 891.0, 542.0], [35.0, 76.0, 931.0, 616.0], [34.0, 99.0, 884.0, 593.0]]),
+]) }
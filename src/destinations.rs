@@ -0,0 +1,112 @@
+// Destinations: a page and a view of it
+//
+// Links and bookmarks point to explicit destinations, arrays of a page and a
+// view such as `[page /XYZ left top zoom]`, or to named destinations looked
+// up in the catalog's `/Dests` name tree or, in PDF 1.1 files, its `/Dests`
+// dictionary.
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{document_utils, object_utils, string_utils, Document, Object, ObjectId, PdfResult};
+
+/// How a destination displays its page; coordinates are in default user space, `None` keeps the
+/// viewer's current value
+#[derive(Debug, Clone, PartialEq)]
+pub enum View {
+    Xyz { left: Option<f64>, top: Option<f64>, zoom: Option<f64> },
+    Fit,
+    FitH { top: Option<f64> },
+    FitV { left: Option<f64> },
+    FitR { left: f64, bottom: f64, right: f64, top: f64 },
+    FitB,
+    FitBH { top: Option<f64> },
+    FitBV { left: Option<f64> },
+}
+
+/// A resolved destination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Destination {
+    /// Page number, `None` if the page reference doesn't point to a page of the document
+    pub page: Option<u32>,
+    pub view: View,
+}
+
+/// Resolves explicit and named destinations of one document
+pub struct Destinations<'a> {
+    doc: &'a Document,
+    pages: HashMap<ObjectId, u32>,
+    named: HashMap<&'a [u8], &'a Object>,
+}
+
+impl<'a> Destinations<'a> {
+    pub fn new(doc: &'a Document) -> PdfResult<Destinations<'a>> {
+        let pages = doc.get_pages().into_iter().map(|(num, id)| (id, num)).collect();
+        let mut named: HashMap<&[u8], &Object> = document_utils::name_tree(doc, b"Dests")?.into_iter().collect();
+        let catalog = document_utils::get_catalog(doc)?;
+        if let Some(dests) = object_utils::maybe_get_obj(doc, catalog, b"Dests").and_then(|o| o.as_dict().ok()) {
+            for (name, dest) in dests.iter() {
+                named.entry(name.as_slice()).or_insert(dest);
+            }
+        }
+        Ok(Destinations { doc, pages, named })
+    }
+
+    /// Resolve an explicit destination array, or the name or string of a named destination
+    pub fn resolve(&self, dest: &Object) -> Option<Destination> {
+        let dest = match object_utils::maybe_deref(self.doc, dest).ok()? {
+            Object::Name(name) | Object::String(name, _) => self.named_array(name)?,
+            dest => dest,
+        };
+        let dest = dest.as_array().ok()?;
+        let page = match dest.first()? {
+            Object::Reference(id) => self.pages.get(id).copied(),
+            // Remote-style destinations give a zero based page index
+            Object::Integer(index) => u32::try_from(*index).ok().map(|i| i + 1),
+            _ => None,
+        };
+        Some(Destination { page, view: view(self.doc, &dest[1..]) })
+    }
+
+    /// Every named destination that resolves, by name
+    pub fn named(&self) -> BTreeMap<String, Destination> {
+        self.named.keys()
+            .filter_map(|name| {
+                let dest = self.resolve(self.named_array(name)?)?;
+                Some((string_utils::pdf_to_utf8(name).ok()?, dest))
+            })
+            .collect()
+    }
+
+    // Named destinations are arrays or dictionaries holding the array in `/D`
+    fn named_array(&self, name: &[u8]) -> Option<&'a Object> {
+        let named = object_utils::maybe_deref(self.doc, self.named.get(name)?).ok()?;
+        match named.as_dict() {
+            Ok(dict) => object_utils::maybe_get_obj(self.doc, dict, b"D"),
+            Err(_) => Some(named),
+        }
+    }
+
+}
+
+/// Named destinations of `doc` by name, resolved to pages and views
+pub fn extract_named_destinations(doc: &Document) -> PdfResult<BTreeMap<String, Destination>> {
+    Ok(Destinations::new(doc)?.named())
+}
+
+// View of the operands after the page; unknown or missing types show the whole page
+fn view(doc: &Document, operands: &[Object]) -> View {
+    let num = |i: usize| operands.get(i).and_then(|o| object_utils::maybe_deref(doc, o).ok()).and_then(|o| object_utils::as_num(o).ok());
+    let fit = operands.first().and_then(|o| o.as_name().ok()).unwrap_or(b"Fit");
+    match fit {
+        b"XYZ" => View::Xyz { left: num(1), top: num(2), zoom: num(3).filter(|&z| z != 0.) },
+        b"FitH" => View::FitH { top: num(1) },
+        b"FitV" => View::FitV { left: num(1) },
+        b"FitR" => match (num(1), num(2), num(3), num(4)) {
+            (Some(left), Some(bottom), Some(right), Some(top)) => View::FitR { left, bottom, right, top },
+            _ => View::Fit,
+        },
+        b"FitB" => View::FitB,
+        b"FitBH" => View::FitBH { top: num(1) },
+        b"FitBV" => View::FitBV { left: num(1) },
+        _ => View::Fit,
+    }
+}
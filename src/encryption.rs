@@ -0,0 +1,116 @@
+// Encryption dictionary of a loaded document
+//
+// `Document::load` leaves encrypted documents encrypted and their
+// `/Encrypt` dictionary in the trailer, so the handler and permissions can
+// be checked before choosing a password; `decrypt` removes the dictionary.
+use crate::{object_utils, Dictionary, Document, Object};
+
+/// The security handler and restrictions of an encrypted document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptionInfo {
+    /// `/Filter`, `Standard` for password security
+    pub handler: String,
+    /// `/SubFilter` of public-key handlers
+    pub sub_filter: Option<String>,
+    /// Algorithm version `/V`
+    pub version: i64,
+    /// Standard handler revision `/R`
+    pub revision: Option<i64>,
+    /// File encryption key length in bits
+    pub key_length: u32,
+    /// Crypt filter method of streams: `RC4`, `AESV2`, `AESV3`, or `Identity` when they are left unencrypted
+    pub method: String,
+    /// Whether the XMP metadata stream is encrypted too
+    pub encrypt_metadata: bool,
+    pub permissions: Permissions,
+}
+
+/// User access permissions, `/P`; owners may do everything
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions {
+    pub print: bool,
+    /// Print at full quality rather than a degraded raster
+    pub print_high_quality: bool,
+    pub modify: bool,
+    /// Copy or otherwise extract text and graphics
+    pub copy: bool,
+    /// Extract text and graphics for accessibility
+    pub extract_for_accessibility: bool,
+    pub annotate: bool,
+    pub fill_forms: bool,
+    /// Insert, rotate or delete pages
+    pub assemble: bool,
+}
+
+impl Permissions {
+    /// Decode the `/P` flags of a standard handler revision
+    pub fn from_flags(p: i64, revision: i64) -> Permissions {
+        let bit = |n: u32| p & (1 << (n - 1)) != 0;
+        if revision >= 3 {
+            Permissions {
+                print: bit(3),
+                print_high_quality: bit(3) && bit(12),
+                modify: bit(4),
+                copy: bit(5),
+                extract_for_accessibility: bit(10),
+                annotate: bit(6),
+                fill_forms: bit(6) || bit(9),
+                assemble: bit(11),
+            }
+        } else {
+            // Revision 2 has no bits 9 to 12; the basic bits cover them
+            Permissions {
+                print: bit(3),
+                print_high_quality: bit(3),
+                modify: bit(4),
+                copy: bit(5),
+                extract_for_accessibility: bit(5),
+                annotate: bit(6),
+                fill_forms: bit(6),
+                assemble: bit(4),
+            }
+        }
+    }
+}
+
+/// Encryption details of `doc`, `None` if it isn't (or is no longer) encrypted
+pub fn encryption_info(doc: &Document) -> Option<EncryptionInfo> {
+    let dict = doc.get_encrypted().ok()?;
+    let name = |dict: &Dictionary, key: &[u8]| {
+        object_utils::maybe_get_obj(doc, dict, key)
+            .and_then(|o| o.as_name().ok())
+            .map(|n| String::from_utf8_lossy(n).into_owned())
+    };
+    let int = |key: &[u8]| object_utils::maybe_get_obj(doc, dict, key).and_then(|o| o.as_i64().ok());
+    let version = int(b"V").unwrap_or(0);
+    let revision = int(b"R");
+
+    // Versions 4 and 5 name a crypt filter for streams, whose `/CFM` is the method
+    let stream_filter = name(dict, b"StmF").unwrap_or_else(|| "Identity".to_string());
+    let crypt_filter = object_utils::maybe_get_obj(doc, dict, b"CF")
+        .and_then(|o| o.as_dict().ok())
+        .and_then(|filters| object_utils::maybe_get_obj(doc, filters, stream_filter.as_bytes()))
+        .and_then(|o| o.as_dict().ok());
+    let method = match (version, crypt_filter.and_then(|cf| name(cf, b"CFM"))) {
+        (4.., Some(method)) if method != "V2" => method,
+        (4.., None) if stream_filter == "Identity" => "Identity".to_string(),
+        _ => "RC4".to_string(),
+    };
+    let key_length = match version {
+        1 => 40,
+        5.. => 256,
+        4 if method.starts_with("AES") => 128,
+        _ => int(b"Length").and_then(|bits| u32::try_from(bits).ok()).unwrap_or(40),
+    };
+
+    Some(EncryptionInfo {
+        handler: name(dict, b"Filter").unwrap_or_default(),
+        sub_filter: name(dict, b"SubFilter"),
+        version,
+        revision,
+        key_length,
+        method,
+        encrypt_metadata: !matches!(object_utils::maybe_get_obj(doc, dict, b"EncryptMetadata"), Some(Object::Boolean(false))),
+        permissions: Permissions::from_flags(int(b"P").unwrap_or(-1), revision.unwrap_or(2)),
+    })
+}
@@ -0,0 +1,305 @@
+// Configurable entry point for extraction
+//
+// `Extractor` gathers what the `extract_text*` free functions spread over
+// name variants (password, in-memory vs. path, whole document vs. by page)
+// plus page selection, layout and interpreter options; the free functions
+// are shorthands for its defaults:
+//
+//     let text = Extractor::new().password("secret").pages(1..=3).build().extract_text("in.pdf")?;
+//
+// Beyond text, the selected pages can be run through any `OutputDev`:
+//
+//     let extractor = Extractor::new().pages(2..=2).options(options).build();
+//     let doc = extractor.load_document("in.pdf")?;
+//     let diagnostics = extractor.output(&doc, &mut HTMLOutput::new(file))?;
+use std::collections::BTreeSet;
+
+use crate::layout::{reflow, PageText, TextCollector};
+use crate::page_labels::extract_page_labels;
+use crate::page_ranges::PageRanges;
+use crate::recovery;
+use crate::spacing::{GapCollector, SpacingModel};
+use crate::{
+    maybe_decrypt, Diagnostics, Document, ExtractionOptions, LayoutParams, OutputDev, PageRunner, PdfError, PdfResult,
+    PlainTextOutput,
+};
+
+/// How extracted text is laid out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextLayout {
+    /// The line breaks and spacing of `PlainTextOutput`, which follow the page visually
+    #[default]
+    Plain,
+    /// One paragraph per line, original line breaks unwrapped, paragraphs separated by blank lines
+    Paragraphs,
+    /// `Paragraphs`, re-wrapped to lines of at most `width` characters
+    Reflow { width: usize },
+    /// `Plain`, with word gaps learned per font in a first pass over the selected pages
+    AdaptiveSpacing,
+}
+
+// Pages chosen with `ExtractorBuilder::pages` or `ExtractorBuilder::page_ranges`
+#[derive(Debug, Clone)]
+enum PageSelection {
+    Pages(BTreeSet<u32>),
+    Ranges(PageRanges),
+}
+
+/// Builder for an `Extractor`, see `Extractor::new`
+#[derive(Debug, Clone, Default)]
+pub struct ExtractorBuilder {
+    password: Option<String>,
+    pages: Option<PageSelection>,
+    layout: TextLayout,
+    params: LayoutParams,
+    options: ExtractionOptions,
+    recover: bool,
+}
+
+impl ExtractorBuilder {
+    /// Password for encrypted documents; without one only an empty user password is tried
+    pub fn password(mut self, password: &str) -> ExtractorBuilder {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Restrict extraction to these 1-based page numbers; pages the document lacks are skipped
+    /// with a `WarningKind::Recovered` warning on their page number
+    pub fn pages<I: IntoIterator<Item = u32>>(mut self, pages: I) -> ExtractorBuilder {
+        self.pages = Some(PageSelection::Pages(pages.into_iter().collect()));
+        self
+    }
+
+    /// Restrict extraction to a page selection expression such as `1-3,7,12-`, resolved against
+    /// each document's page count; pages are extracted in the order the expression lists them
+    pub fn page_ranges(mut self, ranges: PageRanges) -> ExtractorBuilder {
+        self.pages = Some(PageSelection::Ranges(ranges));
+        self
+    }
+
+    pub fn layout(mut self, layout: TextLayout) -> ExtractorBuilder {
+        self.layout = layout;
+        self
+    }
+
+    /// Line and word break thresholds of the plain text and paragraph layouts
+    pub fn layout_params(mut self, params: LayoutParams) -> ExtractorBuilder {
+        self.params = params;
+        self
+    }
+
+    /// Options passed to the content stream interpreter
+    pub fn options(mut self, options: ExtractionOptions) -> ExtractorBuilder {
+        self.options = options;
+        self
+    }
+
+    /// Rebuild the cross-reference table of files that fail to load, see `recovery`
+    pub fn recover(mut self, recover: bool) -> ExtractorBuilder {
+        self.recover = recover;
+        self
+    }
+
+    pub fn build(self) -> Extractor {
+        Extractor {
+            password: self.password,
+            pages: self.pages,
+            layout: self.layout,
+            params: self.params,
+            options: self.options,
+            recover: self.recover,
+        }
+    }
+}
+
+/// Text extraction with a fixed configuration, reusable across documents
+#[derive(Debug, Clone)]
+pub struct Extractor {
+    password: Option<String>,
+    pages: Option<PageSelection>,
+    layout: TextLayout,
+    params: LayoutParams,
+    options: ExtractionOptions,
+    recover: bool,
+}
+
+impl Extractor {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> ExtractorBuilder {
+        ExtractorBuilder::default()
+    }
+
+    pub fn extract_text<P: AsRef<std::path::Path>>(&self, path: P) -> PdfResult<String> {
+        self.text(&self.load_document(path)?)
+    }
+
+    pub fn extract_text_from_mem(&self, buffer: &[u8]) -> PdfResult<String> {
+        self.text(&self.load_document_from_mem(buffer)?)
+    }
+
+    pub fn extract_text_by_pages<P: AsRef<std::path::Path>>(&self, path: P) -> PdfResult<Vec<String>> {
+        self.text_by_pages(&self.load_document(path)?)
+    }
+
+    pub fn extract_text_from_mem_by_pages(&self, buffer: &[u8]) -> PdfResult<Vec<String>> {
+        self.text_by_pages(&self.load_document_from_mem(buffer)?)
+    }
+
+    /// Load and decrypt a document with the configured password and recovery
+    pub fn load_document<P: AsRef<std::path::Path>>(&self, path: P) -> PdfResult<Document> {
+        let doc = match self.recover {
+            true => recovery::load_with_recovery(path)?,
+            false => Document::load(path)?,
+        };
+        self.decrypt(doc)
+    }
+
+    pub fn load_document_from_mem(&self, buffer: &[u8]) -> PdfResult<Document> {
+        let doc = match self.recover {
+            true => recovery::load_mem_with_recovery(buffer)?,
+            false => Document::load_mem(buffer)?,
+        };
+        self.decrypt(doc)
+    }
+
+    /// Run the selected pages of an already loaded document through `output` with the configured
+    /// options
+    pub fn output(&self, doc: &Document, output: &mut dyn OutputDev) -> PdfResult<Diagnostics> {
+        let (mut runner, pages) = self.runner(doc);
+        runner.output(output, &pages)
+    }
+
+    /// Text of an already loaded (and, if needed, decrypted) document
+    pub fn text(&self, doc: &Document) -> PdfResult<String> {
+        let (mut runner, pages) = self.runner(doc);
+        match self.layout {
+            TextLayout::Plain => {
+                let mut s = Vec::new();
+                runner.output(&mut PlainTextOutput::new(&mut s).layout_params(self.params), &pages)?;
+                into_string(s)
+            }
+            TextLayout::Paragraphs | TextLayout::Reflow { .. } => Ok(collect(&mut runner, &pages)?
+                .iter()
+                .map(|page| self.paragraph_text(page))
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n")),
+            TextLayout::AdaptiveSpacing => {
+                let spacing = learn_spacing(&mut runner, &pages)?;
+                let mut s = Vec::new();
+                runner.output(&mut PlainTextOutput::new(&mut s).layout_params(self.params).spacing(spacing), &pages)?;
+                into_string(s)
+            }
+        }
+    }
+
+    /// Text of each selected page of an already loaded document, in page order
+    pub fn text_by_pages(&self, doc: &Document) -> PdfResult<Vec<String>> {
+        self.page_texts(doc)?.map(|page| page.map(|(_, text)| text)).collect()
+    }
+
+    /// Page numbers and text of the selected pages, each page extracted only when the iterator
+    /// reaches it; `AdaptiveSpacing` still makes its first pass over the selection up front.
+    /// The pages share fonts and decoded forms loaded for earlier ones.
+    pub fn page_texts<'a>(&'a self, doc: &'a Document) -> PdfResult<PageTextIter<'a>> {
+        let (mut runner, pages) = self.runner(doc);
+        // Spacing is learned from the whole selection, not page by page
+        let spacing = match self.layout {
+            TextLayout::AdaptiveSpacing => Some(learn_spacing(&mut runner, &pages)?),
+            _ => None,
+        };
+        Ok(PageTextIter { extractor: self, runner, pages: pages.into_iter(), spacing })
+    }
+
+    /// `text_by_pages` with each page's printed label, see `page_labels::extract_page_labels`
+    pub fn text_by_labeled_pages(&self, doc: &Document) -> PdfResult<Vec<(String, String)>> {
+        let mut labels = extract_page_labels(doc)?;
+        self.page_texts(doc)?
+            .map(|page| page.map(|(n, text)| (labels.remove(&n).unwrap_or_else(|| n.to_string()), text)))
+            .collect()
+    }
+
+    fn decrypt(&self, mut doc: Document) -> PdfResult<Document> {
+        match &self.password {
+            Some(password) if doc.is_encrypted() => doc.decrypt(password)?,
+            _ => maybe_decrypt(&mut doc)?,
+        }
+        Ok(doc)
+    }
+
+    // A runner over `doc` with the selected pages it has, in extraction order
+    fn runner<'a>(&'a self, doc: &'a Document) -> (PageRunner<'a>, Vec<u32>) {
+        let mut runner = PageRunner::new(doc, &self.options);
+        let pages = match &self.pages {
+            Some(PageSelection::Pages(selected)) => runner.existing_pages(selected.iter().copied()),
+            Some(PageSelection::Ranges(ranges)) => {
+                let pages = ranges.resolve(runner.page_count());
+                runner.existing_pages(pages)
+            }
+            None => runner.page_numbers(),
+        };
+        (runner, pages)
+    }
+
+    fn page_text(&self, runner: &mut PageRunner<'_>, page_num: u32, spacing: Option<&SpacingModel>) -> PdfResult<String> {
+        match self.layout {
+            TextLayout::Plain | TextLayout::AdaptiveSpacing => {
+                let mut s = Vec::new();
+                let mut output = PlainTextOutput::new(&mut s).layout_params(self.params);
+                if let Some(spacing) = spacing {
+                    output = output.spacing(spacing.clone());
+                }
+                runner.output(&mut output, &[page_num])?;
+                into_string(s)
+            }
+            TextLayout::Paragraphs | TextLayout::Reflow { .. } => {
+                Ok(collect(runner, &[page_num])?.iter().map(|page| self.paragraph_text(page)).collect())
+            }
+        }
+    }
+
+    fn paragraph_text(&self, page: &PageText) -> String {
+        let width = match self.layout {
+            TextLayout::Reflow { width } => Some(width),
+            _ => None,
+        };
+        reflow(&page.paragraphs_with(self.params), width)
+    }
+}
+
+/// Text of one page at a time, see `Extractor::page_texts`
+pub struct PageTextIter<'a> {
+    extractor: &'a Extractor,
+    runner: PageRunner<'a>,
+    pages: std::vec::IntoIter<u32>,
+    spacing: Option<SpacingModel>,
+}
+
+impl Iterator for PageTextIter<'_> {
+    type Item = PdfResult<(u32, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page_num = self.pages.next()?;
+        Some(self.extractor.page_text(&mut self.runner, page_num, self.spacing.as_ref()).map(|text| (page_num, text)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pages.size_hint()
+    }
+}
+
+fn learn_spacing(runner: &mut PageRunner<'_>, pages: &[u32]) -> PdfResult<SpacingModel> {
+    let mut collector = GapCollector::new();
+    runner.output(&mut collector, pages)?;
+    Ok(collector.into_model())
+}
+
+fn collect(runner: &mut PageRunner<'_>, pages: &[u32]) -> PdfResult<Vec<PageText>> {
+    let mut collector = TextCollector::new();
+    runner.output(&mut collector, pages)?;
+    Ok(collector.into_pages())
+}
+
+fn into_string(s: Vec<u8>) -> PdfResult<String> {
+    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+}
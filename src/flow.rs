@@ -0,0 +1,226 @@
+// Reflowable HTML built from the layout analysis
+//
+// `HTMLOutput` places every run of text absolutely, which reproduces the
+// look of a page but not its structure. This device collects the glyphs of
+// each page and writes them as markup a reader can reflow: paragraphs, with
+// those set larger than the body text as headings, bulleted and numbered
+// lines as lists, and the aligned columns `tables` detects as tables. The
+// body size is the lower median of the line sizes seen so far, so it settles
+// after the first pages of body text.
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::layout::{heading_level, join_lines, Paragraph, TextCollector, TextLine};
+use crate::string_utils::escape_xml;
+use crate::tables::{detect_tables, Table};
+use crate::{CharInfo, ColorSpace, MediaBox, OutputDev, PdfFont, PdfResult, PdfTransform};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bulleted,
+    Numbered,
+}
+
+impl ListKind {
+    fn tag(self) -> &'static str {
+        match self {
+            ListKind::Bulleted => "ul",
+            ListKind::Numbered => "ol",
+        }
+    }
+}
+
+/// Output device writing each page as `<p>`, `<h1>` to `<h6>`, `<ul>`, `<ol>` and `<table>`
+/// markup in a `<section>`
+pub struct HtmlFlowOutput<W: Write> {
+    writer: W,
+    collector: TextCollector,
+    // Number of lines of the pages written so far by font size in hundredths of a point
+    sizes: BTreeMap<i64, usize>,
+    lines: usize,
+}
+
+impl<W: Write> HtmlFlowOutput<W> {
+    pub fn new(writer: W) -> HtmlFlowOutput<W> {
+        HtmlFlowOutput { writer, collector: TextCollector::new(), sizes: BTreeMap::new(), lines: 0 }
+    }
+
+    // Lower median of the line sizes seen so far
+    fn body_size(&self) -> f64 {
+        let mut below = self.lines.saturating_sub(1) / 2;
+        for (&size, &count) in &self.sizes {
+            if below < count {
+                return size as f64 / 100.;
+            }
+            below -= count;
+        }
+        0.
+    }
+
+    fn write_page(&mut self, page_num: u32, paragraphs: &[Paragraph], tables: &[Table]) -> PdfResult<()> {
+        let body = self.body_size();
+        let w = &mut self.writer;
+        writeln!(w, "<section id='page{}'>", page_num)?;
+        let mut written = vec![false; tables.len()];
+        let mut list: Option<ListKind> = None;
+        for paragraph in paragraphs {
+            let table = tables.iter().position(|t| paragraph.lines.iter().any(|l| t.bbox.intersects(&l.bbox)));
+            let Some(index) = table else {
+                write_paragraph(w, paragraph, &paragraph.lines, body, &mut list)?;
+                continue;
+            };
+            // Lines of the paragraph outside the table go before or after it
+            let bbox = tables[index].bbox;
+            let outside = |l: &&TextLine| !bbox.intersects(&l.bbox);
+            let (above, below): (Vec<TextLine>, Vec<TextLine>) = paragraph.lines.iter()
+                .filter(outside)
+                .cloned()
+                .partition(|l| l.bbox.lly >= bbox.ury);
+            write_paragraph(w, paragraph, &above, body, &mut list)?;
+            if !written[index] {
+                close_list(w, &mut list)?;
+                write_table(w, &tables[index])?;
+                written[index] = true;
+            }
+            write_paragraph(w, paragraph, &below, body, &mut list)?;
+        }
+        close_list(w, &mut list)?;
+        writeln!(w, "</section>")?;
+        Ok(())
+    }
+}
+
+// Write `lines` of `paragraph` as a heading, list items or a paragraph
+fn write_paragraph<W: Write>(w: &mut W, paragraph: &Paragraph, lines: &[TextLine], body: f64, list: &mut Option<ListKind>) -> PdfResult<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let lang = paragraph.lang().map_or(String::new(), |lang| format!(" lang='{}'", escape_xml(lang)));
+    if list_item(lines[0].text.trim()).is_some() {
+        // A new item starts at each line with a marker
+        let mut start = 0;
+        for end in 1..=lines.len() {
+            if end < lines.len() && list_item(lines[end].text.trim()).is_none() {
+                continue;
+            }
+            let text = join_lines(&lines[start..end]);
+            let (kind, text) = list_item(&text).expect("items start with a marker");
+            if *list != Some(kind) {
+                close_list(w, list)?;
+                writeln!(w, "<{}>", kind.tag())?;
+                *list = Some(kind);
+            }
+            writeln!(w, "<li{}>{}</li>", lang, escape_xml(text))?;
+            start = end;
+        }
+        return Ok(());
+    }
+    close_list(w, list)?;
+    let text = join_lines(lines);
+    if text.is_empty() {
+        return Ok(());
+    }
+    match heading_level(lines, body) {
+        Some(level) => writeln!(w, "<h{}{}>{}</h{}>", level, lang, escape_xml(&text), level)?,
+        None => writeln!(w, "<p{}>{}</p>", lang, escape_xml(&text))?,
+    }
+    Ok(())
+}
+
+fn close_list<W: Write>(w: &mut W, list: &mut Option<ListKind>) -> PdfResult<()> {
+    if let Some(kind) = list.take() {
+        writeln!(w, "</{}>", kind.tag())?;
+    }
+    Ok(())
+}
+
+// The first row as headers
+fn write_table<W: Write>(w: &mut W, table: &Table) -> PdfResult<()> {
+    writeln!(w, "<table>")?;
+    for (i, row) in table.cell_texts().iter().enumerate() {
+        let tag = if i == 0 { "th" } else { "td" };
+        let cells: String = row.iter().map(|cell| format!("<{}>{}</{}>", tag, escape_xml(cell), tag)).collect();
+        writeln!(w, "<tr>{}</tr>", cells)?;
+    }
+    writeln!(w, "</table>")?;
+    Ok(())
+}
+
+// The kind of list a line starting with a bullet or item number belongs to, and the text after
+// the marker
+fn list_item(text: &str) -> Option<(ListKind, &str)> {
+    let (marker, rest) = text.split_once(char::is_whitespace)?;
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        return None;
+    }
+    if matches!(marker, "•" | "◦" | "▪" | "‣" | "●" | "○" | "■" | "–" | "-" | "*") {
+        return Some((ListKind::Bulleted, rest));
+    }
+    let number = marker.strip_suffix(['.', ')'])?;
+    let numbered = (1..=3).contains(&number.len()) && number.bytes().all(|b| b.is_ascii_digit())
+        || number.len() == 1 && number.bytes().all(|b| b.is_ascii_lowercase());
+    numbered.then_some((ListKind::Numbered, rest))
+}
+
+impl<W: Write> OutputDev for HtmlFlowOutput<W> {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.collector.begin_page(page_num, media_box, art_box)
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        self.collector.end_page()?;
+        let Some(page) = self.collector.pages().last() else {
+            return Ok(());
+        };
+        let (page_num, paragraphs, tables) = (page.page_num, page.paragraphs(), detect_tables(page));
+        for line in paragraphs.iter().flat_map(|p| &p.lines) {
+            *self.sizes.entry((line.font_size * 100.).round() as i64).or_default() += 1;
+            self.lines += 1;
+        }
+        self.write_page(page_num, &paragraphs, &tables)?;
+        // The glyphs aren't needed once the page is written
+        self.collector = TextCollector::new();
+        Ok(())
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        self.collector.output_character(trm, width, spacing, font_size, char)
+    }
+
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.collector.output_char_info(trm, width, spacing, font_size, char, info)
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        self.collector.set_font(font)
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.collector.set_fill_color(colorspace, color, alpha)
+    }
+
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> {
+        self.collector.set_language(lang)
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.collector.begin_word()
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> {
+        self.collector.end_word()
+    }
+
+    fn end_line(&mut self) -> PdfResult<()> {
+        self.collector.end_line()
+    }
+}
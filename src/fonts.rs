@@ -0,0 +1,508 @@
+// Font inventory for predicting extraction quality and embedded font program extraction
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::OnceLock;
+
+use cff_parser::{GlyphId, Table};
+
+use crate::report::{self, WarningKind};
+use crate::sfnt::Sfnt;
+use crate::{
+    get_contents, get_inherited, maybe_get_array, maybe_get_name, object_utils, string_utils, CharCode, Dictionary,
+    Document, Object, ObjectId, PdfError, PdfResult, Stream,
+};
+
+/// How a font maps character codes to glyphs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontEncoding {
+    /// A predefined encoding or CMap such as `WinAnsiEncoding` or `Identity-H`
+    Named(String),
+    /// An encoding dictionary with `/Differences`, optionally on top of a base encoding
+    Differences { base: Option<String> },
+    /// An embedded CMap stream (composite fonts)
+    EmbeddedCMap,
+    /// No `/Encoding` entry; the font program's built-in encoding applies
+    Builtin,
+}
+
+/// Summary of a font resource
+#[derive(Debug, Clone)]
+pub struct FontInfo {
+    /// Indirect object of the font dictionary, if it is not inline
+    pub object_id: Option<ObjectId>,
+    /// Resource name the font was first found under, e.g. `F1`
+    pub resource_name: String,
+    pub base_font: Option<String>,
+    pub subtype: String,
+    /// Whether a `FontFile`/`FontFile2`/`FontFile3` program is embedded
+    pub embedded: bool,
+    pub encoding: FontEncoding,
+    pub has_to_unicode: bool,
+    /// Pages (1 based) on which the font is referenced, in ascending order
+    pub pages: Vec<u32>,
+}
+
+impl FontInfo {
+    /// Whether text in this font is likely to decode to meaningful Unicode.
+    ///
+    /// Composite fonts without a ToUnicode map and symbolic core fonts are the
+    /// usual sources of empty or garbled output.
+    pub fn likely_extractable(&self) -> bool {
+        if self.has_to_unicode {
+            return true;
+        }
+        match self.subtype.as_str() {
+            "Type0" => false,
+            _ => !matches!(self.encoding, FontEncoding::Builtin) || !self.embedded,
+        }
+    }
+}
+
+/// Fonts referenced by page `page_num`, including fonts used inside its form XObjects
+pub fn list_page_fonts(doc: &Document, page_num: u32) -> PdfResult<Vec<FontInfo>> {
+    let pages = doc.get_pages();
+    let page_id = pages.get(&page_num)
+        .ok_or_else(|| PdfError::InvalidStructure(format!("Page {} not found", page_num)))?;
+    let mut fonts = Vec::new();
+    collect_page_fonts(doc, page_num, *page_id, &mut fonts)?;
+    Ok(fonts)
+}
+
+/// Every distinct font in the document with the pages it is used on
+pub fn list_fonts(doc: &Document) -> PdfResult<Vec<FontInfo>> {
+    let mut fonts: Vec<FontInfo> = Vec::new();
+    let mut by_id: BTreeMap<ObjectId, usize> = BTreeMap::new();
+    for (page_num, page_id) in doc.get_pages() {
+        let mut page_fonts = Vec::new();
+        collect_page_fonts(doc, page_num, page_id, &mut page_fonts)?;
+        for font in page_fonts {
+            match font.object_id.and_then(|id| by_id.get(&id)) {
+                Some(&index) => {
+                    if !fonts[index].pages.contains(&page_num) {
+                        fonts[index].pages.push(page_num);
+                    }
+                }
+                None => {
+                    if let Some(id) = font.object_id {
+                        by_id.insert(id, fonts.len());
+                    }
+                    fonts.push(font);
+                }
+            }
+        }
+    }
+    Ok(fonts)
+}
+
+/// Format of an embedded font program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontProgramKind {
+    /// `FontFile`, rewrapped as a PFB file
+    Type1,
+    /// `FontFile2`
+    TrueType,
+    /// `FontFile3` with subtype `Type1C` or `CIDFontType0C`
+    Cff,
+    /// `FontFile3` with subtype `OpenType`
+    OpenType,
+}
+
+impl FontProgramKind {
+    /// File extension conventionally used for the program
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FontProgramKind::Type1 => "pfb",
+            FontProgramKind::TrueType => "ttf",
+            FontProgramKind::Cff => "cff",
+            FontProgramKind::OpenType => "otf",
+        }
+    }
+}
+
+/// A font program embedded in the document
+#[derive(Debug, Clone)]
+pub struct EmbeddedFont {
+    /// Font dictionary the program was first found through
+    pub font_id: Option<ObjectId>,
+    /// Object of the font program stream
+    pub program_id: ObjectId,
+    pub base_font: Option<String>,
+    pub kind: FontProgramKind,
+    /// Decompressed program, ready to be written to a file
+    pub data: Vec<u8>,
+}
+
+impl EmbeddedFont {
+    pub fn extension(&self) -> &'static str {
+        self.kind.extension()
+    }
+
+    /// `BaseFont` plus extension, e.g. `ABCDEF+Times-Roman.ttf`
+    pub fn file_name(&self) -> String {
+        let stem: String = self.base_font.as_deref().unwrap_or("font")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || "+-_.".contains(c) { c } else { '_' })
+            .collect();
+        format!("{}.{}", stem, self.extension())
+    }
+}
+
+/// Every distinct embedded font program in the document, in page order.
+///
+/// Programs shared by several font dictionaries are returned once.
+pub fn extract_fonts(doc: &Document) -> PdfResult<Vec<EmbeddedFont>> {
+    let mut fonts: Vec<EmbeddedFont> = Vec::new();
+    for (_, page_id) in doc.get_pages() {
+        for_each_page_font(doc, page_id, &mut |_, font_id, font| {
+            if let Some((program_id, kind, stream)) = font_program(doc, font)
+                && !fonts.iter().any(|f| f.program_id == program_id)
+            {
+                let data = match kind {
+                    FontProgramKind::Type1 => to_pfb(stream)?,
+                    _ => get_contents(stream)?.into_owned(),
+                };
+                fonts.push(EmbeddedFont {
+                    font_id,
+                    program_id,
+                    base_font: name_string(doc, font, b"BaseFont"),
+                    kind,
+                    data,
+                });
+            }
+            Ok(())
+        })?;
+    }
+    Ok(fonts)
+}
+
+fn collect_page_fonts(doc: &Document, page_num: u32, page_id: ObjectId, fonts: &mut Vec<FontInfo>) -> PdfResult<()> {
+    for_each_page_font(doc, page_id, &mut |name, object_id, font| {
+        if object_id.is_none_or(|id| !fonts.iter().any(|f| f.object_id == Some(id))) {
+            fonts.push(font_info(doc, font, object_id, name, page_num)?);
+        }
+        Ok(())
+    })
+}
+
+type FontVisitor<'f, 'a> = dyn FnMut(&str, Option<ObjectId>, &'a Dictionary) -> PdfResult<()> + 'f;
+
+// Calls `visit` with every font resource reachable from the page, including form XObjects
+fn for_each_page_font<'a>(doc: &'a Document, page_id: ObjectId, visit: &mut FontVisitor<'_, 'a>) -> PdfResult<()> {
+    let page = doc.get_dictionary(page_id)?;
+    let Some(resources) = get_inherited::<&Dictionary>(doc, page, b"Resources") else {
+        return Ok(());
+    };
+    let mut visited = HashSet::new();
+    for_each_resource_font(doc, resources, visit, &mut visited)
+}
+
+fn for_each_resource_font<'a>(
+    doc: &'a Document,
+    resources: &'a Dictionary,
+    visit: &mut FontVisitor<'_, 'a>,
+    visited: &mut HashSet<ObjectId>,
+) -> PdfResult<()> {
+    if let Some(Object::Dictionary(font_dict)) = object_utils::maybe_get_obj(doc, resources, b"Font") {
+        for (name, obj) in font_dict.iter() {
+            if let Ok(font) = object_utils::maybe_deref(doc, obj)?.as_dict() {
+                visit(&String::from_utf8_lossy(name), obj.as_reference().ok(), font)?;
+            }
+        }
+    }
+
+    if let Some(Object::Dictionary(xobjects)) = object_utils::maybe_get_obj(doc, resources, b"XObject") {
+        for (_, obj) in xobjects.iter() {
+            if let Ok(id) = obj.as_reference()
+                && !visited.insert(id)
+            {
+                continue;
+            }
+            if let Ok(Object::Stream(xobject)) = object_utils::maybe_deref(doc, obj)
+                && maybe_get_name(doc, &xobject.dict, b"Subtype") == Some(b"Form".as_slice())
+                && let Some(Object::Dictionary(form_resources)) =
+                    object_utils::maybe_get_obj(doc, &xobject.dict, b"Resources")
+            {
+                for_each_resource_font(doc, form_resources, visit, visited)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn name_string(doc: &Document, dict: &Dictionary, key: &[u8]) -> Option<String> {
+    maybe_get_name(doc, dict, key).and_then(|n| string_utils::pdf_to_utf8(n).ok())
+}
+
+pub(crate) fn has_font_file(doc: &Document, font: &Dictionary) -> bool {
+    let Some(Object::Dictionary(descriptor)) = object_utils::maybe_get_obj(doc, font, b"FontDescriptor") else {
+        return false;
+    };
+    [&b"FontFile"[..], b"FontFile2", b"FontFile3"].iter()
+        .any(|key| descriptor.get(key).is_ok())
+}
+
+// The descendant of a composite font, which carries its descriptor, or `font` itself
+fn descriptor_font<'a>(doc: &'a Document, font: &'a Dictionary) -> Option<&'a Dictionary> {
+    match maybe_get_name(doc, font, b"Subtype") {
+        Some(b"Type0") => maybe_get_array(doc, font, b"DescendantFonts")?
+            .first()
+            .and_then(|d| object_utils::maybe_deref(doc, d).ok())?
+            .as_dict().ok(),
+        _ => Some(font),
+    }
+}
+
+fn font_program<'a>(doc: &'a Document, font: &'a Dictionary) -> Option<(ObjectId, FontProgramKind, &'a Stream)> {
+    let font = descriptor_font(doc, font)?;
+    let Some(Object::Dictionary(descriptor)) = object_utils::maybe_get_obj(doc, font, b"FontDescriptor") else {
+        return None;
+    };
+    for key in [&b"FontFile"[..], b"FontFile2", b"FontFile3"] {
+        let Ok(id) = descriptor.get(key).and_then(Object::as_reference) else {
+            continue;
+        };
+        let Ok(stream) = doc.get_object(id).and_then(Object::as_stream) else {
+            continue;
+        };
+        let kind = match key {
+            b"FontFile" => FontProgramKind::Type1,
+            b"FontFile2" => FontProgramKind::TrueType,
+            _ => match maybe_get_name(doc, &stream.dict, b"Subtype") {
+                Some(b"OpenType") => FontProgramKind::OpenType,
+                _ => FontProgramKind::Cff,
+            },
+        };
+        return Some((id, kind, stream));
+    }
+    None
+}
+
+/// Advance widths of a simple font's codes read from its embedded TrueType `hmtx` or CFF charstrings,
+/// for fonts without a `/Widths` array. `encoding` gives the Unicode value of each code, if known.
+pub(crate) fn program_widths(doc: &Document, font: &Dictionary, encoding: Option<&[u16]>) -> PdfResult<Option<HashMap<CharCode, f64>>> {
+    let Some((_, kind, stream)) = font_program(doc, font) else { return Ok(None) };
+    let data = get_contents(stream)?;
+    let mut widths = HashMap::new();
+    let sfnt = match kind {
+        FontProgramKind::TrueType | FontProgramKind::OpenType => Sfnt::parse(&data),
+        _ => None,
+    };
+    if let Some(sfnt) = sfnt {
+        for code in 0..=255u8 {
+            let unicode = encoding.and_then(|e| e.get(code as usize).copied());
+            if let Some(width) = sfnt.glyph_for_code(code, unicode).and_then(|gid| sfnt.advance(gid)) {
+                widths.insert(code as CharCode, width);
+            }
+        }
+    } else if matches!(kind, FontProgramKind::Cff | FontProgramKind::OpenType) {
+        // Bare CFF, or an OpenType wrapper without usable horizontal metrics
+        let Some(cff) = Table::parse(&data) else { return Ok(None) };
+        // Charstring widths are in glyph space; scale them to 1/1000 text space units
+        let scale = cff.matrix().sx as f64 * 1000.;
+        for code in 0..=255u8 {
+            let width = cff.glyph_index(code)
+                .filter(|gid| gid.0 != 0)
+                .and_then(|gid: GlyphId| cff.glyph_width(gid));
+            if let Some(width) = width {
+                widths.insert(code as CharCode, width as f64 * scale);
+            }
+        }
+    }
+    Ok((!widths.is_empty()).then_some(widths))
+}
+
+/// Glyph of each of a simple font's codes in its embedded TrueType or CFF program, looked up as
+/// for `program_widths` when first asked for
+#[derive(Debug, Clone)]
+pub(crate) struct ProgramGlyphIds {
+    program: Option<(FontProgramKind, Stream)>,
+    glyphs: OnceLock<Option<HashMap<CharCode, u16>>>,
+}
+
+impl ProgramGlyphIds {
+    pub(crate) fn new(doc: &Document, font: &Dictionary) -> ProgramGlyphIds {
+        let program = font_program(doc, font)
+            .filter(|(_, kind, _)| *kind != FontProgramKind::Type1)
+            .map(|(_, kind, stream)| (kind, stream.clone()));
+        ProgramGlyphIds { program, glyphs: OnceLock::new() }
+    }
+
+    /// Glyph of `code`, reading the program on the first call; a program that fails to decode
+    /// gives no glyphs
+    pub(crate) fn get(&self, code: CharCode, encoding: Option<&[u16]>) -> Option<u16> {
+        let glyphs = self.glyphs.get_or_init(|| {
+            let (kind, stream) = self.program.as_ref()?;
+            program_glyph_ids(*kind, stream, encoding).unwrap_or_else(|e| {
+                report::warn(WarningKind::Recovered, format!("Skipping glyph ids of font program, {}", e));
+                None
+            })
+        });
+        glyphs.as_ref()?.get(&code).copied()
+    }
+}
+
+fn program_glyph_ids(kind: FontProgramKind, stream: &Stream, encoding: Option<&[u16]>) -> PdfResult<Option<HashMap<CharCode, u16>>> {
+    let data = get_contents(stream)?;
+    let mut glyphs = HashMap::new();
+    let sfnt = match kind {
+        FontProgramKind::TrueType | FontProgramKind::OpenType => Sfnt::parse(&data),
+        _ => None,
+    };
+    if let Some(sfnt) = sfnt {
+        for code in 0..=255u8 {
+            let unicode = encoding.and_then(|e| e.get(code as usize).copied());
+            if let Some(gid) = sfnt.glyph_for_code(code, unicode) {
+                glyphs.insert(code as CharCode, gid);
+            }
+        }
+    } else if matches!(kind, FontProgramKind::Cff | FontProgramKind::OpenType) {
+        let Some(cff) = Table::parse(&data) else { return Ok(None) };
+        for code in 0..=255u8 {
+            if let Some(gid) = cff.glyph_index(code).filter(|gid| gid.0 != 0) {
+                glyphs.insert(code as CharCode, gid.0);
+            }
+        }
+    }
+    Ok((!glyphs.is_empty()).then_some(glyphs))
+}
+
+/// Design of a font as far as CSS can reproduce it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FontStyle {
+    pub bold: bool,
+    pub italic: bool,
+    /// All glyphs share one advance, as in Courier
+    pub fixed_pitch: bool,
+    pub serif: bool,
+}
+
+impl FontStyle {
+    /// Style spelled out in a `BaseFont` name such as `ABCDEF+Arial-BoldItalicMT`
+    pub fn from_name(base_name: &str) -> FontStyle {
+        let name = strip_subset_prefix(base_name).to_ascii_lowercase();
+        let has = |words: &[&str]| words.iter().any(|w| name.contains(w));
+        FontStyle {
+            bold: has(&["bold", "black", "heavy", "demi"]),
+            italic: has(&["italic", "oblique"]),
+            fixed_pitch: has(&["courier", "mono", "consolas"]),
+            serif: has(&["times", "georgia", "garamond", "palatino", "cambria", "minion", "century"])
+                || (name.contains("serif") && !name.contains("sans")),
+        }
+    }
+
+    /// `font-family` list for `base_name`: its family, without subset prefix and style suffix,
+    /// followed by the generic family of this style
+    pub fn css_font_family(&self, base_name: &str) -> String {
+        let generic = if self.fixed_pitch {
+            "monospace"
+        } else if self.serif {
+            "serif"
+        } else {
+            "sans-serif"
+        };
+        let name = strip_subset_prefix(base_name);
+        let name = name.split(['-', ',']).next().unwrap_or_default();
+        let name = ["PSMT", "MT", "PS"].iter().find_map(|suffix| name.strip_suffix(suffix)).unwrap_or(name);
+        // Split camel case, so `TimesNewRoman` matches the installed Times New Roman
+        let mut family = String::new();
+        let mut previous = ' ';
+        for c in name.chars().filter(char::is_ascii_alphanumeric) {
+            if c.is_ascii_uppercase() && previous.is_ascii_lowercase() {
+                family.push(' ');
+            }
+            family.push(c);
+            previous = c;
+        }
+        // Unquoted family names can't start with a digit
+        match family.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => format!("{}, {}", family, generic),
+            _ => generic.to_string(),
+        }
+    }
+}
+
+// Style of `font` from its name and descriptor, `/Flags` and `/FontWeight` adding to what the
+// name says
+pub(crate) fn font_style(doc: &Document, font: &Dictionary, base_name: &str) -> FontStyle {
+    let mut style = FontStyle::from_name(base_name);
+    let descriptor = descriptor_font(doc, font)
+        .and_then(|font| object_utils::maybe_get_obj(doc, font, b"FontDescriptor"))
+        .and_then(|d| d.as_dict().ok());
+    if let Some(descriptor) = descriptor {
+        let number = |key: &[u8]| object_utils::maybe_get_obj(doc, descriptor, key)
+            .and_then(|n| object_utils::as_num(n).ok());
+        let flags = number(b"Flags").unwrap_or(0.) as i64;
+        style.fixed_pitch |= flags & 1 != 0;
+        style.serif |= flags & 2 != 0;
+        style.italic |= flags & 64 != 0;
+        // ForceBold
+        style.bold |= flags & (1 << 18) != 0 || number(b"FontWeight").is_some_and(|w| w >= 600.);
+    }
+    style
+}
+
+fn strip_subset_prefix(base_name: &str) -> &str {
+    match base_name.split_once('+') {
+        Some((tag, name)) if tag.len() == 6 && tag.bytes().all(|b| b.is_ascii_uppercase()) => name,
+        _ => base_name,
+    }
+}
+
+// Split a `FontFile` stream into its clear-text, binary and trailer parts using
+// Length1/Length2/Length3 and emit the PFB segment framing
+fn to_pfb(stream: &Stream) -> PdfResult<Vec<u8>> {
+    let data = get_contents(stream)?;
+    let length = |key: &[u8]| stream.dict.get(key).and_then(Object::as_i64).ok()
+        .and_then(|l| usize::try_from(l).ok());
+    let len1 = length(b"Length1").unwrap_or(data.len()).min(data.len());
+    let len2 = length(b"Length2").unwrap_or(data.len() - len1).min(data.len() - len1);
+    let segments = [
+        (1u8, &data[..len1]),
+        (2, &data[len1..len1 + len2]),
+        (1, &data[len1 + len2..]),
+    ];
+
+    let mut pfb = Vec::with_capacity(data.len() + 20);
+    for (segment_type, segment) in segments {
+        if segment.is_empty() {
+            continue;
+        }
+        pfb.extend_from_slice(&[0x80, segment_type]);
+        pfb.extend_from_slice(&(segment.len() as u32).to_le_bytes());
+        pfb.extend_from_slice(segment);
+    }
+    pfb.extend_from_slice(&[0x80, 0x03]);
+    Ok(pfb)
+}
+
+fn font_info(doc: &Document, font: &Dictionary, object_id: Option<ObjectId>, resource_name: &str, page_num: u32) -> PdfResult<FontInfo> {
+    let subtype = name_string(doc, font, b"Subtype").unwrap_or_default();
+
+    let embedded = if subtype == "Type0" {
+        maybe_get_array(doc, font, b"DescendantFonts")
+            .and_then(|d| d.first())
+            .and_then(|d| object_utils::maybe_deref(doc, d).ok())
+            .and_then(|d| d.as_dict().ok())
+            .is_some_and(|d| has_font_file(doc, d))
+    } else {
+        // Type3 glyphs are content streams in the font dictionary itself
+        subtype == "Type3" || has_font_file(doc, font)
+    };
+
+    let encoding = match object_utils::maybe_get_obj(doc, font, b"Encoding") {
+        Some(Object::Name(name)) => FontEncoding::Named(string_utils::pdf_to_utf8(name)?),
+        Some(Object::Dictionary(dict)) => FontEncoding::Differences { base: name_string(doc, dict, b"BaseEncoding") },
+        Some(Object::Stream(_)) => FontEncoding::EmbeddedCMap,
+        _ => FontEncoding::Builtin,
+    };
+
+    Ok(FontInfo {
+        object_id,
+        resource_name: resource_name.to_string(),
+        base_font: name_string(doc, font, b"BaseFont"),
+        subtype,
+        embedded,
+        encoding,
+        has_to_unicode: font.get(b"ToUnicode").is_ok(),
+        pages: vec![page_num],
+    })
+}
@@ -37,5 +37,15 @@ print "    let names = ["
 print ",\n".join('(\"%s\", 0x%04x)' % (g[0], g[1]) for g in glyphlist)
 print "    ];"
 print "    let result = names.binary_search_by_key(&name, |&(name,_code)| &name);"
-print "    result.ok().map(|indx| names[indx].1)"
+print "    result.ok().map(|indx| names[indx].1).or_else(|| uni_name_to_unicode(name))"
+print "}"
+print ""
+print "/// `uniXXXX` and `uXXXX` names from the Adobe Glyph List specification, BMP only"
+print "fn uni_name_to_unicode(name: &str) -> Option<u16> {"
+print "    let hex = name.strip_prefix(\"uni\").filter(|h| h.len() == 4)"
+print "        .or_else(|| name.strip_prefix('u').filter(|h| h.len() == 4))?;"
+print "    if !hex.bytes().all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b)) {"
+print "        return None;"
+print "    }"
+print "    u16::from_str_radix(hex, 16).ok().filter(|u| !(0xd800..=0xdfff).contains(u))"
 print "}"
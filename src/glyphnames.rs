@@ -4707,5 +4707,15 @@ pub fn name_to_unicode(name: &str) -> Option<u16> {
 ("zukatakana", 0x30ba)
     ];
     let result = names.binary_search_by_key(&name, |&(name,_code)| name);
-    result.ok().map(|indx| names[indx].1)
+    result.ok().map(|indx| names[indx].1).or_else(|| uni_name_to_unicode(name))
+}
+
+/// `uniXXXX` and `uXXXX` names from the Adobe Glyph List specification, BMP only
+fn uni_name_to_unicode(name: &str) -> Option<u16> {
+    let hex = name.strip_prefix("uni").filter(|h| h.len() == 4)
+        .or_else(|| name.strip_prefix('u').filter(|h| h.len() == 4))?;
+    if !hex.bytes().all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b)) {
+        return None;
+    }
+    u16::from_str_radix(hex, 16).ok().filter(|u| !(0xd800..=0xdfff).contains(u))
 }
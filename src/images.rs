@@ -0,0 +1,642 @@
+// Image XObjects and inline images drawn on each page
+//
+// Placement comes from the CTM at the `Do` or `BI` operator. Sample data is
+// decoded through the stream filters lopdf supports (Flate, LZW and ASCII85);
+// images using other filters are reported without data, except that JPEG
+// (DCTDecode) and JPEG 2000 (JPXDecode) images keep their encoded bytes for
+// lossless export. A JPEG 2000 image's own header supplies the colour space
+// and depth the PDF is allowed to omit; with the `jpx` feature its samples
+// are also decoded, to 8 bits per component. Masks and soft masks are
+// returned with the image they belong to, since they're never drawn on their
+// own.
+//
+// For vector export, images with decodable samples are also converted to PNG.
+// Output devices only see the image stream, so the interpreter first writes
+// colour spaces given by reference or resource name, and the palettes of
+// Indexed spaces, into a copy of its dictionary; ICC based and other spaces
+// are told apart by the number of components the samples hold.
+use std::borrow::Cow;
+use std::io::Write;
+
+use euclid::point2;
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+use lopdf::StringFormat;
+
+use crate::layout::BBox;
+#[cfg(feature = "jpx")]
+use crate::report::{self, WarningKind};
+use crate::{
+    get_contents, limits, object_utils, output_doc, Dictionary, Document, MediaBox, Object, OutputDev, PdfResult, PdfTransform,
+    Stream,
+};
+
+/// An image drawn on a page
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedImage {
+    pub page_num: u32,
+    /// Bounding box of the image's placement in user space
+    pub bbox: BBox,
+    /// Size of the raster in pixels
+    pub width: u32,
+    pub height: u32,
+    /// Colour space family, e.g. `DeviceRGB` or `ICCBased`; `None` for image masks
+    pub color_space: Option<String>,
+    pub bits_per_component: u32,
+    /// Drawn with `BI`/`ID`/`EI` rather than an XObject
+    pub inline: bool,
+    /// Decoded samples, when requested and the filters are supported; JPEG 2000 samples are
+    /// decoded with the `jpx` feature and always have 8 bits per component
+    pub data: Option<Vec<u8>>,
+    /// The embedded JPEG file of a DCTDecode image, when data is requested
+    pub jpeg: Option<Vec<u8>>,
+    /// The embedded JP2 file or JPEG 2000 codestream of a JPXDecode image, when data is requested
+    pub jpx: Option<Vec<u8>>,
+    /// A stencil mask (`/ImageMask true`): the samples mark where the fill colour is painted
+    pub image_mask: bool,
+    /// Masking from the image's `/Mask` entry
+    pub mask: Option<Mask>,
+    /// Alpha channel from the image's `/SMask` entry
+    pub soft_mask: Option<MaskImage>,
+}
+
+/// How an image's `/Mask` entry hides parts of it
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mask {
+    /// A separate 1-bit image; samples of 1 hide the base image, unless its `/Decode` is `[1 0]`
+    Stencil(MaskImage),
+    /// Colour key ranges, a min and max per colour component; matching pixels are hidden
+    ColorKey(Vec<i64>),
+}
+
+/// A mask or alpha image belonging to an image
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskImage {
+    pub width: u32,
+    pub height: u32,
+    pub bits_per_component: u32,
+    /// `/Decode` array, e.g. `[1 0]` to invert a stencil
+    pub decode: Option<Vec<f64>>,
+    /// Decoded samples, when requested and the filters are supported
+    pub data: Option<Vec<u8>>,
+}
+
+/// Image header of a JPEG 2000 file or codestream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpxInfo {
+    pub width: u32,
+    pub height: u32,
+    pub components: u16,
+    /// Depth of the first component
+    pub bits_per_component: u8,
+}
+
+/// Every image of `doc` with its placement and decoded samples, in page order
+pub fn extract_images(doc: &Document) -> PdfResult<Vec<PlacedImage>> {
+    let mut collector = ImageCollector::new(doc).with_data();
+    output_doc(doc, &mut collector)?;
+    Ok(collector.into_images())
+}
+
+/// JPEG file embedded in a DCTDecode image, with any filters applied before DCTDecode undone
+pub fn jpeg_data(image: &Stream) -> Option<Vec<u8>> {
+    encoded_data(image, b"DCTDecode").ok().flatten()
+}
+
+/// JPEG 2000 data embedded in a JPXDecode image, with any filters applied before JPXDecode undone
+pub fn jpx_data(image: &Stream) -> Option<Vec<u8>> {
+    encoded_data(image, b"JPXDecode").ok().flatten()
+}
+
+/// Header of a JP2 file (from its `ihdr` box) or of a raw codestream (from its SIZ marker)
+pub fn jpx_info(data: &[u8]) -> Option<JpxInfo> {
+    let be16 = |d: &[u8], at: usize| d.get(at..at + 2).map(|b| u16::from_be_bytes([b[0], b[1]]));
+    let be32 = |d: &[u8], at: usize| d.get(at..at + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]));
+    // SOC then SIZ: Lsiz Rsiz Xsiz Ysiz XOsiz YOsiz, four tile fields, Csiz and per component Ssiz
+    if data.starts_with(&[0xff, 0x4f, 0xff, 0x51]) {
+        let siz = &data[4..];
+        return Some(JpxInfo {
+            width: be32(siz, 4)?.checked_sub(be32(siz, 12)?)?,
+            height: be32(siz, 8)?.checked_sub(be32(siz, 16)?)?,
+            components: be16(siz, 36)?,
+            bits_per_component: (siz.get(38)? & 0x7f) + 1,
+        });
+    }
+    // Boxes are length, type and contents; the image header lives in the `jp2h` superbox
+    let mut boxes = data;
+    while boxes.len() >= 8 {
+        let (length, kind) = (be32(boxes, 0)? as usize, &boxes[4..8]);
+        let (header, length) = match length {
+            0 => (8, boxes.len()),
+            1 => (16, usize::try_from(u64::from_be_bytes(boxes.get(8..16)?.try_into().ok()?)).ok()?),
+            n => (8, n),
+        };
+        let contents = boxes.get(header..length)?;
+        match kind {
+            b"jp2h" => boxes = contents,
+            b"ihdr" => {
+                return Some(JpxInfo {
+                    height: be32(contents, 0)?,
+                    width: be32(contents, 4)?,
+                    components: be16(contents, 8)?,
+                    bits_per_component: (contents.get(10)? & 0x7f) + 1,
+                });
+            }
+            _ => boxes = &boxes[length..],
+        }
+    }
+    None
+}
+
+/// Samples of a JPEG 2000 file or codestream decoded to 8 bits per component, the colour
+/// components of each pixel interleaved and any alpha channel dropped; `None` when it can't be
+/// decoded or its pixels would take more than a stream may decode to
+#[cfg(feature = "jpx")]
+pub fn decode_jpx(data: &[u8]) -> PdfResult<Option<Vec<u8>>> {
+    use hayro_jpeg2000::{DecodeSettings, DecoderContext, Image};
+
+    let unsupported = |e: hayro_jpeg2000::DecodeError| {
+        report::warn(WarningKind::Unsupported, format!("Couldn't decode a JPEG 2000 image, {}", e));
+        Ok(None)
+    };
+    let image = match Image::new(data, &DecodeSettings::default()) {
+        Ok(image) => image,
+        Err(e) => return unsupported(e),
+    };
+    let channels = image.color_space().num_channels() as usize;
+    let stored = channels + image.has_alpha() as usize;
+    let size = (image.width() as usize).checked_mul(image.height() as usize).and_then(|n| n.checked_mul(stored));
+    let Some(size) = size.filter(|&size| size <= limits::current().max_stream_size) else {
+        return Ok(None);
+    };
+    limits::charge(size)?;
+    let mut context = DecoderContext::default();
+    let samples = match image.decode(&mut context) {
+        Ok(decoded) => decoded.data_u8(),
+        Err(e) => return unsupported(e),
+    };
+    if stored == channels {
+        return Ok(Some(samples));
+    }
+    Ok(Some(samples.chunks_exact(stored).flat_map(|pixel| &pixel[..channels]).copied().collect()))
+}
+
+// Decoded samples of JPEG 2000 `data`, which need the `jpx` feature
+#[cfg(feature = "jpx")]
+fn jpx_samples(data: &[u8]) -> PdfResult<Option<Vec<u8>>> {
+    decode_jpx(data)
+}
+
+#[cfg(not(feature = "jpx"))]
+fn jpx_samples(_data: &[u8]) -> PdfResult<Option<Vec<u8>>> {
+    Ok(None)
+}
+
+// Encoded bytes of an image whose last filter is `filter`, which lopdf can't decode
+fn encoded_data(image: &Stream, filter: &[u8]) -> PdfResult<Option<Vec<u8>>> {
+    let Ok(filters) = image.filters() else { return Ok(None) };
+    let Some((last, outer)) = filters.split_last() else { return Ok(None) };
+    if *last != filter {
+        return Ok(None);
+    }
+    if outer.is_empty() {
+        return Ok(Some(image.content.clone()));
+    }
+    let mut dict = image.dict.clone();
+    dict.set("Filter", outer.iter().map(|f| Object::Name(f.to_vec())).collect::<Vec<_>>());
+    match image.dict.get(b"DecodeParms") {
+        Ok(Object::Array(params)) => dict.set("DecodeParms", params[..outer.len().min(params.len())].to_vec()),
+        _ => {
+            dict.remove(b"DecodeParms");
+        }
+    }
+    Ok(limits::decode(&Stream::new(dict, image.content.clone()))?.map(Cow::into_owned))
+}
+
+// How the samples of an image are turned into PNG pixels
+enum Samples {
+    Gray,
+    Rgb,
+    Cmyk,
+    // RGB colours of the palette entries
+    Indexed(Vec<[u8; 3]>),
+    // Fill colour painted where the sample is 0, or 1 if inverted
+    Stencil([u8; 3], bool),
+}
+
+impl Samples {
+    fn components(&self) -> usize {
+        match self {
+            Samples::Rgb => 3,
+            Samples::Cmyk => 4,
+            _ => 1,
+        }
+    }
+}
+
+// The conversion for a colour space object, `None` when it is given by reference or unsupported
+fn samples_for(color_space: &Object) -> Option<Samples> {
+    let family = match color_space {
+        Object::Array(items) => items.first()?.as_name().ok()?,
+        o => o.as_name().ok()?,
+    };
+    Some(match family {
+        b"DeviceGray" | b"CalGray" => Samples::Gray,
+        b"DeviceRGB" | b"CalRGB" => Samples::Rgb,
+        b"DeviceCMYK" => Samples::Cmyk,
+        b"Indexed" => {
+            let items = color_space.as_array().ok()?;
+            let base = samples_for(items.get(1)?)?;
+            let lookup = match items.get(3)? {
+                Object::String(bytes, _) => bytes,
+                Object::Stream(stream) => &stream.content,
+                _ => return None,
+            };
+            let palette = lookup.chunks_exact(base.components()).map(|c| match base {
+                Samples::Rgb => [c[0], c[1], c[2]],
+                Samples::Cmyk => cmyk_to_rgb(c),
+                _ => [c[0]; 3],
+            });
+            Samples::Indexed(palette.collect())
+        }
+        _ => return None,
+    })
+}
+
+fn cmyk_to_rgb(c: &[u8]) -> [u8; 3] {
+    let k = 255 - c[3] as u32;
+    [0, 1, 2].map(|i| ((255 - c[i] as u32) * k / 255) as u8)
+}
+
+/// PNG file of an image with decodable samples, image masks painted in `fill`; `None` for JPEG,
+/// JPEG 2000 without the `jpx` feature and unsupported images
+pub(crate) fn png_data(image: &Stream, fill: [u8; 3]) -> PdfResult<Option<Vec<u8>>> {
+    let get = |key: &[u8]| image.dict.get(key).ok();
+    let dimension = |key: &[u8]| get(key).and_then(|o| o.as_i64().ok()).unwrap_or(0).max(0) as usize;
+    let (width, height) = (dimension(b"Width"), dimension(b"Height"));
+    if width == 0 || height == 0 {
+        return Ok(None);
+    }
+    let is_mask = get(b"ImageMask").and_then(|o| o.as_bool().ok()).unwrap_or(false);
+    let jpx = match encoded_data(image, b"JPXDecode")? {
+        Some(jpx) => jpx_samples(&jpx)?,
+        None => None,
+    };
+    let (data, bits) = match jpx {
+        Some(samples) => (Cow::Owned(samples), 8),
+        None => {
+            let Some(data) = limits::decode(image)? else { return Ok(None) };
+            (data, if is_mask { 1 } else { dimension(b"BitsPerComponent") })
+        }
+    };
+    if !matches!(bits, 1 | 2 | 4 | 8 | 16) {
+        return Ok(None);
+    }
+    // Dimensions from the file can be anything; images whose samples overflow or whose pixels
+    // would take more than a stream may decode to are left out
+    let Some(sample_bits) = width.checked_mul(height).and_then(|n| n.checked_mul(bits)) else {
+        return Ok(None);
+    };
+    let samples = if is_mask {
+        let inverted = get(b"Decode").and_then(|o| o.as_array().ok()).and_then(|d| d.first()?.as_i64().ok()) == Some(1);
+        Samples::Stencil(fill, inverted)
+    } else {
+        match get(b"ColorSpace").and_then(samples_for) {
+            Some(samples) => samples,
+            // Referenced colour spaces go by the number of components in the data
+            None => match data.len().saturating_mul(8) / sample_bits {
+                1 => Samples::Gray,
+                3 => Samples::Rgb,
+                4 => Samples::Cmyk,
+                _ => return Ok(None),
+            },
+        }
+    };
+    let components = samples.components();
+    let (color_type, channels) = match samples {
+        Samples::Gray => (0, 1),
+        Samples::Stencil(..) => (6, 4),
+        _ => (2, 3),
+    };
+    let row_bytes = width.checked_mul(components * bits).map(|bits| bits.div_ceil(8));
+    let pixel_bytes = width.checked_mul(channels).and_then(|n| n.checked_add(1)).and_then(|n| n.checked_mul(height));
+    let (Some(row_bytes), Some(pixel_bytes)) = (row_bytes, pixel_bytes) else {
+        return Ok(None);
+    };
+    if pixel_bytes > limits::current().max_stream_size || row_bytes.checked_mul(height).is_none_or(|size| data.len() < size) {
+        return Ok(None);
+    }
+    limits::charge(pixel_bytes)?;
+    let max = (1u32 << bits.min(8)) - 1;
+    let mut pixels = Vec::with_capacity(pixel_bytes);
+    for row in data.chunks_exact(row_bytes).take(height) {
+        // Samples of 16 bits keep their high byte
+        let sample = |i: usize| -> u32 {
+            let bit = i * bits;
+            let byte = row[bit / 8] as u32;
+            match bits {
+                8 | 16 => byte,
+                _ => (byte >> (8 - bits - bit % 8)) & max,
+            }
+        };
+        let scaled = |i: usize| (sample(i) * 255 / max) as u8;
+        // No filtering
+        pixels.push(0);
+        for x in 0..width {
+            match &samples {
+                Samples::Gray => pixels.push(scaled(x)),
+                Samples::Rgb => pixels.extend([0, 1, 2].map(|c| scaled(3 * x + c))),
+                Samples::Cmyk => pixels.extend(cmyk_to_rgb(&[0, 1, 2, 3].map(|c| scaled(4 * x + c)))),
+                Samples::Indexed(palette) => pixels.extend(palette.get(sample(x) as usize).copied().unwrap_or([0; 3])),
+                Samples::Stencil(color, inverted) => {
+                    let painted = (sample(x) == 1) == *inverted;
+                    pixels.extend(color);
+                    pixels.push(if painted { 255 } else { 0 });
+                }
+            }
+        }
+    }
+    Ok(Some(png(width as u32, height as u32, color_type, &pixels)))
+}
+
+// PNG file of 8-bit `pixels`, rows prefixed with their filter type
+fn png(width: u32, height: u32, color_type: u8, pixels: &[u8]) -> Vec<u8> {
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    let mut chunk = |kind: &[u8], data: &[u8]| {
+        out.extend((data.len() as u32).to_be_bytes());
+        out.extend(kind);
+        out.extend(data);
+        let mut crc = Crc::new();
+        crc.update(kind);
+        crc.update(data);
+        out.extend(crc.sum().to_be_bytes());
+    };
+    let mut header = Vec::new();
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // Bit depth, colour type, then the default compression, filter and interlace methods
+    header.extend([8, color_type, 0, 0, 0]);
+    chunk(b"IHDR", &header);
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing to a vector can't fail
+    let _ = encoder.write_all(pixels);
+    chunk(b"IDAT", &encoder.finish().unwrap_or_default());
+    chunk(b"IEND", &[]);
+    out
+}
+
+// Full names of the keys abbreviated in inline image dictionaries
+fn expand_key(key: &[u8]) -> &[u8] {
+    match key {
+        b"BPC" => b"BitsPerComponent",
+        b"CS" => b"ColorSpace",
+        b"D" => b"Decode",
+        b"DP" => b"DecodeParms",
+        b"F" => b"Filter",
+        b"H" => b"Height",
+        b"IM" => b"ImageMask",
+        b"I" => b"Interpolate",
+        b"W" => b"Width",
+        other => other,
+    }
+}
+
+// Full names of abbreviated colour space and filter names
+fn expand_name(name: &[u8]) -> &[u8] {
+    match name {
+        b"G" => b"DeviceGray",
+        b"RGB" => b"DeviceRGB",
+        b"CMYK" => b"DeviceCMYK",
+        b"I" => b"Indexed",
+        b"AHx" => b"ASCIIHexDecode",
+        b"A85" => b"ASCII85Decode",
+        b"LZW" => b"LZWDecode",
+        b"Fl" => b"FlateDecode",
+        b"RL" => b"RunLengthDecode",
+        b"CCF" => b"CCITTFaxDecode",
+        b"DCT" => b"DCTDecode",
+        other => other,
+    }
+}
+
+/// `image` with its colour space spelled out in its dictionary, so `png_data` can read it without
+/// the document: references and names of `resources`' colour spaces are replaced by what they
+/// stand for, and Indexed palette streams by their decoded bytes; `None` when it already is
+pub(crate) fn resolve_color_space(doc: &Document, image: &Stream, resources: &Dictionary) -> PdfResult<Option<Stream>> {
+    let Ok(cs) = image.dict.get(b"ColorSpace") else { return Ok(None) };
+    let Some(cs) = resolved_color_space(doc, cs, Some(resources), 0)? else { return Ok(None) };
+    let mut image = image.clone();
+    image.dict.set("ColorSpace", cs);
+    Ok(Some(image))
+}
+
+// `cs` without references or resource names, `None` if it has none or can't be resolved
+fn resolved_color_space(doc: &Document, cs: &Object, resources: Option<&Dictionary>, depth: usize) -> PdfResult<Option<Object>> {
+    if depth > 4 {
+        return Ok(None);
+    }
+    let resolve_target = |target: &Object| -> PdfResult<Option<Object>> {
+        Ok(Some(resolved_color_space(doc, target, None, depth + 1)?.unwrap_or_else(|| target.clone())))
+    };
+    match cs {
+        Object::Reference(_) => match object_utils::maybe_deref(doc, cs) {
+            Ok(target) => resolve_target(target),
+            Err(_) => Ok(None),
+        },
+        Object::Name(name) if !matches!(name.as_slice(), b"DeviceGray" | b"DeviceRGB" | b"DeviceCMYK" | b"Pattern") => {
+            let target = resources
+                .and_then(|resources| object_utils::maybe_get_obj(doc, resources, b"ColorSpace"))
+                .and_then(|colorspaces| colorspaces.as_dict().ok())
+                .and_then(|colorspaces| object_utils::maybe_get_obj(doc, colorspaces, name));
+            match target {
+                Some(target) => resolve_target(target),
+                None => Ok(None),
+            }
+        }
+        Object::Array(items) if items.first().and_then(|f| f.as_name().ok()) == Some(b"Indexed") && items.len() == 4 => {
+            let base = resolved_color_space(doc, &items[1], resources, depth + 1)?;
+            let lookup = match object_utils::maybe_deref(doc, &items[3]) {
+                Ok(Object::Stream(stream)) => Some(Object::String(get_contents(stream)?.into_owned(), StringFormat::Hexadecimal)),
+                Ok(lookup @ Object::String(..)) if matches!(items[3], Object::Reference(_)) => Some(lookup.clone()),
+                _ => None,
+            };
+            if base.is_none() && lookup.is_none() {
+                return Ok(None);
+            }
+            Ok(Some(Object::Array(vec![
+                items[0].clone(),
+                base.unwrap_or_else(|| items[1].clone()),
+                items[2].clone(),
+                lookup.unwrap_or_else(|| items[3].clone()),
+            ])))
+        }
+        _ => Ok(None),
+    }
+}
+
+// Inline image with its dictionary spelled out like an image XObject's
+pub(crate) fn expand_inline_image(image: &Stream) -> Stream {
+    let expand_value = |value: &Object| match value {
+        Object::Name(name) => Object::Name(expand_name(name).to_vec()),
+        Object::Array(items) => Object::Array(
+            items.iter()
+                .map(|o| match o {
+                    Object::Name(name) => Object::Name(expand_name(name).to_vec()),
+                    o => o.clone(),
+                })
+                .collect(),
+        ),
+        o => o.clone(),
+    };
+    let mut dict = Dictionary::new();
+    for (key, value) in image.dict.iter() {
+        let key = expand_key(key);
+        let value = match key {
+            b"ColorSpace" | b"Filter" => expand_value(value),
+            _ => value.clone(),
+        };
+        dict.set(key.to_vec(), value);
+    }
+    Stream::new(dict, image.content.clone())
+}
+
+// Device space bounding box of the unit square under `ctm`
+pub(crate) fn placement(ctm: &PdfTransform) -> BBox {
+    let corners = [(0., 0.), (1., 0.), (0., 1.), (1., 1.)].map(|(x, y)| ctm.transform_point(point2(x, y)));
+    corners[1..].iter().fold(
+        BBox { llx: corners[0].x, lly: corners[0].y, urx: corners[0].x, ury: corners[0].y },
+        |b, p| BBox { llx: b.llx.min(p.x), lly: b.lly.min(p.y), urx: b.urx.max(p.x), ury: b.ury.max(p.y) },
+    )
+}
+
+/// Output device that records the images drawn on every page
+pub struct ImageCollector<'a> {
+    doc: &'a Document,
+    page_num: u32,
+    images: Vec<PlacedImage>,
+    decode: bool,
+}
+
+impl<'a> ImageCollector<'a> {
+    pub fn new(doc: &'a Document) -> ImageCollector<'a> {
+        ImageCollector { doc, page_num: 0, images: Vec::new(), decode: false }
+    }
+
+    /// Also decode the samples of each image
+    pub fn with_data(mut self) -> ImageCollector<'a> {
+        self.decode = true;
+        self
+    }
+
+    pub fn images(&self) -> &[PlacedImage] {
+        &self.images
+    }
+
+    pub fn into_images(self) -> Vec<PlacedImage> {
+        self.images
+    }
+}
+
+impl ImageCollector<'_> {
+    fn mask_image(&self, mask: &Stream) -> PdfResult<MaskImage> {
+        let get = |key: &[u8]| object_utils::maybe_get_obj(self.doc, &mask.dict, key);
+        let dimension = |key: &[u8]| get(key).and_then(|o| o.as_i64().ok()).unwrap_or(0).max(0) as u32;
+        let is_stencil = get(b"ImageMask").and_then(|o| o.as_bool().ok()).unwrap_or(false);
+        Ok(MaskImage {
+            width: dimension(b"Width"),
+            height: dimension(b"Height"),
+            bits_per_component: if is_stencil { 1 } else { dimension(b"BitsPerComponent") },
+            decode: get(b"Decode")
+                .and_then(|o| o.as_array().ok())
+                .map(|values| values.iter().filter_map(|o| object_utils::as_num(o).ok()).collect()),
+            data: if self.decode { limits::decode(mask)?.map(Cow::into_owned) } else { None },
+        })
+    }
+
+    fn record(&mut self, ctm: &PdfTransform, image: &Stream, inline: bool) -> PdfResult<()> {
+        let doc = self.doc;
+        let get = |key: &[u8]| object_utils::maybe_get_obj(doc, &image.dict, key);
+        let dimension = |key: &[u8]| get(key).and_then(|o| o.as_i64().ok()).unwrap_or(0).max(0) as u32;
+        let is_mask = get(b"ImageMask").and_then(|o| o.as_bool().ok()).unwrap_or(false);
+        // Array colour spaces such as `[/ICCBased 5 0 R]` are named by their family
+        let mut color_space = get(b"ColorSpace")
+            .and_then(|o| match o {
+                Object::Array(items) => items.first().and_then(|o| o.as_name().ok()),
+                o => o.as_name().ok(),
+            })
+            .filter(|_| !is_mask)
+            .map(|name| String::from_utf8_lossy(name).into_owned());
+        let mut bits_per_component = if is_mask { 1 } else { dimension(b"BitsPerComponent") };
+        let jpx = encoded_data(image, b"JPXDecode")?;
+        // JPEG 2000 images may leave colour space and depth to the codestream
+        if let Some(info) = jpx.as_deref().and_then(jpx_info).filter(|_| !is_mask) {
+            if bits_per_component == 0 {
+                bits_per_component = info.bits_per_component as u32;
+            }
+            if color_space.is_none() {
+                color_space = match info.components {
+                    1 => Some("DeviceGray".to_string()),
+                    3 => Some("DeviceRGB".to_string()),
+                    4 => Some("DeviceCMYK".to_string()),
+                    _ => None,
+                };
+            }
+        }
+        let mask = match get(b"Mask") {
+            Some(Object::Array(ranges)) => Some(Mask::ColorKey(ranges.iter().filter_map(|o| o.as_i64().ok()).collect())),
+            Some(o) => o.as_stream().ok().map(|mask| self.mask_image(mask)).transpose()?.map(Mask::Stencil),
+            None => None,
+        };
+        let soft_mask = get(b"SMask").and_then(|o| o.as_stream().ok()).map(|s| self.mask_image(s)).transpose()?;
+        let (data, jpeg, jpx) = if !self.decode {
+            (None, None, None)
+        } else if let Some(jpeg) = encoded_data(image, b"DCTDecode")? {
+            (None, Some(jpeg), None)
+        } else if let Some(jpx) = jpx {
+            let samples = jpx_samples(&jpx)?;
+            if samples.is_some() {
+                bits_per_component = 8;
+            }
+            (samples, None, Some(jpx))
+        } else {
+            (limits::decode(image)?.map(Cow::into_owned), None, None)
+        };
+        self.images.push(PlacedImage {
+            page_num: self.page_num,
+            bbox: placement(ctm),
+            width: dimension(b"Width"),
+            height: dimension(b"Height"),
+            color_space,
+            bits_per_component,
+            inline,
+            data,
+            jpeg,
+            jpx,
+            image_mask: is_mask,
+            mask,
+            soft_mask,
+        });
+        Ok(())
+    }
+}
+
+impl OutputDev for ImageCollector<'_> {
+    fn begin_page(&mut self, page_num: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.page_num = page_num;
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+    fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> { Ok(()) }
+    fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.record(ctm, image, false)
+    }
+
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.record(ctm, image, true)
+    }
+}
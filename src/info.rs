@@ -0,0 +1,114 @@
+// Document information dictionary
+//
+// The trailer's `/Info` holds the title, author and dates a document was
+// saved with. Values are text strings, except the dates, which are PDF date
+// strings `D:YYYYMMDDHHmmSSOHH'mm'` where everything after the year may be
+// left out and `O` is `+`, `-` or `Z`.
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+
+use crate::{document_utils, object_utils, string_utils, Document, Object};
+
+/// Typed fields of the `/Info` dictionary
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentInfo {
+    /// Header version, e.g. `1.7`
+    pub version: String,
+    pub page_count: usize,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    pub creator: Option<String>,
+    pub producer: Option<String>,
+    pub creation_date: Option<SystemTime>,
+    pub mod_date: Option<SystemTime>,
+    /// `/Trapped`: `True`, `False` or `Unknown`
+    pub trapped: Option<String>,
+    /// Other text entries, by key
+    pub custom: BTreeMap<String, String>,
+}
+
+impl From<&Document> for DocumentInfo {
+    fn from(doc: &Document) -> DocumentInfo {
+        let mut info = DocumentInfo {
+            version: doc.version.clone(),
+            page_count: doc.get_pages().len(),
+            ..Default::default()
+        };
+        let Some(dict) = document_utils::get_info(doc) else { return info };
+        for (key, value) in dict {
+            let Ok(value) = object_utils::maybe_deref(doc, value) else { continue };
+            let text = match value {
+                Object::String(s, _) => string_utils::pdf_to_utf8(s).ok(),
+                Object::Name(name) => Some(String::from_utf8_lossy(name).into_owned()),
+                _ => None,
+            };
+            let Some(text) = text else { continue };
+            match key.as_slice() {
+                b"Title" => info.title = Some(text),
+                b"Author" => info.author = Some(text),
+                b"Subject" => info.subject = Some(text),
+                b"Keywords" => info.keywords = Some(text),
+                b"Creator" => info.creator = Some(text),
+                b"Producer" => info.producer = Some(text),
+                b"CreationDate" => info.creation_date = parse_date(&text),
+                b"ModDate" => info.mod_date = parse_date(&text),
+                b"Trapped" => info.trapped = Some(text),
+                _ => {
+                    info.custom.insert(String::from_utf8_lossy(key).into_owned(), text);
+                }
+            }
+        }
+        info
+    }
+}
+
+/// Parse a PDF date string; fields left out default to the start of their period and a missing
+/// offset is taken as UTC
+pub fn parse_date(date: &str) -> Option<SystemTime> {
+    let date = date.trim();
+    let date = date.strip_prefix("D:").unwrap_or(date);
+    let digits = date.bytes().take_while(u8::is_ascii_digit).count();
+    if digits < 4 || digits % 2 != 0 || digits > 14 {
+        return None;
+    }
+    let field = |start: usize, default: i64| date.get(start..start + 2).filter(|_| start < digits).map_or(Some(default), |s| s.parse().ok());
+    let year: i64 = date[..4].parse().ok()?;
+    let (month, day) = (field(4, 1)?, field(6, 1)?);
+    let (hour, minute, second) = (field(8, 0)?, field(10, 0)?, field(12, 0)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    // Offset of local time from UTC, `HH'mm'` with either apostrophe optional
+    let offset = match date[digits..].chars().next() {
+        Some(sign @ ('+' | '-')) => {
+            let rest: String = date[digits + 1..].chars().filter(char::is_ascii_digit).collect();
+            let hours: i64 = rest.get(..2)?.parse().ok()?;
+            let minutes: i64 = rest.get(2..4).map_or(Some(0), |m| m.parse().ok())?;
+            let offset = hours * 3600 + minutes * 60;
+            if sign == '-' { -offset } else { offset }
+        }
+        _ => 0,
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+    let duration = Duration::from_secs(seconds.unsigned_abs());
+    if seconds >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(duration)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(duration)
+    }
+}
+
+// Days from 1970-01-01 to a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
@@ -0,0 +1,370 @@
+// Invoice fields from positioned text
+//
+// Labelled values ("Invoice No: 123", "Total  $1,234.00") are read from each
+// line, line items from the rows between a table header and the totals, and
+// amounts are normalised to a value plus ISO 4217 currency code. Every field
+// carries a confidence in [0, 1] reflecting how it was found.
+use std::collections::HashMap;
+
+use crate::layout::{collect_text, BBox, PageText, TextLine};
+use crate::{Document, PdfResult};
+
+// Gap between glyphs, in multiples of the font size, that separates table cells
+const CELL_GAP: f64 = 1.0;
+
+/// A monetary amount
+#[derive(Debug, Clone, PartialEq)]
+pub struct Amount {
+    pub value: f64,
+    /// ISO 4217 code such as `EUR`, if a symbol or code was printed or inferred
+    pub currency: Option<String>,
+}
+
+/// An extracted value with where and how reliably it was found
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field<T> {
+    pub value: T,
+    pub confidence: f32,
+    pub page_num: u32,
+    pub bbox: BBox,
+}
+
+impl<T> Field<T> {
+    fn new(value: T, (confidence, page_num, bbox): (f32, u32, BBox)) -> Field<T> {
+        Field { value, confidence, page_num, bbox }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineItem {
+    pub description: String,
+    pub quantity: Option<f64>,
+    pub unit_price: Option<Amount>,
+    pub amount: Amount,
+    pub confidence: f32,
+    pub page_num: u32,
+    pub bbox: BBox,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Totals {
+    pub subtotal: Option<Field<Amount>>,
+    pub tax: Option<Field<Amount>>,
+    pub total: Option<Field<Amount>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Invoice {
+    pub number: Option<Field<String>>,
+    /// `YYYY-MM-DD` when the printed date could be parsed, otherwise as printed
+    pub date: Option<Field<String>>,
+    pub vendor: Option<Field<String>>,
+    pub line_items: Vec<LineItem>,
+    pub totals: Totals,
+}
+
+/// Extract invoice fields from `doc`
+pub fn extract_invoice(doc: &Document) -> PdfResult<Invoice> {
+    Ok(parse_invoice(&collect_text(doc)?))
+}
+
+/// Extract invoice fields from already collected pages
+pub fn parse_invoice(pages: &[PageText]) -> Invoice {
+    let mut invoice = Invoice::default();
+    let mut in_table = false;
+    for page in pages {
+        let lines = page.lines();
+        for (i, line) in lines.iter().enumerate() {
+            let text = line.text.trim();
+            let at = |confidence| (confidence, page.page_num, line.bbox);
+
+            if let Some((label, value, confidence)) = labelled_value(text, &lines[i + 1..]) {
+                match label {
+                    Label::Number if invoice.number.is_none() => {
+                        invoice.number = Some(Field::new(value.to_string(), at(confidence)));
+                    }
+                    Label::Date if invoice.date.is_none() => {
+                        let (date, parsed) = normalize_date(value).map_or((value.to_string(), false), |d| (d, true));
+                        invoice.date = Some(Field::new(date, at(if parsed { confidence } else { confidence * 0.6 })));
+                    }
+                    Label::Subtotal | Label::Tax | Label::Total => {
+                        in_table = false;
+                        if let Some(amount) = parse_amount(value) {
+                            let slot = match label {
+                                Label::Subtotal => &mut invoice.totals.subtotal,
+                                Label::Tax => &mut invoice.totals.tax,
+                                _ => &mut invoice.totals.total,
+                            };
+                            // The last total on the last page wins over carried-forward sums
+                            *slot = Some(Field::new(amount, at(confidence)));
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if is_table_header(text) {
+                in_table = true;
+            } else if in_table {
+                if let Some(item) = line_item(page.page_num, line) {
+                    invoice.line_items.push(item);
+                }
+            } else if invoice.vendor.is_none() && page.page_num == pages[0].page_num && is_vendor_candidate(page, line) {
+                invoice.vendor = Some(Field::new(text.to_string(), at(0.4)));
+            }
+        }
+    }
+    infer_currency(&mut invoice);
+    invoice
+}
+
+/// Parse a printed amount such as `$1,234.50`, `1.234,50 €`, `EUR 12` or `(30.00)`
+pub fn parse_amount(s: &str) -> Option<Amount> {
+    let s = s.trim();
+    let (s, negative) = match s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(inner) => (inner.trim(), true),
+        None => (s, false),
+    };
+
+    let mut currency = None;
+    let mut number = String::new();
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if let Some(code) = currency_symbol(c) {
+            currency = Some(code.to_string());
+            rest = &rest[c.len_utf8()..];
+        } else if rest.len() >= 3 && rest.is_char_boundary(3) && is_currency_code(&rest[..3]) {
+            currency = Some(rest[..3].to_string());
+            rest = &rest[3..];
+        } else if c.is_ascii_digit() || c == '.' || c == ',' || c == '-' {
+            number.push(c);
+            rest = &rest[1..];
+        } else if c.is_whitespace() || c == '\'' || c == '\u{a0}' {
+            rest = &rest[c.len_utf8()..];
+        } else {
+            return None;
+        }
+    }
+
+    let (number, minus) = match number.strip_prefix('-') {
+        Some(n) => (n.to_string(), true),
+        None => match number.strip_suffix('-') {
+            Some(n) => (n.to_string(), true),
+            None => (number, false),
+        },
+    };
+    if !number.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let value: f64 = normalize_separators(&number)?.parse().ok()?;
+    Some(Amount { value: if negative || minus { -value } else { value }, currency })
+}
+
+// Turn `1.234,50` / `1,234.50` / `1 234` into `1234.50`-style input for `str::parse`
+fn normalize_separators(number: &str) -> Option<String> {
+    let decimal = match (number.rfind('.'), number.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(dot.max(comma)),
+        // A lone separator kind is a thousands separator only when followed by three digits
+        (Some(pos), None) | (None, Some(pos)) => (number.len() - pos - 1 != 3).then_some(pos),
+        (None, None) => None,
+    };
+    let mut out = String::with_capacity(number.len());
+    for (i, c) in number.char_indices() {
+        match c {
+            '0'..='9' => out.push(c),
+            _ if Some(i) == decimal => out.push('.'),
+            _ => {}
+        }
+    }
+    (!out.is_empty()).then_some(out)
+}
+
+fn currency_symbol(c: char) -> Option<&'static str> {
+    Some(match c {
+        '$' => "USD",
+        '€' => "EUR",
+        '£' => "GBP",
+        '¥' => "JPY",
+        '₹' => "INR",
+        _ => return None,
+    })
+}
+
+fn is_currency_code(s: &str) -> bool {
+    const CODES: &[&str] = &["USD", "EUR", "GBP", "JPY", "CHF", "CAD", "AUD", "SEK", "NOK", "DKK", "PLN", "CZK", "INR", "CNY"];
+    CODES.contains(&s)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Label {
+    Number,
+    Date,
+    Subtotal,
+    Tax,
+    Total,
+}
+
+fn classify_label(label: &str) -> Option<Label> {
+    let label = label.trim().trim_end_matches([':', '.', '#']).trim().to_lowercase();
+    Some(match label.as_str() {
+        "invoice" | "invoice no" | "invoice number" | "invoice nr" | "invoice #" | "invoice id" | "bill no" => Label::Number,
+        "date" | "invoice date" | "date of issue" | "issue date" | "issued" => Label::Date,
+        "subtotal" | "sub-total" | "sub total" | "net total" | "net amount" => Label::Subtotal,
+        "tax" | "vat" | "gst" | "sales tax" => Label::Tax,
+        "total" | "total due" | "amount due" | "balance due" | "grand total" | "invoice total" => Label::Total,
+        // Rate-qualified tax labels such as "VAT 24%"
+        l if ["tax", "vat", "gst"].iter().any(|p| l.starts_with(p)) && l.ends_with('%') => Label::Tax,
+        _ => return None,
+    })
+}
+
+// Split a line into a recognised label and its value: "Label: value", "Label value",
+// or a label alone on its line with the value on the next one
+fn labelled_value<'a>(text: &'a str, following: &'a [TextLine]) -> Option<(Label, &'a str, f32)> {
+    if let Some((label, value)) = text.split_once(':')
+        && let Some(kind) = classify_label(label)
+    {
+        let value = value.trim();
+        if !value.is_empty() {
+            return Some((kind, value, 0.9));
+        }
+        let next = following.first()?.text.trim();
+        return (!next.is_empty()).then_some((kind, next, 0.6));
+    }
+
+    // Longest label prefix followed by a value on the same line
+    let words: Vec<(usize, &str)> = text.match_indices(|c: char| !c.is_whitespace())
+        .map(|(i, _)| i)
+        .filter(|&i| i == 0 || text[..i].ends_with(char::is_whitespace))
+        .map(|i| (i, &text[i..]))
+        .collect();
+    for &(start, _) in words.iter().skip(1).take(4).rev() {
+        if let Some(kind) = classify_label(&text[..start]) {
+            let value = text[start..].trim().trim_start_matches(['#', ':']).trim();
+            if !value.is_empty() {
+                return Some((kind, value, 0.7));
+            }
+        }
+    }
+    None
+}
+
+fn is_table_header(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let has_description = ["description", "item", "product", "service"].iter().any(|w| lower.contains(w));
+    let has_amount = ["amount", "total", "price", "qty", "quantity"].iter().any(|w| lower.contains(w));
+    has_description && has_amount
+}
+
+// Cells separated by wide horizontal gaps between glyphs
+fn cells(line: &TextLine) -> Vec<String> {
+    let mut cells: Vec<String> = Vec::new();
+    let mut last_end = f64::NEG_INFINITY;
+    for glyph in &line.glyphs {
+        if cells.is_empty() || glyph.x - last_end > glyph.font_size * CELL_GAP {
+            cells.push(String::new());
+        } else if glyph.starts_word && glyph.x > last_end + glyph.font_size * 0.1 {
+            cells.last_mut().expect("pushed above").push(' ');
+        }
+        cells.last_mut().expect("pushed above").push_str(&glyph.text);
+        last_end = glyph.x + glyph.width;
+    }
+    cells.into_iter().map(|c| c.trim().to_string()).filter(|c| !c.is_empty()).collect()
+}
+
+fn line_item(page_num: u32, line: &TextLine) -> Option<LineItem> {
+    let cells = cells(line);
+    let (amount_cell, rest) = cells.split_last()?;
+    let amount = parse_amount(amount_cell)?;
+    let description_end = rest.iter().position(|c| parse_amount(c).is_some()).unwrap_or(rest.len());
+    let description = rest[..description_end].join(" ");
+    if description.is_empty() {
+        return None;
+    }
+    let numbers: Vec<Amount> = rest[description_end..].iter().filter_map(|c| parse_amount(c)).collect();
+    let (quantity, unit_price) = match numbers.as_slice() {
+        [quantity, unit_price, ..] => (Some(quantity.value), Some(unit_price.clone())),
+        [single] if single.currency.is_some() => (None, Some(single.clone())),
+        [single] => (Some(single.value), None),
+        [] => (None, None),
+    };
+    // Rows whose columns multiply out are almost certainly line items
+    let consistent = matches!((quantity, &unit_price), (Some(q), Some(p)) if (q * p.value - amount.value).abs() < 0.01);
+    Some(LineItem {
+        description,
+        quantity,
+        unit_price,
+        amount,
+        confidence: if consistent { 0.9 } else if numbers.is_empty() { 0.5 } else { 0.7 },
+        page_num,
+        bbox: line.bbox,
+    })
+}
+
+// The vendor name is usually the first, prominent line at the top of the first page
+fn is_vendor_candidate(page: &PageText, line: &TextLine) -> bool {
+    let text = line.text.trim();
+    let top = page.media_box.ury - (page.media_box.ury - page.media_box.lly) * 0.25;
+    line.bbox.lly >= top
+        && text.chars().any(char::is_alphabetic)
+        && !text.to_lowercase().starts_with("invoice")
+        && parse_amount(text).is_none()
+}
+
+// Fill in missing currencies with the one used most often on the invoice
+fn infer_currency(invoice: &mut Invoice) {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let totals = [&invoice.totals.subtotal, &invoice.totals.tax, &invoice.totals.total];
+    let seen = totals.into_iter().flatten().map(|f| &f.value)
+        .chain(invoice.line_items.iter().flat_map(|i| std::iter::once(&i.amount).chain(&i.unit_price)));
+    for amount in seen {
+        if let Some(currency) = &amount.currency {
+            *counts.entry(currency.clone()).or_default() += 1;
+        }
+    }
+    let Some(currency) = counts.into_iter().max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0))).map(|c| c.0) else {
+        return;
+    };
+    let fill = |amount: &mut Amount| {
+        if amount.currency.is_none() {
+            amount.currency = Some(currency.clone());
+        }
+    };
+    let totals = [&mut invoice.totals.subtotal, &mut invoice.totals.tax, &mut invoice.totals.total];
+    totals.into_iter().flatten().for_each(|f| fill(&mut f.value));
+    for item in &mut invoice.line_items {
+        fill(&mut item.amount);
+        if let Some(price) = &mut item.unit_price {
+            fill(price);
+        }
+    }
+}
+
+// `2024-03-05`, `05.03.2024`, `03/05/2024` (US order), `5 March 2024`, `March 5, 2024`
+fn normalize_date(s: &str) -> Option<String> {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let format = |y: u32, m: u32, d: u32| {
+        ((1..=12).contains(&m) && (1..=31).contains(&d) && (1900..=2999).contains(&y))
+            .then(|| format!("{:04}-{:02}-{:02}", y, m, d))
+    };
+
+    let parts: Vec<&str> = s.split(|c: char| c == '-' || c == '.' || c == '/' || c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let month_name = |p: &str| {
+        let lower = p.to_lowercase();
+        MONTHS.iter().position(|m| lower.starts_with(m)).map(|i| i as u32 + 1)
+    };
+    let numbers: Vec<Option<u32>> = parts.iter().map(|p| p.parse().ok()).collect();
+    match (parts.as_slice(), numbers.as_slice()) {
+        ([y, ..], [Some(year), Some(m), Some(d), ..]) if y.len() == 4 => format(*year, *m, *d),
+        (_, [Some(a), Some(b), Some(year), ..]) => {
+            // Dots are the European convention, slashes the US one
+            if s.contains('/') && *a <= 12 { format(*year, *a, *b) } else { format(*year, *b, *a) }
+        }
+        ([_, month, _, ..], [Some(d), None, Some(year), ..]) => format(*year, month_name(month)?, *d),
+        ([month, _, _, ..], [None, Some(d), Some(year), ..]) => format(*year, month_name(month)?, *d),
+        _ => None,
+    }
+}
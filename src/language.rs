@@ -0,0 +1,100 @@
+// Natural language of text
+//
+// Tagged documents give the language of their text in `/Lang` entries: the
+// catalog's for the whole document, a structure element's for the content
+// it owns and a marked-content property list's for that span of content.
+// The innermost one applies. Structure elements own content by marked
+// content id (MCID), so the structure tree is mapped to languages by page
+// and MCID once per document; the interpreter looks up each `BDC` and
+// reports the language before each shown string through
+// `OutputDev::set_language`.
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::{document_utils, object_utils, string_utils, Dictionary, Document, Object, ObjectId};
+
+/// `/Lang` of the catalog, e.g. `en-US`
+pub fn document_language(doc: &Document) -> Option<String> {
+    let catalog = document_utils::get_catalog(doc).ok()?;
+    lang(doc, catalog).map(|lang| lang.to_string())
+}
+
+// Languages of the structure tree by page and marked content id
+pub(crate) struct Languages {
+    default: Option<Arc<str>>,
+    by_mcid: HashMap<(ObjectId, i64), Arc<str>>,
+}
+
+impl Languages {
+    pub(crate) fn new(doc: &Document) -> Languages {
+        let catalog = document_utils::get_catalog(doc).ok();
+        let mut languages = Languages { default: catalog.and_then(|c| lang(doc, c)), by_mcid: HashMap::new() };
+        let root = catalog.and_then(|c| object_utils::maybe_get_obj(doc, c, b"StructTreeRoot")).and_then(|o| o.as_dict().ok());
+        if let Some(kids) = root.and_then(|root| root.get(b"K").ok()) {
+            languages.walk(doc, kids, None, None, &mut HashSet::new());
+        }
+        languages
+    }
+
+    /// Language outside any marked content with one
+    pub(crate) fn default(&self) -> Option<Arc<str>> {
+        self.default.clone()
+    }
+
+    /// Language of the marked content a `BDC` on `page` with `properties` begins: their `/Lang`,
+    /// or that of the structure element owning their MCID
+    pub(crate) fn marked_content(&self, doc: &Document, page: ObjectId, properties: &Dictionary) -> Option<Arc<str>> {
+        lang(doc, properties).or_else(|| {
+            let mcid = properties.get(b"MCID").and_then(|o| o.as_i64()).ok()?;
+            self.by_mcid.get(&(page, mcid)).cloned()
+        })
+    }
+
+    // Record the content of `node` and its descendants under the language they inherit
+    fn walk(&mut self, doc: &Document, node: &Object, lang_in: Option<Arc<str>>, page_in: Option<ObjectId>, visited: &mut HashSet<ObjectId>) {
+        if let Object::Reference(id) = node
+            && !visited.insert(*id)
+        {
+            return;
+        }
+        let Ok(node) = object_utils::maybe_deref(doc, node) else { return };
+        match node {
+            Object::Integer(mcid) => {
+                if let (Some(lang), Some(page)) = (lang_in, page_in) {
+                    self.by_mcid.insert((page, *mcid), lang);
+                }
+            }
+            Object::Array(kids) => {
+                for kid in kids {
+                    self.walk(doc, kid, lang_in.clone(), page_in, visited);
+                }
+            }
+            Object::Dictionary(dict) => {
+                let page = dict.get(b"Pg").and_then(|o| o.as_reference()).ok().or(page_in);
+                match dict.get(b"Type").and_then(|o| o.as_name()).ok() {
+                    // A marked-content reference, possibly on another page
+                    Some(b"MCR") => {
+                        if let Ok(mcid) = dict.get(b"MCID") {
+                            self.walk(doc, mcid, lang_in, page, visited);
+                        }
+                    }
+                    // Annotations and XObjects owned by the element don't have MCIDs of the page
+                    Some(b"OBJR") => {}
+                    _ => {
+                        let lang = lang(doc, dict).or(lang_in);
+                        if let Ok(kids) = dict.get(b"K") {
+                            self.walk(doc, kids, lang, page, visited);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn lang(doc: &Document, dict: &Dictionary) -> Option<Arc<str>> {
+    let lang = object_utils::maybe_get_obj(doc, dict, b"Lang")?.as_str().ok()?;
+    let lang = string_utils::pdf_to_utf8(lang).ok()?;
+    (!lang.trim().is_empty()).then(|| Arc::from(lang.trim()))
+}
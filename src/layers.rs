@@ -0,0 +1,298 @@
+// Background/foreground separation of page content
+//
+// Letterheads, scanned page images and watermark layers sit behind the body
+// of a page. `LayerFilter` classifies each drawing call and forwards only the
+// requested layer to the wrapped device, so text output isn't polluted by
+// stamps and raster previews can skip the body (or the backdrop).
+use euclid::point2;
+
+use crate::shading::Shading;
+use crate::watermark::{classify, WatermarkKind};
+use crate::{
+    output_doc, CharCode, CharInfo, ClipPath, ColorSpace, Dictionary, Document, FillRule, LineStyle, MediaBox, OutputDev, Path, PathOp, PdfError, PdfFont, PdfResult,
+    PdfTransform, PlainTextOutput, Stream, TilingPattern, UnmappedGlyph,
+};
+
+// Fraction of the page an image must cover to count as a page backdrop
+const BACKGROUND_IMAGE_COVERAGE: f64 = 0.8;
+// Fraction of the page a filled path must cover to count as a backdrop
+const BACKGROUND_FILL_COVERAGE: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentLayer {
+    /// Full-page images, large fills and diagonal or faint watermark text
+    Background,
+    /// Body text, strokes, small fills and figures
+    Foreground,
+}
+
+/// Device wrapper forwarding only the content of one layer
+pub struct LayerFilter<'a> {
+    inner: &'a mut dyn OutputDev,
+    layer: ContentLayer,
+    media_box: MediaBox,
+    fill_color: Option<(f64, f64, f64)>,
+    fill_alpha: f64,
+    // Layer of the last character shown, which its glyph shares
+    glyph_layer: ContentLayer,
+    // Innermost clipping path, which shadings fill
+    clip: Option<ClipPath>,
+}
+
+impl<'a> LayerFilter<'a> {
+    pub fn new(inner: &'a mut dyn OutputDev, layer: ContentLayer) -> LayerFilter<'a> {
+        LayerFilter {
+            inner,
+            layer,
+            media_box: MediaBox { llx: 0., lly: 0., urx: 0., ury: 0. },
+            fill_color: Some((0., 0., 0.)),
+            fill_alpha: 1.,
+            glyph_layer: ContentLayer::Foreground,
+            clip: None,
+        }
+    }
+
+    // Share of the page covered by the device space bounding box of `points`
+    fn coverage(&self, points: impl IntoIterator<Item = (f64, f64)>, ctm: &PdfTransform) -> f64 {
+        let mut bbox: Option<(f64, f64, f64, f64)> = None;
+        for (x, y) in points {
+            let p = ctm.transform_point(point2(x, y));
+            bbox = Some(match bbox {
+                Some((llx, lly, urx, ury)) => (llx.min(p.x), lly.min(p.y), urx.max(p.x), ury.max(p.y)),
+                None => (p.x, p.y, p.x, p.y),
+            });
+        }
+        let page = &self.media_box;
+        let page_area = (page.urx - page.llx) * (page.ury - page.lly);
+        match bbox {
+            Some((llx, lly, urx, ury)) if page_area > 0. => {
+                let w = (urx.min(page.urx) - llx.max(page.llx)).max(0.);
+                let h = (ury.min(page.ury) - lly.max(page.lly)).max(0.);
+                w * h / page_area
+            }
+            _ => 0.,
+        }
+    }
+
+    fn fill_layer(&self, ctm: &PdfTransform, path: &Path) -> ContentLayer {
+        let mut points = Vec::new();
+        for op in &path.ops {
+            match *op {
+                PathOp::MoveTo(x, y) | PathOp::LineTo(x, y) => points.push((x, y)),
+                PathOp::CurveTo(x1, y1, x2, y2, x, y) => points.extend([(x1, y1), (x2, y2), (x, y)]),
+                PathOp::Rect(x, y, w, h) => points.extend([(x, y), (x + w, y + h)]),
+                PathOp::Close => {}
+            }
+        }
+        if self.coverage(points, ctm) >= BACKGROUND_FILL_COVERAGE {
+            ContentLayer::Background
+        } else {
+            ContentLayer::Foreground
+        }
+    }
+
+    fn image_layer(&self, ctm: &PdfTransform) -> ContentLayer {
+        if self.coverage([(0., 0.), (1., 0.), (0., 1.), (1., 1.)], ctm) >= BACKGROUND_IMAGE_COVERAGE {
+            ContentLayer::Background
+        } else {
+            ContentLayer::Foreground
+        }
+    }
+
+    fn text_layer(&self, trm: &PdfTransform, font_size: f64) -> ContentLayer {
+        let angle = trm.m12.atan2(trm.m11).to_degrees();
+        let size = font_size * trm.determinant().abs().sqrt();
+        let kinds = classify(angle, size, self.fill_color, self.fill_alpha);
+        if kinds.contains(&WatermarkKind::Diagonal) || kinds.contains(&WatermarkKind::Faint) {
+            ContentLayer::Background
+        } else {
+            ContentLayer::Foreground
+        }
+    }
+}
+
+impl OutputDev for LayerFilter<'_> {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.media_box = *media_box;
+        self.inner.begin_page(page_num, media_box, art_box)
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        self.inner.end_page()
+    }
+
+    fn begin_document(&mut self, pages: &[u32]) -> PdfResult<()> {
+        self.inner.begin_document(pages)
+    }
+
+    fn end_document(&mut self) -> PdfResult<()> {
+        self.inner.end_document()
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        self.glyph_layer = self.text_layer(trm, font_size);
+        if self.glyph_layer != self.layer {
+            return Ok(());
+        }
+        self.inner.output_character(trm, width, spacing, font_size, char)
+    }
+
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.glyph_layer = self.text_layer(trm, font_size);
+        if self.glyph_layer != self.layer {
+            return Ok(());
+        }
+        self.inner.output_char_info(trm, width, spacing, font_size, char, info)
+    }
+
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        self.glyph_layer = self.text_layer(trm, font_size);
+        if self.glyph_layer != self.layer {
+            return Ok(());
+        }
+        self.inner.output_unmapped_glyph(trm, width, spacing, font_size, glyph)
+    }
+
+    fn output_glyph(&mut self, gid: Option<u16>, cid: CharCode, unicode: &str, trm: &PdfTransform, advance: f64) -> PdfResult<()> {
+        if self.glyph_layer != self.layer {
+            return Ok(());
+        }
+        self.inner.output_glyph(gid, cid, unicode, trm, advance)
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        self.inner.set_font(font)
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.fill_color = colorspace.to_rgb(color);
+        self.fill_alpha = alpha;
+        self.inner.set_fill_color(colorspace, color, alpha)
+    }
+
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> {
+        self.inner.set_language(lang)
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.inner.begin_word()
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> {
+        self.inner.end_word()
+    }
+
+    fn end_line(&mut self) -> PdfResult<()> {
+        self.inner.end_line()
+    }
+
+    fn text_adjustment(&mut self, amount: f64, displacement: f64) -> PdfResult<()> {
+        self.inner.text_adjustment(amount, displacement)
+    }
+
+    fn end_text_array(&mut self) -> PdfResult<()> {
+        self.inner.end_text_array()
+    }
+
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> {
+        self.inner.glyph_kerning(amount)
+    }
+
+    fn begin_text_object(&mut self) -> PdfResult<()> {
+        self.inner.begin_text_object()
+    }
+
+    fn end_text_object(&mut self) -> PdfResult<()> {
+        self.inner.end_text_object()
+    }
+
+    fn begin_marked_content(&mut self, tag: &str, properties: Option<&Dictionary>) -> PdfResult<()> {
+        self.inner.begin_marked_content(tag, properties)
+    }
+
+    fn end_marked_content(&mut self) -> PdfResult<()> {
+        self.inner.end_marked_content()
+    }
+
+    fn set_line_style(&mut self, style: &LineStyle) -> PdfResult<()> {
+        self.inner.set_line_style(style)
+    }
+
+    fn set_clip(&mut self, clip: &[ClipPath]) -> PdfResult<()> {
+        self.clip = clip.last().cloned();
+        self.inner.set_clip(clip)
+    }
+
+    fn stroke(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path) -> PdfResult<()> {
+        if self.layer != ContentLayer::Foreground {
+            return Ok(());
+        }
+        self.inner.stroke(ctm, colorspace, color, path)
+    }
+
+    fn fill(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path, rule: FillRule) -> PdfResult<()> {
+        if self.fill_layer(ctm, path) != self.layer {
+            return Ok(());
+        }
+        self.inner.fill(ctm, colorspace, color, path, rule)
+    }
+
+    fn shade(&mut self, ctm: &PdfTransform, shading: &Shading) -> PdfResult<()> {
+        // Shadings fill the clipping region, the whole page without one
+        let layer = match &self.clip {
+            Some(clip) => self.fill_layer(&clip.ctm, &clip.path),
+            None => ContentLayer::Background,
+        };
+        if layer != self.layer {
+            return Ok(());
+        }
+        self.inner.shade(ctm, shading)
+    }
+
+    fn begin_pattern(&mut self, ctm: &PdfTransform, pattern: &TilingPattern) -> PdfResult<()> {
+        self.inner.begin_pattern(ctm, pattern)
+    }
+
+    fn end_pattern(&mut self) -> PdfResult<()> {
+        self.inner.end_pattern()
+    }
+
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        if self.image_layer(ctm) != self.layer {
+            return Ok(());
+        }
+        self.inner.draw_image(ctm, image)
+    }
+
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        if self.image_layer(ctm) != self.layer {
+            return Ok(());
+        }
+        self.inner.draw_inline_image(ctm, image)
+    }
+}
+
+/// Plain text of only the foreground or background content of `doc`
+pub fn extract_text_layer(doc: &Document, layer: ContentLayer) -> PdfResult<String> {
+    let mut s = Vec::new();
+    {
+        let mut output = PlainTextOutput::new(&mut s);
+        output_doc(doc, &mut LayerFilter::new(&mut output, layer))?;
+    }
+    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+}
@@ -0,0 +1,597 @@
+// Positioned text collection and line/paragraph grouping
+use std::io::Write;
+use std::sync::Arc;
+
+
+use crate::fonts::FontStyle;
+use crate::string_utils::hex_color;
+#[cfg(not(feature = "serde"))]
+use crate::string_utils::json_string;
+use crate::{output_doc, CharInfo, ColorSpace, Document, LayoutParams, MediaBox, OutputDev, PdfFont, PdfResult, PdfTransform};
+
+/// Axis-aligned box in PDF user space (origin bottom-left)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BBox {
+    pub llx: f64,
+    pub lly: f64,
+    pub urx: f64,
+    pub ury: f64,
+}
+
+impl BBox {
+    pub fn width(&self) -> f64 {
+        self.urx - self.llx
+    }
+
+    pub fn height(&self) -> f64 {
+        self.ury - self.lly
+    }
+
+    pub fn union(&self, other: &BBox) -> BBox {
+        BBox {
+            llx: self.llx.min(other.llx),
+            lly: self.lly.min(other.lly),
+            urx: self.urx.max(other.urx),
+            ury: self.ury.max(other.ury),
+        }
+    }
+
+    pub fn intersects(&self, other: &BBox) -> bool {
+        self.llx < other.urx && other.llx < self.urx && self.lly < other.ury && other.lly < self.ury
+    }
+}
+
+/// A single decoded glyph with its position on the page
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TextGlyph {
+    /// Baseline origin of the glyph
+    pub x: f64,
+    pub y: f64,
+    /// Advance width in user space units
+    pub width: f64,
+    /// Font size after the text and current transformation matrices are applied
+    pub font_size: f64,
+    pub text: String,
+    /// `BaseFont` of the font the glyph was shown in
+    pub font: Option<Arc<str>>,
+    /// Baseline direction in degrees, counterclockwise from the x axis
+    pub angle: f64,
+    /// Colour the glyph is painted in as RGB in 0..1, when the colour space maps to RGB directly:
+    /// the stroke colour for outlined text, the fill colour otherwise
+    pub color: Option<(f64, f64, f64)>,
+    /// Constant alpha (`ca`, or `CA` for outlined text), 0 for invisible text
+    pub alpha: f64,
+    /// Text rendering mode (`Tr`) the glyph was shown with
+    pub render_mode: i64,
+    /// `/Lang` of the marked content, structure element or document the glyph belongs to
+    pub lang: Option<Arc<str>>,
+    /// Set on the first glyph of each shown string (`Tj`/`TJ` element)
+    pub starts_word: bool,
+    /// AFM kerning between the previous glyph and this one in user space units, negative when
+    /// the font sets them closer than their widths, see `OutputDev::glyph_kerning`
+    pub kerning: f64,
+}
+
+impl TextGlyph {
+    /// Black, opaque, upright glyph starting a word, for building pages by hand
+    pub fn new(text: &str, x: f64, y: f64, width: f64, font_size: f64) -> TextGlyph {
+        TextGlyph {
+            x,
+            y,
+            width,
+            font_size,
+            text: text.to_owned(),
+            font: None,
+            angle: 0.,
+            color: Some((0., 0., 0.)),
+            alpha: 1.,
+            render_mode: 0,
+            lang: None,
+            starts_word: true,
+            kerning: 0.,
+        }
+    }
+
+    /// Whether the glyph is painted in a colour that can be seen; invisible text, as laid over
+    /// scans by OCR, and fully transparent text are not
+    pub fn is_visible(&self) -> bool {
+        !matches!(self.render_mode, 3 | 7) && self.alpha > 0.
+    }
+
+    pub fn bbox(&self) -> BBox {
+        BBox {
+            llx: self.x,
+            lly: self.y - self.font_size * 0.2,
+            urx: self.x + self.width,
+            ury: self.y + self.font_size * 0.8,
+        }
+    }
+}
+
+/// A run of glyphs sharing a baseline
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextLine {
+    pub text: String,
+    pub bbox: BBox,
+    pub font_size: f64,
+    pub glyphs: Vec<TextGlyph>,
+}
+
+/// Consecutive glyphs of a line sharing fill colour, alpha and visibility
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextFragment {
+    pub text: String,
+    pub bbox: BBox,
+    pub color: Option<(f64, f64, f64)>,
+    pub alpha: f64,
+    /// Whether the glyphs are seen, see `TextGlyph::is_visible`
+    pub visible: bool,
+}
+
+impl TextLine {
+    /// Language of most of the line's glyphs
+    pub fn lang(&self) -> Option<&str> {
+        majority_lang(self.glyphs.iter())
+    }
+
+    /// The line split where the fill colour, alpha or visibility changes; the fragment texts concatenate to
+    /// the line's text
+    pub fn fragments(&self) -> Vec<TextFragment> {
+        let mut fragments: Vec<TextFragment> = Vec::new();
+        // Walk the line's text alongside its glyphs to find the spaces inserted between them, so
+        // the fragments split the line however it was grouped
+        let mut rest = self.text.as_str();
+        for glyph in &self.glyphs {
+            if !rest.starts_with(glyph.text.as_str())
+                && let Some(after) = rest.strip_prefix(' ')
+            {
+                if let Some(fragment) = fragments.last_mut() {
+                    fragment.text.push(' ');
+                }
+                rest = after;
+            }
+            rest = rest.strip_prefix(glyph.text.as_str()).unwrap_or(rest);
+            match fragments.last_mut() {
+                Some(fragment) if fragment.color == glyph.color && fragment.alpha == glyph.alpha && fragment.visible == glyph.is_visible() => {
+                    fragment.text.push_str(&glyph.text);
+                    fragment.bbox = fragment.bbox.union(&glyph.bbox());
+                }
+                _ => fragments.push(TextFragment {
+                    text: glyph.text.clone(),
+                    bbox: glyph.bbox(),
+                    color: glyph.color,
+                    alpha: glyph.alpha,
+                    visible: glyph.is_visible(),
+                }),
+            }
+        }
+        fragments
+    }
+}
+
+/// Consecutive lines grouped by proximity and font size
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Paragraph {
+    pub page_num: u32,
+    pub bbox: BBox,
+    pub lines: Vec<TextLine>,
+}
+
+impl Paragraph {
+    /// Language of most of the paragraph's glyphs
+    pub fn lang(&self) -> Option<&str> {
+        majority_lang(self.lines.iter().flat_map(|line| &line.glyphs))
+    }
+
+    /// Text of the paragraph with line breaks unwrapped into spaces
+    pub fn text(&self) -> String {
+        join_lines(&self.lines)
+    }
+}
+
+// The most common language of `glyphs`, glyphs without one counted as a language of their own
+fn majority_lang<'a>(glyphs: impl Iterator<Item = &'a TextGlyph>) -> Option<&'a str> {
+    let mut counts: Vec<(Option<&str>, usize)> = Vec::new();
+    for glyph in glyphs {
+        let lang = glyph.lang.as_deref();
+        match counts.iter_mut().find(|(l, _)| *l == lang) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((lang, 1)),
+        }
+    }
+    // The first language wins ties
+    counts.iter().rev().max_by_key(|(_, count)| *count).and_then(|(lang, _)| *lang)
+}
+
+// Unwrap lines into running text, joining words hyphenated across a line break
+pub(crate) fn join_lines(lines: &[TextLine]) -> String {
+    let mut result = String::new();
+    for line in lines {
+        let text = line.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if !result.is_empty() {
+            if result.ends_with('-') {
+                result.pop();
+            } else {
+                result.push(' ');
+            }
+        }
+        result.push_str(text);
+    }
+    result
+}
+
+/// All glyphs collected from one page
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageText {
+    pub page_num: u32,
+    pub media_box: MediaBox,
+    pub glyphs: Vec<TextGlyph>,
+}
+
+impl PageText {
+    /// Group glyphs into lines using the default thresholds of `PlainTextOutput`, see `LayoutParams`
+    pub fn lines(&self) -> Vec<TextLine> {
+        self.lines_with(LayoutParams::default())
+    }
+
+    /// Group glyphs into lines at the thresholds of `params`
+    pub fn lines_with(&self, params: LayoutParams) -> Vec<TextLine> {
+        let mut lines: Vec<TextLine> = Vec::new();
+        let mut last_end = f64::INFINITY;
+        let mut last_y = 0.;
+        for glyph in &self.glyphs {
+            let size = glyph.font_size;
+            let new_line = match lines.last() {
+                None => true,
+                Some(_) => {
+                    (glyph.y - last_y).abs() > size * params.line_break
+                        || (glyph.x < last_end && (glyph.y - last_y).abs() > size * params.baseline_shift)
+                }
+            };
+            if new_line {
+                lines.push(TextLine {
+                    text: String::new(),
+                    bbox: glyph.bbox(),
+                    font_size: size,
+                    glyphs: Vec::new(),
+                });
+            }
+            let line = lines.last_mut().expect("line was pushed above");
+            if !new_line && glyph.starts_word && glyph.x > last_end + glyph.kerning + size * params.word_gap {
+                line.text.push(' ');
+            }
+            line.text.push_str(&glyph.text);
+            line.bbox = line.bbox.union(&glyph.bbox());
+            line.font_size = line.font_size.max(size);
+            line.glyphs.push(glyph.clone());
+            last_end = glyph.x + glyph.width;
+            last_y = glyph.y;
+        }
+        lines.retain(|l| !l.text.trim().is_empty());
+        lines
+    }
+
+    /// Group lines into paragraphs, breaking on large vertical gaps and font size changes
+    pub fn paragraphs(&self) -> Vec<Paragraph> {
+        self.paragraphs_with(LayoutParams::default())
+    }
+
+    /// `paragraphs` of the lines grouped at the thresholds of `params`
+    pub fn paragraphs_with(&self, params: LayoutParams) -> Vec<Paragraph> {
+        let mut paragraphs: Vec<Paragraph> = Vec::new();
+        for line in self.lines_with(params) {
+            let continues = paragraphs.last().and_then(|p| p.lines.last()).is_some_and(|prev| {
+                let size = prev.font_size.max(line.font_size);
+                let gap = prev.bbox.lly - line.bbox.ury;
+                let similar_size = (prev.font_size - line.font_size).abs() <= size * 0.2;
+                similar_size && gap >= -size * 0.5 && gap <= size * 0.8
+            });
+            if continues {
+                let paragraph = paragraphs.last_mut().expect("checked above");
+                paragraph.bbox = paragraph.bbox.union(&line.bbox);
+                paragraph.lines.push(line);
+            } else {
+                paragraphs.push(Paragraph {
+                    page_num: self.page_num,
+                    bbox: line.bbox,
+                    lines: vec![line],
+                });
+            }
+        }
+        paragraphs
+    }
+}
+
+/// Output device that records positioned glyphs for every page
+pub struct TextCollector {
+    pages: Vec<PageText>,
+    font: Option<Arc<str>>,
+    color: Option<(f64, f64, f64)>,
+    alpha: f64,
+    lang: Option<Arc<str>>,
+    starts_word: bool,
+    render_mode: i64,
+    // AFM kerning before the next glyph in ems
+    kerning: f64,
+}
+
+impl TextCollector {
+    pub fn new() -> TextCollector {
+        TextCollector { pages: Vec::new(), font: None, color: Some((0., 0., 0.)), alpha: 1., lang: None, starts_word: false, render_mode: 0, kerning: 0. }
+    }
+
+    pub fn pages(&self) -> &[PageText] {
+        &self.pages
+    }
+
+    pub fn into_pages(self) -> Vec<PageText> {
+        self.pages
+    }
+}
+
+impl Default for TextCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputDev for TextCollector {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.pages.push(PageText { page_num, media_box: *media_box, glyphs: Vec::new() });
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        Ok(())
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, _spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        // Area scale of the text rendering matrix, which stays correct for rotated text
+        let transformed_font_size = font_size * trm.determinant().abs().sqrt();
+        if let Some(page) = self.pages.last_mut() {
+            page.glyphs.push(TextGlyph {
+                x: trm.m31,
+                y: trm.m32,
+                width: width * transformed_font_size,
+                font_size: transformed_font_size,
+                text: char.to_owned(),
+                font: self.font.clone(),
+                angle: trm.m12.atan2(trm.m11).to_degrees(),
+                color: self.color,
+                alpha: self.alpha,
+                lang: self.lang.clone(),
+                starts_word: self.starts_word,
+                render_mode: self.render_mode,
+                kerning: self.kerning * transformed_font_size,
+            });
+        }
+        self.starts_word = false;
+        self.kerning = 0.;
+        Ok(())
+    }
+
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        let fill = (self.color, self.alpha);
+        (self.color, self.alpha) = info.visible_color();
+        self.render_mode = info.render_mode;
+        let result = self.output_character(trm, width, spacing, font_size, char);
+        (self.color, self.alpha) = fill;
+        self.render_mode = 0;
+        result
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        if self.font.as_deref() != font.base_name() {
+            self.font = font.base_name().map(Arc::from);
+        }
+        Ok(())
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.color = colorspace.to_rgb(color);
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> {
+        if self.lang.as_deref() != lang {
+            self.lang = lang.map(Arc::from);
+        }
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.starts_word = true;
+        Ok(())
+    }
+
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> {
+        self.kerning = amount / 1000.;
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+}
+
+/// Collect positioned glyphs for every page of `doc`
+pub fn collect_text(doc: &Document) -> PdfResult<Vec<PageText>> {
+    let mut collector = TextCollector::new();
+    output_doc(doc, &mut collector)?;
+    Ok(collector.into_pages())
+}
+
+/// Detect paragraphs on every page of `doc`, in page order
+pub fn extract_paragraphs(doc: &Document) -> PdfResult<Vec<Paragraph>> {
+    Ok(collect_text(doc)?.iter().flat_map(PageText::paragraphs).collect())
+}
+
+/// Text of `paragraphs` separated by blank lines, each unwrapped and, given a `width`, re-wrapped
+/// to lines of at most that many characters
+pub fn reflow(paragraphs: &[Paragraph], width: Option<usize>) -> String {
+    paragraphs.iter()
+        .map(|p| match width {
+            Some(width) => wrap(&p.text(), width),
+            None => p.text(),
+        })
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Break `text` between words into lines of at most `width` characters; a longer word gets a
+/// line of its own
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut line_len = 0;
+    for word in text.split_whitespace() {
+        let len = word.chars().count();
+        if line_len > 0 && line_len + 1 + len > width {
+            result.push('\n');
+            line_len = 0;
+        } else if line_len > 0 {
+            result.push(' ');
+            line_len += 1;
+        }
+        result.push_str(word);
+        line_len += len;
+    }
+    result
+}
+
+/// Write one JSON object per text line of `pages`, with its position, font size, language and fragments of
+/// uniform fill colour (`"#rrggbb"`, or `null` for colour spaces without an RGB mapping) and alpha;
+/// fragments of invisible text also have `"invisible":true`
+#[cfg(feature = "serde")]
+pub fn write_json_lines<W: Write>(pages: &[PageText], w: &mut W) -> PdfResult<()> {
+    #[derive(serde::Serialize)]
+    struct Fragment<'a> {
+        text: &'a str,
+        bbox: [f64; 4],
+        color: Option<String>,
+        alpha: f64,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        invisible: bool,
+    }
+    #[derive(serde::Serialize)]
+    struct Line<'a> {
+        page: u32,
+        bbox: [f64; 4],
+        size: f64,
+        lang: Option<&'a str>,
+        text: &'a str,
+        fragments: Vec<Fragment<'a>>,
+    }
+    // Two decimals, as without the feature
+    let round = |v: f64| (v * 100.).round() / 100.;
+    let corners = |b: BBox| [b.llx, b.lly, b.urx, b.ury].map(round);
+    for page in pages {
+        for line in page.lines() {
+            let fragments = line.fragments();
+            let record = Line {
+                page: page.page_num,
+                bbox: corners(line.bbox),
+                size: round(line.font_size),
+                lang: line.lang(),
+                text: line.text.trim(),
+                fragments: fragments.iter()
+                    .map(|f| Fragment {
+                        text: &f.text,
+                        bbox: corners(f.bbox),
+                        color: f.color.map(hex_color),
+                        alpha: round(f.alpha),
+                        invisible: !f.visible,
+                    })
+                    .collect(),
+            };
+            serde_json::to_writer(&mut *w, &record).map_err(std::io::Error::from)?;
+            writeln!(w)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write one JSON object per text line of `pages`, with its position, font size, language and fragments of
+/// uniform fill colour (`"#rrggbb"`, or `null` for colour spaces without an RGB mapping) and alpha;
+/// fragments of invisible text also have `"invisible":true`
+#[cfg(not(feature = "serde"))]
+pub fn write_json_lines<W: Write>(pages: &[PageText], w: &mut W) -> PdfResult<()> {
+    for page in pages {
+        for line in page.lines() {
+            let b = line.bbox;
+            let fragments: Vec<String> = line.fragments().iter()
+                .map(|f| {
+                    let color = f.color.map_or("null".to_string(), |c| format!("\"{}\"", hex_color(c)));
+                    let invisible = if f.visible { "" } else { r#","invisible":true"# };
+                    format!(r#"{{"text":{},"bbox":[{:.2},{:.2},{:.2},{:.2}],"color":{},"alpha":{:.2}{}}}"#,
+                            json_string(&f.text), f.bbox.llx, f.bbox.lly, f.bbox.urx, f.bbox.ury, color, f.alpha, invisible)
+                })
+                .collect();
+            let lang = line.lang().map_or("null".to_string(), json_string);
+            writeln!(w, r#"{{"page":{},"bbox":[{:.2},{:.2},{:.2},{:.2}],"size":{:.2},"lang":{},"text":{},"fragments":[{}]}}"#,
+                     page.page_num, b.llx, b.lly, b.urx, b.ury, line.font_size, lang, json_string(line.text.trim()),
+                     fragments.join(","))?;
+        }
+    }
+    Ok(())
+}
+
+// Size relative to the body from which a paragraph is an `h1`, `h2` and so on; single lines in
+// bold at the body size are an `h6`
+const HEADING_RATIOS: [f64; 5] = [2.0, 1.6, 1.35, 1.2, 1.1];
+
+// Heading level from 1 to 6 of `lines` among body text set in `body`, if they are a heading
+pub(crate) fn heading_level(lines: &[TextLine], body: f64) -> Option<usize> {
+    let size = lines.iter().map(|l| l.font_size).fold(0., f64::max);
+    HEADING_RATIOS.iter().position(|ratio| size >= body * ratio).map(|i| i + 1).or_else(|| {
+        let bold = lines.first()?.glyphs.iter().all(|g| g.font.as_deref().is_some_and(|f| FontStyle::from_name(f).bold));
+        (lines.len() == 1 && bold && body > 0.).then_some(6)
+    })
+}
+
+/// Write the paragraphs of `pages` as Markdown: paragraphs set larger than the body text, or
+/// single bold lines, become headings of the levels `flow::HtmlFlowOutput` gives them (`#` from
+/// twice the body size), and pages are separated by rules
+pub fn write_markdown<W: Write>(pages: &[PageText], w: &mut W) -> PdfResult<()> {
+    let paragraphs: Vec<Vec<Paragraph>> = pages.iter().map(PageText::paragraphs).collect();
+    // Body size is the size of most lines
+    let mut sizes: Vec<f64> = paragraphs.iter().flatten()
+        .flat_map(|p| p.lines.iter().map(|l| l.font_size))
+        .collect();
+    sizes.sort_by(f64::total_cmp);
+    let body = sizes.get(sizes.len().saturating_sub(1) / 2).copied().unwrap_or(0.);
+    for (i, page) in paragraphs.iter().enumerate() {
+        if i > 0 {
+            writeln!(w, "---\n")?;
+        }
+        for paragraph in page {
+            let text = paragraph.text();
+            if text.is_empty() {
+                continue;
+            }
+            match heading_level(&paragraph.lines, body) {
+                Some(level) => writeln!(w, "{} {}\n", "#".repeat(level), text)?,
+                None => writeln!(w, "{}\n", text)?,
+            }
+        }
+    }
+    Ok(())
+}
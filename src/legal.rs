@@ -0,0 +1,254 @@
+// Section and clause anchors for contracts and statutes
+//
+// Lines are classified by their leading numbering ("§ 12.3(a)", "Article IV",
+// "Section 2.1", "4.2", "(b)") and nested into an outline by numbering depth.
+use crate::layout::{collect_text, BBox};
+use crate::{Document, PdfResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClauseKind {
+    /// `Article IV`, `ARTICLE 2`, `Art. 5`
+    Article,
+    /// `Section 2.1`, `Sec. 3`
+    Section,
+    /// `§ 12.3(a)`, `§§ 4-5`
+    Paragraph,
+    /// Bare dotted numbering at the start of a line: `4.2`, `7.1.3(c)`
+    Numbered,
+    /// Parenthesised enumeration: `(a)`, `(iv)`, `(3)`
+    Enumerated,
+}
+
+/// A detected clause heading
+#[derive(Debug, Clone)]
+pub struct ClauseAnchor {
+    pub kind: ClauseKind,
+    /// The numbering as written, e.g. `§ 12.3(a)` or `Article IV`
+    pub label: String,
+    /// Remainder of the heading line after the label
+    pub title: String,
+    /// Nesting depth, 1 for top-level articles
+    pub level: usize,
+    /// Byte offset of the heading line in the analysed text
+    pub offset: usize,
+    /// Page (1 based) and position when the anchor comes from a document
+    pub page_num: Option<u32>,
+    pub bbox: Option<BBox>,
+}
+
+/// Outline node: a clause and the clauses nested under it
+#[derive(Debug, Clone)]
+pub struct ClauseNode {
+    pub anchor: ClauseAnchor,
+    pub children: Vec<ClauseNode>,
+}
+
+/// Text of a document together with the clause anchors found in it
+#[derive(Debug, Clone)]
+pub struct LegalOutline {
+    /// Extracted lines joined with `\n`; anchor offsets index into this
+    pub text: String,
+    pub anchors: Vec<ClauseAnchor>,
+}
+
+impl LegalOutline {
+    /// Nest the anchors into a tree by level
+    pub fn outline(&self) -> Vec<ClauseNode> {
+        build_outline(&self.anchors)
+    }
+
+    /// Text between `anchor` and the next anchor at the same or a higher level
+    pub fn clause_text(&self, index: usize) -> &str {
+        let Some(anchor) = self.anchors.get(index) else {
+            return "";
+        };
+        let end = self.anchors[index + 1..].iter()
+            .find(|a| a.level <= anchor.level)
+            .map_or(self.text.len(), |a| a.offset);
+        self.text[anchor.offset..end].trim_end()
+    }
+}
+
+/// Find clause anchors in plain text, one candidate per line
+pub fn find_clauses(text: &str) -> Vec<ClauseAnchor> {
+    let mut detector = Detector::default();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if let Some(anchor) = detector.classify(line.trim_end_matches(['\n', '\r']), offset) {
+            detector.anchors.push(anchor);
+        }
+        offset += line.len();
+    }
+    detector.anchors
+}
+
+/// Extract the document's text line by line and anchor its clauses
+pub fn analyze(doc: &Document) -> PdfResult<LegalOutline> {
+    let mut detector = Detector::default();
+    let mut text = String::new();
+    for page in collect_text(doc)? {
+        for line in page.lines() {
+            let line_text = line.text.trim();
+            if let Some(mut anchor) = detector.classify(line_text, text.len()) {
+                anchor.page_num = Some(page.page_num);
+                anchor.bbox = Some(line.bbox);
+                detector.anchors.push(anchor);
+            }
+            text.push_str(line_text);
+            text.push('\n');
+        }
+    }
+    Ok(LegalOutline { text, anchors: detector.anchors })
+}
+
+/// Nest anchors into a tree using their levels
+pub fn build_outline(anchors: &[ClauseAnchor]) -> Vec<ClauseNode> {
+    fn attach(nodes: &mut Vec<ClauseNode>, node: ClauseNode) {
+        match nodes.last_mut() {
+            Some(last) if last.anchor.level < node.anchor.level => attach(&mut last.children, node),
+            _ => nodes.push(node),
+        }
+    }
+    let mut roots = Vec::new();
+    for anchor in anchors {
+        attach(&mut roots, ClauseNode { anchor: anchor.clone(), children: Vec::new() });
+    }
+    roots
+}
+
+#[derive(Default)]
+struct Detector {
+    anchors: Vec<ClauseAnchor>,
+    // Level of the innermost numbered clause, used to nest bare `(a)` items
+    numbered_level: usize,
+    last_letter: Option<char>,
+}
+
+impl Detector {
+    fn classify(&mut self, line: &str, offset: usize) -> Option<ClauseAnchor> {
+        let trimmed = line.trim_start();
+        let offset = offset + (line.len() - trimmed.len());
+        let (kind, label, rest, level) = if let Some(r) = parse_article(trimmed) {
+            self.numbered_level = 1;
+            (ClauseKind::Article, r.0, r.1, 1)
+        } else if let Some((label, rest, depth)) = parse_prefixed_number(trimmed, &["Section", "SECTION", "Sec."]) {
+            self.numbered_level = depth + 1;
+            (ClauseKind::Section, label, rest, depth + 1)
+        } else if let Some((label, rest, depth)) = parse_prefixed_number(trimmed, &["§§", "§"]) {
+            self.numbered_level = depth + 1;
+            (ClauseKind::Paragraph, label, rest, depth + 1)
+        } else if let Some((label, rest, depth)) = parse_numbering(trimmed).filter(|(_, rest, _)| looks_like_heading(rest)) {
+            self.numbered_level = depth + 1;
+            (ClauseKind::Numbered, label.to_string(), rest, depth + 1)
+        } else if let Some((label, rest)) = parse_enumeration(trimmed) {
+            let inner = &label[1..label.len() - 1];
+            let letter = inner.chars().next().filter(|_| inner.len() == 1).filter(char::is_ascii_lowercase);
+            // Roman numerals nest under lettered items unless they continue the letter sequence
+            let is_roman = is_roman(inner)
+                && !(letter.is_some() && self.last_letter.map(|l| (l as u8 + 1) as char) == letter);
+            let level = if is_roman && self.last_letter.is_some() {
+                self.numbered_level + 2
+            } else {
+                if letter.is_some() {
+                    self.last_letter = letter;
+                }
+                self.numbered_level + 1
+            };
+            (ClauseKind::Enumerated, label.to_string(), rest, level)
+        } else {
+            return None;
+        };
+        if kind != ClauseKind::Enumerated {
+            self.last_letter = None;
+        }
+        Some(ClauseAnchor {
+            kind,
+            label,
+            title: rest.trim_start_matches(['.', ':', '-', '\u{2013}', '\u{2014}', ' ']).trim().to_string(),
+            level,
+            offset,
+            page_num: None,
+            bbox: None,
+        })
+    }
+}
+
+fn is_roman(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| "ivxlcdmIVXLCDM".contains(c))
+}
+
+fn looks_like_heading(rest: &str) -> bool {
+    // "4.2 Payment" but not "4.2 million" or a bare number in a table
+    rest.starts_with(' ') && rest.trim_start().chars().next().is_some_and(|c| c.is_uppercase() || c == '(')
+}
+
+// `Article IV`, `ARTICLE 12`, `Art. 5`
+fn parse_article(line: &str) -> Option<(String, &str)> {
+    let rest = ["Article", "ARTICLE", "Art."].iter().find_map(|p| line.strip_prefix(p))?;
+    if !rest.starts_with(' ') {
+        return None;
+    }
+    let body = rest.trim_start();
+    let end = body.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(body.len());
+    let number = &body[..end];
+    if number.is_empty() || !(number.chars().all(|c| c.is_ascii_digit()) || is_roman(number)) {
+        return None;
+    }
+    let label_len = line.len() - body.len() + end;
+    Some((line[..label_len].to_string(), &line[label_len..]))
+}
+
+// `Section 2.1(b)`, `§ 12.3(a)`; returns the label, the rest and the numbering depth
+fn parse_prefixed_number<'a>(line: &'a str, prefixes: &[&str]) -> Option<(String, &'a str, usize)> {
+    let rest = prefixes.iter().find_map(|p| line.strip_prefix(p))?;
+    let body = rest.trim_start();
+    let (number, after, depth) = parse_numbering(body)?;
+    let label_len = line.len() - body.len() + number.len();
+    debug_assert_eq!(&line[label_len..], after);
+    Some((line[..label_len].to_string(), after, depth))
+}
+
+// `12.3(a)`, `4.`, `7.1.3`; depth counts dotted parts plus parenthesised suffixes
+fn parse_numbering(s: &str) -> Option<(&str, &str, usize)> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut depth = 0;
+    loop {
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == start {
+            if depth == 0 {
+                return None;
+            }
+            // Trailing dot as in "4." belongs to the label
+            break;
+        }
+        depth += 1;
+        if i < bytes.len() && bytes[i] == b'.' {
+            i += 1;
+            if i < bytes.len() && bytes[i].is_ascii_digit() {
+                continue;
+            }
+        }
+        break;
+    }
+    while let Some((label, _)) = parse_enumeration(&s[i..]) {
+        i += label.len();
+        depth += 1;
+    }
+    Some((&s[..i], &s[i..], depth))
+}
+
+// `(a)`, `(iv)`, `(12)`
+fn parse_enumeration(s: &str) -> Option<(&str, &str)> {
+    let inner = s.strip_prefix('(')?;
+    let end = inner.find(')')?;
+    let token = &inner[..end];
+    let valid = (1..=4).contains(&token.len())
+        && (token.chars().all(|c| c.is_ascii_digit())
+            || is_roman(token)
+            || (token.len() == 1 && token.chars().all(|c| c.is_ascii_alphabetic())));
+    valid.then(|| s.split_at(end + 2))
+}
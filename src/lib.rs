@@ -1,31 +1,82 @@
 // Modern Rust 2024 PDF extraction library
 use adobe_cmap_parser::{ByteMapping, CodeRange, CIDRange};
 use encoding_rs::UTF_16BE;
-use euclid::{vec2, Transform2D};
+use euclid::{point2, vec2, Transform2D};
 use log::{debug, warn, error};
 use lopdf::{
-    content::Content,
+    content::{Content, Operation},
     encryption::DecryptionError,
 };
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
     fmt::{self, Debug},
-    marker::PhantomData,
-    sync::Arc,
+    rc::Rc,
+    sync::{atomic::{AtomicBool, Ordering}, Arc, LazyLock, OnceLock},
     slice::Iter,
     str,
+    time::Instant,
 };
 use thiserror::Error;
 use cff_parser::Table;
+use report::WarningKind;
 
 // Re-export lopdf for backward compatibility
 pub use lopdf::*;
 
+pub use extractor::{Extractor, ExtractorBuilder, PageTextIter, TextLayout};
+pub use flow::HtmlFlowOutput;
+pub use multiplex::TeeOutput;
+
 // Specific modules
+pub mod afm;
+pub mod annotations;
+pub mod attachments;
+pub mod bates;
+mod calculator;
+mod cmap;
+pub mod compat;
+pub mod conformance;
 mod core_fonts;
+pub mod destinations;
 mod encodings;
+pub mod encryption;
+pub mod extractor;
+mod flow;
 mod glyphnames;
 mod zapfglyphnames;
+pub mod fonts;
+pub mod images;
+pub mod info;
+#[cfg(feature = "invoice")]
+pub mod invoice;
+pub mod language;
+pub mod layers;
+pub mod layout;
+#[cfg(feature = "legal")]
+pub mod legal;
+pub mod limits;
+pub mod math;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+mod multiplex;
+pub mod ocr;
+pub mod outline;
+mod outlines;
+pub mod overrides;
+pub mod page_labels;
+pub mod page_ranges;
+pub mod profile;
+pub mod pua;
+pub mod recovery;
+pub mod references;
+pub mod report;
+mod sfnt;
+pub mod shading;
+pub mod spacing;
+pub mod tables;
+pub mod watermark;
+pub mod xliff;
 
 // Type definitions with proper naming
 pub struct PdfSpace;
@@ -54,6 +105,12 @@ pub enum PdfError {
     
     #[error("Missing required field: {0}")]
     MissingField(String),
+
+    #[error("Extraction cancelled")]
+    Cancelled,
+
+    #[error("Resource limit exceeded: {0}")]
+    ResourceLimit(String),
 }
 
 pub type PdfResult<T> = std::result::Result<T, PdfError>;
@@ -158,6 +215,72 @@ pub mod string_utils {
                 .ok_or_else(|| PdfError::EncodingError("Invalid encoding".to_string()))
         }
     }
+
+    /// Escape text for use in XML/HTML content and quoted attributes
+    pub(crate) fn escape_xml(s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '&' => result.push_str("&amp;"),
+                '<' => result.push_str("&lt;"),
+                '>' => result.push_str("&gt;"),
+                '"' => result.push_str("&quot;"),
+                '\'' => result.push_str("&apos;"),
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    /// Quote text as a JSON string literal
+    #[cfg(feature = "serde")]
+    pub(crate) fn json_string(s: &str) -> String {
+        serde_json::Value::from(s).to_string()
+    }
+
+    /// Quote text as a JSON string literal
+    #[cfg(not(feature = "serde"))]
+    pub(crate) fn json_string(s: &str) -> String {
+        let mut result = String::with_capacity(s.len() + 2);
+        result.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+                _ => result.push(c),
+            }
+        }
+        result.push('"');
+        result
+    }
+
+    /// CSS hex notation of an RGB colour with components in 0..1
+    pub(crate) fn hex_color((r, g, b): (f64, f64, f64)) -> String {
+        let byte = |c: f64| (c.clamp(0., 1.) * 255.).round() as u8;
+        format!("#{:02x}{:02x}{:02x}", byte(r), byte(g), byte(b))
+    }
+
+    /// Standard base64 with padding
+    pub(crate) fn base64(data: &[u8]) -> String {
+        const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
+        for chunk in data.chunks(3) {
+            let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+            let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    result.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+                } else {
+                    result.push('=');
+                }
+            }
+        }
+        result
+    }
 }
 
 /// PDF document helper functions
@@ -207,6 +330,55 @@ pub mod document_utils {
                 _ => Err(PdfError::InvalidStructure("Pages must be a dictionary".to_string())),
             })
     }
+
+    /// Entries of the name tree `name` (e.g. `EmbeddedFiles` or `Dests`) of the catalog's `/Names`,
+    /// in tree order, values not dereferenced
+    pub fn name_tree<'a>(doc: &'a Document, name: &[u8]) -> PdfResult<Vec<(&'a [u8], &'a Object)>> {
+        let names = object_utils::maybe_get_obj(doc, get_catalog(doc)?, b"Names").and_then(|o| o.as_dict().ok());
+        let Some(root) = names.and_then(|names| object_utils::maybe_get_obj(doc, names, name)) else {
+            return Ok(Vec::new());
+        };
+        let mut entries = Vec::new();
+        let key = |k: &'a Object| object_utils::maybe_deref(doc, k).ok()?.as_str().ok();
+        tree_node(doc, root, b"Names", &key, &mut entries, &mut std::collections::HashSet::new());
+        Ok(entries)
+    }
+
+    /// Entries of the number tree at `root` (e.g. the catalog's `/PageLabels`), in tree order,
+    /// values not dereferenced
+    pub fn number_tree<'a>(doc: &'a Document, root: &'a Object) -> Vec<(i64, &'a Object)> {
+        let mut entries = Vec::new();
+        let key = |k: &'a Object| object_utils::maybe_deref(doc, k).ok()?.as_i64().ok();
+        tree_node(doc, root, b"Nums", &key, &mut entries, &mut std::collections::HashSet::new());
+        entries
+    }
+
+    // Key/value pairs in `entries_key` of a node and its `/Kids`; `visited` breaks reference cycles
+    fn tree_node<'a, K>(
+        doc: &'a Document,
+        node: &'a Object,
+        entries_key: &[u8],
+        key: &dyn Fn(&'a Object) -> Option<K>,
+        entries: &mut Vec<(K, &'a Object)>,
+        visited: &mut std::collections::HashSet<ObjectId>,
+    ) {
+        if let Object::Reference(id) = node
+            && !visited.insert(*id)
+        {
+            return;
+        }
+        let Some(node) = object_utils::maybe_deref(doc, node).ok().and_then(|o| o.as_dict().ok()) else {
+            return;
+        };
+        if let Some(Object::Array(pairs)) = object_utils::maybe_get_obj(doc, node, entries_key) {
+            entries.extend(pairs.chunks_exact(2).filter_map(|pair| Some((key(&pair[0])?, &pair[1]))));
+        }
+        if let Some(Object::Array(kids)) = object_utils::maybe_get_obj(doc, node, b"Kids") {
+            for kid in kids {
+                tree_node(doc, kid, entries_key, key, entries, visited);
+            }
+        }
+    }
 }
 
 /// Object dereferencing and extraction utilities
@@ -364,6 +536,42 @@ pub trait PdfFont: Debug + Send + Sync {
     fn get_width(&self, id: CharCode) -> f64;
     fn next_char(&self, iter: &mut Iter<u8>) -> Option<(CharCode, u8)>;
     fn decode_char(&self, char: CharCode) -> String;
+
+    /// `BaseFont` of the font dictionary, if it has one
+    fn base_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Kerning between two consecutive codes in thousandths of text space,
+    /// negative values moving `right` closer to `left`
+    fn kerning(&self, _left: CharCode, _right: CharCode) -> f64 {
+        0.
+    }
+
+    /// Content stream drawing `char`, for Type3 fonts
+    fn glyph_procedure(&self, _char: CharCode) -> Option<GlyphProcedure<'_>> {
+        None
+    }
+
+    /// Outline of `char` from the embedded font program, 1000 units per em
+    fn glyph_outline(&self, _char: CharCode) -> Option<Path> {
+        None
+    }
+
+    /// Index of the glyph `char` selects in the embedded font program
+    fn glyph_id(&self, _char: CharCode) -> Option<u16> {
+        None
+    }
+
+    /// Advance of the font's space glyph in thousandths of text space, if it has one
+    fn space_width(&self) -> Option<f64> {
+        None
+    }
+
+    /// Weight, slant and design of the font
+    fn style(&self) -> fonts::FontStyle {
+        self.base_name().map(fonts::FontStyle::from_name).unwrap_or_default()
+    }
     
     fn char_codes<'a>(&'a self, chars: &'a [u8]) -> PdfFontIter<'a> 
     where 
@@ -385,6 +593,65 @@ pub trait PdfFont: Debug + Send + Sync {
     }
 }
 
+/// A Type3 `CharProcs` entry together with what is needed to execute it
+#[derive(Debug, Clone, Copy)]
+pub struct GlyphProcedure<'f> {
+    /// Decoded content stream of the glyph
+    pub content: &'f [u8],
+    /// Maps glyph space to text space
+    pub font_matrix: &'f PdfTransform,
+    /// The font's `/Resources`; the resources of the page apply if absent
+    pub resources: Option<&'f Dictionary>,
+    /// Glyph bounding box `[llx lly urx ury]` declared by a `d1` operator
+    pub bbox: Option<[f64; 4]>,
+}
+
+/// A shown glyph whose code has no usable Unicode mapping
+#[derive(Debug)]
+pub struct UnmappedGlyph {
+    pub code: CharCode,
+    /// What the font decoded the code to, typically empty, U+FFFD or a private use codepoint
+    pub text: String,
+    /// Outline in glyph space with 1000 units per em and y pointing up
+    pub outline: Path,
+}
+
+/// What the interpreter knows about a shown character beyond its text and placement
+#[non_exhaustive]
+pub struct CharInfo<'a> {
+    pub font: &'a dyn PdfFont,
+    /// Code of the character in the font's encoding
+    pub code: CharCode,
+    pub fill_colorspace: &'a ColorSpace,
+    pub fill_color: &'a [f64],
+    /// Constant alpha (`ca`) of the fill
+    pub fill_alpha: f64,
+    pub stroke_colorspace: &'a ColorSpace,
+    pub stroke_color: &'a [f64],
+    /// Constant alpha (`CA`) of the stroke
+    pub stroke_alpha: f64,
+    /// Text rendering mode set by `Tr`: 0 fills, 1 strokes, 2 does both, 3 is invisible, and 4 to 7
+    /// do the same as 0 to 3 while also adding the glyph to the clip
+    pub render_mode: i64,
+}
+
+impl CharInfo<'_> {
+    /// Whether the rendering mode paints the glyph at all
+    pub fn is_visible(&self) -> bool {
+        !matches!(self.render_mode, 3 | 7)
+    }
+
+    /// RGB colour, when the colour space maps to RGB, and alpha the glyph appears in: the stroke
+    /// for outlined text, the fill otherwise, and alpha 0 for invisible text
+    pub fn visible_color(&self) -> (Option<(f64, f64, f64)>, f64) {
+        match self.render_mode {
+            1 | 5 => (self.stroke_colorspace.to_rgb(self.stroke_color), self.stroke_alpha),
+            3 | 7 => (self.fill_colorspace.to_rgb(self.fill_color), 0.),
+            _ => (self.fill_colorspace.to_rgb(self.fill_color), self.fill_alpha),
+        }
+    }
+}
+
 pub struct PdfFontIter<'a> {
     iter: Iter<'a, u8>,
     font: &'a dyn PdfFont,
@@ -398,14 +665,48 @@ impl<'a> Iterator for PdfFontIter<'a> {
     }
 }
 
+// Widths of the codes of a single-byte font, looked up by indexing rather than hashing
+#[derive(Clone, Debug)]
+struct WidthTable([Option<f64>; 256]);
+
+impl WidthTable {
+    fn new() -> WidthTable {
+        WidthTable([None; 256])
+    }
+
+    fn get(&self, code: CharCode) -> Option<f64> {
+        self.0.get(code as usize).copied().flatten()
+    }
+
+    // Codes outside the single-byte range are never shown, so they are dropped
+    fn set(&mut self, code: i64, width: f64) {
+        if let Some(slot) = usize::try_from(code).ok().and_then(|code| self.0.get_mut(code)) {
+            *slot = Some(width);
+        }
+    }
+
+    // `/Widths` listing the codes from `/FirstChar` on
+    fn from_array(first_char: i64, widths: &[f64]) -> WidthTable {
+        let mut table = WidthTable::new();
+        for (i, &width) in widths.iter().enumerate() {
+            table.set(first_char + i as i64, width);
+        }
+        table
+    }
+}
+
 // Font implementations
 #[derive(Clone, Debug)]
 pub struct PdfSimpleFont {
     base_name: String,
     encoding: Option<Vec<u16>>,
     unicode_map: Option<HashMap<CharCode, String>>,
-    widths: HashMap<CharCode, f64>,
+    widths: WidthTable,
     missing_width: f64,
+    /// Embedded `Type1C` program, kept for glyph outlines
+    cff_program: Option<Vec<u8>>,
+    glyph_ids: fonts::ProgramGlyphIds,
+    style: fonts::FontStyle,
 }
 
 impl PdfSimpleFont {
@@ -418,51 +719,61 @@ impl PdfSimpleFont {
         let encoding = Self::load_encoding(doc, font, &base_name)?;
         // --- Begin: CFF/Type1C unicode map extraction ---
         let mut unicode_map = None;
+        let mut cff_program = None;
         let descriptor: Option<&Dictionary> = get(doc, font, b"FontDescriptor")?;
-        if let Some(desc) = descriptor {
-            if let Some(Object::Stream(s)) = get::<Option<&Object>>(doc, desc, b"FontFile3")? {
-                let subtype = get_name_string(doc, &s.dict, b"Subtype")?;
-                if subtype == "Type1C" {
-                    let contents = get_contents(s);
-                    if let Some(cff) = Table::parse(&contents) {
-                        let mut mapping = std::collections::HashMap::new();
-                        let charset_table = cff.charset.get_table();
-                        let encoding_table = cff.encoding.get_table();
-                        for (_i, (&cid, &sid)) in encoding_table.iter().zip(charset_table.iter()).enumerate() {
-                            if let Some(name) = cff_parser::string_by_id(&cff, sid) {
-                                let unicode = glyphnames::name_to_unicode(&name)
-                                    .or_else(|| zapfglyphnames::zapfdigbats_names_to_unicode(&name));
-                                if let Some(unicode) = unicode {
-                                    if let Ok(s) = String::from_utf16(&[unicode]) {
-                                        mapping.insert(cid as u32, s);
-                                    }
-                                }
+        if let Some(desc) = descriptor
+            && let Some(Object::Stream(s)) = get::<Option<&Object>>(doc, desc, b"FontFile3")?
+        {
+            let subtype = get_name_string(doc, &s.dict, b"Subtype")?;
+            if subtype == "Type1C" {
+                let contents = get_contents(s)?;
+                if let Some(cff) = Table::parse(&contents) {
+                    let mut mapping = std::collections::HashMap::new();
+                    let charset_table = cff.charset.get_table();
+                    let encoding_table = cff.encoding.get_table();
+                    for (&cid, &sid) in encoding_table.iter().zip(charset_table.iter()) {
+                        if let Some(name) = cff_parser::string_by_id(&cff, sid) {
+                            let unicode = glyphnames::name_to_unicode(name)
+                                .or_else(|| zapfglyphnames::zapfdigbats_names_to_unicode(name));
+                            if let Some(unicode) = unicode
+                                && let Ok(s) = String::from_utf16(&[unicode])
+                            {
+                                mapping.insert(cid as u32, s);
                             }
                         }
-                        // Merge with ToUnicode map if present
-                        if let Some(to_unicode) = get_unicode_map(doc, font)? {
-                            mapping.extend(to_unicode);
-                        }
-                        unicode_map = Some(mapping);
                     }
+                    // Merge with ToUnicode map if present
+                    if let Some(to_unicode) = get_unicode_map(doc, font)? {
+                        mapping.extend(to_unicode);
+                    }
+                    unicode_map = Some(mapping);
                 }
+                cff_program = Some(contents.into_owned());
             }
         }
         // --- End: CFF/Type1C unicode map extraction ---
         // If not set above, fallback to ToUnicode map
-        let unicode_map = unicode_map.or_else(|| Self::load_unicode_map(doc, font).unwrap_or(None));
+        let unicode_map = match unicode_map {
+            Some(map) => Some(map),
+            None => limits::recoverable(Self::load_unicode_map(doc, font))?.flatten(),
+        };
         let (widths, missing_width) = Self::load_widths(doc, font, &base_name, encoding.as_ref())?;
+        let glyph_ids = fonts::ProgramGlyphIds::new(doc, font);
+        let style = fonts::font_style(doc, font, &base_name);
         
         Ok(Self {
-            base_name: base_name,
+            base_name,
             encoding,
             unicode_map,
             widths,
             missing_width,
+            cff_program,
+            glyph_ids,
+            style,
         })
     }
     
-    fn load_encoding(doc: &Document, font: &Dictionary, _base_name: &str) -> PdfResult<Option<Vec<u16>>> {
+    fn load_encoding(doc: &Document, font: &Dictionary, base_name: &str) -> PdfResult<Option<Vec<u16>>> {
         let encoding_obj: Option<&Object> = get(doc, font, b"Encoding")?;
         
         match encoding_obj {
@@ -472,6 +783,9 @@ impl PdfSimpleFont {
             Some(Object::Dictionary(dict)) => {
                 let mut table = if let Some(base_encoding) = maybe_get_name(doc, dict, b"BaseEncoding") {
                     encoding_to_unicode_table(base_encoding)?
+                } else if let Some(builtin) = symbolic_builtin_encoding(base_name) {
+                    // Differences without a base apply to the font's built-in encoding
+                    builtin
                 } else {
                     Vec::from(PDF_DOC_ENCODING)
                 };
@@ -485,10 +799,14 @@ impl PdfSimpleFont {
             None => {
                 // Handle Type1 and TrueType default encodings
                 let descriptor: Option<&Dictionary> = get(doc, font, b"FontDescriptor")?;
-                if let Some(desc) = descriptor {
-                    if let Some(encoding) = Self::load_font_file_encoding(doc, desc, &get_name_string(doc, font, b"Subtype")?)? {
-                        return Ok(Some(encoding));
-                    }
+                if let Some(desc) = descriptor
+                    && let Some(encoding) = Self::load_font_file_encoding(doc, desc, &get_name_string(doc, font, b"Subtype")?)?
+                {
+                    return Ok(Some(encoding));
+                }
+
+                if let Some(builtin) = symbolic_builtin_encoding(base_name) {
+                    return Ok(Some(builtin));
                 }
                 
                 // Default encoding for TrueType
@@ -504,7 +822,7 @@ impl PdfSimpleFont {
     
     fn apply_encoding_differences(
         doc: &Document,
-        table: &mut Vec<u16>,
+        table: &mut [u16],
         differences: &[Object],
     ) -> PdfResult<()> {
         let mut code = 0i64;
@@ -515,12 +833,14 @@ impl PdfSimpleFont {
                 Object::Integer(i) => code = *i,
                 Object::Name(n) => {
                     let name = string_utils::pdf_to_utf8(n)?;
-                    if let Some(unicode) = glyphnames::name_to_unicode(&name) {
+                    let unicode = glyphnames::name_to_unicode(&name)
+                        .or_else(|| zapfglyphnames::zapfdigbats_names_to_unicode(&name));
+                    if let Some(unicode) = unicode {
                         if code >= 0 && (code as usize) < table.len() {
                             table[code as usize] = unicode;
                         }
                     } else {
-                        warn!("Unknown glyph name: {}", name);
+                        report::warn(WarningKind::UnknownGlyphName, format!("Unknown glyph name: {}", name));
                     }
                     code += 1;
                 }
@@ -535,16 +855,15 @@ impl PdfSimpleFont {
         match subtype {
             "Type1" => {
                 if let Some(Object::Stream(s)) = object_utils::maybe_get_obj(doc, descriptor, b"FontFile") {
-                    let contents = get_contents(s);
+                    let contents = get_contents(s)?;
                     if let Ok(encoding_map) = type1_encoding_parser::get_encoding_map(&contents) {
                         let mut table = Vec::from(PDF_DOC_ENCODING);
                         for (code, name) in encoding_map {
-                            if let Ok(name_str) = string_utils::pdf_to_utf8(&name) {
-                                if let Some(unicode) = glyphnames::name_to_unicode(&name_str) {
-                                    if code >= 0 && (code as usize) < table.len() {
-                                        table[code as usize] = unicode;
-                                    }
-                                }
+                            if let Ok(name_str) = string_utils::pdf_to_utf8(&name)
+                                && let Some(unicode) = glyphnames::name_to_unicode(&name_str)
+                                && code >= 0 && (code as usize) < table.len()
+                            {
+                                table[code as usize] = unicode;
                             }
                         }
                         return Ok(Some(table));
@@ -555,7 +874,7 @@ impl PdfSimpleFont {
                 if let Some(Object::Stream(s)) = get::<Option<&Object>>(doc, descriptor, b"FontFile3")? {
                     let subtype = get_name_string(doc, &s.dict, b"Subtype")?;
                     if subtype == "Type1C" {
-                        let contents = get_contents(s);
+                        let contents = get_contents(s)?;
                         if let Some(_cff) = Table::parse(&contents) {
                             // You can now use `_cff` to extract encoding/charset as needed
                             // For now, just return None as before, as this function returns Vec<u16>
@@ -579,8 +898,8 @@ impl PdfSimpleFont {
         font: &Dictionary,
         base_name: &str,
         encoding: Option<&Vec<u16>>,
-    ) -> PdfResult<(HashMap<CharCode, f64>, f64)> {
-        let mut width_map = HashMap::new();
+    ) -> PdfResult<(WidthTable, f64)> {
+        let mut width_map = WidthTable::new();
         let missing_width = get::<Option<f64>>(doc, font, b"MissingWidth")?.unwrap_or(0.0);
         
         // Try to load widths from font dictionary
@@ -589,21 +908,24 @@ impl PdfSimpleFont {
             maybe_get::<i64>(doc, font, b"LastChar"),
             maybe_get::<Vec<f64>>(doc, font, b"Widths"),
         ) {
-            for (i, &width) in widths.iter().enumerate() {
-                width_map.insert((first_char + i as i64) as CharCode, width);
-            }
+            width_map = WidthTable::from_array(first_char, &widths);
         } else if is_core_font(base_name) {
             // Load core font metrics
             Self::load_core_font_widths(&mut width_map, base_name, encoding)?;
+        } else if let Some(widths) = fonts::program_widths(doc, font, encoding.map(Vec::as_slice))? {
+            debug!("Using embedded font program widths for {}", base_name);
+            for (code, width) in widths {
+                width_map.set(code as i64, width);
+            }
         } else {
-            warn!("No widths found for non-core font: {}", base_name);
+            report::warn(WarningKind::MissingWidths, format!("No widths found for non-core font: {}", base_name));
         }
         
         Ok((width_map, missing_width))
     }
     
     fn load_core_font_widths(
-        width_map: &mut HashMap<CharCode, f64>,
+        width_map: &mut WidthTable,
         base_name: &str,
         encoding: Option<&Vec<u16>>,
     ) -> PdfResult<()> {
@@ -611,18 +933,20 @@ impl PdfSimpleFont {
             if font_metrics.0 == base_name {
                 if let Some(encoding) = encoding {
                     for w in font_metrics.2 {
-                        let c = glyphnames::name_to_unicode(w.2).unwrap_or(0);
+                        let Some(c) = glyphnames::name_to_unicode(w.2)
+                            .or_else(|| zapfglyphnames::zapfdigbats_names_to_unicode(w.2))
+                        else {
+                            continue;
+                        };
                         for (i, &enc_char) in encoding.iter().enumerate() {
                             if enc_char == c {
-                                width_map.insert(i as CharCode, w.1);
+                                width_map.set(i as i64, w.1);
                             }
                         }
                     }
                 } else {
                     for w in font_metrics.2 {
-                        if w.0 >= 0 {
-                            width_map.insert(w.0 as CharCode, w.1);
-                        }
+                        width_map.set(w.0, w.1);
                     }
                 }
                 break;
@@ -633,8 +957,32 @@ impl PdfSimpleFont {
 }
 
 impl PdfFont for PdfSimpleFont {
+    fn base_name(&self) -> Option<&str> {
+        Some(&self.base_name)
+    }
+
+    fn glyph_outline(&self, char: CharCode) -> Option<Path> {
+        outlines::cff_outline(self.cff_program.as_deref()?, u8::try_from(char).ok()?)
+    }
+
+    fn glyph_id(&self, char: CharCode) -> Option<u16> {
+        self.glyph_ids.get(char, self.encoding.as_deref())
+    }
+
+    fn style(&self) -> fonts::FontStyle {
+        self.style
+    }
+
+    fn space_width(&self) -> Option<f64> {
+        let is_space = match &self.unicode_map {
+            Some(unicode_map) => unicode_map.get(&32).is_some_and(|s| s == " "),
+            None => self.encoding.as_deref().unwrap_or(PDF_DOC_ENCODING).get(32) == Some(&32),
+        };
+        is_space.then(|| self.get_width(32))
+    }
+
     fn get_width(&self, id: CharCode) -> f64 {
-        self.widths.get(&id).copied().unwrap_or_else(|| {
+        self.widths.get(id).unwrap_or_else(|| {
             debug!("Missing width for char {} in font {}, using missing_width", id, self.base_name);
             self.missing_width
         })
@@ -649,13 +997,13 @@ impl PdfFont for PdfSimpleFont {
             if let Some(s) = unicode_map.get(&char) {
                 return s.clone();
             }
-            warn!("Missing char {} in unicode map for font {}", char, self.base_name);
+            report::warn(WarningKind::MissingUnicode, format!("Missing char {} in unicode map for font {}", char, self.base_name));
         }
         
         let encoding = self.encoding.as_deref().unwrap_or(PDF_DOC_ENCODING);
         let byte = (char & 0xFF) as u8;
         string_utils::to_utf8(encoding, &[byte]).unwrap_or_else(|_| {
-            warn!("Failed to decode char {} in font {}", char, self.base_name);
+            report::warn(WarningKind::MissingUnicode, format!("Failed to decode char {} in font {}", char, self.base_name));
             String::new()
         })
     }
@@ -665,7 +1013,41 @@ impl PdfFont for PdfSimpleFont {
 pub struct PdfType3Font {
     encoding: Option<Vec<u16>>,
     unicode_map: Option<HashMap<CharCode, String>>,
-    widths: HashMap<CharCode, f64>,
+    widths: WidthTable,
+    font_matrix: PdfTransform,
+    char_procs: HashMap<CharCode, Type3Glyph>,
+    resources: Option<Dictionary>,
+}
+
+#[derive(Clone, Debug)]
+struct Type3Glyph {
+    content: Vec<u8>,
+    // Metrics from the leading `d0`/`d1` operator, in glyph space
+    width: Option<f64>,
+    bbox: Option<[f64; 4]>,
+}
+
+impl Type3Glyph {
+    fn new(content: Vec<u8>) -> Type3Glyph {
+        let mut glyph = Type3Glyph { content, width: None, bbox: None };
+        let Ok(mut decoded) = Content::decode(&glyph.content) else {
+            return glyph;
+        };
+        normalize_glyph_metrics(&mut decoded.operations);
+        let Some(first) = decoded.operations.first() else {
+            return glyph;
+        };
+        let numbers: Vec<f64> = first.operands.iter().filter_map(|o| object_utils::as_num(o).ok()).collect();
+        match (first.operator.as_str(), numbers.as_slice()) {
+            ("d0", [wx, _]) => glyph.width = Some(*wx),
+            ("d1", [wx, _, llx, lly, urx, ury]) => {
+                glyph.width = Some(*wx);
+                glyph.bbox = Some([*llx, *lly, *urx, *ury]);
+            }
+            _ => {}
+        }
+        glyph
+    }
 }
 
 impl PdfType3Font {
@@ -673,13 +1055,48 @@ impl PdfType3Font {
         let encoding = Self::load_encoding(doc, font)?;
         let unicode_map = get_unicode_map(doc, font)?;
         let widths = Self::load_widths(doc, font)?;
+        let font_matrix = match get::<Option<[f64; 6]>>(doc, font, b"FontMatrix")? {
+            Some([a, b, c, d, e, f]) => Transform2D::new(a, b, c, d, e, f),
+            None => Transform2D::scale(0.001, 0.001),
+        };
+        let char_procs = Self::load_char_procs(doc, font)?;
+        let resources = get::<Option<&Dictionary>>(doc, font, b"Resources")?.cloned();
         
         Ok(Self {
             encoding,
             unicode_map,
             widths,
+            font_matrix,
+            char_procs,
+            resources,
         })
     }
+
+    // Glyph procedures by code, through the glyph names of the `/Differences` array
+    fn load_char_procs(doc: &Document, font: &Dictionary) -> PdfResult<HashMap<CharCode, Type3Glyph>> {
+        let mut char_procs = HashMap::new();
+        let Some(Object::Dictionary(procs)) = object_utils::maybe_get_obj(doc, font, b"CharProcs") else {
+            return Ok(char_procs);
+        };
+        let differences = match object_utils::maybe_get_obj(doc, font, b"Encoding") {
+            Some(Object::Dictionary(encoding)) => maybe_get_array(doc, encoding, b"Differences"),
+            _ => None,
+        };
+        let mut code = 0;
+        for entry in differences.into_iter().flatten() {
+            match object_utils::maybe_deref(doc, entry) {
+                Ok(Object::Integer(i)) => code = *i,
+                Ok(Object::Name(name)) => {
+                    if let Ok(stream) = get::<&Stream>(doc, procs, name) {
+                        char_procs.insert(code as CharCode, Type3Glyph::new(get_contents(stream)?.into_owned()));
+                    }
+                    code += 1;
+                }
+                _ => {}
+            }
+        }
+        Ok(char_procs)
+    }
     
     fn load_encoding(doc: &Document, font: &Dictionary) -> PdfResult<Option<Vec<u16>>> {
         let encoding_obj: Option<&Object> = get(doc, font, b"Encoding")?;
@@ -703,29 +1120,44 @@ impl PdfType3Font {
         }
     }
     
-    fn load_widths(doc: &Document, font: &Dictionary) -> PdfResult<HashMap<CharCode, f64>> {
-        let first_char: i64 = get(doc, font, b"FirstChar")?;
-        let last_char: i64 = get(doc, font, b"LastChar")?;
-        let widths: Vec<f64> = get(doc, font, b"Widths")?;
-        
-        let mut width_map = HashMap::new();
-        for (i, &width) in widths.iter().enumerate() {
-            width_map.insert((first_char + i as i64) as CharCode, width);
-        }
+    // A missing `/Widths` array leaves the widths to the glyph procedures' `d0`/`d1`
+    fn load_widths(doc: &Document, font: &Dictionary) -> PdfResult<WidthTable> {
+        let (Some(first_char), Some(last_char), Some(widths)) = (
+            get::<Option<i64>>(doc, font, b"FirstChar")?,
+            get::<Option<i64>>(doc, font, b"LastChar")?,
+            get::<Option<Vec<f64>>>(doc, font, b"Widths")?,
+        ) else {
+            return Ok(WidthTable::new());
+        };
         
-        if width_map.len() != (last_char - first_char + 1) as usize {
+        if widths.len() as i64 != last_char - first_char + 1 {
             return Err(PdfError::InvalidStructure("Width array size mismatch".to_string()));
         }
         
-        Ok(width_map)
+        Ok(WidthTable::from_array(first_char, &widths))
     }
 }
 
 impl PdfFont for PdfType3Font {
+    // Widths are in glyph space; report them in 1/1000 text space like other fonts.
+    // Codes missing from `/Widths` use the width set by their glyph procedure.
     fn get_width(&self, id: CharCode) -> f64 {
-        self.widths.get(&id).copied().unwrap_or_else(|| {
-            error!("Missing width for char {} in Type3 font", id);
-            0.0
+        let width = self.widths.get(id)
+            .or_else(|| self.char_procs.get(&id).and_then(|g| g.width))
+            .unwrap_or_else(|| {
+                error!("Missing width for char {} in Type3 font", id);
+                0.0
+            });
+        width * self.font_matrix.m11 * 1000.
+    }
+
+    fn glyph_procedure(&self, char: CharCode) -> Option<GlyphProcedure<'_>> {
+        let glyph = self.char_procs.get(&char)?;
+        Some(GlyphProcedure {
+            content: &glyph.content,
+            font_matrix: &self.font_matrix,
+            resources: self.resources.as_ref(),
+            bbox: glyph.bbox,
         })
     }
     
@@ -734,10 +1166,10 @@ impl PdfFont for PdfType3Font {
     }
     
     fn decode_char(&self, char: CharCode) -> String {
-        if let Some(unicode_map) = &self.unicode_map {
-            if let Some(s) = unicode_map.get(&char) {
-                return s.clone();
-            }
+        if let Some(unicode_map) = &self.unicode_map
+            && let Some(s) = unicode_map.get(&char)
+        {
+            return s.clone();
         }
         
         let encoding = self.encoding.as_deref().unwrap_or(PDF_DOC_ENCODING);
@@ -781,10 +1213,24 @@ impl From<ByteMapping> for CIDFontEncoding {
 
 #[derive(Clone, Debug)]
 pub struct PdfCIDFont {
+    base_name: String,
     encoding: CIDFontEncoding,
     to_unicode: Option<HashMap<CharCode, String>>,
     widths: HashMap<CharCode, f64>,
     default_width: f64,
+    cid_to_gid: CidToGid,
+    style: fonts::FontStyle,
+    space_width: Option<f64>,
+}
+
+// How the CIDs of a composite font select glyphs
+#[derive(Clone, Debug)]
+enum CidToGid {
+    Identity,
+    /// A `CIDToGIDMap` stream, the glyph of each CID in turn
+    Table(Vec<u16>),
+    /// CFF based fonts, whose program maps CIDs itself
+    Unknown,
 }
 
 impl PdfCIDFont {
@@ -802,14 +1248,37 @@ impl PdfCIDFont {
         let encoding = Self::load_encoding(doc, font)?;
         let to_unicode = get_unicode_map(doc, font)?;
         let (widths, default_width) = Self::load_widths(doc, cid_dict)?;
+        let cid_to_gid = Self::load_cid_to_gid(doc, cid_dict)?;
+        let style = fonts::font_style(doc, font, &base_name);
+        // The width of the lowest CID mapped to a space
+        let space_width = to_unicode.as_ref()
+            .and_then(|unicode_map| unicode_map.iter().filter(|(_, s)| *s == " ").map(|(&cid, _)| cid).min())
+            .map(|cid| widths.get(&cid).copied().unwrap_or(default_width));
         
         Ok(Self {
+            base_name,
             encoding: encoding.into(),
             to_unicode,
             widths,
             default_width,
+            cid_to_gid,
+            style,
+            space_width,
         })
     }
+
+    fn load_cid_to_gid(doc: &Document, cid_dict: &Dictionary) -> PdfResult<CidToGid> {
+        if maybe_get_name(doc, cid_dict, b"Subtype") != Some(b"CIDFontType2") {
+            return Ok(CidToGid::Unknown);
+        }
+        match object_utils::maybe_get_obj(doc, cid_dict, b"CIDToGIDMap") {
+            Some(Object::Stream(stream)) => {
+                let data = get_contents(stream)?;
+                Ok(CidToGid::Table(data.chunks_exact(2).map(|b| u16::from_be_bytes([b[0], b[1]])).collect()))
+            }
+            _ => Ok(CidToGid::Identity),
+        }
+    }
     
     fn load_encoding(doc: &Document, font: &Dictionary) -> PdfResult<ByteMapping> {
         let encoding_obj = object_utils::maybe_get_obj(doc, font, b"Encoding")
@@ -819,22 +1288,11 @@ impl PdfCIDFont {
             Object::Name(name) => {
                 let name_str = string_utils::pdf_to_utf8(name)?;
                 match name_str.as_str() {
-                    "Identity-H" | "Identity-V" => Ok(ByteMapping {
-                        codespace: vec![CodeRange { width: 2, start: 0, end: 0xffff }],
-                        cid: vec![CIDRange { 
-                            src_code_lo: 0, 
-                            src_code_hi: 0xffff, 
-                            dst_CID_lo: 0 
-                        }],
-                    }),
+                    "Identity-H" | "Identity-V" => Ok(cmap::identity_mapping()),
                     _ => Err(PdfError::InvalidStructure(format!("Unsupported encoding: {}", name_str))),
                 }
             }
-            Object::Stream(stream) => {
-                let contents = get_contents(stream);
-                adobe_cmap_parser::get_byte_mapping(&contents)
-                    .map_err(|_| PdfError::InvalidStructure("Invalid CMap".to_string()))
-            }
+            Object::Stream(stream) => cmap::load_embedded_cmap(doc, stream),
             _ => Err(PdfError::InvalidStructure("Invalid encoding type".to_string())),
         }
     }
@@ -884,9 +1342,29 @@ impl PdfCIDFont {
 }
 
 impl PdfFont for PdfCIDFont {
+    fn base_name(&self) -> Option<&str> {
+        Some(&self.base_name)
+    }
+
     fn get_width(&self, id: CharCode) -> f64 {
         self.widths.get(&id).copied().unwrap_or(self.default_width)
     }
+
+    fn glyph_id(&self, cid: CharCode) -> Option<u16> {
+        match &self.cid_to_gid {
+            CidToGid::Identity => u16::try_from(cid).ok(),
+            CidToGid::Table(table) => table.get(cid as usize).copied(),
+            CidToGid::Unknown => None,
+        }
+    }
+
+    fn style(&self) -> fonts::FontStyle {
+        self.style
+    }
+
+    fn space_width(&self) -> Option<f64> {
+        self.space_width
+    }
     
     fn next_char(&self, iter: &mut Iter<u8>) -> Option<(CharCode, u8)> {
         let first = *iter.next()?;
@@ -977,14 +1455,30 @@ fn encoding_to_unicode_table(name: &[u8]) -> PdfResult<Vec<u16>> {
         .collect())
 }
 
+// Built-in encodings of the symbolic core fonts, which have no standard encoding to fall back to
+fn symbolic_builtin_encoding(base_name: &str) -> Option<Vec<u16>> {
+    // Subset fonts carry a six letter tag, e.g. "ABCDEF+Symbol"
+    let name = match base_name.split_once('+') {
+        Some((tag, name)) if tag.len() == 6 => name,
+        _ => base_name,
+    };
+    let (encoding, name_to_unicode): (_, fn(&str) -> Option<u16>) = match name {
+        "Symbol" => (encodings::SYMBOL_ENCODING, glyphnames::name_to_unicode),
+        "ZapfDingbats" | "Dingbats" => (encodings::ZAPFDINGBATS_ENCODING, zapfglyphnames::zapfdigbats_names_to_unicode),
+        _ => return None,
+    };
+    Some(encoding.iter()
+        .map(|&opt| opt.and_then(name_to_unicode).unwrap_or(0))
+        .collect())
+}
+
 fn get_unicode_map(doc: &Document, font: &Dictionary) -> PdfResult<Option<HashMap<CharCode, String>>> {
     let to_unicode = object_utils::maybe_get_obj(doc, font, b"ToUnicode");
     
     match to_unicode {
         Some(Object::Stream(stream)) => {
-            let contents = get_contents(stream);
-            let cmap = adobe_cmap_parser::get_unicode_map(&contents)
-                .map_err(|_| PdfError::InvalidStructure("Invalid ToUnicode CMap".to_string()))?;
+            let contents = get_contents(stream)?;
+            let cmap = cmap::parse_unicode_map(&contents)?;
             
             let mut unicode_map = HashMap::new();
             
@@ -1001,7 +1495,7 @@ fn get_unicode_map(doc: &Document, font: &Dictionary) -> PdfResult<Option<HashMa
                 
                 match String::from_utf16(&utf16_values) {
                     Ok(s) => { unicode_map.insert(k, s); }
-                    Err(_) => { warn!("Invalid UTF-16 sequence for character {}", k); }
+                    Err(_) => report::warn(WarningKind::MissingUnicode, format!("Invalid UTF-16 sequence for character {}", k)),
                 }
             }
             
@@ -1010,7 +1504,7 @@ fn get_unicode_map(doc: &Document, font: &Dictionary) -> PdfResult<Option<HashMa
         Some(Object::Name(name)) => {
             let name_str = string_utils::pdf_to_utf8(name)?;
             if name_str != "Identity-H" {
-                warn!("Unsupported ToUnicode name: {}", name_str);
+                report::warn(WarningKind::Unsupported, format!("Unsupported ToUnicode name: {}", name_str));
             }
             Ok(None)
         }
@@ -1019,9 +1513,20 @@ fn get_unicode_map(doc: &Document, font: &Dictionary) -> PdfResult<Option<HashMa
     }
 }
 
-fn get_contents(stream: &Stream) -> Vec<u8> {
-    stream.decompressed_content()
-        .unwrap_or_else(|_| stream.content.clone())
+// Decoded data of `stream`, or its raw data if it can't be decoded; raw data is borrowed
+fn get_contents(stream: &Stream) -> PdfResult<Cow<'_, [u8]>> {
+    Ok(limits::decode(stream)?.unwrap_or(Cow::Borrowed(&stream.content)))
+}
+
+// `Document::get_page_content`, decoding each stream under the resource limits
+fn get_page_content(doc: &Document, page_id: ObjectId) -> PdfResult<Vec<u8>> {
+    let mut content = Vec::new();
+    for id in doc.get_page_contents(page_id) {
+        if let Ok(stream) = doc.get_object(id).and_then(Object::as_stream) {
+            content.extend_from_slice(&get_contents(stream)?);
+        }
+    }
+    Ok(content)
 }
 
 // Add missing type1_encoding_parser module
@@ -1039,16 +1544,158 @@ mod type1_encoding_parser {
 pub trait OutputDev {
     fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()>;
     fn end_page(&mut self) -> PdfResult<()>;
+    /// Called once before the first page with the numbers of the pages that follow, in order
+    fn begin_document(&mut self, _pages: &[u32]) -> PdfResult<()> { Ok(()) }
+    /// Called once after the last page
+    fn end_document(&mut self) -> PdfResult<()> { Ok(()) }
     fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()>;
+    /// Called for each shown character with a Unicode mapping, with the font, code, fill and
+    /// rendering mode it is drawn with; devices that don't need them get `output_character`
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        _info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.output_character(trm, width, spacing, font_size, char)
+    }
+    /// Called for each shown glyph after the character it shows, with the glyph the font program
+    /// draws, if known, the character code (the CID for composite fonts), its Unicode text and
+    /// its advance in ems
+    fn output_glyph(&mut self, _gid: Option<u16>, _cid: CharCode, _unicode: &str, _trm: &PdfTransform, _advance: f64) -> PdfResult<()> {
+        Ok(())
+    }
+    /// Called before each shown string with the font it is drawn in
+    fn set_font(&mut self, _font: &dyn PdfFont) -> PdfResult<()> { Ok(()) }
+    /// Called before each shown string with the nonstroking colour and constant alpha (`ca`)
+    fn set_fill_color(&mut self, _colorspace: &ColorSpace, _color: &[f64], _alpha: f64) -> PdfResult<()> { Ok(()) }
+    /// Called before each shown string with the `/Lang` that applies to it, see `language`
+    fn set_language(&mut self, _lang: Option<&str>) -> PdfResult<()> { Ok(()) }
     fn begin_word(&mut self) -> PdfResult<()>;
     fn end_word(&mut self) -> PdfResult<()>;
     fn end_line(&mut self) -> PdfResult<()>;
+    /// Called for each number in a `TJ` array with the adjustment in thousandths of an em, positive
+    /// values moving the next glyph back, and the displacement it makes in unscaled text space
+    fn text_adjustment(&mut self, _amount: f64, _displacement: f64) -> PdfResult<()> { Ok(()) }
+    /// Called once a `TJ` array has been shown
+    fn end_text_array(&mut self) -> PdfResult<()> { Ok(()) }
+    /// Called before a glyph of a non-embedded standard 14 font with the AFM kerning between it
+    /// and the glyph shown before it, in thousandths of an em; glyphs are placed by their widths
+    /// alone, so devices measuring the gaps between them allow for it
+    fn glyph_kerning(&mut self, _amount: f64) -> PdfResult<()> { Ok(()) }
+    /// Called at `BT`
+    fn begin_text_object(&mut self) -> PdfResult<()> { Ok(()) }
+    /// Called at `ET`
+    fn end_text_object(&mut self) -> PdfResult<()> { Ok(()) }
+    /// Called at `BMC` and `BDC` with the tag and, for `BDC`, the property list: the inline
+    /// dictionary, or the one its name refers to in the `/Properties` resource
+    fn begin_marked_content(&mut self, _tag: &str, _properties: Option<&Dictionary>) -> PdfResult<()> { Ok(()) }
+    /// Called at the `EMC` closing a sequence passed to `begin_marked_content`
+    fn end_marked_content(&mut self) -> PdfResult<()> { Ok(()) }
+    /// Called before each stroked path with the line parameters it is drawn with
+    fn set_line_style(&mut self, _style: &LineStyle) -> PdfResult<()> { Ok(()) }
+    /// Called when the clipping region changes, with the paths intersecting to it, outermost first
+    fn set_clip(&mut self, _clip: &[ClipPath]) -> PdfResult<()> { Ok(()) }
     fn stroke(&mut self, _ctm: &PdfTransform, _colorspace: &ColorSpace, _color: &[f64], _path: &Path) -> PdfResult<()> { Ok(()) }
-    fn fill(&mut self, _ctm: &PdfTransform, _colorspace: &ColorSpace, _color: &[f64], _path: &Path) -> PdfResult<()> { Ok(()) }
+    fn fill(&mut self, _ctm: &PdfTransform, _colorspace: &ColorSpace, _color: &[f64], _path: &Path, _rule: FillRule) -> PdfResult<()> { Ok(()) }
+    /// Paint `shading`, in the user space of `ctm`, over the clipping region
+    fn shade(&mut self, _ctm: &PdfTransform, _shading: &shading::Shading) -> PdfResult<()> { Ok(()) }
+    /// Called before the content of a tiling pattern's cell when a path is filled with it, with
+    /// the pattern space `ctm`; the cell is drawn once, clipped to the filled path and its BBox
+    fn begin_pattern(&mut self, _ctm: &PdfTransform, _pattern: &TilingPattern) -> PdfResult<()> { Ok(()) }
+    /// Called after the cell's content, the clipping region back to the one at `begin_pattern`
+    fn end_pattern(&mut self) -> PdfResult<()> { Ok(()) }
+    /// Called for each image XObject drawn; `ctm` maps the unit square onto the image's placement
+    fn draw_image(&mut self, _ctm: &PdfTransform, _image: &Stream) -> PdfResult<()> { Ok(()) }
+    /// Called for each inline image, its dictionary using the full key names of an image XObject
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> { self.draw_image(ctm, image) }
+    /// Called instead of `output_character` for glyphs without a Unicode mapping when
+    /// `ExtractionOptions::glyph_image_fallback` is set and the glyph's outline is known
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        self.output_character(trm, width, spacing, font_size, &glyph.text)
+    }
+}
+
+// Lets a device be lent to a wrapper such as `TeeOutput` and read back afterwards
+impl<T: OutputDev + ?Sized> OutputDev for &mut T {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        (**self).begin_page(page_num, media_box, art_box)
+    }
+    fn end_page(&mut self) -> PdfResult<()> { (**self).end_page() }
+    fn begin_document(&mut self, pages: &[u32]) -> PdfResult<()> { (**self).begin_document(pages) }
+    fn end_document(&mut self) -> PdfResult<()> { (**self).end_document() }
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        (**self).output_character(trm, width, spacing, font_size, char)
+    }
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        (**self).output_char_info(trm, width, spacing, font_size, char, info)
+    }
+    fn output_glyph(&mut self, gid: Option<u16>, cid: CharCode, unicode: &str, trm: &PdfTransform, advance: f64) -> PdfResult<()> {
+        (**self).output_glyph(gid, cid, unicode, trm, advance)
+    }
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> { (**self).set_font(font) }
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        (**self).set_fill_color(colorspace, color, alpha)
+    }
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> { (**self).set_language(lang) }
+    fn begin_word(&mut self) -> PdfResult<()> { (**self).begin_word() }
+    fn end_word(&mut self) -> PdfResult<()> { (**self).end_word() }
+    fn end_line(&mut self) -> PdfResult<()> { (**self).end_line() }
+    fn text_adjustment(&mut self, amount: f64, displacement: f64) -> PdfResult<()> { (**self).text_adjustment(amount, displacement) }
+    fn end_text_array(&mut self) -> PdfResult<()> { (**self).end_text_array() }
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> { (**self).glyph_kerning(amount) }
+    fn begin_text_object(&mut self) -> PdfResult<()> { (**self).begin_text_object() }
+    fn end_text_object(&mut self) -> PdfResult<()> { (**self).end_text_object() }
+    fn begin_marked_content(&mut self, tag: &str, properties: Option<&Dictionary>) -> PdfResult<()> {
+        (**self).begin_marked_content(tag, properties)
+    }
+    fn end_marked_content(&mut self) -> PdfResult<()> { (**self).end_marked_content() }
+    fn set_line_style(&mut self, style: &LineStyle) -> PdfResult<()> { (**self).set_line_style(style) }
+    fn set_clip(&mut self, clip: &[ClipPath]) -> PdfResult<()> { (**self).set_clip(clip) }
+    fn stroke(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path) -> PdfResult<()> {
+        (**self).stroke(ctm, colorspace, color, path)
+    }
+    fn fill(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path, rule: FillRule) -> PdfResult<()> {
+        (**self).fill(ctm, colorspace, color, path, rule)
+    }
+    fn shade(&mut self, ctm: &PdfTransform, shading: &shading::Shading) -> PdfResult<()> { (**self).shade(ctm, shading) }
+    fn begin_pattern(&mut self, ctm: &PdfTransform, pattern: &TilingPattern) -> PdfResult<()> { (**self).begin_pattern(ctm, pattern) }
+    fn end_pattern(&mut self) -> PdfResult<()> { (**self).end_pattern() }
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> { (**self).draw_image(ctm, image) }
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> { (**self).draw_inline_image(ctm, image) }
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        (**self).output_unmapped_glyph(trm, width, spacing, font_size, glyph)
+    }
 }
 
 // MediaBox type
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaBox {
     pub llx: f64,
     pub lly: f64,
@@ -1057,7 +1704,8 @@ pub struct MediaBox {
 }
 
 // Path operations
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PathOp {
     MoveTo(f64, f64),
     LineTo(f64, f64),
@@ -1066,7 +1714,8 @@ pub enum PathOp {
     Close,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Path {
     pub ops: Vec<PathOp>,
 }
@@ -1076,40 +1725,244 @@ impl Path {
         Path { ops: Vec::new() }
     }
     
-    fn current_point(&self) -> (f64, f64) {
-        match self.ops.last() {
-            Some(PathOp::MoveTo(x, y)) => (*x, *y),
-            Some(PathOp::LineTo(x, y)) => (*x, *y),
-            Some(PathOp::CurveTo(_, _, _, _, x, y)) => (*x, *y),
-            _ => panic!("No current point"),
+    fn current_point(&self) -> Option<(f64, f64)> {
+        match self.ops.last()? {
+            PathOp::MoveTo(x, y) => Some((*x, *y)),
+            PathOp::LineTo(x, y) => Some((*x, *y)),
+            PathOp::CurveTo(_, _, _, _, x, y) => Some((*x, *y)),
+            _ => None,
+        }
+    }
+}
+
+/// Line parameters of the graphics state that stroked paths are drawn with
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineStyle {
+    /// Line width in user space (`w`), 0 for the thinnest line the device can draw
+    pub width: f64,
+    /// Line cap (`J`): 0 butt, 1 round, 2 projecting square
+    pub cap: i64,
+    /// Line join (`j`): 0 miter, 1 round, 2 bevel
+    pub join: i64,
+    /// Miter limit (`M`)
+    pub miter_limit: f64,
+    /// Lengths of alternating dashes and gaps (`d`), empty for solid lines
+    pub dash: Vec<f64>,
+    /// Distance into the dash pattern at which lines start
+    pub dash_phase: f64,
+}
+
+impl Default for LineStyle {
+    fn default() -> LineStyle {
+        LineStyle { width: 1., cap: 0, join: 0, miter_limit: 10., dash: Vec::new(), dash_phase: 0. }
+    }
+}
+
+/// Rule deciding which points a path encloses when it is filled or clipped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillRule {
+    /// Inside where the path winds around the point a nonzero number of times (`f`, `W`)
+    #[default]
+    NonZero,
+    /// Inside where a ray from the point crosses the path an odd number of times (`f*`, `W*`)
+    EvenOdd,
+}
+
+/// A path intersected with the clipping region by `W` or `W*`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClipPath {
+    /// Transformation from the path's user space to the page
+    pub ctm: PdfTransform,
+    pub path: Path,
+    pub rule: FillRule,
+    // The path flattened to page polygons, filled when the clip is made so that testing each
+    // glyph against it doesn't flatten it again
+    #[cfg_attr(feature = "serde", serde(skip))]
+    polygons: OnceLock<Vec<Vec<(f64, f64)>>>,
+}
+
+impl PartialEq for ClipPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.ctm == other.ctm && self.path == other.path && self.rule == other.rule
+    }
+}
+
+/// The cell of a tiling pattern, repeated to fill an area
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TilingPattern {
+    /// Rectangle the cell is clipped to, in pattern space
+    pub bbox: [f64; 4],
+    /// Horizontal spacing of the cells, in pattern space
+    pub x_step: f64,
+    /// Vertical spacing of the cells, in pattern space
+    pub y_step: f64,
+    /// Whether the cell paints its own colours (`PaintType` 1) rather than the fill colour's
+    pub coloured: bool,
+}
+
+// Segments each curve of a clipping path is flattened to for hit testing
+const CURVE_SEGMENTS: usize = 16;
+
+impl ClipPath {
+    /// A clip of `path` in the user space given by `ctm`, flattened to polygons up front
+    pub fn new(ctm: PdfTransform, path: Path, rule: FillRule) -> ClipPath {
+        let clip = ClipPath { ctm, path, rule, polygons: OnceLock::new() };
+        clip.polygons.get_or_init(|| clip.flatten());
+        clip
+    }
+
+    /// Whether the page point `(x, y)` lies inside the path by its fill rule, with curves
+    /// approximated by lines
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let mut winding = 0;
+        for polygon in self.polygons.get_or_init(|| self.flatten()) {
+            for (i, &(x0, y0)) in polygon.iter().enumerate() {
+                let (x1, y1) = polygon[(i + 1) % polygon.len()];
+                // Which side of the edge the point is on, positive to the left
+                let side = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+                if y0 <= y && y1 > y && side > 0. {
+                    winding += 1;
+                } else if y0 > y && y1 <= y && side < 0. {
+                    winding -= 1;
+                }
+            }
+        }
+        match self.rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    // The subpaths as closed polygons on the page
+    fn flatten(&self) -> Vec<Vec<(f64, f64)>> {
+        let page = |x: f64, y: f64| {
+            let p = self.ctm.transform_point(point2(x, y));
+            (p.x, p.y)
+        };
+        let mut polygons = Vec::new();
+        let mut current: Vec<(f64, f64)> = Vec::new();
+        let (mut start, mut point) = ((0., 0.), (0., 0.));
+        for op in &self.path.ops {
+            match *op {
+                PathOp::MoveTo(x, y) => {
+                    polygons.push(std::mem::take(&mut current));
+                    (start, point) = ((x, y), (x, y));
+                    current.push(page(x, y));
+                }
+                PathOp::LineTo(x, y) => {
+                    point = (x, y);
+                    current.push(page(x, y));
+                }
+                PathOp::CurveTo(x1, y1, x2, y2, x3, y3) => {
+                    let (x0, y0) = point;
+                    for i in 1..=CURVE_SEGMENTS {
+                        let t = i as f64 / CURVE_SEGMENTS as f64;
+                        let u = 1. - t;
+                        let (a, b, c, d) = (u * u * u, 3. * u * u * t, 3. * u * t * t, t * t * t);
+                        current.push(page(a * x0 + b * x1 + c * x2 + d * x3, a * y0 + b * y1 + c * y2 + d * y3));
+                    }
+                    point = (x3, y3);
+                }
+                PathOp::Rect(x, y, w, h) => {
+                    polygons.push(std::mem::take(&mut current));
+                    polygons.push(vec![page(x, y), page(x + w, y), page(x + w, y + h), page(x, y + h)]);
+                    (start, point) = ((x, y), (x, y));
+                    current.push(page(x, y));
+                }
+                // Drawing on after a close starts a new subpath at the start of the closed one
+                PathOp::Close => {
+                    polygons.push(std::mem::take(&mut current));
+                    point = start;
+                    current.push(page(start.0, start.1));
+                }
+            }
         }
+        polygons.push(current);
+        polygons.retain(|polygon| polygon.len() > 2);
+        polygons
     }
 }
 
 // Color space types
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalGray {
-    _white_point: [f64; 3],
+    white_point: [f64; 3],
     _black_point: Option<[f64; 3]>,
-    _gamma: Option<f64>,
+    gamma: Option<f64>,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalRGB {
-    _white_point: [f64; 3],
+    white_point: [f64; 3],
     _black_point: Option<[f64; 3]>,
-    _gamma: Option<[f64; 3]>,
-    _matrix: Option<Vec<f64>>,
+    gamma: Option<[f64; 3]>,
+    matrix: Option<Vec<f64>>,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lab {
-    _white_point: [f64; 3],
+    white_point: [f64; 3],
     _black_point: Option<[f64; 3]>,
-    _range: Option<[f64; 4]>,
+    range: Option<[f64; 4]>,
+}
+
+/// What an ICC profile's stream dictionary and header say about the colours it describes
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IccProfile {
+    /// Number of colour components, the stream's `N`
+    pub components: usize,
+    /// Signature of the colour space of the profile's data, e.g. `*b"RGB "`; `None` without a
+    /// valid header
+    pub color_space: Option<[u8; 4]>,
+    /// Rendering intent the profile was made for: 0 perceptual, 1 media-relative colorimetric,
+    /// 2 saturation and 3 ICC-absolute colorimetric
+    pub rendering_intent: Option<u32>,
+}
+
+impl IccProfile {
+    fn new(doc: &Document, stream: &Stream) -> PdfResult<IccProfile> {
+        let data = get_contents(stream)?;
+        // The fixed 128 byte header, recognised by its `acsp` signature
+        let header = data.get(..128).filter(|header| &header[36..40] == b"acsp");
+        let color_space = header.map(|header| [header[16], header[17], header[18], header[19]]);
+        let rendering_intent = header.map(|header| u32::from_be_bytes([header[64], header[65], header[66], header[67]]));
+        let from_header = match color_space.as_ref() {
+            Some(b"GRAY") => Some(1),
+            Some(b"RGB " | b"Lab ") => Some(3),
+            Some(b"CMYK") => Some(4),
+            _ => None,
+        };
+        // Without either, assume the sRGB profiles most files embed
+        let components = get::<i64>(doc, &stream.dict, b"N").ok()
+            .and_then(|n| usize::try_from(n).ok())
+            .filter(|n| (1..=4).contains(n))
+            .or(from_header)
+            .unwrap_or(3);
+        Ok(IccProfile { components, color_space, rendering_intent })
+    }
+
+    fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        // Without a colour management engine, treat the profile as its nearest device or CIE space
+        match self.color_space.as_ref() {
+            Some(b"Lab ") => {
+                let lab = Lab { white_point: [0.9642, 1., 0.8249], _black_point: None, range: Some([-128., 127., -128., 127.]) };
+                lab.to_rgb(color)
+            }
+            _ => device_to_rgb(self.components, color),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlternateColorSpace {
     DeviceGray,
     DeviceRGB,
@@ -1117,41 +1970,189 @@ pub enum AlternateColorSpace {
     CalRGB(CalRGB),
     CalGray(CalGray),
     Lab(Lab),
-    ICCBased(Vec<u8>),
+    ICCBased(IccProfile),
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Separation {
     _name: String,
-    _alternate_space: AlternateColorSpace,
-    _tint_transform: Box<Function>,
+    alternate_space: AlternateColorSpace,
+    tint_transform: Box<Function>,
+}
+
+/// Several colorants, shown through the alternate space by the tint transform
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceN {
+    names: Vec<String>,
+    alternate_space: AlternateColorSpace,
+    tint_transform: Box<Function>,
+}
+
+impl DeviceN {
+    /// Names of the colorants, in the order of the colour components
+    pub fn colorants(&self) -> &[String] {
+        &self.names
+    }
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorSpace {
     DeviceGray,
     DeviceRGB,
     DeviceCMYK,
-    DeviceN,
+    DeviceN(DeviceN),
     Pattern,
     CalRGB(CalRGB),
     CalGray(CalGray),
     Lab(Lab),
     Separation(Separation),
-    ICCBased(Vec<u8>),
+    ICCBased(IccProfile),
 }
 
-// Function types
-#[derive(Clone, Debug)]
-enum Function {
-    Type0(()),
-    Type2(()),
-    Type3,
-    Type4(()),
+impl ColorSpace {
+    /// Approximate sRGB components in 0..1 for spaces whose components map directly
+    pub fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        let components = match self {
+            ColorSpace::DeviceGray => 1,
+            ColorSpace::DeviceRGB => 3,
+            ColorSpace::DeviceCMYK => 4,
+            ColorSpace::CalGray(cs) => return cs.to_rgb(color),
+            ColorSpace::CalRGB(cs) => return cs.to_rgb(color),
+            ColorSpace::Lab(cs) => return cs.to_rgb(color),
+            // Colorants are shown through the alternate space
+            ColorSpace::Separation(cs) => {
+                let tint = color.first().copied().unwrap_or(1.);
+                return cs.alternate_space.to_rgb(&cs.tint_transform.eval(&[tint])?);
+            }
+            ColorSpace::DeviceN(cs) => {
+                let tints = color.get(..cs.names.len())?;
+                return cs.alternate_space.to_rgb(&cs.tint_transform.eval(tints)?);
+            }
+            ColorSpace::ICCBased(profile) => return profile.to_rgb(color),
+            _ => return None,
+        };
+        device_to_rgb(components, color)
+    }
 }
 
-impl Function {
+impl AlternateColorSpace {
+    fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        match self {
+            AlternateColorSpace::DeviceGray => device_to_rgb(1, color),
+            AlternateColorSpace::DeviceRGB => device_to_rgb(3, color),
+            AlternateColorSpace::DeviceCMYK => device_to_rgb(4, color),
+            AlternateColorSpace::CalGray(cs) => cs.to_rgb(color),
+            AlternateColorSpace::CalRGB(cs) => cs.to_rgb(color),
+            AlternateColorSpace::Lab(cs) => cs.to_rgb(color),
+            AlternateColorSpace::ICCBased(profile) => profile.to_rgb(color),
+        }
+    }
+}
+
+// sRGB of `color` in the device space with `components` components
+fn device_to_rgb(components: usize, color: &[f64]) -> Option<(f64, f64, f64)> {
+    let c = |i: usize| color.get(i).copied().unwrap_or(0.).clamp(0., 1.);
+    match (components, color.len() >= components) {
+        (1, true) => Some((c(0), c(0), c(0))),
+        (3, true) => Some((c(0), c(1), c(2))),
+        (4, true) => Some(((1. - c(0)) * (1. - c(3)), (1. - c(1)) * (1. - c(3)), (1. - c(2)) * (1. - c(3)))),
+        _ => None,
+    }
+}
+
+impl CalGray {
+    fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        let a = color.first()?.clamp(0., 1.);
+        let y = a.powf(self.gamma.unwrap_or(1.));
+        let [xw, yw, zw] = self.white_point;
+        xyz_to_rgb([xw * y, yw * y, zw * y], self.white_point)
+    }
+}
+
+impl CalRGB {
+    fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        let [a, b, c] = [color.first()?, color.get(1)?, color.get(2)?].map(|v| v.clamp(0., 1.));
+        let [ga, gb, gc] = self.gamma.unwrap_or([1., 1., 1.]);
+        let (a, b, c) = (a.powf(ga), b.powf(gb), c.powf(gc));
+        let identity = [1., 0., 0., 0., 1., 0., 0., 0., 1.];
+        let m = self.matrix.as_deref().filter(|m| m.len() == 9).unwrap_or(&identity);
+        let xyz = [0, 1, 2].map(|i| m[i] * a + m[3 + i] * b + m[6 + i] * c);
+        xyz_to_rgb(xyz, self.white_point)
+    }
+}
+
+impl Lab {
+    fn to_rgb(&self, color: &[f64]) -> Option<(f64, f64, f64)> {
+        let [a_min, a_max, b_min, b_max] = self.range.unwrap_or([-100., 100., -100., 100.]);
+        let l = color.first()?.clamp(0., 100.);
+        let a = color.get(1)?.clamp(a_min, a_max);
+        let b = color.get(2)?.clamp(b_min, b_max);
+        let fy = (l + 16.) / 116.;
+        let g = |t: f64| if t >= 6. / 29. { t.powi(3) } else { 108. / 841. * (t - 4. / 29.) };
+        let [xw, yw, zw] = self.white_point;
+        xyz_to_rgb([xw * g(fy + a / 500.), yw * g(fy), zw * g(fy - b / 200.)], self.white_point)
+    }
+}
+
+// sRGB of a CIE XYZ colour relative to `white_point`, adapted to the D65 white of sRGB by scaling
+fn xyz_to_rgb(xyz: [f64; 3], white_point: [f64; 3]) -> Option<(f64, f64, f64)> {
+    const D65: [f64; 3] = [0.9505, 1., 1.089];
+    if white_point.iter().any(|&w| w <= 0.) {
+        return None;
+    }
+    let [x, y, z] = [0, 1, 2].map(|i| xyz[i] * D65[i] / white_point[i]);
+    let linear = [
+        3.2406 * x - 1.5372 * y - 0.4986 * z,
+        -0.9689 * x + 1.8758 * y + 0.0415 * z,
+        0.0557 * x - 0.2040 * y + 1.0570 * z,
+    ];
+    let [r, g, b] = linear.map(|c| {
+        let c = c.clamp(0., 1.);
+        if c <= 0.0031308 { 12.92 * c } else { 1.055 * c.powf(1. / 2.4) - 0.055 }
+    });
+    Some((r, g, b))
+}
+
+// Function types
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Function {
+    /// Table of `samples` at the points of a grid of `size`, interpolated between them, with
+    /// inputs mapped onto the grid by `encode` and samples onto `range` by `decode`
+    Type0 {
+        domain: Vec<f64>,
+        range: Vec<f64>,
+        size: Vec<usize>,
+        encode: Vec<f64>,
+        decode: Vec<f64>,
+        bits_per_sample: u32,
+        samples: Vec<u32>,
+    },
+    /// Exponential interpolation between `c0` and `c1`
+    Type2 { domain: [f64; 2], c0: Vec<f64>, c1: Vec<f64>, n: f64 },
+    /// Stitching of `functions` over the subdomains `bounds` splits `domain` into, each mapped to
+    /// its function's input by a pair of `encode`
+    Type3 { domain: [f64; 2], functions: Vec<Function>, bounds: Vec<f64>, encode: Vec<f64> },
+    /// PostScript calculator program, with inputs clipped to `domain` and outputs to `range`
+    Type4 { domain: Vec<f64>, range: Vec<f64>, program: calculator::Program },
+}
+
+// Nesting limit for the functions stitched by Type 3 functions, which a reference cycle would
+// otherwise make infinite
+const MAX_FUNCTION_DEPTH: usize = 8;
+
+// Inputs a Type 0 function can have, as evaluating it interpolates between 2^inputs samples
+const MAX_SAMPLED_INPUTS: usize = 8;
+
+impl Function {
     fn new(doc: &Document, obj: &Object) -> PdfResult<Function> {
+        Function::nested(doc, obj, 0)
+    }
+
+    fn nested(doc: &Document, obj: &Object, depth: usize) -> PdfResult<Function> {
         let dict = match obj {
             Object::Dictionary(dict) => dict,
             Object::Stream(stream) => &stream.dict,
@@ -1166,118 +2167,412 @@ impl Function {
                     Object::Stream(stream) => stream,
                     _ => return Err(PdfError::InvalidStructure("Type 0 function must be stream".to_string())),
                 };
-                let _range: Vec<f64> = get(doc, dict, b"Range")?;
-                let _domain: Vec<f64> = get(doc, dict, b"Domain")?;
-                let _contents = get_contents(stream);
-                let _size: Vec<i64> = get(doc, dict, b"Size")?;
-                let _bits_per_sample: i64 = get(doc, dict, b"BitsPerSample")?;
-
-                // Attempt to re-introduce _encode parsing
-                let encode_obj: Option<Vec<f64>> = get(doc, dict, b"Encode")?;
-                let _encode: Vec<f64> = encode_obj.unwrap_or_else(|| {
-                    let mut default = Vec::new();
-                    for i_val in &_size {
-                        default.push(0.);
-                        default.push((*i_val - 1) as f64);
-                    }
-                    default
-                });
-                
-                /* // _decode still commented out
-                let _decode = get::<Option<Vec<f64>>>(doc, dict, b"Decode")?
-                    .unwrap_or_else(|| _range.clone());
-                */
-
-                Ok(Function::Type0(()))
+                let range: Vec<f64> = get(doc, dict, b"Range")?;
+                let domain: Vec<f64> = get(doc, dict, b"Domain")?;
+                let size = get::<Vec<i64>>(doc, dict, b"Size")?
+                    .into_iter()
+                    .map(|n| usize::try_from(n).ok().filter(|&n| n > 0))
+                    .collect::<Option<Vec<usize>>>()
+                    .ok_or_else(|| PdfError::InvalidStructure("Type 0 function has an empty dimension".to_string()))?;
+                let bits_per_sample: i64 = get(doc, dict, b"BitsPerSample")?;
+                let bits_per_sample = match bits_per_sample {
+                    1 | 2 | 4 | 8 | 12 | 16 | 24 | 32 => bits_per_sample as u32,
+                    _ => return Err(PdfError::InvalidStructure(format!("Invalid BitsPerSample {}", bits_per_sample))),
+                };
+                if size.is_empty() || size.len() > MAX_SAMPLED_INPUTS || domain.len() != 2 * size.len() || range.len() < 2 {
+                    return Err(PdfError::InvalidStructure("Type 0 function needs Domain and Size for each input and a Range".to_string()));
+                }
+                let encode = get::<Option<Vec<f64>>>(doc, dict, b"Encode")?
+                    .unwrap_or_else(|| size.iter().flat_map(|&n| [0., (n - 1) as f64]).collect());
+                let decode = get::<Option<Vec<f64>>>(doc, dict, b"Decode")?.unwrap_or_else(|| range.clone());
+                if encode.len() != domain.len() || decode.len() != range.len() {
+                    return Err(PdfError::InvalidStructure("Type 0 function has invalid Encode or Decode".to_string()));
+                }
+                let count = size
+                    .iter()
+                    .try_fold(range.len() / 2, |count, &n| count.checked_mul(n))
+                    .ok_or_else(|| PdfError::InvalidStructure("Type 0 function has too many samples".to_string()))?;
+                let samples = read_samples(&get_contents(stream)?, bits_per_sample, count)
+                    .ok_or_else(|| PdfError::InvalidStructure("Type 0 function has too few samples".to_string()))?;
+                Ok(Function::Type0 { domain, range, size, encode, decode, bits_per_sample, samples })
             }
             2 => {
-                let _c0 = get::<Option<Vec<f64>>>(doc, dict, b"C0")?;
-                let _c1 = get::<Option<Vec<f64>>>(doc, dict, b"C1")?;
-                let _n = get::<f64>(doc, dict, b"N")?;
-                Ok(Function::Type2(())) // Correctly pass unit type
+                let domain = get::<Vec<f64>>(doc, dict, b"Domain")?;
+                let c0 = get::<Option<Vec<f64>>>(doc, dict, b"C0")?.unwrap_or_else(|| vec![0.]);
+                let c1 = get::<Option<Vec<f64>>>(doc, dict, b"C1")?.unwrap_or_else(|| vec![1.]);
+                let n = get::<f64>(doc, dict, b"N")?;
+                let domain = match domain.as_slice() {
+                    [start, end, ..] => [*start, *end],
+                    _ => [0., 1.],
+                };
+                Ok(Function::Type2 { domain, c0, c1, n })
+            }
+            3 => {
+                if depth >= MAX_FUNCTION_DEPTH {
+                    return Err(PdfError::InvalidStructure("Type 3 functions nested too deeply".to_string()));
+                }
+                let domain: [f64; 2] = get(doc, dict, b"Domain")?;
+                let functions = get::<Vec<&Object>>(doc, dict, b"Functions")?
+                    .into_iter()
+                    .map(|f| Function::nested(doc, f, depth + 1))
+                    .collect::<PdfResult<Vec<_>>>()?;
+                let bounds: Vec<f64> = get(doc, dict, b"Bounds")?;
+                let encode: Vec<f64> = get(doc, dict, b"Encode")?;
+                if functions.is_empty() || bounds.len() + 1 != functions.len() || encode.len() != 2 * functions.len() {
+                    return Err(PdfError::InvalidStructure("Type 3 function needs k functions, k - 1 bounds and 2k encode values".to_string()));
+                }
+                Ok(Function::Type3 { domain, functions, bounds, encode })
             }
-            3 => Ok(Function::Type3),
             4 => {
-                let _contents = match obj { // _contents is now unused due to Type4(()) but needs to be processed for warnings/errors
-                    Object::Stream(stream) => {
-                        let contents = get_contents(stream);
-                        warn!("Unhandled type-4 function");
-                        contents // This value is not used for Type4(()), but keep logic for potential errors/warnings
-                    }
+                let stream = match obj {
+                    Object::Stream(stream) => stream,
                     _ => return Err(PdfError::InvalidStructure("Type 4 function must be stream".to_string())),
                 };
-                Ok(Function::Type4(())) // Correctly pass unit type
+                let domain: Vec<f64> = get(doc, dict, b"Domain")?;
+                let range: Vec<f64> = get(doc, dict, b"Range")?;
+                let program = calculator::Program::parse(&get_contents(stream)?)?;
+                Ok(Function::Type4 { domain, range, program })
             }
             _ => Err(PdfError::InvalidStructure(format!("Unknown function type {}", function_type))),
         }
     }
+
+    /// Outputs for `input`, or `None` when it has too few values or the function can't be evaluated
+    // Functions can also be deserialized, so evaluation doesn't rely on the checks made when parsing
+    fn eval(&self, input: &[f64]) -> Option<Vec<f64>> {
+        match self {
+            Function::Type0 { domain, range, size, encode, decode, bits_per_sample, samples } => {
+                if input.len() < size.len() || size.len() > MAX_SAMPLED_INPUTS {
+                    return None;
+                }
+                // Position on the grid of each input, as the index of the sample below it and how
+                // far it is towards the next
+                let mut cells = Vec::with_capacity(size.len());
+                for (i, &n) in size.iter().enumerate() {
+                    let (d, e) = (domain.get(2 * i..2 * i + 2)?, encode.get(2 * i..2 * i + 2)?);
+                    let x = clip(input[i], d);
+                    let e = if d[1] == d[0] { e[0] } else { e[0] + (x - d[0]) * (e[1] - e[0]) / (d[1] - d[0]) };
+                    let e = e.clamp(0., n.saturating_sub(1) as f64);
+                    let index = (e.floor() as usize).min(n.saturating_sub(2));
+                    cells.push((index, e - index as f64));
+                }
+                // Multilinear interpolation between the samples at the corners of the cell
+                let outputs = range.len() / 2;
+                let mut values = vec![0.; outputs];
+                for corner in 0..1usize << size.len() {
+                    let (mut offset, mut stride, mut weight) = (0, 1, 1.);
+                    for (i, (&n, &(index, f))) in size.iter().zip(&cells).enumerate() {
+                        let upper = corner >> i & 1 == 1;
+                        weight *= if upper { f } else { 1. - f };
+                        offset += (index + usize::from(upper && n > 1)).checked_mul(stride)?;
+                        stride = stride.checked_mul(n)?;
+                    }
+                    if weight == 0. {
+                        continue;
+                    }
+                    for (j, value) in values.iter_mut().enumerate() {
+                        *value += weight * *samples.get(offset.checked_mul(outputs)?.checked_add(j)?)? as f64;
+                    }
+                }
+                let max = (1u64.checked_shl(*bits_per_sample)?.wrapping_sub(1)) as f64;
+                let decoded = values.iter().zip(decode.chunks_exact(2)).map(|(v, d)| d[0] + v * (d[1] - d[0]) / max);
+                Some(decoded.zip(range.chunks_exact(2)).map(|(y, r)| clip(y, r)).collect())
+            }
+            Function::Type2 { domain, c0, c1, n } => {
+                let x = input.first()?.clamp(domain[0].min(domain[1]), domain[0].max(domain[1]));
+                Some(c0.iter().zip(c1).map(|(c0, c1)| c0 + x.powf(*n) * (c1 - c0)).collect())
+            }
+            Function::Type3 { domain, functions, bounds, encode } => {
+                let x = clip(*input.first()?, domain);
+                // Subdomains include their start, and the last one its end too
+                let i = bounds.iter().take_while(|&&bound| x >= bound).count();
+                let start = if i == 0 { domain[0] } else { bounds[i - 1] };
+                let end = bounds.get(i).copied().unwrap_or(domain[1]);
+                let (e0, e1) = (*encode.get(2 * i)?, *encode.get(2 * i + 1)?);
+                let t = if end == start { e0 } else { e0 + (x - start) * (e1 - e0) / (end - start) };
+                functions.get(i)?.eval(&[t])
+            }
+            Function::Type4 { domain, range, program } => {
+                let input: Vec<f64> = input.iter().zip(domain.chunks_exact(2)).map(|(x, d)| clip(*x, d)).collect();
+                let stack = program.run(&input)?;
+                // The outputs are what the program leaves on the stack
+                let outputs = stack.get(stack.len().checked_sub(range.len() / 2)?..)?;
+                Some(outputs.iter().zip(range.chunks_exact(2)).map(|(y, r)| clip(*y, r)).collect())
+            }
+        }
+    }
+}
+
+// The first `count` samples of `bits` bits each packed into `data`, high bits first
+fn read_samples(data: &[u8], bits: u32, count: usize) -> Option<Vec<u32>> {
+    if count.checked_mul(bits as usize)? > data.len() * 8 {
+        return None;
+    }
+    let mut samples = Vec::with_capacity(count);
+    let (mut acc, mut held, mut bytes) = (0u64, 0, data.iter());
+    for _ in 0..count {
+        while held < bits {
+            acc = acc << 8 | u64::from(*bytes.next()?);
+            held += 8;
+        }
+        held -= bits;
+        samples.push((acc >> held & ((1 << bits) - 1)) as u32);
+        acc &= (1 << held) - 1;
+    }
+    Some(samples)
+}
+
+// `x` clipped to the interval of a `Domain` or `Range` pair
+fn clip(x: f64, interval: &[f64]) -> f64 {
+    x.clamp(interval[0].min(interval[1]), interval[0].max(interval[1]))
 }
 
 // PlainTextOutput implementation
+/// Thresholds `PlainTextOutput` breaks lines and inserts spaces at, in multiples of the font size;
+/// raise them for loosely set text, lower them for tight typesetting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutParams {
+    /// Vertical move above which a new line starts; 1.5 by default
+    pub line_break: f64,
+    /// Vertical move above which text continuing left of the previous glyph starts a new line,
+    /// so smaller moves back, as for accents and superscripts, stay on the line; 0.5 by default
+    pub baseline_shift: f64,
+    /// Horizontal gap between glyphs above which a space is inserted, unless a `SpacingModel`
+    /// is used; `spacing::DEFAULT_WORD_GAP` by default
+    pub word_gap: f64,
+    /// Fraction of the width of the font's space glyph above which a gap is a space, for fonts
+    /// that have one, when that is more than `word_gap`; 0.5 by default
+    pub space_fraction: f64,
+}
+
+impl Default for LayoutParams {
+    fn default() -> LayoutParams {
+        LayoutParams { line_break: 1.5, baseline_shift: 0.5, word_gap: spacing::DEFAULT_WORD_GAP, space_fraction: 0.5 }
+    }
+}
+
+/// Line terminator written by `PlainTextOutput`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+    /// `CrLf` on Windows, `Lf` elsewhere
+    Native,
+}
+
+impl LineEnding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Native if cfg!(windows) => "\r\n",
+            LineEnding::Native => "\n",
+        }
+    }
+}
+
 pub struct PlainTextOutput<W: std::io::Write> {
     writer: W,
+    params: LayoutParams,
     last_end: f64,
     last_y: f64,
     first_char: bool,
     flip_ctm: PdfTransform,
+    spacing: Option<spacing::SpacingModel>,
+    font: Option<Arc<str>>,
+    // Text of the current line, written in one go when the line or page ends
+    line: String,
+    page_separator: Option<String>,
+    started: bool,
+    line_ending: LineEnding,
+    trailing_newline: bool,
+    // Whether text was added since the last line break
+    line_open: bool,
+    // `TJ` adjustments since the last glyph, in ems moving the next glyph forward
+    adjustment: Option<f64>,
+    // AFM kerning before the next glyph in ems, see `OutputDev::glyph_kerning`
+    kerning: f64,
+    // Width of the current font's space glyph in ems
+    space_width: Option<f64>,
 }
 
 impl<W: std::io::Write> PlainTextOutput<W> {
     pub fn new(writer: W) -> PlainTextOutput<W> {
         PlainTextOutput {
             writer,
+            params: LayoutParams::default(),
+            line: String::new(),
             last_end: 100000.,
             first_char: false,
             last_y: 0.,
             flip_ctm: Transform2D::identity(),
+            spacing: None,
+            font: None,
+            page_separator: None,
+            started: false,
+            line_ending: LineEnding::Lf,
+            trailing_newline: false,
+            line_open: false,
+            adjustment: None,
+            kerning: 0.,
+            space_width: None,
+        }
+    }
+
+    /// Insert spaces using word gap thresholds learned per font, see `spacing::learn`
+    pub fn spacing(mut self, spacing: spacing::SpacingModel) -> PlainTextOutput<W> {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Break lines and insert spaces at the thresholds of `params`
+    pub fn layout_params(mut self, params: LayoutParams) -> PlainTextOutput<W> {
+        self.params = params;
+        self
+    }
+
+    /// Write `separator` between pages, e.g. `"\x0c"` for pdftotext's form feeds
+    pub fn page_separator(mut self, separator: &str) -> PlainTextOutput<W> {
+        self.page_separator = Some(separator.to_string());
+        self
+    }
+
+    /// End lines with `line_ending` instead of `\n`
+    pub fn line_ending(mut self, line_ending: LineEnding) -> PlainTextOutput<W> {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// End the text of each page with a line break, so the output ends in one
+    pub fn trailing_newline(mut self, trailing_newline: bool) -> PlainTextOutput<W> {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    fn flush_line(&mut self) -> PdfResult<()> {
+        if !self.line.is_empty() {
+            self.writer.write_all(self.line.as_bytes())?;
+            self.line.clear();
         }
+        Ok(())
+    }
+
+    fn break_line(&mut self) -> PdfResult<()> {
+        self.line.push_str(self.line_ending.as_str());
+        self.line_open = false;
+        self.flush_line()
     }
 }
 
+// Position and size of a glyph as `PlainTextOutput` measures them, in top-down page space
+pub(crate) fn plain_text_position(trm: &PdfTransform, flip_ctm: &PdfTransform, font_size: f64) -> (f64, f64, f64) {
+    let position = trm.then(flip_ctm);
+    let transformed_font_size_vec = trm.transform_vector(vec2(font_size, font_size));
+    let transformed_font_size = (transformed_font_size_vec.x * transformed_font_size_vec.y).sqrt();
+    (position.m31, position.m32, transformed_font_size)
+}
+
 impl<W: std::io::Write> OutputDev for PlainTextOutput<W> {
     fn begin_page(&mut self, _page_num: u32, media_box: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        if let Some(separator) = &self.page_separator
+            && self.started
+        {
+            self.writer.write_all(separator.as_bytes())?;
+        }
+        self.started = true;
         self.flip_ctm = Transform2D::new(1., 0., 0., -1., 0., media_box.ury - media_box.lly);
         Ok(())
     }
     
     fn end_page(&mut self) -> PdfResult<()> {
-        Ok(())
+        if self.trailing_newline && self.line_open {
+            self.break_line()?;
+        }
+        self.flush_line()
     }
     
     fn output_character(&mut self, trm: &PdfTransform, width: f64, _spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
-        let position = trm.then(&self.flip_ctm);
-        let transformed_font_size_vec = trm.transform_vector(vec2(font_size, font_size));
-        let transformed_font_size = (transformed_font_size_vec.x * transformed_font_size_vec.y).sqrt();
-        let (x, y) = (position.m31, position.m32);
+        let (x, y, transformed_font_size) = plain_text_position(trm, &self.flip_ctm, font_size);
+        let adjustment = self.adjustment.take();
+        let kerning = std::mem::take(&mut self.kerning);
         
         if self.first_char {
-            if (y - self.last_y).abs() > transformed_font_size * 1.5 {
-                writeln!(self.writer)?;
+            let word_gap = match &self.spacing {
+                Some(spacing) => spacing.word_gap(self.font.as_deref().unwrap_or(""), transformed_font_size),
+                None => match self.space_width {
+                    Some(width) => (width * self.params.space_fraction).max(self.params.word_gap),
+                    None => self.params.word_gap,
+                },
+            };
+            if (y - self.last_y).abs() > transformed_font_size * self.params.line_break {
+                self.break_line()?;
             }
             
-            if x < self.last_end && (y - self.last_y).abs() > transformed_font_size * 0.5 {
-                writeln!(self.writer)?;
+            if x < self.last_end && (y - self.last_y).abs() > transformed_font_size * self.params.baseline_shift {
+                self.break_line()?;
             }
             
-            if x > self.last_end + transformed_font_size * 0.1 {
-                write!(self.writer, " ")?;
+            // Gaps are measured from where the font's kerning puts the glyph
+            let space = match adjustment {
+                // Within a `TJ` array the adjustment tells word gaps from kerning, whichever way
+                // the text runs and however accurate the previous glyph's width
+                Some(gap) => gap - kerning > word_gap,
+                None => x > self.last_end + transformed_font_size * (kerning + word_gap),
+            };
+            if space {
+                self.line.push(' ');
             }
         }
         
-        write!(self.writer, "{}", char)?;
+        self.line.push_str(char);
+        self.line_open = true;
         self.first_char = false;
         self.last_y = y;
         self.last_end = x + width * transformed_font_size;
         Ok(())
     }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        if self.spacing.is_some() && self.font.as_deref() != font.base_name() {
+            self.font = font.base_name().map(Arc::from);
+        }
+        self.space_width = font.space_width().map(|width| width / 1000.);
+        Ok(())
+    }
     
     fn begin_word(&mut self) -> PdfResult<()> {
         self.first_char = true;
         Ok(())
     }
+
+    fn text_adjustment(&mut self, amount: f64, _displacement: f64) -> PdfResult<()> {
+        *self.adjustment.get_or_insert(0.) -= amount / 1000.;
+        Ok(())
+    }
+
+    // Adjustments only tell word gaps from kerning between glyphs of the same array, so they
+    // are dropped once it ends or the text matrix is set
+    fn end_text_array(&mut self) -> PdfResult<()> {
+        self.adjustment = None;
+        Ok(())
+    }
+
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> {
+        self.kerning = amount / 1000.;
+        Ok(())
+    }
+
+    fn end_text_object(&mut self) -> PdfResult<()> {
+        self.adjustment = None;
+        Ok(())
+    }
     
     fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
-    fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_line(&mut self) -> PdfResult<()> {
+        self.adjustment = None;
+        Ok(())
+    }
 }
 
 // HTMLOutput implementation
@@ -1288,6 +2583,15 @@ pub struct HTMLOutput<W: std::io::Write> {
     buf_ctm: PdfTransform,
     buf_font_size: f64,
     buf: String,
+    // Fill colour and alpha of the text shown next, and of the buffered text
+    fill: (Option<(f64, f64, f64)>, f64),
+    buf_fill: (Option<(f64, f64, f64)>, f64),
+    // CSS declarations for the font of the text shown next, and of the buffered text
+    font: Arc<str>,
+    buf_font: Arc<str>,
+    stylesheet: Option<String>,
+    // Whether `begin_document` wrote the head, so pages don't need a charset of their own
+    in_document: bool,
 }
 
 impl<W: std::io::Write> HTMLOutput<W> {
@@ -1299,24 +2603,55 @@ impl<W: std::io::Write> HTMLOutput<W> {
             buf_ctm: Transform2D::identity(),
             buf: String::new(),
             buf_font_size: 0.,
+            fill: (Some((0., 0., 0.)), 1.),
+            buf_fill: (Some((0., 0., 0.)), 1.),
+            font: Arc::from(""),
+            buf_font: Arc::from(""),
+            stylesheet: None,
+            in_document: false,
         }
     }
+
+    /// Link the stylesheet at `href` from the document head
+    pub fn stylesheet(mut self, href: &str) -> HTMLOutput<W> {
+        self.stylesheet = Some(href.to_string());
+        self
+    }
     
     fn flush_string(&mut self) -> PdfResult<()> {
         if !self.buf.is_empty() {
             let position = self.buf_ctm.then(&self.flip_ctm);
-            let transformed_font_size_vec = self.buf_ctm.transform_vector(vec2(self.buf_font_size, self.buf_font_size));
-            let transformed_font_size = (transformed_font_size_vec.x * transformed_font_size_vec.y).sqrt();
+            let m = &self.buf_ctm;
+            let scale = m.determinant().abs().sqrt();
+            let transformed_font_size = self.buf_font_size * scale;
             let (x, y) = (position.m31, position.m32);
+            let mut style = String::new();
+            // Rotated and skewed text turns about its origin; the matrix is flipped for CSS's
+            // downward y and has the scale taken out, which the font size carries
+            if scale > 0. && (m.m12.abs() > 1e-6 || m.m21.abs() > 1e-6) {
+                let n = |v: f64| (v / scale * 1e4).round() / 1e4 + 0.;
+                style += &format!("; transform: matrix({}, {}, {}, {}, 0, 0); transform-origin: 0 0",
+                                  n(m.m11), n(-m.m12), n(-m.m21), n(m.m22));
+            }
+            // Black opaque text, the default, gets no colour style
+            if let (Some(color), _) = self.buf_fill
+                && color != (0., 0., 0.)
+            {
+                style += &format!("; color: {}", string_utils::hex_color(color));
+            }
+            if self.buf_fill.1 < 1. {
+                style += &format!("; opacity: {:.2}", self.buf_fill.1);
+            }
             
-            writeln!(self.file, "<div style='position: absolute; left: {}px; top: {}px; font-size: {}px'>{}</div>",
-                   x, y, transformed_font_size, insert_nbsp(&self.buf))?;
+            writeln!(self.file, "<div style='position: absolute; left: {}px; top: {}px{}; font-size: {}px{}'>{}</div>",
+                   x, y, self.buf_font, transformed_font_size, style, insert_nbsp(&string_utils::escape_xml(&self.buf)))?;
             self.buf.clear();
         }
         Ok(())
     }
 }
 
+// Runs of spaces and spaces at either end of `input` as `&nbsp;`, which HTML doesn't collapse
 fn insert_nbsp(input: &str) -> String {
     let mut result = String::new();
     let mut word_end = false;
@@ -1339,8 +2674,31 @@ fn insert_nbsp(input: &str) -> String {
 }
 
 impl<W: std::io::Write> OutputDev for HTMLOutput<W> {
+    // The head, then an index linking to each page's anchor when there are several
+    fn begin_document(&mut self, pages: &[u32]) -> PdfResult<()> {
+        writeln!(self.file, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset='utf-8' />")?;
+        if let Some(href) = &self.stylesheet {
+            writeln!(self.file, "<link rel='stylesheet' href='{}' />", string_utils::escape_xml(href))?;
+        }
+        writeln!(self.file, "</head>\n<body>")?;
+        if pages.len() > 1 {
+            let links: Vec<String> = pages.iter().map(|n| format!("<a href='#page{}'>{}</a>", n, n)).collect();
+            writeln!(self.file, "<nav>{}</nav>", links.join(" "))?;
+        }
+        self.in_document = true;
+        Ok(())
+    }
+
+    fn end_document(&mut self) -> PdfResult<()> {
+        writeln!(self.file, "\n</body>\n</html>")?;
+        self.in_document = false;
+        Ok(())
+    }
+
     fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
-        write!(self.file, "<meta charset='utf-8' />")?;
+        if !self.in_document {
+            write!(self.file, "<meta charset='utf-8' />")?;
+        }
         write!(self.file, "<!-- page {} -->", page_num)?;
         write!(self.file, "<div id='page{}' style='position: relative; height: {}px; width: {}px; border: 1px black solid'>",
                page_num, media_box.ury - media_box.lly, media_box.urx - media_box.llx)?;
@@ -1357,15 +2715,74 @@ impl<W: std::io::Write> OutputDev for HTMLOutput<W> {
     }
     
     fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
-        if trm.approx_eq(&self.last_ctm) {
+        if trm.approx_eq(&self.last_ctm) && self.fill == self.buf_fill && self.font == self.buf_font {
             self.buf += char;
         } else {
             self.flush_string()?;
             self.buf = char.to_owned();
             self.buf_font_size = font_size;
             self.buf_ctm = *trm;
+            self.buf_fill = self.fill;
+            self.buf_font = self.font.clone();
+        }
+        self.last_ctm = Transform2D::translation(width * font_size + spacing, 0.).then(trm);
+        Ok(())
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.fill = (colorspace.to_rgb(color), alpha);
+        Ok(())
+    }
+
+    // Family from the `BaseFont` name, weight and slant also from the descriptor flags
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        let style = font.style();
+        let mut css = String::new();
+        if let Some(name) = font.base_name() {
+            css += &format!("; font-family: {}", style.css_font_family(name));
+        }
+        if style.bold {
+            css += "; font-weight: bold";
+        }
+        if style.italic {
+            css += "; font-style: italic";
         }
-        self.last_ctm = trm.then(&Transform2D::translation(width * font_size + spacing, 0.));
+        if *self.font != css {
+            self.font = Arc::from(css);
+        }
+        Ok(())
+    }
+
+    // Outlined text takes the stroke colour; invisible text stays selectable at opacity 0
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.fill = info.visible_color();
+        self.output_character(trm, width, spacing, font_size, char)
+    }
+
+    // Inline SVG image of the glyph, sized to the em box and sitting on the baseline
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        self.flush_string()?;
+        let position = trm.then(&self.flip_ctm);
+        let size = font_size * trm.determinant().abs().sqrt();
+        write!(self.file, "<img style='position: absolute; left: {}px; top: {}px; width: {}px; height: {}px' alt='{}' src='{}'>",
+               position.m31, position.m32 - size * outlines::ASCENT_RATIO, width * size, size,
+               string_utils::escape_xml(&glyph.text), outlines::glyph_data_uri(glyph, width * 1000.))?;
+        self.last_ctm = Transform2D::identity();
         Ok(())
     }
     
@@ -1377,16 +2794,94 @@ impl<W: std::io::Write> OutputDev for HTMLOutput<W> {
 // SVGOutput implementation
 pub struct SVGOutput<W: std::io::Write> {
     file: W,
+    // Glyphs shown along one baseline, written as one `<text>` element
+    run: Option<SvgTextRun>,
+    // Font attributes of the text shown next
+    font: Arc<str>,
+    line: LineStyle,
+    // Clipping paths of the open `<g clip-path>` groups, outermost first
+    clips: Vec<ClipPath>,
+    // Number of `<clipPath>` elements written, for their ids
+    clip_count: usize,
+    // Colour image masks are painted in
+    fill: (f64, f64, f64),
+    media_box: MediaBox,
+    // Number of gradients written, for their ids
+    gradient_count: usize,
+    // Number of `<pattern>` elements written, for their ids
+    pattern_count: usize,
+    // Id of each open `<pattern>` and the clipping groups open outside it
+    patterns: Vec<(usize, usize)>,
+}
+
+struct SvgTextRun {
+    // Text rendering matrix of the first glyph
+    trm: PdfTransform,
+    font_size: f64,
+    font: Arc<str>,
+    // Offset of each glyph along the baseline, in the run's text space, and its text
+    glyphs: Vec<(f64, String)>,
+}
+
+impl SvgTextRun {
+    // Offset of a glyph drawn with `trm` along the run's baseline, if it lies on it
+    fn offset(&self, trm: &PdfTransform) -> Option<f64> {
+        let m = &self.trm;
+        let same_matrix = [(m.m11, trm.m11), (m.m12, trm.m12), (m.m21, trm.m21), (m.m22, trm.m22)]
+            .iter()
+            .all(|(a, b)| (a - b).abs() <= 1e-6 * a.abs().max(b.abs()).max(1.));
+        let det = m.determinant();
+        if !same_matrix || det == 0. {
+            return None;
+        }
+        let (dx, dy) = (trm.m31 - m.m31, trm.m32 - m.m32);
+        let x = (dx * m.m22 - dy * m.m21) / det;
+        let y = (m.m11 * dy - m.m12 * dx) / det;
+        (y.abs() < 1e-3).then_some(x)
+    }
 }
 
 impl<W: std::io::Write> SVGOutput<W> {
     pub fn new(file: W) -> SVGOutput<W> {
-        SVGOutput { file }
+        SVGOutput { file, run: None, font: Arc::from(""), line: LineStyle::default(), clips: Vec::new(),
+            clip_count: 0,
+            fill: (0., 0., 0.),
+            media_box: MediaBox { llx: 0., lly: 0., urx: 0., ury: 0. },
+            gradient_count: 0,
+            pattern_count: 0,
+            patterns: Vec::new(),
+        }
+    }
+
+    // Close the clipping groups past the first `keep`
+    fn close_clips(&mut self, keep: usize) -> PdfResult<()> {
+        while self.clips.len() > keep {
+            self.clips.pop();
+            writeln!(self.file, "</g>")?;
+        }
+        Ok(())
+    }
+
+    fn flush_text(&mut self) -> PdfResult<()> {
+        let Some(run) = self.run.take() else {
+            return Ok(());
+        };
+        let m = &run.trm;
+        let n = svg_number;
+        // Glyphs are drawn upright in the page's flipped coordinates
+        write!(self.file, "<text transform='matrix({}, {}, {}, {}, {}, {})' font-size='{}'{} xml:space='preserve'>",
+               n(m.m11), n(m.m12), n(-m.m21), n(-m.m22), n(m.m31), n(m.m32), n(run.font_size), run.font)?;
+        for (x, text) in &run.glyphs {
+            write!(self.file, "<tspan x='{}'>{}</tspan>", n(*x), string_utils::escape_xml(text))?;
+        }
+        writeln!(self.file, "</text>")?;
+        Ok(())
     }
 }
 
 impl<W: std::io::Write> OutputDev for SVGOutput<W> {
     fn begin_page(&mut self, _page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.media_box = *media_box;
         let ver = 1.1;
         writeln!(self.file, "<?xml version=\"1.0\" encoding=\"UTF-8\" ?>")?;
         write!(self.file, r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">"#)?;
@@ -1395,12 +2890,12 @@ impl<W: std::io::Write> OutputDev for SVGOutput<W> {
             let width = art_box.2 - art_box.0;
             let height = art_box.3 - art_box.1;
             let y = media_box.ury - art_box.1 - height;
-            write!(self.file, "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\" version=\"{}\" viewBox='{} {} {} {}'>",
+            write!(self.file, "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" version=\"{}\" viewBox='{} {} {} {}'>",
                    width, height, ver, art_box.0, y, width, height)?;
         } else {
             let width = media_box.urx - media_box.llx;
             let height = media_box.ury - media_box.lly;
-            write!(self.file, "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\" version=\"{}\" viewBox='{} {} {} {}'>",
+            write!(self.file, "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" version=\"{}\" viewBox='{} {} {} {}'>",
                    width, height, ver, media_box.llx, media_box.lly, width, height)?;
         }
         writeln!(self.file)?;
@@ -1412,12 +2907,152 @@ impl<W: std::io::Write> OutputDev for SVGOutput<W> {
     }
     
     fn end_page(&mut self) -> PdfResult<()> {
+        self.flush_text()?;
+        self.close_clips(0)?;
         writeln!(self.file, "</g>")?;
         write!(self.file, "</svg>")?;
         Ok(())
     }
     
-    fn output_character(&mut self, _trm: &PdfTransform, _width: f64, _spacing: f64, _font_size: f64, _char: &str) -> PdfResult<()> {
+    fn output_character(&mut self, trm: &PdfTransform, _width: f64, _spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        if let Some(run) = &mut self.run
+            && run.font_size == font_size
+            && run.font == self.font
+            && let Some(x) = run.offset(trm)
+        {
+            run.glyphs.push((x, char.to_owned()));
+            return Ok(());
+        }
+        self.flush_text()?;
+        self.run = Some(SvgTextRun { trm: *trm, font_size, font: self.font.clone(), glyphs: vec![(0., char.to_owned())] });
+        Ok(())
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], _alpha: f64) -> PdfResult<()> {
+        self.fill = colorspace.to_rgb(color).unwrap_or((0., 0., 0.));
+        Ok(())
+    }
+
+    fn shade(&mut self, ctm: &PdfTransform, shading: &shading::Shading) -> PdfResult<()> {
+        // The page's corners in shading space bound the area to paint
+        let Some(inverse) = ctm.inverse() else { return Ok(()) };
+        let page = &self.media_box;
+        let corners = [(page.llx, page.lly), (page.urx, page.lly), (page.llx, page.ury), (page.urx, page.ury)]
+            .map(|(x, y)| inverse.transform_point(point2(x, y)));
+        self.flush_text()?;
+        self.gradient_count += 1;
+        let id = self.gradient_count;
+        let stops: String = shading.stops.iter()
+            .map(|(offset, rgb)| format!("<stop offset='{}' stop-color='{}' />", svg_number(*offset), string_utils::hex_color(*rgb)))
+            .collect();
+        write!(self.file, "<g transform='matrix({}, {}, {}, {}, {}, {})'>",
+               ctm.m11, ctm.m12, ctm.m21, ctm.m22, ctm.m31, ctm.m32)?;
+        if let Some([x0, y0, x1, y1]) = shading.bbox {
+            self.clip_count += 1;
+            write!(self.file, "<clipPath id='clip{}'><rect x='{}' y='{}' width='{}' height='{}' /></clipPath><g clip-path='url(#clip{})'>",
+                   self.clip_count, x0, y0, x1 - x0, y1 - y0, self.clip_count)?;
+        }
+        match shading.geometry {
+            shading::ShadingGeometry::Axial { start, end } => {
+                // Painted in a frame whose x axis runs along the shading's axis, so a rectangle
+                // between the ends leaves out what isn't extended
+                let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                let length = dx.hypot(dy);
+                let (cos, sin) = if length > 0. { (dx / length, dy / length) } else { (1., 0.) };
+                let along = corners.map(|p| ((p.x - start.0) * cos + (p.y - start.1) * sin, (p.y - start.1) * cos - (p.x - start.0) * sin));
+                let (u0, u1) = along.iter().fold((0f64, length), |(lo, hi), &(u, _)| (lo.min(u), hi.max(u)));
+                let (v0, v1) = along.iter().fold((f64::MAX, f64::MIN), |(lo, hi), &(_, v)| (lo.min(v), hi.max(v)));
+                let u0 = if shading.extend[0] { u0 } else { 0. };
+                let u1 = if shading.extend[1] { u1 } else { length };
+                write!(self.file, "<linearGradient id='shade{}' gradientUnits='userSpaceOnUse' x1='0' y1='0' x2='{}' y2='0'>{}</linearGradient>",
+                       id, svg_number(length), stops)?;
+                write!(self.file, "<rect transform='matrix({}, {}, {}, {}, {}, {})' x='{}' y='{}' width='{}' height='{}' fill='url(#shade{})' />",
+                       svg_number(cos), svg_number(sin), svg_number(-sin), svg_number(cos), start.0, start.1,
+                       svg_number(u0), svg_number(v0), svg_number(u1 - u0), svg_number(v1 - v0), id)?;
+            }
+            shading::ShadingGeometry::Radial { start, start_radius, end, end_radius } => {
+                write!(self.file, "<radialGradient id='shade{}' gradientUnits='userSpaceOnUse' cx='{}' cy='{}' r='{}' fx='{}' fy='{}' fr='{}'>{}</radialGradient>",
+                       id, end.0, end.1, end_radius, start.0, start.1, start_radius, stops)?;
+                // Without extension only the end circle is painted; the colour inside the start
+                // circle isn't left out
+                if shading.extend[1] {
+                    let (x0, x1) = corners.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p.x), hi.max(p.x)));
+                    let (y0, y1) = corners.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| (lo.min(p.y), hi.max(p.y)));
+                    write!(self.file, "<rect x='{}' y='{}' width='{}' height='{}' fill='url(#shade{})' />",
+                           svg_number(x0), svg_number(y0), svg_number(x1 - x0), svg_number(y1 - y0), id)?;
+                } else {
+                    write!(self.file, "<circle cx='{}' cy='{}' r='{}' fill='url(#shade{})' />", end.0, end.1, end_radius, id)?;
+                }
+            }
+        }
+        if shading.bbox.is_some() {
+            write!(self.file, "</g>")?;
+        }
+        writeln!(self.file, "</g>")?;
+        Ok(())
+    }
+
+    fn begin_pattern(&mut self, ctm: &PdfTransform, pattern: &TilingPattern) -> PdfResult<()> {
+        self.flush_text()?;
+        self.pattern_count += 1;
+        // The clipping groups already open bound the fill, not each cell
+        self.patterns.push((self.pattern_count, self.clips.len()));
+        let [x0, y0, ..] = pattern.bbox;
+        write!(self.file, "<pattern id='pattern{}' patternUnits='userSpaceOnUse' x='{}' y='{}' width='{}' height='{}' patternTransform='matrix({}, {}, {}, {}, {}, {})'>",
+               self.pattern_count, x0, y0, pattern.x_step.abs(), pattern.y_step.abs(), ctm.m11, ctm.m12, ctm.m21, ctm.m22, ctm.m31, ctm.m32)?;
+        // The cell's elements are placed on the page, so map them back to pattern space
+        let m = ctm.inverse().unwrap_or_else(Transform2D::identity);
+        writeln!(self.file, "<g transform='matrix({}, {}, {}, {}, {}, {})'>", m.m11, m.m12, m.m21, m.m22, m.m31, m.m32)?;
+        Ok(())
+    }
+
+    fn end_pattern(&mut self) -> PdfResult<()> {
+        self.flush_text()?;
+        let Some((id, clips)) = self.patterns.pop() else { return Ok(()) };
+        self.close_clips(clips)?;
+        writeln!(self.file, "</g></pattern>")?;
+        // Painted over the page, the clipping region leaves the filled path
+        let page = &self.media_box;
+        writeln!(self.file, "<rect x='{}' y='{}' width='{}' height='{}' fill='url(#pattern{})' />",
+                 page.llx, page.lly, page.urx - page.llx, page.ury - page.lly, id)?;
+        Ok(())
+    }
+
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        let byte = |c: f64| (c.clamp(0., 1.) * 255.).round() as u8;
+        let fill = [byte(self.fill.0), byte(self.fill.1), byte(self.fill.2)];
+        let href = if let Some(jpeg) = images::jpeg_data(image) {
+            format!("data:image/jpeg;base64,{}", string_utils::base64(&jpeg))
+        } else if let Some(png) = images::png_data(image, fill)? {
+            format!("data:image/png;base64,{}", string_utils::base64(&png))
+        } else {
+            debug!("Skipping an image SVG can't show");
+            return Ok(());
+        };
+        self.flush_text()?;
+        // Images fill the unit square of their user space, top row first
+        write!(self.file, "<g transform='matrix({}, {}, {}, {}, {}, {})'>",
+               ctm.m11, ctm.m12, ctm.m21, ctm.m22, ctm.m31, ctm.m32)?;
+        write!(self.file, "<image width='1' height='1' transform='matrix(1, 0, 0, -1, 0, 1)' preserveAspectRatio='none' xlink:href='{}' />", href)?;
+        writeln!(self.file, "</g>")?;
+        Ok(())
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        let style = font.style();
+        let mut attributes = String::new();
+        if let Some(name) = font.base_name() {
+            attributes += &format!(" font-family='{}'", style.css_font_family(name));
+        }
+        if style.bold {
+            attributes += " font-weight='bold'";
+        }
+        if style.italic {
+            attributes += " font-style='italic'";
+        }
+        if *self.font != attributes {
+            self.font = Arc::from(attributes);
+        }
         Ok(())
     }
     
@@ -1425,43 +3060,210 @@ impl<W: std::io::Write> OutputDev for SVGOutput<W> {
     fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
     fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
     
-    fn fill(&mut self, ctm: &PdfTransform, _colorspace: &ColorSpace, _color: &[f64], path: &Path) -> PdfResult<()> {
+    fn fill(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path, rule: FillRule) -> PdfResult<()> {
+        // Text shown before the path stays beneath it
+        self.flush_text()?;
         write!(self.file, "<g transform='matrix({}, {}, {}, {}, {}, {})'>",
                ctm.m11, ctm.m12, ctm.m21, ctm.m22, ctm.m31, ctm.m32)?;
-        
-        let mut d = Vec::new();
-        for op in &path.ops {
-            match op {
-                PathOp::MoveTo(x, y) => d.push(format!("M{} {}", x, y)),
-                PathOp::LineTo(x, y) => d.push(format!("L{} {}", x, y)),
-                PathOp::CurveTo(x1, y1, x2, y2, x, y) => d.push(format!("C{} {} {} {} {} {}", x1, y1, x2, y2, x, y)),
-                PathOp::Close => d.push("Z".to_string()),
-                PathOp::Rect(x, y, width, height) => {
-                    d.push(format!("M{} {}", x, y));
-                    d.push(format!("L{} {}", x + width, y));
-                    d.push(format!("L{} {}", x + width, y + height));
-                    d.push(format!("L{} {}", x, y + height));
-                    d.push("Z".to_string());
-                }
+        let rule = if rule == FillRule::EvenOdd { " fill-rule='evenodd'" } else { "" };
+        write!(self.file, "<path d='{}' fill='{}'{} />", svg_path_data(path), svg_color(colorspace, color), rule)?;
+        writeln!(self.file, "</g>")?;
+        Ok(())
+    }
+
+    fn set_line_style(&mut self, style: &LineStyle) -> PdfResult<()> {
+        self.line.clone_from(style);
+        Ok(())
+    }
+
+    fn set_clip(&mut self, clip: &[ClipPath]) -> PdfResult<()> {
+        self.flush_text()?;
+        // Clipping paths only ever get added or restored, so the groups of a common prefix stay open;
+        // those opened outside a pattern can't be closed inside it
+        let outside = self.patterns.last().map_or(0, |&(_, clips)| clips).min(clip.len());
+        let common = self.clips.iter().zip(clip).take_while(|(a, b)| a == b).count().max(outside);
+        self.close_clips(common)?;
+        for c in &clip[common..] {
+            self.clip_count += 1;
+            let m = &c.ctm;
+            let rule = if c.rule == FillRule::EvenOdd { " clip-rule='evenodd'" } else { "" };
+            writeln!(self.file, "<clipPath id='clip{}'><path transform='matrix({}, {}, {}, {}, {}, {})' d='{}'{} /></clipPath>",
+                     self.clip_count, m.m11, m.m12, m.m21, m.m22, m.m31, m.m32, svg_path_data(&c.path), rule)?;
+            writeln!(self.file, "<g clip-path='url(#clip{})'>", self.clip_count)?;
+            self.clips.push(c.clone());
+        }
+        Ok(())
+    }
+
+    fn stroke(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path) -> PdfResult<()> {
+        self.flush_text()?;
+        let line = &self.line;
+        let mut attributes = match line.width {
+            // The thinnest line is one device pixel whatever the scale
+            0. => " stroke-width='1' vector-effect='non-scaling-stroke'".to_string(),
+            width => format!(" stroke-width='{}'", width),
+        };
+        attributes += match line.cap {
+            1 => " stroke-linecap='round'",
+            2 => " stroke-linecap='square'",
+            _ => "",
+        };
+        attributes += match line.join {
+            1 => " stroke-linejoin='round'",
+            2 => " stroke-linejoin='bevel'",
+            _ => "",
+        };
+        if line.join == 0 && line.miter_limit != 4. {
+            attributes += &format!(" stroke-miterlimit='{}'", line.miter_limit.max(1.));
+        }
+        // An all-zero pattern draws nothing in PDF but is invalid in SVG; treat it as solid
+        if line.dash.iter().any(|&d| d > 0.) && line.dash.iter().all(|&d| d >= 0.) {
+            let dash: Vec<String> = line.dash.iter().map(f64::to_string).collect();
+            attributes += &format!(" stroke-dasharray='{}'", dash.join(" "));
+            if line.dash_phase != 0. {
+                attributes += &format!(" stroke-dashoffset='{}'", line.dash_phase);
             }
         }
-        
-        write!(self.file, "<path d='{}' />", d.join(" "))?;
+        write!(self.file, "<g transform='matrix({}, {}, {}, {}, {}, {})'>",
+               ctm.m11, ctm.m12, ctm.m21, ctm.m22, ctm.m31, ctm.m32)?;
+        let stroke = svg_color(colorspace, color);
+        write!(self.file, "<path d='{}' fill='none' stroke='{}'{} />", svg_path_data(path), stroke, attributes)?;
         writeln!(self.file, "</g>")?;
         Ok(())
     }
 }
 
+// Path data of `path` for an SVG `d` attribute
+// `v` to three decimals, without negative zero
+fn svg_number(v: f64) -> f64 {
+    (v * 1e3).round() / 1e3 + 0.
+}
+
+// sRGB hex value of `color`, black for the spaces that can't be converted
+fn svg_color(colorspace: &ColorSpace, color: &[f64]) -> String {
+    string_utils::hex_color(colorspace.to_rgb(color).unwrap_or((0., 0., 0.)))
+}
+
+fn svg_path_data(path: &Path) -> String {
+    let mut d = Vec::new();
+    for op in &path.ops {
+        match op {
+            PathOp::MoveTo(x, y) => d.push(format!("M{} {}", x, y)),
+            PathOp::LineTo(x, y) => d.push(format!("L{} {}", x, y)),
+            PathOp::CurveTo(x1, y1, x2, y2, x, y) => d.push(format!("C{} {} {} {} {} {}", x1, y1, x2, y2, x, y)),
+            PathOp::Close => d.push("Z".to_string()),
+            PathOp::Rect(x, y, width, height) => {
+                d.push(format!("M{} {}", x, y));
+                d.push(format!("L{} {}", x + width, y));
+                d.push(format!("L{} {}", x + width, y + height));
+                d.push(format!("L{} {}", x, y + height));
+                d.push("Z".to_string());
+            }
+        }
+    }
+    d.join(" ")
+}
+
+/// Knobs for the content stream interpreter, shared by the `output_doc*_with_options` functions
+#[derive(Debug, Clone, Default)]
+pub struct ExtractionOptions {
+    /// Per-font char code overrides for documents with broken ToUnicode maps
+    pub glyph_overrides: overrides::GlyphOverrides,
+    /// AFM metrics whose kerning pairs are reported for non-embedded standard 14 fonts, see
+    /// `OutputDev::glyph_kerning`, in place of the built-in pairs of Adobe's Core14 AFM files
+    pub core_font_metrics: afm::CoreFontMetrics,
+    /// Treatment of private use codepoints from fonts without a ToUnicode map
+    pub pua_policy: pua::PuaPolicy,
+    /// Pass outlines of Type3 and CFF glyphs without a Unicode mapping to
+    /// `OutputDev::output_unmapped_glyph`, which `HTMLOutput` renders as inline images
+    pub glyph_image_fallback: bool,
+    /// Time each operator and font, reported in `Diagnostics::profile`
+    pub profile: bool,
+    /// Stop showing text on a page after this many glyphs, so pathological pages can't exhaust
+    /// memory downstream; the pages cut short are listed in `Diagnostics::truncated_pages`
+    pub max_glyphs_per_page: Option<usize>,
+    /// Extract the text of FreeText annotations after each page's content, from their
+    /// appearance streams or, without one, their `/Contents` set at the top of their `/Rect`
+    pub include_free_text: bool,
+    /// Whether malformed content fails the extraction (the default) or is worked around
+    pub strictness: Strictness,
+    /// Set to abort the extraction with `PdfError::Cancelled`; checked before each page and
+    /// every `CANCEL_CHECK_INTERVAL` operators
+    pub cancel: Option<Arc<AtomicBool>>,
+    /// Caps on decoded stream data, form nesting and operators per page, see `limits`
+    pub limits: limits::ResourceLimits,
+    /// Page boundary passed to `OutputDev::begin_page` as the page's geometry
+    pub page_box: PageBox,
+    /// Leave out characters whose origin lies outside `page_box`, such as printer marks and
+    /// text hidden beyond the crop area
+    pub clip_to_page_box: bool,
+    /// Leave out characters whose origin lies outside the current clipping path, such as text
+    /// that overflows a clipped table cell or text box
+    pub clip_to_clip_paths: bool,
+    /// Handling of text shown with an invisible rendering mode (`3 Tr` or `7 Tr`)
+    pub invisible_text: InvisibleText,
+}
+
+/// Operators executed between checks of `ExtractionOptions::cancel`
+pub const CANCEL_CHECK_INTERVAL: usize = 256;
+
+impl ExtractionOptions {
+    fn check_cancelled(&self) -> PdfResult<()> {
+        match &self.cancel {
+            Some(cancel) if cancel.load(Ordering::Relaxed) => Err(PdfError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Page boundary used for page geometry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PageBox {
+    #[default]
+    MediaBox,
+    /// The inherited `/CropBox` within the media box, or the media box for pages without one
+    CropBox,
+}
+
+/// Handling of text drawn with an invisible rendering mode, such as the text layer OCR lays over a
+/// scan or hidden watermark text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvisibleText {
+    /// Extract it like other text; devices see its mode in `CharInfo::render_mode`, and
+    /// `layout::write_json_lines` marks its fragments `"invisible":true`
+    #[default]
+    Keep,
+    /// Leave it out, though it still advances the text position
+    Skip,
+}
+
+/// Handling of malformed content streams, resources and page dictionaries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Return the first error
+    #[default]
+    Strict,
+    /// Log errors at warn level, substitute defaults (Helvetica for fonts that fail to load, a
+    /// US Letter media box) and skip operators that can't be executed
+    Lenient,
+}
+
+/// Observations about the decoding of a document
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    /// Fonts without a ToUnicode map that decoded private use codepoints
+    pub pua_fonts: Vec<pua::PuaFontStats>,
+    /// Operator and font timings, when `ExtractionOptions::profile` is set
+    pub profile: Option<profile::Profile>,
+    /// Pages whose text was cut off at `ExtractionOptions::max_glyphs_per_page`
+    pub truncated_pages: Vec<u32>,
+    /// Warnings raised while decoding, by page
+    pub report: report::ExtractionReport,
+}
+
 // Text extraction functions
 pub fn extract_text<P: AsRef<std::path::Path>>(path: P) -> PdfResult<String> {
-    let mut s = Vec::new();
-    {
-        let mut output = PlainTextOutput::new(&mut s);
-        let mut doc = Document::load(path)?;
-        maybe_decrypt(&mut doc)?;
-        output_doc(&doc, &mut output)?;
-    }
-    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+    Extractor::new().build().extract_text(path)
 }
 
 fn maybe_decrypt(doc: &mut Document) -> PdfResult<()> {
@@ -1483,20 +3285,26 @@ pub fn extract_text_encrypted<P: AsRef<std::path::Path>>(
     path: P,
     password: &str,
 ) -> PdfResult<String> {
-    let mut s = Vec::new();
-    {
-        let mut output = PlainTextOutput::new(&mut s);
-        let mut doc = Document::load(path)?;
-        output_doc_encrypted(&mut doc, &mut output, password)?;
-    }
-    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+    Extractor::new().password(password).build().extract_text(path)
+}
+
+/// Text of the pages in `pages`, e.g. `10..=25`; pages the document lacks are skipped with a
+/// warning
+pub fn extract_text_range<P: AsRef<std::path::Path>, I: IntoIterator<Item = u32>>(path: P, pages: I) -> PdfResult<String> {
+    Extractor::new().pages(pages).build().extract_text(path)
 }
 
 pub fn extract_text_from_mem(buffer: &[u8]) -> PdfResult<String> {
+    Extractor::new().build().extract_text_from_mem(buffer)
+}
+
+/// `extract_text`, loading the file through a memory mapping, see `mmap`
+#[cfg(feature = "mmap")]
+pub fn extract_text_from_mmap<P: AsRef<std::path::Path>>(path: P) -> PdfResult<String> {
     let mut s = Vec::new();
     {
         let mut output = PlainTextOutput::new(&mut s);
-        let mut doc = Document::load_mem(buffer)?;
+        let mut doc = mmap::load_mmap(path)?;
         maybe_decrypt(&mut doc)?;
         output_doc(&doc, &mut output)?;
     }
@@ -1507,101 +3315,29 @@ pub fn extract_text_from_mem_encrypted(
     buffer: &[u8],
     password: &str,
 ) -> PdfResult<String> {
-    let mut s = Vec::new();
-    {
-        let mut output = PlainTextOutput::new(&mut s);
-        let mut doc = Document::load_mem(buffer)?;
-        output_doc_encrypted(&mut doc, &mut output, password)?;
-    }
-    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+    Extractor::new().password(password).build().extract_text_from_mem(buffer)
 }
 
 pub fn extract_text_by_pages<P: AsRef<std::path::Path>>(path: P) -> PdfResult<Vec<String>> {
-    let mut v = Vec::new();
-    {
-        let mut doc = Document::load(path)?;
-        maybe_decrypt(&mut doc)?;
-        let mut page_num = 1;
-        while let Ok(content) = extract_text_by_page(&doc, page_num) {
-            v.push(content);
-            page_num += 1;
-        }
-    }
-    Ok(v)
+    Extractor::new().build().extract_text_by_pages(path)
 }
 
 pub fn extract_text_by_pages_encrypted<P: AsRef<std::path::Path>>(
     path: P,
     password: &str,
 ) -> PdfResult<Vec<String>> {
-    let mut v = Vec::new();
-    {
-        let mut doc = Document::load(path)?;
-        doc.decrypt(password)?;
-        let mut page_num = 1;
-        while let Ok(content) = extract_text_by_page(&doc, page_num) {
-            v.push(content);
-            page_num += 1;
-        }
-    }
-    Ok(v)
+    Extractor::new().password(password).build().extract_text_by_pages(path)
 }
 
 pub fn extract_text_from_mem_by_pages(buffer: &[u8]) -> PdfResult<Vec<String>> {
-    let mut v = Vec::new();
-    {
-        let mut doc = Document::load_mem(buffer)?;
-        maybe_decrypt(&mut doc)?;
-        let mut page_num = 1;
-        while let Ok(content) = extract_text_by_page(&doc, page_num) {
-            v.push(content);
-            page_num += 1;
-        }
-    }
-    Ok(v)
+    Extractor::new().build().extract_text_from_mem_by_pages(buffer)
 }
 
 pub fn extract_text_from_mem_by_pages_encrypted(
     buffer: &[u8],
     password: &str,
 ) -> PdfResult<Vec<String>> {
-    let mut v = Vec::new();
-    {
-        let mut doc = Document::load_mem(buffer)?;
-        doc.decrypt(password)?;
-        let mut page_num = 1;
-        while let Ok(content) = extract_text_by_page(&doc, page_num) {
-            v.push(content);
-            page_num += 1;
-        }
-    }
-    Ok(v)
-}
-
-fn extract_text_by_page(doc: &Document, page_num: u32) -> PdfResult<String> {
-    let mut s = Vec::new();
-    {
-        let mut output = PlainTextOutput::new(&mut s);
-        output_doc_page(doc, &mut output, page_num)?;
-    }
-    String::from_utf8(s).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
-}
-
-// Document processing
-pub fn print_metadata(doc: &Document) {
-    debug!("Version: {}", doc.version);
-    if let Some(info) = document_utils::get_info(doc) {
-        for (k, v) in info {
-            if let Object::String(s, StringFormat::Literal) = v {
-                debug!("{}: {}", string_utils::pdf_to_utf8(k).unwrap_or_default(), 
-                       string_utils::pdf_to_utf8(s).unwrap_or_default());
-            }
-        }
-    }
-    let pages = document_utils::get_pages(doc).ok();
-    if let Some(pages) = pages {
-        debug!("Page count: {}", get::<i64>(doc, pages, b"Count").unwrap_or(0));
-    }
+    Extractor::new().password(password).build().extract_text_from_mem_by_pages(buffer)
 }
 
 pub fn output_doc_encrypted(
@@ -1614,29 +3350,197 @@ pub fn output_doc_encrypted(
 }
 
 pub fn output_doc(doc: &Document, output: &mut dyn OutputDev) -> PdfResult<()> {
-    if doc.is_encrypted() {
-        error!("Encrypted documents must be decrypted with a password");
+    output_doc_with_options(doc, output, &ExtractionOptions::default())
+}
+
+pub fn output_doc_with_options(doc: &Document, output: &mut dyn OutputDev, options: &ExtractionOptions) -> PdfResult<()> {
+    output_doc_with_diagnostics(doc, output, options).map(|_| ())
+}
+
+/// Like `output_doc_with_options`, also returning what was observed while decoding
+pub fn output_doc_with_diagnostics(
+    doc: &Document,
+    output: &mut dyn OutputDev,
+    options: &ExtractionOptions,
+) -> PdfResult<Diagnostics> {
+    let pages: Vec<u32> = doc.get_pages().into_keys().collect();
+    output_doc_selected(doc, output, &pages, options)
+}
+
+/// Like `output_doc`, for the pages in `pages` in the order given; pages the document lacks are
+/// skipped with a warning, as by `extract_text_range` and `ExtractorBuilder::pages`
+pub fn output_doc_pages<I: IntoIterator<Item = u32>>(doc: &Document, output: &mut dyn OutputDev, pages: I) -> PdfResult<()> {
+    let options = ExtractionOptions::default();
+    let mut runner = PageRunner::new(doc, &options);
+    let pages = runner.existing_pages(pages);
+    runner.output(output, &pages).map(|_| ())
+}
+
+// Run the pages `pages` of `doc` in order through one processor, so they share its caches
+pub(crate) fn output_doc_selected(
+    doc: &Document,
+    output: &mut dyn OutputDev,
+    pages: &[u32],
+    options: &ExtractionOptions,
+) -> PdfResult<Diagnostics> {
+    PageRunner::new(doc, options).output(output, pages)
+}
+
+// Pages of one document run through a single processor, so the pages of separate `output` calls
+// share its font and content caches
+pub(crate) struct PageRunner<'a> {
+    doc: &'a Document,
+    page_ids: BTreeMap<u32, ObjectId>,
+    processor: Processor<'a>,
+    // Requested pages the document lacks, reported by the next `output`
+    missing: Vec<u32>,
+}
+
+impl<'a> PageRunner<'a> {
+    pub(crate) fn new(doc: &'a Document, options: &'a ExtractionOptions) -> Self {
+        if doc.is_encrypted() {
+            error!("Encrypted documents must be decrypted with a password");
+        }
+        PageRunner { doc, page_ids: doc.get_pages(), processor: Processor::new(options), missing: Vec::new() }
     }
-    let empty_resources = Dictionary::new();
-    let pages = doc.get_pages();
-    let mut p = Processor::new();
-    for (page_num, object_id) in pages {
-        output_doc_inner(page_num, object_id, doc, &mut p, output, &empty_resources)?;
+
+    pub(crate) fn page_numbers(&self) -> Vec<u32> {
+        self.page_ids.keys().copied().collect()
     }
-    Ok(())
+
+    pub(crate) fn page_count(&self) -> u32 {
+        self.page_ids.len() as u32
+    }
+
+    // `pages` without those the document lacks, which the next `output` reports as `Recovered`
+    // warnings on their own page numbers
+    pub(crate) fn existing_pages<I: IntoIterator<Item = u32>>(&mut self, pages: I) -> Vec<u32> {
+        let (pages, missing): (Vec<u32>, Vec<u32>) = pages.into_iter().partition(|n| self.page_ids.contains_key(n));
+        self.missing.extend(missing);
+        pages
+    }
+
+    // Run the pages `pages` in order through `output`; a page the document lacks is an error
+    pub(crate) fn output(&mut self, output: &mut dyn OutputDev, pages: &[u32]) -> PdfResult<Diagnostics> {
+        self.run(output, pages, true)
+    }
+
+    // Like `output` without calling `begin_document` and `end_document`, for callers that
+    // run a document through `output` page by page and call them once themselves
+    pub(crate) fn output_pages(&mut self, output: &mut dyn OutputDev, pages: &[u32]) -> PdfResult<Diagnostics> {
+        self.run(output, pages, false)
+    }
+
+    fn run(&mut self, output: &mut dyn OutputDev, pages: &[u32], document: bool) -> PdfResult<Diagnostics> {
+        static EMPTY_RESOURCES: LazyLock<Dictionary> = LazyLock::new(Dictionary::new);
+        let (doc, page_ids, p) = (self.doc, &self.page_ids, &mut self.processor);
+        let missing = std::mem::take(&mut self.missing);
+        let (result, report) = report::collect(|| {
+            for page_num in missing {
+                report::set_page(page_num);
+                report::warn(WarningKind::Recovered, format!("Page {} not found, skipping it", page_num));
+            }
+            limits::enforce(p.options.limits, || {
+                if document {
+                    output.begin_document(pages)?;
+                }
+                pages.iter().try_for_each(|&page_num| {
+                    let object_id = page_ids.get(&page_num)
+                        .ok_or_else(|| PdfError::InvalidStructure(format!("Page {} not found", page_num)))?;
+                    output_doc_inner(page_num, *object_id, doc, p, output, &EMPTY_RESOURCES)
+                })?;
+                if document { output.end_document() } else { Ok(()) }
+            })
+        });
+        result?;
+        Ok(p.diagnostics(report))
+    }
+}
+
+/// Which outputs `extract_all` produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExtractTargets {
+    /// Plain text as produced by `PlainTextOutput`
+    pub text: bool,
+    /// Absolutely positioned HTML as produced by `HTMLOutput`
+    pub html: bool,
+    /// Text lines with their positions as JSON lines, see `layout::write_json_lines`
+    pub json: bool,
+    /// Placement of every image XObject
+    pub images: bool,
+    /// Tables detected from aligned columns of text
+    pub tables: bool,
+    /// The document's bookmarks
+    pub outline: bool,
+}
+
+/// Results of `extract_all`; each field is `Some` exactly when its target was requested
+#[derive(Debug, Clone, Default)]
+pub struct Extracted {
+    pub text: Option<String>,
+    pub html: Option<String>,
+    pub json: Option<String>,
+    pub images: Option<Vec<images::PlacedImage>>,
+    pub tables: Option<Vec<tables::Table>>,
+    pub outline: Option<Vec<outline::OutlineItem>>,
+}
+
+/// Produce every requested output of `doc` from a single pass over its content streams
+pub fn extract_all(doc: &Document, targets: ExtractTargets) -> PdfResult<Extracted> {
+    let into_string = |buf: Vec<u8>| String::from_utf8(buf).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()));
+    let (mut text, mut html) = (Vec::new(), Vec::new());
+    let mut text_output = targets.text.then(|| PlainTextOutput::new(&mut text));
+    let mut html_output = targets.html.then(|| HTMLOutput::new(&mut html));
+    let mut collector = (targets.json || targets.tables).then(layout::TextCollector::new);
+    let mut image_collector = targets.images.then(|| images::ImageCollector::new(doc));
+    {
+        let mut mux = TeeOutput::new();
+        if let Some(output) = &mut text_output {
+            mux.push(output);
+        }
+        if let Some(output) = &mut html_output {
+            mux.push(output);
+        }
+        if let Some(output) = &mut collector {
+            mux.push(output);
+        }
+        if let Some(output) = &mut image_collector {
+            mux.push(output);
+        }
+        if !mux.is_empty() {
+            output_doc(doc, &mut mux)?;
+        }
+    }
+
+    let pages = collector.map(layout::TextCollector::into_pages).unwrap_or_default();
+    let json = if targets.json {
+        let mut out = Vec::new();
+        layout::write_json_lines(&pages, &mut out)?;
+        Some(into_string(out)?)
+    } else {
+        None
+    };
+    Ok(Extracted {
+        text: targets.text.then(|| into_string(text)).transpose()?,
+        html: targets.html.then(|| into_string(html)).transpose()?,
+        json,
+        images: image_collector.map(images::ImageCollector::into_images),
+        tables: targets.tables.then(|| pages.iter().flat_map(tables::detect_tables).collect()),
+        outline: targets.outline.then(|| outline::extract_outline(doc)).transpose()?,
+    })
 }
 
 pub fn output_doc_page(doc: &Document, output: &mut dyn OutputDev, page_num: u32) -> PdfResult<()> {
-    if doc.is_encrypted() {
-        error!("Encrypted documents must be decrypted with a password");
-    }
-    let empty_resources = Dictionary::new();
-    let pages = doc.get_pages();
-    let object_id = pages.get(&page_num)
-        .ok_or_else(|| PdfError::InvalidStructure(format!("Page {} not found", page_num)))?;
-    let mut p = Processor::new();
-    output_doc_inner(page_num, *object_id, doc, &mut p, output, &empty_resources)?;
-    Ok(())
+    output_doc_page_with_options(doc, output, page_num, &ExtractionOptions::default())
+}
+
+pub fn output_doc_page_with_options(
+    doc: &Document,
+    output: &mut dyn OutputDev,
+    page_num: u32,
+    options: &ExtractionOptions,
+) -> PdfResult<()> {
+    output_doc_selected(doc, output, &[page_num], options).map(|_| ())
 }
 
 fn output_doc_inner<'a>(
@@ -1647,40 +3551,76 @@ fn output_doc_inner<'a>(
     output: &mut dyn OutputDev,
     empty_resources: &'a Dictionary,
 ) -> PdfResult<()> {
+    p.options.check_cancelled()?;
     let page_dict = doc.get_object(object_id)?
         .as_dict()
         .map_err(|_| PdfError::InvalidStructure("Page object must be dictionary".to_string()))?;
     
     let resources = get_inherited(doc, page_dict, b"Resources").unwrap_or(empty_resources);
-    let media_box: Vec<f64> = get_inherited(doc, page_dict, b"MediaBox")
-        .ok_or_else(|| PdfError::MissingField("MediaBox".to_string()))?;
+    let media_box: Vec<f64> = match get_inherited::<Vec<f64>>(doc, page_dict, b"MediaBox") {
+        Some(media_box) if media_box.len() == 4 => media_box,
+        _ if p.options.strictness == Strictness::Lenient => {
+            report::warn(WarningKind::Recovered, format!("Page {} has no valid MediaBox, assuming US Letter", page_num));
+            vec![0., 0., 612., 792.]
+        }
+        None => return Err(PdfError::MissingField("MediaBox".to_string())),
+        Some(_) => return Err(PdfError::InvalidStructure("MediaBox must have 4 numbers".to_string())),
+    };
     
-    let media_box = MediaBox {
+    let mut media_box = MediaBox {
         llx: media_box[0],
         lly: media_box[1],
         urx: media_box[2],
         ury: media_box[3],
     };
+    if p.options.page_box == PageBox::CropBox
+        && let Some(crop_box) = get_inherited::<Vec<f64>>(doc, page_dict, b"CropBox").filter(|b| b.len() == 4)
+    {
+        media_box = MediaBox {
+            llx: crop_box[0].max(media_box.llx),
+            lly: crop_box[1].max(media_box.lly),
+            urx: crop_box[2].min(media_box.urx),
+            ury: crop_box[3].min(media_box.ury),
+        };
+    }
     
     let art_box = get::<Option<Vec<f64>>>(doc, page_dict, b"ArtBox")?
         .map(|x| (x[0], x[1], x[2], x[3]));
     
     output.begin_page(page_num, &media_box, art_box)?;
-    p.process_stream(doc, doc.get_page_content(object_id)?, resources, &media_box, output, page_num)?;
+    p.begin_page(doc, page_num, object_id);
+    p.page_clip = p.options.clip_to_page_box.then_some(media_box);
+    p.output_clip = Rc::new(Vec::new());
+    match doc.get_page_contents(object_id).as_slice() {
+        &[id] if doc.get_object(id).and_then(Object::as_stream).is_ok() => {
+            if let Some(content) = p.cached_content(doc, id)? {
+                p.process_content(doc, &content, resources, &media_box, output, GraphicsState::new())?;
+            }
+        }
+        _ => p.process_stream(doc, &get_page_content(doc, object_id)?, resources, &media_box, output)?,
+    }
+    if p.options.include_free_text {
+        for annot in annotations::free_text_appearances(doc, page_dict) {
+            p.process_free_text(doc, &annot, resources, &media_box, output)?;
+        }
+    }
     output.end_page()?;
     Ok(())
 }
 
 fn get_inherited<'a, T: FromObj<'a>>(doc: &'a Document, dict: &'a Dictionary, key: &[u8]) -> Option<T> {
-    let o: Option<T> = get(doc, dict, key).ok();
-    if let Some(o) = o {
-        Some(o)
-    } else {
+    let max_depth = limits::current().max_parent_depth;
+    let mut dict = dict;
+    for _ in 0..=max_depth {
+        if let Ok(o) = get(doc, dict, key) {
+            return Some(o);
+        }
         let parent = dict.get(b"Parent").ok()?
             .as_reference().ok()?;
-        let parent_dict = doc.get_dictionary(parent).ok()?;
-        get_inherited(doc, parent_dict, key)
+        dict = doc.get_dictionary(parent).ok()?;
     }
+    report::warn(WarningKind::Recovered, format!("More than {} Parent links, giving up on inherited {}", max_depth, String::from_utf8_lossy(key)));
+    None
 }
 
 // Graphics state
@@ -1693,7 +3633,28 @@ struct TextState {
     horizontal_scaling: f64,
     leading: f64,
     rise: f64,
+    render_mode: i64,
     tm: PdfTransform,
+    // Code of the glyph shown last, which the next one is kerned against until the text
+    // position is set or the font changes
+    last_code: Option<CharCode>,
+}
+
+impl TextState {
+    fn new() -> TextState {
+        TextState {
+            font: None,
+            font_size: f64::NAN,
+            character_spacing: 0.,
+            word_spacing: 0.,
+            horizontal_scaling: 1.0,
+            leading: 0.,
+            rise: 0.,
+            render_mode: 0,
+            tm: Transform2D::identity(),
+            last_code: None,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -1703,349 +3664,1082 @@ struct GraphicsState {
     smask: Option<Dictionary>,
     fill_colorspace: ColorSpace,
     fill_color: Vec<f64>,
+    // Name of the pattern resource filled with in the Pattern colour space
+    fill_pattern: Option<Vec<u8>>,
+    // Space of the `[/Pattern base]` fill colour space that uncoloured patterns take their
+    // colour in, from the components before the pattern name
+    fill_pattern_space: Option<ColorSpace>,
     stroke_colorspace: ColorSpace,
     stroke_color: Vec<f64>,
-    line_width: f64,
+    // Name and base space of the pattern stroked with, as for the fill
+    stroke_pattern: Option<Vec<u8>>,
+    stroke_pattern_space: Option<ColorSpace>,
+    fill_alpha: f64,
+    stroke_alpha: f64,
+    line: LineStyle,
+    // Paths the clipping region is the intersection of, shared between saved states
+    clip: Rc<Vec<ClipPath>>,
 }
 
+impl GraphicsState {
+    // The state at the start of a page
+    fn new() -> GraphicsState {
+        GraphicsState {
+            ts: TextState::new(),
+            fill_color: vec![0.],
+            fill_pattern: None,
+            fill_pattern_space: None,
+            fill_colorspace: ColorSpace::DeviceGray,
+            stroke_color: vec![0.],
+            stroke_colorspace: ColorSpace::DeviceGray,
+            stroke_pattern: None,
+            stroke_pattern_space: None,
+            fill_alpha: 1.,
+            stroke_alpha: 1.,
+            line: LineStyle::default(),
+            clip: Rc::new(Vec::new()),
+            ctm: Transform2D::identity(),
+            smask: None,
+        }
+    }
+}
+
+// A pattern resource paths are filled with, and its matrix
+enum FillPattern<'a> {
+    Shading(PdfTransform, shading::Shading),
+    Tiling(PdfTransform, TilingPattern, &'a Stream),
+}
+
+// The pattern `name`, `None` for unknown pattern types and unsupported shadings
+fn fill_pattern<'a>(doc: &'a Document, resources: &'a Dictionary, name: &[u8]) -> PdfResult<Option<FillPattern<'a>>> {
+    let patterns: &Dictionary = get(doc, resources, b"Pattern")?;
+    let pattern: &Object = get(doc, patterns, name)?;
+    let dict = match pattern {
+        Object::Stream(stream) => &stream.dict,
+        o => o.as_dict().map_err(|_| PdfError::InvalidStructure("Pattern must be a dictionary or stream".to_string()))?,
+    };
+    let matrix = match get::<Option<Vec<f64>>>(doc, dict, b"Matrix")?.as_deref() {
+        Some(&[a, b, c, d, e, f]) => Transform2D::new(a, b, c, d, e, f),
+        _ => Transform2D::identity(),
+    };
+    match (get::<i64>(doc, dict, b"PatternType")?, pattern) {
+        (1, Object::Stream(stream)) => {
+            let [x0, y0, x1, y1] = get::<[f64; 4]>(doc, dict, b"BBox")?;
+            let (x_step, y_step) = (get::<f64>(doc, dict, b"XStep")?, get::<f64>(doc, dict, b"YStep")?);
+            if x_step == 0. || y_step == 0. {
+                return Err(PdfError::InvalidStructure("Tiling pattern steps must not be zero".to_string()));
+            }
+            let tiling = TilingPattern {
+                bbox: [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)],
+                x_step,
+                y_step,
+                coloured: get::<i64>(doc, dict, b"PaintType")? == 1,
+            };
+            Ok(Some(FillPattern::Tiling(matrix, tiling, stream)))
+        }
+        (1, _) => Err(PdfError::InvalidStructure("Tiling pattern must be a stream".to_string())),
+        (2, _) => {
+            let shading: &Object = get(doc, dict, b"Shading")?;
+            Ok(shading::parse_shading(doc, shading, resources)?.map(|shading| FillPattern::Shading(matrix, shading)))
+        }
+        _ => Ok(None),
+    }
+}
+
+// Image masks are painted in the fill colour, which devices otherwise only learn for text
+fn set_mask_color(gs: &GraphicsState, image: &Stream, output: &mut dyn OutputDev) -> PdfResult<()> {
+    if image.dict.get(b"ImageMask").and_then(Object::as_bool).is_ok_and(|mask| mask) {
+        output.set_fill_color(&gs.fill_colorspace, &gs.fill_color, gs.fill_alpha)?;
+    }
+    Ok(())
+}
+
+// Finish the path after painting it, intersecting the clipping region with it if `W` or `W*`
+// preceded the painting operator
+fn end_path(gs: &mut GraphicsState, path: &mut Path, pending_clip: &mut Option<FillRule>) {
+    let path = std::mem::replace(path, Path::new());
+    if let Some(rule) = pending_clip.take() {
+        Rc::make_mut(&mut gs.clip).push(ClipPath::new(gs.ctm, path, rule));
+    }
+}
+
+// Nesting limit for Type3 glyph procedures that show text in Type3 fonts
+const MAX_GLYPH_PROCEDURE_DEPTH: usize = 8;
+
+// Decoded content streams kept at once; the least recently used is dropped beyond this
+const CONTENT_CACHE_SIZE: usize = 64;
+
 // Processor for handling PDF content streams
 struct Processor<'a> {
-    _phantom: PhantomData<&'a ()>,
+    options: &'a ExtractionOptions,
+    pua: pua::PuaRegistry,
+    glyph_procedure_depth: usize,
+    form_depth: usize,
+    profile: Option<profile::Profile>,
+    page_num: u32,
+    page_id: ObjectId,
+    page_glyphs: usize,
+    page_operators: usize,
+    truncated_pages: Vec<u32>,
+    languages: Option<language::Languages>,
+    // Languages of the open marked-content sequences, `None` for those without one
+    lang_stack: Vec<Option<Arc<str>>>,
+    // Decoded content of forms and single-stream pages by stream object, for those drawn again
+    // on later pages or several times on one, with the use at which each was last drawn
+    content_cache: HashMap<ObjectId, (Rc<Content>, u64)>,
+    content_uses: u64,
+    // Fonts by font dictionary object, loaded once per extraction; their decoding warnings are
+    // reported on the first page using them
+    font_cache: HashMap<ObjectId, Arc<dyn PdfFont>>,
+    // Colour spaces set by `cs` and `CS` by colour space object, or by ICC profile stream for
+    // inline `[/ICCBased ...]` arrays, so profiles are decoded once per extraction
+    colorspace_cache: HashMap<ObjectId, ColorSpace>,
+    // Box outside which characters of the current page are dropped
+    page_clip: Option<MediaBox>,
+    // Clipping region last passed to the output device
+    output_clip: Rc<Vec<ClipPath>>,
 }
 
 impl<'a> Processor<'a> {
-    fn new() -> Self {
-        Processor { _phantom: PhantomData }
+    fn new(options: &'a ExtractionOptions) -> Self {
+        Processor {
+            options,
+            pua: pua::PuaRegistry::default(),
+            glyph_procedure_depth: 0,
+            form_depth: 0,
+            profile: options.profile.then(profile::Profile::default),
+            page_num: 0,
+            page_id: (0, 0),
+            page_glyphs: 0,
+            page_operators: 0,
+            truncated_pages: Vec::new(),
+            languages: None,
+            lang_stack: Vec::new(),
+            content_cache: HashMap::new(),
+            content_uses: 0,
+            font_cache: HashMap::new(),
+            colorspace_cache: HashMap::new(),
+            page_clip: None,
+            output_clip: Rc::new(Vec::new()),
+        }
+    }
+
+    fn diagnostics(&self, report: report::ExtractionReport) -> Diagnostics {
+        Diagnostics {
+            report,
+            pua_fonts: self.pua.stats(),
+            profile: self.profile.clone(),
+            truncated_pages: self.truncated_pages.clone(),
+        }
+    }
+
+    fn begin_page(&mut self, doc: &Document, page_num: u32, page_id: ObjectId) {
+        report::set_page(page_num);
+        self.page_num = page_num;
+        self.page_id = page_id;
+        self.page_glyphs = 0;
+        self.page_operators = 0;
+        self.lang_stack.clear();
+        if self.languages.is_none() {
+            self.languages = Some(language::Languages::new(doc));
+        }
+    }
+
+    // Return `e` in strict mode, log it in lenient mode; output device errors, cancellation and
+    // exceeded limits are always returned
+    fn recover(&self, e: PdfError, context: &str) -> PdfResult<()> {
+        match (&e, self.options.strictness) {
+            (PdfError::Io(_) | PdfError::Format(_) | PdfError::Cancelled | PdfError::ResourceLimit(_), _) | (_, Strictness::Strict) => Err(e),
+            (_, Strictness::Lenient) => {
+                report::warn(WarningKind::Recovered, format!("Page {}: skipping {}, {}", self.page_num, context, e));
+                Ok(())
+            }
+        }
+    }
+
+    // The colour space `name` of `resources`; in lenient mode spaces that fail to load are
+    // replaced by DeviceGray
+    fn colorspace(&mut self, doc: &Document, name: &[u8], resources: &Dictionary) -> PdfResult<ColorSpace> {
+        let id = get::<&Dictionary>(doc, resources, b"ColorSpace").ok()
+            .and_then(|colorspaces| colorspaces.get(name).ok())
+            .and_then(|cs| match cs {
+                Object::Reference(id) => Some(*id),
+                Object::Array(cs) if cs.first().and_then(|f| f.as_name().ok()) == Some(b"ICCBased") => {
+                    cs.get(1).and_then(|stream| stream.as_reference().ok())
+                }
+                _ => None,
+            });
+        if let Some(cs) = id.and_then(|id| self.colorspace_cache.get(&id)) {
+            return Ok(cs.clone());
+        }
+        match make_colorspace(doc, name, resources) {
+            Ok(cs) => {
+                if let Some(id) = id {
+                    self.colorspace_cache.insert(id, cs.clone());
+                }
+                Ok(cs)
+            }
+            Err(e) => self.recover(e, "colour space").map(|_| ColorSpace::DeviceGray),
+        }
+    }
+
+    // The font `name` of `resources` with the configured metrics, overrides and PUA policy; in
+    // lenient mode fonts that fail to load are replaced by Helvetica
+    fn load_font(&mut self, doc: &Document, resources: &Dictionary, name: &[u8]) -> PdfResult<Arc<dyn PdfFont>> {
+        let id = get::<&Dictionary>(doc, resources, b"Font").ok()
+            .and_then(|fonts| fonts.get(name).and_then(Object::as_reference).ok());
+        if let Some(font) = id.and_then(|id| self.font_cache.get(&id)) {
+            return Ok(Arc::clone(font));
+        }
+        let font = self.load_font_uncached(doc, resources, name)?;
+        if let Some(id) = id {
+            self.font_cache.insert(id, Arc::clone(&font));
+        }
+        Ok(font)
+    }
+
+    fn load_font_uncached(&mut self, doc: &Document, resources: &Dictionary, name: &[u8]) -> PdfResult<Arc<dyn PdfFont>> {
+        let loaded = get::<&Dictionary>(doc, resources, b"Font")
+            .and_then(|fonts| get::<&Dictionary>(doc, fonts, name))
+            .and_then(|font_dict| Ok((font_dict, make_font(doc, font_dict)?)));
+        let (font_dict, font) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) if self.options.strictness == Strictness::Lenient && !matches!(e, PdfError::ResourceLimit(_)) => {
+                let name = String::from_utf8_lossy(name);
+                report::warn(WarningKind::FontFallback, format!("Page {}: substituting Helvetica for font {}, {}", self.page_num, name, e));
+                let fallback = annotations::fallback_resources();
+                let fallback: &Dictionary = get(doc, &fallback, b"Font").and_then(|fonts| get(doc, fonts, b"F1"))?;
+                return make_font(doc, fallback);
+            }
+            Err(e) => return Err(e),
+        };
+        let base_font = get_name_string(doc, font_dict, b"BaseFont").ok();
+        let kerning_applies = base_font.as_deref().is_some_and(is_core_font) && !fonts::has_font_file(doc, font_dict);
+        let font = afm::apply(&self.options.core_font_metrics, font, kerning_applies);
+        let font = overrides::apply(&self.options.glyph_overrides, font, base_font.as_deref());
+        Ok(pua::apply(&self.options.pua_policy, &mut self.pua, font, font_dict.has(b"ToUnicode")))
+    }
+
+    // Language of the innermost marked content with one, else of the document
+    fn language(&self) -> Option<Arc<str>> {
+        self.lang_stack.iter().rev().find_map(Clone::clone)
+            .or_else(|| self.languages.as_ref()?.default())
+    }
+
+    fn text_options(&self) -> TextOptions {
+        TextOptions {
+            glyph_image_fallback: self.options.glyph_image_fallback,
+            page_clip: self.page_clip,
+            clip_paths: self.options.clip_to_clip_paths,
+            invisible_text: self.options.invisible_text,
+        }
+    }
+
+    // Glyphs the page may still show, or `None` once its cap is reached
+    fn glyph_limit(&mut self) -> Option<usize> {
+        let Some(max) = self.options.max_glyphs_per_page else {
+            return Some(usize::MAX);
+        };
+        let remaining = max.saturating_sub(self.page_glyphs);
+        if remaining == 0 {
+            self.truncate_page();
+            return None;
+        }
+        Some(remaining)
+    }
+
+    fn count_glyphs(&mut self, (shown, complete): (usize, bool)) {
+        self.page_glyphs += shown;
+        if !complete {
+            self.truncate_page();
+        }
+    }
+
+    fn truncate_page(&mut self) {
+        if self.truncated_pages.last() != Some(&self.page_num) {
+            let message = format!("Page {} has more than {:?} glyphs, skipping the rest of its text", self.page_num, self.options.max_glyphs_per_page);
+            report::warn(WarningKind::Truncated, message);
+            self.truncated_pages.push(self.page_num);
+        }
     }
     
     fn process_stream(
         &mut self,
-        doc: &'a Document,
-        content: Vec<u8>,
-        resources: &'a Dictionary,
+        doc: &Document,
+        content: &[u8],
+        resources: &Dictionary,
         media_box: &MediaBox,
         output: &mut dyn OutputDev,
-        page_num: u32,
     ) -> PdfResult<()> {
-        let content = Content::decode(&content)
-            .map_err(|e| PdfError::InvalidStructure(format!("Failed to decode content: {:?}", e)))?;
-        
-        let mut font_table = HashMap::new();
-        let mut gs = GraphicsState {
-            ts: TextState {
-                font: None,
-                font_size: std::f64::NAN,
-                character_spacing: 0.,
-                word_spacing: 0.,
-                horizontal_scaling: 1.0,
-                leading: 0.,
-                rise: 0.,
-                tm: Transform2D::identity(),
-            },
-            fill_color: Vec::new(),
-            fill_colorspace: ColorSpace::DeviceGray,
-            stroke_color: Vec::new(),
-            stroke_colorspace: ColorSpace::DeviceGray,
-            line_width: 1.,
-            ctm: Transform2D::identity(),
-            smask: None,
+        self.process_stream_with_state(doc, content, resources, media_box, output, GraphicsState::new())
+    }
+
+    // Draw a FreeText annotation's appearance stream, or lay out its contents without one
+    fn process_free_text(
+        &mut self,
+        doc: &Document,
+        annot: &annotations::FreeTextAppearance<'_>,
+        page_resources: &Dictionary,
+        media_box: &MediaBox,
+        output: &mut dyn OutputDev,
+    ) -> PdfResult<()> {
+        let mut gs = GraphicsState::new();
+        let fallback_resources = annotations::fallback_resources();
+        let (content, resources) = match annot.appearance {
+            Some((stream, ctm)) => {
+                gs.ctm = ctm;
+                let resources = object_utils::maybe_get_obj(doc, &stream.dict, b"Resources")
+                    .and_then(|o| o.as_dict().ok())
+                    .unwrap_or(page_resources);
+                (get_contents(stream)?, resources)
+            }
+            None => (Cow::Owned(annot.fallback_content()), &fallback_resources),
         };
-        
+        self.process_stream_with_state(doc, &content, resources, media_box, output, gs)
+    }
+
+    // Execute a Type3 glyph procedure; the ctm of `gs` maps glyph space to device space
+    fn render_glyph_procedure(
+        &mut self,
+        doc: &Document,
+        page_resources: &Dictionary,
+        media_box: &MediaBox,
+        procedure: GlyphProcedure<'_>,
+        gs: GraphicsState,
+        output: &mut dyn OutputDev,
+    ) -> PdfResult<()> {
+        if self.glyph_procedure_depth >= MAX_GLYPH_PROCEDURE_DEPTH {
+            report::warn(WarningKind::Recovered, "Type3 glyph procedures nested too deeply, skipping glyph".to_string());
+            return Ok(());
+        }
+        let resources = procedure.resources.unwrap_or(page_resources);
+        self.glyph_procedure_depth += 1;
+        let result = self.process_stream_with_state(doc, procedure.content, resources, media_box, output, gs);
+        self.glyph_procedure_depth -= 1;
+        result
+    }
+
+    fn process_stream_with_state(
+        &mut self,
+        doc: &Document,
+        content: &[u8],
+        resources: &Dictionary,
+        media_box: &MediaBox,
+        output: &mut dyn OutputDev,
+        gs: GraphicsState,
+    ) -> PdfResult<()> {
+        match self.decode_content(content)? {
+            Some(content) => self.process_content(doc, &content, resources, media_box, output, gs),
+            None => Ok(()),
+        }
+    }
+
+    // Operations of a content stream, or `None` for one that doesn't parse and so draws nothing
+    fn decode_content(&self, content: &[u8]) -> PdfResult<Option<Content>> {
+        match Content::decode(content) {
+            Ok(mut content) => {
+                normalize_glyph_metrics(&mut content.operations);
+                Ok(Some(content))
+            }
+            Err(e) => {
+                let e = PdfError::InvalidStructure(format!("Failed to decode content: {:?}", e));
+                self.recover(e, "content stream").map(|_| None)
+            }
+        }
+    }
+
+    // `decode_content` of the stream object `id`, decoded again only once it has dropped out of
+    // the `CONTENT_CACHE_SIZE` most recently drawn streams
+    fn cached_content(&mut self, doc: &Document, id: ObjectId) -> PdfResult<Option<Rc<Content>>> {
+        self.content_uses += 1;
+        if let Some((content, last_use)) = self.content_cache.get_mut(&id) {
+            *last_use = self.content_uses;
+            return Ok(Some(content.clone()));
+        }
+        let stream = doc.get_object(id).and_then(Object::as_stream)?;
+        let Some(content) = self.decode_content(&get_contents(stream)?)? else { return Ok(None) };
+        let content = Rc::new(content);
+        if self.content_cache.len() >= CONTENT_CACHE_SIZE
+            && let Some(oldest) = self.content_cache.iter().min_by_key(|(_, (_, last_use))| *last_use).map(|(id, _)| *id)
+        {
+            self.content_cache.remove(&oldest);
+        }
+        self.content_cache.insert(id, (content.clone(), self.content_uses));
+        Ok(Some(content))
+    }
+
+    // Content of the stream `name` in the resource dictionary `dict`
+    fn resource_content(&mut self, doc: &Document, dict: &Dictionary, name: &[u8], stream: &Stream) -> PdfResult<Option<Rc<Content>>> {
+        match dict.get(name) {
+            Ok(Object::Reference(id)) => self.cached_content(doc, *id),
+            _ => Ok(self.decode_content(&get_contents(stream)?)?.map(Rc::new)),
+        }
+    }
+
+    // Fill `path` with the fill colour, or with the fill pattern clipped to the path
+    #[allow(clippy::too_many_arguments)]
+    fn fill_path(
+        &mut self,
+        doc: &Document,
+        resources: &Dictionary,
+        media_box: &MediaBox,
+        gs: &GraphicsState,
+        path: &Path,
+        rule: FillRule,
+        pattern_ctm: &PdfTransform,
+        output: &mut dyn OutputDev,
+    ) -> PdfResult<()> {
+        let pattern = match (&gs.fill_colorspace, &gs.fill_pattern) {
+            (ColorSpace::Pattern, Some(name)) => fill_pattern(doc, resources, name)?.map(|pattern| (name, pattern)),
+            _ => None,
+        };
+        let Some((name, pattern)) = pattern else {
+            return output.fill(&gs.ctm, &gs.fill_colorspace, &gs.fill_color, path, rule);
+        };
+        let mut clip = (*gs.clip).clone();
+        clip.push(ClipPath::new(gs.ctm, path.clone(), rule));
+        output.set_clip(&clip)?;
+        let result = match pattern {
+            FillPattern::Shading(matrix, shading) => output.shade(&matrix.then(pattern_ctm), &shading),
+            FillPattern::Tiling(matrix, tiling, stream) => {
+                let color = match (tiling.coloured, &gs.fill_pattern_space) {
+                    (false, Some(colorspace)) => Some((colorspace, gs.fill_color.as_slice())),
+                    _ => None,
+                };
+                self.paint_cell(doc, resources, media_box, name, &matrix.then(pattern_ctm), &tiling, color, stream, clip, output)
+            }
+        };
+        output.set_clip(&gs.clip)?;
+        self.output_clip = gs.clip.clone();
+        result
+    }
+
+    // Run the content of a tiling pattern's cell once in pattern space `ctm`, clipped to `clip`
+    // and the cell's BBox, painting in `color` for uncoloured patterns; repeating it is left to
+    // the device, so text in it is seen once
+    #[allow(clippy::too_many_arguments)]
+    fn paint_cell(
+        &mut self,
+        doc: &Document,
+        resources: &Dictionary,
+        media_box: &MediaBox,
+        name: &[u8],
+        ctm: &PdfTransform,
+        pattern: &TilingPattern,
+        color: Option<(&ColorSpace, &[f64])>,
+        stream: &Stream,
+        clip: Vec<ClipPath>,
+        output: &mut dyn OutputDev,
+    ) -> PdfResult<()> {
+        if self.form_depth >= self.options.limits.max_form_depth {
+            report::warn(WarningKind::Recovered, format!("Patterns nested more than {} deep, skipping {}", self.form_depth, String::from_utf8_lossy(name)));
+            return Ok(());
+        }
+        let patterns: &Dictionary = get(doc, resources, b"Pattern")?;
+        let Some(content) = self.resource_content(doc, patterns, name, stream)? else {
+            return Ok(());
+        };
+        let cell_resources = object_utils::maybe_get_obj(doc, &stream.dict, b"Resources")
+            .and_then(|n| n.as_dict().ok())
+            .unwrap_or(resources);
+        // Cells start from the initial graphics state, not the one they are filled in
+        let mut cell_gs = GraphicsState::new();
+        cell_gs.ctm = *ctm;
+        if let Some((colorspace, color)) = color {
+            (cell_gs.fill_colorspace, cell_gs.fill_color) = (colorspace.clone(), color.to_vec());
+            (cell_gs.stroke_colorspace, cell_gs.stroke_color) = (colorspace.clone(), color.to_vec());
+        }
+        let [x0, y0, x1, y1] = pattern.bbox;
+        let mut cell_clip = clip.clone();
+        cell_clip.push(ClipPath::new(*ctm, Path { ops: vec![PathOp::Rect(x0, y0, x1 - x0, y1 - y0)] }, FillRule::NonZero));
+        cell_gs.clip = Rc::new(cell_clip);
+        self.output_clip = Rc::new(clip);
+        output.begin_pattern(ctm, pattern)?;
+        self.form_depth += 1;
+        let result = self.process_content(doc, &content, cell_resources, media_box, output, cell_gs);
+        self.form_depth -= 1;
+        output.end_pattern()?;
+        result
+    }
+
+    fn process_content(
+        &mut self,
+        doc: &Document,
+        content: &Content,
+        resources: &Dictionary,
+        media_box: &MediaBox,
+        output: &mut dyn OutputDev,
+        mut gs: GraphicsState,
+    ) -> PdfResult<()> {
+        let mut font_table = HashMap::new();
         let mut gs_stack = Vec::new();
         let mut mc_stack = Vec::new();
+        // Marked content doesn't continue past the end of its stream
+        let lang_depth = self.lang_stack.len();
         let mut tlm = Transform2D::identity();
         let mut path = Path::new();
-        let flip_ctm = Transform2D::new(1., 0., 0., -1., 0., media_box.ury - media_box.lly);
+        // Set by `W` and `W*` until the path is painted, with the rule it clips by
+        let mut pending_clip = None;
+        // Patterns are placed relative to the space the stream starts in
+        let pattern_ctm = gs.ctm;
         
-        for operation in &content.operations {
-            match operation.operator.as_ref() {
-                "BT" => {
-                    tlm = Transform2D::identity();
-                    gs.ts.tm = tlm;
-                }
-                "ET" => {
-                    tlm = Transform2D::identity();
-                    gs.ts.tm = tlm;
-                }
-                "cm" => {
-                    if operation.operands.len() != 6 {
-                        return Err(PdfError::InvalidStructure("cm requires 6 operands".to_string()));
-                    }
-                    let m = Transform2D::new(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                        object_utils::as_num(&operation.operands[4])?,
-                        object_utils::as_num(&operation.operands[5])?,
-                    );
-                    gs.ctm = gs.ctm.then(&m);
-                }
-                "CS" => {
-                    let name = operation.operands[0].as_name()
-                        .map_err(|_| PdfError::InvalidStructure("CS requires name operand".to_string()))?;
-                    gs.stroke_colorspace = make_colorspace(doc, name, resources);
-                }
-                "cs" => {
-                    let name = operation.operands[0].as_name()
-                        .map_err(|_| PdfError::InvalidStructure("cs requires name operand".to_string()))?;
-                    gs.fill_colorspace = make_colorspace(doc, name, resources);
-                }
-                "SC" | "SCN" => {
-                    gs.stroke_color = match gs.stroke_colorspace {
-                        ColorSpace::Pattern => Vec::new(),
-                        _ => operation.operands.iter()
-                            .map(object_utils::as_num)
-                            .collect::<PdfResult<Vec<_>>>()?,
-                    };
-                }
-                "sc" | "scn" => {
-                    gs.fill_color = match gs.fill_colorspace {
-                        ColorSpace::Pattern => Vec::new(),
-                        _ => operation.operands.iter()
-                            .map(object_utils::as_num)
-                            .collect::<PdfResult<Vec<_>>>()?,
-                    };
+        for (i, operation) in content.operations.iter().enumerate() {
+            if i % CANCEL_CHECK_INTERVAL == CANCEL_CHECK_INTERVAL - 1 {
+                self.options.check_cancelled()?;
+            }
+            self.page_operators += 1;
+            if self.page_operators > self.options.limits.max_operators_per_page {
+                return Err(PdfError::ResourceLimit(format!("Page {} runs more than {} operators", self.page_num, self.options.limits.max_operators_per_page)));
+            }
+            let started = self.profile.is_some().then(Instant::now);
+            // Operators run in a closure so errors can be recovered from per operation
+            let result = (|| -> PdfResult<()> {
+                let needed = operand_count(&operation.operator);
+                if operation.operands.len() < needed {
+                    return Err(PdfError::InvalidStructure(format!("{} requires {} operands", operation.operator, needed)));
                 }
-                "TJ" => {
-                    if let Object::Array(array) = &operation.operands[0] {
-                        for e in array {
-                            match e {
-                                Object::String(s, _) => {
-                                    show_text(&mut gs, s, &tlm, &flip_ctm, output)?;
-                                }
-                                Object::Integer(i) => {
-                                    let ts = &mut gs.ts;
-                                    let w0 = 0.;
-                                    let tj = *i as f64;
-                                    let ty = 0.;
-                                    let tx = ts.horizontal_scaling * ((w0 - tj / 1000.) * ts.font_size);
-                                    ts.tm = ts.tm.then(&Transform2D::translation(tx, ty));
-                                }
-                                Object::Real(f) => {
-                                    let ts = &mut gs.ts;
-                                    let w0 = 0.;
-                                    let tj: f64 = (*f).into();
-                                    let ty = 0.;
-                                    let tx = ts.horizontal_scaling * ((w0 - tj / 1000.) * ts.font_size);
-                                    ts.tm = ts.tm.then(&Transform2D::translation(tx, ty));
+                match operation.operator.as_ref() {
+                    "BT" => {
+                        tlm = Transform2D::identity();
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.begin_text_object()?;
+                    }
+                    "ET" => {
+                        tlm = Transform2D::identity();
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.end_text_object()?;
+                    }
+                    "cm" => {
+                        if operation.operands.len() != 6 {
+                            return Err(PdfError::InvalidStructure("cm requires 6 operands".to_string()));
+                        }
+                        let m = Transform2D::new(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                            object_utils::as_num(&operation.operands[4])?,
+                            object_utils::as_num(&operation.operands[5])?,
+                        );
+                        gs.ctm = m.then(&gs.ctm);
+                    }
+                    "CS" => {
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("CS requires name operand".to_string()))?;
+                        gs.stroke_colorspace = self.colorspace(doc, name, resources)?;
+                        gs.stroke_pattern = None;
+                        gs.stroke_pattern_space = match gs.stroke_colorspace {
+                            ColorSpace::Pattern => pattern_base(doc, name, resources)
+                                .or_else(|e| self.recover(e, "colour space").map(|_| None))?,
+                            _ => None,
+                        };
+                    }
+                    "cs" => {
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("cs requires name operand".to_string()))?;
+                        gs.fill_colorspace = self.colorspace(doc, name, resources)?;
+                        gs.fill_pattern = None;
+                        gs.fill_pattern_space = match gs.fill_colorspace {
+                            ColorSpace::Pattern => pattern_base(doc, name, resources)
+                                .or_else(|e| self.recover(e, "colour space").map(|_| None))?,
+                            _ => None,
+                        };
+                    }
+                    "SC" | "SCN" => {
+                        let color = match gs.stroke_colorspace {
+                            ColorSpace::Pattern => {
+                                let (name, components) = operation.operands.split_last()
+                                    .ok_or_else(|| PdfError::InvalidStructure("SCN requires a pattern name".to_string()))?;
+                                gs.stroke_pattern = name.as_name().ok().map(<[u8]>::to_vec);
+                                components.iter().map(object_utils::as_num).collect::<PdfResult<Vec<_>>>()?
+                            }
+                            _ => operation.operands.iter()
+                                .map(object_utils::as_num)
+                                .collect::<PdfResult<Vec<_>>>()?,
+                        };
+                        check_components(&gs.stroke_colorspace, &color, &operation.operator)?;
+                        gs.stroke_color = color;
+                    }
+                    "sc" | "scn" => {
+                        let color = match gs.fill_colorspace {
+                            ColorSpace::Pattern => {
+                                let (name, components) = operation.operands.split_last()
+                                    .ok_or_else(|| PdfError::InvalidStructure("scn requires a pattern name".to_string()))?;
+                                gs.fill_pattern = name.as_name().ok().map(<[u8]>::to_vec);
+                                // Components only colour uncoloured patterns
+                                components.iter().map(object_utils::as_num).collect::<PdfResult<Vec<_>>>()?
+                            }
+                            _ => operation.operands.iter()
+                                .map(object_utils::as_num)
+                                .collect::<PdfResult<Vec<_>>>()?,
+                        };
+                        check_components(&gs.fill_colorspace, &color, &operation.operator)?;
+                        gs.fill_color = color;
+                    }
+                    "TJ" => {
+                        output.set_language(self.language().as_deref())?;
+                        if let Object::Array(array) = &operation.operands[0] {
+                            for e in array {
+                                match e {
+                                    Object::String(s, _) => {
+                                        if let Some(limit) = self.glyph_limit() {
+                                            let options = self.text_options();
+                                            let shown = show_text(&mut gs, s, limit, output, options, &mut |procedure, gs, output| {
+                                                self.render_glyph_procedure(doc, resources, media_box, procedure, gs, output)
+                                            })?;
+                                            self.count_glyphs(shown);
+                                        }
+                                    }
+                                    Object::Integer(_) | Object::Real(_) => {
+                                        let ts = &mut gs.ts;
+                                        let tj = object_utils::as_num(e)?;
+                                        let tx = -tj / 1000. * ts.font_size * ts.horizontal_scaling;
+                                        ts.tm = Transform2D::translation(tx, 0.).then(&ts.tm);
+                                        output.text_adjustment(tj, tx)?;
+                                    }
+                                    _ => {}
                                 }
-                                _ => {}
                             }
                         }
+                        output.end_text_array()?;
                     }
-                }
-                "Tj" => {
-                    if let Object::String(s, _) = &operation.operands[0] {
-                        show_text(&mut gs, s, &tlm, &flip_ctm, output)?;
+                    "Tj" => {
+                        output.set_language(self.language().as_deref())?;
+                        if let (Object::String(s, _), Some(limit)) = (&operation.operands[0], self.glyph_limit()) {
+                            let options = self.text_options();
+                            let shown = show_text(&mut gs, s, limit, output, options, &mut |procedure, gs, output| {
+                                self.render_glyph_procedure(doc, resources, media_box, procedure, gs, output)
+                            })?;
+                            self.count_glyphs(shown);
+                        }
                     }
-                }
-                "Tc" => {
-                    gs.ts.character_spacing = object_utils::as_num(&operation.operands[0])?;
-                }
-                "Tw" => {
-                    gs.ts.word_spacing = object_utils::as_num(&operation.operands[0])?;
-                }
-                "Tz" => {
-                    gs.ts.horizontal_scaling = object_utils::as_num(&operation.operands[0])? / 100.;
-                }
-                "TL" => {
-                    gs.ts.leading = object_utils::as_num(&operation.operands[0])?;
-                }
-                "Tf" => {
-                    let fonts: &Dictionary = get(doc, resources, b"Font")?;
-                    let name = operation.operands[0].as_name()
-                        .map_err(|_| PdfError::InvalidStructure("Tf requires name operand".to_string()))?;
-                    let font = font_table.entry(name.to_owned())
-                        .or_insert_with(|| make_font(doc, get::<&Dictionary>(doc, fonts, name).unwrap()).unwrap())
-                        .clone();
-                    gs.ts.font = Some(font);
-                    gs.ts.font_size = object_utils::as_num(&operation.operands[1])?;
-                }
-                "Ts" => {
-                    gs.ts.rise = object_utils::as_num(&operation.operands[0])?;
-                }
-                "Tm" => {
-                    if operation.operands.len() != 6 {
-                        return Err(PdfError::InvalidStructure("Tm requires 6 operands".to_string()));
-                    }
-                    tlm = Transform2D::new(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                        object_utils::as_num(&operation.operands[4])?,
-                        object_utils::as_num(&operation.operands[5])?,
-                    );
-                    gs.ts.tm = tlm;
-                    output.end_line()?;
-                }
-                "Td" => {
-                    if operation.operands.len() != 2 {
-                        return Err(PdfError::InvalidStructure("Td requires 2 operands".to_string()));
-                    }
-                    let tx = object_utils::as_num(&operation.operands[0])?;
-                    let ty = object_utils::as_num(&operation.operands[1])?;
-                    tlm = tlm.then(&Transform2D::translation(tx, ty));
-                    gs.ts.tm = tlm;
-                    output.end_line()?;
-                }
-                "TD" => {
-                    if operation.operands.len() != 2 {
-                        return Err(PdfError::InvalidStructure("TD requires 2 operands".to_string()));
-                    }
-                    let tx = object_utils::as_num(&operation.operands[0])?;
-                    let ty = object_utils::as_num(&operation.operands[1])?;
-                    gs.ts.leading = -ty;
-                    tlm = tlm.then(&Transform2D::translation(tx, ty));
-                    gs.ts.tm = tlm;
-                    output.end_line()?;
-                }
-                "T*" => {
-                    let tx = 0.0;
-                    let ty = -gs.ts.leading;
-                    tlm = tlm.then(&Transform2D::translation(tx, ty));
-                    gs.ts.tm = tlm;
-                    output.end_line()?;
-                }
-                "q" => {
-                    gs_stack.push(gs.clone());
-                }
-                "Q" => {
-                    if let Some(s) = gs_stack.pop() {
-                        gs = s;
-                    } else {
-                        warn!("No state to pop");
+                    "Tc" => {
+                        gs.ts.character_spacing = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "Tw" => {
+                        gs.ts.word_spacing = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "Tz" => {
+                        gs.ts.horizontal_scaling = object_utils::as_num(&operation.operands[0])? / 100.;
+                    }
+                    "TL" => {
+                        gs.ts.leading = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "Tf" => {
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("Tf requires name operand".to_string()))?;
+                        let font = match font_table.get(name) {
+                            Some(font) => Arc::clone(font),
+                            None => {
+                                let font = self.load_font(doc, resources, name)?;
+                                font_table.insert(name.to_owned(), Arc::clone(&font));
+                                font
+                            }
+                        };
+                        gs.ts.font = Some(font);
+                        gs.ts.last_code = None;
+                        gs.ts.font_size = object_utils::as_num(&operation.operands[1])?;
+                    }
+                    "Ts" => {
+                        gs.ts.rise = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "Tr" => {
+                        // Some writers emit the mode as a real such as `3.0`
+                        gs.ts.render_mode = operation.operands.first().and_then(|o| object_utils::as_num(o).ok())
+                            .map(|mode| mode as i64)
+                            .ok_or_else(|| PdfError::InvalidStructure("Tr requires a number operand".to_string()))?;
+                    }
+                    "Tm" => {
+                        if operation.operands.len() != 6 {
+                            return Err(PdfError::InvalidStructure("Tm requires 6 operands".to_string()));
+                        }
+                        tlm = Transform2D::new(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                            object_utils::as_num(&operation.operands[4])?,
+                            object_utils::as_num(&operation.operands[5])?,
+                        );
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.end_line()?;
+                    }
+                    "Td" => {
+                        if operation.operands.len() != 2 {
+                            return Err(PdfError::InvalidStructure("Td requires 2 operands".to_string()));
+                        }
+                        let tx = object_utils::as_num(&operation.operands[0])?;
+                        let ty = object_utils::as_num(&operation.operands[1])?;
+                        tlm = Transform2D::translation(tx, ty).then(&tlm);
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.end_line()?;
+                    }
+                    "TD" => {
+                        if operation.operands.len() != 2 {
+                            return Err(PdfError::InvalidStructure("TD requires 2 operands".to_string()));
+                        }
+                        let tx = object_utils::as_num(&operation.operands[0])?;
+                        let ty = object_utils::as_num(&operation.operands[1])?;
+                        gs.ts.leading = -ty;
+                        tlm = Transform2D::translation(tx, ty).then(&tlm);
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.end_line()?;
+                    }
+                    "T*" => {
+                        let tx = 0.0;
+                        let ty = -gs.ts.leading;
+                        tlm = Transform2D::translation(tx, ty).then(&tlm);
+                        gs.ts.tm = tlm;
+                        gs.ts.last_code = None;
+                        output.end_line()?;
+                    }
+                    "q" => {
+                        gs_stack.push(gs.clone());
+                    }
+                    "Q" => {
+                        if let Some(s) = gs_stack.pop() {
+                            gs = s;
+                        } else {
+                            warn!("No state to pop");
+                        }
+                    }
+                    "gs" => {
+                        let ext_gstate: &Dictionary = get(doc, resources, b"ExtGState")?;
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("gs requires name operand".to_string()))?;
+                        let state: &Dictionary = get(doc, ext_gstate, name)?;
+                        apply_state(doc, &mut gs, state)?;
+                    }
+                    "m" => {
+                        path.ops.push(PathOp::MoveTo(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                        ));
+                    }
+                    "l" => {
+                        path.ops.push(PathOp::LineTo(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                        ));
+                    }
+                    "c" => {
+                        path.ops.push(PathOp::CurveTo(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                            object_utils::as_num(&operation.operands[4])?,
+                            object_utils::as_num(&operation.operands[5])?,
+                        ));
+                    }
+                    "v" => {
+                        let (x, y) = path.current_point()
+                            .ok_or_else(|| PdfError::InvalidStructure("v requires a current point".to_string()))?;
+                        path.ops.push(PathOp::CurveTo(
+                            x,
+                            y,
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                        ));
+                    }
+                    "y" => {
+                        path.ops.push(PathOp::CurveTo(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                        ));
+                    }
+                    "h" => {
+                        path.ops.push(PathOp::Close);
+                    }
+                    "re" => {
+                        path.ops.push(PathOp::Rect(
+                            object_utils::as_num(&operation.operands[0])?,
+                            object_utils::as_num(&operation.operands[1])?,
+                            object_utils::as_num(&operation.operands[2])?,
+                            object_utils::as_num(&operation.operands[3])?,
+                        ));
+                    }
+                    "S" | "s" => {
+                        if operation.operator == "s" {
+                            path.ops.push(PathOp::Close);
+                        }
+                        output.set_line_style(&gs.line)?;
+                        stroke_path(doc, resources, &gs, &path, output)?;
+                        end_path(&mut gs, &mut path, &mut pending_clip);
+                    }
+                    "F" | "f" | "f*" => {
+                        let rule = if operation.operator == "f*" { FillRule::EvenOdd } else { FillRule::NonZero };
+                        self.fill_path(doc, resources, media_box, &gs, &path, rule, &pattern_ctm, output)?;
+                        end_path(&mut gs, &mut path, &mut pending_clip);
+                    }
+                    "B" | "B*" | "b" | "b*" => {
+                        if operation.operator.starts_with('b') {
+                            path.ops.push(PathOp::Close);
+                        }
+                        let rule = if operation.operator.ends_with('*') { FillRule::EvenOdd } else { FillRule::NonZero };
+                        self.fill_path(doc, resources, media_box, &gs, &path, rule, &pattern_ctm, output)?;
+                        output.set_line_style(&gs.line)?;
+                        stroke_path(doc, resources, &gs, &path, output)?;
+                        end_path(&mut gs, &mut path, &mut pending_clip);
+                    }
+                    "n" => {
+                        end_path(&mut gs, &mut path, &mut pending_clip);
+                    }
+                    "sh" => {
+                        let shadings: &Dictionary = get(doc, resources, b"Shading")?;
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("sh requires name operand".to_string()))?;
+                        let shading: &Object = get(doc, shadings, name)?;
+                        match shading::parse_shading(doc, shading, resources)? {
+                            Some(shading) => output.shade(&gs.ctm, &shading)?,
+                            None => debug!("Unsupported shading {:?}", operation),
+                        }
+                    }
+                    "BMC" | "BDC" => {
+                        let properties = match operation.operands.get(1).filter(|_| operation.operator == "BDC") {
+                            Some(properties) => marked_content_properties(doc, resources, properties),
+                            None => None,
+                        };
+                        let lang = match (&self.languages, properties) {
+                            (Some(languages), Some(properties)) => languages.marked_content(doc, self.page_id, properties),
+                            _ => None,
+                        };
+                        self.lang_stack.push(lang);
+                        mc_stack.push(operation);
+                        let tag = operation.operands.first().and_then(|o| o.as_name().ok()).unwrap_or_default();
+                        output.begin_marked_content(&String::from_utf8_lossy(tag), properties)?;
+                    }
+                    "EMC" => {
+                        if mc_stack.pop().is_some() {
+                            self.lang_stack.pop();
+                            output.end_marked_content()?;
+                        }
+                    }
+                    "Do" => {
+                        let xobject: &Dictionary = get(doc, resources, b"XObject")?;
+                        let name = operation.operands[0].as_name()
+                            .map_err(|_| PdfError::InvalidStructure("Do requires name operand".to_string()))?;
+                        let xf: &Stream = get(doc, xobject, name)?;
+                        if xf.dict.get(b"Subtype").and_then(|s| s.as_name()).ok() == Some(b"Image".as_slice()) {
+                            let resolved = images::resolve_color_space(doc, xf, resources)?;
+                            let image = resolved.as_ref().unwrap_or(xf);
+                            set_mask_color(&gs, image, output)?;
+                            output.draw_image(&gs.ctm, image)?;
+                        } else if self.form_depth >= self.options.limits.max_form_depth {
+                            report::warn(WarningKind::Recovered, format!("Forms nested more than {} deep, skipping {}", self.form_depth, String::from_utf8_lossy(name)));
+                        } else {
+                            let resources = object_utils::maybe_get_obj(doc, &xf.dict, b"Resources")
+                                .and_then(|n| n.as_dict().ok())
+                                .unwrap_or(resources);
+                            // Forms inherit the graphics state, with `/Matrix` mapping form space to user space
+                            let mut form_gs = gs.clone();
+                            if let Some(m) = get::<Option<Vec<f64>>>(doc, &xf.dict, b"Matrix")?.filter(|m| m.len() == 6) {
+                                form_gs.ctm = Transform2D::new(m[0], m[1], m[2], m[3], m[4], m[5]).then(&gs.ctm);
+                            }
+                            let content = self.resource_content(doc, xobject, name, xf)?;
+                            if let Some(content) = content {
+                                self.form_depth += 1;
+                                let result = self.process_content(doc, &content, resources, media_box, output, form_gs);
+                                self.form_depth -= 1;
+                                result?;
+                            }
+                        }
+                    }
+                    "BI" => {
+                        if let Some(Object::Stream(image)) = operation.operands.first() {
+                            let image = images::expand_inline_image(image);
+                            let image = images::resolve_color_space(doc, &image, resources)?.unwrap_or(image);
+                            set_mask_color(&gs, &image, output)?;
+                            output.draw_inline_image(&gs.ctm, &image)?;
+                        }
+                    }
+                    "w" => {
+                        gs.line.width = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "J" => {
+                        gs.line.cap = object_utils::as_num(&operation.operands[0])? as i64;
+                    }
+                    "j" => {
+                        gs.line.join = object_utils::as_num(&operation.operands[0])? as i64;
+                    }
+                    "M" => {
+                        gs.line.miter_limit = object_utils::as_num(&operation.operands[0])?;
+                    }
+                    "d" => {
+                        let dash = operation.operands[0].as_array()
+                            .map_err(|_| PdfError::InvalidStructure("d requires an array operand".to_string()))?;
+                        gs.line.dash = dash.iter().map(object_utils::as_num).collect::<PdfResult<_>>()?;
+                        gs.line.dash_phase = object_utils::as_num(&operation.operands[1])?;
+                    }
+                    "G" | "g" | "RG" | "rg" | "K" | "k" => {
+                        let (colorspace, components) = match operation.operator.as_str() {
+                            "G" | "g" => (ColorSpace::DeviceGray, 1),
+                            "RG" | "rg" => (ColorSpace::DeviceRGB, 3),
+                            _ => (ColorSpace::DeviceCMYK, 4),
+                        };
+                        // A wrong count leaves the colour as it was rather than half set
+                        if operation.operands.len() != components {
+                            return Err(PdfError::InvalidStructure(format!("{} requires {} operands", operation.operator, components)));
+                        }
+                        let color = operation.operands.iter()
+                            .map(object_utils::as_num)
+                            .collect::<PdfResult<Vec<_>>>()?;
+                        if operation.operator.starts_with(char::is_uppercase) {
+                            gs.stroke_colorspace = colorspace;
+                            gs.stroke_color = color;
+                        } else {
+                            gs.fill_colorspace = colorspace;
+                            gs.fill_color = color;
+                        }
+                    }
+                    "d0" | "d1" => {
+                        // Glyph metrics are read when the Type3 font is loaded
+                    }
+                    "i" | "ri" => {
+                        debug!("Unhandled graphics state operator {:?}", operation);
+                    }
+                    "W" | "W*" => {
+                        pending_clip = Some(if operation.operator == "W*" { FillRule::EvenOdd } else { FillRule::NonZero });
+                    }
+                    _ => {
+                        debug!("Unknown operation {:?}", operation);
+                        report::record(WarningKind::UnhandledOperator, format!("Unhandled operator {}", operation.operator));
                     }
                 }
-                "gs" => {
-                    let ext_gstate: &Dictionary = get(doc, resources, b"ExtGState")?;
-                    let name = operation.operands[0].as_name()
-                        .map_err(|_| PdfError::InvalidStructure("gs requires name operand".to_string()))?;
-                    let state: &Dictionary = get(doc, ext_gstate, name)?;
-                    apply_state(doc, &mut gs, state)?;
-                }
-                "m" => {
-                    path.ops.push(PathOp::MoveTo(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                    ));
-                }
-                "l" => {
-                    path.ops.push(PathOp::LineTo(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                    ));
-                }
-                "c" => {
-                    path.ops.push(PathOp::CurveTo(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                        object_utils::as_num(&operation.operands[4])?,
-                        object_utils::as_num(&operation.operands[5])?,
-                    ));
-                }
-                "v" => {
-                    let (x, y) = path.current_point();
-                    path.ops.push(PathOp::CurveTo(
-                        x,
-                        y,
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                    ));
-                }
-                "y" => {
-                    path.ops.push(PathOp::CurveTo(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                    ));
-                }
-                "h" => {
-                    path.ops.push(PathOp::Close);
-                }
-                "re" => {
-                    path.ops.push(PathOp::Rect(
-                        object_utils::as_num(&operation.operands[0])?,
-                        object_utils::as_num(&operation.operands[1])?,
-                        object_utils::as_num(&operation.operands[2])?,
-                        object_utils::as_num(&operation.operands[3])?,
-                    ));
-                }
-                "S" => {
-                    output.stroke(&gs.ctm, &gs.stroke_colorspace, &gs.stroke_color, &path)?;
-                    path.ops.clear();
-                }
-                "F" | "f" => {
-                    output.fill(&gs.ctm, &gs.fill_colorspace, &gs.fill_color, &path)?;
-                    path.ops.clear();
-                }
-                "n" => {
-                    path.ops.clear();
-                }
-                "BMC" | "BDC" => {
-                    mc_stack.push(operation);
-                }
-                "EMC" => {
-                    mc_stack.pop();
-                }
-                "Do" => {
-                    let xobject: &Dictionary = get(doc, resources, b"XObject")?;
-                    let name = operation.operands[0].as_name()
-                        .map_err(|_| PdfError::InvalidStructure("Do requires name operand".to_string()))?;
-                    let xf: &Stream = get(doc, xobject, name)?;
-                    let resources = object_utils::maybe_get_obj(doc, &xf.dict, b"Resources")
-                        .and_then(|n| n.as_dict().ok())
-                        .unwrap_or(resources);
-                    let contents = get_contents(xf);
-                    self.process_stream(doc, contents, resources, media_box, output, page_num)?;
-                }
-                "w" => {
-                    gs.line_width = object_utils::as_num(&operation.operands[0])?;
-                }
-                "G" | "g" | "RG" | "rg" | "K" | "k" => {
-                    debug!("Unhandled color operation {:?}", operation);
-                }
-                "i" | "J" | "j" | "M" | "d" | "ri" => {
-                    debug!("Unhandled graphics state operator {:?}", operation);
-                }
-                "s" | "f*" | "B" | "B*" | "b" => {
-                    debug!("Unhandled path op {:?}", operation);
-                }
-                "W" | "W*" => {
-                    debug!("Unhandled clipping operation {:?}", operation);
-                }
-                _ => {
-                    debug!("Unknown operation {:?}", operation);
+                Ok(())
+            })();
+            if let Err(e) = result {
+                self.recover(e, &operation.operator)?;
+            }
+            // Clipping changes with `W`, `Q` and the end of forms
+            if !Rc::ptr_eq(&gs.clip, &self.output_clip) {
+                let changed = gs.clip != self.output_clip;
+                self.output_clip = gs.clip.clone();
+                if changed {
+                    output.set_clip(&gs.clip)?;
                 }
             }
+            if let (Some(profile), Some(started)) = (&mut self.profile, started) {
+                let font = gs.ts.font.as_ref().map(|font| font.base_name().unwrap_or(""));
+                profile.record(&operation.operator, font, started.elapsed());
+            }
         }
+        self.lang_stack.truncate(lang_depth);
         Ok(())
     }
 }
 
+// The property list of a `BDC`: the inline dictionary, or the one named in the `/Properties`
+// resource; `None` when the name isn't there
+fn marked_content_properties<'a>(doc: &'a Document, resources: &'a Dictionary, operand: &'a Object) -> Option<&'a Dictionary> {
+    match operand {
+        Object::Name(name) => {
+            let properties = object_utils::maybe_get_obj(doc, resources, b"Properties")?.as_dict().ok()?;
+            let found = object_utils::maybe_get_obj(doc, properties, name).and_then(|p| p.as_dict().ok());
+            if found.is_none() {
+                report::record(WarningKind::Recovered, format!("Missing marked content properties {}", String::from_utf8_lossy(name)));
+            }
+            found
+        }
+        properties => object_utils::maybe_deref(doc, properties).ok()?.as_dict().ok(),
+    }
+}
+
+// Number of operands the operators without a check of their own read, so that shorter operand
+// lists are an error instead of an out of bounds access
+fn operand_count(operator: &str) -> usize {
+    match operator {
+        "CS" | "cs" | "TJ" | "Tj" | "Tc" | "Tw" | "Tz" | "TL" | "Ts" | "gs" | "sh" | "Do" | "w" | "J" | "j" | "M" => 1,
+        "Tf" | "m" | "l" | "d" => 2,
+        "v" | "y" | "re" => 4,
+        "c" => 6,
+        _ => 0,
+    }
+}
+
+// Executes a Type3 glyph procedure in a graphics state whose ctm maps glyph space to device space
+type GlyphRenderer<'r> = dyn FnMut(GlyphProcedure<'_>, GraphicsState, &mut dyn OutputDev) -> PdfResult<()> + 'r;
+
+// lopdf only reads alphabetic operator names, so `wx wy d0` arrives as a `d`
+// with numeric operands followed by a stray `0` operand on the next operation.
+// A genuine `d` (dash pattern) always starts with an array.
+fn normalize_glyph_metrics(operations: &mut [Operation]) {
+    for i in 0..operations.len() {
+        let op = &operations[i];
+        if op.operator != "d" || !op.operands.iter().all(|o| matches!(o, Object::Integer(_) | Object::Real(_))) {
+            continue;
+        }
+        let name = match op.operands.len() {
+            2 => "d0",
+            6 => "d1",
+            _ => continue,
+        };
+        let suffix = if name == "d0" { 0 } else { 1 };
+        if let Some(next) = operations.get_mut(i + 1)
+            && next.operands.first().is_some_and(|o| o.as_i64().ok() == Some(suffix))
+        {
+            next.operands.remove(0);
+        }
+        operations[i].operator = name.to_string();
+    }
+}
+
+// Whether decoded text carries nothing a reader could use
+fn is_unmapped(text: &str) -> bool {
+    text.chars().all(|c| c == '\u{fffd}' || c.is_control() || pua::is_private_use(c))
+}
+
+// Shows at most `max_glyphs` glyphs of `s`, returning how many were shown and whether that was all of them
+// The extraction options `show_text` follows
+#[derive(Clone, Copy)]
+struct TextOptions {
+    glyph_image_fallback: bool,
+    // Box outside which characters are left out
+    page_clip: Option<MediaBox>,
+    // Whether characters outside the clipping path are left out
+    clip_paths: bool,
+    invisible_text: InvisibleText,
+}
+
 fn show_text(
     gs: &mut GraphicsState,
     s: &[u8],
-    _tlm: &PdfTransform,
-    _flip_ctm: &PdfTransform,
+    max_glyphs: usize,
     output: &mut dyn OutputDev,
-) -> PdfResult<()> {
-    let ts = &mut gs.ts;
-    let font = ts.font.as_ref()
+    options: TextOptions,
+    render_glyph: &mut GlyphRenderer<'_>,
+) -> PdfResult<(usize, bool)> {
+    let font = gs.ts.font.clone()
         .ok_or_else(|| PdfError::InvalidStructure("No font set".to_string()))?;
     
+    output.set_font(font.as_ref())?;
+    output.set_fill_color(&gs.fill_colorspace, &gs.fill_color, gs.fill_alpha)?;
     output.begin_word()?;
     
     let mut iter = s.iter();
-    while let Some((c, length)) = font.next_char(&mut iter) {
+    let mut shown = 0;
+    loop {
+        let remaining = iter.as_slice();
+        let Some((c, length)) = font.next_char(&mut iter) else { break };
+        if shown == max_glyphs {
+            output.end_word()?;
+            return Ok((shown, false));
+        }
+        shown += 1;
+        let ts = &mut gs.ts;
+        let kern = ts.last_code.map_or(0., |previous| font.kerning(previous, c));
+        ts.last_code = Some(c);
         let tsm = Transform2D::new(
             ts.horizontal_scaling,
             0.,
@@ -2059,21 +4753,79 @@ fn show_text(
         let w0 = font.get_width(c) / 1000.;
         let mut spacing = ts.character_spacing;
         
-        let is_space = c == 32 && length == 1;
+        // Tw applies to the single-byte code 32, whatever CID a composite font maps it to
+        let is_space = remaining.get(..length as usize) == Some(&[32u8][..]);
         if is_space {
             spacing += ts.word_spacing;
         }
         
-        output.output_character(&trm, w0, spacing, ts.font_size, &font.decode_char(c))?;
+        let font_size = ts.font_size;
+        // Characters set outside the page box or clip, and invisible ones when skipped, still
+        // advance the text position
+        let clipped = options.page_clip.is_some_and(|b| !(b.llx..=b.urx).contains(&trm.m31) || !(b.lly..=b.ury).contains(&trm.m32))
+            || options.clip_paths && !gs.clip.iter().all(|clip| clip.contains(trm.m31, trm.m32))
+            || options.invisible_text == InvisibleText::Skip && matches!(gs.ts.render_mode, 3 | 7);
+        if !clipped {
+            if kern != 0. {
+                output.glyph_kerning(kern)?;
+            }
+            let text = font.decode_char(c);
+            limits::charge_text(text.len())?;
+            let outline = if options.glyph_image_fallback && is_unmapped(&text) {
+                match font.glyph_procedure(c) {
+                    Some(procedure) => {
+                        let mut recorder = outlines::OutlineRecorder::default();
+                        let mut glyph_gs = gs.clone();
+                        glyph_gs.ts = TextState::new();
+                        glyph_gs.ctm = procedure.font_matrix.then(&Transform2D::scale(1000., 1000.));
+                        render_glyph(procedure, glyph_gs, &mut recorder)?;
+                        Some(recorder.into_path()).filter(|p| !p.ops.is_empty())
+                    }
+                    None => font.glyph_outline(c),
+                }
+            } else {
+                None
+            };
+            let gid = font.glyph_id(c);
+            match outline {
+                Some(outline) => {
+                    let glyph = UnmappedGlyph { code: c, text, outline };
+                    output.output_unmapped_glyph(&trm, w0, spacing, font_size, &glyph)?;
+                    output.output_glyph(gid, c, &glyph.text, &trm, w0)?;
+                }
+                None => {
+                    let info = CharInfo {
+                        font: font.as_ref(),
+                        code: c,
+                        fill_colorspace: &gs.fill_colorspace,
+                        fill_color: &gs.fill_color,
+                        fill_alpha: gs.fill_alpha,
+                        stroke_colorspace: &gs.stroke_colorspace,
+                        stroke_color: &gs.stroke_color,
+                        stroke_alpha: gs.stroke_alpha,
+                        render_mode: gs.ts.render_mode,
+                    };
+                    output.output_char_info(&trm, w0, spacing, font_size, &text, &info)?;
+                    output.output_glyph(gid, c, &text, &trm, w0)?;
+                }
+            }
+            if let Some(procedure) = font.glyph_procedure(c) {
+                let mut glyph_gs = gs.clone();
+                glyph_gs.ts = TextState::new();
+                glyph_gs.ctm = procedure.font_matrix.then(&Transform2D::scale(font_size, font_size)).then(&trm);
+                render_glyph(procedure, glyph_gs, output)?;
+            }
+        }
         
+        let ts = &mut gs.ts;
         let tj = 0.;
         let ty = 0.;
         let tx = ts.horizontal_scaling * ((w0 - tj / 1000.) * ts.font_size + spacing);
-        ts.tm = ts.tm.then(&Transform2D::translation(tx, ty));
+        ts.tm = Transform2D::translation(tx, ty).then(&ts.tm);
     }
     
     output.end_word()?;
-    Ok(())
+    Ok((shown, true))
 }
 
 fn apply_state(doc: &Document, gs: &mut GraphicsState, state: &Dictionary) -> PdfResult<()> {
@@ -2093,11 +4845,28 @@ fn apply_state(doc: &Document, gs: &mut GraphicsState, state: &Dictionary) -> Pd
                 }
                 _ => return Err(PdfError::InvalidStructure("Unexpected smask type".to_string())),
             },
+            b"ca" => gs.fill_alpha = object_utils::as_num(object_utils::maybe_deref(doc, v)?)?,
+            b"CA" => gs.stroke_alpha = object_utils::as_num(object_utils::maybe_deref(doc, v)?)?,
+            b"LW" => gs.line.width = object_utils::as_num(object_utils::maybe_deref(doc, v)?)?,
+            b"LC" => gs.line.cap = object_utils::as_num(object_utils::maybe_deref(doc, v)?)? as i64,
+            b"LJ" => gs.line.join = object_utils::as_num(object_utils::maybe_deref(doc, v)?)? as i64,
+            b"ML" => gs.line.miter_limit = object_utils::as_num(object_utils::maybe_deref(doc, v)?)?,
+            // `[dash_array dash_phase]`
+            b"D" => {
+                let d = object_utils::maybe_deref(doc, v)?.as_array()
+                    .map_err(|_| PdfError::InvalidStructure("Expected a dash pattern array".to_string()))?;
+                if let [dash, phase] = d.as_slice() {
+                    let dash = object_utils::maybe_deref(doc, dash)?.as_array()
+                        .map_err(|_| PdfError::InvalidStructure("Expected a dash array".to_string()))?;
+                    gs.line.dash = dash.iter().map(object_utils::as_num).collect::<PdfResult<_>>()?;
+                    gs.line.dash_phase = object_utils::as_num(phase)?;
+                }
+            }
             b"Type" => {
-                if let Object::Name(name) = v {
-                    if name != b"ExtGState" {
-                        return Err(PdfError::InvalidStructure("Expected ExtGState type".to_string()));
-                    }
+                if let Object::Name(name) = v
+                    && name != b"ExtGState"
+                {
+                    return Err(PdfError::InvalidStructure("Expected ExtGState type".to_string()));
                 }
             }
             _ => {
@@ -2108,137 +4877,154 @@ fn apply_state(doc: &Document, gs: &mut GraphicsState, state: &Dictionary) -> Pd
     Ok(())
 }
 
-fn make_colorspace(doc: &Document, name: &[u8], resources: &Dictionary) -> ColorSpace {
+// An error when an ICC-based `color` doesn't have the profile's number of components
+fn check_components(colorspace: &ColorSpace, color: &[f64], operator: &str) -> PdfResult<()> {
+    match colorspace {
+        ColorSpace::ICCBased(profile) if color.len() != profile.components => Err(PdfError::InvalidStructure(format!(
+            "{} needs {} components for the ICC profile, got {}",
+            operator,
+            profile.components,
+            color.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn make_colorspace(doc: &Document, name: &[u8], resources: &Dictionary) -> PdfResult<ColorSpace> {
     match name {
+        b"DeviceGray" => Ok(ColorSpace::DeviceGray),
+        b"DeviceRGB" => Ok(ColorSpace::DeviceRGB),
+        b"DeviceCMYK" => Ok(ColorSpace::DeviceCMYK),
+        b"Pattern" => Ok(ColorSpace::Pattern),
+        _ => {
+            let colorspaces: &Dictionary = get(doc, resources, b"ColorSpace")?;
+            let cs = object_utils::maybe_get_obj(doc, colorspaces, name).ok_or_else(|| {
+                PdfError::InvalidStructure(format!("Missing colour space {}", String::from_utf8_lossy(name)))
+            })?;
+            colorspace_from_object(doc, cs)
+        }
+    }
+}
+
+// Stroke `path` with the stroke colour. Lines can't be clipped to, so patterns aren't painted
+// along them: uncoloured tiling patterns stroke in their colour and others in black
+fn stroke_path(doc: &Document, resources: &Dictionary, gs: &GraphicsState, path: &Path, output: &mut dyn OutputDev) -> PdfResult<()> {
+    let (ColorSpace::Pattern, Some(name)) = (&gs.stroke_colorspace, &gs.stroke_pattern) else {
+        return output.stroke(&gs.ctm, &gs.stroke_colorspace, &gs.stroke_color, path);
+    };
+    if let (Some(FillPattern::Tiling(_, TilingPattern { coloured: false, .. }, _)), Some(colorspace)) =
+        (fill_pattern(doc, resources, name)?, &gs.stroke_pattern_space)
+    {
+        return output.stroke(&gs.ctm, colorspace, &gs.stroke_color, path);
+    }
+    report::warn(WarningKind::Unsupported, format!("Stroking with pattern {} is unsupported, stroking in black", String::from_utf8_lossy(name)));
+    output.stroke(&gs.ctm, &ColorSpace::DeviceGray, &[0.], path)
+}
+
+// Base space of the colour space `name` of `resources` when it is `[/Pattern base]`
+fn pattern_base(doc: &Document, name: &[u8], resources: &Dictionary) -> PdfResult<Option<ColorSpace>> {
+    if name == b"Pattern" {
+        return Ok(None);
+    }
+    let colorspaces: &Dictionary = get(doc, resources, b"ColorSpace")?;
+    match object_utils::maybe_get_obj(doc, colorspaces, name).map(|cs| object_utils::maybe_deref(doc, cs)).transpose()? {
+        Some(Object::Array(cs)) if cs.len() >= 2 => colorspace_from_object(doc, &cs[1]).map(Some),
+        _ => Ok(None),
+    }
+}
+
+// Colour space described by a name or array object
+fn colorspace_from_object(doc: &Document, cs: &Object) -> PdfResult<ColorSpace> {
+    let invalid = |what: &str| PdfError::InvalidStructure(format!("Invalid colour space: {}", what));
+    let (family, params) = match object_utils::maybe_deref(doc, cs)? {
+        Object::Name(name) => (name.as_slice(), &[][..]),
+        Object::Array(cs) => match cs.split_first() {
+            Some((Object::Name(name), params)) => (name.as_slice(), params),
+            _ => return Err(invalid("array must start with a name")),
+        },
+        _ => return Err(invalid("expected a name or array")),
+    };
+    // The parameters after the family name
+    let param = |i: usize| {
+        let param = params.get(i).ok_or_else(|| invalid("missing parameters"))?;
+        object_utils::maybe_deref(doc, param)
+    };
+    let dict = |i: usize| param(i)?.as_dict().map_err(|_| invalid("expected a dictionary"));
+    Ok(match family {
         b"DeviceGray" => ColorSpace::DeviceGray,
         b"DeviceRGB" => ColorSpace::DeviceRGB,
         b"DeviceCMYK" => ColorSpace::DeviceCMYK,
         b"Pattern" => ColorSpace::Pattern,
-        _ => {
-            let colorspaces: &Dictionary = get(doc, resources, b"ColorSpace").expect("ColorSpace");
-            let cs: &Object = object_utils::maybe_get_obj(doc, colorspaces, name)
-                .unwrap_or_else(|| panic!("missing colorspace {:?}", name));
-            
-            if let Ok(cs) = cs.as_array() {
-                let cs_name = string_utils::pdf_to_utf8(cs[0].as_name()
-                    .expect("ColorSpace array must start with name")).expect("valid utf8");
-                
-                match cs_name.as_str() {
-                    "Separation" => {
-                        let name = string_utils::pdf_to_utf8(cs[1].as_name()
-                            .expect("Separation name must be name")).expect("valid utf8");
-                        
-                        let alternate_space = match object_utils::maybe_deref(doc, &cs[2]).expect("deref") {
-                            Object::Name(name) => match &name[..] {
-                                b"DeviceGray" => AlternateColorSpace::DeviceGray,
-                                b"DeviceRGB" => AlternateColorSpace::DeviceRGB,
-                                b"DeviceCMYK" => AlternateColorSpace::DeviceCMYK,
-                                _ => panic!("Unknown alternate colorspace"),
-                            },
-                            Object::Array(cs) => {
-                                let cs_name = string_utils::pdf_to_utf8(cs[0].as_name()
-                                    .expect("Alternate colorspace must start with name")).expect("valid utf8");
-                                
-                                match cs_name.as_str() {
-                                    "ICCBased" => {
-                                        let stream = object_utils::maybe_deref(doc, &cs[1]).expect("deref")
-                                            .as_stream()
-                                            .expect("ICCBased must have stream");
-                                        AlternateColorSpace::ICCBased(get_contents(stream))
-                                    }
-                                    "CalGray" => {
-                                        let dict = cs[1].as_dict()
-                                            .expect("CalGray must have dict");
-                                        AlternateColorSpace::CalGray(CalGray {
-                                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                                            _gamma: get(doc, dict, b"Gamma").ok(),
-                                        })
-                                    }
-                                    "CalRGB" => {
-                                        let dict = cs[1].as_dict()
-                                            .expect("CalRGB must have dict");
-                                        AlternateColorSpace::CalRGB(CalRGB {
-                                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                                            _gamma: get(doc, dict, b"Gamma").ok(),
-                                            _matrix: get(doc, dict, b"Matrix").ok(),
-                                        })
-                                    }
-                                    "Lab" => {
-                                        let dict = cs[1].as_dict()
-                                            .expect("Lab must have dict");
-                                        AlternateColorSpace::Lab(Lab {
-                                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                                            _range: get(doc, dict, b"Range").ok(),
-                                        })
-                                    }
-                                    _ => panic!("Unknown alternate colorspace"),
-                                }
-                            }
-                            _ => panic!("Alternate space must be name or array"),
-                        };
-                        
-                        let _tint_transform = Box::new(Function::new(doc, object_utils::maybe_deref(doc, &cs[3]).expect("deref")).expect("Function"));
-                        
-                        ColorSpace::Separation(Separation {
-                            _name: name,
-                            _alternate_space: alternate_space,
-                            _tint_transform,
-                        })
-                    }
-                    "ICCBased" => {
-                        let stream = object_utils::maybe_deref(doc, &cs[1]).expect("deref")
-                            .as_stream()
-                            .expect("ICCBased must have stream");
-                        ColorSpace::ICCBased(get_contents(stream))
-                    }
-                    "CalGray" => {
-                        let dict = cs[1].as_dict()
-                            .expect("CalGray must have dict");
-                        ColorSpace::CalGray(CalGray {
-                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                            _gamma: get(doc, dict, b"Gamma").ok(),
-                        })
-                    }
-                    "CalRGB" => {
-                        let dict = cs[1].as_dict()
-                            .expect("CalRGB must have dict");
-                        ColorSpace::CalRGB(CalRGB {
-                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                            _gamma: get(doc, dict, b"Gamma").ok(),
-                            _matrix: get(doc, dict, b"Matrix").ok(),
-                        })
-                    }
-                    "Lab" => {
-                        let dict = cs[1].as_dict()
-                            .expect("Lab must have dict");
-                        ColorSpace::Lab(Lab {
-                            _white_point: get(doc, dict, b"WhitePoint").expect("WhitePoint"),
-                            _black_point: get(doc, dict, b"BlackPoint").ok(),
-                            _range: get(doc, dict, b"Range").ok(),
-                        })
-                    }
-                    "Pattern" => ColorSpace::Pattern,
-                    "DeviceGray" => ColorSpace::DeviceGray,
-                    "DeviceRGB" => ColorSpace::DeviceRGB,
-                    "DeviceCMYK" => ColorSpace::DeviceCMYK,
-                    "DeviceN" => ColorSpace::DeviceN,
-                    _ => panic!("Unknown colorspace: {}", cs_name),
-                }
-            } else if let Ok(cs) = cs.as_name() {
-                match string_utils::pdf_to_utf8(cs).expect("valid utf8").as_str() {
-                    "DeviceRGB" => ColorSpace::DeviceRGB,
-                    "DeviceGray" => ColorSpace::DeviceGray,
-                    _ => panic!("Unknown colorspace name"),
-                }
-            } else {
-                panic!("ColorSpace must be name or array")
-            }
+        b"CalGray" => {
+            let dict = dict(0)?;
+            ColorSpace::CalGray(CalGray {
+                white_point: get(doc, dict, b"WhitePoint")?,
+                _black_point: get(doc, dict, b"BlackPoint").ok(),
+                gamma: get(doc, dict, b"Gamma").ok(),
+            })
+        }
+        b"CalRGB" => {
+            let dict = dict(0)?;
+            ColorSpace::CalRGB(CalRGB {
+                white_point: get(doc, dict, b"WhitePoint")?,
+                _black_point: get(doc, dict, b"BlackPoint").ok(),
+                gamma: get(doc, dict, b"Gamma").ok(),
+                matrix: get(doc, dict, b"Matrix").ok(),
+            })
+        }
+        b"Lab" => {
+            let dict = dict(0)?;
+            ColorSpace::Lab(Lab {
+                white_point: get(doc, dict, b"WhitePoint")?,
+                _black_point: get(doc, dict, b"BlackPoint").ok(),
+                range: get(doc, dict, b"Range").ok(),
+            })
+        }
+        b"ICCBased" => {
+            let stream = param(0)?.as_stream().map_err(|_| invalid("ICCBased needs a stream"))?;
+            ColorSpace::ICCBased(IccProfile::new(doc, stream)?)
+        }
+        b"Separation" => {
+            let name = param(0)?.as_name().map_err(|_| invalid("Separation needs a colorant name"))?;
+            ColorSpace::Separation(Separation {
+                _name: string_utils::pdf_to_utf8(name)?,
+                alternate_space: alternate_colorspace(doc, param(1)?)?,
+                tint_transform: Box::new(Function::new(doc, param(2)?)?),
+            })
+        }
+        b"DeviceN" => {
+            let names = param(0)?.as_array().map_err(|_| invalid("DeviceN needs an array of colorant names"))?
+                .iter()
+                .map(|name| string_utils::pdf_to_utf8(name.as_name().map_err(|_| invalid("colorant names must be names"))?))
+                .collect::<PdfResult<_>>()?;
+            ColorSpace::DeviceN(DeviceN {
+                names,
+                alternate_space: alternate_colorspace(doc, param(1)?)?,
+                tint_transform: Box::new(Function::new(doc, param(2)?)?),
+            })
         }
+        _ => return Err(invalid(&format!("unsupported family {}", String::from_utf8_lossy(family)))),
+    })
+}
+
+// Alternate space of a Separation or DeviceN colour space
+fn alternate_colorspace(doc: &Document, alternate: &Object) -> PdfResult<AlternateColorSpace> {
+    // Special spaces can't be alternates, which also keeps a space from being its own alternate
+    if let Object::Array(cs) = object_utils::maybe_deref(doc, alternate)?
+        && let Some(Object::Name(family)) = cs.first()
+        && matches!(family.as_slice(), b"Separation" | b"DeviceN" | b"Indexed" | b"Pattern")
+    {
+        return Err(PdfError::InvalidStructure("Alternate colour space must not be a special colour space".to_string()));
+    }
+    match colorspace_from_object(doc, alternate)? {
+        ColorSpace::DeviceGray => Ok(AlternateColorSpace::DeviceGray),
+        ColorSpace::DeviceRGB => Ok(AlternateColorSpace::DeviceRGB),
+        ColorSpace::DeviceCMYK => Ok(AlternateColorSpace::DeviceCMYK),
+        ColorSpace::CalGray(cs) => Ok(AlternateColorSpace::CalGray(cs)),
+        ColorSpace::CalRGB(cs) => Ok(AlternateColorSpace::CalRGB(cs)),
+        ColorSpace::Lab(cs) => Ok(AlternateColorSpace::Lab(cs)),
+        ColorSpace::ICCBased(profile) => Ok(AlternateColorSpace::ICCBased(profile)),
+        _ => Err(PdfError::InvalidStructure("Alternate colour space must not be a special colour space".to_string())),
     }
 }
 
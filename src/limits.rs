@@ -0,0 +1,245 @@
+// Caps on decoded stream data and nesting
+//
+// A few kilobytes of Flate data can inflate to gigabytes, so a decompression
+// bomb in a content stream, font or image would otherwise exhaust memory
+// before extraction could notice anything wrong. LZW output grows just as
+// fast. Streams are decoded here a filter at a time: Flate and LZW output is
+// produced only up to the per-stream cap, and ASCII85, the only other filter
+// decoded, writes at most four bytes per input byte, so its output is checked
+// once decoded. While `output_doc_with_diagnostics` runs, the decoded sizes
+// and the pixels of images converted to PNG are also summed against a budget
+// for the whole extraction, and the text shown against a separate one. As
+// with `report`, the budgets live in a per-thread slot because streams are
+// decoded far from the interpreter.
+//
+// Crafted documents can also make the interpreter recurse or loop: forms that
+// draw themselves, page trees whose `/Parent` entries form a cycle, or forms
+// drawn twice by forms drawn twice, and so on, multiplying the operators of a
+// page. Nesting beyond the caps is skipped with a warning; a page running
+// more operators than allowed fails.
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io::{self, Read, Write};
+
+use flate2::read::ZlibDecoder;
+use log::warn;
+use lopdf::filters::png;
+use weezl::{decode::Decoder, BitOrder};
+
+use crate::{Dictionary, Object, PdfError, PdfResult, Stream};
+
+/// Caps on the memory and work extraction spends on a document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Bytes a single stream may decode to
+    pub max_stream_size: usize,
+    /// Bytes all streams decoded during one extraction may add up to, with the pixels of the
+    /// images converted to PNG
+    pub max_total_size: usize,
+    /// Bytes of text, as UTF-8, one extraction may pass to the output device
+    pub max_total_text: usize,
+    /// Form XObjects a `Do` may be nested in
+    pub max_form_depth: usize,
+    /// `/Parent` links followed when looking up inherited page attributes
+    pub max_parent_depth: usize,
+    /// Operators run for one page, counting those of the forms it draws
+    pub max_operators_per_page: usize,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> ResourceLimits {
+        ResourceLimits {
+            max_stream_size: 256 << 20,
+            max_total_size: 1 << 30,
+            max_total_text: 256 << 20,
+            max_form_depth: 32,
+            max_parent_depth: 64,
+            max_operators_per_page: 10_000_000,
+        }
+    }
+}
+
+impl ResourceLimits {
+    pub fn unlimited() -> ResourceLimits {
+        ResourceLimits {
+            max_stream_size: usize::MAX,
+            max_total_size: usize::MAX,
+            max_total_text: usize::MAX,
+            max_form_depth: usize::MAX,
+            max_parent_depth: usize::MAX,
+            max_operators_per_page: usize::MAX,
+        }
+    }
+}
+
+// Limits of an extraction and what it has used of them
+struct Budget {
+    limits: ResourceLimits,
+    // Bytes decoded so far
+    decoded: usize,
+    // Bytes of text shown so far
+    text: usize,
+}
+
+// Budgets of the extractions running on this thread, innermost last
+thread_local! {
+    static BUDGETS: RefCell<Vec<Budget>> = const { RefCell::new(Vec::new()) };
+}
+
+// Run `f` with streams decoded under `limits`
+pub(crate) fn enforce<T>(limits: ResourceLimits, f: impl FnOnce() -> T) -> T {
+    BUDGETS.with(|budgets| budgets.borrow_mut().push(Budget { limits, decoded: 0, text: 0 }));
+    let result = f();
+    BUDGETS.with(|budgets| budgets.borrow_mut().pop());
+    result
+}
+
+// Limits of the innermost extraction, or the defaults outside one
+pub(crate) fn current() -> ResourceLimits {
+    BUDGETS.with(|budgets| budgets.borrow().last().map(|budget| budget.limits)).unwrap_or_default()
+}
+
+// Count `size` decoded bytes against the extraction's budget
+pub(crate) fn charge(size: usize) -> PdfResult<()> {
+    BUDGETS.with(|budgets| match budgets.borrow_mut().last_mut() {
+        Some(budget) => {
+            budget.decoded = budget.decoded.saturating_add(size);
+            if budget.decoded > budget.limits.max_total_size {
+                return Err(PdfError::ResourceLimit(format!("Decoded streams exceed {} bytes in total", budget.limits.max_total_size)));
+            }
+            Ok(())
+        }
+        None => Ok(()),
+    })
+}
+
+// Count `size` bytes of shown text against the extraction's budget
+pub(crate) fn charge_text(size: usize) -> PdfResult<()> {
+    BUDGETS.with(|budgets| match budgets.borrow_mut().last_mut() {
+        Some(budget) => {
+            budget.text = budget.text.saturating_add(size);
+            if budget.text > budget.limits.max_total_text {
+                return Err(PdfError::ResourceLimit(format!("Text exceeds {} bytes in total", budget.limits.max_total_text)));
+            }
+            Ok(())
+        }
+        None => Ok(()),
+    })
+}
+
+// `result` with errors other than hitting a limit turned into `None`, for callers that recover
+// from bad data but must still stop at a limit
+pub(crate) fn recoverable<T>(result: PdfResult<T>) -> PdfResult<Option<T>> {
+    match result {
+        Err(e @ PdfError::ResourceLimit(_)) => Err(e),
+        result => Ok(result.ok()),
+    }
+}
+
+/// Decoded data of `stream`, or `None` if a filter is unsupported or fails; streams decoding
+/// past the current limits are a `PdfError::ResourceLimit`. Unfiltered data is borrowed.
+pub(crate) fn decode(stream: &Stream) -> PdfResult<Option<Cow<'_, [u8]>>> {
+    let filters = match stream.filters() {
+        Ok(filters) if !filters.is_empty() => filters,
+        // Undecoded data is already in memory and not charged
+        _ => return Ok(Some(Cow::Borrowed(&stream.content))),
+    };
+    let max = current().max_stream_size;
+    let params = stream.dict.get(b"DecodeParms").and_then(Object::as_dict).ok();
+    let mut data = Cow::Borrowed(stream.content.as_slice());
+    for filter in filters {
+        data = Cow::Owned(match filter {
+            b"FlateDecode" => {
+                let mut inflated = Vec::new();
+                if let Err(e) = ZlibDecoder::new(&*data).take(max as u64 + 1).read_to_end(&mut inflated) {
+                    // Keep what was inflated before the damage, as lopdf does
+                    warn!("{}", e);
+                }
+                if inflated.len() > max {
+                    return Err(too_large(max));
+                }
+                match predictor(inflated, params) {
+                    Some(data) => data,
+                    None => return Ok(None),
+                }
+            }
+            b"LZWDecode" => {
+                // Codes widen one code early unless `EarlyChange` is 0
+                let early_change = params.and_then(|p| p.get(b"EarlyChange").and_then(Object::as_i64).ok()) != Some(0);
+                let mut decoder = if early_change {
+                    Decoder::with_tiff_size_switch(BitOrder::Msb, 8)
+                } else {
+                    Decoder::new(BitOrder::Msb, 8)
+                };
+                let mut output = Capped { data: Vec::new(), max, exceeded: false };
+                let status = decoder.into_stream(&mut output).decode_all(&*data).status;
+                if output.exceeded {
+                    return Err(too_large(max));
+                }
+                if let Err(e) = status {
+                    warn!("{}", e);
+                }
+                match predictor(output.data, params) {
+                    Some(data) => data,
+                    None => return Ok(None),
+                }
+            }
+            _ => match single_filter(filter, params, data.into_owned()).decompressed_content() {
+                Ok(data) => data,
+                Err(_) => return Ok(None),
+            },
+        });
+        if data.len() > max {
+            return Err(too_large(max));
+        }
+    }
+    charge(data.len())?;
+    Ok(Some(data))
+}
+
+// `data` with the PNG predictor of `params` undone, as lopdf does; other predictors are left
+fn predictor(data: Vec<u8>, params: Option<&Dictionary>) -> Option<Vec<u8>> {
+    let Some(params) = params else { return Some(data) };
+    let get = |key: &[u8], default: i64| params.get(key).and_then(Object::as_i64).unwrap_or(default);
+    if !(10..=15).contains(&get(b"Predictor", 1)) {
+        return Some(data);
+    }
+    let colors = get(b"Colors", 1).max(1) as usize;
+    let bits = get(b"BitsPerComponent", 8).max(8) as usize;
+    png::decode_frame(&data, colors * bits / 8, get(b"Columns", 1).max(1) as usize).ok()
+}
+
+// Output of a decoder, failing writes past `max` bytes so decoding stops there
+struct Capped {
+    data: Vec<u8>,
+    max: usize,
+    exceeded: bool,
+}
+
+impl Write for Capped {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.data.len().saturating_add(buf.len()) > self.max {
+            self.exceeded = true;
+            return Err(io::Error::other("decoded data exceeds the stream size limit"));
+        }
+        self.data.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn single_filter(filter: &[u8], params: Option<&Dictionary>, data: Vec<u8>) -> Stream {
+    let mut dict = Dictionary::new();
+    dict.set("Filter", Object::Name(filter.to_vec()));
+    if let Some(params) = params {
+        dict.set("DecodeParms", params.clone());
+    }
+    Stream::new(dict, data)
+}
+
+fn too_large(max: usize) -> PdfError {
+    PdfError::ResourceLimit(format!("Stream decodes to more than {} bytes", max))
+}
@@ -0,0 +1,302 @@
+// Math region detection with a glyph-level sidecar for formula recognisers
+//
+// Runs of words containing math symbols, glyphs from math fonts (CMMI, CMSY,
+// STIX, ...) or raised/lowered script glyphs are reported as regions. Each
+// region keeps its raw glyphs with font and position so an external tool can
+// work from precise input, plus a rough LaTeX hint.
+use std::io::Write;
+
+use crate::layout::{collect_text, BBox, PageText, TextGlyph, TextLine};
+use crate::string_utils::json_string;
+use crate::{Document, PdfError, PdfResult};
+
+// Minimum number of strong math glyphs in a region
+const MIN_STRONG_GLYPHS: usize = 2;
+
+/// Vertical placement of a glyph relative to its line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Base,
+    Superscript,
+    Subscript,
+}
+
+impl Script {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Script::Base => "base",
+            Script::Superscript => "superscript",
+            Script::Subscript => "subscript",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MathRegion {
+    pub page_num: u32,
+    pub bbox: BBox,
+    /// Glyphs in content stream order, paired with their script position
+    pub glyphs: Vec<(TextGlyph, Script)>,
+}
+
+impl MathRegion {
+    /// The glyph text with word gaps as spaces
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        let mut last_end = f64::INFINITY;
+        for (glyph, _) in &self.glyphs {
+            if glyph.x > last_end + glyph.font_size * 0.1 {
+                text.push(' ');
+            }
+            text.push_str(&glyph.text);
+            last_end = glyph.x + glyph.width;
+        }
+        text
+    }
+
+    /// Best-effort LaTeX rendering: symbol names and `^{}`/`_{}` for scripts
+    pub fn latex_hint(&self) -> String {
+        let mut latex = String::new();
+        let mut current = Script::Base;
+        let mut last_end = f64::INFINITY;
+        for (glyph, script) in &self.glyphs {
+            let gap = glyph.x > last_end + glyph.font_size * 0.1;
+            if *script != current && current != Script::Base {
+                latex.push('}');
+            }
+            if gap && !latex.ends_with(' ') {
+                latex.push(' ');
+            }
+            if *script != current {
+                match script {
+                    Script::Superscript => latex.push_str("^{"),
+                    Script::Subscript => latex.push_str("_{"),
+                    Script::Base => {}
+                }
+                current = *script;
+            }
+            for c in glyph.text.chars() {
+                match latex_command(c) {
+                    Some(command) => {
+                        latex.push_str(command);
+                        if command.starts_with('\\') {
+                            latex.push(' ');
+                        }
+                    }
+                    None => {
+                        if matches!(c, '{' | '}' | '%' | '#' | '&' | '$' | '_') {
+                            latex.push('\\');
+                        }
+                        latex.push(c);
+                    }
+                }
+            }
+            last_end = glyph.x + glyph.width;
+        }
+        if current != Script::Base {
+            latex.push('}');
+        }
+        latex.replace(" }", "}").trim_end().to_string()
+    }
+}
+
+/// Math regions on every page of `doc`
+pub fn math_regions(doc: &Document) -> PdfResult<Vec<MathRegion>> {
+    Ok(collect_text(doc)?.iter().flat_map(detect_math).collect())
+}
+
+/// Math regions on a single collected page, in line order
+pub fn detect_math(page: &PageText) -> Vec<MathRegion> {
+    page.lines().iter().flat_map(|line| line_regions(page.page_num, line)).collect()
+}
+
+/// Write one JSON object per math region of `doc`
+pub fn write_math_sidecar<W: Write>(doc: &Document, writer: &mut W) -> PdfResult<()> {
+    write_regions(&math_regions(doc)?, writer)
+}
+
+/// Render the math sidecar of `doc` as JSON lines
+pub fn export_math_sidecar(doc: &Document) -> PdfResult<String> {
+    let mut out = Vec::new();
+    write_math_sidecar(doc, &mut out)?;
+    String::from_utf8(out).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+}
+
+/// Write `regions` as JSON lines
+pub fn write_regions<W: Write>(regions: &[MathRegion], w: &mut W) -> PdfResult<()> {
+    for region in regions {
+        let b = region.bbox;
+        write!(w, r#"{{"page":{},"bbox":[{:.2},{:.2},{:.2},{:.2}],"text":{},"latex":{},"glyphs":["#,
+               region.page_num, b.llx, b.lly, b.urx, b.ury,
+               json_string(&region.text()), json_string(&region.latex_hint()))?;
+        for (i, (glyph, script)) in region.glyphs.iter().enumerate() {
+            if i > 0 {
+                write!(w, ",")?;
+            }
+            let font = glyph.font.as_deref().map_or_else(|| "null".to_string(), json_string);
+            write!(w, r#"{{"text":{},"font":{},"x":{:.2},"y":{:.2},"width":{:.2},"size":{:.2},"script":"{}"}}"#,
+                   json_string(&glyph.text), font, glyph.x, glyph.y, glyph.width, glyph.font_size, script.as_str())?;
+        }
+        writeln!(w, "]}}")?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WordKind {
+    // Contains math symbols, math-font glyphs or scripts
+    Math,
+    // Digits, ASCII operators or a one/two letter variable; extends a region
+    Neutral,
+    Text,
+}
+
+fn line_regions(page_num: u32, line: &TextLine) -> Vec<MathRegion> {
+    let scripts = scripts(line);
+    let words = words(line);
+
+    let mut regions = Vec::new();
+    let mut run: Vec<(usize, usize, WordKind)> = Vec::new();
+    let mut flush = |run: &mut Vec<(usize, usize, WordKind)>| {
+        // Alphabetic neutral words at the edges are more likely prose ("a", "is") than variables
+        let is_prose = |&(start, end, kind): &(usize, usize, WordKind)| {
+            kind == WordKind::Neutral && line.glyphs[start..end].iter().all(|g| g.text.chars().all(char::is_alphabetic))
+        };
+        while run.first().is_some_and(is_prose) {
+            run.remove(0);
+        }
+        while run.last().is_some_and(is_prose) {
+            run.pop();
+        }
+        let strong: usize = run.iter()
+            .filter(|w| w.2 == WordKind::Math)
+            .map(|&(start, end, _)| (start..end).filter(|&i| is_strong(&line.glyphs[i], scripts[i])).count())
+            .sum();
+        if let (Some(first), Some(last)) = (run.first(), run.last())
+            && strong >= MIN_STRONG_GLYPHS
+        {
+            let glyphs: Vec<(TextGlyph, Script)> = (first.0..last.1)
+                .map(|i| (line.glyphs[i].clone(), scripts[i]))
+                .collect();
+            let bbox = glyphs.iter().map(|(g, _)| g.bbox()).reduce(|a, b| a.union(&b)).expect("run is not empty");
+            regions.push(MathRegion { page_num, bbox, glyphs });
+        }
+        run.clear();
+    };
+
+    for (start, end) in words {
+        let kind = word_kind(&line.glyphs[start..end], &scripts[start..end]);
+        if kind == WordKind::Text {
+            flush(&mut run);
+        } else {
+            run.push((start, end, kind));
+        }
+    }
+    flush(&mut run);
+    regions
+}
+
+// Glyph index ranges of the words in `line`, split at visible gaps
+fn words(line: &TextLine) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut last_end = f64::INFINITY;
+    for (i, glyph) in line.glyphs.iter().enumerate() {
+        let gap = glyph.x > last_end + glyph.font_size * 0.1;
+        if (gap || glyph.text.trim().is_empty()) && i > start {
+            words.push((start, i));
+            start = i;
+        }
+        if glyph.text.trim().is_empty() {
+            start = i + 1;
+        }
+        last_end = glyph.x + glyph.width;
+    }
+    if start < line.glyphs.len() {
+        words.push((start, line.glyphs.len()));
+    }
+    words
+}
+
+// Classify glyphs as raised or lowered relative to the line's dominant baseline
+fn scripts(line: &TextLine) -> Vec<Script> {
+    let base_size = line.font_size;
+    let baseline = line.glyphs.iter()
+        .filter(|g| g.font_size >= base_size * 0.9)
+        .map(|g| g.y)
+        .next()
+        .unwrap_or(0.);
+    line.glyphs.iter()
+        .map(|g| {
+            let shift = g.y - baseline;
+            if g.font_size >= base_size * 0.9 || shift.abs() < base_size * 0.2 {
+                Script::Base
+            } else if shift > 0. {
+                Script::Superscript
+            } else {
+                Script::Subscript
+            }
+        })
+        .collect()
+}
+
+fn word_kind(glyphs: &[TextGlyph], scripts: &[Script]) -> WordKind {
+    if glyphs.iter().zip(scripts).any(|(g, &s)| is_strong(g, s)) {
+        return WordKind::Math;
+    }
+    let text: String = glyphs.iter().map(|g| g.text.as_str()).collect();
+    let letters = text.chars().filter(|c| c.is_alphabetic()).count();
+    if letters <= 2 && text.chars().all(|c| c.is_alphanumeric() || "=+-*/<>^()[]{}|.,;:!'".contains(c)) {
+        WordKind::Neutral
+    } else {
+        WordKind::Text
+    }
+}
+
+fn is_strong(glyph: &TextGlyph, script: Script) -> bool {
+    glyph.text.chars().any(is_math_char)
+        || glyph.font.as_deref().is_some_and(is_math_font)
+        || (script != Script::Base && !glyph.text.trim().is_empty())
+}
+
+fn is_math_char(c: char) -> bool {
+    matches!(c as u32,
+        0x391..=0x3a9 | 0x3b1..=0x3c9 | 0x3d0..=0x3f5 // Greek
+        | 0x2032..=0x2037 // primes
+        | 0x2190..=0x21ff // arrows
+        | 0x2200..=0x22ff // mathematical operators
+        | 0x27c0..=0x27ef | 0x2980..=0x2aff // supplemental math
+        | 0x1d400..=0x1d7ff // mathematical alphanumerics
+    ) || matches!(c, '±' | '×' | '÷' | '¬' | '∞')
+}
+
+/// Whether `base_font` names a font used for typesetting formulas
+pub fn is_math_font(base_font: &str) -> bool {
+    let name = base_font.split_once('+').map_or(base_font, |(_, n)| n).to_ascii_lowercase();
+    ["cmmi", "cmsy", "cmex", "msam", "msbm", "math", "stix", "symbol", "euclid", "mtmi", "mtsy", "mtex",
+     "rtxmi", "txmi", "txsy", "pxmi", "pxsy", "esstix", "eufm", "rsfs"]
+        .iter()
+        .any(|m| name.contains(m))
+}
+
+fn latex_command(c: char) -> Option<&'static str> {
+    const COMMANDS: &[(char, &str)] = &[
+        ('α', "\\alpha"), ('β', "\\beta"), ('γ', "\\gamma"), ('δ', "\\delta"), ('ε', "\\epsilon"),
+        ('ζ', "\\zeta"), ('η', "\\eta"), ('θ', "\\theta"), ('ι', "\\iota"), ('κ', "\\kappa"),
+        ('λ', "\\lambda"), ('μ', "\\mu"), ('ν', "\\nu"), ('ξ', "\\xi"), ('π', "\\pi"), ('ρ', "\\rho"),
+        ('σ', "\\sigma"), ('τ', "\\tau"), ('υ', "\\upsilon"), ('φ', "\\phi"), ('χ', "\\chi"),
+        ('ψ', "\\psi"), ('ω', "\\omega"), ('Γ', "\\Gamma"), ('Δ', "\\Delta"), ('Θ', "\\Theta"),
+        ('Λ', "\\Lambda"), ('Ξ', "\\Xi"), ('Π', "\\Pi"), ('Σ', "\\Sigma"), ('Φ', "\\Phi"),
+        ('Ψ', "\\Psi"), ('Ω', "\\Omega"), ('ϕ', "\\phi"), ('ϵ', "\\epsilon"),
+        ('∑', "\\sum"), ('∏', "\\prod"), ('∫', "\\int"), ('∮', "\\oint"), ('√', "\\sqrt"),
+        ('∞', "\\infty"), ('∂', "\\partial"), ('∇', "\\nabla"), ('∀', "\\forall"), ('∃', "\\exists"),
+        ('∈', "\\in"), ('∉', "\\notin"), ('⊂', "\\subset"), ('⊆', "\\subseteq"), ('∪', "\\cup"),
+        ('∩', "\\cap"), ('∅', "\\emptyset"), ('≤', "\\leq"), ('≥', "\\geq"), ('≠', "\\neq"),
+        ('≈', "\\approx"), ('≡', "\\equiv"), ('∼', "\\sim"), ('∝', "\\propto"), ('±', "\\pm"),
+        ('∓', "\\mp"), ('×', "\\times"), ('÷', "\\div"), ('·', "\\cdot"), ('∘', "\\circ"),
+        ('→', "\\to"), ('←', "\\leftarrow"), ('⇒', "\\Rightarrow"), ('⇔', "\\Leftrightarrow"),
+        ('↦', "\\mapsto"), ('∧', "\\wedge"), ('∨', "\\vee"), ('¬', "\\neg"), ('…', "\\ldots"),
+        ('⋯', "\\cdots"), ('′', "'"), ('−', "-"),
+    ];
+    COMMANDS.iter().find(|(ch, _)| *ch == c).map(|(_, command)| *command)
+}
@@ -0,0 +1,24 @@
+// Memory-mapped loading
+//
+// `Document::load` reads the whole file into a buffer before parsing it.
+// Mapping the file instead lets the operating system page it in on demand
+// and share it with the page cache, so the raw bytes of a large file don't
+// need a second copy in process memory. The parsed objects lopdf builds are
+// still owned, so this saves the file buffer, not the document itself.
+// A file truncated while mapped makes reading the mapping fault, so map only
+// files nothing else is writing.
+use std::fs::File;
+use std::path::Path;
+
+pub use memmap2::Mmap;
+
+use crate::{Document, PdfResult};
+
+/// `Document::load` through a memory mapping of the file
+pub fn load_mmap<P: AsRef<Path>>(path: P) -> PdfResult<Document> {
+    let file = File::open(path)?;
+    // SAFETY: the mapping is read-only and dropped before returning; see the note above on
+    // files modified while mapped
+    let map = unsafe { Mmap::map(&file)? };
+    Ok(Document::load_mem(&map)?)
+}
@@ -0,0 +1,167 @@
+// Fan-out of one content stream walk to several output devices
+//
+// Every call is forwarded to each device in order, stopping at the first
+// error, so one pass over a document can feed plain text, HTML and the
+// layout based extractors at once. Devices whose results are needed
+// afterwards, such as a `TextCollector`, can be pushed by `&mut` reference.
+use crate::shading::Shading;
+use crate::{CharCode, CharInfo, ClipPath, ColorSpace, Dictionary, FillRule, LineStyle, MediaBox, OutputDev, Path, PdfFont, PdfResult, PdfTransform, Stream, TilingPattern, UnmappedGlyph};
+
+/// Output device forwarding everything to each of its devices in turn
+#[derive(Default)]
+pub struct TeeOutput<'a>(pub Vec<Box<dyn OutputDev + 'a>>);
+
+impl<'a> TeeOutput<'a> {
+    pub fn new() -> TeeOutput<'a> {
+        TeeOutput(Vec::new())
+    }
+
+    pub fn push<D: OutputDev + 'a>(&mut self, device: D) {
+        self.0.push(Box::new(device));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn each(&mut self, mut f: impl FnMut(&mut dyn OutputDev) -> PdfResult<()>) -> PdfResult<()> {
+        self.0.iter_mut().try_for_each(|device| f(&mut **device))
+    }
+}
+
+impl OutputDev for TeeOutput<'_> {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.each(|d| d.begin_page(page_num, media_box, art_box))
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_page())
+    }
+
+    fn begin_document(&mut self, pages: &[u32]) -> PdfResult<()> {
+        self.each(|d| d.begin_document(pages))
+    }
+
+    fn end_document(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_document())
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        self.each(|d| d.output_character(trm, width, spacing, font_size, char))
+    }
+
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.each(|d| d.output_char_info(trm, width, spacing, font_size, char, info))
+    }
+
+    fn output_glyph(&mut self, gid: Option<u16>, cid: CharCode, unicode: &str, trm: &PdfTransform, advance: f64) -> PdfResult<()> {
+        self.each(|d| d.output_glyph(gid, cid, unicode, trm, advance))
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        self.each(|d| d.set_font(font))
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.each(|d| d.set_fill_color(colorspace, color, alpha))
+    }
+
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> {
+        self.each(|d| d.set_language(lang))
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.each(|d| d.begin_word())
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_word())
+    }
+
+    fn end_line(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_line())
+    }
+
+    fn text_adjustment(&mut self, amount: f64, displacement: f64) -> PdfResult<()> {
+        self.each(|d| d.text_adjustment(amount, displacement))
+    }
+
+    fn end_text_array(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_text_array())
+    }
+
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> {
+        self.each(|d| d.glyph_kerning(amount))
+    }
+
+    fn begin_text_object(&mut self) -> PdfResult<()> {
+        self.each(|d| d.begin_text_object())
+    }
+
+    fn end_text_object(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_text_object())
+    }
+
+    fn begin_marked_content(&mut self, tag: &str, properties: Option<&Dictionary>) -> PdfResult<()> {
+        self.each(|d| d.begin_marked_content(tag, properties))
+    }
+
+    fn end_marked_content(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_marked_content())
+    }
+
+    fn set_line_style(&mut self, style: &LineStyle) -> PdfResult<()> {
+        self.each(|d| d.set_line_style(style))
+    }
+
+    fn set_clip(&mut self, clip: &[ClipPath]) -> PdfResult<()> {
+        self.each(|d| d.set_clip(clip))
+    }
+
+    fn stroke(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path) -> PdfResult<()> {
+        self.each(|d| d.stroke(ctm, colorspace, color, path))
+    }
+
+    fn fill(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path, rule: FillRule) -> PdfResult<()> {
+        self.each(|d| d.fill(ctm, colorspace, color, path, rule))
+    }
+
+    fn shade(&mut self, ctm: &PdfTransform, shading: &Shading) -> PdfResult<()> {
+        self.each(|d| d.shade(ctm, shading))
+    }
+
+    fn begin_pattern(&mut self, ctm: &PdfTransform, pattern: &TilingPattern) -> PdfResult<()> {
+        self.each(|d| d.begin_pattern(ctm, pattern))
+    }
+
+    fn end_pattern(&mut self) -> PdfResult<()> {
+        self.each(|d| d.end_pattern())
+    }
+
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.each(|d| d.draw_image(ctm, image))
+    }
+
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.each(|d| d.draw_inline_image(ctm, image))
+    }
+
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        self.each(|d| d.output_unmapped_glyph(trm, width, spacing, font_size, glyph))
+    }
+}
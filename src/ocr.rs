@@ -0,0 +1,261 @@
+// OCR of images that carry a page's text
+//
+// Scanned pages hold their text as pixels. `output_doc_with_ocr` finds the
+// images without extractable text over them, hands each to an `OcrEngine`
+// and feeds the recognised runs to the output device as ordinary
+// characters at the end of the page, after the page's own content, so
+// every device sees one stream of text whatever its origin. The crate has
+// no OCR engine of its own; implement the trait over Tesseract or a
+// similar library.
+use crate::images::{ImageCollector, PlacedImage};
+use crate::layout::{BBox, TextCollector, TextGlyph};
+use crate::multiplex::TeeOutput;
+use crate::shading::Shading;
+use crate::{
+    CharCode, CharInfo, ClipPath, ColorSpace, Dictionary, Document, ExtractionOptions, FillRule, LineStyle, MediaBox, OutputDev, PageRunner, Path, PdfFont,
+    PdfResult, PdfTransform, Stream, TilingPattern, UnmappedGlyph,
+};
+
+/// A recognised line or word, positioned in the image's pixels with the origin at the top left
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrRun {
+    pub text: String,
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Text recognition over the images of a page
+pub trait OcrEngine {
+    /// Recognise the text of `image`. Its samples are in `data` when the PDF's filters could be
+    /// decoded, and a JPEG or JPEG 2000 file is in `jpeg` or `jpx`; the placement on the page is
+    /// in `bbox`.
+    fn recognize(&mut self, image: &PlacedImage) -> PdfResult<Vec<OcrRun>>;
+}
+
+/// Which images are passed to the `OcrEngine`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OcrMode {
+    /// Every image of pages without any extractable text
+    #[default]
+    ImageOnlyPages,
+    /// Images without extractable text over them, also on pages that have text elsewhere
+    ImageOnlyRegions,
+}
+
+/// Like `output_doc_with_options`, adding the text `engine` recognises in image-only pages or regions
+pub fn output_doc_with_ocr(
+    doc: &Document,
+    output: &mut dyn OutputDev,
+    engine: &mut dyn OcrEngine,
+    mode: OcrMode,
+    options: &ExtractionOptions,
+) -> PdfResult<()> {
+    // One runner for both passes over every page, so they share its caches, and the document
+    // hooks are called once around all of them
+    let mut runner = PageRunner::new(doc, options);
+    let pages = runner.page_numbers();
+    output.begin_document(&pages)?;
+    for page_num in pages {
+        // A first pass finds the text and images of the page
+        let mut text = TextCollector::new();
+        let mut images = ImageCollector::new(doc).with_data();
+        {
+            let mut survey = TeeOutput::new();
+            survey.push(&mut text);
+            survey.push(&mut images);
+            runner.output_pages(&mut survey, &[page_num])?;
+        }
+        let glyphs = text.into_pages().pop().map(|page| page.glyphs).unwrap_or_default();
+
+        let mut runs = Vec::new();
+        for image in images.into_images() {
+            let needs_ocr = match mode {
+                OcrMode::ImageOnlyPages => glyphs.is_empty(),
+                OcrMode::ImageOnlyRegions => !glyphs.iter().any(|g| inside(g, &image.bbox)),
+            };
+            if needs_ocr {
+                for run in engine.recognize(&image)? {
+                    runs.push((page_space(&image, &run), run.text));
+                }
+            }
+        }
+        runner.output_pages(&mut OcrMerge { inner: &mut *output, runs }, &[page_num])?;
+    }
+    output.end_document()
+}
+
+fn inside(glyph: &TextGlyph, bbox: &BBox) -> bool {
+    (bbox.llx..=bbox.urx).contains(&glyph.x) && (bbox.lly..=bbox.ury).contains(&glyph.y)
+}
+
+// Bounding box of `run` in user space, for an image placed without rotation
+fn page_space(image: &PlacedImage, run: &OcrRun) -> BBox {
+    let (sx, sy) = (image.bbox.width() / image.width.max(1) as f64, image.bbox.height() / image.height.max(1) as f64);
+    BBox {
+        llx: image.bbox.llx + run.left * sx,
+        lly: image.bbox.ury - (run.top + run.height) * sy,
+        urx: image.bbox.llx + (run.left + run.width) * sx,
+        ury: image.bbox.ury - run.top * sy,
+    }
+}
+
+// Forwards the page to `inner`, then the recognised runs before the page ends
+struct OcrMerge<'a> {
+    inner: &'a mut dyn OutputDev,
+    runs: Vec<(BBox, String)>,
+}
+
+impl OutputDev for OcrMerge<'_> {
+    fn begin_page(&mut self, page_num: u32, media_box: &MediaBox, art_box: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.inner.begin_page(page_num, media_box, art_box)
+    }
+
+    fn begin_document(&mut self, pages: &[u32]) -> PdfResult<()> {
+        self.inner.begin_document(pages)
+    }
+
+    fn end_document(&mut self) -> PdfResult<()> {
+        self.inner.end_document()
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        for (bbox, text) in std::mem::take(&mut self.runs) {
+            // Runs are set on their bottom edge at their own height, glyphs sharing the width evenly
+            let font_size = bbox.height();
+            let chars: Vec<char> = text.chars().collect();
+            if chars.is_empty() || font_size <= 0. {
+                continue;
+            }
+            let advance = bbox.width() / chars.len() as f64;
+            self.inner.begin_word()?;
+            for (i, c) in chars.iter().enumerate() {
+                let trm = PdfTransform::translation(bbox.llx + advance * i as f64, bbox.lly);
+                self.inner.output_character(&trm, advance / font_size, 0., font_size, c.encode_utf8(&mut [0; 4]))?;
+            }
+            self.inner.end_word()?;
+            self.inner.end_line()?;
+        }
+        self.inner.end_page()
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, spacing: f64, font_size: f64, char: &str) -> PdfResult<()> {
+        self.inner.output_character(trm, width, spacing, font_size, char)
+    }
+
+    fn output_char_info(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        char: &str,
+        info: &CharInfo,
+    ) -> PdfResult<()> {
+        self.inner.output_char_info(trm, width, spacing, font_size, char, info)
+    }
+
+    fn output_glyph(&mut self, gid: Option<u16>, cid: CharCode, unicode: &str, trm: &PdfTransform, advance: f64) -> PdfResult<()> {
+        self.inner.output_glyph(gid, cid, unicode, trm, advance)
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        self.inner.set_font(font)
+    }
+
+    fn set_fill_color(&mut self, colorspace: &ColorSpace, color: &[f64], alpha: f64) -> PdfResult<()> {
+        self.inner.set_fill_color(colorspace, color, alpha)
+    }
+
+    fn set_language(&mut self, lang: Option<&str>) -> PdfResult<()> {
+        self.inner.set_language(lang)
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.inner.begin_word()
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> {
+        self.inner.end_word()
+    }
+
+    fn end_line(&mut self) -> PdfResult<()> {
+        self.inner.end_line()
+    }
+
+    fn text_adjustment(&mut self, amount: f64, displacement: f64) -> PdfResult<()> {
+        self.inner.text_adjustment(amount, displacement)
+    }
+
+    fn end_text_array(&mut self) -> PdfResult<()> {
+        self.inner.end_text_array()
+    }
+
+    fn glyph_kerning(&mut self, amount: f64) -> PdfResult<()> {
+        self.inner.glyph_kerning(amount)
+    }
+
+    fn begin_text_object(&mut self) -> PdfResult<()> {
+        self.inner.begin_text_object()
+    }
+
+    fn end_text_object(&mut self) -> PdfResult<()> {
+        self.inner.end_text_object()
+    }
+
+    fn begin_marked_content(&mut self, tag: &str, properties: Option<&Dictionary>) -> PdfResult<()> {
+        self.inner.begin_marked_content(tag, properties)
+    }
+
+    fn end_marked_content(&mut self) -> PdfResult<()> {
+        self.inner.end_marked_content()
+    }
+
+    fn set_line_style(&mut self, style: &LineStyle) -> PdfResult<()> {
+        self.inner.set_line_style(style)
+    }
+
+    fn set_clip(&mut self, clip: &[ClipPath]) -> PdfResult<()> {
+        self.inner.set_clip(clip)
+    }
+
+    fn stroke(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path) -> PdfResult<()> {
+        self.inner.stroke(ctm, colorspace, color, path)
+    }
+
+    fn fill(&mut self, ctm: &PdfTransform, colorspace: &ColorSpace, color: &[f64], path: &Path, rule: FillRule) -> PdfResult<()> {
+        self.inner.fill(ctm, colorspace, color, path, rule)
+    }
+
+    fn shade(&mut self, ctm: &PdfTransform, shading: &Shading) -> PdfResult<()> {
+        self.inner.shade(ctm, shading)
+    }
+
+    fn begin_pattern(&mut self, ctm: &PdfTransform, pattern: &TilingPattern) -> PdfResult<()> {
+        self.inner.begin_pattern(ctm, pattern)
+    }
+
+    fn end_pattern(&mut self) -> PdfResult<()> {
+        self.inner.end_pattern()
+    }
+
+    fn draw_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.inner.draw_image(ctm, image)
+    }
+
+    fn draw_inline_image(&mut self, ctm: &PdfTransform, image: &Stream) -> PdfResult<()> {
+        self.inner.draw_inline_image(ctm, image)
+    }
+
+    fn output_unmapped_glyph(
+        &mut self,
+        trm: &PdfTransform,
+        width: f64,
+        spacing: f64,
+        font_size: f64,
+        glyph: &UnmappedGlyph,
+    ) -> PdfResult<()> {
+        self.inner.output_unmapped_glyph(trm, width, spacing, font_size, glyph)
+    }
+}
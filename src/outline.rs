@@ -0,0 +1,59 @@
+// Document outline (bookmarks)
+use std::collections::HashSet;
+
+use crate::destinations::Destinations;
+use crate::{document_utils, object_utils, string_utils, Dictionary, Document, Object, ObjectId, PdfResult};
+
+/// A bookmark and the bookmarks nested under it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlineItem {
+    pub title: String,
+    /// Page the bookmark points to, `None` if its destination doesn't resolve to a page
+    pub page: Option<u32>,
+    pub children: Vec<OutlineItem>,
+}
+
+/// Bookmark tree of `doc`, empty if it has no `/Outlines`
+pub fn extract_outline(doc: &Document) -> PdfResult<Vec<OutlineItem>> {
+    let catalog = document_utils::get_catalog(doc)?;
+    let Some(root) = object_utils::maybe_get_obj(doc, catalog, b"Outlines").and_then(|o| o.as_dict().ok()) else {
+        return Ok(Vec::new());
+    };
+    let destinations = Destinations::new(doc)?;
+    let mut visited = HashSet::new();
+    Ok(outline_items(doc, root, &destinations, &mut visited))
+}
+
+// Children of an outline node, following `/First` and `/Next`; `visited` breaks cycles
+fn outline_items(doc: &Document, node: &Dictionary, destinations: &Destinations, visited: &mut HashSet<ObjectId>) -> Vec<OutlineItem> {
+    let mut items = Vec::new();
+    let mut next = node.get(b"First").and_then(|o| o.as_reference()).ok();
+    while let Some(id) = next.filter(|id| visited.insert(*id)) {
+        let Ok(item) = doc.get_dictionary(id) else { break };
+        let title = object_utils::maybe_get_obj(doc, item, b"Title")
+            .and_then(|o| o.as_str().ok())
+            .and_then(|s| string_utils::pdf_to_utf8(s).ok())
+            .unwrap_or_default();
+        items.push(OutlineItem {
+            title,
+            page: destination(doc, item).and_then(|dest| destinations.resolve(dest)?.page),
+            children: outline_items(doc, item, destinations, visited),
+        });
+        next = item.get(b"Next").and_then(|o| o.as_reference()).ok();
+    }
+    items
+}
+
+// `/Dest` of an item, or the `/D` of its GoTo action
+fn destination<'a>(doc: &'a Document, item: &'a Dictionary) -> Option<&'a Object> {
+    if let Some(dest) = object_utils::maybe_get_obj(doc, item, b"Dest") {
+        return Some(dest);
+    }
+    let action = object_utils::maybe_get_obj(doc, item, b"A")?.as_dict().ok()?;
+    if action.get(b"S").and_then(|s| s.as_name()).ok()? != b"GoTo" {
+        return None;
+    }
+    object_utils::maybe_get_obj(doc, action, b"D")
+}
+
@@ -0,0 +1,168 @@
+// Glyph outlines for codes without a Unicode mapping
+//
+// Outlines come from executing Type3 glyph procedures into a recording device
+// or from CFF charstrings. They are normalised to glyph space with 1000 units
+// per em so devices can render them without knowing the font matrix.
+use cff_parser::{OutlineBuilder, Table};
+use euclid::point2;
+
+use crate::{
+    string_utils, ColorSpace, FillRule, MediaBox, OutputDev, Path, PathOp, PdfResult, PdfTransform, UnmappedGlyph,
+};
+
+// Apply `ctm` to every point of `path`; rectangles become closed polygons
+// since they are not preserved by rotation or skew
+fn extend_transformed(target: &mut Vec<PathOp>, path: &Path, ctm: &PdfTransform) {
+    let map = |x: f64, y: f64| {
+        let p = ctm.transform_point(point2(x, y));
+        (p.x, p.y)
+    };
+    for op in &path.ops {
+        match *op {
+            PathOp::MoveTo(x, y) => {
+                let (x, y) = map(x, y);
+                target.push(PathOp::MoveTo(x, y));
+            }
+            PathOp::LineTo(x, y) => {
+                let (x, y) = map(x, y);
+                target.push(PathOp::LineTo(x, y));
+            }
+            PathOp::CurveTo(x1, y1, x2, y2, x, y) => {
+                let ((x1, y1), (x2, y2), (x, y)) = (map(x1, y1), map(x2, y2), map(x, y));
+                target.push(PathOp::CurveTo(x1, y1, x2, y2, x, y));
+            }
+            PathOp::Rect(x, y, w, h) => {
+                let corners = [map(x, y), map(x + w, y), map(x + w, y + h), map(x, y + h)];
+                target.push(PathOp::MoveTo(corners[0].0, corners[0].1));
+                for (x, y) in &corners[1..] {
+                    target.push(PathOp::LineTo(*x, *y));
+                }
+                target.push(PathOp::Close);
+            }
+            PathOp::Close => target.push(PathOp::Close),
+        }
+    }
+}
+
+/// Device collecting the painted paths of a glyph procedure in the coordinates of its initial ctm
+#[derive(Default)]
+pub(crate) struct OutlineRecorder {
+    ops: Vec<PathOp>,
+}
+
+impl OutlineRecorder {
+    pub(crate) fn into_path(self) -> Path {
+        Path { ops: self.ops }
+    }
+}
+
+impl OutputDev for OutlineRecorder {
+    fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+    fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+    fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> { Ok(()) }
+    fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+
+    fn stroke(&mut self, ctm: &PdfTransform, _: &ColorSpace, _: &[f64], path: &Path) -> PdfResult<()> {
+        extend_transformed(&mut self.ops, path, ctm);
+        Ok(())
+    }
+
+    fn fill(&mut self, ctm: &PdfTransform, _: &ColorSpace, _: &[f64], path: &Path, _: FillRule) -> PdfResult<()> {
+        extend_transformed(&mut self.ops, path, ctm);
+        Ok(())
+    }
+}
+
+struct CffOutline {
+    ops: Vec<PathOp>,
+    scale: f64,
+    current: (f64, f64),
+}
+
+impl OutlineBuilder for CffOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.current = (x as f64 * self.scale, y as f64 * self.scale);
+        self.ops.push(PathOp::MoveTo(self.current.0, self.current.1));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.current = (x as f64 * self.scale, y as f64 * self.scale);
+        self.ops.push(PathOp::LineTo(self.current.0, self.current.1));
+    }
+
+    // Raise to a cubic with the usual 2/3 control point construction
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.current;
+        let (x1, y1) = (x1 as f64 * self.scale, y1 as f64 * self.scale);
+        let (x, y) = (x as f64 * self.scale, y as f64 * self.scale);
+        self.ops.push(PathOp::CurveTo(
+            x0 + 2. / 3. * (x1 - x0),
+            y0 + 2. / 3. * (y1 - y0),
+            x + 2. / 3. * (x1 - x),
+            y + 2. / 3. * (y1 - y),
+            x,
+            y,
+        ));
+        self.current = (x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let s = self.scale;
+        self.current = (x as f64 * s, y as f64 * s);
+        self.ops.push(PathOp::CurveTo(
+            x1 as f64 * s,
+            y1 as f64 * s,
+            x2 as f64 * s,
+            y2 as f64 * s,
+            self.current.0,
+            self.current.1,
+        ));
+    }
+
+    fn close(&mut self) {
+        self.ops.push(PathOp::Close);
+    }
+}
+
+/// Outline of `code` in a bare CFF font program
+pub(crate) fn cff_outline(program: &[u8], code: u8) -> Option<Path> {
+    let cff = Table::parse(program)?;
+    let gid = cff.glyph_index(code).filter(|gid| gid.0 != 0)?;
+    let mut outline = CffOutline { ops: Vec::new(), scale: cff.matrix().sx as f64 * 1000., current: (0., 0.) };
+    cff.outline(gid, &mut outline).ok()?;
+    (!outline.ops.is_empty()).then_some(Path { ops: outline.ops })
+}
+
+// Em box used for glyph images, in glyph space
+const ASCENT: f64 = 800.;
+const EM: f64 = 1000.;
+/// Fraction of a glyph image above the baseline
+pub(crate) const ASCENT_RATIO: f64 = ASCENT / EM;
+
+/// Standalone SVG document of a glyph `advance` units wide, the em box spanning its height
+pub(crate) fn glyph_svg(glyph: &UnmappedGlyph, advance: f64) -> String {
+    let mut d = Vec::new();
+    for op in &glyph.outline.ops {
+        match op {
+            PathOp::MoveTo(x, y) => d.push(format!("M{} {}", x, y)),
+            PathOp::LineTo(x, y) => d.push(format!("L{} {}", x, y)),
+            PathOp::CurveTo(x1, y1, x2, y2, x, y) => d.push(format!("C{} {} {} {} {} {}", x1, y1, x2, y2, x, y)),
+            PathOp::Rect(x, y, w, h) => d.push(format!("M{} {}h{}v{}h{}Z", x, y, w, h, -w)),
+            PathOp::Close => d.push("Z".to_string()),
+        }
+    }
+    format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 {} {} {}'><path transform='scale(1 -1)' d='{}'/></svg>",
+        -ASCENT,
+        advance.max(1.),
+        EM,
+        d.join(" ")
+    )
+}
+
+/// `data:` URI of the SVG image of a glyph
+pub(crate) fn glyph_data_uri(glyph: &UnmappedGlyph, advance: f64) -> String {
+    format!("data:image/svg+xml;base64,{}", string_utils::base64(glyph_svg(glyph, advance).as_bytes()))
+}
@@ -0,0 +1,174 @@
+// User supplied char code to text overrides for fonts with broken ToUnicode maps
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::slice::Iter;
+use std::sync::Arc;
+
+use crate::fonts::FontStyle;
+use crate::{CharCode, GlyphProcedure, Path, PdfFont};
+
+/// When an override is consulted relative to the font's own decoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverrideMode {
+    /// The override replaces whatever `decode_char` would produce
+    BeforeDecode,
+    /// The override is only used when `decode_char` yields nothing usable
+    /// (an empty string, U+FFFD or control characters)
+    AfterDecode,
+}
+
+#[derive(Debug, Clone)]
+pub struct GlyphOverrideRule {
+    /// `BaseFont` glob such as `ABCDEF+Garamond*`; `*` and `?` are wildcards.
+    /// A pattern without a subset tag also matches subsetted fonts.
+    pub font_pattern: String,
+    pub mode: OverrideMode,
+    pub codes: HashMap<CharCode, String>,
+}
+
+/// Registry of per-font overrides, consulted in insertion order with later rules winning
+#[derive(Debug, Clone, Default)]
+pub struct GlyphOverrides {
+    rules: Vec<GlyphOverrideRule>,
+}
+
+impl GlyphOverrides {
+    pub fn new() -> GlyphOverrides {
+        GlyphOverrides::default()
+    }
+
+    /// Register `codes` for fonts whose `BaseFont` matches `font_pattern`
+    pub fn add<S: Into<String>>(
+        &mut self,
+        font_pattern: &str,
+        mode: OverrideMode,
+        codes: impl IntoIterator<Item = (CharCode, S)>,
+    ) -> &mut Self {
+        self.rules.push(GlyphOverrideRule {
+            font_pattern: font_pattern.to_string(),
+            mode,
+            codes: codes.into_iter().map(|(code, s)| (code, s.into())).collect(),
+        });
+        self
+    }
+
+    pub fn rules(&self) -> &[GlyphOverrideRule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    // Merge all rules matching `base_font` into before/after tables
+    fn resolve(&self, base_font: &str) -> Option<ResolvedOverrides> {
+        let mut resolved = ResolvedOverrides::default();
+        for rule in self.rules.iter().filter(|r| font_matches(&r.font_pattern, base_font)) {
+            let (table, other) = match rule.mode {
+                OverrideMode::BeforeDecode => (&mut resolved.before, &mut resolved.after),
+                OverrideMode::AfterDecode => (&mut resolved.after, &mut resolved.before),
+            };
+            for (code, text) in &rule.codes {
+                other.remove(code);
+                table.insert(*code, text.clone());
+            }
+        }
+        (!resolved.before.is_empty() || !resolved.after.is_empty()).then_some(resolved)
+    }
+}
+
+/// Whether `pattern` matches `base_font`, ignoring a `ABCDEF+` subset tag the pattern doesn't mention
+pub fn font_matches(pattern: &str, base_font: &str) -> bool {
+    if glob_match(pattern.as_bytes(), base_font.as_bytes()) {
+        return true;
+    }
+    match base_font.split_once('+') {
+        Some((tag, name)) if tag.len() == 6 && tag.bytes().all(|b| b.is_ascii_uppercase()) && !pattern.contains('+') => {
+            glob_match(pattern.as_bytes(), name.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        Some((b'?', rest)) => !text.is_empty() && glob_match(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+#[derive(Debug, Default)]
+struct ResolvedOverrides {
+    before: HashMap<CharCode, String>,
+    after: HashMap<CharCode, String>,
+}
+
+/// Font wrapper that applies overrides around the wrapped font's `decode_char`
+#[derive(Debug)]
+struct OverriddenFont {
+    inner: Arc<dyn PdfFont>,
+    overrides: ResolvedOverrides,
+}
+
+impl PdfFont for OverriddenFont {
+    fn base_name(&self) -> Option<&str> {
+        self.inner.base_name()
+    }
+
+    fn get_width(&self, id: CharCode) -> f64 {
+        self.inner.get_width(id)
+    }
+
+    fn next_char(&self, iter: &mut Iter<u8>) -> Option<(CharCode, u8)> {
+        self.inner.next_char(iter)
+    }
+
+    fn kerning(&self, left: CharCode, right: CharCode) -> f64 {
+        self.inner.kerning(left, right)
+    }
+
+    fn glyph_procedure(&self, char: CharCode) -> Option<GlyphProcedure<'_>> {
+        self.inner.glyph_procedure(char)
+    }
+
+    fn glyph_outline(&self, char: CharCode) -> Option<Path> {
+        self.inner.glyph_outline(char)
+    }
+
+    fn glyph_id(&self, char: CharCode) -> Option<u16> {
+        self.inner.glyph_id(char)
+    }
+
+    fn space_width(&self) -> Option<f64> {
+        self.inner.space_width()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.inner.style()
+    }
+
+    fn decode_char(&self, char: CharCode) -> String {
+        if let Some(s) = self.overrides.before.get(&char) {
+            return s.clone();
+        }
+        let decoded = self.inner.decode_char(char);
+        let unusable = decoded.is_empty() || decoded.chars().all(|c| c == '\u{fffd}' || c.is_control());
+        match self.overrides.after.get(&char) {
+            Some(s) if unusable => s.clone(),
+            _ => decoded,
+        }
+    }
+}
+
+// Wrap `font` if any registered rule applies to it
+pub(crate) fn apply(overrides: &GlyphOverrides, font: Arc<dyn PdfFont>, base_font: Option<&str>) -> Arc<dyn PdfFont> {
+    if overrides.is_empty() {
+        return font;
+    }
+    match overrides.resolve(base_font.unwrap_or_default()) {
+        Some(resolved) => Arc::new(OverriddenFont { inner: font, overrides: resolved }),
+        None => font,
+    }
+}
@@ -0,0 +1,82 @@
+// Page labels
+//
+// The catalog's `/PageLabels` number tree maps the zero based index of the
+// first page of each range to a label dictionary: a numbering style `/S`,
+// a `/P` prefix and the `/St` number of the range's first page. Front
+// matter numbered "i", "ii" and appendices numbered "A-1" are ranges.
+use std::collections::BTreeMap;
+
+use crate::{document_utils, object_utils, string_utils, Dictionary, Document, PdfResult};
+
+// Largest number written as a roman numeral or letters, whose length grows with the number;
+// larger ones are written in decimal
+const MAX_STYLED_NUMBER: u64 = 9999;
+
+/// Printed label of each page by page number; pages before the first range, or every page when
+/// the document has no `/PageLabels`, are labelled with their page number
+pub fn extract_page_labels(doc: &Document) -> PdfResult<BTreeMap<u32, String>> {
+    let catalog = document_utils::get_catalog(doc)?;
+    let mut ranges: Vec<(i64, &Dictionary)> = match catalog.get(b"PageLabels") {
+        Ok(root) => document_utils::number_tree(doc, root)
+            .into_iter()
+            .filter_map(|(start, label)| Some((start, object_utils::maybe_deref(doc, label).ok()?.as_dict().ok()?)))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    ranges.sort_by_key(|(start, _)| *start);
+    Ok(doc.get_pages()
+        .into_keys()
+        .map(|page_num| {
+            let index = i64::from(page_num) - 1;
+            let label = match ranges.iter().rfind(|(start, _)| *start <= index) {
+                Some((start, range)) => label(doc, range, index - start, page_num),
+                None => page_num.to_string(),
+            };
+            (page_num, label)
+        })
+        .collect())
+}
+
+// Label of the page `offset` pages into `range`, numbered `page_num` when the range's numbers
+// overflow
+fn label(doc: &Document, range: &Dictionary, offset: i64, page_num: u32) -> String {
+    let prefix = object_utils::maybe_get_obj(doc, range, b"P")
+        .and_then(|o| o.as_str().ok())
+        .and_then(|s| string_utils::pdf_to_utf8(s).ok())
+        .unwrap_or_default();
+    let start = object_utils::maybe_get_obj(doc, range, b"St").and_then(|o| o.as_i64().ok()).unwrap_or(1);
+    let number = start.checked_add(offset).map_or(u64::from(page_num), |n| n.max(1) as u64);
+    let number = match range.get(b"S").and_then(|o| o.as_name()).ok() {
+        Some(b"D") => number.to_string(),
+        Some(b"R" | b"r" | b"A" | b"a") if number > MAX_STYLED_NUMBER => number.to_string(),
+        Some(b"R") => roman(number).to_uppercase(),
+        Some(b"r") => roman(number),
+        Some(b"A") => letters(number).to_uppercase(),
+        Some(b"a") => letters(number),
+        // Without a style the label is the prefix alone
+        _ => String::new(),
+    };
+    prefix + &number
+}
+
+// Lowercase roman numeral
+fn roman(mut n: u64) -> String {
+    const NUMERALS: [(u64, &str); 13] = [
+        (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"), (100, "c"), (90, "xc"),
+        (50, "l"), (40, "xl"), (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i"),
+    ];
+    let mut s = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            s.push_str(numeral);
+            n -= value;
+        }
+    }
+    s
+}
+
+// a to z, then aa to zz, aaa to zzz and so on
+fn letters(n: u64) -> String {
+    let letter = (b'a' + ((n - 1) % 26) as u8) as char;
+    std::iter::repeat_n(letter, ((n - 1) / 26 + 1) as usize).collect()
+}
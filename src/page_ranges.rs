@@ -0,0 +1,76 @@
+// Page selection expressions
+//
+// The page lists users type, e.g. `1-3,7,12-`: comma-separated pages and
+// ranges, where a range may leave out its start (from the first page) or its
+// end (to the last), `last` names the final page, and a range whose start is
+// after its end runs backwards, so `last-1` is the whole document in reverse.
+// Expressions are parsed up front and resolved once the page count is known.
+use std::str::FromStr;
+
+use crate::{PdfError, PdfResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    Page(u32),
+    Last,
+}
+
+impl Bound {
+    fn resolve(self, page_count: u32) -> u32 {
+        match self {
+            Bound::Page(page) => page,
+            Bound::Last => page_count,
+        }
+    }
+}
+
+/// A parsed page selection expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRanges {
+    // Start and end of each item, equal for single pages
+    ranges: Vec<(Bound, Bound)>,
+}
+
+impl PageRanges {
+    /// Page numbers selected in a document of `page_count` pages, in the order written; pages past
+    /// the end are left out
+    pub fn resolve(&self, page_count: u32) -> Vec<u32> {
+        let mut pages = Vec::new();
+        for &(start, end) in &self.ranges {
+            let (start, end) = (start.resolve(page_count), end.resolve(page_count));
+            if start <= end {
+                pages.extend(start..=end.min(page_count));
+            } else {
+                pages.extend((end..=start.min(page_count)).rev());
+            }
+        }
+        pages
+    }
+}
+
+impl FromStr for PageRanges {
+    type Err = PdfError;
+
+    fn from_str(expression: &str) -> PdfResult<PageRanges> {
+        let invalid = |item: &str| PdfError::InvalidStructure(format!("Invalid page selection: {:?}", item));
+        let bound = |text: &str, item: &str| match text.trim() {
+            "last" => Ok(Bound::Last),
+            page => match page.parse() {
+                Ok(0) | Err(_) => Err(invalid(item)),
+                Ok(page) => Ok(Bound::Page(page)),
+            },
+        };
+        let ranges = expression
+            .split(',')
+            .map(|item| match item.split_once('-') {
+                Some((start, end)) => {
+                    let start = if start.trim().is_empty() { Bound::Page(1) } else { bound(start, item)? };
+                    let end = if end.trim().is_empty() { Bound::Last } else { bound(end, item)? };
+                    Ok((start, end))
+                }
+                None => bound(item, item).map(|page| (page, page)),
+            })
+            .collect::<PdfResult<Vec<_>>>()?;
+        Ok(PageRanges { ranges })
+    }
+}
@@ -0,0 +1,70 @@
+// Time spent per content stream operator and per font
+//
+// Enabled with `ExtractionOptions::profile` and returned in
+// `Diagnostics::profile`. Timings are inclusive: a `Do` includes the
+// operators of the form it runs, and a font's time is that of the `Tj` and
+// `TJ` operators showing text in it, Type3 glyph procedures included.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+/// How often something ran and for how long in total
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    pub count: u64,
+    pub total: Duration,
+}
+
+impl Timing {
+    fn add(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total += elapsed;
+    }
+}
+
+/// Accumulated timings of one extraction
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    /// Keyed by operator, e.g. `Tj` or `Do`
+    pub operators: BTreeMap<String, Timing>,
+    /// Keyed by `BaseFont`, empty for fonts without one
+    pub fonts: BTreeMap<String, Timing>,
+}
+
+impl Profile {
+    pub(crate) fn record(&mut self, operator: &str, font: Option<&str>, elapsed: Duration) {
+        self.operators.entry(operator.to_string()).or_default().add(elapsed);
+        if let ("Tj" | "TJ", Some(font)) = (operator, font) {
+            self.fonts.entry(font.to_string()).or_default().add(elapsed);
+        }
+    }
+
+    /// Operators by descending total time
+    pub fn hottest_operators(&self) -> Vec<(&str, Timing)> {
+        hottest(&self.operators)
+    }
+
+    /// Fonts by descending total time
+    pub fn hottest_fonts(&self) -> Vec<(&str, Timing)> {
+        hottest(&self.fonts)
+    }
+}
+
+fn hottest(timings: &BTreeMap<String, Timing>) -> Vec<(&str, Timing)> {
+    let mut sorted: Vec<_> = timings.iter().map(|(name, timing)| (name.as_str(), *timing)).collect();
+    sorted.sort_by_key(|(_, timing)| std::cmp::Reverse(timing.total));
+    sorted
+}
+
+/// A table of operators then fonts, hottest first
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (title, rows) in [("operator", self.hottest_operators()), ("font", self.hottest_fonts())] {
+            writeln!(f, "{:<32} {:>10} {:>12}", title, "count", "total ms")?;
+            for (name, timing) in rows {
+                writeln!(f, "{:<32} {:>10} {:>12.3}", name, timing.count, timing.total.as_secs_f64() * 1000.)?;
+            }
+        }
+        Ok(())
+    }
+}
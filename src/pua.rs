@@ -0,0 +1,147 @@
+// Handling of Unicode private use area output from fonts without a ToUnicode map
+//
+// Symbol fonts commonly map their glyphs into the PUA (U+F020..U+F0FF for
+// Microsoft symbol fonts). Without a ToUnicode map those codepoints carry no
+// meaning for text consumers, so a policy decides whether they are kept,
+// dropped or replaced, and every occurrence is counted per font.
+use std::collections::{BTreeMap, HashMap};
+use std::slice::Iter;
+use std::sync::{Arc, Mutex};
+
+use crate::fonts::FontStyle;
+use crate::{CharCode, GlyphProcedure, Path, PdfFont};
+
+/// What to do with private use codepoints decoded by fonts without a ToUnicode map
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PuaPolicy {
+    /// Emit the codepoints unchanged
+    #[default]
+    PassThrough,
+    /// Remove the codepoints from the output
+    Drop,
+    /// Substitute each codepoint with the given string, e.g. `"\u{fffd}"`
+    Replace(String),
+}
+
+/// Private use codepoints decoded by one font
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuaFontStats {
+    /// `BaseFont` of the font, empty if it has none
+    pub font: String,
+    /// Occurrences of each codepoint
+    pub codepoints: BTreeMap<char, usize>,
+}
+
+impl PuaFontStats {
+    /// Total number of private use codepoints decoded
+    pub fn occurrences(&self) -> usize {
+        self.codepoints.values().sum()
+    }
+}
+
+pub fn is_private_use(c: char) -> bool {
+    matches!(c, '\u{e000}'..='\u{f8ff}' | '\u{f0000}'..='\u{ffffd}' | '\u{100000}'..='\u{10fffd}')
+}
+
+/// Per-font counters shared between the wrapped fonts of one extraction
+#[derive(Debug, Default)]
+pub(crate) struct PuaRegistry {
+    fonts: HashMap<String, Arc<Mutex<PuaFontStats>>>,
+}
+
+impl PuaRegistry {
+    /// Fonts that decoded at least one private use codepoint, by name
+    pub(crate) fn stats(&self) -> Vec<PuaFontStats> {
+        let mut stats: Vec<PuaFontStats> = self.fonts.values()
+            .map(|s| s.lock().unwrap_or_else(|e| e.into_inner()).clone())
+            .filter(|s| !s.codepoints.is_empty())
+            .collect();
+        stats.sort_by(|a, b| a.font.cmp(&b.font));
+        stats
+    }
+}
+
+/// Font wrapper applying the policy to the wrapped font's `decode_char`
+#[derive(Debug)]
+struct PuaFont {
+    inner: Arc<dyn PdfFont>,
+    policy: PuaPolicy,
+    stats: Arc<Mutex<PuaFontStats>>,
+}
+
+impl PdfFont for PuaFont {
+    fn base_name(&self) -> Option<&str> {
+        self.inner.base_name()
+    }
+
+    fn get_width(&self, id: CharCode) -> f64 {
+        self.inner.get_width(id)
+    }
+
+    fn next_char(&self, iter: &mut Iter<u8>) -> Option<(CharCode, u8)> {
+        self.inner.next_char(iter)
+    }
+
+    fn kerning(&self, left: CharCode, right: CharCode) -> f64 {
+        self.inner.kerning(left, right)
+    }
+
+    fn glyph_procedure(&self, char: CharCode) -> Option<GlyphProcedure<'_>> {
+        self.inner.glyph_procedure(char)
+    }
+
+    fn glyph_outline(&self, char: CharCode) -> Option<Path> {
+        self.inner.glyph_outline(char)
+    }
+
+    fn glyph_id(&self, char: CharCode) -> Option<u16> {
+        self.inner.glyph_id(char)
+    }
+
+    fn space_width(&self) -> Option<f64> {
+        self.inner.space_width()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.inner.style()
+    }
+
+    fn decode_char(&self, char: CharCode) -> String {
+        let decoded = self.inner.decode_char(char);
+        if !decoded.chars().any(is_private_use) {
+            return decoded;
+        }
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::with_capacity(decoded.len());
+        for c in decoded.chars() {
+            if !is_private_use(c) {
+                out.push(c);
+                continue;
+            }
+            *stats.codepoints.entry(c).or_default() += 1;
+            match &self.policy {
+                PuaPolicy::PassThrough => out.push(c),
+                PuaPolicy::Drop => {}
+                PuaPolicy::Replace(replacement) => out.push_str(replacement),
+            }
+        }
+        out
+    }
+}
+
+// Wrap a font that has no ToUnicode map so its private use output is counted and filtered
+pub(crate) fn apply(
+    policy: &PuaPolicy,
+    registry: &mut PuaRegistry,
+    font: Arc<dyn PdfFont>,
+    has_to_unicode: bool,
+) -> Arc<dyn PdfFont> {
+    if has_to_unicode {
+        return font;
+    }
+    let name = font.base_name().unwrap_or_default().to_string();
+    let stats = registry.fonts.entry(name.clone())
+        .or_insert_with(|| Arc::new(Mutex::new(PuaFontStats { font: name, codepoints: BTreeMap::new() })))
+        .clone();
+    Arc::new(PuaFont { inner: font, policy: policy.clone(), stats })
+}
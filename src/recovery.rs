@@ -0,0 +1,167 @@
+// Loading of damaged files
+//
+// `Document::load` trusts the cross-reference table: a truncated file, a
+// table whose offsets were shifted by an FTP transfer in text mode or an
+// incremental update pointing past the end keeps it from loading, or leaves
+// it without pages. The objects themselves are usually intact. Recovery
+// scans the file for `n g obj` headers, appends a fresh table listing their
+// offsets (later definitions winning, as with incremental updates) and a
+// trailer pointing to the catalog, and loads the result.
+use std::collections::BTreeMap;
+
+use log::warn;
+
+use crate::{Document, PdfResult};
+
+/// `Document::load_mem`, rebuilding the cross-reference table if loading fails or finds no pages
+pub fn load_mem_with_recovery(buffer: &[u8]) -> PdfResult<Document> {
+    match Document::load_mem(buffer) {
+        Ok(doc) if !doc.get_pages().is_empty() => Ok(doc),
+        loaded => {
+            let repaired = repair(buffer);
+            match Document::load_mem(&repaired) {
+                Ok(doc) if !doc.get_pages().is_empty() => {
+                    warn!("Rebuilt the cross-reference table of a damaged file");
+                    Ok(doc)
+                }
+                // The original error says more about what is wrong
+                _ => Ok(loaded?),
+            }
+        }
+    }
+}
+
+/// `Document::load` with the recovery of `load_mem_with_recovery`
+pub fn load_with_recovery<P: AsRef<std::path::Path>>(path: P) -> PdfResult<Document> {
+    load_mem_with_recovery(&std::fs::read(path)?)
+}
+
+/// `buffer` with a cross-reference table and trailer rebuilt from its object headers appended
+pub fn repair(buffer: &[u8]) -> Vec<u8> {
+    let objects = scan_objects(buffer);
+    // One entry per object number, the highest generation found; a number without a successor
+    // can't be counted in `/Size`, so it is left out
+    let mut offsets: BTreeMap<u32, (u16, usize)> = BTreeMap::new();
+    for (&(id, generation), &offset) in &objects {
+        if id.checked_add(1).is_some() {
+            offsets.insert(id, (generation, offset));
+        }
+    }
+    let size = offsets.keys().next_back().and_then(|id| id.checked_add(1)).unwrap_or(1);
+
+    let mut out = buffer.to_vec();
+    out.push(b'\n');
+    let xref_start = out.len();
+    out.extend_from_slice(b"xref\n0 1\n0000000000 65535 f\r\n");
+    // A subsection per run of consecutive object numbers, so sparse numbering stays small
+    let ids: Vec<u32> = offsets.keys().copied().collect();
+    for run in ids.chunk_by(|a, b| b.checked_sub(*a) == Some(1)) {
+        out.extend_from_slice(format!("{} {}\n", run[0], run.len()).as_bytes());
+        for id in run {
+            let (generation, offset) = offsets[id];
+            out.extend_from_slice(format!("{:010} {:05} n\r\n", offset, generation).as_bytes());
+        }
+    }
+
+    let trailer = last_trailer(buffer);
+    let reference = |key: &str| trailer.as_deref().and_then(|t| find_reference(t, key));
+    let root = reference("/Root").or_else(|| find_catalog(buffer, &objects));
+    let mut dict = format!("/Size {}", size);
+    if let Some((id, generation)) = root {
+        dict += &format!(" /Root {} {} R", id, generation);
+    }
+    for key in ["/Info", "/Encrypt"] {
+        if let Some((id, generation)) = reference(key) {
+            dict += &format!(" {} {} {} R", key, id, generation);
+        }
+    }
+    // Decryption needs the file identifier
+    if let Some(id) = trailer.as_deref().and_then(find_id) {
+        dict += &format!(" /ID {}", id);
+    }
+    out.extend_from_slice(format!("trailer\n<< {} >>\nstartxref\n{}\n%%EOF\n", dict, xref_start).as_bytes());
+    out
+}
+
+// Offsets of `n g obj` headers by object number and generation
+fn scan_objects(buffer: &[u8]) -> BTreeMap<(u32, u16), usize> {
+    let mut objects = BTreeMap::new();
+    let mut pos = 0;
+    while let Some(found) = find(&buffer[pos..], b"obj") {
+        let at = pos + found;
+        pos = at + 3;
+        if buffer.get(at + 3).is_some_and(|c| !is_delimiter(*c)) {
+            continue;
+        }
+        let Some((id, generation, start)) = object_header(buffer, at) else { continue };
+        objects.insert((id, generation), start);
+        // Skip the body, so stream data can't produce false headers
+        if let Some(end) = find(&buffer[pos..], b"endobj") {
+            pos += end + 6;
+        }
+    }
+    objects
+}
+
+// Object number, generation and start of the header whose `obj` keyword is at `at`
+fn object_header(buffer: &[u8], at: usize) -> Option<(u32, u16, usize)> {
+    let number_before = |end: usize| {
+        let end = end - buffer[..end].iter().rev().take_while(|c| c.is_ascii_whitespace()).count();
+        let digits = buffer[..end].iter().rev().take_while(|c| c.is_ascii_digit()).count();
+        let start = end - digits;
+        (digits > 0).then(|| (std::str::from_utf8(&buffer[start..end]).ok(), start))
+    };
+    let (generation, generation_start) = number_before(at)?;
+    let (id, start) = number_before(generation_start)?;
+    if start > 0 && !is_delimiter(buffer[start - 1]) {
+        return None;
+    }
+    Some((id?.parse().ok()?, generation?.parse().ok()?, start))
+}
+
+// Text of the dictionary after the last `trailer` keyword
+fn last_trailer(buffer: &[u8]) -> Option<String> {
+    let start = rfind(buffer, b"trailer")?;
+    let rest = &buffer[start..];
+    let end = find(rest, b"startxref").unwrap_or(rest.len().min(4096));
+    Some(String::from_utf8_lossy(&rest[..end]).into_owned())
+}
+
+// `n g R` after `key` in dictionary text
+fn find_reference(text: &str, key: &str) -> Option<(u32, u16)> {
+    let rest = &text[text.find(key)? + key.len()..];
+    let mut words = rest.split_whitespace();
+    let id = words.next()?.parse().ok()?;
+    let generation = words.next()?.parse().ok()?;
+    words.next()?.starts_with('R').then_some((id, generation))
+}
+
+// The `[<...> <...>]` array of `/ID` in dictionary text
+fn find_id(text: &str) -> Option<String> {
+    let start = text.find("/ID")? + 3;
+    let open = start + text[start..].find('[')?;
+    let close = open + text[open..].find(']')?;
+    Some(text[open..=close].to_string())
+}
+
+// An object whose body declares `/Type /Catalog`, for files that lost their trailer
+fn find_catalog(buffer: &[u8], objects: &BTreeMap<(u32, u16), usize>) -> Option<(u32, u16)> {
+    objects.iter().rev().find_map(|(&id, &offset)| {
+        let body = &buffer[offset..];
+        let body = &body[..find(body, b"endobj").unwrap_or(body.len())];
+        let body: Vec<u8> = body.iter().copied().filter(|c| !c.is_ascii_whitespace()).collect();
+        find(&body, b"/Type/Catalog").map(|_| id)
+    })
+}
+
+fn is_delimiter(c: u8) -> bool {
+    c.is_ascii_whitespace() || b"()<>[]{}/%".contains(&c)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
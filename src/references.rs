@@ -0,0 +1,242 @@
+// Bibliography splitting for scientific papers
+//
+// The lines following a "References"/"Bibliography" heading are split into
+// entries either at numbering labels ("[12]", "12.", "(12)") or, for
+// author-year lists, at lines that start on the hanging-indent margin.
+use crate::layout::{collect_text, join_lines, BBox, PageText, TextLine};
+use crate::{Document, PdfResult};
+
+const HEADINGS: &[&str] = &["references", "bibliography", "works cited", "literature cited", "literature", "cited literature"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceStyle {
+    /// Entries start with `[1]`, `1.` or `(1)`
+    Numbered,
+    /// Entries start at the margin and wrap onto indented lines
+    HangingIndent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    /// Numbering label as printed, e.g. `[12]`, for numbered lists
+    pub label: Option<String>,
+    /// Entry text with line breaks unwrapped and the label removed
+    pub text: String,
+    pub doi: Option<String>,
+    /// arXiv identifier without the `arXiv:` prefix, e.g. `2101.01234v2`
+    pub arxiv: Option<String>,
+    /// Page (1 based) the entry starts on
+    pub page_num: u32,
+    /// Bounding box of the entry's lines on its first page
+    pub bbox: BBox,
+}
+
+/// Split the reference section(s) of `doc` into entries
+pub fn extract_references(doc: &Document) -> PdfResult<Vec<Reference>> {
+    Ok(parse_references(&collect_text(doc)?))
+}
+
+/// Split the reference section(s) of already collected pages into entries
+pub fn parse_references(pages: &[PageText]) -> Vec<Reference> {
+    let mut section: Vec<(u32, f64, TextLine)> = Vec::new();
+    let mut in_section = false;
+    for page in pages {
+        let width = page.media_box.urx - page.media_box.llx;
+        for line in page.lines() {
+            let text = line.text.trim();
+            if is_reference_heading(text) {
+                in_section = true;
+            } else if in_section && is_section_end(text) {
+                in_section = false;
+            } else if in_section {
+                section.push((page.page_num, width, line));
+            }
+        }
+    }
+    let Some((_, _, first)) = section.first() else {
+        return Vec::new();
+    };
+    let style = if numbering_label(&first.text).is_some() {
+        ReferenceStyle::Numbered
+    } else {
+        ReferenceStyle::HangingIndent
+    };
+    split_entries(&section, style).into_iter().map(|entry| make_reference(entry, style)).collect()
+}
+
+/// The first DOI in `text`, e.g. `10.1000/xyz123` from `https://doi.org/10.1000/xyz123.`
+pub fn find_doi(text: &str) -> Option<&str> {
+    let mut search = 0;
+    while let Some(pos) = text[search..].find("10.") {
+        let start = search + pos;
+        search = start + 3;
+        // "10." must start a token, or follow a URL/`doi:` prefix
+        if text[..start].chars().next_back().is_some_and(|c| c.is_alphanumeric() || c == '.') {
+            continue;
+        }
+        let candidate = &text[start..];
+        let registrant = candidate[3..].bytes().take_while(|b| b.is_ascii_digit() || *b == b'.').count();
+        if registrant < 4 || candidate.as_bytes().get(3 + registrant) != Some(&b'/') {
+            continue;
+        }
+        let end = candidate.find(|c: char| c.is_whitespace() || c == '"' || c == '<' || c == '>').unwrap_or(candidate.len());
+        let doi = candidate[..end].trim_end_matches(['.', ',', ';', ':', ')', ']']);
+        if doi.len() > 4 + registrant {
+            return Some(doi);
+        }
+    }
+    None
+}
+
+/// The first arXiv identifier in `text`: `arXiv:2101.01234v2`, `arxiv.org/abs/hep-th/9901001`
+pub fn find_arxiv_id(text: &str) -> Option<&str> {
+    let lower = text.to_ascii_lowercase();
+    for prefix in ["arxiv:", "arxiv.org/abs/", "arxiv.org/pdf/", "arxiv "] {
+        let mut search = 0;
+        while let Some(pos) = lower[search..].find(prefix) {
+            let start = search + pos + prefix.len();
+            search = start;
+            let candidate = &text[start..];
+            let end = candidate.find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '/' || c == '-'))
+                .unwrap_or(candidate.len());
+            let id = candidate[..end].trim_end_matches(['.', '/']);
+            if is_arxiv_id(id) {
+                return Some(id);
+            }
+        }
+    }
+    None
+}
+
+// New style `YYMM.NNNNN[vN]` or old style `archive[.class]/YYMMNNN[vN]`
+fn is_arxiv_id(id: &str) -> bool {
+    let id = match id.rfind('v') {
+        Some(v) if v > 0 && id[v + 1..].bytes().all(|b| b.is_ascii_digit()) && v + 1 < id.len() => &id[..v],
+        _ => id,
+    };
+    match id.split_once('/') {
+        Some((archive, number)) => {
+            archive.starts_with(|c: char| c.is_ascii_lowercase())
+                && number.len() == 7
+                && number.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => match id.split_once('.') {
+            Some((yymm, number)) => {
+                yymm.len() == 4
+                    && (4..=5).contains(&number.len())
+                    && yymm.bytes().chain(number.bytes()).all(|b| b.is_ascii_digit())
+            }
+            None => false,
+        },
+    }
+}
+
+fn is_reference_heading(text: &str) -> bool {
+    // Allow section numbering such as "7 References" or "VII. REFERENCES"
+    let lower = text.to_lowercase();
+    let title = match lower.split_once(' ') {
+        Some((number, title)) if number.chars().all(|c| c.is_ascii_digit() || "ivxlc.".contains(c)) => title,
+        _ => &lower,
+    };
+    HEADINGS.contains(&title.trim().trim_end_matches(':'))
+}
+
+fn is_section_end(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    text.split_whitespace().count() <= 6
+        && ["appendix", "supplementary material", "supplemental material", "index"].iter()
+            .any(|h| lower.trim_start_matches(|c: char| !c.is_alphabetic()).starts_with(h))
+}
+
+// `[12]`, `12.`, `(12)`, `12` followed by a space; returns (label, number, rest)
+fn numbering_label(text: &str) -> Option<(&str, u32, &str)> {
+    let text = text.trim_start();
+    let (open, close) = match text.chars().next()? {
+        '[' => (1, ']'),
+        '(' => (1, ')'),
+        _ => (0, '.'),
+    };
+    let digits = text[open..].bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 || digits > 4 {
+        return None;
+    }
+    let number = text[open..open + digits].parse().ok()?;
+    let mut label_len = open + digits;
+    if text[label_len..].starts_with(close) {
+        label_len += 1;
+    } else if open == 1 {
+        return None;
+    }
+    let rest = &text[label_len..];
+    rest.starts_with(char::is_whitespace).then_some((&text[..label_len], number, rest.trim_start()))
+}
+
+fn split_entries(section: &[(u32, f64, TextLine)], style: ReferenceStyle) -> Vec<Vec<(u32, TextLine)>> {
+    let mut entries: Vec<Vec<(u32, TextLine)>> = Vec::new();
+    let mut expected = 1;
+    let mut margin = f64::NAN;
+    let mut last_page = 0;
+    for (page_num, page_width, line) in section {
+        let starts_entry = match style {
+            ReferenceStyle::Numbered => match numbering_label(&line.text) {
+                // Bracketed labels are unambiguous; bare numbers must continue the sequence
+                Some((label, number, _)) if label.starts_with(['[', '(']) || number == expected || entries.is_empty() => {
+                    expected = number + 1;
+                    true
+                }
+                _ => false,
+            },
+            ReferenceStyle::HangingIndent => {
+                let x = line.bbox.llx;
+                let tolerance = line.font_size * 0.5;
+                if margin.is_nan() || *page_num != last_page || x < margin - tolerance || x > margin + page_width * 0.25 {
+                    // New page or column: take its first line as the margin
+                    margin = x;
+                    !continues_entry(entries.last(), line)
+                } else {
+                    x <= margin + tolerance
+                }
+            }
+        };
+        last_page = *page_num;
+        if starts_entry || entries.is_empty() {
+            entries.push(Vec::new());
+        }
+        entries.last_mut().expect("pushed above").push((*page_num, line.clone()));
+    }
+    entries
+}
+
+// A column or page break inside an entry: the previous entry ends mid-sentence
+fn continues_entry(entry: Option<&Vec<(u32, TextLine)>>, line: &TextLine) -> bool {
+    let Some((_, last)) = entry.and_then(|e| e.last()) else {
+        return false;
+    };
+    let prev = last.text.trim_end();
+    prev.ends_with('-') || prev.ends_with(',') || line.text.trim_start().starts_with(char::is_lowercase)
+}
+
+fn make_reference(entry: Vec<(u32, TextLine)>, style: ReferenceStyle) -> Reference {
+    let page_num = entry[0].0;
+    let bbox = entry.iter()
+        .filter(|(p, _)| *p == page_num)
+        .map(|(_, l)| l.bbox)
+        .reduce(|a, b| a.union(&b))
+        .expect("entries are never empty");
+    let mut lines: Vec<TextLine> = entry.into_iter().map(|(_, l)| l).collect();
+    let label = numbering_label(&lines[0].text)
+        .filter(|_| style == ReferenceStyle::Numbered)
+        .map(|(label, _, rest)| (label.to_string(), rest.to_string()));
+    if let Some((_, rest)) = &label {
+        lines[0].text = rest.clone();
+    }
+    let text = join_lines(&lines);
+    Reference {
+        label: label.map(|l| l.0),
+        doi: find_doi(&text).map(str::to_string),
+        arxiv: find_arxiv_id(&text).map(str::to_string),
+        text,
+        page_num,
+        bbox,
+    }
+}
@@ -0,0 +1,110 @@
+// Extraction warnings per page
+//
+// Problems the interpreter and font decoders work around are logged at warn
+// level and, while `output_doc_with_diagnostics` runs, also recorded here
+// for the page being processed, so callers can judge the quality of the
+// text without scraping logs. Fonts are decoded far from the interpreter,
+// so the warnings go to a per-thread sink rather than through `Processor`.
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use log::warn;
+
+/// What went wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WarningKind {
+    /// A code without an entry in the font's ToUnicode map or encoding
+    MissingUnicode,
+    /// A glyph name in an encoding's `/Differences` with no known Unicode value
+    UnknownGlyphName,
+    /// A font without widths, whose glyphs are spaced by guesswork
+    MissingWidths,
+    /// A font that failed to load and was replaced by Helvetica
+    FontFallback,
+    /// An operator the interpreter doesn't implement
+    UnhandledOperator,
+    /// Malformed content skipped or replaced by a default, see `Strictness::Lenient`
+    Recovered,
+    /// Text cut off at `ExtractionOptions::max_glyphs_per_page`
+    Truncated,
+    /// Anything else decoding could only partially handle
+    Unsupported,
+}
+
+/// A warning and how often it occurred
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Warning {
+    pub kind: WarningKind,
+    pub message: String,
+    pub count: usize,
+}
+
+/// Warnings by page number; page 0 holds those raised outside any page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtractionReport {
+    pub pages: BTreeMap<u32, Vec<Warning>>,
+}
+
+impl ExtractionReport {
+    /// Warnings of one page, in the order they first occurred
+    pub fn page(&self, page_num: u32) -> &[Warning] {
+        self.pages.get(&page_num).map_or(&[], Vec::as_slice)
+    }
+
+    /// Total occurrences of `kind` across the document
+    pub fn count(&self, kind: WarningKind) -> usize {
+        self.pages.values().flatten().filter(|w| w.kind == kind).map(|w| w.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    fn record(&mut self, page_num: u32, kind: WarningKind, message: String) {
+        let warnings = self.pages.entry(page_num).or_default();
+        match warnings.iter_mut().find(|w| w.kind == kind && w.message == message) {
+            Some(warning) => warning.count += 1,
+            None => warnings.push(Warning { kind, message, count: 1 }),
+        }
+    }
+}
+
+// Reports being collected, innermost last, with the page each is on
+thread_local! {
+    static SINKS: RefCell<Vec<(u32, ExtractionReport)>> = const { RefCell::new(Vec::new()) };
+}
+
+// Run `f`, returning its result with the warnings recorded meanwhile
+pub(crate) fn collect<T>(f: impl FnOnce() -> T) -> (T, ExtractionReport) {
+    SINKS.with(|sinks| sinks.borrow_mut().push((0, ExtractionReport::default())));
+    let result = f();
+    let report = SINKS.with(|sinks| sinks.borrow_mut().pop()).map(|(_, report)| report).unwrap_or_default();
+    (result, report)
+}
+
+// Attribute the following warnings to `page_num`
+pub(crate) fn set_page(page_num: u32) {
+    SINKS.with(|sinks| {
+        if let Some((page, _)) = sinks.borrow_mut().last_mut() {
+            *page = page_num;
+        }
+    });
+}
+
+// Record a warning without logging it, for problems already logged at another level
+pub(crate) fn record(kind: WarningKind, message: String) {
+    SINKS.with(|sinks| {
+        if let Some((page, report)) = sinks.borrow_mut().last_mut() {
+            report.record(*page, kind, message);
+        }
+    });
+}
+
+// Log a warning and record it
+pub(crate) fn warn(kind: WarningKind, message: String) {
+    warn!("{}", message);
+    record(kind, message);
+}
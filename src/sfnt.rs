@@ -0,0 +1,116 @@
+// Minimal TrueType/OpenType table reader for glyph advances
+//
+// Only `head`, `hhea`, `hmtx` and the byte/segment mapped `cmap` subtables
+// are read, which is all that's needed to recover widths of simple fonts
+// embedded without a `/Widths` array.
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Horizontal metrics and character maps of an sfnt font program
+pub(crate) struct Sfnt<'a> {
+    units_per_em: u16,
+    advances: Vec<u16>,
+    // (platform, encoding, subtable) for each supported cmap subtable
+    cmaps: Vec<(u16, u16, &'a [u8])>,
+}
+
+impl<'a> Sfnt<'a> {
+    pub(crate) fn parse(data: &'a [u8]) -> Option<Sfnt<'a>> {
+        let num_tables = u16_at(data, 4)? as usize;
+        let table = |tag: &[u8; 4]| -> Option<&'a [u8]> {
+            (0..num_tables).find_map(|i| {
+                let record = 12 + i * 16;
+                if data.get(record..record + 4)? != tag {
+                    return None;
+                }
+                let offset = u32_at(data, record + 8)? as usize;
+                let length = u32_at(data, record + 12)? as usize;
+                data.get(offset..offset.checked_add(length)?)
+            })
+        };
+
+        let units_per_em = u16_at(table(b"head")?, 18)?;
+        let num_metrics = u16_at(table(b"hhea")?, 34)? as usize;
+        let hmtx = table(b"hmtx")?;
+        let advances = (0..num_metrics).map_while(|i| u16_at(hmtx, i * 4)).collect();
+
+        let mut cmaps = Vec::new();
+        if let Some(cmap) = table(b"cmap") {
+            for i in 0..u16_at(cmap, 2).unwrap_or(0) as usize {
+                let record = 4 + i * 8;
+                let (Some(platform), Some(encoding), Some(offset)) =
+                    (u16_at(cmap, record), u16_at(cmap, record + 2), u32_at(cmap, record + 4))
+                else {
+                    break;
+                };
+                if let Some(subtable) = cmap.get(offset as usize..) {
+                    cmaps.push((platform, encoding, subtable));
+                }
+            }
+        }
+        (units_per_em != 0).then_some(Sfnt { units_per_em, advances, cmaps })
+    }
+
+    /// Advance of `gid` in 1/1000 em; glyphs past the last metric share its advance
+    pub(crate) fn advance(&self, gid: u16) -> Option<f64> {
+        let advance = self.advances.get(gid as usize).or(self.advances.last())?;
+        Some(*advance as f64 * 1000. / self.units_per_em as f64)
+    }
+
+    /// Glyph of a simple font's `code`, following the lookup order of PDF 32000-1 9.6.6.4:
+    /// the (3,0) symbolic table, then (1,0), then (3,1) through the code's Unicode value
+    pub(crate) fn glyph_for_code(&self, code: u8, unicode: Option<u16>) -> Option<u16> {
+        let lookup = |platform: u16, encoding: u16, c: u16| {
+            self.cmaps.iter()
+                .filter(|(p, e, _)| *p == platform && *e == encoding)
+                .find_map(|(_, _, subtable)| map_code(subtable, c))
+                .filter(|&gid| gid != 0)
+        };
+        let code = code as u16;
+        lookup(3, 0, 0xf000 + code)
+            .or_else(|| lookup(3, 0, code))
+            .or_else(|| lookup(1, 0, code))
+            .or_else(|| lookup(3, 1, unicode?))
+    }
+}
+
+// Look `c` up in a format 0 (byte encoding) or format 4 (segment mapping) subtable
+fn map_code(subtable: &[u8], c: u16) -> Option<u16> {
+    match u16_at(subtable, 0)? {
+        0 => subtable.get(6 + c as usize).filter(|_| c < 256).map(|&gid| gid as u16),
+        4 => {
+            let segments = u16_at(subtable, 6)? as usize / 2;
+            let ends = 14;
+            let starts = ends + segments * 2 + 2;
+            let deltas = starts + segments * 2;
+            let range_offsets = deltas + segments * 2;
+            for i in 0..segments {
+                if c > u16_at(subtable, ends + i * 2)? {
+                    continue;
+                }
+                if c < u16_at(subtable, starts + i * 2)? {
+                    return None;
+                }
+                let start = u16_at(subtable, starts + i * 2)?;
+                let delta = u16_at(subtable, deltas + i * 2)?;
+                let range_offset = u16_at(subtable, range_offsets + i * 2)? as usize;
+                if range_offset == 0 {
+                    return Some(c.wrapping_add(delta));
+                }
+                let glyph_offset = range_offsets + i * 2 + range_offset + (c - start) as usize * 2;
+                return match u16_at(subtable, glyph_offset)? {
+                    0 => None,
+                    gid => Some(gid.wrapping_add(delta)),
+                };
+            }
+            None
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,119 @@
+// Axial and radial shadings
+//
+// Shadings paint a colour that varies with position: along the axis between
+// two points (type 2) or between two circles (type 3). They are drawn with
+// the `sh` operator or as the fill of a shading pattern. Devices get the
+// colours already sampled from the shading's function and converted to sRGB,
+// as gradient stops the way vector formats describe gradients. Samples that a
+// straight line between their neighbours reproduces are dropped, so linear
+// functions give just the two end stops. Function-based and mesh shadings
+// (types 1 and 4 to 7) are left out.
+use crate::{colorspace_from_object, get, make_colorspace, object_utils, ColorSpace, Dictionary, Document, Function, Object, PdfError, PdfResult};
+
+// Samples taken of the shading function over its domain
+const SAMPLES: usize = 32;
+
+// Largest sRGB difference from the line between neighbouring stops for a sample to be dropped
+const TOLERANCE: f64 = 1. / 512.;
+
+/// A colour at a position along a shading, in sRGB
+pub type Stop = (f64, (f64, f64, f64));
+
+/// Where the colours of a shading lie, in shading space
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShadingGeometry {
+    /// Colours vary along the axis from `start` to `end`, constant across it
+    Axial { start: (f64, f64), end: (f64, f64) },
+    /// Colours vary over the circles interpolated between the start and end circles
+    Radial { start: (f64, f64), start_radius: f64, end: (f64, f64), end_radius: f64 },
+}
+
+/// An axial or radial shading, its colours converted to sRGB
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shading {
+    pub geometry: ShadingGeometry,
+    /// Whether the start and end colours continue before the start and past the end
+    pub extend: [bool; 2],
+    /// Colours at positions from 0 at the start to 1 at the end, in order
+    pub stops: Vec<Stop>,
+    /// Rectangle outside which nothing is painted, in shading space
+    pub bbox: Option<[f64; 4]>,
+}
+
+// The shading described by a shading dictionary or stream, `None` for unsupported types and
+// colours that can't be evaluated
+pub(crate) fn parse_shading(doc: &Document, shading: &Object, resources: &Dictionary) -> PdfResult<Option<Shading>> {
+    let dict = match shading {
+        Object::Dictionary(dict) => dict,
+        Object::Stream(stream) => &stream.dict,
+        _ => return Err(PdfError::InvalidStructure("Shading must be a dictionary or stream".to_string())),
+    };
+    let coords = get::<Vec<f64>>(doc, dict, b"Coords");
+    let geometry = match (get::<i64>(doc, dict, b"ShadingType")?, coords.as_deref()) {
+        (2, Ok(&[x0, y0, x1, y1])) => ShadingGeometry::Axial { start: (x0, y0), end: (x1, y1) },
+        (3, Ok(&[x0, y0, r0, x1, y1, r1])) => {
+            ShadingGeometry::Radial { start: (x0, y0), start_radius: r0, end: (x1, y1), end_radius: r1 }
+        }
+        (2 | 3, _) => return Err(PdfError::InvalidStructure("Shading has invalid Coords".to_string())),
+        _ => return Ok(None),
+    };
+    let colorspace = match object_utils::maybe_get_obj(doc, dict, b"ColorSpace") {
+        Some(Object::Name(name)) => make_colorspace(doc, name, resources)?,
+        Some(cs) => colorspace_from_object(doc, cs)?,
+        None => return Err(PdfError::InvalidStructure("Shading has no ColorSpace".to_string())),
+    };
+    // One function with all outputs, or one per colour component
+    let functions = match object_utils::maybe_get_obj(doc, dict, b"Function") {
+        Some(Object::Array(functions)) => functions
+            .iter()
+            .map(|f| Function::new(doc, object_utils::maybe_deref(doc, f)?))
+            .collect::<PdfResult<Vec<_>>>()?,
+        Some(function) => vec![Function::new(doc, function)?],
+        None => return Err(PdfError::InvalidStructure("Shading has no Function".to_string())),
+    };
+    let domain = match get::<Option<Vec<f64>>>(doc, dict, b"Domain")?.as_deref() {
+        Some(&[t0, t1, ..]) => (t0, t1),
+        _ => (0., 1.),
+    };
+    let Some(stops) = sample(&colorspace, &functions, domain) else {
+        return Ok(None);
+    };
+    let flag = |o: &Object| object_utils::maybe_deref(doc, o).ok().and_then(|o| o.as_bool().ok()).unwrap_or(false);
+    let extend = match object_utils::maybe_get_obj(doc, dict, b"Extend").and_then(|o| o.as_array().ok()) {
+        Some(extend) if extend.len() == 2 => [flag(&extend[0]), flag(&extend[1])],
+        _ => [false, false],
+    };
+    let bbox = get::<Option<[f64; 4]>>(doc, dict, b"BBox")?
+        .map(|[x0, y0, x1, y1]| [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)]);
+    Ok(Some(Shading { geometry, extend, stops, bbox }))
+}
+
+// sRGB stops of the functions' colours over `domain`
+fn sample(colorspace: &ColorSpace, functions: &[Function], (t0, t1): (f64, f64)) -> Option<Vec<Stop>> {
+    let mut samples = Vec::with_capacity(SAMPLES + 1);
+    for i in 0..=SAMPLES {
+        let offset = i as f64 / SAMPLES as f64;
+        let t = t0 + offset * (t1 - t0);
+        let mut color = Vec::new();
+        for function in functions {
+            color.extend(function.eval(&[t])?);
+        }
+        samples.push((offset, colorspace.to_rgb(&color)?));
+    }
+    let mut stops = vec![samples[0]];
+    for (i, &(offset, rgb)) in samples.iter().enumerate().skip(1) {
+        let Some(&(next_offset, next)) = samples.get(i + 1) else {
+            stops.push((offset, rgb));
+            break;
+        };
+        let (previous_offset, previous) = *stops.last()?;
+        let f = (offset - previous_offset) / (next_offset - previous_offset);
+        let between = |a: f64, b: f64, c: f64| (a + (c - a) * f - b).abs() <= TOLERANCE;
+        if !(between(previous.0, rgb.0, next.0) && between(previous.1, rgb.1, next.1) && between(previous.2, rgb.2, next.2)) {
+            stops.push((offset, rgb));
+        }
+    }
+    Some(stops)
+}
@@ -0,0 +1,166 @@
+// Word spacing thresholds learned per document
+//
+// `PlainTextOutput` inserts a space when a shown string starts more than a
+// tenth of an em after the previous one ended. Generators that track text
+// loosely (every glyph placed on its own with a letter-spaced advance) or
+// set words with less than that between them defeat the fixed threshold.
+// A first pass with `GapCollector` records the gaps at string boundaries
+// per font and size, and `SpacingModel` splits each distribution into
+// letter gaps and word gaps for the second pass. Line breaks keep the fixed
+// rule, which depends on the font size alone.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{output_doc, plain_text_position, Document, MediaBox, OutputDev, PdfFont, PdfResult, PdfTransform, PlainTextOutput};
+
+/// Gap, in ems, above which `PlainTextOutput` inserts a space without a model
+pub const DEFAULT_WORD_GAP: f64 = 0.1;
+// Fewer gaps than this for a font and size keep the default
+const MIN_SAMPLES: usize = 8;
+// Smallest jump between sorted gaps that separates letter and word gaps
+const MIN_SPLIT: f64 = 0.05;
+// Letter tracking rarely exceeds this; larger typical gaps are between words
+const MAX_TRACKING: f64 = 0.2;
+// Gaps outside this range are column or table jumps, not spacing
+const GAP_RANGE: std::ops::RangeInclusive<f64> = -1.0..=3.0;
+
+// Values per font name, then per font size in half points
+type BySize<T> = HashMap<Arc<str>, HashMap<i64, T>>;
+
+fn half_points(font_size: f64) -> i64 {
+    (font_size * 2.).round() as i64
+}
+
+/// Per font and size word gap thresholds
+#[derive(Debug, Clone, Default)]
+pub struct SpacingModel {
+    word_gaps: BySize<f64>,
+}
+
+impl SpacingModel {
+    /// Gap, in ems, above which a string starts a new word
+    pub fn word_gap(&self, font: &str, font_size: f64) -> f64 {
+        self.word_gaps.get(font)
+            .and_then(|sizes| sizes.get(&half_points(font_size)))
+            .copied()
+            .unwrap_or(DEFAULT_WORD_GAP)
+    }
+
+    /// Number of font and size combinations with a learned threshold
+    pub fn len(&self) -> usize {
+        self.word_gaps.values().map(HashMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Learn word gap thresholds from every page of `doc`
+pub fn learn(doc: &Document) -> PdfResult<SpacingModel> {
+    let mut collector = GapCollector::new();
+    output_doc(doc, &mut collector)?;
+    Ok(collector.into_model())
+}
+
+/// Plain text of `doc`, spaced with thresholds learned from the document itself
+pub fn extract_text(doc: &Document) -> PdfResult<String> {
+    let model = learn(doc)?;
+    let mut s = Vec::new();
+    output_doc(doc, &mut PlainTextOutput::new(&mut s).spacing(model))?;
+    String::from_utf8(s).map_err(|_| crate::PdfError::EncodingError("Invalid UTF-8".to_string()))
+}
+
+/// Output device recording the horizontal gap before each shown string, see `SpacingModel`
+#[derive(Debug, Default)]
+pub struct GapCollector {
+    gaps: BySize<Vec<f64>>,
+    font: Option<Arc<str>>,
+    flip_ctm: PdfTransform,
+    last_end: Option<(f64, f64)>,
+    first_char: bool,
+}
+
+impl GapCollector {
+    pub fn new() -> GapCollector {
+        GapCollector::default()
+    }
+
+    pub fn into_model(self) -> SpacingModel {
+        let word_gaps = self.gaps.into_iter()
+            .map(|(font, sizes)| {
+                let sizes: HashMap<i64, f64> = sizes.into_iter()
+                    .filter_map(|(size, mut gaps)| fit(&mut gaps).map(|gap| (size, gap)))
+                    .collect();
+                (font, sizes)
+            })
+            .filter(|(_, sizes)| !sizes.is_empty())
+            .collect();
+        SpacingModel { word_gaps }
+    }
+}
+
+// Word gap threshold for one font and size, if the gaps show one
+fn fit(gaps: &mut Vec<f64>) -> Option<f64> {
+    gaps.retain(|gap| GAP_RANGE.contains(gap));
+    if gaps.len() < MIN_SAMPLES {
+        return None;
+    }
+    gaps.sort_by(f64::total_cmp);
+    let median = |gaps: &[f64]| gaps[gaps.len() / 2];
+    let (split, jump) = gaps.windows(2)
+        .map(|w| w[1] - w[0])
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    if jump >= MIN_SPLIT {
+        // Letter gaps below, word gaps above
+        (median(&gaps[..=split]) < MAX_TRACKING).then(|| (gaps[split] + gaps[split + 1]) / 2.)
+    } else if median(gaps) < MAX_TRACKING && gaps[gaps.len() - 1] > DEFAULT_WORD_GAP {
+        // Only letter gaps, wider than the default allows
+        Some(gaps[gaps.len() - 1] + MIN_SPLIT / 2.)
+    } else {
+        None
+    }
+}
+
+impl OutputDev for GapCollector {
+    fn begin_page(&mut self, _page_num: u32, media_box: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> {
+        self.flip_ctm = PdfTransform::new(1., 0., 0., -1., 0., media_box.ury - media_box.lly);
+        self.last_end = None;
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> PdfResult<()> {
+        Ok(())
+    }
+
+    fn output_character(&mut self, trm: &PdfTransform, width: f64, _spacing: f64, font_size: f64, _char: &str) -> PdfResult<()> {
+        let (x, y, size) = plain_text_position(trm, &self.flip_ctm, font_size);
+        if let (true, Some((last_end, last_y)), Some(font)) = (self.first_char, self.last_end, &self.font)
+            && (y - last_y).abs() <= size * 0.5
+            && size > 0.
+        {
+            let sizes = self.gaps.entry(font.clone()).or_default();
+            sizes.entry(half_points(size)).or_default().push((x - last_end) / size);
+        }
+        self.first_char = false;
+        self.last_end = Some((x + width * size, y));
+        Ok(())
+    }
+
+    fn set_font(&mut self, font: &dyn PdfFont) -> PdfResult<()> {
+        let name = font.base_name().unwrap_or("");
+        if self.font.as_deref() != Some(name) {
+            self.font = Some(Arc::from(name));
+        }
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> PdfResult<()> {
+        self.first_char = true;
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+    fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+}
@@ -0,0 +1,93 @@
+// Detection of tables as runs of lines with aligned columns
+//
+// A line splits into cells wherever the horizontal gap between glyphs is
+// wider than a few spaces. Consecutive lines with the same number of cells
+// (at least two), whose cells overlap horizontally with the cells above,
+// form a table.
+use crate::layout::{collect_text, BBox, PageText, TextLine};
+use crate::{Document, PdfResult};
+
+// Gap between glyphs, in font sizes, that starts a new cell
+const CELL_GAP: f64 = 1.5;
+// Rows a table needs, including the header
+const MIN_ROWS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableCell {
+    pub text: String,
+    pub bbox: BBox,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table {
+    pub page_num: u32,
+    pub bbox: BBox,
+    pub rows: Vec<Vec<TableCell>>,
+}
+
+impl Table {
+    /// Text of each cell, row by row
+    pub fn cell_texts(&self) -> Vec<Vec<&str>> {
+        self.rows.iter().map(|row| row.iter().map(|cell| cell.text.as_str()).collect()).collect()
+    }
+}
+
+/// Tables on every page of `doc`, in page order
+pub fn extract_tables(doc: &Document) -> PdfResult<Vec<Table>> {
+    Ok(collect_text(doc)?.iter().flat_map(detect_tables).collect())
+}
+
+/// Tables on a single collected page, top to bottom
+pub fn detect_tables(page: &PageText) -> Vec<Table> {
+    let mut tables = Vec::new();
+    let mut rows: Vec<Vec<TableCell>> = Vec::new();
+    let mut flush = |rows: &mut Vec<Vec<TableCell>>| {
+        if rows.len() >= MIN_ROWS {
+            let bbox = rows.iter().flatten().map(|c| c.bbox).reduce(|a, b| a.union(&b)).expect("rows are non-empty");
+            tables.push(Table { page_num: page.page_num, bbox, rows: std::mem::take(rows) });
+        }
+        rows.clear();
+    };
+    for line in page.lines() {
+        let cells = cells(&line);
+        let aligned = rows.last().is_some_and(|prev| {
+            prev.len() == cells.len()
+                && prev.iter().zip(&cells).all(|(a, b)| a.bbox.llx < b.bbox.urx && b.bbox.llx < a.bbox.urx)
+        });
+        if !aligned {
+            flush(&mut rows);
+        }
+        if cells.len() >= 2 {
+            rows.push(cells);
+        }
+    }
+    flush(&mut rows);
+    tables
+}
+
+// Split a line into cells at wide gaps
+fn cells(line: &TextLine) -> Vec<TableCell> {
+    let mut cells: Vec<TableCell> = Vec::new();
+    let mut last_end = f64::INFINITY;
+    for glyph in &line.glyphs {
+        let gap = glyph.x - last_end;
+        match cells.last_mut() {
+            Some(cell) if gap <= glyph.font_size * CELL_GAP => {
+                if glyph.starts_word && gap > glyph.font_size * 0.1 && !cell.text.ends_with(' ') {
+                    cell.text.push(' ');
+                }
+                cell.text.push_str(&glyph.text);
+                cell.bbox = cell.bbox.union(&glyph.bbox());
+            }
+            _ => cells.push(TableCell { text: glyph.text.clone(), bbox: glyph.bbox() }),
+        }
+        last_end = glyph.x + glyph.width;
+    }
+    for cell in &mut cells {
+        cell.text = cell.text.trim().to_string();
+    }
+    cells.retain(|c| !c.text.is_empty());
+    cells
+}
@@ -0,0 +1,174 @@
+// Watermark and hidden identifier text for leak tracing
+//
+// Watermarking tools stamp documents with diagonal or faint text repeated on
+// every page ("CONFIDENTIAL - jane@example.com") and with identifiers set in
+// tiny type. Such runs are collected into a report instead of being mixed into
+// or dropped from the body text.
+use std::collections::HashMap;
+
+use crate::layout::{collect_text, BBox, PageText, TextGlyph};
+use crate::{Document, PdfResult};
+
+// Degrees away from the nearest axis for text to count as diagonal
+const DIAGONAL_MIN_ANGLE: f64 = 10.;
+const FAINT_MAX_ALPHA: f64 = 0.6;
+const FAINT_MIN_LUMINANCE: f64 = 0.7;
+const TINY_MAX_FONT_SIZE: f64 = 3.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WatermarkKind {
+    /// Baseline at an angle to the page axes
+    Diagonal,
+    /// Translucent or light coloured fill
+    Faint,
+    /// Font size below 3pt
+    Tiny,
+}
+
+/// A watermark text and the pages it appears on
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatermarkText {
+    pub text: String,
+    pub kinds: Vec<WatermarkKind>,
+    /// Pages (1 based) carrying the text, in ascending order
+    pub pages: Vec<u32>,
+    pub font_size: f64,
+    pub angle: f64,
+    /// Position of the first occurrence
+    pub bbox: BBox,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WatermarkReport {
+    /// Diagonal or faint text repeated across pages
+    pub watermarks: Vec<WatermarkText>,
+    /// Tiny-font strings such as serial numbers
+    pub identifiers: Vec<WatermarkText>,
+}
+
+impl WatermarkReport {
+    pub fn is_empty(&self) -> bool {
+        self.watermarks.is_empty() && self.identifiers.is_empty()
+    }
+}
+
+/// Collect the watermark report of `doc`
+pub fn watermark_report(doc: &Document) -> PdfResult<WatermarkReport> {
+    Ok(detect(&collect_text(doc)?))
+}
+
+/// Collect the watermark report from already collected pages
+pub fn detect(pages: &[PageText]) -> WatermarkReport {
+    let mut groups: Vec<WatermarkText> = Vec::new();
+    let mut index: HashMap<(String, Vec<WatermarkKind>), usize> = HashMap::new();
+    for page in pages {
+        for run in runs(page) {
+            let key = (run.text.clone(), run.kinds.clone());
+            match index.get(&key) {
+                Some(&i) => {
+                    if !groups[i].pages.contains(&page.page_num) {
+                        groups[i].pages.push(page.page_num);
+                    }
+                }
+                None => {
+                    index.insert(key, groups.len());
+                    groups.push(run);
+                }
+            }
+        }
+    }
+
+    let min_pages = pages.len().min(2);
+    let mut report = WatermarkReport::default();
+    for group in groups {
+        let alphanumeric = group.text.chars().filter(|c| c.is_alphanumeric()).count();
+        if group.kinds.contains(&WatermarkKind::Tiny) {
+            if alphanumeric >= 4 {
+                report.identifiers.push(group);
+            }
+        } else if alphanumeric >= 3 && group.pages.len() >= min_pages {
+            report.watermarks.push(group);
+        }
+    }
+    report
+}
+
+fn kinds(glyph: &TextGlyph) -> Vec<WatermarkKind> {
+    classify(glyph.angle, glyph.font_size, glyph.color, glyph.alpha)
+}
+
+// Watermark traits of text drawn at `angle` degrees with the given size and fill
+pub(crate) fn classify(angle: f64, font_size: f64, color: Option<(f64, f64, f64)>, alpha: f64) -> Vec<WatermarkKind> {
+    let mut kinds = Vec::new();
+    let off_axis = angle.rem_euclid(90.);
+    if off_axis.min(90. - off_axis) >= DIAGONAL_MIN_ANGLE {
+        kinds.push(WatermarkKind::Diagonal);
+    }
+    let light = color.is_some_and(|(r, g, b)| 0.2126 * r + 0.7152 * g + 0.0722 * b >= FAINT_MIN_LUMINANCE);
+    if alpha <= FAINT_MAX_ALPHA || light {
+        kinds.push(WatermarkKind::Faint);
+    }
+    if font_size < TINY_MAX_FONT_SIZE {
+        kinds.push(WatermarkKind::Tiny);
+    }
+    kinds
+}
+
+// Consecutive glyphs sharing the same watermark traits, direction and size
+fn runs(page: &PageText) -> Vec<WatermarkText> {
+    let mut runs: Vec<WatermarkText> = Vec::new();
+    let mut last: Option<&TextGlyph> = None;
+    for glyph in &page.glyphs {
+        let kinds = kinds(glyph);
+        if kinds.is_empty() {
+            last = None;
+            continue;
+        }
+        if glyph.text.trim().is_empty() {
+            if let Some(run) = runs.last_mut()
+                && last.is_some()
+                && !run.text.ends_with(' ')
+            {
+                run.text.push(' ');
+            }
+            continue;
+        }
+
+        // Distance along the baseline from the end of the previous glyph
+        let (cos, sin) = (glyph.angle.to_radians().cos(), glyph.angle.to_radians().sin());
+        let continues = last.zip(runs.last()).and_then(|(prev, run)| {
+            let same_style = run.kinds == kinds
+                && (prev.angle - glyph.angle).abs() < 2.
+                && (prev.font_size - glyph.font_size).abs() <= prev.font_size * 0.2;
+            let (dx, dy) = (glyph.x - prev.x, glyph.y - prev.y);
+            let along = dx * cos + dy * sin - prev.width;
+            let across = (dy * cos - dx * sin).abs();
+            (same_style && across < glyph.font_size * 0.5 && along > -glyph.font_size && along < glyph.font_size * 3.)
+                .then_some(along)
+        });
+        match continues {
+            Some(gap) => {
+                let run = runs.last_mut().expect("continues implies a run");
+                if glyph.starts_word && gap > glyph.font_size * 0.1 && !run.text.ends_with(' ') {
+                    run.text.push(' ');
+                }
+                run.text.push_str(&glyph.text);
+                run.bbox = run.bbox.union(&glyph.bbox());
+            }
+            None => runs.push(WatermarkText {
+                text: glyph.text.clone(),
+                kinds,
+                pages: vec![page.page_num],
+                font_size: glyph.font_size,
+                angle: glyph.angle,
+                bbox: glyph.bbox(),
+            }),
+        }
+        last = Some(glyph);
+    }
+    for run in &mut runs {
+        run.text = run.text.trim().to_string();
+    }
+    runs.retain(|r| !r.text.is_empty());
+    runs
+}
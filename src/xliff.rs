@@ -0,0 +1,92 @@
+// XLIFF 1.2 export of detected paragraphs
+use std::io::Write;
+
+use crate::layout::{extract_paragraphs, Paragraph};
+use crate::string_utils::escape_xml;
+use crate::{Document, PdfError, PdfResult};
+
+/// Namespace for the page/bbox attributes attached to groups and trans-units
+pub const XLIFF_LAYOUT_NAMESPACE: &str = "urn:pdf-extract:layout";
+
+#[derive(Debug, Clone)]
+pub struct XliffOptions {
+    /// Value of the `original` attribute on `<file>`, usually the PDF file name
+    pub original: String,
+    pub source_language: String,
+    pub target_language: Option<String>,
+}
+
+impl Default for XliffOptions {
+    fn default() -> Self {
+        XliffOptions {
+            original: "document.pdf".to_string(),
+            source_language: "en".to_string(),
+            target_language: None,
+        }
+    }
+}
+
+/// Identifier of the `index`th paragraph (zero based) on `page_num`.
+///
+/// IDs only depend on reading order within a page, so re-exporting the same
+/// document yields the same IDs and translated units can be mapped back.
+pub fn segment_id(page_num: u32, index: usize) -> String {
+    format!("p{}-s{}", page_num, index + 1)
+}
+
+/// Write every paragraph of `doc` as an XLIFF trans-unit, grouped by page
+pub fn write_xliff<W: Write>(doc: &Document, writer: &mut W, options: &XliffOptions) -> PdfResult<()> {
+    let paragraphs = extract_paragraphs(doc)?;
+    write_paragraphs(&paragraphs, writer, options)
+}
+
+/// Render every paragraph of `doc` as an XLIFF document
+pub fn export_xliff(doc: &Document, options: &XliffOptions) -> PdfResult<String> {
+    let mut out = Vec::new();
+    write_xliff(doc, &mut out, options)?;
+    String::from_utf8(out).map_err(|_| PdfError::EncodingError("Invalid UTF-8".to_string()))
+}
+
+fn write_paragraphs<W: Write>(paragraphs: &[Paragraph], w: &mut W, options: &XliffOptions) -> PdfResult<()> {
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(w, r#"<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2" xmlns:pdf="{}">"#,
+             XLIFF_LAYOUT_NAMESPACE)?;
+    write!(w, r#"<file original="{}" source-language="{}""#,
+           escape_xml(&options.original), escape_xml(&options.source_language))?;
+    if let Some(target) = &options.target_language {
+        write!(w, r#" target-language="{}""#, escape_xml(target))?;
+    }
+    writeln!(w, r#" datatype="plaintext">"#)?;
+    writeln!(w, "<body>")?;
+
+    let mut current_page = None;
+    let mut index = 0;
+    for paragraph in paragraphs {
+        if current_page != Some(paragraph.page_num) {
+            if current_page.is_some() {
+                writeln!(w, "</group>")?;
+            }
+            writeln!(w, r#"<group id="page-{0}" pdf:page="{0}">"#, paragraph.page_num)?;
+            current_page = Some(paragraph.page_num);
+            index = 0;
+        }
+        let text = paragraph.text();
+        if text.is_empty() {
+            continue;
+        }
+        let b = paragraph.bbox;
+        writeln!(w, r#"<trans-unit id="{}" pdf:page="{}" pdf:bbox="{:.2} {:.2} {:.2} {:.2}">"#,
+                 segment_id(paragraph.page_num, index), paragraph.page_num, b.llx, b.lly, b.urx, b.ury)?;
+        writeln!(w, "<source>{}</source>", escape_xml(&text))?;
+        writeln!(w, "</trans-unit>")?;
+        index += 1;
+    }
+    if current_page.is_some() {
+        writeln!(w, "</group>")?;
+    }
+
+    writeln!(w, "</body>")?;
+    writeln!(w, "</file>")?;
+    writeln!(w, "</xliff>")?;
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Command line tool tests
+//
+// Each test writes a small document to a temporary file and checks what
+// `pdf-extract` prints for one output format. The binary needs the `cli`
+// feature:
+//
+//     cargo test --features cli --test cli
+#![cfg(feature = "cli")]
+
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use pdf_extract::{dictionary, Document, Object, Stream};
+
+/// A heading over a line of body text on one page, saved under a name unique to `test`
+fn sample_pdf(test: &str) -> PathBuf {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica" });
+    let content = "BT /F1 24 Tf 72 700 Td (Title) Tj ET BT /F1 12 Tf 72 650 Td (Body text.) Tj ET";
+    let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+    let page_id = doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+        "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+    });
+    doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => 1,
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }));
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    let path = std::env::temp_dir().join(format!("pdf-extract-cli-{}-{}.pdf", test, std::process::id()));
+    doc.save(&path).unwrap();
+    path
+}
+
+/// Standard output of `pdf-extract --format <format>` on the sample document
+fn extract(format: &str) -> String {
+    let path = sample_pdf(format);
+    let output = Command::cargo_bin("pdf-extract").unwrap().args(["--format", format]).arg(&path).output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn text_format() {
+    let text = extract("txt");
+    assert_eq!(text.split_whitespace().collect::<Vec<_>>(), ["Title", "Body", "text."]);
+}
+
+#[test]
+fn html_format() {
+    let html = extract("html");
+    assert!(html.contains(">Title</div>") && html.contains("text.</div>"), "{html}");
+}
+
+#[test]
+fn svg_format() {
+    let svg = extract("svg");
+    assert!(svg.contains("<svg") && svg.trim_end().ends_with("</svg>"), "{svg}");
+}
+
+#[test]
+fn json_format() {
+    let json = extract("json");
+    let texts: Vec<String> = json.lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["text"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(texts, ["Title", "Body text."]);
+}
+
+#[test]
+fn markdown_format() {
+    assert_eq!(extract("md").trim_end(), "# Title\n\nBody text.");
+}
+
+#[test]
+fn output_file_and_page_selection() {
+    let path = sample_pdf("output");
+    let out = path.with_extension("txt");
+    // The document has no second page to print
+    let skipped = Command::cargo_bin("pdf-extract").unwrap().args(["--pages", "2"]).arg(&path).output().unwrap();
+    assert!(skipped.status.success() && !String::from_utf8_lossy(&skipped.stdout).contains("Title"));
+    Command::cargo_bin("pdf-extract").unwrap().arg("-o").arg(&out).arg(&path).assert().success().stdout("");
+    let text = std::fs::read_to_string(&out).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&out).unwrap();
+    assert!(text.contains("Body text."), "{text}");
+}
+
+#[test]
+fn unknown_options_fail_with_usage() {
+    Command::cargo_bin("pdf-extract").unwrap().arg("--frobnicate").assert().failure();
+    Command::cargo_bin("pdf-extract").unwrap().arg("--help").assert().success();
+}
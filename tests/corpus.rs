@@ -0,0 +1,339 @@
+// Golden corpus regression tests
+//
+// Fixtures listed in tests/corpus/manifest.txt are downloaded once into
+// tests/corpus_cache, or built by `generate` below, and their extracted text
+// compared with the snapshots in tests/corpus/snapshots. Generated fixtures
+// are checked on every run; the downloads need network access, so the test
+// of the downloaded ones only runs on request:
+//
+//     cargo test --test corpus -- --ignored
+//     PDF_EXTRACT_UPDATE_SNAPSHOTS=1 cargo test --test corpus -- --include-ignored
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use pdf_extract::{dictionary, Dictionary, Document, EncryptionState, EncryptionVersion, Object, Permissions, Stream, StringFormat};
+use sha2::{Digest, Sha256};
+
+const MANIFEST: &str = "tests/corpus/manifest.txt";
+const SNAPSHOTS: &str = "tests/corpus/snapshots";
+const CACHE: &str = "tests/corpus_cache";
+const CATEGORIES: &[&str] = &["cjk", "rtl", "forms", "scanned", "encrypted", "fonts", "colorspace", "layout"];
+// Source of fixtures built in this file rather than downloaded
+const GENERATED: &str = "generated";
+
+#[derive(Debug)]
+struct Fixture {
+    name: String,
+    category: String,
+    sha256: Option<String>,
+    url: String,
+    password: Option<String>,
+}
+
+fn manifest() -> Vec<Fixture> {
+    let manifest = std::fs::read_to_string(MANIFEST).unwrap();
+    manifest.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            assert!(matches!(fields.len(), 4 | 5), "malformed manifest line {:?}", line);
+            Fixture {
+                name: fields[0].to_string(),
+                category: fields[1].to_string(),
+                sha256: Some(fields[2]).filter(|h| *h != "-").map(str::to_string),
+                url: fields[3].to_string(),
+                password: fields.get(4).map(|p| p.to_string()),
+            }
+        })
+        .collect()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Single-page document with Helvetica bound to /F1 running `content`
+fn single_page(content: &str) -> Document {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+    let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+    let page_id = doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+        "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+    });
+    doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => 1,
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }));
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    doc
+}
+
+// Resource dictionary of the first page of `doc`
+fn page_resources_mut(doc: &mut Document) -> &mut Dictionary {
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().get_mut(b"Resources").unwrap().as_dict_mut().unwrap()
+}
+
+// ToUnicode CMap mapping each two-byte code from 1 to the characters of `text` in turn
+fn to_unicode(text: &str) -> Stream {
+    let chars: Vec<String> = text.chars().enumerate()
+        .map(|(i, c)| {
+            let utf16: String = c.encode_utf16(&mut [0; 2]).iter().map(|u| format!("{:04X}", u)).collect();
+            format!("<{:04X}> <{}>", i + 1, utf16)
+        })
+        .collect();
+    let cmap = format!("/CIDInit /ProcSet findresource begin 12 dict begin begincmap\n\
+                        1 begincodespacerange <0000> <FFFF> endcodespacerange\n\
+                        {} beginbfchar\n{}\nendbfchar\nendcmap CMapName currentdict /CMap defineresource pop end end\n",
+                       chars.len(), chars.join("\n"));
+    Stream::new(dictionary! {}, cmap.into_bytes())
+}
+
+// Hex string of the codes `to_unicode` gives the characters `range` of its text
+fn codes(range: std::ops::Range<usize>) -> String {
+    range.map(|code| format!("{:04X}", code + 1)).collect()
+}
+
+// Composite font `base_font` bound to /F2 of `doc`'s page, its unembedded CID font of
+// `ordering` mapped to `text` by ToUnicode
+fn add_type0_font(doc: &mut Document, base_font: &str, ordering: &str, text: &str) {
+    let to_unicode = doc.add_object(to_unicode(text));
+    let descendant = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "CIDFontType0",
+        "BaseFont" => base_font,
+        "CIDSystemInfo" => dictionary! {
+            "Registry" => Object::string_literal("Adobe"),
+            "Ordering" => Object::string_literal(ordering),
+            "Supplement" => 0,
+        },
+        "DW" => 1000,
+    });
+    let font = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type0",
+        "BaseFont" => base_font,
+        "Encoding" => "Identity-H",
+        "DescendantFonts" => vec![Object::Reference(descendant)],
+        "ToUnicode" => to_unicode,
+    });
+    page_resources_mut(doc).get_mut(b"Font").unwrap().as_dict_mut().unwrap().set("F2", font);
+}
+
+// Built fixture `name` as saved, or None for a name without a builder
+fn generate(name: &str) -> Option<Vec<u8>> {
+    let mut doc = match name {
+        // Japanese and Chinese set in one composite font
+        "cjk-identity-h" => {
+            let (japanese, chinese) = ("日本語の文書", "中文文本");
+            let mut doc = single_page(&format!(
+                "BT /F2 24 Tf 72 700 Td <{}> Tj 0 -36 Td <{}> Tj ET",
+                codes(0..6), codes(6..10),
+            ));
+            add_type0_font(&mut doc, "KozMinPr6N-Regular", "Japan1", &format!("{}{}", japanese, chinese));
+            doc
+        }
+        // Hebrew placed in visual order, the last letter first, as most producers write it
+        "rtl-hebrew" => {
+            let hebrew = "שלום עולם";
+            let visual: String = (0..hebrew.chars().count()).rev().map(|i| codes(i..i + 1)).collect();
+            let mut doc = single_page(&format!("BT /F2 18 Tf 72 700 Td <{}> Tj ET", visual));
+            add_type0_font(&mut doc, "ArialHebrew", "Identity", hebrew);
+            doc
+        }
+        // A text field and a ticked check box beside their labels
+        "forms-acroform" => {
+            let mut doc = single_page("BT /F1 12 Tf 72 700 Td (Name:) Tj 0 -30 Td (Subscribe:) Tj ET");
+            let font = doc.add_object(dictionary! {
+                "Type" => "Font",
+                "Subtype" => "Type1",
+                "BaseFont" => "Helvetica",
+            });
+            let appearance = |content: &str, width: i64| Stream::new(dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Form",
+                "BBox" => vec![0.into(), 0.into(), width.into(), 20.into()],
+                "Resources" => dictionary! { "Font" => dictionary! { "Helv" => font } },
+            }, content.as_bytes().to_vec());
+            let name = doc.add_object(appearance("/Tx BMC BT /Helv 12 Tf 2 5 Td (Ada Lovelace) Tj ET EMC", 200));
+            let yes = doc.add_object(appearance("q 0 0 1 rg 4 4 12 12 re f Q", 20));
+            let off = doc.add_object(appearance("", 20));
+            let text_field = doc.add_object(dictionary! {
+                "Type" => "Annot",
+                "Subtype" => "Widget",
+                "FT" => "Tx",
+                "T" => Object::string_literal("name"),
+                "V" => Object::string_literal("Ada Lovelace"),
+                "DA" => Object::string_literal("/Helv 12 Tf 0 g"),
+                "Rect" => vec![140.into(), 695.into(), 340.into(), 715.into()],
+                "AP" => dictionary! { "N" => name },
+            });
+            let check_box = doc.add_object(dictionary! {
+                "Type" => "Annot",
+                "Subtype" => "Widget",
+                "FT" => "Btn",
+                "T" => Object::string_literal("subscribe"),
+                "V" => "Yes",
+                "AS" => "Yes",
+                "Rect" => vec![140.into(), 665.into(), 160.into(), 685.into()],
+                "AP" => dictionary! { "N" => dictionary! { "Yes" => yes, "Off" => off } },
+            });
+            let page_id = doc.page_iter().next().unwrap();
+            doc.get_dictionary_mut(page_id).unwrap().set("Annots", vec![text_field.into(), check_box.into()]);
+            let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+            doc.get_dictionary_mut(catalog_id).unwrap().set("AcroForm", dictionary! {
+                "Fields" => vec![text_field.into(), check_box.into()],
+                "DA" => Object::string_literal("/Helv 12 Tf 0 g"),
+            });
+            doc
+        }
+        // A page image with an invisible OCR text layer over it
+        "scanned-ocr-layer" => {
+            let mut doc = single_page("q 612 0 0 792 0 0 cm /Im1 Do Q \
+                                                       BT 3 Tr /F1 12 Tf 72 700 Td (Scanned invoice) Tj 0 -14 Td (Total due 42.00) Tj ET");
+            let pixels: Vec<u8> = (0..64).map(|i| if (i / 8 + i % 8) % 2 == 0 { 0xff } else { 0xe0 }).collect();
+            let image = doc.add_object(Stream::new(dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Image",
+                "Width" => 8,
+                "Height" => 8,
+                "ColorSpace" => "DeviceGray",
+                "BitsPerComponent" => 8,
+            }, pixels));
+            page_resources_mut(&mut doc).set("XObject", dictionary! { "Im1" => image });
+            doc
+        }
+        // 128-bit RC4 with the user password `secret`
+        "encrypted-rc4" => {
+            let mut doc = single_page("BT /F1 12 Tf 72 700 Td (Quarterly figures) Tj 0 -14 Td (Not for distribution) Tj ET");
+            let id = Object::String(b"pdf-extract corpus".to_vec(), StringFormat::Hexadecimal);
+            doc.trailer.set("ID", vec![id.clone(), id]);
+            let state = EncryptionState::try_from(EncryptionVersion::V2 {
+                document: &doc,
+                owner_password: "owner",
+                user_password: "secret",
+                key_length: 128,
+                permissions: Permissions::PRINTABLE | Permissions::COPYABLE,
+            }).unwrap();
+            doc.encrypt(&state).unwrap();
+            // The last 16 bytes of /U are arbitrary padding, filled at random; zero them so the
+            // file stays the same for its pinned hash
+            let encrypt_id = doc.trailer.get(b"Encrypt").unwrap().as_reference().unwrap();
+            let encrypt = doc.get_dictionary_mut(encrypt_id).unwrap();
+            let Ok(Object::String(user, _)) = encrypt.get_mut(b"U") else { panic!("no /U in {:?}", encrypt) };
+            user[16..].fill(0);
+            doc
+        }
+        _ => return None,
+    };
+    let mut data = Vec::new();
+    doc.save_to(&mut data).unwrap();
+    Some(data)
+}
+
+// Cached copy of the fixture, downloading it first if needed, or the fixture built here
+fn fetch(fixture: &Fixture) -> Vec<u8> {
+    if fixture.url == GENERATED {
+        return generate(&fixture.name).unwrap_or_else(|| panic!("no builder for generated fixture {}", fixture.name));
+    }
+    let path = PathBuf::from(CACHE).join(format!("{}.pdf", fixture.name));
+    if let Ok(data) = std::fs::read(&path) {
+        return data;
+    }
+    std::fs::create_dir_all(CACHE).unwrap();
+    let resp = ureq::get(&fixture.url).call()
+        .unwrap_or_else(|e| panic!("Failed to download {} from {}, {}", fixture.name, fixture.url, e));
+    let (_parts, body) = resp.into_parts();
+    let mut data = Vec::new();
+    std::io::copy(&mut body.into_reader(), &mut data).unwrap();
+    // Write to a temporary file first so an interrupted download isn't cached
+    let partial = path.with_extension("part");
+    std::fs::write(&partial, &data).unwrap();
+    std::fs::rename(&partial, &path).unwrap();
+    data
+}
+
+fn extract(fixture: &Fixture, data: &[u8]) -> String {
+    let result = match &fixture.password {
+        Some(password) => pdf_extract::extract_text_from_mem_encrypted(data, password),
+        None => pdf_extract::extract_text_from_mem(data),
+    };
+    result.unwrap_or_else(|e| panic!("Failed to extract text from {}, {}", fixture.name, e))
+}
+
+#[test]
+fn corpus_manifest_is_well_formed() {
+    let fixtures = manifest();
+    let mut names = HashSet::new();
+    for fixture in &fixtures {
+        assert!(names.insert(&fixture.name), "duplicate fixture {}", fixture.name);
+        assert!(CATEGORIES.contains(&fixture.category.as_str()), "unknown category {:?}", fixture.category);
+        if fixture.url == GENERATED {
+            assert!(generate(&fixture.name).is_some(), "no builder for generated fixture {}", fixture.name);
+        } else {
+            assert!(fixture.url.starts_with("https://"), "{} must be fetched over https", fixture.name);
+        }
+        if let Some(hash) = &fixture.sha256 {
+            assert!(hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()), "bad sha256 for {}", fixture.name);
+        }
+        assert_eq!(fixture.password.is_some(), fixture.category == "encrypted", "{} password", fixture.name);
+    }
+}
+
+// Problems with `fixture`: a hash that isn't pinned or doesn't match, or text that differs
+// from its snapshot, which is written instead when updating
+fn check(fixture: &Fixture, update: bool) -> Vec<String> {
+    let mut failures = Vec::new();
+    let data = fetch(fixture);
+    let hash = sha256_hex(&data);
+    match &fixture.sha256 {
+        Some(pinned) if *pinned != hash => {
+            failures.push(format!("{}: sha256 {} does not match the pinned {}", fixture.name, hash, pinned));
+            return failures;
+        }
+        Some(_) => {}
+        None => failures.push(format!("{}: unpinned, sha256 is {}", fixture.name, hash)),
+    }
+
+    let text = extract(fixture, &data);
+    let snapshot = Path::new(SNAPSHOTS).join(format!("{}.txt", fixture.name));
+    match std::fs::read_to_string(&snapshot) {
+        Ok(expected) if expected == text => {}
+        _ if update => std::fs::write(&snapshot, &text).unwrap(),
+        Ok(_) => failures.push(format!("{}: text differs from {}", fixture.name, snapshot.display())),
+        Err(_) => failures.push(format!("{}: no snapshot at {}", fixture.name, snapshot.display())),
+    }
+    failures
+}
+
+fn check_all(generated: bool) {
+    let update = std::env::var_os("PDF_EXTRACT_UPDATE_SNAPSHOTS").is_some();
+    let failures: Vec<String> = manifest().iter()
+        .filter(|fixture| (fixture.url == GENERATED) == generated)
+        .flat_map(|fixture| check(fixture, update))
+        .collect();
+    assert!(failures.is_empty(), "corpus failures:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn generated_corpus_matches_snapshots() {
+    check_all(true);
+}
+
+#[test]
+#[ignore = "downloads the corpus; run with `cargo test --test corpus -- --ignored`"]
+fn corpus_matches_snapshots() {
+    check_all(false);
+}
@@ -0,0 +1,224 @@
+// Property tests of font decoding and widths
+//
+// Each property builds synthetic font dictionaries from generated encodings,
+// ToUnicode maps and width arrays, and checks the decoded text and widths
+// against the model they were built from.
+use std::collections::BTreeMap;
+
+use pdf_extract::{dictionary, make_font, Dictionary, Document, Object, Stream};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+const CASES: u32 = 200;
+
+// Glyph names and the text the Adobe Glyph List maps them to
+const GLYPHS: &[(&str, &str)] = &[
+    ("A", "A"),
+    ("z", "z"),
+    ("zero", "0"),
+    ("space", " "),
+    ("eacute", "é"),
+    ("germandbls", "ß"),
+    ("Euro", "€"),
+    ("quotedblleft", "“"),
+    ("emdash", "—"),
+    ("ellipsis", "…"),
+    ("bullet", "•"),
+    ("dagger", "†"),
+    ("fi", "ﬁ"),
+    ("alpha", "α"),
+    ("uni0416", "Ж"),
+    ("uni20AC", "€"),
+];
+
+// Text for ToUnicode destinations, including a supplementary plane character
+const TEXTS: &[&str] = &["A", "ß", "Ж", "€", "fi", "😀", "中", "ﷺ"];
+
+fn hex(text: &str) -> String {
+    text.encode_utf16().map(|u| format!("{:04X}", u)).collect()
+}
+
+fn to_unicode_stream(doc: &mut Document, code_bytes: usize, chars: &[(u32, &str)], ranges: &[(u32, u32, u32)]) -> Object {
+    let code = |c: u32| format!("<{:0width$X}>", c, width = code_bytes * 2);
+    let mut cmap = format!("1 begincodespacerange {} {} endcodespacerange\n", code(0), code((1 << (8 * code_bytes)) - 1));
+    if !chars.is_empty() {
+        cmap += &format!("{} beginbfchar\n", chars.len());
+        for (c, text) in chars {
+            cmap += &format!("{} <{}>\n", code(*c), hex(text));
+        }
+        cmap += "endbfchar\n";
+    }
+    if !ranges.is_empty() {
+        cmap += &format!("{} beginbfrange\n", ranges.len());
+        for (lo, hi, dst) in ranges {
+            cmap += &format!("{} {} <{:04X}>\n", code(*lo), code(*hi), dst);
+        }
+        cmap += "endbfrange\n";
+    }
+    doc.add_object(Stream::new(dictionary! {}, cmap.into_bytes())).into()
+}
+
+// Disjoint `(lo, hi)` code ranges in ascending order within `0..limit`
+fn segments(limit: u32, max_len: u32) -> impl Strategy<Value = Vec<(u32, u32)>> {
+    (0..=16u32, vec((0..max_len, 0..=16u32), 0..=8)).prop_map(move |(start, parts)| {
+        let mut segments = Vec::new();
+        let mut next = start;
+        for (len, gap) in parts {
+            if next >= limit {
+                break;
+            }
+            let hi = (next + len).min(limit - 1);
+            segments.push((next, hi));
+            next = hi + 1 + gap;
+        }
+        segments
+    })
+}
+
+fn simple_font(encoding: Object, first_char: u32, widths: &[u32], to_unicode: Option<Object>) -> Dictionary {
+    let mut font = dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Synthetic",
+        "Encoding" => encoding,
+        "FirstChar" => first_char as i64,
+        "LastChar" => (first_char as usize + widths.len() - 1) as i64,
+        "Widths" => widths.iter().map(|&w| Object::Integer(w as i64)).collect::<Vec<_>>(),
+    };
+    if let Some(to_unicode) = to_unicode {
+        font.set("ToUnicode", to_unicode);
+    }
+    font
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn differences_and_widths_round_trip(
+        runs in segments(224, 6).prop_flat_map(|segments| {
+            segments.into_iter()
+                .map(|(lo, hi)| (Just(lo), vec(select(GLYPHS), (hi - lo + 1) as usize)))
+                .collect::<Vec<_>>()
+        }),
+        (first_char, widths) in (0..64u32).prop_flat_map(|first| (Just(first), vec(0..=2000u32, (256 - first) as usize))),
+    ) {
+        let doc = Document::with_version("1.5");
+        // Runs of consecutive names after a starting code, as Differences is written
+        let mut expected: BTreeMap<u32, &str> = BTreeMap::new();
+        let mut differences = Vec::new();
+        for (lo, glyphs) in &runs {
+            differences.push(Object::Integer(32 + *lo as i64));
+            for (code, (name, text)) in (32 + lo..).zip(glyphs) {
+                differences.push(Object::Name(name.as_bytes().to_vec()));
+                expected.insert(code, text);
+            }
+        }
+        let encoding = dictionary! { "BaseEncoding" => "WinAnsiEncoding", "Differences" => differences };
+        let font = make_font(&doc, &simple_font(encoding.into(), first_char, &widths, None)).unwrap();
+
+        for code in 32..=255u32 {
+            let decoded = font.decode_char(code);
+            match expected.get(&code) {
+                Some(text) => prop_assert_eq!(&decoded, text, "code {}", code),
+                // Untouched ASCII letters keep their WinAnsi meaning
+                None if (code as u8).is_ascii_alphanumeric() => prop_assert_eq!(decoded, (code as u8 as char).to_string()),
+                None => {}
+            }
+            if code >= first_char {
+                prop_assert_eq!(font.get_width(code), widths[(code - first_char) as usize] as f64, "width of {}", code);
+            }
+        }
+    }
+
+    #[test]
+    fn simple_to_unicode_round_trips(
+        segments in segments(256, 12).prop_flat_map(|segments| {
+            // Each segment is written as bfchar entries or as one bfrange, whose destination
+            // stays in the BMP and doesn't cross a 256 boundary
+            segments.into_iter()
+                .map(|(lo, hi)| (Just(lo), Just(hi), any::<bool>(), vec(select(TEXTS), (hi - lo + 1) as usize), 0x20..=0xd7u32))
+                .collect::<Vec<_>>()
+        }),
+    ) {
+        let mut doc = Document::with_version("1.5");
+        let mut expected: BTreeMap<u32, String> = BTreeMap::new();
+        let (mut chars, mut ranges) = (Vec::new(), Vec::new());
+        for (lo, hi, as_chars, texts, dst) in segments {
+            if lo == hi || as_chars {
+                for (code, text) in (lo..=hi).zip(texts) {
+                    chars.push((code, text));
+                    expected.insert(code, text.to_string());
+                }
+            } else {
+                let dst = dst << 8;
+                ranges.push((lo, hi, dst));
+                for code in lo..=hi {
+                    expected.insert(code, char::from_u32(dst + code - lo).unwrap().to_string());
+                }
+            }
+        }
+        let to_unicode = to_unicode_stream(&mut doc, 1, &chars, &ranges);
+        let font = simple_font("WinAnsiEncoding".into(), 0, &[500; 256], Some(to_unicode));
+        let font = make_font(&doc, &font).unwrap();
+
+        let bytes: Vec<u8> = expected.keys().map(|&c| c as u8).collect();
+        prop_assert_eq!(font.decode(&bytes), expected.values().cloned().collect::<String>());
+    }
+
+    #[test]
+    fn cid_widths_and_to_unicode_round_trip(
+        default_width in 0..=1000u32,
+        // Each segment is written as `c [w1 w2 ...]` or as `c_first c_last w` with the first width
+        segments in segments(2000, 40).prop_flat_map(|segments| {
+            segments.into_iter()
+                .map(|(lo, hi)| (Just(lo), Just(hi), any::<bool>(), vec(1..=2000u32, (hi - lo + 1) as usize)))
+                .collect::<Vec<_>>()
+        }),
+        texts in vec(select(TEXTS), 20),
+    ) {
+        let mut doc = Document::with_version("1.5");
+        let mut expected_widths: BTreeMap<u32, f64> = BTreeMap::new();
+        let mut w = Vec::new();
+        for (lo, hi, individual, widths) in segments {
+            if individual {
+                for (cid, width) in (lo..=hi).zip(&widths) {
+                    expected_widths.insert(cid, *width as f64);
+                }
+                w.push(Object::Integer(lo as i64));
+                w.push(widths.iter().map(|&w| Object::Integer(w as i64)).collect::<Vec<_>>().into());
+            } else {
+                for cid in lo..=hi {
+                    expected_widths.insert(cid, widths[0] as f64);
+                }
+                w.extend([Object::Integer(lo as i64), Object::Integer(hi as i64), Object::Integer(widths[0] as i64)]);
+            }
+        }
+        let chars: Vec<(u32, &str)> = expected_widths.keys().copied().zip(texts).collect();
+        let to_unicode = to_unicode_stream(&mut doc, 2, &chars, &[]);
+        let descendant = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "Synthetic",
+            "DW" => default_width as i64,
+            "W" => w,
+        });
+        let font = dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "Synthetic",
+            "Encoding" => "Identity-H",
+            "DescendantFonts" => vec![Object::Reference(descendant)],
+            "ToUnicode" => to_unicode,
+        };
+        let font = make_font(&doc, &font).unwrap();
+
+        for cid in 0..2050 {
+            let expected = expected_widths.get(&cid).copied().unwrap_or(default_width as f64);
+            prop_assert_eq!(font.get_width(cid), expected, "width of CID {}", cid);
+        }
+        let bytes: Vec<u8> = chars.iter().flat_map(|(cid, _)| (*cid as u16).to_be_bytes()).collect();
+        prop_assert_eq!(font.decode(&bytes), chars.iter().map(|(_, text)| *text).collect::<String>());
+    }
+}
@@ -0,0 +1,207 @@
+// Content stream operator conformance
+//
+// `PdfBuilder` assembles small in-memory documents from content operators,
+// fonts and form XObjects, and `glyphs` reports where each glyph landed in
+// user space, so text positioning operators can be checked against the
+// numbers worked out by hand from the PDF specification. All text uses
+// Helvetica, where "A" is 667/1000 em wide and "B" 667 as well.
+use pdf_extract::layout::collect_text;
+use pdf_extract::{dictionary, Dictionary, Document, Object, Stream};
+
+const A: f64 = 0.667;
+
+/// In-memory document with shared resources for every page
+struct PdfBuilder {
+    doc: Document,
+    fonts: Dictionary,
+    xobjects: Dictionary,
+    pages: Vec<String>,
+}
+
+impl PdfBuilder {
+    /// Builder with Helvetica as /F1
+    fn new() -> PdfBuilder {
+        PdfBuilder { doc: Document::with_version("1.5"), fonts: Dictionary::new(), xobjects: Dictionary::new(), pages: Vec::new() }
+            .font("F1", "Helvetica")
+    }
+
+    fn font(mut self, name: &str, base_font: &str) -> PdfBuilder {
+        let id = self.doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => base_font });
+        self.fonts.set(name, id);
+        self
+    }
+
+    /// Form XObject drawn with `/name Do`; it shares the page fonts and forms, so forms can nest
+    fn form(mut self, name: &str, matrix: [f64; 6], content: &str) -> PdfBuilder {
+        let dict = dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Form",
+            "BBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            "Matrix" => matrix.iter().map(|&v| Object::Real(v as f32)).collect::<Vec<_>>(),
+        };
+        let id = self.doc.add_object(Stream::new(dict, content.as_bytes().to_vec()));
+        self.xobjects.set(name, id);
+        self
+    }
+
+    fn page(mut self, content: &str) -> PdfBuilder {
+        self.pages.push(content.to_string());
+        self
+    }
+
+    fn build(mut self) -> Document {
+        let doc = &mut self.doc;
+        let resources = doc.add_object(dictionary! { "Font" => self.fonts, "XObject" => self.xobjects.clone() });
+        // Forms resolve names through the same resources as the page
+        for (_, id) in self.xobjects.iter() {
+            let id = id.as_reference().unwrap();
+            doc.get_object_mut(id).unwrap().as_stream_mut().unwrap().dict.set("Resources", resources);
+        }
+        let pages_id = doc.new_object_id();
+        let kids: Vec<Object> = self.pages.iter()
+            .map(|content| {
+                let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+                doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => content_id,
+                    "Resources" => resources,
+                }).into()
+            })
+            .collect();
+        doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Count" => kids.len() as i64,
+            "Kids" => kids,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        }));
+        let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        doc.trailer.set("Root", catalog_id);
+        self.doc
+    }
+}
+
+/// A glyph as placed on the page: text, origin, size and angle rounded to 1/100
+#[derive(Debug, PartialEq)]
+struct Placed(String, f64, f64, f64, f64);
+
+fn round(v: f64) -> f64 {
+    let v = (v * 100.0).round() / 100.0;
+    if v == 0.0 { 0.0 } else { v }
+}
+
+fn glyphs(content: &str) -> Vec<Placed> {
+    glyphs_of(&PdfBuilder::new().page(content).build())
+}
+
+fn glyphs_of(doc: &Document) -> Vec<Placed> {
+    collect_text(doc).unwrap()
+        .into_iter()
+        .flat_map(|page| page.glyphs)
+        .map(|g| Placed(g.text, round(g.x), round(g.y), round(g.font_size), round(g.angle)))
+        .collect()
+}
+
+fn placed(text: &str, x: f64, y: f64, size: f64) -> Placed {
+    Placed(text.to_string(), round(x), round(y), size, 0.0)
+}
+
+#[test]
+fn single_glyph_at_td() {
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td (A) Tj ET"), [placed("A", 100.0, 200.0, 10.0)]);
+}
+
+#[test]
+fn td_moves_from_the_start_of_the_line() {
+    // The second Td is relative to 100 200, not to where "AB" ended
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td (AB) Tj 0 -20 Td (A) Tj ET"), [
+        placed("A", 100.0, 200.0, 10.0),
+        placed("B", 100.0 + 10.0 * A, 200.0, 10.0),
+        placed("A", 100.0, 180.0, 10.0),
+    ]);
+}
+
+#[test]
+fn td_capital_sets_leading_for_t_star() {
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td 0 -15 TD (A) Tj T* (B) Tj ET"), [
+        placed("A", 100.0, 185.0, 10.0),
+        placed("B", 100.0, 170.0, 10.0),
+    ]);
+    // TL on its own, then T* from the Tm origin
+    assert_eq!(glyphs("BT /F1 10 Tf 12 TL 1 0 0 1 50 60 Tm T* (A) Tj ET"), [placed("A", 50.0, 48.0, 10.0)]);
+}
+
+#[test]
+fn tm_replaces_the_matrix_and_scales_later_moves() {
+    // Tm is absolute: the earlier Td doesn't carry over
+    assert_eq!(glyphs("BT /F1 10 Tf 300 300 Td 1 0 0 1 100 200 Tm (A) Tj ET"), [placed("A", 100.0, 200.0, 10.0)]);
+    // Td after a scaling Tm is in text space, so the offset doubles along with the glyph
+    assert_eq!(glyphs("BT /F1 10 Tf 2 0 0 2 100 200 Tm 10 5 Td (A) Tj ET"), [placed("A", 120.0, 210.0, 20.0)]);
+}
+
+#[test]
+fn bt_resets_the_text_matrix() {
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td (A) Tj ET BT 50 50 Td (B) Tj ET"), [
+        placed("A", 100.0, 200.0, 10.0),
+        placed("B", 50.0, 50.0, 10.0),
+    ]);
+}
+
+#[test]
+fn tj_adjustments_are_thousandths_of_an_em() {
+    // -1000 moves one em (10pt) right, positive numbers move left
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td [(A) -1000 (B) 500 (A)] TJ ET"), [
+        placed("A", 100.0, 200.0, 10.0),
+        placed("B", 100.0 + 10.0 * A + 10.0, 200.0, 10.0),
+        placed("A", 100.0 + 20.0 * A + 5.0, 200.0, 10.0),
+    ]);
+}
+
+#[test]
+fn tc_tz_and_ts_adjust_the_pen() {
+    // Character spacing is added after each glyph
+    assert_eq!(glyphs("BT /F1 10 Tf 2 Tc 100 200 Td (AB) Tj ET")[1], placed("B", 100.0 + 10.0 * A + 2.0, 200.0, 10.0));
+    // Horizontal scaling stretches advances, and the glyph's horizontal extent
+    assert_eq!(glyphs("BT /F1 10 Tf 50 Tz 100 200 Td (AB) Tj ET")[1].1, round(100.0 + 5.0 * A));
+    // Rise lifts glyphs off the baseline without moving the line
+    assert_eq!(glyphs("BT /F1 10 Tf 100 200 Td 3 Ts (A) Tj 0 Ts 0 -20 Td (B) Tj ET"), [
+        placed("A", 100.0, 203.0, 10.0),
+        placed("B", 100.0, 180.0, 10.0),
+    ]);
+}
+
+#[test]
+fn rotated_text_advances_along_its_baseline() {
+    // A quarter turn: the pen moves up the page
+    assert_eq!(glyphs("BT /F1 10 Tf 0 1 -1 0 300 300 Tm (AB) Tj ET"), [
+        Placed("A".into(), 300.0, 300.0, 10.0, 90.0),
+        Placed("B".into(), 300.0, round(300.0 + 10.0 * A), 10.0, 90.0),
+    ]);
+}
+
+#[test]
+fn cm_concatenates_before_the_current_matrix() {
+    // The later cm applies first: (10, 0) scales to (20, 0), then translates to (120, 0)
+    assert_eq!(glyphs("1 0 0 1 100 0 cm 2 0 0 2 0 0 cm BT /F1 10 Tf 10 0 Td (A) Tj ET"), [placed("A", 120.0, 0.0, 20.0)]);
+    // q/Q restores the matrix
+    assert_eq!(glyphs("q 1 0 0 1 100 0 cm Q BT /F1 10 Tf 10 0 Td (A) Tj ET"), [placed("A", 10.0, 0.0, 10.0)]);
+}
+
+#[test]
+fn forms_inherit_the_ctm_and_apply_their_matrix() {
+    let doc = PdfBuilder::new()
+        .form("X1", [1.0, 0.0, 0.0, 1.0, 0.0, 50.0], "BT /F1 10 Tf 10 20 Td (A) Tj ET")
+        .page("q 1 0 0 1 100 0 cm /X1 Do Q BT /F1 10 Tf 10 20 Td (B) Tj ET")
+        .build();
+    assert_eq!(glyphs_of(&doc), [placed("A", 110.0, 70.0, 10.0), placed("B", 10.0, 20.0, 10.0)]);
+}
+
+#[test]
+fn nested_forms_compose_their_matrices() {
+    let doc = PdfBuilder::new()
+        .form("Inner", [2.0, 0.0, 0.0, 2.0, 0.0, 0.0], "BT /F1 10 Tf 5 5 Td (A) Tj ET")
+        .form("Outer", [1.0, 0.0, 0.0, 1.0, 100.0, 100.0], "/Inner Do")
+        .page("/Outer Do")
+        .build();
+    assert_eq!(glyphs_of(&doc), [placed("A", 110.0, 110.0, 20.0)]);
+}
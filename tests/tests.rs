@@ -51,11 +51,11 @@ impl ExpectedText<'_> {
             let docs_cache = "tests/docs_cache";
             if !std::path::Path::new(docs_cache).exists() {
                 // This might race with exists test above, but that's fine
-                if let Err(e) = std::fs::create_dir(docs_cache) {
-                    if e.kind() != std::io::ErrorKind::AlreadyExists {
-                        panic!("Failed to create directory {}, {}", docs_cache, e);
-                    }
-                } 
+                if let Err(e) = std::fs::create_dir(docs_cache)
+                    && e.kind() != std::io::ErrorKind::AlreadyExists
+                {
+                    panic!("Failed to create directory {}, {}", docs_cache, e);
+                }
             }
             let file_path = format!("{}/{}", docs_cache, filename.replace(".link", ""));
             if std::path::Path::new(&file_path).exists() {
@@ -84,3 +84,2935 @@ impl ExpectedText<'_> {
         );
     }
 }
+
+#[test]
+fn usecmap_merges_parent_ranges() {
+    use pdf_extract::{dictionary, make_font, Document, Object, Stream};
+
+    let mut doc = Document::with_version("1.5");
+    let parent = doc.add_object(Stream::new(
+        dictionary! { "Type" => "CMap" },
+        b"1 begincodespacerange <0000> <FFFF> endcodespacerange \
+          1 begincidrange <0000> <FFFF> 0 endcidrange"
+            .to_vec(),
+    ));
+    let child = doc.add_object(Stream::new(
+        dictionary! { "Type" => "CMap", "UseCMap" => parent },
+        b"1 begincidchar <0041> 100 endcidchar".to_vec(),
+    ));
+    let to_unicode = doc.add_object(Stream::new(
+        dictionary! {},
+        b"2 beginbfchar <0064> <005A> <0042> <0042> endbfchar".to_vec(),
+    ));
+    let descendant = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "CIDFontType0",
+        "BaseFont" => "Test",
+    });
+    let font = dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type0",
+        "BaseFont" => "Test",
+        "Encoding" => child,
+        "DescendantFonts" => vec![Object::Reference(descendant)],
+        "ToUnicode" => to_unicode,
+    };
+
+    let loaded = make_font(&doc, &font).unwrap();
+    assert_eq!(loaded.decode(&[0x00, 0x41, 0x00, 0x42]), "ZB");
+
+    // A parent using itself is followed up to the depth limit, keeping what was read
+    doc.get_object_mut(parent).unwrap().as_stream_mut().unwrap().dict.set("UseCMap", parent);
+    let loaded = make_font(&doc, &font).unwrap();
+    assert_eq!(loaded.decode(&[0x00, 0x41, 0x00, 0x42]), "ZB");
+}
+
+#[test]
+fn to_unicode_ranges_map_arrays_and_malformed_maps_are_ignored() {
+    use pdf_extract::{dictionary, make_font, Document, Stream};
+
+    let mut doc = Document::with_version("1.5");
+    let mut font = |cmap: &[u8]| {
+        let to_unicode = doc.add_object(Stream::new(dictionary! {}, cmap.to_vec()));
+        let font = dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica", "ToUnicode" => to_unicode };
+        make_font(&doc, &font).map(|font| font.decode(b"AB"))
+    };
+    assert_eq!(font(b"1 beginbfrange <41> <42> [<0066006C> <0066>] endbfrange").unwrap(), "flf");
+    // Missing destinations, an odd-length start and an array too short for its range; simple
+    // fonts fall back to their encoding
+    for cmap in [&b"2 beginbfchar <41>"[..], b"1 beginbfrange <41> <42> <006600> endbfrange", b"1 beginbfrange <41> <42> [<0066>] endbfrange"] {
+        assert_eq!(font(cmap).unwrap(), "AB", "{}", String::from_utf8_lossy(cmap));
+    }
+}
+
+// Single-page document with Helvetica bound to /F1 running `content`
+fn simple_doc(content: &str) -> pdf_extract::Document {
+    use pdf_extract::{dictionary, Document, Object, Stream};
+
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+    let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+    let page_id = doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+        "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+    });
+    doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Kids" => vec![Object::Reference(page_id)],
+        "Count" => 1,
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }));
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    doc
+}
+
+// Resource dictionary of the first page of `doc`
+fn page_resources_mut(doc: &mut pdf_extract::Document) -> &mut pdf_extract::Dictionary {
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().get_mut(b"Resources").unwrap().as_dict_mut().unwrap()
+}
+
+// Colours of the `fill` attributes of an SVG page, in order
+fn svg_fills(svg: &str) -> Vec<&str> {
+    svg.match_indices("fill='#").map(|(i, _)| &svg[i + 6..i + 13]).collect()
+}
+
+#[test]
+fn xliff_export_has_page_and_bbox() {
+    use pdf_extract::xliff::{export_xliff, XliffOptions};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Fish & Chips) Tj 0 -14 Td (to go) Tj ET \
+                          BT /F1 12 Tf 72 500 Td (Second) Tj ET");
+    let xliff = export_xliff(&doc, &XliffOptions::default()).unwrap();
+    assert!(xliff.contains(r#"<group id="page-1" pdf:page="1">"#), "{}", xliff);
+    assert!(xliff.contains("<source>Fish &amp; Chips to go</source>"), "{}", xliff);
+    assert!(xliff.contains(r#"<trans-unit id="p1-s2""#), "{}", xliff);
+    assert!(xliff.contains(r#"pdf:bbox="72.00 "#), "{}", xliff);
+}
+
+#[test]
+fn list_fonts_reports_core_font() {
+    use pdf_extract::fonts::{list_fonts, FontEncoding};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Hi) Tj ET");
+    let fonts = list_fonts(&doc).unwrap();
+    assert_eq!(fonts.len(), 1);
+    let font = &fonts[0];
+    assert_eq!(font.resource_name, "F1");
+    assert_eq!(font.base_font.as_deref(), Some("Helvetica"));
+    assert_eq!(font.subtype, "Type1");
+    assert!(!font.embedded);
+    assert!(!font.has_to_unicode);
+    assert_eq!(font.encoding, FontEncoding::Builtin);
+    assert_eq!(font.pages, vec![1]);
+}
+
+#[test]
+fn extract_fonts_wraps_type1_as_pfb() {
+    use pdf_extract::fonts::{extract_fonts, FontProgramKind};
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Hi) Tj ET");
+    let mut program = Stream::new(dictionary! { "Length1" => 4, "Length2" => 3, "Length3" => 0 }, b"%!PSabc".to_vec());
+    program.dict.set("Length", 7);
+    let program_id = doc.add_object(program);
+    let descriptor_id = doc.add_object(dictionary! { "Type" => "FontDescriptor", "FontFile" => program_id });
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    if let Some(Object::Dictionary(font)) = doc.objects.get_mut(&font_id) {
+        font.set("FontDescriptor", descriptor_id);
+    }
+
+    let fonts = extract_fonts(&doc).unwrap();
+    assert_eq!(fonts.len(), 1);
+    assert_eq!(fonts[0].kind, FontProgramKind::Type1);
+    assert_eq!(fonts[0].file_name(), "Helvetica.pfb");
+    assert_eq!(fonts[0].data, [&[0x80, 1, 4, 0, 0, 0][..], b"%!PS", &[0x80, 2, 3, 0, 0, 0], b"abc", &[0x80, 3]].concat());
+}
+
+#[cfg(feature = "invoice")]
+#[test]
+fn invoice_fields_and_line_items() {
+    use pdf_extract::invoice::{extract_invoice, parse_amount};
+
+    let doc = simple_doc("BT /F1 14 Tf 72 740 Td (Acme Widgets Ltd) Tj ET \
+                          BT /F1 10 Tf 72 700 Td (Invoice No: INV-0042) Tj ET \
+                          BT /F1 10 Tf 72 680 Td (Invoice Date: 05.03.2024) Tj ET \
+                          BT /F1 10 Tf 72 640 Td (Description) Tj 200 0 Td (Qty) Tj 60 0 Td (Price) Tj 80 0 Td (Amount) Tj ET \
+                          BT /F1 10 Tf 72 620 Td (Widget) Tj 200 0 Td (2) Tj 60 0 Td (10.00) Tj 80 0 Td (20.00) Tj ET \
+                          BT /F1 10 Tf 72 600 Td (Setup fee) Tj 340 0 Td (15.00) Tj ET \
+                          BT /F1 10 Tf 72 560 Td (Subtotal: 35.00) Tj ET \
+                          BT /F1 10 Tf 72 540 Td (VAT 24%: 8.40) Tj ET \
+                          BT /F1 10 Tf 72 520 Td (Total: EUR 43.40) Tj ET");
+    let invoice = extract_invoice(&doc).unwrap();
+    assert_eq!(invoice.vendor.unwrap().value, "Acme Widgets Ltd");
+    assert_eq!(invoice.number.unwrap().value, "INV-0042");
+    assert_eq!(invoice.date.unwrap().value, "2024-03-05");
+    assert_eq!(invoice.line_items.len(), 2, "{:?}", invoice.line_items);
+    assert_eq!(invoice.line_items[0].description, "Widget");
+    assert_eq!(invoice.line_items[0].quantity, Some(2.));
+    assert_eq!(invoice.line_items[1].amount.value, 15.);
+    let total = invoice.totals.total.unwrap();
+    assert_eq!(total.value.value, 43.4);
+    assert_eq!(total.value.currency.as_deref(), Some("EUR"));
+    assert_eq!(invoice.totals.tax.unwrap().value.currency.as_deref(), Some("EUR"));
+
+    assert_eq!(parse_amount("1.234,50 €").unwrap().value, 1234.5);
+    assert_eq!(parse_amount("$1,234").unwrap().value, 1234.);
+    assert_eq!(parse_amount("(30.00)").unwrap().value, -30.);
+}
+
+#[test]
+fn reference_list_styles() {
+    use pdf_extract::references::extract_references;
+
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td (Introduction text.) Tj ET \
+                          BT /F1 12 Tf 72 660 Td (References) Tj ET \
+                          BT /F1 10 Tf 72 640 Td ([1] A. Author. On things. J. Stuff, 2020. doi:10.1000/xyz-) Tj ET \
+                          BT /F1 10 Tf 84 628 Td (123.) Tj ET \
+                          BT /F1 10 Tf 72 616 Td ([2] B. Writer. Other things. arXiv:2101.01234v2.) Tj ET");
+    let refs = extract_references(&doc).unwrap();
+    assert_eq!(refs.len(), 2, "{:?}", refs);
+    assert_eq!(refs[0].label.as_deref(), Some("[1]"));
+    assert_eq!(refs[0].doi.as_deref(), Some("10.1000/xyz123"));
+    assert_eq!(refs[1].arxiv.as_deref(), Some("2101.01234v2"));
+    assert!(refs[1].text.starts_with("B. Writer."));
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Bibliography) Tj ET \
+                          BT /F1 10 Tf 72 680 Td (Smith, J. (2019). A long title that) Tj ET \
+                          BT /F1 10 Tf 90 668 Td (wraps. Nature, 1.) Tj ET \
+                          BT /F1 10 Tf 72 656 Td (Young, K. (2021). Short.) Tj ET");
+    let refs = extract_references(&doc).unwrap();
+    assert_eq!(refs.len(), 2, "{:?}", refs);
+    assert_eq!(refs[0].text, "Smith, J. (2019). A long title that wraps. Nature, 1.");
+    assert_eq!(refs[1].label, None);
+}
+
+#[test]
+fn glyph_overrides_patch_decoding() {
+    use pdf_extract::overrides::{font_matches, OverrideMode};
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, PlainTextOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Hi) Tj ET");
+    let mut options = ExtractionOptions::default();
+    options.glyph_overrides
+        .add("Helv*", OverrideMode::BeforeDecode, [(b'H' as u32, "Y")])
+        .add("Helvetica", OverrideMode::AfterDecode, [(b'i' as u32, "o")])
+        .add("Times-*", OverrideMode::BeforeDecode, [(b'i' as u32, "!")]);
+    let mut out = Vec::new();
+    output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().trim(), "Yi");
+
+    assert!(font_matches("Garamond*", "ABCDEF+Garamond-Bold"));
+    assert!(!font_matches("XYZ+Garamond", "ABCDEF+Garamond"));
+}
+
+#[test]
+fn symbolic_core_fonts_use_builtin_encoding() {
+    use pdf_extract::{output_doc, Object, PlainTextOutput};
+
+    for (base_font, content, expected) in [
+        ("Symbol", "BT /F1 12 Tf 72 700 Td (a\\267) Tj ET", "\u{3b1}\u{2022}"),
+        ("ZapfDingbats", "BT /F1 12 Tf 72 700 Td (l4) Tj ET", "\u{25cf}\u{2714}"),
+    ] {
+        let mut doc = simple_doc(content);
+        for object in doc.objects.values_mut() {
+            if let Object::Dictionary(dict) = object
+                && dict.has_type(b"Font")
+            {
+                dict.set("BaseFont", Object::Name(base_font.as_bytes().to_vec()));
+            }
+        }
+        let mut out = Vec::new();
+        output_doc(&doc, &mut PlainTextOutput::new(&mut out)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), expected, "{}", base_font);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn positioned_text_and_colour_spaces_round_trip_through_serde() {
+    use pdf_extract::layout::{collect_text, PageText};
+    use pdf_extract::{dictionary, output_doc, ColorSpace, FillRule, MediaBox, Object, OutputDev, Path, PdfResult, PdfTransform};
+
+    // Keeps the colour space and path of each fill
+    struct Fills(Vec<(ColorSpace, Path)>);
+    impl OutputDev for Fills {
+        fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+        fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+        fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> { Ok(()) }
+        fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+        fn fill(&mut self, _: &PdfTransform, colorspace: &ColorSpace, _: &[f64], path: &Path, _: FillRule) -> PdfResult<()> {
+            self.0.push((colorspace.clone(), path.clone()));
+            Ok(())
+        }
+    }
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Persisted) Tj ET /Spot cs 0.5 scn 0 0 10 10 re f");
+    let function = dictionary! {
+        "FunctionType" => 2,
+        "Domain" => vec![0.into(), 1.into()],
+        "C0" => vec![1.into(), 1.into(), 1.into()],
+        "C1" => vec![1.into(), 0.into(), 0.into()],
+        "N" => 1,
+    };
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Spot" => vec![Object::Name(b"Separation".to_vec()), "Spot".into(), "DeviceRGB".into(), function.into()],
+    });
+
+    let pages = collect_text(&doc).unwrap();
+    let json = serde_json::to_string(&pages).unwrap();
+    let restored: Vec<PageText> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored[0].lines()[0].text, "Persisted");
+    assert_eq!(restored[0].glyphs.len(), pages[0].glyphs.len());
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+    let mut fills = Fills(Vec::new());
+    output_doc(&doc, &mut fills).unwrap();
+    let (colorspace, path) = &fills.0[0];
+    let restored: ColorSpace = serde_json::from_str(&serde_json::to_string(colorspace).unwrap()).unwrap();
+    assert_eq!(restored.to_rgb(&[0.5]), colorspace.to_rgb(&[0.5]));
+    assert_eq!(restored.to_rgb(&[0.5]), Some((1., 0.5, 0.5)));
+    let restored: Path = serde_json::from_str(&serde_json::to_string(path).unwrap()).unwrap();
+    assert_eq!(&restored, path);
+}
+
+#[cfg(feature = "legal")]
+#[test]
+fn legal_clause_outline() {
+    use pdf_extract::legal::{build_outline, find_clauses, ClauseKind};
+
+    let text = "ARTICLE IV Payment\n\
+                4.1 Fees. The customer pays.\n\
+                (a) monthly; and\n\
+                (i) in advance\n\
+                (b) in euro.\n\
+                § 12 Notices\n\
+                It cost 4.2 million.\n";
+    let anchors = find_clauses(text);
+    let labels: Vec<_> = anchors.iter().map(|a| (a.label.as_str(), a.level)).collect();
+    assert_eq!(labels, vec![
+        ("ARTICLE IV", 1), ("4.1", 3), ("(a)", 4), ("(i)", 5), ("(b)", 4), ("§ 12", 2),
+    ]);
+    assert_eq!(anchors[0].kind, ClauseKind::Article);
+    assert_eq!(anchors[0].title, "Payment");
+    assert_eq!(anchors[1].title, "Fees. The customer pays.");
+    assert_eq!(&text[anchors[5].offset..anchors[5].offset + 5], "§ 12");
+
+    let outline = build_outline(&anchors);
+    assert_eq!(outline.len(), 1);
+    assert_eq!(outline[0].children.len(), 2);
+    assert_eq!(outline[0].children[0].children.len(), 2);
+}
+
+#[test]
+fn bates_numbers_across_pages() {
+    use pdf_extract::bates::detect;
+    use pdf_extract::layout::{PageText, TextGlyph};
+    use pdf_extract::MediaBox;
+
+    let media_box = MediaBox { llx: 0., lly: 0., urx: 612., ury: 792. };
+    let glyph = |x: f64, y: f64, text: &str| TextGlyph::new(text, x, y, 6. * text.len() as f64, 10.);
+    let stamped = |counters: &[u64]| -> Vec<PageText> {
+        counters.iter().zip(1..).map(|(counter, page_num)| PageText {
+            page_num,
+            media_box,
+            glyphs: vec![
+                glyph(72., 700., "Page"),
+                glyph(110., 700., &format!("{}", page_num)),
+                glyph(480., 30., &format!("ACME-{:06}", counter)),
+            ],
+        }).collect()
+    };
+
+    let stamps = detect(&stamped(&[41, 42, 43]));
+    assert_eq!(stamps.len(), 3);
+    assert_eq!(stamps[0].label, "ACME-000041");
+    assert_eq!(stamps[0].prefix, "ACME");
+    assert_eq!(stamps[2].number, 43);
+    assert_eq!(stamps[2].digits, 6);
+
+    // Counters must strictly increase: gaps are allowed, repeats and decreases are not
+    assert_eq!(detect(&stamped(&[41, 45, 46])).len(), 3);
+    assert!(detect(&stamped(&[41, 41, 42])).is_empty());
+    assert!(detect(&stamped(&[43, 42, 44])).is_empty());
+}
+
+#[test]
+fn math_sidecar_keeps_glyph_fonts() {
+    use pdf_extract::layout::{PageText, TextGlyph};
+    use pdf_extract::math::{detect_math, write_regions};
+    use pdf_extract::MediaBox;
+
+    let glyph = |x: f64, y: f64, size: f64, text: &str, font: &str| {
+        let mut glyph = TextGlyph::new(text, x, y, size * 0.5, size);
+        glyph.font = Some(font.into());
+        glyph.starts_word = false;
+        glyph
+    };
+    let mut x = 72.;
+    let mut glyphs = Vec::new();
+    for (text, font, gap) in [("w", "Times", 0.), ("here", "Times", 0.), ("x", "CMMI10", 8.), ("2", "CMR7", 0.),
+                              ("+", "CMR10", 6.), ("\u{3b1}", "CMMI10", 6.), ("holds", "Times", 8.)] {
+        x += gap;
+        let (y, size) = if text == "2" { (704., 7.) } else { (700., 10.) };
+        glyphs.push(glyph(x, y, size, text, font));
+        x += size * 0.5 * text.chars().count() as f64;
+    }
+    let page = PageText { page_num: 1, media_box: MediaBox { llx: 0., lly: 0., urx: 612., ury: 792. }, glyphs };
+
+    let regions = detect_math(&page);
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].text(), "x2 + \u{3b1}");
+    assert_eq!(regions[0].latex_hint(), "x^{2} + \\alpha");
+    let mut out = Vec::new();
+    write_regions(&regions, &mut out).unwrap();
+    let json = String::from_utf8(out).unwrap();
+    assert!(json.contains(r#""font":"CMMI10""#), "{}", json);
+    assert!(json.contains(r#""script":"superscript""#), "{}", json);
+}
+
+#[test]
+fn afm_kerning_is_reported_without_moving_core_font_glyphs() {
+    use pdf_extract::afm::{standard, AfmMetrics};
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, PlainTextOutput};
+
+    let afm = "StartFontMetrics 4.1\nFontName Helvetica\nCapHeight 718\nStartCharMetrics 2\n\
+               C 65 ; WX 667 ; N A ; B 14 0 654 718 ;\nC 86 ; WX 667 ; N V ; B 8 0 659 718 ;\n\
+               EndCharMetrics\nStartKernPairs 1\nKPX A V -70\nEndKernPairs\nEndFontMetrics\n";
+    let metrics = AfmMetrics::parse(afm).unwrap();
+    assert_eq!(metrics.cap_height, Some(718.));
+    assert_eq!(metrics.width("V"), Some(667.));
+    assert_eq!(metrics.kern("A", "V"), -70.);
+
+    // The built-in Core14 metrics are complete
+    let helvetica = standard("Helvetica").unwrap();
+    assert_eq!((helvetica.cap_height, helvetica.x_height, helvetica.descender), (Some(718.), Some(523.), Some(-207.)));
+    assert_eq!(helvetica.font_bbox, Some([-166., -225., 1000., 931.]));
+    assert_eq!(helvetica.char_metrics.iter().find(|c| c.name == "A").unwrap().bbox, Some([14., 0., 654., 718.]));
+    assert_eq!(helvetica.kern("A", "V"), -70.);
+
+    // Glyphs stay where the widths put them, with the pair's kerning alongside
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td (AVAV) Tj ET");
+    let glyphs = |options: &ExtractionOptions| {
+        let mut collector = TextCollector::new();
+        output_doc_with_options(&doc, &mut collector, options).unwrap();
+        collector.pages()[0].glyphs.iter().map(|g| (g.x, g.kerning)).collect::<Vec<_>>()
+    };
+    let builtin = glyphs(&ExtractionOptions::default());
+    let mut options = ExtractionOptions::default();
+    options.core_font_metrics.add(metrics);
+    let registered = glyphs(&options);
+    assert!((builtin[1].0 - 78.67).abs() < 1e-9, "{:?}", builtin);
+    assert_eq!(builtin.iter().map(|g| g.0).collect::<Vec<_>>(), registered.iter().map(|g| g.0).collect::<Vec<_>>());
+    // The Core14 AFM kerns V A by -80 too, the registered one only A V
+    assert!((builtin[1].1 + 0.7).abs() < 1e-9 && (builtin[2].1 + 0.8).abs() < 1e-9, "{:?}", builtin);
+    assert!((registered[1].1 + 0.7).abs() < 1e-9 && registered[2].1 == 0., "{:?}", registered);
+
+    // A gap of 0.1 em is a word gap for glyphs that the font sets 0.07 em closer
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td [(A) -100 (V)] TJ ET");
+    let text = |options: &ExtractionOptions| {
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), options).unwrap();
+        String::from_utf8(out).unwrap().trim().to_string()
+    };
+    assert_eq!(text(&ExtractionOptions::default()), "A V");
+    let mut options = ExtractionOptions::default();
+    options.core_font_metrics.add(AfmMetrics::parse("StartFontMetrics 4.1\nFontName Helvetica\nEndFontMetrics\n").unwrap());
+    assert_eq!(text(&options), "AV");
+}
+
+#[test]
+fn watermark_report_separates_stamps_from_body() {
+    use pdf_extract::watermark::{watermark_report, WatermarkKind};
+
+    let mut doc = simple_doc("");
+    let content = "BT /F1 12 Tf 72 700 Td (Body text) Tj ET \
+                   q /GS1 gs 0.8 sc BT /F1 48 Tf 0.7071 0.7071 -0.7071 0.7071 150 200 Tm (CONFIDENTIAL) Tj ET Q \
+                   BT /F1 2 Tf 10 10 Td (SN-88231) Tj ET";
+    replace_pages(&mut doc, &[content, content]);
+    let report = watermark_report(&doc).unwrap();
+    assert_eq!(report.watermarks.len(), 1, "{:?}", report);
+    assert_eq!(report.watermarks[0].text, "CONFIDENTIAL");
+    assert_eq!(report.watermarks[0].kinds, vec![WatermarkKind::Diagonal, WatermarkKind::Faint]);
+    assert_eq!(report.watermarks[0].pages, vec![1, 2]);
+    assert_eq!(report.identifiers.len(), 1);
+    assert_eq!(report.identifiers[0].text, "SN-88231");
+}
+
+// Replace the pages of a `simple_doc` with one page per content stream, sharing its resources
+fn replace_pages(doc: &mut pdf_extract::Document, contents: &[&str]) {
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let pages_id = doc.catalog().unwrap().get(b"Pages").unwrap().as_reference().unwrap();
+    let page_id = doc.get_pages()[&1];
+    let mut resources = doc.get_dictionary(page_id).unwrap().get(b"Resources").unwrap().as_dict().unwrap().clone();
+    resources.set("ExtGState", dictionary! { "GS1" => dictionary! { "ca" => 0.3 } });
+    let mut kids = Vec::new();
+    for content in contents {
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.as_bytes().to_vec()));
+        kids.push(Object::Reference(doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+            "Resources" => resources.clone(),
+        })));
+    }
+    let pages = doc.get_dictionary_mut(pages_id).unwrap();
+    pages.set("Count", kids.len() as i64);
+    pages.set("Kids", kids);
+}
+
+#[test]
+fn layer_filter_separates_backdrop_from_body() {
+    use pdf_extract::layers::{extract_text_layer, ContentLayer};
+
+    let doc = simple_doc("0.9 g 0 0 612 792 re f \
+                          0 g BT /F1 12 Tf 72 700 Td (Dear customer) Tj ET \
+                          0.8 g BT /F1 48 Tf 0.7071 0.7071 -0.7071 0.7071 150 200 Tm (DRAFT) Tj ET");
+    let foreground = extract_text_layer(&doc, ContentLayer::Foreground).unwrap();
+    let background = extract_text_layer(&doc, ContentLayer::Background).unwrap();
+    assert!(foreground.contains("Dear customer") && !foreground.contains("DRAFT"), "{foreground:?}");
+    assert!(background.contains("DRAFT") && !background.contains("Dear"), "{background:?}");
+}
+
+#[test]
+fn truetype_widths_fall_back_to_hmtx() {
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{dictionary, Object, Stream};
+
+    // head, hhea, hmtx and a (1,0) byte encoding cmap mapping A and B to glyphs 1 and 2
+    let mut head = vec![0u8; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    let mut hhea = vec![0u8; 36];
+    hhea[34..36].copy_from_slice(&3u16.to_be_bytes());
+    let hmtx: Vec<u8> = [500u16, 600, 700].iter().flat_map(|w| [w.to_be_bytes(), [0, 0]].concat()).collect();
+    let mut cmap = vec![0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 12, 0, 0, 1, 6, 0, 0];
+    let mut glyphs = [0u8; 256];
+    glyphs[b'A' as usize] = 1;
+    glyphs[b'B' as usize] = 2;
+    cmap.extend_from_slice(&glyphs);
+    let tables = [(b"cmap", cmap), (b"head", head), (b"hhea", hhea), (b"hmtx", hmtx)];
+    let mut ttf = vec![0, 1, 0, 0, 0, tables.len() as u8, 0, 0, 0, 0, 0, 0];
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        ttf.extend_from_slice(*tag);
+        ttf.extend_from_slice(&[0; 4]);
+        ttf.extend_from_slice(&(offset as u32).to_be_bytes());
+        ttf.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len();
+    }
+    for (_, data) in &tables {
+        ttf.extend_from_slice(data);
+    }
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (AB) Tj ET");
+    let program_id = doc.add_object(Stream::new(dictionary! {}, ttf));
+    let descriptor_id = doc.add_object(dictionary! { "Type" => "FontDescriptor", "FontFile2" => program_id });
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    if let Some(Object::Dictionary(font)) = doc.objects.get_mut(&font_id) {
+        font.set("Subtype", "TrueType");
+        font.set("BaseFont", "ABCDEF+Custom");
+        font.set("Encoding", "WinAnsiEncoding");
+        font.set("FontDescriptor", descriptor_id);
+    }
+
+    let pages = collect_text(&doc).unwrap();
+    let widths: Vec<f64> = pages[0].glyphs.iter().map(|g| g.width).collect();
+    assert_eq!(widths.len(), 2);
+    assert!((widths[0] - 7.2).abs() < 1e-9 && (widths[1] - 8.4).abs() < 1e-9, "{widths:?}");
+}
+
+#[test]
+fn pua_policy_replaces_and_counts_codepoints() {
+    use pdf_extract::pua::PuaPolicy;
+    use pdf_extract::{dictionary, output_doc_with_diagnostics, ExtractionOptions, Object, PlainTextOutput};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (AxA) Tj ET");
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    if let Some(Object::Dictionary(font)) = doc.objects.get_mut(&font_id) {
+        // `Aacutesmall` lives in the Adobe private use block at U+F7E1
+        font.set("Encoding", dictionary! { "Differences" => vec![65.into(), Object::Name(b"Aacutesmall".to_vec())] });
+    }
+
+    let extract = |policy: PuaPolicy| {
+        let options = ExtractionOptions { pua_policy: policy, ..Default::default() };
+        let mut text = Vec::new();
+        let diagnostics = output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut text), &options).unwrap();
+        (String::from_utf8(text).unwrap().trim().to_string(), diagnostics)
+    };
+    let (text, diagnostics) = extract(PuaPolicy::Replace("?".to_string()));
+    assert_eq!(text, "?x?");
+    assert_eq!(diagnostics.pua_fonts.len(), 1);
+    assert_eq!(diagnostics.pua_fonts[0].font, "Helvetica");
+    assert_eq!(diagnostics.pua_fonts[0].occurrences(), 2);
+    assert_eq!(extract(PuaPolicy::Drop).0, "x");
+    assert_eq!(extract(PuaPolicy::PassThrough).0, "\u{f7e1}x\u{f7e1}");
+}
+
+#[test]
+fn type3_glyph_procedures_reach_fill() {
+    use pdf_extract::{
+        dictionary, output_doc, ColorSpace, FillRule, MediaBox, Object, OutputDev, Path, PathOp, PdfResult, PdfTransform, Stream,
+    };
+
+    #[derive(Default)]
+    struct Fills(Vec<(f64, f64, f64, f64)>);
+
+    impl OutputDev for Fills {
+        fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+        fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+        fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> { Ok(()) }
+        fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+        fn fill(&mut self, ctm: &PdfTransform, _: &ColorSpace, _: &[f64], path: &Path, _: FillRule) -> PdfResult<()> {
+            if let Some(PathOp::Rect(x, y, w, h)) = path.ops.first() {
+                let ll = ctm.transform_point(euclid::point2(*x, *y));
+                let ur = ctm.transform_point(euclid::point2(x + w, y + h));
+                self.0.push((ll.x, ll.y, ur.x, ur.y));
+            }
+            Ok(())
+        }
+    }
+
+    let mut doc = simple_doc("BT /F1 10 Tf 72 700 Td (AA) Tj ET");
+    let square = doc.add_object(Stream::new(dictionary! {}, b"0 0 1000 1000 re f".to_vec()));
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    doc.objects.insert(font_id, Object::Dictionary(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type3",
+        "FontBBox" => vec![0.into(), 0.into(), 1000.into(), 1000.into()],
+        "FontMatrix" => vec![0.001.into(), 0.into(), 0.into(), 0.001.into(), 0.into(), 0.into()],
+        "CharProcs" => dictionary! { "square" => square },
+        "Encoding" => dictionary! { "Differences" => vec![65.into(), Object::Name(b"square".to_vec())] },
+        "FirstChar" => 65,
+        "LastChar" => 65,
+        "Widths" => vec![1000.into()],
+    }));
+
+    let mut fills = Fills::default();
+    output_doc(&doc, &mut fills).unwrap();
+    let rounded: Vec<_> = fills.0.iter().map(|&(a, b, c, d)| [a, b, c, d].map(|v| (v * 1000.).round() / 1000.)).collect();
+    assert_eq!(rounded, vec![[72., 700., 82., 710.], [82., 700., 92., 710.]]);
+}
+
+#[test]
+fn unmapped_type3_glyphs_become_html_images() {
+    use pdf_extract::layers::{ContentLayer, LayerFilter};
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, HTMLOutput, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 10 Tf 72 700 Td (\\001) Tj ET");
+    let square = doc.add_object(Stream::new(dictionary! {}, b"0 0 500 700 re f".to_vec()));
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    doc.objects.insert(font_id, Object::Dictionary(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type3",
+        "FontBBox" => vec![0.into(), 0.into(), 1000.into(), 1000.into()],
+        "FontMatrix" => vec![0.001.into(), 0.into(), 0.into(), 0.001.into(), 0.into(), 0.into()],
+        "CharProcs" => dictionary! { "g1" => square },
+        "Encoding" => dictionary! { "Differences" => vec![1.into(), Object::Name(b"g1".to_vec())] },
+        "FirstChar" => 1,
+        "LastChar" => 1,
+        "Widths" => vec![600.into()],
+    }));
+
+    let html = |glyph_image_fallback| {
+        let options = ExtractionOptions { glyph_image_fallback, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut HTMLOutput::new(&mut out), &options).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    assert!(!html(false).contains("<img"));
+    let with_images = html(true);
+    assert!(with_images.contains("<img style='position: absolute; left: 72px;"), "{with_images}");
+    assert!(with_images.contains("src='data:image/svg+xml;base64,PHN2ZyB4bWxucz0n"), "{with_images}");
+
+    // The glyph is body text, so a layer filter keeps its image in the foreground only
+    let layer_html = |layer| {
+        let options = ExtractionOptions { glyph_image_fallback: true, ..Default::default() };
+        let mut out = Vec::new();
+        let mut html = HTMLOutput::new(&mut out);
+        output_doc_with_options(&doc, &mut LayerFilter::new(&mut html, layer), &options).unwrap();
+        drop(html);
+        String::from_utf8(out).unwrap()
+    };
+    assert!(layer_html(ContentLayer::Foreground).contains("<img style='position: absolute; left: 72px;"));
+    assert!(!layer_html(ContentLayer::Background).contains("<img"));
+}
+
+#[test]
+fn type3_d0_d1_supply_missing_widths() {
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 10 Tf 72 700 Td (AB) Tj ET");
+    let a = doc.add_object(Stream::new(dictionary! {}, b"500 0 d0 0 0 500 700 re f".to_vec()));
+    let b = doc.add_object(Stream::new(dictionary! {}, b"800 0 0 0 700 700 d1 0 0 700 700 re f".to_vec()));
+    let font_id = doc.objects.iter()
+        .find(|(_, o)| o.as_dict().is_ok_and(|d| d.has_type(b"Font")))
+        .map(|(id, _)| *id)
+        .unwrap();
+    doc.objects.insert(font_id, Object::Dictionary(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type3",
+        "FontBBox" => vec![0.into(), 0.into(), 1000.into(), 1000.into()],
+        "FontMatrix" => vec![0.001.into(), 0.into(), 0.into(), 0.001.into(), 0.into(), 0.into()],
+        "CharProcs" => dictionary! { "a" => a, "b" => b },
+        "Encoding" => dictionary! { "Differences" => vec![65.into(), Object::Name(b"a".to_vec()), Object::Name(b"b".to_vec())] },
+    }));
+
+    let pages = collect_text(&doc).unwrap();
+    let widths: Vec<f64> = pages[0].glyphs.iter().map(|g| (g.width * 1000.).round() / 1000.).collect();
+    assert_eq!(widths, vec![5., 8.]);
+}
+
+#[test]
+fn extract_all_fans_out_one_pass() {
+    use pdf_extract::{dictionary, extract_all, ExtractTargets, Object};
+
+    let mut doc = simple_doc("BT /F1 10 Tf 72 700 Td (Item) Tj 200 0 Td (Qty) Tj ET \
+                              BT /F1 10 Tf 72 686 Td (Apples) Tj 200 0 Td (3) Tj ET");
+    let page_id = doc.page_iter().next().unwrap();
+    let item_id = doc.new_object_id();
+    let outlines_id = doc.add_object(dictionary! { "First" => item_id, "Last" => item_id, "Count" => 1 });
+    doc.objects.insert(item_id, Object::Dictionary(dictionary! {
+        "Title" => Object::string_literal("Prices"),
+        "Parent" => outlines_id,
+        "Dest" => vec![page_id.into(), "Fit".into()],
+    }));
+    let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    doc.get_dictionary_mut(catalog_id).unwrap().set("Outlines", outlines_id);
+
+    let targets = ExtractTargets { text: true, json: true, tables: true, outline: true, ..Default::default() };
+    let all = extract_all(&doc, targets).unwrap();
+    let mut text = Vec::new();
+    pdf_extract::output_doc(&doc, &mut pdf_extract::PlainTextOutput::new(&mut text)).unwrap();
+    assert_eq!(all.text.unwrap().as_bytes(), text);
+    assert!(all.html.is_none() && all.images.is_none());
+    assert!(all.json.unwrap().contains(r#""text":"Apples 3""#));
+    let tables = all.tables.unwrap();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].cell_texts(), vec![vec!["Item", "Qty"], vec!["Apples", "3"]]);
+    let outline = all.outline.unwrap();
+    assert_eq!((outline[0].title.as_str(), outline[0].page), ("Prices", Some(1)));
+}
+
+#[test]
+fn extract_images_reports_placement_and_samples() {
+    use pdf_extract::images::extract_images;
+    use pdf_extract::{dictionary, Stream};
+
+    let mut content = b"q 100 0 0 50 72 600 cm /Im1 Do Q q 10 0 0 20 300 300 cm BI /W 2 /H 1 /CS /DeviceGray /BPC 8 ID ".to_vec();
+    content.extend_from_slice(&[0x00, 0xff]);
+    content.extend_from_slice(b" EI Q");
+    let mut doc = simple_doc("");
+    let image_id = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 2,
+        "Height" => 1,
+        "ColorSpace" => "DeviceRGB",
+        "BitsPerComponent" => 8,
+    }, vec![255, 0, 0, 0, 0, 255]));
+    let page_id = doc.page_iter().next().unwrap();
+    let page = doc.get_dictionary_mut(page_id).unwrap();
+    page.set("Resources", dictionary! { "XObject" => dictionary! { "Im1" => image_id } });
+    let content_id = page.get(b"Contents").unwrap().as_reference().unwrap();
+    doc.get_object_mut(content_id).unwrap().as_stream_mut().unwrap().set_content(content);
+
+    let images = extract_images(&doc).unwrap();
+    assert_eq!(images.len(), 2);
+    let (xobject, inline) = (&images[0], &images[1]);
+    assert!(!xobject.inline && inline.inline);
+    assert_eq!((xobject.bbox.llx, xobject.bbox.lly, xobject.bbox.urx, xobject.bbox.ury), (72., 600., 172., 650.));
+    assert_eq!((xobject.width, xobject.height, xobject.color_space.as_deref()), (2, 1, Some("DeviceRGB")));
+    assert_eq!(xobject.data.as_deref(), Some([255, 0, 0, 0, 0, 255].as_slice()));
+    assert_eq!((inline.bbox.urx, inline.bbox.ury), (310., 320.));
+    assert_eq!((inline.color_space.as_deref(), inline.bits_per_component), (Some("DeviceGray"), 8));
+    assert_eq!(inline.data.as_deref(), Some([0x00, 0xff].as_slice()));
+}
+
+#[test]
+fn extractor_selects_pages_and_layout() {
+    use pdf_extract::{Extractor, TextLayout};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &[
+        "BT /F1 12 Tf 72 700 Td (Cover) Tj ET",
+        "BT /F1 12 Tf 72 700 Td (a wrapped) Tj 0 -14 Td (line) Tj ET",
+        "BT /F1 12 Tf 72 700 Td (Back) Tj ET",
+    ]);
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf).unwrap();
+
+    let by_pages = Extractor::new().pages([2, 3, 9]).build().extract_text_from_mem_by_pages(&pdf).unwrap();
+    assert_eq!(by_pages.len(), 2);
+    assert!(by_pages[0].contains("a wrapped\nline"), "{:?}", by_pages[0]);
+    assert!(by_pages[1].contains("Back"));
+
+    let text = Extractor::new().pages(2..=2).layout(TextLayout::Paragraphs).build().extract_text_from_mem(&pdf).unwrap();
+    assert_eq!(text, "a wrapped line");
+    let all = Extractor::new().build().extract_text_from_mem(&pdf).unwrap();
+    assert_eq!(all, pdf_extract::extract_text_from_mem(&pdf).unwrap());
+}
+
+#[test]
+fn extractor_runs_selected_pages_through_any_output() {
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::report::WarningKind;
+    use pdf_extract::{ExtractionOptions, Extractor};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (Cover) Tj ET", "zz BT /F1 12 Tf 72 700 Td (Body) Tj ET"]);
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf).unwrap();
+
+    let extractor = Extractor::new().pages([2, 9]).options(ExtractionOptions { profile: true, ..Default::default() }).build();
+    let doc = extractor.load_document_from_mem(&pdf).unwrap();
+    let mut collector = TextCollector::new();
+    let diagnostics = extractor.output(&doc, &mut collector).unwrap();
+    let pages = collector.into_pages();
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].lines()[0].text, "Body");
+    assert_eq!(diagnostics.report.page(2).len(), 1);
+    // The page the document lacks is skipped with a warning
+    assert_eq!(diagnostics.report.page(9).len(), 1);
+    assert_eq!(diagnostics.report.page(9)[0].kind, WarningKind::Recovered);
+    assert_eq!(diagnostics.report.page(9)[0].message, "Page 9 not found, skipping it");
+    assert!(diagnostics.profile.is_some());
+}
+
+#[test]
+fn page_ranges_extract_part_of_a_document() {
+    use pdf_extract::{output_doc_pages, PlainTextOutput};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &[
+        "BT /F1 12 Tf 72 700 Td (One) Tj ET",
+        "BT /F1 12 Tf 72 700 Td (Two) Tj ET",
+        "BT /F1 12 Tf 72 700 Td (Three) Tj ET",
+    ]);
+    let path = std::env::temp_dir().join(format!("pdf-extract-range-{}.pdf", std::process::id()));
+    doc.save(&path).unwrap();
+    let text = pdf_extract::extract_text_range(&path, 2..=5);
+    std::fs::remove_file(&path).unwrap();
+    let text = text.unwrap();
+    assert!(!text.contains("One") && text.contains("Two") && text.contains("Three"));
+
+    let mut out = Vec::new();
+    output_doc_pages(&doc, &mut PlainTextOutput::new(&mut out), [3, 1]).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.find("Three").unwrap() < text.find("One").unwrap());
+    // Pages the document lacks are skipped, as by `extract_text_range`
+    let mut out = Vec::new();
+    output_doc_pages(&doc, &mut PlainTextOutput::new(&mut out), [4, 2]).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().trim(), "Two");
+}
+
+#[test]
+fn page_expressions_resolve_against_the_page_count() {
+    use pdf_extract::page_ranges::PageRanges;
+    use pdf_extract::Extractor;
+
+    let resolve = |expression: &str, count| expression.parse::<PageRanges>().unwrap().resolve(count);
+    assert_eq!(resolve("1-3,7,12-", 14), [1, 2, 3, 7, 12, 13, 14]);
+    assert_eq!(resolve("-2, last", 5), [1, 2, 5]);
+    assert_eq!(resolve("5-3,last-4", 5), [5, 4, 3, 5, 4]);
+    assert_eq!(resolve("2-9,12", 4), [2, 3, 4]);
+    for invalid in ["", "0", "1-x", "3,,4"] {
+        assert!(invalid.parse::<PageRanges>().is_err(), "{:?}", invalid);
+    }
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (One) Tj ET", "BT /F1 12 Tf 72 700 Td (Two) Tj ET"]);
+    let pages = Extractor::new().page_ranges("last-1".parse().unwrap()).build().text_by_pages(&doc).unwrap();
+    assert!(pages[0].contains("Two") && pages[1].contains("One"));
+}
+
+#[test]
+fn page_texts_extract_pages_lazily() {
+    use pdf_extract::{ExtractionOptions, Extractor, Strictness};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &[
+        "BT /F1 12 Tf 72 700 Td (Cover) Tj ET",
+        "BT /F1 12 Tf 72 700 Td (Needle) Tj ET",
+        "BT /F9 12 Tf 72 700 Td (Unreachable) Tj ET",
+    ]);
+    let options = ExtractionOptions { strictness: Strictness::Strict, ..Default::default() };
+    let extractor = Extractor::new().options(options).build();
+    let mut pages = extractor.page_texts(&doc).unwrap();
+    assert_eq!(pages.size_hint(), (3, Some(3)));
+    // The broken third page is never reached
+    let (page_num, _) = pages.find(|page| page.as_ref().is_ok_and(|(_, text)| text.contains("Needle"))).unwrap().unwrap();
+    assert_eq!(page_num, 2);
+    assert!(pages.next().unwrap().is_err());
+}
+
+#[test]
+fn plain_text_is_written_a_line_at_a_time() {
+    use pdf_extract::{output_doc, PlainTextOutput};
+
+    struct CountingWriter(Vec<u8>, usize);
+    impl std::io::Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.1 += 1;
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+    }
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Two words) Tj 0 -14 Td (second line) Tj ET");
+    let mut writer = CountingWriter(Vec::new(), 0);
+    output_doc(&doc, &mut PlainTextOutput::new(&mut writer)).unwrap();
+    let text = String::from_utf8(writer.0).unwrap();
+    assert_eq!(text.trim_start(), "Two words\nsecond line");
+    // One write per line rather than per glyph
+    assert_eq!(writer.1, text.split('\n').count());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mmap_loading_matches_reading() {
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Mapped) Tj ET");
+    let path = std::env::temp_dir().join(format!("pdf-extract-mmap-{}.pdf", std::process::id()));
+    doc.save(&path).unwrap();
+    let mapped = pdf_extract::extract_text_from_mmap(&path);
+    let read = pdf_extract::extract_text(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(mapped.unwrap(), read.unwrap());
+}
+
+#[test]
+fn compat_functions_return_output_error() {
+    use pdf_extract::compat::{self, OutputError};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Upstream) Tj ET");
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf).unwrap();
+    assert_eq!(compat::extract_text_from_mem(&pdf).unwrap(), pdf_extract::extract_text_from_mem(&pdf).unwrap());
+    assert_eq!(compat::extract_text_from_mem_by_pages(&pdf).unwrap().len(), 1);
+    assert!(matches!(compat::extract_text_from_mem(b"not a pdf"), Err(OutputError::PdfError(_))));
+    let missing = compat::output_doc_page(&doc, &mut compat::PlainTextOutput::new(Vec::new()), 2).unwrap_err();
+    assert!(missing.to_string().starts_with("PDF error: "), "{missing}");
+}
+
+#[test]
+fn dct_images_pass_jpeg_through() {
+    use pdf_extract::images::{extract_images, jpeg_data};
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let jpeg = vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0x00, 0xff, 0xd9];
+    let mut doc = simple_doc("q 200 0 0 100 72 500 cm /Im1 Do Q");
+    let image = Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 20,
+        "Height" => 10,
+        "ColorSpace" => "DeviceRGB",
+        "BitsPerComponent" => 8,
+        "Filter" => "DCTDecode",
+    }, jpeg.clone());
+    assert_eq!(jpeg_data(&image).as_deref(), Some(jpeg.as_slice()));
+    let mut wrapped = image.clone();
+    wrapped.dict.set("Filter", vec![Object::Name(b"ASCII85Decode".to_vec()), Object::Name(b"DCTDecode".to_vec())]);
+    wrapped.set_content(b"s4IA)~>".to_vec());
+    assert_eq!(jpeg_data(&wrapped).as_deref(), Some([0xff, 0xd8, 0xff, 0xd9].as_slice()));
+
+    let image_id = doc.add_object(image);
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap()
+        .set("Resources", dictionary! { "XObject" => dictionary! { "Im1" => image_id } });
+    let images = extract_images(&doc).unwrap();
+    assert_eq!(images[0].jpeg.as_deref(), Some(jpeg.as_slice()));
+    assert!(images[0].data.is_none());
+}
+
+#[test]
+fn jpx_images_pass_through_with_header_info() {
+    use pdf_extract::images::{extract_images, jpx_info, JpxInfo};
+    use pdf_extract::{dictionary, Stream};
+
+    // Raw codestream: SOC, then SIZ for a 30x20 three component 8-bit image
+    let mut codestream = vec![0xff, 0x4f, 0xff, 0x51, 0x00, 0x2f, 0x00, 0x00];
+    for v in [30u32, 20, 0, 0, 30, 20, 0, 0] {
+        codestream.extend(v.to_be_bytes());
+    }
+    codestream.extend([0x00, 0x03]);
+    codestream.extend([0x07, 0x01, 0x01].repeat(3));
+    let info = JpxInfo { width: 30, height: 20, components: 3, bits_per_component: 8 };
+    assert_eq!(jpx_info(&codestream), Some(info));
+
+    // JP2 file: signature box, then jp2h holding a 16-bit greyscale ihdr
+    let mut jp2 = vec![0, 0, 0, 12, b'j', b'P', b' ', b' ', 0x0d, 0x0a, 0x87, 0x0a];
+    jp2.extend([0, 0, 0, 30, b'j', b'p', b'2', b'h', 0, 0, 0, 22, b'i', b'h', b'd', b'r']);
+    jp2.extend([0, 0, 0, 10, 0, 0, 0, 40, 0, 1, 15, 7, 0, 0]);
+    jp2.extend([0, 0, 0, 0, b'j', b'p', b'2', b'c']);
+    assert_eq!(jpx_info(&jp2), Some(JpxInfo { width: 40, height: 10, components: 1, bits_per_component: 16 }));
+
+    // The PDF omits ColorSpace and BitsPerComponent, as JPXDecode allows
+    let mut doc = simple_doc("q 200 0 0 100 72 500 cm /Im1 Do Q");
+    let image_id = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 30,
+        "Height" => 20,
+        "Filter" => "JPXDecode",
+    }, codestream.clone()));
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap()
+        .set("Resources", dictionary! { "XObject" => dictionary! { "Im1" => image_id } });
+    let images = extract_images(&doc).unwrap();
+    assert_eq!(images[0].jpx.as_deref(), Some(codestream.as_slice()));
+    assert_eq!(images[0].color_space.as_deref(), Some("DeviceRGB"));
+    assert_eq!(images[0].bits_per_component, 8);
+    assert!(images[0].data.is_none() && images[0].jpeg.is_none());
+}
+
+#[cfg(feature = "jpx")]
+#[test]
+fn jpx_images_are_decoded_with_the_jpx_feature() {
+    use pdf_extract::images::{decode_jpx, extract_images};
+    use pdf_extract::{dictionary, output_doc, SVGOutput, Stream};
+
+    // Lossless codestream of a 4x2 RGB image
+    let codestream = [
+        0xff, 0x4f, 0xff, 0x51, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x01, 0xff, 0x52, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x01, 0x04, 0x04, 0x00, 0x01, 0xff, 0x5c, 0x00, 0x07, 0x40, 0x40, 0x48, 0x48, 0x50, 0xff, 0x90, 0x00, 0x0a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x53, 0x00, 0x01, 0xff, 0x93, 0xc7, 0xd4, 0x04, 0x06, 0x07, 0xcf, 0xb4, 0x08, 0x0a, 0x0f, 0xdf, 0x80,
+        0x10, 0x06, 0xef, 0xc7, 0xda, 0x05, 0x3f, 0x00, 0x39, 0xf9, 0x82, 0x00, 0x07, 0x9f, 0x09, 0xcf, 0xab, 0x0b, 0x9a, 0x5a,
+        0x1f, 0xcf, 0xc0, 0x0e, 0x7e, 0x00, 0x71, 0xf8, 0x01, 0x80, 0x03, 0x38, 0x4f, 0x0a, 0x73, 0x0c, 0x08, 0x62, 0x7f, 0xc7,
+        0xda, 0x05, 0x3f, 0x00, 0x39, 0xf9, 0x82, 0x00, 0x0b, 0x17, 0x02, 0xf2, 0xd5, 0x03, 0x38, 0x4f, 0xbf, 0xff, 0xd9,
+    ];
+    let pixels = [
+        255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255,
+        0, 0, 0, 128, 128, 128, 255, 255, 0, 0, 255, 255,
+    ];
+    assert_eq!(decode_jpx(&codestream).unwrap().as_deref(), Some(&pixels[..]));
+    assert_eq!(decode_jpx(&codestream[..60]).unwrap(), None);
+
+    let mut doc = simple_doc("q 200 0 0 100 72 500 cm /Im1 Do Q");
+    let image_id = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 4,
+        "Height" => 2,
+        "Filter" => "JPXDecode",
+    }, codestream.to_vec()));
+    page_resources_mut(&mut doc).set("XObject", dictionary! { "Im1" => image_id });
+    let images = extract_images(&doc).unwrap();
+    assert_eq!(images[0].data.as_deref(), Some(&pixels[..]));
+    assert_eq!(images[0].jpx.as_deref(), Some(&codestream[..]));
+    assert_eq!((images[0].color_space.as_deref(), images[0].bits_per_component), (Some("DeviceRGB"), 8));
+
+    // Vector output embeds the decoded samples as PNG
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    assert!(String::from_utf8(svg).unwrap().contains("xlink:href='data:image/png;base64,"));
+}
+
+#[test]
+fn images_carry_their_masks() {
+    use pdf_extract::images::{extract_images, Mask, MaskImage};
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("q 20 0 0 20 0 0 cm /Im1 Do /Im2 Do /Im3 Do Q");
+    let image = |extra: pdf_extract::Dictionary| {
+        let mut dict = dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => 2,
+            "Height" => 1,
+            "ColorSpace" => "DeviceGray",
+            "BitsPerComponent" => 8,
+        };
+        dict.extend(&extra);
+        Stream::new(dict, vec![0x10, 0x20])
+    };
+    let stencil = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 2,
+        "Height" => 1,
+        "ImageMask" => true,
+        "Decode" => vec![1.into(), 0.into()],
+    }, vec![0x80]));
+    let alpha = doc.add_object(image(dictionary! {}));
+    let im1 = doc.add_object(image(dictionary! { "Mask" => stencil }));
+    let im2 = doc.add_object(image(dictionary! { "Mask" => vec![Object::Integer(16), Object::Integer(16)] }));
+    let im3 = doc.add_object(image(dictionary! { "SMask" => alpha }));
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Resources", dictionary! {
+        "XObject" => dictionary! { "Im1" => im1, "Im2" => im2, "Im3" => im3 },
+    });
+
+    let images = extract_images(&doc).unwrap();
+    assert_eq!(images.len(), 3, "masks aren't reported as images of their own");
+    assert_eq!(images[0].mask, Some(Mask::Stencil(MaskImage {
+        width: 2,
+        height: 1,
+        bits_per_component: 1,
+        decode: Some(vec![1.0, 0.0]),
+        data: Some(vec![0x80]),
+    })));
+    assert_eq!(images[1].mask, Some(Mask::ColorKey(vec![16, 16])));
+    let soft_mask = images[2].soft_mask.as_ref().unwrap();
+    assert_eq!((soft_mask.bits_per_component, soft_mask.data.as_deref()), (8, Some([0x10, 0x20].as_slice())));
+    assert!(images.iter().all(|image| !image.image_mask));
+}
+
+#[test]
+fn profile_times_operators_and_fonts() {
+    use pdf_extract::{output_doc_with_diagnostics, ExtractionOptions, PlainTextOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (one) Tj 0 -14 Td [(two)] TJ ET");
+    let run = |profile| {
+        let options = ExtractionOptions { profile, ..Default::default() };
+        output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut Vec::new()), &options).unwrap()
+    };
+    assert!(run(false).profile.is_none());
+    let profile = run(true).profile.unwrap();
+    assert_eq!(profile.operators["Td"].count, 2);
+    assert_eq!(profile.operators["Tj"].count, 1);
+    assert_eq!(profile.fonts["Helvetica"].count, 2);
+    assert_eq!(profile.hottest_fonts().len(), 1);
+    let report = profile.to_string();
+    assert!(report.contains("operator") && report.contains("Helvetica"), "{report}");
+}
+
+#[test]
+fn adaptive_spacing_learns_letter_tracking() {
+    use pdf_extract::{output_doc, spacing, Extractor, PlainTextOutput, TextLayout};
+
+    // Every glyph is its own string, 0.15em apart within words and 0.4em between them
+    let spaced = |words: &[&str]| {
+        words.iter()
+            .map(|word| word.chars().map(|c| format!("({c})")).collect::<Vec<_>>().join(" -150 "))
+            .collect::<Vec<_>>()
+            .join(" -400 ")
+    };
+    let doc = simple_doc(&format!(
+        "BT /F1 10 Tf 72 700 Td [{}] TJ 0 -14 Td [{}] TJ ET",
+        spaced(&["Hello", "World"]),
+        spaced(&["tracked", "out"]),
+    ));
+    let mut plain = Vec::new();
+    output_doc(&doc, &mut PlainTextOutput::new(&mut plain)).unwrap();
+    let plain = String::from_utf8(plain).unwrap();
+    assert!(plain.contains("H e l l o"), "{plain:?}");
+
+    let model = spacing::learn(&doc).unwrap();
+    assert_eq!(model.len(), 1);
+    assert!((0.15..0.4).contains(&model.word_gap("Helvetica", 10.)), "{model:?}");
+    assert_eq!(spacing::extract_text(&doc).unwrap().trim(), "Hello World\ntracked out");
+    let extractor = Extractor::new().layout(TextLayout::AdaptiveSpacing).build();
+    assert_eq!(extractor.text_by_pages(&doc).unwrap()[0].trim(), "Hello World\ntracked out");
+}
+
+#[test]
+fn ocr_engine_fills_in_image_only_text() {
+    use pdf_extract::images::PlacedImage;
+    use pdf_extract::ocr::{output_doc_with_ocr, OcrEngine, OcrMode, OcrRun};
+    use pdf_extract::{dictionary, ExtractionOptions, PdfResult, PlainTextOutput, Stream};
+
+    struct FakeOcr(Vec<(u32, u32)>);
+    impl OcrEngine for FakeOcr {
+        fn recognize(&mut self, image: &PlacedImage) -> PdfResult<Vec<OcrRun>> {
+            self.0.push((image.page_num, image.width));
+            assert_eq!(image.data.as_deref(), Some([0u8; 4].as_slice()));
+            Ok(vec![OcrRun { text: "Scanned words".to_string(), left: 0., top: 0., width: 2., height: 1. }])
+        }
+    }
+
+    let mut doc = simple_doc("");
+    let image_id = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 2,
+        "Height" => 2,
+        "ColorSpace" => "DeviceGray",
+        "BitsPerComponent" => 8,
+    }, vec![0; 4]));
+    let font_id = doc.get_dictionary(doc.page_iter().next().unwrap()).unwrap()
+        .get(b"Resources").unwrap().as_dict().unwrap()
+        .get(b"Font").unwrap().as_dict().unwrap().get(b"F1").unwrap().clone();
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Resources", dictionary! {
+        "Font" => dictionary! { "F1" => font_id },
+        "XObject" => dictionary! { "Im1" => image_id },
+    });
+    replace_pages(&mut doc, &[
+        // A scanned page
+        "q 288 0 0 144 72 500 cm /Im1 Do Q",
+        // Native text beside a scanned figure, and text over a background image
+        "BT /F1 12 Tf 72 700 Td (Caption) Tj ET q 288 0 0 144 72 300 cm /Im1 Do Q \
+         q 612 0 0 792 0 0 cm /Im1 Do Q",
+    ]);
+
+    let run = |mode| {
+        let mut engine = FakeOcr(Vec::new());
+        let mut text = Vec::new();
+        output_doc_with_ocr(&doc, &mut PlainTextOutput::new(&mut text), &mut engine, mode, &ExtractionOptions::default())
+            .unwrap();
+        (String::from_utf8(text).unwrap(), engine.0)
+    };
+    let (text, calls) = run(OcrMode::ImageOnlyPages);
+    assert_eq!(calls, [(1, 2)]);
+    assert_eq!(text.matches("Scanned words").count(), 1, "{text:?}");
+    let (text, calls) = run(OcrMode::ImageOnlyRegions);
+    assert_eq!(calls, [(1, 2), (2, 2)]);
+    assert!(text.contains("Caption") && text.matches("Scanned words").count() == 2, "{text:?}");
+}
+
+#[test]
+fn ocr_output_begins_and_ends_the_document_once() {
+    use pdf_extract::images::PlacedImage;
+    use pdf_extract::ocr::{output_doc_with_ocr, OcrEngine, OcrMode, OcrRun};
+    use pdf_extract::{ExtractionOptions, HTMLOutput, PdfResult};
+
+    struct NoOcr;
+    impl OcrEngine for NoOcr {
+        fn recognize(&mut self, _: &PlacedImage) -> PdfResult<Vec<OcrRun>> {
+            Ok(Vec::new())
+        }
+    }
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (One) Tj ET", "BT /F1 12 Tf 72 700 Td (Two) Tj ET"]);
+    let mut html = Vec::new();
+    output_doc_with_ocr(&doc, &mut HTMLOutput::new(&mut html), &mut NoOcr, OcrMode::default(), &ExtractionOptions::default())
+        .unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert_eq!(html.matches("<!DOCTYPE").count(), 1, "{html}");
+    assert_eq!(html.matches("</html>").count(), 1, "{html}");
+}
+
+#[test]
+fn reflow_rewraps_paragraphs() {
+    use pdf_extract::layout::wrap;
+    use pdf_extract::{Extractor, TextLayout};
+
+    assert_eq!(wrap("the quick brown fox jumps", 10), "the quick\nbrown fox\njumps");
+    assert_eq!(wrap("a extraordinarily b", 5), "a\nextraordinarily\nb");
+    assert_eq!(wrap("  spaced   out  ", 80), "spaced out");
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (The quick brown) Tj 0 -14 Td (fox jumps over the) Tj \
+                          0 -14 Td (lazy dog.) Tj 0 -60 Td (Next one.) Tj ET");
+    let text = |layout| Extractor::new().layout(layout).build().text(&doc).unwrap();
+    assert_eq!(text(TextLayout::Paragraphs), "The quick brown fox jumps over the lazy dog.\n\nNext one.");
+    assert_eq!(text(TextLayout::Reflow { width: 20 }), "The quick brown fox\njumps over the lazy\ndog.\n\nNext one.");
+}
+
+#[test]
+fn fill_color_reaches_json_fragments_and_html() {
+    use pdf_extract::layout::{collect_text, write_json_lines};
+    use pdf_extract::{output_doc, HTMLOutput};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (Plain ) Tj /DeviceRGB cs 1 0 0 sc (red) Tj ET \
+                               BT /GS1 gs /DeviceGray cs 0.5 sc /F1 8 Tf 72 100 Td (boilerplate) Tj ET"]);
+    let mut json = Vec::new();
+    write_json_lines(&collect_text(&doc).unwrap(), &mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    let lines: Vec<serde_json::Value> = json.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    let fragments = |text: &str| {
+        let line = lines.iter().find(|l| l["text"] == text).unwrap_or_else(|| panic!("{json}"));
+        line["fragments"].as_array().unwrap().iter()
+            .map(|f| (f["text"].as_str().unwrap().to_string(), f["color"].as_str().unwrap().to_string(), f["alpha"].as_f64().unwrap()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(fragments("Plain red"), [("Plain ".to_string(), "#000000".to_string(), 1.), ("red".to_string(), "#ff0000".to_string(), 1.)]);
+    assert_eq!(fragments("boilerplate"), [("boilerplate".to_string(), "#808080".to_string(), 0.3)]);
+
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("font-size: 12px'>Plain&nbsp;</div>"), "{html}");
+    assert!(html.contains("; color: #ff0000'>red</div>"), "{html}");
+    assert!(html.contains("; color: #808080; opacity: 0.30'>boilerplate"), "{html}");
+}
+
+#[test]
+fn glyph_cap_truncates_pages() {
+    use pdf_extract::{output_doc_with_diagnostics, ExtractionOptions, PlainTextOutput};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (abcdef) Tj [(ghi) -200 (jkl)] TJ ET", "BT /F1 12 Tf 72 700 Td (xyz) Tj ET"]);
+    let options = ExtractionOptions { max_glyphs_per_page: Some(8), ..Default::default() };
+    let mut text = Vec::new();
+    let diagnostics = output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut text), &options).unwrap();
+    let text = String::from_utf8(text).unwrap();
+    assert!(text.contains("abcdefgh") && !text.contains('i') && text.contains("xyz"), "{text:?}");
+    assert_eq!(diagnostics.truncated_pages, [1]);
+}
+
+#[test]
+fn comments_come_with_author_date_and_page() {
+    use pdf_extract::annotations::{extract_annotations, extract_comments, extract_text_with_comments};
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body text) Tj ET");
+    let note = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "Text",
+        "Rect" => vec![100.into(), 700.into(), 80.into(), 680.into()],
+        "Contents" => Object::string_literal("Please check this figure"),
+        "T" => Object::string_literal("Reviewer"),
+        "M" => Object::string_literal("D:20240131120000Z"),
+    });
+    let popup = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "Popup",
+        "Rect" => vec![100.into(), 600.into(), 300.into(), 700.into()],
+        "Parent" => note,
+        "Contents" => Object::string_literal("Please check this figure"),
+    });
+    let link = doc.add_object(dictionary! { "Type" => "Annot", "Subtype" => "Link", "Rect" => vec![0.into(), 0.into(), 10.into(), 10.into()] });
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Annots", vec![note.into(), popup.into(), link.into()]);
+
+    let annotations = extract_annotations(&doc).unwrap();
+    assert_eq!(annotations.iter().map(|a| a.subtype.as_str()).collect::<Vec<_>>(), ["Text", "Popup", "Link"]);
+    let comments = extract_comments(&doc).unwrap();
+    assert_eq!(comments.len(), 1);
+    assert_eq!((comments[0].page_num, comments[0].author.as_deref()), (1, Some("Reviewer")));
+    assert_eq!((comments[0].rect.llx, comments[0].rect.ury), (80., 700.));
+    let text = extract_text_with_comments(&doc).unwrap();
+    assert!(text.contains("Body text\n"), "{text:?}");
+    assert!(text.ends_with("[Comment by Reviewer, D:20240131120000Z] Please check this figure\n"), "{text:?}");
+}
+
+#[test]
+fn text_markup_resolves_highlighted_text() {
+    use pdf_extract::annotations::extract_text_markup;
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (The quick brown fox) Tj 0 -14 Td (jumps over) Tj ET");
+    let quad = |x0: i64, x1: i64, y0: i64, y1: i64| -> Vec<Object> {
+        [x0, y1, x1, y1, x0, y0, x1, y0].into_iter().map(Object::Integer).collect()
+    };
+    let highlight = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "Highlight",
+        "Rect" => vec![95.into(), 697.into(), 161.into(), 711.into()],
+        "QuadPoints" => quad(95, 161, 697, 711),
+        "T" => Object::string_literal("Reviewer"),
+    });
+    // Spanning the end of one line and the start of the next
+    let quads = [quad(160, 190, 697, 711), quad(70, 106, 683, 697)].concat();
+    let strikeout = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "StrikeOut",
+        "Rect" => vec![70.into(), 683.into(), 190.into(), 711.into()],
+        "QuadPoints" => quads,
+    });
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Annots", vec![highlight.into(), strikeout.into()]);
+
+    let markups = extract_text_markup(&doc).unwrap();
+    assert_eq!(markups.len(), 2);
+    assert_eq!(markups[0].annotation.author.as_deref(), Some("Reviewer"));
+    assert_eq!(markups[0].text, "quick brown");
+    assert_eq!(markups[1].quads.len(), 2);
+    assert_eq!(markups[1].text, "fox jumps");
+}
+
+#[test]
+fn free_text_annotations_join_page_text_on_request() {
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    let label = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "FreeText",
+        "Rect" => vec![300.into(), 500.into(), 400.into(), 540.into()],
+        "DA" => Object::string_literal("/Helv 10 Tf 0 g"),
+        "Contents" => Object::string_literal("Label one\nLine two"),
+    });
+    let font = doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica" });
+    let appearance = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), 50.into(), 20.into()],
+        "Resources" => dictionary! { "Font" => dictionary! { "F1" => font } },
+    }, b"BT /F1 10 Tf 2 5 Td (Stamped) Tj ET".to_vec()));
+    let stamped = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "FreeText",
+        "Rect" => vec![100.into(), 100.into(), 200.into(), 140.into()],
+        "Contents" => Object::string_literal("ignored for the appearance"),
+        "AP" => dictionary! { "N" => appearance },
+    });
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Annots", vec![label.into(), stamped.into()]);
+
+    let collect = |include_free_text| {
+        let mut collector = TextCollector::new();
+        let options = ExtractionOptions { include_free_text, ..Default::default() };
+        output_doc_with_options(&doc, &mut collector, &options).unwrap();
+        collector.into_pages().remove(0)
+    };
+    assert_eq!(collect(false).lines().len(), 1);
+    let page = collect(true);
+    let lines: Vec<String> = page.lines().into_iter().map(|l| l.text).collect();
+    assert_eq!(lines, ["Body", "Label one", "Line two", "Stamped"]);
+    let s = page.glyphs.iter().find(|g| g.text == "S").unwrap();
+    assert_eq!((s.x, s.y, s.font_size), (104., 110., 20.));
+    let l = page.glyphs.iter().find(|g| g.text == "L").unwrap();
+    assert_eq!((l.x, l.y, l.font_size), (302., 528., 10.));
+}
+
+#[test]
+fn attachments_from_name_tree_and_annotations() {
+    use pdf_extract::attachments::extract_attachments;
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    let mut csv = Stream::new(dictionary! { "Type" => "EmbeddedFile", "Subtype" => "text/csv" }, b"a,b\n1,2\n".to_vec());
+    csv.compress().unwrap();
+    let csv = doc.add_object(csv);
+    let spec = doc.add_object(dictionary! {
+        "Type" => "Filespec",
+        "F" => Object::string_literal("data.csv"),
+        "Desc" => Object::string_literal("Raw data"),
+        "EF" => dictionary! { "F" => csv },
+    });
+    // Leaf under a kid, as larger trees are split
+    let leaf = doc.add_object(dictionary! { "Names" => vec![Object::string_literal("data.csv"), spec.into()] });
+    let root = doc.add_object(dictionary! { "Kids" => vec![leaf.into()] });
+    let catalog = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    doc.get_dictionary_mut(catalog).unwrap().set("Names", dictionary! { "EmbeddedFiles" => root });
+
+    let note = doc.add_object(Stream::new(dictionary! { "Type" => "EmbeddedFile" }, b"hello".to_vec()));
+    let annot = doc.add_object(dictionary! {
+        "Type" => "Annot",
+        "Subtype" => "FileAttachment",
+        "Rect" => vec![0.into(), 0.into(), 10.into(), 10.into()],
+        "FS" => dictionary! { "Type" => "Filespec", "UF" => Object::string_literal("note.txt"), "EF" => dictionary! { "F" => note } },
+    });
+    let page_id = doc.page_iter().next().unwrap();
+    doc.get_dictionary_mut(page_id).unwrap().set("Annots", vec![annot.into()]);
+
+    let attachments = extract_attachments(&doc).unwrap();
+    assert_eq!(attachments.len(), 2);
+    assert_eq!(attachments[0].filename, "data.csv");
+    assert_eq!(attachments[0].description.as_deref(), Some("Raw data"));
+    assert_eq!(attachments[0].mime_type.as_deref(), Some("text/csv"));
+    assert_eq!(attachments[0].data, b"a,b\n1,2\n");
+    assert_eq!(attachments[0].page_num, None);
+    assert_eq!((attachments[1].filename.as_str(), attachments[1].page_num), ("note.txt", Some(1)));
+    assert_eq!(attachments[1].data, b"hello");
+}
+
+#[test]
+fn outline_resolves_named_destinations() {
+    use pdf_extract::outline::extract_outline;
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    let page_id = doc.page_iter().next().unwrap();
+    let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    let dest = || Object::Array(vec![page_id.into(), "XYZ".into(), 0.into(), 700.into(), Object::Null]);
+    let names = doc.add_object(dictionary! { "Names" => vec![Object::string_literal("intro"), dest()] });
+    let catalog = doc.get_dictionary_mut(catalog_id).unwrap();
+    catalog.set("Names", dictionary! { "Dests" => names });
+    catalog.set("Dests", dictionary! { "legacy" => dictionary! { "D" => dest() } });
+
+    let (intro, legacy, action) = (doc.new_object_id(), doc.new_object_id(), doc.new_object_id());
+    let outlines_id = doc.add_object(dictionary! { "First" => intro, "Last" => intro });
+    doc.objects.insert(intro, Object::Dictionary(dictionary! {
+        "Title" => Object::string_literal("Intro"),
+        "Parent" => outlines_id,
+        "Dest" => Object::string_literal("intro"),
+        "First" => legacy,
+    }));
+    doc.objects.insert(legacy, Object::Dictionary(dictionary! {
+        "Title" => Object::string_literal("Legacy"),
+        "Parent" => intro,
+        "Dest" => "legacy",
+        "Next" => action,
+    }));
+    doc.objects.insert(action, Object::Dictionary(dictionary! {
+        "Title" => Object::string_literal("Action"),
+        "Parent" => intro,
+        "A" => dictionary! { "S" => "GoTo", "D" => Object::string_literal("intro") },
+    }));
+    doc.get_dictionary_mut(catalog_id).unwrap().set("Outlines", outlines_id);
+
+    let outline = extract_outline(&doc).unwrap();
+    assert_eq!((outline[0].title.as_str(), outline[0].page), ("Intro", Some(1)));
+    let children: Vec<_> = outline[0].children.iter().map(|c| (c.title.as_str(), c.page)).collect();
+    assert_eq!(children, [("Legacy", Some(1)), ("Action", Some(1))]);
+}
+
+#[test]
+fn named_destinations_resolve_to_pages_and_views() {
+    use pdf_extract::destinations::{extract_named_destinations, Destination, View};
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    let page_id = doc.page_iter().next().unwrap();
+    let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    let xyz = vec![page_id.into(), "XYZ".into(), 72.into(), 700.into(), Object::Null];
+    let fit_h = doc.add_object(vec![page_id.into(), "FitH".into(), 500.into()]);
+    let leaf = doc.add_object(dictionary! {
+        "Names" => vec![Object::string_literal("intro"), xyz.into(), Object::string_literal("table"), fit_h.into()],
+    });
+    let root = doc.add_object(dictionary! { "Kids" => vec![leaf.into()] });
+    let catalog = doc.get_dictionary_mut(catalog_id).unwrap();
+    catalog.set("Names", dictionary! { "Dests" => root });
+    catalog.set("Dests", dictionary! { "legacy" => dictionary! { "D" => vec![page_id.into(), "Fit".into()] } });
+
+    let dests = extract_named_destinations(&doc).unwrap();
+    assert_eq!(dests.keys().collect::<Vec<_>>(), ["intro", "legacy", "table"]);
+    assert_eq!(dests["intro"], Destination { page: Some(1), view: View::Xyz { left: Some(72.), top: Some(700.), zoom: None } });
+    assert_eq!(dests["table"].view, View::FitH { top: Some(500.) });
+    assert_eq!(dests["legacy"].view, View::Fit);
+}
+
+#[test]
+fn document_info_is_typed() {
+    use pdf_extract::info::{parse_date, DocumentInfo};
+    use pdf_extract::{dictionary, Object};
+    use std::time::{Duration, SystemTime};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    let info = doc.add_object(dictionary! {
+        "Title" => Object::string_literal("Quarterly report"),
+        "Author" => Object::String(b"\xfe\xff\x00A\x00n\x00n\x00a".to_vec(), lopdf::StringFormat::Hexadecimal),
+        "CreationDate" => Object::string_literal("D:20240131120000+02'00'"),
+        "ModDate" => Object::string_literal("D:2024"),
+        "Trapped" => "False",
+        "Department" => Object::string_literal("Finance"),
+    });
+    doc.trailer.set("Info", info);
+
+    let info = DocumentInfo::from(&doc);
+    assert_eq!((info.title.as_deref(), info.author.as_deref()), (Some("Quarterly report"), Some("Anna")));
+    assert_eq!(info.page_count, 1);
+    assert_eq!(info.trapped.as_deref(), Some("False"));
+    assert_eq!(info.custom["Department"], "Finance");
+    // 2024-01-31 10:00 UTC
+    assert_eq!(info.creation_date, Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_706_695_200)));
+    assert_eq!(info.mod_date, Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200)));
+    assert_eq!(parse_date("D:19691231235959Z"), Some(SystemTime::UNIX_EPOCH - Duration::from_secs(1)));
+    assert_eq!(parse_date("D:2024133"), None);
+}
+
+#[test]
+fn page_labels_follow_the_number_tree() {
+    use pdf_extract::page_labels::extract_page_labels;
+    use pdf_extract::{dictionary, Document, Extractor, Object, Stream};
+
+    let mut doc = Document::with_version("1.5");
+    let font = doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica" });
+    let pages_id = doc.new_object_id();
+    let kids: Vec<Object> = (1..=6)
+        .map(|n| {
+            let content = format!("BT /F1 12 Tf 72 700 Td (Page {}) Tj ET", n);
+            let content = doc.add_object(Stream::new(dictionary! {}, content.into_bytes()));
+            doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content,
+                "Resources" => dictionary! { "Font" => dictionary! { "F1" => font } },
+            }).into()
+        })
+        .collect();
+    doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+        "Type" => "Pages",
+        "Count" => 6,
+        "Kids" => kids,
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }));
+    // Roman front matter, body from 1, an appendix range split into a kid
+    let appendix = doc.add_object(dictionary! { "Nums" => vec![5.into(), dictionary! { "S" => "D", "P" => Object::string_literal("A-"), "St" => 3 }.into()] });
+    let labels = dictionary! {
+        "Nums" => vec![0.into(), dictionary! { "S" => "r" }.into(), 3.into(), dictionary! { "S" => "D" }.into()],
+        "Kids" => vec![appendix.into()],
+    };
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id, "PageLabels" => labels });
+    doc.trailer.set("Root", catalog_id);
+
+    let labels: Vec<String> = extract_page_labels(&doc).unwrap().into_values().collect();
+    assert_eq!(labels, ["i", "ii", "iii", "1", "2", "A-3"]);
+    let pages = Extractor::new().pages([2, 6]).build().text_by_labeled_pages(&doc).unwrap();
+    assert_eq!((pages[0].0.as_str(), pages[0].1.trim()), ("ii", "Page 2"));
+    assert_eq!((pages[1].0.as_str(), pages[1].1.trim()), ("A-3", "Page 6"));
+
+    // Hostile starts give decimal numbers, or the page number where they overflow
+    let hostile = dictionary! {
+        "Nums" => vec![
+            0.into(), dictionary! { "S" => "a", "St" => 100_000_000_000i64 }.into(),
+            2.into(), dictionary! { "S" => "R", "St" => 9999 }.into(),
+            4.into(), dictionary! { "S" => "D", "St" => i64::MAX }.into(),
+        ],
+    };
+    doc.get_dictionary_mut(catalog_id).unwrap().set("PageLabels", hostile);
+    let labels: Vec<String> = extract_page_labels(&doc).unwrap().into_values().collect();
+    let max = i64::MAX.to_string();
+    assert_eq!(labels, ["100000000000", "100000000001", "MMMMMMMMMCMXCIX", "10000", max.as_str(), "6"]);
+}
+
+#[test]
+fn conformance_claims_come_from_xmp_and_output_intents() {
+    use pdf_extract::conformance::{DocumentProfile, PdfA};
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    assert_eq!(DocumentProfile::from(&doc), DocumentProfile::default());
+
+    let xmp = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+<rdf:Description rdf:about="" xmlns:pdfaid="http://www.aiim.org/pdfa/ns/id/" pdfaid:part="2" pdfaid:conformance="U"/>
+<rdf:Description rdf:about="" xmlns:pdfuaid="http://www.aiim.org/pdfua/ns/id/"><pdfuaid:part>1</pdfuaid:part></rdf:Description>
+</rdf:RDF></x:xmpmeta>"#;
+    let metadata = doc.add_object(Stream::new(dictionary! { "Type" => "Metadata", "Subtype" => "XML" }, xmp.as_bytes().to_vec()));
+    let icc = doc.add_object(Stream::new(dictionary! { "N" => 3 }, vec![0; 128]));
+    let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    let catalog = doc.get_dictionary_mut(catalog_id).unwrap();
+    catalog.set("Metadata", metadata);
+    catalog.set("OutputIntents", vec![dictionary! {
+        "Type" => "OutputIntent",
+        "S" => "GTS_PDFA1",
+        "OutputConditionIdentifier" => Object::string_literal("sRGB IEC61966-2.1"),
+        "DestOutputProfile" => icc,
+    }.into()]);
+
+    let profile = DocumentProfile::from(&doc);
+    assert_eq!(profile.pdfa, Some(PdfA { part: 2, conformance: Some("U".to_string()) }));
+    assert_eq!(profile.pdfua, Some(1));
+    assert_eq!(profile.output_intents.len(), 1);
+    assert_eq!(profile.output_intents[0].subtype, "GTS_PDFA1");
+    assert_eq!(profile.output_intents[0].condition_identifier.as_deref(), Some("sRGB IEC61966-2.1"));
+    assert!(profile.output_intents[0].has_profile);
+}
+
+#[test]
+fn lang_comes_from_marked_content_structure_and_catalog() {
+    use pdf_extract::language::document_language;
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("/P <</MCID 0>> BDC BT /F1 12 Tf 72 700 Td (Hallo) Tj ET EMC \
+                              BT /F1 12 Tf 72 680 Td (Hello) Tj ET \
+                              /Span <</Lang (fr)>> BDC BT /F1 12 Tf 72 660 Td (Bonjour) Tj ET EMC");
+    let page_id = doc.page_iter().next().unwrap();
+    let root_id = doc.new_object_id();
+    let element = doc.add_object(dictionary! {
+        "Type" => "StructElem",
+        "S" => "P",
+        "P" => root_id,
+        "Pg" => page_id,
+        "Lang" => Object::string_literal("de-DE"),
+        "K" => 0,
+    });
+    doc.objects.insert(root_id, Object::Dictionary(dictionary! { "Type" => "StructTreeRoot", "K" => vec![element.into()] }));
+    let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+    let catalog = doc.get_dictionary_mut(catalog_id).unwrap();
+    catalog.set("StructTreeRoot", root_id);
+    catalog.set("Lang", Object::string_literal("en-US"));
+
+    assert_eq!(document_language(&doc).as_deref(), Some("en-US"));
+    let pages = collect_text(&doc).unwrap();
+    let lines: Vec<(String, Option<String>)> = pages[0].lines().iter()
+        .map(|line| (line.text.clone(), line.lang().map(str::to_string)))
+        .collect();
+    assert_eq!(lines, [
+        ("Hallo".to_string(), Some("de-DE".to_string())),
+        ("Hello".to_string(), Some("en-US".to_string())),
+        ("Bonjour".to_string(), Some("fr".to_string())),
+    ]);
+}
+
+#[test]
+fn encryption_info_reads_the_encrypt_dictionary() {
+    use pdf_extract::encryption::{encryption_info, Permissions};
+    use pdf_extract::{dictionary, Object};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Body) Tj ET");
+    assert_eq!(encryption_info(&doc), None);
+    // Printing allowed, copying not: bits 3 and 12 set, 5 clear; the unused high bits are ones
+    let p = -4096 | 0b1000_0000_0100;
+    let encrypt = doc.add_object(dictionary! {
+        "Filter" => "Standard",
+        "V" => 4,
+        "R" => 4,
+        "Length" => 128,
+        "CF" => dictionary! { "StdCF" => dictionary! { "CFM" => "AESV2", "Length" => 16 } },
+        "StmF" => "StdCF",
+        "StrF" => "StdCF",
+        "P" => p,
+        "EncryptMetadata" => false,
+        "O" => Object::string_literal(vec![0; 32]),
+        "U" => Object::string_literal(vec![0; 32]),
+    });
+    doc.trailer.set("Encrypt", encrypt);
+
+    let info = encryption_info(&doc).unwrap();
+    assert_eq!((info.handler.as_str(), info.version, info.revision), ("Standard", 4, Some(4)));
+    assert_eq!((info.method.as_str(), info.key_length), ("AESV2", 128));
+    assert!(!info.encrypt_metadata);
+    assert_eq!(info.permissions, Permissions::from_flags(p, 4));
+    assert!(info.permissions.print && info.permissions.print_high_quality);
+    assert!(!info.permissions.copy && !info.permissions.modify);
+}
+
+#[test]
+fn strictness_decides_between_errors_and_defaults() {
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, Strictness};
+
+    // An unknown font and a cm with too few operands
+    let doc = simple_doc("1 0 0 cm BT /F9 12 Tf 72 700 Td (Recovered) Tj ET");
+    let run = |strictness| {
+        let mut collector = TextCollector::new();
+        let options = ExtractionOptions { strictness, ..Default::default() };
+        output_doc_with_options(&doc, &mut collector, &options).map(|_| collector.into_pages())
+    };
+    assert!(run(Strictness::Strict).is_err());
+    let pages = run(Strictness::Lenient).unwrap();
+    let lines = pages[0].lines();
+    assert_eq!(lines[0].text, "Recovered");
+    assert_eq!(pages[0].glyphs[0].font.as_deref(), Some("Helvetica"));
+}
+
+#[test]
+fn operators_missing_operands_are_errors_not_panics() {
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, SVGOutput, Strictness};
+
+    let operators = [
+        "cm", "CS", "cs", "TJ", "Tj", "Tc", "Tw", "Tz", "TL", "Tf", "Ts", "Tr", "Tm", "Td", "TD", "gs", "m", "l", "c", "v",
+        "y", "re", "sh", "Do", "w", "J", "j", "M", "d",
+    ];
+    for operator in operators {
+        // `v` also lacks a current point with all its operands
+        let content = if operator == "v" { "1 2 3 4 v".to_string() } else { format!("BT {} ET", operator) };
+        let doc = simple_doc(&content);
+        let run = |strictness| {
+            let options = ExtractionOptions { strictness, ..Default::default() };
+            output_doc_with_options(&doc, &mut SVGOutput::new(Vec::new()), &options)
+        };
+        assert!(run(Strictness::Strict).is_err(), "{}", operator);
+        assert!(run(Strictness::Lenient).is_ok(), "{}", operator);
+    }
+}
+
+#[test]
+fn cancellation_aborts_extraction() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, PdfError, PlainTextOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Text) Tj ET");
+    let cancel = Arc::new(AtomicBool::new(false));
+    let options = ExtractionOptions { cancel: Some(cancel.clone()), ..Default::default() };
+    let mut out = Vec::new();
+    output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).unwrap();
+    cancel.store(true, Ordering::Relaxed);
+    let result = output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options);
+    assert!(matches!(result, Err(PdfError::Cancelled)));
+}
+
+#[test]
+fn resource_limits_stop_decompression_bombs() {
+    use pdf_extract::limits::ResourceLimits;
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, PdfError, PlainTextOutput};
+
+    let padding = " ".repeat(100_000);
+    let mut doc = simple_doc(&format!("BT /F1 12 Tf 72 700 Td (Inflated) Tj ET{}", padding));
+    for object in doc.objects.values_mut() {
+        if let pdf_extract::Object::Stream(stream) = object {
+            stream.compress().unwrap();
+        }
+    }
+    let run = |limits| {
+        let options = ExtractionOptions { limits, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).map(|_| String::from_utf8(out).unwrap())
+    };
+    assert!(run(ResourceLimits::default()).unwrap().contains("Inflated"));
+    let per_stream = ResourceLimits { max_stream_size: 50_000, ..Default::default() };
+    assert!(matches!(run(per_stream), Err(PdfError::ResourceLimit(_))));
+    let total = ResourceLimits { max_total_size: 50_000, ..Default::default() };
+    assert!(matches!(run(total), Err(PdfError::ResourceLimit(_))));
+
+    // LZW data stops decoding at the cap too
+    for object in doc.objects.values_mut() {
+        if let pdf_extract::Object::Stream(stream) = object
+            && stream.dict.get(b"Filter").is_ok()
+        {
+            let content = stream.decompressed_content().unwrap();
+            let lzw = weezl::encode::Encoder::with_tiff_size_switch(weezl::BitOrder::Msb, 8).encode(&content).unwrap();
+            stream.dict.set("Filter", "LZWDecode");
+            stream.set_content(lzw);
+        }
+    }
+    let run = |limits| {
+        let options = ExtractionOptions { limits, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).map(|_| String::from_utf8(out).unwrap())
+    };
+    assert!(run(ResourceLimits::default()).unwrap().contains("Inflated"));
+    let per_stream = ResourceLimits { max_stream_size: 50_000, ..Default::default() };
+    assert!(matches!(run(per_stream), Err(PdfError::ResourceLimit(_))));
+}
+
+#[test]
+fn resource_limits_cover_icc_profiles_and_text() {
+    use pdf_extract::limits::ResourceLimits;
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, Object, PdfError, PlainTextOutput, Stream};
+
+    let mut doc = simple_doc("/ICC cs 0 0 0 sc /ICC CS /ICC cs /ICC CS BT /F1 12 Tf 72 700 Td (Some text) Tj ET");
+    let mut profile = Stream::new(dictionary! { "N" => 3 }, vec![0; 100_000]);
+    profile.compress().unwrap();
+    let profile = doc.add_object(profile);
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! { "ICC" => vec![Object::Name(b"ICCBased".to_vec()), profile.into()] });
+    let run = |limits| {
+        let options = ExtractionOptions { limits, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).map(|_| String::from_utf8(out).unwrap())
+    };
+    assert!(run(ResourceLimits::default()).unwrap().contains("Some text"));
+    // The profile is decoded past the cap, which ends extraction rather than defaulting it
+    let per_stream = ResourceLimits { max_stream_size: 50_000, ..Default::default() };
+    assert!(matches!(run(per_stream), Err(PdfError::ResourceLimit(_))));
+    // The profile is decoded once however often it is selected
+    let total = ResourceLimits { max_total_size: 250_000, ..Default::default() };
+    assert!(run(total).is_ok());
+    let text = ResourceLimits { max_total_text: 5, ..Default::default() };
+    assert!(matches!(run(text), Err(PdfError::ResourceLimit(_))));
+    let text = ResourceLimits { max_total_text: 9, ..Default::default() };
+    assert!(run(text).unwrap().contains("Some text"));
+}
+
+#[test]
+fn recursion_limits_stop_self_drawing_forms() {
+    use pdf_extract::limits::ResourceLimits;
+    use pdf_extract::report::WarningKind;
+    use pdf_extract::{dictionary, output_doc_with_diagnostics, ExtractionOptions, Object, PdfError, PlainTextOutput, Stream, Strictness};
+
+    // A form drawing itself twice, reached from the page
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Outside) Tj ET /X Do");
+    let form_id = doc.new_object_id();
+    let form = Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        "Resources" => dictionary! { "XObject" => dictionary! { "X" => form_id } },
+    }, b"/X Do /X Do".to_vec());
+    doc.objects.insert(form_id, Object::Stream(form));
+    let page_id = *doc.get_pages().get(&1).unwrap();
+    let page = doc.get_dictionary_mut(page_id).unwrap();
+    page.set("Resources", dictionary! { "XObject" => dictionary! { "X" => form_id } });
+
+    let run = |limits| {
+        let options = ExtractionOptions { limits, strictness: Strictness::Lenient, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut out), &options)
+    };
+    let shallow = ResourceLimits { max_form_depth: 4, ..Default::default() };
+    let diagnostics = run(shallow).unwrap();
+    assert_eq!(diagnostics.report.count(WarningKind::Recovered), 16);
+    let busy = ResourceLimits { max_operators_per_page: 1000, ..Default::default() };
+    assert!(matches!(run(busy), Err(PdfError::ResourceLimit(_))));
+}
+
+#[test]
+fn forms_drawn_on_several_pages_are_decoded_once() {
+    use pdf_extract::limits::ResourceLimits;
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, PlainTextOutput, Stream};
+
+    let mut doc = simple_doc("");
+    let mut letterhead = Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }, format!("BT /F1 12 Tf 72 750 Td (Letterhead) Tj ET{}", " ".repeat(1000)).into_bytes());
+    letterhead.compress().unwrap();
+    let form_id = doc.add_object(letterhead);
+    let resources = page_resources_mut(&mut doc);
+    resources.set("XObject", dictionary! { "X" => form_id });
+    replace_pages(&mut doc, &["/X Do BT /F1 12 Tf 72 700 Td (One) Tj ET", "/X Do BT /F1 12 Tf 72 700 Td (Two) Tj ET"]);
+
+    // The form decodes to more than half the budget, so decoding it per page would exceed it
+    let options = ExtractionOptions { limits: ResourceLimits { max_total_size: 1500, ..Default::default() }, ..Default::default() };
+    let mut out = Vec::new();
+    output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().matches("Letterhead").count(), 2);
+}
+
+#[test]
+fn decoded_content_cache_drops_the_least_recently_drawn_stream() {
+    use pdf_extract::limits::ResourceLimits;
+    use pdf_extract::{dictionary, output_doc_with_options, Dictionary, ExtractionOptions, PlainTextOutput, Stream};
+
+    let mut doc = simple_doc("");
+    let form = |content: Vec<u8>| Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    }, content);
+    let mut letterhead = form(format!("BT /F1 12 Tf 72 750 Td (Letterhead) Tj ET{}", " ".repeat(1000)).into_bytes());
+    letterhead.compress().unwrap();
+    let mut xobjects = Dictionary::new();
+    xobjects.set("X", doc.add_object(letterhead));
+    let mut first = String::from("/X Do");
+    for i in 0..70 {
+        xobjects.set(format!("F{i}"), doc.add_object(form(b"q Q".to_vec())));
+        first.push_str(&format!(" /F{i} Do"));
+    }
+    let resources = page_resources_mut(&mut doc);
+    resources.set("XObject", xobjects);
+    replace_pages(&mut doc, &[&first, "/X Do"]);
+
+    // Seventy forms drawn after the letterhead push it out of the cache, so the second page
+    // decodes it again
+    let extract = |max_total_size| {
+        let options = ExtractionOptions { limits: ResourceLimits { max_total_size, ..Default::default() }, ..Default::default() };
+        let mut out = Vec::new();
+        output_doc_with_options(&doc, &mut PlainTextOutput::new(&mut out), &options).map(|_| String::from_utf8(out).unwrap())
+    };
+    assert!(extract(1500).is_err());
+    assert_eq!(extract(2500).unwrap().matches("Letterhead").count(), 2);
+}
+
+#[test]
+fn fonts_shared_by_pages_load_once() {
+    use pdf_extract::report::WarningKind;
+    use pdf_extract::{output_doc_with_diagnostics, ExtractionOptions, Object, PlainTextOutput};
+
+    // A font without widths warns when it loads
+    let mut doc = simple_doc("");
+    for object in doc.objects.values_mut() {
+        if let Object::Dictionary(dict) = object
+            && dict.get(b"BaseFont").is_ok()
+        {
+            dict.set("BaseFont", Object::Name(b"Unknown".to_vec()));
+        }
+    }
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (One) Tj ET", "BT /F1 12 Tf 72 700 Td (Two) Tj ET"]);
+    let mut out = Vec::new();
+    let diagnostics = output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut out), &ExtractionOptions::default()).unwrap();
+    assert_eq!(diagnostics.report.count(WarningKind::MissingWidths), 1);
+    assert!(diagnostics.report.page(2).iter().all(|w| w.kind != WarningKind::MissingWidths));
+}
+
+#[test]
+fn extraction_report_collects_warnings_per_page() {
+    use pdf_extract::report::WarningKind;
+    use pdf_extract::{output_doc_with_diagnostics, ExtractionOptions, PlainTextOutput, Strictness};
+
+    let doc = simple_doc("zz zz BT /F9 12 Tf 72 700 Td (Text) Tj ET");
+    let mut out = Vec::new();
+    // Strict by default, so the missing font is an error
+    assert!(output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut out), &ExtractionOptions::default()).is_err());
+    let options = ExtractionOptions { strictness: Strictness::Lenient, ..Default::default() };
+    let diagnostics = output_doc_with_diagnostics(&doc, &mut PlainTextOutput::new(&mut out), &options).unwrap();
+    let report = diagnostics.report;
+    assert_eq!(report.count(WarningKind::UnhandledOperator), 2);
+    assert_eq!(report.count(WarningKind::FontFallback), 1);
+    let kinds: Vec<_> = report.page(1).iter().map(|w| (w.kind, w.count)).collect();
+    assert_eq!(kinds, [(WarningKind::UnhandledOperator, 2), (WarningKind::FontFallback, 1)]);
+    assert!(report.page(2).is_empty());
+}
+
+#[test]
+fn recovery_rebuilds_a_broken_xref() {
+    use pdf_extract::recovery::load_mem_with_recovery;
+    use pdf_extract::Extractor;
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Recovered text) Tj ET");
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    // A comment after the header shifts every offset the table records
+    let header_end = bytes.iter().position(|&b| b == b'\n').unwrap() + 1;
+    bytes.splice(header_end..header_end, b"% inserted by a broken transfer ............\n".iter().copied());
+    // and the table itself is cut off
+    let xref = bytes.windows(4).rposition(|w| w == b"xref").unwrap();
+    bytes.truncate(xref + 10);
+
+    assert!(lopdf::Document::load_mem(&bytes).map_or(true, |doc| doc.get_pages().is_empty()));
+    let recovered = load_mem_with_recovery(&bytes).unwrap();
+    assert_eq!(recovered.get_pages().len(), 1);
+    let text = Extractor::new().recover(true).build().extract_text_from_mem(&bytes).unwrap();
+    assert_eq!(text.trim(), "Recovered text");
+    assert!(Extractor::new().build().extract_text_from_mem(&bytes).map_or(true, |text| text.trim().is_empty()));
+
+    // Huge object numbers get a table entry of their own instead of one for every number below them
+    let header_end = bytes.iter().position(|&b| b == b'\n').unwrap() + 1;
+    bytes.splice(header_end..header_end, b"400000000 0 obj null endobj\n4294967295 0 obj null endobj\n".iter().copied());
+    let repaired = pdf_extract::recovery::repair(&bytes);
+    assert!(repaired.len() < bytes.len() + 1024, "{}", repaired.len());
+    let table = String::from_utf8_lossy(&repaired[bytes.len()..]);
+    assert!(table.contains("\n400000000 1\n") && table.contains("/Size 400000001"), "{table}");
+    assert_eq!(load_mem_with_recovery(&bytes).unwrap().get_pages().len(), 1);
+}
+
+#[test]
+fn markdown_marks_large_paragraphs_as_headings() {
+    use pdf_extract::layout::{collect_text, write_markdown};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 24 Tf 72 700 Td (Title) Tj ET BT /F1 12 Tf 72 650 Td (Body text.) Tj ET \
+                               BT /F1 12 Tf 72 630 Td (More text.) Tj ET",
+                              "BT /F1 20 Tf 72 740 Td (Part two) Tj ET BT /F1 12 Tf 72 700 Td (Next page.) Tj ET"]);
+    let mut md = Vec::new();
+    write_markdown(&collect_text(&doc).unwrap(), &mut md).unwrap();
+    let md = String::from_utf8(md).unwrap();
+    assert_eq!(md.trim_end(), "# Title\n\nBody text. More text.\n\n---\n\n## Part two\n\nNext page.");
+}
+
+#[test]
+fn tee_output_feeds_several_devices_in_one_pass() {
+    use pdf_extract::layout::{write_json_lines, TextCollector};
+    use pdf_extract::{output_doc, HTMLOutput, PlainTextOutput, TeeOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (Shared) Tj ET");
+    let (mut text, mut html) = (Vec::new(), Vec::new());
+    let mut collector = TextCollector::new();
+    {
+        let mut tee = TeeOutput(vec![Box::new(PlainTextOutput::new(&mut text)), Box::new(HTMLOutput::new(&mut html))]);
+        tee.push(&mut collector);
+        output_doc(&doc, &mut tee).unwrap();
+    }
+    let mut json = Vec::new();
+    write_json_lines(collector.pages(), &mut json).unwrap();
+    assert_eq!(String::from_utf8(text).unwrap().trim(), "Shared");
+    assert!(String::from_utf8(html).unwrap().contains(">Shared</div>"));
+    assert!(String::from_utf8(json).unwrap().contains(r#""text":"Shared""#));
+}
+
+#[test]
+fn characters_carry_font_code_fill_and_render_mode() {
+    use pdf_extract::{output_doc, CharInfo, MediaBox, OutputDev, PdfResult, PdfTransform};
+
+    #[derive(Default)]
+    struct Recorder(Vec<(String, u32, Vec<f64>, i64, String)>);
+    impl OutputDev for Recorder {
+        fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+        fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+        fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> {
+            panic!("output_char_info is overridden")
+        }
+        fn output_char_info(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, char: &str, info: &CharInfo) -> PdfResult<()> {
+            let font = info.font.base_name().unwrap_or_default().to_string();
+            self.0.push((font, info.code, info.fill_color.to_vec(), info.render_mode, char.to_string()));
+            Ok(())
+        }
+        fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+    }
+
+    // A real mode operand is truncated
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td /DeviceRGB cs 1 0 0 sc (A) Tj 3 Tr (B) Tj 1.0 Tr (C) Tj ET");
+    let mut recorder = Recorder::default();
+    output_doc(&doc, &mut recorder).unwrap();
+    assert_eq!(recorder.0, vec![
+        ("Helvetica".to_string(), 65, vec![1., 0., 0.], 0, "A".to_string()),
+        ("Helvetica".to_string(), 66, vec![1., 0., 0.], 3, "B".to_string()),
+        ("Helvetica".to_string(), 67, vec![1., 0., 0.], 1, "C".to_string()),
+    ]);
+}
+
+#[test]
+fn glyph_ids_follow_cid_to_gid_maps() {
+    use pdf_extract::{dictionary, make_font, output_doc, CharCode, MediaBox, Object, OutputDev, PdfResult, PdfTransform, Stream};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (Hi) Tj ET");
+    let map = doc.add_object(Stream::new(dictionary! {}, vec![0, 0, 0, 7, 0, 9]));
+    let type0 = |doc: &mut pdf_extract::Document, subtype: &str, map: Option<Object>| {
+        let mut descendant = dictionary! { "Type" => "Font", "Subtype" => subtype, "BaseFont" => "Test" };
+        if let Some(map) = map {
+            descendant.set("CIDToGIDMap", map);
+        }
+        let descendant = doc.add_object(descendant);
+        make_font(doc, &dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "Test",
+            "Encoding" => "Identity-H",
+            "DescendantFonts" => vec![Object::Reference(descendant)],
+        }).unwrap()
+    };
+    let mapped = type0(&mut doc, "CIDFontType2", Some(Object::Reference(map)));
+    assert_eq!((mapped.glyph_id(1), mapped.glyph_id(2), mapped.glyph_id(3)), (Some(7), Some(9), None));
+    assert_eq!(type0(&mut doc, "CIDFontType2", Some("Identity".into())).glyph_id(300), Some(300));
+    assert_eq!(type0(&mut doc, "CIDFontType0", None).glyph_id(1), None);
+
+    #[derive(Default)]
+    struct Glyphs(Vec<(Option<u16>, CharCode, String)>);
+    impl OutputDev for Glyphs {
+        fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+        fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+        fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, _: &str) -> PdfResult<()> { Ok(()) }
+        fn output_glyph(&mut self, gid: Option<u16>, cid: CharCode, unicode: &str, _: &PdfTransform, _: f64) -> PdfResult<()> {
+            self.0.push((gid, cid, unicode.to_string()));
+            Ok(())
+        }
+        fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+    }
+    let mut glyphs = Glyphs::default();
+    output_doc(&doc, &mut glyphs).unwrap();
+    // Helvetica isn't embedded, so there is no program to name glyphs
+    assert_eq!(glyphs.0, vec![(None, 72, "H".to_string()), (None, 105, "i".to_string())]);
+}
+
+#[test]
+fn devices_see_text_objects_and_marked_content() {
+    use pdf_extract::{dictionary, output_doc, Dictionary, MediaBox, OutputDev, PdfResult, PdfTransform};
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+    impl OutputDev for Events {
+        fn begin_page(&mut self, _: u32, _: &MediaBox, _: Option<(f64, f64, f64, f64)>) -> PdfResult<()> { Ok(()) }
+        fn end_page(&mut self) -> PdfResult<()> { Ok(()) }
+        fn output_character(&mut self, _: &PdfTransform, _: f64, _: f64, _: f64, char: &str) -> PdfResult<()> {
+            self.0.push(char.to_string());
+            Ok(())
+        }
+        fn begin_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_word(&mut self) -> PdfResult<()> { Ok(()) }
+        fn end_line(&mut self) -> PdfResult<()> { Ok(()) }
+        fn begin_text_object(&mut self) -> PdfResult<()> {
+            self.0.push("BT".to_string());
+            Ok(())
+        }
+        fn end_text_object(&mut self) -> PdfResult<()> {
+            self.0.push("ET".to_string());
+            Ok(())
+        }
+        fn begin_marked_content(&mut self, tag: &str, properties: Option<&Dictionary>) -> PdfResult<()> {
+            let mcid = properties.and_then(|p| p.get(b"MCID").ok()).and_then(|m| m.as_i64().ok());
+            self.0.push(format!("<{} {:?}>", tag, mcid));
+            Ok(())
+        }
+        fn end_marked_content(&mut self) -> PdfResult<()> {
+            self.0.push("</>".to_string());
+            Ok(())
+        }
+    }
+
+    // Property lists inline and named in the page's `/Properties`
+    let mut doc = simple_doc("/Artifact BMC BT /F1 12 Tf 72 700 Td (a) Tj ET EMC \
+                              /P << /MCID 3 >> BDC BT /F1 12 Tf 72 680 Td (b) Tj ET EMC EMC \
+                              /Span /MC0 BDC EMC /Span /Missing BDC EMC");
+    let resources = page_resources_mut(&mut doc);
+    resources.set("Properties", dictionary! { "MC0" => dictionary! { "MCID" => 7 } });
+    let mut events = Events::default();
+    output_doc(&doc, &mut events).unwrap();
+    assert_eq!(events.0, [
+        "<Artifact None>", "BT", "a", "ET", "</>", "<P Some(3)>", "BT", "b", "ET", "</>", "<Span Some(7)>", "</>", "<Span None>", "</>",
+    ]);
+}
+
+#[test]
+fn text_color_follows_the_rendering_mode() {
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{output_doc, HTMLOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td /DeviceRGB cs 0 0 1 sc /DeviceRGB CS 1 0 0 SC (f) Tj 1 Tr (s) Tj 3 Tr (i) Tj 0 Tr /DeviceGray cs 1 sc (w) Tj ET");
+    let glyphs = &collect_text(&doc).unwrap()[0].glyphs;
+    let seen: Vec<_> = glyphs.iter().map(|g| (g.text.as_str(), g.color, g.render_mode, g.is_visible())).collect();
+    assert_eq!(seen, [
+        ("f", Some((0., 0., 1.)), 0, true),
+        ("s", Some((1., 0., 0.)), 1, true),
+        ("i", Some((0., 0., 1.)), 3, false),
+        ("w", Some((1., 1., 1.)), 0, true),
+    ]);
+
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("; color: #ff0000'>s</div>"), "{html}");
+    assert!(html.contains("; color: #0000ff; opacity: 0.00'>i</div>"), "{html}");
+}
+
+#[test]
+fn layout_params_tune_line_and_word_breaks() {
+    use pdf_extract::{output_doc, LayoutParams, PlainTextOutput};
+
+    // Words 0.25 em apart, the last one 0.2 em further right and 1.25 em below
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td (ab) Tj 13.6 0 Td (cd) Tj 12.56 -12.5 Td (ef) Tj ET");
+    let text = |params: LayoutParams| {
+        let mut out = Vec::new();
+        output_doc(&doc, &mut PlainTextOutput::new(&mut out).layout_params(params)).unwrap();
+        String::from_utf8(out).unwrap().trim().to_string()
+    };
+    assert_eq!(text(LayoutParams::default()), "ab cd ef");
+    assert_eq!(text(LayoutParams { line_break: 1.2, space_fraction: 1.2, ..Default::default() }), "abcd\nef");
+    // A word gap wider than Helvetica's half space takes over from it
+    assert_eq!(text(LayoutParams { word_gap: 0.3, ..Default::default() }), "abcdef");
+}
+
+#[test]
+fn layout_params_reach_collected_lines_and_the_extractor() {
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{Extractor, LayoutParams, TextLayout};
+
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td (ab) Tj 13.6 0 Td (cd) Tj ET");
+    let tight = LayoutParams { word_gap: 0.3, ..Default::default() };
+    let pages = collect_text(&doc).unwrap();
+    assert_eq!(pages[0].lines()[0].text, "ab cd");
+    let lines = pages[0].lines_with(tight);
+    assert_eq!(lines[0].text, "abcd");
+    // Fragments keep the spacing their line was grouped with
+    assert_eq!(lines[0].fragments()[0].text, "abcd");
+    assert_eq!(pages[0].lines()[0].fragments()[0].text, "ab cd");
+    let text = |layout| Extractor::new().layout(layout).layout_params(tight).build().text(&doc).unwrap();
+    assert_eq!(text(TextLayout::Plain).trim(), "abcd");
+    assert_eq!(text(TextLayout::Paragraphs).trim(), "abcd");
+}
+
+#[test]
+fn page_separators_mark_page_boundaries() {
+    use pdf_extract::{output_doc, PlainTextOutput};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (one) Tj ET", "BT /F1 12 Tf 72 700 Td (two) Tj ET", "BT /F1 12 Tf 72 700 Td (three) Tj ET"]);
+    let mut out = Vec::new();
+    output_doc(&doc, &mut PlainTextOutput::new(&mut out).page_separator("\x0c")).unwrap();
+    let pages: Vec<String> = String::from_utf8(out).unwrap().split('\x0c').map(|p| p.trim().to_string()).collect();
+    assert_eq!(pages, ["one", "two", "three"]);
+}
+
+#[test]
+fn line_endings_are_configurable() {
+    use pdf_extract::{output_doc, LineEnding, PlainTextOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (one) Tj 0 -14 Td (two) Tj ET");
+    let text = |output: fn(&mut Vec<u8>) -> PlainTextOutput<&mut Vec<u8>>| {
+        let mut out = Vec::new();
+        output_doc(&doc, &mut output(&mut out)).unwrap();
+        String::from_utf8(out).unwrap().trim_start().to_string()
+    };
+    assert_eq!(text(|out| PlainTextOutput::new(out)), "one\ntwo");
+    assert_eq!(text(|out| PlainTextOutput::new(out).line_ending(LineEnding::CrLf).trailing_newline(true)), "one\r\ntwo\r\n");
+}
+
+#[test]
+fn crop_box_sets_page_geometry_and_clips_text() {
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, Object, PageBox};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 100 100 Td (in) Tj 300 600 Td (out) Tj ET");
+    let page_id = doc.get_pages()[&1];
+    let crop_box: Vec<Object> = vec![50.into(), 50.into(), 300.into(), 300.into()];
+    doc.get_dictionary_mut(page_id).unwrap().set("CropBox", crop_box);
+    let collect = |options: &ExtractionOptions| {
+        let mut collector = TextCollector::new();
+        output_doc_with_options(&doc, &mut collector, options).unwrap();
+        let page = collector.into_pages().remove(0);
+        let text: String = page.glyphs.iter().map(|g| g.text.as_str()).collect();
+        ((page.media_box.llx, page.media_box.urx), text)
+    };
+    assert_eq!(collect(&ExtractionOptions::default()), ((0., 612.), "inout".to_string()));
+    let options = ExtractionOptions { page_box: PageBox::CropBox, clip_to_page_box: true, ..Default::default() };
+    assert_eq!(collect(&options), ((50., 300.), "in".to_string()));
+}
+
+#[test]
+fn word_spacing_applies_to_single_byte_code_32() {
+    use pdf_extract::layout::collect_text;
+    use pdf_extract::{dictionary, Object, Stream};
+
+    let mut doc = simple_doc("BT /F2 10 Tf 20 Tw 72 700 Td <412041> Tj ET");
+    // One-byte codes; the space maps to CID 3 and `A` to CID 32
+    let cmap = doc.add_object(Stream::new(
+        dictionary! { "Type" => "CMap" },
+        b"1 begincodespacerange <00> <FF> endcodespacerange \
+          2 begincidrange <20> <20> 3 <41> <41> 32 endcidrange".to_vec(),
+    ));
+    let descendant = doc.add_object(dictionary! {
+        "Type" => "Font", "Subtype" => "CIDFontType2", "BaseFont" => "Test", "DW" => 500,
+    });
+    let font = doc.add_object(dictionary! {
+        "Type" => "Font", "Subtype" => "Type0", "BaseFont" => "Test", "Encoding" => cmap,
+        "DescendantFonts" => vec![Object::Reference(descendant)],
+    });
+    let page_id = doc.get_pages()[&1];
+    let page = doc.get_dictionary_mut(page_id).unwrap();
+    let mut resources = page.get(b"Resources").unwrap().as_dict().unwrap().clone();
+    resources.get_mut(b"Font").unwrap().as_dict_mut().unwrap().set("F2", font);
+    page.set("Resources", resources);
+
+    let xs: Vec<f64> = collect_text(&doc).unwrap()[0].glyphs.iter().map(|g| g.x).collect();
+    // 5 units per glyph, plus the word spacing after the space only
+    assert_eq!(xs, [72., 77., 102.]);
+}
+
+#[test]
+fn tj_adjustments_decide_spaces_in_letter_spaced_text() {
+    use pdf_extract::{output_doc, PlainTextOutput};
+
+    // Character spacing widens every gap past the word gap threshold
+    let doc = simple_doc("BT /F1 10 Tf 2 Tc 72 700 Td [(ab) -50 (cd) -400 (ef)] TJ ET");
+    let mut out = Vec::new();
+    output_doc(&doc, &mut PlainTextOutput::new(&mut out)).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().trim(), "abcd ef");
+}
+
+#[test]
+fn tj_adjustments_end_with_their_array() {
+    use pdf_extract::{output_doc, PlainTextOutput};
+
+    // The trailing kern belongs to the array, not to the text shown after the move
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td [(ab) 50] TJ 100 0 Td (cd) Tj ET");
+    let mut out = Vec::new();
+    output_doc(&doc, &mut PlainTextOutput::new(&mut out)).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap().trim(), "ab cd");
+}
+
+#[test]
+fn word_gaps_scale_with_the_space_width() {
+    use pdf_extract::{dictionary, extract_text_from_mem};
+
+    // Both fonts show strings 0.2 em apart, a word gap for Helvetica's narrow space only
+    let mut doc = simple_doc("BT /F1 10 Tf 72 700 Td [(ab) -200 (cd)] TJ ET BT /F2 10 Tf 72 600 Td [(ab) -200 (cd)] TJ ET");
+    let courier = doc.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Courier" });
+    let page_id = doc.get_pages()[&1];
+    let page = doc.get_dictionary_mut(page_id).unwrap();
+    let mut resources = page.get(b"Resources").unwrap().as_dict().unwrap().clone();
+    resources.get_mut(b"Font").unwrap().as_dict_mut().unwrap().set("F2", courier);
+    page.set("Resources", resources);
+    let mut pdf = Vec::new();
+    doc.save_to(&mut pdf).unwrap();
+    let text = extract_text_from_mem(&pdf).unwrap();
+    assert_eq!(text.split_whitespace().collect::<Vec<_>>(), ["ab", "cd", "abcd"]);
+}
+
+#[test]
+fn html_output_escapes_markup_characters() {
+    use pdf_extract::{output_doc, HTMLOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (<b> & 'q'  \"x\") Tj ET");
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains(">&lt;b&gt; &amp; &apos;q&apos;&nbsp;&nbsp;&quot;x&quot;</div>"), "{html}");
+}
+
+#[test]
+fn html_output_styles_text_by_font() {
+    use pdf_extract::{dictionary, output_doc, HTMLOutput};
+
+    let mut doc = simple_doc("BT /F1 12 Tf 72 700 Td (a) Tj /F2 12 Tf (b) Tj /F3 12 Tf /DeviceRGB cs 0 0 1 sc (c) Tj ET");
+    let times = doc.add_object(dictionary! {
+        "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "ABCDEF+TimesNewRomanPS-BoldItalicMT",
+    });
+    let descriptor = doc.add_object(dictionary! { "Type" => "FontDescriptor", "FontName" => "Typewriter", "Flags" => 1 | 1 << 18 });
+    let typewriter = doc.add_object(dictionary! {
+        "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Typewriter", "FontDescriptor" => descriptor,
+    });
+    let page_id = doc.get_pages()[&1];
+    let page = doc.get_dictionary_mut(page_id).unwrap();
+    let mut resources = page.get(b"Resources").unwrap().as_dict().unwrap().clone();
+    let fonts = resources.get_mut(b"Font").unwrap().as_dict_mut().unwrap();
+    fonts.set("F2", times);
+    fonts.set("F3", typewriter);
+    page.set("Resources", resources);
+
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("px; font-family: Helvetica, sans-serif; font-size: 12px'>a</div>"), "{html}");
+    assert!(html.contains("px; font-family: Times New Roman, serif; font-weight: bold; font-style: italic; font-size: 12px'>b</div>"), "{html}");
+    assert!(html.contains("px; font-family: Typewriter, monospace; font-weight: bold; font-size: 12px; color: #0000ff'>c</div>"), "{html}");
+}
+
+#[test]
+fn html_output_transforms_rotated_and_skewed_text() {
+    use pdf_extract::{output_doc, HTMLOutput};
+
+    let doc = simple_doc("BT /F1 10 Tf 72 700 Td (flat) Tj 0 2 -2 0 100 200 Tm (up) Tj 1 0 0.5 1 300 400 Tm (slanted) Tj ET");
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("font-size: 10px'>flat</div>"), "{html}");
+    assert!(html.contains("left: 100px; top: 592px; font-family: Helvetica, sans-serif; font-size: 20px; \
+                           transform: matrix(0, -1, 1, 0, 0, 0); transform-origin: 0 0'>up</div>"), "{html}");
+    assert!(html.contains("font-size: 10px; transform: matrix(1, 0, -0.5, 1, 0, 0); transform-origin: 0 0'>slanted</div>"), "{html}");
+}
+
+#[test]
+fn flow_output_writes_semantic_markup() {
+    use pdf_extract::{output_doc, HtmlFlowOutput};
+
+    let doc = simple_doc("BT /F1 24 Tf 72 720 Td (Title) Tj ET \
+                          BT /F1 10 Tf 72 680 Td (Text & more) Tj 0 -12 Td (continues) Tj ET \
+                          BT /F1 10 Tf 72 600 Td (- first) Tj 0 -12 Td (- second) Tj 0 -12 Td (wrapped) Tj ET \
+                          BT /F1 10 Tf 72 540 Td (1. one) Tj ET \
+                          BT /F1 10 Tf 72 480 Td (Name) Tj 100 0 Td (Qty) Tj -100 -12 Td (Apple) Tj 100 0 Td (3) Tj ET");
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HtmlFlowOutput::new(&mut html)).unwrap();
+    assert_eq!(String::from_utf8(html).unwrap(), "<section id='page1'>\n\
+        <h1>Title</h1>\n\
+        <p>Text &amp; more continues</p>\n\
+        <ul>\n<li>first</li>\n<li>second wrapped</li>\n</ul>\n\
+        <ol>\n<li>one</li>\n</ol>\n\
+        <table>\n<tr><th>Name</th><th>Qty</th></tr>\n<tr><td>Apple</td><td>3</td></tr>\n</table>\n\
+        </section>\n");
+}
+
+#[test]
+fn flow_output_body_size_follows_the_pages_so_far() {
+    use pdf_extract::{output_doc, HtmlFlowOutput};
+
+    // The cover alone sets the body size; after the second page's text the large lines are headings
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 20 Tf 72 700 Td (Cover) Tj ET",
+                              "BT /F1 20 Tf 72 720 Td (Chapter) Tj ET \
+                               BT /F1 10 Tf 72 680 Td (One) Tj ET BT /F1 10 Tf 72 640 Td (Two) Tj ET BT /F1 10 Tf 72 600 Td (Three) Tj ET"]);
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HtmlFlowOutput::new(&mut html)).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("<section id='page1'>\n<p>Cover</p>\n</section>"), "{html}");
+    assert!(html.contains("<section id='page2'>\n<h1>Chapter</h1>\n<p>One</p>"), "{html}");
+}
+
+#[test]
+fn html_output_writes_a_document_with_page_index() {
+    use pdf_extract::{output_doc, output_doc_page, HTMLOutput};
+
+    let mut doc = simple_doc("");
+    replace_pages(&mut doc, &["BT /F1 12 Tf 72 700 Td (one) Tj ET", "BT /F1 12 Tf 72 700 Td (two) Tj ET"]);
+    let mut html = Vec::new();
+    output_doc(&doc, &mut HTMLOutput::new(&mut html).stylesheet("pages.css")).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset='utf-8' />\n\
+                              <link rel='stylesheet' href='pages.css' />\n</head>\n<body>\n\
+                              <nav><a href='#page1'>1</a> <a href='#page2'>2</a></nav>\n\
+                              <!-- page 1 --><div id='page1' "), "{html}");
+    assert!(html.contains("</div><!-- page 2 --><div id='page2' "), "{html}");
+    assert!(html.ends_with("</div>\n</body>\n</html>\n"), "{html}");
+
+    let mut html = Vec::new();
+    output_doc_page(&doc, &mut HTMLOutput::new(&mut html), 2).unwrap();
+    let html = String::from_utf8(html).unwrap();
+    assert!(html.contains("<body>\n<!-- page 2 -->"), "{html}");
+}
+
+#[test]
+fn svg_output_contains_text() {
+    use pdf_extract::{output_doc, SVGOutput};
+
+    let doc = simple_doc("BT /F1 12 Tf 72 700 Td (a<b) Tj 0 -20 Td (c) Tj 0 1 -1 0 300 400 Tm (up) Tj ET");
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<text transform='matrix(1, 0, 0, -1, 72, 700)' font-size='12' font-family='Helvetica, sans-serif' \
+                          xml:space='preserve'><tspan x='0'>a</tspan><tspan x='6.672'>&lt;</tspan><tspan x='13.68'>b</tspan></text>\n\
+                          <text transform='matrix(1, 0, 0, -1, 72, 680)' "), "{svg}");
+    assert!(svg.contains("<text transform='matrix(0, 1, 1, 0, 300, 400)' font-size='12' font-family='Helvetica, sans-serif' \
+                          xml:space='preserve'><tspan x='0'>u</tspan><tspan x='6.672'>p</tspan></text>\n</g>"), "{svg}");
+}
+
+#[test]
+fn svg_output_strokes_with_line_style() {
+    use pdf_extract::{output_doc, SVGOutput};
+
+    let doc = simple_doc("2 w 1 J 2 j [3 1] 0.5 d 10 10 m 100 10 l S 0 w 0 J 0 j 4 M [] 0 d 20 20 m 30 30 l s");
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<path d='M10 10 L100 10' fill='none' stroke='#000000' stroke-width='2' stroke-linecap='round' \
+                          stroke-linejoin='bevel' stroke-dasharray='3 1' stroke-dashoffset='0.5' />"), "{svg}");
+    assert!(svg.contains("<path d='M20 20 L30 30 Z' fill='none' stroke='#000000' stroke-width='1' vector-effect='non-scaling-stroke' />"), "{svg}");
+}
+
+#[test]
+fn svg_output_converts_colours_to_srgb() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput};
+
+    let mut doc = simple_doc("/DeviceRGB cs 1 0 0 sc 0 0 10 10 re f /DeviceCMYK CS 0 1 0 0 SC 0 0 m 10 10 l S /Spot cs 0.5 scn 20 20 10 10 re f");
+    let tint = doc.add_object(dictionary! {
+        "FunctionType" => 2,
+        "Domain" => vec![0.into(), 1.into()],
+        "C0" => vec![1.into(), 1.into(), 1.into()],
+        "C1" => vec![0.into(), 0.into(), 1.into()],
+        "N" => 1,
+    });
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Spot" => vec![Object::Name(b"Separation".to_vec()), Object::Name(b"Blue".to_vec()), Object::Name(b"DeviceRGB".to_vec()), tint.into()],
+    });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("fill='#ff0000'"), "{svg}");
+    assert!(svg.contains("stroke='#ff00ff'"), "{svg}");
+    // Half the tint of blue over white
+    assert!(svg.contains("fill='#8080ff'"), "{svg}");
+}
+
+#[test]
+fn device_colour_operators_need_their_component_counts() {
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, SVGOutput, Strictness};
+
+    // `0 1 rg` is one component short and keeps the gray set before it
+    let doc = simple_doc("0.5 g 0 1 rg 0 0 10 10 re f 0 0 1 RG 0 0 0 1 k 0 0 m 10 10 l B");
+    let mut svg = Vec::new();
+    let options = ExtractionOptions { strictness: Strictness::Lenient, ..Default::default() };
+    output_doc_with_options(&doc, &mut SVGOutput::new(&mut svg), &options).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let fills = svg_fills(&svg);
+    assert_eq!(fills, ["#808080", "#000000"], "{svg}");
+    assert!(svg.contains("stroke='#0000ff'"), "{svg}");
+}
+
+#[test]
+fn svg_output_clips_to_clipping_paths() {
+    use pdf_extract::{output_doc, SVGOutput};
+
+    let doc = simple_doc("q 10 10 50 50 re W n 0 0 100 100 re f q 0 0 m 80 0 l 0 80 l h W* n 0 0 5 5 re f Q Q 200 200 5 5 re f");
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<clipPath id='clip1'><path transform='matrix(1, 0, 0, 1, 0, 0)' d='M10 10 L60 10 L60 60 L10 60 Z' /></clipPath>\n\
+                          <g clip-path='url(#clip1)'>"), "{svg}");
+    assert!(svg.contains("d='M0 0 L80 0 L0 80 Z' clip-rule='evenodd' /></clipPath>\n<g clip-path='url(#clip2)'>"), "{svg}");
+    // Restoring the state closes both groups before the unclipped fill
+    assert!(svg.contains("fill='#000000' /></g>\n</g>\n</g>\n<g transform='matrix(1, 0, 0, 1, 0, 0)'><path d='M200 200"), "{svg}");
+}
+
+#[test]
+fn svg_output_embeds_images() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput, Stream};
+
+    let mut doc = simple_doc("q 20 0 0 10 72 600 cm BI /W 2 /H 1 /CS /RGB /BPC 8 ID \x7f\0\0\0\x7f\0 EI Q \
+                              1 0 0 rg q 8 0 0 8 0 0 cm /Im1 Do Q /Im2 Do /Im3 Do");
+    // Dimensions whose sample count overflows, and ones whose pixels are too many to convert
+    let huge = |width: i64, height: i64| Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => width,
+        "Height" => height,
+        "BitsPerComponent" => 8,
+    }, vec![0; 16]);
+    let overflowing = doc.add_object(huge(1 << 32, 1 << 32));
+    let oversized = doc.add_object(huge(1 << 32, 1));
+    let mask_id = doc.add_object(Stream::new(dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => 8,
+        "Height" => 1,
+        "ImageMask" => true,
+    }, vec![0x0f]));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("XObject", dictionary! { "Im1" => Object::Reference(mask_id), "Im2" => overflowing, "Im3" => oversized });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    // The base64 of a PNG signature
+    let png = "<image width='1' height='1' transform='matrix(1, 0, 0, -1, 0, 1)' preserveAspectRatio='none' \
+               xlink:href='data:image/png;base64,iVBORw0KGgo";
+    assert!(svg.contains(&format!("<g transform='matrix(20, 0, 0, 10, 72, 600)'>{png}")), "{svg}");
+    assert!(svg.contains(&format!("<g transform='matrix(8, 0, 0, 8, 0, 0)'>{png}")), "{svg}");
+    assert_eq!(svg.matches("<image").count(), 2, "{svg}");
+}
+
+#[test]
+fn image_colour_spaces_by_reference_or_name_are_resolved() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput, Stream, StringFormat};
+
+    // Two pixels indexing red and blue, the palette given directly, by reference and by resource name
+    let mut doc = simple_doc("/Im1 Do /Im2 Do /Im3 Do");
+    let indexed = |lookup: Object| Object::Array(vec!["Indexed".into(), "DeviceRGB".into(), 1.into(), lookup]);
+    let image = |cs: Object| Stream::new(dictionary! {
+        "Type" => "XObject", "Subtype" => "Image", "Width" => 2, "Height" => 1, "BitsPerComponent" => 8, "ColorSpace" => cs,
+    }, vec![0, 1]);
+    let palette = vec![255, 0, 0, 0, 0, 255];
+    let mut lookup = Stream::new(dictionary! {}, palette.clone());
+    lookup.compress().unwrap();
+    let lookup = doc.add_object(lookup);
+    let by_reference = doc.add_object(indexed(lookup.into()));
+    let direct = doc.add_object(image(indexed(Object::String(palette.clone(), StringFormat::Hexadecimal))));
+    let referenced = doc.add_object(image(by_reference.into()));
+    let named = doc.add_object(image("P1".into()));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("XObject", dictionary! { "Im1" => direct, "Im2" => referenced, "Im3" => named });
+    resources.set("ColorSpace", dictionary! { "P1" => indexed(Object::String(palette, StringFormat::Literal)) });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let pngs: Vec<&str> = svg.match_indices("base64,").map(|(i, _)| &svg[i..i + svg[i..].find('\'').unwrap()]).collect();
+    assert_eq!(pngs.len(), 3, "{svg}");
+    assert!(pngs[1] == pngs[0] && pngs[2] == pngs[0], "{svg}");
+}
+
+#[test]
+fn text_outside_clipping_paths_can_be_left_out() {
+    use pdf_extract::layout::TextCollector;
+    use pdf_extract::{output_doc_with_options, ClipPath, ExtractionOptions, FillRule, Path, PathOp};
+
+    let doc = simple_doc(
+        "q 0 0 100 100 re W n BT /F1 12 Tf 10 10 Td (Inside) Tj 200 0 Td (Outside) Tj ET Q \
+         BT /F1 12 Tf 300 300 Td (Unclipped) Tj ET",
+    );
+    let run = |clip_to_clip_paths| {
+        let mut collector = TextCollector::new();
+        let options = ExtractionOptions { clip_to_clip_paths, ..Default::default() };
+        output_doc_with_options(&doc, &mut collector, &options).unwrap();
+        let pages = collector.into_pages();
+        let mut lines: Vec<String> = pages[0].lines().iter().map(|l| l.text.clone()).collect();
+        lines.sort();
+        lines
+    };
+    assert_eq!(run(false), ["Inside Outside", "Unclipped"]);
+    assert_eq!(run(true), ["Inside", "Unclipped"]);
+
+    // A square with a hole by the even-odd rule, scaled up by the ctm
+    let path = Path { ops: vec![PathOp::Rect(0., 0., 10., 10.), PathOp::Rect(2., 2., 6., 6.)] };
+    let clip = ClipPath::new(pdf_extract::PdfTransform::scale(2., 2.), path.clone(), FillRule::EvenOdd);
+    assert!(clip.contains(2., 2.));
+    assert!(!clip.contains(10., 10.));
+    assert!(!clip.contains(25., 5.));
+    assert!(ClipPath::new(clip.ctm, path, FillRule::NonZero).contains(10., 10.));
+}
+
+#[test]
+fn invisible_text_can_be_skipped_or_tagged() {
+    use pdf_extract::layout::{write_json_lines, TextCollector};
+    use pdf_extract::{output_doc_with_options, ExtractionOptions, InvisibleText};
+
+    // A visible caption over a hidden OCR layer, and text back in fill mode after it
+    let doc = simple_doc(
+        "BT /F1 12 Tf 72 700 Td (Caption) Tj ET \
+         BT 3 Tr /F1 12 Tf 72 600 Td (Hidden) Tj 0 Tr 100 0 Td (Shown) Tj ET",
+    );
+    let run = |invisible_text| {
+        let mut collector = TextCollector::new();
+        let options = ExtractionOptions { invisible_text, ..Default::default() };
+        output_doc_with_options(&doc, &mut collector, &options).unwrap();
+        let mut json = Vec::new();
+        write_json_lines(&collector.into_pages(), &mut json).unwrap();
+        String::from_utf8(json).unwrap()
+    };
+    let kept = run(InvisibleText::Keep);
+    let line: serde_json::Value = kept.lines().map(|l| serde_json::from_str(l).unwrap())
+        .find(|l: &serde_json::Value| l["text"] == "Hidden Shown")
+        .unwrap_or_else(|| panic!("{kept}"));
+    let fragments = line["fragments"].as_array().unwrap();
+    assert_eq!(fragments.len(), 2, "{kept}");
+    assert_eq!((&fragments[0]["text"], &fragments[0]["alpha"], &fragments[0]["invisible"]), (&"Hidden ".into(), &0.0.into(), &true.into()), "{kept}");
+    assert_eq!(fragments[1]["text"], "Shown", "{kept}");
+    assert_eq!(kept.matches("invisible").count(), 1, "{kept}");
+    let skipped = run(InvisibleText::Skip);
+    assert!(!skipped.contains("Hidden") && !skipped.contains("invisible"), "{skipped}");
+    assert!(skipped.contains(r#""text":"Caption""#) && skipped.contains(r#""text":"Shown""#), "{skipped}");
+}
+
+#[test]
+fn svg_output_fills_by_the_even_odd_rule() {
+    use pdf_extract::{output_doc, SVGOutput};
+
+    let doc = simple_doc("0 0 m 100 0 l 100 100 l h 25 25 m 75 25 l 75 75 l h f* /DeviceRGB CS 0 0 1 SC 10 10 m 20 10 l 20 20 l b");
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<path d='M0 0 L100 0 L100 100 Z M25 25 L75 25 L75 75 Z' fill='#000000' fill-rule='evenodd' />"), "{svg}");
+    // `b` closes, fills and strokes the path
+    assert!(svg.contains("<path d='M10 10 L20 10 L20 20 Z' fill='#000000' />"), "{svg}");
+    assert!(svg.contains("<path d='M10 10 L20 10 L20 20 Z' fill='none' stroke='#0000ff' stroke-width='1' stroke-miterlimit='10' />"), "{svg}");
+}
+
+#[test]
+fn svg_output_paints_shadings_as_gradients() {
+    use pdf_extract::report::WarningKind;
+    use pdf_extract::{dictionary, output_doc_with_diagnostics, ExtractionOptions, SVGOutput};
+
+    let mut doc = simple_doc("/Sh1 sh /Pattern cs /P1 scn 10 10 50 50 re f 0 0 1 RG /Pattern CS /P1 SCN 0 0 m 70 70 l S");
+    let function = doc.add_object(dictionary! {
+        "FunctionType" => 2,
+        "Domain" => vec![0.into(), 1.into()],
+        "C0" => vec![1.into(), 0.into(), 0.into()],
+        "C1" => vec![0.into(), 0.into(), 1.into()],
+        "N" => 1,
+    });
+    let axial = dictionary! {
+        "ShadingType" => 2,
+        "ColorSpace" => "DeviceRGB",
+        "Coords" => vec![0.into(), 0.into(), 100.into(), 0.into()],
+        "Function" => function,
+        "Extend" => vec![true.into(), true.into()],
+    };
+    let radial = dictionary! {
+        "ShadingType" => 3,
+        "ColorSpace" => "DeviceRGB",
+        "Coords" => vec![35.into(), 35.into(), 0.into(), 35.into(), 35.into(), 25.into()],
+        "Function" => function,
+    };
+    let resources = page_resources_mut(&mut doc);
+    resources.set("Shading", dictionary! { "Sh1" => axial });
+    resources.set("Pattern", dictionary! { "P1" => dictionary! { "PatternType" => 2, "Shading" => radial } });
+    let mut svg = Vec::new();
+    let diagnostics = output_doc_with_diagnostics(&doc, &mut SVGOutput::new(&mut svg), &ExtractionOptions::default()).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let stops = "<stop offset='0' stop-color='#ff0000' /><stop offset='1' stop-color='#0000ff' />";
+    assert!(svg.contains(&format!("<linearGradient id='shade1' gradientUnits='userSpaceOnUse' x1='0' y1='0' x2='100' y2='0'>{stops}</linearGradient>\
+                                   <rect transform='matrix(1, 0, 0, 1, 0, 0)' x='0' y='0' width='612' height='792' fill='url(#shade1)' />")), "{svg}");
+    // The pattern fill paints the shading clipped to the rectangle
+    assert!(svg.contains("<clipPath id='clip1'><path transform='matrix(1, 0, 0, 1, 0, 0)' d='M10 10 L60 10 L60 60 L10 60 Z' /></clipPath>"), "{svg}");
+    assert!(svg.contains(&format!("<radialGradient id='shade2' gradientUnits='userSpaceOnUse' cx='35' cy='35' r='25' fx='35' fy='35' fr='0'>{stops}</radialGradient>\
+                                   <circle cx='35' cy='35' r='25' fill='url(#shade2)' />")), "{svg}");
+    assert!(svg.contains("<circle cx='35' cy='35' r='25' fill='url(#shade2)' /></g>\n</g>\n"), "{svg}");
+    // Shadings aren't painted along strokes, which fall back to black
+    assert!(svg.contains("<path d='M0 0 L70 70' fill='none' stroke='#000000' stroke-width='1' stroke-miterlimit='10' /></g>\n</g>\n</svg>"), "{svg}");
+    assert_eq!(diagnostics.report.count(WarningKind::Unsupported), 1);
+}
+
+#[test]
+fn tiling_pattern_cells_run_once_and_become_svg_patterns() {
+    use pdf_extract::{dictionary, output_doc, PlainTextOutput, SVGOutput, Stream};
+
+    let mut doc = simple_doc("/Pattern cs /P1 scn 0 0 300 300 re f BT /F1 12 Tf 10 700 Td (After) Tj ET");
+    let cell = b"0 0 1 rg 0 0 20 20 re f BT /F1 10 Tf 2 5 Td (Cell) Tj ET".to_vec();
+    let pattern = doc.add_object(Stream::new(dictionary! {
+        "PatternType" => 1,
+        "PaintType" => 1,
+        "TilingType" => 1,
+        "BBox" => vec![0.into(), 0.into(), 40.into(), 30.into()],
+        "XStep" => 50,
+        "YStep" => 40,
+        "Matrix" => vec![1.into(), 0.into(), 0.into(), 1.into(), 100.into(), 100.into()],
+    }, cell));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("Pattern", dictionary! { "P1" => pattern });
+    // Text in the cell is seen once, with the page's fonts when the pattern has no resources
+    let mut text = Vec::new();
+    output_doc(&doc, &mut PlainTextOutput::new(&mut text)).unwrap();
+    let text = String::from_utf8(text).unwrap();
+    assert_eq!(text.split_whitespace().collect::<Vec<_>>(), ["Cell", "After"], "{text}");
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<pattern id='pattern1' patternUnits='userSpaceOnUse' x='0' y='0' width='50' height='40' \
+                          patternTransform='matrix(1, 0, 0, 1, 100, 100)'><g transform='matrix(1, 0, 0, 1, -100, -100)'>"), "{svg}");
+    // The cell is clipped to its BBox inside the pattern and the filled path outside it
+    assert!(svg.contains("<clipPath id='clip1'><path transform='matrix(1, 0, 0, 1, 0, 0)' d='M0 0 L300 0 L300 300 L0 300 Z' /></clipPath>"), "{svg}");
+    assert!(svg.contains("<clipPath id='clip2'><path transform='matrix(1, 0, 0, 1, 100, 100)' d='M0 0 L40 0 L40 30 L0 30 Z' /></clipPath>"), "{svg}");
+    assert!(svg.contains("</g></pattern>\n<rect x='0' y='0' width='612' height='792' fill='url(#pattern1)' />\n</g>"), "{svg}");
+    assert_eq!(svg.matches("<g").count(), svg.matches("</g>").count(), "{svg}");
+}
+
+#[test]
+fn uncoloured_tiling_patterns_paint_in_the_scn_colour() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput, Stream};
+
+    let mut doc = simple_doc("/P0 cs 1 0 0 /P1 scn 0 0 300 300 re f /P0 CS 0 0 1 /P1 SCN 0 0 m 300 300 l S");
+    let pattern = doc.add_object(Stream::new(dictionary! {
+        "PatternType" => 1,
+        "PaintType" => 2,
+        "TilingType" => 1,
+        "BBox" => vec![0.into(), 0.into(), 40.into(), 30.into()],
+        "XStep" => 50,
+        "YStep" => 40,
+    }, b"0 0 20 20 re f 0 0 m 20 20 l S".to_vec()));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("Pattern", dictionary! { "P1" => pattern });
+    resources.set("ColorSpace", dictionary! { "P0" => vec![Object::Name(b"Pattern".to_vec()), "DeviceRGB".into()] });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let cell = &svg[svg.find("<pattern").unwrap()..svg.find("</pattern>").unwrap()];
+    assert!(cell.contains("fill='#ff0000'") && cell.contains("stroke='#ff0000'"), "{svg}");
+    // Lines stroked with the pattern take its colour
+    assert!(svg.contains("<path d='M0 0 L300 300' fill='none' stroke='#0000ff'"), "{svg}");
+}
+
+#[test]
+fn calculator_tint_transforms_give_alternate_colours() {
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, Object, SVGOutput, Stream, Strictness};
+
+    let mut doc = simple_doc("/Spot cs 1 scn 0 0 10 10 re f /Step cs 0.7 scn 0 0 10 10 re f 0.3 scn 0 0 10 10 re f \
+                              /Deep cs 1 scn 0 0 10 10 re f");
+    let function = |doc: &mut pdf_extract::Document, range: usize, program: &str| {
+        let range: Vec<Object> = (0..range).flat_map(|_| [0.into(), 1.into()]).collect();
+        doc.add_object(Stream::new(dictionary! {
+            "FunctionType" => 4,
+            "Domain" => vec![0.into(), 1.into()],
+            "Range" => range,
+        }, program.as_bytes().to_vec()))
+    };
+    // Magenta with half as much yellow, and a step from black to white
+    let cmyk = function(&mut doc, 4, "{ 0 exch dup 0.5 mul 0 } % C M Y K");
+    let step = function(&mut doc, 1, "{ 0.5 gt { 1 } { 0 } ifelse }");
+    // Nested past the cap, so the colour space falls back to DeviceGray instead of overflowing the stack
+    let deep = function(&mut doc, 1, &format!("{{ {}{}}}", "true { ".repeat(200_000), "} if ".repeat(200_000)));
+    let separation = |name: &str, alternate: &str, function| {
+        vec![Object::Name(b"Separation".to_vec()), Object::Name(name.into()), Object::Name(alternate.into()), Object::Reference(function)]
+    };
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Spot" => separation("Spot", "DeviceCMYK", cmyk),
+        "Step" => separation("Step", "DeviceGray", step),
+        "Deep" => separation("Deep", "DeviceRGB", deep),
+    });
+    let mut svg = Vec::new();
+    let options = ExtractionOptions { strictness: Strictness::Lenient, ..Default::default() };
+    output_doc_with_options(&doc, &mut SVGOutput::new(&mut svg), &options).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let fills = svg_fills(&svg);
+    assert_eq!(fills, ["#ff0080", "#ffffff", "#000000", "#ffffff"], "{svg}");
+}
+
+#[test]
+fn sampled_tint_transforms_interpolate_between_samples() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput, Stream};
+
+    let mut doc = simple_doc("/Spot cs 0.25 scn 0 0 10 10 re f 1 scn 0 0 10 10 re f");
+    // Red, green and blue at tints 0, 0.5 and 1
+    let function = doc.add_object(Stream::new(dictionary! {
+        "FunctionType" => 0,
+        "Domain" => vec![0.into(), 1.into()],
+        "Range" => vec![0.into(), 1.into(), 0.into(), 1.into(), 0.into(), 1.into()],
+        "Size" => vec![3.into()],
+        "BitsPerSample" => 8,
+    }, vec![255, 0, 0, 0, 255, 0, 0, 0, 255]));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Spot" => vec![Object::Name(b"Separation".to_vec()), "Spot".into(), "DeviceRGB".into(), function.into()],
+    });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let fills = svg_fills(&svg);
+    assert_eq!(fills, ["#808000", "#0000ff"], "{svg}");
+}
+
+#[test]
+fn device_n_colours_go_through_the_tint_transform() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput, Stream};
+
+    let mut doc = simple_doc("/Inks cs 1 0.5 scn 0 0 10 10 re f");
+    // Cyan and magenta inks onto the first two CMYK components
+    let function = doc.add_object(Stream::new(dictionary! {
+        "FunctionType" => 4,
+        "Domain" => vec![0.into(), 1.into(), 0.into(), 1.into()],
+        "Range" => vec![0.into(), 1.into(), 0.into(), 1.into(), 0.into(), 1.into(), 0.into(), 1.into()],
+    }, b"{ 0 0 }".to_vec()));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Inks" => vec![
+            Object::Name(b"DeviceN".to_vec()),
+            vec!["Cyan".into(), "Magenta".into()].into(),
+            "DeviceCMYK".into(),
+            function.into(),
+        ],
+    });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("fill='#0080ff'"), "{svg}");
+}
+
+#[test]
+fn icc_profiles_give_component_counts_and_colour_spaces() {
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, Object, SVGOutput, Stream, Strictness};
+
+    // The second `sc` has too few components for the profile and is skipped
+    let mut doc = simple_doc("/Print cs 0 1 0 0 sc 0 0 10 10 re f 1 0 0 sc 0 0 10 10 re f /Lab cs 50 0 0 sc 0 0 10 10 re f");
+    let print = doc.add_object(Stream::new(dictionary! { "N" => 4 }, Vec::new()));
+    let mut header = vec![0; 128];
+    header[16..20].copy_from_slice(b"Lab ");
+    header[36..40].copy_from_slice(b"acsp");
+    let lab = doc.add_object(Stream::new(dictionary! { "N" => 3 }, header));
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Print" => vec![Object::Name(b"ICCBased".to_vec()), print.into()],
+        "Lab" => vec![Object::Name(b"ICCBased".to_vec()), lab.into()],
+    });
+    let mut svg = Vec::new();
+    let options = ExtractionOptions { strictness: Strictness::Lenient, ..Default::default() };
+    output_doc_with_options(&doc, &mut SVGOutput::new(&mut svg), &options).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    let fills = svg_fills(&svg);
+    assert_eq!(fills, ["#ff00ff", "#ff00ff", "#777777"], "{svg}");
+}
+
+#[test]
+fn broken_colour_spaces_fall_back_to_gray() {
+    use pdf_extract::{dictionary, output_doc_with_options, ExtractionOptions, Object, SVGOutput, Strictness};
+
+    // A Lab space without its white point, and one missing from the resources
+    let mut doc = simple_doc("/Broken cs 1 0 0 sc 0 0 10 10 re f /Missing cs 0.5 sc 0 0 10 10 re f");
+    let resources = page_resources_mut(&mut doc);
+    resources.set("ColorSpace", dictionary! {
+        "Broken" => vec![Object::Name(b"Lab".to_vec()), dictionary! {}.into()],
+    });
+    let run = |strictness| {
+        let mut svg = Vec::new();
+        let options = ExtractionOptions { strictness, ..Default::default() };
+        output_doc_with_options(&doc, &mut SVGOutput::new(&mut svg), &options).map(|_| String::from_utf8(svg).unwrap())
+    };
+    assert!(run(Strictness::Strict).is_err());
+    let svg = run(Strictness::Lenient).unwrap();
+    let fills = svg_fills(&svg);
+    assert_eq!(fills, ["#ffffff", "#808080"], "{svg}");
+}
+
+#[test]
+fn stitching_functions_join_gradient_segments() {
+    use pdf_extract::{dictionary, output_doc, Object, SVGOutput};
+
+    let mut doc = simple_doc("/Sh1 sh");
+    let rgb = |r: i64, g: i64, b: i64| -> Vec<Object> { vec![r.into(), g.into(), b.into()] };
+    let segment = |c0, c1| dictionary! { "FunctionType" => 2, "Domain" => vec![0.into(), 1.into()], "C0" => c0, "C1" => c1, "N" => 1 };
+    let first = doc.add_object(segment(rgb(1, 0, 0), rgb(0, 1, 0)));
+    let stitching = dictionary! {
+        "FunctionType" => 3,
+        "Domain" => vec![0.into(), 1.into()],
+        "Functions" => vec![first.into(), segment(rgb(0, 0, 1), rgb(0, 1, 0)).into()],
+        "Bounds" => vec![0.5.into()],
+        // The second segment runs backwards, from green to blue
+        "Encode" => vec![0.into(), 1.into(), 1.into(), 0.into()],
+    };
+    let resources = page_resources_mut(&mut doc);
+    resources.set("Shading", dictionary! {
+        "Sh1" => dictionary! {
+            "ShadingType" => 2,
+            "ColorSpace" => "DeviceRGB",
+            "Coords" => vec![0.into(), 0.into(), 100.into(), 0.into()],
+            "Function" => stitching,
+        },
+    });
+    let mut svg = Vec::new();
+    output_doc(&doc, &mut SVGOutput::new(&mut svg)).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains("<stop offset='0' stop-color='#ff0000' /><stop offset='0.5' stop-color='#00ff00' />\
+                          <stop offset='1' stop-color='#0000ff' />"), "{svg}");
+}